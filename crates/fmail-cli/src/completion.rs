@@ -4,9 +4,9 @@ use std::collections::{BTreeMap, BTreeSet};
 use std::path::PathBuf;
 
 use chrono::{DateTime, Utc};
-use fmail_core::agent_registry::AgentRecord;
+use fmail_core::agent_registry::{AgentPresence, AgentRecord};
 use fmail_core::message::Message;
-use fmail_core::store::TopicSummary;
+use fmail_core::store::{ReadReceipts, TopicSummary};
 
 use crate::{CommandOutput, FmailBackend};
 
@@ -407,6 +407,16 @@ impl FmailBackend for HelpOnlyBackend {
         Err("help-only backend".to_string())
     }
 
+    fn set_agent_state(
+        &self,
+        _name: &str,
+        _state: Option<AgentPresence>,
+        _status: Option<&str>,
+        _host: &str,
+    ) -> Result<AgentRecord, String> {
+        Err("help-only backend".to_string())
+    }
+
     fn hostname(&self) -> String {
         "localhost".to_string()
     }
@@ -435,6 +445,14 @@ impl FmailBackend for HelpOnlyBackend {
         Err("help-only backend".to_string())
     }
 
+    fn read_receipts(&self, _reader: &str) -> Result<ReadReceipts, String> {
+        Err("help-only backend".to_string())
+    }
+
+    fn mark_read(&self, _message_ids: &[String], _reader: &str) -> Result<(), String> {
+        Err("help-only backend".to_string())
+    }
+
     fn init_project(&self, _project_id: Option<&str>) -> Result<(), String> {
         Err("help-only backend".to_string())
     }
@@ -4,9 +4,9 @@ use std::path::PathBuf;
 use std::sync::OnceLock;
 
 use chrono::{DateTime, Utc};
-use fmail_core::agent_registry::AgentRecord;
+use fmail_core::agent_registry::{AgentPresence, AgentRecord};
 use fmail_core::message::Message;
-use fmail_core::store::TopicSummary;
+use fmail_core::store::{ReadReceipts, TopicSummary};
 
 /// Stable crate label used by bootstrap smoke tests.
 pub fn crate_label() -> &'static str {
@@ -66,15 +66,22 @@ fn robot_help_json() -> String {
         "commands": {
             "send": {
                 "usage": "fmail send <topic|@agent> <message>",
-                "flags": ["-f FILE", "--reply-to ID", "--priority low|normal|high"],
+                "flags": [
+                    "-f FILE",
+                    "--reply-to ID",
+                    "--priority low|normal|high",
+                    "--at TIME",
+                    "--in DURATION"
+                ],
                 "examples": [
                     "fmail send task 'implement auth'",
-                    "fmail send @reviewer 'check PR #42'"
+                    "fmail send @reviewer 'check PR #42'",
+                    "fmail send @reviewer 'any update?' --in 1h"
                 ]
             },
             "log": {
                 "usage": "fmail log [topic|@agent] [-n N] [--since TIME]",
-                "flags": ["-n LIMIT", "--since TIME", "--from AGENT", "--json", "-f/--follow"],
+                "flags": ["-n LIMIT", "--since TIME", "--from AGENT", "--json", "-f/--follow", "--group-by thread", "--expand", "--unread", "--mark-read"],
                 "examples": [
                     "fmail log task -n 5",
                     "fmail log @$FMAIL_AGENT --since 1h"
@@ -82,10 +89,11 @@ fn robot_help_json() -> String {
             },
             "messages": {
                 "usage": "fmail messages [-n N] [--since TIME]",
-                "flags": ["-n LIMIT", "--since TIME", "--from AGENT", "--json", "-f/--follow"],
+                "flags": ["-n LIMIT", "--since TIME", "--from AGENT", "--json", "-f/--follow", "--group-by thread", "--expand", "--unread", "--mark-read"],
                 "examples": [
                     "fmail messages -n 50",
-                    "fmail messages --since 30m --json"
+                    "fmail messages --since 30m --json",
+                    "fmail messages --group-by thread --expand"
                 ],
                 "description": "View all public messages across topics and direct messages"
             },
@@ -98,13 +106,15 @@ fn robot_help_json() -> String {
                 ]
             },
             "who": {
-                "usage": "fmail who [--json]",
+                "usage": "fmail who [--json] [--state STATE]",
                 "description": "List agents in project"
             },
             "status": {
-                "usage": "fmail status [message] [--clear]",
+                "usage": "fmail status [message] [--state STATE] [--clear]",
+                "flags": ["--state busy|available|away|offline", "--clear"],
                 "examples": [
                     "fmail status 'working on auth'",
+                    "fmail status --state busy 'reviewing PR'",
                     "fmail status --clear"
                 ]
             },
@@ -178,6 +188,15 @@ pub trait FmailBackend {
     fn register_agent_record(&self, name: &str, host: &str) -> Result<AgentRecord, String>;
     fn set_agent_status(&self, name: &str, status: &str, host: &str)
         -> Result<AgentRecord, String>;
+    /// Set or clear the structured presence state, writing `status` in the
+    /// same update when given.
+    fn set_agent_state(
+        &self,
+        name: &str,
+        state: Option<AgentPresence>,
+        status: Option<&str>,
+        host: &str,
+    ) -> Result<AgentRecord, String>;
     fn hostname(&self) -> String;
     fn agent_name(&self) -> Result<String, String>;
     fn save_message(&self, message: &mut Message) -> Result<String, String>;
@@ -185,6 +204,10 @@ pub trait FmailBackend {
     fn list_topics(&self) -> Result<Option<Vec<TopicSummary>>, String>;
     fn list_message_files(&self, target: Option<&str>) -> Result<Vec<PathBuf>, String>;
     fn read_message_at(&self, path: &std::path::Path) -> Result<Message, String>;
+    /// Messages `reader` has already read.
+    fn read_receipts(&self, reader: &str) -> Result<ReadReceipts, String>;
+    /// Mark messages as read by `reader` only.
+    fn mark_read(&self, message_ids: &[String], reader: &str) -> Result<(), String>;
     /// Initialize the project (create .fmail + project.json).
     fn init_project(&self, project_id: Option<&str>) -> Result<(), String>;
     /// Garbage-collect old messages. Returns dry-run output or empty string.
@@ -229,6 +252,19 @@ impl FmailBackend for FilesystemFmailBackend {
         store.set_agent_status(name, status, host, now)
     }
 
+    fn set_agent_state(
+        &self,
+        name: &str,
+        state: Option<AgentPresence>,
+        status: Option<&str>,
+        host: &str,
+    ) -> Result<AgentRecord, String> {
+        let root = fmail_core::root::discover_project_root(None)?;
+        let store = fmail_core::store::Store::new(&root)?;
+        let now = chrono::Utc::now();
+        store.set_agent_state(name, state, status, host, now)
+    }
+
     fn hostname(&self) -> String {
         std::process::Command::new("hostname")
             .output()
@@ -283,6 +319,18 @@ impl FmailBackend for FilesystemFmailBackend {
         store.read_message(path)
     }
 
+    fn read_receipts(&self, reader: &str) -> Result<ReadReceipts, String> {
+        let root = fmail_core::root::discover_project_root(None)?;
+        let store = fmail_core::store::Store::new(&root)?;
+        store.read_receipts(reader)
+    }
+
+    fn mark_read(&self, message_ids: &[String], reader: &str) -> Result<(), String> {
+        let root = fmail_core::root::discover_project_root(None)?;
+        let store = fmail_core::store::Store::new(&root)?;
+        store.mark_all_read(message_ids, reader, chrono::Utc::now())
+    }
+
     fn init_project(&self, project_id: Option<&str>) -> Result<(), String> {
         let root = fmail_core::root::discover_project_root(None)?;
         let store = fmail_core::store::Store::new(&root)?;
@@ -345,6 +393,13 @@ impl FmailBackend for FilesystemFmailBackend {
             if file_time == DateTime::<Utc>::default() || file_time >= cutoff {
                 continue;
             }
+            // Scheduled messages age from their delivery time, not when they were sent.
+            let scheduled_recently = store
+                .read_message(&file.path)
+                .is_ok_and(|message| message.deliver_after.is_some_and(|at| at >= cutoff));
+            if scheduled_recently {
+                continue;
+            }
 
             if dry_run {
                 let display_path = file
@@ -364,6 +419,9 @@ impl FmailBackend for FilesystemFmailBackend {
             }
         }
 
+        if !dry_run {
+            store.prune_read_receipts()?;
+        }
         Ok(output)
     }
 }
@@ -374,6 +432,7 @@ pub mod gc;
 pub mod init;
 pub mod log;
 pub mod messages;
+pub(crate) mod presence;
 pub mod register;
 pub mod send;
 pub mod status;
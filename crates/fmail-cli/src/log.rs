@@ -7,7 +7,9 @@ use std::thread;
 use std::time::Duration;
 
 use chrono::{DateTime, Utc};
+use fmail_core::format::{render_message, RenderStyle};
 use fmail_core::message::Message;
+use fmail_core::thread::{group_by_thread, MessageThread};
 use fmail_core::validate::{normalize_agent_name, normalize_topic};
 
 use crate::duration::parse_go_duration_seconds;
@@ -47,15 +49,51 @@ pub fn execute_log(
     let since = parse_since(&parsed.since, now)?;
     let from = normalize_from_filter(&parsed.from)?;
 
-    let (mut messages, mut seen) = load_message_entries(backend, &parsed.target, &since, &from)?;
+    // Read receipts belong to the current agent; only resolve it when needed.
+    let reader = if parsed.unread || parsed.mark_read {
+        Some(backend.agent_name().map_err(|e| (1, e))?)
+    } else {
+        None
+    };
+
+    let (mut messages, mut seen) =
+        load_message_entries(backend, &parsed.target, &since, &from, now)?;
+    if parsed.unread {
+        if let Some(reader) = reader.as_deref() {
+            retain_unread(backend, reader, &mut messages)?;
+        }
+    }
     if parsed.limit > 0 && messages.len() > parsed.limit {
         let start = messages.len() - parsed.limit;
         messages = messages[start..].to_vec();
     }
 
     let mut out = String::new();
+    if parsed.group_by_thread {
+        let flat: Vec<Message> = messages.into_iter().map(|(message, _)| message).collect();
+        for thread in group_by_thread(&flat) {
+            write_thread(&mut out, &thread, parsed.style, parsed.expand)
+                .map_err(|e| (1, format!("output: {e}")))?;
+        }
+        if parsed.mark_read {
+            if let Some(reader) = reader.as_deref() {
+                let ids: Vec<String> = flat.iter().map(|message| message.id.clone()).collect();
+                mark_shown_read(backend, reader, ids)?;
+            }
+        }
+        return Ok(CommandOutput {
+            stdout: out,
+            stderr: String::new(),
+            exit_code: 0,
+        });
+    }
     for (message, _) in &messages {
-        write_message(&mut out, message, parsed.json).map_err(|e| (1, format!("output: {e}")))?;
+        write_message(&mut out, message, parsed.style).map_err(|e| (1, format!("output: {e}")))?;
+    }
+    if parsed.mark_read {
+        if let Some(reader) = reader.as_deref() {
+            mark_shown_read(backend, reader, entry_ids(&messages))?;
+        }
     }
 
     if !parsed.follow {
@@ -68,9 +106,24 @@ pub fn execute_log(
 
     loop {
         thread::sleep(LOG_FOLLOW_POLL_INTERVAL);
-        let updates = scan_new_messages(backend, &parsed.target, &since, &from, &mut seen)?;
+        let mut updates = scan_new_messages(
+            backend,
+            &parsed.target,
+            &since,
+            &from,
+            backend.now_utc(),
+            &mut seen,
+        )?;
+        if let Some(reader) = reader.as_deref() {
+            if parsed.unread {
+                retain_unread(backend, reader, &mut updates)?;
+            }
+            if parsed.mark_read {
+                mark_shown_read(backend, reader, entry_ids(&updates))?;
+            }
+        }
         for (message, _) in &updates {
-            write_message(&mut out, message, parsed.json)
+            write_message(&mut out, message, parsed.style)
                 .map_err(|e| (1, format!("output: {e}")))?;
         }
     }
@@ -90,8 +143,12 @@ struct ParsedLogArgs {
     limit: usize,
     since: String,
     from: String,
-    json: bool,
+    style: RenderStyle,
     follow: bool,
+    group_by_thread: bool,
+    expand: bool,
+    unread: bool,
+    mark_read: bool,
 }
 
 fn parse_log_args(args: &[String], all_messages: bool) -> Result<ParsedLogArgs, (i32, String)> {
@@ -105,8 +162,12 @@ fn parse_log_args(args: &[String], all_messages: bool) -> Result<ParsedLogArgs,
     let mut limit = 20usize;
     let mut since = String::new();
     let mut from = String::new();
-    let mut json = false;
+    let mut style = RenderStyle::Line;
     let mut follow = false;
+    let mut group_by_thread = false;
+    let mut expand = false;
+    let mut unread = false;
+    let mut mark_read = false;
 
     let mut idx = 0usize;
     while idx < args.len() {
@@ -120,7 +181,12 @@ fn parse_log_args(args: &[String], all_messages: bool) -> Result<ParsedLogArgs,
                 };
                 return Err((0, help.to_string()));
             }
-            "--json" => json = true,
+            "--json" => style = RenderStyle::Json,
+            "--format" => {
+                idx += 1;
+                let raw = take_flag_value(args, idx, "--format")?;
+                style = RenderStyle::parse(&raw).map_err(|e| (2, e))?;
+            }
             "-n" | "--limit" => {
                 idx += 1;
                 let raw = take_flag_value(args, idx, "--limit")?;
@@ -144,6 +210,23 @@ fn parse_log_args(args: &[String], all_messages: bool) -> Result<ParsedLogArgs,
             "-f" | "--follow" => {
                 follow = true;
             }
+            "--group-by" => {
+                idx += 1;
+                let raw = take_flag_value(args, idx, "--group-by")?;
+                if raw.trim() != "thread" {
+                    return Err((2, format!("invalid --group-by value: {raw} (use thread)")));
+                }
+                group_by_thread = true;
+            }
+            "--expand" => {
+                expand = true;
+            }
+            "--unread" => {
+                unread = true;
+            }
+            "--mark-read" => {
+                mark_read = true;
+            }
             flag if flag.starts_with('-') => {
                 return Err((2, format!("unknown flag: {flag}")));
             }
@@ -161,13 +244,24 @@ fn parse_log_args(args: &[String], all_messages: bool) -> Result<ParsedLogArgs,
         idx += 1;
     }
 
+    if group_by_thread && follow {
+        return Err((2, "--group-by cannot be used with --follow".to_string()));
+    }
+    if expand && !group_by_thread {
+        return Err((2, "--expand requires --group-by thread".to_string()));
+    }
+
     Ok(ParsedLogArgs {
         target,
         limit,
         since,
         from,
-        json,
+        style,
         follow,
+        group_by_thread,
+        expand,
+        unread,
+        mark_read,
     })
 }
 
@@ -197,6 +291,7 @@ fn load_message_entries(
     target: &LogTarget,
     since: &Option<DateTime<Utc>>,
     from: &Option<String>,
+    now: DateTime<Utc>,
 ) -> Result<MessageEntriesWithSeen, (i32, String)> {
     let files = collect_target_files(backend, target).map_err(|e| (1, format!("log: {e}")))?;
     let mut seen = HashSet::with_capacity(files.len());
@@ -204,11 +299,14 @@ fn load_message_entries(
 
     for path in &files {
         let key = path.to_string_lossy().to_string();
-        seen.insert(key.clone());
-
         let message = backend
             .read_message_at(path)
             .map_err(|e| (1, format!("log: read message {}: {e}", path.display())))?;
+        // Scheduled messages stay unseen so --follow picks them up once due.
+        if !message.is_due(now) {
+            continue;
+        }
+        seen.insert(key.clone());
         if matches_log_target(&message, target) && filter_message(&message, since, from) {
             messages.push((message, key));
         }
@@ -223,6 +321,7 @@ fn scan_new_messages(
     target: &LogTarget,
     since: &Option<DateTime<Utc>>,
     from: &Option<String>,
+    now: DateTime<Utc>,
     seen: &mut HashSet<String>,
 ) -> Result<Vec<MessageEntry>, (i32, String)> {
     let files = collect_target_files(backend, target).map_err(|e| (1, format!("follow: {e}")))?;
@@ -234,10 +333,13 @@ fn scan_new_messages(
             continue;
         }
 
-        seen.insert(key.clone());
         let message = backend
             .read_message_at(path)
             .map_err(|e| (1, format!("follow: read message {}: {e}", path.display())))?;
+        if !message.is_due(now) {
+            continue;
+        }
+        seen.insert(key.clone());
         if matches_log_target(&message, target) && filter_message(&message, since, from) {
             updates.push((message, key));
         }
@@ -247,6 +349,39 @@ fn scan_new_messages(
     Ok(updates)
 }
 
+/// Drop messages `reader` has already read, along with the reader's own messages.
+fn retain_unread(
+    backend: &dyn FmailBackend,
+    reader: &str,
+    entries: &mut Vec<MessageEntry>,
+) -> Result<(), (i32, String)> {
+    let receipts = backend
+        .read_receipts(reader)
+        .map_err(|e| (1, format!("load read receipts: {e}")))?;
+    entries.retain(|(message, _)| receipts.is_unread(message, reader));
+    Ok(())
+}
+
+fn mark_shown_read(
+    backend: &dyn FmailBackend,
+    reader: &str,
+    ids: Vec<String>,
+) -> Result<(), (i32, String)> {
+    if ids.is_empty() {
+        return Ok(());
+    }
+    backend
+        .mark_read(&ids, reader)
+        .map_err(|e| (1, format!("mark read: {e}")))
+}
+
+fn entry_ids(entries: &[MessageEntry]) -> Vec<String> {
+    entries
+        .iter()
+        .map(|(message, _)| message.id.clone())
+        .collect()
+}
+
 fn collect_target_files(
     backend: &dyn FmailBackend,
     target: &LogTarget,
@@ -299,27 +434,53 @@ fn filter_message(msg: &Message, since: &Option<DateTime<Utc>>, from: &Option<St
     true
 }
 
-fn write_message(out: &mut String, message: &Message, json_output: bool) -> Result<(), String> {
-    if json_output {
-        let encoded = serde_json::to_string(message).map_err(|e| format!("encode message: {e}"))?;
+fn write_message(out: &mut String, message: &Message, style: RenderStyle) -> Result<(), String> {
+    out.push_str(&render_message(message, style)?);
+    out.push('\n');
+    if style == RenderStyle::Verbose {
+        out.push('\n');
+    }
+    Ok(())
+}
+
+/// Write a thread's root with its reply count, plus the replies when
+/// `expand` is set. Messages without replies print as a plain line.
+fn write_thread(
+    out: &mut String,
+    thread: &MessageThread,
+    style: RenderStyle,
+    expand: bool,
+) -> Result<(), String> {
+    if style == RenderStyle::Json {
+        let mut value = serde_json::json!({
+            "root": thread.root,
+            "reply_count": thread.replies.len(),
+        });
+        if expand {
+            value["replies"] =
+                serde_json::to_value(&thread.replies).map_err(|e| format!("encode thread: {e}"))?;
+        }
+        let encoded = serde_json::to_string(&value).map_err(|e| format!("encode thread: {e}"))?;
         out.push_str(&encoded);
         out.push('\n');
         return Ok(());
     }
 
-    let body = format_body(&message.body);
-    out.push_str(&format!(
-        "{} {} -> {}: {}\n",
-        message.id, message.from, message.to, body
-    ));
-    Ok(())
-}
+    out.push_str(&render_message(&thread.root, style)?);
+    match thread.replies.len() {
+        0 => {}
+        1 => out.push_str(" [1 reply]"),
+        count => out.push_str(&format!(" [{count} replies]")),
+    }
+    out.push('\n');
 
-fn format_body(body: &serde_json::Value) -> String {
-    match body {
-        serde_json::Value::String(s) => s.clone(),
-        other => serde_json::to_string(other).unwrap_or_else(|_| other.to_string()),
+    if expand {
+        for reply in &thread.replies {
+            out.push_str("  ");
+            write_message(out, reply, style)?;
+        }
     }
+    Ok(())
 }
 
 /// Parse the `--since` value into a DateTime filter.
@@ -359,7 +520,7 @@ fn parse_since(value: &str, now: DateTime<Utc>) -> Result<Option<DateTime<Utc>>,
 }
 
 /// Parse a duration string with `d` (day) support plus Go-style durations.
-fn parse_duration_with_days(raw: &str) -> Option<chrono::Duration> {
+pub(crate) fn parse_duration_with_days(raw: &str) -> Option<chrono::Duration> {
     let trimmed = raw.trim();
     if trimmed.is_empty() {
         return None;
@@ -411,7 +572,12 @@ Flags:
       --since string    Filter by time window (e.g. 1h, 2d, 2024-01-15T10:30:00Z)
       --from string     Filter by sender
   -f, --follow          Stream new messages (poll-based)
-      --json            Output as JSON
+      --group-by string Group output (thread: collapse replies under their root)
+      --expand          With --group-by thread, list replies under each root
+      --unread          Only show messages you have not read
+      --mark-read       Mark the shown messages as read
+      --format string   Output format: line, compact, verbose, json (default: line)
+      --json            Output as JSON (same as --format json)
   -h, --help            Help for log";
 
 const HELP_TEXT_MESSAGES: &str = "\
@@ -425,7 +591,12 @@ Flags:
       --since string    Filter by time window (e.g. 1h, 2d, 2024-01-15T10:30:00Z)
       --from string     Filter by sender
   -f, --follow          Stream new messages (poll-based)
-      --json            Output as JSON
+      --group-by string Group output (thread: collapse replies under their root)
+      --expand          With --group-by thread, list replies under each root
+      --unread          Only show messages you have not read
+      --mark-read       Mark the shown messages as read
+      --format string   Output format: line, compact, verbose, json (default: line)
+      --json            Output as JSON (same as --format json)
   -h, --help            Help for messages";
 
 #[cfg(test)]
@@ -508,19 +679,6 @@ mod tests {
         assert!(parse_duration_with_days("invalid").is_none());
     }
 
-    #[test]
-    fn format_body_string() {
-        let body = serde_json::Value::String("hello".to_string());
-        assert_eq!(format_body(&body), "hello");
-    }
-
-    #[test]
-    fn format_body_object() {
-        let body = serde_json::json!({"key": "value"});
-        let formatted = format_body(&body);
-        assert!(formatted.contains("key"));
-    }
-
     #[test]
     fn normalize_from_empty() {
         let result = normalize_from_filter("");
@@ -0,0 +1,38 @@
+//! `--state` flag parsing shared by `fmail status` and `fmail who`.
+
+use fmail_core::agent_registry::AgentPresence;
+
+use crate::CommandOutput;
+
+/// Parse `--state <value>` or `--state=<value>` when `arg` is a state flag,
+/// advancing `i` past a separate value. Returns `Ok(None)` for any other
+/// argument; errors are the usage failure to return as-is.
+pub(crate) fn take_state_flag(
+    arg: &str,
+    args: &[&str],
+    i: &mut usize,
+) -> Result<Option<AgentPresence>, CommandOutput> {
+    let value = if arg == "--state" {
+        match args.get(*i) {
+            Some(v) => {
+                *i += 1;
+                *v
+            }
+            None => return Err(usage_error("flag needs an argument: --state".to_string())),
+        }
+    } else {
+        match arg.strip_prefix("--state=") {
+            Some(v) => v,
+            None => return Ok(None),
+        }
+    };
+    AgentPresence::parse(value).map(Some).map_err(usage_error)
+}
+
+fn usage_error(message: String) -> CommandOutput {
+    CommandOutput {
+        stdout: String::new(),
+        stderr: format!("{message}\n"),
+        exit_code: 2,
+    }
+}
@@ -1,3 +1,5 @@
+use std::collections::HashSet;
+
 use crate::{CommandOutput, FmailBackend};
 
 pub fn run_register_for_test(args: &[&str], backend: &dyn FmailBackend) -> CommandOutput {
@@ -115,20 +117,21 @@ fn register_generated_agent<R: rand::Rng>(
     rng: &mut R,
     host: &str,
 ) -> Result<fmail_core::agent_registry::AgentRecord, String> {
-    for _ in 0..REGISTER_MAX_ATTEMPTS {
-        let candidate = fmail_core::names::random_loop_name_two_part(rng);
-        match backend.register_agent_record(&candidate, host) {
-            Ok(r) => return Ok(r),
-            Err(e) if e == fmail_core::store::ERR_AGENT_EXISTS => continue,
-            Err(e) => return Err(e),
-        }
-    }
+    let mut existing: HashSet<String> = backend
+        .list_agent_records()?
+        .unwrap_or_default()
+        .into_iter()
+        .map(|record| record.name)
+        .collect();
 
     for _ in 0..REGISTER_MAX_ATTEMPTS {
-        let candidate = fmail_core::names::random_loop_name_three_part(rng);
+        let candidate = fmail_core::names::generate_unique_with(rng, &existing)?;
         match backend.register_agent_record(&candidate, host) {
             Ok(r) => return Ok(r),
-            Err(e) if e == fmail_core::store::ERR_AGENT_EXISTS => continue,
+            // Another agent took the name since the listing; avoid it and retry.
+            Err(e) if e == fmail_core::store::ERR_AGENT_EXISTS => {
+                existing.insert(candidate);
+            }
             Err(e) => return Err(e),
         }
     }
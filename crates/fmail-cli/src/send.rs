@@ -1,8 +1,10 @@
 //! fmail send command ported from Go `internal/fmail/send.go`.
 
+use chrono::{DateTime, Utc};
 use fmail_core::message::{parse_message_body, Message};
 use fmail_core::validate::{normalize_tags, normalize_target, validate_priority};
 
+use crate::log::parse_duration_with_days;
 use crate::{CommandOutput, FmailBackend};
 
 /// Run the send command from test arguments.
@@ -53,6 +55,7 @@ fn execute_send(
         normalize_tags(&parsed.tags).map_err(|e| (1, format!("invalid tags: {e}")))?;
 
     let now = backend.now_utc();
+    let deliver_after = resolve_deliver_after(&parsed, now)?;
     let host = backend.hostname();
 
     let mut message = Message {
@@ -65,6 +68,7 @@ fn execute_send(
         priority,
         host,
         tags: normalized_tags,
+        deliver_after,
     };
 
     let id = backend.save_message(&mut message).map_err(|e| (1, e))?;
@@ -117,6 +121,51 @@ fn resolve_body(
     parse_message_body(&raw).map_err(|e| (1, e))
 }
 
+/// Resolve `--at` / `--in` into a delivery time. Times not in the future
+/// deliver immediately.
+fn resolve_deliver_after(
+    parsed: &ParsedSendArgs,
+    now: DateTime<Utc>,
+) -> Result<Option<DateTime<Utc>>, (i32, String)> {
+    let at = parsed.at.trim();
+    let delay = parsed.delay.trim();
+    if !at.is_empty() && !delay.is_empty() {
+        return Err((2, "use either --at or --in, not both".to_string()));
+    }
+
+    let deliver_after = if !delay.is_empty() {
+        let duration = parse_duration_with_days(delay)
+            .filter(|d| *d >= chrono::Duration::zero())
+            .ok_or_else(|| {
+                (
+                    2,
+                    "invalid --in value: use a duration like '30m' or '1h'".to_string(),
+                )
+            })?;
+        now + duration
+    } else if !at.is_empty() {
+        parse_deliver_at(at).ok_or_else(|| {
+            (
+                2,
+                "invalid --at value: use a timestamp like '2024-01-15T10:30:00Z'".to_string(),
+            )
+        })?
+    } else {
+        return Ok(None);
+    };
+
+    Ok((deliver_after > now).then_some(deliver_after))
+}
+
+fn parse_deliver_at(raw: &str) -> Option<DateTime<Utc>> {
+    if let Ok(dt) = DateTime::parse_from_rfc3339(raw) {
+        return Some(dt.with_timezone(&Utc));
+    }
+    chrono::NaiveDateTime::parse_from_str(raw, "%Y-%m-%dT%H:%M:%S")
+        .ok()
+        .map(|ndt| ndt.and_utc())
+}
+
 #[derive(Debug, Default)]
 struct ParsedSendArgs {
     target: String,
@@ -126,6 +175,8 @@ struct ParsedSendArgs {
     priority: String,
     priority_set: bool,
     tags: Vec<String>,
+    at: String,
+    delay: String,
     json: bool,
 }
 
@@ -173,6 +224,20 @@ fn parse_send_args(args: &[String]) -> Result<ParsedSendArgs, (i32, String)> {
                 idx += 1;
                 add_tag_values(&mut parsed.tags, take_flag_value(args, idx, "--tag")?);
             }
+            flag if flag.starts_with("--at=") => {
+                parsed.at = inline_flag_value(flag);
+            }
+            "--at" => {
+                idx += 1;
+                parsed.at = take_flag_value(args, idx, "--at")?;
+            }
+            flag if flag.starts_with("--in=") => {
+                parsed.delay = inline_flag_value(flag);
+            }
+            "--in" => {
+                idx += 1;
+                parsed.delay = take_flag_value(args, idx, "--in")?;
+            }
             flag if flag.starts_with('-') => {
                 return Err((2, format!("unknown flag: {flag}")));
             }
@@ -232,5 +297,7 @@ Flags:
   -r, --reply-to string   Reference a previous message ID
   -p, --priority string   Set priority (low, normal, high)
   -t, --tag string        Add tag (repeatable, comma-separated)
+      --at string         Deliver at a time (RFC3339, e.g. 2024-01-15T10:30:00Z)
+      --in string         Deliver after a delay (e.g. 30m, 2h, 1d)
       --json              Output result as JSON
   -h, --help              Help for send";
@@ -1,11 +1,26 @@
+use fmail_core::agent_registry::{AgentPresence, AgentRecord};
+
+use crate::presence::take_state_flag;
 use crate::{CommandOutput, FmailBackend};
 
 pub fn run_status_for_test(args: &[&str], backend: &dyn FmailBackend) -> CommandOutput {
     let mut clear = false;
     let mut message: Option<String> = None;
+    let mut state: Option<AgentPresence> = None;
 
-    for arg in args {
-        match *arg {
+    let mut i = 0usize;
+    while i < args.len() {
+        let arg = args[i];
+        i += 1;
+        match take_state_flag(arg, args, &mut i) {
+            Ok(Some(value)) => {
+                state = Some(value);
+                continue;
+            }
+            Ok(None) => {}
+            Err(out) => return out,
+        }
+        match arg {
             "-h" | "--help" | "help" => {
                 return CommandOutput {
                     stdout: format!("{HELP_TEXT}\n"),
@@ -42,6 +57,13 @@ pub fn run_status_for_test(args: &[&str], backend: &dyn FmailBackend) -> Command
             exit_code: 2,
         };
     }
+    if clear && state.is_some() {
+        return CommandOutput {
+            stdout: String::new(),
+            stderr: "--state cannot be used with --clear\n".to_string(),
+            exit_code: 2,
+        };
+    }
 
     let agent = match backend.agent_name() {
         Ok(v) => v,
@@ -54,7 +76,7 @@ pub fn run_status_for_test(args: &[&str], backend: &dyn FmailBackend) -> Command
         }
     };
 
-    if message.is_none() && !clear {
+    if message.is_none() && state.is_none() && !clear {
         let record = match backend.read_agent_record(&agent) {
             Ok(v) => v,
             Err(e) => {
@@ -66,12 +88,7 @@ pub fn run_status_for_test(args: &[&str], backend: &dyn FmailBackend) -> Command
             }
         };
 
-        let status = record
-            .and_then(|r| r.status)
-            .unwrap_or_default()
-            .trim()
-            .to_string();
-
+        let status = record.map(|r| format_status(&r)).unwrap_or_default();
         if status.is_empty() {
             return CommandOutput {
                 stdout: String::new(),
@@ -88,9 +105,8 @@ pub fn run_status_for_test(args: &[&str], backend: &dyn FmailBackend) -> Command
     }
 
     let status = if clear {
-        String::new()
-    } else {
-        let v = message.unwrap_or_default();
+        Some(String::new())
+    } else if let Some(v) = message {
         let trimmed = v.trim().to_string();
         if trimmed.is_empty() {
             return CommandOutput {
@@ -99,14 +115,26 @@ pub fn run_status_for_test(args: &[&str], backend: &dyn FmailBackend) -> Command
                 exit_code: 2,
             };
         }
-        trimmed
+        Some(trimmed)
+    } else {
+        None
     };
 
     let host = backend.hostname();
-    if let Err(e) = backend.set_agent_status(&agent, &status, &host) {
+    // A state change carries the status text in the same write so the two
+    // never diverge on disk.
+    let result = match status {
+        Some(status) if !clear && state.is_none() => backend
+            .set_agent_status(&agent, &status, &host)
+            .map_err(|e| format!("update status: {e}")),
+        status => backend
+            .set_agent_state(&agent, state, status.as_deref(), &host)
+            .map_err(|e| format!("update state: {e}")),
+    };
+    if let Err(e) = result {
         return CommandOutput {
             stdout: String::new(),
-            stderr: format!("update status: {e}\n"),
+            stderr: format!("{e}\n"),
             exit_code: 1,
         };
     }
@@ -118,13 +146,29 @@ pub fn run_status_for_test(args: &[&str], backend: &dyn FmailBackend) -> Command
     }
 }
 
+/// Render a record's status as `[state] text`, omitting whichever part is unset.
+fn format_status(record: &AgentRecord) -> String {
+    let text = record.status.as_deref().unwrap_or_default().trim();
+    match record.state {
+        Some(state) if text.is_empty() => format!("[{state}]"),
+        Some(state) => format!("[{state}] {text}"),
+        None => text.to_string(),
+    }
+}
+
 const HELP_TEXT: &str = "\
 Show or set your status
 
 Usage:
-  fmail status [message] [--clear]
+  fmail status [message] [--state <state>] [--clear]
+
+Flags:
+      --clear          Clear the status message and state
+  -h, --help           help for status
+      --state string   Presence state: busy, available, away, or offline
 
 Examples:
   fmail status                 # Show your current status
   fmail status \"working on auth\"
+  fmail status --state busy \"reviewing PR\"
   fmail status --clear";
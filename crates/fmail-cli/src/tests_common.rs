@@ -3,9 +3,9 @@
 use std::path::PathBuf;
 
 use chrono::{DateTime, Utc};
-use fmail_core::agent_registry::AgentRecord;
+use fmail_core::agent_registry::{AgentPresence, AgentRecord};
 use fmail_core::message::Message;
-use fmail_core::store::TopicSummary;
+use fmail_core::store::{ReadReceipts, TopicSummary};
 
 use crate::FmailBackend;
 
@@ -37,6 +37,7 @@ impl FmailBackend for MockFmailBackend {
             name: name.to_string(),
             host: None,
             status: None,
+            state: None,
             first_seen: chrono::Utc::now(),
             last_seen: chrono::Utc::now(),
         })
@@ -52,6 +53,24 @@ impl FmailBackend for MockFmailBackend {
             name: name.to_string(),
             host: None,
             status: Some(status.to_string()),
+            state: None,
+            first_seen: chrono::Utc::now(),
+            last_seen: chrono::Utc::now(),
+        })
+    }
+
+    fn set_agent_state(
+        &self,
+        name: &str,
+        state: Option<AgentPresence>,
+        status: Option<&str>,
+        _host: &str,
+    ) -> Result<AgentRecord, String> {
+        Ok(AgentRecord {
+            name: name.to_string(),
+            host: None,
+            status: status.map(str::to_string),
+            state,
             first_seen: chrono::Utc::now(),
             last_seen: chrono::Utc::now(),
         })
@@ -88,6 +107,14 @@ impl FmailBackend for MockFmailBackend {
         Err("not found".to_string())
     }
 
+    fn read_receipts(&self, _reader: &str) -> Result<ReadReceipts, String> {
+        Ok(ReadReceipts::default())
+    }
+
+    fn mark_read(&self, _message_ids: &[String], _reader: &str) -> Result<(), String> {
+        Ok(())
+    }
+
     fn init_project(&self, _project_id: Option<&str>) -> Result<(), String> {
         Ok(())
     }
@@ -6,6 +6,8 @@ use std::path::PathBuf;
 use std::thread;
 use std::time::{Duration, Instant};
 
+use chrono::{DateTime, Utc};
+use fmail_core::format::{render_message, RenderStyle};
 use fmail_core::message::Message;
 use fmail_core::validate::{normalize_agent_name, normalize_topic};
 
@@ -25,7 +27,7 @@ enum WatchTarget {
 struct ParsedWatchArgs {
     target: WatchTarget,
     count: usize,
-    json: bool,
+    style: RenderStyle,
     timeout: Option<Duration>,
 }
 
@@ -52,8 +54,8 @@ fn execute_watch(
 ) -> Result<CommandOutput, (i32, String)> {
     let parsed = parse_watch_args(args)?;
 
-    let mut seen =
-        initialize_seen(backend, &parsed.target).map_err(|e| (1, format!("watch: {e}")))?;
+    let mut seen = initialize_seen(backend, &parsed.target, backend.now_utc())
+        .map_err(|e| (1, format!("watch: {e}")))?;
 
     let mut stdout = String::new();
     let deadline = parsed.timeout.map(|timeout| Instant::now() + timeout);
@@ -78,11 +80,11 @@ fn execute_watch(
 
         thread::sleep(WATCH_POLL_INTERVAL);
 
-        let messages = scan_new_messages(backend, &parsed.target, &mut seen)
+        let messages = scan_new_messages(backend, &parsed.target, backend.now_utc(), &mut seen)
             .map_err(|e| (1, format!("watch: {e}")))?;
 
         for message in &messages {
-            write_watch_message(&mut stdout, message, parsed.json)
+            write_watch_message(&mut stdout, message, parsed.style)
                 .map_err(|e| (1, format!("output: {e}")))?;
 
             if let Some(ref mut remaining_count) = remaining {
@@ -104,7 +106,7 @@ fn execute_watch(
 }
 
 fn parse_watch_args(args: &[String]) -> Result<ParsedWatchArgs, (i32, String)> {
-    let mut json = false;
+    let mut style = RenderStyle::Line;
     let mut count_raw: i64 = 0;
     let mut timeout_raw: Option<String> = None;
     let mut target_raw: Option<String> = None;
@@ -114,7 +116,12 @@ fn parse_watch_args(args: &[String]) -> Result<ParsedWatchArgs, (i32, String)> {
         let token = &args[idx];
         match token.as_str() {
             "-h" | "--help" | "help" => return Err((0, HELP_TEXT.to_string())),
-            "--json" => json = true,
+            "--json" => style = RenderStyle::Json,
+            "--format" => {
+                idx += 1;
+                let raw = take_flag_value(args, idx, "--format")?;
+                style = RenderStyle::parse(&raw).map_err(|e| (2, e))?;
+            }
             "-c" | "--count" => {
                 idx += 1;
                 let raw = take_flag_value(args, idx, "--count")?;
@@ -166,7 +173,7 @@ fn parse_watch_args(args: &[String]) -> Result<ParsedWatchArgs, (i32, String)> {
     Ok(ParsedWatchArgs {
         target,
         count: usize::try_from(count_raw).map_err(|_| (2, "count out of range".to_string()))?,
-        json,
+        style,
         timeout,
     })
 }
@@ -191,11 +198,18 @@ fn parse_watch_target(raw: &str) -> Result<WatchTarget, (i32, String)> {
 fn initialize_seen(
     backend: &dyn FmailBackend,
     target: &WatchTarget,
+    now: DateTime<Utc>,
 ) -> Result<HashSet<String>, String> {
     let files = collect_target_files(backend, target)?;
     let mut seen = HashSet::with_capacity(files.len());
     for path in &files {
-        seen.insert(path.to_string_lossy().to_string());
+        // Leave pending scheduled messages unseen so they are reported when due.
+        let pending = backend
+            .read_message_at(path)
+            .is_ok_and(|message| !message.is_due(now));
+        if !pending {
+            seen.insert(path.to_string_lossy().to_string());
+        }
     }
     Ok(seen)
 }
@@ -203,6 +217,7 @@ fn initialize_seen(
 fn scan_new_messages(
     backend: &dyn FmailBackend,
     target: &WatchTarget,
+    now: DateTime<Utc>,
     seen: &mut HashSet<String>,
 ) -> Result<Vec<Message>, String> {
     let files = collect_target_files(backend, target)?;
@@ -214,10 +229,13 @@ fn scan_new_messages(
             continue;
         }
 
-        seen.insert(key.clone());
         let message = backend
             .read_message_at(path)
             .map_err(|e| format!("read message {}: {e}", path.display()))?;
+        if !message.is_due(now) {
+            continue;
+        }
+        seen.insert(key.clone());
         updates.push((message, key));
     }
 
@@ -275,30 +293,16 @@ fn collect_target_files(
 fn write_watch_message(
     out: &mut String,
     message: &Message,
-    json_output: bool,
+    style: RenderStyle,
 ) -> Result<(), String> {
-    if json_output {
-        let encoded = serde_json::to_string(message).map_err(|e| format!("encode message: {e}"))?;
-        out.push_str(&encoded);
+    out.push_str(&render_message(message, style)?);
+    out.push('\n');
+    if style == RenderStyle::Verbose {
         out.push('\n');
-        return Ok(());
     }
-
-    let body = format_body(&message.body);
-    out.push_str(&format!(
-        "{} {} -> {}: {}\n",
-        message.id, message.from, message.to, body
-    ));
     Ok(())
 }
 
-fn format_body(body: &serde_json::Value) -> String {
-    match body {
-        serde_json::Value::String(text) => text.clone(),
-        other => serde_json::to_string(other).unwrap_or_else(|_| other.to_string()),
-    }
-}
-
 fn take_flag_value(args: &[String], idx: usize, flag: &str) -> Result<String, (i32, String)> {
     args.get(idx)
         .cloned()
@@ -313,7 +317,8 @@ Usage:
 
 Flags:
   -c, --count int         Exit after receiving N messages
-      --json              Output as JSON
+      --format string     Output format: line, compact, verbose, json (default: line)
+      --json              Output as JSON (same as --format json)
       --timeout duration  Maximum wait time before exiting
   -h, --help              Help for watch";
 
@@ -1,15 +1,28 @@
 use std::io::Write;
 
-use fmail_core::agent_registry::AgentRecord;
+use fmail_core::agent_registry::{AgentPresence, AgentRecord};
 use tabwriter::TabWriter;
 
+use crate::presence::take_state_flag;
 use crate::{CommandOutput, FmailBackend};
 
 pub fn run_who_for_test(args: &[&str], backend: &dyn FmailBackend) -> CommandOutput {
     let mut json = false;
+    let mut state: Option<AgentPresence> = None;
     let mut positional = 0usize;
-    for arg in args {
-        match *arg {
+    let mut i = 0usize;
+    while i < args.len() {
+        let arg = args[i];
+        i += 1;
+        match take_state_flag(arg, args, &mut i) {
+            Ok(Some(value)) => {
+                state = Some(value);
+                continue;
+            }
+            Ok(None) => {}
+            Err(out) => return out,
+        }
+        match arg {
             "-h" | "--help" | "help" => {
                 return CommandOutput {
                     stdout: format!("{HELP_TEXT}\n"),
@@ -41,7 +54,15 @@ pub fn run_who_for_test(args: &[&str], backend: &dyn FmailBackend) -> CommandOut
 
     let now = backend.now_utc();
     let records = match backend.list_agent_records() {
-        Ok(v) => v,
+        Ok(v) => match state {
+            Some(state) => v.map(|records| {
+                records
+                    .into_iter()
+                    .filter(|record| presence_of(now, record) == Some(state))
+                    .collect()
+            }),
+            None => v,
+        },
         Err(e) => {
             return CommandOutput {
                 stdout: String::new(),
@@ -83,6 +104,9 @@ fn format_who_table(now: chrono::DateTime<chrono::Utc>, mut records: Vec<AgentRe
     for record in records {
         let mut status = record.status.unwrap_or_default();
         status = status.trim().to_string();
+        if let Some(state) = record.state {
+            status = format!("[{state}] {status}").trim_end().to_string();
+        }
 
         if status.is_empty() && !fmail_core::format::is_active(now, record.last_seen) {
             status = "offline".to_string();
@@ -99,6 +123,18 @@ fn format_who_table(now: chrono::DateTime<chrono::Utc>, mut records: Vec<AgentRe
     String::from_utf8_lossy(&bytes).into_owned()
 }
 
+/// Explicit presence state, falling back to `offline` for inactive agents
+/// that never set one.
+fn presence_of(now: chrono::DateTime<chrono::Utc>, record: &AgentRecord) -> Option<AgentPresence> {
+    match record.state {
+        Some(state) => Some(state),
+        None if !fmail_core::format::is_active(now, record.last_seen) => {
+            Some(AgentPresence::Offline)
+        }
+        None => None,
+    }
+}
+
 fn tabwriter_into_bytes(mut tw: TabWriter<Vec<u8>>) -> Vec<u8> {
     loop {
         match tw.into_inner() {
@@ -115,5 +151,6 @@ Usage:
   fmail who [flags]
 
 Flags:
-  -h, --help   help for who
-      --json   Output as JSON";
+  -h, --help           help for who
+      --json           Output as JSON
+      --state string   Only show agents in this state: busy, available, away, or offline";
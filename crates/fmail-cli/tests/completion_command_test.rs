@@ -4,9 +4,9 @@ use std::path::PathBuf;
 
 use chrono::{DateTime, Utc};
 use fmail_cli::{run_cli_for_test, FmailBackend};
-use fmail_core::agent_registry::AgentRecord;
+use fmail_core::agent_registry::{AgentPresence, AgentRecord};
 use fmail_core::message::Message;
-use fmail_core::store::TopicSummary;
+use fmail_core::store::{ReadReceipts, TopicSummary};
 
 /// Minimal backend stub — completion never touches the backend.
 struct StubBackend;
@@ -32,6 +32,16 @@ impl FmailBackend for StubBackend {
     ) -> Result<AgentRecord, String> {
         Err("not implemented".to_string())
     }
+
+    fn set_agent_state(
+        &self,
+        _name: &str,
+        _state: Option<AgentPresence>,
+        _status: Option<&str>,
+        _host: &str,
+    ) -> Result<AgentRecord, String> {
+        Err("not implemented".to_string())
+    }
     fn hostname(&self) -> String {
         "test-host".to_string()
     }
@@ -54,6 +64,14 @@ impl FmailBackend for StubBackend {
         Err("not implemented".to_string())
     }
 
+    fn read_receipts(&self, _reader: &str) -> Result<ReadReceipts, String> {
+        Err("not implemented".to_string())
+    }
+
+    fn mark_read(&self, _message_ids: &[String], _reader: &str) -> Result<(), String> {
+        Err("not implemented".to_string())
+    }
+
     fn init_project(&self, _project_id: Option<&str>) -> Result<(), String> {
         Ok(())
     }
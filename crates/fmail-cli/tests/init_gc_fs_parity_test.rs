@@ -174,3 +174,65 @@ fn gc_delete_removes_only_old_valid_files() {
     assert!(bad_topic.exists(), "invalid topic dir should be ignored");
     assert!(bad_dm.exists(), "invalid dm dir should be ignored");
 }
+
+#[test]
+fn gc_ages_scheduled_messages_from_their_delivery_time() {
+    let tmp = TempDir::new().expect("tempdir");
+    let root = tmp.path().join(".fmail");
+    std::fs::create_dir_all(root.join("dm/alice")).expect("mkdir alice");
+
+    let message = |id: &str, deliver_after: Option<&str>| {
+        let mut value = serde_json::json!({
+            "id": id,
+            "from": "bob",
+            "to": "@alice",
+            "time": "2020-01-01T01:01:01Z",
+            "body": "ping if no response",
+        });
+        if let Some(at) = deliver_after {
+            value["deliver_after"] = Value::String(at.to_owned());
+        }
+        serde_json::to_string(&value).expect("encode message")
+    };
+
+    let plain = root.join("dm/alice/20200101-010101-0001.json");
+    let delivered = root.join("dm/alice/20200101-010101-0002.json");
+    let pending = root.join("dm/alice/20200101-010101-0003.json");
+    std::fs::write(&plain, message("20200101-010101-0001", None)).expect("write plain");
+    std::fs::write(
+        &delivered,
+        message("20200101-010101-0002", Some("2020-01-02T00:00:00Z")),
+    )
+    .expect("write delivered");
+    std::fs::write(
+        &pending,
+        message("20200101-010101-0003", Some("2999-01-01T00:00:00Z")),
+    )
+    .expect("write pending");
+
+    let out = run_fmail(tmp.path(), &["gc", "--days", "1", "--dry-run"]);
+    assert!(
+        out.status.success(),
+        "status={:?} stderr={}",
+        out.status.code(),
+        String::from_utf8_lossy(&out.stderr)
+    );
+    assert_eq!(
+        String::from_utf8_lossy(&out.stdout),
+        "dm/alice/20200101-010101-0001.json\ndm/alice/20200101-010101-0002.json\n"
+    );
+
+    let out = run_fmail(tmp.path(), &["gc", "--days", "1"]);
+    assert!(
+        out.status.success(),
+        "status={:?} stderr={}",
+        out.status.code(),
+        String::from_utf8_lossy(&out.stderr)
+    );
+    assert!(!plain.exists(), "old unscheduled message should be deleted");
+    assert!(
+        !delivered.exists(),
+        "message delivered before the cutoff should be deleted"
+    );
+    assert!(pending.exists(), "pending scheduled message should remain");
+}
@@ -5,9 +5,9 @@ use std::collections::HashSet;
 
 use chrono::{DateTime, Utc};
 use fmail_cli::{run_cli_for_test, FmailBackend};
-use fmail_core::agent_registry::AgentRecord;
+use fmail_core::agent_registry::{AgentPresence, AgentRecord};
 use fmail_core::message::Message;
-use fmail_core::store::ERR_AGENT_EXISTS;
+use fmail_core::store::{ReadReceipts, ERR_AGENT_EXISTS};
 
 struct RegisterBackend {
     now: DateTime<Utc>,
@@ -27,7 +27,20 @@ impl RegisterBackend {
 
 impl FmailBackend for RegisterBackend {
     fn list_agent_records(&self) -> Result<Option<Vec<AgentRecord>>, String> {
-        Ok(Some(vec![]))
+        let names = self.registered.borrow().clone();
+        Ok(Some(
+            names
+                .iter()
+                .map(|name| AgentRecord {
+                    name: name.clone(),
+                    host: None,
+                    status: None,
+                    state: None,
+                    first_seen: self.now,
+                    last_seen: self.now,
+                })
+                .collect(),
+        ))
     }
 
     fn now_utc(&self) -> DateTime<Utc> {
@@ -48,6 +61,7 @@ impl FmailBackend for RegisterBackend {
                 Some(host.to_string())
             },
             status: None,
+            state: None,
             first_seen: self.now,
             last_seen: self.now,
         })
@@ -79,6 +93,16 @@ impl FmailBackend for RegisterBackend {
     ) -> Result<AgentRecord, String> {
         Err("not implemented".to_string())
     }
+
+    fn set_agent_state(
+        &self,
+        _name: &str,
+        _state: Option<AgentPresence>,
+        _status: Option<&str>,
+        _host: &str,
+    ) -> Result<AgentRecord, String> {
+        Err("not implemented".to_string())
+    }
     fn list_topics(&self) -> Result<Option<Vec<fmail_core::store::TopicSummary>>, String> {
         Err("not implemented".to_string())
     }
@@ -89,6 +113,14 @@ impl FmailBackend for RegisterBackend {
         Err("not implemented".to_string())
     }
 
+    fn read_receipts(&self, _reader: &str) -> Result<ReadReceipts, String> {
+        Err("not implemented".to_string())
+    }
+
+    fn mark_read(&self, _message_ids: &[String], _reader: &str) -> Result<(), String> {
+        Err("not implemented".to_string())
+    }
+
     fn init_project(&self, _project_id: Option<&str>) -> Result<(), String> {
         Ok(())
     }
@@ -174,6 +206,24 @@ fn register_auto_generates_name() {
     assert!(name.contains('-'), "name should have parts: {name}");
 }
 
+#[test]
+fn register_auto_generated_names_skip_registered_agents() {
+    let backend = RegisterBackend::new(rfc3339("2026-01-10T18:00:00Z"));
+    let mut names = HashSet::new();
+    for _ in 0..20 {
+        let out = run_cli_for_test(&["register"], &backend);
+        assert_eq!(out.exit_code, 0, "stderr: {}", out.stderr);
+        let name = out.stdout.trim().to_string();
+        let (_, suffix) = name.rsplit_once('-').expect("numbered name");
+        assert!(
+            suffix.parse::<u32>().is_ok(),
+            "expected number suffix: {name}"
+        );
+        assert!(names.insert(name));
+    }
+    assert_eq!(backend.registered.borrow().len(), 20);
+}
+
 #[test]
 fn register_too_many_args() {
     let backend = RegisterBackend::new(rfc3339("2026-01-10T18:00:00Z"));
@@ -5,8 +5,9 @@ use std::collections::HashMap;
 
 use chrono::{DateTime, Utc};
 use fmail_cli::{run_cli_for_test, FmailBackend};
-use fmail_core::agent_registry::AgentRecord;
+use fmail_core::agent_registry::{AgentPresence, AgentRecord};
 use fmail_core::message::{generate_message_id, Message};
+use fmail_core::store::ReadReceipts;
 
 struct SendBackend {
     now: DateTime<Utc>,
@@ -75,6 +76,16 @@ impl FmailBackend for SendBackend {
     ) -> Result<AgentRecord, String> {
         Err("not implemented".to_string())
     }
+
+    fn set_agent_state(
+        &self,
+        _name: &str,
+        _state: Option<AgentPresence>,
+        _status: Option<&str>,
+        _host: &str,
+    ) -> Result<AgentRecord, String> {
+        Err("not implemented".to_string())
+    }
     fn list_topics(&self) -> Result<Option<Vec<fmail_core::store::TopicSummary>>, String> {
         Err("not implemented".to_string())
     }
@@ -85,6 +96,14 @@ impl FmailBackend for SendBackend {
         Err("not implemented".to_string())
     }
 
+    fn read_receipts(&self, _reader: &str) -> Result<ReadReceipts, String> {
+        Err("not implemented".to_string())
+    }
+
+    fn mark_read(&self, _message_ids: &[String], _reader: &str) -> Result<(), String> {
+        Err("not implemented".to_string())
+    }
+
     fn init_project(&self, _project_id: Option<&str>) -> Result<(), String> {
         Ok(())
     }
@@ -281,6 +300,58 @@ fn send_priority_short_flag() {
     assert_eq!(messages[0].priority, "low");
 }
 
+// --- Scheduled delivery ---
+
+#[test]
+fn send_in_sets_deliver_after() {
+    let backend = SendBackend::new(rfc3339("2026-02-09T12:00:00Z"), "alice");
+    let out = run_cli_for_test(&["send", "@bob", "ping?", "--in", "1h"], &backend);
+    assert_eq!(out.exit_code, 0, "stderr: {}", out.stderr);
+
+    let messages = backend.messages.borrow();
+    assert_eq!(
+        messages[0].deliver_after,
+        Some(rfc3339("2026-02-09T13:00:00Z"))
+    );
+}
+
+#[test]
+fn send_at_parses_timestamp_and_rejects_both_flags() {
+    let backend = SendBackend::new(rfc3339("2026-02-09T12:00:00Z"), "alice");
+    let out = run_cli_for_test(
+        &["send", "task", "later", "--at=2026-02-10T09:00:00Z"],
+        &backend,
+    );
+    assert_eq!(out.exit_code, 0, "stderr: {}", out.stderr);
+    assert_eq!(
+        backend.messages.borrow()[0].deliver_after,
+        Some(rfc3339("2026-02-10T09:00:00Z"))
+    );
+
+    let out = run_cli_for_test(
+        &[
+            "send",
+            "task",
+            "x",
+            "--at",
+            "2026-02-10T09:00:00Z",
+            "--in",
+            "1h",
+        ],
+        &backend,
+    );
+    assert_eq!(out.exit_code, 2);
+    assert_eq!(out.stderr, "use either --at or --in, not both\n");
+
+    let out = run_cli_for_test(&["send", "task", "x", "--in", "soon"], &backend);
+    assert_eq!(out.exit_code, 2);
+    assert!(
+        out.stderr.contains("invalid --in value"),
+        "stderr: {}",
+        out.stderr
+    );
+}
+
 // --- Tags ---
 
 #[test]
@@ -5,9 +5,9 @@ use std::path::{Path, PathBuf};
 
 use chrono::{DateTime, Utc};
 use fmail_cli::{run_cli_for_test, FmailBackend};
-use fmail_core::agent_registry::AgentRecord;
+use fmail_core::agent_registry::{AgentPresence, AgentRecord};
 use fmail_core::message::Message;
-use fmail_core::store::TopicSummary;
+use fmail_core::store::{ReadReceipts, TopicSummary};
 
 struct StatusBackend {
     now: DateTime<Utc>,
@@ -15,6 +15,7 @@ struct StatusBackend {
     host: String,
     record: RefCell<Option<AgentRecord>>,
     last_set: RefCell<Option<(String, String, String)>>,
+    writes: RefCell<usize>,
 }
 
 impl StatusBackend {
@@ -25,6 +26,7 @@ impl StatusBackend {
             host: host.to_string(),
             record: RefCell::new(record),
             last_set: RefCell::new(None),
+            writes: RefCell::new(0),
         }
     }
 }
@@ -53,6 +55,7 @@ impl FmailBackend for StatusBackend {
         status: &str,
         host: &str,
     ) -> Result<AgentRecord, String> {
+        *self.writes.borrow_mut() += 1;
         self.last_set.borrow_mut().replace((
             name.to_string(),
             status.to_string(),
@@ -62,6 +65,7 @@ impl FmailBackend for StatusBackend {
             name: name.to_string(),
             host: None,
             status: None,
+            state: None,
             first_seen: self.now,
             last_seen: self.now,
         });
@@ -79,6 +83,39 @@ impl FmailBackend for StatusBackend {
         Ok(record)
     }
 
+    fn set_agent_state(
+        &self,
+        name: &str,
+        state: Option<AgentPresence>,
+        status: Option<&str>,
+        host: &str,
+    ) -> Result<AgentRecord, String> {
+        *self.writes.borrow_mut() += 1;
+        let mut record = self.record.borrow().clone().unwrap_or(AgentRecord {
+            name: name.to_string(),
+            host: None,
+            status: None,
+            state: None,
+            first_seen: self.now,
+            last_seen: self.now,
+        });
+        record.state = state;
+        if let Some(status) = status {
+            self.last_set.borrow_mut().replace((
+                name.to_string(),
+                status.to_string(),
+                host.to_string(),
+            ));
+            record.status = if status.trim().is_empty() {
+                None
+            } else {
+                Some(status.trim().to_string())
+            };
+        }
+        *self.record.borrow_mut() = Some(record.clone());
+        Ok(record)
+    }
+
     fn hostname(&self) -> String {
         self.host.clone()
     }
@@ -107,6 +144,14 @@ impl FmailBackend for StatusBackend {
         Err("not implemented".to_string())
     }
 
+    fn read_receipts(&self, _reader: &str) -> Result<ReadReceipts, String> {
+        Err("not implemented".to_string())
+    }
+
+    fn mark_read(&self, _message_ids: &[String], _reader: &str) -> Result<(), String> {
+        Err("not implemented".to_string())
+    }
+
     fn init_project(&self, _project_id: Option<&str>) -> Result<(), String> {
         Ok(())
     }
@@ -141,6 +186,7 @@ fn status_show_prints_trimmed_status() {
             name: "alice".to_string(),
             host: Some("h1".to_string()),
             status: Some("  working on auth  ".to_string()),
+            state: None,
             first_seen: rfc3339("2026-02-09T11:00:00Z"),
             last_seen: rfc3339("2026-02-09T11:59:00Z"),
         }),
@@ -212,3 +258,53 @@ fn status_unknown_flag() {
         out.stderr
     );
 }
+
+#[test]
+fn status_state_and_message_persist_and_clear_together() {
+    let backend = StatusBackend::new(rfc3339("2026-02-09T12:00:00Z"), "alice", "test-host", None);
+    let out = run_cli_for_test(&["status", "--state", "busy", "reviewing PR"], &backend);
+    assert_eq!(out.exit_code, 0, "stderr: {}", out.stderr);
+
+    let record = backend.record.borrow().clone().expect("record");
+    assert_eq!(record.status.as_deref(), Some("reviewing PR"));
+    assert_eq!(record.state, Some(AgentPresence::Busy));
+    assert_eq!(*backend.writes.borrow(), 1);
+
+    let out = run_cli_for_test(&["status"], &backend);
+    assert_eq!(out.stdout, "[busy] reviewing PR\n");
+
+    let out = run_cli_for_test(&["status", "--clear"], &backend);
+    assert_eq!(out.exit_code, 0, "stderr: {}", out.stderr);
+    let record = backend.record.borrow().clone().expect("record");
+    assert_eq!(*backend.writes.borrow(), 2);
+    assert!(record.status.is_none());
+    assert!(record.state.is_none());
+}
+
+#[test]
+fn status_state_alone_keeps_message() {
+    let backend = StatusBackend::new(rfc3339("2026-02-09T12:00:00Z"), "alice", "test-host", None);
+    run_cli_for_test(&["status", "working on auth"], &backend);
+    let out = run_cli_for_test(&["status", "--state=away"], &backend);
+    assert_eq!(out.exit_code, 0, "stderr: {}", out.stderr);
+
+    let record = backend.record.borrow().clone().expect("record");
+    assert_eq!(record.status.as_deref(), Some("working on auth"));
+    assert_eq!(record.state, Some(AgentPresence::Away));
+}
+
+#[test]
+fn status_rejects_invalid_state_and_state_with_clear() {
+    let backend = StatusBackend::new(rfc3339("2026-02-09T12:00:00Z"), "alice", "test-host", None);
+    let out = run_cli_for_test(&["status", "--state", "sleepy"], &backend);
+    assert_eq!(out.exit_code, 2);
+    assert!(
+        out.stderr.contains("invalid state"),
+        "stderr: {}",
+        out.stderr
+    );
+
+    let out = run_cli_for_test(&["status", "--clear", "--state", "busy"], &backend);
+    assert_eq!(out.exit_code, 2);
+    assert_eq!(out.stderr, "--state cannot be used with --clear\n");
+}
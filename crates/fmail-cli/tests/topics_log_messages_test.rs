@@ -1,26 +1,32 @@
 #![allow(clippy::expect_used, clippy::unwrap_used)]
 
+use std::cell::RefCell;
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
 use chrono::{DateTime, Utc};
 use fmail_cli::{run_cli_for_test, FmailBackend};
-use fmail_core::agent_registry::AgentRecord;
+use fmail_core::agent_registry::{AgentPresence, AgentRecord};
 use fmail_core::message::Message;
-use fmail_core::store::TopicSummary;
+use fmail_core::store::{ReadReceipts, TopicSummary};
 
 /// In-memory backend for topics/log/messages tests.
 struct TopicsLogBackend {
     now: DateTime<Utc>,
+    agent: String,
     topics: Option<Vec<TopicSummary>>,
     messages: Vec<Message>,
+    receipts: RefCell<HashMap<String, ReadReceipts>>,
 }
 
 impl TopicsLogBackend {
     fn new(now: DateTime<Utc>) -> Self {
         Self {
             now,
+            agent: "alice".to_string(),
             topics: Some(Vec::new()),
             messages: Vec::new(),
+            receipts: RefCell::new(HashMap::new()),
         }
     }
 
@@ -61,12 +67,22 @@ impl FmailBackend for TopicsLogBackend {
         Err("not implemented".to_string())
     }
 
+    fn set_agent_state(
+        &self,
+        _name: &str,
+        _state: Option<AgentPresence>,
+        _status: Option<&str>,
+        _host: &str,
+    ) -> Result<AgentRecord, String> {
+        Err("not implemented".to_string())
+    }
+
     fn hostname(&self) -> String {
         "test-host".to_string()
     }
 
     fn agent_name(&self) -> Result<String, String> {
-        Err("not implemented".to_string())
+        Ok(self.agent.clone())
     }
 
     fn save_message(&self, _message: &mut Message) -> Result<String, String> {
@@ -107,6 +123,24 @@ impl FmailBackend for TopicsLogBackend {
             .ok_or_else(|| "message not found".to_string())
     }
 
+    fn read_receipts(&self, reader: &str) -> Result<ReadReceipts, String> {
+        Ok(self
+            .receipts
+            .borrow()
+            .get(reader)
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    fn mark_read(&self, message_ids: &[String], reader: &str) -> Result<(), String> {
+        let mut receipts = self.receipts.borrow_mut();
+        let entry = receipts.entry(reader.to_string()).or_default();
+        for id in message_ids {
+            entry.read.entry(id.clone()).or_insert(self.now);
+        }
+        Ok(())
+    }
+
     fn init_project(&self, _project_id: Option<&str>) -> Result<(), String> {
         Ok(())
     }
@@ -133,6 +167,7 @@ fn make_msg(id: &str, from: &str, to: &str, body: &str, time: &str) -> Message {
         priority: String::new(),
         host: String::new(),
         tags: Vec::new(),
+        deliver_after: None,
     }
 }
 
@@ -344,6 +379,37 @@ fn log_json_matches_golden() {
     assert_eq!(out.stdout, include_str!("golden/log/json.txt"));
 }
 
+#[test]
+fn log_format_selects_render_style() {
+    let now = rfc3339("2026-02-09T12:00:00Z");
+    let backend = TopicsLogBackend::new(now).with_messages(vec![make_msg(
+        "msg-001",
+        "alice",
+        "tasks",
+        "hello",
+        "2026-02-09T11:00:00Z",
+    )]);
+    let out = run_cli_for_test(&["log", "--format", "compact"], &backend);
+    assert_eq!(out.exit_code, 0, "stderr: {}", out.stderr);
+    assert_eq!(out.stdout, "11:00:00 alice: hello\n");
+
+    let out = run_cli_for_test(&["log", "--format", "verbose"], &backend);
+    assert_eq!(out.exit_code, 0, "stderr: {}", out.stderr);
+    assert!(
+        out.stdout.starts_with("ID: msg-001\nFrom: alice\n"),
+        "stdout: {}",
+        out.stdout
+    );
+
+    let out = run_cli_for_test(&["log", "--format", "xml"], &backend);
+    assert_eq!(out.exit_code, 2);
+    assert!(
+        out.stderr.contains("invalid format \"xml\""),
+        "stderr: {}",
+        out.stderr
+    );
+}
+
 #[test]
 fn log_help_matches_golden() {
     let backend = TopicsLogBackend::new(rfc3339("2026-02-09T12:00:00Z"));
@@ -561,6 +627,143 @@ fn messages_text_matches_golden() {
     assert_eq!(out.stdout, include_str!("golden/messages/text.txt"));
 }
 
+#[test]
+fn messages_hide_scheduled_message_until_due() {
+    let mut scheduled = make_msg("msg-002", "bob", "@alice", "ping?", "2026-02-09T12:00:00Z");
+    scheduled.deliver_after = Some(rfc3339("2026-02-09T13:00:00Z"));
+    let messages = vec![
+        make_msg(
+            "msg-001",
+            "alice",
+            "tasks",
+            "task msg",
+            "2026-02-09T11:00:00Z",
+        ),
+        scheduled,
+    ];
+
+    let backend =
+        TopicsLogBackend::new(rfc3339("2026-02-09T12:00:00Z")).with_messages(messages.clone());
+    let out = run_cli_for_test(&["messages"], &backend);
+    assert_eq!(out.exit_code, 0, "stderr: {}", out.stderr);
+    assert!(out.stdout.contains("task msg"), "stdout: {}", out.stdout);
+    assert!(!out.stdout.contains("ping?"), "stdout: {}", out.stdout);
+
+    let later = TopicsLogBackend::new(rfc3339("2026-02-09T13:00:01Z")).with_messages(messages);
+    let out = run_cli_for_test(&["messages"], &later);
+    assert_eq!(out.exit_code, 0, "stderr: {}", out.stderr);
+    assert!(out.stdout.contains("ping?"), "stdout: {}", out.stdout);
+}
+
+#[test]
+fn messages_unread_is_tracked_per_reader() {
+    let mut backend = TopicsLogBackend::new(rfc3339("2026-02-09T12:00:00Z")).with_messages(vec![
+        make_msg("msg-001", "carol", "tasks", "first", "2026-02-09T11:00:00Z"),
+        make_msg("msg-002", "alice", "tasks", "mine", "2026-02-09T11:10:00Z"),
+        make_msg(
+            "msg-003",
+            "carol",
+            "tasks",
+            "second",
+            "2026-02-09T11:20:00Z",
+        ),
+    ]);
+
+    let out = run_cli_for_test(&["messages", "--unread", "--mark-read"], &backend);
+    assert_eq!(out.exit_code, 0, "stderr: {}", out.stderr);
+    assert!(out.stdout.contains("first"), "stdout: {}", out.stdout);
+    assert!(out.stdout.contains("second"), "stdout: {}", out.stdout);
+    assert!(!out.stdout.contains("mine"), "stdout: {}", out.stdout);
+
+    let out = run_cli_for_test(&["messages", "--unread"], &backend);
+    assert_eq!(out.exit_code, 0, "stderr: {}", out.stderr);
+    assert!(out.stdout.is_empty(), "stdout: {}", out.stdout);
+
+    backend.agent = "bob".to_string();
+    let out = run_cli_for_test(&["messages", "--unread"], &backend);
+    assert_eq!(out.exit_code, 0, "stderr: {}", out.stderr);
+    assert!(out.stdout.contains("first"), "stdout: {}", out.stdout);
+    assert!(out.stdout.contains("mine"), "stdout: {}", out.stdout);
+}
+
+#[test]
+fn messages_group_by_thread_collapses_replies() {
+    let now = rfc3339("2026-02-09T12:00:00Z");
+    let mut first_reply = make_msg("msg-002", "bob", "tasks", "on it", "2026-02-09T11:10:00Z");
+    first_reply.reply_to = "msg-001".to_string();
+    let mut second_reply = make_msg(
+        "msg-004",
+        "carol",
+        "tasks",
+        "me too",
+        "2026-02-09T11:30:00Z",
+    );
+    second_reply.reply_to = "msg-001".to_string();
+    let backend = TopicsLogBackend::new(now).with_messages(vec![
+        make_msg(
+            "msg-001",
+            "alice",
+            "tasks",
+            "who can review?",
+            "2026-02-09T11:00:00Z",
+        ),
+        first_reply,
+        make_msg(
+            "msg-003",
+            "dave",
+            "@alice",
+            "unrelated",
+            "2026-02-09T11:20:00Z",
+        ),
+        second_reply,
+    ]);
+
+    let out = run_cli_for_test(&["messages", "--group-by", "thread"], &backend);
+    assert_eq!(out.exit_code, 0, "stderr: {}", out.stderr);
+    assert_eq!(
+        out.stdout,
+        "msg-001 alice -> tasks: who can review? [2 replies]\nmsg-003 dave -> @alice: unrelated\n"
+    );
+
+    let expanded = run_cli_for_test(&["messages", "--group-by", "thread", "--expand"], &backend);
+    assert_eq!(expanded.exit_code, 0, "stderr: {}", expanded.stderr);
+    assert_eq!(
+        expanded.stdout,
+        "msg-001 alice -> tasks: who can review? [2 replies]\n  msg-002 bob -> tasks: on it\n  msg-004 carol -> tasks: me too\nmsg-003 dave -> @alice: unrelated\n"
+    );
+
+    let json = run_cli_for_test(&["messages", "--group-by", "thread", "--json"], &backend);
+    assert_eq!(json.exit_code, 0, "stderr: {}", json.stderr);
+    let lines: Vec<serde_json::Value> = json
+        .stdout
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("json line"))
+        .collect();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0]["root"]["id"], "msg-001");
+    assert_eq!(lines[0]["reply_count"], 2);
+    assert_eq!(lines[1]["reply_count"], 0);
+}
+
+#[test]
+fn messages_group_by_rejects_unknown_mode_and_follow() {
+    let backend = TopicsLogBackend::new(rfc3339("2026-02-09T12:00:00Z"));
+    let out = run_cli_for_test(&["messages", "--group-by", "sender"], &backend);
+    assert_eq!(out.exit_code, 2);
+    assert!(
+        out.stderr.contains("invalid --group-by value"),
+        "{}",
+        out.stderr
+    );
+
+    let out = run_cli_for_test(&["messages", "--group-by", "thread", "--follow"], &backend);
+    assert_eq!(out.exit_code, 2);
+
+    let out = run_cli_for_test(&["messages", "--expand"], &backend);
+    assert_eq!(out.exit_code, 2);
+    assert!(out.stderr.contains("--expand requires --group-by thread"));
+}
+
 #[test]
 fn messages_json_matches_golden() {
     let now = rfc3339("2026-02-09T12:00:00Z");
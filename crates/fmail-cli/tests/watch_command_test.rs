@@ -9,9 +9,9 @@ use std::time::Duration;
 
 use chrono::{DateTime, Utc};
 use fmail_cli::{run_cli_for_test, FmailBackend};
-use fmail_core::agent_registry::AgentRecord;
+use fmail_core::agent_registry::{AgentPresence, AgentRecord};
 use fmail_core::message::Message;
-use fmail_core::store::TopicSummary;
+use fmail_core::store::{ReadReceipts, TopicSummary};
 
 #[derive(Clone)]
 struct WatchBackend {
@@ -81,6 +81,16 @@ impl FmailBackend for WatchBackend {
         Err("not implemented".to_string())
     }
 
+    fn set_agent_state(
+        &self,
+        _name: &str,
+        _state: Option<AgentPresence>,
+        _status: Option<&str>,
+        _host: &str,
+    ) -> Result<AgentRecord, String> {
+        Err("not implemented".to_string())
+    }
+
     fn hostname(&self) -> String {
         "test-host".to_string()
     }
@@ -129,6 +139,14 @@ impl FmailBackend for WatchBackend {
             .ok_or_else(|| format!("message not found: {}", path.display()))
     }
 
+    fn read_receipts(&self, _reader: &str) -> Result<ReadReceipts, String> {
+        Err("not implemented".to_string())
+    }
+
+    fn mark_read(&self, _message_ids: &[String], _reader: &str) -> Result<(), String> {
+        Err("not implemented".to_string())
+    }
+
     fn init_project(&self, _project_id: Option<&str>) -> Result<(), String> {
         Ok(())
     }
@@ -155,6 +173,7 @@ fn make_message(id: &str, to: &str, body: &str) -> Message {
         priority: String::new(),
         host: String::new(),
         tags: Vec::new(),
+        deliver_after: None,
     }
 }
 
@@ -4,9 +4,9 @@ use std::path::PathBuf;
 
 use chrono::{DateTime, Utc};
 use fmail_cli::{run_cli_for_test, FmailBackend};
-use fmail_core::agent_registry::AgentRecord;
+use fmail_core::agent_registry::{AgentPresence, AgentRecord};
 use fmail_core::message::Message;
-use fmail_core::store::TopicSummary;
+use fmail_core::store::{ReadReceipts, TopicSummary};
 
 struct InMemoryBackend {
     now: DateTime<Utc>,
@@ -39,6 +39,16 @@ impl FmailBackend for InMemoryBackend {
         Err("not implemented".to_string())
     }
 
+    fn set_agent_state(
+        &self,
+        _name: &str,
+        _state: Option<AgentPresence>,
+        _status: Option<&str>,
+        _host: &str,
+    ) -> Result<AgentRecord, String> {
+        Err("not implemented".to_string())
+    }
+
     fn hostname(&self) -> String {
         "test-host".to_string()
     }
@@ -67,6 +77,14 @@ impl FmailBackend for InMemoryBackend {
         Err("not implemented".to_string())
     }
 
+    fn read_receipts(&self, _reader: &str) -> Result<ReadReceipts, String> {
+        Err("not implemented".to_string())
+    }
+
+    fn mark_read(&self, _message_ids: &[String], _reader: &str) -> Result<(), String> {
+        Err("not implemented".to_string())
+    }
+
     fn init_project(&self, _project_id: Option<&str>) -> Result<(), String> {
         Ok(())
     }
@@ -104,6 +122,7 @@ fn who_nonempty_text_matches_golden() {
                 name: "alice".to_string(),
                 host: None,
                 status: None,
+                state: None,
                 first_seen: rfc3339("2026-02-08T00:00:00Z"),
                 last_seen: rfc3339("2026-02-08T23:59:30Z"),
             },
@@ -111,6 +130,7 @@ fn who_nonempty_text_matches_golden() {
                 name: "bob".to_string(),
                 host: None,
                 status: None,
+                state: None,
                 first_seen: rfc3339("2026-02-08T00:00:00Z"),
                 last_seen: rfc3339("2026-02-08T22:00:00Z"),
             },
@@ -118,6 +138,7 @@ fn who_nonempty_text_matches_golden() {
                 name: "carol".to_string(),
                 host: Some("macbook-pro".to_string()),
                 status: Some("working on fmail".to_string()),
+                state: None,
                 first_seen: rfc3339("2026-02-07T00:00:00Z"),
                 last_seen: rfc3339("2026-02-06T23:00:00Z"),
             },
@@ -149,6 +170,7 @@ fn who_json_nonempty_matches_golden() {
             name: "alice".to_string(),
             host: Some("macbook-pro".to_string()),
             status: None,
+            state: None,
             first_seen: rfc3339("2026-02-08T00:00:00Z"),
             last_seen: rfc3339("2026-02-09T00:00:00Z"),
         }]),
@@ -191,3 +213,45 @@ fn who_rejects_positional_args_with_argsmax_message() {
     assert!(out.stdout.is_empty(), "stdout: {}", out.stdout);
     assert_eq!(out.stderr, "expected at most 0 args, got 1\n");
 }
+
+#[test]
+fn who_state_filters_and_labels_agents() {
+    let now = rfc3339("2026-02-09T00:00:00Z");
+    let record = |name: &str, state: Option<AgentPresence>, last_seen: &str| AgentRecord {
+        name: name.to_string(),
+        host: None,
+        status: Some("on call".to_string()),
+        state,
+        first_seen: rfc3339("2026-02-08T00:00:00Z"),
+        last_seen: rfc3339(last_seen),
+    };
+    let backend = InMemoryBackend {
+        now,
+        records: Some(vec![
+            record("alice", Some(AgentPresence::Busy), "2026-02-08T23:59:30Z"),
+            record("bob", None, "2026-02-08T22:00:00Z"),
+            record(
+                "carol",
+                Some(AgentPresence::Available),
+                "2026-02-08T23:59:30Z",
+            ),
+        ]),
+    };
+
+    let out = run_cli_for_test(&["who", "--state", "busy"], &backend);
+    assert_eq!(out.exit_code, 0, "stderr: {}", out.stderr);
+    assert!(
+        out.stdout.contains("[busy] on call"),
+        "stdout: {}",
+        out.stdout
+    );
+    assert!(!out.stdout.contains("carol"), "stdout: {}", out.stdout);
+
+    let out = run_cli_for_test(&["who", "--state=offline", "--json"], &backend);
+    assert_eq!(out.exit_code, 0, "stderr: {}", out.stderr);
+    assert!(out.stdout.contains("\"bob\""), "stdout: {}", out.stdout);
+    assert!(!out.stdout.contains("\"alice\""), "stdout: {}", out.stdout);
+
+    let out = run_cli_for_test(&["who", "--state", "asleep"], &backend);
+    assert_eq!(out.exit_code, 2);
+}
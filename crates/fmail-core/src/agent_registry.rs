@@ -11,6 +11,51 @@ pub struct AgentRecord {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<String>,
 
+    /// Structured presence, set independently of the free-text `status`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<AgentPresence>,
+
     pub first_seen: DateTime<Utc>,
     pub last_seen: DateTime<Utc>,
 }
+
+/// Machine-readable presence an agent can advertise alongside its status text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AgentPresence {
+    Busy,
+    Available,
+    Away,
+    Offline,
+}
+
+impl AgentPresence {
+    pub const ALL: [Self; 4] = [Self::Busy, Self::Available, Self::Away, Self::Offline];
+
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Busy => "busy",
+            Self::Available => "available",
+            Self::Away => "away",
+            Self::Offline => "offline",
+        }
+    }
+
+    /// Parse a presence name, case-insensitively.
+    pub fn parse(value: &str) -> Result<Self, String> {
+        let normalized = value.trim().to_ascii_lowercase();
+        Self::ALL
+            .into_iter()
+            .find(|state| state.as_str() == normalized)
+            .ok_or_else(|| {
+                format!("invalid state {value:?} (use busy, available, away, or offline)")
+            })
+    }
+}
+
+impl std::fmt::Display for AgentPresence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
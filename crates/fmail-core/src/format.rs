@@ -1,5 +1,7 @@
 use chrono::{DateTime, Duration, Utc};
 
+use crate::message::Message;
+
 const ACTIVE_WINDOW: Duration = Duration::minutes(1);
 
 pub fn is_active(now: DateTime<Utc>, t: DateTime<Utc>) -> bool {
@@ -46,5 +48,118 @@ fn is_zero_time(t: DateTime<Utc>) -> bool {
         && t.second() == 0
 }
 
+/// Maximum snippet length, in chars, for [`RenderStyle::Compact`].
+pub const COMPACT_SNIPPET_CHARS: usize = 60;
+
+/// How [`render_message`] lays out a message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenderStyle {
+    /// `id from -> to: body` on one line; the default `fmail log` output.
+    Line,
+    /// `HH:MM:SS from: snippet` on one line, body truncated.
+    Compact,
+    /// Headers (including reply-to when set) followed by the full body.
+    Verbose,
+    /// The message encoded as a single JSON object.
+    Json,
+}
+
+impl RenderStyle {
+    pub const ALL: [Self; 4] = [Self::Line, Self::Compact, Self::Verbose, Self::Json];
+
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Line => "line",
+            Self::Compact => "compact",
+            Self::Verbose => "verbose",
+            Self::Json => "json",
+        }
+    }
+
+    /// Parse a `--format` value, case-insensitively.
+    pub fn parse(value: &str) -> Result<Self, String> {
+        let normalized = value.trim().to_ascii_lowercase();
+        Self::ALL
+            .into_iter()
+            .find(|style| style.as_str() == normalized)
+            .ok_or_else(|| {
+                format!("invalid format {value:?} (use line, compact, verbose, or json)")
+            })
+    }
+}
+
+/// Render `message` in `style`. The result has no trailing newline.
+pub fn render_message(message: &Message, style: RenderStyle) -> Result<String, String> {
+    match style {
+        RenderStyle::Line => Ok(format!(
+            "{} {} -> {}: {}",
+            message.id,
+            message.from,
+            message.to,
+            body_text(&message.body)
+        )),
+        RenderStyle::Compact => {
+            let flat = body_text(&message.body)
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" ");
+            Ok(format!(
+                "{} {}: {}",
+                message.time.format("%H:%M:%S"),
+                message.from,
+                truncate_chars(&flat, COMPACT_SNIPPET_CHARS)
+            ))
+        }
+        RenderStyle::Verbose => {
+            let mut out = format!(
+                "ID: {}\nFrom: {}\nTo: {}\nDate: {}\n",
+                message.id,
+                message.from,
+                message.to,
+                message
+                    .time
+                    .to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+            );
+            if !message.reply_to.is_empty() {
+                out.push_str(&format!("Reply-To: {}\n", message.reply_to));
+            }
+            if !message.priority.is_empty() {
+                out.push_str(&format!("Priority: {}\n", message.priority));
+            }
+            if !message.tags.is_empty() {
+                out.push_str(&format!("Tags: {}\n", message.tags.join(", ")));
+            }
+            out.push('\n');
+            out.push_str(&body_text(&message.body));
+            Ok(out)
+        }
+        RenderStyle::Json => {
+            serde_json::to_string(message).map_err(|e| format!("encode message: {e}"))
+        }
+    }
+}
+
+/// Message body as display text: strings verbatim, anything else as JSON.
+pub fn body_text(body: &serde_json::Value) -> String {
+    match body {
+        serde_json::Value::String(text) => text.clone(),
+        other => serde_json::to_string(other).unwrap_or_else(|_| other.to_string()),
+    }
+}
+
+/// Truncate to at most `max_chars` chars, ending with `…` when shortened.
+pub fn truncate_chars(input: &str, max_chars: usize) -> String {
+    if max_chars == 0 {
+        return String::new();
+    }
+    if input.chars().count() <= max_chars {
+        return input.to_owned();
+    }
+    let mut out = input.chars().take(max_chars - 1).collect::<String>();
+    out.push('…');
+    out
+}
+
 use chrono::Datelike;
 use chrono::Timelike;
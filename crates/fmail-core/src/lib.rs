@@ -10,6 +10,7 @@ pub mod names;
 pub mod project;
 pub mod root;
 pub mod store;
+pub mod thread;
 pub mod validate;
 
 #[cfg(test)]
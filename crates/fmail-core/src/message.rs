@@ -31,6 +31,9 @@ pub struct Message {
     pub host: String,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub tags: Vec<String>,
+    /// Scheduled delivery: readers hide the message until this time.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deliver_after: Option<DateTime<Utc>>,
 }
 
 /// Generate a sortable message ID using UTC time and a per-process sequence counter.
@@ -42,6 +45,12 @@ pub fn generate_message_id(now: DateTime<Utc>) -> String {
 }
 
 impl Message {
+    /// Whether the message is visible to readers at `now`.
+    #[must_use]
+    pub fn is_due(&self, now: DateTime<Utc>) -> bool {
+        self.deliver_after.map_or(true, |at| at <= now)
+    }
+
     /// Validate required fields and basic constraints.
     ///
     /// Go parity: `Message.Validate()`.
@@ -161,6 +170,7 @@ mod tests {
             priority: String::new(),
             host: String::new(),
             tags: vec![],
+            deliver_after: None,
         };
         assert!(msg.validate().is_ok());
     }
@@ -177,6 +187,7 @@ mod tests {
             priority: String::new(),
             host: String::new(),
             tags: vec![],
+            deliver_after: None,
         };
         let err = msg.validate().unwrap_err();
         assert!(err.contains("missing id"), "got: {err}");
@@ -194,6 +205,7 @@ mod tests {
             priority: String::new(),
             host: String::new(),
             tags: vec![],
+            deliver_after: None,
         };
         let err = msg.validate().unwrap_err();
         assert!(err.contains("invalid from"), "got: {err}");
@@ -211,6 +223,7 @@ mod tests {
             priority: "urgent".to_string(),
             host: String::new(),
             tags: vec![],
+            deliver_after: None,
         };
         let err = msg.validate().unwrap_err();
         assert!(err.contains("invalid priority"), "got: {err}");
@@ -228,6 +241,7 @@ mod tests {
             priority: String::new(),
             host: String::new(),
             tags: vec![],
+            deliver_after: None,
         };
         assert!(msg.validate().is_ok());
     }
@@ -246,6 +260,7 @@ mod tests {
             priority: "high".to_string(),
             host: String::new(),
             tags: vec!["bug".to_string()],
+            deliver_after: None,
         };
         let json = serde_json::to_string(&msg).unwrap_or_default();
         let parsed: Message = serde_json::from_str(&json).unwrap_or_else(|_| msg.clone());
@@ -1,5 +1,7 @@
 //! Random agent name generation ported from Go `internal/names/cartoon_names.go`.
 
+use std::collections::HashSet;
+
 use rand::Rng;
 
 static BASE_ADJECTIVES: &[&str] = &[
@@ -324,8 +326,51 @@ pub fn random_loop_name_three_part<R: Rng>(rng: &mut R) -> String {
     format!("{adj}-{given}-{family}")
 }
 
+/// Number of random base names tried by [`generate_unique`] before giving up.
+const UNIQUE_MAX_ATTEMPTS: usize = 32;
+
+/// Number of consecutive suffixes tried per base name when the first one is taken.
+const UNIQUE_SUFFIX_SPAN: u32 = 16;
+
+/// Generate a random numbered name: `adjective-singlename-N`.
+pub fn random_numbered_name<R: Rng>(rng: &mut R) -> String {
+    let base = random_loop_name_two_part(rng);
+    let n = rng.gen_range(1..100u32);
+    format!("{base}-{n}")
+}
+
+/// Generate an `adjective-singlename-N` name that is not in `existing`.
+pub fn generate_unique(existing: &HashSet<String>) -> Result<String, String> {
+    generate_unique_with(&mut rand::thread_rng(), existing)
+}
+
+/// Like [`generate_unique`], drawing randomness from `rng`.
+///
+/// Each attempt picks a random base name and number, then walks forward
+/// through the next few numbers before drawing a new base.
+pub fn generate_unique_with<R: Rng>(
+    rng: &mut R,
+    existing: &HashSet<String>,
+) -> Result<String, String> {
+    for _ in 0..UNIQUE_MAX_ATTEMPTS {
+        let base = random_loop_name_two_part(rng);
+        let start = rng.gen_range(1..100u32);
+        for n in start..start + UNIQUE_SUFFIX_SPAN {
+            let candidate = format!("{base}-{n}");
+            if !existing.contains(&candidate) {
+                return Ok(candidate);
+            }
+        }
+    }
+    Err(format!(
+        "unable to generate unique name after {UNIQUE_MAX_ATTEMPTS} attempts"
+    ))
+}
+
 #[cfg(test)]
 mod tests {
+    #![allow(clippy::expect_used)]
+
     use super::*;
     use rand::SeedableRng;
 
@@ -361,6 +406,47 @@ mod tests {
         );
     }
 
+    #[test]
+    fn numbered_name_format() {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+        let name = random_numbered_name(&mut rng);
+        let (base, n) = name.rsplit_once('-').expect("suffix");
+        assert!(base.contains('-'), "expected adjective-name base: {name}");
+        assert!(n.parse::<u32>().is_ok(), "expected numeric suffix: {name}");
+    }
+
+    #[test]
+    fn generate_unique_avoids_taken_names() {
+        let mut taken = HashSet::new();
+        let mut rng = rand::rngs::StdRng::seed_from_u64(11);
+        for _ in 0..5 {
+            let name = generate_unique_with(&mut rng, &taken).expect("generate");
+            taken.insert(name);
+        }
+
+        // Replaying the same seed would reproduce the taken names; generation
+        // must step past every one of them.
+        let mut rng = rand::rngs::StdRng::seed_from_u64(11);
+        for _ in 0..5 {
+            let name = generate_unique_with(&mut rng, &taken).expect("generate");
+            assert!(!taken.contains(&name), "collided with taken name: {name}");
+            taken.insert(name);
+        }
+        assert_eq!(taken.len(), 10);
+    }
+
+    #[test]
+    fn generate_unique_errors_when_exhausted() {
+        // A constant RNG always yields the same base and starting number.
+        let mut rng = rand::rngs::mock::StepRng::new(0, 0);
+        let base = random_loop_name_two_part(&mut rand::rngs::mock::StepRng::new(0, 0));
+        let taken: HashSet<String> = (1..1 + UNIQUE_SUFFIX_SPAN)
+            .map(|n| format!("{base}-{n}"))
+            .collect();
+        let err = generate_unique_with(&mut rng, &taken).expect_err("exhausted");
+        assert!(err.contains("unable to generate unique name"), "{err}");
+    }
+
     #[test]
     fn single_names_are_deduplicated() {
         let singles = build_single_names();
@@ -1,16 +1,19 @@
 //! fmail-core storage primitives.
 
+use std::collections::BTreeMap;
 use std::fs;
 use std::io::Write;
 use std::path::{Path, PathBuf};
 
 use chrono::{DateTime, Utc};
+#[cfg(unix)]
+use nix::fcntl::{Flock, FlockArg};
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
-use crate::agent_registry::AgentRecord;
+use crate::agent_registry::{AgentPresence, AgentRecord};
 use crate::message::{generate_message_id, Message, MAX_MESSAGE_SIZE};
-use crate::validate::{normalize_agent_name, normalize_target};
+use crate::validate::{normalize_agent_name, normalize_target, normalize_topic};
 
 /// Summary info for a topic (used by `topics` command).
 #[derive(Debug, Clone, Serialize)]
@@ -21,9 +24,34 @@ pub struct TopicSummary {
     pub last_activity: Option<DateTime<Utc>>,
 }
 
+/// Messages one reader has seen, keyed by message ID with the first read time.
+///
+/// Stored per reader at `.fmail/read/<reader>.json`, so marking a message read
+/// never touches another agent's unread state.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReadReceipts {
+    #[serde(default)]
+    pub read: BTreeMap<String, DateTime<Utc>>,
+}
+
+impl ReadReceipts {
+    /// Whether `message` is still unread for `reader`. A reader's own
+    /// messages never count as unread.
+    pub fn is_unread(&self, message: &Message, reader: &str) -> bool {
+        !message.from.eq_ignore_ascii_case(reader.trim()) && !self.read.contains_key(&message.id)
+    }
+}
+
 /// Sentinel error message for agent-already-exists.
 pub const ERR_AGENT_EXISTS: &str = "agent already exists";
 
+/// Guard for the store-wide lock: `flock(2)` on unix, the std file lock
+/// elsewhere.
+#[cfg(unix)]
+type StoreLock = Flock<fs::File>;
+#[cfg(not(unix))]
+type StoreLock = fs::File;
+
 /// Store rooted at `<project_root>/.fmail`.
 #[derive(Debug, Clone)]
 pub struct Store {
@@ -81,6 +109,7 @@ impl Store {
                 Some(host_trimmed.to_string())
             },
             status: None,
+            state: None,
             first_seen: now,
             last_seen: now,
         };
@@ -166,6 +195,47 @@ impl Store {
         status: &str,
         host: &str,
         now: DateTime<Utc>,
+    ) -> Result<AgentRecord, String> {
+        self.update_agent_record(name, host, now, |record| {
+            let trimmed = status.trim();
+            record.status = if trimmed.is_empty() {
+                None
+            } else {
+                Some(trimmed.to_string())
+            };
+        })
+    }
+
+    /// Set or clear an agent's structured presence state.
+    ///
+    /// When `status` is given the free-text status is written in the same
+    /// update; otherwise it is left untouched. The record is created when
+    /// missing.
+    pub fn set_agent_state(
+        &self,
+        name: &str,
+        state: Option<AgentPresence>,
+        status: Option<&str>,
+        host: &str,
+        now: DateTime<Utc>,
+    ) -> Result<AgentRecord, String> {
+        self.update_agent_record(name, host, now, |record| {
+            record.state = state;
+            if let Some(status) = status {
+                let trimmed = status.trim();
+                record.status = (!trimmed.is_empty()).then(|| trimmed.to_string());
+            }
+        })
+    }
+
+    /// Load (or create) an agent record, apply `update`, touch `last_seen`,
+    /// and write it back.
+    fn update_agent_record(
+        &self,
+        name: &str,
+        host: &str,
+        now: DateTime<Utc>,
+        update: impl FnOnce(&mut AgentRecord),
     ) -> Result<AgentRecord, String> {
         let (path, normalized) = self.agent_record_path(name)?;
         self.ensure_root()?;
@@ -175,6 +245,7 @@ impl Store {
             name: normalized.clone(),
             host: None,
             status: None,
+            state: None,
             first_seen: now,
             last_seen: now,
         });
@@ -187,12 +258,7 @@ impl Store {
         }
         record.last_seen = now;
 
-        let trimmed = status.trim();
-        record.status = if trimmed.is_empty() {
-            None
-        } else {
-            Some(trimmed.to_string())
-        };
+        update(&mut record);
 
         let host_trimmed = host.trim();
         if !host_trimmed.is_empty() {
@@ -309,6 +375,16 @@ impl Store {
         serde_json::from_str(&data).map_err(|e| format!("parse message: {e}"))
     }
 
+    /// Read a message, returning `None` while its scheduled delivery is still pending.
+    pub fn read_due_message(
+        &self,
+        path: &Path,
+        now: DateTime<Utc>,
+    ) -> Result<Option<Message>, String> {
+        let message = self.read_message(path)?;
+        Ok(message.is_due(now).then_some(message))
+    }
+
     // -----------------------------------------------------------------
     // List topics
     // -----------------------------------------------------------------
@@ -357,6 +433,77 @@ impl Store {
         Ok(topics)
     }
 
+    // -----------------------------------------------------------------
+    // Rename / merge topics
+    // -----------------------------------------------------------------
+
+    /// Rename a topic by moving its messages into a new topic. Refuses when
+    /// the new name already holds messages. Returns the number moved.
+    pub fn rename_topic(&self, from: &str, to: &str) -> Result<usize, String> {
+        let (from, to) = normalize_topic_pair(from, to)?;
+        if !self.list_topic_message_files(&to)?.is_empty() {
+            return Err(format!("topic \"{to}\" already exists"));
+        }
+        self.move_topic_messages(&from, &to)
+    }
+
+    /// Merge one topic into another existing topic. Refuses when a message
+    /// id exists in both. Returns the number of messages moved.
+    pub fn merge_topic(&self, from: &str, into: &str) -> Result<usize, String> {
+        let (from, into) = normalize_topic_pair(from, into)?;
+        if self.list_topic_message_files(&into)?.is_empty() {
+            return Err(format!("topic \"{into}\" not found"));
+        }
+        let into_dir = self.topic_dir(&into);
+        for path in self.list_topic_message_files(&from)? {
+            if let Some(name) = path.file_name() {
+                if into_dir.join(name).exists() {
+                    return Err(format!(
+                        "message {} exists in both topics",
+                        Path::new(name).with_extension("").display()
+                    ));
+                }
+            }
+        }
+        self.move_topic_messages(&from, &into)
+    }
+
+    fn move_topic_messages(&self, from: &str, to: &str) -> Result<usize, String> {
+        let files = self.list_topic_message_files(from)?;
+        if files.is_empty() {
+            return Err(format!("topic \"{from}\" not found"));
+        }
+        let dir = self.topic_dir(to);
+        fs::create_dir_all(&dir).map_err(|e| format!("create topic dir: {e}"))?;
+
+        // Each message is rewritten in place through a temp file, then
+        // renamed into the new topic, so a crash never leaves it in both
+        // topics or in neither.
+        let mut moved = 0usize;
+        for path in files {
+            let Some(name) = path.file_name() else {
+                continue;
+            };
+            let dest = dir.join(name);
+            if dest.exists() {
+                return Err(format!(
+                    "message {} exists in both topics",
+                    Path::new(name).with_extension("").display()
+                ));
+            }
+            let mut message = self.read_message(&path)?;
+            message.to = to.to_string();
+            let data = serde_json::to_string_pretty(&message)
+                .map_err(|e| format!("encode message: {e}"))?;
+            write_file_atomic(&path, data.as_bytes(), "message")?;
+            fs::rename(&path, &dest).map_err(|e| format!("move message: {e}"))?;
+            moved += 1;
+        }
+        // Best effort: the old directory is only removed once empty.
+        let _ = fs::remove_dir(self.topic_dir(from));
+        Ok(moved)
+    }
+
     // -----------------------------------------------------------------
     // List message files
     // -----------------------------------------------------------------
@@ -391,6 +538,141 @@ impl Store {
         Ok(files)
     }
 
+    // -----------------------------------------------------------------
+    // Read receipts
+    // -----------------------------------------------------------------
+
+    /// Directory holding per-reader receipt files.
+    pub fn receipts_dir(&self) -> PathBuf {
+        self.root.join("read")
+    }
+
+    /// Load the receipts for `reader`; a reader with no file has read nothing.
+    pub fn read_receipts(&self, reader: &str) -> Result<ReadReceipts, String> {
+        let path = self.receipts_path(reader)?;
+        let data = match fs::read_to_string(&path) {
+            Ok(v) => v,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                return Ok(ReadReceipts::default());
+            }
+            Err(err) => return Err(format!("read receipts: {err}")),
+        };
+        serde_json::from_str(&data).map_err(|e| format!("parse receipts: {e}"))
+    }
+
+    /// Record that `reader` has read `message_id`.
+    pub fn mark_read(
+        &self,
+        message_id: &str,
+        reader: &str,
+        now: DateTime<Utc>,
+    ) -> Result<(), String> {
+        self.mark_all_read(&[message_id.to_string()], reader, now)
+    }
+
+    /// Record several messages as read by `reader` in a single write.
+    ///
+    /// The first read time is kept when a message is marked again.
+    pub fn mark_all_read(
+        &self,
+        message_ids: &[String],
+        reader: &str,
+        now: DateTime<Utc>,
+    ) -> Result<(), String> {
+        let path = self.receipts_path(reader)?;
+        // Held across the read-modify-write so concurrent markers of the same
+        // reader cannot drop each other's receipts.
+        let _lock = self.lock()?;
+        let mut receipts = self.read_receipts(reader)?;
+        let mut changed = false;
+        for id in message_ids {
+            let id = id.trim();
+            if id.is_empty() {
+                return Err("missing message id".to_string());
+            }
+            if !receipts.read.contains_key(id) {
+                receipts.read.insert(id.to_string(), now);
+                changed = true;
+            }
+        }
+        if !changed {
+            return Ok(());
+        }
+
+        fs::create_dir_all(self.receipts_dir()).map_err(|e| format!("create receipts dir: {e}"))?;
+        write_receipts_file(&path, &receipts)
+    }
+
+    /// Drop receipts for messages that no longer exist, e.g. after `gc`.
+    /// Returns how many receipt entries were removed.
+    pub fn prune_read_receipts(&self) -> Result<usize, String> {
+        let dir = self.receipts_dir();
+        if !dir.is_dir() {
+            return Ok(0);
+        }
+        let _lock = self.lock()?;
+        let existing: std::collections::HashSet<String> = self
+            .list_all_message_files()?
+            .iter()
+            .filter_map(|path| path.file_stem().and_then(|stem| stem.to_str()))
+            .map(str::to_string)
+            .collect();
+
+        let mut removed = 0usize;
+        let entries = fs::read_dir(&dir).map_err(|e| format!("read receipts dir: {e}"))?;
+        for entry in entries {
+            let path = entry.map_err(|e| format!("read receipts dir: {e}"))?.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+                continue;
+            }
+            let data = fs::read_to_string(&path).map_err(|e| format!("read receipts: {e}"))?;
+            let mut receipts: ReadReceipts =
+                serde_json::from_str(&data).map_err(|e| format!("parse receipts: {e}"))?;
+            let before = receipts.read.len();
+            receipts.read.retain(|id, _| existing.contains(id));
+            if receipts.read.len() == before {
+                continue;
+            }
+            removed += before - receipts.read.len();
+            write_receipts_file(&path, &receipts)?;
+        }
+        Ok(removed)
+    }
+
+    /// Whether `reader` has marked `message_id` as read.
+    pub fn is_read_by(&self, message_id: &str, reader: &str) -> Result<bool, String> {
+        Ok(self
+            .read_receipts(reader)?
+            .read
+            .contains_key(message_id.trim()))
+    }
+
+    /// Take the store-wide exclusive lock on `.fmail/.lock`; it is released
+    /// when the returned file is dropped.
+    fn lock(&self) -> Result<StoreLock, String> {
+        self.ensure_root()?;
+        let file = fs::OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(self.root.join(".lock"))
+            .map_err(|e| format!("open store lock: {e}"))?;
+        #[cfg(unix)]
+        {
+            Flock::lock(file, FlockArg::LockExclusive).map_err(|(_, e)| format!("lock store: {e}"))
+        }
+        #[cfg(not(unix))]
+        {
+            file.lock().map_err(|e| format!("lock store: {e}"))?;
+            Ok(file)
+        }
+    }
+
+    fn receipts_path(&self, reader: &str) -> Result<PathBuf, String> {
+        let normalized = normalize_agent_name(reader)?;
+        Ok(self.receipts_dir().join(format!("{normalized}.json")))
+    }
+
     // -----------------------------------------------------------------
     // Project
     // -----------------------------------------------------------------
@@ -509,6 +791,16 @@ fn read_project_file(path: &Path) -> Result<crate::project::Project, String> {
     serde_json::from_str(&data).map_err(|e| format!("parse project: {e}"))
 }
 
+/// Normalize a source/destination topic pair, rejecting identical names.
+fn normalize_topic_pair(from: &str, to: &str) -> Result<(String, String), String> {
+    let from = normalize_topic(from).map_err(|e| format!("invalid topic \"{from}\": {e}"))?;
+    let to = normalize_topic(to).map_err(|e| format!("invalid topic \"{to}\": {e}"))?;
+    if from == to {
+        return Err(format!("topic \"{from}\" cannot be moved onto itself"));
+    }
+    Ok((from, to))
+}
+
 /// List subdirectory names in a directory, sorted alphabetically.
 ///
 /// Go parity: `listSubDirs` in `gc.go`.
@@ -609,6 +901,31 @@ fn write_file_exclusive(path: &Path, data: &[u8]) -> Result<(), String> {
     Ok(())
 }
 
+/// Replace `path` by writing a sibling temp file and renaming it over, so a
+/// concurrent reader sees either the old or the new `what`, never a partial one.
+fn write_file_atomic(path: &Path, data: &[u8], what: &str) -> Result<(), String> {
+    let name = path
+        .file_name()
+        .ok_or_else(|| format!("invalid {what} path {}", path.display()))?;
+    let tmp = path.with_file_name(format!(".{}.tmp", name.to_string_lossy()));
+    fs::write(&tmp, data).map_err(|e| format!("write {what}: {e}"))?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let _ = fs::set_permissions(&tmp, fs::Permissions::from_mode(0o644));
+    }
+    fs::rename(&tmp, path).map_err(|e| {
+        let _ = fs::remove_file(&tmp);
+        format!("replace {what}: {e}")
+    })
+}
+
+fn write_receipts_file(path: &Path, receipts: &ReadReceipts) -> Result<(), String> {
+    let data =
+        serde_json::to_string_pretty(receipts).map_err(|e| format!("encode receipts: {e}"))?;
+    write_file_atomic(path, data.as_bytes(), "receipts")
+}
+
 fn read_agent_record_file(path: &Path) -> Result<Option<AgentRecord>, String> {
     let data = match fs::read_to_string(path) {
         Ok(v) => v,
@@ -0,0 +1,100 @@
+#![allow(clippy::expect_used, clippy::unwrap_used)]
+
+use chrono::{DateTime, Utc};
+use fmail_core::format::{render_message, truncate_chars, RenderStyle, COMPACT_SNIPPET_CHARS};
+use fmail_core::message::Message;
+
+fn message(body: serde_json::Value) -> Message {
+    Message {
+        id: "20260110-153000-0001".to_string(),
+        from: "architect".to_string(),
+        to: "task".to_string(),
+        time: DateTime::parse_from_rfc3339("2026-01-10T15:30:00Z")
+            .unwrap()
+            .with_timezone(&Utc),
+        body,
+        reply_to: String::new(),
+        priority: String::new(),
+        host: String::new(),
+        tags: Vec::new(),
+        deliver_after: None,
+    }
+}
+
+#[test]
+fn line_matches_log_output() {
+    let msg = message(serde_json::json!("implement JWT auth"));
+    assert_eq!(
+        render_message(&msg, RenderStyle::Line).unwrap(),
+        "20260110-153000-0001 architect -> task: implement JWT auth"
+    );
+}
+
+#[test]
+fn compact_is_one_line_with_time_from_and_snippet() {
+    let msg = message(serde_json::json!("implement\nJWT   auth"));
+    assert_eq!(
+        render_message(&msg, RenderStyle::Compact).unwrap(),
+        "15:30:00 architect: implement JWT auth"
+    );
+}
+
+#[test]
+fn compact_truncates_on_char_boundaries() {
+    let msg = message(serde_json::json!("é".repeat(COMPACT_SNIPPET_CHARS + 5)));
+    let out = render_message(&msg, RenderStyle::Compact).unwrap();
+    let snippet = out.strip_prefix("15:30:00 architect: ").unwrap();
+    assert_eq!(snippet.chars().count(), COMPACT_SNIPPET_CHARS);
+    assert!(snippet.ends_with('…'), "{snippet}");
+}
+
+#[test]
+fn compact_renders_structured_body_as_json() {
+    let msg = message(serde_json::json!({"key": "value"}));
+    assert_eq!(
+        render_message(&msg, RenderStyle::Compact).unwrap(),
+        "15:30:00 architect: {\"key\":\"value\"}"
+    );
+}
+
+#[test]
+fn verbose_includes_reply_to_header_when_present() {
+    let mut msg = message(serde_json::json!("line one\nline two"));
+    msg.reply_to = "20260110-152900-0001".to_string();
+    msg.tags = vec!["auth".to_string(), "api".to_string()];
+    assert_eq!(
+        render_message(&msg, RenderStyle::Verbose).unwrap(),
+        "ID: 20260110-153000-0001\n\
+         From: architect\n\
+         To: task\n\
+         Date: 2026-01-10T15:30:00Z\n\
+         Reply-To: 20260110-152900-0001\n\
+         Tags: auth, api\n\
+         \n\
+         line one\nline two"
+    );
+}
+
+#[test]
+fn verbose_omits_reply_to_header_when_absent() {
+    let msg = message(serde_json::json!("hi"));
+    let out = render_message(&msg, RenderStyle::Verbose).unwrap();
+    assert!(!out.contains("Reply-To:"), "{out}");
+}
+
+#[test]
+fn json_round_trips() {
+    let msg = message(serde_json::json!("hi"));
+    let out = render_message(&msg, RenderStyle::Json).unwrap();
+    let decoded: Message = serde_json::from_str(&out).unwrap();
+    assert_eq!(decoded.id, msg.id);
+    assert!(!out.contains('\n'));
+}
+
+#[test]
+fn truncate_chars_edges() {
+    assert_eq!(truncate_chars("abc", 0), "");
+    assert_eq!(truncate_chars("abc", 3), "abc");
+    assert_eq!(truncate_chars("abcd", 3), "ab…");
+    assert_eq!(truncate_chars("abcd", 1), "…");
+}
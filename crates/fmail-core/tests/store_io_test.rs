@@ -22,6 +22,7 @@ fn empty_message(from: &str, to: &str) -> Message {
         priority: String::new(),
         host: String::new(),
         tags: vec![],
+        deliver_after: None,
     }
 }
 
@@ -24,6 +24,7 @@ mod unix_only {
             priority: String::new(),
             host: String::new(),
             tags: vec![],
+            deliver_after: None,
         };
 
         let id = store.save_message(&mut msg, now).expect("save");
@@ -53,6 +54,7 @@ mod unix_only {
             priority: String::new(),
             host: String::new(),
             tags: vec![],
+            deliver_after: None,
         };
 
         let id = store.save_message(&mut msg, now).expect("save");
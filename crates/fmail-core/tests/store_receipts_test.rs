@@ -0,0 +1,130 @@
+#![allow(clippy::expect_used, clippy::unwrap_used)]
+
+use chrono::{TimeZone, Utc};
+use fmail_core::message::Message;
+use fmail_core::store::Store;
+
+#[test]
+fn read_state_is_per_reader() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let store = Store::new(dir.path()).expect("new store");
+    let now = Utc.with_ymd_and_hms(2026, 2, 9, 12, 0, 0).unwrap();
+
+    store
+        .mark_read("20260209-120000-0001", "Alice", now)
+        .expect("mark read");
+
+    assert!(store
+        .is_read_by("20260209-120000-0001", "alice")
+        .expect("alice"));
+    assert!(!store
+        .is_read_by("20260209-120000-0001", "bob")
+        .expect("bob"));
+    assert!(!store
+        .is_read_by("20260209-120000-0002", "alice")
+        .expect("other message"));
+}
+
+#[test]
+fn mark_read_keeps_first_read_time() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let store = Store::new(dir.path()).expect("new store");
+    let t1 = Utc.with_ymd_and_hms(2026, 2, 9, 12, 0, 0).unwrap();
+    let t2 = Utc.with_ymd_and_hms(2026, 2, 9, 13, 0, 0).unwrap();
+
+    store.mark_read("m1", "alice", t1).expect("first");
+    store
+        .mark_all_read(&["m1".to_string(), "m2".to_string()], "alice", t2)
+        .expect("second");
+
+    let receipts = store.read_receipts("alice").expect("receipts");
+    assert_eq!(receipts.read.get("m1"), Some(&t1));
+    assert_eq!(receipts.read.get("m2"), Some(&t2));
+    assert!(store.mark_read(" ", "alice", t2).is_err());
+}
+
+#[test]
+fn mark_read_replaces_receipts_without_leaving_temp_files() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let store = Store::new(dir.path()).expect("new store");
+    let now = Utc.with_ymd_and_hms(2026, 2, 9, 12, 0, 0).unwrap();
+
+    store.mark_read("m1", "alice", now).expect("first");
+    store.mark_read("m2", "alice", now).expect("second");
+
+    let mut names: Vec<String> = std::fs::read_dir(store.receipts_dir())
+        .expect("read receipts dir")
+        .map(|entry| {
+            entry
+                .expect("entry")
+                .file_name()
+                .to_string_lossy()
+                .into_owned()
+        })
+        .collect();
+    names.sort();
+    assert_eq!(names, vec!["alice.json".to_string()]);
+    assert_eq!(
+        store.read_receipts("alice").expect("receipts").read.len(),
+        2
+    );
+}
+
+#[test]
+fn concurrent_marks_for_one_reader_are_all_kept() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let root = dir.path().to_path_buf();
+    let now = Utc.with_ymd_and_hms(2026, 2, 9, 12, 0, 0).unwrap();
+
+    let handles: Vec<_> = (0..8)
+        .map(|n| {
+            let root = root.clone();
+            std::thread::spawn(move || {
+                let store = Store::new(&root).expect("new store");
+                store
+                    .mark_read(&format!("m{n}"), "alice", now)
+                    .expect("mark read");
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().expect("join");
+    }
+
+    let store = Store::new(&root).expect("new store");
+    assert_eq!(
+        store.read_receipts("alice").expect("receipts").read.len(),
+        8
+    );
+}
+
+#[test]
+fn prune_drops_receipts_for_missing_messages() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let store = Store::new(dir.path()).expect("new store");
+    let now = Utc.with_ymd_and_hms(2026, 2, 9, 12, 0, 0).unwrap();
+    assert_eq!(store.prune_read_receipts().expect("prune empty"), 0);
+
+    let mut message = Message {
+        id: String::new(),
+        from: "bob".to_string(),
+        to: "task".to_string(),
+        time: now,
+        body: serde_json::Value::String("hello".to_string()),
+        reply_to: String::new(),
+        priority: String::new(),
+        host: String::new(),
+        tags: Vec::new(),
+        deliver_after: None,
+    };
+    let id = store.save_message(&mut message, now).expect("save");
+    store
+        .mark_all_read(&[id.clone(), "gone".to_string()], "alice", now)
+        .expect("mark alice");
+    store.mark_read("gone", "carol", now).expect("mark carol");
+
+    assert_eq!(store.prune_read_receipts().expect("prune"), 2);
+    assert!(store.is_read_by(&id, "alice").expect("alice kept"));
+    assert!(!store.is_read_by("gone", "alice").expect("alice pruned"));
+    assert!(store.read_receipts("carol").expect("carol").read.is_empty());
+}
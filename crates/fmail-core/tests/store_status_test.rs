@@ -1,6 +1,7 @@
 #![allow(clippy::expect_used, clippy::unwrap_used)]
 
 use chrono::{TimeZone, Utc};
+use fmail_core::agent_registry::AgentPresence;
 use fmail_core::store::Store;
 
 #[test]
@@ -53,3 +54,30 @@ fn clear_status_sets_none_and_preserves_host_when_empty() {
     assert_eq!(cleared.first_seen, t1);
     assert_eq!(cleared.last_seen, t2);
 }
+
+#[test]
+fn state_is_independent_of_status() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let store = Store::new(dir.path()).expect("new store");
+    let now = Utc.with_ymd_and_hms(2026, 2, 9, 12, 0, 0).unwrap();
+
+    store
+        .set_agent_status("alice", "reviewing", "h1", now)
+        .expect("set status");
+    store
+        .set_agent_state("alice", Some(AgentPresence::Busy), None, "", now)
+        .expect("set state");
+
+    let loaded = store
+        .read_agent_record("alice")
+        .expect("read")
+        .expect("some");
+    assert_eq!(loaded.status.as_deref(), Some("reviewing"));
+    assert_eq!(loaded.state, Some(AgentPresence::Busy));
+
+    let cleared = store
+        .set_agent_status("alice", "", "", now)
+        .expect("clear status");
+    assert!(cleared.status.is_none());
+    assert_eq!(cleared.state, Some(AgentPresence::Busy));
+}
@@ -0,0 +1,117 @@
+#![allow(clippy::expect_used, clippy::unwrap_used)]
+
+use chrono::{DateTime, TimeZone, Utc};
+use fmail_core::message::Message;
+use fmail_core::store::Store;
+use serde_json::json;
+
+fn message(to: &str) -> Message {
+    Message {
+        id: String::new(),
+        from: "alice".to_string(),
+        to: to.to_string(),
+        time: DateTime::<Utc>::default(),
+        body: json!("hello"),
+        reply_to: String::new(),
+        priority: String::new(),
+        host: String::new(),
+        tags: vec![],
+        deliver_after: None,
+    }
+}
+
+fn seed(store: &Store, topic: &str, count: usize) -> Vec<String> {
+    let now = Utc.with_ymd_and_hms(2026, 2, 9, 12, 0, 0).unwrap();
+    (0..count)
+        .map(|_| store.save_message(&mut message(topic), now).expect("save"))
+        .collect()
+}
+
+fn counts(store: &Store) -> Vec<(String, usize)> {
+    store
+        .list_topics()
+        .expect("list topics")
+        .into_iter()
+        .map(|t| (t.name, t.messages))
+        .collect()
+}
+
+#[test]
+fn rename_moves_messages_and_rewrites_target() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let store = Store::new(dir.path()).expect("new store");
+    let ids = seed(&store, "tsak", 2);
+
+    assert_eq!(store.rename_topic("tsak", "Task").expect("rename"), 2);
+    assert_eq!(counts(&store), vec![("task".to_string(), 2)]);
+    assert!(!store.topic_dir("tsak").exists());
+
+    let moved = store
+        .read_message(&store.topic_message_path("task", &ids[0]))
+        .expect("read");
+    assert_eq!(moved.to, "task");
+}
+
+#[test]
+fn rename_leaves_no_temp_files_behind() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let store = Store::new(dir.path()).expect("new store");
+    let mut ids = seed(&store, "tsak", 3);
+
+    store.rename_topic("tsak", "task").expect("rename");
+
+    let mut names: Vec<String> = std::fs::read_dir(store.topic_dir("task"))
+        .expect("read topic dir")
+        .map(|entry| {
+            entry
+                .expect("entry")
+                .file_name()
+                .to_string_lossy()
+                .into_owned()
+        })
+        .collect();
+    names.sort();
+    ids.sort();
+    let want: Vec<String> = ids.iter().map(|id| format!("{id}.json")).collect();
+    assert_eq!(names, want);
+}
+
+#[test]
+fn rename_refuses_existing_target() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let store = Store::new(dir.path()).expect("new store");
+    seed(&store, "tsak", 1);
+    seed(&store, "task", 1);
+
+    let err = store.rename_topic("tsak", "task").unwrap_err();
+    assert!(err.contains("already exists"), "{err}");
+    assert_eq!(
+        counts(&store),
+        vec![("task".to_string(), 1), ("tsak".to_string(), 1)]
+    );
+}
+
+#[test]
+fn merge_consolidates_into_existing_topic() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let store = Store::new(dir.path()).expect("new store");
+    seed(&store, "builds", 3);
+    seed(&store, "build", 2);
+
+    assert_eq!(store.merge_topic("builds", "build").expect("merge"), 3);
+    assert_eq!(counts(&store), vec![("build".to_string(), 5)]);
+}
+
+#[test]
+fn merge_requires_existing_target_and_distinct_names() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let store = Store::new(dir.path()).expect("new store");
+    seed(&store, "builds", 1);
+
+    let err = store.merge_topic("builds", "build").unwrap_err();
+    assert!(err.contains("not found"), "{err}");
+    let err = store.merge_topic("builds", "builds").unwrap_err();
+    assert!(err.contains("onto itself"), "{err}");
+    let err = store.rename_topic("missing", "other").unwrap_err();
+    assert!(err.contains("not found"), "{err}");
+}
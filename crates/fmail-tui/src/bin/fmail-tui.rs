@@ -3,6 +3,7 @@ use std::thread;
 use std::time::Duration;
 
 use fmail_core::agent_registry::AgentRecord;
+use fmail_core::constants::ENV_AGENT;
 use fmail_core::message::Message;
 use fmail_core::store::Store;
 use fmail_core::store::TopicSummary;
@@ -16,6 +17,8 @@ struct LiveMailboxSnapshot {
     total_messages: usize,
     total_threads: usize,
     latest_thread: Option<ThreadSummary>,
+    /// Unread direct messages for the current agent, when one is set.
+    inbox_unread: Option<usize>,
 }
 
 fn main() {
@@ -57,7 +60,10 @@ fn render_snapshot() {
         }
     };
 
-    let snapshot = match load_live_mailbox_snapshot(&store) {
+    let reader = std::env::var(ENV_AGENT)
+        .ok()
+        .filter(|name| !name.trim().is_empty());
+    let snapshot = match load_live_mailbox_snapshot(&store, reader.as_deref()) {
         Ok(snapshot) => snapshot,
         Err(err) => {
             println!("error: load live mailbox snapshot: {err}");
@@ -69,6 +75,9 @@ fn render_snapshot() {
     println!("topics: {}", snapshot.topics.len());
     println!("messages: {}", snapshot.total_messages);
     println!("threads: {}", snapshot.total_threads);
+    if let (Some(reader), Some(unread)) = (reader.as_deref(), snapshot.inbox_unread) {
+        println!("inbox: {unread} unread (@{})", reader.trim());
+    }
     if let Some(thread) = snapshot.latest_thread.as_ref() {
         println!(
             "latest thread: {} ({} msgs, {} participants, last {})",
@@ -83,7 +92,10 @@ fn render_snapshot() {
     if !snapshot.agents.is_empty() {
         println!("AGENTS");
         for agent in snapshot.agents.into_iter().take(20) {
-            let status = agent.status.unwrap_or_else(|| "unknown".to_string());
+            let mut status = agent.status.unwrap_or_else(|| "unknown".to_string());
+            if let Some(state) = agent.state {
+                status = format!("[{state}] {status}");
+            }
             println!(
                 "  {:<24} {:<12} {}",
                 trim(&agent.name, 24),
@@ -114,10 +126,17 @@ fn render_snapshot() {
     }
 }
 
-fn load_live_mailbox_snapshot(store: &Store) -> Result<LiveMailboxSnapshot, String> {
+fn load_live_mailbox_snapshot(
+    store: &Store,
+    reader: Option<&str>,
+) -> Result<LiveMailboxSnapshot, String> {
     let topics = store.list_topics()?;
     let agents = store.list_agent_records()?.unwrap_or_default();
     let messages = load_messages(store)?;
+    let inbox_unread = match reader {
+        Some(reader) => Some(count_inbox_unread(store, &messages, reader)?),
+        None => None,
+    };
 
     let thread_messages: Vec<ThreadMessage> = messages.iter().map(to_thread_message).collect();
     let threads = build_threads(&thread_messages);
@@ -132,13 +151,28 @@ fn load_live_mailbox_snapshot(store: &Store) -> Result<LiveMailboxSnapshot, Stri
         total_messages: thread_messages.len(),
         total_threads: threads.len(),
         latest_thread,
+        inbox_unread,
     })
 }
 
+/// Direct messages to `reader` that the reader's receipts do not cover.
+fn count_inbox_unread(store: &Store, messages: &[Message], reader: &str) -> Result<usize, String> {
+    let receipts = store.read_receipts(reader)?;
+    let inbox = format!("@{}", reader.trim());
+    Ok(messages
+        .iter()
+        .filter(|message| message.to.eq_ignore_ascii_case(&inbox))
+        .filter(|message| receipts.is_unread(message, reader))
+        .count())
+}
+
 fn load_messages(store: &Store) -> Result<Vec<Message>, String> {
+    let now = chrono::Utc::now();
     let mut messages = Vec::new();
     for path in store.list_all_message_files()? {
-        messages.push(store.read_message(&path)?);
+        if let Some(message) = store.read_due_message(&path, now)? {
+            messages.push(message);
+        }
     }
     Ok(messages)
 }
@@ -223,7 +257,7 @@ mod tests {
             .save_message(&mut reply_msg, now)
             .expect("save reply message");
 
-        let snapshot = load_live_mailbox_snapshot(&store).expect("load snapshot");
+        let snapshot = load_live_mailbox_snapshot(&store, None).expect("load snapshot");
         assert_eq!(snapshot.agents.len(), 2);
         assert_eq!(snapshot.total_messages, 2);
         assert_eq!(snapshot.total_threads, 1);
@@ -246,7 +280,7 @@ mod tests {
             .save_message(&mut first, now)
             .expect("save first message");
 
-        let before = load_live_mailbox_snapshot(&store).expect("load before");
+        let before = load_live_mailbox_snapshot(&store, None).expect("load before");
         assert_eq!(before.total_messages, 1);
 
         let mut second = message("coder", "task", json!("second"));
@@ -254,12 +288,37 @@ mod tests {
             .save_message(&mut second, now)
             .expect("save second message");
 
-        let after = load_live_mailbox_snapshot(&store).expect("load after");
+        let after = load_live_mailbox_snapshot(&store, None).expect("load after");
         assert_eq!(after.total_messages, 2);
 
         cleanup_temp_dir(&root);
     }
 
+    #[test]
+    fn inbox_badge_counts_only_the_readers_unread_direct_messages() {
+        let root = temp_project_dir("live-snapshot-inbox");
+        let store = Store::new(&root).expect("init store");
+        let now = message("seed", "task", json!("seed")).time;
+
+        let mut first = message("architect", "@coder", json!("first"));
+        store.save_message(&mut first, now).expect("save first");
+        let mut second = message("architect", "@coder", json!("second"));
+        store.save_message(&mut second, now).expect("save second");
+        let mut topic = message("architect", "task", json!("topic"));
+        store.save_message(&mut topic, now).expect("save topic");
+
+        let snapshot = load_live_mailbox_snapshot(&store, Some("coder")).expect("load");
+        assert_eq!(snapshot.inbox_unread, Some(2));
+
+        store.mark_read(&first.id, "coder", now).expect("mark read");
+        let snapshot = load_live_mailbox_snapshot(&store, Some("coder")).expect("load");
+        assert_eq!(snapshot.inbox_unread, Some(1));
+        let snapshot = load_live_mailbox_snapshot(&store, None).expect("load");
+        assert_eq!(snapshot.inbox_unread, None);
+
+        cleanup_temp_dir(&root);
+    }
+
     fn message(from: &str, to: &str, body: serde_json::Value) -> Message {
         Message {
             id: String::new(),
@@ -271,6 +330,7 @@ mod tests {
             priority: String::new(),
             host: String::new(),
             tags: Vec::new(),
+            deliver_after: None,
         }
     }
 
@@ -14,6 +14,8 @@ pub struct BookmarkEntry {
     pub from: String,
     pub preview: String,
     pub note: String,
+    /// Lowercase labels for grouping; filter with `tag:<name>`.
+    pub tags: Vec<String>,
     pub pinned: bool,
     /// Bookmark creation time as seconds since Unix epoch.
     pub created_at: i64,
@@ -31,11 +33,47 @@ impl BookmarkEntry {
             from: String::new(),
             preview: preview.to_owned(),
             note: String::new(),
+            tags: Vec::new(),
             pinned: false,
             created_at: 0,
             message_time: 0,
         }
     }
+
+    #[must_use]
+    pub fn has_tag(&self, tag: &str) -> bool {
+        let tag = normalize_tag(tag);
+        self.tags.contains(&tag)
+    }
+}
+
+impl From<&BookmarkEntry> for crate::state_help::Bookmark {
+    fn from(entry: &BookmarkEntry) -> Self {
+        Self {
+            message_id: entry.message_id.clone(),
+            target: entry.target.clone(),
+            note: entry.note.clone(),
+            tags: entry.tags.clone(),
+        }
+    }
+}
+
+/// Parse a tag list typed by the user: comma or space separated, `#` optional.
+/// Tags are lowercased and deduplicated, keeping first-seen order.
+#[must_use]
+pub fn parse_bookmark_tags(input: &str) -> Vec<String> {
+    let mut tags: Vec<String> = Vec::new();
+    for raw in input.split(|c: char| c == ',' || c.is_whitespace()) {
+        let tag = normalize_tag(raw);
+        if !tag.is_empty() && !tags.contains(&tag) {
+            tags.push(tag);
+        }
+    }
+    tags
+}
+
+fn normalize_tag(raw: &str) -> String {
+    raw.trim().trim_start_matches('#').to_ascii_lowercase()
 }
 
 // ---------------------------------------------------------------------------
@@ -81,6 +119,7 @@ impl BookmarkSort {
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct BookmarksFilter {
     pub target: String,
+    pub tag: String,
     pub text: String,
     pub pinned_only: bool,
 }
@@ -88,10 +127,13 @@ pub struct BookmarksFilter {
 impl BookmarksFilter {
     #[must_use]
     pub fn active_label(&self) -> String {
-        let mut parts = Vec::with_capacity(3);
+        let mut parts = Vec::with_capacity(4);
         if !self.target.trim().is_empty() {
             parts.push(format!("target:{}", self.target.trim()));
         }
+        if !self.tag.trim().is_empty() {
+            parts.push(format!("tag:{}", self.tag.trim()));
+        }
         if !self.text.trim().is_empty() {
             parts.push(format!("text:{}", self.text.trim()));
         }
@@ -115,14 +157,18 @@ impl BookmarksFilter {
         {
             return false;
         }
+        if !self.tag.trim().is_empty() && !bookmark.has_tag(&self.tag) {
+            return false;
+        }
         if !self.text.trim().is_empty() {
             let needle = self.text.trim().to_ascii_lowercase();
             let blob = format!(
-                "{} {} {} {}",
+                "{} {} {} {} {}",
                 bookmark.preview.to_ascii_lowercase(),
                 bookmark.note.to_ascii_lowercase(),
                 bookmark.target.to_ascii_lowercase(),
                 bookmark.from.to_ascii_lowercase(),
+                bookmark.tags.join(" "),
             );
             if !blob.contains(&needle) {
                 return false;
@@ -149,6 +195,7 @@ pub fn parse_bookmarks_filter(input: &str) -> BookmarksFilter {
         let value = value.trim();
         match key.as_str() {
             "target" => filter.target = value.to_owned(),
+            "tag" | "tags" => filter.tag = normalize_tag(value),
             "text" => text_terms.push(value.to_owned()),
             "pinned" => {
                 if matches!(value, "1" | "true" | "only") {
@@ -177,6 +224,8 @@ pub struct BookmarksViewModel {
     filter_input: String,
 
     edit_active: bool,
+    /// Whether the open edit targets the tag list instead of the note.
+    edit_tags: bool,
     edit_input: String,
 
     status_line: String,
@@ -200,6 +249,7 @@ impl BookmarksViewModel {
             filter_active: false,
             filter_input: String::new(),
             edit_active: false,
+            edit_tags: false,
             edit_input: String::new(),
             status_line: String::new(),
             status_err: false,
@@ -248,6 +298,11 @@ impl BookmarksViewModel {
         self.edit_active
     }
 
+    #[must_use]
+    pub fn filter(&self) -> &BookmarksFilter {
+        &self.filter
+    }
+
     #[must_use]
     pub fn status_line(&self) -> &str {
         &self.status_line
@@ -370,30 +425,51 @@ impl BookmarksViewModel {
         };
         if let Some(entry) = self.entries.get(idx) {
             self.edit_active = true;
+            self.edit_tags = false;
             self.edit_input = entry.note.trim().to_owned();
         }
     }
 
+    /// Open the edit prompt on the selected entry's tags.
+    pub fn activate_tag_edit(&mut self) {
+        let visible = self.visible_indices();
+        let Some(idx) = visible.get(self.selected).copied() else {
+            return;
+        };
+        if let Some(entry) = self.entries.get(idx) {
+            self.edit_active = true;
+            self.edit_tags = true;
+            self.edit_input = entry.tags.join(" ");
+        }
+    }
+
     pub fn cancel_edit(&mut self) {
         self.edit_active = false;
+        self.edit_tags = false;
         self.edit_input.clear();
     }
 
-    /// Save the current edit input to the selected entry's note.
+    /// Save the current edit input to the selected entry's note (or tags).
     /// Returns `true` if save succeeded (caller may persist to disk).
     pub fn save_edit(&mut self) -> bool {
         let visible = self.visible_indices();
         let Some(idx) = visible.get(self.selected).copied() else {
             self.edit_active = false;
+            self.edit_tags = false;
             return false;
         };
-        let note = self.edit_input.trim().to_owned();
         if let Some(entry) = self.entries.get_mut(idx) {
-            entry.note = note;
-            self.status_line = "note saved".to_owned();
+            if self.edit_tags {
+                entry.tags = parse_bookmark_tags(&self.edit_input);
+                self.status_line = "tags saved".to_owned();
+            } else {
+                entry.note = self.edit_input.trim().to_owned();
+                self.status_line = "note saved".to_owned();
+            }
             self.status_err = false;
         }
         self.edit_active = false;
+        self.edit_tags = false;
         self.edit_input.clear();
         true
     }
@@ -493,6 +569,12 @@ pub enum BookmarksAction {
         topic: String,
         note: String,
     },
+    /// Tag edit was saved — app should persist the tags.
+    TagsSaved {
+        message_id: String,
+        topic: String,
+        tags: Vec<String>,
+    },
 }
 
 pub fn apply_bookmarks_input(view: &mut BookmarksViewModel, event: InputEvent) -> BookmarksAction {
@@ -533,6 +615,10 @@ pub fn apply_bookmarks_input(view: &mut BookmarksViewModel, event: InputEvent) -
                     view.activate_edit();
                     return BookmarksAction::None;
                 }
+                Key::Char('t') => {
+                    view.activate_tag_edit();
+                    return BookmarksAction::None;
+                }
                 Key::Char('d') => {
                     if let Some(entry) = view.selected_entry() {
                         let message_id = entry.message_id.clone();
@@ -587,12 +673,22 @@ fn apply_edit_input(view: &mut BookmarksViewModel, event: InputEvent) -> Bookmar
             let info = view
                 .selected_entry()
                 .map(|e| (e.message_id.clone(), e.topic.clone()));
+            let editing_tags = view.edit_tags;
             if view.save_edit() {
                 if let Some((message_id, topic)) = info {
-                    let note = view
-                        .selected_entry()
-                        .map(|e| e.note.clone())
-                        .unwrap_or_default();
+                    let saved = view
+                        .entries
+                        .iter()
+                        .find(|e| e.message_id == message_id && e.topic == topic);
+                    if editing_tags {
+                        let tags = saved.map(|e| e.tags.clone()).unwrap_or_default();
+                        return BookmarksAction::TagsSaved {
+                            message_id,
+                            topic,
+                            tags,
+                        };
+                    }
+                    let note = saved.map(|e| e.note.clone()).unwrap_or_default();
                     return BookmarksAction::NoteSaved {
                         message_id,
                         topic,
@@ -642,7 +738,7 @@ pub fn render_bookmarks_frame(
     }
 
     // Row 1: help text.
-    let help = "Enter:open  e:edit  d:delete  x:export  /:filter  s:sort  p:pin  Esc:back";
+    let help = "Enter:open  e:edit  t:tags  d:delete  x:export  /:filter  s:sort  p:pin  Esc:back";
     frame.draw_text(0, 1, &truncate(help, width), TextRole::Muted);
     if height == 2 {
         return frame;
@@ -687,14 +783,17 @@ pub fn render_bookmarks_frame(
     // Edit prompt.
     if view.edit_active {
         let edit_row = height.saturating_sub(reserved_bottom);
-        frame.draw_text(
-            0,
-            edit_row,
-            &truncate("edit note (Enter save, Esc cancel)", width),
-            TextRole::Accent,
-        );
+        let (label, prompt_label) = if view.edit_tags {
+            (
+                "edit tags (space separated, Enter save, Esc cancel)",
+                "tags",
+            )
+        } else {
+            ("edit note (Enter save, Esc cancel)", "note")
+        };
+        frame.draw_text(0, edit_row, &truncate(label, width), TextRole::Accent);
         if edit_row + 1 < height {
-            let prompt = format!("note> {}_", view.edit_input);
+            let prompt = format!("{prompt_label}> {}_", view.edit_input);
             frame.draw_text(
                 0,
                 edit_row + 1,
@@ -775,6 +874,11 @@ fn render_bookmark_list(
             entry.from.trim()
         };
         let pin_marker = if entry.pinned { " \u{2605}" } else { "" };
+        let tag_marker = entry
+            .tags
+            .iter()
+            .map(|t| format!(" #{t}"))
+            .collect::<String>();
         let ts = if entry.message_time > 0 {
             format_utc_hhmm(entry.message_time)
         } else {
@@ -782,7 +886,7 @@ fn render_bookmark_list(
         };
 
         let head = format!(
-            "{cursor}{title}{pin_marker} \u{2014} {from} in {topic} ({ts})",
+            "{cursor}{title}{pin_marker}{tag_marker} \u{2014} {from} in {topic} ({ts})",
             topic = entry.topic
         );
         frame.draw_text(0, row, &truncate(&head, width), TextRole::Primary);
@@ -878,6 +982,11 @@ pub fn render_bookmarks_markdown(entries: &[BookmarkEntry], export_time_label: &
             b.push_str(note);
             b.push('\n');
         }
+        if !entry.tags.is_empty() {
+            b.push_str("**Tags:** ");
+            b.push_str(&entry.tags.join(", "));
+            b.push('\n');
+        }
         b.push('\n');
         let body = entry.preview.trim();
         if body.is_empty() {
@@ -940,6 +1049,83 @@ mod tests {
         assert!(parsed.pinned_only);
     }
 
+    #[test]
+    fn parse_filter_tag() {
+        let parsed = parse_bookmarks_filter("tag:#Auth refresh");
+        assert_eq!(parsed.tag, "auth");
+        assert_eq!(parsed.text, "refresh");
+        assert_eq!(parsed.active_label(), "tag:auth text:refresh");
+    }
+
+    #[test]
+    fn parse_tags_dedupes_and_normalizes() {
+        assert_eq!(
+            parse_bookmark_tags("#Auth, api auth  ,"),
+            vec!["auth".to_owned(), "api".to_owned()]
+        );
+    }
+
+    #[test]
+    fn tag_filter_shows_only_tagged_and_clear_shows_all() {
+        let mut vm = BookmarksViewModel::new();
+        let mut a = make_entry("m1", "task", "alice", "token refresh");
+        a.tags = vec!["auth".to_owned()];
+        let mut b = make_entry("m2", "task", "bob", "deploy plan");
+        b.tags = vec!["ops".to_owned()];
+        let c = make_entry("m3", "task", "carol", "auth notes");
+        vm.set_entries(vec![a, b, c]);
+
+        vm.set_filter_from_input("tag:auth");
+        assert_eq!(vm.visible_indices().len(), 1);
+        assert_eq!(
+            vm.selected_entry().map(|e| e.message_id.as_str()),
+            Some("m1")
+        );
+
+        let frame = render_bookmarks_frame(&vm, 72, 10, ThemeSpec::default());
+        let snap = frame.snapshot();
+        assert!(snap.contains("filter: tag:auth"));
+        assert!(snap.contains("#auth"));
+        assert!(!snap.contains("deploy plan"));
+
+        vm.clear_filter();
+        assert_eq!(vm.visible_indices().len(), 3);
+    }
+
+    #[test]
+    fn free_text_matches_tags() {
+        let mut vm = BookmarksViewModel::new();
+        let mut a = make_entry("m1", "task", "alice", "one");
+        a.tags = vec!["release".to_owned()];
+        vm.set_entries(vec![a, make_entry("m2", "task", "bob", "two")]);
+        vm.set_filter_from_input("release");
+        assert_eq!(vm.visible_indices().len(), 1);
+    }
+
+    #[test]
+    fn tag_edit_saves_tags() {
+        let mut vm = BookmarksViewModel::new();
+        vm.add(make_entry("m1", "task", "arch", "one"));
+        apply_bookmarks_input(&mut vm, InputEvent::Key(KeyEvent::plain(Key::Char('t'))));
+        assert!(vm.edit_active());
+        for ch in "Auth api".chars() {
+            apply_bookmarks_input(&mut vm, InputEvent::Key(KeyEvent::plain(Key::Char(ch))));
+        }
+        let action = apply_bookmarks_input(&mut vm, InputEvent::Key(KeyEvent::plain(Key::Enter)));
+        assert_eq!(
+            vm.entries()[0].tags,
+            vec!["auth".to_owned(), "api".to_owned()]
+        );
+        assert_eq!(vm.entries()[0].note, "");
+        match action {
+            BookmarksAction::TagsSaved { tags, .. } => assert_eq!(tags.len(), 2),
+            other => panic!("expected TagsSaved, got {other:?}"),
+        }
+
+        let persisted = crate::state_help::Bookmark::from(&vm.entries()[0]);
+        assert_eq!(persisted.tags, vec!["auth".to_owned(), "api".to_owned()]);
+    }
+
     #[test]
     fn parse_filter_empty() {
         let parsed = parse_bookmarks_filter("");
@@ -518,6 +518,17 @@ impl ComposeViewModel {
         self.reset_compose_completion();
     }
 
+    /// Insert pasted text into the focused compose field without interpreting
+    /// it as keys. Single-line fields get line breaks folded into spaces.
+    pub fn compose_insert_paste(&mut self, text: &str) {
+        if self.compose.focus == ComposeField::Body {
+            self.compose.body.push_str(text);
+            self.reset_compose_completion();
+            return;
+        }
+        self.compose_insert_char(&fold_line_breaks(text));
+    }
+
     /// Tab-complete target in compose To field.
     pub fn complete_compose_target(&mut self, known_targets: &[String]) {
         let prefix = self.compose.to.trim().to_owned();
@@ -639,6 +650,12 @@ fn handle_compose_overlay_key(
 ) -> ComposeAction {
     let key_event = match event {
         InputEvent::Key(k) => k,
+        InputEvent::Paste(text) => {
+            if !vm.compose.restore_ask && !vm.compose.save_prompt && !vm.compose.sending {
+                vm.compose_insert_paste(&text);
+            }
+            return ComposeAction::None;
+        }
         _ => return ComposeAction::None,
     };
 
@@ -768,6 +785,14 @@ fn handle_quick_send_key(
 ) -> ComposeAction {
     let key_event = match event {
         InputEvent::Key(k) => k,
+        InputEvent::Paste(text) => {
+            if !vm.quick.sending {
+                vm.quick.input.push_str(&fold_line_breaks(&text));
+                vm.quick.err.clear();
+                vm.reset_quick_completion();
+            }
+            return ComposeAction::None;
+        }
         _ => return ComposeAction::None,
     };
 
@@ -1123,6 +1148,11 @@ fn wrap_lines(s: &str, max_width: usize) -> Vec<String> {
     out
 }
 
+/// Fold pasted line breaks into spaces for single-line inputs.
+fn fold_line_breaks(text: &str) -> String {
+    text.replace("\r\n", " ").replace(['\r', '\n'], " ")
+}
+
 /// Get the first non-empty line from a multiline string.
 #[must_use]
 pub fn first_non_empty_line(text: &str) -> String {
@@ -1725,6 +1755,28 @@ mod tests {
         assert_eq!(vm.compose.body, "line1\n");
     }
 
+    #[test]
+    fn compose_paste_inserts_body_literally() {
+        let mut vm = ComposeViewModel::new("me");
+        vm.open_compose("task", &ComposeReplySeed::default(), None);
+        vm.compose.focus = ComposeField::Body;
+        let pasted = "fn main() {\n\tq\n}".to_owned();
+        let action = apply_compose_input(&mut vm, InputEvent::Paste(pasted.clone()), &[], &[]);
+        assert_eq!(action, ComposeAction::None);
+        assert_eq!(vm.compose.body, pasted);
+        assert_eq!(vm.compose.focus, ComposeField::Body);
+        assert!(!vm.compose.save_prompt);
+    }
+
+    #[test]
+    fn compose_paste_into_single_line_field_folds_line_breaks() {
+        let mut vm = ComposeViewModel::new("me");
+        vm.open_compose("", &ComposeReplySeed::default(), None);
+        vm.compose.focus = ComposeField::To;
+        let _ = apply_compose_input(&mut vm, InputEvent::Paste("a\r\nb".into()), &[], &[]);
+        assert_eq!(vm.compose.to, "a b");
+    }
+
     #[test]
     fn compose_enter_on_non_body_advances_field() {
         let mut vm = ComposeViewModel::new("me");
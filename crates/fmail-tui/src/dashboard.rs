@@ -55,6 +55,8 @@ impl DashboardFocus {
 pub struct AgentEntry {
     pub name: String,
     pub status: String,
+    /// Structured presence (`busy`, `available`, `away`, `offline`); empty when unset.
+    pub state: String,
     /// Seconds since epoch of last seen timestamp.
     pub last_seen_secs: i64,
 }
@@ -65,6 +67,7 @@ impl AgentEntry {
         Self {
             name: name.to_owned(),
             status: String::new(),
+            state: String::new(),
             last_seen_secs: 0,
         }
     }
@@ -310,6 +313,16 @@ fn presence_indicator(now_secs: i64, last_seen_secs: i64) -> &'static str {
     }
 }
 
+/// Colour for an agent line, keyed by its structured presence state.
+fn presence_role(state: &str) -> TextRole {
+    match state.trim() {
+        "busy" => TextRole::Warning,
+        "available" => TextRole::Success,
+        "away" | "offline" => TextRole::Muted,
+        _ => TextRole::Primary,
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Heat bar
 // ---------------------------------------------------------------------------
@@ -474,10 +487,18 @@ fn render_left_panel(
             "  "
         };
         let mut line = format!("{prefix}{presence} {}", agent.name);
+        if !agent.state.trim().is_empty() {
+            line = format!("{line} [{}]", agent.state.trim());
+        }
         if !agent.status.trim().is_empty() {
             line = format!("{line} {:?}", agent.status.trim());
         }
-        frame.draw_text(x_off, y, &truncate(&line, width), TextRole::Primary);
+        frame.draw_text(
+            x_off,
+            y,
+            &truncate(&line, width),
+            presence_role(&agent.state),
+        );
         y += 1;
     }
 
@@ -1038,6 +1059,25 @@ mod tests {
 
     // -- Presence indicator --------------------------------------------------
 
+    #[test]
+    fn agent_state_is_labelled_and_coloured() {
+        let mut vm = DashboardViewModel::new();
+        vm.now_secs = 1050;
+        let mut agents = sample_agents();
+        agents[0].state = "busy".to_owned();
+        vm.set_agents(agents);
+
+        let frame = render_dashboard_frame(&vm, 100, 20, ThemeSpec::default());
+        let all_text: String = (0..20)
+            .map(|r| frame.row_text(r))
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert!(all_text.contains("alice [busy] \"building\""), "{all_text}");
+        assert_eq!(presence_role("busy"), TextRole::Warning);
+        assert_eq!(presence_role("available"), TextRole::Success);
+        assert_eq!(presence_role(""), TextRole::Primary);
+    }
+
     #[test]
     fn presence_thresholds() {
         assert_eq!(presence_indicator(100, 80), "\u{25cf}"); // online (20s)
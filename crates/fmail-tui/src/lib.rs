@@ -25,7 +25,7 @@ pub mod topics;
 
 pub use app::{App, Command, LayoutMode, PlaceholderView, View, ViewId};
 pub use bookmarks::{
-    apply_bookmarks_input, parse_bookmarks_filter, render_bookmarks_frame,
+    apply_bookmarks_input, parse_bookmark_tags, parse_bookmarks_filter, render_bookmarks_frame,
     render_bookmarks_markdown, BookmarkEntry, BookmarkSort, BookmarksAction, BookmarksFilter,
     BookmarksViewModel,
 };
@@ -64,8 +64,8 @@ pub use state_help::{
     default_keymap, render_help_frame, Bookmark, KeyBinding, PersistedState, UiPreferences,
 };
 pub use stats::{
-    apply_stats_input, compute_stats, render_stats_frame, StatsBucket, StatsMessage, StatsSnapshot,
-    StatsViewModel,
+    apply_stats_input, compute_grouped_stats, compute_stats, render_stats_frame, StatsBucket,
+    StatsGroup, StatsGrouping, StatsMessage, StatsSnapshot, StatsViewModel,
 };
 pub use thread::{
     apply_thread_input, render_thread_frame, ThreadMode, ThreadRow, ThreadViewModel, TopicInfo,
@@ -79,8 +79,8 @@ pub use timeline::{
     TimelineMessage, TimelineMode, TimelineViewModel,
 };
 pub use topics::{
-    apply_topics_input, render_topics_frame, PreviewMessage, TopicSortKey, TopicsItem, TopicsMode,
-    TopicsViewModel,
+    apply_topics_input, render_topics_frame, PreviewMessage, TopicOp, TopicOpKind, TopicSortKey,
+    TopicStore, TopicsItem, TopicsMode, TopicsViewModel,
 };
 
 /// Stable crate label used by bootstrap smoke tests.
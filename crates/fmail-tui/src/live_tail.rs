@@ -410,19 +410,7 @@ fn trim_tail(list: &mut Vec<LiveTailMessage>) {
 }
 
 fn truncate(input: &str, max_chars: usize) -> String {
-    if max_chars == 0 {
-        return String::new();
-    }
-    let chars = input.chars().collect::<Vec<_>>();
-    if chars.len() <= max_chars {
-        return input.to_owned();
-    }
-    if max_chars == 1 {
-        return "…".to_owned();
-    }
-    let mut out = chars.into_iter().take(max_chars - 1).collect::<String>();
-    out.push('…');
-    out
+    fmail_core::format::truncate_chars(input, max_chars)
 }
 
 fn eq_ci(lhs: &str, rhs: &str) -> bool {
@@ -54,9 +54,10 @@ pub enum ReplayAction {
     ExportRequested { markdown: String },
 }
 
-const REPLAY_SPEED_PRESETS: [f64; 4] = [1.0, 5.0, 10.0, 50.0];
+const REPLAY_SPEED_PRESETS: [f64; 4] = [0.5, 1.0, 2.0, 4.0];
+const REPLAY_DEFAULT_SPEED_IDX: usize = 1;
 
-#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ReplayViewModel {
     loading: bool,
     last_err: Option<String>,
@@ -69,6 +70,10 @@ pub struct ReplayViewModel {
     idx: usize,
     playing: bool,
     speed_idx: usize,
+    /// Step mode: ticks are ignored and each step key advances one entry.
+    step_mode: bool,
+    /// Wrap to the first entry at the end instead of stopping.
+    looping: bool,
     highlight_ticks: u8,
     mode: ReplayMode,
     status_line: String,
@@ -78,6 +83,30 @@ pub struct ReplayViewModel {
     marks: BTreeMap<char, usize>,
 }
 
+impl Default for ReplayViewModel {
+    fn default() -> Self {
+        Self {
+            loading: false,
+            last_err: None,
+            entries: Vec::new(),
+            times: Vec::new(),
+            start_secs: 0,
+            end_secs: 0,
+            idx: 0,
+            playing: false,
+            speed_idx: REPLAY_DEFAULT_SPEED_IDX,
+            step_mode: false,
+            looping: false,
+            highlight_ticks: 0,
+            mode: ReplayMode::default(),
+            status_line: String::new(),
+            pending_mark: false,
+            pending_jump: false,
+            marks: BTreeMap::new(),
+        }
+    }
+}
+
 impl ReplayViewModel {
     #[must_use]
     pub fn new() -> Self {
@@ -150,12 +179,52 @@ impl ReplayViewModel {
             return;
         }
         self.playing = !self.playing;
+        if self.playing {
+            self.step_mode = false;
+        }
     }
 
     pub fn set_speed_idx(&mut self, idx: usize) {
         self.speed_idx = idx.min(REPLAY_SPEED_PRESETS.len().saturating_sub(1));
     }
 
+    /// Move one speed preset faster (`delta > 0`) or slower, clamped.
+    pub fn shift_speed(&mut self, delta: isize) {
+        let idx = clamp_isize(
+            self.speed_idx as isize + delta,
+            0,
+            REPLAY_SPEED_PRESETS.len() as isize - 1,
+        );
+        self.set_speed_idx(idx as usize);
+    }
+
+    #[must_use]
+    pub fn speed(&self) -> f64 {
+        REPLAY_SPEED_PRESETS[self.speed_idx.min(REPLAY_SPEED_PRESETS.len() - 1)]
+    }
+
+    /// Toggle step mode. Entering it pauses playback.
+    pub fn toggle_step_mode(&mut self) {
+        self.step_mode = !self.step_mode;
+        if self.step_mode {
+            self.playing = false;
+        }
+    }
+
+    #[must_use]
+    pub fn step_mode(&self) -> bool {
+        self.step_mode
+    }
+
+    pub fn toggle_looping(&mut self) {
+        self.looping = !self.looping;
+    }
+
+    #[must_use]
+    pub fn looping(&self) -> bool {
+        self.looping
+    }
+
     pub fn step(&mut self, delta: isize) {
         if self.entries.is_empty() {
             self.idx = 0;
@@ -190,12 +259,19 @@ impl ReplayViewModel {
         self.highlight_ticks = 0;
     }
 
+    /// Advance playback by exactly one entry, whatever the speed; speed
+    /// only changes the delay between ticks. Stops at the end unless looping.
     pub fn handle_tick(&mut self) {
-        if !self.playing || self.entries.is_empty() {
+        if !self.playing || self.step_mode || self.entries.is_empty() {
             return;
         }
         if self.idx >= self.entries.len().saturating_sub(1) {
-            self.playing = false;
+            if self.looping {
+                self.idx = 0;
+                self.highlight_ticks = 1;
+            } else {
+                self.playing = false;
+            }
             return;
         }
         self.idx += 1;
@@ -209,8 +285,7 @@ impl ReplayViewModel {
         }
         let curr = self.times.get(self.idx).copied().unwrap_or(0);
         let next = self.times.get(self.idx + 1).copied().unwrap_or(0);
-        let speed = REPLAY_SPEED_PRESETS[self.speed_idx.min(REPLAY_SPEED_PRESETS.len() - 1)];
-        replay_next_interval_ms(curr, next, speed)
+        replay_next_interval_ms(curr, next, self.speed())
     }
 
     #[must_use]
@@ -305,10 +380,30 @@ pub fn apply_replay_input(vm: &mut ReplayViewModel, event: InputEvent) -> Replay
                 vm.playing = false;
                 return ReplayAction::PopView;
             }
+            Key::Char(' ') | Key::Char('.') if vm.step_mode => {
+                vm.step(1);
+                return ReplayAction::None;
+            }
             Key::Char(' ') => {
                 vm.toggle_playing();
                 return ReplayAction::None;
             }
+            Key::Char('s') => {
+                vm.toggle_step_mode();
+                return ReplayAction::None;
+            }
+            Key::Char('L') => {
+                vm.toggle_looping();
+                return ReplayAction::None;
+            }
+            Key::Char('+') | Key::Char('=') => {
+                vm.shift_speed(1);
+                return ReplayAction::None;
+            }
+            Key::Char('-') => {
+                vm.shift_speed(-1);
+                return ReplayAction::None;
+            }
             Key::Char('t') => {
                 vm.toggle_mode();
                 return ReplayAction::None;
@@ -473,20 +568,28 @@ pub fn render_replay_frame(
     }
 
     let cursor_t = vm.cursor_time_secs();
-    let speed = REPLAY_SPEED_PRESETS[vm.speed_idx.min(REPLAY_SPEED_PRESETS.len() - 1)];
-    let play_glyph = if vm.playing { "▶" } else { "▌▌" };
+    let play_glyph = if vm.step_mode {
+        "STEP"
+    } else if vm.playing {
+        "▶"
+    } else {
+        "▌▌"
+    };
     let mode = match vm.mode {
         ReplayMode::Feed => "feed",
         ReplayMode::Timeline => "timeline",
     };
-    let header = format!(
-        "REPLAY  {}  {:.0}x  {} / {}  mode:{}",
+    let mut header = format!(
+        "REPLAY  {}  {}x  {} / {}  mode:{}",
         play_glyph,
-        speed,
+        vm.speed(),
         format_hhmmss(cursor_t),
         format_hhmmss(vm.end_secs),
         mode
     );
+    if vm.looping {
+        header.push_str("  loop");
+    }
     frame.draw_text(0, 0, &truncate_vis(&header, width), TextRole::Accent);
     if height == 1 {
         return frame;
@@ -552,7 +655,7 @@ pub fn render_replay_frame(
             0,
             controls_y,
             &truncate_vis(
-                "Space:play/pause  \u{2190}/\u{2192}:step  Shift+\u{2190}/\u{2192}:\u{00b1}1m  1-4:speed  t:mode  m/':marks  e:export  Esc:back  (R: replay)",
+                "Space:play/pause  \u{2190}/\u{2192}:step  Shift+\u{2190}/\u{2192}:\u{00b1}1m  1-4:speed  t:mode  m/':marks  e:export  Esc:back  (R: replay)  s:step mode  .:next  +/-:speed  L:loop",
                 width,
             ),
             TextRole::Muted,
@@ -1157,4 +1260,81 @@ mod tests {
         );
         assert_eq!(vm.cursor(), 2);
     }
+
+    fn key(c: char) -> InputEvent {
+        InputEvent::Key(KeyEvent::plain(Key::Char(c)))
+    }
+
+    fn four_entries() -> Vec<ReplayEntry> {
+        vec![
+            ReplayEntry::new("20260210-055800-0000", "a", "topic", "one"),
+            ReplayEntry::new("20260210-055801-0000", "b", "topic", "two"),
+            ReplayEntry::new("20260210-055802-0000", "c", "topic", "three"),
+            ReplayEntry::new("20260210-055803-0000", "d", "topic", "four"),
+        ]
+    }
+
+    #[test]
+    fn step_mode_advances_one_entry_per_key() {
+        let mut vm = ReplayViewModel::new();
+        vm.set_entries(four_entries());
+        vm.toggle_playing();
+
+        let _ = apply_replay_input(&mut vm, key('s'));
+        assert!(vm.step_mode());
+        assert!(!vm.playing());
+
+        // Ticks are ignored while stepping.
+        vm.handle_tick();
+        assert_eq!(vm.cursor(), 0);
+
+        let _ = apply_replay_input(&mut vm, key('.'));
+        assert_eq!(vm.cursor(), 1);
+        let _ = apply_replay_input(&mut vm, key(' '));
+        assert_eq!(vm.cursor(), 2);
+        let _ = apply_replay_input(&mut vm, key('.'));
+        assert_eq!(vm.cursor(), 3);
+        let _ = apply_replay_input(&mut vm, key('.'));
+        assert_eq!(vm.cursor(), 3);
+    }
+
+    #[test]
+    fn speed_changes_do_not_skip_entries() {
+        let mut vm = ReplayViewModel::new();
+        vm.set_entries(four_entries());
+        assert_eq!(vm.speed(), 1.0);
+        vm.toggle_playing();
+
+        let mut visited = vec![vm.cursor()];
+        for c in ['+', '+', '-'] {
+            let _ = apply_replay_input(&mut vm, key(c));
+            vm.handle_tick();
+            visited.push(vm.cursor());
+        }
+        assert_eq!(visited, vec![0, 1, 2, 3]);
+        assert_eq!(vm.speed(), 2.0);
+
+        let _ = apply_replay_input(&mut vm, key('-'));
+        let _ = apply_replay_input(&mut vm, key('-'));
+        let _ = apply_replay_input(&mut vm, key('-'));
+        assert_eq!(vm.speed(), 0.5);
+    }
+
+    #[test]
+    fn playback_stops_at_end_unless_looping() {
+        let mut vm = ReplayViewModel::new();
+        vm.set_entries(four_entries());
+        vm.set_index(3);
+        vm.toggle_playing();
+        vm.handle_tick();
+        assert!(!vm.playing());
+        assert_eq!(vm.cursor(), 3);
+
+        let _ = apply_replay_input(&mut vm, key('L'));
+        assert!(vm.looping());
+        vm.toggle_playing();
+        vm.handle_tick();
+        assert!(vm.playing());
+        assert_eq!(vm.cursor(), 0);
+    }
 }
@@ -11,6 +11,8 @@ pub struct Bookmark {
     pub message_id: String,
     pub target: String,
     pub note: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -190,6 +192,7 @@ mod tests {
             message_id: "20260209-120100-0001".to_owned(),
             target: "task".to_owned(),
             note: "follow up".to_owned(),
+            tags: vec!["auth".to_owned()],
         });
         state.highlight_patterns = vec!["panic".to_owned(), "error".to_owned()];
 
@@ -200,6 +203,19 @@ mod tests {
         assert_eq!(reparsed.unwrap_or_default(), state);
     }
 
+    #[test]
+    fn bookmark_without_tags_parses() {
+        let raw = r#"{"message_id":"m1","target":"task","note":""}"#;
+        let bookmark: Bookmark = serde_json::from_str(raw).unwrap_or_else(|_| Bookmark {
+            message_id: String::new(),
+            target: String::new(),
+            note: String::new(),
+            tags: vec!["unparsed".to_owned()],
+        });
+        assert_eq!(bookmark.message_id, "m1");
+        assert!(bookmark.tags.is_empty());
+    }
+
     #[test]
     fn save_and_load_state_file() {
         let unique = format!(
@@ -50,6 +50,49 @@ impl StatsMessage {
     }
 }
 
+/// Dimension used to slice stats into a ranked breakdown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StatsGrouping {
+    /// Aggregate view across all messages.
+    #[default]
+    Overall,
+    /// One group per sender.
+    Agent,
+    /// One group per destination topic or DM target.
+    Topic,
+}
+
+impl StatsGrouping {
+    #[must_use]
+    pub fn next(self) -> Self {
+        match self {
+            Self::Overall => Self::Agent,
+            Self::Agent => Self::Topic,
+            Self::Topic => Self::Overall,
+        }
+    }
+
+    #[must_use]
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Overall => "all",
+            Self::Agent => "agent",
+            Self::Topic => "topic",
+        }
+    }
+}
+
+/// Stats for one agent or topic in a grouped breakdown.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatsGroup {
+    pub label: String,
+    pub count: usize,
+    pub reply_samples: usize,
+    pub avg_reply_secs: f64,
+    /// Reply latency distribution for replies in this group.
+    pub response_latency: Vec<StatsBucket>,
+}
+
 /// Complete stats computation result (Go: statsSnapshot).
 #[derive(Debug, Clone, PartialEq)]
 pub struct StatsSnapshot {
@@ -150,6 +193,10 @@ pub struct StatsViewModel {
     /// Computed stats.
     snap: StatsSnapshot,
 
+    /// Active breakdown dimension and its ranked groups.
+    grouping: StatsGrouping,
+    groups: Vec<StatsGroup>,
+
     /// Loading / error state.
     loading: bool,
     error: Option<String>,
@@ -172,6 +219,8 @@ impl StatsViewModel {
             loaded_end_secs: 0,
             messages: Vec::new(),
             snap: StatsSnapshot::default(),
+            grouping: StatsGrouping::default(),
+            groups: Vec::new(),
             loading: false,
             error: None,
         }
@@ -252,6 +301,24 @@ impl StatsViewModel {
         }
     }
 
+    /// Current breakdown dimension.
+    #[must_use]
+    pub fn grouping(&self) -> StatsGrouping {
+        self.grouping
+    }
+
+    /// Ranked groups for the current dimension (empty when `Overall`).
+    #[must_use]
+    pub fn groups(&self) -> &[StatsGroup] {
+        &self.groups
+    }
+
+    /// Cycle overall -> agent -> topic. The time window is left unchanged.
+    pub fn cycle_grouping(&mut self) {
+        self.grouping = self.grouping.next();
+        self.recompute_groups();
+    }
+
     /// Request refresh (caller should reload data).
     pub fn request_refresh(&mut self) {
         self.loading = true;
@@ -285,6 +352,7 @@ impl StatsViewModel {
         }
 
         self.snap = compute_stats(&self.messages, self.loaded_start_secs, self.loaded_end_secs);
+        self.recompute_groups();
     }
 
     /// Add a single incoming message (real-time update).
@@ -314,6 +382,7 @@ impl StatsViewModel {
         }
 
         self.snap = compute_stats(&self.messages, self.loaded_start_secs, self.loaded_end_secs);
+        self.recompute_groups();
     }
 
     /// Access the computed snapshot.
@@ -357,6 +426,15 @@ impl StatsViewModel {
 
     // -- internal helpers --
 
+    fn recompute_groups(&mut self) {
+        self.groups = compute_grouped_stats(
+            &self.messages,
+            self.loaded_start_secs,
+            self.loaded_end_secs,
+            self.grouping,
+        );
+    }
+
     fn effective_end(&self) -> i64 {
         if self.window_end_secs > 0 {
             self.window_end_secs
@@ -411,6 +489,7 @@ pub fn apply_stats_input(view: &mut StatsViewModel, event: InputEvent) {
                 forge_ftui_adapter::input::Key::Char('[') => view.prev_window(),
                 forge_ftui_adapter::input::Key::Char(']') => view.next_window(),
                 forge_ftui_adapter::input::Key::Char('r') => view.request_refresh(),
+                forge_ftui_adapter::input::Key::Char('g') => view.cycle_grouping(),
                 _ => {}
             }
         }
@@ -434,7 +513,11 @@ pub fn render_stats_frame(
     }
 
     let range_label = view.range_label();
-    let header = truncate(&format!("STATS  {range_label}"), width);
+    let header = match view.grouping {
+        StatsGrouping::Overall => format!("STATS  {range_label}"),
+        grouping => format!("STATS  {range_label}  by:{}", grouping.label()),
+    };
+    let header = truncate(&header, width);
     frame.draw_text(0, 0, &header, TextRole::Accent);
 
     if height == 1 {
@@ -453,6 +536,26 @@ pub fn render_stats_frame(
     }
 
     let s = &view.snap;
+    let footer_row = height.saturating_sub(1);
+    let footer = truncate(
+        "[/]: range  \u{2190}/\u{2192}: pan  r: refresh  g: group",
+        width,
+    );
+
+    if view.grouping != StatsGrouping::Overall {
+        let start_row = 2.min(height);
+        let lines = render_groups(view, width);
+        for (i, line) in lines.iter().enumerate() {
+            if start_row + i >= footer_row {
+                break;
+            }
+            frame.draw_text(0, start_row + i, line, TextRole::Primary);
+        }
+        if footer_row > start_row {
+            frame.draw_text(0, footer_row, &footer, TextRole::Muted);
+        }
+        return frame;
+    }
 
     // Determine layout: two columns or single.
     let inner_w = width;
@@ -504,15 +607,50 @@ pub fn render_stats_frame(
     }
 
     // Footer.
-    let footer_row = height.saturating_sub(1);
     if footer_row > start_row {
-        let footer = truncate("[/]: range  \u{2190}/\u{2192}: pan  r: refresh", width);
         frame.draw_text(0, footer_row, &footer, TextRole::Muted);
     }
 
     frame
 }
 
+fn render_groups(view: &StatsViewModel, width: usize) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::with_capacity(view.groups.len() + 1);
+    let title = match view.grouping {
+        StatsGrouping::Agent => "BY AGENT (msgs sent)",
+        _ => "BY TOPIC (msgs received)",
+    };
+    lines.push(title.to_owned());
+    if view.groups.is_empty() {
+        lines.push("No data".to_owned());
+        return lines;
+    }
+    let max_c = view.groups.iter().map(|g| g.count).max().unwrap_or(0);
+    let bar_w = width.saturating_sub(40).min(24);
+    for (i, g) in view.groups.iter().enumerate() {
+        let bar = render_bar(g.count, max_c, bar_w);
+        let reply = if g.reply_samples > 0 {
+            format!(
+                "replies:{} avg:{}",
+                g.reply_samples,
+                format_duration_compact(g.avg_reply_secs as i64)
+            )
+        } else {
+            "replies:0".to_owned()
+        };
+        let line = format!(
+            "{:2}. {:<12} {:4} {} {}",
+            i + 1,
+            truncate(&g.label, 12),
+            g.count,
+            bar,
+            reply
+        );
+        lines.push(truncate(&line, width));
+    }
+    lines
+}
+
 fn render_left(s: &StatsSnapshot, messages: &[StatsMessage], width: usize) -> Vec<String> {
     let mut lines: Vec<String> = Vec::with_capacity(32);
     if width == 0 {
@@ -820,6 +958,70 @@ pub fn compute_stats(
     out
 }
 
+/// Compute a ranked per-agent or per-topic breakdown within a time window.
+///
+/// Replies count toward the replier's agent (or the reply's topic); latency
+/// is measured against the parent anywhere in the window. Groups are ordered
+/// by message count, then label. `Overall` yields no groups.
+#[must_use]
+pub fn compute_grouped_stats(
+    messages: &[StatsMessage],
+    window_start_secs: i64,
+    window_end_secs: i64,
+    grouping: StatsGrouping,
+) -> Vec<StatsGroup> {
+    if grouping == StatsGrouping::Overall {
+        return Vec::new();
+    }
+    let filtered = filter_messages_by_time(messages, window_start_secs, window_end_secs);
+    let by_id: HashMap<&str, &StatsMessage> = filtered
+        .iter()
+        .filter(|msg| !msg.id.trim().is_empty())
+        .map(|&msg| (msg.id.trim(), msg))
+        .collect();
+
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    let mut deltas: HashMap<&str, Vec<i64>> = HashMap::new();
+    for msg in &filtered {
+        let key = match grouping {
+            StatsGrouping::Agent => msg.from.trim(),
+            _ => msg.to.trim(),
+        };
+        if key.is_empty() {
+            continue;
+        }
+        *counts.entry(key).or_insert(0) += 1;
+        let Some(parent) = by_id.get(msg.reply_to.trim()) else {
+            continue;
+        };
+        if parent.time_secs > 0 && msg.time_secs >= parent.time_secs {
+            deltas
+                .entry(key)
+                .or_default()
+                .push(msg.time_secs - parent.time_secs);
+        }
+    }
+
+    top_n(&counts, counts.len())
+        .into_iter()
+        .map(|bar| {
+            let group_deltas = deltas.get(bar.label.as_str()).cloned().unwrap_or_default();
+            let avg_reply_secs = if group_deltas.is_empty() {
+                0.0
+            } else {
+                group_deltas.iter().sum::<i64>() as f64 / group_deltas.len() as f64
+            };
+            StatsGroup {
+                label: bar.label,
+                count: bar.count,
+                reply_samples: group_deltas.len(),
+                avg_reply_secs,
+                response_latency: latency_buckets(&group_deltas),
+            }
+        })
+        .collect()
+}
+
 fn filter_messages_by_time(
     messages: &[StatsMessage],
     start_secs: i64,
@@ -1513,6 +1715,69 @@ mod tests {
         assert_eq!(vm.window_idx(), 2); // back to 24h
     }
 
+    #[test]
+    fn grouping_by_agent_yields_one_group_per_sender() {
+        let groups =
+            compute_grouped_stats(&sample_messages(), T0, T0 + 2 * 3600, StatsGrouping::Agent);
+        let labels: Vec<&str> = groups.iter().map(|g| g.label.as_str()).collect();
+        assert_eq!(labels, vec!["architect", "coder", "reviewer", "tester"]);
+        assert_eq!(groups[0].count, 3);
+        // architect replied to tester after 40s.
+        assert_eq!(groups[0].reply_samples, 1);
+        assert_eq!(groups[0].response_latency[1].count, 1);
+        assert_eq!(groups[1].reply_samples, 1);
+        assert_eq!(groups[3].reply_samples, 0);
+    }
+
+    #[test]
+    fn grouping_by_topic_ranks_destinations() {
+        let groups =
+            compute_grouped_stats(&sample_messages(), T0, T0 + 2 * 3600, StatsGrouping::Topic);
+        assert_eq!(groups.len(), 4);
+        assert_eq!(groups[0].label, "task");
+        assert_eq!(groups[0].count, 3);
+        assert!(compute_grouped_stats(&sample_messages(), 0, 0, StatsGrouping::Overall).is_empty());
+    }
+
+    #[test]
+    fn cycling_grouping_preserves_window() {
+        let mut vm = StatsViewModel::new();
+        vm.set_now(T0 + 2 * 3600);
+        vm.load_messages(sample_messages(), T0, T0 + 2 * 3600, T0 + 2 * 3600);
+        apply_stats_input(&mut vm, InputEvent::Key(KeyEvent::plain(Key::Char('['))));
+        apply_stats_input(&mut vm, InputEvent::Key(KeyEvent::plain(Key::Left)));
+        let bounds = vm.window_bounds();
+        let window_idx = vm.window_idx();
+
+        apply_stats_input(&mut vm, InputEvent::Key(KeyEvent::plain(Key::Char('g'))));
+        assert_eq!(vm.grouping(), StatsGrouping::Agent);
+        assert_eq!(vm.groups().len(), 4);
+        assert_eq!(vm.window_idx(), window_idx);
+        assert_eq!(vm.window_bounds(), bounds);
+
+        apply_stats_input(&mut vm, InputEvent::Key(KeyEvent::plain(Key::Char('g'))));
+        assert_eq!(vm.grouping(), StatsGrouping::Topic);
+        apply_stats_input(&mut vm, InputEvent::Key(KeyEvent::plain(Key::Char('g'))));
+        assert_eq!(vm.grouping(), StatsGrouping::Overall);
+        assert!(vm.groups().is_empty());
+        assert_eq!(vm.window_bounds(), bounds);
+    }
+
+    #[test]
+    fn render_grouped_breakdown() {
+        let mut vm = StatsViewModel::new();
+        vm.set_now(T0 + 2 * 3600);
+        vm.load_messages(sample_messages(), T0, T0 + 2 * 3600, T0 + 2 * 3600);
+        vm.cycle_grouping();
+
+        let frame = render_stats_frame(&vm, 72, 10, ThemeSpec::default());
+        let rendered = frame_to_string(&frame, 72, 10);
+        assert!(rendered.contains("STATS  last 1d  by:agent"));
+        assert!(rendered.contains("BY AGENT"));
+        assert!(rendered.contains(" 1. architect"));
+        assert!(rendered.contains("g: group"));
+    }
+
     #[test]
     fn input_refresh() {
         let mut vm = StatsViewModel::new();
@@ -48,6 +48,8 @@ pub struct ThreadRow {
     pub cross_target: String,
     pub truncated: bool,
     pub hidden_lines: usize,
+    /// Descendants hidden because this node is collapsed (0 when expanded).
+    pub hidden_replies: usize,
 }
 
 // ---------------------------------------------------------------------------
@@ -402,6 +404,42 @@ impl ThreadViewModel {
         }
     }
 
+    /// Fold or unfold the selected node's subtree. On a leaf, fold its
+    /// parent instead and move the selection up to the folded parent.
+    pub fn toggle_collapse(&mut self) {
+        if self.mode != ThreadMode::Threaded {
+            return;
+        }
+        let Some(row) = self.rows.get(self.selected) else {
+            return;
+        };
+        let id = row.msg.id.trim().to_owned();
+        let target = if row.has_children {
+            id
+        } else {
+            row.reply_to.clone()
+        };
+        if target.is_empty() {
+            return;
+        }
+        if !self.collapsed.remove(&target) {
+            self.collapsed.insert(target.clone());
+        }
+        self.rebuild_rows(&target, false);
+        self.ensure_visible();
+    }
+
+    /// Unfold every collapsed node, keeping the current selection.
+    pub fn expand_all(&mut self) {
+        if self.collapsed.is_empty() {
+            return;
+        }
+        self.collapsed.clear();
+        let anchor = self.selected_id().to_owned();
+        self.rebuild_rows(&anchor, false);
+        self.ensure_visible();
+    }
+
     // -- topic switching -----------------------------------------------------
 
     pub fn switch_topic(&mut self, delta: i32) {
@@ -538,6 +576,7 @@ impl ThreadViewModel {
                     cross_target: String::new(),
                     truncated,
                     hidden_lines: hidden,
+                    hidden_replies: 0,
                 });
             }
         } else {
@@ -574,6 +613,11 @@ impl ThreadViewModel {
                     };
 
                     let (truncated, hidden) = self.body_truncation(&node.message);
+                    let hidden_replies = if self.collapsed.contains(node.message.id.trim()) {
+                        count_descendants(node, &node_map)
+                    } else {
+                        0
+                    };
 
                     rows.push(ThreadRow {
                         msg: node.message.clone(),
@@ -586,6 +630,7 @@ impl ThreadViewModel {
                         cross_target,
                         truncated,
                         hidden_lines: hidden,
+                        hidden_replies,
                     });
                 }
             }
@@ -612,13 +657,27 @@ impl ThreadViewModel {
                 .saturating_sub(self.viewport_rows.saturating_sub(1));
             return;
         }
-        if let Some(&idx) = self.row_index_by_id.get(anchor_id) {
+        if let Some(idx) = self.visible_row_for(anchor_id) {
             self.selected = idx;
         } else {
             self.selected = self.selected.min(self.rows.len() - 1);
         }
     }
 
+    /// Row index of `id`, or of its nearest visible ancestor when `id` is
+    /// folded away under a collapsed node.
+    fn visible_row_for(&self, id: &str) -> Option<usize> {
+        let mut cur = id.trim();
+        let mut seen = HashSet::new();
+        while !cur.is_empty() && seen.insert(cur) {
+            if let Some(&idx) = self.row_index_by_id.get(cur) {
+                return Some(idx);
+            }
+            cur = self.msg_by_id.get(cur).map_or("", |m| m.reply_to.trim());
+        }
+        None
+    }
+
     fn hidden_by_collapsed_ancestor(
         &self,
         node: &ThreadNode,
@@ -676,6 +735,24 @@ pub fn apply_thread_input(view: &mut ThreadViewModel, event: InputEvent) {
             view.handle_enter();
             return;
         }
+        // Fold/unfold subtree.
+        InputEvent::Key(KeyEvent {
+            key: Key::Char('z'),
+            modifiers,
+        }) if !modifiers.ctrl && !modifiers.alt => {
+            view.bookmark_confirm_id.clear();
+            view.toggle_collapse();
+            return;
+        }
+        // Unfold all.
+        InputEvent::Key(KeyEvent {
+            key: Key::Char('Z'),
+            modifiers,
+        }) if !modifiers.ctrl && !modifiers.alt => {
+            view.bookmark_confirm_id.clear();
+            view.expand_all();
+            return;
+        }
         // Jump top.
         InputEvent::Key(KeyEvent {
             key: Key::Char('g'),
@@ -858,7 +935,7 @@ pub fn render_thread_frame(
         };
         let unread = view.unread_count();
         let mut meta = format!(
-            "mode:{mode_label}  msg:{sel_display}/{total_rows}  unread:{unread}  j/k move  g/G top/bot  Enter expand  f toggle  [ ] topic"
+            "mode:{mode_label}  msg:{sel_display}/{total_rows}  unread:{unread}  j/k move  g/G top/bot  Enter expand  f toggle  [ ] topic  z fold  Z unfold all"
         );
         let marker = view
             .read_markers
@@ -1017,6 +1094,9 @@ fn render_row_card(
         header.push_str(&format!(" [{}]", row.msg.priority.trim()));
     }
     header.push_str(bookmark_star);
+    if row.hidden_replies > 0 {
+        header.push_str(&format!(" [+{} hidden]", row.hidden_replies));
+    }
     lines.push(header);
 
     let body_indent = format!(
@@ -1095,6 +1175,15 @@ fn render_row_card(
 // Tree connector generation
 // ---------------------------------------------------------------------------
 
+/// Count all replies below `node`, however deep.
+fn count_descendants(node: &ThreadNode, node_map: &HashMap<&str, &ThreadNode>) -> usize {
+    node.children_ids
+        .iter()
+        .filter_map(|id| node_map.get(id.as_str()))
+        .map(|child| 1 + count_descendants(child, node_map))
+        .sum()
+}
+
 /// Generate box-drawing connector prefix for a thread node.
 fn prefix_for_node(
     node: &ThreadNode,
@@ -1362,6 +1451,69 @@ mod tests {
         assert_eq!(view.rows().len(), 3);
     }
 
+    fn deep_messages() -> Vec<ThreadMessage> {
+        vec![
+            tmsg("m1", "alice", "task", "15:00", "root"),
+            tmsg_reply("m2", "bob", "task", "15:01", "child", "m1"),
+            tmsg_reply("m3", "carol", "task", "15:02", "grandchild", "m2"),
+            tmsg_reply("m4", "dave", "task", "15:03", "great-grandchild", "m3"),
+            tmsg_reply("m5", "erin", "task", "15:04", "second child", "m1"),
+        ]
+    }
+
+    fn row_ids(view: &ThreadViewModel) -> Vec<&str> {
+        view.rows().iter().map(|r| r.msg.id.as_str()).collect()
+    }
+
+    #[test]
+    fn fold_hides_descendants_and_unfold_restores() {
+        let mut view = ThreadViewModel::new();
+        view.set_data("task", sample_topics(), deep_messages());
+        assert_eq!(row_ids(&view), vec!["m1", "m2", "m3", "m4", "m5"]);
+
+        view.move_selection(1); // m2
+        apply_thread_input(&mut view, InputEvent::Key(KeyEvent::plain(Key::Char('z'))));
+        assert_eq!(row_ids(&view), vec!["m1", "m2", "m5"]);
+        assert_eq!(view.rows()[1].hidden_replies, 2);
+        assert_eq!(view.selected_id(), "m2");
+
+        let frame = render_thread_frame(&view, 60, 20, ThemeSpec::default());
+        assert!(frame.snapshot().contains("bob · 15:01 [+2 hidden]"));
+
+        // Navigation skips the hidden rows.
+        view.move_selection(1);
+        assert_eq!(view.selected_id(), "m5");
+
+        view.move_selection(-1);
+        apply_thread_input(&mut view, InputEvent::Key(KeyEvent::plain(Key::Char('z'))));
+        assert_eq!(row_ids(&view), vec!["m1", "m2", "m3", "m4", "m5"]);
+        assert_eq!(view.rows()[1].hidden_replies, 0);
+    }
+
+    #[test]
+    fn folding_ancestor_moves_selection_to_visible_row() {
+        let mut view = ThreadViewModel::new();
+        view.set_data("task", sample_topics(), deep_messages());
+        view.move_selection(3); // m4 (leaf)
+        assert_eq!(view.selected_id(), "m4");
+
+        // Folding from a leaf folds its parent and selects it.
+        apply_thread_input(&mut view, InputEvent::Key(KeyEvent::plain(Key::Char('z'))));
+        assert_eq!(view.selected_id(), "m3");
+        assert_eq!(row_ids(&view), vec!["m1", "m2", "m3", "m5"]);
+
+        // Folding the root with a nested fold keeps a valid selection.
+        view.jump_top();
+        apply_thread_input(&mut view, InputEvent::Key(KeyEvent::plain(Key::Char('z'))));
+        assert_eq!(row_ids(&view), vec!["m1"]);
+        assert_eq!(view.rows()[0].hidden_replies, 4);
+        assert_eq!(view.selected_id(), "m1");
+
+        apply_thread_input(&mut view, InputEvent::Key(KeyEvent::plain(Key::Char('Z'))));
+        assert_eq!(row_ids(&view).len(), 5);
+        assert_eq!(view.selected_id(), "m1");
+    }
+
     #[test]
     fn bookmark_toggle() {
         let mut view = ThreadViewModel::new();
@@ -1,7 +1,8 @@
 //! Topics view for the fmail TUI, ported from Go `topicsView`.
 //!
 //! Displays a list of topics or DM conversations with metadata, filtering,
-//! sorting, starring, and a preview panel for the selected item.
+//! sorting, starring, and a preview panel for the selected item. Topics can
+//! also be renamed or merged after confirmation, via a [`TopicStore`].
 
 use forge_ftui_adapter::input::{translate_input, InputEvent, Key, KeyEvent, UiAction};
 use forge_ftui_adapter::render::{FrameSize, RenderFrame, TextRole};
@@ -92,6 +93,51 @@ impl TopicsItem {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Topic rename / merge
+// ---------------------------------------------------------------------------
+
+/// Storage backend for destructive topic operations.
+pub trait TopicStore {
+    /// Move all messages of `from` into the new topic `to`; returns the count.
+    fn rename_topic(&self, from: &str, to: &str) -> Result<usize, String>;
+    /// Move all messages of `from` into the existing topic `into`; returns the count.
+    fn merge_topic(&self, from: &str, into: &str) -> Result<usize, String>;
+}
+
+impl TopicStore for fmail_core::store::Store {
+    fn rename_topic(&self, from: &str, to: &str) -> Result<usize, String> {
+        fmail_core::store::Store::rename_topic(self, from, to)
+    }
+
+    fn merge_topic(&self, from: &str, into: &str) -> Result<usize, String> {
+        fmail_core::store::Store::merge_topic(self, from, into)
+    }
+}
+
+/// Which topic operation a prompt is collecting a destination for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TopicOpKind {
+    Rename,
+    Merge,
+}
+
+/// A fully specified topic operation awaiting confirmation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TopicOp {
+    Rename { from: String, to: String },
+    Merge { from: String, into: String },
+}
+
+impl TopicOp {
+    fn describe(&self) -> String {
+        match self {
+            Self::Rename { from, to } => format!("rename {from} -> {to}"),
+            Self::Merge { from, into } => format!("merge {from} into {into}"),
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // PreviewMessage
 // ---------------------------------------------------------------------------
@@ -131,6 +177,14 @@ pub struct TopicsViewModel {
 
     status_line: String,
     error: Option<String>,
+
+    /// Destination prompt for a rename/merge of the selected topic.
+    op_prompt: Option<TopicOpKind>,
+    op_input: String,
+    /// Operation waiting for y/n confirmation.
+    pending_op: Option<TopicOp>,
+    /// Operation confirmed by the user, to be run against a [`TopicStore`].
+    confirmed_op: Option<TopicOp>,
 }
 
 impl Default for TopicsViewModel {
@@ -156,6 +210,10 @@ impl TopicsViewModel {
             now_secs: 0,
             status_line: String::new(),
             error: None,
+            op_prompt: None,
+            op_input: String::new(),
+            pending_op: None,
+            confirmed_op: None,
         }
     }
 
@@ -306,6 +364,137 @@ impl TopicsViewModel {
         self.starred = starred;
     }
 
+    // -- rename / merge ------------------------------------------------------
+
+    #[must_use]
+    pub fn status_line(&self) -> &str {
+        &self.status_line
+    }
+
+    #[must_use]
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    #[must_use]
+    pub fn pending_op(&self) -> Option<&TopicOp> {
+        self.pending_op.as_ref()
+    }
+
+    /// Start prompting for the destination of a rename or merge of the
+    /// selected topic. DM conversations cannot be renamed or merged.
+    pub fn begin_topic_op(&mut self, kind: TopicOpKind) {
+        if self.mode != TopicsMode::Topics || self.selected_target().is_empty() {
+            return;
+        }
+        self.op_prompt = Some(kind);
+        self.op_input.clear();
+        self.error = None;
+    }
+
+    /// Validate the prompt input and move to confirmation.
+    pub fn submit_topic_op(&mut self) {
+        let Some(kind) = self.op_prompt.take() else {
+            return;
+        };
+        let from = self.selected_target().to_owned();
+        let dest = self.op_input.trim().to_ascii_lowercase();
+        self.op_input.clear();
+        if dest.is_empty() {
+            return;
+        }
+        if dest == from {
+            self.error = Some(format!("{from}: source and destination are the same"));
+            return;
+        }
+        let exists = self.items.iter().any(|item| item.target == dest);
+        let op = match kind {
+            TopicOpKind::Rename if exists => {
+                self.error = Some(format!("rename refused: topic {dest} already exists"));
+                return;
+            }
+            TopicOpKind::Rename => TopicOp::Rename { from, to: dest },
+            TopicOpKind::Merge if !exists => {
+                self.error = Some(format!("merge refused: topic {dest} not found"));
+                return;
+            }
+            TopicOpKind::Merge => TopicOp::Merge { from, into: dest },
+        };
+        self.pending_op = Some(op);
+    }
+
+    pub fn cancel_topic_op(&mut self) {
+        self.op_prompt = None;
+        self.op_input.clear();
+        self.pending_op = None;
+    }
+
+    /// Confirm the pending operation; the app then runs it with
+    /// [`TopicsViewModel::run_confirmed_op`].
+    pub fn confirm_topic_op(&mut self) {
+        self.confirmed_op = self.pending_op.take();
+    }
+
+    /// Take the confirmed operation, if any, without running it.
+    pub fn take_confirmed_op(&mut self) -> Option<TopicOp> {
+        self.confirmed_op.take()
+    }
+
+    /// Run the confirmed operation against `store` and fold the result into
+    /// the item list. Returns the number of messages moved.
+    pub fn run_confirmed_op(&mut self, store: &dyn TopicStore) -> Option<usize> {
+        let op = self.confirmed_op.take()?;
+        let result = match &op {
+            TopicOp::Rename { from, to } => store.rename_topic(from, to),
+            TopicOp::Merge { from, into } => store.merge_topic(from, into),
+        };
+        match result {
+            Ok(moved) => {
+                self.apply_topic_op(&op);
+                self.status_line = format!("{} ({moved} messages moved)", op.describe());
+                self.error = None;
+                Some(moved)
+            }
+            Err(err) => {
+                self.error = Some(format!("{}: {err}", op.describe()));
+                None
+            }
+        }
+    }
+
+    fn apply_topic_op(&mut self, op: &TopicOp) {
+        match op {
+            TopicOp::Rename { from, to } => {
+                for item in self.items.iter_mut().filter(|item| item.target == *from) {
+                    item.target = to.clone();
+                    item.label = to.clone();
+                }
+                if let Some(star) = self.starred.iter_mut().find(|s| *s == from) {
+                    *star = to.clone();
+                }
+            }
+            TopicOp::Merge { from, into } => {
+                let Some(pos) = self.items.iter().position(|item| item.target == *from) else {
+                    return;
+                };
+                let source = self.items.remove(pos);
+                if let Some(target) = self.items.iter_mut().find(|item| item.target == *into) {
+                    target.message_count += source.message_count;
+                    target.unread += source.unread;
+                    target.last_activity_secs =
+                        target.last_activity_secs.max(source.last_activity_secs);
+                    for agent in source.participants {
+                        if !target.participants.contains(&agent) {
+                            target.participants.push(agent);
+                        }
+                    }
+                }
+                self.starred.retain(|s| s != from);
+            }
+        }
+        self.sort_and_filter();
+    }
+
     // -- internal ------------------------------------------------------------
 
     fn sort_and_filter(&mut self) {
@@ -445,6 +634,34 @@ fn truncate(input: &str, max_chars: usize) -> String {
 /// Process an input event on the topics view model.
 /// Returns `true` if the event was consumed (caller should not propagate).
 pub fn apply_topics_input(view: &mut TopicsViewModel, event: InputEvent) -> bool {
+    // Confirmation for a destructive topic operation.
+    if view.pending_op.is_some() {
+        if let InputEvent::Key(KeyEvent { key, .. }) = event {
+            match key {
+                Key::Char('y') | Key::Char('Y') => view.confirm_topic_op(),
+                _ => view.cancel_topic_op(),
+            }
+        }
+        return true;
+    }
+
+    // Destination prompt for rename/merge: raw key capture.
+    if view.op_prompt.is_some() {
+        if let InputEvent::Key(KeyEvent { key, .. }) = event {
+            match key {
+                Key::Escape => view.cancel_topic_op(),
+                Key::Enter => view.submit_topic_op(),
+                Key::Backspace => {
+                    view.op_input.pop();
+                }
+                Key::Char(ch) => view.op_input.push(ch),
+                _ => return false,
+            }
+            return true;
+        }
+        return false;
+    }
+
     // Filter mode: raw key capture.
     if view.filter_active {
         if let InputEvent::Key(KeyEvent { key, .. }) = event {
@@ -491,6 +708,14 @@ pub fn apply_topics_input(view: &mut TopicsViewModel, event: InputEvent) -> bool
                     view.toggle_star_selected();
                     return true;
                 }
+                Key::Char('R') => {
+                    view.begin_topic_op(TopicOpKind::Rename);
+                    return true;
+                }
+                Key::Char('M') => {
+                    view.begin_topic_op(TopicOpKind::Merge);
+                    return true;
+                }
                 _ => {}
             }
         }
@@ -566,10 +791,22 @@ pub fn render_topics_frame(
         }
     }
 
-    // Error line at bottom if present.
-    if let Some(ref err) = view.error {
-        let y = height.saturating_sub(1);
+    // Bottom line: operation prompt/confirmation, then error, then status.
+    let y = height.saturating_sub(1);
+    if let Some(kind) = view.op_prompt {
+        let verb = match kind {
+            TopicOpKind::Rename => "rename",
+            TopicOpKind::Merge => "merge into",
+        };
+        let prompt = format!("{verb}> {}_  (Enter next, Esc cancel)", view.op_input);
+        frame.draw_text(0, y, &truncate(&prompt, width), TextRole::Accent);
+    } else if let Some(op) = &view.pending_op {
+        let prompt = format!("{}? moves message files (y/n)", op.describe());
+        frame.draw_text(0, y, &truncate(&prompt, width), TextRole::Danger);
+    } else if let Some(ref err) = view.error {
         frame.draw_text(0, y, &truncate(err, width), TextRole::Danger);
+    } else if !view.status_line.is_empty() {
+        frame.draw_text(0, y, &truncate(&view.status_line, width), TextRole::Muted);
     }
 
     frame
@@ -608,7 +845,7 @@ fn render_list_panel(
     // Key hints line.
     let hints = match view.mode {
         TopicsMode::Topics => {
-            "j/k move  Enter open  / filter  d toggle  s sort  * star  n compose  Esc back"
+            "j/k move  Enter open  / filter  d toggle  s sort  * star  n compose  R rename  M merge  Esc back"
         }
         TopicsMode::DM => "j/k move  Enter open  / filter  d toggle  s sort  n compose  Esc back",
     };
@@ -1256,6 +1493,139 @@ mod tests {
         );
     }
 
+    // -- Rename / merge ------------------------------------------------------
+
+    /// In-memory topic store: topic -> message count.
+    struct MockTopicStore {
+        topics: std::cell::RefCell<std::collections::BTreeMap<String, usize>>,
+    }
+
+    impl MockTopicStore {
+        fn from_items(items: &[TopicsItem]) -> Self {
+            Self {
+                topics: std::cell::RefCell::new(
+                    items
+                        .iter()
+                        .map(|i| (i.target.clone(), i.message_count))
+                        .collect(),
+                ),
+            }
+        }
+    }
+
+    impl TopicStore for MockTopicStore {
+        fn rename_topic(&self, from: &str, to: &str) -> Result<usize, String> {
+            let mut topics = self.topics.borrow_mut();
+            if topics.contains_key(to) {
+                return Err(format!("topic \"{to}\" already exists"));
+            }
+            let moved = topics.remove(from).ok_or("not found")?;
+            topics.insert(to.to_owned(), moved);
+            Ok(moved)
+        }
+
+        fn merge_topic(&self, from: &str, into: &str) -> Result<usize, String> {
+            let mut topics = self.topics.borrow_mut();
+            if !topics.contains_key(into) {
+                return Err("not found".to_owned());
+            }
+            let moved = topics.remove(from).ok_or("not found")?;
+            *topics.entry(into.to_owned()).or_default() += moved;
+            Ok(moved)
+        }
+    }
+
+    fn type_str(vm: &mut TopicsViewModel, text: &str) {
+        for ch in text.chars() {
+            apply_topics_input(vm, key(Key::Char(ch)));
+        }
+    }
+
+    fn select(vm: &mut TopicsViewModel, target: &str) {
+        vm.selected = vm
+            .items()
+            .iter()
+            .position(|i| i.target == target)
+            .unwrap_or_default();
+    }
+
+    #[test]
+    fn merge_consolidates_counts_under_target() {
+        let mut vm = TopicsViewModel::new();
+        vm.set_items(sample_items());
+        let store = MockTopicStore::from_items(vm.items());
+        select(&mut vm, "review");
+
+        apply_topics_input(&mut vm, key(Key::Char('M')));
+        type_str(&mut vm, "task");
+        apply_topics_input(&mut vm, key(Key::Enter));
+        assert_eq!(
+            vm.pending_op(),
+            Some(&TopicOp::Merge {
+                from: "review".to_owned(),
+                into: "task".to_owned()
+            })
+        );
+        apply_topics_input(&mut vm, key(Key::Char('y')));
+        assert_eq!(vm.run_confirmed_op(&store), Some(3));
+
+        assert_eq!(vm.items().len(), 2);
+        let Some(task) = vm.items().iter().find(|i| i.target == "task") else {
+            panic!("task topic missing");
+        };
+        assert_eq!(task.message_count, 8);
+        assert_eq!(task.unread, 3);
+        assert_eq!(task.participants, vec!["alice", "bob", "dave"]);
+        assert_eq!(store.topics.borrow().get("task"), Some(&8));
+        assert_eq!(
+            vm.status_line(),
+            "merge review into task (3 messages moved)"
+        );
+    }
+
+    #[test]
+    fn rename_moves_item_and_refuses_collision() {
+        let mut vm = TopicsViewModel::new();
+        vm.set_items(sample_items());
+        let store = MockTopicStore::from_items(vm.items());
+        select(&mut vm, "build");
+
+        apply_topics_input(&mut vm, key(Key::Char('R')));
+        type_str(&mut vm, "task");
+        apply_topics_input(&mut vm, key(Key::Enter));
+        assert!(vm.pending_op().is_none());
+        assert!(vm.error().unwrap_or_default().contains("already exists"));
+
+        apply_topics_input(&mut vm, key(Key::Char('R')));
+        type_str(&mut vm, "builds");
+        apply_topics_input(&mut vm, key(Key::Enter));
+        apply_topics_input(&mut vm, key(Key::Char('y')));
+        assert_eq!(vm.run_confirmed_op(&store), Some(7));
+        assert!(vm.items().iter().any(|i| i.target == "builds"));
+        assert!(!vm.items().iter().any(|i| i.target == "build"));
+    }
+
+    #[test]
+    fn topic_op_requires_confirmation() {
+        let mut vm = TopicsViewModel::new();
+        vm.set_items(sample_items());
+        let store = MockTopicStore::from_items(vm.items());
+        select(&mut vm, "review");
+
+        apply_topics_input(&mut vm, key(Key::Char('M')));
+        type_str(&mut vm, "task");
+        apply_topics_input(&mut vm, key(Key::Enter));
+        let frame = render_topics_frame(&vm, 80, 10, ThemeSpec::default());
+        assert!(frame
+            .row_text(9)
+            .contains("merge review into task? moves message files (y/n)"));
+
+        apply_topics_input(&mut vm, key(Key::Char('n')));
+        assert!(vm.pending_op().is_none());
+        assert_eq!(vm.run_confirmed_op(&store), None);
+        assert_eq!(vm.items().len(), 3);
+    }
+
     // -- Relative time -------------------------------------------------------
 
     #[test]
@@ -44,6 +44,9 @@ pub enum AgentServiceError {
         last_observed_state: String,
     },
 
+    /// A long-running operation was cancelled by the caller.
+    Cancelled { agent_id: String, operation: String },
+
     /// Requested agent mode does not match harness command capability.
     CapabilityMismatch {
         adapter: String,
@@ -88,6 +91,10 @@ impl fmt::Display for AgentServiceError {
                 f,
                 "wait cancelled for agent {agent_id:?}: last observed state {last_observed_state:?}"
             ),
+            Self::Cancelled {
+                agent_id,
+                operation,
+            } => write!(f, "{operation} cancelled for agent {agent_id:?}"),
             Self::CapabilityMismatch {
                 adapter,
                 requested_mode,
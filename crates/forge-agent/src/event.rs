@@ -3,6 +3,12 @@
 //! Each service operation emits an event that can be stored for later
 //! querying (audit trail, explain support, debugging).
 
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
 use chrono::{DateTime, Utc};
 
 /// The kind of agent operation that generated an event.
@@ -37,6 +43,8 @@ impl std::fmt::Display for AgentEventKind {
 pub enum AgentEventOutcome {
     Success,
     Error(String),
+    /// The caller cancelled the operation before it completed.
+    Cancelled,
 }
 
 impl std::fmt::Display for AgentEventOutcome {
@@ -44,6 +52,7 @@ impl std::fmt::Display for AgentEventOutcome {
         match self {
             Self::Success => f.write_str("success"),
             Self::Error(msg) => write!(f, "error: {msg}"),
+            Self::Cancelled => f.write_str("cancelled"),
         }
     }
 }
@@ -81,6 +90,14 @@ impl AgentEvent {
 /// them to subscribers.
 pub trait AgentEventSink: Send + Sync {
     fn record(&self, event: AgentEvent);
+
+    /// Record several events in order. Sinks backed by a database or network
+    /// can override this to write the batch in one round trip.
+    fn record_batch(&self, events: Vec<AgentEvent>) {
+        for event in events {
+            self.record(event);
+        }
+    }
 }
 
 /// In-memory event sink for testing.
@@ -124,3 +141,208 @@ pub struct NullEventSink;
 impl AgentEventSink for NullEventSink {
     fn record(&self, _event: AgentEvent) {}
 }
+
+/// Thresholds for [`BatchingEventSink`].
+#[derive(Debug, Clone, Copy)]
+pub struct BatchingConfig {
+    /// Flush once this many events are buffered.
+    pub batch_size: usize,
+    /// Flush buffered events at least this often.
+    pub flush_interval: Duration,
+    /// Events recorded while this many are already buffered are dropped.
+    pub capacity: usize,
+}
+
+impl Default for BatchingConfig {
+    fn default() -> Self {
+        Self {
+            batch_size: 64,
+            flush_interval: Duration::from_millis(500),
+            capacity: 4096,
+        }
+    }
+}
+
+/// Counters exposed by [`BatchingEventSink`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BatchingStats {
+    pub buffered: u64,
+    pub flushed: u64,
+    pub dropped: u64,
+}
+
+struct BatchState {
+    queue: VecDeque<AgentEvent>,
+    shutdown: bool,
+}
+
+struct BatchShared {
+    inner: Arc<dyn AgentEventSink>,
+    config: BatchingConfig,
+    state: Mutex<BatchState>,
+    wake: Condvar,
+    // Held while a batch is handed to `inner`, so batches are delivered in
+    // the order they were drained.
+    delivery: Mutex<()>,
+    flushed: AtomicU64,
+    dropped: AtomicU64,
+}
+
+impl BatchShared {
+    fn lock_state(&self) -> std::sync::MutexGuard<'_, BatchState> {
+        match self.state.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+
+    fn flush(&self) {
+        let _delivery = match self.delivery.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        let batch: Vec<AgentEvent> = self.lock_state().queue.drain(..).collect();
+        if batch.is_empty() {
+            return;
+        }
+        // Count before delivering: once the inner sink has the events,
+        // `stats()` must already include them.
+        self.flushed
+            .fetch_add(batch.len() as u64, Ordering::Relaxed);
+        self.inner.record_batch(batch);
+    }
+}
+
+/// Returned by [`BatchingEventSink::try_record`] once the sink is closed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SinkClosedError;
+
+impl std::fmt::Display for SinkClosedError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("batching event sink is closed")
+    }
+}
+
+impl std::error::Error for SinkClosedError {}
+
+/// Event sink that buffers events and hands them to an inner sink in
+/// batches from a background thread.
+///
+/// Batches flush when `batch_size` events are buffered, every
+/// `flush_interval`, on an explicit [`BatchingEventSink::flush`], and on
+/// drop. Events reach the inner sink in the order they were recorded.
+pub struct BatchingEventSink {
+    shared: Arc<BatchShared>,
+    worker: Mutex<Option<JoinHandle<()>>>,
+}
+
+impl BatchingEventSink {
+    pub fn new(inner: Arc<dyn AgentEventSink>, config: BatchingConfig) -> Self {
+        let config = BatchingConfig {
+            batch_size: config.batch_size.max(1),
+            capacity: config.capacity.max(config.batch_size.max(1)),
+            ..config
+        };
+        let shared = Arc::new(BatchShared {
+            inner,
+            config,
+            state: Mutex::new(BatchState {
+                queue: VecDeque::new(),
+                shutdown: false,
+            }),
+            wake: Condvar::new(),
+            delivery: Mutex::new(()),
+            flushed: AtomicU64::new(0),
+            dropped: AtomicU64::new(0),
+        });
+        let worker_shared = Arc::clone(&shared);
+        let worker = std::thread::spawn(move || batch_worker(worker_shared));
+        Self {
+            shared,
+            worker: Mutex::new(Some(worker)),
+        }
+    }
+
+    /// Deliver everything buffered so far before returning.
+    pub fn flush(&self) {
+        self.shared.flush();
+    }
+
+    pub fn stats(&self) -> BatchingStats {
+        BatchingStats {
+            buffered: self.shared.lock_state().queue.len() as u64,
+            flushed: self.shared.flushed.load(Ordering::Relaxed),
+            dropped: self.shared.dropped.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Buffer `event` for delivery. Fails once [`close`](Self::close) has
+    /// run; events dropped because the buffer is full still return `Ok`.
+    pub fn try_record(&self, event: AgentEvent) -> Result<(), SinkClosedError> {
+        let mut state = self.shared.lock_state();
+        if state.shutdown {
+            self.shared.dropped.fetch_add(1, Ordering::Relaxed);
+            return Err(SinkClosedError);
+        }
+        if state.queue.len() >= self.shared.config.capacity {
+            self.shared.dropped.fetch_add(1, Ordering::Relaxed);
+            return Ok(());
+        }
+        state.queue.push_back(event);
+        if state.queue.len() >= self.shared.config.batch_size {
+            self.shared.wake.notify_all();
+        }
+        Ok(())
+    }
+
+    /// Stop the background thread and deliver any remaining events.
+    /// Later records are rejected.
+    pub fn close(&self) {
+        self.shared.lock_state().shutdown = true;
+        self.shared.wake.notify_all();
+        let worker = match self.worker.lock() {
+            Ok(mut guard) => guard.take(),
+            Err(poisoned) => poisoned.into_inner().take(),
+        };
+        if let Some(worker) = worker {
+            let _ = worker.join();
+        }
+        self.shared.flush();
+    }
+}
+
+impl AgentEventSink for BatchingEventSink {
+    /// Events recorded after close are dropped and counted in
+    /// [`BatchingStats::dropped`]; use [`try_record`](Self::try_record) to
+    /// observe the rejection.
+    fn record(&self, event: AgentEvent) {
+        let _ = self.try_record(event);
+    }
+}
+
+impl Drop for BatchingEventSink {
+    fn drop(&mut self) {
+        self.close();
+    }
+}
+
+fn batch_worker(shared: Arc<BatchShared>) {
+    loop {
+        {
+            let state = shared.lock_state();
+            let (state, _) =
+                match shared
+                    .wake
+                    .wait_timeout_while(state, shared.config.flush_interval, |state| {
+                        !state.shutdown && state.queue.len() < shared.config.batch_size
+                    }) {
+                    Ok(result) => result,
+                    Err(poisoned) => poisoned.into_inner(),
+                };
+            if state.shutdown {
+                return;
+            }
+        }
+        shared.flush();
+    }
+}
@@ -5,6 +5,7 @@ use std::time::Duration;
 
 use async_trait::async_trait;
 use chrono::{DateTime, TimeZone, Utc};
+use tokio_util::sync::CancellationToken;
 use tonic::transport::Endpoint;
 
 use forge_rpc::forged::v1 as proto;
@@ -140,6 +141,51 @@ impl AgentService for ForgedTransport {
         Ok(snapshot)
     }
 
+    async fn spawn_agent_cancellable(
+        &self,
+        params: SpawnAgentParams,
+        cancel: CancellationToken,
+    ) -> Result<AgentSnapshot, AgentServiceError> {
+        let agent_id = params.agent_id.clone();
+        // Set once the pre-spawn lookup finishes. An agent that is already
+        // live was not created by this call and must survive the
+        // cancellation; if the lookup itself is cancelled, the spawn never
+        // started and there is nothing to remove.
+        let existed_before = std::sync::OnceLock::new();
+        let spawn = async {
+            let existed = !agent_id.is_empty() && self.get_agent(&agent_id).await.is_ok();
+            let _ = existed_before.set(existed);
+            self.spawn_agent(params).await
+        };
+        tokio::select! {
+            biased;
+            _ = cancel.cancelled() => {
+                // The daemon may have created the agent before the request
+                // was dropped; remove it so no half-spawned agent lingers.
+                if existed_before.get() == Some(&false) {
+                    let _ = self
+                        .kill_agent(KillAgentParams {
+                            agent_id: agent_id.clone(),
+                            force: true,
+                            grace_period: None,
+                        })
+                        .await;
+                }
+                self.emit_event(
+                    Some(agent_id.clone()),
+                    AgentEventKind::Spawn,
+                    AgentEventOutcome::Cancelled,
+                    "spawn cancelled by caller",
+                );
+                Err(AgentServiceError::Cancelled {
+                    agent_id,
+                    operation: "spawn".into(),
+                })
+            }
+            result = spawn => result,
+        }
+    }
+
     async fn send_message(&self, params: SendMessageParams) -> Result<bool, AgentServiceError> {
         if params.agent_id.is_empty() {
             return Err(AgentServiceError::InvalidArgument {
@@ -3,6 +3,7 @@
 //! Provides a transport-agnostic `AgentService` trait with implementations for:
 //! - `ForgedTransport`: gRPC-backed service using the forged daemon
 //! - `MockAgentService`: Configurable mock for unit testing
+//! - `ReconnectingForgedTransport`: Wrapper that reconnects after daemon restarts
 //!
 //! Each operation emits an `AgentEvent` for audit/debugging via the `AgentEventSink` trait.
 
@@ -12,6 +13,7 @@ pub mod event;
 pub mod forged;
 pub mod lifecycle;
 pub mod mock;
+pub mod reconnect;
 pub mod service;
 pub mod types;
 pub mod wait;
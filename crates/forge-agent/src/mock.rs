@@ -1,16 +1,20 @@
 //! Mock agent service for unit testing.
 //!
 //! Provides a configurable mock that records all calls and returns
-//! pre-configured responses.
+//! pre-configured responses. Per-operation scripts can queue a sequence of
+//! outcomes (e.g. fail, fail, succeed) to exercise retry logic.
 
-use std::collections::HashMap;
-use std::sync::Mutex;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
 use async_trait::async_trait;
 use chrono::Utc;
+use tokio_util::sync::CancellationToken;
 
 use crate::capability::validate_spawn_guardrails;
 use crate::error::AgentServiceError;
+use crate::event::{AgentEvent, AgentEventKind, AgentEventOutcome, AgentEventSink, NullEventSink};
 use crate::lifecycle::{validate_operation_state, AgentOperation};
 use crate::service::AgentService;
 use crate::types::{
@@ -30,6 +34,15 @@ pub enum MockCall {
     GetAgent(String),
 }
 
+/// Scripted outcome for a single mock call.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MockOutcome {
+    /// Return this error without touching the registry.
+    Fail(AgentServiceError),
+    /// Run the normal mock behavior, skipping any configured one-shot error.
+    Succeed,
+}
+
 /// Mock implementation of `AgentService` for testing.
 pub struct MockAgentService {
     agents: Mutex<HashMap<String, AgentSnapshot>>,
@@ -38,6 +51,9 @@ pub struct MockAgentService {
     send_error: Mutex<Option<AgentServiceError>>,
     kill_error: Mutex<Option<AgentServiceError>>,
     get_error: Mutex<Option<AgentServiceError>>,
+    scripts: Mutex<HashMap<AgentEventKind, VecDeque<MockOutcome>>>,
+    event_sink: Arc<dyn AgentEventSink>,
+    spawn_delay: Duration,
 }
 
 impl Default for MockAgentService {
@@ -55,7 +71,41 @@ impl MockAgentService {
             send_error: Mutex::new(None),
             kill_error: Mutex::new(None),
             get_error: Mutex::new(None),
+            scripts: Mutex::new(HashMap::new()),
+            event_sink: Arc::new(NullEventSink),
+            spawn_delay: Duration::ZERO,
+        }
+    }
+
+    /// Keep spawned agents in their startup phase for `delay` before the
+    /// spawn call returns, so callers can cancel mid-spawn.
+    pub fn with_spawn_delay(mut self, delay: Duration) -> Self {
+        self.spawn_delay = delay;
+        self
+    }
+
+    /// Record an `AgentEvent` for every call on `sink`.
+    pub fn with_event_sink(mut self, sink: Arc<dyn AgentEventSink>) -> Self {
+        self.event_sink = sink;
+        self
+    }
+
+    /// Queue outcomes for successive calls of `operation`, one per call.
+    /// Once the script is exhausted the configured behavior applies again.
+    pub fn with_script(
+        self,
+        operation: AgentEventKind,
+        outcomes: impl IntoIterator<Item = MockOutcome>,
+    ) -> Self {
+        match self.scripts.lock() {
+            Ok(mut scripts) => scripts.entry(operation).or_default().extend(outcomes),
+            Err(poisoned) => poisoned
+                .into_inner()
+                .entry(operation)
+                .or_default()
+                .extend(outcomes),
         }
+        self
     }
 
     /// Pre-populate an agent in the mock registry.
@@ -148,6 +198,44 @@ impl MockAgentService {
             Err(poisoned) => poisoned.into_inner().take(),
         }
     }
+
+    /// Error to inject for this call: the next scripted outcome if any,
+    /// otherwise the configured one-shot error.
+    fn injected_error(
+        &self,
+        operation: AgentEventKind,
+        configured: Option<&Mutex<Option<AgentServiceError>>>,
+    ) -> Option<AgentServiceError> {
+        let scripted = {
+            let mut scripts = match self.scripts.lock() {
+                Ok(guard) => guard,
+                Err(poisoned) => poisoned.into_inner(),
+            };
+            scripts
+                .get_mut(&operation)
+                .and_then(|script| script.pop_front())
+        };
+        match scripted {
+            Some(MockOutcome::Fail(err)) => Some(err),
+            Some(MockOutcome::Succeed) => None,
+            None => configured.and_then(Self::take_error),
+        }
+    }
+
+    fn emit<T>(
+        &self,
+        agent_id: Option<String>,
+        kind: AgentEventKind,
+        result: &Result<T, AgentServiceError>,
+    ) {
+        let outcome = match result {
+            Ok(_) => AgentEventOutcome::Success,
+            Err(AgentServiceError::Cancelled { .. }) => AgentEventOutcome::Cancelled,
+            Err(err) => AgentEventOutcome::Error(err.to_string()),
+        };
+        self.event_sink
+            .record(AgentEvent::new(agent_id, kind, outcome, "mock"));
+    }
 }
 
 /// Helper to create a test snapshot with sensible defaults.
@@ -166,16 +254,15 @@ pub fn test_snapshot(id: &str, state: AgentState) -> AgentSnapshot {
     }
 }
 
-#[async_trait]
-impl AgentService for MockAgentService {
-    async fn spawn_agent(
+impl MockAgentService {
+    fn handle_spawn_agent(
         &self,
         params: SpawnAgentParams,
     ) -> Result<AgentSnapshot, AgentServiceError> {
         validate_spawn_guardrails(&params)?;
         self.record(MockCall::Spawn(params.clone()));
 
-        if let Some(err) = Self::take_error(&self.spawn_error) {
+        if let Some(err) = self.injected_error(AgentEventKind::Spawn, Some(&self.spawn_error)) {
             return Err(err);
         }
 
@@ -219,10 +306,11 @@ impl AgentService for MockAgentService {
         Ok(snapshot)
     }
 
-    async fn send_message(&self, params: SendMessageParams) -> Result<bool, AgentServiceError> {
+    fn handle_send_message(&self, params: SendMessageParams) -> Result<bool, AgentServiceError> {
         self.record(MockCall::SendMessage(params.clone()));
 
-        if let Some(err) = Self::take_error(&self.send_error) {
+        if let Some(err) = self.injected_error(AgentEventKind::SendMessage, Some(&self.send_error))
+        {
             return Err(err);
         }
 
@@ -245,12 +333,16 @@ impl AgentService for MockAgentService {
         Ok(true)
     }
 
-    async fn wait_state(
+    fn handle_wait_state(
         &self,
         params: WaitStateParams,
     ) -> Result<AgentSnapshot, AgentServiceError> {
         self.record(MockCall::WaitState(params.clone()));
 
+        if let Some(err) = self.injected_error(AgentEventKind::WaitState, None) {
+            return Err(err);
+        }
+
         let agents = match self.agents.lock() {
             Ok(guard) => guard,
             Err(poisoned) => poisoned.into_inner(),
@@ -278,9 +370,13 @@ impl AgentService for MockAgentService {
         })
     }
 
-    async fn interrupt_agent(&self, agent_id: &str) -> Result<bool, AgentServiceError> {
+    fn handle_interrupt_agent(&self, agent_id: &str) -> Result<bool, AgentServiceError> {
         self.record(MockCall::Interrupt(agent_id.to_string()));
 
+        if let Some(err) = self.injected_error(AgentEventKind::Interrupt, None) {
+            return Err(err);
+        }
+
         let agents = match self.agents.lock() {
             Ok(guard) => guard,
             Err(poisoned) => poisoned.into_inner(),
@@ -299,10 +395,10 @@ impl AgentService for MockAgentService {
         Ok(true)
     }
 
-    async fn kill_agent(&self, params: KillAgentParams) -> Result<bool, AgentServiceError> {
+    fn handle_kill_agent(&self, params: KillAgentParams) -> Result<bool, AgentServiceError> {
         self.record(MockCall::Kill(params.clone()));
 
-        if let Some(err) = Self::take_error(&self.kill_error) {
+        if let Some(err) = self.injected_error(AgentEventKind::Kill, Some(&self.kill_error)) {
             return Err(err);
         }
 
@@ -328,12 +424,16 @@ impl AgentService for MockAgentService {
         Ok(true)
     }
 
-    async fn list_agents(
+    fn handle_list_agents(
         &self,
         filter: ListAgentsFilter,
     ) -> Result<Vec<AgentSnapshot>, AgentServiceError> {
         self.record(MockCall::ListAgents(filter.clone()));
 
+        if let Some(err) = self.injected_error(AgentEventKind::ListAgents, None) {
+            return Err(err);
+        }
+
         let agents = match self.agents.lock() {
             Ok(guard) => guard,
             Err(poisoned) => poisoned.into_inner(),
@@ -359,10 +459,10 @@ impl AgentService for MockAgentService {
         Ok(result)
     }
 
-    async fn get_agent(&self, agent_id: &str) -> Result<AgentSnapshot, AgentServiceError> {
+    fn handle_get_agent(&self, agent_id: &str) -> Result<AgentSnapshot, AgentServiceError> {
         self.record(MockCall::GetAgent(agent_id.to_string()));
 
-        if let Some(err) = Self::take_error(&self.get_error) {
+        if let Some(err) = self.injected_error(AgentEventKind::GetAgent, Some(&self.get_error)) {
             return Err(err);
         }
 
@@ -379,3 +479,104 @@ impl AgentService for MockAgentService {
             })
     }
 }
+
+#[async_trait]
+impl AgentService for MockAgentService {
+    async fn spawn_agent(
+        &self,
+        params: SpawnAgentParams,
+    ) -> Result<AgentSnapshot, AgentServiceError> {
+        self.spawn_agent_cancellable(params, CancellationToken::new())
+            .await
+    }
+
+    async fn spawn_agent_cancellable(
+        &self,
+        params: SpawnAgentParams,
+        cancel: CancellationToken,
+    ) -> Result<AgentSnapshot, AgentServiceError> {
+        let agent_id = params.agent_id.clone();
+        let cancelled = || AgentServiceError::Cancelled {
+            agent_id: agent_id.clone(),
+            operation: "spawn".into(),
+        };
+
+        let result = if cancel.is_cancelled() {
+            Err(cancelled())
+        } else {
+            match self.handle_spawn_agent(params) {
+                Ok(snapshot) if !self.spawn_delay.is_zero() => {
+                    tokio::select! {
+                        biased;
+                        _ = cancel.cancelled() => {
+                            let mut agents = match self.agents.lock() {
+                                Ok(guard) => guard,
+                                Err(poisoned) => poisoned.into_inner(),
+                            };
+                            agents.remove(&agent_id);
+                            Err(cancelled())
+                        }
+                        _ = tokio::time::sleep(self.spawn_delay) => Ok(snapshot),
+                    }
+                }
+                other => other,
+            }
+        };
+
+        self.emit(Some(agent_id.clone()), AgentEventKind::Spawn, &result);
+        result
+    }
+
+    async fn send_message(&self, params: SendMessageParams) -> Result<bool, AgentServiceError> {
+        let agent_id = params.agent_id.clone();
+        let result = self.handle_send_message(params);
+        self.emit(Some(agent_id), AgentEventKind::SendMessage, &result);
+        result
+    }
+
+    async fn wait_state(
+        &self,
+        params: WaitStateParams,
+    ) -> Result<AgentSnapshot, AgentServiceError> {
+        let agent_id = params.agent_id.clone();
+        let result = self.handle_wait_state(params);
+        self.emit(Some(agent_id), AgentEventKind::WaitState, &result);
+        result
+    }
+
+    async fn interrupt_agent(&self, agent_id: &str) -> Result<bool, AgentServiceError> {
+        let result = self.handle_interrupt_agent(agent_id);
+        self.emit(
+            Some(agent_id.to_string()),
+            AgentEventKind::Interrupt,
+            &result,
+        );
+        result
+    }
+
+    async fn kill_agent(&self, params: KillAgentParams) -> Result<bool, AgentServiceError> {
+        let agent_id = params.agent_id.clone();
+        let result = self.handle_kill_agent(params);
+        self.emit(Some(agent_id), AgentEventKind::Kill, &result);
+        result
+    }
+
+    async fn list_agents(
+        &self,
+        filter: ListAgentsFilter,
+    ) -> Result<Vec<AgentSnapshot>, AgentServiceError> {
+        let result = self.handle_list_agents(filter);
+        self.emit(None, AgentEventKind::ListAgents, &result);
+        result
+    }
+
+    async fn get_agent(&self, agent_id: &str) -> Result<AgentSnapshot, AgentServiceError> {
+        let result = self.handle_get_agent(agent_id);
+        self.emit(
+            Some(agent_id.to_string()),
+            AgentEventKind::GetAgent,
+            &result,
+        );
+        result
+    }
+}
@@ -0,0 +1,253 @@
+//! Reconnecting wrapper around an `AgentService` transport.
+//!
+//! When the forged daemon restarts, calls fail with `TransportUnavailable`.
+//! `ReconnectingForgedTransport` re-establishes the connection with
+//! exponential backoff and retries idempotent calls (get, list, wait).
+//! Non-idempotent calls (spawn, send, interrupt, kill) are never replayed;
+//! they fail with an error that says so.
+
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use tokio_util::sync::CancellationToken;
+
+use crate::error::AgentServiceError;
+use crate::forged::ForgedTransport;
+use crate::service::AgentService;
+use crate::types::{
+    AgentSnapshot, KillAgentParams, ListAgentsFilter, SendMessageParams, SpawnAgentParams,
+    WaitStateParams,
+};
+
+/// Backoff settings for re-establishing the daemon connection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReconnectPolicy {
+    /// Retries after the initial failure before giving up.
+    pub max_attempts: u32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(5),
+        }
+    }
+}
+
+impl ReconnectPolicy {
+    /// Delay before reconnect attempt `attempt` (1-based).
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 1u32
+            .checked_shl(attempt.saturating_sub(1))
+            .unwrap_or(u32::MAX);
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+}
+
+/// Connection state reported to callbacks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionState {
+    Connected,
+    Disconnected,
+    Reconnecting { attempt: u32 },
+}
+
+type StateCallback = Arc<dyn Fn(ConnectionState) + Send + Sync>;
+
+/// `AgentService` that transparently reconnects after transport failures.
+pub struct ReconnectingForgedTransport<S = ForgedTransport> {
+    inner: S,
+    policy: ReconnectPolicy,
+    state: Mutex<ConnectionState>,
+    callbacks: Mutex<Vec<StateCallback>>,
+}
+
+impl<S: AgentService> ReconnectingForgedTransport<S> {
+    pub fn new(inner: S, policy: ReconnectPolicy) -> Self {
+        Self {
+            inner,
+            policy,
+            state: Mutex::new(ConnectionState::Connected),
+            callbacks: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Register a callback invoked on every connection state change.
+    pub fn on_state_change(&self, callback: impl Fn(ConnectionState) + Send + Sync + 'static) {
+        match self.callbacks.lock() {
+            Ok(mut guard) => guard.push(Arc::new(callback)),
+            Err(poisoned) => poisoned.into_inner().push(Arc::new(callback)),
+        }
+    }
+
+    pub fn connection_state(&self) -> ConnectionState {
+        match self.state.lock() {
+            Ok(guard) => *guard,
+            Err(poisoned) => *poisoned.into_inner(),
+        }
+    }
+
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+
+    fn set_state(&self, next: ConnectionState) {
+        {
+            let mut state = match self.state.lock() {
+                Ok(guard) => guard,
+                Err(poisoned) => poisoned.into_inner(),
+            };
+            if *state == next {
+                return;
+            }
+            *state = next;
+        }
+        let callbacks = match self.callbacks.lock() {
+            Ok(guard) => guard.clone(),
+            Err(poisoned) => poisoned.into_inner().clone(),
+        };
+        for callback in callbacks {
+            callback(next);
+        }
+    }
+
+    /// Run an idempotent call, reconnecting and retrying on transport loss.
+    async fn retrying<T, F, Fut>(&self, call: F) -> Result<T, AgentServiceError>
+    where
+        F: Fn() -> Fut + Send + Sync,
+        Fut: Future<Output = Result<T, AgentServiceError>> + Send,
+        T: Send,
+    {
+        let mut attempt = 0u32;
+        loop {
+            match call().await {
+                Err(err) if is_disconnect(&err) => {
+                    if attempt >= self.policy.max_attempts {
+                        self.set_state(ConnectionState::Disconnected);
+                        return Err(err);
+                    }
+                    if attempt == 0 {
+                        self.set_state(ConnectionState::Disconnected);
+                    }
+                    attempt += 1;
+                    tokio::time::sleep(self.policy.backoff(attempt)).await;
+                    self.set_state(ConnectionState::Reconnecting { attempt });
+                }
+                result => {
+                    self.set_state(ConnectionState::Connected);
+                    return result;
+                }
+            }
+        }
+    }
+
+    /// Run a non-idempotent call once. A transport failure is reported
+    /// without replaying the call, since it may already have taken effect.
+    async fn once<T, Fut>(&self, operation: &str, call: Fut) -> Result<T, AgentServiceError>
+    where
+        Fut: Future<Output = Result<T, AgentServiceError>> + Send,
+    {
+        match call.await {
+            Err(err) if is_disconnect(&err) => {
+                self.set_state(ConnectionState::Disconnected);
+                Err(AgentServiceError::TransportUnavailable {
+                    message: format!(
+                        "connection lost during {operation}; not retried because {operation} is not idempotent: {err}"
+                    ),
+                })
+            }
+            result => {
+                self.set_state(ConnectionState::Connected);
+                result
+            }
+        }
+    }
+}
+
+fn is_disconnect(err: &AgentServiceError) -> bool {
+    matches!(err, AgentServiceError::TransportUnavailable { .. })
+}
+
+#[async_trait]
+impl<S: AgentService> AgentService for ReconnectingForgedTransport<S> {
+    async fn spawn_agent(
+        &self,
+        params: SpawnAgentParams,
+    ) -> Result<AgentSnapshot, AgentServiceError> {
+        self.once("spawn_agent", self.inner.spawn_agent(params))
+            .await
+    }
+
+    async fn spawn_agent_cancellable(
+        &self,
+        params: SpawnAgentParams,
+        cancel: CancellationToken,
+    ) -> Result<AgentSnapshot, AgentServiceError> {
+        self.once(
+            "spawn_agent",
+            self.inner.spawn_agent_cancellable(params, cancel),
+        )
+        .await
+    }
+
+    async fn send_message(&self, params: SendMessageParams) -> Result<bool, AgentServiceError> {
+        self.once("send_message", self.inner.send_message(params))
+            .await
+    }
+
+    async fn wait_state(
+        &self,
+        params: WaitStateParams,
+    ) -> Result<AgentSnapshot, AgentServiceError> {
+        self.retrying(|| self.inner.wait_state(params.clone()))
+            .await
+    }
+
+    async fn interrupt_agent(&self, agent_id: &str) -> Result<bool, AgentServiceError> {
+        self.once("interrupt_agent", self.inner.interrupt_agent(agent_id))
+            .await
+    }
+
+    async fn kill_agent(&self, params: KillAgentParams) -> Result<bool, AgentServiceError> {
+        self.once("kill_agent", self.inner.kill_agent(params)).await
+    }
+
+    async fn list_agents(
+        &self,
+        filter: ListAgentsFilter,
+    ) -> Result<Vec<AgentSnapshot>, AgentServiceError> {
+        self.retrying(|| self.inner.list_agents(filter.clone()))
+            .await
+    }
+
+    async fn get_agent(&self, agent_id: &str) -> Result<AgentSnapshot, AgentServiceError> {
+        self.retrying(|| self.inner.get_agent(agent_id)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_doubles_up_to_max() {
+        let policy = ReconnectPolicy {
+            max_attempts: 10,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_millis(700),
+        };
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff(3), Duration::from_millis(400));
+        assert_eq!(policy.backoff(4), Duration::from_millis(700));
+        assert_eq!(policy.backoff(40), Duration::from_millis(700));
+    }
+}
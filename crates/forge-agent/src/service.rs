@@ -4,6 +4,7 @@
 //! for testing.
 
 use async_trait::async_trait;
+use tokio_util::sync::CancellationToken;
 
 use crate::error::AgentServiceError;
 use crate::types::{
@@ -24,6 +25,27 @@ pub trait AgentService: Send + Sync {
         params: SpawnAgentParams,
     ) -> Result<AgentSnapshot, AgentServiceError>;
 
+    /// Spawn a new agent, giving up when `cancel` fires first.
+    ///
+    /// A cancelled spawn returns `Cancelled`. Implementations that can tell
+    /// a half-created agent apart should remove it; the default only stops
+    /// waiting and leaves the daemon state as it is.
+    async fn spawn_agent_cancellable(
+        &self,
+        params: SpawnAgentParams,
+        cancel: CancellationToken,
+    ) -> Result<AgentSnapshot, AgentServiceError> {
+        let agent_id = params.agent_id.clone();
+        tokio::select! {
+            biased;
+            _ = cancel.cancelled() => Err(AgentServiceError::Cancelled {
+                agent_id,
+                operation: "spawn".into(),
+            }),
+            result = self.spawn_agent(params) => result,
+        }
+    }
+
     /// Send a message (text/keys) to a running agent.
     async fn send_message(&self, params: SendMessageParams) -> Result<bool, AgentServiceError>;
 
@@ -2,8 +2,12 @@
 
 //! Tests for the agent event recording system.
 
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
 use forge_agent::event::{
-    AgentEvent, AgentEventKind, AgentEventOutcome, AgentEventSink, InMemoryEventSink, NullEventSink,
+    AgentEvent, AgentEventKind, AgentEventOutcome, AgentEventSink, BatchingConfig,
+    BatchingEventSink, BatchingStats, InMemoryEventSink, NullEventSink, SinkClosedError,
 };
 
 #[test]
@@ -91,3 +95,100 @@ fn event_has_timestamp() {
     let elapsed = chrono::Utc::now() - event.timestamp;
     assert!(elapsed.num_seconds() < 2);
 }
+
+// ── Batching sink tests ──
+
+fn numbered_event(n: usize) -> AgentEvent {
+    AgentEvent::new(
+        Some(format!("a{n}")),
+        AgentEventKind::SendMessage,
+        AgentEventOutcome::Success,
+        format!("event {n}"),
+    )
+}
+
+fn wait_for_count(sink: &InMemoryEventSink, want: usize) {
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while sink.count() < want {
+        assert!(
+            Instant::now() < deadline,
+            "timed out waiting for {want} events, have {}",
+            sink.count()
+        );
+        std::thread::sleep(Duration::from_millis(5));
+    }
+}
+
+#[test]
+fn batching_sink_flushes_on_batch_size() {
+    let inner = Arc::new(InMemoryEventSink::new());
+    let sink = BatchingEventSink::new(
+        inner.clone(),
+        BatchingConfig {
+            batch_size: 3,
+            flush_interval: Duration::from_secs(3600),
+            capacity: 16,
+        },
+    );
+
+    sink.record(numbered_event(0));
+    sink.record(numbered_event(1));
+    std::thread::sleep(Duration::from_millis(50));
+    assert_eq!(inner.count(), 0);
+    assert_eq!(sink.stats().buffered, 2);
+
+    sink.record(numbered_event(2));
+    wait_for_count(&inner, 3);
+
+    let details: Vec<String> = inner.events().into_iter().map(|e| e.detail).collect();
+    assert_eq!(details, vec!["event 0", "event 1", "event 2"]);
+    // The worker may still be inside the delivery; flush waits for it.
+    sink.flush();
+    assert_eq!(
+        sink.stats(),
+        BatchingStats {
+            buffered: 0,
+            flushed: 3,
+            dropped: 0,
+        }
+    );
+}
+
+#[test]
+fn batching_sink_rejects_records_after_close() {
+    let inner = Arc::new(InMemoryEventSink::new());
+    let sink = BatchingEventSink::new(inner.clone(), BatchingConfig::default());
+    assert_eq!(sink.try_record(numbered_event(0)), Ok(()));
+    sink.close();
+    assert_eq!(inner.count(), 1);
+
+    assert_eq!(sink.try_record(numbered_event(1)), Err(SinkClosedError));
+    sink.record(numbered_event(2));
+    assert_eq!(inner.count(), 1);
+    assert_eq!(sink.stats().dropped, 2);
+}
+
+#[test]
+fn batching_sink_drop_flushes_remainder_in_order() {
+    let inner = Arc::new(InMemoryEventSink::new());
+    {
+        let sink = BatchingEventSink::new(
+            inner.clone(),
+            BatchingConfig {
+                batch_size: 100,
+                flush_interval: Duration::from_secs(3600),
+                capacity: 100,
+            },
+        );
+        for n in 0..5 {
+            sink.record(numbered_event(n));
+        }
+        assert_eq!(inner.count(), 0);
+    }
+
+    let details: Vec<String> = inner.events().into_iter().map(|e| e.detail).collect();
+    assert_eq!(
+        details,
+        vec!["event 0", "event 1", "event 2", "event 3", "event 4"]
+    );
+}
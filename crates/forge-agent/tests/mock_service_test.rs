@@ -6,15 +6,18 @@
 //! behavior without requiring a running forged daemon.
 
 use std::collections::HashMap;
+use std::sync::Arc;
 use std::time::Duration;
 
 use forge_agent::error::AgentServiceError;
-use forge_agent::mock::{test_snapshot, MockAgentService};
+use forge_agent::event::{AgentEventKind, AgentEventOutcome, InMemoryEventSink};
+use forge_agent::mock::{test_snapshot, MockAgentService, MockOutcome};
 use forge_agent::service::AgentService;
 use forge_agent::types::{
-    AgentRequestMode, AgentState, KillAgentParams, ListAgentsFilter, SendMessageParams,
-    SpawnAgentParams, WaitStateParams,
+    AgentRequestMode, AgentSnapshot, AgentState, KillAgentParams, ListAgentsFilter,
+    SendMessageParams, SpawnAgentParams, WaitStateParams,
 };
+use tokio_util::sync::CancellationToken;
 
 fn test_spawn_params(id: &str) -> SpawnAgentParams {
     SpawnAgentParams {
@@ -471,6 +474,192 @@ async fn calls_are_recorded() {
     assert_eq!(svc.call_count(), 3);
 }
 
+// ── Scripted outcome tests ──
+
+#[tokio::test]
+async fn scripted_failures_drive_retrying_caller_to_success() {
+    let sink = Arc::new(InMemoryEventSink::new());
+    let unavailable = AgentServiceError::TransportUnavailable {
+        message: "connection refused".into(),
+    };
+    let svc = MockAgentService::new()
+        .with_event_sink(sink.clone())
+        .with_script(
+            AgentEventKind::Spawn,
+            [
+                MockOutcome::Fail(unavailable.clone()),
+                MockOutcome::Fail(unavailable),
+                MockOutcome::Succeed,
+            ],
+        );
+
+    let mut attempts = 0;
+    let snapshot = loop {
+        attempts += 1;
+        match svc.spawn_agent(test_spawn_params("a1")).await {
+            Ok(snapshot) => break snapshot,
+            Err(err) if err.is_retryable() && attempts < 5 => continue,
+            Err(err) => panic!("unexpected error after {attempts} attempts: {err}"),
+        }
+    };
+
+    assert_eq!(attempts, 3);
+    assert_eq!(snapshot.id, "a1");
+    assert_eq!(svc.call_count(), 3);
+
+    let outcomes: Vec<bool> = sink
+        .events()
+        .iter()
+        .map(|event| {
+            assert_eq!(event.kind, AgentEventKind::Spawn);
+            event.outcome == AgentEventOutcome::Success
+        })
+        .collect();
+    assert_eq!(outcomes, vec![false, false, true]);
+}
+
+#[tokio::test]
+async fn exhausted_script_falls_back_to_configured_behavior() {
+    let svc = MockAgentService::new()
+        .with_agent(test_snapshot("a1", AgentState::Idle))
+        .with_get_error(AgentServiceError::Internal {
+            message: "configured".into(),
+        })
+        .with_script(AgentEventKind::GetAgent, [MockOutcome::Succeed]);
+
+    assert!(svc.get_agent("a1").await.is_ok());
+    let err = svc.get_agent("a1").await.unwrap_err();
+    assert_eq!(
+        err,
+        AgentServiceError::Internal {
+            message: "configured".into()
+        }
+    );
+    assert!(svc.get_agent("a1").await.is_ok());
+}
+
+// ── Cancellation tests ──
+
+#[tokio::test]
+async fn cancelling_mid_spawn_returns_cancelled_and_cleans_up() {
+    let sink = Arc::new(InMemoryEventSink::new());
+    let svc = Arc::new(
+        MockAgentService::new()
+            .with_event_sink(sink.clone())
+            .with_spawn_delay(Duration::from_secs(30)),
+    );
+    let cancel = CancellationToken::new();
+
+    let task = {
+        let svc = Arc::clone(&svc);
+        let cancel = cancel.clone();
+        tokio::spawn(async move {
+            svc.spawn_agent_cancellable(test_spawn_params("a1"), cancel)
+                .await
+        })
+    };
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    cancel.cancel();
+
+    let err = task.await.unwrap().unwrap_err();
+    assert_eq!(
+        err,
+        AgentServiceError::Cancelled {
+            agent_id: "a1".into(),
+            operation: "spawn".into(),
+        }
+    );
+    assert!(!err.is_retryable());
+
+    let events = sink.events();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].kind, AgentEventKind::Spawn);
+    assert_eq!(events[0].outcome, AgentEventOutcome::Cancelled);
+
+    let missing = svc.get_agent("a1").await.unwrap_err();
+    assert_eq!(
+        missing,
+        AgentServiceError::NotFound {
+            agent_id: "a1".into()
+        }
+    );
+}
+
+#[tokio::test]
+async fn spawn_with_untriggered_token_succeeds() {
+    let svc = MockAgentService::new().with_spawn_delay(Duration::from_millis(5));
+    let snapshot = svc
+        .spawn_agent_cancellable(test_spawn_params("a1"), CancellationToken::new())
+        .await
+        .unwrap();
+    assert_eq!(snapshot.id, "a1");
+    assert!(svc.get_agent("a1").await.is_ok());
+}
+
+/// Implements only the required trait methods; spawns never finish.
+struct PendingSpawnService;
+
+#[async_trait::async_trait]
+impl AgentService for PendingSpawnService {
+    async fn spawn_agent(
+        &self,
+        _params: SpawnAgentParams,
+    ) -> Result<AgentSnapshot, AgentServiceError> {
+        std::future::pending().await
+    }
+
+    async fn send_message(&self, _params: SendMessageParams) -> Result<bool, AgentServiceError> {
+        Ok(false)
+    }
+
+    async fn wait_state(
+        &self,
+        params: WaitStateParams,
+    ) -> Result<AgentSnapshot, AgentServiceError> {
+        Err(AgentServiceError::NotFound {
+            agent_id: params.agent_id,
+        })
+    }
+
+    async fn interrupt_agent(&self, _agent_id: &str) -> Result<bool, AgentServiceError> {
+        Ok(false)
+    }
+
+    async fn kill_agent(&self, _params: KillAgentParams) -> Result<bool, AgentServiceError> {
+        Ok(false)
+    }
+
+    async fn list_agents(
+        &self,
+        _filter: ListAgentsFilter,
+    ) -> Result<Vec<AgentSnapshot>, AgentServiceError> {
+        Ok(Vec::new())
+    }
+
+    async fn get_agent(&self, agent_id: &str) -> Result<AgentSnapshot, AgentServiceError> {
+        Err(AgentServiceError::NotFound {
+            agent_id: agent_id.to_string(),
+        })
+    }
+}
+
+#[tokio::test]
+async fn default_cancellable_spawn_stops_waiting_on_cancel() {
+    let cancel = CancellationToken::new();
+    cancel.cancel();
+    let err = PendingSpawnService
+        .spawn_agent_cancellable(test_spawn_params("a1"), cancel)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err,
+        AgentServiceError::Cancelled {
+            agent_id: "a1".into(),
+            operation: "spawn".into(),
+        }
+    );
+}
+
 // ── Concurrent operation tests ──
 
 #[tokio::test]
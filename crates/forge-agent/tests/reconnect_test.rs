@@ -0,0 +1,107 @@
+#![allow(clippy::expect_used, clippy::unwrap_used)]
+
+//! Tests for the reconnecting transport wrapper, driven by the mock service.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use forge_agent::error::AgentServiceError;
+use forge_agent::event::AgentEventKind;
+use forge_agent::mock::{test_snapshot, MockAgentService, MockOutcome};
+use forge_agent::reconnect::{ConnectionState, ReconnectPolicy, ReconnectingForgedTransport};
+use forge_agent::service::AgentService;
+use forge_agent::types::{AgentRequestMode, AgentState, SpawnAgentParams};
+
+fn fast_policy() -> ReconnectPolicy {
+    ReconnectPolicy {
+        max_attempts: 3,
+        initial_backoff: Duration::from_millis(1),
+        max_backoff: Duration::from_millis(4),
+    }
+}
+
+fn dropped_connection() -> MockOutcome {
+    MockOutcome::Fail(AgentServiceError::TransportUnavailable {
+        message: "connection reset by peer".into(),
+    })
+}
+
+fn record_states(
+    transport: &ReconnectingForgedTransport<MockAgentService>,
+) -> Arc<Mutex<Vec<ConnectionState>>> {
+    let states = Arc::new(Mutex::new(Vec::new()));
+    let sink = Arc::clone(&states);
+    transport.on_state_change(move |state| sink.lock().unwrap().push(state));
+    states
+}
+
+#[tokio::test]
+async fn idempotent_call_succeeds_after_reconnect() {
+    let mock = MockAgentService::new()
+        .with_agent(test_snapshot("a1", AgentState::Idle))
+        .with_script(AgentEventKind::GetAgent, [dropped_connection()]);
+    let transport = ReconnectingForgedTransport::new(mock, fast_policy());
+    let states = record_states(&transport);
+
+    let snapshot = transport.get_agent("a1").await.unwrap();
+    assert_eq!(snapshot.id, "a1");
+    assert_eq!(transport.inner().call_count(), 2);
+    assert_eq!(transport.connection_state(), ConnectionState::Connected);
+    assert_eq!(
+        *states.lock().unwrap(),
+        vec![
+            ConnectionState::Disconnected,
+            ConnectionState::Reconnecting { attempt: 1 },
+            ConnectionState::Connected,
+        ]
+    );
+}
+
+#[tokio::test]
+async fn idempotent_call_gives_up_after_max_attempts() {
+    let mock = MockAgentService::new().with_script(
+        AgentEventKind::ListAgents,
+        std::iter::repeat_with(dropped_connection).take(10),
+    );
+    let transport = ReconnectingForgedTransport::new(mock, fast_policy());
+
+    let err = transport.list_agents(Default::default()).await.unwrap_err();
+    assert!(matches!(
+        err,
+        AgentServiceError::TransportUnavailable { .. }
+    ));
+    assert_eq!(transport.inner().call_count(), 4);
+    assert_eq!(transport.connection_state(), ConnectionState::Disconnected);
+}
+
+#[tokio::test]
+async fn non_idempotent_call_is_not_retried() {
+    let mock = MockAgentService::new().with_script(AgentEventKind::Spawn, [dropped_connection()]);
+    let transport = ReconnectingForgedTransport::new(mock, fast_policy());
+
+    let err = transport
+        .spawn_agent(SpawnAgentParams {
+            agent_id: "a1".to_string(),
+            workspace_id: "test-ws".to_string(),
+            command: "claude".to_string(),
+            args: Vec::new(),
+            env: HashMap::new(),
+            working_dir: "/tmp".to_string(),
+            session_name: String::new(),
+            adapter: "claude_code".to_string(),
+            requested_mode: AgentRequestMode::Continuous,
+            allow_oneshot_fallback: false,
+        })
+        .await
+        .unwrap_err();
+
+    match err {
+        AgentServiceError::TransportUnavailable { message } => {
+            assert!(message.contains("not idempotent"), "{message}");
+        }
+        other => panic!("unexpected error: {other:?}"),
+    }
+    assert_eq!(transport.inner().call_count(), 1);
+    assert_eq!(transport.connection_state(), ConnectionState::Disconnected);
+}
@@ -224,7 +224,7 @@ pub struct ForgedAgentBackend {
 
 impl ForgedAgentBackend {
     pub fn open_from_env() -> Self {
-        let target = resolved_daemon_target();
+        let target = crate::daemon_target::resolve_daemon_target("");
         let config = ForgedTransportConfig {
             target,
             ..ForgedTransportConfig::default()
@@ -259,25 +259,6 @@ impl AgentBackend for ForgedAgentBackend {
     }
 }
 
-fn resolved_daemon_target() -> String {
-    let env_target = std::env::var("FORGE_DAEMON_TARGET")
-        .ok()
-        .filter(|v| !v.trim().is_empty())
-        .or_else(|| {
-            std::env::var("FORGED_ADDR")
-                .ok()
-                .filter(|v| !v.trim().is_empty())
-        });
-    if let Some(target) = env_target {
-        let trimmed = target.trim();
-        if trimmed.contains("://") {
-            return trimmed.to_string();
-        }
-        return format!("http://{trimmed}");
-    }
-    "http://127.0.0.1:50051".to_string()
-}
-
 // ── Arg parsing ──────────────────────────────────────────────────────────────
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -4371,6 +4352,7 @@ Will resume after unblock"
                 .to_string(),
             content_hash: "hash".to_string(),
             captured_at: "2026-02-12T00:00:00Z".to_string(),
+            repeat_count: 1,
         };
         let events = vec![PersistentAgentEvent {
             id: 7,
@@ -3,9 +3,16 @@ use std::io::Write;
 use std::path::PathBuf;
 use std::time::{SystemTime, UNIX_EPOCH};
 
+use hmac::{Hmac, Mac};
 use serde::Serialize;
+use sha2::Sha256;
 use tabwriter::TabWriter;
 
+/// Environment variable holding the HMAC key for `forge audit export --sign`.
+pub const AUDIT_SIGNING_KEY_ENV: &str = "FORGE_AUDIT_SIGNING_KEY";
+
+const NO_CHAIN_ERROR: &str = "audit backend does not record a hash chain";
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CommandOutput {
     pub stdout: String,
@@ -41,8 +48,40 @@ pub struct EventPage {
     pub next_cursor: String,
 }
 
+/// An audit event with its hash-chain links.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ChainedAuditEvent {
+    pub event: AuditEvent,
+    pub prev_hash: String,
+    pub chain_hash: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChainBreak {
+    pub event_id: String,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ChainReport {
+    pub verified: usize,
+    pub unchained: usize,
+    pub head_hash: String,
+    pub first_break: Option<ChainBreak>,
+}
+
 pub trait AuditBackend {
     fn query_events(&self, query: &EventQuery) -> Result<EventPage, String>;
+
+    /// Every event in insertion order with its hash-chain links.
+    fn chain_events(&self) -> Result<Vec<ChainedAuditEvent>, String> {
+        Err(NO_CHAIN_ERROR.to_string())
+    }
+
+    /// Walk the hash chain and report the first break, if any.
+    fn verify_chain(&self) -> Result<ChainReport, String> {
+        Err(NO_CHAIN_ERROR.to_string())
+    }
 }
 
 #[derive(Debug, Clone, Default)]
@@ -170,6 +209,15 @@ impl SqliteAuditBackend {
     pub fn new(db_path: PathBuf) -> Self {
         Self { db_path }
     }
+
+    fn open_db(&self) -> Result<Option<forge_db::Db>, String> {
+        if !self.db_path.exists() {
+            return Ok(None);
+        }
+        forge_db::Db::open(forge_db::Config::new(&self.db_path))
+            .map(Some)
+            .map_err(|err| format!("open database {}: {err}", self.db_path.display()))
+    }
 }
 
 impl AuditBackend for SqliteAuditBackend {
@@ -200,27 +248,62 @@ impl AuditBackend for SqliteAuditBackend {
             Err(err) => return Err(err.to_string()),
         };
 
-        let events = page
-            .events
-            .into_iter()
-            .map(|event| AuditEvent {
-                id: event.id,
-                timestamp: event.timestamp,
-                event_type: event.event_type,
-                entity_type: event.entity_type,
-                entity_id: event.entity_id,
-                payload: event.payload,
-                metadata: event
-                    .metadata
-                    .map(|metadata| metadata.into_iter().collect::<BTreeMap<String, String>>()),
-            })
-            .collect();
+        let events = page.events.into_iter().map(to_audit_event).collect();
 
         Ok(EventPage {
             events,
             next_cursor: page.next_cursor,
         })
     }
+
+    fn chain_events(&self) -> Result<Vec<ChainedAuditEvent>, String> {
+        let Some(db) = self.open_db()? else {
+            return Ok(Vec::new());
+        };
+        let chain = forge_db::event_repository::EventRepository::new(&db)
+            .list_chain()
+            .map_err(|err| err.to_string())?;
+        Ok(chain
+            .into_iter()
+            .map(|chained| ChainedAuditEvent {
+                event: to_audit_event(chained.event),
+                prev_hash: chained.prev_hash,
+                chain_hash: chained.chain_hash,
+            })
+            .collect())
+    }
+
+    fn verify_chain(&self) -> Result<ChainReport, String> {
+        let Some(db) = self.open_db()? else {
+            return Ok(ChainReport::default());
+        };
+        let report = forge_db::event_repository::EventRepository::new(&db)
+            .verify_chain()
+            .map_err(|err| err.to_string())?;
+        Ok(ChainReport {
+            verified: report.verified,
+            unchained: report.unchained,
+            head_hash: report.head_hash,
+            first_break: report.first_break.map(|found| ChainBreak {
+                event_id: found.event_id,
+                reason: found.reason,
+            }),
+        })
+    }
+}
+
+fn to_audit_event(event: forge_db::event_repository::Event) -> AuditEvent {
+    AuditEvent {
+        id: event.id,
+        timestamp: event.timestamp,
+        event_type: event.event_type,
+        entity_type: event.entity_type,
+        entity_id: event.entity_id,
+        payload: event.payload,
+        metadata: event
+            .metadata
+            .map(|metadata| metadata.into_iter().collect::<BTreeMap<String, String>>()),
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -237,6 +320,12 @@ struct ParsedArgs {
     limit: i64,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+struct ExportArgs {
+    sign: bool,
+    key_file: String,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 struct ParsedTime {
     canonical: String,
@@ -257,6 +346,49 @@ struct JsonAuditEvent<'a> {
     metadata: Option<&'a BTreeMap<String, String>>,
 }
 
+#[derive(Debug, Serialize)]
+struct JsonChainReport<'a> {
+    intact: bool,
+    verified: usize,
+    unchained: usize,
+    head_hash: &'a str,
+    #[serde(rename = "break", skip_serializing_if = "Option::is_none")]
+    first_break: Option<JsonChainBreak<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+struct JsonChainBreak<'a> {
+    event_id: &'a str,
+    reason: &'a str,
+}
+
+/// Export bundle. When signed, `signature` is the HMAC-SHA256 of the unsigned
+/// bundle exactly as `forge audit export` prints it (without the trailing
+/// newline), so a verifier can recompute it from an unsigned export.
+#[derive(Debug, Serialize)]
+struct ExportBundle<'a> {
+    version: u32,
+    head_hash: &'a str,
+    event_count: usize,
+    events: Vec<JsonChainedEvent<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    signature: Option<ExportSignature>,
+}
+
+#[derive(Debug, Serialize)]
+struct JsonChainedEvent<'a> {
+    #[serde(flatten)]
+    event: JsonAuditEvent<'a>,
+    prev_hash: &'a str,
+    chain_hash: &'a str,
+}
+
+#[derive(Debug, Serialize)]
+struct ExportSignature {
+    algorithm: &'static str,
+    value: String,
+}
+
 pub fn run_for_test(args: &[&str], backend: &dyn AuditBackend) -> CommandOutput {
     let owned_args: Vec<String> = args.iter().map(|arg| (*arg).to_string()).collect();
     let mut stdout = Vec::new();
@@ -289,6 +421,13 @@ fn execute(
     backend: &dyn AuditBackend,
     stdout: &mut dyn Write,
 ) -> Result<(), String> {
+    if let Some((subcommand, sub_args)) = split_subcommand(args) {
+        return match subcommand {
+            "verify" => execute_verify(&sub_args, backend, stdout),
+            _ => execute_export(&sub_args, backend, stdout),
+        };
+    }
+
     let parsed = parse_args(args)?;
 
     if !parsed.event_types_raw.trim().is_empty() && !parsed.action_types_raw.trim().is_empty() {
@@ -381,6 +520,191 @@ fn execute(
     Ok(())
 }
 
+/// Split off a `verify`/`export` subcommand, keeping any global output flags
+/// forwarded ahead of it.
+fn split_subcommand(args: &[String]) -> Option<(&'static str, Vec<String>)> {
+    let rest = match args.first() {
+        Some(token) if token == "audit" => &args[1..],
+        _ => args,
+    };
+    let position = rest
+        .iter()
+        .position(|token| !matches!(token.as_str(), "--json" | "--jsonl" | "--quiet"))?;
+    let subcommand = match rest[position].as_str() {
+        "verify" => "verify",
+        "export" => "export",
+        _ => return None,
+    };
+    let mut sub_args = rest.to_vec();
+    sub_args.remove(position);
+    Some((subcommand, sub_args))
+}
+
+fn execute_verify(
+    args: &[String],
+    backend: &dyn AuditBackend,
+    stdout: &mut dyn Write,
+) -> Result<(), String> {
+    let mut json = false;
+    let mut quiet = false;
+    for token in args {
+        match token.as_str() {
+            "-h" | "--help" | "help" => return Err(VERIFY_HELP_TEXT.to_string()),
+            "--json" | "--jsonl" => json = true,
+            "--quiet" => quiet = true,
+            other => {
+                return Err(format!(
+                    "error: unknown argument for audit verify: '{other}'"
+                ))
+            }
+        }
+    }
+
+    let report = backend.verify_chain()?;
+    if json {
+        let payload = JsonChainReport {
+            intact: report.first_break.is_none(),
+            verified: report.verified,
+            unchained: report.unchained,
+            head_hash: &report.head_hash,
+            first_break: report.first_break.as_ref().map(|found| JsonChainBreak {
+                event_id: &found.event_id,
+                reason: &found.reason,
+            }),
+        };
+        serde_json::to_writer_pretty(&mut *stdout, &payload).map_err(|err| err.to_string())?;
+        writeln!(stdout).map_err(|err| err.to_string())?;
+    }
+
+    if let Some(found) = &report.first_break {
+        return Err(format!(
+            "audit chain broken at event {}: {} ({} events verified before the break)",
+            found.event_id, found.reason, report.verified
+        ));
+    }
+
+    if !json && !quiet {
+        write!(
+            stdout,
+            "Audit chain intact: {} events verified",
+            report.verified
+        )
+        .map_err(|err| err.to_string())?;
+        if report.unchained > 0 {
+            write!(
+                stdout,
+                " ({} earlier events predate the chain)",
+                report.unchained
+            )
+            .map_err(|err| err.to_string())?;
+        }
+        writeln!(stdout).map_err(|err| err.to_string())?;
+        if !report.head_hash.is_empty() {
+            writeln!(stdout, "Head: {}", report.head_hash).map_err(|err| err.to_string())?;
+        }
+    }
+    Ok(())
+}
+
+fn execute_export(
+    args: &[String],
+    backend: &dyn AuditBackend,
+    stdout: &mut dyn Write,
+) -> Result<(), String> {
+    let parsed = parse_export_args(args)?;
+
+    // Never hand out a bundle (signed or not) for a chain that is already broken.
+    let report = backend.verify_chain()?;
+    if let Some(found) = &report.first_break {
+        return Err(format!(
+            "refusing to export: audit chain broken at event {}: {}",
+            found.event_id, found.reason
+        ));
+    }
+
+    let chain = backend.chain_events()?;
+    let mut bundle = ExportBundle {
+        version: 1,
+        head_hash: &report.head_hash,
+        event_count: chain.len(),
+        events: chain
+            .iter()
+            .map(|chained| JsonChainedEvent {
+                event: to_json_event(&chained.event),
+                prev_hash: &chained.prev_hash,
+                chain_hash: &chained.chain_hash,
+            })
+            .collect(),
+        signature: None,
+    };
+
+    if parsed.sign {
+        let key = resolve_signing_key(&parsed.key_file)?;
+        let unsigned = serde_json::to_vec_pretty(&bundle).map_err(|err| err.to_string())?;
+        bundle.signature = Some(ExportSignature {
+            algorithm: "hmac-sha256",
+            value: hex::encode(hmac_sha256(key.as_bytes(), &unsigned)?),
+        });
+    }
+
+    serde_json::to_writer_pretty(&mut *stdout, &bundle).map_err(|err| err.to_string())?;
+    writeln!(stdout).map_err(|err| err.to_string())?;
+    Ok(())
+}
+
+fn parse_export_args(args: &[String]) -> Result<ExportArgs, String> {
+    let mut parsed = ExportArgs::default();
+    let mut index = 0usize;
+    while let Some(token) = args.get(index) {
+        match token.as_str() {
+            "-h" | "--help" | "help" => return Err(EXPORT_HELP_TEXT.to_string()),
+            // The bundle is always JSON.
+            "--json" | "--jsonl" | "--quiet" => index += 1,
+            "--sign" => {
+                parsed.sign = true;
+                index += 1;
+            }
+            "--key-file" => {
+                parsed.key_file = take_value(args, index, "--key-file")?;
+                index += 2;
+            }
+            other => {
+                return Err(format!(
+                    "error: unknown argument for audit export: '{other}'"
+                ))
+            }
+        }
+    }
+    if !parsed.key_file.is_empty() && !parsed.sign {
+        return Err("error: --key-file requires --sign".to_string());
+    }
+    Ok(parsed)
+}
+
+fn resolve_signing_key(key_file: &str) -> Result<String, String> {
+    let key = if key_file.trim().is_empty() {
+        std::env::var(AUDIT_SIGNING_KEY_ENV).unwrap_or_default()
+    } else {
+        std::fs::read_to_string(key_file.trim())
+            .map_err(|err| format!("read signing key {}: {err}", key_file.trim()))?
+    };
+    let key = key.trim().to_string();
+    if key.is_empty() {
+        return Err(format!(
+            "--sign requires a signing key: set {AUDIT_SIGNING_KEY_ENV} or pass --key-file"
+        ));
+    }
+    Ok(key)
+}
+
+/// HMAC-SHA256 (RFC 2104).
+fn hmac_sha256(key: &[u8], message: &[u8]) -> Result<[u8; 32], String> {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(key).map_err(|err| format!("invalid signing key: {err}"))?;
+    mac.update(message);
+    Ok(mac.finalize().into_bytes().into())
+}
+
 fn to_json_event(event: &AuditEvent) -> JsonAuditEvent<'_> {
     JsonAuditEvent {
         id: &event.id,
@@ -799,11 +1123,19 @@ const HELP_TEXT: &str = "View the Forge audit log
 
 Usage:
   forge audit [flags]
+  forge audit verify [--json]
+  forge audit export [--sign] [--key-file path]
 
 Examples:
   forge audit --since 1h
   forge audit --type agent.state_changed --entity-type agent
   forge audit --action message.dispatched --limit 200
+  forge audit verify
+  FORGE_AUDIT_SIGNING_KEY=... forge audit export --sign > audit-bundle.json
+
+Subcommands:
+  verify                    walk the event hash chain and report the first tampered event
+  export                    write every event with its chain hashes as a JSON bundle
 
 Flags:
       --type string         filter by event type (comma-separated)
@@ -817,10 +1149,34 @@ Flags:
       --json                output in JSON format
       --jsonl               output in JSON Lines format";
 
+const VERIFY_HELP_TEXT: &str = "Verify the audit log hash chain
+
+Walks every event in insertion order and reports the first event whose
+content or previous-hash link no longer matches. Exits non-zero on a break.
+
+Usage:
+  forge audit verify [flags]
+
+Flags:
+      --json                output the verification report as JSON";
+
+const EXPORT_HELP_TEXT: &str = "Export the audit log as a tamper-evident bundle
+
+Writes every event with its chain hashes as JSON. With --sign, the bundle
+carries an HMAC-SHA256 over the unsigned bundle exactly as printed without
+--sign. Refuses to export a chain that fails verification.
+
+Usage:
+  forge audit export [flags]
+
+Flags:
+      --sign                sign the bundle with FORGE_AUDIT_SIGNING_KEY
+      --key-file string     read the signing key from a file instead";
+
 #[cfg(test)]
 mod tests {
     use super::{
-        parse_event_types, parse_since, run_for_test, AuditEvent, CommandOutput,
+        hmac_sha256, parse_event_types, parse_since, run_for_test, AuditEvent, CommandOutput,
         InMemoryAuditBackend, SqliteAuditBackend,
     };
     use std::path::PathBuf;
@@ -965,6 +1321,132 @@ mod tests {
             .contains("No events matched the current filters."));
     }
 
+    fn seed_chain(db_path: &std::path::Path) -> forge_db::Db {
+        let mut db = forge_db::Db::open(forge_db::Config::new(db_path))
+            .unwrap_or_else(|err| panic!("open db: {err}"));
+        db.migrate_up()
+            .unwrap_or_else(|err| panic!("migrate db: {err}"));
+        let repo = forge_db::event_repository::EventRepository::new(&db);
+        for (id, state) in [
+            ("evt-a", "starting"),
+            ("evt-b", "running"),
+            ("evt-c", "stopped"),
+        ] {
+            let mut event = forge_db::event_repository::Event {
+                id: id.to_string(),
+                timestamp: "2026-01-01T00:00:00Z".to_string(),
+                event_type: "agent.state_changed".to_string(),
+                entity_type: "agent".to_string(),
+                entity_id: "agent-1".to_string(),
+                payload: format!(r#"{{"state":"{state}"}}"#),
+                metadata: None,
+            };
+            repo.create(&mut event)
+                .unwrap_or_else(|err| panic!("create event {id}: {err}"));
+        }
+        db
+    }
+
+    #[test]
+    fn audit_verify_detects_altered_middle_record() {
+        let db_path = temp_db_path("verify-tamper");
+        let db = seed_chain(&db_path);
+        let backend = SqliteAuditBackend::new(db_path.clone());
+
+        let out = run_for_test(&["audit", "verify"], &backend);
+        assert_success(&out);
+        assert!(out.stdout.contains("Audit chain intact: 3 events verified"));
+
+        db.conn()
+            .execute(
+                "UPDATE events SET payload_json = '{\"state\":\"idle\"}' WHERE id = 'evt-b'",
+                [],
+            )
+            .unwrap_or_else(|err| panic!("tamper: {err}"));
+
+        let out = run_for_test(&["audit", "verify"], &backend);
+        assert_eq!(out.exit_code, 1);
+        assert!(
+            out.stderr.contains("audit chain broken at event evt-b"),
+            "stderr: {}",
+            out.stderr
+        );
+
+        let out = run_for_test(&["audit", "export", "--sign"], &backend);
+        assert_eq!(out.exit_code, 1);
+        assert!(out.stderr.contains("refusing to export"));
+
+        let _ = std::fs::remove_file(db_path);
+    }
+
+    #[test]
+    fn audit_export_sign_covers_unsigned_bundle() {
+        let db_path = temp_db_path("export-sign");
+        let _db = seed_chain(&db_path);
+        let key_path = temp_db_path("export-key");
+        std::fs::write(&key_path, "audit-secret\n")
+            .unwrap_or_else(|err| panic!("write key: {err}"));
+        let backend = SqliteAuditBackend::new(db_path.clone());
+
+        let unsigned = run_for_test(&["audit", "export"], &backend);
+        assert_success(&unsigned);
+        assert!(!unsigned.stdout.contains("\"signature\""));
+
+        let key_arg = key_path.display().to_string();
+        let out = run_for_test(
+            &["audit", "export", "--sign", "--key-file", &key_arg],
+            &backend,
+        );
+        assert_success(&out);
+
+        let bundle: serde_json::Value =
+            serde_json::from_str(&out.stdout).unwrap_or_else(|err| panic!("parse bundle: {err}"));
+        assert_eq!(bundle["event_count"], 3);
+        assert_eq!(bundle["events"][1]["id"], "evt-b");
+        assert_eq!(
+            bundle["events"][1]["prev_hash"],
+            bundle["events"][0]["chain_hash"]
+        );
+        assert_eq!(bundle["head_hash"], bundle["events"][2]["chain_hash"]);
+        assert_eq!(bundle["signature"]["algorithm"], "hmac-sha256");
+        assert_eq!(
+            bundle["signature"]["value"],
+            hex::encode(
+                hmac_sha256(b"audit-secret", unsigned.stdout.trim_end().as_bytes())
+                    .unwrap_or_else(|err| panic!("hmac: {err}"))
+            )
+        );
+
+        let out = run_for_test(&["audit", "export", "--key-file", &key_arg], &backend);
+        assert_eq!(out.exit_code, 1);
+        assert_eq!(out.stderr, "error: --key-file requires --sign\n");
+
+        let _ = std::fs::remove_file(db_path);
+        let _ = std::fs::remove_file(key_path);
+    }
+
+    #[test]
+    fn hmac_sha256_matches_rfc4231() {
+        assert_eq!(
+            hex::encode(
+                hmac_sha256(b"Jefe", b"what do ya want for nothing?")
+                    .unwrap_or_else(|err| panic!("hmac: {err}"))
+            ),
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+
+    #[test]
+    fn audit_verify_requires_chained_backend() {
+        // Global flags are forwarded ahead of the subcommand.
+        let out = run_for_test(
+            &["audit", "--json", "verify"],
+            &InMemoryAuditBackend::default(),
+        );
+        assert_eq!(out.exit_code, 1);
+        assert_eq!(out.stderr, "audit backend does not record a hash chain\n");
+    }
+
     fn assert_success(out: &CommandOutput) {
         assert_eq!(out.exit_code, 0);
         assert!(out.stderr.is_empty(), "unexpected stderr: {}", out.stderr);
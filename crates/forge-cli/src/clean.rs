@@ -2,6 +2,7 @@ use std::env;
 use std::io::Write;
 use std::path::PathBuf;
 
+use chrono::{DateTime, Utc};
 use serde::Serialize;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -17,6 +18,7 @@ pub enum LoopState {
     Running,
     Sleeping,
     Waiting,
+    Paused,
     Stopped,
     Error,
 }
@@ -30,6 +32,81 @@ pub struct LoopRecord {
     pub profile: String,
     pub tags: Vec<String>,
     pub state: LoopState,
+    pub last_exit_code: Option<i64>,
+    /// RFC3339 timestamp of the last state change; empty when unknown.
+    pub updated_at: String,
+}
+
+/// Inactive loop states that `--state` can target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CleanState {
+    /// Stopped after a successful final run.
+    Completed,
+    /// Stopped in the error state.
+    Failed,
+    /// Stopped for any other reason (manual stop, stop condition, ...).
+    Stopped,
+}
+
+impl CleanState {
+    fn parse(value: &str) -> Result<Self, String> {
+        match value.trim() {
+            "completed" => Ok(Self::Completed),
+            "failed" => Ok(Self::Failed),
+            "stopped" => Ok(Self::Stopped),
+            other => Err(format!(
+                "error: invalid value for --state: '{other}' (expected completed, failed, or stopped)"
+            )),
+        }
+    }
+
+    /// Classify a loop; `None` for loops that are still active.
+    pub fn of(entry: &LoopRecord) -> Option<Self> {
+        match entry.state {
+            LoopState::Error => Some(Self::Failed),
+            LoopState::Stopped if entry.last_exit_code == Some(0) => Some(Self::Completed),
+            LoopState::Stopped => Some(Self::Stopped),
+            LoopState::Pending
+            | LoopState::Running
+            | LoopState::Sleeping
+            | LoopState::Waiting
+            | LoopState::Paused => None,
+        }
+    }
+}
+
+/// Which loops `forge clean` should consider for removal.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CleanFilter {
+    pub selector: LoopSelector,
+    /// Only loops last updated before this instant.
+    pub updated_before: Option<DateTime<Utc>>,
+    /// Restrict to these states; empty means every inactive state.
+    pub states: Vec<CleanState>,
+}
+
+impl CleanFilter {
+    fn matches(&self, entry: &LoopRecord) -> bool {
+        let Some(state) = CleanState::of(entry) else {
+            return false;
+        };
+        if !self.states.is_empty() && !self.states.contains(&state) {
+            return false;
+        }
+        match self.updated_before {
+            None => true,
+            // Loops with no recorded update time are never considered old.
+            Some(cutoff) => DateTime::parse_from_rfc3339(&entry.updated_at)
+                .is_ok_and(|updated| updated.with_timezone(&Utc) < cutoff),
+        }
+    }
+}
+
+/// Loops eligible for removal plus the number of active loops passed over.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CleanCandidates {
+    pub candidates: Vec<LoopRecord>,
+    pub skipped: usize,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
@@ -43,6 +120,19 @@ pub struct LoopSelector {
 pub trait LoopBackend {
     fn select_loops(&self, selector: &LoopSelector) -> Result<Vec<LoopRecord>, String>;
     fn delete_loop(&mut self, loop_id: &str) -> Result<(), String>;
+
+    /// Select loops matching `filter` without modifying anything.
+    fn clean_candidates(&self, filter: &CleanFilter) -> Result<CleanCandidates, String> {
+        let mut out = CleanCandidates::default();
+        for entry in self.select_loops(&filter.selector)? {
+            if CleanState::of(&entry).is_none() {
+                out.skipped += 1;
+            } else if filter.matches(&entry) {
+                out.candidates.push(entry);
+            }
+        }
+        Ok(out)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -137,6 +227,8 @@ impl LoopBackend for SqliteCleanBackend {
                 profile: profile_name,
                 tags: entry.tags,
                 state: map_loop_state(&entry.state),
+                last_exit_code: entry.last_exit_code,
+                updated_at: entry.updated_at,
             });
         }
 
@@ -198,7 +290,18 @@ struct ParsedArgs {
     json: bool,
     jsonl: bool,
     quiet: bool,
-    selector: LoopSelector,
+    dry_run: bool,
+    filter: CleanFilter,
+}
+
+#[derive(Debug, Serialize)]
+struct CleanDryRunResult<'a> {
+    dry_run: bool,
+    would_remove: usize,
+    loop_ids: Vec<&'a str>,
+    names: Vec<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    skipped: Option<usize>,
 }
 
 #[derive(Debug, Serialize)]
@@ -259,17 +362,13 @@ fn execute(
     stdout: &mut dyn Write,
 ) -> Result<(), String> {
     let parsed = parse_args(args)?;
-    let loops = backend.select_loops(&parsed.selector)?;
+    let CleanCandidates {
+        candidates: cleanable,
+        skipped,
+    } = backend.clean_candidates(&parsed.filter)?;
 
-    let mut cleanable = Vec::new();
-    let mut skipped = 0usize;
-    for entry in loops {
-        match entry.state {
-            LoopState::Stopped | LoopState::Error => cleanable.push(entry),
-            LoopState::Pending | LoopState::Running | LoopState::Sleeping | LoopState::Waiting => {
-                skipped += 1
-            }
-        }
+    if parsed.dry_run {
+        return write_dry_run(stdout, &cleanable, skipped, &parsed);
     }
 
     if cleanable.is_empty() {
@@ -309,6 +408,36 @@ fn execute(
     Ok(())
 }
 
+fn write_dry_run(
+    stdout: &mut dyn Write,
+    cleanable: &[LoopRecord],
+    skipped: usize,
+    parsed: &ParsedArgs,
+) -> Result<(), String> {
+    if parsed.json || parsed.jsonl {
+        let payload = CleanDryRunResult {
+            dry_run: true,
+            would_remove: cleanable.len(),
+            loop_ids: cleanable.iter().map(|entry| entry.id.as_str()).collect(),
+            names: cleanable.iter().map(|entry| entry.name.as_str()).collect(),
+            skipped: (skipped > 0).then_some(skipped),
+        };
+        if parsed.jsonl {
+            serde_json::to_writer(&mut *stdout, &payload).map_err(|err| err.to_string())?;
+        } else {
+            serde_json::to_writer_pretty(&mut *stdout, &payload).map_err(|err| err.to_string())?;
+        }
+        writeln!(stdout).map_err(|err| err.to_string())?;
+        return Ok(());
+    }
+
+    writeln!(stdout, "Would remove {} loop(s):", cleanable.len()).map_err(|err| err.to_string())?;
+    for entry in cleanable {
+        writeln!(stdout, "  {} ({})", entry.name, entry.id).map_err(|err| err.to_string())?;
+    }
+    Ok(())
+}
+
 fn write_json(
     stdout: &mut dyn Write,
     cleanable: &[LoopRecord],
@@ -356,7 +485,8 @@ fn parse_args(args: &[String]) -> Result<ParsedArgs, String> {
     let mut json = false;
     let mut jsonl = false;
     let mut quiet = false;
-    let mut selector = LoopSelector::default();
+    let mut dry_run = false;
+    let mut filter = CleanFilter::default();
     while let Some(token) = args.get(index) {
         match token.as_str() {
             "--json" => {
@@ -371,20 +501,40 @@ fn parse_args(args: &[String]) -> Result<ParsedArgs, String> {
                 quiet = true;
                 index += 1;
             }
+            "--dry-run" => {
+                dry_run = true;
+                index += 1;
+            }
+            "--older-than" => {
+                let raw = take_value(args, index, "--older-than")?;
+                let age = parse_age(&raw)?;
+                let cutoff = Utc::now().checked_sub_signed(age).ok_or_else(|| {
+                    format!("error: invalid value for --older-than: '{}'", raw.trim())
+                })?;
+                filter.updated_before = Some(cutoff);
+                index += 2;
+            }
+            "--state" => {
+                let state = CleanState::parse(&take_value(args, index, "--state")?)?;
+                if !filter.states.contains(&state) {
+                    filter.states.push(state);
+                }
+                index += 2;
+            }
             "--repo" => {
-                selector.repo = take_value(args, index, "--repo")?;
+                filter.selector.repo = take_value(args, index, "--repo")?;
                 index += 2;
             }
             "--pool" => {
-                selector.pool = take_value(args, index, "--pool")?;
+                filter.selector.pool = take_value(args, index, "--pool")?;
                 index += 2;
             }
             "--profile" => {
-                selector.profile = take_value(args, index, "--profile")?;
+                filter.selector.profile = take_value(args, index, "--profile")?;
                 index += 2;
             }
             "--tag" => {
-                selector.tag = take_value(args, index, "--tag")?;
+                filter.selector.tag = take_value(args, index, "--tag")?;
                 index += 2;
             }
             unknown => return Err(format!("error: unknown argument for clean: '{unknown}'")),
@@ -395,10 +545,31 @@ fn parse_args(args: &[String]) -> Result<ParsedArgs, String> {
         json,
         jsonl,
         quiet,
-        selector,
+        dry_run,
+        filter,
     })
 }
 
+/// Parse an age such as `90s`, `30m`, `12h`, `7d`, or `2w`.
+fn parse_age(raw: &str) -> Result<chrono::Duration, String> {
+    let trimmed = raw.trim();
+    let invalid = || format!("error: invalid value for --older-than: '{trimmed}'");
+    let split = trimmed
+        .find(|ch: char| !ch.is_ascii_digit())
+        .ok_or_else(invalid)?;
+    let (number, unit) = trimmed.split_at(split);
+    let value: i64 = number.parse().map_err(|_| invalid())?;
+    let age = match unit {
+        "s" => chrono::Duration::try_seconds(value),
+        "m" => chrono::Duration::try_minutes(value),
+        "h" => chrono::Duration::try_hours(value),
+        "d" => chrono::Duration::try_days(value),
+        "w" => chrono::Duration::try_weeks(value),
+        _ => None,
+    };
+    age.ok_or_else(invalid)
+}
+
 fn take_value(args: &[String], index: usize, flag: &str) -> Result<String, String> {
     args.get(index + 1)
         .cloned()
@@ -452,6 +623,7 @@ fn map_loop_state(state: &forge_db::loop_repository::LoopState) -> LoopState {
         forge_db::loop_repository::LoopState::Running => LoopState::Running,
         forge_db::loop_repository::LoopState::Sleeping => LoopState::Sleeping,
         forge_db::loop_repository::LoopState::Waiting => LoopState::Waiting,
+        forge_db::loop_repository::LoopState::Paused => LoopState::Paused,
         forge_db::loop_repository::LoopState::Stopped => LoopState::Stopped,
         forge_db::loop_repository::LoopState::Error => LoopState::Error,
     }
@@ -471,6 +643,8 @@ mod tests {
             profile: "codex".to_string(),
             tags: vec!["tag-a".to_string()],
             state: LoopState::Stopped,
+            last_exit_code: None,
+            updated_at: String::new(),
         }];
         let mut backend = InMemoryLoopBackend::with_loops(loops);
         let out = run_for_test(&["clean"], &mut backend);
@@ -489,6 +663,8 @@ mod tests {
             profile: "codex".to_string(),
             tags: vec!["tag-a".to_string()],
             state: LoopState::Running,
+            last_exit_code: None,
+            updated_at: String::new(),
         }];
         let mut backend = InMemoryLoopBackend::with_loops(loops);
         let out = run_for_test(&["clean"], &mut backend);
@@ -1,8 +1,13 @@
+use std::collections::BTreeMap;
 use std::env;
 use std::fs;
 use std::io::Write;
 use std::path::{Path, PathBuf};
 
+use forge_core::config::{
+    parse_profile_overlays, ConfigLayer, ConfigSource, ConfigWithProvenance, ACTIVE_PROFILE_FILE,
+    PROFILES_FILE,
+};
 use serde::Serialize;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -17,10 +22,21 @@ pub trait ConfigBackend {
     fn file_exists(&self, path: &Path) -> bool;
     fn create_dir_all(&self, path: &Path) -> Result<(), String>;
     fn write_file(&self, path: &Path, contents: &str) -> Result<(), String>;
+    /// Read a file, returning `None` when it does not exist.
+    fn read_file(&self, path: &Path) -> Result<Option<String>, String>;
+    /// Look up an environment variable (`FORGE_*` overrides).
+    fn env_var(&self, name: &str) -> Option<String>;
 }
 
 pub struct FilesystemConfigBackend;
 
+impl FilesystemConfigBackend {
+    /// Base config with the active profile overlay applied.
+    pub fn effective_config(&self) -> Result<EffectiveConfig, String> {
+        load_effective_config(self)
+    }
+}
+
 impl ConfigBackend for FilesystemConfigBackend {
     fn home_dir(&self) -> Result<PathBuf, String> {
         env::var("HOME")
@@ -39,14 +55,30 @@ impl ConfigBackend for FilesystemConfigBackend {
     fn write_file(&self, path: &Path, contents: &str) -> Result<(), String> {
         fs::write(path, contents).map_err(|err| format!("failed to write config file: {err}"))
     }
+
+    fn read_file(&self, path: &Path) -> Result<Option<String>, String> {
+        match fs::read_to_string(path) {
+            Ok(raw) => Ok(Some(raw)),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(format!("failed to read {}: {err}", path.display())),
+        }
+    }
+
+    fn env_var(&self, name: &str) -> Option<String> {
+        env::var(name).ok()
+    }
 }
 
 #[derive(Default)]
 pub struct InMemoryConfigBackend {
     pub home: Option<PathBuf>,
     pub existing_files: Vec<PathBuf>,
+    /// Seeded file contents for `read_file`; later writes take precedence.
+    pub file_contents: Vec<(PathBuf, String)>,
     pub created_dirs: std::cell::RefCell<Vec<PathBuf>>,
     pub written_files: std::cell::RefCell<Vec<(PathBuf, String)>>,
+    /// Environment seen by `env_var`.
+    pub env: Vec<(String, String)>,
 }
 
 impl ConfigBackend for InMemoryConfigBackend {
@@ -58,6 +90,7 @@ impl ConfigBackend for InMemoryConfigBackend {
 
     fn file_exists(&self, path: &Path) -> bool {
         self.existing_files.iter().any(|p| p == path)
+            || self.file_contents.iter().any(|(p, _)| p == path)
     }
 
     fn create_dir_all(&self, path: &Path) -> Result<(), String> {
@@ -71,6 +104,171 @@ impl ConfigBackend for InMemoryConfigBackend {
             .push((path.to_path_buf(), contents.to_string()));
         Ok(())
     }
+
+    fn read_file(&self, path: &Path) -> Result<Option<String>, String> {
+        if let Some((_, contents)) = self
+            .written_files
+            .borrow()
+            .iter()
+            .rev()
+            .find(|(p, _)| p == path)
+        {
+            return Ok(Some(contents.clone()));
+        }
+        Ok(self
+            .file_contents
+            .iter()
+            .find(|(p, _)| p == path)
+            .map(|(_, contents)| contents.clone()))
+    }
+
+    fn env_var(&self, name: &str) -> Option<String> {
+        self.env
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.clone())
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Profile overlays
+// ---------------------------------------------------------------------------
+
+/// The base config merged with the active profile overlay, if any.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EffectiveConfig {
+    pub path: PathBuf,
+    pub active_profile: Option<String>,
+    pub values: serde_yaml::Value,
+}
+
+fn config_dir(backend: &dyn ConfigBackend) -> Result<PathBuf, String> {
+    Ok(backend.home_dir()?.join(".config").join("forge"))
+}
+
+fn parse_yaml_file(path: &Path, raw: &str) -> Result<serde_yaml::Value, String> {
+    if raw.trim().is_empty() {
+        return Ok(serde_yaml::Value::Mapping(serde_yaml::Mapping::new()));
+    }
+    serde_yaml::from_str(raw).map_err(|err| format!("parse {}: {err}", path.display()))
+}
+
+/// Load the overlays from `profiles.yaml`, keyed by profile name.
+fn load_profiles(
+    backend: &dyn ConfigBackend,
+) -> Result<BTreeMap<String, serde_yaml::Value>, String> {
+    let path = config_dir(backend)?.join(PROFILES_FILE);
+    match backend.read_file(&path)? {
+        Some(raw) => parse_profile_overlays(&path, &raw),
+        None => Ok(BTreeMap::new()),
+    }
+}
+
+fn load_active_profile(backend: &dyn ConfigBackend) -> Result<Option<String>, String> {
+    let path = config_dir(backend)?.join(ACTIVE_PROFILE_FILE);
+    Ok(backend
+        .read_file(&path)?
+        .map(|raw| raw.trim().to_string())
+        .filter(|name| !name.is_empty()))
+}
+
+/// Recursively merge `overlay` into `base`; mappings merge key by key and
+/// any other value replaces the base value.
+fn merge_yaml(base: &mut serde_yaml::Value, overlay: serde_yaml::Value) {
+    match (base, overlay) {
+        (serde_yaml::Value::Mapping(base_map), serde_yaml::Value::Mapping(overlay_map)) => {
+            for (key, value) in overlay_map {
+                match base_map.get_mut(&key) {
+                    Some(existing) => merge_yaml(existing, value),
+                    None => {
+                        base_map.insert(key, value);
+                    }
+                }
+            }
+        }
+        (base, overlay) => *base = overlay,
+    }
+}
+
+/// The base config file and the active profile overlay, before merging.
+struct ConfigSources {
+    path: PathBuf,
+    active_profile: Option<String>,
+    base: serde_yaml::Value,
+    overlay: Option<serde_yaml::Value>,
+}
+
+/// The base config file; `FORGE_CONFIG_PATH` overrides the default location.
+fn config_file_path(backend: &dyn ConfigBackend) -> Result<PathBuf, String> {
+    if let Some(path) = backend
+        .env_var("FORGE_CONFIG_PATH")
+        .filter(|path| !path.trim().is_empty())
+    {
+        return Ok(PathBuf::from(path));
+    }
+    Ok(config_dir(backend)?.join("config.yaml"))
+}
+
+fn load_config_sources(backend: &dyn ConfigBackend) -> Result<ConfigSources, String> {
+    let path = config_file_path(backend)?;
+    let base = match backend.read_file(&path)? {
+        Some(raw) => parse_yaml_file(&path, &raw)?,
+        None => serde_yaml::Value::Mapping(serde_yaml::Mapping::new()),
+    };
+
+    let active_profile = load_active_profile(backend)?;
+    let overlay = match &active_profile {
+        Some(name) => {
+            let mut profiles = load_profiles(backend)?;
+            Some(profiles.remove(name).ok_or_else(|| {
+                format!("active config profile {name:?} is not defined in {PROFILES_FILE}")
+            })?)
+        }
+        None => None,
+    };
+
+    Ok(ConfigSources {
+        path,
+        active_profile,
+        base,
+        overlay,
+    })
+}
+
+/// Read the base config and apply the active profile overlay on top.
+pub fn load_effective_config(backend: &dyn ConfigBackend) -> Result<EffectiveConfig, String> {
+    let sources = load_config_sources(backend)?;
+    let mut values = sources.base;
+    if let Some(overlay) = sources.overlay {
+        merge_yaml(&mut values, overlay);
+    }
+
+    Ok(EffectiveConfig {
+        path: sources.path,
+        active_profile: sources.active_profile,
+        values,
+    })
+}
+
+/// Resolve the typed config with the same precedence the daemon's
+/// `load_layered_config` applies: defaults < config.yaml < active profile
+/// overlay < `FORGE_*` env, recording which layer each setting came from.
+pub fn resolve_config_with_origins(
+    backend: &dyn ConfigBackend,
+) -> Result<ConfigWithProvenance, String> {
+    let sources = load_config_sources(backend)?;
+    let mut layers = vec![ConfigLayer::from_yaml_value(
+        ConfigSource::File,
+        &sources.base,
+    )];
+    if let Some(overlay) = &sources.overlay {
+        layers.push(ConfigLayer::from_yaml_value(ConfigSource::Profile, overlay));
+    }
+    layers.push(ConfigLayer::from_env_lookup(|name| backend.env_var(name)));
+
+    let mut resolved = ConfigWithProvenance::resolve(&layers)?;
+    resolved.config.expand_paths();
+    Ok(resolved)
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -78,6 +276,8 @@ enum Command {
     Help,
     Init { force: bool },
     Path,
+    Show { show_origin: bool },
+    UseProfile { name: String },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -204,6 +404,98 @@ fn execute(
                 .map_err(|err| err.to_string())?;
             Ok(())
         }
+        Command::Show { show_origin: true } => {
+            let resolved = resolve_config_with_origins(backend)?;
+            let entries: Vec<ConfigOriginEntry> = resolved
+                .entries()
+                .into_iter()
+                .map(|(key, value, source)| ConfigOriginEntry {
+                    key: key.to_string(),
+                    value,
+                    source: source.as_str().to_string(),
+                })
+                .collect();
+            if parsed.json || parsed.jsonl {
+                write_json_output(stdout, &entries, parsed.jsonl)?;
+                return Ok(());
+            }
+            let width = entries
+                .iter()
+                .map(|entry| entry.key.len())
+                .max()
+                .unwrap_or(0);
+            for entry in &entries {
+                writeln!(
+                    stdout,
+                    "{:<width$}  {:<7}  {}",
+                    entry.key, entry.source, entry.value
+                )
+                .map_err(|err| err.to_string())?;
+            }
+            Ok(())
+        }
+        Command::Show { show_origin: false } => {
+            let effective = load_effective_config(backend)?;
+            if parsed.json || parsed.jsonl {
+                write_json_output(
+                    stdout,
+                    &ConfigShowResult {
+                        path: effective.path.display().to_string(),
+                        active_profile: effective.active_profile.clone(),
+                        config: effective.values.clone(),
+                    },
+                    parsed.jsonl,
+                )?;
+                return Ok(());
+            }
+
+            writeln!(stdout, "Config file: {}", effective.path.display())
+                .map_err(|err| err.to_string())?;
+            writeln!(
+                stdout,
+                "Active profile: {}",
+                effective.active_profile.as_deref().unwrap_or("(none)")
+            )
+            .map_err(|err| err.to_string())?;
+            writeln!(stdout).map_err(|err| err.to_string())?;
+            let text = serde_yaml::to_string(&effective.values).map_err(|err| err.to_string())?;
+            write!(stdout, "{text}").map_err(|err| err.to_string())?;
+            Ok(())
+        }
+        Command::UseProfile { name } => {
+            let profiles = load_profiles(backend)?;
+            if !profiles.contains_key(&name) {
+                if profiles.is_empty() {
+                    return Err(format!(
+                        "unknown config profile {name:?} (no profiles defined in {PROFILES_FILE})"
+                    ));
+                }
+                let available: Vec<&str> = profiles.keys().map(String::as_str).collect();
+                return Err(format!(
+                    "unknown config profile {name:?} (available: {})",
+                    available.join(", ")
+                ));
+            }
+
+            let previous = load_active_profile(backend)?;
+            let dir = config_dir(backend)?;
+            backend.create_dir_all(&dir)?;
+            backend.write_file(&dir.join(ACTIVE_PROFILE_FILE), &format!("{name}\n"))?;
+
+            if parsed.json || parsed.jsonl {
+                write_json_output(
+                    stdout,
+                    &ConfigUseProfileResult {
+                        active_profile: name,
+                        previous_profile: previous,
+                    },
+                    parsed.jsonl,
+                )?;
+                return Ok(());
+            }
+            writeln!(stdout, "Active profile: {name}").map_err(|err| err.to_string())?;
+            Ok(())
+        }
     }
 }
 
@@ -225,7 +517,9 @@ fn parse_args(args: &[String]) -> Result<ParsedArgs, String> {
     let mut json = false;
     let mut jsonl = false;
     let mut force = false;
+    let mut show_origin = false;
     let mut subcommand: Option<String> = None;
+    let mut positionals: Vec<String> = Vec::new();
 
     let mut idx = start;
     while idx < args.len() {
@@ -245,11 +539,18 @@ fn parse_args(args: &[String]) -> Result<ParsedArgs, String> {
                 idx += 1;
                 continue;
             }
+            "--show-origin" => {
+                show_origin = true;
+                idx += 1;
+                continue;
+            }
             _ => {}
         }
 
         if subcommand.is_none() {
             subcommand = Some(args[idx].clone());
+        } else if subcommand.as_deref() == Some("use-profile") && positionals.is_empty() {
+            positionals.push(args[idx].clone());
         } else {
             return Err(format!("unexpected argument: {}", args[idx]));
         }
@@ -259,11 +560,21 @@ fn parse_args(args: &[String]) -> Result<ParsedArgs, String> {
     if json && jsonl {
         return Err("error: --json and --jsonl cannot be used together".to_string());
     }
+    if show_origin && subcommand.as_deref() != Some("show") {
+        return Err("--show-origin is only valid with show".to_string());
+    }
 
     let command = match subcommand.as_deref() {
         None | Some("help") | Some("-h") | Some("--help") => Command::Help,
         Some("init") => Command::Init { force },
         Some("path") => Command::Path,
+        Some("show") => Command::Show { show_origin },
+        Some("use-profile") => match positionals.pop() {
+            Some(name) if !name.trim().is_empty() => Command::UseProfile {
+                name: name.trim().to_string(),
+            },
+            _ => return Err("usage: forge config use-profile <name>".to_string()),
+        },
         Some(other) => return Err(format!("unknown config subcommand: {other}")),
     };
 
@@ -279,6 +590,26 @@ struct ConfigPathResult {
     path: String,
 }
 
+#[derive(Debug, Serialize)]
+struct ConfigShowResult {
+    path: String,
+    active_profile: Option<String>,
+    config: serde_yaml::Value,
+}
+
+#[derive(Debug, Serialize)]
+struct ConfigOriginEntry {
+    key: String,
+    value: String,
+    source: String,
+}
+
+#[derive(Debug, Serialize)]
+struct ConfigUseProfileResult {
+    active_profile: String,
+    previous_profile: Option<String>,
+}
+
 #[derive(Debug, Serialize)]
 struct ConfigInitResult {
     path: String,
@@ -309,14 +640,32 @@ fn write_help(stdout: &mut dyn Write) -> std::io::Result<()> {
     )?;
     writeln!(stdout)?;
     writeln!(stdout, "Commands:")?;
-    writeln!(stdout, "  init    Create a default global config file")?;
-    writeln!(stdout, "  path    Print the global config file path")?;
+    writeln!(
+        stdout,
+        "  init                Create a default global config file"
+    )?;
+    writeln!(
+        stdout,
+        "  path                Print the global config file path"
+    )?;
+    writeln!(
+        stdout,
+        "  show                Print the effective config and active profile"
+    )?;
+    writeln!(
+        stdout,
+        "  use-profile <name>  Apply a named overlay from profiles.yaml"
+    )?;
     writeln!(stdout)?;
     writeln!(stdout, "Flags:")?;
     writeln!(
         stdout,
         "  -f, --force   Overwrite existing config file (init only)"
     )?;
+    writeln!(
+        stdout,
+        "  --show-origin Print each setting with its source: default, file, profile or env (show only)"
+    )?;
     Ok(())
 }
 
@@ -431,6 +780,11 @@ tui:
   # Default: 500ms
   # refresh_interval: 500ms
 
+  # When to refetch data: interval (every tick) or event-driven
+  # (only after a relevant loop/agent event)
+  # Default: interval
+  # refresh_mode: interval
+
   # Color theme: default, high-contrast, low-light, colorblind-safe, ocean, sunset
   # Default: default
   # theme: default
@@ -782,4 +1136,150 @@ mod tests {
         assert!(DEFAULT_GLOBAL_CONFIG.contains("agent_defaults:"));
         assert!(DEFAULT_GLOBAL_CONFIG.contains("event_retention:"));
     }
+
+    // -- profiles --
+
+    fn backend_with_profiles(home: &str) -> InMemoryConfigBackend {
+        let dir = PathBuf::from(home).join(".config").join("forge");
+        InMemoryConfigBackend {
+            home: Some(PathBuf::from(home)),
+            file_contents: vec![
+                (
+                    dir.join("config.yaml"),
+                    "logging:\n  level: info\ntui:\n  theme: default\n".to_string(),
+                ),
+                (
+                    dir.join("profiles.yaml"),
+                    "dev:\n  logging:\n    level: debug\nprod:\n  tui:\n    theme: high-contrast\n"
+                        .to_string(),
+                ),
+            ],
+            ..Default::default()
+        }
+    }
+
+    fn effective_value(backend: &InMemoryConfigBackend, section: &str, key: &str) -> String {
+        let effective = load_effective_config(backend).unwrap();
+        effective.values[section][key].as_str().unwrap().to_string()
+    }
+
+    #[test]
+    fn active_profile_overrides_base_and_switching_reverts() {
+        let backend = backend_with_profiles("/home/user");
+        assert_eq!(effective_value(&backend, "logging", "level"), "info");
+
+        let out = run_for_test(&["config", "use-profile", "dev"], &backend);
+        assert_eq!(out.exit_code, 0, "stderr: {}", out.stderr);
+        assert_eq!(out.stdout, "Active profile: dev\n");
+        assert_eq!(effective_value(&backend, "logging", "level"), "debug");
+        assert_eq!(effective_value(&backend, "tui", "theme"), "default");
+
+        let out = run_for_test(&["config", "use-profile", "prod"], &backend);
+        assert_eq!(out.exit_code, 0, "stderr: {}", out.stderr);
+        assert_eq!(effective_value(&backend, "logging", "level"), "info");
+        assert_eq!(effective_value(&backend, "tui", "theme"), "high-contrast");
+
+        let out = run_for_test(&["config", "show"], &backend);
+        assert_eq!(out.exit_code, 0, "stderr: {}", out.stderr);
+        assert!(out.stdout.contains("Active profile: prod"));
+        assert!(out.stdout.contains("theme: high-contrast"));
+        assert!(out.stdout.contains("level: info"));
+    }
+
+    #[test]
+    fn config_path_override_still_applies_active_profile() {
+        let mut backend = backend_with_profiles("/home/user");
+        backend.file_contents.push((
+            PathBuf::from("/srv/forge.yaml"),
+            "logging:\n  level: warn\nscheduler:\n  workflow_max_parallel: 2\n".to_string(),
+        ));
+        backend.env = vec![(
+            "FORGE_CONFIG_PATH".to_string(),
+            "/srv/forge.yaml".to_string(),
+        )];
+        assert_eq!(effective_value(&backend, "logging", "level"), "warn");
+
+        let out = run_for_test(&["config", "use-profile", "dev"], &backend);
+        assert_eq!(out.exit_code, 0, "stderr: {}", out.stderr);
+        let effective = load_effective_config(&backend).unwrap();
+        assert_eq!(effective.path, PathBuf::from("/srv/forge.yaml"));
+        assert_eq!(effective.values["logging"]["level"], "debug");
+        assert_eq!(effective.values["scheduler"]["workflow_max_parallel"], 2);
+    }
+
+    #[test]
+    fn unknown_profile_errors_without_changing_active() {
+        let backend = backend_with_profiles("/home/user");
+        let out = run_for_test(&["config", "use-profile", "dev"], &backend);
+        assert_eq!(out.exit_code, 0, "stderr: {}", out.stderr);
+
+        let out = run_for_test(&["config", "use-profile", "staging"], &backend);
+        assert_eq!(out.exit_code, 1);
+        assert!(out
+            .stderr
+            .contains("unknown config profile \"staging\" (available: dev, prod)"));
+        assert_eq!(backend.written_files.borrow().len(), 1);
+
+        let effective = load_effective_config(&backend).unwrap();
+        assert_eq!(effective.active_profile.as_deref(), Some("dev"));
+    }
+
+    #[test]
+    fn show_json_without_profile() {
+        let backend = backend_with_profiles("/home/user");
+        let out = run_for_test(&["config", "show", "--json"], &backend);
+        assert_eq!(out.exit_code, 0, "stderr: {}", out.stderr);
+        let parsed: serde_json::Value = serde_json::from_str(&out.stdout).unwrap();
+        assert!(parsed["active_profile"].is_null());
+        assert_eq!(parsed["config"]["logging"]["level"], "info");
+    }
+
+    #[test]
+    fn show_origin_reports_file_profile_env_and_default() {
+        let mut backend = backend_with_profiles("/home/user");
+        backend.env = vec![("FORGE_SCHEDULER_MAX_RETRIES".to_string(), "9".to_string())];
+        let out = run_for_test(&["config", "use-profile", "dev"], &backend);
+        assert_eq!(out.exit_code, 0, "stderr: {}", out.stderr);
+
+        let out = run_for_test(&["config", "show", "--show-origin", "--json"], &backend);
+        assert_eq!(out.exit_code, 0, "stderr: {}", out.stderr);
+        let parsed: serde_json::Value = serde_json::from_str(&out.stdout).unwrap();
+        let entries = parsed.as_array().unwrap();
+        let find = |key: &str| {
+            entries
+                .iter()
+                .find(|entry| entry["key"] == key)
+                .map(|entry| (entry["value"].clone(), entry["source"].clone()))
+                .unwrap()
+        };
+        assert_eq!(find("logging.level"), ("debug".into(), "profile".into()));
+        assert_eq!(find("tui.theme"), ("default".into(), "file".into()));
+        assert_eq!(find("scheduler.max_retries"), ("9".into(), "env".into()));
+        assert_eq!(find("logging.format"), ("console".into(), "default".into()));
+
+        let out = run_for_test(&["config", "show", "--show-origin"], &backend);
+        assert_eq!(out.exit_code, 0, "stderr: {}", out.stderr);
+        assert!(out
+            .stdout
+            .lines()
+            .any(|line| line.starts_with("logging.level") && line.ends_with("profile  debug")));
+    }
+
+    #[test]
+    fn show_origin_rejected_for_other_subcommands() {
+        let backend = backend_with_home("/home/user");
+        let out = run_for_test(&["config", "path", "--show-origin"], &backend);
+        assert_eq!(out.exit_code, 1);
+        assert!(out.stderr.contains("--show-origin is only valid with show"));
+    }
+
+    #[test]
+    fn use_profile_requires_name() {
+        let backend = backend_with_profiles("/home/user");
+        let out = run_for_test(&["config", "use-profile"], &backend);
+        assert_eq!(out.exit_code, 1);
+        assert!(out
+            .stderr
+            .contains("usage: forge config use-profile <name>"));
+    }
 }
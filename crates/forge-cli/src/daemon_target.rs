@@ -0,0 +1,52 @@
+const DAEMON_TARGET_ENV_KEYS: [&str; 2] = ["FORGE_DAEMON_TARGET", "FORGED_ADDR"];
+const DEFAULT_DAEMON_TARGET: &str = "http://127.0.0.1:50051";
+
+/// Resolve the forged gRPC endpoint: `explicit` first, then the environment,
+/// then the local default. Bare `host:port` values get an `http://` scheme.
+pub fn resolve_daemon_target(explicit: &str) -> String {
+    if !explicit.trim().is_empty() {
+        return normalize_daemon_target(explicit);
+    }
+    for key in DAEMON_TARGET_ENV_KEYS {
+        if let Ok(value) = std::env::var(key) {
+            if !value.trim().is_empty() {
+                return normalize_daemon_target(&value);
+            }
+        }
+    }
+    DEFAULT_DAEMON_TARGET.to_string()
+}
+
+fn normalize_daemon_target(raw: &str) -> String {
+    let trimmed = raw.trim();
+    if trimmed.contains("://") {
+        return trimmed.to_string();
+    }
+    format!("http://{trimmed}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{normalize_daemon_target, resolve_daemon_target};
+
+    #[test]
+    fn explicit_target_wins_and_gets_a_scheme() {
+        assert_eq!(
+            resolve_daemon_target(" 127.0.0.1:7777 "),
+            "http://127.0.0.1:7777"
+        );
+        assert_eq!(
+            resolve_daemon_target("https://daemon.local:9999"),
+            "https://daemon.local:9999"
+        );
+    }
+
+    #[test]
+    fn normalize_keeps_existing_scheme() {
+        assert_eq!(
+            normalize_daemon_target("unix:///tmp/forged.sock"),
+            "unix:///tmp/forged.sock"
+        );
+        assert_eq!(normalize_daemon_target("host:1"), "http://host:1");
+    }
+}
@@ -4,7 +4,7 @@ use std::path::{Path, PathBuf};
 use std::process::Command;
 
 use forge_agent::capability::capability_for_harness;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use tabwriter::TabWriter;
 
 /// Status of a single diagnostic check, matching Go's `DoctorCheckStatus`.
@@ -26,6 +26,16 @@ impl CheckStatus {
         }
     }
 
+    fn parse(value: &str) -> Option<Self> {
+        match value {
+            "pass" => Some(Self::Pass),
+            "warn" => Some(Self::Warn),
+            "fail" => Some(Self::Fail),
+            "skip" => Some(Self::Skip),
+            _ => None,
+        }
+    }
+
     fn icon(&self) -> &'static str {
         match self {
             Self::Pass => "\u{2713}", // ✓
@@ -36,6 +46,10 @@ impl CheckStatus {
     }
 }
 
+/// File in the forge data directory where the TUI leaves its latest runtime
+/// performance gate checks for `forge doctor` to report.
+pub const TUI_PERFORMANCE_REPORT_FILE: &str = "tui-performance.json";
+
 /// A single diagnostic check result, matching Go's `DoctorCheck`.
 #[derive(Debug, Clone)]
 pub struct DoctorCheck {
@@ -428,6 +442,10 @@ impl FilesystemDoctorBackend {
             },
         });
 
+        checks.extend(tui_performance_checks(
+            &data_dir.join(TUI_PERFORMANCE_REPORT_FILE),
+        ));
+
         let db_path = data_dir.join("forge.db");
         checks.push(if db_path.exists() {
             DoctorCheck {
@@ -467,6 +485,63 @@ impl DoctorBackend for FilesystemDoctorBackend {
     }
 }
 
+/// Checks recorded by the TUI; absent when the TUI has not written a report.
+fn tui_performance_checks(path: &Path) -> Vec<DoctorCheck> {
+    if !path.exists() {
+        return Vec::new();
+    }
+    match read_check_report(path) {
+        Ok(checks) => checks,
+        Err(err) => vec![DoctorCheck {
+            category: "tui-performance".to_string(),
+            name: "report".to_string(),
+            status: CheckStatus::Warn,
+            details: Some(path.display().to_string()),
+            error: Some(err),
+        }],
+    }
+}
+
+/// Write checks produced outside the CLI (e.g. by the TUI) for a later
+/// `forge doctor` run. The report is written to a temp file and renamed into
+/// place, so a concurrent `forge doctor` never reads a partial report.
+pub fn write_check_report(path: &Path, checks: &[DoctorCheck]) -> Result<(), String> {
+    let rows = checks.iter().map(check_json).collect::<Vec<_>>();
+    let body = serde_json::to_string_pretty(&rows).map_err(|err| err.to_string())?;
+    if let Some(parent) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent)
+            .map_err(|err| format!("create {}: {err}", parent.display()))?;
+    }
+    let mut temp = path.as_os_str().to_os_string();
+    temp.push(format!(".tmp-{}", std::process::id()));
+    let temp = PathBuf::from(temp);
+    std::fs::write(&temp, body).map_err(|err| format!("write {}: {err}", temp.display()))?;
+    std::fs::rename(&temp, path).map_err(|err| {
+        let _ = std::fs::remove_file(&temp);
+        format!("rename {} -> {}: {err}", temp.display(), path.display())
+    })
+}
+
+pub fn read_check_report(path: &Path) -> Result<Vec<DoctorCheck>, String> {
+    let body =
+        std::fs::read_to_string(path).map_err(|err| format!("read {}: {err}", path.display()))?;
+    let rows: Vec<StoredDoctorCheck> =
+        serde_json::from_str(&body).map_err(|err| format!("parse {}: {err}", path.display()))?;
+    rows.into_iter()
+        .map(|row| {
+            let status = CheckStatus::parse(&row.status)
+                .ok_or_else(|| format!("invalid check status {:?}", row.status))?;
+            Ok(DoctorCheck {
+                category: row.category,
+                name: row.name,
+                status,
+                details: row.details,
+                error: row.error,
+            })
+        })
+        .collect()
+}
+
 fn lookup_path(path_value: &OsString, binary: &str) -> bool {
     std::env::split_paths(path_value).any(|dir| {
         let candidate = dir.join(binary);
@@ -635,6 +710,27 @@ struct DoctorCheckJson<'a> {
     error: Option<&'a str>,
 }
 
+#[derive(Debug, Deserialize)]
+struct StoredDoctorCheck {
+    category: String,
+    name: String,
+    status: String,
+    #[serde(default)]
+    details: Option<String>,
+    #[serde(default)]
+    error: Option<String>,
+}
+
+fn check_json(c: &DoctorCheck) -> DoctorCheckJson<'_> {
+    DoctorCheckJson {
+        category: &c.category,
+        name: &c.name,
+        status: c.status.as_str(),
+        details: c.details.as_deref(),
+        error: c.error.as_deref(),
+    }
+}
+
 #[derive(Debug, Serialize)]
 struct DoctorSummaryJson {
     total: usize,
@@ -646,17 +742,7 @@ struct DoctorSummaryJson {
 
 fn build_json_report(report: &DoctorReport) -> DoctorReportJson<'_> {
     DoctorReportJson {
-        checks: report
-            .checks
-            .iter()
-            .map(|c| DoctorCheckJson {
-                category: &c.category,
-                name: &c.name,
-                status: c.status.as_str(),
-                details: c.details.as_deref(),
-                error: c.error.as_deref(),
-            })
-            .collect(),
+        checks: report.checks.iter().map(check_json).collect(),
         summary: DoctorSummaryJson {
             total: report.summary.total,
             passed: report.summary.passed,
@@ -1385,6 +1471,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn filesystem_backend_includes_tui_performance_report() {
+        let temp = TempDir::new("doctor-tui-performance");
+        let data_dir = temp.path.join(".local").join("share").join("forge");
+        let report = data_dir.join(TUI_PERFORMANCE_REPORT_FILE);
+        write_check_report(
+            &report,
+            &[DoctorCheck {
+                category: "tui-performance".to_string(),
+                name: "render_ms".to_string(),
+                status: CheckStatus::Fail,
+                details: None,
+                error: Some("p95 48ms exceeds 33ms budget".to_string()),
+            }],
+        )
+        .unwrap_or_else(|err| panic!("write report: {err}"));
+        let entries = std::fs::read_dir(&data_dir)
+            .unwrap_or_else(|err| panic!("read data dir: {err}"))
+            .filter_map(|entry| entry.ok().map(|entry| entry.file_name()))
+            .collect::<Vec<_>>();
+        assert_eq!(entries, vec![OsString::from(TUI_PERFORMANCE_REPORT_FILE)]);
+
+        let backend =
+            FilesystemDoctorBackend::new(Some(temp.path.clone()), Some(OsString::from("")));
+        let checks = backend.run_checks();
+
+        let render = find_check(&checks, "tui-performance", "render_ms");
+        assert_eq!(render.status, CheckStatus::Fail);
+        assert_eq!(
+            render.error.as_deref(),
+            Some("p95 48ms exceeds 33ms budget")
+        );
+    }
+
     #[test]
     fn filesystem_backend_reports_harness_capability_matrix() {
         let temp = TempDir::new("doctor-capability-matrix");
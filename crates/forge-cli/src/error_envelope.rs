@@ -1,5 +1,6 @@
 use std::io::Write;
 
+use forge_core::error::codes;
 use serde::Serialize;
 
 /// JSON/JSONL error response shape matching Go's `ErrorEnvelope`.
@@ -110,7 +111,7 @@ fn classify_error(message: &str) -> Classification {
 
     if lower.contains("ambiguous") {
         return Classification {
-            code: "ERR_AMBIGUOUS",
+            code: codes::AMBIGUOUS,
             hint: Some("Use a longer prefix or full ID.".to_string()),
             details: None,
             exit_code: 1,
@@ -130,7 +131,7 @@ fn classify_error(message: &str) -> Classification {
             None
         };
         return Classification {
-            code: "ERR_NOT_FOUND",
+            code: codes::NOT_FOUND,
             hint: if hint.is_empty() { None } else { Some(hint) },
             details,
             exit_code: 1,
@@ -138,7 +139,7 @@ fn classify_error(message: &str) -> Classification {
     }
     if lower.contains("already exists") {
         return Classification {
-            code: "ERR_EXISTS",
+            code: codes::EXISTS,
             hint: None,
             details: None,
             exit_code: 1,
@@ -146,7 +147,7 @@ fn classify_error(message: &str) -> Classification {
     }
     if lower.contains("unknown flag") {
         return Classification {
-            code: "ERR_INVALID_FLAG",
+            code: codes::INVALID_FLAG,
             hint: None,
             details: None,
             exit_code: 1,
@@ -158,7 +159,7 @@ fn classify_error(message: &str) -> Classification {
         || lower.contains("must")
     {
         return Classification {
-            code: "ERR_INVALID",
+            code: codes::INVALID,
             hint: None,
             details: None,
             exit_code: 1,
@@ -169,7 +170,7 @@ fn classify_error(message: &str) -> Classification {
         || lower.contains("connection")
     {
         return Classification {
-            code: "ERR_OPERATION_FAILED",
+            code: codes::OPERATION_FAILED,
             hint: None,
             details: None,
             exit_code: 2,
@@ -177,7 +178,7 @@ fn classify_error(message: &str) -> Classification {
     }
     if lower.contains("failed to") || lower.contains("unable to") {
         return Classification {
-            code: "ERR_OPERATION_FAILED",
+            code: codes::OPERATION_FAILED,
             hint: None,
             details: None,
             exit_code: 2,
@@ -185,7 +186,7 @@ fn classify_error(message: &str) -> Classification {
     }
 
     Classification {
-        code: "ERR_UNKNOWN",
+        code: codes::UNKNOWN,
         hint: None,
         details: None,
         exit_code: 1,
@@ -2,13 +2,17 @@ use std::io::Write;
 use std::path::PathBuf;
 
 use chrono::TimeZone;
+use forge_core::event::{EntityType, EventType};
 use rusqlite::OptionalExtension;
 use serde::Deserialize;
 use serde::Serialize;
 
 use crate::context::{ContextBackend, FilesystemContextBackend};
+use crate::stop::DEFAULT_STOP_REASON;
 
 const EXPLAIN_EVENT_LIMIT: i64 = 48;
+const TIMELINE_SOURCE_LIMIT: i64 = 100;
+const TIMELINE_SUMMARY_MAX: usize = 96;
 const PERSISTENT_STALE_IDLE_SECONDS: i64 = 3600;
 
 // ---------------------------------------------------------------------------
@@ -191,6 +195,85 @@ pub struct AgentEventRecord {
     pub timestamp: String,
 }
 
+/// A loop record for explain, with its most recent operator stop request.
+#[derive(Debug, Clone)]
+pub struct LoopRecord {
+    pub id: String,
+    pub short_id: String,
+    pub name: String,
+    pub state: String,
+    pub stop_cause: Option<StopCause>,
+}
+
+/// Why an operator asked a loop to stop (`forge stop --reason`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct StopCause {
+    pub reason: String,
+    pub requested_at: String,
+}
+
+/// Latest stop cause recorded for a loop, if it was ever asked to stop.
+pub fn load_stop_cause(db: &forge_db::Db, loop_id: &str) -> Result<Option<StopCause>, String> {
+    let event_repo = forge_db::event_repository::EventRepository::new(db);
+    let event = match event_repo.latest_by_entity(
+        &EventType::LoopStopRequested.to_string(),
+        &EntityType::Loop.to_string(),
+        loop_id,
+    ) {
+        Ok(event) => event,
+        Err(err) if err.to_string().contains("no such table: events") => None,
+        Err(err) => return Err(err.to_string()),
+    };
+    Ok(event.map(|event| {
+        let reason = serde_json::from_str::<serde_json::Value>(&event.payload)
+            .ok()
+            .and_then(|payload| {
+                payload
+                    .get("reason")
+                    .and_then(serde_json::Value::as_str)
+                    .map(str::to_string)
+            })
+            .filter(|reason| !reason.trim().is_empty())
+            .unwrap_or_else(|| DEFAULT_STOP_REASON.to_string());
+        StopCause {
+            reason,
+            requested_at: event.timestamp,
+        }
+    }))
+}
+
+/// Where a timeline entry came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimelineSource {
+    Event,
+    Approval,
+    Transcript,
+    AgentEvent,
+    Queue,
+}
+
+impl TimelineSource {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Event => "event",
+            Self::Approval => "approval",
+            Self::Transcript => "transcript",
+            Self::AgentEvent => "agent_event",
+            Self::Queue => "queue",
+        }
+    }
+}
+
+/// One step in the causal history rendered by `explain --timeline`.
+#[derive(Debug, Clone)]
+pub struct TimelineEntry {
+    pub timestamp: String,
+    pub source: TimelineSource,
+    pub summary: String,
+    /// Source-specific status: approval status, event outcome, queue status.
+    pub status: String,
+}
+
 // ---------------------------------------------------------------------------
 // Backend trait
 // ---------------------------------------------------------------------------
@@ -221,6 +304,13 @@ pub trait ExplainBackend {
         agent_id: &str,
         limit: i64,
     ) -> Result<Vec<AgentEventRecord>, String>;
+
+    /// Collect events, approvals, transcripts and agent events that make up
+    /// the agent's timeline, in any order.
+    fn list_timeline(&self, agent_id: &str, limit: i64) -> Result<Vec<TimelineEntry>, String>;
+
+    /// Resolve a loop by short ID, ID or name; `None` when nothing matches.
+    fn resolve_loop(&self, target: &str) -> Result<Option<LoopRecord>, String>;
 }
 
 // ---------------------------------------------------------------------------
@@ -234,6 +324,8 @@ pub struct InMemoryExplainBackend {
     pub queue_items: Vec<QueueItemRecord>,
     pub accounts: Vec<(String, AccountRecord)>,
     pub agent_events: Vec<(String, AgentEventRecord)>,
+    pub timeline: Vec<(String, TimelineEntry)>,
+    pub loops: Vec<LoopRecord>,
     pub context_agent_id: Option<String>,
     pub workspace_first_agent_id: Option<String>,
 }
@@ -304,6 +396,28 @@ impl ExplainBackend for InMemoryExplainBackend {
             .take(max)
             .collect())
     }
+
+    fn list_timeline(&self, agent_id: &str, limit: i64) -> Result<Vec<TimelineEntry>, String> {
+        let max = if limit <= 0 { 100 } else { limit as usize };
+        let mut entries: Vec<TimelineEntry> = self
+            .timeline
+            .iter()
+            .filter(|(id, _)| id == agent_id)
+            .map(|(_, entry)| entry.clone())
+            .collect();
+        // Keep the newest entries when the limit applies, like the SQLite backend.
+        entries.sort_by_key(|entry| parse_timestamp_utc(&entry.timestamp));
+        let skip = entries.len().saturating_sub(max);
+        Ok(entries.split_off(skip))
+    }
+
+    fn resolve_loop(&self, target: &str) -> Result<Option<LoopRecord>, String> {
+        Ok(self
+            .loops
+            .iter()
+            .find(|entry| entry.short_id == target || entry.id == target || entry.name == target)
+            .cloned())
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -600,6 +714,176 @@ impl ExplainBackend for SqliteExplainBackend {
         }
         Ok(out)
     }
+
+    fn list_timeline(&self, agent_id: &str, limit: i64) -> Result<Vec<TimelineEntry>, String> {
+        if !self.db_path.exists() {
+            return Ok(Vec::new());
+        }
+        let max = if limit <= 0 { 100 } else { limit };
+
+        let db = self.open_db()?;
+        let mut entries = Vec::new();
+
+        let event_repo = forge_db::event_repository::EventRepository::new(&db);
+        match event_repo.list_recent_by_entity("agent", agent_id, max) {
+            Ok(events) => {
+                entries.extend(events.into_iter().map(|event| {
+                    let payload = event.payload.trim();
+                    let summary = if payload.is_empty() || payload == "null" {
+                        event.event_type.clone()
+                    } else {
+                        format!("{} {payload}", event.event_type)
+                    };
+                    TimelineEntry {
+                        timestamp: event.timestamp,
+                        source: TimelineSource::Event,
+                        summary,
+                        status: String::new(),
+                    }
+                }));
+            }
+            Err(err) if err.to_string().contains("no such table: events") => {}
+            Err(err) => return Err(err.to_string()),
+        }
+
+        entries.extend(list_approval_timeline(db.conn(), agent_id, max)?);
+
+        let transcript_repo = forge_db::transcript_repository::TranscriptRepository::new(&db);
+        match transcript_repo.list_by_agent(agent_id, max as usize) {
+            Ok(transcripts) => {
+                entries.extend(transcripts.into_iter().map(|transcript| {
+                    let lines = transcript.content.lines().count();
+                    let mut summary = format!("transcript captured ({lines} lines)");
+                    if transcript.repeat_count > 1 {
+                        summary.push_str(&format!(", unchanged x{}", transcript.repeat_count));
+                    }
+                    TimelineEntry {
+                        timestamp: transcript.captured_at,
+                        source: TimelineSource::Transcript,
+                        summary,
+                        status: String::new(),
+                    }
+                }));
+            }
+            Err(err) if err.to_string().contains("no such table: transcripts") => {}
+            Err(err) => return Err(err.to_string()),
+        }
+
+        entries.extend(
+            self.list_agent_events(agent_id, max)?
+                .into_iter()
+                .map(|event| TimelineEntry {
+                    timestamp: event.timestamp,
+                    source: TimelineSource::AgentEvent,
+                    summary: match event.detail.as_deref().map(str::trim) {
+                        Some(detail) if !detail.is_empty() => {
+                            format!("{}: {} ({detail})", event.kind, event.outcome)
+                        }
+                        _ => format!("{}: {}", event.kind, event.outcome),
+                    },
+                    status: event.outcome,
+                }),
+        );
+
+        Ok(entries)
+    }
+
+    fn resolve_loop(&self, target: &str) -> Result<Option<LoopRecord>, String> {
+        let trimmed = target.trim();
+        if trimmed.is_empty() || !self.db_path.exists() {
+            return Ok(None);
+        }
+
+        let db = self.open_db()?;
+        let loop_repo = forge_db::loop_repository::LoopRepository::new(&db);
+        let Some(entry) = loop_repo
+            .get_by_short_id(trimmed)
+            .or_else(|_| loop_repo.get(trimmed))
+            .or_else(|_| loop_repo.get_by_name(trimmed))
+            .ok()
+        else {
+            return Ok(None);
+        };
+
+        let stop_cause = load_stop_cause(&db, &entry.id)?;
+
+        Ok(Some(LoopRecord {
+            short_id: if entry.short_id.is_empty() {
+                entry.id.clone()
+            } else {
+                entry.short_id.clone()
+            },
+            id: entry.id,
+            name: entry.name,
+            state: entry.state.as_str().to_string(),
+            stop_cause,
+        }))
+    }
+}
+
+/// Approval requests plus their resolutions, one entry each. The limit keeps
+/// the newest approvals; callers sort the merged timeline.
+fn list_approval_timeline(
+    conn: &rusqlite::Connection,
+    agent_id: &str,
+    limit: i64,
+) -> Result<Vec<TimelineEntry>, String> {
+    let mut stmt = match conn.prepare(
+        "SELECT id, request_type, status, created_at, NULLIF(resolved_at, ''), COALESCE(resolved_by, '')
+         FROM approvals
+         WHERE agent_id = ?1
+         ORDER BY created_at DESC, id DESC
+         LIMIT ?2",
+    ) {
+        Ok(stmt) => stmt,
+        Err(err) if err.to_string().contains("no such table: approvals") => {
+            return Ok(Vec::new());
+        }
+        Err(err) => return Err(err.to_string()),
+    };
+
+    let rows = stmt
+        .query_map(rusqlite::params![agent_id, limit], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, String>(3)?,
+                row.get::<_, Option<String>>(4)?,
+                row.get::<_, String>(5)?,
+            ))
+        })
+        .map_err(|err| err.to_string())?;
+
+    let mut out = Vec::new();
+    for row in rows {
+        let (id, request_type, status, created_at, resolved_at, resolved_by) =
+            row.map_err(|err| err.to_string())?;
+        out.push(TimelineEntry {
+            timestamp: created_at,
+            source: TimelineSource::Approval,
+            summary: format!("approval {id} requested ({request_type})"),
+            status: if resolved_at.is_some() {
+                "requested".to_string()
+            } else {
+                status.clone()
+            },
+        });
+        if let Some(resolved_at) = resolved_at {
+            let by = if resolved_by.is_empty() {
+                String::new()
+            } else {
+                format!(" by {resolved_by}")
+            };
+            out.push(TimelineEntry {
+                timestamp: resolved_at,
+                source: TimelineSource::Approval,
+                summary: format!("approval {id} {status}{by}"),
+                status,
+            });
+        }
+    }
+    Ok(out)
 }
 
 fn resolve_agent_from_agents_table(
@@ -1005,6 +1289,7 @@ struct ParsedArgs {
     target: Option<String>,
     json: bool,
     jsonl: bool,
+    timeline: bool,
 }
 
 fn parse_args(args: &[String]) -> Result<ParsedArgs, String> {
@@ -1015,6 +1300,7 @@ fn parse_args(args: &[String]) -> Result<ParsedArgs, String> {
 
     let mut json = false;
     let mut jsonl = false;
+    let mut timeline = false;
     let mut target: Option<String> = None;
 
     while let Some(token) = args.get(index) {
@@ -1030,6 +1316,10 @@ fn parse_args(args: &[String]) -> Result<ParsedArgs, String> {
                 jsonl = true;
                 index += 1;
             }
+            "--timeline" => {
+                timeline = true;
+                index += 1;
+            }
             flag if flag.starts_with('-') => {
                 return Err(format!("error: unknown argument for explain: '{flag}'"));
             }
@@ -1051,6 +1341,7 @@ fn parse_args(args: &[String]) -> Result<ParsedArgs, String> {
         target,
         json,
         jsonl,
+        timeline,
     })
 }
 
@@ -1070,12 +1361,25 @@ fn execute(
         None => resolve_context_target(backend)?,
     };
 
+    if parsed.timeline {
+        return explain_timeline(&target, backend, &parsed, stdout);
+    }
+
     // If target starts with "qi_", explain as queue item
     if target.starts_with("qi_") {
         return explain_queue_item(&target, backend, &parsed, stdout);
     }
 
-    explain_agent(&target, backend, &parsed, stdout)
+    let agent = match backend.resolve_agent(&target) {
+        Ok(agent) => agent,
+        Err(agent_err) => {
+            if let Some(loop_record) = backend.resolve_loop(&target)? {
+                return explain_loop(&loop_record, &parsed, stdout);
+            }
+            return Err(agent_err);
+        }
+    };
+    explain_agent(agent, backend, &parsed, stdout)
 }
 
 fn resolve_context_target(backend: &dyn ExplainBackend) -> Result<String, String> {
@@ -1102,12 +1406,11 @@ fn resolve_context_target(backend: &dyn ExplainBackend) -> Result<String, String
 // ---------------------------------------------------------------------------
 
 fn explain_agent(
-    target: &str,
+    agent: AgentRecord,
     backend: &dyn ExplainBackend,
     parsed: &ParsedArgs,
     stdout: &mut dyn Write,
 ) -> Result<(), String> {
-    let agent = backend.resolve_agent(target)?;
     let queue_items = backend.list_queue(&agent.id)?;
     let events = backend.list_agent_events(&agent.id, EXPLAIN_EVENT_LIMIT)?;
 
@@ -1139,6 +1442,55 @@ fn explain_agent(
     write_agent_explanation_human(&explanation, stdout)
 }
 
+// ---------------------------------------------------------------------------
+// Loop explanation
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize)]
+struct LoopExplanationJson<'a> {
+    loop_id: &'a str,
+    short_id: &'a str,
+    name: &'a str,
+    state: &'a str,
+    stop_cause: Option<&'a StopCause>,
+}
+
+fn explain_loop(
+    loop_record: &LoopRecord,
+    parsed: &ParsedArgs,
+    stdout: &mut dyn Write,
+) -> Result<(), String> {
+    if parsed.json || parsed.jsonl {
+        let payload = LoopExplanationJson {
+            loop_id: &loop_record.id,
+            short_id: &loop_record.short_id,
+            name: &loop_record.name,
+            state: &loop_record.state,
+            stop_cause: loop_record.stop_cause.as_ref(),
+        };
+        return write_json(&payload, parsed.jsonl, stdout);
+    }
+
+    writeln!(
+        stdout,
+        "Loop {} ({}) is {}",
+        loop_record.name, loop_record.short_id, loop_record.state
+    )
+    .map_err(|err| err.to_string())?;
+    writeln!(stdout).map_err(|err| err.to_string())?;
+    match &loop_record.stop_cause {
+        Some(cause) => {
+            writeln!(stdout, "Stop cause: {}", cause.reason).map_err(|err| err.to_string())?;
+            writeln!(stdout, "Stop requested: {}", cause.requested_at)
+                .map_err(|err| err.to_string())?;
+        }
+        None => {
+            writeln!(stdout, "Stop cause: none recorded").map_err(|err| err.to_string())?;
+        }
+    }
+    Ok(())
+}
+
 #[derive(Debug, Clone, Serialize)]
 struct AgentExplanationJson<'a> {
     agent_id: &'a str,
@@ -1880,6 +2232,193 @@ fn write_queue_item_explanation_human(
     Ok(())
 }
 
+// ---------------------------------------------------------------------------
+// Causal timeline
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize)]
+struct TimelineJson<'a> {
+    agent_id: &'a str,
+    state: &'a str,
+    is_blocked: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    blocking_reason: Option<&'a str>,
+    timeline: Vec<TimelineEntryJson<'a>>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct TimelineEntryJson<'a> {
+    timestamp: &'a str,
+    source: &'a str,
+    summary: &'a str,
+    #[serde(skip_serializing_if = "str::is_empty")]
+    status: &'a str,
+    blocking: bool,
+}
+
+struct TimelineExplanation {
+    agent_id: String,
+    state: AgentState,
+    is_blocked: bool,
+    blocking_reason: Option<String>,
+    entries: Vec<TimelineEntry>,
+    /// Index into `entries` of the entry that holds the agent up.
+    blocker: Option<usize>,
+}
+
+fn explain_timeline(
+    target: &str,
+    backend: &dyn ExplainBackend,
+    parsed: &ParsedArgs,
+    stdout: &mut dyn Write,
+) -> Result<(), String> {
+    let (agent, item) = if target.starts_with("qi_") {
+        let item = backend.get_queue_item(target)?;
+        (backend.resolve_agent(&item.agent_id)?, Some(item))
+    } else {
+        (backend.resolve_agent(target)?, None)
+    };
+
+    let mut entries = backend.list_timeline(&agent.id, TIMELINE_SOURCE_LIMIT)?;
+    if let Some(item) = &item {
+        entries.push(TimelineEntry {
+            timestamp: item.created_at.clone(),
+            source: TimelineSource::Queue,
+            summary: format!(
+                "queue item {} created ({}, position {})",
+                item.id,
+                item.item_type.as_str(),
+                item.position
+            ),
+            status: item.status.as_str().to_string(),
+        });
+    }
+
+    let explanation = build_timeline_explanation(&agent, entries);
+    if parsed.json || parsed.jsonl {
+        return write_json(&explanation.to_json(), parsed.jsonl, stdout);
+    }
+    write_timeline_human(&explanation, stdout)
+}
+
+fn build_timeline_explanation(
+    agent: &AgentRecord,
+    mut entries: Vec<TimelineEntry>,
+) -> TimelineExplanation {
+    entries.sort_by(|left, right| {
+        let left_ts = parse_timestamp_utc(&left.timestamp);
+        let right_ts = parse_timestamp_utc(&right.timestamp);
+        left_ts
+            .cmp(&right_ts)
+            .then_with(|| left.timestamp.cmp(&right.timestamp))
+    });
+
+    let summary = build_agent_explanation(agent, &[]);
+    let blocker = if summary.is_blocked {
+        find_blocking_entry(&agent.state, &entries)
+    } else {
+        None
+    };
+    let blocking_reason = match blocker {
+        Some(index) => Some(entries[index].summary.clone()),
+        None => summary.block_reasons.first().cloned(),
+    };
+
+    TimelineExplanation {
+        agent_id: agent.id.clone(),
+        state: agent.state.clone(),
+        is_blocked: summary.is_blocked,
+        blocking_reason,
+        entries,
+        blocker,
+    }
+}
+
+/// Pick the most recent entry that explains the agent's blocked state.
+fn find_blocking_entry(state: &AgentState, entries: &[TimelineEntry]) -> Option<usize> {
+    let is_match = |entry: &TimelineEntry| -> bool {
+        let status = entry.status.to_ascii_lowercase();
+        let summary = entry.summary.to_ascii_lowercase();
+        match state {
+            AgentState::AwaitingApproval => {
+                entry.source == TimelineSource::Approval && status == "pending"
+            }
+            AgentState::RateLimited => status.contains("rate") || summary.contains("rate limit"),
+            AgentState::Error => status.contains("error") || status.contains("fail"),
+            AgentState::Paused => {
+                entry.source != TimelineSource::Transcript && summary.contains("pause")
+            }
+            _ => false,
+        }
+    };
+    entries.iter().rposition(is_match)
+}
+
+impl TimelineExplanation {
+    fn to_json(&self) -> TimelineJson<'_> {
+        TimelineJson {
+            agent_id: &self.agent_id,
+            state: self.state.as_str(),
+            is_blocked: self.is_blocked,
+            blocking_reason: self.blocking_reason.as_deref(),
+            timeline: self
+                .entries
+                .iter()
+                .enumerate()
+                .map(|(index, entry)| TimelineEntryJson {
+                    timestamp: &entry.timestamp,
+                    source: entry.source.as_str(),
+                    summary: &entry.summary,
+                    status: &entry.status,
+                    blocking: self.blocker == Some(index),
+                })
+                .collect(),
+        }
+    }
+}
+
+fn write_timeline_human(e: &TimelineExplanation, stdout: &mut dyn Write) -> Result<(), String> {
+    let blocked = if e.is_blocked { ", BLOCKED" } else { "" };
+    writeln!(
+        stdout,
+        "Timeline for agent {} ({}{blocked})",
+        short_id(&e.agent_id),
+        format_agent_state(&e.state)
+    )
+    .map_err(|err| err.to_string())?;
+    writeln!(stdout).map_err(|err| err.to_string())?;
+
+    if e.entries.is_empty() {
+        writeln!(stdout, "No recorded events for this agent.").map_err(|err| err.to_string())?;
+    } else {
+        let source_width = e
+            .entries
+            .iter()
+            .map(|entry| entry.source.as_str().len())
+            .max()
+            .unwrap_or(0);
+        for (index, entry) in e.entries.iter().enumerate() {
+            let marker = if e.blocker == Some(index) { ">" } else { " " };
+            let mut line = format!(
+                "{marker} {}  {:<source_width$}  {}",
+                entry.timestamp,
+                entry.source.as_str(),
+                truncate_string(&entry.summary, TIMELINE_SUMMARY_MAX)
+            );
+            if e.blocker == Some(index) {
+                line.push_str("  <- BLOCKING");
+            }
+            writeln!(stdout, "{line}").map_err(|err| err.to_string())?;
+        }
+    }
+
+    if let Some(reason) = &e.blocking_reason {
+        writeln!(stdout).map_err(|err| err.to_string())?;
+        writeln!(stdout, "Blocked by: {reason}").map_err(|err| err.to_string())?;
+    }
+    Ok(())
+}
+
 // ---------------------------------------------------------------------------
 // Helpers
 // ---------------------------------------------------------------------------
@@ -1897,14 +2436,13 @@ fn format_agent_state(state: &AgentState) -> &'static str {
     state.as_str()
 }
 
-#[allow(dead_code)]
 fn truncate_string(s: &str, max_len: usize) -> String {
-    if s.len() <= max_len {
+    if s.chars().count() <= max_len {
         s.to_string()
     } else if max_len < 3 {
-        s[..max_len].to_string()
+        s.chars().take(max_len).collect()
     } else {
-        format!("{}...", &s[..max_len - 3])
+        format!("{}...", s.chars().take(max_len - 3).collect::<String>())
     }
 }
 
@@ -1913,22 +2451,26 @@ fn truncate_string(s: &str, max_len: usize) -> String {
 // ---------------------------------------------------------------------------
 
 const HELP_TEXT: &str = "\
-Explain agent or queue item status
+Explain agent, loop or queue item status
 
-Show a human-readable explanation of why an agent or queue item is in its current state.
+Show a human-readable explanation of why an agent, loop or queue item is in its current state.
+For loops this includes the stop cause recorded by 'forge stop --reason'.
 
 If no argument is given, explains the agent from the current context (set with 'forge use').
 
 Usage:
-  forge explain [agent-id|queue-item-id] [flags]
+  forge explain [agent-id|loop|queue-item-id] [flags]
 
 Examples:
   forge explain abc123        # Explain agent status
   forge explain qi_789        # Explain queue item status
+  forge explain review-loop   # Explain why a loop stopped
   forge explain               # Explain context agent
+  forge explain abc123 --timeline  # Show the events that led here
 
 Flags:
-  -h, --help    help for explain";
+  -h, --help      help for explain
+      --timeline  show a chronological history with the current blocker";
 
 // ---------------------------------------------------------------------------
 // Tests
@@ -2029,14 +2571,14 @@ mod tests {
     fn parse_help_flag() {
         let args = vec![s("explain"), s("--help")];
         let err = parse_args(&args).unwrap_err();
-        assert!(err.contains("Explain agent or queue item status"));
+        assert!(err.contains("Explain agent, loop or queue item status"));
     }
 
     #[test]
     fn parse_short_help_flag() {
         let args = vec![s("explain"), s("-h")];
         let err = parse_args(&args).unwrap_err();
-        assert!(err.contains("Explain agent or queue item status"));
+        assert!(err.contains("Explain agent, loop or queue item status"));
     }
 
     // --- build_agent_explanation tests ---
@@ -2363,6 +2905,50 @@ mod tests {
         assert!(out.stdout.contains("Suggestions:"));
     }
 
+    #[test]
+    fn explain_loop_reports_stop_cause() {
+        let backend = InMemoryExplainBackend {
+            loops: vec![
+                LoopRecord {
+                    id: "loop-0001".to_string(),
+                    short_id: "ab12cd".to_string(),
+                    name: "review-loop".to_string(),
+                    state: "stopped".to_string(),
+                    stop_cause: Some(StopCause {
+                        reason: "deploy freeze".to_string(),
+                        requested_at: "2026-03-01T09:00:00Z".to_string(),
+                    }),
+                },
+                LoopRecord {
+                    id: "loop-0002".to_string(),
+                    short_id: "ef34gh".to_string(),
+                    name: "docs-loop".to_string(),
+                    state: "running".to_string(),
+                    stop_cause: None,
+                },
+            ],
+            ..Default::default()
+        };
+
+        let out = run_for_test(&["explain", "review-loop"], &backend);
+        assert_eq!(out.exit_code, 0, "stderr: {}", out.stderr);
+        assert_eq!(
+            out.stdout,
+            "Loop review-loop (ab12cd) is stopped\n\nStop cause: deploy freeze\nStop requested: 2026-03-01T09:00:00Z\n"
+        );
+
+        let out = run_for_test(&["explain", "ef34gh", "--jsonl"], &backend);
+        assert_eq!(out.exit_code, 0, "stderr: {}", out.stderr);
+        assert_eq!(
+            out.stdout,
+            "{\"loop_id\":\"loop-0002\",\"short_id\":\"ef34gh\",\"name\":\"docs-loop\",\"state\":\"running\",\"stop_cause\":null}\n"
+        );
+
+        let missing = run_for_test(&["explain", "nope"], &backend);
+        assert_eq!(missing.exit_code, 1);
+        assert!(missing.stderr.contains("agent 'nope' not found"));
+    }
+
     #[test]
     fn explain_agent_blocked_output() {
         let backend = InMemoryExplainBackend {
@@ -2570,7 +3156,9 @@ mod tests {
         let backend = InMemoryExplainBackend::default();
         let out = run_for_test(&["explain", "--help"], &backend);
         assert_eq!(out.exit_code, 1);
-        assert!(out.stderr.contains("Explain agent or queue item status"));
+        assert!(out
+            .stderr
+            .contains("Explain agent, loop or queue item status"));
         assert!(out.stderr.contains("forge explain"));
     }
 
@@ -2717,6 +3305,186 @@ mod tests {
             .any(|value| value.as_str() == Some("account cooldown active")));
     }
 
+    #[test]
+    fn timeline_without_events_reports_empty_history() {
+        let backend = InMemoryExplainBackend {
+            agents: vec![make_agent("agent_12345678", AgentState::Idle)],
+            ..Default::default()
+        };
+        let out = run_for_test(&["explain", "agent_12345678", "--timeline"], &backend);
+        assert_eq!(out.exit_code, 0, "stderr: {}", out.stderr);
+        assert_eq!(
+            out.stdout,
+            "Timeline for agent agent_12 (idle)\n\nNo recorded events for this agent.\n"
+        );
+    }
+
+    #[test]
+    fn timeline_blocked_without_matching_entry_falls_back_to_block_reason() {
+        let backend = InMemoryExplainBackend {
+            agents: vec![make_agent("agent_12345678", AgentState::AwaitingApproval)],
+            ..Default::default()
+        };
+        let out = run_for_test(&["explain", "agent_12345678", "--timeline"], &backend);
+        assert_eq!(out.exit_code, 0, "stderr: {}", out.stderr);
+        assert!(out.stdout.contains("No recorded events for this agent."));
+        assert!(out.stdout.contains("Blocked by: waiting for user approval"));
+    }
+
+    #[test]
+    fn sqlite_backend_timeline_shows_pending_approval_as_blocker() {
+        let fixture = SqliteExplainFixture::new("sqlite_backend_timeline_approval");
+        {
+            let db = forge_db::Db::open(forge_db::Config::new(&fixture.db_path)).unwrap();
+            let conn = db.conn();
+            conn.execute(
+                "UPDATE agents SET state = 'awaiting_approval' WHERE id = 'agent_99999999'",
+                [],
+            )
+            .unwrap();
+            conn.execute(
+                "INSERT INTO events (id, timestamp, type, entity_type, entity_id, payload_json)
+                 VALUES ('ev_1', '2026-01-01T00:00:00Z', 'agent.state_changed', 'agent', 'agent_99999999', NULL)",
+                [],
+            )
+            .unwrap();
+            conn.execute(
+                "INSERT INTO approvals (id, agent_id, request_type, request_details_json, status, created_at, resolved_at, resolved_by)
+                 VALUES ('ap_0', 'agent_99999999', 'shell', '{}', 'approved', '2026-01-01T00:01:00Z', '2026-01-01T00:02:00Z', 'operator')",
+                [],
+            )
+            .unwrap();
+            conn.execute(
+                "INSERT INTO approvals (id, agent_id, request_type, request_details_json, status, created_at)
+                 VALUES ('ap_1', 'agent_99999999', 'file_write', '{}', 'pending', '2026-01-01T00:03:00Z')",
+                [],
+            )
+            .unwrap();
+        }
+        let backend = fixture.backend();
+
+        let out = run_for_test(
+            &["explain", "agent_99999999", "--timeline", "--json"],
+            &backend,
+        );
+        assert_eq!(out.exit_code, 0, "stderr: {}", out.stderr);
+        let parsed: serde_json::Value = serde_json::from_str(&out.stdout).unwrap();
+        assert_eq!(parsed["is_blocked"], true);
+        assert_eq!(
+            parsed["blocking_reason"],
+            "approval ap_1 requested (file_write)"
+        );
+        let summaries: Vec<&str> = parsed["timeline"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|entry| entry["summary"].as_str().unwrap())
+            .collect();
+        assert_eq!(
+            summaries,
+            vec![
+                "agent.state_changed",
+                "approval ap_0 requested (shell)",
+                "approval ap_0 approved by operator",
+                "approval ap_1 requested (file_write)",
+            ]
+        );
+        assert_eq!(parsed["timeline"][3]["blocking"], true);
+        assert_eq!(parsed["timeline"][1]["blocking"], false);
+
+        let human = run_for_test(&["explain", "agent_99999999", "--timeline"], &backend);
+        assert_eq!(human.exit_code, 0, "stderr: {}", human.stderr);
+        assert!(human
+            .stdout
+            .contains("approval ap_1 requested (file_write)  <- BLOCKING"));
+        assert!(human
+            .stdout
+            .contains("Blocked by: approval ap_1 requested (file_write)"));
+    }
+
+    #[test]
+    fn approval_timeline_limit_keeps_newest_approvals() {
+        let fixture = SqliteExplainFixture::new("approval_timeline_limit");
+        let db = forge_db::Db::open(forge_db::Config::new(&fixture.db_path)).unwrap();
+        for (id, created_at) in [
+            ("ap_old", "2026-01-01T00:01:00Z"),
+            ("ap_new", "2026-01-01T00:03:00Z"),
+            ("ap_mid", "2026-01-01T00:02:00Z"),
+        ] {
+            db.conn()
+                .execute(
+                    "INSERT INTO approvals (id, agent_id, request_type, request_details_json, status, created_at)
+                     VALUES (?1, 'agent_99999999', 'shell', '{}', 'pending', ?2)",
+                    rusqlite::params![id, created_at],
+                )
+                .unwrap();
+        }
+
+        let entries = list_approval_timeline(db.conn(), "agent_99999999", 1).unwrap();
+        let summaries: Vec<&str> = entries.iter().map(|entry| entry.summary.as_str()).collect();
+        assert_eq!(summaries, vec!["approval ap_new requested (shell)"]);
+    }
+
+    #[test]
+    fn sqlite_timeline_limit_keeps_newest_events() {
+        let fixture = SqliteExplainFixture::new("event_timeline_limit");
+        {
+            let db = forge_db::Db::open(forge_db::Config::new(&fixture.db_path)).unwrap();
+            let repo = forge_db::event_repository::EventRepository::new(&db);
+            for (event_type, timestamp) in [
+                ("agent.old", "2026-01-01T00:01:00Z"),
+                ("agent.new", "2026-01-01T00:03:00Z"),
+                ("agent.mid", "2026-01-01T00:02:00Z"),
+            ] {
+                let mut event = forge_db::event_repository::Event {
+                    timestamp: timestamp.to_string(),
+                    event_type: event_type.to_string(),
+                    entity_type: "agent".to_string(),
+                    entity_id: "agent_99999999".to_string(),
+                    ..Default::default()
+                };
+                repo.create(&mut event).unwrap();
+            }
+        }
+
+        let entries = fixture
+            .backend()
+            .list_timeline("agent_99999999", 2)
+            .unwrap();
+        let events: Vec<&str> = entries
+            .iter()
+            .filter(|entry| entry.source == TimelineSource::Event)
+            .map(|entry| entry.summary.as_str())
+            .collect();
+        assert_eq!(events, vec!["agent.mid", "agent.new"]);
+    }
+
+    #[test]
+    fn in_memory_timeline_limit_keeps_newest_entries() {
+        let entry = |timestamp: &str, summary: &str| {
+            (
+                "agent_12345678".to_string(),
+                TimelineEntry {
+                    timestamp: timestamp.to_string(),
+                    source: TimelineSource::Event,
+                    summary: summary.to_string(),
+                    status: String::new(),
+                },
+            )
+        };
+        let backend = InMemoryExplainBackend {
+            timeline: vec![
+                entry("2026-01-01T00:03:00Z", "new"),
+                entry("2026-01-01T00:01:00Z", "old"),
+                entry("2026-01-01T00:02:00Z", "mid"),
+            ],
+            ..Default::default()
+        };
+        let entries = backend.list_timeline("agent_12345678", 2).unwrap();
+        let summaries: Vec<&str> = entries.iter().map(|entry| entry.summary.as_str()).collect();
+        assert_eq!(summaries, vec!["mid", "new"]);
+    }
+
     #[test]
     fn sqlite_backend_queue_payload_parity() {
         let fixture = SqliteExplainFixture::new("sqlite_backend_queue_payload_parity");
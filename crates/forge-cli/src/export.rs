@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 use std::io::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use rusqlite::Connection;
 use serde::Serialize;
@@ -92,6 +92,13 @@ pub struct ExportEvent {
     pub metadata: Option<std::collections::HashMap<String, String>>,
 }
 
+/// Result of an online database backup for `forge export --db-backup`.
+#[derive(Debug, Clone, Serialize)]
+pub struct ExportDbBackup {
+    pub path: String,
+    pub pages: i32,
+}
+
 // ---------------------------------------------------------------------------
 // Backend trait
 // ---------------------------------------------------------------------------
@@ -113,6 +120,14 @@ pub trait ExportBackend {
         entity_id: &str,
         limit: usize,
     ) -> Result<(Vec<ExportEvent>, String), String>;
+
+    /// Copy the live database to `dest` without stopping writers, calling
+    /// `on_progress(copied_pages, total_pages)` after every copy step.
+    fn backup_database(
+        &self,
+        dest: &Path,
+        on_progress: &mut dyn FnMut(i32, i32),
+    ) -> Result<ExportDbBackup, String>;
 }
 
 // ---------------------------------------------------------------------------
@@ -126,6 +141,8 @@ pub struct InMemoryExportBackend {
     pub events: Vec<ExportEvent>,
     pub status_error: Option<String>,
     pub events_error: Option<String>,
+    pub backup_pages: i32,
+    pub backup_error: Option<String>,
 }
 
 impl InMemoryExportBackend {
@@ -148,6 +165,16 @@ impl InMemoryExportBackend {
         self.events_error = Some(err.to_string());
         self
     }
+
+    pub fn with_backup_pages(mut self, pages: i32) -> Self {
+        self.backup_pages = pages;
+        self
+    }
+
+    pub fn with_backup_error(mut self, err: &str) -> Self {
+        self.backup_error = Some(err.to_string());
+        self
+    }
 }
 
 impl ExportBackend for InMemoryExportBackend {
@@ -200,6 +227,22 @@ impl ExportBackend for InMemoryExportBackend {
         // In-memory returns all at once (no pagination).
         Ok((filtered, String::new()))
     }
+
+    fn backup_database(
+        &self,
+        dest: &Path,
+        on_progress: &mut dyn FnMut(i32, i32),
+    ) -> Result<ExportDbBackup, String> {
+        if let Some(ref err) = self.backup_error {
+            return Err(err.clone());
+        }
+        on_progress(self.backup_pages / 2, self.backup_pages);
+        on_progress(self.backup_pages, self.backup_pages);
+        Ok(ExportDbBackup {
+            path: dest.display().to_string(),
+            pages: self.backup_pages,
+        })
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -364,6 +407,29 @@ impl ExportBackend for SqliteExportBackend {
 
         Ok((events, page.next_cursor))
     }
+
+    fn backup_database(
+        &self,
+        dest: &Path,
+        on_progress: &mut dyn FnMut(i32, i32),
+    ) -> Result<ExportDbBackup, String> {
+        if !self.db_path.exists() {
+            return Err(format!("database not found: {}", self.db_path.display()));
+        }
+
+        let db = forge_db::Db::open(forge_db::Config::new(&self.db_path))
+            .map_err(|err| format!("open database {}: {err}", self.db_path.display()))?;
+        let mut pages = 0;
+        db.backup_to_with_progress(dest, |progress| {
+            pages = progress.total_pages;
+            on_progress(progress.copied_pages(), progress.total_pages);
+        })
+        .map_err(|err| format!("backup database to {}: {err}", dest.display()))?;
+        Ok(ExportDbBackup {
+            path: dest.display().to_string(),
+            pages,
+        })
+    }
 }
 
 fn parse_payload(raw: &str) -> Option<serde_json::Value> {
@@ -592,7 +658,7 @@ pub fn run_with_backend(
     stdout: &mut dyn Write,
     stderr: &mut dyn Write,
 ) -> i32 {
-    match execute(args, backend, stdout) {
+    match execute(args, backend, stdout, stderr) {
         Ok(()) => 0,
         Err(message) => {
             let _ = writeln!(stderr, "{message}");
@@ -609,15 +675,55 @@ fn execute(
     args: &[String],
     backend: &dyn ExportBackend,
     stdout: &mut dyn Write,
+    stderr: &mut dyn Write,
 ) -> Result<(), String> {
     let parsed = parse_args(args)?;
 
     match parsed.subcommand {
         Subcommand::Status => execute_status(backend, &parsed, stdout),
         Subcommand::Events => execute_events(backend, &parsed, stdout),
+        Subcommand::DbBackup(ref dest) => execute_db_backup(backend, &parsed, dest, stdout, stderr),
     }
 }
 
+fn execute_db_backup(
+    backend: &dyn ExportBackend,
+    parsed: &ParsedArgs,
+    dest: &str,
+    stdout: &mut dyn Write,
+    stderr: &mut dyn Write,
+) -> Result<(), String> {
+    // Human runs report progress on stderr at every 10% step.
+    let report = !(parsed.json || parsed.jsonl);
+    let mut last_decile = -1;
+    let backup = backend.backup_database(Path::new(dest), &mut |copied, total| {
+        if !report || total <= 0 {
+            return;
+        }
+        let percent = (i64::from(copied) * 100 / i64::from(total)) as i32;
+        if percent / 10 > last_decile {
+            last_decile = percent / 10;
+            let _ = writeln!(
+                stderr,
+                "Backing up database: {percent}% ({copied}/{total} pages)"
+            );
+        }
+    })?;
+
+    if parsed.json || parsed.jsonl {
+        write_json_output(stdout, &backup, parsed.jsonl)?;
+        return Ok(());
+    }
+
+    writeln!(
+        stdout,
+        "Database backup written to {} ({} pages)",
+        backup.path, backup.pages
+    )
+    .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
 fn execute_status(
     backend: &dyn ExportBackend,
     parsed: &ParsedArgs,
@@ -855,6 +961,7 @@ fn write_json_output<T: Serialize>(
 enum Subcommand {
     Status,
     Events,
+    DbBackup(String),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -888,6 +995,15 @@ fn parse_args(args: &[String]) -> Result<ParsedArgs, String> {
             index += 1;
             Subcommand::Events
         }
+        Some("--db-backup") => {
+            let dest = args
+                .get(index + 1)
+                .filter(|value| !value.trim().is_empty())
+                .ok_or("error: --db-backup requires a path")?
+                .clone();
+            index += 2;
+            Subcommand::DbBackup(dest)
+        }
         Some("-h") | Some("--help") | Some("help") => {
             return Err(HELP_TEXT.to_string());
         }
@@ -915,6 +1031,7 @@ fn parse_args(args: &[String]) -> Result<ParsedArgs, String> {
                 return match subcommand {
                     Subcommand::Status => Err(HELP_STATUS.to_string()),
                     Subcommand::Events => Err(HELP_EVENTS.to_string()),
+                    Subcommand::DbBackup(_) => Err(HELP_TEXT.to_string()),
                 };
             }
             "--json" => {
@@ -939,7 +1056,7 @@ fn parse_args(args: &[String]) -> Result<ParsedArgs, String> {
                 index += 1;
             }
             "--until" => {
-                if subcommand == Subcommand::Status {
+                if subcommand != Subcommand::Events {
                     return Err("error: --until is only valid for 'export events'".to_string());
                 }
                 index += 1;
@@ -951,7 +1068,7 @@ fn parse_args(args: &[String]) -> Result<ParsedArgs, String> {
                 index += 1;
             }
             "--type" => {
-                if subcommand == Subcommand::Status {
+                if subcommand != Subcommand::Events {
                     return Err("error: --type is only valid for 'export events'".to_string());
                 }
                 index += 1;
@@ -962,7 +1079,7 @@ fn parse_args(args: &[String]) -> Result<ParsedArgs, String> {
                 index += 1;
             }
             "--agent" => {
-                if subcommand == Subcommand::Status {
+                if subcommand != Subcommand::Events {
                     return Err("error: --agent is only valid for 'export events'".to_string());
                 }
                 index += 1;
@@ -1008,13 +1125,15 @@ Export Forge state for automation or reporting.
 
 Usage:
   forge export [command]
+  forge export --db-backup <path>
 
 Available Commands:
   events      Export events
   status      Export full status
 
 Flags:
-  -h, --help   help for export";
+      --db-backup path   copy the live database to path (safe while loops run)
+  -h, --help             help for export";
 
 const HELP_STATUS: &str = "\
 Export full status as JSON: nodes, workspaces, agents, queues, alerts.
@@ -1173,6 +1292,46 @@ mod tests {
         assert!(!parsed.jsonl);
     }
 
+    #[test]
+    fn parse_db_backup_flag() {
+        let args = to_args(&["export", "--db-backup", "/tmp/forge-backup.db", "--json"]);
+        let parsed = parse_args(&args).unwrap();
+        assert_eq!(
+            parsed.subcommand,
+            Subcommand::DbBackup("/tmp/forge-backup.db".to_string())
+        );
+        assert!(parsed.json);
+
+        let err = parse_args(&to_args(&["export", "--db-backup"])).unwrap_err();
+        assert!(err.contains("--db-backup requires a path"));
+    }
+
+    #[test]
+    fn db_backup_reports_written_copy() {
+        let backend = default_backend().with_backup_pages(42);
+        let out = run(&["export", "--db-backup", "/tmp/forge-backup.db"], &backend);
+        assert_eq!(out.exit_code, 0);
+        assert_eq!(
+            out.stdout,
+            "Database backup written to /tmp/forge-backup.db (42 pages)\n"
+        );
+        assert_eq!(
+            out.stderr,
+            "Backing up database: 50% (21/42 pages)\nBacking up database: 100% (42/42 pages)\n"
+        );
+
+        let out = run(
+            &["export", "--db-backup", "/tmp/forge-backup.db", "--json"],
+            &backend,
+        );
+        assert_success(&out);
+
+        let backend = default_backend().with_backup_error("database not found: /nope");
+        let out = run(&["export", "--db-backup", "/tmp/forge-backup.db"], &backend);
+        assert_eq!(out.exit_code, 1);
+        assert_eq!(out.stderr, "database not found: /nope\n");
+    }
+
     #[test]
     fn parse_events_subcommand() {
         let args = to_args(&["export", "events"]);
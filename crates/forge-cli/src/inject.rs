@@ -52,6 +52,17 @@ impl AgentState {
     }
 }
 
+/// Non-ready agents only take an injection with `--force`.
+fn check_injectable(agent: &AgentRecord, force: bool) -> Result<(), String> {
+    if force || agent.state.is_ready_for_inject() {
+        return Ok(());
+    }
+    Err(format!(
+        "agent is {}; use --force to inject without confirmation",
+        agent.state.as_str()
+    ))
+}
+
 /// Minimal agent info returned by the backend.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AgentRecord {
@@ -68,6 +79,14 @@ pub trait InjectBackend {
     /// Resolve an agent target (ID or prefix) to a concrete agent record.
     fn resolve_agent(&self, target: &str) -> Result<AgentRecord, String>;
 
+    /// Resolve `target` and check it can take a direct injection, without
+    /// writing anything. `force` skips the readiness check.
+    fn validate_target(&self, target: &str, force: bool) -> Result<AgentRecord, String> {
+        let agent = self.resolve_agent(target)?;
+        check_injectable(&agent, force)?;
+        Ok(agent)
+    }
+
     /// Load the current agent context (from `forge use --agent`).
     fn load_agent_context(&self) -> Result<Option<String>, String>;
 
@@ -532,6 +551,8 @@ struct InjectResultJson {
     agent_id: String,
     agent_state: String,
     bypassed_queue: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    dry_run: bool,
     injected: bool,
     message: String,
 }
@@ -577,6 +598,7 @@ struct ParsedArgs {
     jsonl: bool,
     quiet: bool,
     force: bool,
+    dry_run: bool,
     file: String,
     stdin: bool,
     editor: bool,
@@ -600,29 +622,23 @@ fn execute(
         return Ok(());
     }
 
-    // Resolve agent target.
+    // Resolve the target before reading the message so an unknown agent
+    // fails fast (ERR_NOT_FOUND). A dry run also checks readiness up front;
+    // a real injection reports a missing message before the agent state.
     let agent_target = resolve_agent_target(&parsed, backend)?;
-    let agent = backend.resolve_agent(&agent_target)?;
+    let agent = if parsed.dry_run {
+        backend.validate_target(&agent_target, parsed.force)?
+    } else {
+        backend.resolve_agent(&agent_target)?
+    };
 
     // Resolve the message.
     let message = resolve_message(&parsed, backend)?;
 
-    // Check agent state and potentially require confirmation.
-    if !parsed.force && !agent.state.is_ready_for_inject() {
-        if !backend.is_interactive() {
-            return Err(format!(
-                "agent is {}; use --force to inject without confirmation",
-                agent.state.as_str()
-            ));
-        }
-        // In interactive mode we would prompt; for the Rust CLI parity port
-        // this is handled by the real backend. The in-memory backend is
-        // non-interactive by default, so this branch returns the error.
-        return Err(format!(
-            "agent is {}; use --force to inject without confirmation",
-            agent.state.as_str()
-        ));
+    if parsed.dry_run {
+        return write_dry_run(&parsed, &agent, message, stdout);
     }
+    check_injectable(&agent, parsed.force)?;
 
     // Send the message directly (bypasses queue).
     backend.send_message(&agent.id, &message).map_err(|err| {
@@ -639,6 +655,7 @@ fn execute(
             agent_id: agent.id.clone(),
             agent_state: agent.state.as_str().to_string(),
             bypassed_queue: true,
+            dry_run: false,
             injected: true,
             message,
         };
@@ -664,6 +681,43 @@ fn execute(
     Ok(())
 }
 
+fn write_dry_run(
+    parsed: &ParsedArgs,
+    agent: &AgentRecord,
+    message: String,
+    stdout: &mut dyn Write,
+) -> Result<(), String> {
+    if parsed.json || parsed.jsonl {
+        let payload = InjectResultJson {
+            agent_id: agent.id.clone(),
+            agent_state: agent.state.as_str().to_string(),
+            bypassed_queue: true,
+            dry_run: true,
+            injected: false,
+            message,
+        };
+        if parsed.jsonl {
+            serde_json::to_writer(&mut *stdout, &payload).map_err(|e| e.to_string())?;
+        } else {
+            serde_json::to_writer_pretty(&mut *stdout, &payload).map_err(|e| e.to_string())?;
+        }
+        writeln!(stdout).map_err(|e| e.to_string())?;
+        return Ok(());
+    }
+
+    if !parsed.quiet {
+        writeln!(
+            stdout,
+            "Dry run: agent {} ({}) accepts direct injection",
+            short_id(&agent.id),
+            agent.state.as_str()
+        )
+        .map_err(|e| e.to_string())?;
+        writeln!(stdout, "Would inject: {message}").map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
 fn resolve_agent_target(
     parsed: &ParsedArgs,
     backend: &dyn InjectBackend,
@@ -762,6 +816,7 @@ fn parse_args(args: &[String]) -> Result<ParsedArgs, String> {
     let mut jsonl = false;
     let mut quiet = false;
     let mut force = false;
+    let mut dry_run = false;
     let mut file = String::new();
     let mut stdin = false;
     let mut editor = false;
@@ -790,6 +845,10 @@ fn parse_args(args: &[String]) -> Result<ParsedArgs, String> {
                 force = true;
                 index += 1;
             }
+            "--dry-run" => {
+                dry_run = true;
+                index += 1;
+            }
             "-f" | "--file" => {
                 file = take_value(args, index, "--file")?;
                 index += 2;
@@ -821,6 +880,7 @@ fn parse_args(args: &[String]) -> Result<ParsedArgs, String> {
         jsonl,
         quiet,
         force,
+        dry_run,
         file,
         stdin,
         editor,
@@ -893,12 +953,20 @@ fn write_help(stdout: &mut dyn Write) -> std::io::Result<()> {
         stdout,
         "  forge inject abc123 --editor                      # compose in editor"
     )?;
+    writeln!(
+        stdout,
+        "  forge inject --dry-run abc123 \"Stop\"              # validate only"
+    )?;
     writeln!(stdout)?;
     writeln!(stdout, "Flags:")?;
     writeln!(
         stdout,
         "  -F, --force            skip confirmation for non-idle agents"
     )?;
+    writeln!(
+        stdout,
+        "      --dry-run          validate the target and message without injecting"
+    )?;
     writeln!(stdout, "  -f, --file string      read message from file")?;
     writeln!(stdout, "      --stdin            read message from stdin")?;
     writeln!(
@@ -1516,6 +1584,62 @@ mod tests {
         assert_eq!(out.stdout, expected);
     }
 
+    // --- Dry run ---
+
+    #[test]
+    fn inject_dry_run_missing_agent_reports_error_and_writes_nothing() {
+        let mut backend = single_idle_backend();
+        let out = run(
+            &["inject", "--dry-run", "nonexistent", "hello"],
+            &mut backend,
+        );
+        assert_eq!(out.exit_code, 1);
+        assert!(out.stdout.is_empty());
+        assert_eq!(out.stderr, "agent not found: nonexistent\n");
+        assert!(backend.sent_messages.is_empty());
+    }
+
+    #[test]
+    fn inject_dry_run_reports_without_sending() {
+        let mut backend = single_idle_backend();
+        let out = run(
+            &["inject", "--dry-run", "agent-inject-idle", "ping", "--json"],
+            &mut backend,
+        );
+        assert_success(&out);
+        let parsed: serde_json::Value =
+            serde_json::from_str(&out.stdout).unwrap_or_else(|err| panic!("parse json: {err}"));
+        assert_eq!(parsed["dry_run"], true);
+        assert_eq!(parsed["injected"], false);
+        assert_eq!(parsed["message"], "ping");
+        assert!(backend.sent_messages.is_empty());
+    }
+
+    #[test]
+    fn inject_dry_run_busy_agent_requires_force() {
+        let mut backend = multi_agent_backend();
+        let out = run(
+            &["inject", "--dry-run", "agent-inject-busy", "ping"],
+            &mut backend,
+        );
+        assert_eq!(out.exit_code, 1);
+        assert!(out.stderr.contains("agent is working"));
+
+        let out = run(
+            &[
+                "inject",
+                "--dry-run",
+                "--force",
+                "agent-inject-busy",
+                "ping",
+            ],
+            &mut backend,
+        );
+        assert_success(&out);
+        assert!(out.stdout.contains("Dry run: agent agent-in (working)"));
+        assert!(backend.sent_messages.is_empty());
+    }
+
     struct MockInjectTransport {
         calls: Rc<RefCell<Vec<(String, String)>>>,
         fail_message: Option<String>,
@@ -1690,5 +1814,29 @@ mod tests {
         let _ = std::fs::remove_file(ctx_path);
     }
 
+    #[test]
+    fn sqlite_inject_dry_run_does_not_touch_pane() {
+        let calls: Rc<RefCell<Vec<(String, String)>>> = Rc::new(RefCell::new(Vec::new()));
+        let (mut backend, db_path, ctx_path) = setup_sqlite_backend_with_transport(
+            "idle",
+            "forge-ws-1:0.1",
+            None,
+            calls.clone(),
+            None,
+        );
+
+        let out = run(&["inject", "--dry-run", "agent-001", "hello"], &mut backend);
+        assert_success(&out);
+        assert!(out.stdout.contains("Would inject: hello"));
+
+        let out = run(&["inject", "--dry-run", "agent-404", "hello"], &mut backend);
+        assert_eq!(out.exit_code, 1);
+        assert_eq!(out.stderr, "agent not found: agent-404\n");
+        assert!(calls.borrow().is_empty());
+
+        let _ = std::fs::remove_file(db_path);
+        let _ = std::fs::remove_file(ctx_path);
+    }
+
     fn _assert_backend_object_safe(_b: &mut dyn InjectBackend) {}
 }
@@ -9,6 +9,7 @@ mod command_renderer;
 pub mod completion;
 pub mod config;
 pub mod context;
+mod daemon_target;
 pub mod delegation;
 mod diff_renderer;
 pub mod doctor;
@@ -33,6 +34,7 @@ pub mod mesh;
 pub mod migrate;
 pub mod msg;
 pub mod node;
+pub mod pause;
 pub mod pool;
 pub mod profile;
 mod profile_catalog;
@@ -281,6 +283,11 @@ pub fn run_with_args(args: &[String], stdout: &mut dyn Write, stderr: &mut dyn W
             let forwarded = forward_args(remaining, &flags);
             pool::run_with_backend(&forwarded, &mut backend, stdout, stderr)
         }
+        Some("pause") => {
+            let mut backend = pause::SqlitePauseBackend::open_from_env();
+            let forwarded = forward_args(remaining, &flags);
+            pause::run_with_backend(&forwarded, &mut backend, stdout, stderr)
+        }
         Some("profile") => {
             let mut backend = profile::SqliteProfileBackend::open_from_env();
             let forwarded = forward_args(remaining, &flags);
@@ -468,7 +475,7 @@ fn write_root_help(out: &mut dyn Write) -> std::io::Result<()> {
     writeln!(out, "  config    Manage global configuration")?;
     writeln!(out, "  delegation  Evaluate delegation rules")?;
     writeln!(out, "  doctor    Run environment diagnostics")?;
-    writeln!(out, "  explain   Explain agent or queue item status")?;
+    writeln!(out, "  explain   Explain agent, loop or queue item status")?;
     writeln!(out, "  export    Export Forge data")?;
     writeln!(out, "  hook      Manage event hooks")?;
     writeln!(out, "  inject    Inject message directly into agent")?;
@@ -482,6 +489,7 @@ fn write_root_help(out: &mut dyn Write) -> std::io::Result<()> {
     writeln!(out, "  mem       Loop memory command family")?;
     writeln!(out, "  mesh      Manage mesh registry and master")?;
     writeln!(out, "  msg       Queue a message for loop(s)")?;
+    writeln!(out, "  pause     Suspend a daemon-owned loop runner")?;
     writeln!(out, "  pool      Profile pool command family")?;
     writeln!(out, "  profile   Harness profile command family")?;
     writeln!(out, "  prompt    Loop prompt command family")?;
@@ -580,9 +588,9 @@ mod tests {
     use super::{
         agent, audit, clean, completion, config, context, crate_label, delegation, doctor, explain,
         export, external_adapter, hook, init, inject, job, kill, lock, logs, loop_internal, mail,
-        mem, mesh, migrate, msg, node, pool, profile, prompt, ps, queue, registry, resume, rm, run,
-        run_for_test, scale, send, seq, skills, status, stop, task, team, team_heartbeat_watchdog,
-        template, trigger, tui, up, wait, work, workflow,
+        mem, mesh, migrate, msg, node, pause, pool, profile, prompt, ps, queue, registry, resume,
+        rm, run, run_for_test, scale, send, seq, skills, status, stop, task, team,
+        team_heartbeat_watchdog, template, trigger, tui, up, wait, work, workflow,
     };
 
     #[test]
@@ -771,6 +779,11 @@ mod tests {
         let _ = scale::InMemoryScaleBackend::default();
     }
 
+    #[test]
+    fn pause_module_is_accessible() {
+        let _ = pause::InMemoryPauseBackend::default();
+    }
+
     #[test]
     fn resume_module_is_accessible() {
         let _ = resume::InMemoryResumeBackend::default();
@@ -1261,7 +1261,7 @@ fn matches_path_pattern(path_value: &str, pattern: &str) -> bool {
 }
 
 /// Simple path-style glob matching (supports `*` and `?` like Go's `path.Match`).
-fn glob_match(pattern: &str, name: &str) -> bool {
+pub(crate) fn glob_match(pattern: &str, name: &str) -> bool {
     let pattern_bytes = pattern.as_bytes();
     let name_bytes = name.as_bytes();
     let mut pi = 0;
@@ -1,9 +1,9 @@
 use std::collections::BTreeMap;
 use std::io::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::thread;
-use std::time::Duration;
 
+use forge_loop::log_tail::{LogFollower, TailOptions};
 use serde_json::Value;
 
 use crate::command_renderer::{
@@ -34,8 +34,9 @@ pub struct LoopRecord {
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 struct ParsedArgs {
-    loop_ref: String,
+    loop_refs: Vec<String>,
     all: bool,
+    merge: bool,
     follow: bool,
     lines: i32,
     since: String,
@@ -102,6 +103,20 @@ pub trait LogsBackend {
         render: RenderOptions,
         stdout: &mut dyn Write,
     ) -> Result<(), String>;
+    fn follow_merged_logs(
+        &mut self,
+        sources: &[MergeSource],
+        lines: i32,
+        render: RenderOptions,
+        stdout: &mut dyn Write,
+    ) -> Result<(), String>;
+}
+
+/// One loop log participating in a `--merge` stream; `tag` prefixes each of its lines.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MergeSource {
+    pub tag: String,
+    pub path: String,
 }
 
 #[derive(Debug, Clone)]
@@ -195,6 +210,25 @@ impl LogsBackend for InMemoryLogsBackend {
         write_log_block(stdout, &rendered)?;
         Ok(())
     }
+
+    fn follow_merged_logs(
+        &mut self,
+        sources: &[MergeSource],
+        lines: i32,
+        render: RenderOptions,
+        stdout: &mut dyn Write,
+    ) -> Result<(), String> {
+        let mut contents = Vec::with_capacity(sources.len());
+        for source in sources {
+            self.followed_paths.push((source.path.clone(), lines));
+            let content = match self.follow_output.get(&source.path) {
+                Some(text) => text.clone(),
+                None => self.read_log(&source.path, lines, "")?,
+            };
+            contents.push(content);
+        }
+        write_log_block(stdout, &render_merged_sources(sources, &contents, render))
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -285,44 +319,81 @@ impl LogsBackend for SqliteLogsBackend {
             return Ok(());
         }
 
-        let mut known_content =
-            std::fs::read_to_string(path).map_err(|err| format!("open {path}: {err}"))?;
-        let mut carry = String::new();
-
+        // Start following from the current end so the tail above is not
+        // repeated; the follower reopens the path when the log is rotated.
+        let mut follower = LogFollower::open(Path::new(path), TailOptions::default())
+            .map_err(|err| format!("open {path}: {err}"))?;
+        let mut rotations = follower.rotations();
         loop {
-            thread::sleep(Duration::from_millis(250));
-            let current =
-                std::fs::read_to_string(path).map_err(|err| format!("open {path}: {err}"))?;
-            if current == known_content {
+            let lines = follower
+                .poll_lines()
+                .map_err(|err| format!("read {path}: {err}"))?;
+            if follower.rotations() != rotations {
+                // Rotated or truncated: do not carry a diff run across files.
+                rotations = follower.rotations();
+                diff_state.reset();
+            }
+            if lines.is_empty() {
+                thread::sleep(follower.options().poll_interval);
                 continue;
             }
+            let mut chunk = lines.join("\n");
+            chunk.push('\n');
+            let rendered = render_log_chunk(&chunk, render, &mut diff_state);
+            write_log_block(stdout, &rendered)?;
+        }
+    }
 
-            let delta = if current.starts_with(&known_content) {
-                current[known_content.len()..].to_string()
-            } else {
-                // File truncated/rotated/replaced: treat full current content as new.
-                diff_state.reset();
-                carry.clear();
-                current.clone()
-            };
-            known_content = current;
-            if delta.is_empty() {
-                continue;
+    fn follow_merged_logs(
+        &mut self,
+        sources: &[MergeSource],
+        lines: i32,
+        render: RenderOptions,
+        stdout: &mut dyn Write,
+    ) -> Result<(), String> {
+        let mut tails = Vec::with_capacity(sources.len());
+        for source in sources {
+            tails.push(self.read_log(&source.path, lines, "")?);
+        }
+        write_log_block(stdout, &render_merged_sources(sources, &tails, render))?;
+        if std::env::var_os("FORGE_LOGS_FOLLOW_ONCE").is_some() {
+            return Ok(());
+        }
+
+        // One follower per stream, each starting at its current end and
+        // reopening its own path on rotation.
+        let mut followers = Vec::with_capacity(sources.len());
+        for source in sources {
+            followers.push(
+                LogFollower::open(Path::new(&source.path), TailOptions::default())
+                    .map_err(|err| format!("open {}: {err}", source.path))?,
+            );
+        }
+        let poll_interval = TailOptions::default().poll_interval;
+
+        loop {
+            let mut chunks = vec![String::new(); sources.len()];
+            let mut changed = false;
+            for ((source, follower), chunk) in sources
+                .iter()
+                .zip(followers.iter_mut())
+                .zip(chunks.iter_mut())
+            {
+                let lines = follower
+                    .poll_lines()
+                    .map_err(|err| format!("read {}: {err}", source.path))?;
+                if !lines.is_empty() {
+                    *chunk = lines.join("\n");
+                    chunk.push('\n');
+                    changed = true;
+                }
             }
 
-            let chunk = if carry.is_empty() {
-                delta
+            if changed {
+                write_log_block(stdout, &render_merged_sources(sources, &chunks, render))?;
             } else {
-                format!("{carry}{delta}")
-            };
-            let (complete, rest) = split_complete_lines(&chunk);
-            carry = rest;
-
-            if complete.is_empty() {
-                continue;
+                thread::sleep(poll_interval);
             }
-            let rendered = render_log_chunk(&complete, render, &mut diff_state);
-            write_log_block(stdout, &rendered)?;
         }
     }
 }
@@ -333,6 +404,84 @@ pub fn default_log_path(data_dir: &str, name: &str, id: &str) -> String {
     format!("{data_dir}/logs/loops/{file_stem}.log")
 }
 
+fn loop_log_path(data_dir: &str, entry: &LoopRecord) -> String {
+    if entry.log_path.is_empty() {
+        default_log_path(data_dir, &entry.name, &entry.id)
+    } else {
+        entry.log_path.clone()
+    }
+}
+
+/// Interleave several log streams into one chronological sequence of
+/// `(source index, line)` pairs.
+///
+/// Lines without a timestamp inherit the previous timestamp of their own
+/// stream so continuation output stays attached to its header. Each stream
+/// keeps its own order, and equal timestamps resolve to the earlier source.
+pub fn merge_log_streams(contents: &[&str]) -> Vec<(usize, String)> {
+    let keyed: Vec<Vec<(Option<&str>, &str)>> = contents
+        .iter()
+        .map(|content| {
+            let mut current = None;
+            content
+                .lines()
+                .map(|line| {
+                    if let Some(ts) = parse_log_timestamp(line) {
+                        current = Some(ts);
+                    }
+                    (current, line)
+                })
+                .collect()
+        })
+        .collect();
+    let total: usize = keyed.iter().map(Vec::len).sum();
+    let mut cursors = vec![0usize; keyed.len()];
+    let mut merged = Vec::with_capacity(total);
+
+    while merged.len() < total {
+        let mut next: Option<(usize, Option<&str>, &str)> = None;
+        for (source, lines) in keyed.iter().enumerate() {
+            let Some((ts, line)) = lines.get(cursors[source]) else {
+                continue;
+            };
+            if next.map_or(true, |(_, best, _)| *ts < best) {
+                next = Some((source, *ts, *line));
+            }
+        }
+        let Some((source, _, line)) = next else {
+            break;
+        };
+        merged.push((source, line.to_string()));
+        cursors[source] += 1;
+    }
+    merged
+}
+
+fn render_merged_sources(
+    sources: &[MergeSource],
+    contents: &[String],
+    render: RenderOptions,
+) -> String {
+    let borrowed: Vec<&str> = contents.iter().map(String::as_str).collect();
+    let merged = merge_log_streams(&borrowed);
+    let mut out = Vec::new();
+    for run in merged.chunk_by(|left, right| left.0 == right.0) {
+        let Some((source, _)) = run.first() else {
+            continue;
+        };
+        let tag = sources.get(*source).map_or("?", |entry| entry.tag.as_str());
+        let text = run
+            .iter()
+            .map(|(_, line)| line.as_str())
+            .collect::<Vec<_>>()
+            .join("\n");
+        for line in render_log_content(&text, render).lines() {
+            out.push(format!("[{tag}] {line}"));
+        }
+    }
+    out.join("\n")
+}
+
 fn resolve_database_path() -> PathBuf {
     crate::runtime_paths::resolve_database_path()
 }
@@ -410,20 +559,42 @@ fn execute(
         loops.retain(|entry| entry.repo == repo);
     }
 
-    if !parsed.loop_ref.is_empty() {
-        loops = match_loop_ref(&loops, &parsed.loop_ref)?;
+    if !parsed.loop_refs.is_empty() {
+        let mut selected: Vec<LoopRecord> = Vec::new();
+        for loop_ref in &parsed.loop_refs {
+            for entry in match_loop_ref(&loops, loop_ref)? {
+                if !selected.iter().any(|existing| existing.id == entry.id) {
+                    selected.push(entry);
+                }
+            }
+        }
+        loops = selected;
     }
 
     if loops.is_empty() {
         return Err("no loops matched".to_string());
     }
 
+    if parsed.merge {
+        let sources: Vec<MergeSource> = loops
+            .iter()
+            .map(|entry| MergeSource {
+                tag: short_id(entry).to_string(),
+                path: loop_log_path(backend.data_dir(), entry),
+            })
+            .collect();
+        if parsed.follow {
+            return backend.follow_merged_logs(&sources, parsed.lines, render, stdout);
+        }
+        let mut contents = Vec::with_capacity(sources.len());
+        for source in &sources {
+            contents.push(backend.read_log(&source.path, parsed.lines, &parsed.since)?);
+        }
+        return write_log_block(stdout, &render_merged_sources(&sources, &contents, render));
+    }
+
     for (index, entry) in loops.iter().enumerate() {
-        let path = if entry.log_path.is_empty() {
-            default_log_path(backend.data_dir(), &entry.name, &entry.id)
-        } else {
-            entry.log_path.clone()
-        };
+        let path = loop_log_path(backend.data_dir(), entry);
 
         if index > 0 {
             writeln!(stdout).map_err(|err| err.to_string())?;
@@ -452,6 +623,7 @@ fn parse_args(args: &[String]) -> Result<ParsedArgs, String> {
     }
 
     let mut all = false;
+    let mut merge = false;
     let mut follow = false;
     let mut lines: i32 = 50;
     let mut since = String::new();
@@ -482,6 +654,10 @@ fn parse_args(args: &[String]) -> Result<ParsedArgs, String> {
                 all = true;
                 index += 1;
             }
+            "--merge" => {
+                merge = true;
+                index += 1;
+            }
             "--no-color" => {
                 no_color = true;
                 index += 1;
@@ -504,18 +680,21 @@ fn parse_args(args: &[String]) -> Result<ParsedArgs, String> {
         }
     }
 
-    if positionals.len() > 1 {
-        return Err("error: accepts at most 1 argument, received multiple".to_string());
+    if positionals.len() > 1 && !merge {
+        return Err(
+            "error: accepts at most 1 argument, received multiple (use --merge to interleave loops)"
+                .to_string(),
+        );
     }
 
-    let loop_ref = positionals.into_iter().next().unwrap_or_default();
-    if loop_ref.is_empty() && !all {
+    if positionals.is_empty() && !all {
         return Err("loop name required (or use --all)".to_string());
     }
 
     Ok(ParsedArgs {
-        loop_ref,
+        loop_refs: positionals,
         all,
+        merge,
         follow,
         lines,
         since,
@@ -536,19 +715,6 @@ fn write_log_block(stdout: &mut dyn Write, content: &str) -> Result<(), String>
     Ok(())
 }
 
-fn split_complete_lines(input: &str) -> (String, String) {
-    if input.is_empty() {
-        return (String::new(), String::new());
-    }
-    if input.ends_with('\n') {
-        return (input.to_string(), String::new());
-    }
-    match input.rfind('\n') {
-        Some(index) => (input[..=index].to_string(), input[index + 1..].to_string()),
-        None => (String::new(), input.to_string()),
-    }
-}
-
 fn render_log_content(content: &str, options: RenderOptions) -> String {
     if options.raw || content.is_empty() {
         return content.to_string();
@@ -1229,12 +1395,14 @@ Tail loop logs.
 
 Usage:
   forge logs [loop]
+  forge logs <loop> <loop>... --merge
 
 Flags:
   -f, --follow      follow log output
   -n, --lines N     number of lines to show (default 50)
       --since VAL   show logs since duration or timestamp
       --all         show logs for all loops in repo
+      --merge       interleave logs from several loops by timestamp, tagged by loop id
       --compact     collapse thinking blocks and large code fences
       --raw         disable Claude stream-json rendering
       --no-color    disable colored log rendering
@@ -1243,8 +1411,8 @@ Flags:
 #[cfg(test)]
 mod tests {
     use super::{
-        default_log_path, render_lines_for_layer, render_log_chunk, run_for_test,
-        split_complete_lines, InMemoryLogsBackend, LogRenderLayer, LoopRecord, RenderOptions,
+        default_log_path, merge_log_streams, render_lines_for_layer, render_log_chunk,
+        run_for_test, InMemoryLogsBackend, LogRenderLayer, LoopRecord, RenderOptions,
     };
     use crate::diff_renderer::DiffRenderState;
 
@@ -1523,6 +1691,103 @@ mod tests {
         );
     }
 
+    fn merge_backend() -> InMemoryLogsBackend {
+        let alpha_path = "/tmp/forge/logs/loops/alpha.log";
+        let beta_path = "/tmp/forge/logs/loops/beta.log";
+        InMemoryLogsBackend::with_loops(vec![
+            LoopRecord {
+                id: "loop-001".to_string(),
+                short_id: "abc001".to_string(),
+                name: "alpha".to_string(),
+                repo: "/repo".to_string(),
+                log_path: alpha_path.to_string(),
+            },
+            LoopRecord {
+                id: "loop-002".to_string(),
+                short_id: "abc002".to_string(),
+                name: "beta".to_string(),
+                repo: "/repo".to_string(),
+                log_path: beta_path.to_string(),
+            },
+        ])
+        .with_log(
+            alpha_path,
+            "[2026-01-01T00:00:00Z] alpha start\n[2026-01-01T00:00:02Z] alpha claims lock\n  detail line\n[2026-01-01T00:00:04Z] alpha done\n",
+        )
+        .with_log(
+            beta_path,
+            "[2026-01-01T00:00:01Z] beta start\n[2026-01-01T00:00:02Z] beta waits on lock\n[2026-01-01T00:00:03Z] beta done\n",
+        )
+    }
+
+    #[test]
+    fn logs_merge_interleaves_sources_by_timestamp() {
+        let mut backend = merge_backend();
+        let out = run_for_test(
+            &["logs", "alpha", "beta", "--merge", "--no-color"],
+            &mut backend,
+        );
+        assert_eq!(out.exit_code, 0, "stderr: {}", out.stderr);
+        assert_eq!(
+            out.stdout,
+            "[abc001] [2026-01-01T00:00:00Z] alpha start\n\
+             [abc002] [2026-01-01T00:00:01Z] beta start\n\
+             [abc001] [2026-01-01T00:00:02Z] alpha claims lock\n\
+             [abc001]   detail line\n\
+             [abc002] [2026-01-01T00:00:02Z] beta waits on lock\n\
+             [abc002] [2026-01-01T00:00:03Z] beta done\n\
+             [abc001] [2026-01-01T00:00:04Z] alpha done\n"
+        );
+
+        let out = run_for_test(&["logs", "alpha", "beta", "--no-color"], &mut backend);
+        assert_eq!(out.exit_code, 1);
+        assert!(out.stderr.contains("--merge"));
+    }
+
+    #[test]
+    fn logs_merge_follow_uses_every_source() {
+        let mut backend = merge_backend().with_follow_output(
+            "/tmp/forge/logs/loops/beta.log",
+            "[2026-01-01T00:00:05Z] beta restarted\n",
+        );
+        let out = run_for_test(
+            &["logs", "beta", "alpha", "--merge", "--follow", "--raw"],
+            &mut backend,
+        );
+        assert_eq!(out.exit_code, 0, "stderr: {}", out.stderr);
+        assert_eq!(backend.followed_paths.len(), 2);
+        let lines: Vec<&str> = out.stdout.lines().collect();
+        assert_eq!(
+            lines.first(),
+            Some(&"[abc001] [2026-01-01T00:00:00Z] alpha start")
+        );
+        assert_eq!(
+            lines.last(),
+            Some(&"[abc002] [2026-01-01T00:00:05Z] beta restarted")
+        );
+    }
+
+    #[test]
+    fn merge_log_streams_is_stable_for_equal_timestamps() {
+        let merged = merge_log_streams(&[
+            "[2026-01-01T00:00:01Z] a1\n[2026-01-01T00:00:01Z] a2",
+            "[2026-01-01T00:00:01Z] b1\n[2026-01-01T00:00:00Z] b0",
+        ]);
+        let order: Vec<(usize, &str)> = merged
+            .iter()
+            .map(|(source, line)| (*source, line.as_str()))
+            .collect();
+        assert_eq!(
+            order,
+            vec![
+                (0, "[2026-01-01T00:00:01Z] a1"),
+                (0, "[2026-01-01T00:00:01Z] a2"),
+                (1, "[2026-01-01T00:00:01Z] b1"),
+                (1, "[2026-01-01T00:00:00Z] b0"),
+            ]
+        );
+    }
+
     #[test]
     fn logs_unknown_flag_is_error() {
         let mut backend = InMemoryLogsBackend::default();
@@ -1627,27 +1892,6 @@ mod tests {
         );
     }
 
-    #[test]
-    fn split_complete_lines_handles_trailing_partial() {
-        let (complete, rest) = split_complete_lines("one\ntwo\nthr");
-        assert_eq!(complete, "one\ntwo\n");
-        assert_eq!(rest, "thr");
-    }
-
-    #[test]
-    fn split_complete_lines_handles_full_block() {
-        let (complete, rest) = split_complete_lines("one\ntwo\n");
-        assert_eq!(complete, "one\ntwo\n");
-        assert_eq!(rest, "");
-    }
-
-    #[test]
-    fn split_complete_lines_handles_no_newline() {
-        let (complete, rest) = split_complete_lines("partial");
-        assert_eq!(complete, "");
-        assert_eq!(rest, "partial");
-    }
-
     #[test]
     fn render_log_chunk_carries_diff_run_across_boundaries() {
         let mut diff_state = DiffRenderState::default();
@@ -550,6 +550,7 @@ impl MailBackend for FilesystemMailBackend {
                 priority: String::new(),
                 host: String::new(),
                 tags: Vec::new(),
+                deliver_after: None,
             };
             let saved_id = store
                 .save_message(&mut message, now)
@@ -575,10 +576,14 @@ impl MailBackend for FilesystemMailBackend {
             .list_dm_message_files(&normalized_agent)
             .map_err(|err| format!("list inbox: {err}"))?;
 
+        let now = Utc::now();
         for path in paths {
-            let message = store
-                .read_message(&path)
-                .map_err(|err| format!("read message {}: {err}", path.display()))?;
+            let Some(message) = store
+                .read_due_message(&path, now)
+                .map_err(|err| format!("read message {}: {err}", path.display()))?
+            else {
+                continue;
+            };
             let local_id = if let Some(existing) = index.ids.get(&message.id) {
                 *existing
             } else {
@@ -17,6 +17,15 @@ pub struct LoopKVEntry {
     pub value: String,
 }
 
+/// A key/value entry found by a cross-loop search.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LoopKVMatch {
+    pub key: String,
+    pub loop_id: String,
+    pub loop_name: String,
+    pub value: String,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CommandOutput {
     pub stdout: String,
@@ -36,6 +45,13 @@ pub trait MemBackend {
     fn get(&self, loop_id: &str, key: &str) -> Result<LoopKVEntry, String>;
     fn list_by_loop(&self, loop_id: &str) -> Result<Vec<LoopKVEntry>, String>;
     fn delete(&mut self, loop_id: &str, key: &str) -> Result<(), String>;
+    /// Find entries in any loop whose key matches `key_glob` and whose value
+    /// contains `value_contains`.
+    fn search(
+        &self,
+        key_glob: Option<&str>,
+        value_contains: Option<&str>,
+    ) -> Result<Vec<LoopKVMatch>, String>;
 }
 
 #[derive(Debug, Clone, Default)]
@@ -162,15 +178,64 @@ impl MemBackend for InMemoryMemBackend {
         }
         Ok(())
     }
+
+    fn search(
+        &self,
+        key_glob: Option<&str>,
+        value_contains: Option<&str>,
+    ) -> Result<Vec<LoopKVMatch>, String> {
+        let mut matches = Vec::new();
+        for (loop_id, bucket) in &self.records {
+            for entry in bucket.values() {
+                if !entry_matches(entry, key_glob, value_contains) {
+                    continue;
+                }
+                matches.push(LoopKVMatch {
+                    key: entry.key.clone(),
+                    loop_id: loop_id.clone(),
+                    loop_name: self.loops_by_id.get(loop_id).cloned().unwrap_or_default(),
+                    value: entry.value.clone(),
+                });
+            }
+        }
+        Ok(matches)
+    }
+}
+
+fn entry_matches(
+    entry: &LoopKVEntry,
+    key_glob: Option<&str>,
+    value_contains: Option<&str>,
+) -> bool {
+    if let Some(pattern) = key_glob {
+        if !crate::lock::glob_match(pattern, &entry.key) {
+            return false;
+        }
+    }
+    match value_contains {
+        Some(needle) => entry.value.contains(needle),
+        None => true,
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 enum Command {
     Help,
-    Set { key: String, value: String },
-    Get { key: String },
+    Set {
+        key: String,
+        value: String,
+    },
+    Get {
+        key: String,
+    },
     List,
-    Remove { key: String },
+    Remove {
+        key: String,
+    },
+    Search {
+        key_glob: Option<String>,
+        value_contains: Option<String>,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -296,6 +361,37 @@ fn execute(
             }
             Ok(())
         }
+        Command::Search {
+            key_glob,
+            value_contains,
+        } => {
+            let mut matches = backend.search(key_glob.as_deref(), value_contains.as_deref())?;
+            matches.sort_by(|left, right| {
+                (&left.loop_name, &left.loop_id, &left.key).cmp(&(
+                    &right.loop_name,
+                    &right.loop_id,
+                    &right.key,
+                ))
+            });
+            if parsed.json || parsed.jsonl {
+                write_serialized(stdout, &matches, parsed.jsonl)?;
+                return Ok(());
+            }
+            if matches.is_empty() {
+                writeln!(stdout, "(no matches)").map_err(|err| err.to_string())?;
+                return Ok(());
+            }
+            for item in matches {
+                let loop_label = if item.loop_name.is_empty() {
+                    &item.loop_id
+                } else {
+                    &item.loop_name
+                };
+                writeln!(stdout, "{loop_label} {}={}", item.key, item.value)
+                    .map_err(|err| err.to_string())?;
+            }
+            Ok(())
+        }
     }
 }
 
@@ -368,6 +464,7 @@ fn parse_args(args: &[String]) -> Result<ParsedArgs, String> {
             Command::List
         }
         Some("rm") | Some("remove") => parse_rm_args(&subcommand_args)?,
+        Some("search") | Some("find") => parse_search_args(&subcommand_args)?,
         Some(other) => return Err(format!("unknown mem argument: {other}")),
     };
 
@@ -433,6 +530,38 @@ fn parse_rm_args(args: &[String]) -> Result<Command, String> {
     }
 }
 
+fn parse_search_args(args: &[String]) -> Result<Command, String> {
+    let mut key_glob: Option<String> = None;
+    let mut value_contains: Option<String> = None;
+    let mut idx = 0;
+    while idx < args.len() {
+        match args[idx].as_str() {
+            "--key-glob" => {
+                key_glob = Some(next_value(args, idx, "--key-glob")?.trim().to_string());
+                idx += 2;
+            }
+            "--value-contains" => {
+                value_contains = Some(next_value(args, idx, "--value-contains")?.to_string());
+                idx += 2;
+            }
+            other if other.starts_with("--") => {
+                return Err(format!("unknown mem search flag: {other}"));
+            }
+            other => return Err(format!("unexpected argument for mem search: {other}")),
+        }
+    }
+
+    let key_glob = key_glob.filter(|value| !value.is_empty());
+    let value_contains = value_contains.filter(|value| !value.is_empty());
+    if key_glob.is_none() && value_contains.is_none() {
+        return Err("mem search requires --key-glob or --value-contains".to_string());
+    }
+    Ok(Command::Search {
+        key_glob,
+        value_contains,
+    })
+}
+
 fn ensure_empty_args(command: &str, args: &[String]) -> Result<(), String> {
     if let Some(first) = args.first() {
         return Err(format!("unexpected argument for {command}: {first}"));
@@ -525,6 +654,7 @@ fn write_help(stdout: &mut dyn Write) -> std::io::Result<()> {
     writeln!(stdout, "  get <key>          Get a memory key")?;
     writeln!(stdout, "  ls                 List memory keys")?;
     writeln!(stdout, "  rm <key>           Remove a memory key")?;
+    writeln!(stdout, "  search             Search keys across all loops")?;
     writeln!(stdout)?;
     writeln!(stdout, "Flags:")?;
     writeln!(
@@ -537,6 +667,16 @@ fn write_help(stdout: &mut dyn Write) -> std::io::Result<()> {
         stdout,
         "  --quiet       suppress human output for mutating commands"
     )?;
+    writeln!(stdout)?;
+    writeln!(stdout, "Search Flags:")?;
+    writeln!(
+        stdout,
+        "  --key-glob <glob>        match keys (* and ? wildcards)"
+    )?;
+    writeln!(
+        stdout,
+        "  --value-contains <text>  match values containing text"
+    )?;
     Ok(())
 }
 
@@ -1,6 +1,6 @@
 use std::path::PathBuf;
 
-use super::{LoopEntry, LoopKVEntry, MemBackend};
+use super::{LoopEntry, LoopKVEntry, LoopKVMatch, MemBackend};
 
 #[derive(Debug, Clone)]
 pub struct SqliteMemBackend {
@@ -98,6 +98,36 @@ impl MemBackend for SqliteMemBackend {
         let repo = forge_db::LoopKVRepository::new(&db);
         repo.delete(loop_id, key).map_err(map_repo_error)
     }
+
+    fn search(
+        &self,
+        key_glob: Option<&str>,
+        value_contains: Option<&str>,
+    ) -> Result<Vec<LoopKVMatch>, String> {
+        if !self.db_path.exists() {
+            return Ok(Vec::new());
+        }
+        let names: std::collections::HashMap<String, String> = self
+            .list_loops()?
+            .into_iter()
+            .map(|entry| (entry.id, entry.name))
+            .collect();
+
+        let db = self.open_db()?;
+        let repo = forge_db::LoopKVRepository::new(&db);
+        let items = repo
+            .search(key_glob, value_contains)
+            .map_err(map_repo_error)?;
+        Ok(items
+            .into_iter()
+            .map(|entry| LoopKVMatch {
+                loop_name: names.get(&entry.loop_id).cloned().unwrap_or_default(),
+                key: entry.key,
+                loop_id: entry.loop_id,
+                value: entry.value,
+            })
+            .collect())
+    }
 }
 
 fn map_repo_error(err: forge_db::DbError) -> String {
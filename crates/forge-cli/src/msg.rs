@@ -2,6 +2,7 @@ use std::collections::BTreeMap;
 use std::io::Write;
 use std::path::{Path, PathBuf};
 
+use forge_loop::queue_interactions::QueuePriority;
 use serde::Serialize;
 use serde_json::json;
 
@@ -300,6 +301,7 @@ struct ParsedArgs {
     jsonl: bool,
     quiet: bool,
     now: bool,
+    priority: QueuePriority,
     next_prompt: String,
     template: String,
     sequence: String,
@@ -395,13 +397,15 @@ fn execute(
 
         if !message.trim().is_empty() {
             if parsed.now {
-                let payload = json!({ "message": message });
+                let mut payload = json!({ "message": message });
+                with_priority(&mut payload, parsed.priority);
                 items.push(QueueItem {
                     item_type: "steer_message".to_string(),
                     payload: serde_json::to_string(&payload).map_err(|err| err.to_string())?,
                 });
             } else {
-                let payload = json!({ "text": message });
+                let mut payload = json!({ "text": message });
+                with_priority(&mut payload, parsed.priority);
                 items.push(QueueItem {
                     item_type: "message_append".to_string(),
                     payload: serde_json::to_string(&payload).map_err(|err| err.to_string())?,
@@ -441,6 +445,15 @@ fn execute(
     Ok(())
 }
 
+fn with_priority(payload: &mut serde_json::Value, priority: QueuePriority) {
+    if priority == QueuePriority::Normal {
+        return;
+    }
+    if let Some(object) = payload.as_object_mut() {
+        object.insert("priority".to_string(), json!(priority.as_str()));
+    }
+}
+
 fn parse_args(args: &[String]) -> Result<ParsedArgs, String> {
     let mut index = 0usize;
     if args.get(index).is_some_and(|token| token == "msg") {
@@ -451,6 +464,7 @@ fn parse_args(args: &[String]) -> Result<ParsedArgs, String> {
     let mut jsonl = false;
     let mut quiet = false;
     let mut now = false;
+    let mut priority = QueuePriority::Normal;
     let mut next_prompt = String::new();
     let mut template = String::new();
     let mut sequence = String::new();
@@ -479,6 +493,13 @@ fn parse_args(args: &[String]) -> Result<ParsedArgs, String> {
                 now = true;
                 index += 1;
             }
+            "--priority" => {
+                let value = take_value(args, index, "--priority")?;
+                priority = QueuePriority::parse(&value).ok_or_else(|| {
+                    format!("invalid --priority {value:?} (use low, normal, high, or urgent)")
+                })?;
+                index += 2;
+            }
             "--next-prompt" => {
                 next_prompt = take_value(args, index, "--next-prompt")?;
                 index += 2;
@@ -571,6 +592,7 @@ fn parse_args(args: &[String]) -> Result<ParsedArgs, String> {
         jsonl,
         quiet,
         now,
+        priority,
         next_prompt,
         template,
         sequence,
@@ -736,7 +758,8 @@ fn map_loop_state(state: &forge_db::loop_repository::LoopState) -> LoopState {
     match state {
         forge_db::loop_repository::LoopState::Running
         | forge_db::loop_repository::LoopState::Sleeping
-        | forge_db::loop_repository::LoopState::Waiting => LoopState::Running,
+        | forge_db::loop_repository::LoopState::Waiting
+        | forge_db::loop_repository::LoopState::Paused => LoopState::Running,
         forge_db::loop_repository::LoopState::Stopped => LoopState::Stopped,
         forge_db::loop_repository::LoopState::Error => LoopState::Error,
     }
@@ -752,6 +775,7 @@ Flags:
       --all               target all loops
       --now               interrupt and restart immediately
       --next-prompt path  override prompt for next iteration
+      --priority level    message priority: low, normal, high, urgent
       --repo path         filter by repo path
       --template name     message template name
       --seq name          sequence name
@@ -819,6 +843,38 @@ mod tests {
         assert_eq!(items[0].payload, "{\"text\":\"rendered text\"}");
     }
 
+    #[test]
+    fn priority_is_recorded_in_the_message_payload() {
+        let mut backend = seeded();
+        let out = run_for_test(
+            &[
+                "msg",
+                "oracle-loop",
+                "--priority",
+                "high",
+                "fix the build",
+                "--json",
+            ],
+            &mut backend,
+        );
+        assert_eq!(out.exit_code, 0);
+        let (_, items) = &backend.enqueued[0];
+        assert_eq!(
+            items[0].payload,
+            "{\"priority\":\"high\",\"text\":\"fix the build\"}"
+        );
+
+        let out = run_for_test(
+            &["msg", "oracle-loop", "--priority", "soon", "hi"],
+            &mut backend,
+        );
+        assert_eq!(out.exit_code, 1);
+        assert_eq!(
+            out.stderr,
+            "invalid --priority \"soon\" (use low, normal, high, or urgent)\n"
+        );
+    }
+
     #[test]
     fn sequence_and_next_prompt_are_enqueued_in_order() {
         let mut backend = seeded()
@@ -0,0 +1,522 @@
+use std::io::Write;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use forge_rpc::forged::v1 as proto;
+use forge_rpc::forged::v1::forged_service_client::ForgedServiceClient;
+use serde::Serialize;
+use serde_json::Value;
+use tonic::transport::Endpoint;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandOutput {
+    pub stdout: String,
+    pub stderr: String,
+    pub exit_code: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LoopState {
+    Pending,
+    Running,
+    Sleeping,
+    Waiting,
+    Paused,
+    Stopped,
+    Error,
+}
+
+impl LoopState {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Running => "running",
+            Self::Sleeping => "sleeping",
+            Self::Waiting => "waiting",
+            Self::Paused => "paused",
+            Self::Stopped => "stopped",
+            Self::Error => "error",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LoopRecord {
+    pub id: String,
+    pub short_id: String,
+    pub name: String,
+    pub state: LoopState,
+    pub runner_owner: String,
+}
+
+pub trait PauseBackend {
+    fn list_loops(&self) -> Result<Vec<LoopRecord>, String>;
+    /// Pause the daemon runner and persist the paused state.
+    fn pause_loop(&mut self, loop_id: &str) -> Result<(), String>;
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryPauseBackend {
+    loops: Vec<LoopRecord>,
+    pub daemon_paused: Vec<String>,
+}
+
+impl InMemoryPauseBackend {
+    pub fn with_loops(loops: Vec<LoopRecord>) -> Self {
+        Self {
+            loops,
+            daemon_paused: Vec::new(),
+        }
+    }
+}
+
+impl PauseBackend for InMemoryPauseBackend {
+    fn list_loops(&self) -> Result<Vec<LoopRecord>, String> {
+        Ok(self.loops.clone())
+    }
+
+    fn pause_loop(&mut self, loop_id: &str) -> Result<(), String> {
+        let Some(loop_entry) = self.loops.iter_mut().find(|entry| entry.id == loop_id) else {
+            return Err(format!("loop {loop_id} not found"));
+        };
+        self.daemon_paused.push(loop_id.to_string());
+        loop_entry.state = LoopState::Paused;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SqlitePauseBackend {
+    db_path: PathBuf,
+}
+
+impl SqlitePauseBackend {
+    pub fn open_from_env() -> Self {
+        Self {
+            db_path: crate::runtime_paths::resolve_database_path(),
+        }
+    }
+
+    pub fn new(db_path: PathBuf) -> Self {
+        Self { db_path }
+    }
+
+    fn open_db(&self) -> Result<forge_db::Db, String> {
+        forge_db::Db::open(forge_db::Config::new(&self.db_path))
+            .map_err(|err| format!("open database {}: {err}", self.db_path.display()))
+    }
+}
+
+impl PauseBackend for SqlitePauseBackend {
+    fn list_loops(&self) -> Result<Vec<LoopRecord>, String> {
+        if !self.db_path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let db = self.open_db()?;
+        let loop_repo = forge_db::loop_repository::LoopRepository::new(&db);
+        let loops = match loop_repo.list() {
+            Ok(loops) => loops,
+            Err(err) if err.to_string().contains("no such table: loops") => return Ok(Vec::new()),
+            Err(err) => return Err(err.to_string()),
+        };
+
+        Ok(loops
+            .into_iter()
+            .map(|entry| LoopRecord {
+                id: entry.id.clone(),
+                short_id: if entry.short_id.is_empty() {
+                    entry.id
+                } else {
+                    entry.short_id
+                },
+                name: entry.name,
+                state: map_loop_state(&entry.state),
+                runner_owner: metadata_string(entry.metadata.as_ref(), "runner_owner"),
+            })
+            .collect())
+    }
+
+    fn pause_loop(&mut self, loop_id: &str) -> Result<(), String> {
+        signal_daemon_runner(loop_id, RunnerSignal::Pause)?;
+        let db = self.open_db()?;
+        let loop_repo = forge_db::loop_repository::LoopRepository::new(&db);
+        let mut loop_entry = loop_repo.get(loop_id).map_err(|err| err.to_string())?;
+        loop_entry.state = forge_db::loop_repository::LoopState::Paused;
+        loop_repo
+            .update(&mut loop_entry)
+            .map_err(|err| err.to_string())
+    }
+}
+
+/// Which daemon RPC [`signal_daemon_runner`] issues.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RunnerSignal {
+    Pause,
+    Resume,
+}
+
+/// Ask forged to pause or resume a daemon-owned loop runner.
+pub fn signal_daemon_runner(loop_id: &str, signal: RunnerSignal) -> Result<(), String> {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .map_err(|err| format!("initialize daemon RPC runtime: {err}"))?;
+    runtime.block_on(signal_daemon_runner_async(loop_id, signal))
+}
+
+async fn signal_daemon_runner_async(loop_id: &str, signal: RunnerSignal) -> Result<(), String> {
+    let endpoint = Endpoint::from_shared(crate::daemon_target::resolve_daemon_target(""))
+        .map_err(|err| format!("forged daemon unavailable: {err}"))?
+        .connect_timeout(Duration::from_secs(2))
+        .timeout(Duration::from_secs(2));
+
+    let channel = endpoint
+        .connect()
+        .await
+        .map_err(|err| format!("forged daemon unavailable: {err}"))?;
+
+    let mut client = ForgedServiceClient::new(channel);
+    let result = match signal {
+        RunnerSignal::Pause => client
+            .pause_loop_runner(proto::PauseLoopRunnerRequest {
+                loop_id: loop_id.to_string(),
+            })
+            .await
+            .map(|_| ()),
+        RunnerSignal::Resume => client
+            .resume_loop_runner(proto::ResumeLoopRunnerRequest {
+                loop_id: loop_id.to_string(),
+            })
+            .await
+            .map(|_| ()),
+    };
+    let action = match signal {
+        RunnerSignal::Pause => "pause",
+        RunnerSignal::Resume => "resume",
+    };
+    result.map_err(|status| format!("failed to {action} loop via daemon: {}", status.message()))
+}
+
+fn map_loop_state(state: &forge_db::loop_repository::LoopState) -> LoopState {
+    match state {
+        forge_db::loop_repository::LoopState::Running => LoopState::Running,
+        forge_db::loop_repository::LoopState::Sleeping => LoopState::Sleeping,
+        forge_db::loop_repository::LoopState::Waiting => LoopState::Waiting,
+        forge_db::loop_repository::LoopState::Paused => LoopState::Paused,
+        forge_db::loop_repository::LoopState::Stopped => LoopState::Stopped,
+        forge_db::loop_repository::LoopState::Error => LoopState::Error,
+    }
+}
+
+fn metadata_string(
+    metadata: Option<&std::collections::HashMap<String, Value>>,
+    key: &str,
+) -> String {
+    metadata
+        .and_then(|map| map.get(key))
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .to_string()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ParsedArgs {
+    loop_ref: String,
+    json: bool,
+    jsonl: bool,
+    quiet: bool,
+}
+
+pub fn run_for_test(args: &[&str], backend: &mut dyn PauseBackend) -> CommandOutput {
+    let owned_args: Vec<String> = args.iter().map(|arg| (*arg).to_string()).collect();
+    let mut stdout = Vec::new();
+    let mut stderr = Vec::new();
+    let exit_code = run_with_backend(&owned_args, backend, &mut stdout, &mut stderr);
+    CommandOutput {
+        stdout: String::from_utf8_lossy(&stdout).into_owned(),
+        stderr: String::from_utf8_lossy(&stderr).into_owned(),
+        exit_code,
+    }
+}
+
+pub fn run_with_backend(
+    args: &[String],
+    backend: &mut dyn PauseBackend,
+    stdout: &mut dyn Write,
+    stderr: &mut dyn Write,
+) -> i32 {
+    match execute(args, backend, stdout) {
+        Ok(()) => 0,
+        Err(message) => {
+            let _ = writeln!(stderr, "{message}");
+            1
+        }
+    }
+}
+
+fn execute(
+    args: &[String],
+    backend: &mut dyn PauseBackend,
+    stdout: &mut dyn Write,
+) -> Result<(), String> {
+    let parsed = parse_args(args)?;
+    let loops = backend.list_loops()?;
+    let loop_entry = match_loop_ref(&loops, &parsed.loop_ref)?;
+
+    let hint = match loop_entry.state {
+        LoopState::Running | LoopState::Sleeping | LoopState::Waiting => None,
+        LoopState::Paused => Some(format!(
+            "continue it with: forge resume {}",
+            loop_entry.short_id
+        )),
+        LoopState::Stopped | LoopState::Error => Some(format!(
+            "restart it with: forge resume {}",
+            loop_entry.short_id
+        )),
+        LoopState::Pending => Some("wait for its runner to start, then pause it".to_string()),
+    };
+    if let Some(hint) = hint {
+        return Err(format!(
+            "loop \"{}\" is {}; only live loops can be paused ({hint})",
+            loop_entry.name,
+            loop_entry.state.as_str()
+        ));
+    }
+    if !loop_entry.runner_owner.eq_ignore_ascii_case("daemon") {
+        return Err(format!(
+            "loop \"{}\" is not owned by forged; only daemon runners can be paused",
+            loop_entry.name
+        ));
+    }
+
+    backend.pause_loop(&loop_entry.id)?;
+
+    if parsed.json || parsed.jsonl {
+        let payload = serde_json::json!({
+            "paused": true,
+            "loop_id": loop_entry.id,
+            "name": loop_entry.name,
+        });
+        if parsed.jsonl {
+            serde_json::to_writer(&mut *stdout, &payload).map_err(|err| err.to_string())?;
+        } else {
+            serde_json::to_writer_pretty(&mut *stdout, &payload).map_err(|err| err.to_string())?;
+        }
+        writeln!(stdout).map_err(|err| err.to_string())?;
+        return Ok(());
+    }
+
+    if parsed.quiet {
+        return Ok(());
+    }
+
+    writeln!(
+        stdout,
+        "Loop \"{}\" paused ({}); continue it with: forge resume {}",
+        loop_entry.name,
+        short_id(&loop_entry),
+        short_id(&loop_entry)
+    )
+    .map_err(|err| err.to_string())?;
+    Ok(())
+}
+
+fn parse_args(args: &[String]) -> Result<ParsedArgs, String> {
+    let mut index = 0usize;
+    if args.get(index).is_some_and(|arg| arg == "pause") {
+        index += 1;
+    }
+
+    let mut json = false;
+    let mut jsonl = false;
+    let mut quiet = false;
+    let mut loop_ref = String::new();
+
+    while let Some(token) = args.get(index) {
+        match token.as_str() {
+            "--json" => json = true,
+            "--jsonl" => jsonl = true,
+            "--quiet" => quiet = true,
+            "--help" | "-h" => {
+                return Err("usage: pause <loop> [--json|--jsonl] [--quiet]".to_string());
+            }
+            flag if flag.starts_with('-') => {
+                return Err(format!("error: unknown argument for pause: '{flag}'"));
+            }
+            value => {
+                if !loop_ref.is_empty() {
+                    return Err("pause accepts exactly 1 loop reference".to_string());
+                }
+                loop_ref = value.to_string();
+            }
+        }
+        index += 1;
+    }
+
+    if json && jsonl {
+        return Err("error: --json and --jsonl cannot be used together".to_string());
+    }
+    if loop_ref.trim().is_empty() {
+        return Err("loop name or ID required".to_string());
+    }
+
+    Ok(ParsedArgs {
+        loop_ref,
+        json,
+        jsonl,
+        quiet,
+    })
+}
+
+fn match_loop_ref(loops: &[LoopRecord], loop_ref: &str) -> Result<LoopRecord, String> {
+    if loops.is_empty() {
+        return Err(format!("loop \"{loop_ref}\" not found"));
+    }
+
+    if let Some(entry) = loops
+        .iter()
+        .find(|entry| short_id(entry).eq_ignore_ascii_case(loop_ref))
+    {
+        return Ok(entry.clone());
+    }
+    if let Some(entry) = loops.iter().find(|entry| entry.id == loop_ref) {
+        return Ok(entry.clone());
+    }
+    if let Some(entry) = loops.iter().find(|entry| entry.name == loop_ref) {
+        return Ok(entry.clone());
+    }
+
+    let normalized = loop_ref.to_ascii_lowercase();
+    let mut prefix_matches: Vec<LoopRecord> = loops
+        .iter()
+        .filter(|entry| {
+            short_id(entry)
+                .to_ascii_lowercase()
+                .starts_with(&normalized)
+                || entry.id.starts_with(loop_ref)
+        })
+        .cloned()
+        .collect();
+
+    if prefix_matches.len() == 1 {
+        return Ok(prefix_matches.remove(0));
+    }
+    if !prefix_matches.is_empty() {
+        prefix_matches.sort_by(|left, right| left.name.cmp(&right.name));
+        let labels = prefix_matches
+            .iter()
+            .map(|entry| format!("{} ({})", entry.name, short_id(entry)))
+            .collect::<Vec<String>>()
+            .join(", ");
+        return Err(format!(
+            "loop '{loop_ref}' is ambiguous; matches: {labels} (use a longer prefix or full ID)"
+        ));
+    }
+
+    Err(format!("loop \"{loop_ref}\" not found"))
+}
+
+fn short_id(entry: &LoopRecord) -> &str {
+    if entry.short_id.is_empty() {
+        &entry.id
+    } else {
+        &entry.short_id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{run_for_test, InMemoryPauseBackend, LoopRecord, LoopState, PauseBackend};
+
+    fn daemon_loop(state: LoopState, owner: &str) -> LoopRecord {
+        LoopRecord {
+            id: "loop-1".to_string(),
+            short_id: "abc123".to_string(),
+            name: "demo".to_string(),
+            state,
+            runner_owner: owner.to_string(),
+        }
+    }
+
+    #[test]
+    fn pause_running_daemon_loop_persists_paused_state() {
+        let mut backend =
+            InMemoryPauseBackend::with_loops(vec![daemon_loop(LoopState::Running, "daemon")]);
+        let out = run_for_test(&["pause", "demo"], &mut backend);
+        assert_eq!(out.exit_code, 0, "stderr: {}", out.stderr);
+        assert_eq!(
+            out.stdout,
+            "Loop \"demo\" paused (abc123); continue it with: forge resume abc123\n"
+        );
+        assert_eq!(backend.daemon_paused, vec!["loop-1".to_string()]);
+        let listed = backend
+            .list_loops()
+            .unwrap_or_else(|err| panic!("list loops: {err}"));
+        assert_eq!(listed[0].state, LoopState::Paused);
+    }
+
+    #[test]
+    fn pause_sleeping_and_waiting_daemon_loops() {
+        for state in [LoopState::Sleeping, LoopState::Waiting] {
+            let mut backend = InMemoryPauseBackend::with_loops(vec![daemon_loop(state, "daemon")]);
+            let out = run_for_test(&["pause", "demo"], &mut backend);
+            assert_eq!(out.exit_code, 0, "stderr: {}", out.stderr);
+            assert_eq!(backend.daemon_paused, vec!["loop-1".to_string()]);
+            let listed = backend
+                .list_loops()
+                .unwrap_or_else(|err| panic!("list loops: {err}"));
+            assert_eq!(listed[0].state, LoopState::Paused);
+        }
+    }
+
+    #[test]
+    fn pause_rejects_non_running_and_local_loops() {
+        let mut backend =
+            InMemoryPauseBackend::with_loops(vec![daemon_loop(LoopState::Stopped, "daemon")]);
+        let out = run_for_test(&["pause", "abc"], &mut backend);
+        assert_eq!(out.exit_code, 1);
+        assert_eq!(
+            out.stderr,
+            "loop \"demo\" is stopped; only live loops can be paused (restart it with: forge resume abc123)\n"
+        );
+
+        let mut backend =
+            InMemoryPauseBackend::with_loops(vec![daemon_loop(LoopState::Paused, "daemon")]);
+        let out = run_for_test(&["pause", "abc"], &mut backend);
+        assert_eq!(out.exit_code, 1);
+        assert_eq!(
+            out.stderr,
+            "loop \"demo\" is paused; only live loops can be paused (continue it with: forge resume abc123)\n"
+        );
+
+        let mut backend =
+            InMemoryPauseBackend::with_loops(vec![daemon_loop(LoopState::Pending, "daemon")]);
+        let out = run_for_test(&["pause", "abc"], &mut backend);
+        assert_eq!(out.exit_code, 1);
+        assert!(out
+            .stderr
+            .contains("(wait for its runner to start, then pause it)"));
+        assert!(backend.daemon_paused.is_empty());
+
+        let mut backend =
+            InMemoryPauseBackend::with_loops(vec![daemon_loop(LoopState::Running, "local")]);
+        let out = run_for_test(&["pause", "demo"], &mut backend);
+        assert_eq!(out.exit_code, 1);
+        assert!(out.stderr.contains("only daemon runners can be paused"));
+        assert!(backend.daemon_paused.is_empty());
+    }
+
+    #[test]
+    fn pause_json_output() {
+        let mut backend =
+            InMemoryPauseBackend::with_loops(vec![daemon_loop(LoopState::Running, "daemon")]);
+        let out = run_for_test(&["pause", "demo", "--jsonl"], &mut backend);
+        assert_eq!(out.exit_code, 0, "stderr: {}", out.stderr);
+        assert_eq!(
+            out.stdout,
+            "{\"loop_id\":\"loop-1\",\"name\":\"demo\",\"paused\":true}\n"
+        );
+    }
+}
@@ -3,6 +3,9 @@ use std::env;
 use std::io::Write;
 use std::path::PathBuf;
 
+use forge_loop::profile_selection::{
+    weighted_selection_order, WeightedCandidate, WeightedRoundRobin, WEIGHTED_STATE_METADATA_KEY,
+};
 use serde::Serialize;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -34,6 +37,21 @@ pub struct PoolView {
     pub members: Vec<PoolMemberView>,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PoolMemberWeight {
+    pub profile_id: String,
+    pub profile_name: String,
+    pub weight: u32,
+    pub cooldown_until_epoch: Option<i64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+struct PoolOrderView {
+    pool: String,
+    members: Vec<PoolMemberWeight>,
+    order: Vec<String>,
+}
+
 pub trait PoolBackend {
     fn list_pools(&self) -> Result<Vec<Pool>, String>;
     fn create_pool(&mut self, name: &str, strategy: &str) -> Result<Pool, String>;
@@ -44,6 +62,16 @@ pub trait PoolBackend {
     ) -> Result<(Pool, Vec<String>), String>;
     fn show_pool(&self, pool_ref: &str) -> Result<PoolView, String>;
     fn set_default(&mut self, pool_ref: &str) -> Result<Pool, String>;
+    fn set_member_weight(
+        &mut self,
+        pool_ref: &str,
+        profile_ref: &str,
+        weight: u32,
+    ) -> Result<Pool, String>;
+    fn member_weights(&self, pool_ref: &str) -> Result<(Pool, Vec<PoolMemberWeight>), String>;
+    /// The weighted rotation the scheduler saved for the pool, so previews
+    /// continue from the same credits.
+    fn rotation_state(&self, pool_ref: &str) -> Result<WeightedRoundRobin, String>;
 }
 
 #[derive(Debug, Clone)]
@@ -53,12 +81,15 @@ struct InMemoryMember {
     harness: String,
     auth_kind: String,
     position: usize,
+    weight: u32,
+    cooldown_until_epoch: Option<i64>,
 }
 
 #[derive(Debug, Clone, Default)]
 pub struct InMemoryPoolBackend {
     pools: Vec<Pool>,
     members: BTreeMap<String, Vec<InMemoryMember>>,
+    rotations: BTreeMap<String, WeightedRoundRobin>,
     next_pool_id: usize,
     next_profile_id: usize,
 }
@@ -83,6 +114,27 @@ impl InMemoryPoolBackend {
     fn has_default_pool(&self) -> bool {
         self.pools.iter().any(|pool| pool.is_default)
     }
+
+    /// Seed the saved weighted rotation for a pool, as the scheduler would
+    /// have left it.
+    pub fn with_rotation_state(mut self, pool_ref: &str, encoded: &str) -> Self {
+        if let Some(index) = self.resolve_pool_index(pool_ref) {
+            let pool_id = self.pools[index].id.clone();
+            self.rotations
+                .insert(pool_id, WeightedRoundRobin::decode(encoded));
+        }
+        self
+    }
+
+    /// Put a member's profile on cooldown until `until_epoch` (unix seconds).
+    pub fn with_member_cooldown(mut self, profile_name: &str, until_epoch: i64) -> Self {
+        for member in self.members.values_mut().flatten() {
+            if member.profile_name == profile_name {
+                member.cooldown_until_epoch = Some(until_epoch);
+            }
+        }
+        self
+    }
 }
 
 impl PoolBackend for InMemoryPoolBackend {
@@ -136,6 +188,8 @@ impl PoolBackend for InMemoryPoolBackend {
                 harness: String::new(),
                 auth_kind: String::new(),
                 position: next_position,
+                weight: 1,
+                cooldown_until_epoch: None,
             });
             added.push(profile.clone());
         }
@@ -177,6 +231,64 @@ impl PoolBackend for InMemoryPoolBackend {
         }
         Ok(self.pools[target].clone())
     }
+
+    fn set_member_weight(
+        &mut self,
+        pool_ref: &str,
+        profile_ref: &str,
+        weight: u32,
+    ) -> Result<Pool, String> {
+        let pool = match self.resolve_pool_index(pool_ref) {
+            Some(index) => self.pools[index].clone(),
+            None => return Err(format!("pool not found: {pool_ref}")),
+        };
+        let member = self
+            .members
+            .get_mut(&pool.id)
+            .and_then(|bucket| {
+                bucket.iter_mut().find(|member| {
+                    member.profile_name == profile_ref || member.profile_id == profile_ref
+                })
+            })
+            .ok_or_else(|| {
+                format!(
+                    "profile {profile_ref} is not a member of pool {}",
+                    pool.name
+                )
+            })?;
+        member.weight = weight;
+        Ok(pool)
+    }
+
+    fn member_weights(&self, pool_ref: &str) -> Result<(Pool, Vec<PoolMemberWeight>), String> {
+        let pool = match self.resolve_pool_index(pool_ref) {
+            Some(index) => self.pools[index].clone(),
+            None => return Err(format!("pool not found: {pool_ref}")),
+        };
+        let mut members = self.members.get(&pool.id).cloned().unwrap_or_default();
+        members.sort_by_key(|member| member.position);
+        let weights = members
+            .into_iter()
+            .map(|member| PoolMemberWeight {
+                profile_id: member.profile_id,
+                profile_name: member.profile_name,
+                weight: member.weight,
+                cooldown_until_epoch: member.cooldown_until_epoch,
+            })
+            .collect();
+        Ok((pool, weights))
+    }
+
+    fn rotation_state(&self, pool_ref: &str) -> Result<WeightedRoundRobin, String> {
+        let Some(index) = self.resolve_pool_index(pool_ref) else {
+            return Err(format!("pool not found: {pool_ref}"));
+        };
+        Ok(self
+            .rotations
+            .get(&self.pools[index].id)
+            .cloned()
+            .unwrap_or_default())
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -347,6 +459,76 @@ impl PoolBackend for SqlitePoolBackend {
         let updated = repo.get(&pool.id).map_err(|e| e.to_string())?;
         Ok(Self::db_pool_to_cli(&updated))
     }
+
+    fn set_member_weight(
+        &mut self,
+        pool_ref: &str,
+        profile_ref: &str,
+        weight: u32,
+    ) -> Result<Pool, String> {
+        let db = self.open_db()?;
+        let pool_repo = forge_db::pool_repository::PoolRepository::new(&db);
+        let profile_repo = forge_db::profile_repository::ProfileRepository::new(&db);
+
+        let pool = Self::resolve_pool(&pool_repo, pool_ref)?;
+        let profile = Self::resolve_profile(&profile_repo, profile_ref)?;
+        pool_repo
+            .set_member_weight(&pool.id, &profile.id, i64::from(weight))
+            .map_err(|err| match err {
+                forge_db::DbError::PoolNotFound => format!(
+                    "profile {} is not a member of pool {}",
+                    profile.name, pool.name
+                ),
+                other => other.to_string(),
+            })?;
+        Ok(Self::db_pool_to_cli(&pool))
+    }
+
+    fn member_weights(&self, pool_ref: &str) -> Result<(Pool, Vec<PoolMemberWeight>), String> {
+        let db = self.open_db()?;
+        let pool_repo = forge_db::pool_repository::PoolRepository::new(&db);
+        let profile_repo = forge_db::profile_repository::ProfileRepository::new(&db);
+
+        let pool = Self::resolve_pool(&pool_repo, pool_ref)?;
+        let members = pool_repo
+            .list_members(&pool.id)
+            .map_err(|e| e.to_string())?;
+
+        let mut weights = Vec::new();
+        for member in &members {
+            let profile = profile_repo
+                .get(&member.profile_id)
+                .map_err(|err| format!("pool {} member {}: {err}", pool.name, member.profile_id))?;
+            let cooldown_until_epoch = profile
+                .cooldown_until
+                .as_deref()
+                .and_then(|value| chrono::DateTime::parse_from_rfc3339(value).ok())
+                .map(|value| value.timestamp());
+            weights.push(PoolMemberWeight {
+                profile_id: profile.id,
+                profile_name: profile.name,
+                weight: member.weight.clamp(0, i64::from(u32::MAX)) as u32,
+                cooldown_until_epoch,
+            });
+        }
+        Ok((Self::db_pool_to_cli(&pool), weights))
+    }
+
+    fn rotation_state(&self, pool_ref: &str) -> Result<WeightedRoundRobin, String> {
+        let db = self.open_db()?;
+        let pool_repo = forge_db::pool_repository::PoolRepository::new(&db);
+        let pool = Self::resolve_pool(&pool_repo, pool_ref)?;
+        Ok(
+            match pool
+                .metadata
+                .as_ref()
+                .and_then(|metadata| metadata.get(WEIGHTED_STATE_METADATA_KEY))
+            {
+                Some(serde_json::Value::String(encoded)) => WeightedRoundRobin::decode(encoded),
+                _ => WeightedRoundRobin::default(),
+            },
+        )
+    }
 }
 
 fn resolve_database_path() -> PathBuf {
@@ -371,6 +553,15 @@ enum Command {
     SetDefault {
         pool_ref: String,
     },
+    Weight {
+        pool_ref: String,
+        profile_ref: String,
+        weight: u32,
+    },
+    Order {
+        pool_ref: String,
+        count: Option<usize>,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -517,6 +708,121 @@ fn execute(
                 .map_err(|err| err.to_string())?;
             Ok(())
         }
+        Command::Weight {
+            pool_ref,
+            profile_ref,
+            weight,
+        } => {
+            let pool = backend.set_member_weight(&pool_ref, &profile_ref, weight)?;
+            if parsed.json || parsed.jsonl {
+                let payload = serde_json::json!({
+                    "pool": pool.name,
+                    "profile": profile_ref,
+                    "weight": weight,
+                });
+                write_serialized(stdout, &payload, parsed.jsonl)?;
+                return Ok(());
+            }
+            writeln!(
+                stdout,
+                "Weight for {profile_ref} in pool \"{}\" set to {weight}",
+                pool.name
+            )
+            .map_err(|err| err.to_string())?;
+            Ok(())
+        }
+        Command::Order { pool_ref, count } => {
+            let (pool, members) = backend.member_weights(&pool_ref)?;
+            let rotation = backend.rotation_state(&pool_ref)?;
+            let now_epoch = chrono::Utc::now().timestamp();
+            let view = pool_order_view(pool, members, rotation, now_epoch, count);
+            if parsed.json || parsed.jsonl {
+                write_serialized(stdout, &view, parsed.jsonl)?;
+                return Ok(());
+            }
+            if view.members.is_empty() {
+                writeln!(stdout, "No members").map_err(|err| err.to_string())?;
+                return Ok(());
+            }
+            let rows: Vec<Vec<String>> = view
+                .members
+                .iter()
+                .map(|member| {
+                    vec![
+                        member.profile_name.clone(),
+                        member.weight.to_string(),
+                        member_status(member, now_epoch).to_string(),
+                    ]
+                })
+                .collect();
+            write_table(stdout, &["PROFILE", "WEIGHT", "STATUS"], &rows)?;
+            writeln!(stdout).map_err(|err| err.to_string())?;
+            if view.order.is_empty() {
+                writeln!(stdout, "No selectable members").map_err(|err| err.to_string())?;
+                return Ok(());
+            }
+            writeln!(stdout, "Selection order: {}", view.order.join(", "))
+                .map_err(|err| err.to_string())?;
+            Ok(())
+        }
+    }
+}
+
+/// Build the upcoming selection order, continuing from the pool's saved
+/// `rotation`. Without an explicit count this covers one full rotation, i.e.
+/// the sum of the selectable weights.
+fn pool_order_view(
+    pool: Pool,
+    members: Vec<PoolMemberWeight>,
+    rotation: WeightedRoundRobin,
+    now_epoch: i64,
+    count: Option<usize>,
+) -> PoolOrderView {
+    let candidates: Vec<WeightedCandidate> = members
+        .iter()
+        .map(|member| WeightedCandidate {
+            profile: forge_loop::profile_selection::Profile {
+                id: member.profile_id.clone(),
+                name: member.profile_name.clone(),
+                max_concurrency: 0,
+                cooldown_until_epoch: member.cooldown_until_epoch,
+            },
+            weight: member.weight,
+        })
+        .collect();
+    let count = count.unwrap_or_else(|| {
+        members
+            .iter()
+            .filter(|member| member_status(member, now_epoch) == "active")
+            .map(|member| member.weight as usize)
+            .sum()
+    });
+    let order = weighted_selection_order(rotation, &candidates, now_epoch, count)
+        .into_iter()
+        .filter_map(|profile_id| {
+            members
+                .iter()
+                .find(|member| member.profile_id == profile_id)
+                .map(|member| member.profile_name.clone())
+        })
+        .collect();
+    PoolOrderView {
+        pool: pool.name,
+        members,
+        order,
+    }
+}
+
+fn member_status(member: &PoolMemberWeight, now_epoch: i64) -> &'static str {
+    if member.weight == 0 {
+        "disabled"
+    } else if member
+        .cooldown_until_epoch
+        .is_some_and(|until| until > now_epoch)
+    {
+        "cooldown"
+    } else {
+        "active"
     }
 }
 
@@ -579,6 +885,8 @@ fn parse_args(args: &[String]) -> Result<ParsedArgs, String> {
                 Command::SetDefault { pool_ref }
             })?
         }
+        Some("weight") => parse_weight_args(&subcommand_args)?,
+        Some("order") => parse_order_args(&subcommand_args)?,
         Some(other) => return Err(format!("unknown pool argument: {other}")),
     };
 
@@ -629,6 +937,55 @@ fn parse_add_args(args: &[String]) -> Result<Command, String> {
     })
 }
 
+fn parse_weight_args(args: &[String]) -> Result<Command, String> {
+    if args.len() != 3 {
+        return Err("pool weight requires <pool> <profile> <weight>".to_string());
+    }
+    let weight = args[2]
+        .parse::<u32>()
+        .map_err(|_| format!("invalid weight \"{}\" (expected integer >= 0)", args[2]))?;
+    Ok(Command::Weight {
+        pool_ref: args[0].clone(),
+        profile_ref: args[1].clone(),
+        weight,
+    })
+}
+
+fn parse_order_args(args: &[String]) -> Result<Command, String> {
+    let mut pool_ref: Option<String> = None;
+    let mut count: Option<usize> = None;
+    let mut idx = 0;
+    while idx < args.len() {
+        match args[idx].as_str() {
+            "--count" => {
+                let value = next_value(args, idx, "--count")?;
+                let parsed = value
+                    .parse::<usize>()
+                    .ok()
+                    .filter(|parsed| *parsed > 0)
+                    .ok_or_else(|| format!("invalid --count value \"{value}\""))?;
+                count = Some(parsed);
+                idx += 2;
+            }
+            token if token.starts_with("--") => {
+                return Err(format!("unknown pool order flag: {token}"));
+            }
+            token => {
+                if pool_ref.is_some() {
+                    return Err(format!("unexpected argument for pool order: {token}"));
+                }
+                pool_ref = Some(token.to_string());
+                idx += 1;
+            }
+        }
+    }
+    let pool_ref = match pool_ref {
+        Some(value) => value,
+        None => return Err("pool order requires <pool>".to_string()),
+    };
+    Ok(Command::Order { pool_ref, count })
+}
+
 fn parse_single_ref<F>(name: &str, args: &[String], builder: F) -> Result<Command, String>
 where
     F: FnOnce(String) -> Command,
@@ -704,6 +1061,14 @@ fn write_help(out: &mut dyn Write) -> std::io::Result<()> {
     writeln!(out, "  add <pool> <profile..>  Add profiles to a pool")?;
     writeln!(out, "  show <name>             Show pool details")?;
     writeln!(out, "  set-default <name>      Set the default pool")?;
+    writeln!(
+        out,
+        "  weight <pool> <profile> <n>  Set a member's selection weight (0 disables)"
+    )?;
+    writeln!(
+        out,
+        "  order <pool>            Show weights and the upcoming selection order"
+    )?;
     writeln!(out)?;
     writeln!(out, "Flags:")?;
     writeln!(out, "  --json                  output JSON")?;
@@ -712,6 +1077,10 @@ fn write_help(out: &mut dyn Write) -> std::io::Result<()> {
         out,
         "  --strategy <strategy>   create: strategy (round_robin)"
     )?;
+    writeln!(
+        out,
+        "  --count <n>             order: number of selections to show"
+    )?;
     Ok(())
 }
 
@@ -751,6 +1120,73 @@ fn write_table(out: &mut dyn Write, headers: &[&str], rows: &[Vec<String>]) -> R
 mod tests {
     use super::{run_for_test, InMemoryPoolBackend};
 
+    #[test]
+    fn pool_weight_and_order_flow() {
+        let mut backend = InMemoryPoolBackend::default();
+        assert_eq!(
+            run_for_test(&["pool", "create", "alpha"], &mut backend).exit_code,
+            0
+        );
+        let add = run_for_test(
+            &[
+                "pool", "add", "alpha", "heavy", "light", "parked", "cooling",
+            ],
+            &mut backend,
+        );
+        assert_eq!(add.exit_code, 0);
+
+        for (profile, weight) in [("heavy", "2"), ("parked", "0"), ("cooling", "4")] {
+            let set = run_for_test(&["pool", "weight", "alpha", profile, weight], &mut backend);
+            assert_eq!(set.exit_code, 0, "stderr: {}", set.stderr);
+            assert_eq!(
+                set.stdout,
+                format!("Weight for {profile} in pool \"alpha\" set to {weight}\n")
+            );
+        }
+        let mut backend = backend.with_member_cooldown("cooling", i64::MAX);
+
+        let order = run_for_test(&["pool", "order", "alpha"], &mut backend);
+        assert_eq!(order.exit_code, 0, "stderr: {}", order.stderr);
+        assert!(order.stdout.contains("PROFILE  WEIGHT  STATUS"));
+        assert!(order.stdout.contains("parked   0       disabled"));
+        assert!(order.stdout.contains("cooling  4       cooldown"));
+        assert!(order
+            .stdout
+            .ends_with("Selection order: heavy, light, heavy\n"));
+
+        let json = run_for_test(
+            &["pool", "order", "alpha", "--count", "6", "--json"],
+            &mut backend,
+        );
+        assert_eq!(json.exit_code, 0);
+        let value: serde_json::Value = match serde_json::from_str(&json.stdout) {
+            Ok(value) => value,
+            Err(err) => panic!("order json: {err}"),
+        };
+        assert_eq!(
+            value["order"],
+            serde_json::json!(["heavy", "light", "heavy", "heavy", "light", "heavy"])
+        );
+
+        // The preview continues from the rotation the scheduler saved.
+        let mut backend = backend.with_rotation_state("alpha", "profile-001=-1;profile-002=1");
+        let resumed = run_for_test(&["pool", "order", "alpha"], &mut backend);
+        assert_eq!(resumed.exit_code, 0, "stderr: {}", resumed.stderr);
+        assert!(resumed
+            .stdout
+            .ends_with("Selection order: light, heavy, heavy\n"));
+
+        let bad_weight = run_for_test(&["pool", "weight", "alpha", "heavy", "-1"], &mut backend);
+        assert_eq!(bad_weight.exit_code, 1);
+        assert!(bad_weight.stderr.contains("invalid weight \"-1\""));
+
+        let not_member = run_for_test(&["pool", "weight", "alpha", "ghost", "1"], &mut backend);
+        assert_eq!(not_member.exit_code, 1);
+        assert!(not_member
+            .stderr
+            .contains("profile ghost is not a member of pool alpha"));
+    }
+
     #[test]
     fn pool_create_list_and_set_default_flow() {
         let mut backend = InMemoryPoolBackend::default();
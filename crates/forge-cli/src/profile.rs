@@ -5,7 +5,7 @@ use std::io::Write;
 use std::path::{Path, PathBuf};
 use std::process::Command as ProcessCommand;
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::profile_catalog::{AuthStatus, ProfileCatalogStore};
 
@@ -80,6 +80,122 @@ pub struct ProfilePatch {
     pub max_concurrency: Option<i32>,
 }
 
+/// Placeholder written for env values unless export is run with `--include-env`.
+pub const REDACTED_ENV_VALUE: &str = "<redacted>";
+
+/// Portable profile document used by `forge profile export` / `import`.
+///
+/// Only the harness configuration travels: ids, timestamps and cooldowns are
+/// local state and are left out. Optional fields fall back to the harness
+/// defaults on import, same as `forge profile add`. Env values often hold
+/// credentials, so export redacts them unless asked not to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProfileToml {
+    pub name: String,
+    pub harness: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auth_kind: Option<String>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub auth_home: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prompt_mode: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub command_template: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_concurrency: Option<i32>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extra_args: Vec<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub env: BTreeMap<String, String>,
+}
+
+impl ProfileToml {
+    pub fn from_profile(profile: &Profile) -> Self {
+        Self {
+            name: profile.name.clone(),
+            harness: profile.harness.clone(),
+            auth_kind: Some(profile.auth_kind.clone()),
+            auth_home: profile.auth_home.clone(),
+            prompt_mode: Some(profile.prompt_mode.clone()),
+            command_template: Some(profile.command_template.clone()),
+            model: Some(profile.model.clone()),
+            max_concurrency: Some(profile.max_concurrency),
+            extra_args: profile.extra_args.clone(),
+            env: profile.env.clone(),
+        }
+    }
+
+    /// Replace every env value with [`REDACTED_ENV_VALUE`].
+    pub fn redact_env(&mut self) {
+        for value in self.env.values_mut() {
+            *value = REDACTED_ENV_VALUE.to_string();
+        }
+    }
+
+    pub fn to_toml_string(&self) -> Result<String, String> {
+        toml::to_string(self).map_err(|err| format!("encode profile {}: {err}", self.name))
+    }
+
+    /// Parse and validate a TOML profile document.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut doc: Self =
+            toml::from_str(text).map_err(|err| format!("invalid profile TOML: {err}"))?;
+        doc.name = doc.name.trim().to_string();
+        if doc.name.is_empty() {
+            return Err("invalid profile TOML: name is required".to_string());
+        }
+        doc.harness = normalize_harness(&doc.harness)?;
+        if let Some(prompt_mode) = doc.prompt_mode.as_deref() {
+            validate_prompt_mode(prompt_mode)?;
+        }
+        if doc.max_concurrency.is_some_and(|value| value < 1) {
+            return Err("max concurrency must be >= 1".to_string());
+        }
+        if let Some(key) = doc
+            .env
+            .iter()
+            .find_map(|(key, value)| (value == REDACTED_ENV_VALUE).then_some(key))
+        {
+            return Err(format!(
+                "invalid profile TOML: env {key} is redacted (set it or re-export with --include-env)"
+            ));
+        }
+        Ok(doc)
+    }
+
+    fn into_create_input(self) -> ProfileCreateInput {
+        ProfileCreateInput {
+            name: self.name,
+            harness: self.harness,
+            auth_kind: self.auth_kind,
+            auth_home: Some(self.auth_home),
+            prompt_mode: self.prompt_mode,
+            command_template: self.command_template,
+            model: self.model,
+            extra_args: self.extra_args,
+            env: self.env,
+            max_concurrency: self.max_concurrency,
+        }
+    }
+
+    fn into_patch(self) -> ProfilePatch {
+        ProfilePatch {
+            name: None,
+            auth_kind: self.auth_kind,
+            auth_home: Some(self.auth_home),
+            prompt_mode: self.prompt_mode,
+            command_template: self.command_template,
+            model: self.model,
+            extra_args: Some(self.extra_args),
+            env: Some(self.env),
+            max_concurrency: self.max_concurrency,
+        }
+    }
+}
+
 pub trait ProfileBackend {
     fn list_profiles(&self) -> Result<Vec<Profile>, String>;
     fn create_profile(&mut self, input: ProfileCreateInput) -> Result<Profile, String>;
@@ -594,6 +710,14 @@ enum Command {
     CooldownClear {
         name: String,
     },
+    Export {
+        name: String,
+        include_env: bool,
+    },
+    Import {
+        path: String,
+        force: bool,
+    },
     CatalogStatus,
     CatalogInit {
         node_id: String,
@@ -827,6 +951,61 @@ fn execute(
                 .map_err(|err| err.to_string())?;
             Ok(())
         }
+        Command::Export { name, include_env } => {
+            let profiles = backend.list_profiles()?;
+            let Some(profile) = profiles
+                .iter()
+                .find(|profile| profile.name == name || profile.id == name)
+            else {
+                return Err(format!("profile not found: {name}"));
+            };
+            let mut doc = ProfileToml::from_profile(profile);
+            if !include_env {
+                doc.redact_env();
+            }
+            let text = doc.to_toml_string()?;
+            write!(stdout, "{text}").map_err(|err| err.to_string())?;
+            Ok(())
+        }
+        Command::Import { path, force } => {
+            let text = fs::read_to_string(&path).map_err(|err| format!("read {path}: {err}"))?;
+            let doc = ProfileToml::parse(&text)?;
+            let existing = backend
+                .list_profiles()?
+                .into_iter()
+                .find(|profile| profile.name == doc.name);
+
+            let (profile, action) = match existing {
+                None => (backend.create_profile(doc.into_create_input())?, "imported"),
+                Some(_) if !force => {
+                    return Err(format!(
+                        "profile \"{}\" already exists (use --force to overwrite)",
+                        doc.name
+                    ));
+                }
+                Some(current) if current.harness == doc.harness => {
+                    let name = doc.name.clone();
+                    (backend.update_profile(&name, doc.into_patch())?, "replaced")
+                }
+                Some(current) => {
+                    // Harness is fixed at creation; replacing it would mean a
+                    // non-atomic delete + create, so leave that to the operator.
+                    return Err(format!(
+                        "profile \"{}\" uses harness {}, import has {} (remove it first to change harness)",
+                        doc.name, current.harness, doc.harness
+                    ));
+                }
+            };
+
+            if parsed.json || parsed.jsonl {
+                write_serialized(stdout, &profile, parsed.jsonl)?;
+                return Ok(());
+            }
+
+            writeln!(stdout, "Profile \"{}\" {action}", profile.name)
+                .map_err(|err| err.to_string())?;
+            Ok(())
+        }
         Command::CatalogStatus => {
             let store = ProfileCatalogStore::open_from_env();
             let catalog = store.status()?;
@@ -1283,6 +1462,8 @@ fn parse_args(args: &[String]) -> Result<ParsedArgs, String> {
             Command::Init
         }
         Some("cooldown") => parse_cooldown_args(&subcommand_args)?,
+        Some("export") => parse_export_args(&subcommand_args)?,
+        Some("import") => parse_import_args(&subcommand_args)?,
         Some("catalog") => parse_catalog_args(&subcommand_args)?,
         Some(other) => return Err(format!("unknown profile argument: {other}")),
     };
@@ -1560,6 +1741,52 @@ fn parse_catalog_auth_args(args: &[String]) -> Result<Command, String> {
     })
 }
 
+fn parse_export_args(args: &[String]) -> Result<Command, String> {
+    let mut include_env = false;
+    let mut name: Option<String> = None;
+    for arg in args {
+        match arg.as_str() {
+            "--include-env" => include_env = true,
+            flag if flag.starts_with('-') => {
+                return Err(format!("unknown profile export flag: {flag}"));
+            }
+            value => {
+                if name.is_some() {
+                    return Err("profile export requires exactly 1 argument".to_string());
+                }
+                name = Some(value.to_string());
+            }
+        }
+    }
+    match name {
+        Some(name) => Ok(Command::Export { name, include_env }),
+        None => Err("profile export requires exactly 1 argument".to_string()),
+    }
+}
+
+fn parse_import_args(args: &[String]) -> Result<Command, String> {
+    let mut force = false;
+    let mut path: Option<String> = None;
+    for arg in args {
+        match arg.as_str() {
+            "--force" | "-f" => force = true,
+            flag if flag.starts_with('-') => {
+                return Err(format!("unknown profile import flag: {flag}"));
+            }
+            value => {
+                if path.is_some() {
+                    return Err("profile import requires exactly 1 file".to_string());
+                }
+                path = Some(value.to_string());
+            }
+        }
+    }
+    match path {
+        Some(path) => Ok(Command::Import { path, force }),
+        None => Err("profile import requires exactly 1 file".to_string()),
+    }
+}
+
 fn parse_single_ref<F>(name: &str, args: &[String], builder: F) -> Result<Command, String>
 where
     F: FnOnce(String) -> Command,
@@ -1860,6 +2087,14 @@ fn write_help(out: &mut dyn Write) -> std::io::Result<()> {
     writeln!(out, "  doctor <name>           Check profile configuration")?;
     writeln!(out, "  cooldown set <name>     Set profile cooldown")?;
     writeln!(out, "  cooldown clear <name>   Clear profile cooldown")?;
+    writeln!(
+        out,
+        "  export <name> [--include-env] Print a profile as TOML (env values redacted)"
+    )?;
+    writeln!(
+        out,
+        "  import <file> [--force] Create a profile from TOML (--force overwrites)"
+    )?;
     writeln!(
         out,
         "  catalog status          Show mesh profile catalog status"
@@ -1915,7 +2150,7 @@ mod tests {
     use super::{
         detect_installed_harnesses, instantiate_profiles_from_detection, parse_alias_line,
         parse_alias_lines, parse_go_duration, parse_time_or_duration, run_for_test, AliasDetection,
-        InMemoryProfileBackend, ProfileInitDetection,
+        InMemoryProfileBackend, ProfileBackend, ProfileInitDetection, ProfileToml,
     };
 
     struct EnvVarGuard {
@@ -2011,6 +2246,117 @@ mod tests {
         );
     }
 
+    #[test]
+    fn profile_export_import_round_trips_toml() {
+        let mut source = InMemoryProfileBackend::default();
+        let add = run_for_test(
+            &[
+                "profile",
+                "add",
+                "claude",
+                "--name",
+                "review",
+                "--home",
+                "/tmp/auth-review",
+                "--max-concurrency",
+                "3",
+                "--extra-arg",
+                "--verbose",
+                "--env",
+                "FORGE_MODE=review",
+                "--env",
+                "LANG=C",
+            ],
+            &mut source,
+        );
+        assert_eq!(add.exit_code, 0, "{}", add.stderr);
+
+        let redacted = run_for_test(&["profile", "export", "review"], &mut source);
+        assert_eq!(redacted.exit_code, 0, "{}", redacted.stderr);
+        assert!(redacted.stdout.contains("FORGE_MODE = \"<redacted>\""));
+
+        let exported = run_for_test(
+            &["profile", "export", "review", "--include-env"],
+            &mut source,
+        );
+        assert_eq!(exported.exit_code, 0, "{}", exported.stderr);
+        assert!(exported.stdout.contains("name = \"review\""));
+        assert!(exported.stdout.contains("FORGE_MODE = \"review\""));
+        assert!(!exported.stdout.contains("created_at"));
+
+        let dir = temp_path("export-import");
+        fs::create_dir_all(&dir).unwrap_or_else(|err| panic!("create temp dir: {err}"));
+        let file = dir.join("review.toml");
+        fs::write(&file, &exported.stdout).unwrap_or_else(|err| panic!("write toml: {err}"));
+        let file = file.to_string_lossy().into_owned();
+
+        let mut target = InMemoryProfileBackend::default();
+        let imported = run_for_test(&["profile", "import", &file], &mut target);
+        assert_eq!(imported.exit_code, 0, "{}", imported.stderr);
+        assert_eq!(imported.stdout, "Profile \"review\" imported\n");
+
+        let reexported = run_for_test(
+            &["profile", "export", "review", "--include-env"],
+            &mut target,
+        );
+        assert_eq!(reexported.stdout, exported.stdout);
+
+        let source_profiles = source
+            .list_profiles()
+            .unwrap_or_else(|err| panic!("list source: {err}"));
+        let target_profiles = target
+            .list_profiles()
+            .unwrap_or_else(|err| panic!("list target: {err}"));
+        assert_eq!(
+            source_profiles
+                .iter()
+                .map(ProfileToml::from_profile)
+                .collect::<Vec<_>>(),
+            target_profiles
+                .iter()
+                .map(ProfileToml::from_profile)
+                .collect::<Vec<_>>()
+        );
+
+        let collision = run_for_test(&["profile", "import", &file], &mut target);
+        assert_eq!(collision.exit_code, 1);
+        assert_eq!(
+            collision.stderr,
+            "profile \"review\" already exists (use --force to overwrite)\n"
+        );
+
+        let forced = run_for_test(&["profile", "import", &file, "--force"], &mut target);
+        assert_eq!(forced.exit_code, 0, "{}", forced.stderr);
+        assert_eq!(forced.stdout, "Profile \"review\" replaced\n");
+
+        fs::write(&file, exported.stdout.replace("\"claude\"", "\"codex\""))
+            .unwrap_or_else(|err| panic!("write harness change: {err}"));
+        let harness_change = run_for_test(&["profile", "import", &file, "--force"], &mut target);
+        assert_eq!(harness_change.exit_code, 1);
+        assert!(
+            harness_change
+                .stderr
+                .contains("uses harness claude, import has codex"),
+            "{}",
+            harness_change.stderr
+        );
+
+        fs::write(&file, &redacted.stdout).unwrap_or_else(|err| panic!("write redacted: {err}"));
+        let redacted_import = run_for_test(&["profile", "import", &file, "--force"], &mut target);
+        assert_eq!(redacted_import.exit_code, 1);
+        assert!(redacted_import
+            .stderr
+            .contains("env FORGE_MODE is redacted"));
+
+        fs::write(&file, "name = \"bad\"\nharness = \"codex\"\nbogus = 1\n")
+            .unwrap_or_else(|err| panic!("write bad toml: {err}"));
+        let invalid = run_for_test(&["profile", "import", &file], &mut target);
+        assert_eq!(invalid.exit_code, 1);
+        assert!(invalid.stderr.starts_with("invalid profile TOML"));
+
+        let _ = fs::remove_dir_all(dir);
+    }
+
     #[test]
     fn profile_cooldown_and_doctor_flow() {
         let mut backend = InMemoryProfileBackend::default();
@@ -27,6 +27,7 @@ pub enum LoopState {
     Running,
     Sleeping,
     Waiting,
+    Paused,
     Stopped,
     Error,
 }
@@ -38,6 +39,7 @@ impl LoopState {
             Self::Running => "running",
             Self::Sleeping => "sleeping",
             Self::Waiting => "waiting",
+            Self::Paused => "paused",
             Self::Stopped => "stopped",
             Self::Error => "error",
         }
@@ -443,6 +445,7 @@ fn map_loop_state(state: &forge_db::loop_repository::LoopState) -> LoopState {
         forge_db::loop_repository::LoopState::Running => LoopState::Running,
         forge_db::loop_repository::LoopState::Sleeping => LoopState::Sleeping,
         forge_db::loop_repository::LoopState::Waiting => LoopState::Waiting,
+        forge_db::loop_repository::LoopState::Paused => LoopState::Paused,
         forge_db::loop_repository::LoopState::Stopped => LoopState::Stopped,
         forge_db::loop_repository::LoopState::Error => LoopState::Error,
     }
@@ -453,6 +456,7 @@ fn map_stale_loop_state(state: &forge_db::loop_repository::LoopState) -> StaleLo
         forge_db::loop_repository::LoopState::Running => StaleLoopState::Running,
         forge_db::loop_repository::LoopState::Sleeping => StaleLoopState::Sleeping,
         forge_db::loop_repository::LoopState::Waiting => StaleLoopState::Waiting,
+        forge_db::loop_repository::LoopState::Paused => StaleLoopState::Paused,
         forge_db::loop_repository::LoopState::Stopped => StaleLoopState::Stopped,
         forge_db::loop_repository::LoopState::Error => StaleLoopState::Error,
     }
@@ -575,7 +579,7 @@ pub(crate) fn list_daemon_runners() -> (HashMap<String, DaemonRunner>, bool) {
 }
 
 async fn list_daemon_runners_async() -> Result<HashMap<String, DaemonRunner>, String> {
-    let target = resolved_daemon_target();
+    let target = crate::daemon_target::resolve_daemon_target("");
     let endpoint = Endpoint::from_shared(target.clone())
         .map_err(|err| format!("forged daemon unavailable: {err}"))?
         .connect_timeout(Duration::from_secs(2))
@@ -611,35 +615,14 @@ async fn list_daemon_runners_async() -> Result<HashMap<String, DaemonRunner>, St
 
 fn map_daemon_runner_state(value: i32) -> DaemonRunnerState {
     match proto::LoopRunnerState::try_from(value).unwrap_or(proto::LoopRunnerState::Unspecified) {
-        proto::LoopRunnerState::Running => DaemonRunnerState::Running,
+        proto::LoopRunnerState::Running | proto::LoopRunnerState::Paused => {
+            DaemonRunnerState::Running
+        }
         proto::LoopRunnerState::Stopped => DaemonRunnerState::Stopped,
         _ => DaemonRunnerState::Unknown,
     }
 }
 
-fn resolved_daemon_target() -> String {
-    let env_target = std::env::var("FORGE_DAEMON_TARGET")
-        .ok()
-        .filter(|value| !value.trim().is_empty())
-        .or_else(|| {
-            std::env::var("FORGED_ADDR")
-                .ok()
-                .filter(|value| !value.trim().is_empty())
-        });
-    if let Some(target) = env_target {
-        return normalize_daemon_target(&target);
-    }
-    "http://127.0.0.1:50051".to_string()
-}
-
-fn normalize_daemon_target(raw: &str) -> String {
-    let trimmed = raw.trim();
-    if trimmed.contains("://") {
-        return trimmed.to_string();
-    }
-    format!("http://{trimmed}")
-}
-
 fn metadata_string(metadata: Option<&HashMap<String, Value>>, key: &str) -> String {
     metadata
         .and_then(|meta| meta.get(key))
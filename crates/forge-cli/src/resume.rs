@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::env;
 use std::io::Write;
 use std::path::PathBuf;
@@ -5,7 +6,7 @@ use std::path::PathBuf;
 use serde::Serialize;
 use serde_json::Value;
 
-use crate::spawn_loop::SpawnOptions;
+pub use crate::spawn_loop::SpawnOptions;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CommandOutput {
@@ -19,6 +20,7 @@ pub struct CommandOutput {
 pub enum LoopState {
     Pending,
     Running,
+    Paused,
     Stopped,
     Error,
 }
@@ -28,6 +30,7 @@ impl LoopState {
         match self {
             Self::Pending => "pending",
             Self::Running => "running",
+            Self::Paused => "paused",
             Self::Stopped => "stopped",
             Self::Error => "error",
         }
@@ -50,8 +53,23 @@ pub struct ResumeResult {
     pub instance_id: String,
 }
 
+/// Extra context injected before a loop is resumed: LoopKV entries plus
+/// one-shot notes delivered to the next iteration as operator messages.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ResumeContext {
+    pub set_kv: Vec<(String, String)>,
+    pub append_context: Vec<String>,
+}
+
+impl ResumeContext {
+    pub fn is_empty(&self) -> bool {
+        self.set_kv.is_empty() && self.append_context.is_empty()
+    }
+}
+
 pub trait ResumeBackend {
     fn list_loops(&self) -> Result<Vec<LoopRecord>, String>;
+    fn apply_context(&mut self, loop_id: &str, context: &ResumeContext) -> Result<(), String>;
     fn resume_loop(
         &mut self,
         loop_id: &str,
@@ -59,17 +77,38 @@ pub trait ResumeBackend {
         spawn_options: &SpawnOptions,
         warning_writer: &mut dyn Write,
     ) -> Result<ResumeResult, String>;
+    /// Continue a paused daemon runner in place instead of respawning it.
+    fn unpause_loop(&mut self, loop_id: &str) -> Result<(), String>;
 }
 
 #[derive(Debug, Clone, Default)]
 pub struct InMemoryResumeBackend {
     loops: Vec<LoopRecord>,
     tick: usize,
+    kv: BTreeMap<String, BTreeMap<String, String>>,
+    context_notes: BTreeMap<String, Vec<String>>,
 }
 
 impl InMemoryResumeBackend {
     pub fn with_loops(loops: Vec<LoopRecord>) -> Self {
-        Self { loops, tick: 0 }
+        Self {
+            loops,
+            ..Self::default()
+        }
+    }
+
+    pub fn kv_value(&self, loop_id: &str, key: &str) -> Option<&str> {
+        self.kv
+            .get(loop_id)
+            .and_then(|entries| entries.get(key))
+            .map(String::as_str)
+    }
+
+    pub fn context_notes(&self, loop_id: &str) -> &[String] {
+        self.context_notes
+            .get(loop_id)
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
     }
 
     fn next_instance_id(&mut self, owner: &str) -> String {
@@ -86,6 +125,18 @@ impl ResumeBackend for InMemoryResumeBackend {
         Ok(self.loops.clone())
     }
 
+    fn apply_context(&mut self, loop_id: &str, context: &ResumeContext) -> Result<(), String> {
+        let entries = self.kv.entry(loop_id.to_string()).or_default();
+        for (key, value) in &context.set_kv {
+            entries.insert(key.clone(), value.clone());
+        }
+        self.context_notes
+            .entry(loop_id.to_string())
+            .or_default()
+            .extend(context.append_context.iter().cloned());
+        Ok(())
+    }
+
     fn resume_loop(
         &mut self,
         loop_id: &str,
@@ -119,6 +170,17 @@ impl ResumeBackend for InMemoryResumeBackend {
 
         Ok(ResumeResult { owner, instance_id })
     }
+
+    fn unpause_loop(&mut self, loop_id: &str) -> Result<(), String> {
+        let Some(loop_entry) = self.loops.iter_mut().find(|entry| entry.id == loop_id) else {
+            return Err(format!("loop {loop_id} not found"));
+        };
+        if loop_entry.state != LoopState::Paused {
+            return Err(format!("loop \"{}\" is not paused", loop_entry.name));
+        }
+        loop_entry.state = LoopState::Running;
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -175,6 +237,36 @@ impl ResumeBackend for SqliteResumeBackend {
         Ok(out)
     }
 
+    fn apply_context(&mut self, loop_id: &str, context: &ResumeContext) -> Result<(), String> {
+        let mut items = Vec::new();
+        for text in &context.append_context {
+            let payload = serde_json::json!({ "text": text });
+            items.push(forge_db::loop_queue_repository::LoopQueueItem {
+                item_type: "message_append".to_string(),
+                payload: serde_json::to_string(&payload).map_err(|err| err.to_string())?,
+                ..Default::default()
+            });
+        }
+
+        let db = self.open_db()?;
+        // The kv updates and context messages land together or not at all.
+        db.immediate_transaction(|db| {
+            let kv_repo = forge_db::LoopKVRepository::new(db);
+            for (key, value) in &context.set_kv {
+                kv_repo.set(loop_id, key, value).map_err(|err| {
+                    forge_db::DbError::Transaction(format!("set loop kv {key}: {err}"))
+                })?;
+            }
+
+            forge_db::loop_queue_repository::LoopQueueRepository::new(db)
+                .enqueue(loop_id, &mut items)
+                .map_err(|err| {
+                    forge_db::DbError::Transaction(format!("enqueue resume context: {err}"))
+                })
+        })
+        .map_err(|err| err.to_string())
+    }
+
     fn resume_loop(
         &mut self,
         loop_id: &str,
@@ -225,6 +317,17 @@ impl ResumeBackend for SqliteResumeBackend {
 
         Ok(ResumeResult { owner, instance_id })
     }
+
+    fn unpause_loop(&mut self, loop_id: &str) -> Result<(), String> {
+        crate::pause::signal_daemon_runner(loop_id, crate::pause::RunnerSignal::Resume)?;
+        let db = self.open_db()?;
+        let loop_repo = forge_db::loop_repository::LoopRepository::new(&db);
+        let mut loop_entry = loop_repo.get(loop_id).map_err(|err| err.to_string())?;
+        loop_entry.state = forge_db::loop_repository::LoopState::Running;
+        loop_repo
+            .update(&mut loop_entry)
+            .map_err(|err| err.to_string())
+    }
 }
 
 fn map_loop_state(state: &forge_db::loop_repository::LoopState) -> LoopState {
@@ -232,6 +335,7 @@ fn map_loop_state(state: &forge_db::loop_repository::LoopState) -> LoopState {
         forge_db::loop_repository::LoopState::Running => LoopState::Running,
         forge_db::loop_repository::LoopState::Sleeping
         | forge_db::loop_repository::LoopState::Waiting => LoopState::Pending,
+        forge_db::loop_repository::LoopState::Paused => LoopState::Paused,
         forge_db::loop_repository::LoopState::Stopped => LoopState::Stopped,
         forge_db::loop_repository::LoopState::Error => LoopState::Error,
     }
@@ -257,6 +361,7 @@ struct ParsedArgs {
     loop_ref: String,
     spawn_owner: String,
     config_path: String,
+    context: ResumeContext,
     json: bool,
     jsonl: bool,
     quiet: bool,
@@ -312,7 +417,7 @@ fn execute(
     let loop_entry = match_loop_ref(&loops, &parsed.loop_ref)?;
 
     match loop_entry.state {
-        LoopState::Stopped | LoopState::Error => {}
+        LoopState::Stopped | LoopState::Error | LoopState::Paused => {}
         _ => {
             return Err(format!(
                 "loop \"{}\" is {}; only stopped or errored loops can be resumed",
@@ -322,7 +427,15 @@ fn execute(
         }
     }
 
-    let _ = backend.resume_loop(&loop_entry.id, &parsed.spawn_owner, &spawn_options, stderr)?;
+    if !parsed.context.is_empty() {
+        backend.apply_context(&loop_entry.id, &parsed.context)?;
+    }
+
+    if loop_entry.state == LoopState::Paused {
+        backend.unpause_loop(&loop_entry.id)?;
+    } else {
+        let _ = backend.resume_loop(&loop_entry.id, &parsed.spawn_owner, &spawn_options, stderr)?;
+    }
 
     if parsed.json || parsed.jsonl {
         let payload = serde_json::json!({
@@ -361,6 +474,7 @@ fn parse_args(args: &[String]) -> Result<ParsedArgs, String> {
     let mut spawn_owner_explicit = false;
     let mut config_path = String::new();
     let mut loop_ref = String::new();
+    let mut context = ResumeContext::default();
 
     while let Some(token) = args.get(index) {
         match token.as_str() {
@@ -385,9 +499,22 @@ fn parse_args(args: &[String]) -> Result<ParsedArgs, String> {
                 config_path = take_value(args, index, "--config")?;
                 index += 2;
             }
+            "--set-kv" => {
+                let pair = take_value(args, index, "--set-kv")?;
+                context.set_kv.push(parse_kv_pair(&pair)?);
+                index += 2;
+            }
+            "--append-context" => {
+                let text = take_value(args, index, "--append-context")?;
+                if text.trim().is_empty() {
+                    return Err("error: --append-context requires non-empty text".to_string());
+                }
+                context.append_context.push(text.trim().to_string());
+                index += 2;
+            }
             "--help" | "-h" => {
                 return Err(
-                    "usage: resume <loop> [--spawn-owner local|daemon|auto] [--config <path>]"
+                    "usage: resume <loop> [--spawn-owner local|daemon|auto] [--config <path>] [--set-kv key=value]... [--append-context <text>]..."
                         .to_string(),
                 );
             }
@@ -421,12 +548,33 @@ fn parse_args(args: &[String]) -> Result<ParsedArgs, String> {
         loop_ref,
         spawn_owner,
         config_path,
+        context,
         json,
         jsonl,
         quiet,
     })
 }
 
+fn parse_kv_pair(value: &str) -> Result<(String, String), String> {
+    let Some((key, val)) = value.split_once('=') else {
+        return Err(format!(
+            "error: invalid --set-kv value \"{value}\" (expected key=value)"
+        ));
+    };
+    let key = key.trim();
+    if key.is_empty() {
+        return Err(format!(
+            "error: invalid --set-kv value \"{value}\" (key is required)"
+        ));
+    }
+    if val.is_empty() {
+        return Err(format!(
+            "error: invalid --set-kv value \"{value}\" (value is required)"
+        ));
+    }
+    Ok((key.to_string(), val.to_string()))
+}
+
 fn take_value(args: &[String], index: usize, flag: &str) -> Result<String, String> {
     args.get(index + 1)
         .cloned()
@@ -522,7 +670,7 @@ mod tests {
 
     use super::{
         parse_args, run_for_test, InMemoryResumeBackend, LoopRecord, LoopState, ResumeBackend,
-        SqliteResumeBackend,
+        ResumeContext, SqliteResumeBackend,
     };
 
     #[test]
@@ -576,6 +724,114 @@ mod tests {
         assert_eq!(parsed.spawn_owner, "auto");
     }
 
+    #[test]
+    fn parse_collects_resume_context_and_rejects_bad_pairs() {
+        let args: Vec<String> = [
+            "resume",
+            "abc",
+            "--set-kv",
+            "focus=fix the flaky test",
+            "--set-kv",
+            "ticket=a=b",
+            "--append-context",
+            "  skip the docs for now ",
+        ]
+        .iter()
+        .map(|arg| (*arg).to_string())
+        .collect();
+        let parsed = match parse_args(&args) {
+            Ok(parsed) => parsed,
+            Err(err) => panic!("parse: {err}"),
+        };
+        assert_eq!(
+            parsed.context,
+            ResumeContext {
+                set_kv: vec![
+                    ("focus".to_string(), "fix the flaky test".to_string()),
+                    ("ticket".to_string(), "a=b".to_string()),
+                ],
+                append_context: vec!["skip the docs for now".to_string()],
+            }
+        );
+
+        for (pair, reason) in [
+            ("novalue", "expected key=value"),
+            ("=value", "key is required"),
+            ("key=", "value is required"),
+        ] {
+            let args = vec![
+                "resume".to_string(),
+                "abc".to_string(),
+                "--set-kv".to_string(),
+                pair.to_string(),
+            ];
+            let err = match parse_args(&args) {
+                Ok(_) => panic!("expected parse failure for {pair}"),
+                Err(message) => message,
+            };
+            assert!(err.contains(reason), "{pair}: {err}");
+        }
+    }
+
+    #[test]
+    fn resume_paused_loop_continues_runner_without_respawn() {
+        let loops = vec![LoopRecord {
+            id: "loop-1".to_string(),
+            short_id: "abc123".to_string(),
+            name: "demo".to_string(),
+            state: LoopState::Paused,
+            runner_owner: "daemon".to_string(),
+            runner_instance_id: "daemon-7".to_string(),
+        }];
+        let mut backend = InMemoryResumeBackend::with_loops(loops);
+        let out = run_for_test(&["resume", "demo"], &mut backend);
+        assert_eq!(out.exit_code, 0, "stderr: {}", out.stderr);
+        assert_eq!(out.stdout, "Loop \"demo\" resumed (abc123)\n");
+        let listed = backend
+            .list_loops()
+            .unwrap_or_else(|err| panic!("list loops: {err}"));
+        assert_eq!(listed[0].state, LoopState::Running);
+        assert_eq!(listed[0].runner_instance_id, "daemon-7");
+    }
+
+    #[test]
+    fn resume_with_invalid_kv_does_not_resume() {
+        let loops = vec![LoopRecord {
+            id: "loop-1".to_string(),
+            short_id: "abc123".to_string(),
+            name: "demo".to_string(),
+            state: LoopState::Stopped,
+            runner_owner: String::new(),
+            runner_instance_id: String::new(),
+        }];
+        let mut backend = InMemoryResumeBackend::with_loops(loops);
+        let out = run_for_test(&["resume", "demo", "--set-kv", "broken"], &mut backend);
+        assert_eq!(out.exit_code, 1);
+        assert_eq!(backend.kv_value("loop-1", "broken"), None);
+        let listed = backend
+            .list_loops()
+            .unwrap_or_else(|err| panic!("list loops: {err}"));
+        assert_eq!(listed[0].state, LoopState::Stopped);
+
+        let out = run_for_test(
+            &[
+                "resume",
+                "demo",
+                "--set-kv",
+                "focus=tests",
+                "--append-context",
+                "rebase first",
+            ],
+            &mut backend,
+        );
+        assert_eq!(out.exit_code, 0, "stderr: {}", out.stderr);
+        assert_eq!(backend.kv_value("loop-1", "focus"), Some("tests"));
+        assert_eq!(
+            backend.context_notes("loop-1"),
+            ["rebase first".to_string()]
+        );
+    }
+
     #[test]
     fn resume_running_loop_fails() {
         let loops = vec![LoopRecord {
@@ -646,6 +902,68 @@ mod tests {
         );
     }
 
+    #[test]
+    fn sqlite_resume_set_kv_persists_value_and_runs_loop() {
+        let (db_path, _tmp, loop_id) = setup_sqlite_resume_fixture();
+        let mut backend = SqliteResumeBackend::new(db_path.clone());
+
+        let out = run_for_test(
+            &[
+                "resume",
+                "demo",
+                "--spawn-owner",
+                "local",
+                "--set-kv",
+                "focus=finish the migration",
+                "--append-context",
+                "Prefer small commits.",
+                "--json",
+            ],
+            &mut backend,
+        );
+        assert_eq!(out.exit_code, 0, "stderr: {}", out.stderr);
+
+        let db = forge_db::Db::open(forge_db::Config::new(&db_path))
+            .unwrap_or_else(|err| panic!("open db {}: {err}", db_path.display()));
+        let kv = forge_db::LoopKVRepository::new(&db)
+            .get(&loop_id, "focus")
+            .unwrap_or_else(|err| panic!("get loop kv: {err}"));
+        assert_eq!(kv.value, "finish the migration");
+
+        let entry = forge_db::loop_repository::LoopRepository::new(&db)
+            .get(&loop_id)
+            .unwrap_or_else(|err| panic!("get loop {loop_id}: {err}"));
+        assert_eq!(entry.state, forge_db::loop_repository::LoopState::Running);
+
+        let queued = forge_db::loop_queue_repository::LoopQueueRepository::new(&db)
+            .peek(&loop_id)
+            .unwrap_or_else(|err| panic!("peek queue: {err}"));
+        assert_eq!(queued.item_type, "message_append");
+        assert!(queued.payload.contains("Prefer small commits."));
+    }
+
+    #[test]
+    fn sqlite_apply_context_rolls_back_kv_when_enqueue_fails() {
+        let (db_path, _tmp, loop_id) = setup_sqlite_resume_fixture();
+        let mut backend = SqliteResumeBackend::new(db_path.clone());
+
+        let context = ResumeContext {
+            set_kv: vec![("focus".to_string(), "finish the migration".to_string())],
+            append_context: vec!["   ".to_string()],
+        };
+        let err = match backend.apply_context(&loop_id, &context) {
+            Ok(()) => panic!("blank context should fail to enqueue"),
+            Err(err) => err,
+        };
+        assert!(err.contains("enqueue resume context"), "{err}");
+
+        let db = forge_db::Db::open(forge_db::Config::new(&db_path))
+            .unwrap_or_else(|err| panic!("open db {}: {err}", db_path.display()));
+        assert!(forge_db::LoopKVRepository::new(&db)
+            .get(&loop_id, "focus")
+            .is_err());
+    }
+
     #[test]
     fn sqlite_resume_local_owner_sets_metadata() {
         let (db_path, _tmp, loop_id) = setup_sqlite_resume_fixture();
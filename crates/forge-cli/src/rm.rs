@@ -541,7 +541,8 @@ fn map_loop_state(state: &forge_db::loop_repository::LoopState) -> LoopState {
     match state {
         forge_db::loop_repository::LoopState::Running
         | forge_db::loop_repository::LoopState::Sleeping
-        | forge_db::loop_repository::LoopState::Waiting => LoopState::Running,
+        | forge_db::loop_repository::LoopState::Waiting
+        | forge_db::loop_repository::LoopState::Paused => LoopState::Running,
         forge_db::loop_repository::LoopState::Stopped => LoopState::Stopped,
         forge_db::loop_repository::LoopState::Error => LoopState::Error,
     }
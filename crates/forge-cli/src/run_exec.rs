@@ -1,29 +1,37 @@
 use std::collections::HashMap;
 use std::fs;
-use std::io::{Read, Write};
-use std::path::Path;
-use std::process::{Command, Stdio};
-use std::sync::mpsc::{self, Sender};
+use std::io::Write;
+use std::path::{Path, PathBuf};
 use std::time::Duration;
 
 use chrono::{DateTime, Utc};
+use forge_core::loop_runner::{self, LoopProgressPhase};
 use forge_loop::harness_wrapper::{
-    build_execution_plan, HarnessKind, ProfileSpec, PromptMode as HarnessPromptMode,
+    build_execution_plan, run_harness_streaming, HarnessKind, HarnessRunOptions, ProfileSpec,
+    PromptMode as HarnessPromptMode,
 };
 use forge_loop::ledger_writer::{
     append_ledger_entry, ensure_ledger_file, LoopLedgerRecord, LoopRunRecord, ProfileRecord,
 };
 use forge_loop::log_io::{LoopLogger, DEFAULT_OUTPUT_TAIL_LINES};
+use forge_loop::profile_selection::{
+    AccountSelector, RunningCounts, WeightedCandidate, WeightedRoundRobin,
+    WEIGHTED_STATE_METADATA_KEY,
+};
 use forge_loop::prompt_composition::{
-    append_operator_messages, resolve_base_prompt, resolve_override_prompt, LoopPromptConfig,
-    OperatorMessage, PromptOverridePayload,
+    compose_prompt, render_loop_memory, resolve_base_prompt, resolve_override_prompt,
+    LoopPromptConfig, OperatorMessage, PromptOverridePayload, DEFAULT_MEMORY_MAX_CHARS,
+};
+use forge_loop::queue_interactions::{
+    should_inject_qualitative_stop, InteractionQueue, QueueControlItem, QueueInteractionPlan,
+    QueuePriority,
 };
-use forge_loop::queue_interactions::{should_inject_qualitative_stop, QueueInteractionPlan};
 use forge_loop::stop_rules;
 use serde::Deserialize;
 use serde_json::Value;
 
 const DEFAULT_WAIT_SECONDS: i64 = 5;
+const PAUSE_POLL_INTERVAL: Duration = Duration::from_millis(200);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum IterationControl {
@@ -87,7 +95,11 @@ pub fn run_single_iteration(db_path: &Path, loop_id: &str) -> Result<(), String>
 pub fn run_loop_until_stop(db_path: &Path, loop_id: &str) -> Result<(), String> {
     let db = forge_db::Db::open(forge_db::Config::new(db_path))
         .map_err(|err| format!("open database {}: {err}", db_path.display()))?;
+    let pause_file = loop_runner::pause_file_from_env();
     loop {
+        if let Some(pause_file) = pause_file.as_deref() {
+            wait_while_paused(&db, loop_id, pause_file)?;
+        }
         match run_iteration(&db, loop_id, false)? {
             IterationControl::Stop => return Ok(()),
             IterationControl::Sleep(duration) => {
@@ -99,6 +111,37 @@ pub fn run_loop_until_stop(db_path: &Path, loop_id: &str) -> Result<(), String>
     }
 }
 
+/// Idle between iterations while the daemon holds the pause flag raised.
+fn wait_while_paused(db: &forge_db::Db, loop_id: &str, pause_file: &Path) -> Result<(), String> {
+    if !pause_file.exists() {
+        return Ok(());
+    }
+    let loop_repo = forge_db::loop_repository::LoopRepository::new(db);
+    let mut loop_entry = loop_repo
+        .get(loop_id)
+        .map_err(|err| format!("load loop {loop_id}: {err}"))?;
+    if loop_entry.state != forge_db::loop_repository::LoopState::Paused {
+        loop_entry.state = forge_db::loop_repository::LoopState::Paused;
+        loop_repo
+            .update(&mut loop_entry)
+            .map_err(|err| format!("persist pause state {}: {err}", loop_entry.id))?;
+    }
+    while pause_file.exists() {
+        std::thread::sleep(PAUSE_POLL_INTERVAL);
+    }
+    Ok(())
+}
+
+fn report_progress(iteration: i32, phase: LoopProgressPhase, detail: &str) {
+    if !loop_runner::progress_enabled_from_env() {
+        return;
+    }
+    let line = loop_runner::format_progress_line(i64::from(iteration), phase, detail);
+    let mut stdout = std::io::stdout().lock();
+    let _ = writeln!(stdout, "{line}");
+    let _ = stdout.flush();
+}
+
 fn run_iteration(
     db: &forge_db::Db,
     loop_id: &str,
@@ -149,6 +192,7 @@ fn run_iteration(
     loop_repo
         .update(&mut loop_entry)
         .map_err(|err| format!("set loop {} running: {err}", loop_entry.id))?;
+    report_progress(iteration_index, LoopProgressPhase::IterationStarted, "");
 
     let queue_items = queue_repo
         .list(&loop_entry.id)
@@ -157,7 +201,12 @@ fn run_iteration(
 
     if !plan.stop_ids.is_empty() {
         mark_queue_completed(&queue_repo, &plan.stop_ids)?;
-        let _ = logger.write_line("graceful stop requested");
+        let line = if plan.stop_reason.is_empty() {
+            "graceful stop requested".to_string()
+        } else {
+            format!("graceful stop requested: {}", plan.stop_reason)
+        };
+        let _ = logger.write_line(&line);
         loop_entry.state = forge_db::loop_repository::LoopState::Stopped;
         loop_repo
             .update(&mut loop_entry)
@@ -269,7 +318,20 @@ fn run_iteration(
         )?;
     }
 
-    let prompt_content = append_operator_messages(&prompt.content, &plan.messages);
+    let loop_memory = match forge_db::LoopKVRepository::new(db).list_by_loop(&loop_entry.id) {
+        Ok(entries) => render_loop_memory(
+            &entries
+                .into_iter()
+                .map(|entry| (entry.key, entry.value))
+                .collect::<Vec<_>>(),
+            DEFAULT_MEMORY_MAX_CHARS,
+        ),
+        Err(err) => {
+            let _ = logger.write_line(&format!("memory injection failed: {err}"));
+            String::new()
+        }
+    };
+    let prompt_content = compose_prompt(&prompt.content, &loop_memory, &plan.messages);
 
     let mut run_record = forge_db::loop_run_repository::LoopRun {
         loop_id: loop_entry.id.clone(),
@@ -301,6 +363,11 @@ fn run_iteration(
         "run {} start (profile={})",
         run_record.id, profile.name
     ));
+    report_progress(
+        iteration_index,
+        LoopProgressPhase::HarnessInvoked,
+        &format!("profile={}", profile.name),
+    );
     let exec_result = execute_profile(
         &profile,
         &loop_entry,
@@ -308,6 +375,11 @@ fn run_iteration(
         &prepared.prompt_content,
         &mut logger,
     );
+    report_progress(
+        iteration_index,
+        LoopProgressPhase::OutputCaptured,
+        &format!("lines={}", exec_result.output_tail.lines().count()),
+    );
 
     run_record.status = if exec_result.err_text.is_empty() && exec_result.exit_code == 0 {
         forge_db::loop_run_repository::LoopRunStatus::Success
@@ -319,6 +391,15 @@ fn run_iteration(
     run_repo
         .finish(&mut run_record)
         .map_err(|err| format!("finish run {}: {err}", run_record.id))?;
+    report_progress(
+        iteration_index,
+        LoopProgressPhase::Outcome,
+        &format!(
+            "status={} exit={}",
+            run_record.status.as_str(),
+            exec_result.exit_code
+        ),
+    );
 
     let mut metadata = loop_entry.metadata.take().unwrap_or_default();
     let next_iteration = metadata_i64(&metadata, "iteration_count").unwrap_or(0) + 1;
@@ -579,6 +660,7 @@ struct QueuePlan {
     consume_ids: Vec<String>,
     pause_ids: Vec<String>,
     stop_ids: Vec<String>,
+    stop_reason: String,
     kill_ids: Vec<String>,
 }
 
@@ -587,12 +669,26 @@ fn build_queue_plan(
 ) -> Result<QueuePlan, String> {
     let mut plan = QueuePlan::default();
 
-    for item in items {
-        if item.status != "pending" {
+    // Pending items are served by priority (payload `priority`), then in
+    // queue position order.
+    let now = Utc::now();
+    let mut queue = InteractionQueue::new();
+    let mut by_position = HashMap::new();
+    for item in items.iter().filter(|item| item.status == "pending") {
+        let position = queue.enqueue(
+            QueueControlItem::from_item_type(&item.item_type),
+            queue_item_priority(&item.payload),
+            parse_rfc3339_utc(&item.created_at).unwrap_or(now),
+        );
+        by_position.insert(position, item);
+    }
+
+    while let Some(next) = queue.dequeue_next(now) {
+        let Some(item) = by_position.get(&next.position).copied() else {
             continue;
-        }
-        match item.item_type.as_str() {
-            "message_append" => {
+        };
+        match next.item {
+            QueueControlItem::MessageAppend => {
                 let payload: Value = serde_json::from_str(&item.payload)
                     .map_err(|err| format!("decode message_append payload {}: {err}", item.id))?;
                 let text = payload
@@ -609,7 +705,7 @@ fn build_queue_plan(
                     plan.consume_ids.push(item.id.clone());
                 }
             }
-            "steer_message" => {
+            QueueControlItem::SteerMessage => {
                 let payload: Value = serde_json::from_str(&item.payload)
                     .map_err(|err| format!("decode steer_message payload {}: {err}", item.id))?;
                 let text = payload
@@ -626,7 +722,7 @@ fn build_queue_plan(
                     plan.consume_ids.push(item.id.clone());
                 }
             }
-            "next_prompt_override" => {
+            QueueControlItem::NextPromptOverride => {
                 if plan.override_prompt.is_none() {
                     let payload: Value = serde_json::from_str(&item.payload).map_err(|err| {
                         format!("decode next_prompt_override payload {}: {err}", item.id)
@@ -646,7 +742,7 @@ fn build_queue_plan(
                     }
                 }
             }
-            "pause" => {
+            QueueControlItem::Pause => {
                 let payload: Value = serde_json::from_str(&item.payload)
                     .map_err(|err| format!("decode pause payload {}: {err}", item.id))?;
                 let seconds = payload
@@ -659,21 +755,44 @@ fn build_queue_plan(
                 plan.pause_ids.push(item.id.clone());
                 break;
             }
-            "stop_graceful" => {
+            QueueControlItem::StopGraceful => {
+                plan.stop_reason = serde_json::from_str::<Value>(&item.payload)
+                    .ok()
+                    .and_then(|payload| {
+                        payload
+                            .get("reason")
+                            .and_then(Value::as_str)
+                            .map(|reason| reason.trim().to_string())
+                    })
+                    .unwrap_or_default();
                 plan.stop_ids.push(item.id.clone());
                 break;
             }
-            "kill_now" => {
+            QueueControlItem::KillNow => {
                 plan.kill_ids.push(item.id.clone());
                 break;
             }
-            other => return Err(format!("unsupported queue item type \"{other}\"")),
+            QueueControlItem::Unsupported(other) => {
+                return Err(format!("unsupported queue item type \"{other}\""));
+            }
         }
     }
 
     Ok(plan)
 }
 
+fn queue_item_priority(payload: &str) -> QueuePriority {
+    serde_json::from_str::<Value>(payload)
+        .ok()
+        .and_then(|payload| {
+            payload
+                .get("priority")
+                .and_then(Value::as_str)
+                .and_then(QueuePriority::parse)
+        })
+        .unwrap_or_default()
+}
+
 fn mark_queue_completed(
     queue_repo: &forge_db::loop_queue_repository::LoopQueueRepository<'_>,
     ids: &[String],
@@ -767,36 +886,151 @@ fn select_profile(
     if members.is_empty() {
         return Err("pool unavailable".to_string());
     }
+    if members.iter().any(|member| member.weight != 1) {
+        // The rotation state is a read-modify-write of pool metadata; re-read
+        // the pool under a write lock so concurrent loops advance it in turn.
+        let pool_id = pool.id.clone();
+        let select = |db: &forge_db::Db| {
+            let mut pool = forge_db::pool_repository::PoolRepository::new(db)
+                .get(&pool_id)
+                .map_err(|err| {
+                    forge_db::DbError::Transaction(format!("load pool {pool_id}: {err}"))
+                })?;
+            select_weighted_member(db, &mut pool, &members, now)
+                .map_err(forge_db::DbError::Transaction)
+        };
+        let selected = if db.conn().is_autocommit() {
+            db.immediate_transaction(select)
+        } else {
+            select(db)
+        };
+        return selected.map_err(|err| err.to_string());
+    }
 
+    // Round-robin from the member after the last pick; the account selector
+    // falls back past accounts that are cooling down or at capacity.
     let start_index = pool_last_index(&pool);
-    let mut earliest_wait: Option<DateTime<Utc>> = None;
+    let mut ordered = Vec::with_capacity(members.len());
     for offset in 0..members.len() {
         let idx = ((start_index + 1 + offset as i32).rem_euclid(members.len() as i32)) as usize;
-        let member = &members[idx];
-        let profile = match profile_repo.get(&member.profile_id) {
-            Ok(profile) => profile,
-            Err(_) => continue,
-        };
-        let (available, next_wait, _) = match profile_available(&run_repo, &profile, now) {
-            Ok(result) => result,
-            Err(_) => continue,
-        };
-        if available {
+        if let Ok(profile) = profile_repo.get(&members[idx].profile_id) {
+            ordered.push((idx, profile));
+        }
+    }
+    let candidates: Vec<_> = ordered
+        .iter()
+        .map(|(_, profile)| forge_loop::profile_selection::Profile {
+            id: profile.id.clone(),
+            name: profile.name.clone(),
+            max_concurrency: profile.max_concurrency as i32,
+            cooldown_until_epoch: profile
+                .cooldown_until
+                .as_deref()
+                .and_then(parse_rfc3339_utc)
+                .map(|until| until.timestamp()),
+        })
+        .collect();
+
+    match AccountSelector::new(&RunCounts(&run_repo)).select(&candidates, now.timestamp()) {
+        Ok(selection) => {
+            let (idx, profile) = ordered.swap_remove(selection.candidate_index);
             set_pool_last_index(&mut pool, idx as i32);
-            let _ = pool_repo.update(&mut pool);
-            return Ok((Some(profile), None));
+            pool_repo
+                .update(&mut pool)
+                .map_err(|err| format!("update pool {}: {err}", pool.id))?;
+            Ok((Some(profile), None))
         }
-        if let Some(wait_until) = next_wait {
-            earliest_wait = Some(match earliest_wait {
-                Some(existing) if existing <= wait_until => existing,
-                _ => wait_until,
-            });
+        Err(none) => {
+            let wait_until = DateTime::from_timestamp(none.wait_until_epoch, 0)
+                .unwrap_or_else(|| now + chrono::Duration::seconds(DEFAULT_WAIT_SECONDS));
+            Ok((None, Some(wait_until)))
+        }
+    }
+}
+
+/// Running-loop counts for [`AccountSelector`], read from `loop_runs`.
+struct RunCounts<'a>(&'a forge_db::loop_run_repository::LoopRunRepository<'a>);
+
+impl RunningCounts for RunCounts<'_> {
+    fn count_running_by_profile(&self, profile_id: &str) -> Result<i32, String> {
+        self.0
+            .count_running_by_profile(profile_id)
+            .map(|count| count as i32)
+            .map_err(|err| format!("count running for profile {profile_id}: {err}"))
+    }
+}
+
+fn select_weighted_member(
+    db: &forge_db::Db,
+    pool: &mut forge_db::pool_repository::Pool,
+    members: &[forge_db::pool_repository::PoolMember],
+    now: DateTime<Utc>,
+) -> Result<
+    (
+        Option<forge_db::profile_repository::Profile>,
+        Option<DateTime<Utc>>,
+    ),
+    String,
+> {
+    let profile_repo = forge_db::profile_repository::ProfileRepository::new(db);
+    let pool_repo = forge_db::pool_repository::PoolRepository::new(db);
+    let run_repo = forge_db::loop_run_repository::LoopRunRepository::new(db);
+
+    let mut profiles = Vec::new();
+    let mut candidates = Vec::new();
+    let mut available = Vec::new();
+    let mut earliest_wait: Option<DateTime<Utc>> = None;
+    for member in members {
+        let Ok(profile) = profile_repo.get(&member.profile_id) else {
+            continue;
+        };
+        let (is_available, next_wait) = match profile_available(&run_repo, &profile, now) {
+            Ok((is_available, next_wait, _)) => (is_available, next_wait),
+            Err(_) => (false, None),
+        };
+        if member.weight > 0 {
+            if let Some(wait_until) = next_wait {
+                earliest_wait =
+                    Some(earliest_wait.map_or(wait_until, |existing| existing.min(wait_until)));
+            }
         }
+        available.push(is_available);
+        candidates.push(WeightedCandidate {
+            profile: forge_loop::profile_selection::Profile {
+                id: profile.id.clone(),
+                name: profile.name.clone(),
+                max_concurrency: profile.max_concurrency as i32,
+                cooldown_until_epoch: next_wait.map(|wait_until| wait_until.timestamp()),
+            },
+            weight: member.weight.clamp(0, i64::from(u32::MAX)) as u32,
+        });
+        profiles.push(profile);
     }
 
-    let wait_until =
-        earliest_wait.unwrap_or_else(|| now + chrono::Duration::seconds(DEFAULT_WAIT_SECONDS));
-    Ok((None, Some(wait_until)))
+    let mut rotation = match pool
+        .metadata
+        .as_ref()
+        .and_then(|metadata| metadata.get(WEIGHTED_STATE_METADATA_KEY))
+    {
+        Some(Value::String(encoded)) => WeightedRoundRobin::decode(encoded),
+        _ => WeightedRoundRobin::default(),
+    };
+    let Some(idx) = rotation.next(&candidates, |idx| {
+        available.get(idx).copied().unwrap_or(false)
+    }) else {
+        let wait_until =
+            earliest_wait.unwrap_or_else(|| now + chrono::Duration::seconds(DEFAULT_WAIT_SECONDS));
+        return Ok((None, Some(wait_until)));
+    };
+
+    pool.metadata.get_or_insert_with(HashMap::new).insert(
+        WEIGHTED_STATE_METADATA_KEY.to_string(),
+        Value::from(rotation.encode()),
+    );
+    pool_repo
+        .update(pool)
+        .map_err(|err| format!("update pool {}: {err}", pool.id))?;
+    Ok((profiles.into_iter().nth(idx), None))
 }
 
 fn profile_available(
@@ -942,136 +1176,29 @@ fn execute_profile(
         }
     };
 
-    let mut command = Command::new("bash");
-    command.arg("-lc").arg(&plan.command);
-    command.current_dir(&loop_entry.repo_path);
-    command.env_clear();
-    for env_pair in &plan.env {
-        if let Some((key, value)) = env_pair.split_once('=') {
-            command.env(key, value);
-        }
-    }
-    if plan.stdin.is_some() {
-        command.stdin(Stdio::piped());
-    } else {
-        command.stdin(Stdio::null());
-    }
-    command.stdout(Stdio::piped());
-    command.stderr(Stdio::piped());
-
-    let mut child = match command.spawn() {
-        Ok(child) => child,
-        Err(err) => {
-            return ExecutionResult {
-                exit_code: -1,
-                output_tail: String::new(),
-                err_text: err.to_string(),
-            };
-        }
+    let options = HarnessRunOptions {
+        workdir: Some(PathBuf::from(&loop_entry.repo_path)),
+        login_shell: true,
+        ..HarnessRunOptions::default()
     };
-
-    if let Some(stdin_payload) = &plan.stdin {
-        if let Some(mut stdin) = child.stdin.take() {
-            let _ = stdin.write_all(stdin_payload.as_bytes());
-        }
-    }
-
     let mut combined = String::new();
-    let mut stream_errors = Vec::new();
-
-    let stdout = match child.stdout.take() {
-        Some(stdout) => stdout,
-        None => {
-            return ExecutionResult {
-                exit_code: -1,
-                output_tail: String::new(),
-                err_text: "child stdout pipe unavailable".to_string(),
-            };
-        }
-    };
-    let stderr = match child.stderr.take() {
-        Some(stderr) => stderr,
-        None => {
-            return ExecutionResult {
-                exit_code: -1,
-                output_tail: String::new(),
-                err_text: "child stderr pipe unavailable".to_string(),
-            };
-        }
-    };
-
-    let (tx, rx) = mpsc::channel::<Vec<u8>>();
-    let stdout_reader = std::thread::spawn({
-        let tx = tx.clone();
-        move || read_stream_chunks(stdout, tx)
-    });
-    let stderr_reader = std::thread::spawn({
-        let tx = tx.clone();
-        move || read_stream_chunks(stderr, tx)
+    let run = run_harness_streaming(&plan, &options, |chunk| {
+        let _ = logger.write_all(chunk);
+        combined.push_str(&String::from_utf8_lossy(chunk));
     });
-    drop(tx);
-
-    while let Ok(chunk) = rx.recv() {
-        if chunk.is_empty() {
-            continue;
-        }
-        let _ = logger.write_all(&chunk);
-        combined.push_str(&String::from_utf8_lossy(&chunk));
-    }
     let _ = logger.flush();
 
-    if let Ok(Some(err)) = stdout_reader.join() {
-        stream_errors.push(format!("stdout read error: {err}"));
-    }
-    if let Ok(Some(err)) = stderr_reader.join() {
-        stream_errors.push(format!("stderr read error: {err}"));
-    }
-
-    let status = match child.wait() {
-        Ok(status) => status,
-        Err(err) => {
-            return ExecutionResult {
-                exit_code: -1,
-                output_tail: tail_lines(&combined, DEFAULT_OUTPUT_TAIL_LINES),
-                err_text: format!("wait failed: {err}"),
-            };
-        }
-    };
-
-    let exit_code = status.code().unwrap_or(-1);
-    let mut err_text = if status.success() {
-        String::new()
-    } else {
-        format!("exit status {exit_code}")
-    };
-    if !stream_errors.is_empty() {
-        let stream_msg = stream_errors.join("; ");
-        if err_text.is_empty() {
-            err_text = stream_msg;
-        } else {
-            err_text.push_str("; ");
-            err_text.push_str(&stream_msg);
-        }
-    }
-    ExecutionResult {
-        exit_code,
-        output_tail: tail_lines(&combined, DEFAULT_OUTPUT_TAIL_LINES),
-        err_text,
-    }
-}
-
-fn read_stream_chunks<R: Read>(mut reader: R, tx: Sender<Vec<u8>>) -> Option<String> {
-    let mut buf = [0u8; 8192];
-    loop {
-        match reader.read(&mut buf) {
-            Ok(0) => return None,
-            Ok(n) => {
-                if tx.send(buf[..n].to_vec()).is_err() {
-                    return None;
-                }
-            }
-            Err(err) => return Some(err.to_string()),
-        }
+    match run {
+        Ok(run) => ExecutionResult {
+            exit_code: run.outcome.exit_code(),
+            output_tail: tail_lines(&combined, DEFAULT_OUTPUT_TAIL_LINES),
+            err_text: run.outcome.error_message().unwrap_or_default(),
+        },
+        Err(err) => ExecutionResult {
+            exit_code: -1,
+            output_tail: tail_lines(&combined, DEFAULT_OUTPUT_TAIL_LINES),
+            err_text: err.to_string(),
+        },
     }
 }
 
@@ -3,6 +3,7 @@ use std::io::Write;
 use std::path::{Path, PathBuf};
 
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 
 mod filesystem_backend;
 pub use filesystem_backend::FilesystemSeqBackend;
@@ -60,11 +61,32 @@ pub struct SequenceVar {
     pub required: bool,
 }
 
+/// Queue items of the latest `seq run`, kept so a failed run can resume.
+///
+/// Keyed by sequence name and agent. `item_ids[i]` is the queue item for
+/// step `completed_steps + i`. `fingerprint` hashes the rendered steps so a
+/// checkpoint is discarded once the sequence or its variables change.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SeqCheckpoint {
+    pub sequence: String,
+    pub agent_id: String,
+    pub fingerprint: String,
+    pub completed_steps: usize,
+    #[serde(default)]
+    pub item_ids: Vec<String>,
+}
+
 #[derive(Debug, Serialize)]
 struct SequenceRunResult {
     sequence: String,
     agent_id: String,
     item_ids: Vec<String>,
+    #[serde(skip_serializing_if = "is_zero")]
+    skipped_steps: usize,
+}
+
+fn is_zero(value: &usize) -> bool {
+    *value == 0
 }
 
 #[derive(Debug, Serialize)]
@@ -94,11 +116,23 @@ pub trait SeqBackend {
     fn open_editor(&self, path: &Path) -> Result<(), String>;
 
     fn resolve_agent_id(&self, agent_flag: &str) -> Result<String, String>;
+    /// Queue `items` in one transaction. Items in `superseded` that are
+    /// still pending are marked skipped in the same transaction.
     fn enqueue_sequence_items(
         &mut self,
         agent_id: &str,
         items: &[RenderedQueueItem],
+        superseded: &[String],
     ) -> Result<Vec<String>, String>;
+    /// Status of each queue item, or `None` when the item no longer exists.
+    fn queue_item_statuses(&self, item_ids: &[String]) -> Result<Vec<Option<String>>, String>;
+
+    fn load_checkpoint(
+        &self,
+        sequence: &str,
+        agent_id: &str,
+    ) -> Result<Option<SeqCheckpoint>, String>;
+    fn save_checkpoint(&self, checkpoint: &SeqCheckpoint) -> Result<(), String>;
 }
 
 // ---------------------------------------------------------------------------
@@ -117,6 +151,9 @@ pub struct InMemorySeqBackend {
     pub editor_opened: std::cell::RefCell<Vec<PathBuf>>,
     pub agent_id: Option<String>,
     pub enqueued: std::cell::RefCell<Vec<(String, usize)>>,
+    /// Queue item status by id; enqueued items start out `pending`.
+    pub item_statuses: std::cell::RefCell<HashMap<String, String>>,
+    pub checkpoints: std::cell::RefCell<Vec<SeqCheckpoint>>,
 }
 
 impl SeqBackend for InMemorySeqBackend {
@@ -171,14 +208,58 @@ impl SeqBackend for InMemorySeqBackend {
         &mut self,
         agent_id: &str,
         items: &[RenderedQueueItem],
+        superseded: &[String],
     ) -> Result<Vec<String>, String> {
+        let mut statuses = self.item_statuses.borrow_mut();
+        for id in superseded {
+            if let Some(status) = statuses.get_mut(id) {
+                if status == "pending" {
+                    *status = "skipped".to_string();
+                }
+            }
+        }
+        let offset: usize = self.enqueued.borrow().iter().map(|(_, count)| count).sum();
         self.enqueued
             .borrow_mut()
             .push((agent_id.to_string(), items.len()));
-        Ok((0..items.len())
+        let ids: Vec<String> = (offset..offset + items.len())
             .map(|idx| format!("item-{idx:03}"))
+            .collect();
+        for id in &ids {
+            statuses.insert(id.clone(), "pending".to_string());
+        }
+        Ok(ids)
+    }
+
+    fn queue_item_statuses(&self, item_ids: &[String]) -> Result<Vec<Option<String>>, String> {
+        let statuses = self.item_statuses.borrow();
+        Ok(item_ids
+            .iter()
+            .map(|id| statuses.get(id).cloned())
             .collect())
     }
+
+    fn load_checkpoint(
+        &self,
+        sequence: &str,
+        agent_id: &str,
+    ) -> Result<Option<SeqCheckpoint>, String> {
+        Ok(self
+            .checkpoints
+            .borrow()
+            .iter()
+            .find(|cp| cp.sequence == sequence && cp.agent_id == agent_id)
+            .cloned())
+    }
+
+    fn save_checkpoint(&self, checkpoint: &SeqCheckpoint) -> Result<(), String> {
+        let mut checkpoints = self.checkpoints.borrow_mut();
+        checkpoints.retain(|cp| {
+            !(cp.sequence == checkpoint.sequence && cp.agent_id == checkpoint.agent_id)
+        });
+        checkpoints.push(checkpoint.clone());
+        Ok(())
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -204,6 +285,8 @@ enum SubCommand {
         name: String,
         agent: String,
         vars: Vec<String>,
+        resume: bool,
+        restart: bool,
     },
     Delete {
         name: String,
@@ -283,11 +366,18 @@ fn execute(
         SubCommand::Edit { name } => {
             execute_edit(backend, &name, parsed.json, parsed.jsonl, stdout)
         }
-        SubCommand::Run { name, agent, vars } => execute_run(
+        SubCommand::Run {
+            name,
+            agent,
+            vars,
+            resume,
+            restart,
+        } => execute_run(
             backend,
             &name,
             &agent,
             &vars,
+            RunMode { resume, restart },
             parsed.json,
             parsed.jsonl,
             stdout,
@@ -513,11 +603,48 @@ fn execute_delete(
     Ok(())
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RunProgress {
+    /// Every checkpointed step completed.
+    Finished,
+    /// A step is still queued or running.
+    Running { done: usize },
+    /// `step` failed, was skipped, or is gone from the queue.
+    Failed { step: usize },
+}
+
+/// Where the checkpointed run stands, judged by its queue items.
+fn checkpoint_progress(
+    backend: &dyn SeqBackend,
+    checkpoint: &SeqCheckpoint,
+) -> Result<RunProgress, String> {
+    let statuses = backend.queue_item_statuses(&checkpoint.item_ids)?;
+    let done = statuses
+        .iter()
+        .take_while(|status| status.as_deref() == Some("completed"))
+        .count();
+    let step = checkpoint.completed_steps + done;
+    Ok(match statuses.get(done) {
+        None => RunProgress::Finished,
+        Some(Some(status)) if status == "pending" || status == "dispatched" => {
+            RunProgress::Running { done: step }
+        }
+        Some(_) => RunProgress::Failed { step },
+    })
+}
+
+#[derive(Debug, Clone, Copy)]
+struct RunMode {
+    resume: bool,
+    restart: bool,
+}
+
 fn execute_run(
     backend: &mut dyn SeqBackend,
     name: &str,
     agent: &str,
     vars: &[String],
+    mode: RunMode,
     json: bool,
     jsonl: bool,
     stdout: &mut dyn Write,
@@ -530,30 +657,103 @@ fn execute_run(
     let rendered = render_sequence(seq, &vars)?;
 
     let agent_id = backend.resolve_agent_id(agent)?;
-    let item_ids = backend.enqueue_sequence_items(&agent_id, &rendered)?;
+    let fingerprint = sequence_fingerprint(&rendered)?;
+
+    let mut stale = false;
+    let mut skipped_steps = 0;
+    let mut superseded = Vec::new();
+    if let Some(cp) = backend.load_checkpoint(&seq.name, &agent_id)? {
+        match checkpoint_progress(backend, &cp)? {
+            RunProgress::Finished => {}
+            _ if mode.restart => superseded = cp.item_ids,
+            RunProgress::Running { done } | RunProgress::Failed { step: done } if !mode.resume => {
+                return Err(format!(
+                    "sequence {:?} has an unfinished run for agent {} ({} of {} steps done); use --resume or --restart",
+                    seq.name,
+                    short_id(&agent_id),
+                    done,
+                    rendered.len()
+                ));
+            }
+            RunProgress::Running { done } => {
+                return Err(format!(
+                    "sequence {:?} is still running for agent {} ({} of {} steps done)",
+                    seq.name,
+                    short_id(&agent_id),
+                    done,
+                    rendered.len()
+                ));
+            }
+            RunProgress::Failed { step } => {
+                if cp.fingerprint == fingerprint && step < rendered.len() {
+                    skipped_steps = step;
+                } else {
+                    stale = true;
+                }
+                superseded = cp.item_ids;
+            }
+        }
+    }
 
-    let result = SequenceRunResult {
+    let item_ids = backend
+        .enqueue_sequence_items(&agent_id, &rendered[skipped_steps..], &superseded)
+        .map_err(|err| format!("sequence {:?}: {err}", seq.name))?;
+    backend.save_checkpoint(&SeqCheckpoint {
         sequence: seq.name.clone(),
         agent_id: agent_id.clone(),
+        fingerprint,
+        completed_steps: skipped_steps,
         item_ids: item_ids.clone(),
+    })?;
+
+    let result = SequenceRunResult {
+        sequence: seq.name.clone(),
+        agent_id: agent_id.clone(),
+        item_ids,
+        skipped_steps,
     };
 
     if json || jsonl {
         return write_json_or_jsonl(stdout, &result, jsonl);
     }
 
-    writeln!(
-        stdout,
-        "Queued sequence {:?} ({} steps) for agent {}",
-        seq.name,
-        rendered.len(),
-        short_id(&agent_id)
-    )
-    .map_err(|e| e.to_string())?;
+    if stale {
+        writeln!(
+            stdout,
+            "Sequence {:?} changed since its checkpoint; starting from step 1",
+            seq.name
+        )
+        .map_err(|e| e.to_string())?;
+    }
+    if skipped_steps > 0 {
+        writeln!(
+            stdout,
+            "Resumed sequence {:?} at step {} ({} steps) for agent {}",
+            seq.name,
+            skipped_steps + 1,
+            rendered.len(),
+            short_id(&agent_id)
+        )
+        .map_err(|e| e.to_string())?;
+    } else {
+        writeln!(
+            stdout,
+            "Queued sequence {:?} ({} steps) for agent {}",
+            seq.name,
+            rendered.len(),
+            short_id(&agent_id)
+        )
+        .map_err(|e| e.to_string())?;
+    }
     for (idx, step) in seq.steps.iter().enumerate() {
+        let status = if idx < skipped_steps {
+            "skipped (already done)"
+        } else {
+            "queued"
+        };
         writeln!(
             stdout,
-            "  Step {}: {} -> queued",
+            "  Step {}: {} -> {status}",
             idx + 1,
             format_sequence_step_short(step)
         )
@@ -587,6 +787,8 @@ fn parse_args(args: &[String]) -> Result<ParsedArgs, String> {
     let mut tags: Vec<String> = Vec::new();
     let mut agent = String::new();
     let mut var_args: Vec<String> = Vec::new();
+    let mut resume = false;
+    let mut restart = false;
     let mut positionals: Vec<String> = Vec::new();
 
     let mut idx = start;
@@ -609,6 +811,14 @@ fn parse_args(args: &[String]) -> Result<ParsedArgs, String> {
                 // accepted but ignored
                 idx += 1;
             }
+            "--resume" => {
+                resume = true;
+                idx += 1;
+            }
+            "--restart" => {
+                restart = true;
+                idx += 1;
+            }
             "--tags" => {
                 idx += 1;
                 let value = args
@@ -662,6 +872,9 @@ fn parse_args(args: &[String]) -> Result<ParsedArgs, String> {
     if json && jsonl {
         return Err("error: --json and --jsonl cannot be used together".to_string());
     }
+    if resume && restart {
+        return Err("error: --resume and --restart cannot be used together".to_string());
+    }
 
     let subcmd = positionals.first().map(|s| s.as_str());
     let command = match subcmd {
@@ -697,6 +910,8 @@ fn parse_args(args: &[String]) -> Result<ParsedArgs, String> {
                 name,
                 agent,
                 vars: var_args,
+                resume,
+                restart,
             }
         }
         Some("delete") | Some("rm") => {
@@ -820,6 +1035,14 @@ fn parse_sequence_vars(values: &[String]) -> Result<HashMap<String, String>, Str
     Ok(vars)
 }
 
+/// Hash of the rendered steps, so edited bodies or new variable values
+/// invalidate a checkpoint.
+fn sequence_fingerprint(items: &[RenderedQueueItem]) -> Result<String, String> {
+    let rendered =
+        serde_json::to_vec(items).map_err(|err| format!("fingerprint sequence: {err}"))?;
+    Ok(hex::encode(Sha256::digest(rendered)))
+}
+
 fn format_sequence_step(step: &SequenceStep) -> String {
     let step_type = step.step_type.as_str();
     match step_type {
@@ -891,7 +1114,7 @@ fn normalized_step_content(step: &SequenceStep) -> &str {
 // Rendering (validation parity for `seq run`)
 // ---------------------------------------------------------------------------
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub enum RenderedQueueItem {
     Message {
         text: String,
@@ -1244,5 +1467,13 @@ fn write_help(stdout: &mut dyn Write) -> std::io::Result<()> {
         stdout,
         "      --var string    sequence variable key=value (run only)"
     )?;
+    writeln!(
+        stdout,
+        "      --resume        continue a failed run from its checkpoint (run only)"
+    )?;
+    writeln!(
+        stdout,
+        "      --restart       discard any checkpoint and run from step 1 (run only)"
+    )?;
     Ok(())
 }
@@ -3,7 +3,7 @@ use std::path::{Path, PathBuf};
 
 use rusqlite::OptionalExtension;
 
-use super::{RenderedQueueItem, SeqBackend, Sequence, SequenceStep, SequenceVar};
+use super::{RenderedQueueItem, SeqBackend, SeqCheckpoint, Sequence, SequenceStep, SequenceVar};
 use crate::context::{ContextBackend, FilesystemContextBackend};
 
 #[derive(Debug, Clone)]
@@ -153,6 +153,21 @@ impl FilesystemSeqBackend {
             .collect())
     }
 
+    /// Checkpoints live next to the database so they follow its data dir.
+    fn checkpoint_path(&self, sequence: &str, agent_id: &str) -> PathBuf {
+        let dir = self
+            .db_path
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_default()
+            .join("seq-checkpoints");
+        dir.join(format!(
+            "{}--{}.json",
+            checkpoint_file_component(sequence),
+            checkpoint_file_component(agent_id)
+        ))
+    }
+
     fn ensure_agent_exists(conn: &rusqlite::Connection, agent_id: &str) -> Result<(), String> {
         let row = conn
             .query_row(
@@ -299,15 +314,28 @@ impl SeqBackend for FilesystemSeqBackend {
         &mut self,
         agent_id: &str,
         items: &[RenderedQueueItem],
+        superseded: &[String],
     ) -> Result<Vec<String>, String> {
         if !self.db_path.exists() {
             return Err("database not found".to_string());
         }
         let db = self.open_db()?;
-        let conn = db.conn();
-        Self::ensure_agent_exists(conn, agent_id)?;
+        let tx = rusqlite::Transaction::new_unchecked(
+            db.conn(),
+            rusqlite::TransactionBehavior::Immediate,
+        )
+        .map_err(|err| format!("begin transaction: {err}"))?;
+        Self::ensure_agent_exists(&tx, agent_id)?;
+
+        for id in superseded {
+            tx.execute(
+                "UPDATE queue_items SET status = 'skipped' WHERE id = ?1 AND status = 'pending'",
+                rusqlite::params![id],
+            )
+            .map_err(|err| format!("skip queue item {id}: {err}"))?;
+        }
 
-        let mut next = Self::next_position(conn, agent_id)?;
+        let mut next = Self::next_position(&tx, agent_id)?;
         let mut ids = Vec::with_capacity(items.len());
         for item in items {
             let id = Self::next_item_id();
@@ -352,12 +380,73 @@ impl SeqBackend for FilesystemSeqBackend {
                     ("conditional", payload)
                 }
             };
-            Self::insert_item(conn, &id, agent_id, item_type, next, &payload)?;
+            Self::insert_item(&tx, &id, agent_id, item_type, next, &payload)?;
             next += 1;
             ids.push(id);
         }
+        tx.commit()
+            .map_err(|err| format!("commit queue items: {err}"))?;
         Ok(ids)
     }
+
+    fn queue_item_statuses(&self, item_ids: &[String]) -> Result<Vec<Option<String>>, String> {
+        if !self.db_path.exists() {
+            return Err("database not found".to_string());
+        }
+        let db = self.open_db()?;
+        let mut stmt = db
+            .conn()
+            .prepare("SELECT status FROM queue_items WHERE id = ?1")
+            .map_err(|err| format!("prepare queue item status: {err}"))?;
+        item_ids
+            .iter()
+            .map(|id| {
+                stmt.query_row(rusqlite::params![id], |row| row.get::<_, String>(0))
+                    .optional()
+                    .map_err(|err| format!("queue item {id}: {err}"))
+            })
+            .collect()
+    }
+
+    fn load_checkpoint(
+        &self,
+        sequence: &str,
+        agent_id: &str,
+    ) -> Result<Option<SeqCheckpoint>, String> {
+        let path = self.checkpoint_path(sequence, agent_id);
+        let raw = match std::fs::read_to_string(&path) {
+            Ok(raw) => raw,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(format!("read checkpoint {}: {err}", path.display())),
+        };
+        serde_json::from_str(&raw)
+            .map(Some)
+            .map_err(|err| format!("parse checkpoint {}: {err}", path.display()))
+    }
+
+    fn save_checkpoint(&self, checkpoint: &SeqCheckpoint) -> Result<(), String> {
+        let path = self.checkpoint_path(&checkpoint.sequence, &checkpoint.agent_id);
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)
+                .map_err(|err| format!("create checkpoint dir {}: {err}", dir.display()))?;
+        }
+        let body = serde_json::to_string_pretty(checkpoint).map_err(|err| err.to_string())?;
+        std::fs::write(&path, body)
+            .map_err(|err| format!("write checkpoint {}: {err}", path.display()))
+    }
+}
+
+fn checkpoint_file_component(value: &str) -> String {
+    value
+        .chars()
+        .map(|ch| {
+            if ch.is_ascii_alphanumeric() || ch == '-' || ch == '_' || ch == '.' {
+                ch
+            } else {
+                '_'
+            }
+        })
+        .collect()
 }
 
 #[derive(Debug, serde::Deserialize)]
@@ -1,5 +1,5 @@
 use std::cell::RefCell;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::io::Write;
 use std::path::{Path, PathBuf};
 
@@ -43,7 +43,7 @@ pub struct SkillsConfig {
     pub default_pool: String,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Default, Deserialize)]
 struct SkillsConfigYaml {
     #[serde(default)]
     profiles: Vec<SkillsProfileYaml>,
@@ -87,6 +87,49 @@ struct BootstrapOutput {
     installed: Vec<InstallResult>,
 }
 
+/// A skill read from the frontmatter of `<dir>/SKILL.md`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SkillDef {
+    pub name: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub description: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub requires: Vec<String>,
+    /// Directory holding the skill, relative to the skills source.
+    #[serde(skip)]
+    pub dir: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct SkillFrontmatter {
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    description: String,
+    #[serde(default)]
+    requires: Vec<String>,
+}
+
+/// One node of `skills list --tree`; children are the skill's requirements.
+#[derive(Debug, Clone, Serialize)]
+struct SkillTreeNode {
+    name: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    requires: Vec<SkillTreeNode>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    missing: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    cycle: bool,
+}
+
+/// Top-level JSON output from `skills enable`.
+#[derive(Debug, Clone, Serialize)]
+struct EnableOutput {
+    source: String,
+    enabled: Vec<String>,
+    installed: Vec<InstallResult>,
+}
+
 // ---------------------------------------------------------------------------
 // Builtin skills (embedded at compile time)
 // ---------------------------------------------------------------------------
@@ -168,22 +211,15 @@ impl SkillsBackend for FilesystemSkillsBackend {
     }
 
     fn load_config(&self) -> Result<Option<SkillsConfig>, String> {
-        let config_path = resolve_config_path(&self.home_dir()?);
-        let raw = match std::fs::read_to_string(&config_path) {
-            Ok(value) => value,
-            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
-            Err(err) => {
-                return Err(format!(
-                    "failed to read config file {}: {err}",
-                    config_path.display()
-                ))
-            }
-        };
+        let effective = crate::config::FilesystemConfigBackend.effective_config()?;
+        if effective.active_profile.is_none() && !effective.path.is_file() {
+            return Ok(None);
+        }
 
-        let parsed = parse_skills_config_yaml(&raw).map_err(|err| {
+        let parsed = parse_skills_config_value(effective.values).map_err(|err| {
             format!(
                 "failed to parse config file {}: {err}",
-                config_path.display()
+                effective.path.display()
             )
         })?;
         Ok(Some(parsed))
@@ -220,16 +256,12 @@ impl SkillsBackend for FilesystemSkillsBackend {
     }
 }
 
-fn resolve_config_path(home: &Path) -> PathBuf {
-    if let Some(path) = std::env::var_os("FORGE_CONFIG_PATH") {
-        return PathBuf::from(path);
-    }
-    home.join(".config").join("forge").join("config.yaml")
-}
-
-fn parse_skills_config_yaml(raw: &str) -> Result<SkillsConfig, String> {
-    let parsed: SkillsConfigYaml =
-        serde_yaml::from_str(raw).map_err(|err| format!("yaml decode error: {err}"))?;
+fn parse_skills_config_value(value: serde_yaml::Value) -> Result<SkillsConfig, String> {
+    let parsed: SkillsConfigYaml = if value.is_null() {
+        SkillsConfigYaml::default()
+    } else {
+        serde_yaml::from_value(value).map_err(|err| format!("yaml decode error: {err}"))?
+    };
     Ok(SkillsConfig {
         profiles: parsed
             .profiles
@@ -366,7 +398,11 @@ impl SkillsBackend for InMemorySkillsBackend {
 
 enum SubCommand {
     Help,
+    ListHelp,
+    EnableHelp,
     Bootstrap(BootstrapArgs),
+    List(ListArgs),
+    Enable(EnableArgs),
 }
 
 struct BootstrapArgs {
@@ -377,6 +413,22 @@ struct BootstrapArgs {
     jsonl: bool,
 }
 
+struct ListArgs {
+    tree: bool,
+    path: String,
+    json: bool,
+    jsonl: bool,
+}
+
+struct EnableArgs {
+    skills: Vec<String>,
+    force: bool,
+    path: String,
+    all_profiles: bool,
+    json: bool,
+    jsonl: bool,
+}
+
 fn parse_args(args: &[String]) -> Result<SubCommand, String> {
     // args[0] == "skills"
     if args.len() < 2 {
@@ -419,6 +471,71 @@ fn parse_args(args: &[String]) -> Result<SubCommand, String> {
                 jsonl,
             }))
         }
+        "list" | "ls" => {
+            let mut parsed = ListArgs {
+                tree: false,
+                path: String::new(),
+                json: false,
+                jsonl: false,
+            };
+            let mut i = 2;
+            while i < args.len() {
+                match args[i].as_str() {
+                    "--help" | "-h" => return Ok(SubCommand::ListHelp),
+                    "--tree" => parsed.tree = true,
+                    "--path" => {
+                        i += 1;
+                        if i >= args.len() {
+                            return Err("--path requires a value".to_string());
+                        }
+                        parsed.path = args[i].clone();
+                    }
+                    "--json" => parsed.json = true,
+                    "--jsonl" => parsed.jsonl = true,
+                    other => {
+                        return Err(format!("unknown flag for skills list: {other}"));
+                    }
+                }
+                i += 1;
+            }
+            Ok(SubCommand::List(parsed))
+        }
+        "enable" => {
+            let mut parsed = EnableArgs {
+                skills: Vec::new(),
+                force: false,
+                path: String::new(),
+                all_profiles: false,
+                json: false,
+                jsonl: false,
+            };
+            let mut i = 2;
+            while i < args.len() {
+                match args[i].as_str() {
+                    "--help" | "-h" => return Ok(SubCommand::EnableHelp),
+                    "--force" | "-f" => parsed.force = true,
+                    "--path" => {
+                        i += 1;
+                        if i >= args.len() {
+                            return Err("--path requires a value".to_string());
+                        }
+                        parsed.path = args[i].clone();
+                    }
+                    "--all-profiles" => parsed.all_profiles = true,
+                    "--json" => parsed.json = true,
+                    "--jsonl" => parsed.jsonl = true,
+                    other if other.starts_with('-') => {
+                        return Err(format!("unknown flag for skills enable: {other}"));
+                    }
+                    name => parsed.skills.push(name.to_string()),
+                }
+                i += 1;
+            }
+            if parsed.skills.is_empty() {
+                return Err("usage: forge skills enable <skill>...".to_string());
+            }
+            Ok(SubCommand::Enable(parsed))
+        }
         other => Err(format!("unknown skills subcommand: {other}")),
     }
 }
@@ -528,12 +645,22 @@ fn install_source_to_dest(
     dest: &str,
     force: bool,
     backend: &dyn SkillsBackend,
+) -> Result<(Vec<String>, Vec<String>), String> {
+    let entries = backend.walk_dir(Path::new(source))?;
+    install_entries_to_dest(&entries, dest, force, backend)
+}
+
+/// Install already-collected source entries into the given destination directory.
+fn install_entries_to_dest(
+    entries: &[DirEntry],
+    dest: &str,
+    force: bool,
+    backend: &dyn SkillsBackend,
 ) -> Result<(Vec<String>, Vec<String>), String> {
     let mut created = Vec::new();
     let mut skipped = Vec::new();
 
-    let entries = backend.walk_dir(Path::new(source))?;
-    for (rel_path, is_dir, contents) in &entries {
+    for (rel_path, is_dir, contents) in entries {
         let target = format!("{dest}/{rel_path}");
         let target_path = Path::new(&target);
 
@@ -560,11 +687,10 @@ fn install_source_to_dest(
     Ok((created, skipped))
 }
 
-/// Install builtin skills to harness-specific destinations.
-fn install_builtin_to_harnesses(
+/// Group profiles by harness destination, one empty result per destination.
+fn harness_destinations(
     base_dir: &str,
     profiles: &[SkillsProfile],
-    force: bool,
     backend: &dyn SkillsBackend,
 ) -> Result<Vec<InstallResult>, String> {
     let home = backend.home_dir()?;
@@ -586,8 +712,18 @@ fn install_builtin_to_harnesses(
         }
     }
 
+    Ok(destinations.into_values().collect())
+}
+
+/// Install builtin skills to harness-specific destinations.
+fn install_builtin_to_harnesses(
+    base_dir: &str,
+    profiles: &[SkillsProfile],
+    force: bool,
+    backend: &dyn SkillsBackend,
+) -> Result<Vec<InstallResult>, String> {
     let mut results = Vec::new();
-    for (_, mut item) in destinations {
+    for mut item in harness_destinations(base_dir, profiles, backend)? {
         let (created, skipped) = install_builtin_to_dest(&item.dest, force, backend)?;
         item.created = created;
         item.skipped = skipped;
@@ -605,27 +741,8 @@ fn install_to_harnesses(
     force: bool,
     backend: &dyn SkillsBackend,
 ) -> Result<Vec<InstallResult>, String> {
-    let home = backend.home_dir()?;
-    let mut destinations: BTreeMap<String, InstallResult> = BTreeMap::new();
-
-    for profile in profiles {
-        if let Some(dest) =
-            resolve_harness_dest(base_dir, &profile.harness, &profile.auth_home, &home)
-        {
-            let entry = destinations
-                .entry(dest.clone())
-                .or_insert_with(|| InstallResult {
-                    dest,
-                    harnesses: Vec::new(),
-                    created: Vec::new(),
-                    skipped: Vec::new(),
-                });
-            entry.harnesses.push(profile.harness.clone());
-        }
-    }
-
     let mut results = Vec::new();
-    for (_, mut item) in destinations {
+    for mut item in harness_destinations(base_dir, profiles, backend)? {
         let (created, skipped) = install_source_to_dest(source_dir, &item.dest, force, backend)?;
         item.created = created;
         item.skipped = skipped;
@@ -635,6 +752,207 @@ fn install_to_harnesses(
     Ok(results)
 }
 
+// ---------------------------------------------------------------------------
+// Skill catalog and requirement resolution
+// ---------------------------------------------------------------------------
+
+/// Resolve where skills come from: an explicit `--path`, the repo's
+/// `.agent-skills` directory, or the embedded builtins.
+fn resolve_skills_source(path_arg: &str, repo_str: &str, backend: &dyn SkillsBackend) -> String {
+    let source_raw = path_arg.trim();
+    if !source_raw.is_empty() {
+        if Path::new(source_raw).is_absolute() {
+            return source_raw.to_string();
+        }
+        return format!("{repo_str}/{source_raw}");
+    }
+    let repo_skills = format!("{repo_str}/.agent-skills");
+    if backend.is_dir(Path::new(&repo_skills)) {
+        return repo_skills;
+    }
+    "builtin".to_string()
+}
+
+fn skills_source_entries(
+    source: &str,
+    backend: &dyn SkillsBackend,
+) -> Result<Vec<DirEntry>, String> {
+    if source == "builtin" {
+        return Ok(builtin_skill_files()
+            .into_iter()
+            .map(|(rel_path, data)| (rel_path.to_string(), false, Some(data.to_vec())))
+            .collect());
+    }
+    backend.walk_dir(Path::new(source))
+}
+
+fn parse_skill_frontmatter(raw: &str) -> Result<SkillFrontmatter, String> {
+    let Some(rest) = raw.strip_prefix("---") else {
+        return Ok(SkillFrontmatter::default());
+    };
+    let Some(end) = rest.find("\n---") else {
+        return Ok(SkillFrontmatter::default());
+    };
+    serde_yaml::from_str(&rest[..end]).map_err(|err| err.to_string())
+}
+
+/// Read every `<dir>/SKILL.md` in `entries`, sorted by skill name.
+fn load_skill_catalog(entries: &[DirEntry]) -> Result<Vec<SkillDef>, String> {
+    let mut skills: Vec<SkillDef> = Vec::new();
+    for (rel_path, is_dir, contents) in entries {
+        if *is_dir {
+            continue;
+        }
+        let Some((dir, "SKILL.md")) = rel_path.split_once('/') else {
+            continue;
+        };
+        let raw = String::from_utf8_lossy(contents.as_deref().unwrap_or_default());
+        let meta = parse_skill_frontmatter(&raw)
+            .map_err(|err| format!("parse skill {rel_path}: {err}"))?;
+        let name = match meta.name.trim() {
+            "" => dir.to_string(),
+            name => name.to_string(),
+        };
+        if skills.iter().any(|skill| skill.name == name) {
+            return Err(format!("duplicate skill {name:?}"));
+        }
+        skills.push(SkillDef {
+            name,
+            description: meta.description.trim().to_string(),
+            requires: meta
+                .requires
+                .iter()
+                .map(|req| req.trim().to_string())
+                .filter(|req| !req.is_empty())
+                .collect(),
+            dir: dir.to_string(),
+        });
+    }
+    skills.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(skills)
+}
+
+/// Expand `requested` with their transitive requirements, requirements first.
+fn resolve_skill_requirements(
+    catalog: &[SkillDef],
+    requested: &[String],
+) -> Result<Vec<String>, String> {
+    fn visit(
+        name: &str,
+        catalog: &BTreeMap<&str, &SkillDef>,
+        stack: &mut Vec<String>,
+        ordered: &mut Vec<String>,
+    ) -> Result<(), String> {
+        if ordered.iter().any(|done| done == name) {
+            return Ok(());
+        }
+        if let Some(start) = stack.iter().position(|entry| entry == name) {
+            let mut cycle = stack[start..].to_vec();
+            cycle.push(name.to_string());
+            return Err(format!(
+                "circular skill requirements: {}",
+                cycle.join(" -> ")
+            ));
+        }
+        let Some(skill) = catalog.get(name) else {
+            return Err(match stack.last() {
+                Some(parent) => format!("skill {parent:?} requires unknown skill {name:?}"),
+                None => format!("skill {name:?} not found"),
+            });
+        };
+        stack.push(name.to_string());
+        for requirement in &skill.requires {
+            visit(requirement, catalog, stack, ordered)?;
+        }
+        stack.pop();
+        ordered.push(name.to_string());
+        Ok(())
+    }
+
+    let lookup: BTreeMap<&str, &SkillDef> = catalog
+        .iter()
+        .map(|skill| (skill.name.as_str(), skill))
+        .collect();
+    let mut ordered = Vec::new();
+    for name in requested {
+        visit(name.trim(), &lookup, &mut Vec::new(), &mut ordered)?;
+    }
+    Ok(ordered)
+}
+
+/// Build the requirement forest: roots are skills no other skill requires.
+fn skill_tree(catalog: &[SkillDef]) -> Vec<SkillTreeNode> {
+    fn node(
+        name: &str,
+        lookup: &BTreeMap<&str, &SkillDef>,
+        path: &mut Vec<String>,
+    ) -> SkillTreeNode {
+        let mut out = SkillTreeNode {
+            name: name.to_string(),
+            requires: Vec::new(),
+            missing: false,
+            cycle: false,
+        };
+        if path.iter().any(|entry| entry == name) {
+            out.cycle = true;
+            return out;
+        }
+        let Some(skill) = lookup.get(name) else {
+            out.missing = true;
+            return out;
+        };
+        path.push(name.to_string());
+        out.requires = skill
+            .requires
+            .iter()
+            .map(|requirement| node(requirement, lookup, path))
+            .collect();
+        path.pop();
+        out
+    }
+
+    let lookup: BTreeMap<&str, &SkillDef> = catalog
+        .iter()
+        .map(|skill| (skill.name.as_str(), skill))
+        .collect();
+    let required: BTreeSet<&str> = catalog
+        .iter()
+        .flat_map(|skill| skill.requires.iter().map(String::as_str))
+        .collect();
+    let mut roots: Vec<&str> = catalog
+        .iter()
+        .map(|skill| skill.name.as_str())
+        .filter(|name| !required.contains(name))
+        .collect();
+    if roots.is_empty() {
+        // Every skill is required by another one, so all of them sit on cycles.
+        roots = catalog.iter().map(|skill| skill.name.as_str()).collect();
+    }
+    roots
+        .into_iter()
+        .map(|name| node(name, &lookup, &mut Vec::new()))
+        .collect()
+}
+
+fn write_skill_tree(
+    out: &mut dyn Write,
+    nodes: &[SkillTreeNode],
+    depth: usize,
+) -> Result<(), String> {
+    for item in nodes {
+        let mut line = format!("{}{}", "  ".repeat(depth), item.name);
+        if item.missing {
+            line.push_str(" (missing)");
+        }
+        if item.cycle {
+            line.push_str(" (cycle)");
+        }
+        writeln!(out, "{line}").map_err(|e| e.to_string())?;
+        write_skill_tree(out, &item.requires, depth + 1)?;
+    }
+    Ok(())
+}
+
 fn write_help(out: &mut dyn Write) -> Result<(), String> {
     writeln!(out, "Manage workspace skills").map_err(|e| e.to_string())?;
     writeln!(out).map_err(|e| e.to_string())?;
@@ -647,6 +965,47 @@ fn write_help(out: &mut dyn Write) -> Result<(), String> {
         "  bootstrap   Bootstrap repo skills and install to configured harnesses"
     )
     .map_err(|e| e.to_string())?;
+    writeln!(
+        out,
+        "  enable      Install skills together with their requirements"
+    )
+    .map_err(|e| e.to_string())?;
+    writeln!(out, "  list        List available skills").map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+fn write_list_help(out: &mut dyn Write) -> Result<(), String> {
+    writeln!(out, "List available skills and their requirements").map_err(|e| e.to_string())?;
+    writeln!(out).map_err(|e| e.to_string())?;
+    writeln!(out, "Usage:").map_err(|e| e.to_string())?;
+    writeln!(out, "  forge skills list [flags]").map_err(|e| e.to_string())?;
+    writeln!(out).map_err(|e| e.to_string())?;
+    writeln!(out, "Flags:").map_err(|e| e.to_string())?;
+    writeln!(out, "      --path string   skills source directory").map_err(|e| e.to_string())?;
+    writeln!(out, "      --tree          show the requirement hierarchy")
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+fn write_enable_help(out: &mut dyn Write) -> Result<(), String> {
+    writeln!(
+        out,
+        "Install skills and their transitive requirements, requirements first"
+    )
+    .map_err(|e| e.to_string())?;
+    writeln!(out).map_err(|e| e.to_string())?;
+    writeln!(out, "Usage:").map_err(|e| e.to_string())?;
+    writeln!(out, "  forge skills enable <skill>... [flags]").map_err(|e| e.to_string())?;
+    writeln!(out).map_err(|e| e.to_string())?;
+    writeln!(out, "Flags:").map_err(|e| e.to_string())?;
+    writeln!(
+        out,
+        "      --all-profiles  install for every configured profile"
+    )
+    .map_err(|e| e.to_string())?;
+    writeln!(out, "  -f, --force         overwrite existing skill files")
+        .map_err(|e| e.to_string())?;
+    writeln!(out, "      --path string   skills source directory").map_err(|e| e.to_string())?;
     Ok(())
 }
 
@@ -662,8 +1021,133 @@ fn execute(
             write_help(stdout)?;
             Ok(())
         }
+        SubCommand::ListHelp => write_list_help(stdout),
+        SubCommand::EnableHelp => write_enable_help(stdout),
         SubCommand::Bootstrap(bargs) => execute_bootstrap(&bargs, backend, stdout),
+        SubCommand::List(largs) => execute_list(&largs, backend, stdout),
+        SubCommand::Enable(eargs) => execute_enable(&eargs, backend, stdout),
+    }
+}
+
+fn execute_list(
+    args: &ListArgs,
+    backend: &dyn SkillsBackend,
+    stdout: &mut dyn Write,
+) -> Result<(), String> {
+    let repo_str = backend.resolve_working_dir()?.to_string_lossy().to_string();
+    let source = resolve_skills_source(&args.path, &repo_str, backend);
+    let catalog = load_skill_catalog(&skills_source_entries(&source, backend)?)?;
+
+    if args.json || args.jsonl {
+        let text = if args.tree {
+            serde_json::to_string(&skill_tree(&catalog))
+        } else {
+            serde_json::to_string(&catalog)
+        }
+        .map_err(|e| format!("failed to marshal output: {e}"))?;
+        writeln!(stdout, "{text}").map_err(|e| e.to_string())?;
+        return Ok(());
+    }
+
+    if catalog.is_empty() {
+        writeln!(stdout, "No skills found in {source}").map_err(|e| e.to_string())?;
+        return Ok(());
+    }
+    if args.tree {
+        return write_skill_tree(stdout, &skill_tree(&catalog), 0);
+    }
+
+    let width = catalog
+        .iter()
+        .map(|skill| skill.name.len())
+        .max()
+        .unwrap_or(0);
+    for skill in &catalog {
+        let mut line = format!("{:<width$}  {}", skill.name, skill.description);
+        if !skill.requires.is_empty() {
+            line.push_str(&format!(" (requires: {})", skill.requires.join(", ")));
+        }
+        writeln!(stdout, "{}", line.trim_end()).map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+fn execute_enable(
+    args: &EnableArgs,
+    backend: &dyn SkillsBackend,
+    stdout: &mut dyn Write,
+) -> Result<(), String> {
+    let repo_str = backend.resolve_working_dir()?.to_string_lossy().to_string();
+    let config = backend
+        .load_config()?
+        .ok_or_else(|| "config not loaded".to_string())?;
+    let profiles = select_profiles_for_skills(&config, args.all_profiles);
+    if profiles.is_empty() {
+        return Err("no profiles configured for skills install".to_string());
+    }
+
+    let source = resolve_skills_source(&args.path, &repo_str, backend);
+    let entries = skills_source_entries(&source, backend)?;
+    let catalog = load_skill_catalog(&entries)?;
+    let enabled = resolve_skill_requirements(&catalog, &args.skills)?;
+
+    // Install skill directories in requirement order.
+    let mut ordered_entries: Vec<DirEntry> = Vec::new();
+    for name in &enabled {
+        let Some(skill) = catalog.iter().find(|skill| &skill.name == name) else {
+            continue;
+        };
+        let prefix = format!("{}/", skill.dir);
+        ordered_entries.extend(
+            entries
+                .iter()
+                .filter(|(rel_path, _, _)| rel_path == &skill.dir || rel_path.starts_with(&prefix))
+                .cloned(),
+        );
+    }
+
+    let mut installed = Vec::new();
+    for mut item in harness_destinations(&repo_str, &profiles, backend)? {
+        let (created, skipped) =
+            install_entries_to_dest(&ordered_entries, &item.dest, args.force, backend)?;
+        item.created = created;
+        item.skipped = skipped;
+        installed.push(item);
+    }
+
+    let output = EnableOutput {
+        source,
+        enabled,
+        installed,
+    };
+    if args.json || args.jsonl {
+        let text =
+            serde_json::to_string(&output).map_err(|e| format!("failed to marshal output: {e}"))?;
+        writeln!(stdout, "{text}").map_err(|e| e.to_string())?;
+        return Ok(());
     }
+
+    writeln!(stdout, "Skills source: {}", output.source).map_err(|e| e.to_string())?;
+    writeln!(stdout, "Enabled: {}", output.enabled.join(", ")).map_err(|e| e.to_string())?;
+    writeln!(stdout, "Installed:").map_err(|e| e.to_string())?;
+    for item in &output.installed {
+        writeln!(
+            stdout,
+            "  - {} (harnesses: {})",
+            item.dest,
+            item.harnesses.join(", ")
+        )
+        .map_err(|e| e.to_string())?;
+        if !item.created.is_empty() {
+            writeln!(stdout, "    created: {} files", item.created.len())
+                .map_err(|e| e.to_string())?;
+        }
+        if !item.skipped.is_empty() {
+            writeln!(stdout, "    skipped: {} files", item.skipped.len())
+                .map_err(|e| e.to_string())?;
+        }
+    }
+    Ok(())
 }
 
 fn execute_bootstrap(
@@ -683,26 +1167,11 @@ fn execute_bootstrap(
         return Err("no profiles configured for skills install".to_string());
     }
 
-    let source_raw = args.path.trim().to_string();
-    let (source, installed) = if !source_raw.is_empty() {
-        let source = if Path::new(&source_raw).is_absolute() {
-            source_raw
-        } else {
-            format!("{}/{}", repo_str, source_raw)
-        };
-        let installed = install_to_harnesses(&repo_str, &source, &profiles, args.force, backend)?;
-        (source, installed)
+    let source = resolve_skills_source(&args.path, &repo_str, backend);
+    let installed = if source == "builtin" {
+        install_builtin_to_harnesses(&repo_str, &profiles, args.force, backend)?
     } else {
-        let repo_skills = format!("{repo_str}/.agent-skills");
-        if backend.is_dir(Path::new(&repo_skills)) {
-            let installed =
-                install_to_harnesses(&repo_str, &repo_skills, &profiles, args.force, backend)?;
-            (repo_skills, installed)
-        } else {
-            let installed =
-                install_builtin_to_harnesses(&repo_str, &profiles, args.force, backend)?;
-            ("builtin".to_string(), installed)
-        }
+        install_to_harnesses(&repo_str, &source, &profiles, args.force, backend)?
     };
 
     let output = BootstrapOutput { source, installed };
@@ -833,6 +1302,12 @@ mod tests {
         }
     }
 
+    fn parse_skills_config_yaml(raw: &str) -> Result<SkillsConfig, String> {
+        let value: serde_yaml::Value =
+            serde_yaml::from_str(raw).map_err(|err| format!("yaml decode error: {err}"))?;
+        parse_skills_config_value(value)
+    }
+
     #[test]
     fn parse_skills_config_yaml_reads_profiles_pools_and_default_pool() {
         let raw = r#"
@@ -1200,4 +1675,87 @@ profiles:
         assert_eq!(installed.len(), 1);
         assert_eq!(installed[0]["dest"], "/custom/auth/skills");
     }
+
+    // -- Requirements -------------------------------------------------------
+
+    fn skill_entry(dir: &str, requires: &[&str]) -> Vec<DirEntry> {
+        let mut skill_md = format!("---\nname: {dir}\ndescription: {dir} skill\n");
+        if !requires.is_empty() {
+            skill_md.push_str(&format!("requires: [{}]\n", requires.join(", ")));
+        }
+        skill_md.push_str("---\n\n# Skill\n");
+        vec![
+            (dir.to_string(), true, None),
+            (
+                format!("{dir}/SKILL.md"),
+                false,
+                Some(skill_md.into_bytes()),
+            ),
+        ]
+    }
+
+    fn layered_backend() -> InMemorySkillsBackend {
+        let mut entries = skill_entry("deploy", &["build"]);
+        entries.extend(skill_entry("build", &["checkout"]));
+        entries.extend(skill_entry("checkout", &[]));
+        entries.extend(skill_entry("unrelated", &[]));
+        test_backend().with_source_entries(entries)
+    }
+
+    #[test]
+    fn enable_pulls_in_transitive_requirements_in_order() {
+        let backend = layered_backend();
+        let out = run_for_test(
+            &["skills", "enable", "deploy", "--path", "/src", "--json"],
+            &backend,
+        );
+        assert_eq!(out.exit_code, 0, "stderr: {}", out.stderr);
+        let parsed = parse_json_or_panic(&out.stdout, "parse enable json");
+        assert_eq!(
+            parsed["enabled"],
+            serde_json::json!(["checkout", "build", "deploy"])
+        );
+
+        let written: Vec<String> = backend
+            .written_files()
+            .keys()
+            .map(|path| path.to_string_lossy().to_string())
+            .collect();
+        for skill in ["checkout", "build", "deploy"] {
+            let expected = format!("/repo/.claude/skills/{skill}/SKILL.md");
+            assert!(
+                written.contains(&expected),
+                "missing {expected}: {written:?}"
+            );
+        }
+        assert!(!written.iter().any(|path| path.contains("unrelated")));
+    }
+
+    #[test]
+    fn enable_rejects_missing_and_circular_requirements() {
+        let backend = test_backend().with_source_entries(skill_entry("deploy", &["build"]));
+        let out = run_for_test(&["skills", "enable", "deploy", "--path", "/src"], &backend);
+        assert_eq!(out.exit_code, 1);
+        assert!(out
+            .stderr
+            .contains("skill \"deploy\" requires unknown skill \"build\""));
+
+        let mut entries = skill_entry("a", &["b"]);
+        entries.extend(skill_entry("b", &["a"]));
+        let backend = test_backend().with_source_entries(entries);
+        let out = run_for_test(&["skills", "enable", "a", "--path", "/src"], &backend);
+        assert_eq!(out.exit_code, 1);
+        assert!(out
+            .stderr
+            .contains("circular skill requirements: a -> b -> a"));
+        assert!(backend.written_files().is_empty());
+    }
+
+    #[test]
+    fn list_tree_shows_requirement_hierarchy() {
+        let backend = layered_backend();
+        let out = run_for_test(&["skills", "list", "--tree", "--path", "/src"], &backend);
+        assert_eq!(out.exit_code, 0, "stderr: {}", out.stderr);
+        assert_eq!(out.stdout, "deploy\n  build\n    checkout\nunrelated\n");
+    }
 }
@@ -38,12 +38,7 @@ impl SpawnOptions {
     }
 
     fn resolved_daemon_target(&self) -> String {
-        let env_target = std::env::var("FORGE_DAEMON_TARGET").unwrap_or_default();
-        if let Some(target) = first_non_empty([self.daemon_target.as_str(), env_target.as_str()]) {
-            return normalize_daemon_target(target);
-        }
-
-        "http://127.0.0.1:50051".to_string()
+        crate::daemon_target::resolve_daemon_target(&self.daemon_target)
     }
 }
 
@@ -245,21 +240,6 @@ fn emit_spawn_owner_warning(options: &SpawnOptions, warning_writer: &mut dyn Wri
     );
 }
 
-fn first_non_empty<'a, I>(values: I) -> Option<&'a str>
-where
-    I: IntoIterator<Item = &'a str>,
-{
-    values.into_iter().find(|value| !value.trim().is_empty())
-}
-
-fn normalize_daemon_target(raw: &str) -> String {
-    let trimmed = raw.trim();
-    if trimmed.contains("://") {
-        return trimmed.to_string();
-    }
-    format!("http://{trimmed}")
-}
-
 fn skip_spawn_for_test_harness() -> bool {
     if let Ok(exe) = std::env::current_exe() {
         let path = exe.to_string_lossy();
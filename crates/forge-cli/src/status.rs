@@ -1,8 +1,13 @@
-use std::collections::{HashMap, HashSet};
+use std::cell::Cell;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::io::Write;
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Once;
+use std::time::{Duration, Instant};
 
 use chrono::{DateTime, SecondsFormat, Utc};
+use forge_db::metrics_repository::{MetricSample, MetricsRepository};
 use forge_loop::stale_runner::{self, DaemonRunner};
 use serde::Serialize;
 use serde_json::Value;
@@ -11,6 +16,9 @@ use tabwriter::TabWriter;
 use crate::ps::list_daemon_runners;
 
 const STATUS_ALERT_LIMIT: usize = 5;
+const DEFAULT_WATCH_INTERVAL: Duration = Duration::from_secs(2);
+/// Window over which each daemon gauge's peak is reported.
+const GAUGE_PEAK_WINDOW: Duration = Duration::from_secs(60 * 60);
 
 /// Alert severity levels matching Go's `models.AlertSeverity`.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -156,6 +164,18 @@ pub struct AlertSummary {
     pub items: Vec<Alert>,
 }
 
+/// Latest value of one daemon gauge series, from the `metrics` table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DaemonGauge {
+    pub name: String,
+    pub tags: BTreeMap<String, String>,
+    pub value: f64,
+    /// Highest value recorded over the last hour, including `value`.
+    pub peak_1h: f64,
+    /// When `value` was recorded (RFC3339).
+    pub timestamp: String,
+}
+
 /// Full status summary matching Go's `StatusSummary`.
 #[derive(Debug, Clone)]
 pub struct StatusSummary {
@@ -164,11 +184,53 @@ pub struct StatusSummary {
     pub workspaces: u64,
     pub agents: AgentSummary,
     pub alerts: AlertSummary,
+    pub gauges: Vec<DaemonGauge>,
 }
 
 /// Backend trait for fetching status data.
 pub trait StatusBackend {
     fn get_status(&self) -> Result<StatusSummary, String>;
+
+    /// Block until the next `--watch` refresh is due. Returns `false` once
+    /// watching should stop, e.g. after Ctrl-C.
+    fn wait_for_refresh(&self, interval: Duration) -> bool {
+        sleep_unless_interrupted(interval)
+    }
+}
+
+static INTERRUPTED: AtomicBool = AtomicBool::new(false);
+static INTERRUPT_LISTENER: Once = Once::new();
+
+/// Route SIGINT into [`INTERRUPTED`] so watch mode can finish its current
+/// frame and exit cleanly instead of being killed mid-write.
+fn listen_for_interrupt() {
+    INTERRUPT_LISTENER.call_once(|| {
+        std::thread::spawn(|| {
+            let runtime = match tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+            {
+                Ok(value) => value,
+                Err(_) => return,
+            };
+            if runtime.block_on(tokio::signal::ctrl_c()).is_ok() {
+                INTERRUPTED.store(true, Ordering::SeqCst);
+            }
+        });
+    });
+}
+
+fn sleep_unless_interrupted(interval: Duration) -> bool {
+    listen_for_interrupt();
+    let deadline = Instant::now() + interval;
+    while !INTERRUPTED.load(Ordering::SeqCst) {
+        let now = Instant::now();
+        if now >= deadline {
+            return true;
+        }
+        std::thread::sleep((deadline - now).min(Duration::from_millis(100)));
+    }
+    false
 }
 
 type DaemonLister = fn() -> (HashMap<String, DaemonRunner>, bool);
@@ -201,22 +263,40 @@ impl SqliteStatusBackend {
 }
 
 /// In-memory backend for testing.
+///
+/// With several summaries, each `--watch` refresh advances to the next one
+/// and watching stops after the last.
 #[derive(Debug, Clone, Default)]
 pub struct InMemoryStatusBackend {
-    summary: Option<StatusSummary>,
+    summaries: Vec<StatusSummary>,
+    current: Cell<usize>,
 }
 
 impl InMemoryStatusBackend {
     pub fn with_summary(summary: StatusSummary) -> Self {
+        Self::with_summaries(vec![summary])
+    }
+
+    pub fn with_summaries(summaries: Vec<StatusSummary>) -> Self {
         Self {
-            summary: Some(summary),
+            summaries,
+            current: Cell::new(0),
         }
     }
 }
 
 impl StatusBackend for InMemoryStatusBackend {
+    fn wait_for_refresh(&self, _interval: Duration) -> bool {
+        let next = self.current.get() + 1;
+        if next >= self.summaries.len() {
+            return false;
+        }
+        self.current.set(next);
+        true
+    }
+
     fn get_status(&self) -> Result<StatusSummary, String> {
-        match &self.summary {
+        match self.summaries.get(self.current.get()) {
             Some(summary) => Ok(summary.clone()),
             None => Ok(StatusSummary {
                 timestamp: "2026-01-01T00:00:00Z".to_string(),
@@ -224,6 +304,7 @@ impl StatusBackend for InMemoryStatusBackend {
                 workspaces: 0,
                 agents: AgentSummary::default(),
                 alerts: AlertSummary::default(),
+                gauges: Vec::new(),
             }),
         }
     }
@@ -376,6 +457,7 @@ impl StatusBackend for SqliteStatusBackend {
             .map(|state| (state.clone(), *state_counts.get(state).unwrap_or(&0)))
             .collect();
         let top_alerts = select_top_alerts(&alerts, STATUS_ALERT_LIMIT);
+        let gauges = load_daemon_gauges(&db, &now)?;
 
         Ok(StatusSummary {
             timestamp,
@@ -389,6 +471,7 @@ impl StatusBackend for SqliteStatusBackend {
                 total: alerts.len() as u64,
                 items: top_alerts,
             },
+            gauges,
         })
     }
 }
@@ -405,6 +488,8 @@ struct ParsedArgs {
     json: bool,
     jsonl: bool,
     quiet: bool,
+    watch: bool,
+    interval: Duration,
 }
 
 // --- JSON serialization types ---
@@ -416,6 +501,8 @@ struct StatusJson<'a> {
     workspaces: u64,
     agents: AgentSummaryJson,
     alerts: AlertSummaryJson<'a>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    gauges: Vec<DaemonGaugeJson<'a>>,
 }
 
 #[derive(Debug, Serialize)]
@@ -439,6 +526,16 @@ struct AlertSummaryJson<'a> {
     items: Vec<AlertJson<'a>>,
 }
 
+#[derive(Debug, Serialize)]
+struct DaemonGaugeJson<'a> {
+    name: &'a str,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    tags: &'a BTreeMap<String, String>,
+    value: f64,
+    peak_1h: f64,
+    timestamp: &'a str,
+}
+
 #[derive(Debug, Serialize)]
 struct AlertJson<'a> {
     #[serde(rename = "type")]
@@ -483,6 +580,9 @@ fn execute(
     stdout: &mut dyn Write,
 ) -> Result<(), String> {
     let parsed = parse_args(args)?;
+    if parsed.watch {
+        return watch(&parsed, backend, stdout);
+    }
     let summary = backend.get_status()?;
 
     if parsed.json || parsed.jsonl {
@@ -504,6 +604,71 @@ fn execute(
     write_human(&summary, stdout)
 }
 
+/// Re-render the summary every interval until the backend says to stop.
+fn watch(
+    parsed: &ParsedArgs,
+    backend: &dyn StatusBackend,
+    stdout: &mut dyn Write,
+) -> Result<(), String> {
+    let mut renderer = WatchRenderer::default();
+    loop {
+        let summary = backend.get_status()?;
+        if parsed.jsonl {
+            serde_json::to_writer(&mut *stdout, &build_json_summary(&summary))
+                .map_err(|err| err.to_string())?;
+            writeln!(stdout).map_err(|err| err.to_string())?;
+        } else {
+            let mut frame = Vec::new();
+            write_human(&summary, &mut frame)?;
+            renderer.draw(&String::from_utf8_lossy(&frame), stdout)?;
+        }
+        stdout.flush().map_err(|err| err.to_string())?;
+
+        if !backend.wait_for_refresh(parsed.interval) {
+            return Ok(());
+        }
+    }
+}
+
+/// Redraws only the lines that changed since the previous frame so the
+/// terminal does not flicker on every tick.
+#[derive(Debug, Default)]
+struct WatchRenderer {
+    previous: Option<Vec<String>>,
+}
+
+impl WatchRenderer {
+    fn draw(&mut self, frame: &str, out: &mut dyn Write) -> Result<(), String> {
+        let lines: Vec<String> = frame.lines().map(str::to_string).collect();
+        let mut buf = String::new();
+        match &self.previous {
+            None => {
+                buf.push_str("\x1b[2J\x1b[H");
+                for line in &lines {
+                    buf.push_str(line);
+                    buf.push('\n');
+                }
+            }
+            Some(previous) if *previous == lines => return Ok(()),
+            Some(previous) => {
+                for (row, line) in lines.iter().enumerate() {
+                    if previous.get(row) != Some(line) {
+                        buf.push_str(&format!("\x1b[{};1H{line}\x1b[K", row + 1));
+                    }
+                }
+                buf.push_str(&format!("\x1b[{};1H", lines.len() + 1));
+                if lines.len() < previous.len() {
+                    buf.push_str("\x1b[J");
+                }
+            }
+        }
+        out.write_all(buf.as_bytes())
+            .map_err(|err| err.to_string())?;
+        self.previous = Some(lines);
+        Ok(())
+    }
+}
+
 fn build_json_summary(summary: &StatusSummary) -> StatusJson<'_> {
     let mut by_state = serde_json::Map::new();
     for (state, count) in &summary.agents.by_state {
@@ -541,6 +706,17 @@ fn build_json_summary(summary: &StatusSummary) -> StatusJson<'_> {
                 })
                 .collect(),
         },
+        gauges: summary
+            .gauges
+            .iter()
+            .map(|gauge| DaemonGaugeJson {
+                name: &gauge.name,
+                tags: &gauge.tags,
+                value: gauge.value,
+                peak_1h: gauge.peak_1h,
+                timestamp: &gauge.timestamp,
+            })
+            .collect(),
     }
 }
 
@@ -576,9 +752,36 @@ fn write_human(summary: &StatusSummary, stdout: &mut dyn Write) -> Result<(), St
         }
     }
 
+    if !summary.gauges.is_empty() {
+        writeln!(stdout, "Daemon gauges:").map_err(|err| err.to_string())?;
+        for gauge in &summary.gauges {
+            writeln!(
+                stdout,
+                "- {} {} (1h peak {}, at {})",
+                format_gauge_series(gauge),
+                gauge.value,
+                gauge.peak_1h,
+                gauge.timestamp
+            )
+            .map_err(|err| err.to_string())?;
+        }
+    }
+
     Ok(())
 }
 
+fn format_gauge_series(gauge: &DaemonGauge) -> String {
+    if gauge.tags.is_empty() {
+        return gauge.name.clone();
+    }
+    let tags: Vec<String> = gauge
+        .tags
+        .iter()
+        .map(|(key, value)| format!("{key}={value}"))
+        .collect();
+    format!("{}{{{}}}", gauge.name, tags.join(","))
+}
+
 fn format_agent_state_counts(by_state: &[(AgentState, u64)]) -> String {
     let parts: Vec<String> = by_state
         .iter()
@@ -598,9 +801,50 @@ fn empty_summary(timestamp: String) -> StatusSummary {
         workspaces: 0,
         agents: AgentSummary::default(),
         alerts: AlertSummary::default(),
+        gauges: Vec::new(),
     }
 }
 
+/// Latest sample of each daemon gauge series with its peak over the last
+/// [`GAUGE_PEAK_WINDOW`]. Databases from before the metrics migration have
+/// no gauges.
+fn load_daemon_gauges(db: &forge_db::Db, now: &DateTime<Utc>) -> Result<Vec<DaemonGauge>, String> {
+    let repo = MetricsRepository::new(db);
+    let latest = match repo.latest_per_series() {
+        Ok(latest) => latest,
+        Err(err) if err.to_string().contains("no such table: metrics") => return Ok(Vec::new()),
+        Err(err) => return Err(format!("list daemon metrics: {err}")),
+    };
+    let window = chrono::Duration::from_std(GAUGE_PEAK_WINDOW).unwrap_or_default();
+    let since = (*now - window).to_rfc3339_opts(SecondsFormat::Secs, true);
+
+    let mut recent: HashMap<String, Vec<MetricSample>> = HashMap::new();
+    let mut gauges = Vec::with_capacity(latest.len());
+    for sample in latest {
+        if !recent.contains_key(&sample.name) {
+            let samples = repo
+                .query_range(&sample.name, &since, "")
+                .map_err(|err| format!("query metric {}: {err}", sample.name))?;
+            recent.insert(sample.name.clone(), samples);
+        }
+        let peak_1h = recent
+            .get(&sample.name)
+            .into_iter()
+            .flatten()
+            .filter(|recorded| recorded.tags == sample.tags)
+            .map(|recorded| recorded.value)
+            .fold(sample.value, f64::max);
+        gauges.push(DaemonGauge {
+            name: sample.name,
+            tags: sample.tags,
+            value: sample.value,
+            peak_1h,
+            timestamp: sample.timestamp,
+        });
+    }
+    Ok(gauges)
+}
+
 fn load_profile_cooldowns(
     profile_repo: &forge_db::profile_repository::ProfileRepository<'_>,
 ) -> Result<HashMap<String, String>, String> {
@@ -657,6 +901,7 @@ fn classify_agent_state(
     match loop_state {
         forge_db::loop_repository::LoopState::Error => AgentState::Error,
         forge_db::loop_repository::LoopState::Stopped => AgentState::Stopped,
+        forge_db::loop_repository::LoopState::Paused => AgentState::Paused,
         forge_db::loop_repository::LoopState::Waiting => {
             if cooldown_active {
                 AgentState::RateLimited
@@ -819,6 +1064,8 @@ fn parse_args(args: &[String]) -> Result<ParsedArgs, String> {
     let mut json = false;
     let mut jsonl = false;
     let mut quiet = false;
+    let mut watch = false;
+    let mut interval: Option<Duration> = None;
 
     while let Some(token) = args.get(index) {
         match token.as_str() {
@@ -837,6 +1084,17 @@ fn parse_args(args: &[String]) -> Result<ParsedArgs, String> {
                 quiet = true;
                 index += 1;
             }
+            "--watch" => {
+                watch = true;
+                index += 1;
+            }
+            "--interval" => {
+                let raw = args
+                    .get(index + 1)
+                    .ok_or_else(|| "error: missing value for --interval".to_string())?;
+                interval = Some(parse_interval(raw)?);
+                index += 2;
+            }
             flag if flag.starts_with('-') => {
                 return Err(format!("error: unknown argument for status: '{flag}'"));
             }
@@ -851,8 +1109,46 @@ fn parse_args(args: &[String]) -> Result<ParsedArgs, String> {
     if json && jsonl {
         return Err("error: --json and --jsonl cannot be used together".to_string());
     }
+    if interval.is_some() && !watch {
+        return Err("error: --interval requires --watch".to_string());
+    }
+    if watch && json {
+        return Err("error: --watch streams one summary per interval; use --jsonl".to_string());
+    }
+    if watch && quiet {
+        return Err("error: --watch cannot be used with --quiet".to_string());
+    }
 
-    Ok(ParsedArgs { json, jsonl, quiet })
+    Ok(ParsedArgs {
+        json,
+        jsonl,
+        quiet,
+        watch,
+        interval: interval.unwrap_or(DEFAULT_WATCH_INTERVAL),
+    })
+}
+
+/// Parse a refresh interval such as `500ms`, `5s`, or `1m`.
+fn parse_interval(raw: &str) -> Result<Duration, String> {
+    let trimmed = raw.trim();
+    let invalid = || format!("error: invalid value for --interval: '{trimmed}'");
+    let split = trimmed
+        .find(|ch: char| !ch.is_ascii_digit())
+        .ok_or_else(invalid)?;
+    let (number, unit) = trimmed.split_at(split);
+    let value: u64 = number.parse().map_err(|_| invalid())?;
+    let interval = match unit {
+        "ms" => Duration::from_millis(value),
+        "s" => Duration::from_secs(value),
+        "m" => Duration::from_secs(value.saturating_mul(60)),
+        _ => return Err(invalid()),
+    };
+    if interval.is_zero() {
+        return Err(format!(
+            "error: invalid value for --interval: '{trimmed}' (must be > 0)"
+        ));
+    }
+    Ok(interval)
 }
 
 const HELP_TEXT: &str = "\
@@ -862,7 +1158,9 @@ Usage:
   forge status [flags]
 
 Flags:
-  -h, --help    help for status";
+  -h, --help              help for status
+      --interval string   refresh interval for --watch (default 2s)
+      --watch             re-render the summary until interrupted (Ctrl-C)";
 
 #[cfg(test)]
 #[allow(clippy::unwrap_used, clippy::expect_used)]
@@ -939,6 +1237,7 @@ mod tests {
                     },
                 ],
             },
+            gauges: Vec::new(),
         }
     }
 
@@ -949,6 +1248,7 @@ mod tests {
             workspaces: 0,
             agents: AgentSummary::default(),
             alerts: AlertSummary::default(),
+            gauges: Vec::new(),
         }
     }
 
@@ -1141,6 +1441,96 @@ mod tests {
         assert!(out.stderr.contains("forge status"));
     }
 
+    // --- watch tests ---
+
+    fn advanced_summary() -> StatusSummary {
+        let mut summary = sample_summary();
+        summary.timestamp = "2026-01-15T12:00:02Z".to_string();
+        summary.agents.total = 43;
+        summary
+    }
+
+    #[test]
+    fn parse_watch_interval() {
+        let args: Vec<String> = ["status", "--watch", "--interval", "500ms"]
+            .iter()
+            .map(|arg| arg.to_string())
+            .collect();
+        let parsed = parse_ok(&args);
+        assert!(parsed.watch);
+        assert_eq!(parsed.interval, Duration::from_millis(500));
+
+        let args = vec![
+            "status".to_string(),
+            "--interval".to_string(),
+            "5s".to_string(),
+        ];
+        assert!(parse_err(&args).contains("--interval requires --watch"));
+
+        let args: Vec<String> = ["status", "--watch", "--interval", "0s"]
+            .iter()
+            .map(|arg| arg.to_string())
+            .collect();
+        assert!(parse_err(&args).contains("must be > 0"));
+    }
+
+    #[test]
+    fn watch_renders_each_interval_and_redraws_changed_lines() {
+        let backend =
+            InMemoryStatusBackend::with_summaries(vec![sample_summary(), advanced_summary()]);
+        let out = run_for_test(&["status", "--watch", "--interval", "1s"], &backend);
+        assert_eq!(out.exit_code, 0, "stderr: {}", out.stderr);
+
+        let (first, second) = out
+            .stdout
+            .split_once("\x1b[1;1H")
+            .expect("second frame redraws from the first row");
+        assert!(first.starts_with("\x1b[2J\x1b[H"));
+        assert!(first.contains("2026-01-15T12:00:00Z"));
+        assert!(first.contains("42"));
+        assert!(second.contains("2026-01-15T12:00:02Z"));
+        assert!(second.contains("43"));
+        assert_ne!(first, second);
+        // Unchanged lines are not rewritten.
+        assert!(!second.contains("Workspaces:"));
+    }
+
+    #[test]
+    fn watch_skips_identical_frames() {
+        let backend =
+            InMemoryStatusBackend::with_summaries(vec![sample_summary(), sample_summary()]);
+        let once = run_for_test(
+            &["status"],
+            &InMemoryStatusBackend::with_summary(sample_summary()),
+        );
+        let out = run_for_test(&["status", "--watch"], &backend);
+        assert_eq!(out.exit_code, 0, "stderr: {}", out.stderr);
+        assert_eq!(out.stdout, format!("\x1b[2J\x1b[H{}", once.stdout));
+    }
+
+    #[test]
+    fn watch_jsonl_emits_one_summary_per_interval() {
+        let backend =
+            InMemoryStatusBackend::with_summaries(vec![sample_summary(), advanced_summary()]);
+        let out = run_for_test(&["status", "--watch", "--jsonl"], &backend);
+        assert_eq!(out.exit_code, 0, "stderr: {}", out.stderr);
+
+        let lines: Vec<&str> = out.stdout.lines().collect();
+        assert_eq!(lines.len(), 2);
+        let first = parse_json(lines[0]);
+        let second = parse_json(lines[1]);
+        assert_eq!(first["agents"]["total"], 42);
+        assert_eq!(second["agents"]["total"], 43);
+    }
+
+    #[test]
+    fn watch_rejects_json() {
+        let backend = InMemoryStatusBackend::default();
+        let out = run_for_test(&["status", "--watch", "--json"], &backend);
+        assert_eq!(out.exit_code, 1);
+        assert!(out.stderr.contains("use --jsonl"));
+    }
+
     // --- select_top_alerts tests ---
 
     #[test]
@@ -1300,6 +1690,7 @@ mod tests {
                     created_at: "2026-02-01T09:58:00Z".to_string(),
                 }],
             },
+            gauges: Vec::new(),
         });
         let out = run_for_test(&["status", "--json"], &backend);
         assert_eq!(out.exit_code, 0);
@@ -1350,6 +1741,7 @@ mod tests {
                     created_at: "2026-01-01T00:00:00Z".to_string(),
                 }],
             },
+            gauges: Vec::new(),
         });
         let out = run_for_test(&["status", "--json"], &backend);
         assert_eq!(out.exit_code, 0);
@@ -1381,6 +1773,7 @@ mod tests {
                     created_at: "2026-01-01T00:00:00Z".to_string(),
                 }],
             },
+            gauges: Vec::new(),
         });
         let out = run_for_test(&["status"], &backend);
         assert_eq!(out.exit_code, 0);
@@ -1565,6 +1958,71 @@ mod tests {
         let _ = std::fs::remove_file(db_path);
     }
 
+    #[test]
+    fn status_sqlite_backend_reports_latest_daemon_gauges_with_hourly_peak() {
+        let db_path = temp_db_path("sqlite-gauges");
+        let mut db = forge_db::Db::open(forge_db::Config::new(&db_path))
+            .unwrap_or_else(|err| panic!("open db: {err}"));
+        db.migrate_up()
+            .unwrap_or_else(|err| panic!("migrate db: {err}"));
+
+        let now = Utc::now();
+        let ago = |minutes: i64| {
+            (now - chrono::Duration::minutes(minutes)).to_rfc3339_opts(SecondsFormat::Secs, true)
+        };
+        let repo = MetricsRepository::new(&db);
+        for (name, state, value, minutes_ago) in [
+            ("daemon_in_flight_ops", None, 9.0, 120),
+            ("daemon_in_flight_ops", None, 5.0, 20),
+            ("daemon_in_flight_ops", None, 1.0, 1),
+            ("daemon_loop_runners", Some("running"), 3.0, 1),
+            ("daemon_loop_runners", Some("error"), 0.0, 1),
+        ] {
+            let mut sample = MetricSample {
+                name: name.to_string(),
+                value,
+                tags: state
+                    .map(|state| BTreeMap::from([("state".to_string(), state.to_string())]))
+                    .unwrap_or_default(),
+                timestamp: ago(minutes_ago),
+            };
+            repo.record(&mut sample)
+                .unwrap_or_else(|err| panic!("record {name}: {err}"));
+        }
+
+        fn no_daemon() -> (HashMap<String, DaemonRunner>, bool) {
+            (HashMap::new(), false)
+        }
+        let backend = SqliteStatusBackend::new(db_path.clone()).with_daemon_lister(no_daemon);
+        let summary = backend
+            .get_status()
+            .unwrap_or_else(|err| panic!("get status summary: {err}"));
+
+        let series: Vec<(String, f64, f64)> = summary
+            .gauges
+            .iter()
+            .map(|gauge| (format_gauge_series(gauge), gauge.value, gauge.peak_1h))
+            .collect();
+        assert_eq!(
+            series,
+            vec![
+                ("daemon_in_flight_ops".to_string(), 1.0, 5.0),
+                ("daemon_loop_runners{state=error}".to_string(), 0.0, 0.0),
+                ("daemon_loop_runners{state=running}".to_string(), 3.0, 3.0),
+            ]
+        );
+
+        let mut human = Vec::new();
+        write_human(&summary, &mut human).unwrap_or_else(|err| panic!("write human: {err}"));
+        let human = String::from_utf8_lossy(&human);
+        assert!(human.contains(&format!(
+            "Daemon gauges:\n- daemon_in_flight_ops 1 (1h peak 5, at {})\n",
+            ago(1)
+        )));
+
+        let _ = std::fs::remove_file(db_path);
+    }
+
     #[test]
     fn status_sqlite_backend_missing_db_returns_empty_summary() {
         let db_path = temp_db_path("sqlite-missing");
@@ -3,12 +3,16 @@ use std::io::Write;
 use std::path::PathBuf;
 use std::time::Duration;
 
+use forge_core::event::{EntityType, EventType};
 use forge_rpc::forged::v1 as proto;
 use forge_rpc::forged::v1::forged_service_client::ForgedServiceClient;
 use serde::Serialize;
 use serde_json::Value;
 use tonic::{transport::Endpoint, Code};
 
+/// Stop cause recorded when `--reason` is omitted or blank.
+pub const DEFAULT_STOP_REASON: &str = "operator";
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CommandOutput {
     pub stdout: String,
@@ -62,7 +66,7 @@ pub trait StopBackend {
     fn list_loops(&self) -> Result<Vec<LoopRecord>, String>;
     fn runner_owner(&self, loop_id: &str) -> Result<String, String>;
     fn stop_daemon_runner(&mut self, loop_id: &str) -> Result<(), String>;
-    fn enqueue_stop(&mut self, loop_id: &str) -> Result<(), String>;
+    fn enqueue_stop(&mut self, loop_id: &str, reason: &str) -> Result<(), String>;
 }
 
 #[derive(Debug, Clone, Default)]
@@ -72,6 +76,7 @@ pub struct InMemoryStopBackend {
     pub daemon_stop_error: Option<String>,
     pub daemon_stopped: Vec<String>,
     pub enqueued: Vec<String>,
+    pub stop_reasons: HashMap<String, String>,
 }
 
 impl InMemoryStopBackend {
@@ -86,6 +91,7 @@ impl InMemoryStopBackend {
             daemon_stop_error: None,
             daemon_stopped: Vec::new(),
             enqueued: Vec::new(),
+            stop_reasons: HashMap::new(),
         }
     }
 
@@ -113,11 +119,13 @@ impl StopBackend for InMemoryStopBackend {
         Ok(())
     }
 
-    fn enqueue_stop(&mut self, loop_id: &str) -> Result<(), String> {
+    fn enqueue_stop(&mut self, loop_id: &str, reason: &str) -> Result<(), String> {
         if !self.loops.iter().any(|entry| entry.id == loop_id) {
             return Err(format!("loop {loop_id} not found"));
         }
         self.enqueued.push(loop_id.to_string());
+        self.stop_reasons
+            .insert(loop_id.to_string(), reason.to_string());
         Ok(())
     }
 }
@@ -195,22 +203,39 @@ impl StopBackend for SqliteStopBackend {
         stop_daemon_loop_runner(loop_id)
     }
 
-    fn enqueue_stop(&mut self, loop_id: &str) -> Result<(), String> {
+    fn enqueue_stop(&mut self, loop_id: &str, reason: &str) -> Result<(), String> {
         let db = self.open_db()?;
-
-        let queue_repo = forge_db::loop_queue_repository::LoopQueueRepository::new(&db);
-
-        let mut items = vec![forge_db::loop_queue_repository::LoopQueueItem {
-            item_type: "stop_graceful".to_string(),
-            payload: r#"{"reason":"operator"}"#.to_string(),
-            ..Default::default()
-        }];
-
-        queue_repo
-            .enqueue(loop_id, &mut items)
-            .map_err(|err| format!("enqueue stop for {loop_id}: {err}"))?;
-
-        Ok(())
+        let payload = serde_json::json!({ "reason": reason }).to_string();
+
+        // The stop item and its audit event land together or not at all.
+        db.immediate_transaction(|db| {
+            let mut items = vec![forge_db::loop_queue_repository::LoopQueueItem {
+                item_type: "stop_graceful".to_string(),
+                payload: payload.clone(),
+                ..Default::default()
+            }];
+            forge_db::loop_queue_repository::LoopQueueRepository::new(db)
+                .enqueue(loop_id, &mut items)
+                .map_err(|err| {
+                    forge_db::DbError::Transaction(format!("enqueue stop for {loop_id}: {err}"))
+                })?;
+
+            let mut event = forge_db::event_repository::Event {
+                event_type: EventType::LoopStopRequested.to_string(),
+                entity_type: EntityType::Loop.to_string(),
+                entity_id: loop_id.to_string(),
+                payload: payload.clone(),
+                ..Default::default()
+            };
+            forge_db::event_repository::EventRepository::new(db)
+                .append(&mut event)
+                .map_err(|err| {
+                    forge_db::DbError::Transaction(format!(
+                        "record stop reason for {loop_id}: {err}"
+                    ))
+                })
+        })
+        .map_err(|err| err.to_string())
     }
 }
 
@@ -222,7 +247,8 @@ fn map_loop_state(state: &forge_db::loop_repository::LoopState) -> LoopState {
     match state {
         forge_db::loop_repository::LoopState::Running => LoopState::Running,
         forge_db::loop_repository::LoopState::Sleeping
-        | forge_db::loop_repository::LoopState::Waiting => LoopState::Running,
+        | forge_db::loop_repository::LoopState::Waiting
+        | forge_db::loop_repository::LoopState::Paused => LoopState::Running,
         forge_db::loop_repository::LoopState::Stopped => LoopState::Stopped,
         forge_db::loop_repository::LoopState::Error => LoopState::Error,
     }
@@ -233,6 +259,7 @@ struct ParsedArgs {
     json: bool,
     jsonl: bool,
     quiet: bool,
+    reason: String,
     selector: LoopSelector,
 }
 
@@ -291,7 +318,7 @@ fn execute(
         if should_stop_daemon_runner(entry, &runner_owner) {
             backend.stop_daemon_runner(&entry.id)?;
         }
-        backend.enqueue_stop(&entry.id)?;
+        backend.enqueue_stop(&entry.id, &parsed.reason)?;
     }
 
     if parsed.json || parsed.jsonl {
@@ -432,7 +459,7 @@ fn stop_daemon_loop_runner(loop_id: &str) -> Result<(), String> {
 }
 
 async fn stop_daemon_loop_runner_async(loop_id: &str) -> Result<(), String> {
-    let target = resolved_daemon_target();
+    let target = crate::daemon_target::resolve_daemon_target("");
     let endpoint = Endpoint::from_shared(target.clone())
         .map_err(|err| format!("forged daemon unavailable: {err}"))?
         .connect_timeout(Duration::from_secs(2))
@@ -455,22 +482,6 @@ async fn stop_daemon_loop_runner_async(loop_id: &str) -> Result<(), String> {
     }
 }
 
-fn resolved_daemon_target() -> String {
-    let env_target = std::env::var("FORGE_DAEMON_TARGET").unwrap_or_default();
-    if !env_target.trim().is_empty() {
-        return normalize_daemon_target(&env_target);
-    }
-    "http://127.0.0.1:50051".to_string()
-}
-
-fn normalize_daemon_target(raw: &str) -> String {
-    let trimmed = raw.trim();
-    if trimmed.contains("://") {
-        return trimmed.to_string();
-    }
-    format!("http://{trimmed}")
-}
-
 fn format_loop_match(entry: &LoopRecord) -> String {
     format!("{} ({})", entry.name, short_id(entry))
 }
@@ -484,6 +495,7 @@ fn parse_args(args: &[String]) -> Result<ParsedArgs, String> {
     let mut json = false;
     let mut jsonl = false;
     let mut quiet = false;
+    let mut reason = String::new();
     let mut selector = LoopSelector::default();
 
     while let Some(token) = args.get(index) {
@@ -527,6 +539,10 @@ fn parse_args(args: &[String]) -> Result<ParsedArgs, String> {
                 selector.tag = take_value(args, index, "--tag")?;
                 index += 2;
             }
+            "--reason" => {
+                reason = take_value(args, index, "--reason")?;
+                index += 2;
+            }
             flag if flag.starts_with('-') => {
                 return Err(format!("error: unknown argument for stop: '{flag}'"));
             }
@@ -559,10 +575,16 @@ fn parse_args(args: &[String]) -> Result<ParsedArgs, String> {
         return Err("specify a loop or selector".to_string());
     }
 
+    let reason = match reason.trim() {
+        "" => DEFAULT_STOP_REASON.to_string(),
+        trimmed => trimmed.to_string(),
+    };
+
     Ok(ParsedArgs {
         json,
         jsonl,
         quiet,
+        reason,
         selector,
     })
 }
@@ -584,6 +606,7 @@ Flags:
   -h, --help             help for stop
       --pool string      filter by pool
       --profile string   filter by profile
+      --reason string    why the loop is stopped (recorded in the audit log)
       --repo string      filter by repo path
       --state string     filter by state
       --tag string       filter by tag";
@@ -593,7 +616,9 @@ Flags:
 mod tests {
     use super::{
         parse_args, run_for_test, InMemoryStopBackend, LoopRecord, LoopState, SqliteStopBackend,
+        DEFAULT_STOP_REASON,
     };
+    use forge_core::event::EventType;
 
     #[test]
     fn parse_requires_selector_or_loop() {
@@ -865,6 +890,50 @@ mod tests {
             .contains("loop 'abc' is ambiguous; matches: alpha (abc001), beta (abc002)"));
     }
 
+    #[test]
+    fn stop_reason_defaults_to_generic_cause() {
+        let loops = vec![
+            LoopRecord {
+                id: "loop-001".to_string(),
+                short_id: "abc01".to_string(),
+                name: "alpha".to_string(),
+                repo: "/repo".to_string(),
+                pool: "default".to_string(),
+                profile: "codex".to_string(),
+                state: LoopState::Running,
+                tags: vec![],
+            },
+            LoopRecord {
+                id: "loop-002".to_string(),
+                short_id: "abc02".to_string(),
+                name: "beta".to_string(),
+                repo: "/repo".to_string(),
+                pool: "default".to_string(),
+                profile: "codex".to_string(),
+                state: LoopState::Running,
+                tags: vec![],
+            },
+        ];
+        let mut backend = InMemoryStopBackend::with_loops(loops);
+
+        let out = run_for_test(
+            &["stop", "alpha", "--reason", "  deploy freeze  "],
+            &mut backend,
+        );
+        assert_eq!(out.exit_code, 0, "stderr: {}", out.stderr);
+        let out = run_for_test(&["stop", "beta", "--reason", " "], &mut backend);
+        assert_eq!(out.exit_code, 0, "stderr: {}", out.stderr);
+
+        assert_eq!(
+            backend.stop_reasons.get("loop-001").map(String::as_str),
+            Some("deploy freeze")
+        );
+        assert_eq!(
+            backend.stop_reasons.get("loop-002").map(String::as_str),
+            Some(DEFAULT_STOP_REASON)
+        );
+    }
+
     // -----------------------------------------------------------------------
     // SQLite integration tests
     // -----------------------------------------------------------------------
@@ -926,6 +995,110 @@ mod tests {
         let _ = std::fs::remove_file(db_path);
     }
 
+    #[test]
+    fn stop_sqlite_reason_is_audited_and_explained() {
+        let db_path = temp_db_path("sqlite-reason");
+        let mut db = forge_db::Db::open(forge_db::Config::new(&db_path))
+            .unwrap_or_else(|err| panic!("open db: {err}"));
+        db.migrate_up()
+            .unwrap_or_else(|err| panic!("migrate db: {err}"));
+
+        let loop_repo = forge_db::loop_repository::LoopRepository::new(&db);
+        let mut loop_entry = forge_db::loop_repository::Loop {
+            name: "reason-loop".to_string(),
+            repo_path: "/tmp/reason".to_string(),
+            state: forge_db::loop_repository::LoopState::Running,
+            ..Default::default()
+        };
+        loop_repo
+            .create(&mut loop_entry)
+            .unwrap_or_else(|err| panic!("create loop: {err}"));
+
+        let mut backend = SqliteStopBackend::new(db_path.clone());
+        let out = run_for_test(
+            &["stop", "reason-loop", "--reason", "deploy freeze"],
+            &mut backend,
+        );
+        assert_eq!(out.exit_code, 0, "stderr: {}", out.stderr);
+
+        let items = forge_db::loop_queue_repository::LoopQueueRepository::new(&db)
+            .list(&loop_entry.id)
+            .unwrap_or_else(|err| panic!("list queue: {err}"));
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].payload, r#"{"reason":"deploy freeze"}"#);
+
+        let event = forge_db::event_repository::EventRepository::new(&db)
+            .latest_by_entity(
+                &EventType::LoopStopRequested.to_string(),
+                "loop",
+                &loop_entry.id,
+            )
+            .unwrap_or_else(|err| panic!("latest stop event: {err}"))
+            .unwrap_or_else(|| panic!("expected a stop event"));
+        assert_eq!(event.payload, r#"{"reason":"deploy freeze"}"#);
+
+        let context_backend = crate::context::FilesystemContextBackend::new(
+            db_path.with_extension("context.yaml"),
+            db_path.clone(),
+        );
+        let explain_backend =
+            crate::explain::SqliteExplainBackend::new(db_path.clone(), context_backend);
+        let explained =
+            crate::explain::run_for_test(&["explain", "reason-loop", "--json"], &explain_backend);
+        assert_eq!(explained.exit_code, 0, "stderr: {}", explained.stderr);
+        let parsed: serde_json::Value = serde_json::from_str(&explained.stdout)
+            .unwrap_or_else(|err| panic!("parse explain json: {err}"));
+        assert_eq!(parsed["name"], "reason-loop");
+        assert_eq!(parsed["stop_cause"]["reason"], "deploy freeze");
+        assert_eq!(
+            parsed["stop_cause"]["requested_at"],
+            event.timestamp.as_str()
+        );
+
+        let _ = std::fs::remove_file(db_path);
+    }
+
+    #[test]
+    fn stop_sqlite_rolls_back_queue_item_when_audit_fails() {
+        let db_path = temp_db_path("sqlite-audit-fail");
+        let mut db = forge_db::Db::open(forge_db::Config::new(&db_path))
+            .unwrap_or_else(|err| panic!("open db: {err}"));
+        db.migrate_up()
+            .unwrap_or_else(|err| panic!("migrate db: {err}"));
+
+        let mut loop_entry = forge_db::loop_repository::Loop {
+            name: "audit-loop".to_string(),
+            repo_path: "/tmp/audit".to_string(),
+            state: forge_db::loop_repository::LoopState::Running,
+            ..Default::default()
+        };
+        forge_db::loop_repository::LoopRepository::new(&db)
+            .create(&mut loop_entry)
+            .unwrap_or_else(|err| panic!("create loop: {err}"));
+        db.conn()
+            .execute_batch(
+                "CREATE TRIGGER events_read_only BEFORE INSERT ON events
+                 BEGIN SELECT RAISE(ABORT, 'audit log is read-only'); END;",
+            )
+            .unwrap_or_else(|err| panic!("create trigger: {err}"));
+
+        let mut backend = SqliteStopBackend::new(db_path.clone());
+        let out = run_for_test(&["stop", "audit-loop"], &mut backend);
+        assert_eq!(out.exit_code, 1);
+        assert!(
+            out.stderr.contains("record stop reason for"),
+            "stderr: {}",
+            out.stderr
+        );
+
+        let items = forge_db::loop_queue_repository::LoopQueueRepository::new(&db)
+            .list(&loop_entry.id)
+            .unwrap_or_else(|err| panic!("list queue: {err}"));
+        assert!(items.is_empty());
+
+        let _ = std::fs::remove_file(db_path);
+    }
+
     #[test]
     fn stop_sqlite_backend_lists_loops() {
         let db_path = temp_db_path("sqlite-list");
@@ -38,6 +38,74 @@ pub struct TemplateVar {
     pub default: String,
     #[serde(default)]
     pub required: bool,
+    /// Allowed values; empty means any value is accepted.
+    #[serde(default, rename = "enum", skip_serializing_if = "Vec::is_empty")]
+    pub allowed: Vec<String>,
+}
+
+/// A single problem found while validating template variables.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VarError {
+    Missing {
+        name: String,
+    },
+    NotAllowed {
+        name: String,
+        value: String,
+        allowed: Vec<String>,
+    },
+}
+
+impl std::fmt::Display for VarError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Missing { name } => write!(f, "missing required variable {name:?}"),
+            Self::NotAllowed {
+                name,
+                value,
+                allowed,
+            } => write!(
+                f,
+                "variable {name:?} has value {value:?}, expected one of: {}",
+                allowed.join(", ")
+            ),
+        }
+    }
+}
+
+impl Template {
+    /// Check `provided` against the declared variables, applying defaults.
+    /// Every problem is reported, not just the first.
+    pub fn validate_vars(&self, provided: &HashMap<String, String>) -> Result<(), Vec<VarError>> {
+        let mut errors = Vec::new();
+        for variable in &self.variables {
+            let value = provided
+                .get(&variable.name)
+                .map(|value| value.trim())
+                .filter(|value| !value.is_empty())
+                .unwrap_or(variable.default.as_str());
+            if value.is_empty() {
+                if variable.required {
+                    errors.push(VarError::Missing {
+                        name: variable.name.clone(),
+                    });
+                }
+                continue;
+            }
+            if !variable.allowed.is_empty() && !variable.allowed.iter().any(|item| item == value) {
+                errors.push(VarError::NotAllowed {
+                    name: variable.name.clone(),
+                    value: value.to_string(),
+                    allowed: variable.allowed.clone(),
+                });
+            }
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
 }
 
 #[derive(Debug, Serialize)]
@@ -683,6 +751,9 @@ fn execute_show(
         if !var.default.is_empty() {
             line.push_str(&format!(" [default: {}]", var.default));
         }
+        if !var.allowed.is_empty() {
+            line.push_str(&format!(" [one of: {}]", var.allowed.join(", ")));
+        }
         writeln!(stdout, "{line}").map_err(|e| e.to_string())?;
     }
     Ok(())
@@ -1169,6 +1240,21 @@ fn parse_template_yaml(data: &str, source: &str) -> Result<Template, String> {
         if !seen.insert(variable.name.clone()) {
             return Err(format!("duplicate template variable {:?}", variable.name));
         }
+        variable.allowed = variable
+            .allowed
+            .iter()
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+            .collect();
+        if !variable.default.is_empty()
+            && !variable.allowed.is_empty()
+            && !variable.allowed.contains(&variable.default)
+        {
+            return Err(format!(
+                "template variable {:?} default {:?} is not one of its allowed values",
+                variable.name, variable.default
+            ));
+        }
     }
 
     tmpl.source = source.to_string();
@@ -1210,20 +1296,21 @@ fn load_builtin_templates() -> Result<Vec<Template>, String> {
 fn render_template(tmpl: &Template, vars: &HashMap<String, String>) -> Result<String, String> {
     // Simple variable substitution: replace {{.VarName}} and {{ .VarName }} patterns.
     // This matches Go's text/template basic variable expansion for the common case.
-    let mut data: HashMap<String, String> = vars.clone();
+    tmpl.validate_vars(vars).map_err(|errors| {
+        errors
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("; ")
+    })?;
 
-    // Apply defaults and check required variables
+    let mut data: HashMap<String, String> = vars.clone();
     for variable in &tmpl.variables {
-        let value = data.get(&variable.name).map(|s| s.trim().to_string());
-        let is_empty = value.as_ref().map_or(true, |v| v.is_empty());
-        if is_empty {
-            if !variable.default.is_empty() {
-                data.insert(variable.name.clone(), variable.default.clone());
-                continue;
-            }
-            if variable.required {
-                return Err(format!("missing required variable {:?}", variable.name));
-            }
+        let is_empty = data
+            .get(&variable.name)
+            .map_or(true, |value| value.trim().is_empty());
+        if is_empty && !variable.default.is_empty() {
+            data.insert(variable.name.clone(), variable.default.clone());
         }
     }
 
@@ -1346,6 +1433,7 @@ mod tests {
                     description: "Deployment target".to_string(),
                     default: "main".to_string(),
                     required: false,
+                    allowed: vec![],
                 }],
                 tags: vec!["ops".to_string(), "git".to_string()],
                 source: "/project/.forge/templates/deploy.yaml".to_string(),
@@ -1991,6 +2079,7 @@ mod tests {
                 description: "".to_string(),
                 default: "main".to_string(),
                 required: false,
+                allowed: vec![],
             }],
             tags: vec![],
             source: "".to_string(),
@@ -2010,6 +2099,7 @@ mod tests {
                 description: "".to_string(),
                 default: "".to_string(),
                 required: true,
+                allowed: vec![],
             }],
             tags: vec![],
             source: "".to_string(),
@@ -2019,6 +2109,49 @@ mod tests {
         assert!(result.unwrap_err().contains("missing required variable"));
     }
 
+    #[test]
+    fn validate_vars_reports_every_problem() {
+        let tmpl = parse_template_yaml(
+            "name: deploy\nmessage: Deploy {{.target}} to {{.env}}.\nvariables:\n  - name: target\n    required: true\n  - name: env\n    default: staging\n    enum: [staging, prod]\n",
+            "test",
+        )
+        .unwrap();
+        assert_eq!(tmpl.variables[1].allowed, vec!["staging", "prod"]);
+
+        let mut vars = HashMap::new();
+        vars.insert("env".to_string(), "qa".to_string());
+        let errors = tmpl.validate_vars(&vars).unwrap_err();
+        assert_eq!(
+            errors,
+            vec![
+                VarError::Missing {
+                    name: "target".to_string()
+                },
+                VarError::NotAllowed {
+                    name: "env".to_string(),
+                    value: "qa".to_string(),
+                    allowed: vec!["staging".to_string(), "prod".to_string()],
+                },
+            ]
+        );
+
+        let err = render_template(&tmpl, &vars).unwrap_err();
+        assert_eq!(
+            err,
+            "missing required variable \"target\"; variable \"env\" has value \"qa\", expected one of: staging, prod"
+        );
+    }
+
+    #[test]
+    fn parse_template_rejects_default_outside_enum() {
+        let err = parse_template_yaml(
+            "name: deploy\nmessage: hi\nvariables:\n  - name: env\n    default: qa\n    enum: [staging, prod]\n",
+            "test",
+        )
+        .unwrap_err();
+        assert!(err.contains("not one of its allowed values"));
+    }
+
     #[test]
     fn short_id_truncates() {
         assert_eq!(short_id("abcdefghijklmnop"), "abcdefgh");
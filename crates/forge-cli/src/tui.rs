@@ -454,8 +454,10 @@ mod tests {
             parsed["error"]["message"],
             "TUI requires an interactive terminal"
         );
-        assert!(str_or_panic(parsed["error"]["hint"].as_str(), "hint present")
-            .contains("non-interactive"));
+        assert!(
+            str_or_panic(parsed["error"]["hint"].as_str(), "hint present")
+                .contains("non-interactive")
+        );
         assert_eq!(parsed["error"]["next_step"], "forge --help");
         assert!(out.stderr.is_empty());
     }
@@ -5,7 +5,7 @@ use std::path::{Path, PathBuf};
 use serde::Serialize;
 use serde_json::json;
 
-use crate::spawn_loop::SpawnOptions;
+use crate::spawn_loop::{SpawnOptions, SpawnResult};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CommandOutput {
@@ -83,17 +83,24 @@ pub trait UpBackend {
         spawn_owner: &str,
         spawn_options: &SpawnOptions,
         warning_writer: &mut dyn Write,
-    ) -> Result<(), String>;
+    ) -> Result<SpawnResult, String>;
+    /// Stream a started loop's log output until the loop stops.
+    fn attach_loop(&mut self, loop_id: &str, stdout: &mut dyn Write) -> Result<(), String>;
 }
 
 #[derive(Debug, Clone, Default)]
 pub struct InMemoryUpBackend {
-    existing_names: Vec<String>,
-    next_id: u64,
+    /// Names of loops that already exist before `up` runs.
+    pub existing_names: Vec<String>,
+    /// Counter used to mint loop ids and short ids.
+    pub next_id: u64,
     pub created_specs: Vec<LoopCreateSpec>,
     pub created_records: Vec<LoopRecord>,
     pub queued: Vec<(String, QueueItem)>,
     pub starts: Vec<(String, String)>,
+    pub attached: Vec<String>,
+    /// Output written when a loop is attached.
+    pub attach_output: String,
 }
 
 impl InMemoryUpBackend {
@@ -149,10 +156,19 @@ impl UpBackend for InMemoryUpBackend {
         spawn_owner: &str,
         _spawn_options: &SpawnOptions,
         _warning_writer: &mut dyn Write,
-    ) -> Result<(), String> {
+    ) -> Result<SpawnResult, String> {
         self.starts
             .push((loop_id.to_string(), spawn_owner.to_string()));
-        Ok(())
+        Ok(SpawnResult {
+            owner: spawn_owner.to_string(),
+            instance_id: format!("inst-{loop_id}"),
+            pid: None,
+        })
+    }
+
+    fn attach_loop(&mut self, loop_id: &str, stdout: &mut dyn Write) -> Result<(), String> {
+        self.attached.push(loop_id.to_string());
+        write!(stdout, "{}", self.attach_output).map_err(|err| err.to_string())
     }
 }
 
@@ -293,7 +309,7 @@ impl UpBackend for SqliteUpBackend {
         spawn_owner: &str,
         spawn_options: &SpawnOptions,
         warning_writer: &mut dyn Write,
-    ) -> Result<(), String> {
+    ) -> Result<SpawnResult, String> {
         let spawn_result = crate::spawn_loop::start_loop_runner(
             loop_id,
             spawn_owner,
@@ -325,7 +341,50 @@ impl UpBackend for SqliteUpBackend {
 
         loop_repo
             .update(&mut loop_entry)
-            .map_err(|err| format!("start loop {loop_id}: {err}"))
+            .map_err(|err| format!("start loop {loop_id}: {err}"))?;
+        Ok(spawn_result)
+    }
+
+    fn attach_loop(&mut self, loop_id: &str, stdout: &mut dyn Write) -> Result<(), String> {
+        let data_dir = crate::runtime_paths::resolve_data_dir()
+            .to_string_lossy()
+            .into_owned();
+        let mut offset = 0usize;
+        loop {
+            // Read state before the log so the final chunk is flushed once the
+            // loop is seen as stopped.
+            let entry = {
+                let db = self.open_db()?;
+                forge_db::loop_repository::LoopRepository::new(&db)
+                    .get(loop_id)
+                    .map_err(|err| format!("loop {loop_id}: {err}"))?
+            };
+            let path = if entry.log_path.is_empty() {
+                crate::logs::default_log_path(&data_dir, &entry.name, &entry.id)
+            } else {
+                entry.log_path.clone()
+            };
+            if let Ok(content) = std::fs::read(&path) {
+                if content.len() < offset {
+                    // Log was truncated or rotated.
+                    offset = 0;
+                }
+                stdout
+                    .write_all(&content[offset..])
+                    .map_err(|err| err.to_string())?;
+                stdout.flush().map_err(|err| err.to_string())?;
+                offset = content.len();
+            }
+
+            if matches!(
+                entry.state,
+                forge_db::loop_repository::LoopState::Stopped
+                    | forge_db::loop_repository::LoopState::Error
+            ) {
+                return Ok(());
+            }
+            std::thread::sleep(std::time::Duration::from_millis(250));
+        }
     }
 }
 
@@ -403,6 +462,7 @@ struct ParsedArgs {
     json: bool,
     jsonl: bool,
     quiet: bool,
+    mode: AttachMode,
     count: usize,
     name: String,
     name_prefix: String,
@@ -420,10 +480,30 @@ struct ParsedArgs {
     stop_config: StopConfig,
 }
 
+/// Whether `forge up` returns immediately or follows the first loop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum AttachMode {
+    /// Print the started loops and a hint for following them.
+    #[default]
+    Default,
+    /// Print the started loops and their count, nothing else.
+    Detach,
+    /// Stream the first loop's output until it exits.
+    Attach,
+}
+
 #[derive(Debug, Serialize)]
 struct UpResultEntry {
     name: String,
     short_id: String,
+    loop_id: String,
+    owner: String,
+}
+
+/// A loop created and started by this invocation.
+struct StartedLoop {
+    record: LoopRecord,
+    spawn: SpawnResult,
 }
 
 fn execute(
@@ -442,7 +522,7 @@ fn execute(
     let existing_names_list = backend.list_loop_names()?;
     let mut existing_names: BTreeSet<String> = existing_names_list.into_iter().collect();
 
-    let mut created: Vec<LoopRecord> = Vec::new();
+    let mut created: Vec<StartedLoop> = Vec::new();
 
     for index in 0..parsed.count {
         let name = if !parsed.name.is_empty() {
@@ -482,35 +562,76 @@ fn execute(
                 },
             )?;
         }
-        backend.start_loop(&record.id, &parsed.spawn_owner, &spawn_options, stderr)?;
-        created.push(record);
+        let spawn = backend.start_loop(&record.id, &parsed.spawn_owner, &spawn_options, stderr)?;
+        created.push(StartedLoop { record, spawn });
     }
 
     if parsed.json || parsed.jsonl {
         let entries: Vec<UpResultEntry> = created
             .iter()
-            .map(|record| UpResultEntry {
-                name: record.name.clone(),
-                short_id: record.short_id.clone(),
+            .map(|started| UpResultEntry {
+                name: started.record.name.clone(),
+                short_id: started.record.short_id.clone(),
+                loop_id: started.record.id.clone(),
+                owner: started.spawn.owner.clone(),
             })
             .collect();
         write_serialized(stdout, &entries, parsed.jsonl)?;
         return Ok(());
     }
 
-    if parsed.quiet {
-        return Ok(());
+    if !parsed.quiet {
+        for started in &created {
+            writeln!(
+                stdout,
+                "Loop \"{}\" started ({})",
+                started.record.name, started.record.short_id
+            )
+            .map_err(|err| err.to_string())?;
+        }
     }
 
-    for record in &created {
-        writeln!(
-            stdout,
-            "Loop \"{}\" started ({})",
-            record.name, record.short_id
-        )
-        .map_err(|err| err.to_string())?;
+    match parsed.mode {
+        AttachMode::Default => {
+            if !parsed.quiet {
+                if let Some(first) = created.first() {
+                    writeln!(
+                        stdout,
+                        "Follow output with: forge logs {} --follow",
+                        first.record.short_id
+                    )
+                    .map_err(|err| err.to_string())?;
+                }
+            }
+            Ok(())
+        }
+        AttachMode::Detach => {
+            if !parsed.quiet {
+                writeln!(
+                    stdout,
+                    "{} loop(s) started in the background",
+                    created.len()
+                )
+                .map_err(|err| err.to_string())?;
+            }
+            Ok(())
+        }
+        AttachMode::Attach => {
+            let Some(first) = created.first() else {
+                return Ok(());
+            };
+            if created.len() > 1 {
+                writeln!(
+                    stderr,
+                    "Attaching to \"{}\"; the other {} loop(s) keep running",
+                    first.record.name,
+                    created.len() - 1
+                )
+                .map_err(|err| err.to_string())?;
+            }
+            backend.attach_loop(&first.record.id, stdout)
+        }
     }
-    Ok(())
 }
 
 fn parse_args(args: &[String]) -> Result<ParsedArgs, String> {
@@ -522,6 +643,8 @@ fn parse_args(args: &[String]) -> Result<ParsedArgs, String> {
     let mut json = false;
     let mut jsonl = false;
     let mut quiet = false;
+    let mut detach = false;
+    let mut attach = false;
     let mut count = 1usize;
     let mut name = String::new();
     let mut name_prefix = String::new();
@@ -570,6 +693,14 @@ fn parse_args(args: &[String]) -> Result<ParsedArgs, String> {
                 quiet = true;
                 index += 1;
             }
+            "--detach" | "-d" => {
+                detach = true;
+                index += 1;
+            }
+            "--attach" => {
+                attach = true;
+                index += 1;
+            }
             "--count" | "-n" => {
                 let value = take_value(args, index, token)?;
                 let parsed = parse_i32(token, &value)?;
@@ -790,6 +921,20 @@ fn parse_args(args: &[String]) -> Result<ParsedArgs, String> {
         });
     }
 
+    if detach && attach {
+        return Err("use either --detach or --attach, not both".to_string());
+    }
+    if attach && (json || jsonl) {
+        return Err("--attach cannot be used with --json or --jsonl".to_string());
+    }
+    let mode = if attach {
+        AttachMode::Attach
+    } else if detach {
+        AttachMode::Detach
+    } else {
+        AttachMode::Default
+    };
+
     if qual_every > 0 || !qual_prompt.trim().is_empty() || !qual_prompt_msg.trim().is_empty() {
         if qual_every <= 0 {
             return Err("qualitative stop every must be > 0".to_string());
@@ -830,6 +975,7 @@ fn parse_args(args: &[String]) -> Result<ParsedArgs, String> {
         json,
         jsonl,
         quiet,
+        mode,
         count,
         name,
         name_prefix,
@@ -976,6 +1122,8 @@ Flags:
   -i, --max-iterations int                 max iterations before stopping (0 = no limit)
       --tags string                        comma-separated tags
       --spawn-owner string                 loop runner owner (local|daemon|auto)
+  -d, --detach                             return immediately after starting loops
+      --attach                             stream the first loop's output until it exits
       --quantitative-stop-cmd string       quantitative stop: command to execute
       --quantitative-stop-every int        quantitative stop: evaluate every N iterations
       --quantitative-stop-when string      quantitative stop: when to evaluate (before|after|both)
@@ -1089,7 +1237,7 @@ mod tests {
         );
         assert_eq!(out.exit_code, 0);
         assert!(out.stderr.is_empty());
-        assert_eq!(out.stdout, "Loop \"batch-1\" started (s001)\nLoop \"batch-2\" started (s002)\nLoop \"batch-3\" started (s003)\n");
+        assert_eq!(out.stdout, "Loop \"batch-1\" started (s001)\nLoop \"batch-2\" started (s002)\nLoop \"batch-3\" started (s003)\nFollow output with: forge logs s001 --follow\n");
         assert_eq!(backend.created_specs.len(), 3);
         assert_eq!(backend.created_specs[0].name, "batch-1");
         assert_eq!(backend.created_specs[1].name, "batch-2");
@@ -1145,7 +1293,7 @@ mod tests {
         assert_eq!(out.exit_code, 0);
         assert_eq!(
             out.stdout,
-            "[\n  {\n    \"name\": \"oracle-loop\",\n    \"short_id\": \"s001\"\n  }\n]\n"
+            "[\n  {\n    \"name\": \"oracle-loop\",\n    \"short_id\": \"s001\",\n    \"loop_id\": \"loop-001\",\n    \"owner\": \"local\"\n  }\n]\n"
         );
     }
 
@@ -1156,7 +1304,7 @@ mod tests {
         assert_eq!(out.exit_code, 0);
         assert_eq!(
             out.stdout,
-            "[{\"name\":\"oracle-loop\",\"short_id\":\"s001\"}]\n"
+            "[{\"name\":\"oracle-loop\",\"short_id\":\"s001\",\"loop_id\":\"loop-001\",\"owner\":\"local\"}]\n"
         );
     }
 
@@ -1174,7 +1322,10 @@ mod tests {
         let mut backend = InMemoryUpBackend::default();
         let out = run_for_test(&["up", "--name", "oracle-loop"], &mut backend);
         assert_eq!(out.exit_code, 0);
-        assert_eq!(out.stdout, "Loop \"oracle-loop\" started (s001)\n");
+        assert_eq!(
+            out.stdout,
+            "Loop \"oracle-loop\" started (s001)\nFollow output with: forge logs s001 --follow\n"
+        );
     }
 
     #[test]
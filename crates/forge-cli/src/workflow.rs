@@ -79,6 +79,10 @@ pub struct Workflow {
     pub steps: Vec<WorkflowStep>,
     #[serde(default, skip_serializing_if = "is_zero")]
     pub max_parallel: i64,
+    /// Keep running independent branches after a step fails instead of
+    /// stopping the whole run.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub continue_on_error: bool,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub hooks: Option<WorkflowHooks>,
     #[serde(skip)]
@@ -390,13 +394,17 @@ impl FilesystemWorkflowBackend {
         let source = path.to_string_lossy().to_string();
         let data = fs::read_to_string(path)
             .map_err(|err| format!("read workflow {}: {err}", path.display()))?;
-        parse_workflow_toml(&data, &source).map_err(|errors| {
+        let wf = parse_workflow_toml(&data, &source).map_err(|errors| {
             errors
                 .iter()
                 .map(WorkflowError::human_string)
                 .collect::<Vec<_>>()
                 .join("\n")
-        })
+        })?;
+        if let Some(err) = dependency_cycle_error(&wf) {
+            return Err(err.human_string());
+        }
+        Ok(wf)
     }
 
     fn workflow_dir(&self) -> PathBuf {
@@ -774,18 +782,6 @@ fn parse_max_parallel_i64(value: i64, source: &str) -> Result<usize, String> {
     usize::try_from(value).map_err(|_| format!("{source} is too large"))
 }
 
-fn global_config_path() -> Option<PathBuf> {
-    if let Some(path) = std::env::var_os("FORGE_CONFIG_PATH") {
-        return Some(PathBuf::from(path));
-    }
-    std::env::var_os("HOME").map(|home| {
-        PathBuf::from(home)
-            .join(".config")
-            .join("forge")
-            .join("config.yaml")
-    })
-}
-
 fn resolve_workflow_max_parallel(wf: &Workflow) -> Result<(usize, WorkflowParallelSource), String> {
     if wf.max_parallel != 0 {
         let parsed = parse_max_parallel_i64(wf.max_parallel, "workflow.max_parallel")?;
@@ -801,22 +797,16 @@ fn resolve_workflow_max_parallel(wf: &Workflow) -> Result<(usize, WorkflowParall
         return Ok((parsed, WorkflowParallelSource::Environment));
     }
 
-    if let Some(path) = global_config_path() {
-        let raw = match fs::read_to_string(&path) {
-            Ok(value) => value,
-            Err(err) if err.kind() == std::io::ErrorKind::NotFound => String::new(),
-            Err(err) => return Err(format!("read global config {}: {err}", path.display())),
-        };
-        if !raw.is_empty() {
-            let parsed: GlobalWorkflowConfig = serde_yaml::from_str(&raw)
-                .map_err(|err| format!("parse global config {}: {err}", path.display()))?;
-            if parsed.scheduler.workflow_max_parallel != 0 {
-                let value = parse_max_parallel_i64(
-                    parsed.scheduler.workflow_max_parallel,
-                    "scheduler.workflow_max_parallel",
-                )?;
-                return Ok((value, WorkflowParallelSource::GlobalConfig));
-            }
+    let effective = crate::config::FilesystemConfigBackend.effective_config()?;
+    if !effective.values.is_null() {
+        let parsed: GlobalWorkflowConfig = serde_yaml::from_value(effective.values)
+            .map_err(|err| format!("parse global config {}: {err}", effective.path.display()))?;
+        if parsed.scheduler.workflow_max_parallel != 0 {
+            let value = parse_max_parallel_i64(
+                parsed.scheduler.workflow_max_parallel,
+                "scheduler.workflow_max_parallel",
+            )?;
+            return Ok((value, WorkflowParallelSource::GlobalConfig));
         }
     }
 
@@ -826,6 +816,14 @@ fn resolve_workflow_max_parallel(wf: &Workflow) -> Result<(usize, WorkflowParall
     ))
 }
 
+fn workflow_failure_policy(wf: &Workflow) -> run_persistence::WorkflowFailurePolicy {
+    if wf.continue_on_error {
+        run_persistence::WorkflowFailurePolicy::ContinueOnError
+    } else {
+        run_persistence::WorkflowFailurePolicy::FailFast
+    }
+}
+
 fn run_workflow(wf: &Workflow) -> Result<WorkflowRunCommandResult, String> {
     let (max_parallel, _) = resolve_workflow_max_parallel(wf)?;
     let store = Arc::new(run_persistence::WorkflowRunStore::open_from_env());
@@ -883,8 +881,11 @@ fn run_workflow(wf: &Workflow) -> Result<WorkflowRunCommandResult, String> {
         }
     }
 
-    let engine_result =
-        run_persistence::execute_parallel_workflow(&engine_steps, max_parallel, |step_id| {
+    let engine_result = run_persistence::execute_parallel_workflow(
+        &engine_steps,
+        max_parallel,
+        workflow_failure_policy(wf),
+        |step_id| {
             let Some(step) = step_lookup.get(step_id) else {
                 return Err(format!("step {:?} missing from lookup", step_id));
             };
@@ -1058,7 +1059,8 @@ fn run_workflow(wf: &Workflow) -> Result<WorkflowRunCommandResult, String> {
                     fail_step(err)
                 }
             }
-        })?;
+        },
+    )?;
 
     let mut failed = false;
     let mut waiting_approval = false;
@@ -1727,8 +1729,11 @@ fn resume_workflow_after_approval(
     let status_lock = Arc::new(Mutex::new(()));
     let run_id = run.id.clone();
 
-    let engine_result =
-        run_persistence::execute_parallel_workflow(&engine_steps, max_parallel, |step_id| {
+    let engine_result = run_persistence::execute_parallel_workflow(
+        &engine_steps,
+        max_parallel,
+        workflow_failure_policy(&wf),
+        |step_id| {
             let Some(step) = step_lookup.get(step_id) else {
                 return Err(format!("step {:?} missing from lookup", step_id));
             };
@@ -1851,7 +1856,8 @@ fn resume_workflow_after_approval(
                     other
                 )),
             }
-        })?;
+        },
+    )?;
 
     let mut failed = false;
     let mut waiting_approval = false;
@@ -2477,7 +2483,9 @@ fn validate_workflow(wf: &Workflow) -> (Workflow, Vec<WorkflowError>) {
 
     validate_dependency_targets(&wf, &step_index, &mut errors);
     validate_logic_targets(&wf, &step_index, &mut errors);
-    validate_cycles(&wf, &step_index, &mut errors);
+    if let Some(err) = dependency_cycle_error(&wf) {
+        errors.push(err);
+    }
 
     (wf, errors)
 }
@@ -2796,78 +2804,64 @@ fn validate_logic_targets(
     }
 }
 
-fn validate_cycles(
-    wf: &Workflow,
-    step_index: &HashMap<String, usize>,
-    errors: &mut Vec<WorkflowError>,
-) {
-    if step_index.is_empty() {
-        return;
-    }
-
-    let mut adj: HashMap<String, Vec<String>> = HashMap::new();
-    let mut in_degree: HashMap<String, usize> = HashMap::new();
-
-    for id in step_index.keys() {
-        in_degree.insert(id.clone(), 0);
-    }
-
-    for step in &wf.steps {
-        if step.id.is_empty() {
-            continue;
+/// Find a dependency cycle, returned as a closed path (`a -> b -> a`).
+/// Dependencies on unknown step ids are ignored here; they are reported
+/// separately.
+fn find_dependency_cycle(steps: &[WorkflowStep]) -> Option<Vec<String>> {
+    fn visit<'a>(
+        id: &'a str,
+        deps: &HashMap<&'a str, &'a [String]>,
+        done: &mut HashSet<&'a str>,
+        path: &mut Vec<&'a str>,
+    ) -> Option<Vec<String>> {
+        if let Some(start) = path.iter().position(|entry| *entry == id) {
+            let mut cycle: Vec<String> = path[start..].iter().map(|s| s.to_string()).collect();
+            cycle.push(id.to_string());
+            return Some(cycle);
         }
-        for dep in &step.depends_on {
-            if !in_degree.contains_key(dep) {
+        if done.contains(id) {
+            return None;
+        }
+        path.push(id);
+        for dep in deps.get(id).copied().unwrap_or_default() {
+            if !deps.contains_key(dep.as_str()) {
                 continue;
             }
-            adj.entry(dep.clone()).or_default().push(step.id.clone());
-            *in_degree.entry(step.id.clone()).or_insert(0) += 1;
-        }
-    }
-
-    let mut queue: Vec<String> = in_degree
-        .iter()
-        .filter(|(_, &deg)| deg == 0)
-        .map(|(id, _)| id.clone())
-        .collect();
-    queue.sort(); // deterministic
-
-    let mut processed = 0;
-    while !queue.is_empty() {
-        let id = queue.remove(0);
-        processed += 1;
-        if let Some(neighbors) = adj.get(&id) {
-            for next in neighbors {
-                if let Some(deg) = in_degree.get_mut(next) {
-                    *deg -= 1;
-                    if *deg == 0 {
-                        queue.push(next.clone());
-                    }
-                }
+            if let Some(cycle) = visit(dep.as_str(), deps, done, path) {
+                return Some(cycle);
             }
         }
+        path.pop();
+        done.insert(id);
+        None
     }
 
-    if processed == in_degree.len() {
-        return;
-    }
-
-    let mut cycle: Vec<String> = in_degree
+    let deps: HashMap<&str, &[String]> = steps
         .iter()
-        .filter(|(_, &deg)| deg > 0)
-        .map(|(id, _)| id.clone())
+        .filter(|step| !step.id.is_empty())
+        .map(|step| (step.id.as_str(), step.depends_on.as_slice()))
         .collect();
-    if cycle.is_empty() {
-        return;
+    let mut done = HashSet::new();
+    for step in steps {
+        if step.id.is_empty() {
+            continue;
+        }
+        let mut path = Vec::new();
+        if let Some(cycle) = visit(step.id.as_str(), &deps, &mut done, &mut path) {
+            return Some(cycle);
+        }
     }
-    cycle.sort();
+    None
+}
 
-    errors.push(WorkflowError {
+fn dependency_cycle_error(wf: &Workflow) -> Option<WorkflowError> {
+    let cycle = find_dependency_cycle(&wf.steps)?;
+    Some(WorkflowError {
         code: ERR_CYCLE.to_string(),
-        message: format!("cycle detected among steps: {}", cycle.join(", ")),
+        message: format!("cycle detected among steps: {}", cycle.join(" -> ")),
         path: wf.source.clone(),
         ..default_error()
-    });
+    })
 }
 
 fn missing_field_error(path: &str, step_id: &str, index: usize, field: &str) -> WorkflowError {
@@ -3071,6 +3065,9 @@ fn print_workflow(wf: &Workflow, project_dir: &Path, stdout: &mut dyn Write) ->
         workflow_parallel_source_label(max_parallel_source)
     )
     .map_err(|e| e.to_string())?;
+    if wf.continue_on_error {
+        writeln!(stdout, "On Failure: continue independent steps").map_err(|e| e.to_string())?;
+    }
 
     if !wf.inputs.is_empty() {
         writeln!(stdout, "Inputs: {}", format_workflow_map(&wf.inputs))
@@ -3674,6 +3671,7 @@ mod tests {
             },
             outputs: BTreeMap::new(),
             max_parallel: 0,
+            continue_on_error: false,
             steps: vec![
                 WorkflowStep {
                     id: "plan".to_string(),
@@ -3702,6 +3700,7 @@ mod tests {
             inputs: BTreeMap::new(),
             outputs: BTreeMap::new(),
             max_parallel: 0,
+            continue_on_error: false,
             steps: vec![
                 WorkflowStep {
                     id: "setup".to_string(),
@@ -3738,6 +3737,7 @@ mod tests {
             inputs: BTreeMap::new(),
             outputs: BTreeMap::new(),
             max_parallel: 0,
+            continue_on_error: false,
             steps: vec![WorkflowStep {
                 id: "build".to_string(),
                 step_type: "bash".to_string(),
@@ -4122,6 +4122,7 @@ mod tests {
                 inputs: BTreeMap::new(),
                 outputs: BTreeMap::new(),
                 max_parallel: 0,
+                continue_on_error: false,
                 steps: vec![
                     WorkflowStep {
                         id: "approve".to_string(),
@@ -4426,6 +4427,7 @@ mod tests {
                 inputs: BTreeMap::new(),
                 outputs: BTreeMap::new(),
                 max_parallel: 0,
+                continue_on_error: false,
                 steps: vec![
                     WorkflowStep {
                         id: "build".to_string(),
@@ -4525,6 +4527,7 @@ mod tests {
                 inputs: BTreeMap::new(),
                 outputs: BTreeMap::new(),
                 max_parallel: 0,
+                continue_on_error: false,
                 steps: vec![WorkflowStep {
                     id: "approve".to_string(),
                     step_type: "human".to_string(),
@@ -4576,6 +4579,7 @@ mod tests {
                 inputs: BTreeMap::new(),
                 outputs: BTreeMap::new(),
                 max_parallel: 0,
+                continue_on_error: false,
                 steps: vec![
                     WorkflowStep {
                         id: "build".to_string(),
@@ -4651,6 +4655,7 @@ mod tests {
                 inputs: BTreeMap::new(),
                 outputs: BTreeMap::new(),
                 max_parallel: 0,
+                continue_on_error: false,
                 steps: vec![
                     WorkflowStep {
                         id: "build".to_string(),
@@ -4723,6 +4728,7 @@ mod tests {
                 inputs: BTreeMap::new(),
                 outputs: BTreeMap::new(),
                 max_parallel: 0,
+                continue_on_error: false,
                 steps: vec![WorkflowStep {
                     id: "build".to_string(),
                     step_type: "bash".to_string(),
@@ -4796,6 +4802,7 @@ mod tests {
                 inputs: BTreeMap::new(),
                 outputs: BTreeMap::new(),
                 max_parallel: 0,
+                continue_on_error: false,
                 steps: vec![WorkflowStep {
                     id: "build".to_string(),
                     step_type: "bash".to_string(),
@@ -4856,6 +4863,7 @@ mod tests {
                 inputs: BTreeMap::new(),
                 outputs: BTreeMap::new(),
                 max_parallel: 0,
+                continue_on_error: false,
                 steps: vec![WorkflowStep {
                     id: "build".to_string(),
                     step_type: "bash".to_string(),
@@ -4972,6 +4980,7 @@ mod tests {
                 inputs: BTreeMap::new(),
                 outputs: BTreeMap::new(),
                 max_parallel: 0,
+                continue_on_error: false,
                 steps: vec![
                     WorkflowStep {
                         id: "plan".to_string(),
@@ -5102,6 +5111,7 @@ mod tests {
                 inputs: BTreeMap::new(),
                 outputs: BTreeMap::new(),
                 max_parallel: 0,
+                continue_on_error: false,
                 steps: vec![
                     WorkflowStep {
                         id: "build".to_string(),
@@ -5197,6 +5207,7 @@ mod tests {
                 inputs: BTreeMap::new(),
                 outputs: BTreeMap::new(),
                 max_parallel: 0,
+                continue_on_error: false,
                 steps: vec![
                     WorkflowStep {
                         id: "build".to_string(),
@@ -5291,6 +5302,7 @@ mod tests {
                 inputs: BTreeMap::new(),
                 outputs: BTreeMap::new(),
                 max_parallel: 0,
+                continue_on_error: false,
                 steps: vec![
                     WorkflowStep {
                         id: "build".to_string(),
@@ -5514,6 +5526,7 @@ mod tests {
             inputs: BTreeMap::new(),
             outputs: BTreeMap::new(),
             max_parallel: 0,
+            continue_on_error: false,
             steps: steps
                 .into_iter()
                 .map(|(id, cmd, depends_on)| WorkflowStep {
@@ -5795,7 +5808,42 @@ mod tests {
             ..default_workflow()
         };
         let (_, errors) = validate_workflow(&wf);
-        assert!(errors.iter().any(|e| e.code == ERR_CYCLE));
+        assert!(
+            errors
+                .iter()
+                .any(|e| e.code == ERR_CYCLE
+                    && e.message == "cycle detected among steps: a -> b -> a")
+        );
+    }
+
+    #[test]
+    fn find_dependency_cycle_accepts_diamond() {
+        let step = |id: &str, deps: &[&str]| WorkflowStep {
+            id: id.to_string(),
+            step_type: "bash".to_string(),
+            cmd: "echo".to_string(),
+            depends_on: deps.iter().map(|dep| dep.to_string()).collect(),
+            ..default_step()
+        };
+        let steps = vec![
+            step("a", &[]),
+            step("b", &["a"]),
+            step("c", &["a"]),
+            step("d", &["b", "c"]),
+        ];
+        assert_eq!(find_dependency_cycle(&steps), None);
+
+        let mut cyclic = steps;
+        cyclic[0].depends_on = vec!["d".to_string()];
+        assert_eq!(
+            find_dependency_cycle(&cyclic),
+            Some(vec![
+                "a".to_string(),
+                "d".to_string(),
+                "b".to_string(),
+                "a".to_string()
+            ])
+        );
     }
 
     #[test]
@@ -6329,6 +6377,7 @@ cmd = "echo ok"
             inputs: BTreeMap::new(),
             outputs: BTreeMap::new(),
             max_parallel: 0,
+            continue_on_error: false,
             steps: Vec::new(),
             hooks: None,
             source: String::new(),
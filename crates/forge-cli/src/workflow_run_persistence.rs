@@ -1747,6 +1747,16 @@ pub enum WorkflowEngineStepStatus {
     Skipped,
 }
 
+/// What the parallel engine does with the rest of the graph once a step fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WorkflowFailurePolicy {
+    /// Stop launching new steps; steps already running are allowed to finish.
+    #[default]
+    FailFast,
+    /// Skip only the failed step's dependents and keep running other branches.
+    ContinueOnError,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum WorkflowEngineStepResult {
     Success,
@@ -1942,6 +1952,7 @@ where
 pub fn execute_parallel_workflow<F>(
     steps: &[WorkflowEngineStep],
     max_parallel: usize,
+    failure_policy: WorkflowFailurePolicy,
     execute_step: F,
 ) -> Result<WorkflowEngineRun, String>
 where
@@ -1969,6 +1980,7 @@ where
     let concurrency_limit = max_parallel.max(1);
     let execute_step = &execute_step;
     let mut pause_requested = false;
+    let mut halt_requested = false;
     std::thread::scope(|scope| -> Result<(), String> {
         let (tx, rx) =
             std::sync::mpsc::channel::<(String, Result<WorkflowEngineStepResult, String>)>();
@@ -2005,7 +2017,7 @@ where
             }
 
             // Launch currently ready steps up to concurrency limit.
-            if !pause_requested {
+            if !pause_requested && !halt_requested {
                 for step_id in &ordered_step_ids {
                     if running_count >= concurrency_limit {
                         break;
@@ -2094,6 +2106,9 @@ where
                         record.status = WorkflowEngineStepStatus::Failed;
                         record.error = err;
                     }
+                    if failure_policy == WorkflowFailurePolicy::FailFast {
+                        halt_requested = true;
+                    }
                 }
             }
         }
@@ -2118,7 +2133,7 @@ mod engine_tests {
     use super::{
         execute_parallel_workflow, execute_sequential_workflow, workflow_step_order,
         WorkflowEngineStep, WorkflowEngineStepRecord, WorkflowEngineStepResult,
-        WorkflowEngineStepStatus,
+        WorkflowEngineStepStatus, WorkflowFailurePolicy,
     };
     use std::sync::atomic::{AtomicUsize, Ordering};
     use std::time::Duration;
@@ -2180,25 +2195,26 @@ mod engine_tests {
         let active = AtomicUsize::new(0);
         let peak = AtomicUsize::new(0);
 
-        let run = execute_parallel_workflow(&steps, 2, |_step_id| {
-            let now_active = active.fetch_add(1, Ordering::SeqCst) + 1;
-            loop {
-                let prev = peak.load(Ordering::SeqCst);
-                if now_active <= prev {
-                    break;
-                }
-                if peak
-                    .compare_exchange(prev, now_active, Ordering::SeqCst, Ordering::SeqCst)
-                    .is_ok()
-                {
-                    break;
+        let run =
+            execute_parallel_workflow(&steps, 2, WorkflowFailurePolicy::FailFast, |_step_id| {
+                let now_active = active.fetch_add(1, Ordering::SeqCst) + 1;
+                loop {
+                    let prev = peak.load(Ordering::SeqCst);
+                    if now_active <= prev {
+                        break;
+                    }
+                    if peak
+                        .compare_exchange(prev, now_active, Ordering::SeqCst, Ordering::SeqCst)
+                        .is_ok()
+                    {
+                        break;
+                    }
                 }
-            }
-            std::thread::sleep(Duration::from_millis(40));
-            active.fetch_sub(1, Ordering::SeqCst);
-            Ok(WorkflowEngineStepResult::Success)
-        })
-        .unwrap_or_else(|err| panic!("execute parallel workflow: {err}"));
+                std::thread::sleep(Duration::from_millis(40));
+                active.fetch_sub(1, Ordering::SeqCst);
+                Ok(WorkflowEngineStepResult::Success)
+            })
+            .unwrap_or_else(|err| panic!("execute parallel workflow: {err}"));
 
         assert_eq!(
             step_record(&run.steps, "a").status,
@@ -2238,25 +2254,26 @@ mod engine_tests {
         let active = AtomicUsize::new(0);
         let peak = AtomicUsize::new(0);
 
-        let run = execute_parallel_workflow(&steps, 2, |_step_id| {
-            let now_active = active.fetch_add(1, Ordering::SeqCst) + 1;
-            loop {
-                let prev = peak.load(Ordering::SeqCst);
-                if now_active <= prev {
-                    break;
-                }
-                if peak
-                    .compare_exchange(prev, now_active, Ordering::SeqCst, Ordering::SeqCst)
-                    .is_ok()
-                {
-                    break;
+        let run =
+            execute_parallel_workflow(&steps, 2, WorkflowFailurePolicy::FailFast, |_step_id| {
+                let now_active = active.fetch_add(1, Ordering::SeqCst) + 1;
+                loop {
+                    let prev = peak.load(Ordering::SeqCst);
+                    if now_active <= prev {
+                        break;
+                    }
+                    if peak
+                        .compare_exchange(prev, now_active, Ordering::SeqCst, Ordering::SeqCst)
+                        .is_ok()
+                    {
+                        break;
+                    }
                 }
-            }
-            std::thread::sleep(Duration::from_millis(40));
-            active.fetch_sub(1, Ordering::SeqCst);
-            Ok(WorkflowEngineStepResult::Success)
-        })
-        .unwrap_or_else(|err| panic!("execute parallel workflow: {err}"));
+                std::thread::sleep(Duration::from_millis(40));
+                active.fetch_sub(1, Ordering::SeqCst);
+                Ok(WorkflowEngineStepResult::Success)
+            })
+            .unwrap_or_else(|err| panic!("execute parallel workflow: {err}"));
 
         assert_eq!(run.steps.len(), 3);
         assert!(
@@ -2291,13 +2308,18 @@ mod engine_tests {
             },
         ];
 
-        let run = execute_parallel_workflow(&steps, 2, |step_id| {
-            if step_id == "a" {
-                Err("step failed".to_string())
-            } else {
-                Ok(WorkflowEngineStepResult::Success)
-            }
-        })
+        let run = execute_parallel_workflow(
+            &steps,
+            2,
+            WorkflowFailurePolicy::ContinueOnError,
+            |step_id| {
+                if step_id == "a" {
+                    Err("step failed".to_string())
+                } else {
+                    Ok(WorkflowEngineStepResult::Success)
+                }
+            },
+        )
         .unwrap_or_else(|err| panic!("execute parallel workflow: {err}"));
 
         assert_eq!(
@@ -2323,7 +2345,7 @@ mod engine_tests {
     }
 
     #[test]
-    fn parallel_workflow_zero_limit_defaults_to_one() {
+    fn parallel_workflow_fail_fast_skips_independent_pending_steps() {
         let steps = vec![
             WorkflowEngineStep {
                 id: "a".to_string(),
@@ -2331,31 +2353,127 @@ mod engine_tests {
             },
             WorkflowEngineStep {
                 id: "b".to_string(),
+                depends_on: vec!["a".to_string()],
+            },
+            WorkflowEngineStep {
+                id: "c".to_string(),
                 depends_on: vec![],
             },
         ];
+
+        let run =
+            execute_parallel_workflow(&steps, 1, WorkflowFailurePolicy::FailFast, |step_id| {
+                if step_id == "a" {
+                    Err("step failed".to_string())
+                } else {
+                    Ok(WorkflowEngineStepResult::Success)
+                }
+            })
+            .unwrap_or_else(|err| panic!("execute parallel workflow: {err}"));
+
+        assert_eq!(
+            step_record(&run.steps, "a").status,
+            WorkflowEngineStepStatus::Failed
+        );
+        assert_eq!(
+            step_record(&run.steps, "b").status,
+            WorkflowEngineStepStatus::Skipped
+        );
+        assert_eq!(
+            step_record(&run.steps, "c").status,
+            WorkflowEngineStepStatus::Skipped
+        );
+    }
+
+    #[test]
+    fn parallel_workflow_diamond_joins_after_both_branches() {
+        let steps = vec![
+            WorkflowEngineStep {
+                id: "a".to_string(),
+                depends_on: vec![],
+            },
+            WorkflowEngineStep {
+                id: "b".to_string(),
+                depends_on: vec!["a".to_string()],
+            },
+            WorkflowEngineStep {
+                id: "c".to_string(),
+                depends_on: vec!["a".to_string()],
+            },
+            WorkflowEngineStep {
+                id: "d".to_string(),
+                depends_on: vec!["b".to_string(), "c".to_string()],
+            },
+        ];
+        let finished = std::sync::Mutex::new(Vec::new());
         let active = AtomicUsize::new(0);
         let peak = AtomicUsize::new(0);
 
-        let run = execute_parallel_workflow(&steps, 0, |_step_id| {
-            let now_active = active.fetch_add(1, Ordering::SeqCst) + 1;
-            loop {
-                let prev = peak.load(Ordering::SeqCst);
-                if now_active <= prev {
-                    break;
+        let run =
+            execute_parallel_workflow(&steps, 4, WorkflowFailurePolicy::FailFast, |step_id| {
+                let now_active = active.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(now_active, Ordering::SeqCst);
+                std::thread::sleep(Duration::from_millis(30));
+                active.fetch_sub(1, Ordering::SeqCst);
+                match finished.lock() {
+                    Ok(mut guard) => guard.push(step_id.to_string()),
+                    Err(err) => panic!("finished lock poisoned: {err}"),
                 }
-                if peak
-                    .compare_exchange(prev, now_active, Ordering::SeqCst, Ordering::SeqCst)
-                    .is_ok()
-                {
-                    break;
+                Ok(WorkflowEngineStepResult::Success)
+            })
+            .unwrap_or_else(|err| panic!("execute parallel workflow: {err}"));
+
+        assert!(run
+            .steps
+            .iter()
+            .all(|step| step.status == WorkflowEngineStepStatus::Success));
+        let finished = finished
+            .into_inner()
+            .unwrap_or_else(|err| panic!("finished lock poisoned: {err}"));
+        assert_eq!(finished.first().map(String::as_str), Some("a"));
+        assert_eq!(finished.last().map(String::as_str), Some("d"));
+        assert_eq!(
+            peak.load(Ordering::SeqCst),
+            2,
+            "b and c should run concurrently"
+        );
+    }
+
+    #[test]
+    fn parallel_workflow_zero_limit_defaults_to_one() {
+        let steps = vec![
+            WorkflowEngineStep {
+                id: "a".to_string(),
+                depends_on: vec![],
+            },
+            WorkflowEngineStep {
+                id: "b".to_string(),
+                depends_on: vec![],
+            },
+        ];
+        let active = AtomicUsize::new(0);
+        let peak = AtomicUsize::new(0);
+
+        let run =
+            execute_parallel_workflow(&steps, 0, WorkflowFailurePolicy::FailFast, |_step_id| {
+                let now_active = active.fetch_add(1, Ordering::SeqCst) + 1;
+                loop {
+                    let prev = peak.load(Ordering::SeqCst);
+                    if now_active <= prev {
+                        break;
+                    }
+                    if peak
+                        .compare_exchange(prev, now_active, Ordering::SeqCst, Ordering::SeqCst)
+                        .is_ok()
+                    {
+                        break;
+                    }
                 }
-            }
-            std::thread::sleep(Duration::from_millis(25));
-            active.fetch_sub(1, Ordering::SeqCst);
-            Ok(WorkflowEngineStepResult::Success)
-        })
-        .unwrap_or_else(|err| panic!("execute parallel workflow: {err}"));
+                std::thread::sleep(Duration::from_millis(25));
+                active.fetch_sub(1, Ordering::SeqCst);
+                Ok(WorkflowEngineStepResult::Success)
+            })
+            .unwrap_or_else(|err| panic!("execute parallel workflow: {err}"));
 
         assert_eq!(run.steps.len(), 2);
         assert_eq!(
@@ -2416,14 +2534,15 @@ mod engine_tests {
             },
         ];
 
-        let run = execute_parallel_workflow(&steps, 2, |step_id| {
-            if step_id == "approve" {
-                Ok(WorkflowEngineStepResult::WaitingApproval)
-            } else {
-                Ok(WorkflowEngineStepResult::Success)
-            }
-        })
-        .unwrap_or_else(|err| panic!("execute parallel workflow: {err}"));
+        let run =
+            execute_parallel_workflow(&steps, 2, WorkflowFailurePolicy::FailFast, |step_id| {
+                if step_id == "approve" {
+                    Ok(WorkflowEngineStepResult::WaitingApproval)
+                } else {
+                    Ok(WorkflowEngineStepResult::Success)
+                }
+            })
+            .unwrap_or_else(|err| panic!("execute parallel workflow: {err}"));
 
         assert!(run.paused);
         assert_eq!(
@@ -13,6 +13,8 @@ fn clean_single_human_matches_golden() {
         profile: "codex".to_string(),
         tags: vec!["team-a".to_string()],
         state: LoopState::Stopped,
+        last_exit_code: None,
+        updated_at: String::new(),
     }]);
     let out = run(&["clean"], &mut backend);
     assert_success(&out);
@@ -29,6 +31,8 @@ fn clean_single_json_matches_golden() {
         profile: "codex".to_string(),
         tags: vec!["team-a".to_string()],
         state: LoopState::Stopped,
+        last_exit_code: None,
+        updated_at: String::new(),
     }]);
     let out = run(&["clean", "--json"], &mut backend);
     assert_success(&out);
@@ -53,6 +57,8 @@ fn clean_requires_inactive_match() {
         profile: "claude".to_string(),
         tags: vec!["team-b".to_string()],
         state: LoopState::Running,
+        last_exit_code: None,
+        updated_at: String::new(),
     }]);
     let out = run(&["clean"], &mut backend);
     assert_eq!(out.exit_code, 1);
@@ -80,6 +86,118 @@ fn clean_integration_removes_only_inactive() {
     assert_eq!(remaining[0].state, LoopState::Running);
 }
 
+#[test]
+fn clean_older_than_excludes_recent_loops() {
+    let old = (chrono::Utc::now() - chrono::Duration::days(10)).to_rfc3339();
+    let recent = (chrono::Utc::now() - chrono::Duration::hours(1)).to_rfc3339();
+    let mut backend = InMemoryLoopBackend::with_loops(vec![
+        aged("loop-001", "stale", LoopState::Stopped, &old),
+        aged("loop-002", "fresh", LoopState::Stopped, &recent),
+        aged("loop-003", "undated", LoopState::Stopped, ""),
+    ]);
+
+    let out = run(&["clean", "--older-than", "7d"], &mut backend);
+    assert_success(&out);
+    assert_eq!(out.stdout, "Loop 'stale' removed\n");
+
+    let remaining = remaining_names(&backend);
+    assert_eq!(remaining, vec!["fresh", "undated"]);
+}
+
+#[test]
+fn clean_state_failed_only_targets_failed_loops() {
+    let old = (chrono::Utc::now() - chrono::Duration::days(1)).to_rfc3339();
+    let mut completed = aged("loop-001", "done", LoopState::Stopped, &old);
+    completed.last_exit_code = Some(0);
+    let mut backend = InMemoryLoopBackend::with_loops(vec![
+        completed,
+        aged("loop-002", "broken", LoopState::Error, &old),
+        aged("loop-003", "halted", LoopState::Stopped, &old),
+        aged("loop-004", "crashed", LoopState::Error, &old),
+    ]);
+
+    let out = run(&["clean", "--state", "failed", "--json"], &mut backend);
+    assert_success(&out);
+    assert_eq!(
+        out.stdout,
+        "{\n  \"removed\": 2,\n  \"loop_ids\": [\n    \"loop-002\",\n    \"loop-004\"\n  ],\n  \"names\": [\n    \"broken\",\n    \"crashed\"\n  ]\n}\n"
+    );
+    assert_eq!(remaining_names(&backend), vec!["done", "halted"]);
+
+    let out = run(&["clean", "--state", "bogus"], &mut backend);
+    assert_eq!(out.exit_code, 1);
+    assert!(out.stderr.contains("invalid value for --state"));
+}
+
+#[test]
+fn clean_dry_run_reports_without_deleting() {
+    let mut backend = seeded();
+
+    let out = run(&["clean", "--dry-run"], &mut backend);
+    assert_success(&out);
+    assert_eq!(
+        out.stdout,
+        "Would remove 2 loop(s):\n  alpha (loop-001)\n  beta (loop-002)\n"
+    );
+
+    let out = run(&["clean", "--dry-run", "--jsonl"], &mut backend);
+    assert_success(&out);
+    assert_eq!(
+        out.stdout,
+        "{\"dry_run\":true,\"would_remove\":2,\"loop_ids\":[\"loop-001\",\"loop-002\"],\"names\":[\"alpha\",\"beta\"],\"skipped\":1}\n"
+    );
+
+    assert_eq!(remaining_names(&backend), vec!["alpha", "beta", "gamma"]);
+}
+
+#[test]
+fn clean_dry_run_reports_zero_matches() {
+    let mut backend = InMemoryLoopBackend::with_loops(Vec::new());
+
+    let out = run(&["clean", "--dry-run"], &mut backend);
+    assert_success(&out);
+    assert_eq!(out.stdout, "Would remove 0 loop(s):\n");
+
+    let out = run(&["clean", "--dry-run", "--jsonl"], &mut backend);
+    assert_success(&out);
+    assert_eq!(
+        out.stdout,
+        "{\"dry_run\":true,\"would_remove\":0,\"loop_ids\":[],\"names\":[]}\n"
+    );
+}
+
+#[test]
+fn clean_rejects_out_of_range_age() {
+    let mut backend = InMemoryLoopBackend::with_loops(Vec::new());
+    let out = run(&["clean", "--older-than", "999999999999999d"], &mut backend);
+    assert_eq!(out.exit_code, 1);
+    assert_eq!(
+        out.stderr,
+        "error: invalid value for --older-than: '999999999999999d'\n"
+    );
+}
+
+fn aged(id: &str, name: &str, state: LoopState, updated_at: &str) -> LoopRecord {
+    LoopRecord {
+        id: id.to_string(),
+        name: name.to_string(),
+        repo: "/repo/alpha".to_string(),
+        pool: "default".to_string(),
+        profile: "codex".to_string(),
+        tags: Vec::new(),
+        state,
+        last_exit_code: None,
+        updated_at: updated_at.to_string(),
+    }
+}
+
+fn remaining_names(backend: &InMemoryLoopBackend) -> Vec<String> {
+    match backend.select_loops(&LoopSelector::default()) {
+        Ok(loops) => loops.into_iter().map(|entry| entry.name).collect(),
+        Err(err) => panic!("backend select should succeed: {err}"),
+    }
+}
+
 fn seeded() -> InMemoryLoopBackend {
     InMemoryLoopBackend::with_loops(vec![
         LoopRecord {
@@ -90,6 +208,8 @@ fn seeded() -> InMemoryLoopBackend {
             profile: "codex".to_string(),
             tags: vec!["team-a".to_string()],
             state: LoopState::Stopped,
+            last_exit_code: None,
+            updated_at: String::new(),
         },
         LoopRecord {
             id: "loop-002".to_string(),
@@ -99,6 +219,8 @@ fn seeded() -> InMemoryLoopBackend {
             profile: "codex".to_string(),
             tags: vec!["team-a".to_string()],
             state: LoopState::Error,
+            last_exit_code: None,
+            updated_at: String::new(),
         },
         LoopRecord {
             id: "loop-003".to_string(),
@@ -108,6 +230,8 @@ fn seeded() -> InMemoryLoopBackend {
             profile: "claude".to_string(),
             tags: vec!["team-b".to_string()],
             state: LoopState::Running,
+            last_exit_code: None,
+            updated_at: String::new(),
         },
     ])
 }
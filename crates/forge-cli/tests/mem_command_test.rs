@@ -254,6 +254,51 @@ fn mem_keys_are_scoped_per_loop() {
     assert_eq!(get_second.stdout, "agent-b\n");
 }
 
+#[test]
+fn mem_search_value_substring_matches_across_loops() {
+    let mut backend = seeded();
+    backend.seed_loop("loop-999", "second-loop");
+    for (loop_ref, key, value) in [
+        ("oracle-loop", "blocked_on", "agent-b"),
+        ("oracle-loop", "owner", "agent-a"),
+        ("second-loop", "waiting_for", "reply from agent-b"),
+    ] {
+        let out = run(
+            &["mem", "--loop", loop_ref, "set", key, value, "--quiet"],
+            &mut backend,
+        );
+        assert_success(&out);
+    }
+
+    let out = run(
+        &["mem", "search", "--value-contains", "agent-b", "--json"],
+        &mut backend,
+    );
+    assert_success(&out);
+    let parsed: serde_json::Value = match serde_json::from_str(&out.stdout) {
+        Ok(value) => value,
+        Err(err) => panic!("parse search json: {err}"),
+    };
+    assert_eq!(
+        parsed,
+        serde_json::json!([
+            {"key": "blocked_on", "loop_id": "loop-123", "loop_name": "oracle-loop", "value": "agent-b"},
+            {"key": "waiting_for", "loop_id": "loop-999", "loop_name": "second-loop", "value": "reply from agent-b"}
+        ])
+    );
+
+    let text = run(&["mem", "search", "--key-glob", "own*"], &mut backend);
+    assert_success(&text);
+    assert_eq!(text.stdout, "oracle-loop owner=agent-a\n");
+
+    let missing = run(&["mem", "search"], &mut backend);
+    assert_eq!(missing.exit_code, 1);
+    assert_eq!(
+        missing.stderr,
+        "mem search requires --key-glob or --value-contains\n"
+    );
+}
+
 fn seeded() -> InMemoryMemBackend {
     let mut backend = InMemoryMemBackend::default();
     backend.seed_loop("loop-123", "oracle-loop");
@@ -152,6 +152,108 @@ fn run_dispatch_profile_selection_error_sets_loop_error_state() {
     );
 }
 
+#[test]
+fn run_dispatch_weighted_pool_update_error_sets_loop_error_state() {
+    let _guard = match env_lock().lock() {
+        Ok(guard) => guard,
+        Err(poison) => poison.into_inner(),
+    };
+
+    let (db_path, dir) = setup_db("run_dispatch_weighted_pool_update_error_sets_loop_error_state");
+    std::env::set_var("FORGE_DATABASE_PATH", &db_path);
+    std::env::set_var("FORGE_DATA_DIR", dir.path.join("data"));
+
+    let (loop_id, pool_id) = {
+        let mut db = forge_db::Db::open(forge_db::Config::new(&db_path))
+            .unwrap_or_else(|err| panic!("open db {}: {err}", db_path.display()));
+        db.migrate_up()
+            .unwrap_or_else(|err| panic!("migrate db {}: {err}", db_path.display()));
+
+        let loop_repo = forge_db::loop_repository::LoopRepository::new(&db);
+        let profile_repo = forge_db::profile_repository::ProfileRepository::new(&db);
+        let pool_repo = forge_db::pool_repository::PoolRepository::new(&db);
+        let repo_path = dir.path.join("repo");
+        std::fs::create_dir_all(&repo_path)
+            .unwrap_or_else(|err| panic!("mkdir {}: {err}", repo_path.display()));
+
+        let mut profile = forge_db::profile_repository::Profile {
+            name: "weighted-profile".to_string(),
+            harness: "codex".to_string(),
+            prompt_mode: "env".to_string(),
+            command_template: "printf 'run ok\\n'".to_string(),
+            ..Default::default()
+        };
+        profile_repo
+            .create(&mut profile)
+            .unwrap_or_else(|err| panic!("create profile: {err}"));
+
+        let mut pool = forge_db::pool_repository::Pool {
+            name: "weighted-pool".to_string(),
+            strategy: "round_robin".to_string(),
+            is_default: true,
+            ..Default::default()
+        };
+        pool_repo
+            .create(&mut pool)
+            .unwrap_or_else(|err| panic!("create pool: {err}"));
+        let mut member = forge_db::pool_repository::PoolMember {
+            pool_id: pool.id.clone(),
+            profile_id: profile.id.clone(),
+            weight: 3,
+            ..Default::default()
+        };
+        pool_repo
+            .add_member(&mut member)
+            .unwrap_or_else(|err| panic!("add member: {err}"));
+
+        db.conn()
+            .execute_batch(
+                "CREATE TRIGGER pools_read_only BEFORE UPDATE ON pools
+                 BEGIN SELECT RAISE(ABORT, 'pools are read-only'); END;",
+            )
+            .unwrap_or_else(|err| panic!("create trigger: {err}"));
+
+        let mut loop_entry = forge_db::loop_repository::Loop {
+            name: "weighted-loop".to_string(),
+            repo_path: repo_path.to_string_lossy().into_owned(),
+            base_prompt_msg: "hello".to_string(),
+            max_iterations: 1,
+            state: forge_db::loop_repository::LoopState::Stopped,
+            ..Default::default()
+        };
+        loop_repo
+            .create(&mut loop_entry)
+            .unwrap_or_else(|err| panic!("create loop: {err}"));
+        (loop_entry.id, pool.id)
+    };
+
+    let (code, _stdout, stderr) = run(&["run", "weighted-loop"]);
+    assert_eq!(code, 1);
+    let expected = format!("update pool {pool_id}");
+    assert!(stderr.contains(&expected), "stderr: {stderr}");
+    assert!(stderr.contains("pools are read-only"), "stderr: {stderr}");
+
+    let db = forge_db::Db::open(forge_db::Config::new(&db_path))
+        .unwrap_or_else(|err| panic!("reopen db {}: {err}", db_path.display()));
+    let run_repo = forge_db::loop_run_repository::LoopRunRepository::new(&db);
+    let loop_entry = forge_db::loop_repository::LoopRepository::new(&db)
+        .get(&loop_id)
+        .unwrap_or_else(|err| panic!("get loop: {err}"));
+    assert_eq!(
+        loop_entry.state,
+        forge_db::loop_repository::LoopState::Error
+    );
+    assert!(
+        loop_entry.last_error.contains(&expected),
+        "last_error: {}",
+        loop_entry.last_error
+    );
+    let runs = run_repo
+        .list_by_loop(&loop_id)
+        .unwrap_or_else(|err| panic!("list runs: {err}"));
+    assert!(runs.is_empty());
+}
+
 #[test]
 fn run_dispatch_quantitative_stop_before_run_short_circuits_iteration() {
     let _guard = match env_lock().lock() {
@@ -353,6 +455,178 @@ fn run_dispatch_qualitative_stop_after_run_stops_loop() {
     );
 }
 
+#[test]
+fn run_dispatch_serves_higher_priority_messages_first() {
+    let _guard = match env_lock().lock() {
+        Ok(guard) => guard,
+        Err(poison) => poison.into_inner(),
+    };
+
+    let (db_path, dir) = setup_db("run_dispatch_serves_higher_priority_messages_first");
+    std::env::set_var("FORGE_DATABASE_PATH", &db_path);
+    std::env::set_var("FORGE_DATA_DIR", dir.path.join("data"));
+    let prompt_path = dir.path.join("prompt.txt");
+
+    {
+        let mut db = forge_db::Db::open(forge_db::Config::new(&db_path))
+            .unwrap_or_else(|err| panic!("open db {}: {err}", db_path.display()));
+        db.migrate_up()
+            .unwrap_or_else(|err| panic!("migrate db {}: {err}", db_path.display()));
+
+        let loop_repo = forge_db::loop_repository::LoopRepository::new(&db);
+        let profile_repo = forge_db::profile_repository::ProfileRepository::new(&db);
+        let queue_repo = forge_db::loop_queue_repository::LoopQueueRepository::new(&db);
+        let repo_path = dir.path.join("repo");
+        std::fs::create_dir_all(&repo_path)
+            .unwrap_or_else(|err| panic!("mkdir {}: {err}", repo_path.display()));
+
+        let mut profile = forge_db::profile_repository::Profile {
+            name: "priority-profile".to_string(),
+            harness: "codex".to_string(),
+            prompt_mode: "env".to_string(),
+            command_template: format!(
+                "printf '%s' \"$FORGE_PROMPT_CONTENT\" > '{}'",
+                prompt_path.display()
+            ),
+            ..Default::default()
+        };
+        profile_repo
+            .create(&mut profile)
+            .unwrap_or_else(|err| panic!("create profile: {err}"));
+
+        let mut loop_entry = forge_db::loop_repository::Loop {
+            name: "priority-loop".to_string(),
+            repo_path: repo_path.to_string_lossy().into_owned(),
+            profile_id: profile.id.clone(),
+            base_prompt_msg: "base".to_string(),
+            max_iterations: 1,
+            ..Default::default()
+        };
+        loop_repo
+            .create(&mut loop_entry)
+            .unwrap_or_else(|err| panic!("create loop: {err}"));
+
+        let mut items = vec![
+            forge_db::loop_queue_repository::LoopQueueItem {
+                item_type: "message_append".to_string(),
+                payload: json!({ "text": "normal note" }).to_string(),
+                ..Default::default()
+            },
+            forge_db::loop_queue_repository::LoopQueueItem {
+                item_type: "message_append".to_string(),
+                payload: json!({ "text": "fix the build", "priority": "high" }).to_string(),
+                ..Default::default()
+            },
+        ];
+        queue_repo
+            .enqueue(&loop_entry.id, &mut items)
+            .unwrap_or_else(|err| panic!("enqueue messages: {err}"));
+    }
+
+    let (code, _stdout, stderr) = run(&["run", "priority-loop"]);
+    assert_eq!(code, 0, "stderr: {stderr}");
+
+    let prompt = std::fs::read_to_string(&prompt_path)
+        .unwrap_or_else(|err| panic!("read {}: {err}", prompt_path.display()));
+    let high = prompt
+        .find("fix the build")
+        .unwrap_or_else(|| panic!("high priority message missing: {prompt}"));
+    let normal = prompt
+        .find("normal note")
+        .unwrap_or_else(|| panic!("normal message missing: {prompt}"));
+    assert!(high < normal, "{prompt}");
+}
+
+#[test]
+fn run_loop_idles_while_pause_flag_is_raised() {
+    let _guard = match env_lock().lock() {
+        Ok(guard) => guard,
+        Err(poison) => poison.into_inner(),
+    };
+
+    let (db_path, dir) = setup_db("run_loop_idles_while_pause_flag_is_raised");
+    std::env::set_var("FORGE_DATABASE_PATH", &db_path);
+    std::env::set_var("FORGE_DATA_DIR", dir.path.join("data"));
+    let pause_file = dir.path.join("runner.pause");
+    std::fs::write(&pause_file, b"")
+        .unwrap_or_else(|err| panic!("write {}: {err}", pause_file.display()));
+    std::env::set_var("FORGE_LOOP_PAUSE_FILE", &pause_file);
+
+    let loop_id = {
+        let mut db = forge_db::Db::open(forge_db::Config::new(&db_path))
+            .unwrap_or_else(|err| panic!("open db {}: {err}", db_path.display()));
+        db.migrate_up()
+            .unwrap_or_else(|err| panic!("migrate db {}: {err}", db_path.display()));
+
+        let loop_repo = forge_db::loop_repository::LoopRepository::new(&db);
+        let profile_repo = forge_db::profile_repository::ProfileRepository::new(&db);
+        let repo_path = dir.path.join("repo");
+        std::fs::create_dir_all(&repo_path)
+            .unwrap_or_else(|err| panic!("mkdir {}: {err}", repo_path.display()));
+
+        let mut profile = forge_db::profile_repository::Profile {
+            name: "pause-profile".to_string(),
+            harness: "codex".to_string(),
+            prompt_mode: "env".to_string(),
+            command_template: "printf 'run ok\\n'".to_string(),
+            ..Default::default()
+        };
+        profile_repo
+            .create(&mut profile)
+            .unwrap_or_else(|err| panic!("create profile: {err}"));
+
+        let mut loop_entry = forge_db::loop_repository::Loop {
+            name: "pause-loop".to_string(),
+            repo_path: repo_path.to_string_lossy().into_owned(),
+            profile_id: profile.id.clone(),
+            base_prompt_msg: "hello".to_string(),
+            max_iterations: 1,
+            state: forge_db::loop_repository::LoopState::Running,
+            ..Default::default()
+        };
+        loop_repo
+            .create(&mut loop_entry)
+            .unwrap_or_else(|err| panic!("create loop: {err}"));
+        loop_entry.id
+    };
+
+    let runner = std::thread::spawn(|| run(&["loop", "run", "pause-loop"]));
+
+    let db = forge_db::Db::open(forge_db::Config::new(&db_path))
+        .unwrap_or_else(|err| panic!("reopen db {}: {err}", db_path.display()));
+    let loop_repo = forge_db::loop_repository::LoopRepository::new(&db);
+    let run_repo = forge_db::loop_run_repository::LoopRunRepository::new(&db);
+    let deadline = Instant::now() + Duration::from_secs(5);
+    loop {
+        let loop_entry = loop_repo
+            .get(&loop_id)
+            .unwrap_or_else(|err| panic!("get loop: {err}"));
+        if loop_entry.state == forge_db::loop_repository::LoopState::Paused {
+            break;
+        }
+        assert!(Instant::now() < deadline, "runner never recorded the pause");
+        std::thread::sleep(Duration::from_millis(20));
+    }
+    std::env::remove_var("FORGE_LOOP_PAUSE_FILE");
+    std::thread::sleep(Duration::from_millis(300));
+    let runs = run_repo
+        .list_by_loop(&loop_id)
+        .unwrap_or_else(|err| panic!("list runs: {err}"));
+    assert!(runs.is_empty(), "iteration ran while paused");
+
+    std::fs::remove_file(&pause_file)
+        .unwrap_or_else(|err| panic!("remove {}: {err}", pause_file.display()));
+    let (code, _stdout, stderr) = runner
+        .join()
+        .unwrap_or_else(|_| panic!("runner thread panicked"));
+    assert_eq!(code, 0, "stderr: {stderr}");
+
+    let runs = run_repo
+        .list_by_loop(&loop_id)
+        .unwrap_or_else(|err| panic!("list runs: {err}"));
+    assert_eq!(runs.len(), 1);
+}
+
 #[test]
 fn run_dispatch_streams_process_output_to_log_before_exit() {
     let _guard = match env_lock().lock() {
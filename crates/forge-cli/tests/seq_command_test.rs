@@ -89,6 +89,214 @@ fn seq_run_matches_golden() {
     assert_eq!(out.stdout, include_str!("golden/seq/run_bugfix.txt"));
 }
 
+#[test]
+fn seq_run_queues_every_step_in_one_call() {
+    let mut backend = seeded();
+    let out = run(
+        &["seq", "run", "review-loop", "--agent", "agent_ab"],
+        &mut backend,
+    );
+    assert_eq!(out.exit_code, 0, "stderr: {}", out.stderr);
+    assert_eq!(
+        *backend.enqueued.borrow(),
+        vec![("agent_abcdef1234567890".to_string(), 2)]
+    );
+    let checkpoint = backend.checkpoints.borrow()[0].clone();
+    assert_eq!(checkpoint.completed_steps, 0);
+    assert_eq!(checkpoint.item_ids, vec!["item-000", "item-001"]);
+}
+
+#[test]
+fn seq_run_resumes_at_failed_step() {
+    let mut backend = seeded();
+    let out = run(
+        &["seq", "run", "review-loop", "--agent", "agent_ab"],
+        &mut backend,
+    );
+    assert_eq!(out.exit_code, 0, "stderr: {}", out.stderr);
+    set_status(&backend, "item-000", "completed");
+    set_status(&backend, "item-001", "failed");
+
+    let out = run(
+        &["seq", "run", "review-loop", "--agent", "agent_ab"],
+        &mut backend,
+    );
+    assert_eq!(out.exit_code, 1);
+    assert_eq!(
+        out.stderr,
+        "sequence \"review-loop\" has an unfinished run for agent agent_ab (1 of 2 steps done); use --resume or --restart\n"
+    );
+
+    let out = run(
+        &[
+            "seq",
+            "run",
+            "review-loop",
+            "--agent",
+            "agent_ab",
+            "--resume",
+        ],
+        &mut backend,
+    );
+    assert_eq!(out.exit_code, 0, "stderr: {}", out.stderr);
+    assert_eq!(
+        out.stdout,
+        "Resumed sequence \"review-loop\" at step 2 (2 steps) for agent agent_ab\n  Step 1: message -> skipped (already done)\n  Step 2: pause 20s -> queued\n"
+    );
+    assert_eq!(backend.enqueued.borrow()[1].1, 1);
+    let checkpoint = backend.checkpoints.borrow()[0].clone();
+    assert_eq!(checkpoint.completed_steps, 1);
+    assert_eq!(checkpoint.item_ids, vec!["item-002"]);
+
+    // Once the resumed step completes, a plain run starts over.
+    set_status(&backend, "item-002", "completed");
+    let out = run(
+        &["seq", "run", "review-loop", "--agent", "agent_ab"],
+        &mut backend,
+    );
+    assert_eq!(out.exit_code, 0, "stderr: {}", out.stderr);
+    assert!(out
+        .stdout
+        .starts_with("Queued sequence \"review-loop\" (2 steps)"));
+}
+
+#[test]
+fn seq_run_resume_refuses_while_steps_are_pending() {
+    let mut backend = seeded();
+    let out = run(
+        &["seq", "run", "review-loop", "--agent", "agent_ab"],
+        &mut backend,
+    );
+    assert_eq!(out.exit_code, 0, "stderr: {}", out.stderr);
+    set_status(&backend, "item-000", "completed");
+
+    let out = run(
+        &[
+            "seq",
+            "run",
+            "review-loop",
+            "--agent",
+            "agent_ab",
+            "--resume",
+        ],
+        &mut backend,
+    );
+    assert_eq!(out.exit_code, 1);
+    assert_eq!(
+        out.stderr,
+        "sequence \"review-loop\" is still running for agent agent_ab (1 of 2 steps done)\n"
+    );
+    assert_eq!(backend.enqueued.borrow().len(), 1);
+}
+
+#[test]
+fn seq_run_resume_ignores_checkpoint_after_definition_change() {
+    let mut backend = seeded();
+    let out = run(
+        &["seq", "run", "review-loop", "--agent", "agent_ab", "--json"],
+        &mut backend,
+    );
+    assert_eq!(out.exit_code, 0, "stderr: {}", out.stderr);
+    set_status(&backend, "item-000", "completed");
+    set_status(&backend, "item-001", "failed");
+
+    backend.sequences[2].steps[0].content = "Review the latest changes again.".to_string();
+    let out = run(
+        &[
+            "seq",
+            "run",
+            "review-loop",
+            "--agent",
+            "agent_ab",
+            "--resume",
+            "--json",
+        ],
+        &mut backend,
+    );
+    assert_eq!(out.exit_code, 0, "stderr: {}", out.stderr);
+    let value: serde_json::Value = serde_json::from_str(&out.stdout).unwrap();
+    assert_eq!(value["item_ids"].as_array().map(Vec::len), Some(2));
+    assert!(value.get("skipped_steps").is_none());
+    assert_eq!(backend.enqueued.borrow().len(), 2);
+}
+
+#[test]
+fn seq_run_resume_ignores_checkpoint_after_variable_change() {
+    let mut backend = seeded();
+    backend.sequences[2].steps[0].content = "Review {{.Target}}.".to_string();
+    backend.sequences[2].variables = vec![SequenceVar {
+        name: "Target".to_string(),
+        description: String::new(),
+        default_value: "main".to_string(),
+        required: false,
+    }];
+    let out = run(
+        &["seq", "run", "review-loop", "--agent", "agent_ab"],
+        &mut backend,
+    );
+    assert_eq!(out.exit_code, 0, "stderr: {}", out.stderr);
+    set_status(&backend, "item-000", "completed");
+    set_status(&backend, "item-001", "failed");
+
+    let out = run(
+        &[
+            "seq",
+            "run",
+            "review-loop",
+            "--agent",
+            "agent_ab",
+            "--var",
+            "Target=release",
+            "--resume",
+        ],
+        &mut backend,
+    );
+    assert_eq!(out.exit_code, 0, "stderr: {}", out.stderr);
+    assert!(out.stdout.starts_with(
+        "Sequence \"review-loop\" changed since its checkpoint; starting from step 1\n"
+    ));
+    assert_eq!(backend.enqueued.borrow()[1].1, 2);
+}
+
+#[test]
+fn seq_run_restart_skips_pending_steps() {
+    let mut backend = seeded();
+    let out = run(
+        &["seq", "run", "review-loop", "--agent", "agent_ab"],
+        &mut backend,
+    );
+    assert_eq!(out.exit_code, 0, "stderr: {}", out.stderr);
+    set_status(&backend, "item-000", "completed");
+
+    let out = run(
+        &[
+            "seq",
+            "run",
+            "review-loop",
+            "--agent",
+            "agent_ab",
+            "--restart",
+        ],
+        &mut backend,
+    );
+    assert_eq!(out.exit_code, 0, "stderr: {}", out.stderr);
+    assert!(out
+        .stdout
+        .starts_with("Queued sequence \"review-loop\" (2 steps)"));
+    assert_eq!(backend.enqueued.borrow().len(), 2);
+    let statuses = backend.item_statuses.borrow();
+    assert_eq!(statuses["item-000"], "completed");
+    assert_eq!(statuses["item-001"], "skipped");
+    assert_eq!(statuses["item-002"], "pending");
+}
+
+fn set_status(backend: &InMemorySeqBackend, id: &str, status: &str) {
+    backend
+        .item_statuses
+        .borrow_mut()
+        .insert(id.to_string(), status.to_string());
+}
+
 fn run(args: &[&str], backend: &mut InMemorySeqBackend) -> forge_cli::seq::CommandOutput {
     run_for_test(args, backend)
 }
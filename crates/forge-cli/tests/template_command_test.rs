@@ -30,18 +30,21 @@ impl RecordingTemplateBackend {
                         description: String::new(),
                         default: String::new(),
                         required: true,
+                        allowed: Vec::new(),
                     },
                     TemplateVar {
                         name: "env".to_string(),
                         description: String::new(),
                         default: "staging".to_string(),
                         required: false,
+                        allowed: vec!["staging".to_string(), "prod".to_string()],
                     },
                     TemplateVar {
                         name: "strategy".to_string(),
                         description: String::new(),
                         default: "rolling".to_string(),
                         required: false,
+                        allowed: Vec::new(),
                     },
                 ],
                 tags: vec!["ops".to_string()],
@@ -154,6 +157,30 @@ fn template_run_json_matches_golden_and_uses_default_values() {
     );
 }
 
+#[test]
+fn template_run_reports_all_variable_errors_without_enqueueing() {
+    let backend = RecordingTemplateBackend::new();
+    let out = run(
+        &[
+            "template",
+            "run",
+            "deploy",
+            "--agent",
+            "agent_oracle",
+            "--var",
+            "env=qa",
+        ],
+        &backend,
+    );
+    assert_eq!(out.exit_code, 1);
+    assert!(out.stdout.is_empty());
+    assert_eq!(
+        out.stderr,
+        "missing required variable \"service\"; variable \"env\" has value \"qa\", expected one of: staging, prod\n"
+    );
+    assert!(backend.last_message().is_none());
+}
+
 fn run(args: &[&str], backend: &dyn TemplateBackend) -> forge_cli::template::CommandOutput {
     run_for_test(args, backend)
 }
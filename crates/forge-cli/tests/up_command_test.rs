@@ -181,6 +181,66 @@ fn up_with_qualitative_stop_passes_config() {
     assert!(!qual.is_prompt_path);
 }
 
+#[test]
+fn up_detach_reports_ids_and_count_without_attaching() {
+    let mut backend = InMemoryUpBackend::default();
+    let out = run(
+        &["up", "--count", "2", "--name-prefix", "bg", "--detach"],
+        &mut backend,
+    );
+    assert_success(&out);
+    assert_eq!(
+        out.stdout,
+        "Loop \"bg-1\" started (s001)\nLoop \"bg-2\" started (s002)\n2 loop(s) started in the background\n"
+    );
+    assert_eq!(backend.starts.len(), 2);
+    assert!(backend.attached.is_empty());
+
+    let json = run(
+        &[
+            "up",
+            "--count",
+            "2",
+            "--name-prefix",
+            "json",
+            "--detach",
+            "--jsonl",
+        ],
+        &mut backend,
+    );
+    assert_success(&json);
+    assert_eq!(
+        json.stdout,
+        "[{\"name\":\"json-1\",\"short_id\":\"s003\",\"loop_id\":\"loop-003\",\"owner\":\"local\"},{\"name\":\"json-2\",\"short_id\":\"s004\",\"loop_id\":\"loop-004\",\"owner\":\"local\"}]\n"
+    );
+}
+
+#[test]
+fn up_attach_streams_first_loop_output() {
+    let mut backend = InMemoryUpBackend {
+        attach_output: "iteration 1 done\n".to_string(),
+        ..Default::default()
+    };
+    let out = run(
+        &["up", "--count", "2", "--name-prefix", "fg", "--attach"],
+        &mut backend,
+    );
+    assert_eq!(out.exit_code, 0, "stderr: {}", out.stderr);
+    assert_eq!(
+        out.stdout,
+        "Loop \"fg-1\" started (s001)\nLoop \"fg-2\" started (s002)\niteration 1 done\n"
+    );
+    assert_eq!(backend.attached, vec!["loop-001".to_string()]);
+    assert!(out.stderr.contains("the other 1 loop(s) keep running"));
+
+    let conflict = run(&["up", "--attach", "--detach"], &mut backend);
+    assert_eq!(conflict.exit_code, 1);
+    assert_eq!(
+        conflict.stderr,
+        "use either --detach or --attach, not both\n"
+    );
+}
+
 fn run(args: &[&str], backend: &mut dyn UpBackend) -> CommandOutput {
     run_for_test(args, backend)
 }
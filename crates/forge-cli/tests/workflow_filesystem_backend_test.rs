@@ -64,6 +64,80 @@ fn workflow_dispatch_uses_filesystem_backend_for_ls_show_validate() {
     });
 }
 
+#[test]
+fn workflow_filesystem_backend_accepts_diamond_and_rejects_cycle_on_load() {
+    let _guard = match env_lock().lock() {
+        Ok(guard) => guard,
+        Err(poison) => poison.into_inner(),
+    };
+
+    let repo = TempDir::new("workflow-fs-backend-dag");
+    let workflows_dir = repo.path.join(".forge").join("workflows");
+    std::fs::create_dir_all(&workflows_dir)
+        .unwrap_or_else(|e| panic!("mkdir {}: {e}", workflows_dir.display()));
+    let diamond = r#"
+name = "diamond"
+max_parallel = 2
+
+[[steps]]
+id = "a"
+type = "bash"
+cmd = "true"
+
+[[steps]]
+id = "b"
+type = "bash"
+cmd = "true"
+depends_on = ["a"]
+
+[[steps]]
+id = "c"
+type = "bash"
+cmd = "true"
+depends_on = ["a"]
+
+[[steps]]
+id = "d"
+type = "bash"
+cmd = "true"
+depends_on = ["b", "c"]
+"#;
+    let cyclic = r#"
+name = "cyclic"
+
+[[steps]]
+id = "a"
+type = "bash"
+cmd = "true"
+depends_on = ["b"]
+
+[[steps]]
+id = "b"
+type = "bash"
+cmd = "true"
+depends_on = ["a"]
+"#;
+    std::fs::write(workflows_dir.join("diamond.toml"), diamond.trim_start())
+        .unwrap_or_else(|e| panic!("write diamond workflow fixture: {e}"));
+
+    with_working_dir(&repo.path, || {
+        let (code, stdout, stderr) = run(&["workflow", "validate", "diamond", "--json"]);
+        assert_eq!(code, 0, "stderr: {stderr}");
+        let validate: serde_json::Value =
+            serde_json::from_str(&stdout).unwrap_or_else(|e| panic!("parse json: {e}\n{stdout}"));
+        assert_eq!(validate["valid"], true);
+
+        std::fs::write(workflows_dir.join("cyclic.toml"), cyclic.trim_start())
+            .unwrap_or_else(|e| panic!("write cyclic workflow fixture: {e}"));
+        let (code, _stdout, stderr) = run(&["workflow", "show", "cyclic"]);
+        assert_eq!(code, 1);
+        assert!(
+            stderr.contains("cycle detected among steps: a -> b -> a"),
+            "stderr: {stderr}"
+        );
+    });
+}
+
 fn run(args: &[&str]) -> (i32, String, String) {
     let argv: Vec<String> = args.iter().map(|arg| (*arg).to_string()).collect();
     let mut stdout = Vec::new();
@@ -4,7 +4,7 @@
 //! section types with full defaults, validation, YAML file loading,
 //! environment variable overrides, and tilde path expansion.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::path::{Path, PathBuf};
 use std::time::Duration;
 
@@ -215,6 +215,10 @@ impl Config {
         if self.tui.refresh_interval.is_zero() {
             return Err("tui.refresh_interval must be greater than 0".into());
         }
+        match self.tui.refresh_mode.to_lowercase().trim() {
+            "interval" | "event-driven" => {}
+            _ => return Err("tui.refresh_mode must be one of interval, event-driven".into()),
+        }
         match self.tui.theme.to_lowercase().trim() {
             "default"
             | "high-contrast"
@@ -553,6 +557,9 @@ impl Default for LoopDefaultsConfig {
 #[derive(Debug, Clone)]
 pub struct TuiConfig {
     pub refresh_interval: Duration,
+    /// When views recompute: `interval` (every tick) or `event-driven`
+    /// (only after a relevant event).
+    pub refresh_mode: String,
     pub theme: String,
     pub show_timestamps: bool,
     pub compact_mode: bool,
@@ -562,6 +569,7 @@ impl Default for TuiConfig {
     fn default() -> Self {
         Self {
             refresh_interval: Duration::from_millis(500),
+            refresh_mode: "interval".into(),
             theme: "default".into(),
             show_timestamps: true,
             compact_mode: false,
@@ -621,6 +629,635 @@ impl Default for EventRetentionConfig {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Layered resolution with provenance
+// ---------------------------------------------------------------------------
+
+/// Environment variable prefix for config overrides (`FORGE_LOGGING_LEVEL`).
+pub const ENV_PREFIX: &str = "FORGE_";
+
+/// Pre-rename prefix still honoured after `FORGE_` (`SWARM_LOGGING_LEVEL`).
+pub const LEGACY_ENV_PREFIX: &str = "SWARM_";
+
+/// Scalar config keys that can be set by a layer, in dotted form.
+pub const LAYERED_KEYS: &[&str] = &[
+    "global.data_dir",
+    "global.config_dir",
+    "global.auto_register_local_node",
+    "database.path",
+    "database.max_connections",
+    "database.busy_timeout_ms",
+    "logging.level",
+    "logging.format",
+    "logging.file",
+    "logging.enable_caller",
+    "default_pool",
+    "node_defaults.ssh_backend",
+    "node_defaults.ssh_timeout",
+    "node_defaults.ssh_key_path",
+    "node_defaults.health_check_interval",
+    "workspace_defaults.tmux_prefix",
+    "workspace_defaults.default_agent_type",
+    "workspace_defaults.auto_import_existing",
+    "agent_defaults.default_type",
+    "agent_defaults.state_polling_interval",
+    "agent_defaults.idle_timeout",
+    "agent_defaults.transcript_buffer_size",
+    "agent_defaults.approval_policy",
+    "scheduler.dispatch_interval",
+    "scheduler.max_retries",
+    "scheduler.retry_backoff",
+    "scheduler.default_cooldown_duration",
+    "scheduler.auto_rotate_on_rate_limit",
+    "loop_defaults.interval",
+    "loop_defaults.prompt",
+    "loop_defaults.prompt_msg",
+    "tui.refresh_interval",
+    "tui.refresh_mode",
+    "tui.theme",
+    "tui.show_timestamps",
+    "tui.compact_mode",
+    "mail.relay.enabled",
+    "mail.relay.dial_timeout",
+    "mail.relay.reconnect_interval",
+    "event_retention.enabled",
+    "event_retention.max_age",
+    "event_retention.max_count",
+    "event_retention.cleanup_interval",
+    "event_retention.archive_before_delete",
+    "event_retention.archive_dir",
+    "event_retention.batch_size",
+];
+
+/// Where a resolved setting came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ConfigSource {
+    Default,
+    File,
+    Profile,
+    Env,
+}
+
+impl ConfigSource {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Default => "default",
+            Self::File => "file",
+            Self::Profile => "profile",
+            Self::Env => "env",
+        }
+    }
+}
+
+impl std::fmt::Display for ConfigSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// One layer of raw settings keyed by dotted path (`logging.level`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigLayer {
+    pub source: ConfigSource,
+    pub values: BTreeMap<String, String>,
+}
+
+impl ConfigLayer {
+    pub fn new(source: ConfigSource) -> Self {
+        Self {
+            source,
+            values: BTreeMap::new(),
+        }
+    }
+
+    pub fn set(&mut self, key: impl Into<String>, value: impl Into<String>) -> &mut Self {
+        self.values.insert(key.into(), value.into());
+        self
+    }
+
+    /// Builds a layer from parsed YAML. Scalars at layered keys are kept;
+    /// empty strings count as unset and list sections (profiles, pools, ...)
+    /// are not layered.
+    pub fn from_yaml_value(source: ConfigSource, value: &serde_yaml::Value) -> Self {
+        let mut layer = Self::new(source);
+        collect_yaml_scalars(value, "", &mut layer.values);
+        layer
+    }
+
+    /// Parses `raw` YAML text into a layer.
+    pub fn from_yaml(source: ConfigSource, raw: &str) -> Result<Self, String> {
+        if raw.trim().is_empty() {
+            return Ok(Self::new(source));
+        }
+        let value: serde_yaml::Value =
+            serde_yaml::from_str(raw).map_err(|err| format!("parse config: {err}"))?;
+        Ok(Self::from_yaml_value(source, &value))
+    }
+
+    /// Builds an env layer by looking up `FORGE_<KEY>` (then the legacy
+    /// `SWARM_<KEY>`) for every layered key. Blank values are ignored.
+    pub fn from_env_lookup<F>(mut lookup: F) -> Self
+    where
+        F: FnMut(&str) -> Option<String>,
+    {
+        let mut layer = Self::new(ConfigSource::Env);
+        for key in LAYERED_KEYS {
+            let suffix = key.replace('.', "_").to_ascii_uppercase();
+            let value = [ENV_PREFIX, LEGACY_ENV_PREFIX]
+                .iter()
+                .filter_map(|prefix| lookup(&format!("{prefix}{suffix}")))
+                .map(|value| value.trim().to_string())
+                .find(|value| !value.is_empty());
+            if let Some(value) = value {
+                layer.values.insert((*key).to_string(), value);
+            }
+        }
+        layer
+    }
+
+    /// Builds an env layer from `FORGE_*` variables. Only variables that map
+    /// to a known key are kept; `FORGE_MAIL_RELAY_ENABLED` maps to
+    /// `mail.relay.enabled`.
+    pub fn from_env_vars<I, K, V>(vars: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: Into<String>,
+    {
+        let mut layer = Self::new(ConfigSource::Env);
+        for (name, value) in vars {
+            if let Some(key) = env_var_key(name.as_ref()) {
+                layer.values.insert(key.to_string(), value.into());
+            }
+        }
+        layer
+    }
+}
+
+/// A merged config plus the layer each scalar setting was resolved from.
+#[derive(Debug, Clone)]
+pub struct ConfigWithProvenance {
+    pub config: Config,
+    origins: BTreeMap<String, ConfigSource>,
+    warnings: Vec<String>,
+}
+
+impl ConfigWithProvenance {
+    /// Applies `layers` in order on top of [`Config::default`]. Later layers
+    /// win; keys a layer does not mention keep their earlier value and origin.
+    /// An env value that does not parse is skipped with a warning, as the
+    /// daemon always did; a bad value in a file or profile is an error.
+    pub fn resolve(layers: &[ConfigLayer]) -> Result<Self, String> {
+        let mut config = Config::default();
+        let mut origins = BTreeMap::new();
+        let mut warnings = Vec::new();
+        for layer in layers {
+            for (key, value) in &layer.values {
+                if let Err(err) = apply_config_value(&mut config, key, value) {
+                    if layer.source != ConfigSource::Env {
+                        return Err(err);
+                    }
+                    warnings.push(format!("ignoring environment override: {err}"));
+                    continue;
+                }
+                origins.insert(key.clone(), layer.source);
+            }
+        }
+        Ok(Self {
+            config,
+            origins,
+            warnings,
+        })
+    }
+
+    /// Env overrides that were skipped because they did not parse.
+    pub fn warnings(&self) -> &[String] {
+        &self.warnings
+    }
+
+    /// Source of `key`, or `None` when the key is not a layered setting.
+    pub fn origin(&self, key: &str) -> Option<ConfigSource> {
+        if let Some(source) = self.origins.get(key) {
+            return Some(*source);
+        }
+        LAYERED_KEYS.contains(&key).then_some(ConfigSource::Default)
+    }
+
+    /// Every layered key with its source, in `LAYERED_KEYS` order.
+    pub fn origins(&self) -> Vec<(&'static str, ConfigSource)> {
+        LAYERED_KEYS
+            .iter()
+            .map(|key| {
+                let source = self
+                    .origins
+                    .get(*key)
+                    .copied()
+                    .unwrap_or(ConfigSource::Default);
+                (*key, source)
+            })
+            .collect()
+    }
+
+    /// Every layered key with its resolved value and source, for
+    /// `forge config show --show-origin`.
+    pub fn entries(&self) -> Vec<(&'static str, String, ConfigSource)> {
+        self.origins()
+            .into_iter()
+            .map(|(key, source)| {
+                (
+                    key,
+                    config_value(&self.config, key).unwrap_or_default(),
+                    source,
+                )
+            })
+            .collect()
+    }
+}
+
+/// Named overlays live in `profiles.yaml` next to the base config; the active
+/// name is recorded in its own file so switching never rewrites hand-edited
+/// YAML.
+pub const PROFILES_FILE: &str = "profiles.yaml";
+pub const ACTIVE_PROFILE_FILE: &str = "active-profile";
+
+/// Parses `profiles.yaml` text into overlays keyed by profile name. `path` is
+/// only used in error messages.
+pub fn parse_profile_overlays(
+    path: &Path,
+    raw: &str,
+) -> Result<BTreeMap<String, serde_yaml::Value>, String> {
+    if raw.trim().is_empty() {
+        return Ok(BTreeMap::new());
+    }
+    let value: serde_yaml::Value =
+        serde_yaml::from_str(raw).map_err(|err| format!("parse {}: {err}", path.display()))?;
+    match value {
+        serde_yaml::Value::Mapping(map) => {
+            let mut profiles = BTreeMap::new();
+            for (name, overlay) in map {
+                let Some(name) = name.as_str() else {
+                    return Err(format!("{}: profile names must be strings", path.display()));
+                };
+                profiles.insert(name.to_string(), overlay);
+            }
+            Ok(profiles)
+        }
+        serde_yaml::Value::Null => Ok(BTreeMap::new()),
+        _ => Err(format!(
+            "{}: expected a mapping of profile name to overrides",
+            path.display()
+        )),
+    }
+}
+
+/// The active profile name and its overlay from `profile_dir`, or `None`
+/// when no profile is active.
+pub fn load_active_profile_overlay(
+    profile_dir: &Path,
+) -> Result<Option<(String, serde_yaml::Value)>, String> {
+    let Some(name) = read_optional_file(&profile_dir.join(ACTIVE_PROFILE_FILE))?
+        .map(|raw| raw.trim().to_string())
+        .filter(|name| !name.is_empty())
+    else {
+        return Ok(None);
+    };
+    let path = profile_dir.join(PROFILES_FILE);
+    let raw = read_optional_file(&path)?.unwrap_or_default();
+    let mut profiles = parse_profile_overlays(&path, &raw)?;
+    let overlay = profiles.remove(&name).ok_or_else(|| {
+        format!("active config profile {name:?} is not defined in {PROFILES_FILE}")
+    })?;
+    Ok(Some((name, overlay)))
+}
+
+fn read_optional_file(path: &Path) -> Result<Option<String>, String> {
+    match std::fs::read_to_string(path) {
+        Ok(raw) => Ok(Some(raw)),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(format!("failed to read {}: {err}", path.display())),
+    }
+}
+
+/// Resolves defaults < config file < active profile overlay < env through
+/// [`ConfigWithProvenance`], then expands paths and validates. `config_file`
+/// overrides discovery; an explicit file must exist. Profiles are read from
+/// `~/.config/forge`, as `forge config use-profile` writes them. Returns the
+/// resolved config and the file used.
+pub fn load_layered_config<F>(
+    config_file: &str,
+    env_lookup: F,
+) -> Result<(ConfigWithProvenance, Option<PathBuf>), String>
+where
+    F: FnMut(&str) -> Option<String>,
+{
+    load_layered_config_with_profiles(config_file, &home_dir().join(".config/forge"), env_lookup)
+}
+
+/// [`load_layered_config`] with the profile directory made explicit.
+pub fn load_layered_config_with_profiles<F>(
+    config_file: &str,
+    profile_dir: &Path,
+    env_lookup: F,
+) -> Result<(ConfigWithProvenance, Option<PathBuf>), String>
+where
+    F: FnMut(&str) -> Option<String>,
+{
+    let explicit = (!config_file.trim().is_empty()).then(|| PathBuf::from(config_file.trim()));
+    let path_to_try = explicit.clone().or_else(find_config_file);
+    let mut layers = Vec::new();
+    let mut loaded_path = None;
+
+    if let Some(path) = path_to_try {
+        match std::fs::read_to_string(&path) {
+            Ok(raw) => {
+                layers.push(ConfigLayer::from_yaml(ConfigSource::File, &raw)?);
+                loaded_path = Some(path);
+            }
+            Err(err) => {
+                if explicit.is_some() || err.kind() != std::io::ErrorKind::NotFound {
+                    return Err(format!("failed to load config file: {err}"));
+                }
+            }
+        }
+    }
+    if let Some((_, overlay)) = load_active_profile_overlay(profile_dir)? {
+        layers.push(ConfigLayer::from_yaml_value(
+            ConfigSource::Profile,
+            &overlay,
+        ));
+    }
+    layers.push(ConfigLayer::from_env_lookup(env_lookup));
+
+    let mut resolved = ConfigWithProvenance::resolve(&layers)?;
+    resolved.config.expand_paths();
+    resolved
+        .config
+        .validate()
+        .map_err(|err| format!("config validation failed: {err}"))?;
+    Ok((resolved, loaded_path))
+}
+
+fn collect_yaml_scalars(
+    value: &serde_yaml::Value,
+    prefix: &str,
+    out: &mut BTreeMap<String, String>,
+) {
+    let serde_yaml::Value::Mapping(map) = value else {
+        return;
+    };
+    for (key, child) in map {
+        let Some(key) = key.as_str() else {
+            continue;
+        };
+        let path = if prefix.is_empty() {
+            key.to_string()
+        } else {
+            format!("{prefix}.{key}")
+        };
+        let scalar = match child {
+            serde_yaml::Value::Mapping(_) => {
+                collect_yaml_scalars(child, &path, out);
+                continue;
+            }
+            serde_yaml::Value::String(text) => text.trim().to_string(),
+            serde_yaml::Value::Bool(flag) => flag.to_string(),
+            serde_yaml::Value::Number(number) => number.to_string(),
+            _ => continue,
+        };
+        if !scalar.is_empty() && LAYERED_KEYS.contains(&path.as_str()) {
+            out.insert(path, scalar);
+        }
+    }
+}
+
+fn config_value(cfg: &Config, key: &str) -> Option<String> {
+    let value = match key {
+        "global.data_dir" => cfg.global.data_dir.clone(),
+        "global.config_dir" => cfg.global.config_dir.clone(),
+        "global.auto_register_local_node" => cfg.global.auto_register_local_node.to_string(),
+        "database.path" => cfg.database.path.clone(),
+        "database.max_connections" => cfg.database.max_connections.to_string(),
+        "database.busy_timeout_ms" => cfg.database.busy_timeout_ms.to_string(),
+        "logging.level" => cfg.logging.level.clone(),
+        "logging.format" => cfg.logging.format.clone(),
+        "logging.file" => cfg.logging.file.clone(),
+        "logging.enable_caller" => cfg.logging.enable_caller.to_string(),
+        "default_pool" => cfg.default_pool.clone(),
+        "node_defaults.ssh_backend" => cfg.node_defaults.ssh_backend.clone(),
+        "node_defaults.ssh_timeout" => format_duration(cfg.node_defaults.ssh_timeout),
+        "node_defaults.ssh_key_path" => cfg.node_defaults.ssh_key_path.clone(),
+        "node_defaults.health_check_interval" => {
+            format_duration(cfg.node_defaults.health_check_interval)
+        }
+        "workspace_defaults.tmux_prefix" => cfg.workspace_defaults.tmux_prefix.clone(),
+        "workspace_defaults.default_agent_type" => {
+            cfg.workspace_defaults.default_agent_type.clone()
+        }
+        "workspace_defaults.auto_import_existing" => {
+            cfg.workspace_defaults.auto_import_existing.to_string()
+        }
+        "agent_defaults.default_type" => cfg.agent_defaults.default_type.clone(),
+        "agent_defaults.state_polling_interval" => {
+            format_duration(cfg.agent_defaults.state_polling_interval)
+        }
+        "agent_defaults.idle_timeout" => format_duration(cfg.agent_defaults.idle_timeout),
+        "agent_defaults.transcript_buffer_size" => {
+            cfg.agent_defaults.transcript_buffer_size.to_string()
+        }
+        "agent_defaults.approval_policy" => cfg.agent_defaults.approval_policy.clone(),
+        "scheduler.dispatch_interval" => format_duration(cfg.scheduler.dispatch_interval),
+        "scheduler.max_retries" => cfg.scheduler.max_retries.to_string(),
+        "scheduler.retry_backoff" => format_duration(cfg.scheduler.retry_backoff),
+        "scheduler.default_cooldown_duration" => {
+            format_duration(cfg.scheduler.default_cooldown_duration)
+        }
+        "scheduler.auto_rotate_on_rate_limit" => {
+            cfg.scheduler.auto_rotate_on_rate_limit.to_string()
+        }
+        "loop_defaults.interval" => format_duration(cfg.loop_defaults.interval),
+        "loop_defaults.prompt" => cfg.loop_defaults.prompt.clone(),
+        "loop_defaults.prompt_msg" => cfg.loop_defaults.prompt_msg.clone(),
+        "tui.refresh_interval" => format_duration(cfg.tui.refresh_interval),
+        "tui.refresh_mode" => cfg.tui.refresh_mode.clone(),
+        "tui.theme" => cfg.tui.theme.clone(),
+        "tui.show_timestamps" => cfg.tui.show_timestamps.to_string(),
+        "tui.compact_mode" => cfg.tui.compact_mode.to_string(),
+        "mail.relay.enabled" => cfg.mail.relay.enabled.to_string(),
+        "mail.relay.dial_timeout" => format_duration(cfg.mail.relay.dial_timeout),
+        "mail.relay.reconnect_interval" => format_duration(cfg.mail.relay.reconnect_interval),
+        "event_retention.enabled" => cfg.event_retention.enabled.to_string(),
+        "event_retention.max_age" => format_duration(cfg.event_retention.max_age),
+        "event_retention.max_count" => cfg.event_retention.max_count.to_string(),
+        "event_retention.cleanup_interval" => format_duration(cfg.event_retention.cleanup_interval),
+        "event_retention.archive_before_delete" => {
+            cfg.event_retention.archive_before_delete.to_string()
+        }
+        "event_retention.archive_dir" => cfg.event_retention.archive_dir.clone(),
+        "event_retention.batch_size" => cfg.event_retention.batch_size.to_string(),
+        _ => return None,
+    };
+    Some(value)
+}
+
+/// Formats a duration the way [`parse_duration`] reads it (`1h30m`, `500ms`).
+fn format_duration(value: Duration) -> String {
+    if value.is_zero() {
+        return "0s".to_string();
+    }
+    if value.subsec_nanos() != 0 {
+        return format!("{}ms", value.as_millis());
+    }
+    let secs = value.as_secs();
+    let mut out = String::new();
+    if secs >= 3600 {
+        out.push_str(&format!("{}h", secs / 3600));
+    }
+    if secs % 3600 >= 60 {
+        out.push_str(&format!("{}m", secs % 3600 / 60));
+    }
+    if secs % 60 != 0 || out.is_empty() {
+        out.push_str(&format!("{}s", secs % 60));
+    }
+    out
+}
+
+fn env_var_key(name: &str) -> Option<&'static str> {
+    let suffix = name.strip_prefix(ENV_PREFIX)?.to_ascii_lowercase();
+    LAYERED_KEYS
+        .iter()
+        .copied()
+        .find(|key| key.replace('.', "_") == suffix)
+}
+
+fn apply_config_value(cfg: &mut Config, key: &str, value: &str) -> Result<(), String> {
+    let value = value.trim();
+    match key {
+        "global.data_dir" => cfg.global.data_dir = value.to_string(),
+        "global.config_dir" => cfg.global.config_dir = value.to_string(),
+        "global.auto_register_local_node" => {
+            cfg.global.auto_register_local_node = parse_bool(key, value)?
+        }
+        "database.path" => cfg.database.path = value.to_string(),
+        "database.max_connections" => cfg.database.max_connections = parse_int(key, value)?,
+        "database.busy_timeout_ms" => cfg.database.busy_timeout_ms = parse_int(key, value)?,
+        "logging.level" => cfg.logging.level = value.to_string(),
+        "logging.format" => cfg.logging.format = value.to_string(),
+        "logging.file" => cfg.logging.file = value.to_string(),
+        "logging.enable_caller" => cfg.logging.enable_caller = parse_bool(key, value)?,
+        "default_pool" => cfg.default_pool = value.to_string(),
+        "node_defaults.ssh_backend" => cfg.node_defaults.ssh_backend = value.to_string(),
+        "node_defaults.ssh_timeout" => cfg.node_defaults.ssh_timeout = parse_duration(key, value)?,
+        "node_defaults.ssh_key_path" => cfg.node_defaults.ssh_key_path = value.to_string(),
+        "node_defaults.health_check_interval" => {
+            cfg.node_defaults.health_check_interval = parse_duration(key, value)?
+        }
+        "workspace_defaults.tmux_prefix" => cfg.workspace_defaults.tmux_prefix = value.to_string(),
+        "workspace_defaults.default_agent_type" => {
+            cfg.workspace_defaults.default_agent_type = value.to_string()
+        }
+        "workspace_defaults.auto_import_existing" => {
+            cfg.workspace_defaults.auto_import_existing = parse_bool(key, value)?
+        }
+        "agent_defaults.default_type" => cfg.agent_defaults.default_type = value.to_string(),
+        "agent_defaults.state_polling_interval" => {
+            cfg.agent_defaults.state_polling_interval = parse_duration(key, value)?
+        }
+        "agent_defaults.idle_timeout" => {
+            cfg.agent_defaults.idle_timeout = parse_duration(key, value)?
+        }
+        "agent_defaults.transcript_buffer_size" => {
+            cfg.agent_defaults.transcript_buffer_size = parse_int(key, value)?
+        }
+        "agent_defaults.approval_policy" => cfg.agent_defaults.approval_policy = value.to_string(),
+        "scheduler.dispatch_interval" => {
+            cfg.scheduler.dispatch_interval = parse_duration(key, value)?
+        }
+        "scheduler.max_retries" => cfg.scheduler.max_retries = parse_int(key, value)?,
+        "scheduler.retry_backoff" => cfg.scheduler.retry_backoff = parse_duration(key, value)?,
+        "scheduler.default_cooldown_duration" => {
+            cfg.scheduler.default_cooldown_duration = parse_duration(key, value)?
+        }
+        "scheduler.auto_rotate_on_rate_limit" => {
+            cfg.scheduler.auto_rotate_on_rate_limit = parse_bool(key, value)?
+        }
+        "loop_defaults.interval" => cfg.loop_defaults.interval = parse_duration(key, value)?,
+        "loop_defaults.prompt" => cfg.loop_defaults.prompt = value.to_string(),
+        "loop_defaults.prompt_msg" => cfg.loop_defaults.prompt_msg = value.to_string(),
+        "tui.refresh_interval" => cfg.tui.refresh_interval = parse_duration(key, value)?,
+        "tui.refresh_mode" => cfg.tui.refresh_mode = value.to_string(),
+        "tui.theme" => cfg.tui.theme = value.to_string(),
+        "tui.show_timestamps" => cfg.tui.show_timestamps = parse_bool(key, value)?,
+        "tui.compact_mode" => cfg.tui.compact_mode = parse_bool(key, value)?,
+        "mail.relay.enabled" => cfg.mail.relay.enabled = parse_bool(key, value)?,
+        "mail.relay.dial_timeout" => cfg.mail.relay.dial_timeout = parse_duration(key, value)?,
+        "mail.relay.reconnect_interval" => {
+            cfg.mail.relay.reconnect_interval = parse_duration(key, value)?
+        }
+        "event_retention.enabled" => cfg.event_retention.enabled = parse_bool(key, value)?,
+        "event_retention.max_age" => cfg.event_retention.max_age = parse_duration(key, value)?,
+        "event_retention.max_count" => cfg.event_retention.max_count = parse_int(key, value)?,
+        "event_retention.cleanup_interval" => {
+            cfg.event_retention.cleanup_interval = parse_duration(key, value)?
+        }
+        "event_retention.archive_before_delete" => {
+            cfg.event_retention.archive_before_delete = parse_bool(key, value)?
+        }
+        "event_retention.archive_dir" => cfg.event_retention.archive_dir = value.to_string(),
+        "event_retention.batch_size" => cfg.event_retention.batch_size = parse_int(key, value)?,
+        _ => return Err(format!("unknown config key {key:?}")),
+    }
+    Ok(())
+}
+
+fn parse_bool(key: &str, value: &str) -> Result<bool, String> {
+    match value.to_ascii_lowercase().as_str() {
+        "1" | "t" | "true" | "yes" | "on" => Ok(true),
+        "0" | "f" | "false" | "no" | "off" => Ok(false),
+        _ => Err(format!("{key}: invalid boolean {value:?}")),
+    }
+}
+
+fn parse_int(key: &str, value: &str) -> Result<i32, String> {
+    value
+        .parse::<i32>()
+        .map_err(|_| format!("{key}: invalid integer {value:?}"))
+}
+
+/// Parses Go-style durations (`1h30m`, `500ms`) or bare seconds.
+fn parse_duration(key: &str, value: &str) -> Result<Duration, String> {
+    let invalid = || format!("{key}: invalid duration {value:?}");
+    if let Ok(secs) = value.parse::<u64>() {
+        return Ok(Duration::from_secs(secs));
+    }
+    let mut total = Duration::ZERO;
+    let mut rest = value;
+    if rest.is_empty() {
+        return Err(invalid());
+    }
+    while !rest.is_empty() {
+        let digits = rest
+            .find(|c: char| !c.is_ascii_digit() && c != '.')
+            .ok_or_else(invalid)?;
+        let amount: f64 = rest[..digits].parse().map_err(|_| invalid())?;
+        rest = &rest[digits..];
+        let unit_len = rest
+            .find(|c: char| c.is_ascii_digit() || c == '.')
+            .unwrap_or(rest.len());
+        let nanos_per_unit = match &rest[..unit_len] {
+            "ns" => 1.0,
+            "us" | "µs" => 1e3,
+            "ms" => 1e6,
+            "s" => 1e9,
+            "m" => 60e9,
+            "h" => 3600e9,
+            _ => return Err(invalid()),
+        };
+        rest = &rest[unit_len..];
+        total += Duration::from_nanos((amount * nanos_per_unit) as u64);
+    }
+    Ok(total)
+}
+
 // ---------------------------------------------------------------------------
 // Helpers
 // ---------------------------------------------------------------------------
@@ -743,6 +1380,57 @@ fn home_dir() -> PathBuf {
 mod tests {
     use super::*;
 
+    #[test]
+    fn load_layered_config_applies_active_profile_between_file_and_env() {
+        let dir = std::env::temp_dir().join(format!(
+            "forge-core-profile-{}-{}",
+            std::process::id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|elapsed| elapsed.as_nanos())
+                .unwrap_or_default()
+        ));
+        std::fs::create_dir_all(&dir).unwrap_or_else(|err| panic!("create dir: {err}"));
+        let config_path = dir.join("config.yaml");
+        let write = |name: &str, raw: &str| {
+            std::fs::write(dir.join(name), raw).unwrap_or_else(|err| panic!("write {name}: {err}"));
+        };
+        write(
+            "config.yaml",
+            "logging:\n  level: info\n  format: console\n",
+        );
+        write(
+            PROFILES_FILE,
+            "dev:\n  logging:\n    level: debug\n    format: json\n",
+        );
+        write(ACTIVE_PROFILE_FILE, "dev\n");
+
+        let (resolved, _) =
+            match load_layered_config_with_profiles(&config_path.to_string_lossy(), &dir, |name| {
+                (name == "FORGE_LOGGING_FORMAT").then(|| "console".to_string())
+            }) {
+                Ok(value) => value,
+                Err(err) => panic!("load config: {err}"),
+            };
+        assert_eq!(resolved.config.logging.level, "debug");
+        assert_eq!(
+            resolved.origin("logging.level"),
+            Some(ConfigSource::Profile)
+        );
+        assert_eq!(resolved.config.logging.format, "console");
+        assert_eq!(resolved.origin("logging.format"), Some(ConfigSource::Env));
+
+        write(ACTIVE_PROFILE_FILE, "missing\n");
+        let err =
+            match load_layered_config_with_profiles(&config_path.to_string_lossy(), &dir, |_| None)
+            {
+                Ok(_) => panic!("expected unknown profile to fail"),
+                Err(err) => err,
+            };
+        assert!(err.contains("\"missing\" is not defined"), "err={err}");
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
     #[test]
     fn config_defaults() {
         let cfg = Config::default();
@@ -851,6 +1539,19 @@ mod tests {
         assert!(err.contains("max_age"), "err={err}");
     }
 
+    #[test]
+    fn validate_tui_refresh_mode() {
+        let mut cfg = Config::default();
+        cfg.tui.refresh_mode = "event-driven".into();
+        assert!(cfg.validate().is_ok());
+        cfg.tui.refresh_mode = "sometimes".into();
+        let err = match cfg.validate() {
+            Ok(()) => panic!("expected error"),
+            Err(err) => err,
+        };
+        assert!(err.contains("tui.refresh_mode"), "err={err}");
+    }
+
     #[test]
     fn validate_tui_theme() {
         let mut cfg = Config::default();
@@ -910,4 +1611,162 @@ mod tests {
         cfg.expand_paths();
         assert!(!cfg.global.data_dir.starts_with('~'));
     }
+
+    #[test]
+    fn provenance_env_override_reports_env() {
+        let env = ConfigLayer::from_env_vars([
+            ("FORGE_LOGGING_LEVEL", "debug"),
+            ("FORGE_MAIL_RELAY_DIAL_TIMEOUT", "2s"),
+            ("HOME", "/home/forge"),
+        ]);
+        let resolved = match ConfigWithProvenance::resolve(&[env]) {
+            Ok(resolved) => resolved,
+            Err(err) => panic!("resolve: {err}"),
+        };
+        assert_eq!(resolved.config.logging.level, "debug");
+        assert_eq!(
+            resolved.config.mail.relay.dial_timeout,
+            Duration::from_secs(2)
+        );
+        assert_eq!(resolved.origin("logging.level"), Some(ConfigSource::Env));
+        assert_eq!(
+            resolved.origin("mail.relay.dial_timeout"),
+            Some(ConfigSource::Env)
+        );
+        assert_eq!(
+            resolved.origin("logging.format"),
+            Some(ConfigSource::Default)
+        );
+        assert_eq!(resolved.config.logging.format, "console");
+        assert_eq!(resolved.origin("no.such.key"), None);
+    }
+
+    #[test]
+    fn provenance_later_layers_win_per_field() {
+        let mut file = ConfigLayer::new(ConfigSource::File);
+        file.set("scheduler.max_retries", "7")
+            .set("scheduler.retry_backoff", "1m30s");
+        let mut env = ConfigLayer::new(ConfigSource::Env);
+        env.set("scheduler.max_retries", "9");
+
+        let resolved = match ConfigWithProvenance::resolve(&[file, env]) {
+            Ok(resolved) => resolved,
+            Err(err) => panic!("resolve: {err}"),
+        };
+        assert_eq!(resolved.config.scheduler.max_retries, 9);
+        assert_eq!(
+            resolved.config.scheduler.retry_backoff,
+            Duration::from_secs(90)
+        );
+        assert_eq!(
+            resolved.origin("scheduler.max_retries"),
+            Some(ConfigSource::Env)
+        );
+        assert_eq!(
+            resolved.origin("scheduler.retry_backoff"),
+            Some(ConfigSource::File)
+        );
+        assert_eq!(
+            resolved.origin("scheduler.dispatch_interval"),
+            Some(ConfigSource::Default)
+        );
+        assert_eq!(resolved.origins().len(), LAYERED_KEYS.len());
+    }
+
+    #[test]
+    fn provenance_skips_unparseable_env_values_with_warning() {
+        let mut file = ConfigLayer::new(ConfigSource::File);
+        file.set("logging.enable_caller", "true");
+        let env = ConfigLayer::from_env_lookup(|name| match name {
+            "FORGE_LOGGING_ENABLE_CALLER" => Some("maybe".to_string()),
+            "FORGE_SCHEDULER_MAX_RETRIES" => Some("lots".to_string()),
+            _ => None,
+        });
+        let resolved = match ConfigWithProvenance::resolve(&[file, env]) {
+            Ok(resolved) => resolved,
+            Err(err) => panic!("resolve: {err}"),
+        };
+
+        assert!(resolved.config.logging.enable_caller);
+        assert_eq!(
+            resolved.origin("logging.enable_caller"),
+            Some(ConfigSource::File)
+        );
+        assert_eq!(
+            resolved.config.scheduler.max_retries,
+            Config::default().scheduler.max_retries
+        );
+        assert_eq!(resolved.warnings().len(), 2);
+        assert!(resolved.warnings()[0].contains("logging.enable_caller"));
+
+        let mut bad_file = ConfigLayer::new(ConfigSource::File);
+        bad_file.set("logging.enable_caller", "maybe");
+        assert!(ConfigWithProvenance::resolve(&[bad_file]).is_err());
+    }
+
+    #[test]
+    fn provenance_yaml_layer_handles_nested_partial_overrides() {
+        let file = match ConfigLayer::from_yaml(
+            ConfigSource::File,
+            "mail:\n  relay:\n    enabled: true\nlogging:\n  level: debug\n  file: \"\"\nprofiles: []\n",
+        ) {
+            Ok(layer) => layer,
+            Err(err) => panic!("parse yaml layer: {err}"),
+        };
+        let env = ConfigLayer::from_env_lookup(|name| match name {
+            "SWARM_LOGGING_LEVEL" => Some("warn".to_string()),
+            "FORGE_TUI_THEME" => Some("  ".to_string()),
+            _ => None,
+        });
+        let resolved = match ConfigWithProvenance::resolve(&[file, env]) {
+            Ok(resolved) => resolved,
+            Err(err) => panic!("resolve: {err}"),
+        };
+
+        assert!(resolved.config.mail.relay.enabled);
+        assert_eq!(
+            resolved.origin("mail.relay.enabled"),
+            Some(ConfigSource::File)
+        );
+        assert_eq!(
+            resolved.origin("mail.relay.dial_timeout"),
+            Some(ConfigSource::Default)
+        );
+        assert_eq!(resolved.config.logging.level, "warn");
+        assert_eq!(resolved.origin("logging.level"), Some(ConfigSource::Env));
+        assert_eq!(resolved.origin("logging.file"), Some(ConfigSource::Default));
+        assert_eq!(resolved.origin("tui.theme"), Some(ConfigSource::Default));
+
+        let entries = resolved.entries();
+        let retry = entries
+            .iter()
+            .find(|(key, _, _)| *key == "scheduler.retry_backoff");
+        assert_eq!(
+            retry.map(|(_, value, source)| (value.as_str(), *source)),
+            Some(("5s", ConfigSource::Default))
+        );
+    }
+
+    #[test]
+    fn format_duration_round_trips_through_parse() {
+        for raw in ["0s", "500ms", "45s", "1m30s", "2h", "1h0m5s"] {
+            let parsed = match parse_duration("test", raw) {
+                Ok(value) => value,
+                Err(err) => panic!("parse {raw}: {err}"),
+            };
+            assert_eq!(parse_duration("test", &format_duration(parsed)), Ok(parsed));
+        }
+        assert_eq!(format_duration(Duration::from_secs(5400)), "1h30m");
+    }
+
+    #[test]
+    fn provenance_rejects_bad_values() {
+        let mut file = ConfigLayer::new(ConfigSource::File);
+        file.set("tui.compact_mode", "maybe");
+        let err = match ConfigWithProvenance::resolve(&[file]) {
+            Ok(_) => panic!("expected error"),
+            Err(err) => err,
+        };
+        assert!(err.contains("tui.compact_mode"), "{err}");
+    }
 }
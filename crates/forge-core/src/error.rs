@@ -5,6 +5,19 @@
 
 use std::fmt;
 
+/// Machine-readable error codes. `ForgeError::code` and the CLI error
+/// envelope both draw from this list.
+pub mod codes {
+    pub const AMBIGUOUS: &str = "ERR_AMBIGUOUS";
+    pub const NOT_FOUND: &str = "ERR_NOT_FOUND";
+    pub const EXISTS: &str = "ERR_EXISTS";
+    pub const INVALID_FLAG: &str = "ERR_INVALID_FLAG";
+    pub const INVALID: &str = "ERR_INVALID";
+    pub const OPERATION_FAILED: &str = "ERR_OPERATION_FAILED";
+    pub const INTERNAL: &str = "ERR_INTERNAL";
+    pub const UNKNOWN: &str = "ERR_UNKNOWN";
+}
+
 /// Top-level error type for Forge operations.
 #[derive(Debug, Clone)]
 pub enum ForgeError {
@@ -16,6 +29,17 @@ pub enum ForgeError {
     Internal(String),
 }
 
+impl ForgeError {
+    /// Stable machine-readable code for this error, used by error envelopes.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Validation(_) => codes::INVALID,
+            Self::NotFound(_) => codes::NOT_FOUND,
+            Self::Internal(_) => codes::INTERNAL,
+        }
+    }
+}
+
 impl fmt::Display for ForgeError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -44,6 +68,32 @@ mod tests {
         assert_eq!(e.to_string(), "internal error: unexpected");
     }
 
+    fn all_variants() -> Vec<ForgeError> {
+        let variants = vec![
+            ForgeError::Validation(String::new()),
+            ForgeError::NotFound(String::new()),
+            ForgeError::Internal(String::new()),
+        ];
+        // Adding a variant breaks this match until it is listed above.
+        for variant in &variants {
+            match variant {
+                ForgeError::Validation(_) | ForgeError::NotFound(_) | ForgeError::Internal(_) => {}
+            }
+        }
+        variants
+    }
+
+    #[test]
+    fn error_codes_are_unique_and_non_empty() {
+        let mut seen = std::collections::HashSet::new();
+        for err in all_variants() {
+            let code = err.code();
+            assert!(code.starts_with("ERR_"), "{err:?} has code {code:?}");
+            assert!(seen.insert(code), "duplicate code {code}");
+        }
+        assert_eq!(ForgeError::NotFound("x".into()).code(), "ERR_NOT_FOUND");
+    }
+
     #[test]
     fn error_is_std_error() {
         let e: Box<dyn std::error::Error> = Box::new(ForgeError::Internal("test".into()));
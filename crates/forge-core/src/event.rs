@@ -5,6 +5,8 @@
 
 use std::fmt;
 
+use serde::{Deserialize, Serialize};
+
 /// Classification of events in the append-only log.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum EventType {
@@ -17,10 +19,14 @@ pub enum EventType {
     AgentStarted,
     AgentStopped,
     AgentStateChanged,
+    LoopStateChanged,
+    LoopStopRequested,
+    PortAllocated,
     MessageQueued,
     MessageDispatched,
     ApprovalRequested,
     ApprovalGranted,
+    ApprovalExpired,
     RateLimitHit,
     CooldownStarted,
     CooldownEnded,
@@ -42,10 +48,14 @@ impl fmt::Display for EventType {
             Self::AgentStarted => "agent.started",
             Self::AgentStopped => "agent.stopped",
             Self::AgentStateChanged => "agent.state_changed",
+            Self::LoopStateChanged => "loop.state_changed",
+            Self::LoopStopRequested => "loop.stop_requested",
+            Self::PortAllocated => "port.allocated",
             Self::MessageQueued => "message.queued",
             Self::MessageDispatched => "message.dispatched",
             Self::ApprovalRequested => "approval.requested",
             Self::ApprovalGranted => "approval.granted",
+            Self::ApprovalExpired => "approval.expired",
             Self::RateLimitHit => "rate_limit.hit",
             Self::CooldownStarted => "cooldown.started",
             Self::CooldownEnded => "cooldown.ended",
@@ -67,6 +77,7 @@ pub enum EntityType {
     Queue,
     Account,
     System,
+    Loop,
 }
 
 impl fmt::Display for EntityType {
@@ -78,11 +89,121 @@ impl fmt::Display for EntityType {
             Self::Queue => "queue",
             Self::Account => "account",
             Self::System => "system",
+            Self::Loop => "loop",
         };
         f.write_str(s)
     }
 }
 
+/// Structured event payload, tagged by `kind` when serialized.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum EventPayload {
+    AgentStateChanged {
+        #[serde(alias = "old_state")]
+        from: String,
+        #[serde(alias = "new_state")]
+        to: String,
+        #[serde(default, skip_serializing_if = "String::is_empty")]
+        reason: String,
+    },
+    LoopStateChanged {
+        from: String,
+        to: String,
+    },
+    PortAllocated {
+        port: u16,
+    },
+    MessageQueued {
+        queue_item_id: String,
+        agent_id: String,
+    },
+    MessageDispatched {
+        queue_item_id: String,
+        agent_id: String,
+        #[serde(default, skip_serializing_if = "String::is_empty")]
+        message: String,
+    },
+    RateLimitHit {
+        account_id: String,
+        cooldown_seconds: i64,
+        #[serde(default, skip_serializing_if = "String::is_empty")]
+        reason: String,
+    },
+    AccountRotated {
+        agent_id: String,
+        old_account_id: String,
+        new_account_id: String,
+        #[serde(default, skip_serializing_if = "String::is_empty")]
+        reason: String,
+    },
+    Error {
+        error: String,
+        #[serde(default, skip_serializing_if = "String::is_empty")]
+        context: String,
+    },
+    Warning {
+        message: String,
+    },
+    /// Payload that could not be mapped to a typed variant; kept verbatim.
+    Legacy {
+        event_type: String,
+        data: String,
+    },
+}
+
+impl EventPayload {
+    /// Event type this payload belongs to, if it is a typed variant.
+    pub fn event_type(&self) -> Option<EventType> {
+        match self {
+            Self::AgentStateChanged { .. } => Some(EventType::AgentStateChanged),
+            Self::LoopStateChanged { .. } => Some(EventType::LoopStateChanged),
+            Self::PortAllocated { .. } => Some(EventType::PortAllocated),
+            Self::MessageQueued { .. } => Some(EventType::MessageQueued),
+            Self::MessageDispatched { .. } => Some(EventType::MessageDispatched),
+            Self::RateLimitHit { .. } => Some(EventType::RateLimitHit),
+            Self::AccountRotated { .. } => Some(EventType::AccountRotated),
+            Self::Error { .. } => Some(EventType::Error),
+            Self::Warning { .. } => Some(EventType::Warning),
+            Self::Legacy { .. } => None,
+        }
+    }
+
+    /// Converts a legacy untagged JSON payload string (as stored by the Go
+    /// implementation) into a typed payload. Payloads that don't match the
+    /// expected shape for `event_type` come back as [`EventPayload::Legacy`].
+    pub fn from_legacy(event_type: EventType, data: &str) -> Self {
+        let legacy = || Self::Legacy {
+            event_type: event_type.to_string(),
+            data: data.to_string(),
+        };
+        let Some(kind) = payload_kind(event_type) else {
+            return legacy();
+        };
+        let mut value = match serde_json::from_str::<serde_json::Value>(data) {
+            Ok(serde_json::Value::Object(map)) => map,
+            _ => return legacy(),
+        };
+        value.insert("kind".to_string(), serde_json::Value::from(kind));
+        serde_json::from_value(serde_json::Value::Object(value)).unwrap_or_else(|_| legacy())
+    }
+}
+
+fn payload_kind(event_type: EventType) -> Option<&'static str> {
+    match event_type {
+        EventType::AgentStateChanged => Some("agent_state_changed"),
+        EventType::LoopStateChanged => Some("loop_state_changed"),
+        EventType::PortAllocated => Some("port_allocated"),
+        EventType::MessageQueued => Some("message_queued"),
+        EventType::MessageDispatched => Some("message_dispatched"),
+        EventType::RateLimitHit => Some("rate_limit_hit"),
+        EventType::AccountRotated => Some("account_rotated"),
+        EventType::Error => Some("error"),
+        EventType::Warning => Some("warning"),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -94,6 +215,10 @@ mod tests {
             EventType::AgentStateChanged.to_string(),
             "agent.state_changed"
         );
+        assert_eq!(
+            EventType::LoopStopRequested.to_string(),
+            "loop.stop_requested"
+        );
         assert_eq!(EventType::Error.to_string(), "error");
     }
 
@@ -101,5 +226,111 @@ mod tests {
     fn entity_type_display() {
         assert_eq!(EntityType::Node.to_string(), "node");
         assert_eq!(EntityType::System.to_string(), "system");
+        assert_eq!(EntityType::Loop.to_string(), "loop");
+    }
+
+    fn all_payloads() -> Vec<EventPayload> {
+        vec![
+            EventPayload::AgentStateChanged {
+                from: "idle".into(),
+                to: "working".into(),
+                reason: "prompt sent".into(),
+            },
+            EventPayload::LoopStateChanged {
+                from: "running".into(),
+                to: "sleeping".into(),
+            },
+            EventPayload::PortAllocated { port: 47_123 },
+            EventPayload::MessageQueued {
+                queue_item_id: "q-1".into(),
+                agent_id: "a-1".into(),
+            },
+            EventPayload::MessageDispatched {
+                queue_item_id: "q-1".into(),
+                agent_id: "a-1".into(),
+                message: "hello".into(),
+            },
+            EventPayload::RateLimitHit {
+                account_id: "acct".into(),
+                cooldown_seconds: 300,
+                reason: String::new(),
+            },
+            EventPayload::AccountRotated {
+                agent_id: "a-1".into(),
+                old_account_id: "old".into(),
+                new_account_id: "new".into(),
+                reason: "cooldown".into(),
+            },
+            EventPayload::Error {
+                error: "boom".into(),
+                context: "spawn".into(),
+            },
+            EventPayload::Warning {
+                message: "slow".into(),
+            },
+            EventPayload::Legacy {
+                event_type: "node.created".into(),
+                data: "{}".into(),
+            },
+        ]
+    }
+
+    #[test]
+    fn payload_variants_roundtrip() {
+        for payload in all_payloads() {
+            let json = match serde_json::to_string(&payload) {
+                Ok(json) => json,
+                Err(err) => panic!("serialize {payload:?}: {err}"),
+            };
+            let decoded: EventPayload = match serde_json::from_str(&json) {
+                Ok(decoded) => decoded,
+                Err(err) => panic!("deserialize {json}: {err}"),
+            };
+            assert_eq!(decoded, payload, "{json}");
+        }
+    }
+
+    #[test]
+    fn payload_event_type_matches_kind() {
+        for payload in all_payloads() {
+            if let Some(event_type) = payload.event_type() {
+                let json = match serde_json::to_value(&payload) {
+                    Ok(json) => json,
+                    Err(err) => panic!("serialize {payload:?}: {err}"),
+                };
+                assert_eq!(
+                    json.get("kind").and_then(|kind| kind.as_str()),
+                    payload_kind(event_type)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn payload_from_legacy_string() {
+        let payload = EventPayload::from_legacy(
+            EventType::AgentStateChanged,
+            r#"{"old_state":"idle","new_state":"working","confidence":"high","reason":"x"}"#,
+        );
+        assert_eq!(
+            payload,
+            EventPayload::AgentStateChanged {
+                from: "idle".into(),
+                to: "working".into(),
+                reason: "x".into(),
+            }
+        );
+
+        let payload = EventPayload::from_legacy(EventType::PortAllocated, "not json");
+        assert_eq!(
+            payload,
+            EventPayload::Legacy {
+                event_type: "port.allocated".into(),
+                data: "not json".into(),
+            }
+        );
+
+        let payload = EventPayload::from_legacy(EventType::NodeCreated, r#"{"id":"n1"}"#);
+        assert!(matches!(payload, EventPayload::Legacy { .. }));
     }
 }
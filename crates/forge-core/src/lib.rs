@@ -8,6 +8,7 @@
 pub mod config;
 pub mod error;
 pub mod event;
+pub mod loop_runner;
 pub mod models;
 pub mod queue;
 pub mod validation;
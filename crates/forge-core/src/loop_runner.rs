@@ -0,0 +1,153 @@
+//! Contract between the daemon and the loop runner processes it spawns.
+//!
+//! Runners started with [`PROGRESS_ENV_VAR`] set to `1` print one progress
+//! line per iteration boundary on stdout:
+//!
+//! ```text
+//! forge-loop-progress iteration=3 phase=harness_invoked detail=profile=codex
+//! ```
+
+use std::path::PathBuf;
+
+/// Environment variable naming the runner's pause flag file.
+///
+/// The daemon creates the file to pause a runner and removes it to resume.
+/// Runners check for it between iterations and idle while it exists.
+pub const PAUSE_FILE_ENV_VAR: &str = "FORGE_LOOP_PAUSE_FILE";
+
+/// Pause flag file handed to this process by the daemon, if any.
+pub fn pause_file_from_env() -> Option<PathBuf> {
+    let value = std::env::var(PAUSE_FILE_ENV_VAR).ok()?;
+    let value = value.trim();
+    if value.is_empty() {
+        return None;
+    }
+    Some(PathBuf::from(value))
+}
+
+/// Environment variable that asks a runner process to emit progress lines.
+pub const PROGRESS_ENV_VAR: &str = "FORGE_LOOP_PROGRESS";
+
+/// Prefix identifying a progress line on runner stdout.
+pub const PROGRESS_LINE_PREFIX: &str = "forge-loop-progress";
+
+/// Whether the daemon asked this process to emit progress lines.
+pub fn progress_enabled_from_env() -> bool {
+    std::env::var(PROGRESS_ENV_VAR).ok().as_deref() == Some("1")
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoopProgressPhase {
+    IterationStarted,
+    HarnessInvoked,
+    OutputCaptured,
+    Outcome,
+}
+
+impl LoopProgressPhase {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::IterationStarted => "iteration_started",
+            Self::HarnessInvoked => "harness_invoked",
+            Self::OutputCaptured => "output_captured",
+            Self::Outcome => "outcome",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim() {
+            "iteration_started" => Some(Self::IterationStarted),
+            "harness_invoked" => Some(Self::HarnessInvoked),
+            "output_captured" => Some(Self::OutputCaptured),
+            "outcome" => Some(Self::Outcome),
+            _ => None,
+        }
+    }
+}
+
+/// One parsed progress line, before a sequence number is assigned.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProgressLine {
+    pub iteration: i64,
+    pub phase: LoopProgressPhase,
+    pub detail: String,
+}
+
+/// Render a progress line in the format understood by [`parse_progress_line`].
+pub fn format_progress_line(iteration: i64, phase: LoopProgressPhase, detail: &str) -> String {
+    let detail = detail.replace(['\r', '\n'], " ");
+    if detail.trim().is_empty() {
+        return format!(
+            "{PROGRESS_LINE_PREFIX} iteration={iteration} phase={}",
+            phase.as_str()
+        );
+    }
+    format!(
+        "{PROGRESS_LINE_PREFIX} iteration={iteration} phase={} detail={}",
+        phase.as_str(),
+        detail.trim()
+    )
+}
+
+/// Parse a runner stdout line. Returns `None` for anything that is not a
+/// well-formed progress line.
+pub fn parse_progress_line(line: &str) -> Option<ProgressLine> {
+    let rest = line.trim_end().strip_prefix(PROGRESS_LINE_PREFIX)?;
+    let rest = rest.strip_prefix(' ')?;
+
+    let (head, detail) = match rest.split_once(" detail=") {
+        Some((head, detail)) => (head, detail.to_string()),
+        None => (rest, String::new()),
+    };
+
+    let mut iteration = None;
+    let mut phase = None;
+    for field in head.split_whitespace() {
+        match field.split_once('=') {
+            Some(("iteration", value)) => iteration = value.parse::<i64>().ok(),
+            Some(("phase", value)) => phase = LoopProgressPhase::parse(value),
+            _ => {}
+        }
+    }
+
+    Some(ProgressLine {
+        iteration: iteration?,
+        phase: phase?,
+        detail,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_progress_line, parse_progress_line, LoopProgressPhase, ProgressLine};
+
+    #[test]
+    fn progress_line_roundtrip() {
+        let line = format_progress_line(4, LoopProgressPhase::Outcome, "exit=0\nok");
+        assert_eq!(
+            line,
+            "forge-loop-progress iteration=4 phase=outcome detail=exit=0 ok"
+        );
+        assert_eq!(
+            parse_progress_line(&line),
+            Some(ProgressLine {
+                iteration: 4,
+                phase: LoopProgressPhase::Outcome,
+                detail: "exit=0 ok".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn parse_ignores_unrelated_output() {
+        assert_eq!(parse_progress_line("loop started"), None);
+        assert_eq!(
+            parse_progress_line("forge-loop-progress iteration=x phase=outcome"),
+            None
+        );
+        assert_eq!(
+            parse_progress_line("forge-loop-progress iteration=1 phase=bogus"),
+            None
+        );
+    }
+}
@@ -15,16 +15,35 @@ pub enum LoopState {
     Running,
     Sleeping,
     Waiting,
+    Paused,
     Stopped,
     Error,
 }
 
+impl LoopState {
+    /// Whether a loop may move from `self` to `next`.
+    ///
+    /// Pausing is legal from any live state (`Running`, `Sleeping` between
+    /// iterations, or `Waiting`); a paused loop can only resume
+    /// (`Running`) or be stopped. Other transitions are unrestricted.
+    pub fn can_transition_to(self, next: LoopState) -> bool {
+        match (self, next) {
+            (Self::Running | Self::Sleeping | Self::Waiting, Self::Paused) => true,
+            (_, Self::Paused) => false,
+            (Self::Paused, Self::Running | Self::Stopped) => true,
+            (Self::Paused, _) => false,
+            _ => true,
+        }
+    }
+}
+
 impl fmt::Display for LoopState {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let s = match self {
             Self::Running => "running",
             Self::Sleeping => "sleeping",
             Self::Waiting => "waiting",
+            Self::Paused => "paused",
             Self::Stopped => "stopped",
             Self::Error => "error",
         };
@@ -167,6 +186,20 @@ mod tests {
         assert_eq!(LoopState::Running.to_string(), "running");
         assert_eq!(LoopState::Stopped.to_string(), "stopped");
         assert_eq!(LoopState::Error.to_string(), "error");
+        assert_eq!(LoopState::Paused.to_string(), "paused");
+    }
+
+    #[test]
+    fn loop_state_pause_transitions() {
+        assert!(LoopState::Running.can_transition_to(LoopState::Paused));
+        assert!(LoopState::Paused.can_transition_to(LoopState::Running));
+        assert!(LoopState::Paused.can_transition_to(LoopState::Stopped));
+        assert!(!LoopState::Paused.can_transition_to(LoopState::Sleeping));
+        assert!(!LoopState::Stopped.can_transition_to(LoopState::Paused));
+        assert!(LoopState::Sleeping.can_transition_to(LoopState::Paused));
+        assert!(LoopState::Waiting.can_transition_to(LoopState::Paused));
+        assert!(!LoopState::Error.can_transition_to(LoopState::Paused));
+        assert!(LoopState::Sleeping.can_transition_to(LoopState::Running));
     }
 
     #[test]
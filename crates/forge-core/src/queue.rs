@@ -46,10 +46,154 @@ pub enum QueueItemType {
     Conditional,
 }
 
+/// Default number of failed dispatch attempts before an item is dead-lettered.
+pub const DEFAULT_MAX_ATTEMPTS: u32 = 5;
+
+/// Processing status of an agent queue item.
+///
+/// `DeadLetter` is terminal: the item failed `max_attempts` times and is kept
+/// aside with its last failure reason instead of being retried. The
+/// `queue_items.status` column accepts `dead_letter` from migration 024.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum QueueItemStatus {
+    Pending,
+    Dispatched,
+    Completed,
+    Failed,
+    Skipped,
+    DeadLetter,
+}
+
+impl QueueItemStatus {
+    /// Whether no further dispatch attempts will be made.
+    pub fn is_terminal(self) -> bool {
+        matches!(self, Self::Completed | Self::Skipped | Self::DeadLetter)
+    }
+}
+
+impl fmt::Display for QueueItemStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::Pending => "pending",
+            Self::Dispatched => "dispatched",
+            Self::Completed => "completed",
+            Self::Failed => "failed",
+            Self::Skipped => "skipped",
+            Self::DeadLetter => "dead_letter",
+        };
+        f.write_str(s)
+    }
+}
+
+/// Dispatch bookkeeping for an agent queue item (`queue_items.attempts`,
+/// added in migration 003).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueueItem {
+    pub id: String,
+    pub item_type: QueueItemType,
+    pub status: QueueItemStatus,
+    pub attempts: u32,
+    pub max_attempts: u32,
+    pub error: Option<String>,
+}
+
+impl QueueItem {
+    pub fn new(id: impl Into<String>, item_type: QueueItemType) -> Self {
+        Self {
+            id: id.into(),
+            item_type,
+            status: QueueItemStatus::Pending,
+            attempts: 0,
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            error: None,
+        }
+    }
+}
+
+/// Whether `item` has used up its attempts and should move to the dead-letter
+/// state. A `max_attempts` of zero falls back to [`DEFAULT_MAX_ATTEMPTS`].
+pub fn should_dead_letter(item: &QueueItem) -> bool {
+    if item.status.is_terminal() {
+        return false;
+    }
+    let max_attempts = if item.max_attempts == 0 {
+        DEFAULT_MAX_ATTEMPTS
+    } else {
+        item.max_attempts
+    };
+    item.attempts >= max_attempts
+}
+
+/// Record a failed dispatch attempt.
+///
+/// The item goes back to `Pending` for another attempt, or to `DeadLetter`
+/// once the attempt count reaches the threshold. The failure reason is kept
+/// either way. Returns the resulting status.
+pub fn record_failed_attempt(item: &mut QueueItem, reason: &str) -> QueueItemStatus {
+    if item.status.is_terminal() {
+        return item.status;
+    }
+    item.attempts = item.attempts.saturating_add(1);
+    item.error = Some(reason.to_string());
+    item.status = if should_dead_letter(item) {
+        QueueItemStatus::DeadLetter
+    } else {
+        QueueItemStatus::Pending
+    };
+    item.status
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn item_dead_letters_exactly_at_threshold() {
+        let mut item = QueueItem::new("qi-1", QueueItemType::Message);
+        item.max_attempts = 3;
+
+        assert_eq!(
+            record_failed_attempt(&mut item, "agent busy"),
+            QueueItemStatus::Pending
+        );
+        assert!(!should_dead_letter(&item));
+        assert_eq!(
+            record_failed_attempt(&mut item, "agent busy"),
+            QueueItemStatus::Pending
+        );
+        assert!(!should_dead_letter(&item));
+        assert_eq!(item.attempts, 2);
+
+        assert_eq!(
+            record_failed_attempt(&mut item, "pane gone"),
+            QueueItemStatus::DeadLetter
+        );
+        assert_eq!(item.attempts, 3);
+        assert_eq!(item.error.as_deref(), Some("pane gone"));
+        assert_eq!(item.status.to_string(), "dead_letter");
+
+        // Terminal: further failures change nothing.
+        assert_eq!(
+            record_failed_attempt(&mut item, "again"),
+            QueueItemStatus::DeadLetter
+        );
+        assert_eq!(item.attempts, 3);
+        assert_eq!(item.error.as_deref(), Some("pane gone"));
+    }
+
+    #[test]
+    fn zero_max_attempts_uses_default() {
+        let mut item = QueueItem::new("qi-2", QueueItemType::Pause);
+        item.max_attempts = 0;
+        item.attempts = DEFAULT_MAX_ATTEMPTS - 1;
+        assert!(!should_dead_letter(&item));
+        item.attempts = DEFAULT_MAX_ATTEMPTS;
+        assert!(should_dead_letter(&item));
+
+        item.status = QueueItemStatus::Completed;
+        assert!(!should_dead_letter(&item));
+    }
+
     #[test]
     fn loop_queue_item_type_display() {
         assert_eq!(LoopQueueItemType::Message.to_string(), "message_append");
@@ -1,20 +1,30 @@
 //! Validation framework for composable error aggregation.
 //!
 //! Mirrors Go `internal/models/validation.go` — collects multiple field-level
-//! errors into one report.
+//! errors into one report. Field names are dotted paths
+//! (`config.ssh.timeout_seconds`) so nested validators can be composed with
+//! [`ValidationErrors::merge_prefixed`].
 
 use std::fmt;
 
 /// A single validation error tied to a field.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ValidationError {
+    /// Dotted field path, e.g. `node_defaults.ssh_timeout`.
     pub field: String,
+    /// Machine-readable error code (e.g. `required`, `out_of_range`); empty
+    /// when the caller did not supply one.
+    pub code: String,
     pub message: String,
 }
 
 impl fmt::Display for ValidationError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}: {}", self.field, self.message)
+        if self.field.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{}: {}", self.field, self.message)
+        }
     }
 }
 
@@ -32,12 +42,47 @@ impl ValidationErrors {
 
     /// Add a field-level validation error.
     pub fn add(&mut self, field: impl Into<String>, message: impl Into<String>) {
+        self.add_with_code(field, "", message);
+    }
+
+    /// Add a field-level validation error with a machine-readable code.
+    pub fn add_with_code(
+        &mut self,
+        field: impl Into<String>,
+        code: impl Into<String>,
+        message: impl Into<String>,
+    ) {
         self.errors.push(ValidationError {
             field: field.into(),
+            code: code.into(),
             message: message.into(),
         });
     }
 
+    /// Append all errors from `other` unchanged.
+    pub fn merge(&mut self, other: ValidationErrors) {
+        self.errors.extend(other.errors);
+    }
+
+    /// Append all errors from a nested validator, prefixing each field path
+    /// with `prefix` (`ssh` + `timeout_seconds` -> `ssh.timeout_seconds`).
+    pub fn merge_prefixed(&mut self, prefix: &str, other: ValidationErrors) {
+        let prefix = prefix.trim_matches('.');
+        for mut err in other.errors {
+            err.field = join_field_path(prefix, &err.field);
+            self.errors.push(err);
+        }
+    }
+
+    /// `Ok(())` when empty, otherwise `Err(self)`.
+    pub fn into_result(self) -> Result<(), ValidationErrors> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self)
+        }
+    }
+
     /// Returns `true` if there are no errors.
     pub fn is_empty(&self) -> bool {
         self.errors.is_empty()
@@ -54,6 +99,17 @@ impl ValidationErrors {
     }
 }
 
+impl std::error::Error for ValidationErrors {}
+
+fn join_field_path(prefix: &str, field: &str) -> String {
+    match (prefix.is_empty(), field.is_empty()) {
+        (true, _) => field.to_string(),
+        (false, true) => prefix.to_string(),
+        (false, false) if field.starts_with('[') => format!("{prefix}{field}"),
+        (false, false) => format!("{prefix}.{field}"),
+    }
+}
+
 impl fmt::Display for ValidationErrors {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         for (i, e) in self.errors.iter().enumerate() {
@@ -96,5 +152,82 @@ mod tests {
         assert_eq!(errs.len(), 1);
         assert_eq!(errs[0].field, "field1");
         assert_eq!(errs[0].message, "err1");
+        assert_eq!(errs[0].code, "");
+    }
+
+    struct SshSettings {
+        timeout_seconds: i64,
+        backend: String,
+    }
+
+    struct NodeSettings {
+        name: String,
+        ssh: SshSettings,
+    }
+
+    fn validate_ssh(ssh: &SshSettings) -> ValidationErrors {
+        let mut v = ValidationErrors::new();
+        if ssh.timeout_seconds <= 0 {
+            v.add_with_code("timeout_seconds", "out_of_range", "must be greater than 0");
+        }
+        if !matches!(ssh.backend.as_str(), "native" | "system" | "auto") {
+            v.add_with_code(
+                "backend",
+                "invalid_choice",
+                "must be native, system, or auto",
+            );
+        }
+        v
+    }
+
+    fn validate_node(node: &NodeSettings) -> ValidationErrors {
+        let mut v = ValidationErrors::new();
+        if node.name.trim().is_empty() {
+            v.add_with_code("name", "required", "is required");
+        }
+        v.merge_prefixed("ssh", validate_ssh(&node.ssh));
+        v
+    }
+
+    #[test]
+    fn nested_validators_report_all_errors_with_paths() {
+        let node = NodeSettings {
+            name: "node-a".to_string(),
+            ssh: SshSettings {
+                timeout_seconds: 0,
+                backend: "telnet".to_string(),
+            },
+        };
+
+        let mut v = ValidationErrors::new();
+        v.merge_prefixed("config", validate_node(&node));
+
+        let fields: Vec<&str> = v.errors().iter().map(|e| e.field.as_str()).collect();
+        assert_eq!(
+            fields,
+            vec!["config.ssh.timeout_seconds", "config.ssh.backend"]
+        );
+        let codes: Vec<&str> = v.errors().iter().map(|e| e.code.as_str()).collect();
+        assert_eq!(codes, vec!["out_of_range", "invalid_choice"]);
+        assert_eq!(
+            v.to_string(),
+            "config.ssh.timeout_seconds: must be greater than 0; \
+             config.ssh.backend: must be native, system, or auto"
+        );
+        assert!(v.into_result().is_err());
+    }
+
+    #[test]
+    fn merge_prefixed_handles_index_and_empty_paths() {
+        let mut inner = ValidationErrors::new();
+        inner.add("[2].provider", "is required");
+        inner.add("", "list is empty");
+
+        let mut v = ValidationErrors::new();
+        v.merge_prefixed("accounts", inner);
+        let fields: Vec<&str> = v.errors().iter().map(|e| e.field.as_str()).collect();
+        assert_eq!(fields, vec!["accounts[2].provider", "accounts"]);
+
+        assert!(ValidationErrors::new().into_result().is_ok());
     }
 }
@@ -1,24 +1,43 @@
 //! Shared entrypoint implementation for daemon binaries (`forged`, `rforged`).
 
+use std::collections::BTreeMap;
 use std::future::Future;
 use std::net::{SocketAddr, ToSocketAddrs};
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Duration;
 
 use forge_daemon::agent::AgentManager;
 use forge_daemon::bootstrap::{build_daemon_options, init_logger, DaemonArgs, VersionInfo};
+use forge_daemon::database::SharedDb;
+use forge_daemon::events::EventBus;
+use forge_daemon::health::HealthService;
+use forge_daemon::loop_runner::{LoopRunner, LoopRunnerManager, LoopRunnerState};
+use forge_daemon::node_registry::{
+    NodeHealth, NodeHealthTracker, TcpReachabilityProbe, DEFAULT_PROBE_INTERVAL,
+};
 use forge_daemon::server::ForgedAgentService;
+use forge_daemon::shutdown::InFlightOps;
 use forge_daemon::tmux::ShellTmuxClient;
+use forge_db::approval_repository::ApprovalRepository;
+use forge_db::metrics_repository::{MetricSample, MetricsRepository, DEFAULT_METRICS_RETENTION};
+use forge_rpc::forged::v1::forged_health_server::ForgedHealthServer;
 use forge_rpc::forged::v1::forged_service_server::ForgedServiceServer;
-use serde::Deserialize;
 use tonic::transport::Server;
 
+/// How often the daemon sweeps pending approvals past their deadline.
+const APPROVAL_EXPIRY_INTERVAL: Duration = Duration::from_secs(30);
+/// How often the daemon drops metric samples older than the retention window.
+const METRICS_PRUNE_INTERVAL: Duration = Duration::from_secs(60 * 60);
+/// How often the daemon records its own gauges into the metrics table.
+const METRICS_SAMPLE_INTERVAL: Duration = Duration::from_secs(60);
+
 pub fn run(process_label: &str) {
     let version = VersionInfo::default();
     let args = parse_args();
 
     // Load merged config: defaults < config file < environment overrides.
-    let (cfg, config_file_used) = match load_forge_config(&args.config_file) {
+    let (cfg, config_file_used, config_warnings) = match load_forge_config(&args.config_file) {
         Ok(value) => value,
         Err(err) => {
             eprintln!("{process_label} failed to load config: {err}");
@@ -29,6 +48,9 @@ pub fn run(process_label: &str) {
     // Build daemon options and logging config from CLI args + config.
     let (opts, log_cfg) = build_daemon_options(&args, &cfg);
     let logger = init_logger(&log_cfg);
+    for warning in &config_warnings {
+        logger.warn_with("config override ignored", &[("error", warning.as_str())]);
+    }
     let config_source = config_file_used
         .as_ref()
         .map(|path| path.display().to_string())
@@ -54,7 +76,19 @@ pub fn run(process_label: &str) {
         &[("bind", &opts.bind_addr()), ("config", &config_source)],
     );
 
-    if let Err(err) = run_grpc_server(process_label, &opts.bind_addr(), &logger) {
+    let db_path = (!opts.disable_database).then(|| PathBuf::from(cfg.database_path()));
+    let shared_db = db_path.as_ref().map(|path| Arc::new(SharedDb::new(path)));
+    let health = build_health_service(shared_db.clone());
+    if let Err(err) = run_grpc_server(
+        process_label,
+        &opts.bind_addr(),
+        health,
+        opts.shutdown_grace,
+        PathBuf::from(&cfg.global.data_dir),
+        db_path,
+        shared_db,
+        &logger,
+    ) {
         logger.error_with(
             &format!("{process_label} failed"),
             &[("error", err.as_str())],
@@ -64,9 +98,24 @@ pub fn run(process_label: &str) {
     }
 }
 
+/// Health checks probe the database on the shared pool's read connections,
+/// so concurrent probes do not queue behind transcript writes.
+fn build_health_service(shared_db: Option<Arc<SharedDb>>) -> HealthService {
+    let health = HealthService::new();
+    match shared_db {
+        Some(db) => health.with_database_probe(move || db.probe()),
+        None => health,
+    }
+}
+
 fn run_grpc_server(
     process_label: &str,
     bind_addr: &str,
+    health: HealthService,
+    shutdown_grace: Duration,
+    data_dir: PathBuf,
+    db_path: Option<PathBuf>,
+    shared_db: Option<Arc<SharedDb>>,
     logger: &forge_daemon::bootstrap::Logger,
 ) -> Result<(), String> {
     let resolved_addr = resolve_bind_addr(bind_addr)?;
@@ -75,10 +124,17 @@ fn run_grpc_server(
     // clear diagnostic instead of a generic tonic transport error.
     check_bind_available(resolved_addr)?;
 
-    let service = ForgedAgentService::new(AgentManager::new(), Arc::new(ShellTmuxClient));
+    let mut service = ForgedAgentService::new(AgentManager::new(), Arc::new(ShellTmuxClient))
+        .with_node_health_dir(&data_dir);
+    if let Some(shared_db) = shared_db {
+        service = service.with_transcript_db(shared_db);
+    }
+    let events = service.event_bus();
     let loop_runners = service.loop_runner_manager();
+    let in_flight = service.in_flight_ops();
     let shutdown_logger = logger.clone();
     let shutdown_label = process_label.to_string();
+    let shutdown_health = health.clone();
 
     logger.info_with(
         &format!("{process_label} gRPC serving"),
@@ -90,19 +146,288 @@ fn run_grpc_server(
         .build()
         .map_err(|err| format!("failed to initialize tokio runtime: {err}"))?;
 
+    let liveness_logger = logger.component("node-liveness");
+    let expiry_logger = logger.component("approval-expiry");
+    let metrics_logger = logger.component("metrics-retention");
+    let sample_logger = logger.component("metrics-sample");
+    let sample_runners = loop_runners.clone();
+    let sample_in_flight = in_flight.clone();
+
     runtime.block_on(async move {
-        let shutdown = async move {
+        tokio::spawn(run_node_liveness_loop(data_dir, events, liveness_logger));
+        if let Some(db_path) = db_path {
+            tokio::spawn(run_approval_expiry_loop(db_path.clone(), expiry_logger));
+            tokio::spawn(run_metrics_sample_loop(
+                db_path.clone(),
+                sample_runners,
+                sample_in_flight,
+                sample_logger,
+            ));
+            tokio::spawn(run_metrics_retention_loop(db_path, metrics_logger));
+        }
+
+        let signal_logger = shutdown_logger.clone();
+        let signal_label = shutdown_label.clone();
+        let signal = async move {
             wait_for_shutdown_signal().await;
-            shutdown_logger.info_with(
-                &format!("{shutdown_label} shutdown signal received"),
+            shutdown_health.set_serving(false);
+            signal_logger.info_with(
+                &format!("{signal_label} shutdown signal received"),
                 &[("signal", "SIGINT/SIGTERM")],
             );
+        };
+        let drain = async move {
+            // The listener is closed and new agent/loop operations are
+            // rejected from here on; wait for the active ones up to the
+            // grace period.
+            let report = in_flight.drain(shutdown_grace).await;
+            shutdown_logger.info_with(
+                &format!("{shutdown_label} in-flight operations drained"),
+                &[
+                    ("drained", &report.drained.to_string()),
+                    ("aborted", &report.aborted.to_string()),
+                    ("elapsed_ms", &report.elapsed.as_millis().to_string()),
+                ],
+            );
             loop_runners.stop_all_loop_runners(true);
             shutdown_logger.info(&format!("{shutdown_label} loop runners drained"));
         };
 
-        serve_with_shutdown(service, resolved_addr, shutdown).await
+        health.set_serving(true);
+        serve_then_drain(
+            service,
+            health,
+            resolved_addr,
+            shutdown_grace,
+            signal,
+            drain,
+        )
+        .await
+    })
+}
+
+/// Periodically probe registered mesh nodes, publishing reachability changes
+/// on the event bus and persisting the health snapshot for GetStatus.
+async fn run_node_liveness_loop(
+    data_dir: PathBuf,
+    events: Arc<EventBus>,
+    logger: forge_daemon::bootstrap::Logger,
+) {
+    let probe = TcpReachabilityProbe::default();
+    let mut tracker = NodeHealthTracker::default();
+    let mut ticker = tokio::time::interval(DEFAULT_PROBE_INTERVAL);
+    loop {
+        ticker.tick().await;
+        let dir = data_dir.clone();
+        let round = tokio::task::spawn_blocking(move || {
+            let result = tracker.probe_registry(&dir, &probe, chrono::Utc::now());
+            (tracker, result)
+        })
+        .await;
+        let (next_tracker, result) = match round {
+            Ok(value) => value,
+            Err(err) => {
+                logger.error_with("node probe task failed", &[("error", &err.to_string())]);
+                return;
+            }
+        };
+        tracker = next_tracker;
+        match result {
+            Ok(changes) => {
+                for event in changes {
+                    events.publish_node_health_changed(
+                        &event.node_id,
+                        event.current == NodeHealth::Reachable,
+                        event.consecutive_failures,
+                        &event.reason,
+                    );
+                    logger.warn_with(
+                        "node reachability changed",
+                        &[
+                            ("node_id", &event.node_id),
+                            ("health", &format!("{:?}", event.current).to_lowercase()),
+                            ("reason", &event.reason),
+                        ],
+                    );
+                }
+            }
+            Err(err) => logger.warn_with("node probe round failed", &[("error", &err)]),
+        }
+    }
+}
+
+/// Periodically auto-deny pending approvals whose deadline has passed.
+async fn run_approval_expiry_loop(db_path: PathBuf, logger: forge_daemon::bootstrap::Logger) {
+    let mut db = LazyDb::new(db_path);
+    let mut ticker = tokio::time::interval(APPROVAL_EXPIRY_INTERVAL);
+    loop {
+        ticker.tick().await;
+        let round = tokio::task::spawn_blocking(move || {
+            let result = expire_overdue_approvals(&mut db);
+            (db, result)
+        })
+        .await;
+        let (next_db, result) = match round {
+            Ok(value) => value,
+            Err(err) => {
+                logger.error_with(
+                    "approval expiry task failed",
+                    &[("error", &err.to_string())],
+                );
+                return;
+            }
+        };
+        db = next_db;
+        match result {
+            Ok(0) => {}
+            Ok(expired) => logger.info_with(
+                "expired pending approvals",
+                &[("count", &expired.to_string())],
+            ),
+            Err(err) => logger.warn_with("approval expiry failed", &[("error", &err)]),
+        }
+    }
+}
+
+fn expire_overdue_approvals(db: &mut LazyDb) -> Result<usize, String> {
+    // Nothing to expire until the CLI has created and migrated the database.
+    let Some(db) = db.get()? else {
+        return Ok(0);
+    };
+    let now = chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true);
+    ApprovalRepository::new(db)
+        .expire_pending(&now)
+        .map_err(|err| err.to_string())
+}
+
+/// Periodically drop metric samples past [`DEFAULT_METRICS_RETENTION`].
+async fn run_metrics_retention_loop(db_path: PathBuf, logger: forge_daemon::bootstrap::Logger) {
+    let mut ticker = tokio::time::interval(METRICS_PRUNE_INTERVAL);
+    loop {
+        ticker.tick().await;
+        let path = db_path.clone();
+        let round = tokio::task::spawn_blocking(move || prune_expired_metrics(&path)).await;
+        match round {
+            Ok(Ok(0)) => {}
+            Ok(Ok(pruned)) => logger.info_with(
+                "pruned expired metric samples",
+                &[("count", &pruned.to_string())],
+            ),
+            Ok(Err(err)) => logger.warn_with("metrics prune failed", &[("error", &err)]),
+            Err(err) => {
+                logger.error_with("metrics prune task failed", &[("error", &err.to_string())]);
+                return;
+            }
+        }
+    }
+}
+
+fn prune_expired_metrics(db_path: &std::path::Path) -> Result<usize, String> {
+    if !db_path.is_file() {
+        return Ok(0);
+    }
+    let db = forge_db::Db::open(forge_db::Config::new(db_path)).map_err(|err| err.to_string())?;
+    MetricsRepository::new(&db)
+        .prune_expired(DEFAULT_METRICS_RETENTION)
+        .map_err(|err| err.to_string())
+}
+
+/// Database handle opened on first use and kept across rounds of a background
+/// loop. Stays closed until the CLI has created the database file.
+struct LazyDb {
+    path: PathBuf,
+    db: Option<forge_db::Db>,
+}
+
+impl LazyDb {
+    fn new(path: PathBuf) -> Self {
+        Self { path, db: None }
+    }
+
+    fn get(&mut self) -> Result<Option<&forge_db::Db>, String> {
+        if self.db.is_none() {
+            if !self.path.is_file() {
+                return Ok(None);
+            }
+            let db = forge_db::Db::open(forge_db::Config::new(&self.path))
+                .map_err(|err| err.to_string())?;
+            self.db = Some(db);
+        }
+        Ok(self.db.as_ref())
+    }
+}
+
+/// Periodically record daemon gauges: loop runners by state and in-flight
+/// operations.
+async fn run_metrics_sample_loop(
+    db_path: PathBuf,
+    loop_runners: LoopRunnerManager,
+    in_flight: InFlightOps,
+    logger: forge_daemon::bootstrap::Logger,
+) {
+    let mut db = LazyDb::new(db_path);
+    let mut ticker = tokio::time::interval(METRICS_SAMPLE_INTERVAL);
+    loop {
+        ticker.tick().await;
+        let samples = daemon_gauge_samples(&loop_runners.list_loop_runners(), in_flight.active());
+        let round = tokio::task::spawn_blocking(move || {
+            let result = record_metric_samples(&mut db, samples);
+            (db, result)
+        })
+        .await;
+        let (next_db, result) = match round {
+            Ok(value) => value,
+            Err(err) => {
+                logger.error_with("metrics sample task failed", &[("error", &err.to_string())]);
+                return;
+            }
+        };
+        db = next_db;
+        if let Err(err) = result {
+            logger.warn_with("metrics sample failed", &[("error", &err)]);
+        }
+    }
+}
+
+fn daemon_gauge_samples(runners: &[LoopRunner], in_flight: usize) -> Vec<MetricSample> {
+    let mut samples = Vec::new();
+    for (state, label) in [
+        (LoopRunnerState::Running, "running"),
+        (LoopRunnerState::Paused, "paused"),
+        (LoopRunnerState::Stopped, "stopped"),
+        (LoopRunnerState::Error, "error"),
+    ] {
+        let count = runners
+            .iter()
+            .filter(|runner| runner.state == state)
+            .count();
+        samples.push(MetricSample {
+            name: "daemon_loop_runners".to_string(),
+            value: count as f64,
+            tags: BTreeMap::from([("state".to_string(), label.to_string())]),
+            ..Default::default()
+        });
+    }
+    samples.push(MetricSample {
+        name: "daemon_in_flight_ops".to_string(),
+        value: in_flight as f64,
+        ..Default::default()
+    });
+    samples
+}
+
+fn record_metric_samples(db: &mut LazyDb, samples: Vec<MetricSample>) -> Result<(), String> {
+    let Some(db) = db.get()? else {
+        return Ok(());
+    };
+    db.immediate_transaction(|db| {
+        let repo = MetricsRepository::new(db);
+        for mut sample in samples {
+            repo.record(&mut sample)?;
+        }
+        Ok(())
     })
+    .map_err(|err| err.to_string())
 }
 
 fn check_bind_available(addr: SocketAddr) -> Result<(), String> {
@@ -136,6 +461,7 @@ fn resolve_bind_addr(bind_addr: &str) -> Result<SocketAddr, String> {
 
 async fn serve_with_shutdown<F>(
     service: ForgedAgentService,
+    health: HealthService,
     bind_addr: SocketAddr,
     shutdown: F,
 ) -> Result<(), String>
@@ -144,11 +470,58 @@ where
 {
     Server::builder()
         .add_service(ForgedServiceServer::new(service))
+        .add_service(ForgedHealthServer::new(health))
         .serve_with_shutdown(bind_addr, shutdown)
         .await
         .map_err(|err| format!("gRPC server failed: {err}"))
 }
 
+/// Serve until `signal` resolves, then close the listener and run `drain`
+/// while the connections already open finish, so no new connection arrives
+/// during the drain.
+///
+/// Graceful shutdown waits for every open RPC, including long-lived streams,
+/// so the server is aborted once `grace` has passed since the signal.
+async fn serve_then_drain<S, D>(
+    service: ForgedAgentService,
+    health: HealthService,
+    bind_addr: SocketAddr,
+    grace: Duration,
+    signal: S,
+    drain: D,
+) -> Result<(), String>
+where
+    S: Future<Output = ()> + Send + 'static,
+    D: Future<Output = ()>,
+{
+    let (stopped_tx, stopped_rx) = tokio::sync::oneshot::channel::<()>();
+    let mut server = tokio::spawn(serve_with_shutdown(
+        service,
+        health,
+        bind_addr,
+        async move {
+            signal.await;
+            let _ = stopped_tx.send(());
+        },
+    ));
+    // A closed channel means the server stopped on its own (e.g. an error).
+    if stopped_rx.await.is_err() {
+        return server
+            .await
+            .map_err(|err| format!("gRPC server task failed: {err}"))?;
+    }
+    let deadline = tokio::time::Instant::now() + grace;
+    drain.await;
+    match tokio::time::timeout_at(deadline, &mut server).await {
+        Ok(joined) => joined.map_err(|err| format!("gRPC server task failed: {err}"))?,
+        Err(_) => {
+            // Streams still open past the deadline are cut off.
+            server.abort();
+            Ok(())
+        }
+    }
+}
+
 async fn wait_for_shutdown_signal() {
     #[cfg(unix)]
     {
@@ -174,180 +547,22 @@ async fn wait_for_shutdown_signal() {
     }
 }
 
-fn load_forge_config(
-    config_file: &str,
-) -> Result<(forge_core::config::Config, Option<PathBuf>), String> {
-    load_forge_config_with_env(config_file, |key| std::env::var(key).ok())
-}
-
-fn load_forge_config_with_env<F>(
-    config_file: &str,
-    mut env_lookup: F,
-) -> Result<(forge_core::config::Config, Option<PathBuf>), String>
-where
-    F: FnMut(&str) -> Option<String>,
-{
-    let mut cfg = forge_core::config::Config::default();
-    let explicit = (!config_file.trim().is_empty()).then(|| PathBuf::from(config_file.trim()));
-    let path_to_try = explicit
-        .clone()
-        .or_else(forge_core::config::find_config_file);
-    let mut loaded_path: Option<PathBuf> = None;
-
-    if let Some(path) = path_to_try {
-        match std::fs::read_to_string(&path) {
-            Ok(raw) => {
-                apply_partial_yaml_config(&mut cfg, &raw)?;
-                loaded_path = Some(path);
-            }
-            Err(err) => {
-                if explicit.is_some() || err.kind() != std::io::ErrorKind::NotFound {
-                    return Err(format!("failed to load config file: {err}"));
-                }
-            }
-        }
-    }
-
-    apply_env_overrides_with(&mut cfg, &mut env_lookup);
-    cfg.expand_paths();
-    cfg.validate()
-        .map_err(|err| format!("config validation failed: {err}"))?;
-
-    Ok((cfg, loaded_path))
-}
-
-fn apply_partial_yaml_config(
-    cfg: &mut forge_core::config::Config,
-    raw: &str,
-) -> Result<(), String> {
-    let parsed: PartialConfig =
-        serde_yaml::from_str(raw).map_err(|err| format!("parse config: {err}"))?;
-
-    if !parsed.global.data_dir.trim().is_empty() {
-        cfg.global.data_dir = parsed.global.data_dir;
-    }
-    if !parsed.global.config_dir.trim().is_empty() {
-        cfg.global.config_dir = parsed.global.config_dir;
-    }
-    if let Some(value) = parsed.global.auto_register_local_node {
-        cfg.global.auto_register_local_node = value;
-    }
-
-    if !parsed.logging.level.trim().is_empty() {
-        cfg.logging.level = parsed.logging.level;
-    }
-    if !parsed.logging.format.trim().is_empty() {
-        cfg.logging.format = parsed.logging.format;
-    }
-    if !parsed.logging.file.trim().is_empty() {
-        cfg.logging.file = parsed.logging.file;
-    }
-    if let Some(value) = parsed.logging.enable_caller {
-        cfg.logging.enable_caller = value;
-    }
+type LoadedConfig = (forge_core::config::Config, Option<PathBuf>, Vec<String>);
 
-    Ok(())
+fn load_forge_config(config_file: &str) -> Result<LoadedConfig, String> {
+    load_forge_config_with_env(config_file, |key| std::env::var(key).ok())
 }
 
-fn apply_env_overrides_with<F>(cfg: &mut forge_core::config::Config, env_lookup: &mut F)
+/// Env overrides that do not parse (`FORGE_LOGGING_ENABLE_CALLER=maybe`) are
+/// returned as warnings and the setting keeps its file/default value, so a
+/// typo in the environment never stops the daemon from starting.
+fn load_forge_config_with_env<F>(config_file: &str, env_lookup: F) -> Result<LoadedConfig, String>
 where
     F: FnMut(&str) -> Option<String>,
 {
-    if let Some(value) = env_value(
-        env_lookup,
-        &["FORGE_GLOBAL_DATA_DIR", "SWARM_GLOBAL_DATA_DIR"],
-    ) {
-        cfg.global.data_dir = value;
-    }
-    if let Some(value) = env_value(
-        env_lookup,
-        &["FORGE_GLOBAL_CONFIG_DIR", "SWARM_GLOBAL_CONFIG_DIR"],
-    ) {
-        cfg.global.config_dir = value;
-    }
-    if let Some(value) = env_value(
-        env_lookup,
-        &[
-            "FORGE_GLOBAL_AUTO_REGISTER_LOCAL_NODE",
-            "SWARM_GLOBAL_AUTO_REGISTER_LOCAL_NODE",
-        ],
-    ) {
-        if let Some(parsed) = parse_bool_value(&value) {
-            cfg.global.auto_register_local_node = parsed;
-        }
-    }
-
-    if let Some(value) = env_value(env_lookup, &["FORGE_LOGGING_LEVEL", "SWARM_LOGGING_LEVEL"]) {
-        cfg.logging.level = value;
-    }
-    if let Some(value) = env_value(
-        env_lookup,
-        &["FORGE_LOGGING_FORMAT", "SWARM_LOGGING_FORMAT"],
-    ) {
-        cfg.logging.format = value;
-    }
-    if let Some(value) = env_value(env_lookup, &["FORGE_LOGGING_FILE", "SWARM_LOGGING_FILE"]) {
-        cfg.logging.file = value;
-    }
-    if let Some(value) = env_value(
-        env_lookup,
-        &["FORGE_LOGGING_ENABLE_CALLER", "SWARM_LOGGING_ENABLE_CALLER"],
-    ) {
-        if let Some(parsed) = parse_bool_value(&value) {
-            cfg.logging.enable_caller = parsed;
-        }
-    }
-}
-
-fn env_value<F>(env_lookup: &mut F, keys: &[&str]) -> Option<String>
-where
-    F: FnMut(&str) -> Option<String>,
-{
-    for key in keys {
-        if let Some(value) = env_lookup(key) {
-            let trimmed = value.trim();
-            if !trimmed.is_empty() {
-                return Some(trimmed.to_string());
-            }
-        }
-    }
-    None
-}
-
-fn parse_bool_value(value: &str) -> Option<bool> {
-    match value.trim().to_lowercase().as_str() {
-        "1" | "true" | "yes" | "on" => Some(true),
-        "0" | "false" | "no" | "off" => Some(false),
-        _ => None,
-    }
-}
-
-#[derive(Debug, Default, Deserialize)]
-struct PartialConfig {
-    #[serde(default)]
-    global: PartialGlobalConfig,
-    #[serde(default)]
-    logging: PartialLoggingConfig,
-}
-
-#[derive(Debug, Default, Deserialize)]
-struct PartialGlobalConfig {
-    #[serde(default)]
-    data_dir: String,
-    #[serde(default)]
-    config_dir: String,
-    auto_register_local_node: Option<bool>,
-}
-
-#[derive(Debug, Default, Deserialize)]
-struct PartialLoggingConfig {
-    #[serde(default)]
-    level: String,
-    #[serde(default)]
-    format: String,
-    #[serde(default)]
-    file: String,
-    enable_caller: Option<bool>,
+    let (resolved, loaded_path) = forge_core::config::load_layered_config(config_file, env_lookup)?;
+    let warnings = resolved.warnings().to_vec();
+    Ok((resolved.config, loaded_path, warnings))
 }
 
 fn parse_args() -> DaemonArgs {
@@ -411,6 +626,13 @@ fn parse_args() -> DaemonArgs {
             "--disk-pause" => {
                 args.disk_pause = true;
             }
+            "--shutdown-grace" => {
+                if let Some(v) = iter.next() {
+                    if let Ok(secs) = v.parse::<u64>() {
+                        args.shutdown_grace_seconds = secs;
+                    }
+                }
+            }
             _ => {} // Ignore unknown flags for forward-compatibility.
         }
     }
@@ -424,15 +646,20 @@ mod tests {
     use std::sync::Arc;
     use std::time::Duration;
 
-    use forge_daemon::agent::AgentManager;
+    use forge_daemon::agent::{AgentInfo, AgentManager, AgentState};
+    use forge_daemon::health::HealthService;
     use forge_daemon::server::ForgedAgentService;
     use forge_daemon::tmux::TmuxClient;
+    use forge_daemon::transcript::TranscriptStore;
+    use forge_db::metrics_repository::{MetricSample, MetricsRepository};
     use forge_rpc::forged::v1 as proto;
     use forge_rpc::forged::v1::forged_service_client::ForgedServiceClient;
     use tonic::transport::Channel;
 
     use super::{
-        check_bind_available, load_forge_config_with_env, resolve_bind_addr, serve_with_shutdown,
+        check_bind_available, daemon_gauge_samples, expire_overdue_approvals,
+        load_forge_config_with_env, prune_expired_metrics, record_metric_samples,
+        resolve_bind_addr, serve_then_drain, serve_with_shutdown, LazyDb, SharedDb,
     };
 
     struct NoopTmux;
@@ -500,10 +727,11 @@ logging:
 "#,
         );
 
-        let (cfg, used_path) = match load_forge_config_with_env(&file.to_string_lossy(), |_| None) {
-            Ok(value) => value,
-            Err(err) => panic!("expected config load to succeed: {err}"),
-        };
+        let (cfg, used_path, _) =
+            match load_forge_config_with_env(&file.to_string_lossy(), |_| None) {
+                Ok(value) => value,
+                Err(err) => panic!("expected config load to succeed: {err}"),
+            };
 
         assert_eq!(cfg.global.data_dir, "/tmp/forge-data");
         assert_eq!(cfg.logging.level, "debug");
@@ -538,7 +766,7 @@ logging:
             ("FORGE_LOGGING_LEVEL".to_string(), "warn".to_string()),
         ]);
 
-        let (cfg, _) = match load_forge_config_with_env(&file.to_string_lossy(), |key| {
+        let (cfg, _, _) = match load_forge_config_with_env(&file.to_string_lossy(), |key| {
             env_map.get(key).cloned()
         }) {
             Ok(value) => value,
@@ -551,6 +779,31 @@ logging:
         let _ = std::fs::remove_file(file);
     }
 
+    #[test]
+    fn load_config_invalid_env_override_warns_and_keeps_default() {
+        let env_map = std::collections::HashMap::from([
+            (
+                "FORGE_LOGGING_ENABLE_CALLER".to_string(),
+                "maybe".to_string(),
+            ),
+            ("FORGE_LOGGING_LEVEL".to_string(), "warn".to_string()),
+        ]);
+
+        let (cfg, _, warnings) =
+            match load_forge_config_with_env("", |key| env_map.get(key).cloned()) {
+                Ok(value) => value,
+                Err(err) => panic!("expected config load to succeed: {err}"),
+            };
+
+        assert_eq!(
+            cfg.logging.enable_caller,
+            forge_core::config::Config::default().logging.enable_caller
+        );
+        assert_eq!(cfg.logging.level, "warn");
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("logging.enable_caller"));
+    }
+
     #[test]
     fn load_config_explicit_missing_file_returns_error() {
         let missing_path = unique_temp_path("missing-config");
@@ -575,9 +828,10 @@ logging:
         drop(listener);
 
         let service = ForgedAgentService::new(AgentManager::new(), Arc::new(NoopTmux));
+        let health = HealthService::new().with_tmux_probe(|| Ok(()));
         let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<()>();
         let serve_task = tokio::spawn(async move {
-            serve_with_shutdown(service, bind_addr, async move {
+            serve_with_shutdown(service, health, bind_addr, async move {
                 let _ = shutdown_rx.await;
             })
             .await
@@ -606,6 +860,267 @@ logging:
         }
     }
 
+    #[tokio::test]
+    async fn serve_then_drain_closes_listener_before_draining() {
+        let listener = match std::net::TcpListener::bind("127.0.0.1:0") {
+            Ok(listener) => listener,
+            Err(err) => panic!("failed to reserve local test port: {err}"),
+        };
+        let bind_addr = match listener.local_addr() {
+            Ok(addr) => addr,
+            Err(err) => panic!("failed to inspect reserved local test port: {err}"),
+        };
+        drop(listener);
+
+        let service = ForgedAgentService::new(AgentManager::new(), Arc::new(NoopTmux));
+        let in_flight = service.in_flight_ops();
+        let op = match in_flight.try_begin() {
+            Some(guard) => guard,
+            None => panic!("expected op to start"),
+        };
+        let health = HealthService::new().with_tmux_probe(|| Ok(()));
+        let (signal_tx, signal_rx) = tokio::sync::oneshot::channel::<()>();
+        let (drain_started_tx, drain_started_rx) = tokio::sync::oneshot::channel::<()>();
+        let serve_task = tokio::spawn(async move {
+            serve_then_drain(
+                service,
+                health,
+                bind_addr,
+                Duration::from_secs(5),
+                async move {
+                    let _ = signal_rx.await;
+                },
+                async move {
+                    let _ = drain_started_tx.send(());
+                    in_flight.drain(Duration::from_secs(5)).await;
+                },
+            )
+            .await
+        });
+
+        let mut client = match connect_with_retry(bind_addr).await {
+            Ok(client) => client,
+            Err(err) => panic!("failed to connect test client: {err}"),
+        };
+        if let Err(err) = client.ping(proto::PingRequest {}).await {
+            panic!("ping request failed: {err}");
+        }
+
+        if signal_tx.send(()).is_err() {
+            panic!("failed to trigger test shutdown");
+        }
+        if drain_started_rx.await.is_err() {
+            panic!("drain never started");
+        }
+        let mut refused = false;
+        for _ in 0..40 {
+            if tokio::net::TcpStream::connect(bind_addr).await.is_err() {
+                refused = true;
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(25)).await;
+        }
+        assert!(refused, "listener still accepting while draining");
+        assert!(!serve_task.is_finished());
+
+        drop(client);
+        drop(op);
+        let serve_result = match serve_task.await {
+            Ok(res) => res,
+            Err(err) => panic!("serve task panicked: {err}"),
+        };
+        if let Err(err) = serve_result {
+            panic!("serve_then_drain returned error: {err}");
+        }
+    }
+
+    #[tokio::test]
+    async fn serve_then_drain_aborts_streams_still_open_after_grace() {
+        let listener = match std::net::TcpListener::bind("127.0.0.1:0") {
+            Ok(listener) => listener,
+            Err(err) => panic!("failed to reserve local test port: {err}"),
+        };
+        let bind_addr = match listener.local_addr() {
+            Ok(addr) => addr,
+            Err(err) => panic!("failed to inspect reserved local test port: {err}"),
+        };
+        drop(listener);
+
+        let agents = AgentManager::new();
+        let now = chrono::Utc::now();
+        agents.register(AgentInfo {
+            id: "a1".to_string(),
+            workspace_id: "ws1".to_string(),
+            state: AgentState::Running,
+            pane_id: "sess:a1.0".to_string(),
+            pid: 42,
+            command: "claude".to_string(),
+            adapter: "claude_code".to_string(),
+            spawned_at: now,
+            last_activity_at: now,
+            content_hash: String::new(),
+            transcript: TranscriptStore::new(),
+        });
+        let service = ForgedAgentService::new(agents, Arc::new(NoopTmux));
+        let health = HealthService::new().with_tmux_probe(|| Ok(()));
+        let (signal_tx, signal_rx) = tokio::sync::oneshot::channel::<()>();
+        let serve_task = tokio::spawn(async move {
+            serve_then_drain(
+                service,
+                health,
+                bind_addr,
+                Duration::from_millis(200),
+                async move {
+                    let _ = signal_rx.await;
+                },
+                async {},
+            )
+            .await
+        });
+
+        let mut client = match connect_with_retry(bind_addr).await {
+            Ok(client) => client,
+            Err(err) => panic!("failed to connect test client: {err}"),
+        };
+        // The agent never exits, so this stream stays open until cut off.
+        let _stream = match client
+            .stream_agent_output(proto::StreamAgentOutputRequest {
+                agent_id: "a1".to_string(),
+                cursor: String::new(),
+            })
+            .await
+        {
+            Ok(response) => response.into_inner(),
+            Err(err) => panic!("open agent output stream: {err}"),
+        };
+
+        if signal_tx.send(()).is_err() {
+            panic!("failed to trigger test shutdown");
+        }
+        let serve_result = match tokio::time::timeout(Duration::from_secs(5), serve_task).await {
+            Ok(Ok(res)) => res,
+            Ok(Err(err)) => panic!("serve task panicked: {err}"),
+            Err(_) => panic!("serve_then_drain hung on an open stream"),
+        };
+        if let Err(err) = serve_result {
+            panic!("serve_then_drain returned error: {err}");
+        }
+    }
+
+    #[test]
+    fn probe_database_runs_query_against_sqlite_file() {
+        let path = unique_temp_path("health-db");
+        if let Err(err) = forge_db::Db::open(forge_db::Config::new(&path)) {
+            panic!("create database: {err}");
+        }
+        assert_eq!(SharedDb::new(&path).probe(), Ok(()));
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn probe_database_rejects_corrupt_file() {
+        let path = unique_temp_path("health-db-corrupt");
+        if let Err(err) = std::fs::write(&path, vec![0x5a_u8; 4096]) {
+            panic!("write corrupt database: {err}");
+        }
+        let err = match SharedDb::new(&path).probe() {
+            Ok(()) => panic!("corrupt database should fail the probe"),
+            Err(err) => err,
+        };
+        assert!(err.contains(&path.display().to_string()), "{err}");
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn prune_expired_metrics_drops_samples_past_retention() {
+        let path = unique_temp_path("metrics-db");
+        assert_eq!(prune_expired_metrics(&path), Ok(0));
+
+        let mut db = match forge_db::Db::open(forge_db::Config::new(&path)) {
+            Ok(db) => db,
+            Err(err) => panic!("create database: {err}"),
+        };
+        if let Err(err) = db.migrate_up() {
+            panic!("migrate database: {err}");
+        }
+        let repo = MetricsRepository::new(&db);
+        for timestamp in ["2020-01-01T00:00:00Z", ""] {
+            let mut sample = MetricSample {
+                name: "queue_latency_ms".to_owned(),
+                value: 1.0,
+                timestamp: timestamp.to_owned(),
+                ..Default::default()
+            };
+            if let Err(err) = repo.record(&mut sample) {
+                panic!("record sample: {err}");
+            }
+        }
+
+        assert_eq!(prune_expired_metrics(&path), Ok(1));
+        match repo.query_range("queue_latency_ms", "", "") {
+            Ok(samples) => assert_eq!(samples.len(), 1),
+            Err(err) => panic!("query samples: {err}"),
+        }
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn daemon_gauges_are_recorded_once_the_database_exists() {
+        let path = unique_temp_path("metrics-sample-db");
+        let mut lazy = LazyDb::new(path.clone());
+        let samples = daemon_gauge_samples(&[], 2);
+        assert_eq!(record_metric_samples(&mut lazy, samples.clone()), Ok(()));
+
+        let mut db = match forge_db::Db::open(forge_db::Config::new(&path)) {
+            Ok(db) => db,
+            Err(err) => panic!("create database: {err}"),
+        };
+        if let Err(err) = db.migrate_up() {
+            panic!("migrate database: {err}");
+        }
+        assert_eq!(record_metric_samples(&mut lazy, samples), Ok(()));
+
+        let repo = MetricsRepository::new(&db);
+        match repo.query_range("daemon_in_flight_ops", "", "") {
+            Ok(found) => assert_eq!(
+                found.iter().map(|sample| sample.value).collect::<Vec<_>>(),
+                vec![2.0]
+            ),
+            Err(err) => panic!("query samples: {err}"),
+        }
+        match repo.query_range("daemon_loop_runners", "", "") {
+            Ok(found) => assert_eq!(found.len(), 4),
+            Err(err) => panic!("query samples: {err}"),
+        }
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn approval_expiry_keeps_one_handle_once_the_database_exists() {
+        let path = unique_temp_path("approval-expiry-db");
+        let mut lazy = LazyDb::new(path.clone());
+        assert_eq!(expire_overdue_approvals(&mut lazy), Ok(0));
+        assert!(lazy.db.is_none());
+
+        let mut db = match forge_db::Db::open(forge_db::Config::new(&path)) {
+            Ok(db) => db,
+            Err(err) => panic!("create database: {err}"),
+        };
+        if let Err(err) = db.migrate_up() {
+            panic!("migrate database: {err}");
+        }
+        assert_eq!(expire_overdue_approvals(&mut lazy), Ok(0));
+        assert!(lazy.db.is_some());
+        assert_eq!(expire_overdue_approvals(&mut lazy), Ok(0));
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn probe_database_reports_missing_file() {
+        let path = unique_temp_path("health-db-missing");
+        assert!(SharedDb::new(&path).probe().is_err());
+    }
+
     #[test]
     fn check_bind_available_succeeds_on_free_port() {
         // Bind to port 0 to get a free port, then check that port is available.
@@ -5,6 +5,9 @@
 
 use std::fmt;
 use std::io::{IsTerminal, Write};
+use std::time::Duration;
+
+use crate::shutdown::DEFAULT_SHUTDOWN_GRACE;
 
 // ---------------------------------------------------------------------------
 // Constants (mirrors Go internal/forged/constants.go)
@@ -86,6 +89,8 @@ pub struct DaemonOptions {
     pub disk_monitor_config: Option<DiskMonitorConfig>,
     pub default_resource_limits: Option<ResourceLimits>,
     pub disable_database: bool,
+    /// How long shutdown waits for in-flight operations before forcing.
+    pub shutdown_grace: Duration,
 }
 
 impl Default for DaemonOptions {
@@ -100,6 +105,7 @@ impl Default for DaemonOptions {
             disk_monitor_config: None,
             default_resource_limits: None,
             disable_database: false,
+            shutdown_grace: DEFAULT_SHUTDOWN_GRACE,
         }
     }
 }
@@ -536,6 +542,7 @@ pub struct DaemonArgs {
     pub disk_critical: f64,
     pub disk_resume: f64,
     pub disk_pause: bool,
+    pub shutdown_grace_seconds: u64,
 }
 
 impl Default for DaemonArgs {
@@ -552,6 +559,7 @@ impl Default for DaemonArgs {
             disk_critical: disk.critical_percent,
             disk_resume: disk.resume_percent,
             disk_pause: disk.pause_agents,
+            shutdown_grace_seconds: DEFAULT_SHUTDOWN_GRACE.as_secs(),
         }
     }
 }
@@ -599,6 +607,7 @@ pub fn build_daemon_options(
         hostname: args.hostname.clone(),
         port: args.port,
         disk_monitor_config: Some(disk),
+        shutdown_grace: Duration::from_secs(args.shutdown_grace_seconds),
         ..DaemonOptions::default()
     };
 
@@ -843,6 +852,20 @@ mod tests {
         }
     }
 
+    #[test]
+    fn build_daemon_options_applies_shutdown_grace() {
+        let cfg = forge_core::config::Config::default();
+        let (opts, _) = build_daemon_options(&DaemonArgs::default(), &cfg);
+        assert_eq!(opts.shutdown_grace, DEFAULT_SHUTDOWN_GRACE);
+
+        let args = DaemonArgs {
+            shutdown_grace_seconds: 5,
+            ..DaemonArgs::default()
+        };
+        let (opts, _) = build_daemon_options(&args, &cfg);
+        assert_eq!(opts.shutdown_grace, Duration::from_secs(5));
+    }
+
     #[test]
     fn build_daemon_options_uses_config_when_cli_empty() {
         let cfg = forge_core::config::Config::default();
@@ -0,0 +1,113 @@
+//! Database handle shared by the daemon's RPC handlers and health probe.
+//!
+//! The CLI creates and migrates the database, so the pool is opened on first
+//! use once the file exists. Reads (health probes) run on pooled read-only
+//! connections; writes (run transcripts) go through the single writer.
+
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use forge_db::DbPool;
+
+pub struct SharedDb {
+    path: PathBuf,
+    pool: Mutex<Option<Arc<DbPool>>>,
+}
+
+impl SharedDb {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            pool: Mutex::new(None),
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The pool, or `None` while the database file does not exist yet.
+    pub fn pool(&self) -> Result<Option<Arc<DbPool>>, String> {
+        let mut slot = match self.pool.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        if let Some(pool) = slot.as_ref() {
+            return Ok(Some(Arc::clone(pool)));
+        }
+        if !self.path.is_file() {
+            return Ok(None);
+        }
+        let pool = DbPool::open(forge_db::Config::new(&self.path), DbPool::DEFAULT_READERS)
+            .map_err(|err| format!("open {}: {err}", self.path.display()))?;
+        let pool = Arc::new(pool);
+        *slot = Some(Arc::clone(&pool));
+        Ok(Some(pool))
+    }
+
+    /// Health probe: run a query that reads the schema page on a pooled
+    /// reader, so a corrupt or locked file is reported as unhealthy, not
+    /// just a missing one. A bare `SELECT 1` would never touch the file.
+    pub fn probe(&self) -> Result<(), String> {
+        match std::fs::metadata(&self.path) {
+            Ok(meta) if meta.is_file() => {}
+            Ok(_) => return Err(format!("{} is not a file", self.path.display())),
+            Err(err) => return Err(format!("{}: {err}", self.path.display())),
+        }
+        let Some(pool) = self.pool()? else {
+            return Err(format!("{}: not found", self.path.display()));
+        };
+        pool.read(|conn| {
+            conn.query_row("SELECT count(*) FROM sqlite_master", [], |row| {
+                row.get::<_, i64>(0)
+            })?;
+            Ok(())
+        })
+        .map_err(|err| format!("query {}: {err}", self.path.display()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_db_path(tag: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "forge-daemon-shared-db-{tag}-{}-{}.sqlite",
+            std::process::id(),
+            chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default()
+        ))
+    }
+
+    #[test]
+    fn pool_waits_for_the_database_file() {
+        let path = temp_db_path("lazy");
+        let db = SharedDb::new(&path);
+
+        assert!(matches!(db.pool(), Ok(None)));
+        assert!(db.probe().is_err());
+        assert!(!path.exists(), "probe must not create the database");
+
+        let mut created = match forge_db::Db::open(forge_db::Config::new(&path)) {
+            Ok(db) => db,
+            Err(err) => panic!("create db: {err}"),
+        };
+        if let Err(err) = created.migrate_up() {
+            panic!("migrate: {err}");
+        }
+
+        assert_eq!(db.probe(), Ok(()));
+        let first = match db.pool() {
+            Ok(Some(pool)) => pool,
+            other => panic!("expected pool, got {:?}", other.map(|pool| pool.is_some())),
+        };
+        let second = match db.pool() {
+            Ok(Some(pool)) => pool,
+            other => panic!("expected pool, got {:?}", other.map(|pool| pool.is_some())),
+        };
+        assert!(Arc::ptr_eq(&first, &second));
+
+        drop(created);
+        let _ = std::fs::remove_file(path);
+    }
+}
@@ -302,6 +302,58 @@ impl EventBus {
             )),
         });
     }
+
+    /// Publish a loop runner progress event.
+    pub fn publish_loop_progress(
+        &self,
+        loop_id: &str,
+        iteration: i64,
+        sequence: i64,
+        phase: proto::LoopProgressPhase,
+        detail: &str,
+    ) {
+        self.publish(proto::Event {
+            id: String::new(),
+            r#type: proto::EventType::LoopProgress as i32,
+            timestamp: Some(datetime_to_timestamp(Utc::now())),
+            agent_id: String::new(),
+            workspace_id: String::new(),
+            payload: Some(proto::event::Payload::LoopProgress(
+                proto::LoopProgressEvent {
+                    loop_id: loop_id.to_string(),
+                    iteration,
+                    sequence,
+                    phase: phase as i32,
+                    detail: detail.to_string(),
+                },
+            )),
+        });
+    }
+
+    /// Publish a mesh node reachability transition.
+    pub fn publish_node_health_changed(
+        &self,
+        node_id: &str,
+        reachable: bool,
+        consecutive_failures: u32,
+        reason: &str,
+    ) {
+        self.publish(proto::Event {
+            id: String::new(),
+            r#type: proto::EventType::NodeHealthChanged as i32,
+            timestamp: Some(datetime_to_timestamp(Utc::now())),
+            agent_id: String::new(),
+            workspace_id: String::new(),
+            payload: Some(proto::event::Payload::NodeHealthChanged(
+                proto::NodeHealthChangedEvent {
+                    node_id: node_id.to_string(),
+                    reachable,
+                    consecutive_failures: i32::try_from(consecutive_failures).unwrap_or(i32::MAX),
+                    reason: reason.to_string(),
+                },
+            )),
+        });
+    }
 }
 
 /// Check if an event matches a subscriber's filters.
@@ -458,6 +510,25 @@ mod tests {
         assert_eq!(events[events.len() - 1].id, (MAX_STORED_EVENTS + 49) as i64);
     }
 
+    #[test]
+    fn publish_node_health_changed_stores_typed_payload() {
+        let bus = make_bus();
+        bus.publish_node_health_changed("node-a", false, 3, "connect refused");
+
+        let events = bus.events.read().unwrap();
+        let event = &events[0].event;
+        assert_eq!(event.r#type, proto::EventType::NodeHealthChanged as i32);
+        match &event.payload {
+            Some(proto::event::Payload::NodeHealthChanged(payload)) => {
+                assert_eq!(payload.node_id, "node-a");
+                assert!(!payload.reachable);
+                assert_eq!(payload.consecutive_failures, 3);
+                assert_eq!(payload.reason, "connect refused");
+            }
+            other => panic!("unexpected payload: {other:?}"),
+        }
+    }
+
     // -- Subscriber + replay --
 
     #[tokio::test]
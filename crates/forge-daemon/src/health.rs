@@ -0,0 +1,160 @@
+//! Health/readiness gRPC service for orchestrator probes.
+//!
+//! The daemon reports `NOT_SERVING` until bootstrap marks it serving, and
+//! flips back to `NOT_SERVING` once shutdown begins. Readiness checks for the
+//! database and tmux are evaluated on every `Check` call.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use tonic::{Request, Response, Status};
+
+use forge_rpc::forged::v1 as proto;
+use forge_rpc::forged::v1::forged_health_server::ForgedHealth;
+
+use crate::status::default_tmux_health_probe;
+
+type ReadinessProbe = Arc<dyn Fn() -> Result<(), String> + Send + Sync>;
+
+/// Shared health state; clones observe the same serving flag.
+#[derive(Clone)]
+pub struct HealthService {
+    serving: Arc<AtomicBool>,
+    tmux_probe: ReadinessProbe,
+    database_probe: Option<ReadinessProbe>,
+}
+
+impl Default for HealthService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HealthService {
+    pub fn new() -> Self {
+        Self {
+            serving: Arc::new(AtomicBool::new(false)),
+            tmux_probe: Arc::new(default_tmux_health_probe),
+            database_probe: None,
+        }
+    }
+
+    pub fn with_tmux_probe<F>(mut self, probe: F) -> Self
+    where
+        F: Fn() -> Result<(), String> + Send + Sync + 'static,
+    {
+        self.tmux_probe = Arc::new(probe);
+        self
+    }
+
+    /// Register a database connectivity probe. Without one the database
+    /// check is omitted (e.g. `--disable-database`).
+    pub fn with_database_probe<F>(mut self, probe: F) -> Self
+    where
+        F: Fn() -> Result<(), String> + Send + Sync + 'static,
+    {
+        self.database_probe = Some(Arc::new(probe));
+        self
+    }
+
+    pub fn set_serving(&self, serving: bool) {
+        self.serving.store(serving, Ordering::SeqCst);
+    }
+
+    pub fn is_serving(&self) -> bool {
+        self.serving.load(Ordering::SeqCst)
+    }
+
+    pub fn current_status(&self) -> proto::HealthCheckResponse {
+        let now = Utc::now();
+        let mut checks = Vec::new();
+        if let Some(probe) = &self.database_probe {
+            checks.push(readiness_check("database", probe(), now));
+        }
+        checks.push(readiness_check("tmux", (self.tmux_probe)(), now));
+
+        let serving = self.is_serving();
+        let ready = serving
+            && checks
+                .iter()
+                .all(|check| check.health == proto::Health::Healthy as i32);
+        let status = if serving {
+            proto::ServingStatus::Serving
+        } else {
+            proto::ServingStatus::NotServing
+        };
+
+        proto::HealthCheckResponse {
+            status: status as i32,
+            ready,
+            checks,
+        }
+    }
+}
+
+fn readiness_check(
+    name: &str,
+    result: Result<(), String>,
+    now: DateTime<Utc>,
+) -> proto::HealthCheck {
+    let (health, message) = match result {
+        Ok(()) => (proto::Health::Healthy, format!("{name} available")),
+        Err(err) => (proto::Health::Unhealthy, format!("{name} error: {err}")),
+    };
+    proto::HealthCheck {
+        name: name.to_string(),
+        health: health as i32,
+        message,
+        last_check: Some(prost_types::Timestamp {
+            seconds: now.timestamp(),
+            nanos: now.timestamp_subsec_nanos() as i32,
+        }),
+    }
+}
+
+#[tonic::async_trait]
+impl ForgedHealth for HealthService {
+    async fn check(
+        &self,
+        _request: Request<proto::HealthCheckRequest>,
+    ) -> Result<Response<proto::HealthCheckResponse>, Status> {
+        Ok(Response::new(self.current_status()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{proto, HealthService};
+
+    #[test]
+    fn check_reports_not_serving_until_marked() {
+        let health = HealthService::new().with_tmux_probe(|| Ok(()));
+        let resp = health.current_status();
+        assert_eq!(resp.status, proto::ServingStatus::NotServing as i32);
+        assert!(!resp.ready);
+
+        health.clone().set_serving(true);
+        let resp = health.current_status();
+        assert_eq!(resp.status, proto::ServingStatus::Serving as i32);
+        assert!(resp.ready);
+    }
+
+    #[test]
+    fn failing_probe_keeps_serving_but_not_ready() {
+        let health = HealthService::new()
+            .with_tmux_probe(|| Ok(()))
+            .with_database_probe(|| Err("database locked".to_string()));
+        health.set_serving(true);
+
+        let resp = health.current_status();
+        assert_eq!(resp.status, proto::ServingStatus::Serving as i32);
+        assert!(!resp.ready);
+        assert_eq!(resp.checks.len(), 2);
+        assert_eq!(resp.checks[0].name, "database");
+        assert_eq!(resp.checks[0].health, proto::Health::Unhealthy as i32);
+        assert_eq!(resp.checks[0].message, "database error: database locked");
+        assert_eq!(resp.checks[1].name, "tmux");
+        assert_eq!(resp.checks[1].health, proto::Health::Healthy as i32);
+    }
+}
@@ -2,10 +2,13 @@
 
 pub mod agent;
 pub mod bootstrap;
+pub mod database;
 pub mod events;
+pub mod health;
 pub mod loop_runner;
 pub mod node_registry;
 pub mod server;
+pub mod shutdown;
 pub mod status;
 pub mod tmux;
 pub mod transcript;
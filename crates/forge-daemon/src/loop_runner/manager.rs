@@ -1,5 +1,6 @@
 use std::{
     collections::BTreeMap,
+    path::{Path, PathBuf},
     process::{Command, Stdio},
     sync::{Arc, Mutex},
     thread,
@@ -7,8 +8,12 @@ use std::{
 };
 
 use chrono::Utc;
+use forge_core::loop_runner::PAUSE_FILE_ENV_VAR;
 use uuid::Uuid;
 
+use crate::events::EventBus;
+
+use super::progress::{LoopProgressEmitter, PROGRESS_ENV_VAR};
 use super::types::{
     LoopRunner, LoopRunnerError, LoopRunnerState, StartLoopRunnerRequest, StopLoopRunnerResult,
 };
@@ -21,6 +26,7 @@ struct LoopRunnerInfo {
     config_path: String,
     command_path: String,
     pid: i32,
+    pause_file: PathBuf,
     state: LoopRunnerState,
     last_error: String,
     started_at: chrono::DateTime<chrono::Utc>,
@@ -31,6 +37,7 @@ struct LoopRunnerInfo {
 struct Inner {
     loop_runners: BTreeMap<String, LoopRunnerInfo>,
     command_builder: LoopCommandBuilder,
+    events: Option<Arc<EventBus>>,
 }
 
 /// Owns and tracks daemon-spawned loop runner processes.
@@ -57,10 +64,18 @@ impl LoopRunnerManager {
             inner: Arc::new(Mutex::new(Inner {
                 loop_runners: BTreeMap::new(),
                 command_builder,
+                events: None,
             })),
         }
     }
 
+    /// Publish iteration progress reported by runner processes on `events`.
+    ///
+    /// Only runners started after this call report progress.
+    pub fn set_event_bus(&self, events: Arc<EventBus>) {
+        lock_inner(&self.inner).events = Some(events);
+    }
+
     pub fn start_loop_runner(
         &self,
         req: StartLoopRunnerRequest,
@@ -82,30 +97,45 @@ impl LoopRunnerManager {
             let mut guard = lock_inner(&self.inner);
             if let Some(existing) = guard.loop_runners.get_mut(&loop_id) {
                 refresh_loop_runner_locked(existing);
-                if existing.state == LoopRunnerState::Running {
+                if is_live(&existing.state) {
                     return Err(LoopRunnerError::AlreadyExists(loop_id));
                 }
             }
         }
 
-        let mut command = {
+        let instance_id = Uuid::new_v4().to_string();
+        let pause_file = pause_file_path(&instance_id);
+
+        let (mut command, events) = {
             let guard = lock_inner(&self.inner);
-            (guard.command_builder)(&command_path, &args)
+            (
+                (guard.command_builder)(&command_path, &args),
+                guard.events.clone(),
+            )
         };
         command
             .stdin(Stdio::null())
-            .stdout(Stdio::null())
-            .stderr(Stdio::null());
+            .stderr(Stdio::null())
+            .env(PAUSE_FILE_ENV_VAR, &pause_file);
+        if events.is_some() {
+            command.env(PROGRESS_ENV_VAR, "1").stdout(Stdio::piped());
+        } else {
+            command.stdout(Stdio::null());
+        }
 
-        let child = match command.spawn() {
+        let mut child = match command.spawn() {
             Ok(child) => child,
             Err(err) => return Err(LoopRunnerError::StartFailed(err.to_string())),
         };
 
+        if let (Some(events), Some(stdout)) = (events, child.stdout.take()) {
+            let mut emitter = LoopProgressEmitter::new(loop_id.clone(), events);
+            thread::spawn(move || emitter.forward(stdout));
+        }
+
         let pid = child.id() as i32;
 
         let now = Utc::now();
-        let instance_id = Uuid::new_v4().to_string();
 
         {
             let mut guard = lock_inner(&self.inner);
@@ -117,6 +147,7 @@ impl LoopRunnerManager {
                     config_path: config_path.clone(),
                     command_path: command_path.clone(),
                     pid,
+                    pause_file,
                     state: LoopRunnerState::Running,
                     last_error: String::new(),
                     started_at: now,
@@ -141,7 +172,7 @@ impl LoopRunnerManager {
             return Err(LoopRunnerError::InvalidArgument);
         }
 
-        let pid = {
+        let (pid, pause_file) = {
             let mut guard = lock_inner(&self.inner);
             let info = match guard.loop_runners.get_mut(&loop_id) {
                 Some(info) => info,
@@ -150,7 +181,7 @@ impl LoopRunnerManager {
 
             refresh_loop_runner_locked(info);
 
-            if info.state != LoopRunnerState::Running {
+            if !is_live(&info.state) {
                 return Ok(StopLoopRunnerResult {
                     success: true,
                     runner: info_to_runner(info),
@@ -159,12 +190,13 @@ impl LoopRunnerManager {
             if info.child.is_none() {
                 return Err(LoopRunnerError::NoProcessHandle(loop_id));
             }
-            info.pid
+            (info.pid, info.pause_file.clone())
         };
 
         if let Err(err) = stop_loop_runner_process(pid, force) {
             return Err(LoopRunnerError::StopFailed(loop_id, err));
         }
+        let _ = clear_pause_file(&pause_file);
 
         {
             let mut guard = lock_inner(&self.inner);
@@ -181,6 +213,71 @@ impl LoopRunnerManager {
         })
     }
 
+    /// Raise the runner's pause flag. The runner finishes its current
+    /// iteration, then issues no more until [`Self::resume_loop_runner`].
+    pub fn pause_loop_runner(&self, loop_id: &str) -> Result<LoopRunner, LoopRunnerError> {
+        self.transition_loop_runner(
+            loop_id,
+            "pause",
+            LoopRunnerState::Running,
+            LoopRunnerState::Paused,
+        )
+    }
+
+    pub fn resume_loop_runner(&self, loop_id: &str) -> Result<LoopRunner, LoopRunnerError> {
+        self.transition_loop_runner(
+            loop_id,
+            "resume",
+            LoopRunnerState::Paused,
+            LoopRunnerState::Running,
+        )
+    }
+
+    fn transition_loop_runner(
+        &self,
+        loop_id: &str,
+        action: &str,
+        from: LoopRunnerState,
+        to: LoopRunnerState,
+    ) -> Result<LoopRunner, LoopRunnerError> {
+        let loop_id = loop_id.trim().to_string();
+        if loop_id.is_empty() {
+            return Err(LoopRunnerError::InvalidArgument);
+        }
+
+        let mut guard = lock_inner(&self.inner);
+        let info = match guard.loop_runners.get_mut(&loop_id) {
+            Some(info) => info,
+            None => return Err(LoopRunnerError::NotFound(loop_id)),
+        };
+        refresh_loop_runner_locked(info);
+
+        if info.state == to {
+            return Ok(info_to_runner(info));
+        }
+        if info.state != from {
+            return Err(LoopRunnerError::InvalidState(loop_id, action.to_string()));
+        }
+        if info.child.is_none() {
+            return Err(LoopRunnerError::NoProcessHandle(loop_id));
+        }
+
+        let result = if to == LoopRunnerState::Paused {
+            std::fs::write(&info.pause_file, b"").map_err(|err| err.to_string())
+        } else {
+            clear_pause_file(&info.pause_file)
+        };
+        if let Err(err) = result {
+            return Err(LoopRunnerError::TransitionFailed(
+                loop_id,
+                action.to_string(),
+                err,
+            ));
+        }
+        info.state = to;
+        Ok(info_to_runner(info))
+    }
+
     pub fn get_loop_runner(&self, loop_id: &str) -> Result<LoopRunner, LoopRunnerError> {
         let loop_id = loop_id.trim().to_string();
         if loop_id.is_empty() {
@@ -284,6 +381,22 @@ fn stop_loop_runner_process(pid: i32, force: bool) -> Result<(), String> {
     }
 }
 
+fn pause_file_path(instance_id: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("forge-loop-runner-{instance_id}.pause"))
+}
+
+fn clear_pause_file(path: &Path) -> Result<(), String> {
+    match std::fs::remove_file(path) {
+        Ok(()) => Ok(()),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(err) => Err(err.to_string()),
+    }
+}
+
+fn is_live(state: &LoopRunnerState) -> bool {
+    matches!(state, LoopRunnerState::Running | LoopRunnerState::Paused)
+}
+
 fn refresh_loop_runner_locked(info: &mut LoopRunnerInfo) {
     let child = match info.child.as_mut() {
         Some(child) => child,
@@ -309,11 +422,12 @@ fn refresh_loop_runner_locked(info: &mut LoopRunnerInfo) {
             info.state = LoopRunnerState::Stopped;
             info.last_error.clear();
         }
-    } else if info.state == LoopRunnerState::Running {
+    } else if is_live(&info.state) {
         info.state = LoopRunnerState::Error;
         info.last_error = status.to_string();
     }
 
+    let _ = clear_pause_file(&info.pause_file);
     info.child = None;
 }
 
@@ -1,4 +1,5 @@
 mod manager;
+pub mod progress;
 mod types;
 
 pub use manager::LoopRunnerManager;
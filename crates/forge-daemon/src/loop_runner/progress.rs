@@ -0,0 +1,86 @@
+//! Structured iteration progress reported by loop runner processes.
+//!
+//! Runner processes started by the daemon get [`PROGRESS_ENV_VAR`] set and
+//! print progress lines in the format defined by `forge_core::loop_runner`.
+//! The manager parses those lines and republishes them on the [`EventBus`]
+//! as `EVENT_TYPE_LOOP_PROGRESS` events.
+
+use std::io::{BufRead, BufReader, Read};
+use std::sync::Arc;
+
+pub use forge_core::loop_runner::{
+    format_progress_line, parse_progress_line, LoopProgressPhase, ProgressLine, PROGRESS_ENV_VAR,
+    PROGRESS_LINE_PREFIX,
+};
+use forge_rpc::forged::v1 as proto;
+
+use crate::events::EventBus;
+
+fn phase_to_proto(phase: LoopProgressPhase) -> proto::LoopProgressPhase {
+    match phase {
+        LoopProgressPhase::IterationStarted => proto::LoopProgressPhase::IterationStarted,
+        LoopProgressPhase::HarnessInvoked => proto::LoopProgressPhase::HarnessInvoked,
+        LoopProgressPhase::OutputCaptured => proto::LoopProgressPhase::OutputCaptured,
+        LoopProgressPhase::Outcome => proto::LoopProgressPhase::Outcome,
+    }
+}
+
+/// A progress event as published for a loop runner.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoopProgress {
+    pub loop_id: String,
+    pub iteration: i64,
+    pub sequence: i64,
+    pub phase: LoopProgressPhase,
+    pub detail: String,
+}
+
+/// Assigns per-runner sequence numbers and publishes progress on the bus.
+pub struct LoopProgressEmitter {
+    loop_id: String,
+    next_sequence: i64,
+    events: Arc<EventBus>,
+}
+
+impl LoopProgressEmitter {
+    pub fn new(loop_id: impl Into<String>, events: Arc<EventBus>) -> Self {
+        Self {
+            loop_id: loop_id.into(),
+            next_sequence: 1,
+            events,
+        }
+    }
+
+    pub fn emit(&mut self, line: ProgressLine) -> LoopProgress {
+        let sequence = self.next_sequence;
+        self.next_sequence = self.next_sequence.saturating_add(1);
+        self.events.publish_loop_progress(
+            &self.loop_id,
+            line.iteration,
+            sequence,
+            phase_to_proto(line.phase),
+            &line.detail,
+        );
+        LoopProgress {
+            loop_id: self.loop_id.clone(),
+            iteration: line.iteration,
+            sequence,
+            phase: line.phase,
+            detail: line.detail,
+        }
+    }
+
+    /// Forward every progress line from `reader` until EOF. Other output is
+    /// ignored.
+    pub fn forward<R: Read>(&mut self, reader: R) {
+        for line in BufReader::new(reader).lines() {
+            let line = match line {
+                Ok(line) => line,
+                Err(_) => return,
+            };
+            if let Some(progress) = parse_progress_line(&line) {
+                self.emit(progress);
+            }
+        }
+    }
+}
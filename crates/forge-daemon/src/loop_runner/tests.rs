@@ -1,4 +1,6 @@
 use super::{LoopRunnerError, LoopRunnerManager, LoopRunnerState, StartLoopRunnerRequest};
+use crate::events::EventBus;
+use forge_rpc::forged::v1 as proto;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
@@ -278,6 +280,79 @@ fn reconnect_recovers_from_stale_exited_runner_with_new_instance() {
     assert!(stop.success);
 }
 
+#[test]
+fn single_iteration_emits_ordered_progress_events() {
+    let events = Arc::new(EventBus::new());
+    let (sub_id, mut rx, _) = match events.subscribe(&proto::StreamEventsRequest::default()) {
+        Ok(value) => value,
+        Err(err) => panic!("subscribe error: {err:?}"),
+    };
+
+    let mgr = LoopRunnerManager::with_command_builder(Arc::new(|_, _| {
+        let mut c = std::process::Command::new("sh");
+        c.args([
+            "-c",
+            "[ \"$FORGE_LOOP_PROGRESS\" = 1 ] || exit 3
+echo 'loop started'
+echo 'forge-loop-progress iteration=1 phase=iteration_started'
+echo 'forge-loop-progress iteration=1 phase=harness_invoked detail=profile=codex'
+echo 'forge-loop-progress iteration=1 phase=output_captured detail=lines=2'
+echo 'forge-loop-progress iteration=1 phase=outcome detail=exit=0'",
+        ]);
+        c
+    }));
+    mgr.set_event_bus(Arc::clone(&events));
+
+    let _ = match mgr.start_loop_runner(StartLoopRunnerRequest {
+        loop_id: "loop-progress".to_string(),
+        config_path: "".to_string(),
+        command_path: "forge".to_string(),
+    }) {
+        Ok(runner) => runner,
+        Err(err) => panic!("start error: {err:?}"),
+    };
+
+    let deadline = Instant::now() + Duration::from_secs(5);
+    let mut received = Vec::new();
+    while received.len() < 4 {
+        match rx.try_recv() {
+            Ok(event) => received.push(event),
+            Err(_) if Instant::now() < deadline => std::thread::sleep(Duration::from_millis(10)),
+            Err(_) => panic!("timed out waiting for progress events, got {received:?}"),
+        }
+    }
+    events.unsubscribe(&sub_id);
+
+    let progress: Vec<proto::LoopProgressEvent> = received
+        .into_iter()
+        .map(|event| {
+            assert_eq!(event.r#type, proto::EventType::LoopProgress as i32);
+            match event.payload {
+                Some(proto::event::Payload::LoopProgress(progress)) => progress,
+                other => panic!("unexpected payload: {other:?}"),
+            }
+        })
+        .collect();
+
+    let phases: Vec<i32> = progress.iter().map(|p| p.phase).collect();
+    assert_eq!(
+        phases,
+        vec![
+            proto::LoopProgressPhase::IterationStarted as i32,
+            proto::LoopProgressPhase::HarnessInvoked as i32,
+            proto::LoopProgressPhase::OutputCaptured as i32,
+            proto::LoopProgressPhase::Outcome as i32,
+        ]
+    );
+    let sequences: Vec<i64> = progress.iter().map(|p| p.sequence).collect();
+    assert_eq!(sequences, vec![1, 2, 3, 4]);
+    assert!(progress
+        .iter()
+        .all(|p| p.loop_id == "loop-progress" && p.iteration == 1));
+    assert_eq!(progress[1].detail, "profile=codex");
+    assert_eq!(progress[3].detail, "exit=0");
+}
+
 fn wait_for_state(
     mgr: &LoopRunnerManager,
     loop_id: &str,
@@ -302,3 +377,93 @@ fn wait_for_state(
         std::thread::sleep(Duration::from_millis(25));
     }
 }
+
+#[test]
+fn paused_runner_issues_no_iterations_until_resumed() {
+    let events = Arc::new(EventBus::new());
+    let (sub_id, mut rx, _) = match events.subscribe(&proto::StreamEventsRequest::default()) {
+        Ok(value) => value,
+        Err(err) => panic!("subscribe error: {err:?}"),
+    };
+
+    let mgr = LoopRunnerManager::with_command_builder(Arc::new(|_, _| {
+        let mut c = std::process::Command::new("sh");
+        c.args([
+            "-c",
+            "i=0
+while true; do
+  if [ ! -e \"$FORGE_LOOP_PAUSE_FILE\" ]; then
+    i=$((i+1))
+    echo \"forge-loop-progress iteration=$i phase=iteration_started\"
+  fi
+  sleep 0.05
+done",
+        ]);
+        c
+    }));
+    mgr.set_event_bus(Arc::clone(&events));
+
+    let _ = match mgr.start_loop_runner(StartLoopRunnerRequest {
+        loop_id: "loop-pause".to_string(),
+        config_path: "".to_string(),
+        command_path: "forge".to_string(),
+    }) {
+        Ok(runner) => runner,
+        Err(err) => panic!("start error: {err:?}"),
+    };
+
+    wait_for_iteration(&mut rx);
+
+    let paused = match mgr.pause_loop_runner("loop-pause") {
+        Ok(runner) => runner,
+        Err(err) => panic!("pause error: {err:?}"),
+    };
+    assert_eq!(paused.state, LoopRunnerState::Paused);
+
+    // Let anything already in flight drain, then expect silence.
+    std::thread::sleep(Duration::from_millis(150));
+    while rx.try_recv().is_ok() {}
+    std::thread::sleep(Duration::from_millis(300));
+    assert!(rx.try_recv().is_err(), "iteration fired while paused");
+    assert_eq!(
+        mgr.get_loop_runner("loop-pause").map(|runner| runner.state),
+        Ok(LoopRunnerState::Paused)
+    );
+
+    let resumed = match mgr.resume_loop_runner("loop-pause") {
+        Ok(runner) => runner,
+        Err(err) => panic!("resume error: {err:?}"),
+    };
+    assert_eq!(resumed.state, LoopRunnerState::Running);
+    wait_for_iteration(&mut rx);
+    events.unsubscribe(&sub_id);
+
+    assert!(matches!(
+        mgr.resume_loop_runner("loop-pause"),
+        Ok(runner) if runner.state == LoopRunnerState::Running
+    ));
+    let _ = mgr.pause_loop_runner("loop-pause");
+    let stop = match mgr.stop_loop_runner("loop-pause", true) {
+        Ok(result) => result,
+        Err(err) => panic!("stop error: {err:?}"),
+    };
+    assert_eq!(stop.runner.state, LoopRunnerState::Stopped);
+    assert_eq!(
+        mgr.pause_loop_runner("loop-pause").err(),
+        Some(LoopRunnerError::InvalidState(
+            "loop-pause".to_string(),
+            "pause".to_string()
+        ))
+    );
+}
+
+fn wait_for_iteration(rx: &mut tokio::sync::mpsc::Receiver<proto::Event>) {
+    let deadline = Instant::now() + Duration::from_secs(5);
+    loop {
+        match rx.try_recv() {
+            Ok(_) => return,
+            Err(_) if Instant::now() < deadline => std::thread::sleep(Duration::from_millis(10)),
+            Err(_) => panic!("timed out waiting for an iteration"),
+        }
+    }
+}
@@ -4,6 +4,7 @@ use thiserror::Error;
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum LoopRunnerState {
     Running,
+    Paused,
     Stopped,
     Error,
 }
@@ -48,4 +49,8 @@ pub enum LoopRunnerError {
     StopFailed(String, String),
     #[error("loop runner {0:?} has no process handle")]
     NoProcessHandle(String),
+    #[error("loop runner {0:?} cannot {1} in its current state")]
+    InvalidState(String, String),
+    #[error("failed to {1} loop runner {0:?}: {2}")]
+    TransitionFailed(String, String, String),
 }
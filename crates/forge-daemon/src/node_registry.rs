@@ -1,8 +1,10 @@
 use std::collections::BTreeMap;
 use std::fs;
+use std::net::{TcpStream, ToSocketAddrs};
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -58,7 +60,7 @@ pub fn register_local_node(
         return Err("endpoint is required for registration".to_string());
     }
 
-    let path = data_dir.join("mesh").join("registry.json");
+    let path = registry_path(data_dir);
     let mut registry = load_registry(&path)?;
 
     let entry = registry.nodes.entry(node_id.to_string()).or_default();
@@ -90,6 +92,254 @@ pub fn register_local_node(
     })
 }
 
+/// List `(node_id, endpoint)` pairs from the mesh registry.
+pub fn list_registered_nodes(data_dir: &Path) -> Result<Vec<(String, String)>, String> {
+    let registry = load_registry(&registry_path(data_dir))?;
+    Ok(registry
+        .nodes
+        .into_iter()
+        .map(|(node_id, record)| (node_id, record.endpoint))
+        .collect())
+}
+
+fn registry_path(data_dir: &Path) -> PathBuf {
+    data_dir.join("mesh").join("registry.json")
+}
+
+// ---------------------------------------------------------------------------
+// Liveness probing + eviction
+// ---------------------------------------------------------------------------
+
+/// Consecutive probe failures before a node is marked unreachable.
+pub const DEFAULT_EVICTION_THRESHOLD: u32 = 3;
+
+/// Interval between liveness probe rounds.
+pub const DEFAULT_PROBE_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Checks whether a node can currently be reached.
+pub trait NodeProbe: Send + Sync {
+    fn probe(&self, node_id: &str, endpoint: &str) -> Result<(), String>;
+}
+
+/// Probes a node by opening a TCP connection to its endpoint.
+#[derive(Debug, Clone, Copy)]
+pub struct TcpReachabilityProbe {
+    pub timeout: Duration,
+}
+
+impl Default for TcpReachabilityProbe {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(3),
+        }
+    }
+}
+
+impl NodeProbe for TcpReachabilityProbe {
+    fn probe(&self, _node_id: &str, endpoint: &str) -> Result<(), String> {
+        let authority = endpoint
+            .split_once("://")
+            .map_or(endpoint, |(_, rest)| rest)
+            .trim_end_matches('/');
+        let addr = authority
+            .to_socket_addrs()
+            .map_err(|err| format!("resolve {authority}: {err}"))?
+            .next()
+            .ok_or_else(|| format!("no addresses resolved for {authority}"))?;
+        TcpStream::connect_timeout(&addr, self.timeout)
+            .map(|_| ())
+            .map_err(|err| format!("connect {addr}: {err}"))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NodeHealth {
+    Reachable,
+    Unreachable,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NodeHealthRecord {
+    pub node_id: String,
+    pub endpoint: String,
+    pub health: NodeHealth,
+    pub consecutive_failures: u32,
+    pub last_probe_at: Option<String>,
+    pub last_error: String,
+}
+
+/// Emitted when a node crosses between reachable and unreachable.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NodeHealthEvent {
+    pub node_id: String,
+    pub previous: NodeHealth,
+    pub current: NodeHealth,
+    pub consecutive_failures: u32,
+    pub reason: String,
+}
+
+/// Tracks per-node liveness and applies the eviction policy: a node is
+/// evicted after `eviction_threshold` consecutive failed probes and
+/// re-admitted on the next successful one.
+#[derive(Debug, Clone)]
+pub struct NodeHealthTracker {
+    eviction_threshold: u32,
+    nodes: BTreeMap<String, NodeHealthRecord>,
+}
+
+impl Default for NodeHealthTracker {
+    fn default() -> Self {
+        Self::new(DEFAULT_EVICTION_THRESHOLD)
+    }
+}
+
+impl NodeHealthTracker {
+    pub fn new(eviction_threshold: u32) -> Self {
+        Self {
+            eviction_threshold: eviction_threshold.max(1),
+            nodes: BTreeMap::new(),
+        }
+    }
+
+    /// Start tracking a node (or refresh its endpoint). New nodes are
+    /// assumed reachable until a probe says otherwise.
+    pub fn track(&mut self, node_id: &str, endpoint: &str) {
+        let record = self
+            .nodes
+            .entry(node_id.to_string())
+            .or_insert_with(|| NodeHealthRecord {
+                node_id: node_id.to_string(),
+                endpoint: String::new(),
+                health: NodeHealth::Reachable,
+                consecutive_failures: 0,
+                last_probe_at: None,
+                last_error: String::new(),
+            });
+        record.endpoint = endpoint.to_string();
+    }
+
+    /// Drop nodes that are no longer present in the registry.
+    pub fn retain_nodes(&mut self, node_ids: &[String]) {
+        self.nodes.retain(|node_id, _| node_ids.contains(node_id));
+    }
+
+    /// Apply one probe result and return a state-change event, if any.
+    pub fn record_probe(
+        &mut self,
+        node_id: &str,
+        result: Result<(), String>,
+        now: DateTime<Utc>,
+    ) -> Option<NodeHealthEvent> {
+        let threshold = self.eviction_threshold;
+        let record = self.nodes.get_mut(node_id)?;
+        let previous = record.health;
+        record.last_probe_at = Some(now.to_rfc3339());
+
+        match result {
+            Ok(()) => {
+                record.consecutive_failures = 0;
+                record.last_error.clear();
+                record.health = NodeHealth::Reachable;
+            }
+            Err(err) => {
+                record.consecutive_failures = record.consecutive_failures.saturating_add(1);
+                record.last_error = err;
+                if record.consecutive_failures >= threshold {
+                    record.health = NodeHealth::Unreachable;
+                }
+            }
+        }
+
+        if record.health == previous {
+            return None;
+        }
+        let reason = match record.health {
+            NodeHealth::Reachable => "probe succeeded".to_string(),
+            NodeHealth::Unreachable => format!(
+                "{} consecutive probe failures: {}",
+                record.consecutive_failures, record.last_error
+            ),
+        };
+        Some(NodeHealthEvent {
+            node_id: node_id.to_string(),
+            previous,
+            current: record.health,
+            consecutive_failures: record.consecutive_failures,
+            reason,
+        })
+    }
+
+    /// Probe every tracked node once.
+    pub fn probe_all(&mut self, probe: &dyn NodeProbe, now: DateTime<Utc>) -> Vec<NodeHealthEvent> {
+        let targets: Vec<(String, String)> = self
+            .nodes
+            .values()
+            .map(|record| (record.node_id.clone(), record.endpoint.clone()))
+            .collect();
+        targets
+            .into_iter()
+            .filter_map(|(node_id, endpoint)| {
+                let result = probe.probe(&node_id, &endpoint);
+                self.record_probe(&node_id, result, now)
+            })
+            .collect()
+    }
+
+    /// Sync tracked nodes with the registry, probe them, and persist the
+    /// resulting health snapshot.
+    pub fn probe_registry(
+        &mut self,
+        data_dir: &Path,
+        probe: &dyn NodeProbe,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<NodeHealthEvent>, String> {
+        let nodes = list_registered_nodes(data_dir)?;
+        let node_ids: Vec<String> = nodes.iter().map(|(node_id, _)| node_id.clone()).collect();
+        self.retain_nodes(&node_ids);
+        for (node_id, endpoint) in &nodes {
+            self.track(node_id, endpoint);
+        }
+        let events = self.probe_all(probe, now);
+        write_node_health(data_dir, &self.snapshot())?;
+        Ok(events)
+    }
+
+    /// Current health of all tracked nodes, ordered by node id.
+    pub fn snapshot(&self) -> Vec<NodeHealthRecord> {
+        self.nodes.values().cloned().collect()
+    }
+}
+
+/// Persist node health next to the registry so `forge status` can read it.
+pub fn write_node_health(data_dir: &Path, records: &[NodeHealthRecord]) -> Result<(), String> {
+    let path = node_health_path(data_dir);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|err| format!("create directory {}: {err}", parent.display()))?;
+    }
+    let encoded = serde_json::to_string_pretty(records)
+        .map_err(|err| format!("encode {}: {err}", path.display()))?;
+    fs::write(&path, encoded).map_err(|err| format!("write {}: {err}", path.display()))
+}
+
+/// Load the last persisted node health; empty when never probed. The daemon
+/// status RPC reports it as the `nodes` health check.
+pub fn load_node_health(data_dir: &Path) -> Result<Vec<NodeHealthRecord>, String> {
+    let path = node_health_path(data_dir);
+    match fs::read_to_string(&path) {
+        Ok(raw) => {
+            serde_json::from_str(&raw).map_err(|err| format!("parse {}: {err}", path.display()))
+        }
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(err) => Err(format!("read {}: {err}", path.display())),
+    }
+}
+
+fn node_health_path(data_dir: &Path) -> PathBuf {
+    data_dir.join("mesh").join("node-health.json")
+}
+
 fn load_registry(path: &Path) -> Result<MeshRegistry, String> {
     match fs::read_to_string(path) {
         Ok(raw) => serde_json::from_str::<MeshRegistry>(&raw)
@@ -119,7 +369,26 @@ mod tests {
     use std::sync::atomic::{AtomicU64, Ordering};
     use std::time::{SystemTime, UNIX_EPOCH};
 
-    use super::register_local_node;
+    use chrono::{TimeZone, Utc};
+
+    use super::{
+        list_registered_nodes, load_node_health, register_local_node, write_node_health,
+        NodeHealth, NodeHealthTracker, NodeProbe,
+    };
+
+    struct ScriptedProbe {
+        failing: Vec<&'static str>,
+    }
+
+    impl NodeProbe for ScriptedProbe {
+        fn probe(&self, node_id: &str, _endpoint: &str) -> Result<(), String> {
+            if self.failing.contains(&node_id) {
+                Err("connection refused".to_string())
+            } else {
+                Ok(())
+            }
+        }
+    }
 
     #[test]
     fn register_creates_registry_and_token() {
@@ -174,6 +443,78 @@ mod tests {
         cleanup_dir(&dir);
     }
 
+    #[test]
+    fn consecutive_probe_failures_evict_and_success_readmits() {
+        let now = Utc.timestamp_opt(1_700_000_000, 0).single();
+        let Some(now) = now else {
+            panic!("invalid fixture timestamp");
+        };
+        let mut tracker = NodeHealthTracker::new(3);
+        tracker.track("node-a", "127.0.0.1:1");
+        tracker.track("node-b", "127.0.0.1:2");
+        let down = ScriptedProbe {
+            failing: vec!["node-a"],
+        };
+
+        assert!(tracker.probe_all(&down, now).is_empty());
+        assert!(tracker.probe_all(&down, now).is_empty());
+        assert_eq!(health_of(&tracker, "node-a"), Some(NodeHealth::Reachable));
+
+        let events = tracker.probe_all(&down, now);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].node_id, "node-a");
+        assert_eq!(events[0].previous, NodeHealth::Reachable);
+        assert_eq!(events[0].current, NodeHealth::Unreachable);
+        assert_eq!(events[0].consecutive_failures, 3);
+        assert_eq!(health_of(&tracker, "node-a"), Some(NodeHealth::Unreachable));
+        assert_eq!(health_of(&tracker, "node-b"), Some(NodeHealth::Reachable));
+
+        let up = ScriptedProbe { failing: vec![] };
+        let events = tracker.probe_all(&up, now);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].current, NodeHealth::Reachable);
+        let snapshot = tracker.snapshot();
+        assert_eq!(snapshot[0].consecutive_failures, 0);
+        assert!(snapshot[0].last_error.is_empty());
+    }
+
+    #[test]
+    fn node_health_round_trips_and_registry_lists_nodes() {
+        let dir = temp_dir_path("health");
+        if let Err(err) = register_local_node(&dir, "local-node", "127.0.0.1:50051", None) {
+            panic!("register node failed: {err}");
+        }
+        let nodes = match list_registered_nodes(&dir) {
+            Ok(value) => value,
+            Err(err) => panic!("list nodes failed: {err}"),
+        };
+        assert_eq!(
+            nodes,
+            vec![("local-node".to_string(), "127.0.0.1:50051".to_string())]
+        );
+
+        let mut tracker = NodeHealthTracker::default();
+        tracker.track("local-node", "127.0.0.1:50051");
+        if let Err(err) = write_node_health(&dir, &tracker.snapshot()) {
+            panic!("write node health failed: {err}");
+        }
+        let loaded = match load_node_health(&dir) {
+            Ok(value) => value,
+            Err(err) => panic!("load node health failed: {err}"),
+        };
+        assert_eq!(loaded, tracker.snapshot());
+
+        cleanup_dir(&dir);
+    }
+
+    fn health_of(tracker: &NodeHealthTracker, node_id: &str) -> Option<NodeHealth> {
+        tracker
+            .snapshot()
+            .into_iter()
+            .find(|record| record.node_id == node_id)
+            .map(|record| record.health)
+    }
+
     fn temp_dir_path(tag: &str) -> std::path::PathBuf {
         static COUNTER: AtomicU64 = AtomicU64::new(0);
         let nanos = SystemTime::now()
@@ -12,13 +12,16 @@ use chrono::{DateTime, TimeZone, Utc};
 use sha2::{Digest, Sha256};
 use tonic::{Request, Response, Status};
 
+use forge_db::transcript_repository::{Transcript, TranscriptRepository};
 use forge_rpc::forged::v1 as proto;
 
 use crate::agent::{Agent, AgentInfo, AgentManager, AgentState};
+use crate::database::SharedDb;
 use crate::events::EventBus;
 use crate::loop_runner::{
     LoopRunner, LoopRunnerError, LoopRunnerManager, LoopRunnerState, StartLoopRunnerRequest,
 };
+use crate::shutdown::{InFlightGuard, InFlightOps};
 use crate::status::StatusService;
 use crate::tmux::TmuxClient;
 use crate::transcript::{TranscriptEntry, TranscriptEntryType, TranscriptStore};
@@ -31,9 +34,15 @@ pub struct ForgedAgentService {
     loop_runners: LoopRunnerManager,
     status: StatusService,
     auth_token: Option<String>,
+    in_flight: InFlightOps,
+    transcript_db: Option<Arc<SharedDb>>,
 }
 
 const DEFAULT_POLL_INTERVAL: Duration = Duration::from_millis(500);
+/// How often StreamAgentOutput checks for new output.
+const AGENT_OUTPUT_POLL_INTERVAL: Duration = Duration::from_millis(100);
+/// Scrollback lines captured into the transcript when an agent fails.
+const FAILURE_SNAPSHOT_LINES: usize = 2000;
 
 impl ForgedAgentService {
     pub fn new(agents: AgentManager, tmux: Arc<dyn TmuxClient>) -> Self {
@@ -65,15 +74,19 @@ impl ForgedAgentService {
         let hostname = nix::unistd::gethostname()
             .map(|h| h.to_string_lossy().to_string())
             .unwrap_or_else(|_| "unknown".to_string());
+        let events = Arc::new(EventBus::new());
+        loop_runners.set_event_bus(Arc::clone(&events));
         Self {
             agents,
             tmux,
-            events: Arc::new(EventBus::new()),
+            events,
             loop_runners,
             status: StatusService::new("dev", hostname),
             auth_token: auth_token
                 .map(|value| value.trim().to_string())
                 .filter(|value| !value.is_empty()),
+            in_flight: InFlightOps::new(),
+            transcript_db: None,
         }
     }
 
@@ -87,6 +100,36 @@ impl ForgedAgentService {
         self.loop_runners.clone()
     }
 
+    /// Access the event bus (used by daemon background loops to publish).
+    pub fn event_bus(&self) -> Arc<EventBus> {
+        Arc::clone(&self.events)
+    }
+
+    /// Surface mesh node health persisted under `data_dir` in GetStatus.
+    pub fn with_node_health_dir(mut self, data_dir: impl Into<std::path::PathBuf>) -> Self {
+        self.status = self.status.with_node_health_dir(data_dir);
+        self
+    }
+
+    /// Persist the pane output of finished runs to the `transcripts` table
+    /// through the shared database's writer.
+    pub fn with_transcript_db(mut self, db: Arc<SharedDb>) -> Self {
+        self.transcript_db = Some(db);
+        self
+    }
+
+    /// Access the in-flight operation tracker (used by daemon shutdown flow).
+    pub fn in_flight_ops(&self) -> InFlightOps {
+        self.in_flight.clone()
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn begin_op(&self) -> Result<InFlightGuard, Status> {
+        self.in_flight
+            .try_begin()
+            .ok_or_else(|| Status::unavailable("daemon is shutting down"))
+    }
+
     #[allow(clippy::result_large_err)]
     fn require_auth<T>(&self, req: &Request<T>) -> Result<(), Status> {
         let Some(expected) = self.auth_token.as_deref() else {
@@ -454,6 +497,44 @@ impl ForgedAgentService {
         }))
     }
 
+    /// PauseLoopRunner raises the pause flag of a daemon-owned loop runner.
+    #[allow(clippy::result_large_err)]
+    pub fn pause_loop_runner(
+        &self,
+        req: Request<proto::PauseLoopRunnerRequest>,
+    ) -> Result<Response<proto::PauseLoopRunnerResponse>, Status> {
+        self.require_auth(&req)?;
+        let req = req.into_inner();
+
+        let runner = self
+            .loop_runners
+            .pause_loop_runner(&req.loop_id)
+            .map_err(loop_runner_error_to_status)?;
+
+        Ok(Response::new(proto::PauseLoopRunnerResponse {
+            runner: Some(loop_runner_to_proto(&runner)),
+        }))
+    }
+
+    /// ResumeLoopRunner continues a paused daemon-owned loop runner process.
+    #[allow(clippy::result_large_err)]
+    pub fn resume_loop_runner(
+        &self,
+        req: Request<proto::ResumeLoopRunnerRequest>,
+    ) -> Result<Response<proto::ResumeLoopRunnerResponse>, Status> {
+        self.require_auth(&req)?;
+        let req = req.into_inner();
+
+        let runner = self
+            .loop_runners
+            .resume_loop_runner(&req.loop_id)
+            .map_err(loop_runner_error_to_status)?;
+
+        Ok(Response::new(proto::ResumeLoopRunnerResponse {
+            runner: Some(loop_runner_to_proto(&runner)),
+        }))
+    }
+
     /// GetLoopRunner returns one daemon-owned loop runner.
     #[allow(clippy::result_large_err)]
     pub fn get_loop_runner(
@@ -555,6 +636,49 @@ impl ForgedAgentService {
         }))
     }
 
+    /// Persist the pane content a run finished with. Best effort: the live
+    /// stream must not fail because the database is unavailable.
+    fn persist_run_transcript(&self, agent_id: &str, content: &str, content_hash: &str) {
+        let Some(Ok(Some(pool))) = self.transcript_db.as_ref().map(|db| db.pool()) else {
+            return;
+        };
+        let _ = pool.write(|db| {
+            let mut transcript = Transcript {
+                agent_id: agent_id.to_string(),
+                content: content.to_string(),
+                content_hash: content_hash.to_string(),
+                ..Default::default()
+            };
+            TranscriptRepository::new(db).capture(&mut transcript)
+        });
+    }
+
+    /// Store the pane scrollback as an error transcript entry so a failure
+    /// can be diagnosed beyond the visible screen.
+    fn record_failure_snapshot(&self, agent_id: &str, pane_id: &str) {
+        let mut metadata = HashMap::new();
+        metadata.insert("snapshot".to_string(), "scrollback".to_string());
+        let content = match self
+            .tmux
+            .capture_scrollback(pane_id, Some(FAILURE_SNAPSHOT_LINES))
+        {
+            Ok(content) => content,
+            Err(err) => {
+                metadata.insert("capture_error".to_string(), err.to_string());
+                String::new()
+            }
+        };
+        self.agents.add_transcript_entry_full(
+            agent_id,
+            TranscriptEntry {
+                entry_type: TranscriptEntryType::Error,
+                content,
+                timestamp: Utc::now(),
+                metadata,
+            },
+        );
+    }
+
     /// CapturePane returns current content for an agent pane.
     #[allow(clippy::result_large_err)]
     pub fn capture_pane(
@@ -678,6 +802,12 @@ impl ForgedAgentService {
                             metadata,
                         },
                     );
+                    if detected_state == AgentState::Failed {
+                        self.record_failure_snapshot(&req.agent_id, &agent.pane_id);
+                    }
+                    if matches!(detected_state, AgentState::Idle | AgentState::Failed) {
+                        self.persist_run_transcript(&req.agent_id, &content, &content_hash);
+                    }
                 }
 
                 self.agents.update_snapshot(
@@ -890,6 +1020,89 @@ impl ForgedAgentService {
 
         Ok(updates)
     }
+
+    /// Validates a StreamAgentOutput request and returns the agent id and
+    /// starting cursor.
+    #[allow(clippy::result_large_err)]
+    pub fn begin_agent_output_stream(
+        &self,
+        req: Request<proto::StreamAgentOutputRequest>,
+    ) -> Result<(String, i64), Status> {
+        self.require_auth(&req)?;
+        let req = req.into_inner();
+
+        if req.agent_id.is_empty() {
+            return Err(Status::invalid_argument("agent_id is required"));
+        }
+        let cursor = if req.cursor.is_empty() {
+            0i64
+        } else {
+            parse_cursor_i64(&req.cursor)?
+        };
+        if !self.agents.contains(&req.agent_id) {
+            return Err(Status::not_found(format!(
+                "agent {:?} not found",
+                req.agent_id
+            )));
+        }
+        Ok((req.agent_id, cursor))
+    }
+}
+
+/// Output lines recorded for `agent_id` from `cursor` on, plus the next
+/// cursor. The chunk is marked final once the agent is gone or has exited.
+fn agent_output_chunk(
+    agents: &AgentManager,
+    agent_id: &str,
+    cursor: i64,
+) -> (proto::StreamAgentOutputResponse, i64) {
+    let mut next_cursor = cursor;
+    let mut lines = Vec::new();
+    for (id, entry) in agents.transcript_snapshot(agent_id).unwrap_or_default() {
+        if id < cursor {
+            continue;
+        }
+        next_cursor = id + 1;
+        if entry.entry_type == TranscriptEntryType::Output {
+            lines.extend(entry.content.lines().map(str::to_string));
+        }
+    }
+    let agent_exited = match agents.get(agent_id) {
+        Some(agent) => matches!(agent.state, AgentState::Stopped | AgentState::Failed),
+        None => true,
+    };
+    (
+        proto::StreamAgentOutputResponse {
+            lines,
+            cursor: format!("{next_cursor}"),
+            agent_exited,
+        },
+        next_cursor,
+    )
+}
+
+/// Forwards agent output chunks to `tx` until the agent exits or the
+/// receiver is dropped.
+async fn forward_agent_output(
+    agents: AgentManager,
+    agent_id: String,
+    mut cursor: i64,
+    tx: tokio::sync::mpsc::Sender<Result<proto::StreamAgentOutputResponse, Status>>,
+    poll_interval: Duration,
+    op: InFlightGuard,
+) {
+    loop {
+        let (chunk, next_cursor) = agent_output_chunk(&agents, &agent_id, cursor);
+        cursor = next_cursor;
+        let exited = chunk.agent_exited;
+        if (exited || !chunk.lines.is_empty()) && tx.send(Ok(chunk)).await.is_err() {
+            return;
+        }
+        if exited || tx.is_closed() || op.is_draining() {
+            return;
+        }
+        tokio::time::sleep(poll_interval).await;
+    }
 }
 
 fn bearer_token_from_request<T>(req: &Request<T>) -> Option<String> {
@@ -920,6 +1133,7 @@ impl ForgedService for ForgedAgentService {
         &self,
         request: Request<proto::SpawnAgentRequest>,
     ) -> Result<Response<proto::SpawnAgentResponse>, Status> {
+        let _op = self.begin_op()?;
         self.spawn_agent(request)
     }
 
@@ -927,6 +1141,7 @@ impl ForgedService for ForgedAgentService {
         &self,
         request: Request<proto::KillAgentRequest>,
     ) -> Result<Response<proto::KillAgentResponse>, Status> {
+        let _op = self.begin_op()?;
         self.kill_agent(request)
     }
 
@@ -934,6 +1149,7 @@ impl ForgedService for ForgedAgentService {
         &self,
         request: Request<proto::SendInputRequest>,
     ) -> Result<Response<proto::SendInputResponse>, Status> {
+        let _op = self.begin_op()?;
         self.send_input(request)
     }
 
@@ -955,6 +1171,7 @@ impl ForgedService for ForgedAgentService {
         &self,
         request: Request<proto::StartLoopRunnerRequest>,
     ) -> Result<Response<proto::StartLoopRunnerResponse>, Status> {
+        let _op = self.begin_op()?;
         self.start_loop_runner(request)
     }
 
@@ -962,9 +1179,26 @@ impl ForgedService for ForgedAgentService {
         &self,
         request: Request<proto::StopLoopRunnerRequest>,
     ) -> Result<Response<proto::StopLoopRunnerResponse>, Status> {
+        let _op = self.begin_op()?;
         self.stop_loop_runner(request)
     }
 
+    async fn pause_loop_runner(
+        &self,
+        request: Request<proto::PauseLoopRunnerRequest>,
+    ) -> Result<Response<proto::PauseLoopRunnerResponse>, Status> {
+        let _op = self.begin_op()?;
+        self.pause_loop_runner(request)
+    }
+
+    async fn resume_loop_runner(
+        &self,
+        request: Request<proto::ResumeLoopRunnerRequest>,
+    ) -> Result<Response<proto::ResumeLoopRunnerResponse>, Status> {
+        let _op = self.begin_op()?;
+        self.resume_loop_runner(request)
+    }
+
     async fn get_loop_runner(
         &self,
         request: Request<proto::GetLoopRunnerRequest>,
@@ -983,6 +1217,7 @@ impl ForgedService for ForgedAgentService {
         &self,
         request: Request<proto::ExecuteCommandRequest>,
     ) -> Result<Response<proto::ExecuteCommandResponse>, Status> {
+        let _op = self.begin_op()?;
         self.execute_command(request)
     }
 
@@ -1033,6 +1268,27 @@ impl ForgedService for ForgedAgentService {
         Ok(Response::new(Box::pin(stream)))
     }
 
+    type StreamAgentOutputStream = BoxStream<proto::StreamAgentOutputResponse>;
+
+    async fn stream_agent_output(
+        &self,
+        request: Request<proto::StreamAgentOutputRequest>,
+    ) -> Result<Response<Self::StreamAgentOutputStream>, Status> {
+        let op = self.begin_op()?;
+        let (agent_id, cursor) = self.begin_agent_output_stream(request)?;
+        let (tx, rx) = tokio::sync::mpsc::channel(16);
+        tokio::spawn(forward_agent_output(
+            self.agents.clone(),
+            agent_id,
+            cursor,
+            tx,
+            AGENT_OUTPUT_POLL_INTERVAL,
+            op,
+        ));
+        let stream = tokio_stream::wrappers::ReceiverStream::new(rx);
+        Ok(Response::new(Box::pin(stream)))
+    }
+
     async fn get_status(
         &self,
         request: Request<proto::GetStatusRequest>,
@@ -1086,6 +1342,7 @@ fn loop_runner_to_proto(runner: &LoopRunner) -> proto::LoopRunner {
 fn loop_runner_state_to_proto_i32(state: &LoopRunnerState) -> i32 {
     match state {
         LoopRunnerState::Running => proto::LoopRunnerState::Running as i32,
+        LoopRunnerState::Paused => proto::LoopRunnerState::Paused as i32,
         LoopRunnerState::Stopped => proto::LoopRunnerState::Stopped as i32,
         LoopRunnerState::Error => proto::LoopRunnerState::Error as i32,
     }
@@ -1110,6 +1367,14 @@ fn loop_runner_error_to_status(err: LoopRunnerError) -> Status {
         LoopRunnerError::NoProcessHandle(loop_id) => {
             Status::internal(format!("loop runner {:?} has no process handle", loop_id))
         }
+        LoopRunnerError::InvalidState(loop_id, action) => Status::failed_precondition(format!(
+            "loop runner {:?} cannot {action} in its current state",
+            loop_id
+        )),
+        LoopRunnerError::TransitionFailed(loop_id, action, cause) => Status::internal(format!(
+            "failed to {action} loop runner {:?}: {cause}",
+            loop_id
+        )),
     }
 }
 
@@ -2264,6 +2529,26 @@ mod tests {
         assert_eq!(err.code(), tonic::Code::NotFound);
     }
 
+    #[test]
+    fn pause_and_resume_loop_runner_not_found() {
+        let svc = make_service_with_loop_runners(
+            Arc::new(MockTmux::new()),
+            make_loop_runner_manager_for_tests(),
+        );
+        let err = svc
+            .pause_loop_runner(Request::new(proto::PauseLoopRunnerRequest {
+                loop_id: "missing".to_string(),
+            }))
+            .unwrap_err();
+        assert_eq!(err.code(), tonic::Code::NotFound);
+        let err = svc
+            .resume_loop_runner(Request::new(proto::ResumeLoopRunnerRequest {
+                loop_id: "missing".to_string(),
+            }))
+            .unwrap_err();
+        assert_eq!(err.code(), tonic::Code::NotFound);
+    }
+
     #[test]
     fn list_loop_runners_returns_sorted_ids() {
         let loop_runners = make_loop_runner_manager_for_tests();
@@ -2514,6 +2799,110 @@ mod tests {
         assert_eq!(transcript.entries[1].content, "idle");
     }
 
+    #[test]
+    fn stream_pane_updates_persists_finished_run_transcripts() {
+        let db_path = std::env::temp_dir().join(format!(
+            "forge-daemon-transcript-{}-{}.sqlite",
+            std::process::id(),
+            Utc::now().timestamp_nanos_opt().unwrap_or_default()
+        ));
+        let mut db = forge_db::Db::open(forge_db::Config::new(&db_path)).unwrap();
+        db.migrate_up().unwrap();
+        db.conn()
+            .execute_batch(
+                "INSERT INTO nodes (id, name, status, is_local, ssh_backend)
+                 VALUES ('node-1', 'local', 'online', 1, 'auto');
+                 INSERT INTO workspaces (id, name, node_id, repo_path, tmux_session, status)
+                 VALUES ('ws1', 'ws1', 'node-1', '/tmp/repo', 'sess', 'active');
+                 INSERT INTO agents (id, workspace_id, type, tmux_pane, state, state_confidence)
+                 VALUES ('a1', 'ws1', 'claude-code', 'sess:a1.0', 'idle', 'high');",
+            )
+            .unwrap();
+
+        let svc = make_service(Arc::new(MockTmux::with_capture("work complete\n$")))
+            .with_transcript_db(Arc::new(SharedDb::new(&db_path)));
+        register_agent(&svc, "a1", "ws1", AgentState::Running);
+        let request = || {
+            Request::new(proto::StreamPaneUpdatesRequest {
+                agent_id: "a1".to_string(),
+                min_interval: Some(prost_types::Duration {
+                    seconds: 0,
+                    nanos: 1,
+                }),
+                last_known_hash: String::new(),
+                include_content: false,
+            })
+        };
+
+        // Two runs that finish on the same screen fold into one row.
+        svc.stream_pane_updates(request(), 1).unwrap();
+        svc.agents
+            .update_snapshot("a1", String::new(), Some(AgentState::Running));
+        svc.stream_pane_updates(request(), 1).unwrap();
+
+        let rows = TranscriptRepository::new(&db)
+            .list_by_agent("a1", 0)
+            .unwrap();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].content, "work complete\n$");
+        assert_eq!(rows[0].repeat_count, 2);
+
+        drop(db);
+        let _ = std::fs::remove_file(db_path);
+    }
+
+    #[test]
+    fn stream_pane_updates_records_scrollback_snapshot_on_failure() {
+        let tmux = Arc::new(MockTmux::with_capture("step 1\nerror: build failed"));
+        let svc = make_service(tmux.clone());
+        register_agent(&svc, "a1", "ws1", AgentState::Running);
+
+        let updates = svc
+            .stream_pane_updates(
+                Request::new(proto::StreamPaneUpdatesRequest {
+                    agent_id: "a1".to_string(),
+                    min_interval: Some(prost_types::Duration {
+                        seconds: 0,
+                        nanos: 1,
+                    }),
+                    last_known_hash: String::new(),
+                    include_content: false,
+                }),
+                1,
+            )
+            .unwrap();
+        assert_eq!(updates[0].detected_state, proto::AgentState::Failed as i32);
+
+        let calls = tmux.calls.lock().unwrap().clone();
+        assert!(calls.iter().any(|call| matches!(
+            call,
+            TmuxCall::CapturePane {
+                include_history: true,
+                ..
+            }
+        )));
+
+        let transcript = svc
+            .get_transcript(Request::new(proto::GetTranscriptRequest {
+                agent_id: "a1".to_string(),
+                start_time: None,
+                end_time: None,
+                limit: 0,
+            }))
+            .unwrap()
+            .into_inner();
+        let snapshot = transcript
+            .entries
+            .iter()
+            .find(|entry| entry.r#type == proto::TranscriptEntryType::Error as i32)
+            .unwrap();
+        assert_eq!(
+            snapshot.metadata.get("snapshot").map(String::as_str),
+            Some("scrollback")
+        );
+        assert!(snapshot.content.contains("error: build failed"));
+    }
+
     // -- StreamEvents tests --
 
     #[test]
@@ -2858,4 +3247,96 @@ mod tests {
             .unwrap_err();
         assert_eq!(err.code(), tonic::Code::InvalidArgument);
     }
+
+    #[tokio::test]
+    async fn stream_agent_output_yields_lines_in_order_until_exit() {
+        use tokio_stream::StreamExt;
+
+        let svc = make_service(Arc::new(MockTmux::new()));
+        register_agent(&svc, "a1", "ws1", AgentState::Running);
+        svc.agents
+            .add_transcript_entry("a1", TranscriptEntryType::Output, "one\ntwo");
+        svc.agents
+            .add_transcript_entry("a1", TranscriptEntryType::Command, "ignored");
+
+        let mut stream = ForgedService::stream_agent_output(
+            &svc,
+            Request::new(proto::StreamAgentOutputRequest {
+                agent_id: "a1".to_string(),
+                cursor: String::new(),
+            }),
+        )
+        .await
+        .unwrap()
+        .into_inner();
+
+        let first = stream.next().await.unwrap().unwrap();
+        assert_eq!(first.lines, vec!["one", "two"]);
+        assert!(!first.agent_exited);
+
+        svc.agents
+            .add_transcript_entry("a1", TranscriptEntryType::Output, "three");
+        let second = stream.next().await.unwrap().unwrap();
+        assert_eq!(second.lines, vec!["three"]);
+        assert_eq!(second.cursor, "3");
+
+        svc.agents
+            .add_transcript_entry("a1", TranscriptEntryType::Output, "four");
+        svc.agents
+            .update_snapshot("a1", String::new(), Some(AgentState::Stopped));
+        let mut rest = Vec::new();
+        let mut exited = false;
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk.unwrap();
+            rest.extend(chunk.lines);
+            exited = chunk.agent_exited;
+        }
+        assert_eq!(rest, vec!["four"]);
+        assert!(exited, "stream must end with an agent_exited chunk");
+    }
+
+    #[tokio::test]
+    async fn stream_agent_output_counts_as_in_flight_and_ends_on_drain() {
+        use tokio_stream::StreamExt;
+
+        let svc = make_service(Arc::new(MockTmux::new()));
+        register_agent(&svc, "a1", "ws1", AgentState::Running);
+        svc.agents
+            .add_transcript_entry("a1", TranscriptEntryType::Output, "one");
+
+        let request = || {
+            Request::new(proto::StreamAgentOutputRequest {
+                agent_id: "a1".to_string(),
+                cursor: String::new(),
+            })
+        };
+        let mut stream = ForgedService::stream_agent_output(&svc, request())
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(stream.next().await.unwrap().unwrap().lines, vec!["one"]);
+        assert_eq!(svc.in_flight_ops().active(), 1);
+
+        let report = svc.in_flight_ops().drain(Duration::from_secs(5)).await;
+        assert_eq!(report.aborted, 0);
+        assert!(stream.next().await.is_none());
+
+        let err = match ForgedService::stream_agent_output(&svc, request()).await {
+            Ok(_) => panic!("expected stream to be rejected while draining"),
+            Err(err) => err,
+        };
+        assert_eq!(err.code(), tonic::Code::Unavailable);
+    }
+
+    #[test]
+    fn stream_agent_output_unknown_agent_is_not_found() {
+        let svc = make_service(Arc::new(MockTmux::new()));
+        let err = svc
+            .begin_agent_output_stream(Request::new(proto::StreamAgentOutputRequest {
+                agent_id: "missing".to_string(),
+                cursor: String::new(),
+            }))
+            .unwrap_err();
+        assert_eq!(err.code(), tonic::Code::NotFound);
+    }
 }
@@ -0,0 +1,169 @@
+//! Graceful shutdown: track in-flight agent/loop operations and drain them
+//! within a bounded grace period before the daemon forces termination.
+
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use tokio::sync::Notify;
+
+/// Default time to wait for in-flight operations after SIGTERM/SIGINT.
+pub const DEFAULT_SHUTDOWN_GRACE: Duration = Duration::from_secs(30);
+
+#[derive(Default)]
+struct InFlightInner {
+    active: AtomicUsize,
+    draining: AtomicBool,
+    notify: Notify,
+}
+
+/// Counter of in-flight operations shared between RPC handlers and the
+/// shutdown path. Clones share the same state.
+#[derive(Clone, Default)]
+pub struct InFlightOps {
+    inner: Arc<InFlightInner>,
+}
+
+/// Outcome of a drain, for shutdown logging.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DrainReport {
+    pub in_flight_at_start: usize,
+    pub drained: usize,
+    pub aborted: usize,
+    pub elapsed: Duration,
+}
+
+impl InFlightOps {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a new operation. Returns `None` once draining has started so
+    /// callers can reject new work.
+    pub fn try_begin(&self) -> Option<InFlightGuard> {
+        if self.is_draining() {
+            return None;
+        }
+        self.inner.active.fetch_add(1, Ordering::SeqCst);
+        let guard = InFlightGuard {
+            inner: Arc::clone(&self.inner),
+        };
+        if self.is_draining() {
+            // Lost the race with drain(); the guard's drop undoes the count.
+            return None;
+        }
+        Some(guard)
+    }
+
+    pub fn active(&self) -> usize {
+        self.inner.active.load(Ordering::SeqCst)
+    }
+
+    pub fn is_draining(&self) -> bool {
+        self.inner.draining.load(Ordering::SeqCst)
+    }
+
+    /// Stop accepting new operations and wait up to `grace` for the active
+    /// ones to finish.
+    pub async fn drain(&self, grace: Duration) -> DrainReport {
+        let started = Instant::now();
+        self.inner.draining.store(true, Ordering::SeqCst);
+        let in_flight_at_start = self.active();
+
+        loop {
+            let notified = self.inner.notify.notified();
+            if self.active() == 0 {
+                break;
+            }
+            let Some(remaining) = grace.checked_sub(started.elapsed()) else {
+                break;
+            };
+            if tokio::time::timeout(remaining, notified).await.is_err() {
+                break;
+            }
+        }
+
+        let aborted = self.active().min(in_flight_at_start);
+        DrainReport {
+            in_flight_at_start,
+            drained: in_flight_at_start - aborted,
+            aborted,
+            elapsed: started.elapsed(),
+        }
+    }
+}
+
+/// Marks one operation as in flight until dropped.
+pub struct InFlightGuard {
+    inner: Arc<InFlightInner>,
+}
+
+impl InFlightGuard {
+    /// Whether shutdown has started; long-running operations should wind
+    /// down so the drain does not wait out the whole grace period.
+    pub fn is_draining(&self) -> bool {
+        self.inner.draining.load(Ordering::SeqCst)
+    }
+}
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        self.inner.active.fetch_sub(1, Ordering::SeqCst);
+        self.inner.notify.notify_waiters();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::InFlightOps;
+
+    #[tokio::test]
+    async fn drain_waits_for_slow_operation() {
+        let ops = InFlightOps::new();
+        let guard = match ops.try_begin() {
+            Some(guard) => guard,
+            None => panic!("expected op to start"),
+        };
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            drop(guard);
+        });
+
+        let report = ops.drain(Duration::from_secs(5)).await;
+        assert_eq!(report.in_flight_at_start, 1);
+        assert_eq!(report.drained, 1);
+        assert_eq!(report.aborted, 0);
+        assert!(report.elapsed >= Duration::from_millis(90));
+        assert!(report.elapsed < Duration::from_secs(5));
+        assert_eq!(ops.active(), 0);
+    }
+
+    #[tokio::test]
+    async fn drain_gives_up_at_grace_deadline() {
+        let ops = InFlightOps::new();
+        let _stuck = match ops.try_begin() {
+            Some(guard) => guard,
+            None => panic!("expected op to start"),
+        };
+        let quick = ops.try_begin();
+        drop(quick);
+
+        let report = ops.drain(Duration::from_millis(50)).await;
+        assert_eq!(report.in_flight_at_start, 1);
+        assert_eq!(report.drained, 0);
+        assert_eq!(report.aborted, 1);
+        assert!(report.elapsed >= Duration::from_millis(50));
+    }
+
+    #[tokio::test]
+    async fn new_operations_are_rejected_while_draining() {
+        let ops = InFlightOps::new();
+        let report = ops.drain(Duration::from_millis(10)).await;
+        assert_eq!(report.in_flight_at_start, 0);
+        assert!(ops.is_draining());
+        assert!(ops.try_begin().is_none());
+        assert_eq!(ops.active(), 0);
+    }
+}
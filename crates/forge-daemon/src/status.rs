@@ -1,3 +1,4 @@
+use std::path::PathBuf;
 use std::process::Command;
 use std::sync::Arc;
 
@@ -5,6 +6,8 @@ use chrono::{DateTime, Duration, Utc};
 
 use forge_rpc::forged::v1 as proto;
 
+use crate::node_registry::{load_node_health, NodeHealth};
+
 type TmuxHealthProbe = Arc<dyn Fn() -> Result<(), String> + Send + Sync>;
 
 #[derive(Clone)]
@@ -13,6 +16,7 @@ pub struct StatusService {
     hostname: String,
     started_at: DateTime<Utc>,
     tmux_health_probe: TmuxHealthProbe,
+    node_health_dir: Option<PathBuf>,
 }
 
 impl StatusService {
@@ -22,6 +26,7 @@ impl StatusService {
             hostname: hostname.into(),
             started_at: Utc::now(),
             tmux_health_probe: Arc::new(default_tmux_health_probe),
+            node_health_dir: None,
         }
     }
 
@@ -38,6 +43,13 @@ impl StatusService {
         self
     }
 
+    /// Report mesh node reachability persisted by the liveness loop under
+    /// `data_dir` as a `nodes` health check.
+    pub fn with_node_health_dir(mut self, data_dir: impl Into<PathBuf>) -> Self {
+        self.node_health_dir = Some(data_dir.into());
+        self
+    }
+
     pub fn ping(&self) -> proto::PingResponse {
         proto::PingResponse {
             timestamp: Some(datetime_to_timestamp(Utc::now())),
@@ -80,6 +92,10 @@ impl StatusService {
             checks[0].message = format!("tmux error: {err}");
         }
 
+        if let Some(data_dir) = &self.node_health_dir {
+            checks.push(node_health_check(data_dir, now));
+        }
+
         proto::HealthStatus {
             health: overall_health(&checks) as i32,
             checks,
@@ -87,6 +103,41 @@ impl StatusService {
     }
 }
 
+fn node_health_check(data_dir: &std::path::Path, now: DateTime<Utc>) -> proto::HealthCheck {
+    let (health, message) = match load_node_health(data_dir) {
+        Ok(records) => {
+            let unreachable: Vec<&str> = records
+                .iter()
+                .filter(|record| record.health == NodeHealth::Unreachable)
+                .map(|record| record.node_id.as_str())
+                .collect();
+            if unreachable.is_empty() {
+                (
+                    proto::Health::Healthy,
+                    format!("{} nodes reachable", records.len()),
+                )
+            } else {
+                (
+                    proto::Health::Degraded,
+                    format!(
+                        "{} of {} nodes unreachable: {}",
+                        unreachable.len(),
+                        records.len(),
+                        unreachable.join(", ")
+                    ),
+                )
+            }
+        }
+        Err(err) => (proto::Health::Degraded, format!("node health: {err}")),
+    };
+    proto::HealthCheck {
+        name: "nodes".to_string(),
+        health: health as i32,
+        message,
+        last_check: Some(datetime_to_timestamp(now)),
+    }
+}
+
 fn overall_health(checks: &[proto::HealthCheck]) -> proto::Health {
     let mut overall = proto::Health::Healthy;
 
@@ -152,7 +203,7 @@ fn duration_to_prost(d: Duration) -> prost_types::Duration {
     prost_types::Duration { seconds, nanos }
 }
 
-fn default_tmux_health_probe() -> Result<(), String> {
+pub(crate) fn default_tmux_health_probe() -> Result<(), String> {
     let output = Command::new("tmux")
         .arg("list-sessions")
         .output()
@@ -175,6 +226,7 @@ fn default_tmux_health_probe() -> Result<(), String> {
 #[allow(clippy::expect_used)]
 mod tests {
     use super::{overall_health, proto, StatusService};
+    use crate::node_registry::{write_node_health, NodeHealthTracker};
     use chrono::{Duration, TimeZone, Utc};
 
     #[test]
@@ -235,6 +287,30 @@ mod tests {
         assert_eq!(health.checks[0].message, "tmux error: dial timeout");
     }
 
+    #[test]
+    fn get_status_degrades_when_a_mesh_node_is_unreachable() {
+        let dir = std::env::temp_dir().join(format!(
+            "forge-status-nodes-{}-{}",
+            std::process::id(),
+            Utc::now().timestamp_nanos_opt().unwrap_or(0)
+        ));
+        let mut tracker = NodeHealthTracker::new(1);
+        tracker.track("node-a", "127.0.0.1:1");
+        tracker.track("node-b", "127.0.0.1:2");
+        let _ = tracker.record_probe("node-a", Err("refused".to_string()), Utc::now());
+        write_node_health(&dir, &tracker.snapshot()).expect("write node health");
+
+        let health = StatusService::new("dev", "node")
+            .with_tmux_health_probe(|| Ok(()))
+            .with_node_health_dir(&dir)
+            .get_health_status();
+
+        assert_eq!(health.health, proto::Health::Degraded as i32);
+        assert_eq!(health.checks[1].name, "nodes");
+        assert_eq!(health.checks[1].message, "1 of 2 nodes unreachable: node-a");
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
     #[test]
     fn overall_health_prefers_unhealthy_then_degraded() {
         let healthy = proto::HealthCheck {
@@ -2,6 +2,19 @@
 
 use std::process::Command;
 
+use thiserror::Error;
+
+/// Typed failures for tmux operations that callers need to tell apart.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum TmuxError {
+    #[error("target is required")]
+    MissingTarget,
+    #[error("tmux pane {0:?} not found")]
+    PaneNotFound(String),
+    #[error("{0}")]
+    CommandFailed(String),
+}
+
 /// Trait for sending keys/text to a tmux pane.
 ///
 /// Abstracted for testability — the default implementation shells out to tmux.
@@ -18,6 +31,24 @@ pub trait TmuxClient: Send + Sync {
     /// If `include_history` is true, include scrollback (`-S -`).
     fn capture_pane(&self, target: &str, include_history: bool) -> Result<String, String>;
 
+    /// Capture pane scrollback history, bounded to the last `lines` lines
+    /// when given (`capture-pane -S -<lines>`), or the full history otherwise.
+    ///
+    /// The default implementation captures full history via `capture_pane`
+    /// and trims it locally.
+    fn capture_scrollback(&self, target: &str, lines: Option<usize>) -> Result<String, TmuxError> {
+        if target.trim().is_empty() {
+            return Err(TmuxError::MissingTarget);
+        }
+        let content = self
+            .capture_pane(target, true)
+            .map_err(|err| classify_tmux_failure(target, &err))?;
+        Ok(match lines {
+            Some(limit) => last_lines(&content, limit),
+            None => content,
+        })
+    }
+
     /// Check if a tmux session exists.
     fn has_session(&self, session_name: &str) -> Result<bool, String>;
 
@@ -96,6 +127,20 @@ impl TmuxClient for ShellTmuxClient {
         exec_shell_output(&cmd_str)
     }
 
+    fn capture_scrollback(&self, target: &str, lines: Option<usize>) -> Result<String, TmuxError> {
+        capture_scrollback_with(target, lines, |args| {
+            let output = Command::new("tmux")
+                .args(args)
+                .output()
+                .map_err(|e| format!("failed to execute tmux command: {e}"))?;
+            Ok(TmuxOutput {
+                success: output.status.success(),
+                stdout: String::from_utf8_lossy(&output.stdout).into_owned(),
+                stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+            })
+        })
+    }
+
     fn has_session(&self, session_name: &str) -> Result<bool, String> {
         if session_name.trim().is_empty() {
             return Err("session_name is required".to_string());
@@ -172,6 +217,80 @@ impl TmuxClient for ShellTmuxClient {
     }
 }
 
+/// Raw result of a tmux invocation, separated from process spawning so the
+/// argument building and error mapping can be exercised with a fake runner.
+struct TmuxOutput {
+    success: bool,
+    stdout: String,
+    stderr: String,
+}
+
+fn scrollback_capture_args(target: &str, lines: Option<usize>) -> Vec<String> {
+    let start = match lines {
+        Some(limit) => format!("-{limit}"),
+        None => "-".to_string(),
+    };
+    vec![
+        "capture-pane".to_string(),
+        "-t".to_string(),
+        target.to_string(),
+        "-p".to_string(),
+        "-J".to_string(),
+        "-S".to_string(),
+        start,
+    ]
+}
+
+fn capture_scrollback_with<F>(
+    target: &str,
+    lines: Option<usize>,
+    run: F,
+) -> Result<String, TmuxError>
+where
+    F: FnOnce(&[String]) -> Result<TmuxOutput, String>,
+{
+    if target.trim().is_empty() {
+        return Err(TmuxError::MissingTarget);
+    }
+
+    let output = run(&scrollback_capture_args(target, lines)).map_err(TmuxError::CommandFailed)?;
+    if !output.success {
+        return Err(classify_tmux_failure(target, output.stderr.trim()));
+    }
+
+    Ok(match lines {
+        Some(limit) => last_lines(&output.stdout, limit),
+        None => output.stdout,
+    })
+}
+
+/// Map tmux's "can't find pane/window/session" diagnostics to
+/// [`TmuxError::PaneNotFound`].
+fn classify_tmux_failure(target: &str, message: &str) -> TmuxError {
+    let lower = message.to_ascii_lowercase();
+    if lower.contains("can't find") || lower.contains("no such") || lower.contains("not found") {
+        return TmuxError::PaneNotFound(target.to_string());
+    }
+    if message.is_empty() {
+        return TmuxError::CommandFailed("tmux command failed".to_string());
+    }
+    TmuxError::CommandFailed(format!("tmux command failed: {message}"))
+}
+
+/// Keep at most the last `limit` lines of `content`. `capture-pane -S -N`
+/// also returns the visible screen, so the bound is applied after capture.
+fn last_lines(content: &str, limit: usize) -> String {
+    let lines: Vec<&str> = content.lines().collect();
+    if lines.len() <= limit {
+        return content.to_string();
+    }
+    let mut out = lines[lines.len() - limit..].join("\n");
+    if content.ends_with('\n') {
+        out.push('\n');
+    }
+    out
+}
+
 /// Shell-escape an argument using single quotes.
 fn escape_arg(arg: &str) -> String {
     format!("'{}'", arg.replace('\'', "'\\''"))
@@ -204,3 +323,84 @@ fn exec_shell_output(cmd: &str) -> Result<String, String> {
 
     String::from_utf8(output.stdout).map_err(|e| format!("tmux output was not valid UTF-8: {e}"))
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::{capture_scrollback_with, TmuxError, TmuxOutput};
+
+    #[test]
+    fn capture_scrollback_passes_bounded_history_args() {
+        let seen = Mutex::new(Vec::new());
+        let result = capture_scrollback_with("%3", Some(200), |args| {
+            if let Ok(mut guard) = seen.lock() {
+                *guard = args.to_vec();
+            }
+            Ok(TmuxOutput {
+                success: true,
+                stdout: "one\ntwo\n".to_string(),
+                stderr: String::new(),
+            })
+        });
+
+        assert_eq!(result, Ok("one\ntwo\n".to_string()));
+        let args = match seen.lock() {
+            Ok(guard) => guard.clone(),
+            Err(poisoned) => poisoned.into_inner().clone(),
+        };
+        assert_eq!(
+            args,
+            vec!["capture-pane", "-t", "%3", "-p", "-J", "-S", "-200"]
+        );
+    }
+
+    #[test]
+    fn capture_scrollback_without_bound_requests_full_history() {
+        let seen = Mutex::new(Vec::new());
+        let _ = capture_scrollback_with("%3", None, |args| {
+            if let Ok(mut guard) = seen.lock() {
+                *guard = args.to_vec();
+            }
+            Ok(TmuxOutput {
+                success: true,
+                stdout: String::new(),
+                stderr: String::new(),
+            })
+        });
+        let args = match seen.lock() {
+            Ok(guard) => guard.clone(),
+            Err(poisoned) => poisoned.into_inner().clone(),
+        };
+        assert_eq!(args.last().map(String::as_str), Some("-"));
+    }
+
+    #[test]
+    fn capture_scrollback_trims_to_line_bound() {
+        let result = capture_scrollback_with("%3", Some(2), |_| {
+            Ok(TmuxOutput {
+                success: true,
+                stdout: "a\nb\nc\nd\n".to_string(),
+                stderr: String::new(),
+            })
+        });
+        assert_eq!(result, Ok("c\nd\n".to_string()));
+    }
+
+    #[test]
+    fn capture_scrollback_missing_pane_is_typed_error() {
+        let result = capture_scrollback_with("%99", Some(10), |_| {
+            Ok(TmuxOutput {
+                success: false,
+                stdout: String::new(),
+                stderr: "can't find pane: %99\n".to_string(),
+            })
+        });
+        assert_eq!(result, Err(TmuxError::PaneNotFound("%99".to_string())));
+
+        let empty = capture_scrollback_with("  ", None, |_| {
+            panic!("runner should not be called for an empty target")
+        });
+        assert_eq!(empty, Err(TmuxError::MissingTarget));
+    }
+}
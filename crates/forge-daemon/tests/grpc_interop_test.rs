@@ -11,9 +11,12 @@ use std::net::SocketAddr;
 use std::sync::Arc;
 
 use forge_daemon::agent::AgentManager;
+use forge_daemon::health::HealthService;
 use forge_daemon::server::ForgedAgentService;
 use forge_daemon::tmux::TmuxClient;
 use forge_rpc::forged::v1 as proto;
+use forge_rpc::forged::v1::forged_health_client::ForgedHealthClient;
+use forge_rpc::forged::v1::forged_health_server::ForgedHealthServer;
 use forge_rpc::forged::v1::forged_service_client::ForgedServiceClient;
 use forge_rpc::forged::v1::forged_service_server::ForgedServiceServer;
 use tonic::transport::{Channel, Server};
@@ -677,3 +680,49 @@ async fn stream_transcript_round_trip() {
         "first chunk should contain entries"
     );
 }
+
+#[tokio::test]
+async fn health_check_reports_not_serving_until_initialized() {
+    let health = HealthService::new().with_tmux_probe(|| Ok(()));
+    let server_health = health.clone();
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr: SocketAddr = listener.local_addr().unwrap();
+    let incoming = tokio_stream::wrappers::TcpListenerStream::new(listener);
+
+    tokio::spawn(async move {
+        Server::builder()
+            .add_service(ForgedHealthServer::new(server_health))
+            .serve_with_incoming(incoming)
+            .await
+            .unwrap();
+    });
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+
+    let channel = Channel::from_shared(format!("http://{addr}"))
+        .unwrap()
+        .connect()
+        .await
+        .unwrap();
+    let mut client = ForgedHealthClient::new(channel);
+
+    let before = client
+        .check(proto::HealthCheckRequest::default())
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(before.status, proto::ServingStatus::NotServing as i32);
+    assert!(!before.ready);
+
+    health.set_serving(true);
+
+    let after = client
+        .check(proto::HealthCheckRequest::default())
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(after.status, proto::ServingStatus::Serving as i32);
+    assert!(after.ready);
+    assert_eq!(after.checks.len(), 1);
+    assert_eq!(after.checks[0].name, "tmux");
+}
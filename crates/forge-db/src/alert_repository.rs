@@ -1,9 +1,9 @@
 //! Alert repository — persistence for the `alerts` table.
 
-use rusqlite::{params, OptionalExtension};
+use rusqlite::{params, params_from_iter, types::Value, OptionalExtension};
 use uuid::Uuid;
 
-use crate::{Db, DbError};
+use crate::{Db, DbError, Page};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum AlertType {
@@ -95,6 +95,31 @@ impl Default for Alert {
     }
 }
 
+/// Filter for [`AlertRepository::list`]. `None` fields match everything.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AlertFilter {
+    pub severity: Option<AlertSeverity>,
+    pub is_resolved: Option<bool>,
+    pub workspace_id: Option<String>,
+    pub agent_id: Option<String>,
+    /// Page size; defaults to 100 when not positive.
+    pub limit: i64,
+    /// Opaque cursor from a previous page's `next_cursor`.
+    pub cursor: String,
+}
+
+/// Sort order for [`AlertRepository::list`]. It matches
+/// `idx_alerts_list_order` (migration 023) term for term so SQLite walks the
+/// index instead of sorting; change both together.
+const LIST_ORDER_BY: &str = "is_resolved,
+    CASE severity
+        WHEN 'critical' THEN 0
+        WHEN 'error' THEN 1
+        WHEN 'warning' THEN 2
+        ELSE 3
+    END,
+    created_at DESC, id";
+
 pub struct AlertRepository<'a> {
     db: &'a Db,
 }
@@ -202,6 +227,72 @@ impl<'a> AlertRepository<'a> {
         Ok(alerts)
     }
 
+    /// Filtered, paginated listing. Unresolved alerts come first, then by
+    /// severity (critical first), newest first within a severity.
+    pub fn list(&self, filter: AlertFilter) -> Result<Page<Alert>, DbError> {
+        let limit = if filter.limit <= 0 { 100 } else { filter.limit };
+        let offset = if filter.cursor.is_empty() {
+            0
+        } else {
+            match filter.cursor.parse::<i64>() {
+                Ok(value) if value >= 0 => value,
+                _ => {
+                    return Err(DbError::Validation(format!(
+                        "invalid alert cursor: {}",
+                        filter.cursor
+                    )))
+                }
+            }
+        };
+
+        let mut query = String::from(
+            "SELECT
+                id, workspace_id, agent_id, type,
+                severity, message, is_resolved, created_at, resolved_at
+             FROM alerts
+             WHERE 1=1",
+        );
+        let mut args: Vec<Value> = Vec::new();
+
+        if let Some(severity) = filter.severity {
+            query.push_str(" AND severity = ?");
+            args.push(Value::from(severity.as_str().to_string()));
+        }
+        if let Some(is_resolved) = filter.is_resolved {
+            query.push_str(" AND is_resolved = ?");
+            args.push(Value::from(bool_to_int(is_resolved)));
+        }
+        if let Some(workspace_id) = filter.workspace_id {
+            query.push_str(" AND workspace_id = ?");
+            args.push(Value::from(workspace_id));
+        }
+        if let Some(agent_id) = filter.agent_id {
+            query.push_str(" AND agent_id = ?");
+            args.push(Value::from(agent_id));
+        }
+
+        query.push_str(" ORDER BY ");
+        query.push_str(LIST_ORDER_BY);
+        query.push_str(" LIMIT ? OFFSET ?");
+        args.push(Value::from(limit + 1));
+        args.push(Value::from(offset));
+
+        let mut stmt = self.db.conn().prepare(&query)?;
+        let rows = stmt.query_map(params_from_iter(args.iter()), scan_alert)?;
+        let mut items = Vec::new();
+        for row in rows {
+            items.push(row?);
+        }
+
+        let next_cursor = if (items.len() as i64) > limit {
+            items.truncate(limit as usize);
+            (offset + limit).to_string()
+        } else {
+            String::new()
+        };
+        Ok(Page { items, next_cursor })
+    }
+
     pub fn resolve(&self, id: &str) -> Result<(), DbError> {
         if id.trim().is_empty() {
             return Err(DbError::Validation("alert id is required".into()));
@@ -272,3 +363,46 @@ fn to_sql_conversion_error(err: DbError) -> rusqlite::Error {
         )),
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Config;
+    use std::path::PathBuf;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    fn temp_db_path(tag: &str) -> PathBuf {
+        let nanos = match SystemTime::now().duration_since(UNIX_EPOCH) {
+            Ok(duration) => duration.as_nanos(),
+            Err(_) => 0,
+        };
+        std::env::temp_dir().join(format!(
+            "forge-db-alert-{tag}-{nanos}-{}.sqlite",
+            std::process::id()
+        ))
+    }
+
+    #[test]
+    fn list_order_is_served_by_index() {
+        let path = temp_db_path("list-order-plan");
+        let mut db = Db::open(Config::new(&path)).unwrap_or_else(|e| panic!("open db: {e}"));
+        db.migrate_up().unwrap_or_else(|e| panic!("migrate: {e}"));
+
+        let sql = format!("EXPLAIN QUERY PLAN SELECT id FROM alerts ORDER BY {LIST_ORDER_BY}");
+        let mut stmt = db
+            .conn()
+            .prepare(&sql)
+            .unwrap_or_else(|e| panic!("prepare plan: {e}"));
+        let plan = stmt
+            .query_map([], |row| row.get::<_, String>(3))
+            .unwrap_or_else(|e| panic!("query plan: {e}"))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap_or_else(|e| panic!("read plan: {e}"))
+            .join("\n");
+
+        assert!(plan.contains("idx_alerts_list_order"), "plan: {plan}");
+        assert!(!plan.contains("TEMP B-TREE"), "plan: {plan}");
+
+        let _ = std::fs::remove_file(path);
+    }
+}
@@ -1,10 +1,19 @@
 //! Approval repository — CRUD for the `approvals` table with Go parity.
 
+use std::time::Duration;
+
 use rusqlite::{params, OptionalExtension};
 use uuid::Uuid;
 
+use crate::event_repository::{Event, EventRepository};
 use crate::{Db, DbError};
 
+/// Event recorded for each approval auto-denied by [`ApprovalRepository::expire_pending`].
+pub const APPROVAL_EXPIRED_EVENT: &str = "approval.expired";
+
+/// `resolved_by` value for approvals expired on timeout.
+pub const APPROVAL_EXPIRY_RESOLVER: &str = "system";
+
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub enum ApprovalStatus {
     #[default]
@@ -47,6 +56,9 @@ pub struct Approval {
     pub created_at: String,
     pub resolved_at: Option<String>,
     pub resolved_by: String,
+    /// Deadline after which a still-pending approval is expired. `None`
+    /// waits indefinitely.
+    pub expires_at: Option<String>,
 }
 
 pub struct ApprovalRepository<'a> {
@@ -79,8 +91,8 @@ impl<'a> ApprovalRepository<'a> {
         self.db.conn().execute(
             "INSERT INTO approvals (
                 id, agent_id, request_type, request_details_json,
-                status, created_at, resolved_at, resolved_by
-            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+                status, created_at, resolved_at, resolved_by, expires_at
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
             params![
                 approval.id,
                 approval.agent_id,
@@ -90,18 +102,29 @@ impl<'a> ApprovalRepository<'a> {
                 approval.created_at,
                 approval.resolved_at,
                 nullable_string(&approval.resolved_by),
+                approval.expires_at,
             ],
         )?;
 
         Ok(())
     }
 
+    /// Create an approval that expires `ttl` after creation.
+    pub fn create_with_deadline(
+        &self,
+        approval: &mut Approval,
+        ttl: Duration,
+    ) -> Result<(), DbError> {
+        approval.expires_at = Some(crate::rfc3339_after(ttl));
+        self.create(approval)
+    }
+
     /// List pending approvals for one agent, oldest first.
     pub fn list_pending_by_agent(&self, agent_id: &str) -> Result<Vec<Approval>, DbError> {
         let mut stmt = self.db.conn().prepare(
             "SELECT
                 id, agent_id, request_type, request_details_json,
-                status, created_at, resolved_at, resolved_by
+                status, created_at, resolved_at, resolved_by, expires_at
              FROM approvals
              WHERE agent_id = ?1 AND status = 'pending'
              ORDER BY created_at",
@@ -142,6 +165,76 @@ impl<'a> ApprovalRepository<'a> {
         Ok(())
     }
 
+    /// Expire every pending approval whose deadline is at or before `now`
+    /// (RFC3339), recording an [`APPROVAL_EXPIRED_EVENT`] for each.
+    /// Returns how many approvals were expired.
+    pub fn expire_pending(&self, now: &str) -> Result<usize, DbError> {
+        // One transaction keeps each status flip and its event together.
+        if self.db.conn().is_autocommit() {
+            self.db.immediate_transaction(|_| self.expire_overdue(now))
+        } else {
+            self.expire_overdue(now)
+        }
+    }
+
+    fn expire_overdue(&self, now: &str) -> Result<usize, DbError> {
+        let mut stmt = self.db.conn().prepare(
+            "SELECT id, agent_id, expires_at
+             FROM approvals
+             WHERE status = 'pending' AND expires_at IS NOT NULL AND expires_at <= ?1
+             ORDER BY expires_at, id",
+        )?;
+        let rows = stmt.query_map(params![now], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+            ))
+        })?;
+        let mut overdue = Vec::new();
+        for row in rows {
+            overdue.push(row?);
+        }
+        drop(stmt);
+
+        let events = EventRepository::new(self.db);
+        let mut expired = 0usize;
+        for (id, agent_id, expires_at) in overdue {
+            // Guard on status so an approval resolved since the scan keeps
+            // its decision.
+            let rows = self.db.conn().execute(
+                "UPDATE approvals
+                 SET status = ?1, resolved_at = ?2, resolved_by = ?3
+                 WHERE id = ?4 AND status = 'pending'",
+                params![
+                    ApprovalStatus::Expired.as_str(),
+                    now,
+                    APPROVAL_EXPIRY_RESOLVER,
+                    id
+                ],
+            )?;
+            if rows == 0 {
+                continue;
+            }
+            expired += 1;
+
+            let mut event = Event {
+                timestamp: now.to_string(),
+                event_type: APPROVAL_EXPIRED_EVENT.to_string(),
+                entity_type: "agent".to_string(),
+                entity_id: agent_id,
+                payload: serde_json::json!({
+                    "approval_id": id,
+                    "expires_at": expires_at,
+                })
+                .to_string(),
+                ..Default::default()
+            };
+            events.create(&mut event)?;
+        }
+        Ok(expired)
+    }
+
     pub fn get(&self, id: &str) -> Result<Approval, DbError> {
         let result = self
             .db
@@ -149,7 +242,7 @@ impl<'a> ApprovalRepository<'a> {
             .query_row(
                 "SELECT
                     id, agent_id, request_type, request_details_json,
-                    status, created_at, resolved_at, resolved_by
+                    status, created_at, resolved_at, resolved_by, expires_at
                  FROM approvals
                  WHERE id = ?1",
                 params![id],
@@ -184,6 +277,7 @@ fn scan_approval(row: &rusqlite::Row<'_>) -> rusqlite::Result<Approval> {
         created_at: row.get(5)?,
         resolved_at: row.get(6)?,
         resolved_by: resolved_by.unwrap_or_default(),
+        expires_at: row.get(8)?,
     })
 }
 
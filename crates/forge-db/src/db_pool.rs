@@ -0,0 +1,97 @@
+//! Bounded read-connection pool with a single writer.
+//!
+//! Under WAL, readers never block the writer (or each other), so concurrent
+//! read RPCs can run on separate connections while writes and migrations stay
+//! serialized through one [`Db`].
+
+use std::sync::{Condvar, Mutex, MutexGuard};
+
+use rusqlite::Connection;
+
+use crate::{open_connection, Config, Db, DbError};
+
+pub struct DbPool {
+    writer: Mutex<Db>,
+    readers: Mutex<Vec<Connection>>,
+    reader_released: Condvar,
+}
+
+impl DbPool {
+    pub const DEFAULT_READERS: usize = 4;
+
+    /// Open the writer plus `readers` read-only connections (at least one).
+    ///
+    /// Every connection gets the same pragmas as [`Db::open`]; readers are
+    /// additionally marked `query_only`.
+    pub fn open(cfg: Config, readers: usize) -> Result<Self, DbError> {
+        // Open the writer first so the file exists and WAL is enabled before
+        // any reader attaches.
+        let writer = Db::open(cfg.clone())?;
+        let mut pool = Vec::with_capacity(readers.max(1));
+        for _ in 0..readers.max(1) {
+            let conn = open_connection(&cfg)?;
+            conn.pragma_update(None, "query_only", "ON")?;
+            pool.push(conn);
+        }
+        Ok(Self {
+            writer: Mutex::new(writer),
+            readers: Mutex::new(pool),
+            reader_released: Condvar::new(),
+        })
+    }
+
+    /// Run `f` on a pooled read-only connection, waiting for one to free up
+    /// if all are in use.
+    pub fn read<T>(&self, f: impl FnOnce(&Connection) -> Result<T, DbError>) -> Result<T, DbError> {
+        let mut idle = recover(self.readers.lock());
+        let conn = loop {
+            if let Some(conn) = idle.pop() {
+                break conn;
+            }
+            idle = recover(self.reader_released.wait(idle));
+        };
+        drop(idle);
+
+        let reader = PooledReader {
+            pool: self,
+            conn: Some(conn),
+        };
+        let result = match reader.conn.as_ref() {
+            Some(conn) => f(conn),
+            None => Err(DbError::Transaction("pooled reader missing".to_string())),
+        };
+        drop(reader);
+        result
+    }
+
+    /// Run `f` with exclusive access to the writer connection.
+    pub fn write<T>(&self, f: impl FnOnce(&mut Db) -> Result<T, DbError>) -> Result<T, DbError> {
+        let mut writer = recover(self.writer.lock());
+        f(&mut writer)
+    }
+}
+
+/// Returns its connection to the pool on drop, even if the read closure panics.
+struct PooledReader<'a> {
+    pool: &'a DbPool,
+    conn: Option<Connection>,
+}
+
+impl Drop for PooledReader<'_> {
+    fn drop(&mut self) {
+        if let Some(conn) = self.conn.take() {
+            recover(self.pool.readers.lock()).push(conn);
+            self.pool.reader_released.notify_one();
+        }
+    }
+}
+
+// A panic inside a closure leaves the connections themselves usable.
+fn recover<'a, T>(
+    result: Result<MutexGuard<'a, T>, std::sync::PoisonError<MutexGuard<'a, T>>>,
+) -> MutexGuard<'a, T> {
+    match result {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    }
+}
@@ -1,8 +1,20 @@
 //! Event repository — persistence for append-only `events` audit log.
+//!
+//! Each inserted row is hash-chained to the row inserted before it:
+//! `chain_hash = sha256(prev_hash, row columns)`, so editing or removing a
+//! row in the middle of the log breaks the chain at that point. The
+//! `event_chain_anchor` row records the hash the oldest event must link to
+//! and the hash of the newest event, so removing rows from either end is
+//! caught too. Retention prunes in insertion (rowid) order and moves the
+//! genesis hash forward as it goes.
 
 use std::collections::HashMap;
 
-use rusqlite::{params, params_from_iter, types::Value, OptionalExtension};
+use rusqlite::{
+    params, params_from_iter, types::Value, Connection, OptionalExtension, Transaction,
+    TransactionBehavior,
+};
+use sha2::{Digest, Sha256};
 use uuid::Uuid;
 
 use crate::{Db, DbError};
@@ -35,10 +47,245 @@ pub struct EventPage {
     pub next_cursor: String,
 }
 
+/// An event with its hash-chain links. Rows written before the chain
+/// existed have empty hashes.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ChainedEvent {
+    pub event: Event,
+    pub prev_hash: String,
+    pub chain_hash: String,
+}
+
+/// First point where the chain fails to verify.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChainBreak {
+    pub event_id: String,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ChainVerification {
+    /// Chained events checked before the first break (or all of them).
+    pub verified: usize,
+    /// Leading events written before the chain existed.
+    pub unchained: usize,
+    /// Hash of the last verified event.
+    pub head_hash: String,
+    pub first_break: Option<ChainBreak>,
+}
+
 fn now_rfc3339() -> String {
     crate::now_rfc3339()
 }
 
+/// Raw column values covered by the chain hash. NULL and empty strings hash
+/// differently so a payload cannot be silently dropped.
+struct ChainColumns<'a> {
+    id: &'a str,
+    timestamp: &'a str,
+    event_type: &'a str,
+    entity_type: &'a str,
+    entity_id: &'a str,
+    payload_json: Option<&'a str>,
+    metadata_json: Option<&'a str>,
+}
+
+fn chain_hash(prev_hash: &str, columns: &ChainColumns<'_>) -> String {
+    let mut hasher = Sha256::new();
+    for value in [
+        Some(prev_hash),
+        Some(columns.id),
+        Some(columns.timestamp),
+        Some(columns.event_type),
+        Some(columns.entity_type),
+        Some(columns.entity_id),
+        columns.payload_json,
+        columns.metadata_json,
+    ] {
+        // Length-prefix each column so field boundaries are unambiguous.
+        match value {
+            Some(value) => {
+                hasher.update((value.len() as u64).to_be_bytes());
+                hasher.update(value.as_bytes());
+            }
+            None => hasher.update(u64::MAX.to_be_bytes()),
+        }
+    }
+    hex::encode(hasher.finalize())
+}
+
+/// A chained row as stored, keeping the raw JSON columns the hash covers.
+struct RawChainRow {
+    chained: ChainedEvent,
+    payload_json: Option<String>,
+    metadata_json: Option<String>,
+}
+
+impl RawChainRow {
+    fn expected_hash(&self) -> String {
+        let event = &self.chained.event;
+        chain_hash(
+            &self.chained.prev_hash,
+            &ChainColumns {
+                id: &event.id,
+                timestamp: &event.timestamp,
+                event_type: &event.event_type,
+                entity_type: &event.entity_type,
+                entity_id: &event.entity_id,
+                payload_json: self.payload_json.as_deref(),
+                metadata_json: self.metadata_json.as_deref(),
+            },
+        )
+    }
+}
+
+fn scan_chain_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<RawChainRow> {
+    let event = scan_event_row(row)?;
+    let prev_hash: Option<String> = row.get(7)?;
+    let chain_hash: Option<String> = row.get(8)?;
+    Ok(RawChainRow {
+        chained: ChainedEvent {
+            event,
+            prev_hash: prev_hash.unwrap_or_default(),
+            chain_hash: chain_hash.unwrap_or_default(),
+        },
+        payload_json: row.get(5)?,
+        metadata_json: row.get(6)?,
+    })
+}
+
+/// Stored chain endpoints (migration 022).
+#[derive(Debug, Clone, Default)]
+struct ChainAnchor {
+    genesis_hash: String,
+    tail_hash: String,
+}
+
+/// The stored anchor, or `None` on databases migrated below 022.
+fn load_anchor(conn: &Connection) -> Result<Option<ChainAnchor>, DbError> {
+    let has_table: bool = conn.query_row(
+        "SELECT EXISTS (
+            SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = 'event_chain_anchor'
+        )",
+        [],
+        |row| row.get(0),
+    )?;
+    if !has_table {
+        return Ok(None);
+    }
+    let anchor = conn
+        .query_row(
+            "SELECT genesis_hash, tail_hash FROM event_chain_anchor WHERE id = 1",
+            [],
+            |row| {
+                Ok(ChainAnchor {
+                    genesis_hash: row.get(0)?,
+                    tail_hash: row.get(1)?,
+                })
+            },
+        )
+        .optional()?;
+    Ok(Some(anchor.unwrap_or_default()))
+}
+
+/// Read the chain tail and insert `event` linked onto it.
+///
+/// The tail comes from the anchor rather than the newest row, so an event
+/// written after the tail was deleted still links to the missing hash and
+/// the gap stays visible.
+fn insert_chained(
+    conn: &Connection,
+    event: &Event,
+    metadata_json: Option<&str>,
+) -> Result<(), DbError> {
+    let anchor = load_anchor(conn)?;
+    let prev_hash: String = match &anchor {
+        Some(anchor) => anchor.tail_hash.clone(),
+        None => conn
+            .query_row(
+                "SELECT chain_hash FROM events
+                 WHERE chain_hash IS NOT NULL
+                 ORDER BY rowid DESC
+                 LIMIT 1",
+                [],
+                |row| row.get(0),
+            )
+            .optional()?
+            .unwrap_or_default(),
+    };
+    let payload_json = nullable_string(&event.payload);
+    let hash = chain_hash(
+        &prev_hash,
+        &ChainColumns {
+            id: &event.id,
+            timestamp: &event.timestamp,
+            event_type: &event.event_type,
+            entity_type: &event.entity_type,
+            entity_id: &event.entity_id,
+            payload_json,
+            metadata_json,
+        },
+    );
+
+    conn.execute(
+        "INSERT INTO events (
+            id, timestamp, type, entity_type, entity_id, payload_json, metadata_json,
+            prev_hash, chain_hash
+        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+        params![
+            event.id,
+            event.timestamp,
+            event.event_type,
+            event.entity_type,
+            event.entity_id,
+            payload_json,
+            metadata_json,
+            prev_hash,
+            hash,
+        ],
+    )?;
+    if anchor.is_some() {
+        conn.execute(
+            "INSERT INTO event_chain_anchor (id, tail_hash) VALUES (1, ?1)
+             ON CONFLICT(id) DO UPDATE SET tail_hash = excluded.tail_hash",
+            params![hash],
+        )?;
+    }
+    Ok(())
+}
+
+/// Delete every event up to and including `last_rowid` and move the genesis
+/// hash to the last chained event removed, so the new oldest event still
+/// verifies.
+fn delete_through(conn: &Connection, last_rowid: i64) -> Result<i64, DbError> {
+    let genesis: Option<String> = conn
+        .query_row(
+            "SELECT chain_hash FROM events
+             WHERE rowid <= ?1 AND chain_hash IS NOT NULL
+             ORDER BY rowid DESC
+             LIMIT 1",
+            params![last_rowid],
+            |row| row.get(0),
+        )
+        .optional()?;
+    let rows = conn.execute("DELETE FROM events WHERE rowid <= ?1", params![last_rowid])?;
+    if let (Some(genesis), Some(_)) = (genesis, load_anchor(conn)?) {
+        conn.execute(
+            "INSERT INTO event_chain_anchor (id, genesis_hash) VALUES (1, ?1)
+             ON CONFLICT(id) DO UPDATE SET genesis_hash = excluded.genesis_hash",
+            params![genesis],
+        )?;
+    }
+    Ok(rows as i64)
+}
+
+/// Rowid-ordered prefix of events whose timestamps are all before `before`.
+/// Pruning stops at the first newer event so it never cuts the chain.
+const OLDER_THAN_PREFIX: &str = "rowid < COALESCE(
+        (SELECT MIN(rowid) FROM events WHERE timestamp >= ?1),
+        (SELECT MAX(rowid) + 1 FROM events)
+    )";
+
 fn nullable_string(value: &str) -> Option<&str> {
     if value.is_empty() {
         None
@@ -112,20 +359,19 @@ impl<'a> EventRepository<'a> {
             None => None,
         };
 
-        self.db.conn().execute(
-            "INSERT INTO events (
-                id, timestamp, type, entity_type, entity_id, payload_json, metadata_json
-            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
-            params![
-                event.id,
-                event.timestamp,
-                event.event_type,
-                event.entity_type,
-                event.entity_id,
-                nullable_string(&event.payload),
-                metadata_json,
-            ],
-        )?;
+        // Every process opens its own connection, so the tail read and the
+        // insert must hold the write lock together; otherwise two writers can
+        // both chain onto the same tail. Inside a caller's transaction the
+        // caller owns atomicity (and must have written before, or opened it
+        // IMMEDIATE, to hold the lock).
+        let conn = self.db.conn();
+        if conn.is_autocommit() {
+            let tx = Transaction::new_unchecked(conn, TransactionBehavior::Immediate)?;
+            insert_chained(&tx, event, metadata_json.as_deref())?;
+            tx.commit()?;
+        } else {
+            insert_chained(conn, event, metadata_json.as_deref())?;
+        }
 
         Ok(())
     }
@@ -228,6 +474,53 @@ impl<'a> EventRepository<'a> {
         Ok(events)
     }
 
+    /// The newest `limit` events for one entity, returned oldest first.
+    pub fn list_recent_by_entity(
+        &self,
+        entity_type: &str,
+        entity_id: &str,
+        limit: i64,
+    ) -> Result<Vec<Event>, DbError> {
+        let limit = if limit <= 0 { 100 } else { limit };
+        let mut stmt = self.db.conn().prepare(
+            "SELECT id, timestamp, type, entity_type, entity_id, payload_json, metadata_json
+             FROM events
+             WHERE entity_type = ?1 AND entity_id = ?2
+             ORDER BY timestamp DESC, rowid DESC
+             LIMIT ?3",
+        )?;
+        let rows = stmt.query_map(params![entity_type, entity_id, limit], scan_event_row)?;
+        let mut events = Vec::new();
+        for row in rows {
+            events.push(row?);
+        }
+        events.reverse();
+        Ok(events)
+    }
+
+    /// Most recent event of `event_type` for one entity, if any.
+    pub fn latest_by_entity(
+        &self,
+        event_type: &str,
+        entity_type: &str,
+        entity_id: &str,
+    ) -> Result<Option<Event>, DbError> {
+        let event = self
+            .db
+            .conn()
+            .query_row(
+                "SELECT id, timestamp, type, entity_type, entity_id, payload_json, metadata_json
+                 FROM events
+                 WHERE type = ?1 AND entity_type = ?2 AND entity_id = ?3
+                 ORDER BY timestamp DESC, rowid DESC
+                 LIMIT 1",
+                params![event_type, entity_type, entity_id],
+                scan_event_row,
+            )
+            .optional()?;
+        Ok(event)
+    }
+
     pub fn count(&self) -> Result<i64, DbError> {
         let count: i64 = self
             .db
@@ -246,15 +539,24 @@ impl<'a> EventRepository<'a> {
         Ok(value)
     }
 
+    /// Delete up to `limit` of the oldest events, in insertion order,
+    /// stopping at the first event at or after `before`.
     pub fn delete_older_than(&self, before: &str, limit: i64) -> Result<i64, DbError> {
         let limit = if limit <= 0 { 1000 } else { limit };
-        let rows = self.db.conn().execute(
-            "DELETE FROM events WHERE id IN (
-                SELECT id FROM events WHERE timestamp < ?1 ORDER BY timestamp LIMIT ?2
-            )",
+        let conn = self.db.conn();
+        let last_rowid: Option<i64> = conn.query_row(
+            &format!(
+                "SELECT MAX(rowid) FROM (
+                    SELECT rowid FROM events WHERE {OLDER_THAN_PREFIX} ORDER BY rowid LIMIT ?2
+                )"
+            ),
             params![before, limit],
+            |row| row.get(0),
         )?;
-        Ok(rows as i64)
+        match last_rowid {
+            Some(last_rowid) => self.prune_through(last_rowid),
+            None => Ok(0),
+        }
     }
 
     pub fn delete_excess(&self, max_count: i64, limit: i64) -> Result<i64, DbError> {
@@ -268,24 +570,29 @@ impl<'a> EventRepository<'a> {
             return Ok(0);
         }
         let delete_count = excess.min(limit);
-        let rows = self.db.conn().execute(
-            "DELETE FROM events WHERE id IN (
-                SELECT id FROM events ORDER BY timestamp LIMIT ?1
+        let last_rowid: Option<i64> = self.db.conn().query_row(
+            "SELECT MAX(rowid) FROM (
+                SELECT rowid FROM events ORDER BY rowid LIMIT ?1
             )",
             params![delete_count],
+            |row| row.get(0),
         )?;
-        Ok(rows as i64)
+        match last_rowid {
+            Some(last_rowid) => self.prune_through(last_rowid),
+            None => Ok(0),
+        }
     }
 
+    /// The events [`Self::delete_older_than`] would remove, for archiving.
     pub fn list_older_than(&self, before: &str, limit: i64) -> Result<Vec<Event>, DbError> {
         let limit = if limit <= 0 { 1000 } else { limit };
-        let mut stmt = self.db.conn().prepare(
+        let mut stmt = self.db.conn().prepare(&format!(
             "SELECT id, timestamp, type, entity_type, entity_id, payload_json, metadata_json
              FROM events
-             WHERE timestamp < ?1
-             ORDER BY timestamp
+             WHERE {OLDER_THAN_PREFIX}
+             ORDER BY rowid
              LIMIT ?2",
-        )?;
+        ))?;
         let rows = stmt.query_map(params![before, limit], scan_event_row)?;
         let mut events = Vec::new();
         for row in rows {
@@ -299,7 +606,7 @@ impl<'a> EventRepository<'a> {
         let mut stmt = self.db.conn().prepare(
             "SELECT id, timestamp, type, entity_type, entity_id, payload_json, metadata_json
              FROM events
-             ORDER BY timestamp
+             ORDER BY rowid
              LIMIT ?1",
         )?;
         let rows = stmt.query_map(params![limit], scan_event_row)?;
@@ -310,6 +617,10 @@ impl<'a> EventRepository<'a> {
         Ok(events)
     }
 
+    /// Delete events by id. When the ids are the oldest events (as listed
+    /// by [`Self::list_oldest`] or [`Self::list_older_than`]) this is a
+    /// head prune and the genesis hash moves forward; deleting from the
+    /// middle of the log leaves a break for `verify_chain` to report.
     pub fn delete_by_ids(&self, ids: &[String]) -> Result<i64, DbError> {
         if ids.is_empty() {
             return Ok(0);
@@ -319,17 +630,138 @@ impl<'a> EventRepository<'a> {
             .take(ids.len())
             .collect::<Vec<_>>()
             .join(",");
-        let query = format!("DELETE FROM events WHERE id IN ({placeholders})");
-
         let values = ids
             .iter()
             .map(|id| Value::from(id.clone()))
             .collect::<Vec<_>>();
 
-        let rows = self
-            .db
-            .conn()
-            .execute(&query, params_from_iter(values.iter()))?;
+        let conn = self.db.conn();
+        let (matched, last_rowid): (i64, Option<i64>) = conn.query_row(
+            &format!("SELECT COUNT(*), MAX(rowid) FROM events WHERE id IN ({placeholders})"),
+            params_from_iter(values.iter()),
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )?;
+        let Some(last_rowid) = last_rowid else {
+            return Ok(0);
+        };
+        let through: i64 = conn.query_row(
+            "SELECT COUNT(*) FROM events WHERE rowid <= ?1",
+            params![last_rowid],
+            |row| row.get(0),
+        )?;
+        if through == matched {
+            return self.prune_through(last_rowid);
+        }
+
+        let rows = conn.execute(
+            &format!("DELETE FROM events WHERE id IN ({placeholders})"),
+            params_from_iter(values.iter()),
+        )?;
         Ok(rows as i64)
     }
+
+    fn prune_through(&self, last_rowid: i64) -> Result<i64, DbError> {
+        let conn = self.db.conn();
+        if conn.is_autocommit() {
+            let tx = Transaction::new_unchecked(conn, TransactionBehavior::Immediate)?;
+            let rows = delete_through(&tx, last_rowid)?;
+            tx.commit()?;
+            Ok(rows)
+        } else {
+            delete_through(conn, last_rowid)
+        }
+    }
+
+    /// Every event in insertion order with its chain links.
+    pub fn list_chain(&self) -> Result<Vec<ChainedEvent>, DbError> {
+        Ok(self
+            .chain_rows()?
+            .into_iter()
+            .map(|row| row.chained)
+            .collect())
+    }
+
+    /// Walk the chain in insertion order and report the first break.
+    ///
+    /// The walk starts from the stored genesis hash and must end on the
+    /// stored tail hash, so removing the oldest or newest events is a break;
+    /// retention pruning moves the genesis hash and is not. Databases below
+    /// migration 022 have no anchor and start from the first chained row.
+    pub fn verify_chain(&self) -> Result<ChainVerification, DbError> {
+        let anchor = load_anchor(self.db.conn())?;
+        let mut report = ChainVerification::default();
+        let mut started = false;
+        let mut expected_prev: Option<String> =
+            anchor.as_ref().map(|anchor| anchor.genesis_hash.clone());
+        let mut last_id = String::new();
+        for row in self.chain_rows()? {
+            let chained = &row.chained;
+            let reason = if chained.chain_hash.is_empty() {
+                if !started {
+                    report.unchained += 1;
+                    continue;
+                }
+                Some("missing chain hash")
+            } else if !started
+                && expected_prev
+                    .as_deref()
+                    .is_some_and(|prev| prev != chained.prev_hash)
+            {
+                Some("oldest event does not link to the chain genesis; leading events were removed")
+            } else if expected_prev
+                .as_deref()
+                .is_some_and(|prev| prev != chained.prev_hash)
+            {
+                Some("previous-hash link does not match the preceding event")
+            } else if row.expected_hash() != chained.chain_hash {
+                Some("event content does not match its chain hash")
+            } else {
+                None
+            };
+
+            if let Some(reason) = reason {
+                report.first_break = Some(ChainBreak {
+                    event_id: chained.event.id.clone(),
+                    reason: reason.to_string(),
+                });
+                break;
+            }
+            started = true;
+            report.verified += 1;
+            report.head_hash = chained.chain_hash.clone();
+            last_id = chained.event.id.clone();
+            expected_prev = Some(chained.chain_hash.clone());
+        }
+
+        if let Some(anchor) = anchor {
+            let head = if started {
+                report.head_hash.as_str()
+            } else {
+                anchor.genesis_hash.as_str()
+            };
+            if report.first_break.is_none() && head != anchor.tail_hash {
+                report.first_break = Some(ChainBreak {
+                    event_id: last_id,
+                    reason: "chain ends before the recorded tail; newest events were removed"
+                        .to_string(),
+                });
+            }
+        }
+        Ok(report)
+    }
+
+    fn chain_rows(&self) -> Result<Vec<RawChainRow>, DbError> {
+        let mut stmt = self.db.conn().prepare(
+            "SELECT id, timestamp, type, entity_type, entity_id, payload_json, metadata_json,
+                    prev_hash, chain_hash
+             FROM events
+             ORDER BY rowid",
+        )?;
+        let rows = stmt.query_map([], scan_chain_row)?;
+        let mut chain = Vec::new();
+        for row in rows {
+            chain.push(row?);
+        }
+        Ok(chain)
+    }
 }
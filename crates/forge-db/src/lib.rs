@@ -2,6 +2,7 @@
 
 pub mod alert_repository;
 pub mod approval_repository;
+pub mod db_pool;
 pub mod event_repository;
 pub mod file_lock_repository;
 pub mod loop_queue_repository;
@@ -9,11 +10,14 @@ pub mod loop_repository;
 pub mod loop_run_repository;
 pub mod loop_work_state_repository;
 pub mod mail_repository;
+pub mod metrics_repository;
 pub mod persistent_agent_event_repository;
 pub mod persistent_agent_repository;
 pub mod pool_repository;
 pub mod port_repository;
 pub mod profile_repository;
+#[cfg(feature = "trace")]
+pub mod query_trace;
 pub mod team_delegation;
 pub mod team_repository;
 pub mod team_task_repository;
@@ -28,6 +32,8 @@ use rusqlite::{params, Connection, OptionalExtension};
 use thiserror::Error;
 use uuid::Uuid;
 
+pub use db_pool::DbPool;
+
 include!(concat!(env!("OUT_DIR"), "/migrations.rs"));
 
 /// Crate identity label used for parity verification.
@@ -50,9 +56,18 @@ impl Config {
     }
 }
 
+/// One page of a paginated listing. `next_cursor` is empty on the last page.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub next_cursor: String,
+}
+
 #[derive(Debug)]
 pub struct Db {
     conn: Connection,
+    #[cfg(feature = "trace")]
+    slow_query_slot: Option<query_trace::HookSlot>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -63,6 +78,23 @@ pub struct MigrationStatus {
     pub applied_at: String,
 }
 
+/// Snapshot of an online backup in flight, reported after each copy step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BackupProgress {
+    pub remaining_pages: i32,
+    pub total_pages: i32,
+}
+
+impl BackupProgress {
+    pub fn copied_pages(&self) -> i32 {
+        (self.total_pages - self.remaining_pages).max(0)
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.remaining_pages <= 0
+    }
+}
+
 #[derive(Debug, Error)]
 pub enum DbError {
     #[error("open database: {0}")]
@@ -145,17 +177,19 @@ pub enum DbError {
 impl Db {
     const DEFAULT_RETRY_ATTEMPTS: usize = 3;
     const DEFAULT_RETRY_BACKOFF_MS: u64 = 50;
+    const BACKUP_PAGES_PER_STEP: i32 = 64;
+    const BACKUP_BUSY_BACKOFF_MS: u64 = 25;
+    const BACKUP_BUSY_MAX_RETRIES: u32 = 200;
+    const BACKUP_MAX_RESTARTS: u32 = 16;
 
     pub fn open(cfg: Config) -> Result<Self, DbError> {
         ensure_parent_dir(&cfg.path)?;
-        let conn = Connection::open(&cfg.path)?;
-        conn.busy_timeout(Duration::from_millis(cfg.busy_timeout_ms))?;
-        // Match Go connection defaults as closely as possible.
-        // Best-effort: ignore pragma errors on older SQLite builds.
-        let _ = conn.pragma_update(None, "journal_mode", "WAL");
-        let _ = conn.pragma_update(None, "foreign_keys", "ON");
-        let _ = conn.pragma_update(None, "synchronous", "NORMAL");
-        Ok(Self { conn })
+        let conn = open_connection(&cfg)?;
+        Ok(Self {
+            conn,
+            #[cfg(feature = "trace")]
+            slow_query_slot: None,
+        })
     }
 
     pub fn migrate_up(&mut self) -> Result<usize, DbError> {
@@ -329,6 +363,70 @@ impl Db {
         Ok(())
     }
 
+    /// Copy the live database to `dest` using SQLite's online backup API.
+    ///
+    /// The source stays usable while the copy runs; writes from other
+    /// connections restart the copy so `dest` always ends up as a consistent
+    /// snapshot. After `BACKUP_MAX_RESTARTS` restarts the remaining pages are
+    /// copied in one step under a read lock, so steady writes cannot keep the
+    /// backup from finishing.
+    pub fn backup_to(&self, dest: &Path) -> Result<(), DbError> {
+        self.backup_to_with_progress(dest, |_| {})
+    }
+
+    /// Like [`Db::backup_to`], calling `on_progress` after every copy step.
+    ///
+    /// Busy/locked steps are retried with a short backoff; the backup fails
+    /// once `BACKUP_BUSY_MAX_RETRIES` consecutive steps make no progress.
+    pub fn backup_to_with_progress<F>(&self, dest: &Path, mut on_progress: F) -> Result<(), DbError>
+    where
+        F: FnMut(BackupProgress),
+    {
+        ensure_parent_dir(dest)?;
+        let mut dest_conn = Connection::open(dest)?;
+        let backup = rusqlite::backup::Backup::new(&self.conn, &mut dest_conn)?;
+        let mut busy_retries = 0u32;
+        let mut restarts = 0u32;
+        let mut last_remaining: Option<i32> = None;
+        loop {
+            // -1 copies every remaining page in a single step.
+            let pages = if restarts >= Self::BACKUP_MAX_RESTARTS {
+                -1
+            } else {
+                Self::BACKUP_PAGES_PER_STEP
+            };
+            let step = backup.step(pages)?;
+            let progress = backup.progress();
+            on_progress(BackupProgress {
+                remaining_pages: progress.remaining,
+                total_pages: progress.pagecount,
+            });
+            match step {
+                rusqlite::backup::StepResult::Done => return Ok(()),
+                rusqlite::backup::StepResult::More => {
+                    busy_retries = 0;
+                    // A step that copied pages without shrinking the
+                    // remainder means another connection's write restarted
+                    // the copy.
+                    if last_remaining.is_some_and(|last| progress.remaining >= last) {
+                        restarts += 1;
+                    }
+                    last_remaining = Some(progress.remaining);
+                }
+                _ => {
+                    busy_retries += 1;
+                    if busy_retries >= Self::BACKUP_BUSY_MAX_RETRIES {
+                        return Err(DbError::Transaction(format!(
+                            "backup to {}: database busy after {busy_retries} retries",
+                            dest.display()
+                        )));
+                    }
+                    std::thread::sleep(Duration::from_millis(Self::BACKUP_BUSY_BACKOFF_MS));
+                }
+            }
+        }
+    }
+
     /// Returns a reference to the underlying SQLite connection.
     pub fn conn(&self) -> &Connection {
         &self.conn
@@ -359,6 +457,35 @@ impl Db {
         }
     }
 
+    /// Run `f` inside an IMMEDIATE transaction on this handle.
+    ///
+    /// Unlike [`Db::transaction`], `f` receives the `Db` itself, so repositories
+    /// can be composed into one atomic write. The write lock is taken up front.
+    pub fn immediate_transaction<T>(
+        &self,
+        f: impl FnOnce(&Db) -> Result<T, DbError>,
+    ) -> Result<T, DbError> {
+        let tx = rusqlite::Transaction::new_unchecked(
+            &self.conn,
+            rusqlite::TransactionBehavior::Immediate,
+        )?;
+
+        match f(self) {
+            Ok(v) => {
+                tx.commit()?;
+                Ok(v)
+            }
+            Err(e) => {
+                if let Err(rb) = tx.rollback() {
+                    return Err(DbError::Transaction(format!(
+                        "rollback failed: {rb} (original error: {e})"
+                    )));
+                }
+                Err(e)
+            }
+        }
+    }
+
     /// TransactionWithRetry retries a transaction when SQLite reports busy/locked.
     ///
     /// Mirrors Go's `db.TransactionWithRetry` string-matching behavior.
@@ -406,6 +533,18 @@ impl Db {
     }
 }
 
+/// Open a connection with the standard Forge pragmas applied.
+pub(crate) fn open_connection(cfg: &Config) -> Result<Connection, DbError> {
+    let conn = Connection::open(&cfg.path)?;
+    conn.busy_timeout(Duration::from_millis(cfg.busy_timeout_ms))?;
+    // Match Go connection defaults as closely as possible.
+    // Best-effort: ignore pragma errors on older SQLite builds.
+    let _ = conn.pragma_update(None, "journal_mode", "WAL");
+    let _ = conn.pragma_update(None, "foreign_keys", "ON");
+    let _ = conn.pragma_update(None, "synchronous", "NORMAL");
+    Ok(conn)
+}
+
 fn is_busy_error(err: &DbError) -> bool {
     let msg = err.to_string().to_lowercase();
     msg.contains("database is locked")
@@ -535,6 +674,40 @@ impl<'a> LoopKVRepository<'a> {
         Ok(out)
     }
 
+    /// Search key-value pairs across all loops, sorted by loop then key.
+    ///
+    /// `key_glob` uses SQLite `GLOB` syntax; `value_contains` is a
+    /// case-sensitive substring match. Empty filters are ignored.
+    pub fn search(
+        &self,
+        key_glob: Option<&str>,
+        value_contains: Option<&str>,
+    ) -> Result<Vec<LoopKV>, DbError> {
+        let key_glob = key_glob.map(str::trim).filter(|value| !value.is_empty());
+        let value_contains = value_contains.filter(|value| !value.is_empty());
+        let mut stmt = self.db.conn.prepare(
+            "SELECT id, loop_id, key, value, created_at, updated_at \
+             FROM loop_kv \
+             WHERE (?1 IS NULL OR key GLOB ?1) AND (?2 IS NULL OR instr(value, ?2) > 0) \
+             ORDER BY loop_id, key",
+        )?;
+        let rows = stmt.query_map(params![key_glob, value_contains], |row| {
+            Ok(LoopKV {
+                id: row.get(0)?,
+                loop_id: row.get(1)?,
+                key: row.get(2)?,
+                value: row.get(3)?,
+                created_at: row.get(4)?,
+                updated_at: row.get(5)?,
+            })
+        })?;
+        let mut out = Vec::new();
+        for row in rows {
+            out.push(row?);
+        }
+        Ok(out)
+    }
+
     /// Delete a key-value pair.
     pub fn delete(&self, loop_id: &str, key: &str) -> Result<(), DbError> {
         let rows = self.db.conn.execute(
@@ -549,7 +722,26 @@ impl<'a> LoopKVRepository<'a> {
 }
 
 fn now_rfc3339() -> String {
+    rfc3339_after(std::time::Duration::ZERO)
+}
+
+/// RFC3339 UTC timestamp `offset` from now, in the same format as
+/// [`now_rfc3339`] so stored timestamps compare lexically.
+fn rfc3339_after(offset: std::time::Duration) -> String {
     let now = std::time::SystemTime::now();
+    rfc3339_at(now.checked_add(offset).unwrap_or(now))
+}
+
+/// RFC3339 UTC timestamp `offset` before now; counterpart of [`rfc3339_after`].
+fn rfc3339_before(offset: std::time::Duration) -> String {
+    let now = std::time::SystemTime::now();
+    rfc3339_at(
+        now.checked_sub(offset)
+            .unwrap_or(std::time::SystemTime::UNIX_EPOCH),
+    )
+}
+
+fn rfc3339_at(now: std::time::SystemTime) -> String {
     let duration = match now.duration_since(std::time::UNIX_EPOCH) {
         Ok(d) => d,
         Err(_) => std::time::Duration::from_secs(0),
@@ -18,6 +18,7 @@ pub enum LoopState {
     Running,
     Sleeping,
     Waiting,
+    Paused,
     #[default]
     Stopped,
     Error,
@@ -29,6 +30,7 @@ impl LoopState {
             Self::Running => "running",
             Self::Sleeping => "sleeping",
             Self::Waiting => "waiting",
+            Self::Paused => "paused",
             Self::Stopped => "stopped",
             Self::Error => "error",
         }
@@ -39,6 +41,7 @@ impl LoopState {
             "running" => Ok(Self::Running),
             "sleeping" => Ok(Self::Sleeping),
             "waiting" => Ok(Self::Waiting),
+            "paused" => Ok(Self::Paused),
             "stopped" => Ok(Self::Stopped),
             "error" => Ok(Self::Error),
             other => Err(DbError::Validation(format!("invalid loop state: {other}"))),
@@ -948,6 +951,7 @@ mod tests {
             LoopState::Running,
             LoopState::Sleeping,
             LoopState::Waiting,
+            LoopState::Paused,
             LoopState::Stopped,
             LoopState::Error,
         ]
@@ -1030,6 +1034,7 @@ mod tests {
         assert_eq!(LoopState::parse("running").ok(), Some(LoopState::Running));
         assert_eq!(LoopState::parse("sleeping").ok(), Some(LoopState::Sleeping));
         assert_eq!(LoopState::parse("waiting").ok(), Some(LoopState::Waiting));
+        assert_eq!(LoopState::parse("paused").ok(), Some(LoopState::Paused));
         assert_eq!(LoopState::parse("stopped").ok(), Some(LoopState::Stopped));
         assert_eq!(LoopState::parse("error").ok(), Some(LoopState::Error));
     }
@@ -0,0 +1,215 @@
+//! Metrics repository — time-series gauges for daemon internals (`metrics` table).
+//!
+//! Samples are keyed by a series: the metric name plus its tag set, tracked
+//! in `metric_series`. Series cardinality is capped at [`MAX_METRIC_SERIES`]
+//! and each sample carries at most [`MAX_METRIC_TAGS`] tags, so a misbehaving
+//! caller cannot grow the table without bound by minting unique tag values.
+//!
+//! Retention: rows are kept until pruned. The daemon calls
+//! [`MetricsRepository::prune_expired`] hourly with
+//! [`DEFAULT_METRICS_RETENTION`] (7 days); pruning also frees series slots.
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use rusqlite::{params, OptionalExtension};
+
+use crate::{Db, DbError};
+
+/// Maximum number of distinct (name, tags) series stored at once.
+pub const MAX_METRIC_SERIES: i64 = 1000;
+/// Maximum number of tags attached to a single sample.
+pub const MAX_METRIC_TAGS: usize = 8;
+/// How long samples are kept before [`MetricsRepository::prune_expired`] drops them.
+pub const DEFAULT_METRICS_RETENTION: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// One recorded gauge value.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MetricSample {
+    pub name: String,
+    pub value: f64,
+    pub tags: BTreeMap<String, String>,
+    /// RFC3339 UTC; filled with the current time when empty on record.
+    pub timestamp: String,
+}
+
+pub struct MetricsRepository<'a> {
+    db: &'a Db,
+}
+
+impl<'a> MetricsRepository<'a> {
+    pub fn new(db: &'a Db) -> Self {
+        Self { db }
+    }
+
+    /// Record one sample, rejecting it if it would open a series beyond
+    /// [`MAX_METRIC_SERIES`].
+    pub fn record(&self, sample: &mut MetricSample) -> Result<(), DbError> {
+        let name = sample.name.trim();
+        if name.is_empty() {
+            return Err(DbError::Validation("metric name is required".into()));
+        }
+        if !sample.value.is_finite() {
+            return Err(DbError::Validation(format!(
+                "metric {name}: value must be finite"
+            )));
+        }
+        if sample.tags.len() > MAX_METRIC_TAGS {
+            return Err(DbError::Validation(format!(
+                "metric {name}: {} tags exceeds limit of {MAX_METRIC_TAGS}",
+                sample.tags.len()
+            )));
+        }
+        sample.name = name.to_string();
+        if sample.timestamp.trim().is_empty() {
+            sample.timestamp = crate::now_rfc3339();
+        }
+
+        let tags_json = serde_json::to_string(&sample.tags)
+            .map_err(|err| DbError::Validation(format!("marshal metric tags: {err}")))?;
+
+        // The cap check and insert must hold the write lock together, or two
+        // daemons can each open a new series past the limit. Inside a caller's
+        // transaction the caller owns atomicity.
+        if self.db.conn().is_autocommit() {
+            self.db
+                .immediate_transaction(|_| self.insert_within_cap(sample, &tags_json))
+        } else {
+            self.insert_within_cap(sample, &tags_json)
+        }
+    }
+
+    fn insert_within_cap(&self, sample: &MetricSample, tags_json: &str) -> Result<(), DbError> {
+        if !self.series_exists(&sample.name, tags_json)? {
+            let series = self.series_count()?;
+            if series >= MAX_METRIC_SERIES {
+                return Err(DbError::Validation(format!(
+                    "metric {}: series limit of {MAX_METRIC_SERIES} reached",
+                    sample.name
+                )));
+            }
+            self.db.conn().execute(
+                "INSERT INTO metric_series (name, tags_json) VALUES (?1, ?2)",
+                params![sample.name, tags_json],
+            )?;
+        }
+
+        self.db.conn().execute(
+            "INSERT INTO metrics (name, value, tags_json, timestamp) VALUES (?1, ?2, ?3, ?4)",
+            params![sample.name, sample.value, tags_json, sample.timestamp],
+        )?;
+        Ok(())
+    }
+
+    /// Samples for `name` with `since <= timestamp < until`, oldest first.
+    /// Empty bounds are open.
+    pub fn query_range(
+        &self,
+        name: &str,
+        since: &str,
+        until: &str,
+    ) -> Result<Vec<MetricSample>, DbError> {
+        let since = if since.trim().is_empty() { "" } else { since };
+        let until = if until.trim().is_empty() {
+            "9999-12-31T23:59:59Z"
+        } else {
+            until
+        };
+        let mut stmt = self.db.conn().prepare(
+            "SELECT name, value, tags_json, timestamp
+             FROM metrics
+             WHERE name = ?1 AND timestamp >= ?2 AND timestamp < ?3
+             ORDER BY timestamp ASC, id ASC",
+        )?;
+        let rows = stmt.query_map(params![name.trim(), since, until], scan_metric_row)?;
+
+        let mut out = Vec::new();
+        for row in rows {
+            out.push(row?);
+        }
+        Ok(out)
+    }
+
+    /// Most recent sample of every series, for point-in-time exports such as
+    /// Prometheus text format.
+    pub fn latest_per_series(&self) -> Result<Vec<MetricSample>, DbError> {
+        let mut stmt = self.db.conn().prepare(
+            "SELECT m.name, m.value, m.tags_json, m.timestamp
+             FROM metrics m
+             WHERE m.id = (
+                SELECT id FROM metrics
+                WHERE name = m.name AND tags_json = m.tags_json
+                ORDER BY timestamp DESC, id DESC
+                LIMIT 1
+             )
+             ORDER BY m.name ASC, m.tags_json ASC",
+        )?;
+        let rows = stmt.query_map([], scan_metric_row)?;
+
+        let mut out = Vec::new();
+        for row in rows {
+            out.push(row?);
+        }
+        Ok(out)
+    }
+
+    /// Number of distinct (name, tags) series currently stored.
+    pub fn series_count(&self) -> Result<i64, DbError> {
+        let count = self
+            .db
+            .conn()
+            .query_row("SELECT COUNT(*) FROM metric_series", [], |row| row.get(0))?;
+        Ok(count)
+    }
+
+    /// Delete samples older than `cutoff` (RFC3339), then the series left
+    /// without samples. Returns sample rows removed.
+    pub fn prune_before(&self, cutoff: &str) -> Result<usize, DbError> {
+        self.db.immediate_transaction(|db| {
+            let rows = db
+                .conn()
+                .execute("DELETE FROM metrics WHERE timestamp < ?1", params![cutoff])?;
+            db.conn().execute(
+                "DELETE FROM metric_series
+                 WHERE NOT EXISTS (
+                    SELECT 1 FROM metrics
+                    WHERE metrics.name = metric_series.name
+                      AND metrics.tags_json = metric_series.tags_json
+                 )",
+                [],
+            )?;
+            Ok(rows)
+        })
+    }
+
+    /// Delete samples older than `retention` from now.
+    pub fn prune_expired(&self, retention: Duration) -> Result<usize, DbError> {
+        self.prune_before(&crate::rfc3339_before(retention))
+    }
+
+    fn series_exists(&self, name: &str, tags_json: &str) -> Result<bool, DbError> {
+        let found = self
+            .db
+            .conn()
+            .query_row(
+                "SELECT 1 FROM metric_series WHERE name = ?1 AND tags_json = ?2",
+                params![name, tags_json],
+                |row| row.get::<_, i32>(0),
+            )
+            .optional()?;
+        Ok(found.is_some())
+    }
+}
+
+fn scan_metric_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<MetricSample> {
+    let tags_json: String = row.get(2)?;
+    let tags = serde_json::from_str(&tags_json).map_err(|err| {
+        rusqlite::Error::FromSqlConversionFailure(2, rusqlite::types::Type::Text, Box::new(err))
+    })?;
+    Ok(MetricSample {
+        name: row.get(0)?,
+        value: row.get(1)?,
+        tags,
+        timestamp: row.get(3)?,
+    })
+}
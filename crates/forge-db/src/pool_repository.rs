@@ -362,6 +362,30 @@ impl<'a> PoolRepository<'a> {
         Ok(())
     }
 
+    /// SetMemberWeight changes a member's selection weight. Unlike AddMember,
+    /// a weight of 0 is kept so the account stays pooled but is never picked.
+    pub fn set_member_weight(
+        &self,
+        pool_id: &str,
+        profile_id: &str,
+        weight: i64,
+    ) -> Result<(), DbError> {
+        if weight < 0 {
+            return Err(DbError::Validation(format!(
+                "pool member weight must be >= 0 (got {weight})"
+            )));
+        }
+        let rows_affected = self.db.conn().execute(
+            "UPDATE pool_members SET weight = ?1 WHERE pool_id = ?2 AND profile_id = ?3",
+            params![weight, pool_id, profile_id],
+        )?;
+
+        if rows_affected == 0 {
+            return Err(DbError::PoolNotFound);
+        }
+        Ok(())
+    }
+
     /// ListMembers returns members for a pool ordered by position then created_at.
     pub fn list_members(&self, pool_id: &str) -> Result<Vec<PoolMember>, DbError> {
         let mut stmt = self.db.conn().prepare(
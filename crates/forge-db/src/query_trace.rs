@@ -0,0 +1,135 @@
+//! Slow-query instrumentation, enabled by the `trace` feature.
+//!
+//! SQLite reports the wall time of every finished statement through its
+//! profiling callback. That callback is a plain function pointer with no
+//! captured state, so each [`Db`] with a hook claims one of a fixed set of
+//! slots and registers the reporter monomorphized for that slot. The slot
+//! holds the hook, which keeps hooks on different connections apart.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crate::{Db, DbError};
+
+/// Number of connections that can have a slow-query hook at the same time.
+pub const MAX_SLOW_QUERY_HOOKS: usize = 16;
+
+/// A statement that ran longer than the configured threshold.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlowQuery {
+    pub sql: String,
+    pub duration: Duration,
+}
+
+type SlowQueryCallback = Arc<dyn Fn(&SlowQuery) + Send + Sync>;
+
+struct SlowQueryHook {
+    threshold: Duration,
+    callback: SlowQueryCallback,
+}
+
+static CLAIMED: [AtomicBool; MAX_SLOW_QUERY_HOOKS] =
+    [const { AtomicBool::new(false) }; MAX_SLOW_QUERY_HOOKS];
+
+static HOOKS: [Mutex<Option<SlowQueryHook>>; MAX_SLOW_QUERY_HOOKS] =
+    [const { Mutex::new(None) }; MAX_SLOW_QUERY_HOOKS];
+
+const REPORTERS: [fn(&str, Duration); MAX_SLOW_QUERY_HOOKS] = [
+    report_slow_query::<0>,
+    report_slow_query::<1>,
+    report_slow_query::<2>,
+    report_slow_query::<3>,
+    report_slow_query::<4>,
+    report_slow_query::<5>,
+    report_slow_query::<6>,
+    report_slow_query::<7>,
+    report_slow_query::<8>,
+    report_slow_query::<9>,
+    report_slow_query::<10>,
+    report_slow_query::<11>,
+    report_slow_query::<12>,
+    report_slow_query::<13>,
+    report_slow_query::<14>,
+    report_slow_query::<15>,
+];
+
+/// A claimed hook slot; released when the owning [`Db`] drops it.
+#[derive(Debug)]
+pub(crate) struct HookSlot(usize);
+
+impl HookSlot {
+    fn claim() -> Option<Self> {
+        CLAIMED
+            .iter()
+            .position(|claimed| {
+                claimed
+                    .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+                    .is_ok()
+            })
+            .map(Self)
+    }
+
+    fn set(&self, hook: SlowQueryHook) {
+        *lock_slot(self.0) = Some(hook);
+    }
+}
+
+impl Drop for HookSlot {
+    fn drop(&mut self) {
+        *lock_slot(self.0) = None;
+        CLAIMED[self.0].store(false, Ordering::Release);
+    }
+}
+
+impl Db {
+    /// Invoke `callback` for every statement on this connection that takes
+    /// longer than `threshold`. Replaces any hook already set on this
+    /// connection; fails once [`MAX_SLOW_QUERY_HOOKS`] connections have one.
+    pub fn set_slow_query_hook(
+        &mut self,
+        threshold: Duration,
+        callback: impl Fn(&SlowQuery) + Send + Sync + 'static,
+    ) -> Result<(), DbError> {
+        let slot = match self.slow_query_slot.take() {
+            Some(slot) => slot,
+            None => HookSlot::claim().ok_or_else(|| {
+                DbError::Validation(format!(
+                    "slow-query hooks are limited to {MAX_SLOW_QUERY_HOOKS} connections"
+                ))
+            })?,
+        };
+        slot.set(SlowQueryHook {
+            threshold,
+            callback: Arc::new(callback),
+        });
+        self.conn.profile(Some(REPORTERS[slot.0]));
+        self.slow_query_slot = Some(slot);
+        Ok(())
+    }
+
+    /// Stop timing statements on this connection.
+    pub fn clear_slow_query_hook(&mut self) {
+        self.conn.profile(None);
+        self.slow_query_slot = None;
+    }
+}
+
+fn lock_slot(slot: usize) -> std::sync::MutexGuard<'static, Option<SlowQueryHook>> {
+    match HOOKS[slot].lock() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    }
+}
+
+fn report_slow_query<const SLOT: usize>(sql: &str, duration: Duration) {
+    // Clone the callback out so it never runs under the lock.
+    let callback = match lock_slot(SLOT).as_ref() {
+        Some(hook) if duration > hook.threshold => Arc::clone(&hook.callback),
+        _ => return,
+    };
+    callback(&SlowQuery {
+        sql: sql.to_string(),
+        duration,
+    });
+}
@@ -11,6 +11,19 @@ pub struct Transcript {
     pub content: String,
     pub content_hash: String,
     pub captured_at: String,
+    /// Number of consecutive identical captures folded into this row.
+    pub repeat_count: i64,
+}
+
+/// Result of [`TranscriptRepository::capture`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TranscriptCapture {
+    /// Row id holding the captured content.
+    pub id: i64,
+    /// True when the capture matched the latest transcript for the agent and
+    /// only bumped its repeat counter instead of inserting a new row.
+    pub deduped: bool,
+    pub repeat_count: i64,
 }
 
 pub struct TranscriptRepository<'a> {
@@ -32,15 +45,19 @@ impl<'a> TranscriptRepository<'a> {
         if transcript.captured_at.is_empty() {
             transcript.captured_at = crate::now_rfc3339();
         }
+        if transcript.repeat_count < 1 {
+            transcript.repeat_count = 1;
+        }
 
         self.db.conn().execute(
-            "INSERT INTO transcripts (agent_id, content, content_hash, captured_at)
-             VALUES (?1, ?2, ?3, ?4)",
+            "INSERT INTO transcripts (agent_id, content, content_hash, captured_at, repeat_count)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
             params![
                 transcript.agent_id,
                 transcript.content,
                 transcript.content_hash,
                 transcript.captured_at,
+                transcript.repeat_count,
             ],
         )?;
 
@@ -48,12 +65,75 @@ impl<'a> TranscriptRepository<'a> {
         Ok(())
     }
 
+    /// Record a capture, skipping the insert when its content hash matches the
+    /// most recent transcript for the same agent. A skipped capture increments
+    /// that row's `repeat_count` instead.
+    pub fn capture(&self, transcript: &mut Transcript) -> Result<TranscriptCapture, DbError> {
+        if transcript.agent_id.trim().is_empty() {
+            return Err(DbError::Validation(
+                "transcript agent id is required".into(),
+            ));
+        }
+
+        // The latest-row lookup and the bump or insert must see the same
+        // latest row, or two concurrent captures can both insert.
+        if self.db.conn().is_autocommit() {
+            self.db
+                .immediate_transaction(|_| self.capture_latest(transcript))
+        } else {
+            self.capture_latest(transcript)
+        }
+    }
+
+    fn capture_latest(&self, transcript: &mut Transcript) -> Result<TranscriptCapture, DbError> {
+        let latest = self
+            .db
+            .conn()
+            .query_row(
+                "SELECT id, repeat_count
+                 FROM transcripts
+                 WHERE agent_id = ?1 AND content_hash = ?2
+                   AND id = (
+                     SELECT id FROM transcripts
+                     WHERE agent_id = ?1
+                     ORDER BY captured_at DESC, id DESC
+                     LIMIT 1
+                   )",
+                params![transcript.agent_id, transcript.content_hash],
+                |row| Ok((row.get::<_, i64>(0)?, row.get::<_, i64>(1)?)),
+            )
+            .optional()?;
+
+        if let Some((id, repeat_count)) = latest {
+            let repeat_count = repeat_count.saturating_add(1);
+            self.db.conn().execute(
+                "UPDATE transcripts SET repeat_count = ?1 WHERE id = ?2",
+                params![repeat_count, id],
+            )?;
+            transcript.id = id;
+            transcript.repeat_count = repeat_count;
+            return Ok(TranscriptCapture {
+                id,
+                deduped: true,
+                repeat_count,
+            });
+        }
+
+        transcript.repeat_count = 1;
+        self.create(transcript)?;
+        Ok(TranscriptCapture {
+            id: transcript.id,
+            deduped: false,
+            repeat_count: transcript.repeat_count,
+        })
+    }
+
     pub fn get(&self, id: i64) -> Result<Transcript, DbError> {
         let result = self
             .db
             .conn()
             .query_row(
-                "SELECT id, agent_id, content, content_hash, captured_at
+                "SELECT id, agent_id, content, content_hash, captured_at, repeat_count
                  FROM transcripts
                  WHERE id = ?1",
                 params![id],
@@ -69,7 +149,7 @@ impl<'a> TranscriptRepository<'a> {
             .db
             .conn()
             .query_row(
-                "SELECT id, agent_id, content, content_hash, captured_at
+                "SELECT id, agent_id, content, content_hash, captured_at, repeat_count
                  FROM transcripts
                  WHERE agent_id = ?1
                  ORDER BY captured_at DESC, id DESC
@@ -84,7 +164,7 @@ impl<'a> TranscriptRepository<'a> {
 
     pub fn list_by_agent(&self, agent_id: &str, limit: usize) -> Result<Vec<Transcript>, DbError> {
         let mut stmt = self.db.conn().prepare(
-            "SELECT id, agent_id, content, content_hash, captured_at
+            "SELECT id, agent_id, content, content_hash, captured_at, repeat_count
              FROM transcripts
              WHERE agent_id = ?1
              ORDER BY captured_at DESC, id DESC
@@ -115,5 +195,6 @@ fn scan_transcript(row: &rusqlite::Row<'_>) -> rusqlite::Result<Transcript> {
         content: row.get(2)?,
         content_hash: row.get(3)?,
         captured_at: row.get(4)?,
+        repeat_count: row.get(5)?,
     })
 }
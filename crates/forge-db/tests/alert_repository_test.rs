@@ -2,7 +2,7 @@ use std::path::PathBuf;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::{SystemTime, UNIX_EPOCH};
 
-use forge_db::alert_repository::{Alert, AlertRepository, AlertSeverity, AlertType};
+use forge_db::alert_repository::{Alert, AlertFilter, AlertRepository, AlertSeverity, AlertType};
 use forge_db::{Config, Db, DbError};
 
 fn temp_db_path(prefix: &str) -> PathBuf {
@@ -127,6 +127,106 @@ fn create_list_resolve_roundtrip() {
     let _ = std::fs::remove_file(path);
 }
 
+#[test]
+fn list_paginates_mixed_severities_unresolved_critical_first() {
+    let (db, path) = setup_db("list-paginated");
+    let repo = AlertRepository::new(&db);
+
+    let severities = [
+        AlertSeverity::Info,
+        AlertSeverity::Critical,
+        AlertSeverity::Warning,
+        AlertSeverity::Error,
+        AlertSeverity::Critical,
+        AlertSeverity::Info,
+        AlertSeverity::Warning,
+    ];
+    let mut created = Vec::new();
+    for (idx, severity) in severities.iter().enumerate() {
+        let mut alert = Alert {
+            alert_type: AlertType::Error,
+            severity: severity.clone(),
+            message: format!("alert {idx}"),
+            ..Default::default()
+        };
+        if let Err(err) = repo.create(&mut alert) {
+            panic!("create alert failed: {err}");
+        }
+        created.push(alert);
+    }
+    // Resolve one critical and one warning; they must sort after every open alert.
+    for idx in [1, 2] {
+        if let Err(err) = repo.resolve(&created[idx].id) {
+            panic!("resolve alert failed: {err}");
+        }
+    }
+
+    let mut seen = Vec::new();
+    let mut cursor = String::new();
+    let mut pages = 0;
+    loop {
+        let page = match repo.list(AlertFilter {
+            limit: 3,
+            cursor: cursor.clone(),
+            ..Default::default()
+        }) {
+            Ok(value) => value,
+            Err(err) => panic!("list alerts failed: {err}"),
+        };
+        pages += 1;
+        assert!(page.items.len() <= 3);
+        seen.extend(page.items);
+        if page.next_cursor.is_empty() {
+            break;
+        }
+        cursor = page.next_cursor;
+    }
+
+    assert_eq!(pages, 3);
+    assert_eq!(seen.len(), created.len());
+    let mut ids: Vec<&str> = seen.iter().map(|alert| alert.id.as_str()).collect();
+    ids.sort_unstable();
+    ids.dedup();
+    assert_eq!(ids.len(), created.len(), "pages overlapped");
+
+    let order: Vec<(bool, &str)> = seen
+        .iter()
+        .map(|alert| (alert.is_resolved, alert.severity.as_str()))
+        .collect();
+    assert_eq!(
+        order,
+        vec![
+            (false, "critical"),
+            (false, "error"),
+            (false, "warning"),
+            (false, "info"),
+            (false, "info"),
+            (true, "critical"),
+            (true, "warning"),
+        ]
+    );
+
+    let critical_open = match repo.list(AlertFilter {
+        severity: Some(AlertSeverity::Critical),
+        is_resolved: Some(false),
+        ..Default::default()
+    }) {
+        Ok(value) => value,
+        Err(err) => panic!("filtered list failed: {err}"),
+    };
+    assert_eq!(critical_open.items.len(), 1);
+    assert_eq!(critical_open.items[0].id, created[4].id);
+    assert!(critical_open.next_cursor.is_empty());
+
+    let bad_cursor = repo.list(AlertFilter {
+        cursor: "not-a-cursor".to_string(),
+        ..Default::default()
+    });
+    assert!(matches!(bad_cursor, Err(DbError::Validation(_))));
+
+    let _ = std::fs::remove_file(path);
+}
+
 #[test]
 fn resolve_missing_returns_not_found() {
     let (db, path) = setup_db("resolve-missing");
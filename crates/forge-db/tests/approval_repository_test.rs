@@ -1,8 +1,11 @@
 use std::path::PathBuf;
 use std::sync::atomic::{AtomicU64, Ordering};
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
-use forge_db::approval_repository::{Approval, ApprovalRepository, ApprovalStatus};
+use forge_db::approval_repository::{
+    Approval, ApprovalRepository, ApprovalStatus, APPROVAL_EXPIRED_EVENT, APPROVAL_EXPIRY_RESOLVER,
+};
+use forge_db::event_repository::{EventQuery, EventRepository};
 use forge_db::{Config, Db, DbError};
 
 fn temp_db_path(prefix: &str) -> PathBuf {
@@ -155,3 +158,112 @@ fn create_validates_required_fields() {
 
     let _ = std::fs::remove_file(path);
 }
+
+#[test]
+fn expire_pending_expires_only_overdue_approvals() {
+    let (db, path) = setup_db("expire-pending");
+    let repo = ApprovalRepository::new(&db);
+    let agent_id = seed_agent(&db, "approval-expiry");
+
+    let mut overdue = Approval {
+        agent_id: agent_id.clone(),
+        request_type: "file_write".to_string(),
+        request_details_json: "{}".to_string(),
+        expires_at: Some("2026-01-01T00:00:00Z".to_string()),
+        ..Default::default()
+    };
+    let mut upcoming = Approval {
+        agent_id: agent_id.clone(),
+        request_type: "file_write".to_string(),
+        request_details_json: "{}".to_string(),
+        expires_at: Some("2026-01-01T00:10:00Z".to_string()),
+        ..Default::default()
+    };
+    let mut open_ended = Approval {
+        agent_id: agent_id.clone(),
+        request_type: "file_write".to_string(),
+        request_details_json: "{}".to_string(),
+        ..Default::default()
+    };
+    for approval in [&mut overdue, &mut upcoming, &mut open_ended] {
+        if let Err(err) = repo.create(approval) {
+            panic!("create approval failed: {err}");
+        }
+    }
+
+    let expired = match repo.expire_pending("2026-01-01T00:05:00Z") {
+        Ok(value) => value,
+        Err(err) => panic!("expire pending failed: {err}"),
+    };
+    assert_eq!(expired, 1);
+
+    let overdue = match repo.get(&overdue.id) {
+        Ok(value) => value,
+        Err(err) => panic!("get overdue approval failed: {err}"),
+    };
+    assert_eq!(overdue.status, ApprovalStatus::Expired);
+    assert_eq!(overdue.resolved_by, APPROVAL_EXPIRY_RESOLVER);
+    assert_eq!(overdue.resolved_at.as_deref(), Some("2026-01-01T00:05:00Z"));
+
+    let pending = match repo.list_pending_by_agent(&agent_id) {
+        Ok(value) => value,
+        Err(err) => panic!("list pending failed: {err}"),
+    };
+    let pending_ids: Vec<&str> = pending.iter().map(|item| item.id.as_str()).collect();
+    assert_eq!(pending_ids.len(), 2);
+    assert!(pending_ids.contains(&upcoming.id.as_str()));
+    assert!(pending_ids.contains(&open_ended.id.as_str()));
+
+    let events = match EventRepository::new(&db).query(EventQuery {
+        event_type: Some(APPROVAL_EXPIRED_EVENT.to_string()),
+        ..Default::default()
+    }) {
+        Ok(value) => value,
+        Err(err) => panic!("query events failed: {err}"),
+    };
+    assert_eq!(events.events.len(), 1);
+    assert_eq!(events.events[0].entity_id, agent_id);
+    assert!(events.events[0].payload.contains(&overdue.id));
+
+    // A second sweep finds nothing left to expire.
+    match repo.expire_pending("2026-01-01T00:05:00Z") {
+        Ok(value) => assert_eq!(value, 0),
+        Err(err) => panic!("second expire pending failed: {err}"),
+    }
+
+    let _ = std::fs::remove_file(path);
+}
+
+#[test]
+fn create_with_deadline_sets_future_expiry() {
+    let (db, path) = setup_db("create-deadline");
+    let repo = ApprovalRepository::new(&db);
+    let agent_id = seed_agent(&db, "approval-deadline");
+
+    let mut approval = Approval {
+        agent_id,
+        request_type: "file_write".to_string(),
+        request_details_json: "{}".to_string(),
+        ..Default::default()
+    };
+    if let Err(err) = repo.create_with_deadline(&mut approval, Duration::from_secs(3600)) {
+        panic!("create with deadline failed: {err}");
+    }
+
+    let stored = match repo.get(&approval.id) {
+        Ok(value) => value,
+        Err(err) => panic!("get approval failed: {err}"),
+    };
+    let expires_at = match stored.expires_at {
+        Some(value) => value,
+        None => panic!("expected expires_at to be set"),
+    };
+    assert!(expires_at > stored.created_at);
+
+    match repo.expire_pending(&stored.created_at) {
+        Ok(value) => assert_eq!(value, 0),
+        Err(err) => panic!("expire pending failed: {err}"),
+    }
+
+    let _ = std::fs::remove_file(path);
+}
@@ -0,0 +1,213 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use forge_db::{BackupProgress, Config, Db, LoopKVRepository};
+use rusqlite::params;
+
+fn setup_db(path: &Path) -> Db {
+    let mut db = match Db::open(Config::new(path)) {
+        Ok(db) => db,
+        Err(err) => panic!("open db: {err}"),
+    };
+    if let Err(err) = db.migrate_up() {
+        panic!("migrate_up: {err}");
+    }
+    if let Err(err) = db.conn().execute(
+        "INSERT INTO loops (id, name, repo_path) VALUES (?1, ?2, ?3)",
+        params!["loop-backup-001", "backup-loop", "/repo/backup"],
+    ) {
+        panic!("insert test loop: {err}");
+    }
+    db
+}
+
+#[test]
+fn backup_during_writes_opens_with_schema_version() {
+    let source_path = temp_db_path("backup-source");
+    let backup_path = temp_db_path("backup-dest");
+    let db = setup_db(&source_path);
+    let expected_version = match db.schema_version() {
+        Ok(version) => version,
+        Err(err) => panic!("schema_version: {err}"),
+    };
+
+    let stop = Arc::new(AtomicBool::new(false));
+    let writer_stop = Arc::clone(&stop);
+    let writer_path = source_path.clone();
+    let writer = std::thread::spawn(move || {
+        let writer_db = match Db::open(Config::new(&writer_path)) {
+            Ok(db) => db,
+            Err(err) => panic!("open writer db: {err}"),
+        };
+        let repo = LoopKVRepository::new(&writer_db);
+        let mut written = 0usize;
+        while !writer_stop.load(Ordering::Relaxed) || written < 50 {
+            if let Err(err) = repo.set(
+                "loop-backup-001",
+                &format!("key-{written}"),
+                &"x".repeat(512),
+            ) {
+                panic!("concurrent write {written}: {err}");
+            }
+            written += 1;
+        }
+        written
+    });
+
+    let mut steps: Vec<BackupProgress> = Vec::new();
+    if let Err(err) = db.backup_to_with_progress(&backup_path, |progress| steps.push(progress)) {
+        panic!("backup_to: {err}");
+    }
+    stop.store(true, Ordering::Relaxed);
+    let written = match writer.join() {
+        Ok(written) => written,
+        Err(_) => panic!("writer thread panicked"),
+    };
+    assert!(written >= 50);
+
+    let last = match steps.last() {
+        Some(progress) => *progress,
+        None => panic!("backup reported no progress"),
+    };
+    assert!(last.is_done());
+    assert_eq!(last.copied_pages(), last.total_pages);
+
+    let backup = match Db::open(Config::new(&backup_path)) {
+        Ok(db) => db,
+        Err(err) => panic!("open backup: {err}"),
+    };
+    match backup.schema_version() {
+        Ok(version) => assert_eq!(version, expected_version),
+        Err(err) => panic!("backup schema_version: {err}"),
+    }
+    let integrity: String = match backup
+        .conn()
+        .query_row("PRAGMA integrity_check", [], |row| row.get(0))
+    {
+        Ok(value) => value,
+        Err(err) => panic!("integrity_check: {err}"),
+    };
+    assert_eq!(integrity, "ok");
+    let loops: i64 = match backup
+        .conn()
+        .query_row("SELECT COUNT(*) FROM loops", [], |row| row.get(0))
+    {
+        Ok(count) => count,
+        Err(err) => panic!("count loops: {err}"),
+    };
+    assert_eq!(loops, 1);
+
+    let _ = std::fs::remove_file(&source_path);
+    let _ = std::fs::remove_file(&backup_path);
+}
+
+#[test]
+fn backup_finishes_when_every_step_is_interrupted_by_a_write() {
+    let source_path = temp_db_path("backup-restart-source");
+    let backup_path = temp_db_path("backup-restart-dest");
+    let db = setup_db(&source_path);
+    let repo = LoopKVRepository::new(&db);
+    for idx in 0..400 {
+        if let Err(err) = repo.set("loop-backup-001", &format!("seed-{idx}"), &"y".repeat(2048)) {
+            panic!("seed row {idx}: {err}");
+        }
+    }
+
+    let writer = match Db::open(Config::new(&source_path)) {
+        Ok(db) => db,
+        Err(err) => panic!("open writer db: {err}"),
+    };
+    let writer_repo = LoopKVRepository::new(&writer);
+    let mut steps = 0usize;
+    let result = db.backup_to_with_progress(&backup_path, |progress| {
+        steps += 1;
+        assert!(steps < 10_000, "backup never finished");
+        if !progress.is_done() {
+            if let Err(err) = writer_repo.set("loop-backup-001", "churn", &steps.to_string()) {
+                panic!("write during backup: {err}");
+            }
+        }
+    });
+    if let Err(err) = result {
+        panic!("backup_to: {err}");
+    }
+
+    let backup = match Db::open(Config::new(&backup_path)) {
+        Ok(db) => db,
+        Err(err) => panic!("open backup: {err}"),
+    };
+    let integrity: String = match backup
+        .conn()
+        .query_row("PRAGMA integrity_check", [], |row| row.get(0))
+    {
+        Ok(value) => value,
+        Err(err) => panic!("integrity_check: {err}"),
+    };
+    assert_eq!(integrity, "ok");
+
+    let _ = std::fs::remove_file(&source_path);
+    let _ = std::fs::remove_file(&backup_path);
+}
+
+#[test]
+fn backup_creates_missing_parent_directories() {
+    let source_path = temp_db_path("backup-nested-source");
+    let db = setup_db(&source_path);
+    let backup_dir = temp_db_path("backup-nested-dir");
+    let backup_path = backup_dir.join("snapshots").join("forge.db");
+
+    if let Err(err) = db.backup_to(&backup_path) {
+        panic!("backup_to: {err}");
+    }
+    let backup = match Db::open(Config::new(&backup_path)) {
+        Ok(db) => db,
+        Err(err) => panic!("open backup: {err}"),
+    };
+    let repo = LoopKVRepository::new(&backup);
+    match repo.list_by_loop("loop-backup-001") {
+        Ok(entries) => assert!(entries.is_empty()),
+        Err(err) => panic!("list backup kv: {err}"),
+    }
+
+    let _ = std::fs::remove_file(&source_path);
+    let _ = std::fs::remove_dir_all(&backup_dir);
+}
+
+#[test]
+fn backup_gives_up_when_destination_stays_locked() {
+    let source_path = temp_db_path("backup-locked-source");
+    let backup_path = temp_db_path("backup-locked-dest");
+    let db = setup_db(&source_path);
+
+    let holder = match rusqlite::Connection::open(&backup_path) {
+        Ok(conn) => conn,
+        Err(err) => panic!("open lock holder: {err}"),
+    };
+    if let Err(err) = holder.execute_batch("CREATE TABLE held (id INTEGER); BEGIN EXCLUSIVE;") {
+        panic!("lock destination: {err}");
+    }
+
+    match db.backup_to(&backup_path) {
+        Ok(()) => panic!("backup should fail while the destination is locked"),
+        Err(err) => assert!(err.to_string().contains("busy after"), "{err}"),
+    }
+
+    drop(holder);
+    let _ = std::fs::remove_file(&source_path);
+    let _ = std::fs::remove_file(&backup_path);
+}
+
+fn temp_db_path(prefix: &str) -> PathBuf {
+    static UNIQUE_SUFFIX: AtomicU64 = AtomicU64::new(0);
+    let nanos = match SystemTime::now().duration_since(UNIX_EPOCH) {
+        Ok(d) => d.as_nanos(),
+        Err(_) => 0,
+    };
+    let suffix = UNIQUE_SUFFIX.fetch_add(1, Ordering::Relaxed);
+    std::env::temp_dir().join(format!(
+        "forge-db-{prefix}-{nanos}-{}-{suffix}.sqlite",
+        std::process::id(),
+    ))
+}
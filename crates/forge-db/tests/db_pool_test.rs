@@ -0,0 +1,88 @@
+#![allow(clippy::expect_used, clippy::unwrap_used)]
+
+use forge_db::{Config, DbError, DbPool};
+use std::path::PathBuf;
+use std::sync::mpsc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+fn temp_db_path(tag: &str) -> PathBuf {
+    let nanos = match SystemTime::now().duration_since(UNIX_EPOCH) {
+        Ok(d) => d.as_nanos(),
+        Err(_) => 0,
+    };
+    std::env::temp_dir().join(format!(
+        "forge-db-pool-{tag}-{nanos}-{}.sqlite",
+        std::process::id()
+    ))
+}
+
+fn count_rows(pool: &DbPool) -> Result<i64, DbError> {
+    pool.read(|conn| Ok(conn.query_row("SELECT COUNT(*) FROM pool_test", [], |row| row.get(0))?))
+}
+
+#[test]
+fn reads_proceed_while_write_transaction_is_open() {
+    let path = temp_db_path("concurrent");
+    let pool = DbPool::open(Config::new(&path), 2).unwrap();
+    pool.write(|db| {
+        db.migrate_up()?;
+        db.conn()
+            .execute_batch("CREATE TABLE pool_test (id TEXT PRIMARY KEY);")?;
+        db.conn()
+            .execute("INSERT INTO pool_test (id) VALUES ('seed')", [])?;
+        Ok(())
+    })
+    .unwrap();
+
+    let (in_tx_tx, in_tx_rx) = mpsc::channel();
+    let (release_tx, release_rx) = mpsc::channel::<()>();
+    let pool = &pool;
+    std::thread::scope(|scope| {
+        let writer = scope.spawn(move || {
+            pool.write(|db| {
+                db.transaction(|tx| {
+                    tx.execute("INSERT INTO pool_test (id) VALUES ('pending')", [])?;
+                    in_tx_tx.send(()).unwrap();
+                    release_rx.recv().unwrap();
+                    Ok(())
+                })
+            })
+        });
+
+        in_tx_rx.recv().unwrap();
+        let readers: Vec<_> = (0..4).map(|_| scope.spawn(|| count_rows(pool))).collect();
+        for reader in readers {
+            // Uncommitted rows are invisible to WAL readers.
+            assert_eq!(reader.join().unwrap().unwrap(), 1);
+        }
+
+        release_tx.send(()).unwrap();
+        writer.join().unwrap().unwrap();
+    });
+
+    assert_eq!(count_rows(pool).unwrap(), 2);
+    let _ = std::fs::remove_file(path);
+}
+
+#[test]
+fn pooled_readers_share_pragmas_and_reject_writes() {
+    let path = temp_db_path("pragmas");
+    let pool = DbPool::open(Config::new(&path), 1).unwrap();
+
+    let (journal_mode, foreign_keys) = pool
+        .read(|conn| {
+            let mode: String = conn.query_row("PRAGMA journal_mode", [], |row| row.get(0))?;
+            let fk: i64 = conn.query_row("PRAGMA foreign_keys", [], |row| row.get(0))?;
+            Ok((mode, fk))
+        })
+        .unwrap();
+    assert_eq!(journal_mode.to_lowercase(), "wal");
+    assert_eq!(foreign_keys, 1);
+
+    let err = pool
+        .read(|conn| Ok(conn.execute_batch("CREATE TABLE nope (id TEXT);")?))
+        .unwrap_err();
+    assert!(err.to_string().contains("readonly"), "{err}");
+
+    let _ = std::fs::remove_file(path);
+}
@@ -172,6 +172,61 @@ fn time_range_and_entity_list_filters() {
     let _ = std::fs::remove_file(path);
 }
 
+#[test]
+fn latest_by_entity_returns_newest_matching_event() {
+    let (db, path) = open_migrated("latest-by-entity");
+    let repo = EventRepository::new(&db);
+
+    for (event_type, entity_id, timestamp, payload) in [
+        (
+            "loop.stop_requested",
+            "loop-1",
+            "2026-01-10T10:00:00Z",
+            "first",
+        ),
+        (
+            "loop.stop_requested",
+            "loop-1",
+            "2026-01-10T11:00:00Z",
+            "second",
+        ),
+        (
+            "loop.stop_requested",
+            "loop-2",
+            "2026-01-10T12:00:00Z",
+            "other",
+        ),
+        ("loop.started", "loop-1", "2026-01-10T13:00:00Z", "later"),
+    ] {
+        let mut event = Event {
+            event_type: event_type.to_string(),
+            entity_type: "loop".to_string(),
+            entity_id: entity_id.to_string(),
+            timestamp: timestamp.to_string(),
+            payload: format!("{{\"reason\":\"{payload}\"}}"),
+            ..Event::default()
+        };
+        if let Err(err) = repo.append(&mut event) {
+            panic!("append {payload}: {err}");
+        }
+    }
+
+    let latest = match repo.latest_by_entity("loop.stop_requested", "loop", "loop-1") {
+        Ok(Some(event)) => event,
+        Ok(None) => panic!("expected a stop event"),
+        Err(err) => panic!("latest_by_entity: {err}"),
+    };
+    assert_eq!(latest.timestamp, "2026-01-10T11:00:00Z");
+    assert!(latest.payload.contains("second"));
+
+    match repo.latest_by_entity("loop.stop_requested", "loop", "loop-3") {
+        Ok(None) => {}
+        other => panic!("expected no event, got {other:?}"),
+    }
+
+    let _ = std::fs::remove_file(path);
+}
+
 #[test]
 fn maintenance_operations_behave() {
     let (db, path) = open_migrated("maintenance");
@@ -260,3 +315,249 @@ fn append_validation_matches_go() {
 
     let _ = std::fs::remove_file(path);
 }
+
+#[test]
+fn hash_chain_detects_altered_middle_event() {
+    let (db, path) = open_migrated("hash-chain");
+    let repo = EventRepository::new(&db);
+
+    for (idx, state) in ["starting", "running", "stopped"].iter().enumerate() {
+        let mut event = Event {
+            id: format!("evt-{idx}"),
+            event_type: "agent.state_changed".to_string(),
+            entity_type: "agent".to_string(),
+            entity_id: "agent-1".to_string(),
+            timestamp: format!("2026-01-10T10:00:0{idx}Z"),
+            payload: format!("{{\"state\":\"{state}\"}}"),
+            ..Event::default()
+        };
+        if let Err(err) = repo.create(&mut event) {
+            panic!("create: {err}");
+        }
+    }
+
+    let chain = match repo.list_chain() {
+        Ok(chain) => chain,
+        Err(err) => panic!("list_chain: {err}"),
+    };
+    assert_eq!(chain.len(), 3);
+    assert!(chain[0].prev_hash.is_empty());
+    assert_eq!(chain[1].prev_hash, chain[0].chain_hash);
+    assert_eq!(chain[2].prev_hash, chain[1].chain_hash);
+
+    let intact = match repo.verify_chain() {
+        Ok(report) => report,
+        Err(err) => panic!("verify_chain: {err}"),
+    };
+    assert_eq!(intact.verified, 3);
+    assert_eq!(intact.head_hash, chain[2].chain_hash);
+    assert!(intact.first_break.is_none());
+
+    if let Err(err) = db.conn().execute(
+        "UPDATE events SET payload_json = '{\"state\":\"crashed\"}' WHERE id = 'evt-1'",
+        [],
+    ) {
+        panic!("tamper: {err}");
+    }
+
+    let tampered = match repo.verify_chain() {
+        Ok(report) => report,
+        Err(err) => panic!("verify_chain after tamper: {err}"),
+    };
+    assert_eq!(tampered.verified, 1);
+    let first_break = match tampered.first_break {
+        Some(value) => value,
+        None => panic!("expected chain break"),
+    };
+    assert_eq!(first_break.event_id, "evt-1");
+
+    let _ = std::fs::remove_file(path);
+}
+
+#[test]
+fn hash_chain_stays_linear_with_concurrent_writers() {
+    let (db, path) = open_migrated("chain-concurrent");
+    drop(db);
+
+    let writers: Vec<_> = (0..4)
+        .map(|writer| {
+            let path = path.clone();
+            std::thread::spawn(move || {
+                let db = match Db::open(Config::new(&path)) {
+                    Ok(db) => db,
+                    Err(err) => panic!("open writer {writer}: {err}"),
+                };
+                let repo = EventRepository::new(&db);
+                for i in 0..25 {
+                    let mut event = Event {
+                        event_type: "agent.state_changed".to_string(),
+                        entity_type: "agent".to_string(),
+                        entity_id: format!("agent-{writer}-{i}"),
+                        ..Event::default()
+                    };
+                    if let Err(err) = repo.append(&mut event) {
+                        panic!("append {writer}/{i}: {err}");
+                    }
+                }
+            })
+        })
+        .collect();
+    for writer in writers {
+        if writer.join().is_err() {
+            panic!("writer thread panicked");
+        }
+    }
+
+    let db = match Db::open(Config::new(&path)) {
+        Ok(db) => db,
+        Err(err) => panic!("reopen db: {err}"),
+    };
+    let report = match EventRepository::new(&db).verify_chain() {
+        Ok(report) => report,
+        Err(err) => panic!("verify_chain: {err}"),
+    };
+    assert_eq!(report.first_break, None);
+    assert_eq!(report.verified, 100);
+
+    let _ = std::fs::remove_file(path);
+}
+
+fn append_chain_events(repo: &EventRepository<'_>, timestamps: &[&str]) {
+    for (idx, timestamp) in timestamps.iter().enumerate() {
+        let mut event = Event {
+            id: format!("evt-{idx}"),
+            event_type: "agent.state_changed".to_string(),
+            entity_type: "agent".to_string(),
+            entity_id: "agent-1".to_string(),
+            timestamp: (*timestamp).to_string(),
+            ..Event::default()
+        };
+        if let Err(err) = repo.create(&mut event) {
+            panic!("create {idx}: {err}");
+        }
+    }
+}
+
+#[test]
+fn hash_chain_detects_removed_first_and_last_events() {
+    let (db, path) = open_migrated("chain-ends");
+    let repo = EventRepository::new(&db);
+    append_chain_events(
+        &repo,
+        &[
+            "2026-01-10T10:00:00Z",
+            "2026-01-10T10:00:01Z",
+            "2026-01-10T10:00:02Z",
+        ],
+    );
+
+    if let Err(err) = db
+        .conn()
+        .execute("DELETE FROM events WHERE id = 'evt-2'", [])
+    {
+        panic!("delete tail: {err}");
+    }
+    let report = match repo.verify_chain() {
+        Ok(report) => report,
+        Err(err) => panic!("verify_chain after tail delete: {err}"),
+    };
+    let first_break = match report.first_break {
+        Some(value) => value,
+        None => panic!("expected tail deletion to break the chain"),
+    };
+    assert_eq!(first_break.event_id, "evt-1");
+    assert!(first_break.reason.contains("newest events were removed"));
+
+    if let Err(err) = db
+        .conn()
+        .execute("DELETE FROM events WHERE id = 'evt-0'", [])
+    {
+        panic!("delete head: {err}");
+    }
+    let report = match repo.verify_chain() {
+        Ok(report) => report,
+        Err(err) => panic!("verify_chain after head delete: {err}"),
+    };
+    assert_eq!(report.verified, 0);
+    let first_break = match report.first_break {
+        Some(value) => value,
+        None => panic!("expected head deletion to break the chain"),
+    };
+    assert_eq!(first_break.event_id, "evt-1");
+    assert!(first_break.reason.contains("leading events were removed"));
+
+    let _ = std::fs::remove_file(path);
+}
+
+#[test]
+fn retention_prunes_in_insertion_order_and_keeps_chain_valid() {
+    let (db, path) = open_migrated("chain-prune");
+    let repo = EventRepository::new(&db);
+    // evt-2 was recorded with a clock that ran behind; pruning by timestamp
+    // alone would remove it ahead of evt-1 and cut the chain.
+    append_chain_events(
+        &repo,
+        &[
+            "2026-01-10T10:00:01Z",
+            "2026-01-10T10:00:05Z",
+            "2026-01-10T10:00:00Z",
+            "2026-01-10T10:00:06Z",
+            "2026-01-10T10:00:07Z",
+        ],
+    );
+
+    let listed = match repo.list_older_than("2026-01-10T10:00:03Z", 10) {
+        Ok(events) => events,
+        Err(err) => panic!("list_older_than: {err}"),
+    };
+    let listed_ids: Vec<&str> = listed.iter().map(|event| event.id.as_str()).collect();
+    assert_eq!(listed_ids, vec!["evt-0"]);
+
+    let deleted = match repo.delete_older_than("2026-01-10T10:00:03Z", 10) {
+        Ok(value) => value,
+        Err(err) => panic!("delete_older_than: {err}"),
+    };
+    assert_eq!(deleted, 1);
+
+    let deleted = match repo.delete_excess(3, 10) {
+        Ok(value) => value,
+        Err(err) => panic!("delete_excess: {err}"),
+    };
+    assert_eq!(deleted, 1);
+
+    let oldest = match repo.list_oldest(1) {
+        Ok(events) => events,
+        Err(err) => panic!("list_oldest: {err}"),
+    };
+    assert_eq!(oldest[0].id, "evt-2");
+    let deleted = match repo.delete_by_ids(&[oldest[0].id.clone()]) {
+        Ok(value) => value,
+        Err(err) => panic!("delete_by_ids: {err}"),
+    };
+    assert_eq!(deleted, 1);
+
+    let report = match repo.verify_chain() {
+        Ok(report) => report,
+        Err(err) => panic!("verify_chain: {err}"),
+    };
+    assert_eq!(report.first_break, None);
+    assert_eq!(report.verified, 2);
+
+    let mut next = Event {
+        event_type: "agent.state_changed".to_string(),
+        entity_type: "agent".to_string(),
+        entity_id: "agent-1".to_string(),
+        ..Event::default()
+    };
+    if let Err(err) = repo.append(&mut next) {
+        panic!("append after prune: {err}");
+    }
+    let report = match repo.verify_chain() {
+        Ok(report) => report,
+        Err(err) => panic!("verify_chain after append: {err}"),
+    };
+    assert_eq!(report.first_break, None);
+    assert_eq!(report.verified, 3);
+
+    let _ = std::fs::remove_file(path);
+}
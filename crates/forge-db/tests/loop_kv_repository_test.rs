@@ -236,6 +236,56 @@ fn list_by_loop_isolates_loops() {
     assert_eq!(items2[0].value, "loop2-val");
 }
 
+#[test]
+fn search_matches_key_glob_and_value_substring_across_loops() {
+    let (db, loop_id) = setup_db();
+    let loop_id2 = "loop-test-002";
+    if let Err(err) = db.conn().execute(
+        "INSERT INTO loops (id, name, repo_path) VALUES (?1, ?2, ?3)",
+        params![loop_id2, "other-loop", "/repo/other"],
+    ) {
+        panic!("insert second loop: {err}");
+    }
+
+    let repo = LoopKVRepository::new(&db);
+    for (loop_ref, key, value) in [
+        (loop_id.as_str(), "blocked_on", "agent-b"),
+        (loop_id.as_str(), "owner", "agent-a"),
+        (loop_id2, "blocked_on", "review by agent-b"),
+    ] {
+        if let Err(err) = repo.set(loop_ref, key, value) {
+            panic!("set {loop_ref}/{key}: {err}");
+        }
+    }
+
+    let by_value = match repo.search(None, Some("agent-b")) {
+        Ok(items) => items,
+        Err(err) => panic!("search by value: {err}"),
+    };
+    let hits: Vec<(&str, &str)> = by_value
+        .iter()
+        .map(|kv| (kv.loop_id.as_str(), kv.key.as_str()))
+        .collect();
+    assert_eq!(
+        hits,
+        vec![(loop_id.as_str(), "blocked_on"), (loop_id2, "blocked_on")]
+    );
+
+    let by_key = match repo.search(Some("own*"), None) {
+        Ok(items) => items,
+        Err(err) => panic!("search by key: {err}"),
+    };
+    assert_eq!(by_key.len(), 1);
+    assert_eq!(by_key[0].value, "agent-a");
+
+    let both = match repo.search(Some("blocked_*"), Some("review")) {
+        Ok(items) => items,
+        Err(err) => panic!("search by key and value: {err}"),
+    };
+    assert_eq!(both.len(), 1);
+    assert_eq!(both[0].loop_id, loop_id2);
+}
+
 // -----------------------------------------------------------------------
 // Delete edge cases
 // -----------------------------------------------------------------------
@@ -0,0 +1,206 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use forge_db::metrics_repository::{
+    MetricSample, MetricsRepository, MAX_METRIC_SERIES, MAX_METRIC_TAGS,
+};
+use forge_db::{Config, Db, DbError};
+
+fn setup_db() -> Db {
+    let path = temp_db_path("metrics-repo");
+    let mut db = match Db::open(Config::new(&path)) {
+        Ok(db) => db,
+        Err(err) => panic!("open db: {err}"),
+    };
+    if let Err(err) = db.migrate_up() {
+        panic!("migrate_up: {err}");
+    }
+    db
+}
+
+fn sample(name: &str, value: f64, timestamp: &str, queue: &str) -> MetricSample {
+    let mut tags = BTreeMap::new();
+    tags.insert("queue".to_string(), queue.to_string());
+    MetricSample {
+        name: name.to_string(),
+        value,
+        tags,
+        timestamp: timestamp.to_string(),
+    }
+}
+
+#[test]
+fn record_and_query_range_returns_ordered_series() {
+    let db = setup_db();
+    let repo = MetricsRepository::new(&db);
+
+    for mut entry in [
+        sample("queue_latency_ms", 40.0, "2026-01-01T00:00:02Z", "default"),
+        sample("queue_latency_ms", 12.5, "2026-01-01T00:00:00Z", "default"),
+        sample("queue_latency_ms", 30.0, "2026-01-01T00:00:01Z", "priority"),
+        sample("queue_latency_ms", 90.0, "2026-01-01T00:00:05Z", "default"),
+        sample("daemon_threads", 8.0, "2026-01-01T00:00:01Z", "default"),
+    ] {
+        if let Err(err) = repo.record(&mut entry) {
+            panic!("record: {err}");
+        }
+    }
+
+    let series = match repo.query_range(
+        "queue_latency_ms",
+        "2026-01-01T00:00:00Z",
+        "2026-01-01T00:00:05Z",
+    ) {
+        Ok(series) => series,
+        Err(err) => panic!("query_range: {err}"),
+    };
+    let points: Vec<(&str, f64, &str)> = series
+        .iter()
+        .map(|entry| {
+            (
+                entry.timestamp.as_str(),
+                entry.value,
+                entry.tags.get("queue").map_or("", String::as_str),
+            )
+        })
+        .collect();
+    assert_eq!(
+        points,
+        vec![
+            ("2026-01-01T00:00:00Z", 12.5, "default"),
+            ("2026-01-01T00:00:01Z", 30.0, "priority"),
+            ("2026-01-01T00:00:02Z", 40.0, "default"),
+        ]
+    );
+
+    let open = match repo.query_range("queue_latency_ms", "", "") {
+        Ok(series) => series,
+        Err(err) => panic!("query_range open: {err}"),
+    };
+    assert_eq!(open.len(), 4);
+
+    let latest = match repo.latest_per_series() {
+        Ok(latest) => latest,
+        Err(err) => panic!("latest_per_series: {err}"),
+    };
+    assert_eq!(latest.len(), 3);
+    assert!(latest
+        .iter()
+        .any(|entry| entry.name == "queue_latency_ms" && entry.value == 90.0));
+
+    match repo.prune_before("2026-01-01T00:00:02Z") {
+        Ok(removed) => assert_eq!(removed, 3),
+        Err(err) => panic!("prune_before: {err}"),
+    }
+    match repo.series_count() {
+        Ok(count) => assert_eq!(count, 1),
+        Err(err) => panic!("series_count: {err}"),
+    }
+}
+
+#[test]
+fn record_rejects_unbounded_tags_and_bad_values() {
+    let db = setup_db();
+    let repo = MetricsRepository::new(&db);
+
+    let mut wide = sample("queue_latency_ms", 1.0, "", "default");
+    for index in 0..MAX_METRIC_TAGS {
+        wide.tags.insert(format!("tag{index}"), "x".to_string());
+    }
+    assert!(matches!(
+        repo.record(&mut wide),
+        Err(DbError::Validation(_))
+    ));
+
+    let mut nan = sample("queue_latency_ms", f64::NAN, "", "default");
+    assert!(matches!(repo.record(&mut nan), Err(DbError::Validation(_))));
+
+    let mut unnamed = sample("  ", 1.0, "", "default");
+    assert!(matches!(
+        repo.record(&mut unnamed),
+        Err(DbError::Validation(_))
+    ));
+
+    let mut stamped = sample("daemon_threads", 4.0, "", "default");
+    if let Err(err) = repo.record(&mut stamped) {
+        panic!("record: {err}");
+    }
+    assert_eq!(stamped.timestamp.len(), 20);
+    assert!(stamped.timestamp.ends_with('Z'));
+}
+
+#[test]
+fn record_caps_series_until_prune_frees_them() {
+    let db = setup_db();
+    let seeded = db.immediate_transaction(|db| {
+        let repo = MetricsRepository::new(db);
+        for index in 0..MAX_METRIC_SERIES {
+            let mut entry = sample(
+                "queue_latency_ms",
+                1.0,
+                "2026-01-01T00:00:00Z",
+                &format!("q{index}"),
+            );
+            repo.record(&mut entry)?;
+        }
+        Ok(())
+    });
+    if let Err(err) = seeded {
+        panic!("seed series: {err}");
+    }
+
+    let repo = MetricsRepository::new(&db);
+    let mut extra = sample("queue_latency_ms", 1.0, "2026-01-02T00:00:00Z", "new");
+    assert!(matches!(
+        repo.record(&mut extra),
+        Err(DbError::Validation(_))
+    ));
+    let mut existing = sample("queue_latency_ms", 2.0, "2026-01-02T00:00:00Z", "q0");
+    if let Err(err) = repo.record(&mut existing) {
+        panic!("record existing series: {err}");
+    }
+
+    match repo.prune_before("2026-01-01T12:00:00Z") {
+        Ok(removed) => assert_eq!(removed, MAX_METRIC_SERIES as usize),
+        Err(err) => panic!("prune_before: {err}"),
+    }
+    match repo.series_count() {
+        Ok(count) => assert_eq!(count, 1),
+        Err(err) => panic!("series_count: {err}"),
+    }
+    if let Err(err) = repo.record(&mut extra) {
+        panic!("record after prune: {err}");
+    }
+}
+
+#[test]
+fn query_range_surfaces_corrupt_tags_json() {
+    let db = setup_db();
+    if let Err(err) = db.conn().execute(
+        "INSERT INTO metrics (name, value, tags_json, timestamp) VALUES (?1, ?2, ?3, ?4)",
+        rusqlite::params!["daemon_threads", 4.0, "{not json", "2026-02-10T10:00:00Z"],
+    ) {
+        panic!("insert corrupt row: {err}");
+    }
+
+    let repo = MetricsRepository::new(&db);
+    match repo.query_range("daemon_threads", "", "") {
+        Ok(samples) => panic!("corrupt tags should fail, got {samples:?}"),
+        Err(err) => assert!(matches!(err, DbError::Open(_)), "{err}"),
+    }
+}
+
+fn temp_db_path(prefix: &str) -> PathBuf {
+    static UNIQUE_SUFFIX: AtomicU64 = AtomicU64::new(0);
+    let nanos = match SystemTime::now().duration_since(UNIX_EPOCH) {
+        Ok(d) => d.as_nanos(),
+        Err(_) => 0,
+    };
+    let suffix = UNIQUE_SUFFIX.fetch_add(1, Ordering::Relaxed);
+    std::env::temp_dir().join(format!(
+        "forge-db-{prefix}-{nanos}-{}-{suffix}.sqlite",
+        std::process::id(),
+    ))
+}
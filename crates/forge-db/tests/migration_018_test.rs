@@ -0,0 +1,101 @@
+#![allow(clippy::expect_used, clippy::unwrap_used)]
+
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use forge_db::{Config, Db, MIGRATIONS};
+use rusqlite::{params, Connection, OptionalExtension};
+
+#[test]
+fn migration_018_embedded_sql_matches_go_files() {
+    let migration = match MIGRATIONS.iter().find(|entry| entry.version == 18) {
+        Some(migration) => migration,
+        None => panic!("migration 018 not embedded"),
+    };
+
+    let up = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../old/go/internal/db/migrations/018_event_hash_chain.up.sql"
+    ));
+    let down = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../old/go/internal/db/migrations/018_event_hash_chain.down.sql"
+    ));
+
+    assert_eq!(migration.up_sql, up);
+    assert_eq!(migration.down_sql, down);
+}
+
+#[test]
+fn migration_018_up_down_parity() {
+    let path = temp_db_path("migration-018");
+
+    let mut db = Db::open(Config::new(&path)).unwrap_or_else(|err| panic!("open db: {err}"));
+    db.migrate_to(17)
+        .unwrap_or_else(|err| panic!("migrate_to(17): {err}"));
+    drop(db);
+
+    let conn = Connection::open(&path).unwrap_or_else(|err| panic!("open sqlite: {err}"));
+    assert!(!column_exists(&conn, "events", "chain_hash"));
+    drop(conn);
+
+    let mut db = Db::open(Config::new(&path)).unwrap_or_else(|err| panic!("open db: {err}"));
+    db.migrate_to(18)
+        .unwrap_or_else(|err| panic!("migrate_to(18): {err}"));
+    drop(db);
+
+    let conn = Connection::open(&path).unwrap_or_else(|err| panic!("open sqlite: {err}"));
+    assert!(column_exists(&conn, "events", "prev_hash"));
+    assert!(column_exists(&conn, "events", "chain_hash"));
+    drop(conn);
+
+    let mut db = Db::open(Config::new(&path)).unwrap_or_else(|err| panic!("open db: {err}"));
+    db.migrate_to(17)
+        .unwrap_or_else(|err| panic!("migrate_to(17): {err}"));
+    drop(db);
+
+    let conn = Connection::open(&path).unwrap_or_else(|err| panic!("open sqlite: {err}"));
+    assert!(!column_exists(&conn, "events", "prev_hash"));
+    assert!(!column_exists(&conn, "events", "chain_hash"));
+    assert!(index_exists(&conn, "idx_events_timestamp"));
+    assert!(index_exists(&conn, "idx_events_entity_timestamp"));
+    drop(conn);
+
+    let _ = std::fs::remove_file(path);
+}
+
+fn column_exists(conn: &Connection, table: &str, column: &str) -> bool {
+    let mut stmt = conn
+        .prepare(&format!("PRAGMA table_info({table})"))
+        .unwrap_or_else(|err| panic!("prepare table_info: {err}"));
+    let names = stmt
+        .query_map([], |row| row.get::<_, String>(1))
+        .unwrap_or_else(|err| panic!("query table_info: {err}"));
+    for name in names {
+        if name.unwrap_or_else(|err| panic!("read column name: {err}")) == column {
+            return true;
+        }
+    }
+    false
+}
+
+fn index_exists(conn: &Connection, name: &str) -> bool {
+    let row = conn
+        .query_row(
+            "SELECT 1 FROM sqlite_master WHERE type = 'index' AND name = ?1 LIMIT 1",
+            params![name],
+            |row| row.get::<_, i32>(0),
+        )
+        .optional()
+        .unwrap_or_else(|err| panic!("sqlite_master query failed: {err}"));
+    row.is_some()
+}
+
+fn temp_db_path(prefix: &str) -> PathBuf {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_else(|err| panic!("clock before epoch: {err}"))
+        .as_nanos();
+    let suffix = uuid::Uuid::new_v4();
+    std::env::temp_dir().join(format!("forge-db-{prefix}-{nanos}-{suffix}.sqlite"))
+}
@@ -0,0 +1,100 @@
+#![allow(clippy::expect_used, clippy::unwrap_used)]
+
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use forge_db::{Config, Db, MIGRATIONS};
+use rusqlite::{params, Connection, OptionalExtension};
+
+#[test]
+fn migration_019_embedded_sql_matches_go_files() {
+    let migration = match MIGRATIONS.iter().find(|entry| entry.version == 19) {
+        Some(migration) => migration,
+        None => panic!("migration 019 not embedded"),
+    };
+
+    let up = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../old/go/internal/db/migrations/019_daemon_metrics.up.sql"
+    ));
+    let down = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../old/go/internal/db/migrations/019_daemon_metrics.down.sql"
+    ));
+
+    assert_eq!(migration.up_sql, up);
+    assert_eq!(migration.down_sql, down);
+}
+
+#[test]
+fn migration_019_up_down_parity() {
+    let path = temp_db_path("migration-019");
+
+    let mut db = Db::open(Config::new(&path)).unwrap_or_else(|err| panic!("open db: {err}"));
+    db.migrate_to(18)
+        .unwrap_or_else(|err| panic!("migrate_to(18): {err}"));
+    drop(db);
+
+    let conn = Connection::open(&path).unwrap_or_else(|err| panic!("open sqlite: {err}"));
+    assert!(!table_exists(&conn, "metrics"));
+    drop(conn);
+
+    let mut db = Db::open(Config::new(&path)).unwrap_or_else(|err| panic!("open db: {err}"));
+    db.migrate_to(19)
+        .unwrap_or_else(|err| panic!("migrate_to(19): {err}"));
+    drop(db);
+
+    let conn = Connection::open(&path).unwrap_or_else(|err| panic!("open sqlite: {err}"));
+    assert!(table_exists(&conn, "metrics"));
+    assert!(index_exists(&conn, "idx_metrics_name_timestamp"));
+    assert!(index_exists(&conn, "idx_metrics_timestamp"));
+    assert!(index_exists(&conn, "idx_metrics_series_timestamp"));
+    assert!(table_exists(&conn, "metric_series"));
+    drop(conn);
+
+    let mut db = Db::open(Config::new(&path)).unwrap_or_else(|err| panic!("open db: {err}"));
+    db.migrate_to(18)
+        .unwrap_or_else(|err| panic!("migrate_to(18): {err}"));
+    drop(db);
+
+    let conn = Connection::open(&path).unwrap_or_else(|err| panic!("open sqlite: {err}"));
+    assert!(!table_exists(&conn, "metrics"));
+    assert!(!index_exists(&conn, "idx_metrics_name_timestamp"));
+    assert!(!table_exists(&conn, "metric_series"));
+    drop(conn);
+
+    let _ = std::fs::remove_file(path);
+}
+
+fn table_exists(conn: &Connection, name: &str) -> bool {
+    let row = conn
+        .query_row(
+            "SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = ?1 LIMIT 1",
+            params![name],
+            |row| row.get::<_, i32>(0),
+        )
+        .optional()
+        .unwrap_or_else(|err| panic!("sqlite_master query failed: {err}"));
+    row.is_some()
+}
+
+fn index_exists(conn: &Connection, name: &str) -> bool {
+    let row = conn
+        .query_row(
+            "SELECT 1 FROM sqlite_master WHERE type = 'index' AND name = ?1 LIMIT 1",
+            params![name],
+            |row| row.get::<_, i32>(0),
+        )
+        .optional()
+        .unwrap_or_else(|err| panic!("sqlite_master query failed: {err}"));
+    row.is_some()
+}
+
+fn temp_db_path(prefix: &str) -> PathBuf {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_else(|err| panic!("clock before epoch: {err}"))
+        .as_nanos();
+    let suffix = uuid::Uuid::new_v4();
+    std::env::temp_dir().join(format!("forge-db-{prefix}-{nanos}-{suffix}.sqlite"))
+}
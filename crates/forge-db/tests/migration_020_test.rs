@@ -0,0 +1,126 @@
+#![allow(clippy::expect_used, clippy::unwrap_used)]
+
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use forge_db::{Config, Db, MIGRATIONS};
+use rusqlite::{params, Connection};
+
+#[test]
+fn migration_020_embedded_sql_matches_go_files() {
+    let migration = match MIGRATIONS.iter().find(|entry| entry.version == 20) {
+        Some(migration) => migration,
+        None => panic!("migration 020 not embedded"),
+    };
+
+    let up = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../old/go/internal/db/migrations/020_loop_paused_state.up.sql"
+    ));
+    let down = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../old/go/internal/db/migrations/020_loop_paused_state.down.sql"
+    ));
+
+    assert_eq!(migration.up_sql, up);
+    assert_eq!(migration.down_sql, down);
+}
+
+#[test]
+fn migration_020_allows_paused_and_keeps_child_rows() {
+    let path = temp_db_path("migration-020");
+
+    let mut db = Db::open(Config::new(&path)).unwrap_or_else(|err| panic!("open db: {err}"));
+    db.migrate_to(19)
+        .unwrap_or_else(|err| panic!("migrate_to(19): {err}"));
+    drop(db);
+
+    let conn = Connection::open(&path).unwrap_or_else(|err| panic!("open sqlite: {err}"));
+    conn.execute(
+        "INSERT INTO loops (id, short_id, name, repo_path, max_iterations) VALUES ('loop-1', 'abc12345', 'demo', '/repo', 7)",
+        [],
+    )
+    .unwrap_or_else(|err| panic!("insert loop: {err}"));
+    conn.execute(
+        "INSERT INTO loop_kv (id, loop_id, key, value) VALUES ('kv-1', 'loop-1', 'focus', 'tests')",
+        [],
+    )
+    .unwrap_or_else(|err| panic!("insert loop kv: {err}"));
+    conn.execute(
+        "INSERT INTO loop_queue_items (id, loop_id, type, position, payload_json) VALUES ('q-1', 'loop-1', 'message_append', 1, '{}')",
+        [],
+    )
+    .unwrap_or_else(|err| panic!("insert queue item: {err}"));
+    conn.execute(
+        "INSERT INTO loop_runs (id, loop_id) VALUES ('run-1', 'loop-1')",
+        [],
+    )
+    .unwrap_or_else(|err| panic!("insert loop run: {err}"));
+    assert!(set_state(&conn, "paused").is_err());
+    drop(conn);
+
+    let mut db = Db::open(Config::new(&path)).unwrap_or_else(|err| panic!("open db: {err}"));
+    db.migrate_to(20)
+        .unwrap_or_else(|err| panic!("migrate_to(20): {err}"));
+    drop(db);
+
+    let conn = Connection::open(&path).unwrap_or_else(|err| panic!("open sqlite: {err}"));
+    set_state(&conn, "paused").unwrap_or_else(|err| panic!("set paused: {err}"));
+    assert_child_rows_kept(&conn);
+    let (short_id, max_iterations): (String, i64) = conn
+        .query_row(
+            "SELECT short_id, max_iterations FROM loops WHERE id = 'loop-1'",
+            [],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .unwrap_or_else(|err| panic!("read loop: {err}"));
+    assert_eq!(short_id, "abc12345");
+    assert_eq!(max_iterations, 7);
+    drop(conn);
+
+    let mut db = Db::open(Config::new(&path)).unwrap_or_else(|err| panic!("open db: {err}"));
+    db.migrate_to(19)
+        .unwrap_or_else(|err| panic!("migrate_to(19): {err}"));
+    drop(db);
+
+    let conn = Connection::open(&path).unwrap_or_else(|err| panic!("open sqlite: {err}"));
+    let state: String = conn
+        .query_row("SELECT state FROM loops WHERE id = 'loop-1'", [], |row| {
+            row.get(0)
+        })
+        .unwrap_or_else(|err| panic!("read state: {err}"));
+    assert_eq!(state, "stopped");
+    assert_child_rows_kept(&conn);
+    drop(conn);
+
+    let _ = std::fs::remove_file(path);
+}
+
+fn assert_child_rows_kept(conn: &Connection) {
+    assert_eq!(count(conn, "loop_kv"), 1);
+    assert_eq!(count(conn, "loop_queue_items"), 1);
+    assert_eq!(count(conn, "loop_runs"), 1);
+}
+
+fn set_state(conn: &Connection, state: &str) -> rusqlite::Result<usize> {
+    conn.execute(
+        "UPDATE loops SET state = ?1 WHERE id = 'loop-1'",
+        params![state],
+    )
+}
+
+fn count(conn: &Connection, table: &str) -> i64 {
+    conn.query_row(&format!("SELECT COUNT(*) FROM {table}"), [], |row| {
+        row.get(0)
+    })
+    .unwrap_or_else(|err| panic!("count {table}: {err}"))
+}
+
+fn temp_db_path(prefix: &str) -> PathBuf {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_else(|err| panic!("clock before epoch: {err}"))
+        .as_nanos();
+    let suffix = uuid::Uuid::new_v4();
+    std::env::temp_dir().join(format!("forge-db-{prefix}-{nanos}-{suffix}.sqlite"))
+}
@@ -0,0 +1,116 @@
+#![allow(clippy::expect_used, clippy::unwrap_used)]
+
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use forge_db::event_repository::{Event, EventRepository};
+use forge_db::{Config, Db, MIGRATIONS};
+use rusqlite::{params, Connection, OptionalExtension};
+
+#[test]
+fn migration_021_embedded_sql_matches_go_files() {
+    let migration = match MIGRATIONS.iter().find(|entry| entry.version == 21) {
+        Some(migration) => migration,
+        None => panic!("migration 021 not embedded"),
+    };
+
+    let up = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../old/go/internal/db/migrations/021_event_loop_entity.up.sql"
+    ));
+    let down = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../old/go/internal/db/migrations/021_event_loop_entity.down.sql"
+    ));
+
+    assert_eq!(migration.up_sql, up);
+    assert_eq!(migration.down_sql, down);
+}
+
+#[test]
+fn migration_021_allows_loop_events_and_keeps_the_chain_across_rollback() {
+    let path = temp_db_path("migration-021");
+
+    let mut db = Db::open(Config::new(&path)).unwrap_or_else(|err| panic!("open db: {err}"));
+    db.migrate_to(20)
+        .unwrap_or_else(|err| panic!("migrate_to(20): {err}"));
+    append(&db, "system", "sys-1").unwrap_or_else(|err| panic!("append system: {err}"));
+    assert!(append(&db, "loop", "loop-1").is_err());
+
+    db.migrate_to(21)
+        .unwrap_or_else(|err| panic!("migrate_to(21): {err}"));
+    append(&db, "loop", "loop-1").unwrap_or_else(|err| panic!("append loop: {err}"));
+    append(&db, "system", "sys-2").unwrap_or_else(|err| panic!("append system: {err}"));
+
+    let report = EventRepository::new(&db)
+        .verify_chain()
+        .unwrap_or_else(|err| panic!("verify_chain: {err}"));
+    assert_eq!(report.verified, 3);
+    assert!(report.first_break.is_none(), "{:?}", report.first_break);
+    drop(db);
+
+    let conn = Connection::open(&path).unwrap_or_else(|err| panic!("open sqlite: {err}"));
+    assert!(index_exists(&conn, "idx_events_entity_timestamp"));
+    drop(conn);
+
+    let mut db = Db::open(Config::new(&path)).unwrap_or_else(|err| panic!("open db: {err}"));
+    db.migrate_to(20)
+        .unwrap_or_else(|err| panic!("migrate_to(20): {err}"));
+    drop(db);
+
+    let conn = Connection::open(&path).unwrap_or_else(|err| panic!("open sqlite: {err}"));
+    let mut stmt = conn
+        .prepare("SELECT entity_id FROM events ORDER BY rowid")
+        .unwrap_or_else(|err| panic!("prepare events: {err}"));
+    let ids = stmt
+        .query_map([], |row| row.get::<_, String>(0))
+        .unwrap_or_else(|err| panic!("query events: {err}"))
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap_or_else(|err| panic!("read events: {err}"));
+    assert_eq!(ids, vec!["sys-1", "loop-1", "sys-2"]);
+    assert!(index_exists(&conn, "idx_events_timestamp"));
+    assert!(index_exists(&conn, "idx_events_entity_timestamp"));
+    drop(stmt);
+    drop(conn);
+
+    let db = Db::open(Config::new(&path)).unwrap_or_else(|err| panic!("open db: {err}"));
+    let report = EventRepository::new(&db)
+        .verify_chain()
+        .unwrap_or_else(|err| panic!("verify_chain after down: {err}"));
+    assert_eq!(report.verified, 3);
+    assert!(report.first_break.is_none(), "{:?}", report.first_break);
+    drop(db);
+
+    let _ = std::fs::remove_file(path);
+}
+
+fn append(db: &Db, entity_type: &str, entity_id: &str) -> Result<(), forge_db::DbError> {
+    let mut event = Event {
+        event_type: "test.event".to_string(),
+        entity_type: entity_type.to_string(),
+        entity_id: entity_id.to_string(),
+        ..Default::default()
+    };
+    EventRepository::new(db).append(&mut event)
+}
+
+fn index_exists(conn: &Connection, name: &str) -> bool {
+    let row = conn
+        .query_row(
+            "SELECT 1 FROM sqlite_master WHERE type = 'index' AND name = ?1 LIMIT 1",
+            params![name],
+            |row| row.get::<_, i32>(0),
+        )
+        .optional()
+        .unwrap_or_else(|err| panic!("sqlite_master query failed: {err}"));
+    row.is_some()
+}
+
+fn temp_db_path(prefix: &str) -> PathBuf {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_else(|err| panic!("clock before epoch: {err}"))
+        .as_nanos();
+    let suffix = uuid::Uuid::new_v4();
+    std::env::temp_dir().join(format!("forge-db-{prefix}-{nanos}-{suffix}.sqlite"))
+}
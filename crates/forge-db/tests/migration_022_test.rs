@@ -0,0 +1,98 @@
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use forge_db::event_repository::{Event, EventRepository};
+use forge_db::{Config, Db, MIGRATIONS};
+use rusqlite::Connection;
+
+#[test]
+fn migration_022_embedded_sql_matches_go_files() {
+    let migration = match MIGRATIONS.iter().find(|entry| entry.version == 22) {
+        Some(migration) => migration,
+        None => panic!("migration 022 not embedded"),
+    };
+
+    let up = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../old/go/internal/db/migrations/022_event_chain_anchor.up.sql"
+    ));
+    let down = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../old/go/internal/db/migrations/022_event_chain_anchor.down.sql"
+    ));
+
+    assert_eq!(migration.up_sql, up);
+    assert_eq!(migration.down_sql, down);
+}
+
+#[test]
+fn migration_022_anchors_an_existing_chain() {
+    let path = temp_db_path("migration-022");
+
+    let mut db = Db::open(Config::new(&path)).unwrap_or_else(|err| panic!("open db: {err}"));
+    db.migrate_to(21)
+        .unwrap_or_else(|err| panic!("migrate_to(21): {err}"));
+    append(&db, "sys-1").unwrap_or_else(|err| panic!("append sys-1: {err}"));
+    append(&db, "sys-2").unwrap_or_else(|err| panic!("append sys-2: {err}"));
+
+    db.migrate_to(22)
+        .unwrap_or_else(|err| panic!("migrate_to(22): {err}"));
+    let chain = EventRepository::new(&db)
+        .list_chain()
+        .unwrap_or_else(|err| panic!("list_chain: {err}"));
+    let (genesis, tail): (String, String) = db
+        .conn()
+        .query_row(
+            "SELECT genesis_hash, tail_hash FROM event_chain_anchor WHERE id = 1",
+            [],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .unwrap_or_else(|err| panic!("read anchor: {err}"));
+    assert_eq!(genesis, "");
+    assert_eq!(tail, chain[1].chain_hash);
+
+    append(&db, "sys-3").unwrap_or_else(|err| panic!("append sys-3: {err}"));
+    let report = EventRepository::new(&db)
+        .verify_chain()
+        .unwrap_or_else(|err| panic!("verify_chain: {err}"));
+    assert_eq!(report.verified, 3);
+    assert!(report.first_break.is_none(), "{:?}", report.first_break);
+
+    db.migrate_to(21)
+        .unwrap_or_else(|err| panic!("migrate_to(21): {err}"));
+    drop(db);
+
+    let conn = Connection::open(&path).unwrap_or_else(|err| panic!("open sqlite: {err}"));
+    let anchors: i64 = conn
+        .query_row(
+            "SELECT COUNT(*) FROM sqlite_master WHERE type = 'table' AND name = 'event_chain_anchor'",
+            [],
+            |row| row.get(0),
+        )
+        .unwrap_or_else(|err| panic!("query sqlite_master: {err}"));
+    assert_eq!(anchors, 0);
+    drop(conn);
+
+    let _ = std::fs::remove_file(path);
+}
+
+fn append(db: &Db, entity_id: &str) -> Result<(), forge_db::DbError> {
+    let mut event = Event {
+        event_type: "test.event".to_string(),
+        entity_type: "system".to_string(),
+        entity_id: entity_id.to_string(),
+        ..Event::default()
+    };
+    EventRepository::new(db).append(&mut event)
+}
+
+fn temp_db_path(tag: &str) -> PathBuf {
+    let nanos = match SystemTime::now().duration_since(UNIX_EPOCH) {
+        Ok(d) => d.as_nanos(),
+        Err(_) => 0,
+    };
+    std::env::temp_dir().join(format!(
+        "forge-db-{tag}-{nanos}-{}.sqlite",
+        std::process::id()
+    ))
+}
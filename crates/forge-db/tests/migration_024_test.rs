@@ -0,0 +1,114 @@
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use forge_db::{Config, Db, MIGRATIONS};
+use rusqlite::{params, Connection};
+
+#[test]
+fn migration_024_embedded_sql_matches_go_files() {
+    let migration = match MIGRATIONS.iter().find(|entry| entry.version == 24) {
+        Some(migration) => migration,
+        None => panic!("migration 024 not embedded"),
+    };
+
+    let up = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../old/go/internal/db/migrations/024_queue_dead_letter.up.sql"
+    ));
+    let down = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../old/go/internal/db/migrations/024_queue_dead_letter.down.sql"
+    ));
+
+    assert_eq!(migration.up_sql, up);
+    assert_eq!(migration.down_sql, down);
+}
+
+#[test]
+fn migration_024_allows_dead_letter_and_keeps_attempts() {
+    let path = temp_db_path("migration-024");
+
+    let mut db = Db::open(Config::new(&path)).unwrap_or_else(|err| panic!("open db: {err}"));
+    db.migrate_to(23)
+        .unwrap_or_else(|err| panic!("migrate_to(23): {err}"));
+    drop(db);
+
+    let conn = Connection::open(&path).unwrap_or_else(|err| panic!("open sqlite: {err}"));
+    seed_queue_item(&conn);
+    assert!(set_status(&conn, "dead_letter").is_err());
+    drop(conn);
+
+    let mut db = Db::open(Config::new(&path)).unwrap_or_else(|err| panic!("open db: {err}"));
+    db.migrate_to(24)
+        .unwrap_or_else(|err| panic!("migrate_to(24): {err}"));
+    drop(db);
+
+    let conn = Connection::open(&path).unwrap_or_else(|err| panic!("open sqlite: {err}"));
+    set_status(&conn, "dead_letter").unwrap_or_else(|err| panic!("set dead_letter: {err}"));
+    assert!(set_status(&conn, "bogus").is_err());
+    assert_eq!(read_item(&conn), ("dead_letter".to_string(), 5, 1));
+    drop(conn);
+
+    let mut db = Db::open(Config::new(&path)).unwrap_or_else(|err| panic!("open db: {err}"));
+    db.migrate_to(23)
+        .unwrap_or_else(|err| panic!("migrate_to(23): {err}"));
+    drop(db);
+
+    let conn = Connection::open(&path).unwrap_or_else(|err| panic!("open sqlite: {err}"));
+    assert_eq!(read_item(&conn), ("failed".to_string(), 5, 1));
+    assert!(set_status(&conn, "dead_letter").is_err());
+    drop(conn);
+
+    let _ = std::fs::remove_file(path);
+}
+
+fn seed_queue_item(conn: &Connection) {
+    conn.execute(
+        "INSERT INTO nodes (id, name) VALUES ('node-1', 'node-1')",
+        [],
+    )
+    .unwrap_or_else(|err| panic!("insert node: {err}"));
+    conn.execute(
+        "INSERT INTO workspaces (id, name, node_id, repo_path, tmux_session)
+         VALUES ('ws-1', 'ws', 'node-1', '/tmp/repo', 'forge-test:0')",
+        [],
+    )
+    .unwrap_or_else(|err| panic!("insert workspace: {err}"));
+    conn.execute(
+        "INSERT INTO agents (id, workspace_id, type, tmux_pane)
+         VALUES ('agent-1', 'ws-1', 'opencode', 'forge-test:0.1')",
+        [],
+    )
+    .unwrap_or_else(|err| panic!("insert agent: {err}"));
+    conn.execute(
+        "INSERT INTO queue_items (id, agent_id, type, position, payload_json, attempts)
+         VALUES ('qi-1', 'agent-1', 'message', 1, '{}', 5)",
+        [],
+    )
+    .unwrap_or_else(|err| panic!("insert queue item: {err}"));
+}
+
+fn set_status(conn: &Connection, status: &str) -> rusqlite::Result<usize> {
+    conn.execute(
+        "UPDATE queue_items SET status = ?1 WHERE id = 'qi-1'",
+        params![status],
+    )
+}
+
+fn read_item(conn: &Connection) -> (String, i64, i64) {
+    conn.query_row(
+        "SELECT status, attempts, (SELECT COUNT(*) FROM queue_items) FROM queue_items WHERE id = 'qi-1'",
+        [],
+        |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+    )
+    .unwrap_or_else(|err| panic!("read queue item: {err}"))
+}
+
+fn temp_db_path(prefix: &str) -> PathBuf {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_else(|err| panic!("clock before epoch: {err}"))
+        .as_nanos();
+    let suffix = uuid::Uuid::new_v4();
+    std::env::temp_dir().join(format!("forge-db-{prefix}-{nanos}-{suffix}.sqlite"))
+}
@@ -622,6 +622,55 @@ fn remove_member_not_found() {
     let _ = std::fs::remove_file(path);
 }
 
+#[test]
+fn set_member_weight_allows_zero() {
+    let (db, path) = open_migrated("set-member-weight");
+    let pool_repo = PoolRepository::new(&db);
+    let profile_repo = ProfileRepository::new(&db);
+
+    let mut profile = sample_profile("pi-weight");
+    match profile_repo.create(&mut profile) {
+        Ok(()) => {}
+        Err(e) => panic!("create profile: {e}"),
+    }
+
+    let mut pool = sample_pool("weight-pool");
+    match pool_repo.create(&mut pool) {
+        Ok(()) => {}
+        Err(e) => panic!("create pool: {e}"),
+    }
+
+    let mut member = PoolMember {
+        pool_id: pool.id.clone(),
+        profile_id: profile.id.clone(),
+        ..PoolMember::default()
+    };
+    match pool_repo.add_member(&mut member) {
+        Ok(()) => {}
+        Err(e) => panic!("add_member: {e}"),
+    }
+
+    for weight in [4, 0] {
+        match pool_repo.set_member_weight(&pool.id, &profile.id, weight) {
+            Ok(()) => {}
+            Err(e) => panic!("set_member_weight({weight}): {e}"),
+        }
+        let members = match pool_repo.list_members(&pool.id) {
+            Ok(m) => m,
+            Err(e) => panic!("list_members: {e}"),
+        };
+        assert_eq!(members.len(), 1);
+        assert_eq!(members[0].weight, weight);
+    }
+
+    let err = pool_repo.set_member_weight(&pool.id, &profile.id, -1);
+    assert!(matches!(err, Err(DbError::Validation(_))));
+    let err = pool_repo.set_member_weight(&pool.id, "no-profile", 2);
+    assert!(matches!(err, Err(DbError::PoolNotFound)));
+
+    let _ = std::fs::remove_file(path);
+}
+
 #[test]
 fn list_members_empty() {
     let (db, path) = open_migrated("list-members-empty");
@@ -0,0 +1,89 @@
+#![cfg(feature = "trace")]
+#![allow(clippy::expect_used, clippy::unwrap_used)]
+
+use forge_db::query_trace::SlowQuery;
+use forge_db::{Config, Db};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+fn temp_db_path(tag: &str) -> PathBuf {
+    let nanos = match SystemTime::now().duration_since(UNIX_EPOCH) {
+        Ok(d) => d.as_nanos(),
+        Err(_) => 0,
+    };
+    std::env::temp_dir().join(format!(
+        "forge-db-trace-{tag}-{nanos}-{}.sqlite",
+        std::process::id()
+    ))
+}
+
+#[test]
+fn slow_query_hook_fires_once_for_query_over_threshold() {
+    let path = temp_db_path("slow");
+    let mut db = Db::open(Config::new(&path)).unwrap();
+    let seen: Arc<Mutex<Vec<SlowQuery>>> = Arc::new(Mutex::new(Vec::new()));
+    let sink = Arc::clone(&seen);
+    db.set_slow_query_hook(Duration::from_millis(20), move |query| {
+        sink.lock().unwrap().push(query.clone());
+    })
+    .unwrap();
+
+    let one: i64 = db
+        .conn()
+        .query_row("SELECT 1", [], |row| row.get(0))
+        .unwrap();
+    assert_eq!(one, 1);
+
+    let slow_sql =
+        "WITH RECURSIVE n(x) AS (SELECT 1 UNION ALL SELECT x + 1 FROM n WHERE x < 3000000) \
+                    SELECT SUM(x) FROM n";
+    let _: i64 = db.conn().query_row(slow_sql, [], |row| row.get(0)).unwrap();
+
+    db.clear_slow_query_hook();
+    let _: i64 = db.conn().query_row(slow_sql, [], |row| row.get(0)).unwrap();
+
+    let seen = seen.lock().unwrap();
+    assert_eq!(seen.len(), 1, "{seen:?}");
+    assert_eq!(seen[0].sql, slow_sql);
+    assert!(seen[0].duration > Duration::from_millis(20));
+
+    let _ = std::fs::remove_file(path);
+}
+
+#[test]
+fn slow_query_hooks_stay_with_their_connection() {
+    let fast_path = temp_db_path("fast-hook");
+    let slow_path = temp_db_path("slow-hook");
+    let mut fast = Db::open(Config::new(&fast_path)).unwrap();
+    let mut slow = Db::open(Config::new(&slow_path)).unwrap();
+    let fast_seen: Arc<Mutex<Vec<SlowQuery>>> = Arc::new(Mutex::new(Vec::new()));
+    let slow_seen: Arc<Mutex<Vec<SlowQuery>>> = Arc::new(Mutex::new(Vec::new()));
+    let sink = Arc::clone(&fast_seen);
+    fast.set_slow_query_hook(Duration::ZERO, move |query| {
+        sink.lock().unwrap().push(query.clone());
+    })
+    .unwrap();
+    let sink = Arc::clone(&slow_seen);
+    slow.set_slow_query_hook(Duration::from_secs(3600), move |query| {
+        sink.lock().unwrap().push(query.clone());
+    })
+    .unwrap();
+
+    let sql = "WITH RECURSIVE n(x) AS (SELECT 1 UNION ALL SELECT x + 1 FROM n WHERE x < 100000) \
+               SELECT SUM(x) FROM n";
+    let _: i64 = fast.conn().query_row(sql, [], |row| row.get(0)).unwrap();
+    let _: i64 = slow.conn().query_row(sql, [], |row| row.get(0)).unwrap();
+
+    assert!(fast_seen
+        .lock()
+        .unwrap()
+        .iter()
+        .any(|query| query.sql == sql));
+    assert!(slow_seen.lock().unwrap().is_empty());
+
+    drop(fast);
+    drop(slow);
+    let _ = std::fs::remove_file(fast_path);
+    let _ = std::fs::remove_file(slow_path);
+}
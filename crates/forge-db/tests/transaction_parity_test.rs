@@ -102,3 +102,31 @@ fn transaction_with_retry_retries_on_busy_error_message() {
 
     let _ = std::fs::remove_file(path);
 }
+
+#[test]
+fn immediate_transaction_rolls_back_repository_writes_on_error() {
+    let (db, path) = open_migrated("immediate");
+
+    let err = db
+        .immediate_transaction(|db| {
+            let mut event = forge_db::event_repository::Event {
+                event_type: "loop.takeover".into(),
+                entity_type: "system".into(),
+                entity_id: "loop-a".into(),
+                payload: "{}".into(),
+                ..Default::default()
+            };
+            forge_db::event_repository::EventRepository::new(db).create(&mut event)?;
+            Err::<(), DbError>(DbError::Validation("boom".into()))
+        })
+        .unwrap_err();
+    assert!(matches!(err, DbError::Validation(_)));
+
+    let count: i64 = db
+        .conn()
+        .query_row("SELECT COUNT(*) FROM events", [], |row| row.get(0))
+        .unwrap();
+    assert_eq!(count, 0);
+
+    let _ = std::fs::remove_file(path);
+}
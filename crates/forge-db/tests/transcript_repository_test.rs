@@ -168,3 +168,61 @@ fn create_requires_agent_id() {
 
     let _ = std::fs::remove_file(path);
 }
+
+#[test]
+fn capture_dedupes_identical_consecutive_content() {
+    let (db, path) = setup_db("capture-dedupe");
+    let repo = TranscriptRepository::new(&db);
+    let agent_id = seed_agent(&db, "tx-dedupe");
+
+    let mut first = Transcript {
+        agent_id: agent_id.clone(),
+        content: "idle".to_string(),
+        content_hash: "hash-idle".to_string(),
+        captured_at: "2026-02-09T10:00:00Z".to_string(),
+        ..Default::default()
+    };
+    let first_capture = match repo.capture(&mut first) {
+        Ok(value) => value,
+        Err(err) => panic!("capture first failed: {err}"),
+    };
+    assert!(!first_capture.deduped);
+    assert_eq!(first_capture.repeat_count, 1);
+
+    let mut second = Transcript {
+        agent_id: agent_id.clone(),
+        content: "idle".to_string(),
+        content_hash: "hash-idle".to_string(),
+        captured_at: "2026-02-09T10:00:30Z".to_string(),
+        ..Default::default()
+    };
+    let second_capture = match repo.capture(&mut second) {
+        Ok(value) => value,
+        Err(err) => panic!("capture second failed: {err}"),
+    };
+    assert!(second_capture.deduped);
+    assert_eq!(second_capture.id, first_capture.id);
+
+    let rows = match repo.list_by_agent(&agent_id, 0) {
+        Ok(value) => value,
+        Err(err) => panic!("list_by_agent failed: {err}"),
+    };
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].repeat_count, 2);
+
+    let mut changed = Transcript {
+        agent_id: agent_id.clone(),
+        content: "working".to_string(),
+        content_hash: "hash-working".to_string(),
+        captured_at: "2026-02-09T10:01:00Z".to_string(),
+        ..Default::default()
+    };
+    let changed_capture = match repo.capture(&mut changed) {
+        Ok(value) => value,
+        Err(err) => panic!("capture changed failed: {err}"),
+    };
+    assert!(!changed_capture.deduped);
+    assert_ne!(changed_capture.id, first_capture.id);
+
+    let _ = std::fs::remove_file(path);
+}
@@ -471,6 +471,52 @@ pub mod render {
                 Self::Rgb(r, g, b) => rgb_to_ansi256(r, g, b),
             }
         }
+
+        /// Resolve to RGB using the xterm 256-color palette for indexed colors.
+        #[must_use]
+        pub fn to_rgb(self) -> (u8, u8, u8) {
+            match self {
+                Self::Rgb(r, g, b) => (r, g, b),
+                Self::Ansi256(idx) => ansi256_to_rgb(idx),
+            }
+        }
+    }
+
+    fn ansi256_to_rgb(idx: u8) -> (u8, u8, u8) {
+        const SYSTEM: [(u8, u8, u8); 16] = [
+            (0, 0, 0),
+            (205, 0, 0),
+            (0, 205, 0),
+            (205, 205, 0),
+            (0, 0, 238),
+            (205, 0, 205),
+            (0, 205, 205),
+            (229, 229, 229),
+            (127, 127, 127),
+            (255, 0, 0),
+            (0, 255, 0),
+            (255, 255, 0),
+            (92, 92, 255),
+            (255, 0, 255),
+            (0, 255, 255),
+            (255, 255, 255),
+        ];
+        const LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+        match idx {
+            0..=15 => SYSTEM[idx as usize],
+            16..=231 => {
+                let cube = idx - 16;
+                (
+                    LEVELS[(cube / 36) as usize],
+                    LEVELS[((cube / 6) % 6) as usize],
+                    LEVELS[(cube % 6) as usize],
+                )
+            }
+            _ => {
+                let grey = 8 + (idx - 232) * 10;
+                (grey, grey, grey)
+            }
+        }
     }
 
     fn rgb_to_ansi256(r: u8, g: u8, b: u8) -> u8 {
@@ -581,6 +627,153 @@ pub mod render {
                 },
             )
         }
+
+        /// Overlapping region of two rects; zero-sized when they do not overlap.
+        #[must_use]
+        pub fn intersect(self, other: Self) -> Self {
+            let x = self.x.max(other.x);
+            let y = self.y.max(other.y);
+            let right = (self.x + self.width).min(other.x + other.width);
+            let bottom = (self.y + self.height).min(other.y + other.height);
+            Self {
+                x,
+                y,
+                width: right.saturating_sub(x),
+                height: bottom.saturating_sub(y),
+            }
+        }
+
+        /// Returns true when `(x, y)` lies inside this rect.
+        #[must_use]
+        pub fn contains(self, x: usize, y: usize) -> bool {
+            x >= self.x && x < self.x + self.width && y >= self.y && y < self.y + self.height
+        }
+    }
+
+    /// Axis along which a [`Layout`] places its children.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum LayoutAxis {
+        Horizontal,
+        Vertical,
+    }
+
+    /// Size constraint for one child of a [`Layout`].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Constraint {
+        /// Exactly `n` cells (less if the parent is too small).
+        Fixed(usize),
+        /// At least `n` cells; absorbs space left over after ratios.
+        Min(usize),
+        /// `num/den` of the space left after fixed and minimum sizes.
+        Ratio(u32, u32),
+    }
+
+    /// Constraint solver splitting a rect into children along one axis.
+    ///
+    /// Fixed and minimum sizes are reserved first, in order, until the parent
+    /// runs out of space. The remainder is shared between `Ratio` children and
+    /// whatever is still left is split evenly across `Min` children. Without
+    /// any `Min` child, unclaimed space stays empty at the end of the axis.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Layout {
+        axis: LayoutAxis,
+        constraints: Vec<Constraint>,
+    }
+
+    impl Layout {
+        #[must_use]
+        pub fn new(axis: LayoutAxis, constraints: Vec<Constraint>) -> Self {
+            Self { axis, constraints }
+        }
+
+        /// Children laid out left to right.
+        #[must_use]
+        pub fn horizontal(constraints: Vec<Constraint>) -> Self {
+            Self::new(LayoutAxis::Horizontal, constraints)
+        }
+
+        /// Children laid out top to bottom.
+        #[must_use]
+        pub fn vertical(constraints: Vec<Constraint>) -> Self {
+            Self::new(LayoutAxis::Vertical, constraints)
+        }
+
+        /// Resolve one child rect per constraint, in constraint order.
+        #[must_use]
+        pub fn split(&self, area: Rect) -> Vec<Rect> {
+            let total = match self.axis {
+                LayoutAxis::Horizontal => area.width,
+                LayoutAxis::Vertical => area.height,
+            };
+
+            let mut sizes = vec![0usize; self.constraints.len()];
+            let mut remaining = total;
+            for (size, constraint) in sizes.iter_mut().zip(&self.constraints) {
+                if let Constraint::Fixed(n) | Constraint::Min(n) = *constraint {
+                    *size = n.min(remaining);
+                    remaining -= *size;
+                }
+            }
+
+            let leftover = remaining;
+            let mut cumulative = 0.0f64;
+            let mut claimed = 0usize;
+            for (size, constraint) in sizes.iter_mut().zip(&self.constraints) {
+                if let Constraint::Ratio(num, den) = *constraint {
+                    if den == 0 {
+                        continue;
+                    }
+                    cumulative += f64::from(num) / f64::from(den);
+                    let end =
+                        ((leftover as f64 * cumulative + 1e-9).floor() as usize).min(leftover);
+                    let share = end.saturating_sub(claimed);
+                    *size = share;
+                    claimed += share;
+                }
+            }
+            remaining = leftover - claimed;
+
+            let min_count = self
+                .constraints
+                .iter()
+                .filter(|constraint| matches!(constraint, Constraint::Min(_)))
+                .count();
+            if let Some(base) = remaining.checked_div(min_count) {
+                let mut extra = remaining % min_count;
+                for (size, constraint) in sizes.iter_mut().zip(&self.constraints) {
+                    if matches!(constraint, Constraint::Min(_)) {
+                        *size += base;
+                        if extra > 0 {
+                            *size += 1;
+                            extra -= 1;
+                        }
+                    }
+                }
+            }
+
+            let mut offset = 0usize;
+            sizes
+                .into_iter()
+                .map(|size| {
+                    let rect = match self.axis {
+                        LayoutAxis::Horizontal => Rect {
+                            x: area.x + offset,
+                            y: area.y,
+                            width: size,
+                            height: area.height,
+                        },
+                        LayoutAxis::Vertical => Rect {
+                            x: area.x,
+                            y: area.y + offset,
+                            width: area.width,
+                            height: size,
+                        },
+                    };
+                    offset += size;
+                    rect
+                })
+                .collect()
+        }
     }
 
     /// Cell style represented as terminal colors and text attributes.
@@ -911,6 +1104,262 @@ pub mod render {
         }
     }
 
+    /// Lightweight markdown span source for message bodies and help text.
+    ///
+    /// Recognizes ATX headings, `**bold**`, `*italic*`/`_italic_`, `` `code` ``,
+    /// bullet lists and fenced code blocks. Anything it cannot match (unclosed
+    /// markers, unterminated fences) is rendered as plain text.
+    pub struct MarkdownSpanSource;
+
+    impl MarkdownSpanSource {
+        const FENCE: &'static str = "```";
+
+        fn is_fence(line: &str) -> bool {
+            line.trim_start().starts_with(Self::FENCE)
+        }
+
+        fn code_line(line: &str) -> StyledLine {
+            let mut styled = StyledLine::new();
+            styled.push_token(line, StyleToken::Muted);
+            styled
+        }
+
+        fn heading(line: &str) -> Option<&str> {
+            let trimmed = line.trim_start();
+            let level = trimmed.chars().take_while(|ch| *ch == '#').count();
+            if level == 0 || level > 6 {
+                return None;
+            }
+            let rest = &trimmed[level..];
+            if rest.is_empty() {
+                return Some("");
+            }
+            rest.strip_prefix(' ').map(str::trim)
+        }
+
+        fn bullet(line: &str) -> Option<(&str, &str)> {
+            let indent_len = line.len() - line.trim_start().len();
+            let (indent, rest) = line.split_at(indent_len);
+            ["- ", "* ", "+ "]
+                .iter()
+                .find_map(|marker| rest.strip_prefix(marker))
+                .map(|body| (indent, body))
+        }
+
+        fn push_inline(line: &mut StyledLine, input: &str) {
+            let mut plain = String::new();
+            let mut rest = input;
+            while let Some(ch) = rest.chars().next() {
+                let matched = match ch {
+                    '`' => Self::delimited(rest, "`").map(|(inner, len)| {
+                        (OwnedStyledSpan::token(inner, StyleToken::Warning), len)
+                    }),
+                    '*' if rest.starts_with("**") => Self::delimited(rest, "**")
+                        .map(|(inner, len)| (OwnedStyledSpan::role(inner, TextRole::Accent), len)),
+                    '*' => Self::delimited(rest, "*")
+                        .map(|(inner, len)| (OwnedStyledSpan::role(inner, TextRole::Info), len)),
+                    '_' if !plain.ends_with(|prev: char| prev.is_alphanumeric()) => {
+                        Self::delimited(rest, "_")
+                            .map(|(inner, len)| (OwnedStyledSpan::role(inner, TextRole::Info), len))
+                    }
+                    _ => None,
+                };
+                match matched {
+                    Some((span, len)) => {
+                        if !plain.is_empty() {
+                            line.push_role(std::mem::take(&mut plain), TextRole::Primary);
+                        }
+                        line.push(span);
+                        rest = &rest[len..];
+                    }
+                    None => {
+                        plain.push(ch);
+                        rest = &rest[ch.len_utf8()..];
+                    }
+                }
+            }
+            if !plain.is_empty() || line.is_empty() {
+                line.push_role(plain, TextRole::Primary);
+            }
+        }
+
+        /// Returns the inner text and total byte length of a `marker`-delimited
+        /// run at the start of `input`, if it is closed and non-empty.
+        fn delimited<'a>(input: &'a str, marker: &str) -> Option<(&'a str, usize)> {
+            let body = input.strip_prefix(marker)?;
+            let end = body.find(marker)?;
+            if end == 0 {
+                return None;
+            }
+            Some((&body[..end], marker.len() * 2 + end))
+        }
+    }
+
+    impl SpanSource for MarkdownSpanSource {
+        fn style_line(&self, input: &str) -> StyledLine {
+            if Self::is_fence(input) {
+                return StyledLine::from_role(input, TextRole::Muted);
+            }
+            if let Some(title) = Self::heading(input) {
+                return StyledLine::from_role(title, TextRole::Accent);
+            }
+            let mut line = StyledLine::new();
+            match Self::bullet(input) {
+                Some((indent, body)) => {
+                    line.push_role(format!("{indent}\u{2022} "), TextRole::Muted);
+                    Self::push_inline(&mut line, body);
+                }
+                None => Self::push_inline(&mut line, input),
+            }
+            line
+        }
+
+        fn style_text(&self, input: &str) -> StyledText {
+            let lines: Vec<&str> = input.lines().collect();
+            let mut out = StyledText::new();
+            let mut idx = 0;
+            while idx < lines.len() {
+                let line = lines[idx];
+                if !Self::is_fence(line) {
+                    out.push(self.style_line(line));
+                    idx += 1;
+                    continue;
+                }
+                let close = lines[idx + 1..]
+                    .iter()
+                    .position(|candidate| Self::is_fence(candidate))
+                    .map(|offset| idx + 1 + offset);
+                match close {
+                    Some(close) => {
+                        out.push(StyledLine::from_role(line, TextRole::Muted));
+                        for code in &lines[idx + 1..close] {
+                            out.push(Self::code_line(code));
+                        }
+                        out.push(StyledLine::from_role(lines[close], TextRole::Muted));
+                        idx = close + 1;
+                    }
+                    None => {
+                        // Unterminated fence: keep the marker as literal text.
+                        out.push(StyledLine::plain(line));
+                        idx += 1;
+                    }
+                }
+            }
+            out
+        }
+    }
+
+    /// Line-oriented span source for harness and daemon log output.
+    ///
+    /// Leading timestamps are muted, the first level token (`ERROR`, `WARN`,
+    /// `INFO`, `DEBUG`, optionally bracketed or colon-suffixed) takes its severity
+    /// role, and `key=value` pairs get muted keys. Everything else is primary.
+    pub struct LogSpanSource;
+
+    impl LogSpanSource {
+        fn level_role(word: &str) -> Option<TextRole> {
+            let bare = word
+                .trim_start_matches('[')
+                .trim_end_matches(':')
+                .trim_end_matches(']');
+            match bare.to_ascii_uppercase().as_str() {
+                "ERROR" | "ERR" | "FATAL" | "PANIC" => Some(TextRole::Danger),
+                "WARN" | "WARNING" => Some(TextRole::Warning),
+                "INFO" => Some(TextRole::Info),
+                "DEBUG" | "TRACE" => Some(TextRole::Muted),
+                _ => None,
+            }
+        }
+
+        /// `2026-01-02`, `2026-01-02T03:04:05Z`, `03:04:05.123` and bracketed forms.
+        fn is_timestamp(word: &str) -> bool {
+            let bare = word.trim_start_matches('[').trim_end_matches(']');
+            let bytes = bare.as_bytes();
+            let digits = |range: std::ops::Range<usize>| {
+                bytes
+                    .get(range)
+                    .is_some_and(|part| part.iter().all(u8::is_ascii_digit))
+            };
+            let date = bytes.len() >= 10
+                && digits(0..4)
+                && bytes[4] == b'-'
+                && digits(5..7)
+                && bytes[7] == b'-'
+                && digits(8..10);
+            let time = bytes.len() >= 8
+                && digits(0..2)
+                && bytes[2] == b':'
+                && digits(3..5)
+                && bytes[5] == b':'
+                && digits(6..8);
+            date || time
+        }
+
+        fn key_value(word: &str) -> Option<(&str, &str)> {
+            let (key, value) = word.split_once('=')?;
+            let valid_key = !key.is_empty()
+                && key
+                    .chars()
+                    .all(|ch| ch.is_ascii_alphanumeric() || matches!(ch, '_' | '-' | '.'));
+            valid_key.then(|| (&word[..=key.len()], value))
+        }
+
+        fn push(line: &mut StyledLine, text: &str, role: TextRole) {
+            if text.is_empty() {
+                return;
+            }
+            if let Some(last) = line.spans.last_mut() {
+                if last.style == SpanStyle::Role(role) {
+                    last.text.push_str(text);
+                    return;
+                }
+            }
+            line.push_role(text, role);
+        }
+    }
+
+    impl SpanSource for LogSpanSource {
+        fn style_line(&self, input: &str) -> StyledLine {
+            let mut line = StyledLine::new();
+            let mut in_prefix = true;
+            let mut rest = input;
+            while !rest.is_empty() {
+                let space_len = rest.len() - rest.trim_start().len();
+                Self::push(&mut line, &rest[..space_len], TextRole::Primary);
+                rest = &rest[space_len..];
+                let word_len = rest.find(char::is_whitespace).unwrap_or(rest.len());
+                let word = &rest[..word_len];
+                rest = &rest[word_len..];
+                if word.is_empty() {
+                    continue;
+                }
+
+                if in_prefix && Self::is_timestamp(word) {
+                    Self::push(&mut line, word, TextRole::Muted);
+                    continue;
+                }
+                if in_prefix {
+                    in_prefix = false;
+                    if let Some(role) = Self::level_role(word) {
+                        line.push_role(word, role);
+                        continue;
+                    }
+                }
+                match Self::key_value(word) {
+                    Some((key, value)) => {
+                        Self::push(&mut line, key, TextRole::Muted);
+                        Self::push(&mut line, value, TextRole::Primary);
+                    }
+                    None => Self::push(&mut line, word, TextRole::Primary),
+                }
+            }
+            if line.is_empty() {
+                line.push_role("", TextRole::Primary);
+            }
+            line
+        }
+    }
+
     /// Box-drawing character sets.
     struct BorderChars {
         top_left: char,
@@ -950,12 +1399,30 @@ pub mod render {
         }
     }
 
+    /// Direction of a scrollbar.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Orientation {
+        /// Drawn on the right edge of the rect, scrolling rows.
+        Vertical,
+        /// Drawn on the bottom edge of the rect, scrolling columns.
+        Horizontal,
+    }
+
+    /// One cell that differs between two frames.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct CellChange {
+        pub x: usize,
+        pub y: usize,
+        pub cell: FrameCell,
+    }
+
     /// Stable frame abstraction shielding app crates from FrankenTUI internals.
     #[derive(Debug, Clone, PartialEq, Eq)]
     pub struct RenderFrame {
         size: FrameSize,
         cells: Vec<FrameCell>,
         theme: ThemeSpec,
+        clips: Vec<Rect>,
     }
 
     impl RenderFrame {
@@ -976,6 +1443,7 @@ pub mod render {
                 size,
                 cells: vec![default_cell; size.width.saturating_mul(size.height)],
                 theme,
+                clips: Vec::new(),
             }
         }
 
@@ -1017,14 +1485,44 @@ pub mod render {
             Some(self.cells[y * self.size.width + x])
         }
 
-        /// Write a single cell, clipped to frame bounds.
+        /// Write a single cell, clipped to frame bounds and the active clip.
         pub fn set_cell(&mut self, x: usize, y: usize, cell: FrameCell) {
             if x >= self.size.width || y >= self.size.height {
                 return;
             }
+            if let Some(clip) = self.clips.last() {
+                if !clip.contains(x, y) {
+                    return;
+                }
+            }
             self.cells[y * self.size.width + x] = cell;
         }
 
+        /// Push a clip region, intersected with the currently active clip.
+        ///
+        /// All `set_cell`/`draw_*` calls ignore cells outside the active clip
+        /// until the matching [`RenderFrame::pop_clip`].
+        pub fn push_clip(&mut self, rect: Rect) {
+            let active = self.clip_rect();
+            self.clips.push(active.intersect(rect));
+        }
+
+        /// Pop the most recent clip region, restoring the previous one.
+        pub fn pop_clip(&mut self) {
+            self.clips.pop();
+        }
+
+        /// Active clip region; the full frame when no clip is pushed.
+        #[must_use]
+        pub fn clip_rect(&self) -> Rect {
+            self.clips.last().copied().unwrap_or(Rect {
+                x: 0,
+                y: 0,
+                width: self.size.width,
+                height: self.size.height,
+            })
+        }
+
         /// Draw text on a single row, clipped to frame width.
         ///
         /// Legacy single-span helper retained during migration to `draw_spans`.
@@ -1067,7 +1565,7 @@ pub mod render {
                     if col >= self.size.width {
                         return;
                     }
-                    self.cells[y * self.size.width + col] = FrameCell { glyph, style };
+                    self.set_cell(col, y, FrameCell { glyph, style });
                     col += 1;
                 }
             }
@@ -1095,7 +1593,7 @@ pub mod render {
                     if col >= max_col {
                         return;
                     }
-                    self.cells[abs_y * self.size.width + col] = FrameCell { glyph, style };
+                    self.set_cell(col, abs_y, FrameCell { glyph, style });
                     col += 1;
                 }
             }
@@ -1334,6 +1832,94 @@ pub mod render {
             }
         }
 
+        /// Draw a scrollbar on the right (vertical) or bottom (horizontal) edge of `rect`.
+        ///
+        /// The thumb size is proportional to `visible / total` and its position to
+        /// `offset`. Nothing is drawn when all content fits.
+        pub fn draw_scrollbar(
+            &mut self,
+            rect: Rect,
+            total: usize,
+            visible: usize,
+            offset: usize,
+            orientation: Orientation,
+        ) {
+            self.draw_scrollbar_with_track(rect, total, visible, offset, orientation, false);
+        }
+
+        /// Like [`RenderFrame::draw_scrollbar`], but draws the bare track when all
+        /// content fits if `track_when_fits` is set.
+        pub fn draw_scrollbar_with_track(
+            &mut self,
+            rect: Rect,
+            total: usize,
+            visible: usize,
+            offset: usize,
+            orientation: Orientation,
+            track_when_fits: bool,
+        ) {
+            if rect.width == 0 || rect.height == 0 {
+                return;
+            }
+            let track_len = match orientation {
+                Orientation::Vertical => rect.height,
+                Orientation::Horizontal => rect.width,
+            };
+            let fits = total <= visible;
+            if fits && !track_when_fits {
+                return;
+            }
+
+            let (thumb_start, thumb_len) = if fits {
+                (0, 0)
+            } else {
+                let thumb_len = visible
+                    .saturating_mul(track_len)
+                    .div_ceil(total)
+                    .clamp(1, track_len);
+                let max_offset = total - visible;
+                let travel = track_len - thumb_len;
+                let offset = offset.min(max_offset);
+                let start = (travel * offset + max_offset / 2) / max_offset;
+                (start, thumb_len)
+            };
+
+            let bg = TermColor::Ansi256(self.theme.color(StyleToken::Background));
+            let track_style = CellStyle {
+                fg: self.color_for_role(TextRole::Muted),
+                bg,
+                bold: false,
+                dim: false,
+                underline: false,
+            };
+            let thumb_style = CellStyle {
+                fg: self.color_for_role(TextRole::Accent),
+                bg,
+                bold: false,
+                dim: false,
+                underline: false,
+            };
+
+            for i in 0..track_len {
+                let (col, row) = match orientation {
+                    Orientation::Vertical => (rect.x + rect.width - 1, rect.y + i),
+                    Orientation::Horizontal => (rect.x + i, rect.y + rect.height - 1),
+                };
+                let cell = if i >= thumb_start && i < thumb_start + thumb_len {
+                    FrameCell {
+                        glyph: '\u{2588}', // █
+                        style: thumb_style,
+                    }
+                } else {
+                    FrameCell {
+                        glyph: '\u{2591}', // ░
+                        style: track_style,
+                    }
+                };
+                self.set_cell(col, row, cell);
+            }
+        }
+
         /// Draw a gauge/progress bar at (x, y) with given width.
         /// `ratio` is 0.0..=1.0. Uses block characters for sub-cell precision.
         pub fn draw_gauge(
@@ -1491,6 +2077,25 @@ pub mod render {
                 .collect()
         }
 
+        /// Cells that differ from `previous`, in row-major order.
+        ///
+        /// Frames of differing sizes report every cell so the backend redraws fully.
+        #[must_use]
+        pub fn diff(&self, previous: &RenderFrame) -> Vec<CellChange> {
+            let width = self.size.width;
+            let full_redraw = self.size != previous.size;
+            self.cells
+                .iter()
+                .enumerate()
+                .filter(|(idx, cell)| full_redraw || previous.cells[*idx] != **cell)
+                .map(|(idx, cell)| CellChange {
+                    x: idx % width,
+                    y: idx / width,
+                    cell: *cell,
+                })
+                .collect()
+        }
+
         /// Text-only snapshot helper for lightweight regression tests.
         #[must_use]
         pub fn snapshot(&self) -> String {
@@ -1585,6 +2190,8 @@ pub mod render {
 
 /// Stable widget primitives consumed by Forge TUI crates.
 pub mod widgets {
+    use super::render::{StyledLine, TextRole};
+
     /// Border treatment exposed by the adapter.
     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
     pub enum BorderStyle {
@@ -1796,60 +2403,340 @@ pub mod widgets {
             },
         ]
     }
-}
-
-/// Snapshot helpers for adapter-based render abstractions.
-pub mod snapshot;
 
-/// Lightweight perf measurement helpers (no CI gating; intended for local regression checks).
-pub mod perf;
+    /// Glyph drawn at the cursor position by [`TextInput::styled_line`].
+    pub const TEXT_INPUT_CURSOR: char = '\u{258f}'; // ▏
 
-/// Stable input/event abstraction shielding TUI crates from upstream key models.
-pub mod input {
-    /// Canonical key set exposed to Forge TUI crates.
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-    pub enum Key {
-        Char(char),
-        Enter,
-        Escape,
-        Tab,
-        Backspace,
-        Up,
-        Down,
-        Left,
-        Right,
+    /// Single-line text editing model for compose and search boxes.
+    ///
+    /// The cursor is a char index (not a byte offset), so multibyte text edits
+    /// correctly. Word motions treat runs of non-whitespace as words.
+    #[derive(Debug, Clone, Default, PartialEq, Eq)]
+    pub struct TextInput {
+        buffer: String,
+        cursor: usize,
     }
 
-    /// Canonical keyboard modifiers.
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-    pub struct Modifiers {
-        pub shift: bool,
-        pub ctrl: bool,
-        pub alt: bool,
-    }
+    impl TextInput {
+        #[must_use]
+        pub fn new() -> Self {
+            Self::default()
+        }
 
-    impl Modifiers {
+        /// Build an input holding `text` with the cursor at the end.
         #[must_use]
-        pub const fn none() -> Self {
-            Self {
-                shift: false,
-                ctrl: false,
-                alt: false,
-            }
+        pub fn with_text(text: impl Into<String>) -> Self {
+            let buffer = text.into();
+            let cursor = buffer.chars().count();
+            Self { buffer, cursor }
         }
-    }
 
-    /// Canonical key event.
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-    pub struct KeyEvent {
-        pub key: Key,
-        pub modifiers: Modifiers,
-    }
+        #[must_use]
+        pub fn text(&self) -> &str {
+            &self.buffer
+        }
 
-    impl KeyEvent {
+        /// Cursor position in chars.
         #[must_use]
-        pub const fn plain(key: Key) -> Self {
-            Self {
+        pub fn cursor(&self) -> usize {
+            self.cursor
+        }
+
+        #[must_use]
+        pub fn is_empty(&self) -> bool {
+            self.buffer.is_empty()
+        }
+
+        /// Number of chars in the buffer.
+        #[must_use]
+        pub fn len(&self) -> usize {
+            self.buffer.chars().count()
+        }
+
+        /// Move the cursor, clamped to the buffer.
+        pub fn set_cursor(&mut self, cursor: usize) {
+            self.cursor = cursor.min(self.len());
+        }
+
+        pub fn clear(&mut self) {
+            self.buffer.clear();
+            self.cursor = 0;
+        }
+
+        pub fn insert_char(&mut self, ch: char) {
+            let at = self.byte_offset(self.cursor);
+            self.buffer.insert(at, ch);
+            self.cursor += 1;
+        }
+
+        pub fn insert_str(&mut self, text: &str) {
+            let at = self.byte_offset(self.cursor);
+            self.buffer.insert_str(at, text);
+            self.cursor += text.chars().count();
+        }
+
+        /// Delete the char before the cursor.
+        pub fn backspace(&mut self) {
+            if self.cursor > 0 {
+                self.remove_range(self.cursor - 1, self.cursor);
+            }
+        }
+
+        /// Delete the char under the cursor.
+        pub fn delete(&mut self) {
+            if self.cursor < self.len() {
+                self.remove_range(self.cursor, self.cursor + 1);
+            }
+        }
+
+        /// Delete from the start of the previous word to the cursor.
+        pub fn delete_word_left(&mut self) {
+            let start = self.word_left_index();
+            self.remove_range(start, self.cursor);
+        }
+
+        /// Delete from the cursor to the end of the next word.
+        pub fn delete_word_right(&mut self) {
+            let end = self.word_right_index();
+            self.remove_range(self.cursor, end);
+        }
+
+        pub fn move_left(&mut self) {
+            self.cursor = self.cursor.saturating_sub(1);
+        }
+
+        pub fn move_right(&mut self) {
+            self.set_cursor(self.cursor + 1);
+        }
+
+        pub fn word_left(&mut self) {
+            self.cursor = self.word_left_index();
+        }
+
+        pub fn word_right(&mut self) {
+            self.cursor = self.word_right_index();
+        }
+
+        pub fn home(&mut self) {
+            self.cursor = 0;
+        }
+
+        pub fn end(&mut self) {
+            self.cursor = self.len();
+        }
+
+        /// Render the buffer with a [`TEXT_INPUT_CURSOR`] marker when focused.
+        #[must_use]
+        pub fn styled_line(&self, focused: bool) -> StyledLine {
+            if !focused {
+                return StyledLine::plain(self.buffer.as_str());
+            }
+            let split = self.byte_offset(self.cursor);
+            let (before, after) = self.buffer.split_at(split);
+            let mut line = StyledLine::new();
+            if !before.is_empty() {
+                line.push_role(before, TextRole::Primary);
+            }
+            line.push_role(TEXT_INPUT_CURSOR.to_string(), TextRole::Focus);
+            if !after.is_empty() {
+                line.push_role(after, TextRole::Primary);
+            }
+            line
+        }
+
+        fn byte_offset(&self, char_idx: usize) -> usize {
+            self.buffer
+                .char_indices()
+                .nth(char_idx)
+                .map_or(self.buffer.len(), |(offset, _)| offset)
+        }
+
+        fn remove_range(&mut self, start: usize, end: usize) {
+            let start_byte = self.byte_offset(start);
+            let end_byte = self.byte_offset(end);
+            self.buffer.replace_range(start_byte..end_byte, "");
+            self.cursor = start;
+        }
+
+        fn word_left_index(&self) -> usize {
+            let chars: Vec<char> = self.buffer.chars().collect();
+            let mut idx = self.cursor.min(chars.len());
+            while idx > 0 && chars[idx - 1].is_whitespace() {
+                idx -= 1;
+            }
+            while idx > 0 && !chars[idx - 1].is_whitespace() {
+                idx -= 1;
+            }
+            idx
+        }
+
+        fn word_right_index(&self) -> usize {
+            let chars: Vec<char> = self.buffer.chars().collect();
+            let mut idx = self.cursor.min(chars.len());
+            while idx < chars.len() && chars[idx].is_whitespace() {
+                idx += 1;
+            }
+            while idx < chars.len() && !chars[idx].is_whitespace() {
+                idx += 1;
+            }
+            idx
+        }
+    }
+
+    /// Ordered Tab/Shift-Tab focus traversal over focusable widget ids.
+    ///
+    /// Traversal wraps at both ends and skips disabled ids. An empty ring, or a
+    /// ring whose ids are all disabled, has no current focus.
+    #[derive(Debug, Clone, Default, PartialEq, Eq)]
+    pub struct FocusRing {
+        ids: Vec<String>,
+        disabled: std::collections::HashSet<String>,
+        current: Option<usize>,
+    }
+
+    impl FocusRing {
+        /// Build a ring focused on its first id.
+        #[must_use]
+        pub fn new<I, S>(ids: I) -> Self
+        where
+            I: IntoIterator<Item = S>,
+            S: Into<String>,
+        {
+            let ids: Vec<String> = ids.into_iter().map(Into::into).collect();
+            let current = if ids.is_empty() { None } else { Some(0) };
+            Self {
+                ids,
+                disabled: std::collections::HashSet::new(),
+                current,
+            }
+        }
+
+        /// Currently focused id.
+        #[must_use]
+        pub fn current(&self) -> Option<&str> {
+            self.current
+                .and_then(|idx| self.ids.get(idx))
+                .map(String::as_str)
+        }
+
+        /// Advance focus to the next enabled id (Tab).
+        #[allow(clippy::should_implement_trait)] // cycles forever; not an iterator
+        pub fn next(&mut self) -> Option<&str> {
+            self.step(true)
+        }
+
+        /// Move focus to the previous enabled id (Shift-Tab).
+        pub fn prev(&mut self) -> Option<&str> {
+            self.step(false)
+        }
+
+        /// Focus `id` directly. Returns false for unknown or disabled ids.
+        pub fn focus(&mut self, id: &str) -> bool {
+            if self.disabled.contains(id) {
+                return false;
+            }
+            match self.ids.iter().position(|candidate| candidate == id) {
+                Some(idx) => {
+                    self.current = Some(idx);
+                    true
+                }
+                None => false,
+            }
+        }
+
+        /// Enable or disable `id`. Disabling the focused id moves focus forward.
+        pub fn set_disabled(&mut self, id: &str, disabled: bool) {
+            if !disabled {
+                self.disabled.remove(id);
+                if self.current.is_none() {
+                    self.focus(id);
+                }
+                return;
+            }
+            self.disabled.insert(id.to_owned());
+            if self.current() == Some(id) {
+                self.step(true);
+            }
+        }
+
+        /// Whether `id` is currently disabled.
+        #[must_use]
+        pub fn is_disabled(&self, id: &str) -> bool {
+            self.disabled.contains(id)
+        }
+
+        fn step(&mut self, forward: bool) -> Option<&str> {
+            let len = self.ids.len();
+            if len == 0 {
+                return None;
+            }
+            let start = self.current.unwrap_or(if forward { len - 1 } else { 0 });
+            self.current = (1..=len)
+                .map(|offset| {
+                    if forward {
+                        (start + offset) % len
+                    } else {
+                        (start + len - offset % len) % len
+                    }
+                })
+                .find(|idx| !self.disabled.contains(&self.ids[*idx]));
+            self.current()
+        }
+    }
+}
+
+/// Snapshot helpers for adapter-based render abstractions.
+pub mod snapshot;
+
+/// Lightweight perf measurement helpers (no CI gating; intended for local regression checks).
+pub mod perf;
+
+/// Stable input/event abstraction shielding TUI crates from upstream key models.
+pub mod input {
+    /// Canonical key set exposed to Forge TUI crates.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Key {
+        Char(char),
+        Enter,
+        Escape,
+        Tab,
+        Backspace,
+        Up,
+        Down,
+        Left,
+        Right,
+    }
+
+    /// Canonical keyboard modifiers.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Modifiers {
+        pub shift: bool,
+        pub ctrl: bool,
+        pub alt: bool,
+    }
+
+    impl Modifiers {
+        #[must_use]
+        pub const fn none() -> Self {
+            Self {
+                shift: false,
+                ctrl: false,
+                alt: false,
+            }
+        }
+    }
+
+    /// Canonical key event.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct KeyEvent {
+        pub key: Key,
+        pub modifiers: Modifiers,
+    }
+
+    impl KeyEvent {
+        #[must_use]
+        pub const fn plain(key: Key) -> Self {
+            Self {
                 key,
                 modifiers: Modifiers::none(),
             }
@@ -1897,16 +2784,21 @@ pub mod input {
     }
 
     /// Stable input stream event consumed by Forge target TUI crates.
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    ///
+    /// Not `Copy`: bracketed-paste content is carried as an owned string.
+    #[derive(Debug, Clone, PartialEq, Eq)]
     pub enum InputEvent {
         Key(KeyEvent),
         Mouse(MouseEvent),
         Resize(ResizeEvent),
+        /// Bracketed-paste content delivered as one event; embedded keys must
+        /// be inserted literally, never interpreted as bindings.
+        Paste(String),
         Tick,
     }
 
     /// Stable high-level actions produced by adapter input translation.
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, PartialEq, Eq)]
     pub enum UiAction {
         Noop,
         MoveUp,
@@ -1920,6 +2812,7 @@ pub mod input {
         Compose,
         ScrollUp,
         ScrollDown,
+        Paste(String),
     }
 
     /// Translator trait allowing alternate mappings without exposing upstream APIs.
@@ -1982,6 +2875,7 @@ pub mod input {
                     kind: MouseEventKind::Wheel(MouseWheelDirection::Down),
                     ..
                 }) => UiAction::ScrollDown,
+                InputEvent::Paste(text) => UiAction::Paste(text.clone()),
                 InputEvent::Resize(_) | InputEvent::Tick => UiAction::Refresh,
                 _ => UiAction::Noop,
             }
@@ -2002,12 +2896,14 @@ mod tests {
         MouseWheelDirection, ResizeEvent, UiAction,
     };
     use super::render::{
-        FrameSize, OwnedStyledSpan, PlainSpanSource, RenderFrame, SpanSource, SpanStyle,
-        StyledLine, StyledSpan, StyledText, TermColor, TextRole,
-        LEGACY_RENDER_FRAME_API_DELETE_GATE,
+        CellChange, Constraint, FrameSize, Layout, LogSpanSource, MarkdownSpanSource, Orientation,
+        OwnedStyledSpan, PlainSpanSource, Rect, RenderFrame, SpanSource, SpanStyle, StyledLine,
+        StyledSpan, StyledText, TermColor, TextRole, LEGACY_RENDER_FRAME_API_DELETE_GATE,
     };
     use super::style::{StyleToken, ThemeKind, ThemeSpec};
-    use super::widgets::{self, Padding, TextAlign, WidgetSpec};
+    use super::widgets::{
+        self, FocusRing, Padding, TextAlign, TextInput, WidgetSpec, TEXT_INPUT_CURSOR,
+    };
     use super::{crate_label, FRANKENTUI_PIN};
 
     #[test]
@@ -2083,6 +2979,282 @@ mod tests {
         assert_eq!(LEGACY_RENDER_FRAME_API_DELETE_GATE, "forge-brp");
     }
 
+    #[test]
+    fn render_frame_clip_discards_draws_outside_active_clip() {
+        let mut frame = RenderFrame::new(
+            FrameSize {
+                width: 6,
+                height: 2,
+            },
+            ThemeSpec::default(),
+        );
+        frame.push_clip(Rect {
+            x: 1,
+            y: 0,
+            width: 3,
+            height: 1,
+        });
+        frame.draw_text(0, 0, "abcdef", TextRole::Primary);
+        frame.draw_text(0, 1, "ghijkl", TextRole::Primary);
+        frame.pop_clip();
+        assert_eq!(frame.snapshot(), " bcd  \n      ");
+
+        frame.draw_text(0, 1, "ghijkl", TextRole::Primary);
+        assert_eq!(frame.row_text(1), "ghijkl");
+    }
+
+    #[test]
+    fn render_frame_nested_clips_narrow_and_restore() {
+        let mut frame = RenderFrame::new(
+            FrameSize {
+                width: 8,
+                height: 1,
+            },
+            ThemeSpec::default(),
+        );
+        frame.push_clip(Rect {
+            x: 1,
+            y: 0,
+            width: 5,
+            height: 1,
+        });
+        frame.push_clip(Rect {
+            x: 4,
+            y: 0,
+            width: 4,
+            height: 1,
+        });
+        assert_eq!(
+            frame.clip_rect(),
+            Rect {
+                x: 4,
+                y: 0,
+                width: 2,
+                height: 1,
+            }
+        );
+        frame.draw_text(0, 0, "xxxxxxxx", TextRole::Primary);
+        assert_eq!(frame.row_text(0), "    xx  ");
+
+        frame.pop_clip();
+        frame.draw_text(0, 0, "yyyyyyyy", TextRole::Primary);
+        assert_eq!(frame.row_text(0), " yyyyy  ");
+
+        frame.push_clip(Rect {
+            x: 7,
+            y: 0,
+            width: 1,
+            height: 1,
+        });
+        assert_eq!(frame.clip_rect().width, 0);
+        frame.draw_text(0, 0, "zzzzzzzz", TextRole::Primary);
+        assert_eq!(frame.row_text(0), " yyyyy  ");
+    }
+
+    #[test]
+    fn render_frame_diff_reports_only_changed_cells() {
+        let size = FrameSize {
+            width: 4,
+            height: 2,
+        };
+        let previous = RenderFrame::new(size, ThemeSpec::default());
+        let mut next = previous.clone();
+        assert!(next.diff(&previous).is_empty());
+
+        next.draw_text(2, 1, "x", TextRole::Primary);
+        let changes = next.diff(&previous);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(
+            changes
+                .first()
+                .map(|change| (change.x, change.y, change.cell.glyph)),
+            Some((2, 1, 'x'))
+        );
+        assert_eq!(
+            changes.first().copied(),
+            next.cell(2, 1).map(|cell| CellChange { x: 2, y: 1, cell })
+        );
+    }
+
+    #[test]
+    fn render_frame_diff_with_resized_frame_is_full_redraw() {
+        let previous = RenderFrame::new(
+            FrameSize {
+                width: 3,
+                height: 1,
+            },
+            ThemeSpec::default(),
+        );
+        let next = RenderFrame::new(
+            FrameSize {
+                width: 2,
+                height: 2,
+            },
+            ThemeSpec::default(),
+        );
+        assert_eq!(next.diff(&previous).len(), 4);
+    }
+
+    fn widths(rects: &[Rect]) -> Vec<usize> {
+        rects.iter().map(|rect| rect.width).collect()
+    }
+
+    #[test]
+    fn layout_mixes_fixed_and_ratio_constraints() {
+        let area = Rect {
+            x: 2,
+            y: 1,
+            width: 21,
+            height: 5,
+        };
+        let rects = Layout::horizontal(vec![
+            Constraint::Fixed(4),
+            Constraint::Ratio(1, 2),
+            Constraint::Ratio(1, 2),
+        ])
+        .split(area);
+        assert_eq!(widths(&rects), vec![4, 8, 9]);
+        assert_eq!(
+            rects.iter().map(|rect| rect.x).collect::<Vec<_>>(),
+            vec![2, 6, 14]
+        );
+        assert!(rects.iter().all(|rect| rect.y == 1 && rect.height == 5));
+
+        let rows = Layout::vertical(vec![
+            Constraint::Ratio(1, 3),
+            Constraint::Ratio(1, 3),
+            Constraint::Ratio(1, 3),
+        ])
+        .split(area);
+        assert_eq!(
+            rows.iter()
+                .map(|rect| (rect.y, rect.height))
+                .collect::<Vec<_>>(),
+            vec![(1, 1), (2, 2), (4, 2)]
+        );
+    }
+
+    #[test]
+    fn layout_min_absorbs_leftover_after_ratios() {
+        let area = Rect {
+            x: 0,
+            y: 0,
+            width: 20,
+            height: 1,
+        };
+        let rects =
+            Layout::horizontal(vec![Constraint::Ratio(1, 4), Constraint::Min(3)]).split(area);
+        assert_eq!(widths(&rects), vec![4, 16]);
+    }
+
+    #[test]
+    fn layout_handles_under_and_over_constrained_areas() {
+        let area = Rect {
+            x: 0,
+            y: 0,
+            width: 12,
+            height: 1,
+        };
+        // Not enough room: earlier constraints win, minimums are truncated.
+        let rects = Layout::horizontal(vec![
+            Constraint::Fixed(10),
+            Constraint::Min(5),
+            Constraint::Ratio(1, 1),
+        ])
+        .split(area);
+        assert_eq!(widths(&rects), vec![10, 2, 0]);
+
+        // Space nobody claims stays empty at the end.
+        let rects =
+            Layout::horizontal(vec![Constraint::Fixed(3), Constraint::Ratio(1, 3)]).split(area);
+        assert_eq!(widths(&rects), vec![3, 3]);
+
+        assert!(Layout::horizontal(Vec::new()).split(area).is_empty());
+        assert_eq!(
+            widths(&Layout::horizontal(vec![Constraint::Ratio(1, 0)]).split(area)),
+            vec![0]
+        );
+    }
+
+    fn scrollbar_column(offset: usize, total: usize, visible: usize) -> String {
+        let mut frame = RenderFrame::new(
+            FrameSize {
+                width: 3,
+                height: 10,
+            },
+            ThemeSpec::default(),
+        );
+        frame.draw_scrollbar(
+            Rect {
+                x: 0,
+                y: 0,
+                width: 3,
+                height: 10,
+            },
+            total,
+            visible,
+            offset,
+            Orientation::Vertical,
+        );
+        (0..10)
+            .filter_map(|row| frame.cell(2, row).map(|cell| cell.glyph))
+            .collect()
+    }
+
+    #[test]
+    fn scrollbar_thumb_scales_with_offset_and_visible_ratio() {
+        assert_eq!(scrollbar_column(0, 100, 50), "█████░░░░░");
+        assert_eq!(scrollbar_column(25, 100, 50), "░░░█████░░");
+        assert_eq!(scrollbar_column(50, 100, 50), "░░░░░█████");
+        // Offsets past the end clamp to the bottom.
+        assert_eq!(scrollbar_column(500, 100, 50), "░░░░░█████");
+
+        assert_eq!(scrollbar_column(0, 1000, 10), "█░░░░░░░░░");
+        assert_eq!(scrollbar_column(495, 1000, 10), "░░░░░█░░░░");
+        assert_eq!(scrollbar_column(990, 1000, 10), "░░░░░░░░░█");
+    }
+
+    #[test]
+    fn scrollbar_skips_or_draws_track_when_content_fits() {
+        assert_eq!(scrollbar_column(0, 5, 10), "          ");
+
+        let mut frame = RenderFrame::new(
+            FrameSize {
+                width: 4,
+                height: 2,
+            },
+            ThemeSpec::default(),
+        );
+        frame.draw_scrollbar_with_track(
+            Rect {
+                x: 0,
+                y: 0,
+                width: 4,
+                height: 2,
+            },
+            3,
+            4,
+            0,
+            Orientation::Horizontal,
+            true,
+        );
+        assert_eq!(frame.snapshot(), "    \n░░░░");
+
+        frame.draw_scrollbar(
+            Rect {
+                x: 0,
+                y: 0,
+                width: 4,
+                height: 2,
+            },
+            8,
+            4,
+            4,
+            Orientation::Horizontal,
+        );
+        assert_eq!(frame.row_text(1), "░░██");
+    }
+
     #[test]
     fn render_frame_uses_role_color_tokens() {
         use super::render::TermColor;
@@ -2397,6 +3569,125 @@ mod tests {
         );
     }
 
+    #[test]
+    fn input_translation_paste_maps_to_paste_action() {
+        let pasted = "line one\nj k /\n".to_owned();
+        assert_eq!(
+            translate_input(&InputEvent::Paste(pasted.clone())),
+            UiAction::Paste(pasted)
+        );
+    }
+
+    #[test]
+    fn focus_ring_wraps_in_both_directions() {
+        let mut ring = FocusRing::new(["to", "tags", "body"]);
+        assert_eq!(ring.current(), Some("to"));
+        assert_eq!(ring.next(), Some("tags"));
+        assert_eq!(ring.next(), Some("body"));
+        assert_eq!(ring.next(), Some("to"));
+        assert_eq!(ring.prev(), Some("body"));
+        assert_eq!(ring.prev(), Some("tags"));
+        assert!(ring.focus("to"));
+        assert_eq!(ring.prev(), Some("body"));
+        assert!(!ring.focus("missing"));
+        assert_eq!(ring.current(), Some("body"));
+    }
+
+    #[test]
+    fn focus_ring_skips_disabled_ids() {
+        let mut ring = FocusRing::new(["a", "b", "c", "d"]);
+        ring.set_disabled("b", true);
+        ring.set_disabled("c", true);
+        assert_eq!(ring.next(), Some("d"));
+        assert_eq!(ring.prev(), Some("a"));
+        assert!(!ring.focus("b"));
+
+        ring.set_disabled("a", true);
+        assert_eq!(ring.current(), Some("d"));
+        ring.set_disabled("d", true);
+        assert_eq!(ring.current(), None);
+        assert_eq!(ring.next(), None);
+
+        ring.set_disabled("c", false);
+        assert_eq!(ring.current(), Some("c"));
+        assert!(!ring.is_disabled("c"));
+    }
+
+    #[test]
+    fn focus_ring_handles_empty_and_single_element_rings() {
+        let mut empty = FocusRing::new(Vec::<String>::new());
+        assert_eq!(empty.current(), None);
+        assert_eq!(empty.next(), None);
+        assert_eq!(empty.prev(), None);
+
+        let mut single = FocusRing::new(["only"]);
+        assert_eq!(single.next(), Some("only"));
+        assert_eq!(single.prev(), Some("only"));
+    }
+
+    #[test]
+    fn text_input_deletes_words_over_multibyte_text() {
+        let mut input = TextInput::with_text("héllo wörld  ünï");
+        input.delete_word_left();
+        assert_eq!(input.text(), "héllo wörld  ");
+        assert_eq!(input.cursor(), 13);
+        input.delete_word_left();
+        assert_eq!(input.text(), "héllo ");
+
+        input.home();
+        input.delete_word_right();
+        assert_eq!(input.text(), " ");
+        assert_eq!(input.cursor(), 0);
+
+        let mut input = TextInput::with_text("añb");
+        input.move_left();
+        input.backspace();
+        assert_eq!(input.text(), "ab");
+        input.insert_str("ß→");
+        assert_eq!(input.text(), "aß→b");
+        assert_eq!(input.cursor(), 3);
+        input.delete();
+        assert_eq!(input.text(), "aß→");
+    }
+
+    #[test]
+    fn text_input_clamps_cursor_at_boundaries() {
+        let mut input = TextInput::with_text("ab");
+        input.move_right();
+        input.delete();
+        assert_eq!((input.text(), input.cursor()), ("ab", 2));
+        input.set_cursor(99);
+        assert_eq!(input.cursor(), 2);
+
+        input.home();
+        input.move_left();
+        input.backspace();
+        input.word_left();
+        input.delete_word_left();
+        assert_eq!((input.text(), input.cursor()), ("ab", 0));
+
+        input.end();
+        input.word_right();
+        assert_eq!(input.cursor(), 2);
+
+        let mut empty = TextInput::new();
+        empty.backspace();
+        empty.delete();
+        empty.delete_word_right();
+        assert!(empty.is_empty());
+        assert_eq!(empty.cursor(), 0);
+    }
+
+    #[test]
+    fn text_input_renders_cursor_marker() {
+        let mut input = TextInput::with_text("héllo");
+        input.set_cursor(2);
+        let line = input.styled_line(true);
+        assert_eq!(line.plain_text(), format!("hé{TEXT_INPUT_CURSOR}llo"));
+        assert_eq!(line.spans[1].style, SpanStyle::Role(TextRole::Focus));
+        assert_eq!(input.styled_line(false).plain_text(), "héllo");
+    }
+
     #[test]
     fn fmail_widget_panel_snapshot() {
         let panels = [
@@ -2616,6 +3907,139 @@ mod tests {
         assert_eq!(line.spans[0].style, SpanStyle::Role(TextRole::Primary));
     }
 
+    #[test]
+    fn markdown_span_source_styles_inline_markers() {
+        let line = MarkdownSpanSource.style_line("run **forge up** with `--force` or _later_");
+        let spans: Vec<(&str, SpanStyle)> = line
+            .spans
+            .iter()
+            .map(|span| (span.text.as_str(), span.style))
+            .collect();
+        assert_eq!(
+            spans,
+            vec![
+                ("run ", SpanStyle::Role(TextRole::Primary)),
+                ("forge up", SpanStyle::Role(TextRole::Accent)),
+                (" with ", SpanStyle::Role(TextRole::Primary)),
+                ("--force", SpanStyle::Token(StyleToken::Warning)),
+                (" or ", SpanStyle::Role(TextRole::Primary)),
+                ("later", SpanStyle::Role(TextRole::Info)),
+            ]
+        );
+
+        let mut frame = RenderFrame::new(
+            FrameSize {
+                width: 8,
+                height: 1,
+            },
+            ThemeSpec::default(),
+        );
+        frame.draw_styled_line(0, 0, &MarkdownSpanSource.style_line("**bold**"));
+        assert_eq!(frame.row_text(0), "bold    ");
+        assert_eq!(frame.cell(0, 0).map(|cell| cell.style.bold), Some(true));
+    }
+
+    #[test]
+    fn markdown_span_source_styles_headings_bullets_and_fences() {
+        let text =
+            MarkdownSpanSource.style_text("# Title\n- item *one*\n```\nlet x = 1;\n```\ntail");
+        assert_eq!(text.line_count(), 6);
+        assert_eq!(text.lines[0].plain_text(), "Title");
+        assert_eq!(
+            text.lines[0].spans[0].style,
+            SpanStyle::Role(TextRole::Accent)
+        );
+        assert_eq!(text.lines[1].plain_text(), "\u{2022} item one");
+        assert_eq!(
+            text.lines[1].spans[0].style,
+            SpanStyle::Role(TextRole::Muted)
+        );
+        assert_eq!(
+            text.lines[2].spans[0].style,
+            SpanStyle::Role(TextRole::Muted)
+        );
+        assert_eq!(text.lines[3].plain_text(), "let x = 1;");
+        assert_eq!(text.lines[3].len(), 1);
+        assert_eq!(
+            text.lines[3].spans[0].style,
+            SpanStyle::Token(StyleToken::Muted)
+        );
+        assert_eq!(
+            text.lines[4].spans[0].style,
+            SpanStyle::Role(TextRole::Muted)
+        );
+        assert_eq!(
+            text.lines[5].spans[0].style,
+            SpanStyle::Role(TextRole::Primary)
+        );
+    }
+
+    #[test]
+    fn markdown_span_source_falls_back_to_plain_for_malformed_input() {
+        for input in [
+            "**unclosed",
+            "a `tick",
+            "snake_case_name",
+            "****",
+            "#nospace",
+        ] {
+            let line = MarkdownSpanSource.style_line(input);
+            assert_eq!(line.plain_text(), input);
+            assert!(line
+                .spans
+                .iter()
+                .all(|span| span.style == SpanStyle::Role(TextRole::Primary)));
+        }
+
+        let text = MarkdownSpanSource.style_text("```\n**x**");
+        assert_eq!(text.lines[0].plain_text(), "```");
+        assert_eq!(
+            text.lines[0].spans[0].style,
+            SpanStyle::Role(TextRole::Primary)
+        );
+        assert_eq!(
+            text.lines[1].spans[0].style,
+            SpanStyle::Role(TextRole::Accent)
+        );
+    }
+
+    #[test]
+    fn log_span_source_styles_level_tokens() {
+        let line = LogSpanSource.style_line("2026-03-01T10:00:00Z ERROR harness exited code=2");
+        let spans: Vec<(&str, SpanStyle)> = line
+            .spans
+            .iter()
+            .map(|span| (span.text.as_str(), span.style))
+            .collect();
+        assert_eq!(
+            spans,
+            vec![
+                ("2026-03-01T10:00:00Z", SpanStyle::Role(TextRole::Muted)),
+                (" ", SpanStyle::Role(TextRole::Primary)),
+                ("ERROR", SpanStyle::Role(TextRole::Danger)),
+                (" harness exited ", SpanStyle::Role(TextRole::Primary)),
+                ("code=", SpanStyle::Role(TextRole::Muted)),
+                ("2", SpanStyle::Role(TextRole::Primary)),
+            ]
+        );
+
+        let info = LogSpanSource.style_line("[INFO] loop started");
+        assert_eq!(info.spans[0].text, "[INFO]");
+        assert_eq!(info.spans[0].style, SpanStyle::Role(TextRole::Info));
+        let warn = LogSpanSource.style_line("WARN: slow");
+        assert_eq!(warn.spans[0].style, SpanStyle::Role(TextRole::Warning));
+    }
+
+    #[test]
+    fn log_span_source_leaves_unrecognized_lines_primary() {
+        for input in ["just some output", "", "  indented error text"] {
+            let line = LogSpanSource.style_line(input);
+            assert_eq!(line.len(), 1);
+            assert_eq!(line.plain_text(), input);
+            assert_eq!(line.spans[0].style, SpanStyle::Role(TextRole::Primary));
+        }
+    }
+
     #[test]
     fn plain_span_source_handles_multiline() {
         let source = PlainSpanSource;
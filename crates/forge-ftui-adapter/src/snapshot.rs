@@ -1,6 +1,6 @@
 //! Snapshot helpers for adapter-based render abstractions.
 
-use crate::render::RenderFrame;
+use crate::render::{RenderFrame, TermColor};
 
 /// Assert a stable text snapshot for a render frame.
 ///
@@ -13,3 +13,106 @@ pub fn assert_render_frame_snapshot(label: &str, frame: &RenderFrame, expected:
         "render frame snapshot mismatch ({label})\n--- expected\n{expected}\n--- got\n{got}",
     );
 }
+
+/// Cell width in SVG user units.
+pub const SVG_CELL_WIDTH: usize = 9;
+/// Cell height in SVG user units.
+pub const SVG_CELL_HEIGHT: usize = 18;
+
+/// Render a frame as a self-contained SVG for sharing dashboards.
+///
+/// Each cell becomes a background `<rect>` plus a positioned `<text>` with
+/// RGB colors (indexed colors resolve through [`TermColor::to_rgb`]). Bold
+/// maps to `font-weight`, underline to `text-decoration`, dim to `opacity`.
+#[must_use]
+pub fn to_svg(frame: &RenderFrame) -> String {
+    let size = frame.size();
+    let width = size.width * SVG_CELL_WIDTH;
+    let height = size.height * SVG_CELL_HEIGHT;
+    let mut out = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+         viewBox=\"0 0 {width} {height}\" font-family=\"monospace\" font-size=\"15\" \
+         xml:space=\"preserve\">\n"
+    );
+    for y in 0..size.height {
+        for x in 0..size.width {
+            let Some(cell) = frame.cell(x, y) else {
+                continue;
+            };
+            let px = x * SVG_CELL_WIDTH;
+            let py = y * SVG_CELL_HEIGHT;
+            out.push_str(&format!(
+                "<rect x=\"{px}\" y=\"{py}\" width=\"{SVG_CELL_WIDTH}\" height=\"{SVG_CELL_HEIGHT}\" fill=\"{}\"/>",
+                svg_color(cell.style.bg)
+            ));
+            let mut attrs = format!("fill=\"{}\"", svg_color(cell.style.fg));
+            if cell.style.bold {
+                attrs.push_str(" font-weight=\"bold\"");
+            }
+            if cell.style.underline {
+                attrs.push_str(" text-decoration=\"underline\"");
+            }
+            if cell.style.dim {
+                attrs.push_str(" opacity=\"0.6\"");
+            }
+            out.push_str(&format!(
+                "<text x=\"{px}\" y=\"{}\" {attrs}>{}</text>\n",
+                py + SVG_CELL_HEIGHT - 4,
+                svg_escape(cell.glyph)
+            ));
+        }
+    }
+    out.push_str("</svg>\n");
+    out
+}
+
+fn svg_color(color: TermColor) -> String {
+    let (r, g, b) = color.to_rgb();
+    format!("#{r:02x}{g:02x}{b:02x}")
+}
+
+fn svg_escape(glyph: char) -> String {
+    match glyph {
+        '&' => "&amp;".to_owned(),
+        '<' => "&lt;".to_owned(),
+        '>' => "&gt;".to_owned(),
+        '"' => "&quot;".to_owned(),
+        '\'' => "&apos;".to_owned(),
+        _ => glyph.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::to_svg;
+    use crate::render::{FrameSize, RenderFrame, TermColor};
+    use crate::style::ThemeSpec;
+
+    #[test]
+    fn svg_export_emits_one_text_per_cell_with_colors_and_attrs() {
+        let mut frame = RenderFrame::new(
+            FrameSize {
+                width: 4,
+                height: 2,
+            },
+            ThemeSpec::default(),
+        );
+        frame.draw_styled_text(
+            0,
+            0,
+            "A<",
+            TermColor::Rgb(255, 0, 0),
+            TermColor::Ansi256(16),
+            true,
+        );
+
+        let svg = to_svg(&frame);
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"36\""));
+        assert_eq!(svg.matches("<text ").count(), 8);
+        assert_eq!(svg.matches("<rect ").count(), 8);
+        assert!(svg.contains("fill=\"#ff0000\" font-weight=\"bold\">A</text>"));
+        assert!(svg.contains(">&lt;</text>"));
+        assert!(svg.contains("fill=\"#000000\"/>"));
+        assert!(svg.trim_end().ends_with("</svg>"));
+    }
+}
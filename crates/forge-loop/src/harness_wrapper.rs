@@ -1,4 +1,17 @@
 use std::collections::BTreeMap;
+use std::io::{self, Read, Write};
+use std::path::PathBuf;
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use wait_timeout::ChildExt;
+
+use crate::iteration_result::{build_persisted_run_update, PersistedRunUpdate};
+use crate::log_tail::{TailWriter, DEFAULT_OUTPUT_TAIL_LINES};
+
+pub const DEFAULT_STDERR_TAIL_BYTES: usize = 8 * 1024;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum HarnessKind {
@@ -110,10 +123,344 @@ fn build_env(
     env
 }
 
+/// How a harness process ended, with a bounded tail of its stderr.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HarnessOutcome {
+    CleanExit {
+        stderr_tail: String,
+    },
+    NonZeroExit {
+        exit_code: i32,
+        stderr_tail: String,
+    },
+    Signaled {
+        signal: i32,
+        stderr_tail: String,
+    },
+    TimedOut {
+        timeout: Duration,
+        stderr_tail: String,
+    },
+}
+
+impl HarnessOutcome {
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Self::CleanExit { .. } => 0,
+            Self::NonZeroExit { exit_code, .. } => *exit_code,
+            Self::Signaled { .. } | Self::TimedOut { .. } => -1,
+        }
+    }
+
+    pub fn signal(&self) -> Option<i32> {
+        match self {
+            Self::Signaled { signal, .. } => Some(*signal),
+            _ => None,
+        }
+    }
+
+    pub fn stderr_tail(&self) -> &str {
+        match self {
+            Self::CleanExit { stderr_tail }
+            | Self::NonZeroExit { stderr_tail, .. }
+            | Self::Signaled { stderr_tail, .. }
+            | Self::TimedOut { stderr_tail, .. } => stderr_tail,
+        }
+    }
+
+    pub fn is_clean(&self) -> bool {
+        matches!(self, Self::CleanExit { .. })
+    }
+
+    pub fn is_nonzero_exit(&self) -> bool {
+        matches!(self, Self::NonZeroExit { .. })
+    }
+
+    pub fn error_message(&self) -> Option<String> {
+        match self {
+            Self::CleanExit { .. } => None,
+            Self::NonZeroExit { exit_code, .. } => {
+                Some(format!("harness exited with code {exit_code}"))
+            }
+            Self::Signaled { signal, .. } => Some(format!("harness killed by signal {signal}")),
+            Self::TimedOut { timeout, .. } => {
+                Some(format!("harness timed out after {}ms", timeout.as_millis()))
+            }
+        }
+    }
+
+    /// Build the `loop_run` update for this outcome, falling back to the
+    /// stderr tail when the harness produced no stdout.
+    pub fn persisted_run_update(&self, output_tail: &str) -> PersistedRunUpdate {
+        let err = self.error_message();
+        build_persisted_run_update(
+            self.exit_code(),
+            output_tail,
+            self.stderr_tail(),
+            err.as_deref(),
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HarnessRunOptions {
+    pub workdir: Option<PathBuf>,
+    /// Run the command under a login shell (`bash -lc`).
+    pub login_shell: bool,
+    pub timeout: Option<Duration>,
+    pub stderr_tail_bytes: usize,
+    pub output_tail_lines: usize,
+}
+
+impl Default for HarnessRunOptions {
+    fn default() -> Self {
+        Self {
+            workdir: None,
+            login_shell: false,
+            timeout: None,
+            stderr_tail_bytes: DEFAULT_STDERR_TAIL_BYTES,
+            output_tail_lines: DEFAULT_OUTPUT_TAIL_LINES,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HarnessRun {
+    pub outcome: HarnessOutcome,
+    pub output_tail: String,
+}
+
+/// Run an execution plan under `bash -c` and classify how it ended.
+pub fn run_harness(plan: &ExecutionPlan, options: &HarnessRunOptions) -> io::Result<HarnessRun> {
+    run_harness_streaming(plan, options, |_| {})
+}
+
+/// Like [`run_harness`], handing every stdout and stderr chunk to
+/// `on_output` as it arrives.
+pub fn run_harness_streaming(
+    plan: &ExecutionPlan,
+    options: &HarnessRunOptions,
+    mut on_output: impl FnMut(&[u8]),
+) -> io::Result<HarnessRun> {
+    let mut command = Command::new("bash");
+    command
+        .arg(if options.login_shell { "-lc" } else { "-c" })
+        .arg(&plan.command);
+    if let Some(workdir) = &options.workdir {
+        command.current_dir(workdir);
+    }
+    command.env_clear();
+    for pair in &plan.env {
+        if let Some((key, value)) = pair.split_once('=') {
+            command.env(key, value);
+        }
+    }
+    command.stdout(Stdio::piped());
+    command.stderr(Stdio::piped());
+    command.stdin(if plan.stdin.is_some() {
+        Stdio::piped()
+    } else {
+        Stdio::null()
+    });
+
+    // Run the harness in its own process group so a timeout can take down
+    // anything it spawned; grandchildren would otherwise keep the pipes open.
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        command.process_group(0);
+    }
+
+    let mut child = command.spawn()?;
+
+    // Start draining output before feeding stdin so a large prompt cannot
+    // deadlock against a harness that writes before it finishes reading.
+    let (tx, rx) = mpsc::channel::<(OutputStream, Vec<u8>)>();
+    if let Some(stdout) = child.stdout.take() {
+        let tx = tx.clone();
+        thread::spawn(move || forward_chunks(stdout, OutputStream::Stdout, tx));
+    }
+    if let Some(stderr) = child.stderr.take() {
+        let tx = tx.clone();
+        thread::spawn(move || forward_chunks(stderr, OutputStream::Stderr, tx));
+    }
+    drop(tx);
+
+    let stdin_writer = match (&plan.stdin, child.stdin.take()) {
+        (Some(payload), Some(mut stdin)) => {
+            let payload = payload.clone();
+            Some(thread::spawn(move || {
+                // A harness that exits without reading stdin is classified below.
+                let _ = stdin.write_all(payload.as_bytes());
+            }))
+        }
+        _ => None,
+    };
+
+    let timeout = options.timeout.filter(|timeout| !timeout.is_zero());
+    let deadline = timeout.map(|timeout| Instant::now() + timeout);
+    let mut output_tail = TailWriter::new(options.output_tail_lines);
+    let mut stderr_tail = BoundedTail::new(options.stderr_tail_bytes);
+    let mut timed_out = false;
+    loop {
+        let received = match deadline {
+            Some(deadline) if !timed_out => {
+                match rx.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+                    Ok(message) => Some(message),
+                    Err(RecvTimeoutError::Timeout) => {
+                        kill_process_group(&mut child);
+                        timed_out = true;
+                        continue;
+                    }
+                    Err(RecvTimeoutError::Disconnected) => None,
+                }
+            }
+            _ => rx.recv().ok(),
+        };
+        let Some((stream, chunk)) = received else {
+            break;
+        };
+        on_output(&chunk);
+        match stream {
+            OutputStream::Stdout => {
+                output_tail.write(&chunk);
+            }
+            OutputStream::Stderr => stderr_tail.push(&chunk),
+        }
+    }
+
+    let status = match deadline {
+        Some(_) if timed_out => {
+            child.wait()?;
+            None
+        }
+        Some(deadline) => {
+            match child.wait_timeout(deadline.saturating_duration_since(Instant::now()))? {
+                Some(status) => Some(status),
+                None => {
+                    kill_process_group(&mut child);
+                    child.wait()?;
+                    None
+                }
+            }
+        }
+        None => Some(child.wait()?),
+    };
+
+    if let Some(writer) = stdin_writer {
+        let _ = writer.join();
+    }
+    let stderr_tail = stderr_tail.into_string();
+    let outcome = match status {
+        Some(status) => classify_exit_status(status, stderr_tail),
+        None => HarnessOutcome::TimedOut {
+            timeout: timeout.unwrap_or_default(),
+            stderr_tail,
+        },
+    };
+    Ok(HarnessRun {
+        outcome,
+        output_tail: output_tail.as_string(),
+    })
+}
+
+#[derive(Debug, Clone, Copy)]
+enum OutputStream {
+    Stdout,
+    Stderr,
+}
+
+fn forward_chunks<T: Read>(mut pipe: T, stream: OutputStream, tx: Sender<(OutputStream, Vec<u8>)>) {
+    let mut chunk = [0_u8; 4096];
+    while let Ok(read) = pipe.read(&mut chunk) {
+        if read == 0 || tx.send((stream, chunk[..read].to_vec())).is_err() {
+            break;
+        }
+    }
+}
+
+#[cfg(unix)]
+fn kill_process_group(child: &mut Child) {
+    use nix::sys::signal::{killpg, Signal};
+    use nix::unistd::Pid;
+
+    let Ok(pgid) = i32::try_from(child.id()) else {
+        let _ = child.kill();
+        return;
+    };
+    if killpg(Pid::from_raw(pgid), Signal::SIGKILL).is_err() {
+        let _ = child.kill();
+    }
+}
+
+#[cfg(not(unix))]
+fn kill_process_group(child: &mut Child) {
+    let _ = child.kill();
+}
+
+fn classify_exit_status(status: ExitStatus, stderr_tail: String) -> HarnessOutcome {
+    if let Some(code) = status.code() {
+        if code == 0 {
+            return HarnessOutcome::CleanExit { stderr_tail };
+        }
+        return HarnessOutcome::NonZeroExit {
+            exit_code: code,
+            stderr_tail,
+        };
+    }
+    HarnessOutcome::Signaled {
+        signal: exit_signal(status).unwrap_or(-1),
+        stderr_tail,
+    }
+}
+
+#[cfg(unix)]
+fn exit_signal(status: ExitStatus) -> Option<i32> {
+    use std::os::unix::process::ExitStatusExt;
+    status.signal()
+}
+
+#[cfg(not(unix))]
+fn exit_signal(_status: ExitStatus) -> Option<i32> {
+    None
+}
+
+/// Keeps the last `max_bytes` bytes written to it.
+struct BoundedTail {
+    max_bytes: usize,
+    bytes: Vec<u8>,
+}
+
+impl BoundedTail {
+    fn new(max_bytes: usize) -> Self {
+        Self {
+            max_bytes,
+            bytes: Vec::new(),
+        }
+    }
+
+    fn push(&mut self, chunk: &[u8]) {
+        self.bytes.extend_from_slice(chunk);
+        if self.bytes.len() > self.max_bytes {
+            let excess = self.bytes.len() - self.max_bytes;
+            self.bytes.drain(..excess);
+        }
+    }
+
+    fn into_string(self) -> String {
+        String::from_utf8_lossy(&self.bytes).into_owned()
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{build_execution_plan, HarnessKind, ProfileSpec, PromptMode};
+    use super::{
+        build_execution_plan, run_harness, run_harness_streaming, ExecutionPlan, HarnessKind,
+        HarnessOutcome, HarnessRun, HarnessRunOptions, ProfileSpec, PromptMode,
+    };
+    use crate::iteration_result::LoopRunStatus;
     use std::collections::BTreeMap;
+    use std::time::{Duration, Instant};
 
     #[test]
     fn env_mode_sets_prompt_content_env() {
@@ -258,4 +605,122 @@ mod tests {
             Some("FORGE_PROMPT_CONTENT=override")
         );
     }
+
+    #[test]
+    fn run_harness_classifies_clean_exit() {
+        let run = run_fake("echo done; echo note >&2", HarnessRunOptions::default());
+        assert_eq!(
+            run.outcome,
+            HarnessOutcome::CleanExit {
+                stderr_tail: "note\n".to_string()
+            }
+        );
+        assert_eq!(run.output_tail, "done");
+        let update = run.outcome.persisted_run_update(&run.output_tail);
+        assert_eq!(update.status, LoopRunStatus::Success);
+        assert_eq!(update.exit_code, 0);
+    }
+
+    #[test]
+    fn run_harness_classifies_nonzero_exit() {
+        let run = run_fake("echo boom >&2; exit 3", HarnessRunOptions::default());
+        assert_eq!(
+            run.outcome,
+            HarnessOutcome::NonZeroExit {
+                exit_code: 3,
+                stderr_tail: "boom\n".to_string()
+            }
+        );
+        let update = run.outcome.persisted_run_update(&run.output_tail);
+        assert_eq!(update.status, LoopRunStatus::Error);
+        assert_eq!(update.exit_code, 3);
+        assert_eq!(update.output_tail, "boom\n");
+        assert_eq!(update.last_error, "harness exited with code 3");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn run_harness_classifies_signal() {
+        let run = run_fake("kill -9 $$", HarnessRunOptions::default());
+        assert_eq!(run.outcome.signal(), Some(9));
+        assert_eq!(run.outcome.exit_code(), -1);
+    }
+
+    #[test]
+    fn run_harness_classifies_timeout() {
+        let options = HarnessRunOptions {
+            timeout: Some(Duration::from_millis(100)),
+            ..HarnessRunOptions::default()
+        };
+        let started = Instant::now();
+        // `sleep` runs as a grandchild holding the output pipes open.
+        let run = run_fake("echo waiting >&2; sleep 5; echo late", options);
+        assert!(started.elapsed() < Duration::from_secs(4));
+        assert_eq!(
+            run.outcome,
+            HarnessOutcome::TimedOut {
+                timeout: Duration::from_millis(100),
+                stderr_tail: "waiting\n".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn run_harness_streams_large_stdin_while_draining_output() {
+        let payload = "x".repeat(1024 * 1024);
+        let plan = ExecutionPlan {
+            command: "cat; echo done".to_string(),
+            env: vec!["PATH=/usr/bin:/bin".to_string()],
+            stdin: Some(payload),
+        };
+        let run = match run_harness(&plan, &HarnessRunOptions::default()) {
+            Ok(value) => value,
+            Err(err) => panic!("run harness failed: {err}"),
+        };
+        assert_eq!(run.outcome.exit_code(), 0);
+        assert!(run.output_tail.ends_with("done"));
+    }
+
+    #[test]
+    fn run_harness_bounds_stderr_tail() {
+        let options = HarnessRunOptions {
+            stderr_tail_bytes: 8,
+            ..HarnessRunOptions::default()
+        };
+        let run = run_fake("printf 'aaaaaaaaaaaa-tail-end' >&2; exit 1", options);
+        assert_eq!(run.outcome.stderr_tail(), "tail-end");
+    }
+
+    #[test]
+    fn run_harness_streaming_forwards_stdout_and_stderr() {
+        let plan = ExecutionPlan {
+            command: "echo out; echo err >&2".to_string(),
+            env: vec!["PATH=/usr/bin:/bin".to_string()],
+            stdin: None,
+        };
+        let mut streamed = Vec::new();
+        let run = match run_harness_streaming(&plan, &HarnessRunOptions::default(), |chunk| {
+            streamed.extend_from_slice(chunk)
+        }) {
+            Ok(value) => value,
+            Err(err) => panic!("run harness failed: {err}"),
+        };
+        let streamed = String::from_utf8_lossy(&streamed);
+        assert!(streamed.contains("out\n"), "streamed: {streamed}");
+        assert!(streamed.contains("err\n"), "streamed: {streamed}");
+        assert_eq!(run.output_tail, "out");
+        assert_eq!(run.outcome.stderr_tail(), "err\n");
+    }
+
+    fn run_fake(command: &str, options: HarnessRunOptions) -> HarnessRun {
+        let plan = ExecutionPlan {
+            command: command.to_string(),
+            env: vec!["PATH=/usr/bin:/bin".to_string()],
+            stdin: None,
+        };
+        match run_harness(&plan, &options) {
+            Ok(value) => value,
+            Err(err) => panic!("run harness failed: {err}"),
+        }
+    }
 }
@@ -1,8 +1,10 @@
 use std::fs::{File, OpenOptions};
-use std::io::{self, BufRead, BufReader, Write};
-use std::path::Path;
+use std::io::{self, BufRead, BufReader, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 pub const DEFAULT_OUTPUT_TAIL_LINES: usize = 60;
+pub const DEFAULT_TAIL_POLL_INTERVAL: Duration = Duration::from_millis(250);
 
 pub struct LoopLogger {
     file: File,
@@ -99,6 +101,168 @@ pub fn tail_file(path: &Path, max_lines: usize) -> io::Result<String> {
     Ok(lines.join("\n"))
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TailOptions {
+    pub poll_interval: Duration,
+    pub follow_rotations: bool,
+}
+
+impl Default for TailOptions {
+    fn default() -> Self {
+        Self {
+            poll_interval: DEFAULT_TAIL_POLL_INTERVAL,
+            follow_rotations: true,
+        }
+    }
+}
+
+/// Incremental reader for a log file that may be rotated underneath it.
+///
+/// Rotation is detected when the path resolves to a different inode or the
+/// file shrinks below the current read offset; the follower then drains the
+/// old handle and reopens the path from the start.
+pub struct LogFollower {
+    path: PathBuf,
+    options: TailOptions,
+    file: Option<File>,
+    identity: Option<FileIdentity>,
+    offset: u64,
+    buffer: String,
+    rotations: u64,
+}
+
+impl LogFollower {
+    /// Open `path` positioned at its current end, like `tail -f`.
+    pub fn open(path: &Path, options: TailOptions) -> io::Result<Self> {
+        let mut follower = Self::open_at_start(path, options)?;
+        if let Some(file) = follower.file.as_mut() {
+            follower.offset = file.seek(SeekFrom::End(0))?;
+        }
+        Ok(follower)
+    }
+
+    /// Open `path` positioned at its start so existing content is emitted.
+    pub fn open_at_start(path: &Path, options: TailOptions) -> io::Result<Self> {
+        let file = File::open(path)?;
+        let identity = file_identity(&file.metadata()?);
+        Ok(Self {
+            path: path.to_path_buf(),
+            options,
+            file: Some(file),
+            identity: Some(identity),
+            offset: 0,
+            buffer: String::new(),
+            rotations: 0,
+        })
+    }
+
+    pub fn options(&self) -> TailOptions {
+        self.options
+    }
+
+    /// Number of times the path has been reopened after rotation or truncation.
+    pub fn rotations(&self) -> u64 {
+        self.rotations
+    }
+
+    /// Read any newly completed lines, reopening the path on rotation.
+    pub fn poll_lines(&mut self) -> io::Result<Vec<String>> {
+        let mut lines = Vec::new();
+        self.drain_current(&mut lines)?;
+
+        if !self.options.follow_rotations {
+            return Ok(lines);
+        }
+
+        let metadata = match std::fs::metadata(&self.path) {
+            Ok(value) => value,
+            // Between rename and recreate the path may briefly not exist.
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(lines),
+            Err(err) => return Err(err),
+        };
+        let rotated = match self.identity {
+            Some(identity) => identity != file_identity(&metadata) || metadata.len() < self.offset,
+            None => true,
+        };
+        if rotated {
+            self.flush_partial(&mut lines);
+            let file = File::open(&self.path)?;
+            self.identity = Some(file_identity(&file.metadata()?));
+            self.file = Some(file);
+            self.offset = 0;
+            self.rotations += 1;
+            self.drain_current(&mut lines)?;
+        }
+        Ok(lines)
+    }
+
+    /// Poll until `on_line` returns `false`, sleeping `poll_interval` between
+    /// polls that yield nothing.
+    pub fn follow<F>(&mut self, mut on_line: F) -> io::Result<()>
+    where
+        F: FnMut(&str) -> bool,
+    {
+        loop {
+            let lines = self.poll_lines()?;
+            let idle = lines.is_empty();
+            for line in &lines {
+                if !on_line(line) {
+                    return Ok(());
+                }
+            }
+            if idle {
+                std::thread::sleep(self.options.poll_interval);
+            }
+        }
+    }
+
+    fn drain_current(&mut self, lines: &mut Vec<String>) -> io::Result<()> {
+        let Some(file) = self.file.as_mut() else {
+            return Ok(());
+        };
+        let mut chunk = Vec::new();
+        let read = file.read_to_end(&mut chunk)?;
+        if read == 0 {
+            return Ok(());
+        }
+        self.offset += read as u64;
+        self.buffer.push_str(&String::from_utf8_lossy(&chunk));
+        while let Some(idx) = self.buffer.find('\n') {
+            let line = self.buffer[..idx].trim_end_matches('\r').to_string();
+            self.buffer.drain(..=idx);
+            lines.push(line);
+        }
+        Ok(())
+    }
+
+    fn flush_partial(&mut self, lines: &mut Vec<String>) {
+        if !self.buffer.is_empty() {
+            lines.push(std::mem::take(&mut self.buffer));
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct FileIdentity {
+    dev: u64,
+    ino: u64,
+}
+
+#[cfg(unix)]
+fn file_identity(metadata: &std::fs::Metadata) -> FileIdentity {
+    use std::os::unix::fs::MetadataExt;
+    FileIdentity {
+        dev: metadata.dev(),
+        ino: metadata.ino(),
+    }
+}
+
+#[cfg(not(unix))]
+fn file_identity(_metadata: &std::fs::Metadata) -> FileIdentity {
+    // Without inode numbers only size-shrink detection applies.
+    FileIdentity { dev: 0, ino: 0 }
+}
+
 fn now_rfc3339_utc() -> String {
     use std::time::{SystemTime, UNIX_EPOCH};
     let now = match SystemTime::now().duration_since(UNIX_EPOCH) {
@@ -111,7 +275,9 @@ fn now_rfc3339_utc() -> String {
 
 #[cfg(test)]
 mod tests {
-    use super::{tail_file, LoopLogger, TailWriter, DEFAULT_OUTPUT_TAIL_LINES};
+    use super::{
+        tail_file, LogFollower, LoopLogger, TailOptions, TailWriter, DEFAULT_OUTPUT_TAIL_LINES,
+    };
     use std::fs;
     use std::path::PathBuf;
     use std::time::{SystemTime, UNIX_EPOCH};
@@ -197,6 +363,102 @@ mod tests {
         assert!(out.is_empty());
     }
 
+    #[test]
+    fn log_follower_survives_rename_rotation() {
+        let path = temp_path("follow-rotate");
+        let rotated = path.with_extension("log.1");
+        if let Err(err) = fs::write(path.as_path(), "a1\na2\n") {
+            panic!("write fixture failed: {err}");
+        }
+        let mut follower = match LogFollower::open_at_start(path.as_path(), TailOptions::default())
+        {
+            Ok(value) => value,
+            Err(err) => panic!("open follower failed: {err}"),
+        };
+        let mut seen = poll(&mut follower);
+
+        append(path.as_path(), "a3\n");
+        if let Err(err) = fs::rename(path.as_path(), rotated.as_path()) {
+            panic!("rotate failed: {err}");
+        }
+        append(rotated.as_path(), "a4\n");
+        if let Err(err) = fs::write(path.as_path(), "b1\n") {
+            panic!("write rotated fixture failed: {err}");
+        }
+        seen.extend(poll(&mut follower));
+        append(path.as_path(), "b2\n");
+        seen.extend(poll(&mut follower));
+
+        assert_eq!(seen, vec!["a1", "a2", "a3", "a4", "b1", "b2"]);
+        assert_eq!(follower.rotations(), 1);
+        let _ = fs::remove_file(rotated);
+        let _ = fs::remove_file(path);
+    }
+
+    #[test]
+    fn log_follower_reopens_after_truncation() {
+        let path = temp_path("follow-truncate");
+        if let Err(err) = fs::write(path.as_path(), "old-1\nold-2\n") {
+            panic!("write fixture failed: {err}");
+        }
+        let mut follower = match LogFollower::open(path.as_path(), TailOptions::default()) {
+            Ok(value) => value,
+            Err(err) => panic!("open follower failed: {err}"),
+        };
+        assert!(poll(&mut follower).is_empty());
+
+        if let Err(err) = fs::write(path.as_path(), "new\n") {
+            panic!("truncate failed: {err}");
+        }
+        assert_eq!(poll(&mut follower), vec!["new"]);
+        let _ = fs::remove_file(path);
+    }
+
+    #[test]
+    fn log_follower_without_rotation_keeps_stale_handle() {
+        let path = temp_path("follow-stale");
+        let rotated = path.with_extension("log.1");
+        if let Err(err) = fs::write(path.as_path(), "a1\n") {
+            panic!("write fixture failed: {err}");
+        }
+        let options = TailOptions {
+            follow_rotations: false,
+            ..TailOptions::default()
+        };
+        let mut follower = match LogFollower::open_at_start(path.as_path(), options) {
+            Ok(value) => value,
+            Err(err) => panic!("open follower failed: {err}"),
+        };
+        assert_eq!(poll(&mut follower), vec!["a1"]);
+        if let Err(err) = fs::rename(path.as_path(), rotated.as_path()) {
+            panic!("rotate failed: {err}");
+        }
+        if let Err(err) = fs::write(path.as_path(), "b1\n") {
+            panic!("write rotated fixture failed: {err}");
+        }
+        assert!(poll(&mut follower).is_empty());
+        let _ = fs::remove_file(rotated);
+        let _ = fs::remove_file(path);
+    }
+
+    fn poll(follower: &mut LogFollower) -> Vec<String> {
+        match follower.poll_lines() {
+            Ok(value) => value,
+            Err(err) => panic!("poll failed: {err}"),
+        }
+    }
+
+    fn append(path: &std::path::Path, text: &str) {
+        use std::io::Write;
+        let mut file = match fs::OpenOptions::new().append(true).open(path) {
+            Ok(value) => value,
+            Err(err) => panic!("open append failed: {err}"),
+        };
+        if let Err(err) = file.write_all(text.as_bytes()) {
+            panic!("append failed: {err}");
+        }
+    }
+
     fn temp_path(prefix: &str) -> PathBuf {
         let mut path = std::env::temp_dir();
         let nonce = match SystemTime::now().duration_since(UNIX_EPOCH) {
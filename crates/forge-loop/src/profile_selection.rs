@@ -3,6 +3,8 @@ use std::collections::BTreeMap;
 pub const ERR_PROFILE_UNAVAILABLE: &str = "profile unavailable";
 pub const ERR_POOL_UNAVAILABLE: &str = "pool unavailable";
 pub const DEFAULT_WAIT_INTERVAL_SECONDS: i64 = 5;
+/// Pool metadata key holding the weighted round-robin state between selections.
+pub const WEIGHTED_STATE_METADATA_KEY: &str = "wrr_state";
 
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct LoopSpec {
@@ -37,6 +39,8 @@ pub struct Pool {
 pub struct PoolMember {
     pub profile_id: String,
     pub position: i32,
+    /// Relative share of selections; 0 keeps the member in the pool but never picks it.
+    pub weight: i32,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -45,13 +49,17 @@ pub struct SelectionResult {
     pub wait_until_epoch: Option<i64>,
 }
 
-pub trait SelectionBackend {
+/// Running-loop counts per profile; all [`AccountSelector`] needs from storage.
+pub trait RunningCounts {
+    fn count_running_by_profile(&self, profile_id: &str) -> Result<i32, String>;
+}
+
+pub trait SelectionBackend: RunningCounts {
     fn get_profile(&self, profile_id: &str) -> Result<Profile, String>;
     fn get_pool(&self, pool_id: &str) -> Result<Pool, String>;
     fn get_pool_by_name(&self, name: &str) -> Result<Pool, String>;
     fn get_default_pool(&self) -> Result<Pool, String>;
     fn list_pool_members(&self, pool_id: &str) -> Result<Vec<PoolMember>, String>;
-    fn count_running_by_profile(&self, profile_id: &str) -> Result<i32, String>;
     fn update_pool(&mut self, pool: &Pool) -> Result<(), String>;
 }
 
@@ -132,10 +140,6 @@ impl SelectionBackend for InMemorySelectionBackend {
             .unwrap_or_default())
     }
 
-    fn count_running_by_profile(&self, profile_id: &str) -> Result<i32, String> {
-        Ok(*self.running_by_profile.get(profile_id).unwrap_or(&0))
-    }
-
     fn update_pool(&mut self, pool: &Pool) -> Result<(), String> {
         self.pools_by_name
             .insert(pool.name.clone(), pool.id.clone());
@@ -144,6 +148,12 @@ impl SelectionBackend for InMemorySelectionBackend {
     }
 }
 
+impl RunningCounts for InMemorySelectionBackend {
+    fn count_running_by_profile(&self, profile_id: &str) -> Result<i32, String> {
+        Ok(*self.running_by_profile.get(profile_id).unwrap_or(&0))
+    }
+}
+
 pub fn select_profile(
     backend: &mut dyn SelectionBackend,
     loop_spec: &LoopSpec,
@@ -168,45 +178,304 @@ pub fn select_profile(
         return Err(ERR_POOL_UNAVAILABLE.to_string());
     }
 
-    let start_index = pool_last_index(&pool);
-    let mut earliest_wait: Option<i64> = None;
+    if members.iter().any(|member| member.weight != 1) {
+        return select_weighted_pool_member(backend, &mut pool, &members, now_epoch);
+    }
 
+    // Walk the members round-robin from the one after the last pick and take
+    // the first account that is neither cooling down nor at capacity.
+    let start_index = pool_last_index(&pool);
+    let mut member_indexes = Vec::with_capacity(members.len());
+    let mut candidates = Vec::with_capacity(members.len());
     for i in 0..members.len() {
         let idx = (start_index + 1 + i as i32).rem_euclid(members.len() as i32);
-        let member = &members[idx as usize];
-        let profile = match backend.get_profile(&member.profile_id) {
-            Ok(value) => value,
-            Err(_) => continue,
-        };
-        let (available, next_wait, _) = match profile_available(backend, &profile, now_epoch) {
-            Ok(value) => value,
-            Err(_) => continue,
+        let Ok(profile) = backend.get_profile(&members[idx as usize].profile_id) else {
+            continue;
         };
-        if available {
-            set_pool_last_index(&mut pool, idx);
-            let _ = backend.update_pool(&pool);
-            return Ok(SelectionResult {
-                selected_profile: Some(profile),
+        member_indexes.push(idx);
+        candidates.push(profile);
+    }
+
+    match AccountSelector::new(&*backend).select(&candidates, now_epoch) {
+        Ok(selection) => {
+            set_pool_last_index(&mut pool, member_indexes[selection.candidate_index]);
+            backend.update_pool(&pool)?;
+            Ok(SelectionResult {
+                selected_profile: Some(selection.profile),
                 wait_until_epoch: None,
-            });
+            })
         }
-        if let Some(next) = next_wait {
-            earliest_wait = Some(match earliest_wait {
-                Some(existing) if existing <= next => existing,
-                _ => next,
-            });
+        Err(none) => Ok(SelectionResult {
+            selected_profile: None,
+            wait_until_epoch: Some(none.wait_until_epoch),
+        }),
+    }
+}
+
+fn select_weighted_pool_member(
+    backend: &mut dyn SelectionBackend,
+    pool: &mut Pool,
+    members: &[PoolMember],
+    now_epoch: i64,
+) -> Result<SelectionResult, String> {
+    let mut candidates = Vec::new();
+    let mut available = Vec::new();
+    let mut earliest_wait: Option<i64> = None;
+    for member in members {
+        let Ok(profile) = backend.get_profile(&member.profile_id) else {
+            continue;
+        };
+        let (is_available, next_wait) = match profile_available(backend, &profile, now_epoch) {
+            Ok((is_available, next_wait, _)) => (is_available, next_wait),
+            Err(_) => (false, None),
+        };
+        if member.weight > 0 {
+            if let Some(next) = next_wait {
+                earliest_wait = Some(earliest_wait.map_or(next, |existing| existing.min(next)));
+            }
         }
+        available.push(is_available);
+        candidates.push(WeightedCandidate {
+            profile,
+            weight: member.weight.max(0) as u32,
+        });
     }
 
-    let wait_until = earliest_wait.unwrap_or(now_epoch + DEFAULT_WAIT_INTERVAL_SECONDS);
+    let mut rotation = match pool.metadata.get(WEIGHTED_STATE_METADATA_KEY) {
+        Some(MetaValue::Text(encoded)) => WeightedRoundRobin::decode(encoded),
+        _ => WeightedRoundRobin::default(),
+    };
+    let Some(idx) = rotation.next(&candidates, |idx| {
+        available.get(idx).copied().unwrap_or(false)
+    }) else {
+        return Ok(SelectionResult {
+            selected_profile: None,
+            wait_until_epoch: Some(
+                earliest_wait.unwrap_or(now_epoch + DEFAULT_WAIT_INTERVAL_SECONDS),
+            ),
+        });
+    };
+
+    pool.metadata.insert(
+        WEIGHTED_STATE_METADATA_KEY.to_string(),
+        MetaValue::Text(rotation.encode()),
+    );
+    backend.update_pool(pool)?;
     Ok(SelectionResult {
-        selected_profile: None,
-        wait_until_epoch: Some(wait_until),
+        selected_profile: candidates
+            .get(idx)
+            .map(|candidate| candidate.profile.clone()),
+        wait_until_epoch: None,
     })
 }
 
+/// A pool account together with its selection weight.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WeightedCandidate {
+    pub profile: Profile,
+    pub weight: u32,
+}
+
+/// Deterministic smooth weighted round-robin over pool accounts.
+///
+/// Each pick adds every eligible account's weight to its running credit,
+/// selects the highest credit (earliest candidate on ties) and charges it the
+/// total eligible weight. Over any window of `sum(weights)` picks each account
+/// is chosen exactly `weight` times, interleaved rather than in bursts.
+/// Credits are keyed by profile id so reordering members keeps the rotation.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WeightedRoundRobin {
+    credits: BTreeMap<String, i64>,
+}
+
+impl WeightedRoundRobin {
+    /// Restore state written by [`WeightedRoundRobin::encode`]; malformed
+    /// entries are dropped.
+    pub fn decode(encoded: &str) -> Self {
+        let credits = encoded
+            .split(';')
+            .filter_map(|entry| {
+                let (id, credit) = entry.rsplit_once('=')?;
+                Some((id.to_string(), credit.parse::<i64>().ok()?))
+            })
+            .collect();
+        Self { credits }
+    }
+
+    pub fn encode(&self) -> String {
+        self.credits
+            .iter()
+            .map(|(id, credit)| format!("{id}={credit}"))
+            .collect::<Vec<_>>()
+            .join(";")
+    }
+
+    /// Pick the next candidate index. Candidates with weight 0 or for which
+    /// `eligible(index)` is false (cooldown, capacity) are skipped and keep
+    /// their credit untouched.
+    pub fn next<F>(&mut self, candidates: &[WeightedCandidate], eligible: F) -> Option<usize>
+    where
+        F: Fn(usize) -> bool,
+    {
+        let mut total: i64 = 0;
+        let mut best: Option<(usize, i64)> = None;
+        for (idx, candidate) in candidates.iter().enumerate() {
+            if candidate.weight == 0 || !eligible(idx) {
+                continue;
+            }
+            let weight = i64::from(candidate.weight);
+            total += weight;
+            let credit = self
+                .credits
+                .entry(candidate.profile.id.clone())
+                .or_insert(0);
+            *credit += weight;
+            if best.map_or(true, |(_, best_credit)| *credit > best_credit) {
+                best = Some((idx, *credit));
+            }
+        }
+
+        let (idx, _) = best?;
+        if let Some(credit) = candidates
+            .get(idx)
+            .and_then(|candidate| self.credits.get_mut(&candidate.profile.id))
+        {
+            *credit -= total;
+        }
+        Some(idx)
+    }
+}
+
+/// The next `count` profile ids `rotation` would pick from its current
+/// credits, skipping zero-weight accounts and those cooling down at
+/// `now_epoch`. Pass the pool's decoded saved state to preview what the
+/// scheduler will actually pick next.
+pub fn weighted_selection_order(
+    mut rotation: WeightedRoundRobin,
+    candidates: &[WeightedCandidate],
+    now_epoch: i64,
+    count: usize,
+) -> Vec<String> {
+    let mut order = Vec::with_capacity(count);
+    for _ in 0..count {
+        let next = rotation.next(candidates, |idx| {
+            candidates.get(idx).is_some_and(|candidate| {
+                candidate
+                    .profile
+                    .cooldown_until_epoch
+                    .map_or(true, |until| until <= now_epoch)
+            })
+        });
+        let Some(idx) = next else {
+            break;
+        };
+        if let Some(candidate) = candidates.get(idx) {
+            order.push(candidate.profile.id.clone());
+        }
+    }
+    order
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SkipReason {
+    Cooldown { until_epoch: i64 },
+    AtCapacity,
+    Unavailable(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkippedAccount {
+    pub profile_id: String,
+    pub reason: SkipReason,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Selection {
+    pub profile: Profile,
+    pub candidate_index: usize,
+    pub skipped: Vec<SkippedAccount>,
+}
+
+impl Selection {
+    /// True when the first candidate was passed over for this one.
+    pub fn is_fallback(&self) -> bool {
+        self.candidate_index > 0
+    }
+}
+
+/// Every candidate was cooling down or busy; the caller should wait until
+/// `wait_until_epoch` before retrying.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoEligibleAccount {
+    pub wait_until_epoch: i64,
+    pub skipped: Vec<SkippedAccount>,
+}
+
+impl std::fmt::Display for NoEligibleAccount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "no eligible account among {} candidates (retry at {})",
+            self.skipped.len(),
+            self.wait_until_epoch
+        )
+    }
+}
+
+impl std::error::Error for NoEligibleAccount {}
+
+/// Picks the first candidate account not in cooldown or at capacity,
+/// recording which accounts were skipped on the way.
+pub struct AccountSelector<'a> {
+    backend: &'a dyn RunningCounts,
+}
+
+impl<'a> AccountSelector<'a> {
+    pub fn new(backend: &'a dyn RunningCounts) -> Self {
+        Self { backend }
+    }
+
+    pub fn select(
+        &self,
+        candidates: &[Profile],
+        now_epoch: i64,
+    ) -> Result<Selection, NoEligibleAccount> {
+        let mut skipped = Vec::new();
+        let mut earliest_wait: Option<i64> = None;
+
+        for (idx, profile) in candidates.iter().enumerate() {
+            let reason = match profile_available(self.backend, profile, now_epoch) {
+                Ok((true, _, _)) => {
+                    return Ok(Selection {
+                        profile: profile.clone(),
+                        candidate_index: idx,
+                        skipped,
+                    });
+                }
+                Ok((false, Some(until_epoch), _)) => {
+                    earliest_wait = Some(
+                        earliest_wait.map_or(until_epoch, |existing| existing.min(until_epoch)),
+                    );
+                    SkipReason::Cooldown { until_epoch }
+                }
+                Ok((false, None, _)) => SkipReason::AtCapacity,
+                Err(err) => SkipReason::Unavailable(err),
+            };
+            skipped.push(SkippedAccount {
+                profile_id: profile.id.clone(),
+                reason,
+            });
+        }
+
+        Err(NoEligibleAccount {
+            wait_until_epoch: earliest_wait.unwrap_or(now_epoch + DEFAULT_WAIT_INTERVAL_SECONDS),
+            skipped,
+        })
+    }
+}
+
 fn profile_available(
-    backend: &dyn SelectionBackend,
+    backend: &dyn RunningCounts,
     profile: &Profile,
     now_epoch: i64,
 ) -> Result<(bool, Option<i64>, Option<String>), String> {
@@ -263,8 +532,10 @@ fn set_pool_last_index(pool: &mut Pool, idx: i32) {
 #[cfg(test)]
 mod tests {
     use super::{
-        select_profile, InMemorySelectionBackend, LoopSpec, MetaValue, Pool, PoolMember, Profile,
-        SelectionBackend, DEFAULT_WAIT_INTERVAL_SECONDS, ERR_POOL_UNAVAILABLE,
+        select_profile, weighted_selection_order, AccountSelector, InMemorySelectionBackend,
+        LoopSpec, MetaValue, Pool, PoolMember, Profile, RunningCounts, SelectionBackend,
+        SkipReason, SkippedAccount, WeightedCandidate, WeightedRoundRobin,
+        DEFAULT_WAIT_INTERVAL_SECONDS, ERR_POOL_UNAVAILABLE, WEIGHTED_STATE_METADATA_KEY,
     };
     use std::collections::BTreeMap;
 
@@ -326,10 +597,12 @@ mod tests {
                     PoolMember {
                         profile_id: "profile-cool".to_string(),
                         position: 1,
+                        weight: 1,
                     },
                     PoolMember {
                         profile_id: "profile-ready".to_string(),
                         position: 2,
+                        weight: 1,
                     },
                 ],
             );
@@ -379,10 +652,12 @@ mod tests {
                     PoolMember {
                         profile_id: "profile-early".to_string(),
                         position: 1,
+                        weight: 1,
                     },
                     PoolMember {
                         profile_id: "profile-late".to_string(),
                         position: 2,
+                        weight: 1,
                     },
                 ],
             );
@@ -417,6 +692,7 @@ mod tests {
                 vec![PoolMember {
                     profile_id: "profile-busy".to_string(),
                     position: 1,
+                    weight: 1,
                 }],
             )
             .with_running_count("profile-busy", 1);
@@ -473,6 +749,7 @@ mod tests {
                 vec![PoolMember {
                     profile_id: profile.id.clone(),
                     position: 1,
+                    weight: 1,
                 }],
             )
             .with_pool_members(
@@ -480,6 +757,7 @@ mod tests {
                 vec![PoolMember {
                     profile_id: profile.id.clone(),
                     position: 1,
+                    weight: 1,
                 }],
             )
             .with_pool_members(
@@ -487,6 +765,7 @@ mod tests {
                 vec![PoolMember {
                     profile_id: profile.id.clone(),
                     position: 1,
+                    weight: 1,
                 }],
             );
 
@@ -560,10 +839,12 @@ mod tests {
                     PoolMember {
                         profile_id: profile_a.id.clone(),
                         position: 1,
+                        weight: 1,
                     },
                     PoolMember {
                         profile_id: profile_b.id.clone(),
                         position: 2,
+                        weight: 1,
                     },
                 ],
             );
@@ -599,4 +880,267 @@ mod tests {
         };
         assert_eq!(err, ERR_POOL_UNAVAILABLE);
     }
+
+    #[test]
+    fn account_selector_falls_back_past_cooling_accounts() {
+        let now = 1_700_000_000i64;
+        let candidates = vec![
+            account("acct-a", Some(now + 300), 0),
+            account("acct-b", Some(now - 10), 0),
+            account("acct-c", None, 0),
+        ];
+        let backend = InMemorySelectionBackend::default();
+        let selection = match AccountSelector::new(&backend).select(&candidates, now) {
+            Ok(value) => value,
+            Err(err) => panic!("unexpected error: {err}"),
+        };
+        assert_eq!(selection.profile.id, "acct-b");
+        assert!(selection.is_fallback());
+        assert_eq!(
+            selection.skipped,
+            vec![SkippedAccount {
+                profile_id: "acct-a".to_string(),
+                reason: SkipReason::Cooldown {
+                    until_epoch: now + 300
+                },
+            }]
+        );
+    }
+
+    #[test]
+    fn account_selector_skips_busy_accounts() {
+        let now = 1_700_000_000i64;
+        let candidates = vec![account("acct-a", None, 1), account("acct-b", None, 1)];
+        let backend = InMemorySelectionBackend::default().with_running_count("acct-a", 1);
+        let selection = match AccountSelector::new(&backend).select(&candidates, now) {
+            Ok(value) => value,
+            Err(err) => panic!("unexpected error: {err}"),
+        };
+        assert_eq!(selection.profile.id, "acct-b");
+        assert_eq!(selection.skipped[0].reason, SkipReason::AtCapacity);
+    }
+
+    #[test]
+    fn account_selector_reports_earliest_cooldown_when_all_cooling() {
+        let now = 1_700_000_000i64;
+        let candidates = vec![
+            account("acct-a", Some(now + 600), 0),
+            account("acct-b", Some(now + 120), 0),
+        ];
+        let backend = InMemorySelectionBackend::default();
+        let err = match AccountSelector::new(&backend).select(&candidates, now) {
+            Ok(value) => panic!("expected no eligible account, got {}", value.profile.id),
+            Err(err) => err,
+        };
+        assert_eq!(err.wait_until_epoch, now + 120);
+        assert_eq!(err.skipped.len(), 2);
+    }
+
+    #[test]
+    fn account_selector_uses_default_wait_when_only_busy() {
+        let now = 1_700_000_000i64;
+        let candidates = vec![account("acct-a", None, 1)];
+        let backend = InMemorySelectionBackend::default().with_running_count("acct-a", 2);
+        let err = match AccountSelector::new(&backend).select(&candidates, now) {
+            Ok(value) => panic!("expected no eligible account, got {}", value.profile.id),
+            Err(err) => err,
+        };
+        assert_eq!(err.wait_until_epoch, now + DEFAULT_WAIT_INTERVAL_SECONDS);
+    }
+
+    #[test]
+    fn weighted_rotation_matches_weights_and_skips_cooldown_and_zero_weight() {
+        let now = 1_700_000_000i64;
+        let candidates = vec![
+            WeightedCandidate {
+                profile: account("heavy", None, 0),
+                weight: 3,
+            },
+            WeightedCandidate {
+                profile: account("medium", None, 0),
+                weight: 2,
+            },
+            WeightedCandidate {
+                profile: account("light", None, 0),
+                weight: 1,
+            },
+            WeightedCandidate {
+                profile: account("parked", None, 0),
+                weight: 0,
+            },
+            WeightedCandidate {
+                profile: account("cooling", Some(now + 600), 0),
+                weight: 5,
+            },
+        ];
+
+        let order = weighted_selection_order(WeightedRoundRobin::default(), &candidates, now, 60);
+        assert_eq!(order.len(), 60);
+        let count = |id: &str| order.iter().filter(|picked| picked.as_str() == id).count();
+        assert_eq!(count("heavy"), 30);
+        assert_eq!(count("medium"), 20);
+        assert_eq!(count("light"), 10);
+        assert_eq!(count("parked"), 0);
+        assert_eq!(count("cooling"), 0);
+        assert_eq!(
+            &order[..6],
+            &["heavy", "medium", "heavy", "light", "medium", "heavy"]
+        );
+        assert_eq!(
+            weighted_selection_order(WeightedRoundRobin::default(), &candidates, now, 60),
+            order
+        );
+
+        // Once the cooldown expires the account joins the rotation at its weight.
+        let after =
+            weighted_selection_order(WeightedRoundRobin::default(), &candidates, now + 600, 110);
+        assert_eq!(
+            after
+                .iter()
+                .filter(|picked| picked.as_str() == "cooling")
+                .count(),
+            50
+        );
+
+        let mut rotation = WeightedRoundRobin::default();
+        let _ = rotation.next(&candidates, |_| true);
+        assert_eq!(WeightedRoundRobin::decode(&rotation.encode()), rotation);
+
+        // A saved rotation continues where the scheduler left off.
+        let mut saved = WeightedRoundRobin::default();
+        let _ = saved.next(&candidates, |idx| idx != 4);
+        let resumed = weighted_selection_order(
+            WeightedRoundRobin::decode(&saved.encode()),
+            &candidates,
+            now,
+            5,
+        );
+        assert_eq!(resumed, &order[1..6]);
+    }
+
+    #[test]
+    fn weighted_pool_selection_persists_rotation_state() {
+        let now = 1_700_000_000i64;
+        let pool = Pool {
+            id: "pool-w".to_string(),
+            name: "pool-w".to_string(),
+            is_default: true,
+            metadata: BTreeMap::new(),
+        };
+        let mut backend = InMemorySelectionBackend::default()
+            .with_profiles(vec![
+                account("a", None, 0),
+                account("b", None, 0),
+                account("zero", None, 0),
+            ])
+            .with_pools(vec![pool.clone()])
+            .with_pool_members(
+                &pool.id,
+                vec![
+                    PoolMember {
+                        profile_id: "a".to_string(),
+                        position: 1,
+                        weight: 2,
+                    },
+                    PoolMember {
+                        profile_id: "b".to_string(),
+                        position: 2,
+                        weight: 1,
+                    },
+                    PoolMember {
+                        profile_id: "zero".to_string(),
+                        position: 3,
+                        weight: 0,
+                    },
+                ],
+            );
+
+        let mut picks = Vec::new();
+        for _ in 0..6 {
+            let result = select_profile(&mut backend, &LoopSpec::default(), "", now)
+                .unwrap_or_else(|err| panic!("select_profile: {err}"));
+            let Some(profile) = result.selected_profile else {
+                panic!("expected a selected profile");
+            };
+            picks.push(profile.id);
+        }
+        assert_eq!(picks, vec!["a", "b", "a", "a", "b", "a"]);
+
+        let stored = backend
+            .get_pool(&pool.id)
+            .unwrap_or_else(|err| panic!("get_pool: {err}"));
+        assert!(matches!(
+            stored.metadata.get(WEIGHTED_STATE_METADATA_KEY),
+            Some(MetaValue::Text(_))
+        ));
+    }
+
+    struct ReadOnlyPools(InMemorySelectionBackend);
+
+    impl SelectionBackend for ReadOnlyPools {
+        fn get_profile(&self, profile_id: &str) -> Result<Profile, String> {
+            self.0.get_profile(profile_id)
+        }
+        fn get_pool(&self, pool_id: &str) -> Result<Pool, String> {
+            self.0.get_pool(pool_id)
+        }
+        fn get_pool_by_name(&self, name: &str) -> Result<Pool, String> {
+            self.0.get_pool_by_name(name)
+        }
+        fn get_default_pool(&self) -> Result<Pool, String> {
+            self.0.get_default_pool()
+        }
+        fn list_pool_members(&self, pool_id: &str) -> Result<Vec<PoolMember>, String> {
+            self.0.list_pool_members(pool_id)
+        }
+        fn update_pool(&mut self, pool: &Pool) -> Result<(), String> {
+            Err(format!("pool {} is read-only", pool.id))
+        }
+    }
+
+    impl RunningCounts for ReadOnlyPools {
+        fn count_running_by_profile(&self, profile_id: &str) -> Result<i32, String> {
+            self.0.count_running_by_profile(profile_id)
+        }
+    }
+
+    #[test]
+    fn pool_selection_surfaces_update_errors() {
+        let now = 1_700_000_000i64;
+        let pool = Pool {
+            id: "pool-ro".to_string(),
+            name: "pool-ro".to_string(),
+            is_default: true,
+            metadata: BTreeMap::new(),
+        };
+        for weight in [1, 2] {
+            let mut backend = ReadOnlyPools(
+                InMemorySelectionBackend::default()
+                    .with_profiles(vec![account("a", None, 0)])
+                    .with_pools(vec![pool.clone()])
+                    .with_pool_members(
+                        &pool.id,
+                        vec![PoolMember {
+                            profile_id: "a".to_string(),
+                            position: 1,
+                            weight,
+                        }],
+                    ),
+            );
+            let err = match select_profile(&mut backend, &LoopSpec::default(), "", now) {
+                Ok(result) => panic!("expected update error, got {result:?}"),
+                Err(err) => err,
+            };
+            assert_eq!(err, "pool pool-ro is read-only");
+        }
+    }
+
+    fn account(id: &str, cooldown_until_epoch: Option<i64>, max_concurrency: i32) -> Profile {
+        Profile {
+            id: id.to_string(),
+            name: id.to_string(),
+            max_concurrency,
+            cooldown_until_epoch,
+        }
+    }
 }
@@ -1,5 +1,7 @@
 use std::path::{Path, PathBuf};
 
+pub const DEFAULT_MEMORY_MAX_CHARS: usize = 6000;
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct OperatorMessage {
     pub timestamp_rfc3339: String,
@@ -114,6 +116,46 @@ pub fn resolve_repo_path(repo_root: &str, path: &str) -> PathBuf {
     Path::new(repo_root).join(path)
 }
 
+/// Render per-loop key/value memory as the persistent loop context block
+/// (Go `buildLoopMemory` "Mem" section). Entries are sorted by key; an empty
+/// slice renders nothing so loops without memory keep their prompt unchanged.
+pub fn render_loop_memory(entries: &[(String, String)], max_chars: usize) -> String {
+    if entries.is_empty() {
+        return String::new();
+    }
+    let max_chars = if max_chars == 0 {
+        DEFAULT_MEMORY_MAX_CHARS
+    } else {
+        max_chars
+    };
+
+    let mut sorted: Vec<&(String, String)> = entries.iter().collect();
+    sorted.sort_by(|left, right| left.0.cmp(&right.0));
+
+    let mut out = String::from("\n\n## Loop Context (persistent)\n\nMem:\n");
+    for (key, value) in sorted {
+        out.push_str("- ");
+        out.push_str(key);
+        out.push_str(": ");
+        out.push_str(value);
+        out.push('\n');
+    }
+    out.push_str("\nCLI:\n");
+    out.push_str("- forge mem set <key> \"<value>\"  (defaults to $FORGE_LOOP_ID)\n");
+
+    if out.len() > max_chars {
+        let mut cut = max_chars;
+        while !out.is_char_boundary(cut) {
+            cut -= 1;
+        }
+        out.truncate(cut);
+        let trimmed_len = out.trim_end_matches('\n').len();
+        out.truncate(trimmed_len);
+        out.push_str("\n(truncated)\n");
+    }
+    out
+}
+
 pub fn inject_loop_memory(base_prompt: &str, loop_memory: &str) -> String {
     if loop_memory.trim().is_empty() {
         return base_prompt.to_string();
@@ -148,9 +190,9 @@ pub fn compose_prompt(
 #[cfg(test)]
 mod tests {
     use super::{
-        append_operator_messages, compose_prompt, inject_loop_memory, resolve_base_prompt,
-        resolve_override_prompt, resolve_repo_path, LoopPromptConfig, OperatorMessage,
-        PromptOverridePayload,
+        append_operator_messages, compose_prompt, inject_loop_memory, render_loop_memory,
+        resolve_base_prompt, resolve_override_prompt, resolve_repo_path, LoopPromptConfig,
+        OperatorMessage, PromptOverridePayload,
     };
     use std::fs;
     use std::path::{Path, PathBuf};
@@ -167,6 +209,26 @@ mod tests {
         assert_eq!(got, "base\n\n## Loop Context (persistent)\n");
     }
 
+    #[test]
+    fn render_loop_memory_sorts_keys_and_truncates() {
+        assert_eq!(render_loop_memory(&[], 0), "");
+
+        let entries = vec![
+            ("focus".to_string(), "ship the parser".to_string()),
+            ("blocker".to_string(), "flaky ci".to_string()),
+        ];
+        assert_eq!(
+            render_loop_memory(&entries, 0),
+            "\n\n## Loop Context (persistent)\n\nMem:\n- blocker: flaky ci\n- focus: ship the parser\n\nCLI:\n- forge mem set <key> \"<value>\"  (defaults to $FORGE_LOOP_ID)\n"
+        );
+
+        let truncated = render_loop_memory(&entries, 40);
+        assert_eq!(
+            truncated,
+            "\n\n## Loop Context (persistent)\n\nMem:\n- b\n(truncated)\n"
+        );
+    }
+
     #[test]
     fn append_operator_messages_keeps_base_when_empty() {
         assert_eq!(append_operator_messages("base", &[]), "base");
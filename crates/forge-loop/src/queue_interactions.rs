@@ -1,3 +1,7 @@
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum QueueControlItem {
     MessageAppend,
@@ -9,6 +13,20 @@ pub enum QueueControlItem {
     Unsupported(String),
 }
 
+impl QueueControlItem {
+    pub fn from_item_type(value: &str) -> Self {
+        match value {
+            "message_append" => Self::MessageAppend,
+            "next_prompt_override" => Self::NextPromptOverride,
+            "pause" => Self::Pause,
+            "stop_graceful" => Self::StopGraceful,
+            "kill_now" => Self::KillNow,
+            "steer_message" => Self::SteerMessage,
+            other => Self::Unsupported(other.to_string()),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct QueueInteractionPlan {
     pub has_messages: bool,
@@ -80,12 +98,159 @@ pub fn should_inject_qualitative_stop(
     true
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum QueuePriority {
+    Low,
+    #[default]
+    Normal,
+    High,
+    Urgent,
+}
+
+impl QueuePriority {
+    pub fn rank(self) -> u32 {
+        match self {
+            Self::Low => 0,
+            Self::Normal => 1,
+            Self::High => 2,
+            Self::Urgent => 3,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Low => "low",
+            Self::Normal => "normal",
+            Self::High => "high",
+            Self::Urgent => "urgent",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "low" => Some(Self::Low),
+            "normal" => Some(Self::Normal),
+            "high" => Some(Self::High),
+            "urgent" => Some(Self::Urgent),
+            _ => None,
+        }
+    }
+}
+
+/// Starvation protection: every `interval` an item waits adds one rank,
+/// capped at `max_boost`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueueAging {
+    pub interval: Duration,
+    pub max_boost: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueuedInteraction {
+    pub item: QueueControlItem,
+    pub priority: QueuePriority,
+    pub position: u64,
+    pub enqueued_at: DateTime<Utc>,
+}
+
+impl QueuedInteraction {
+    pub fn effective_rank(&self, aging: Option<QueueAging>, now: DateTime<Utc>) -> u32 {
+        let base = self.priority.rank();
+        let Some(aging) = aging else {
+            return base;
+        };
+        if aging.interval.is_zero() {
+            return base;
+        }
+        let waited = match now.signed_duration_since(self.enqueued_at).to_std() {
+            Ok(value) => value,
+            Err(_) => return base,
+        };
+        let steps = waited.as_millis() / aging.interval.as_millis().max(1);
+        let boost = u32::try_from(steps)
+            .unwrap_or(u32::MAX)
+            .min(aging.max_boost);
+        base.saturating_add(boost)
+    }
+}
+
+/// Pending interactions ordered by priority, then by arrival position.
+#[derive(Debug, Clone, Default)]
+pub struct InteractionQueue {
+    items: Vec<QueuedInteraction>,
+    next_position: u64,
+    aging: Option<QueueAging>,
+}
+
+impl InteractionQueue {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_aging(aging: QueueAging) -> Self {
+        Self {
+            aging: Some(aging),
+            ..Self::default()
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn enqueue(
+        &mut self,
+        item: QueueControlItem,
+        priority: QueuePriority,
+        now: DateTime<Utc>,
+    ) -> u64 {
+        let position = self.next_position;
+        self.next_position += 1;
+        self.items.push(QueuedInteraction {
+            item,
+            priority,
+            position,
+            enqueued_at: now,
+        });
+        position
+    }
+
+    pub fn peek_next(&self, now: DateTime<Utc>) -> Option<&QueuedInteraction> {
+        self.next_index(now).map(|idx| &self.items[idx])
+    }
+
+    pub fn dequeue_next(&mut self, now: DateTime<Utc>) -> Option<QueuedInteraction> {
+        let idx = self.next_index(now)?;
+        Some(self.items.remove(idx))
+    }
+
+    fn next_index(&self, now: DateTime<Utc>) -> Option<usize> {
+        self.items
+            .iter()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| {
+                let rank_a = a.effective_rank(self.aging, now);
+                let rank_b = b.effective_rank(self.aging, now);
+                rank_a
+                    .cmp(&rank_b)
+                    .then_with(|| b.position.cmp(&a.position))
+            })
+            .map(|(idx, _)| idx)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{
-        build_queue_interaction_plan, should_inject_qualitative_stop, QueueControlItem,
-        QueueInteractionPlan,
+        build_queue_interaction_plan, should_inject_qualitative_stop, InteractionQueue, QueueAging,
+        QueueControlItem, QueueInteractionPlan, QueuePriority,
     };
+    use chrono::{Duration as ChronoDuration, TimeZone, Utc};
+    use std::time::Duration;
 
     #[test]
     fn pending_steer_marks_messages() {
@@ -219,4 +384,82 @@ mod tests {
         };
         assert_eq!(err, "unsupported queue item type \"unknown\"");
     }
+
+    #[test]
+    fn high_priority_late_arrival_is_served_first() {
+        let t0 = fixed_now();
+        let mut queue = InteractionQueue::new();
+        queue.enqueue(QueueControlItem::MessageAppend, QueuePriority::Normal, t0);
+        queue.enqueue(
+            QueueControlItem::StopGraceful,
+            QueuePriority::High,
+            t0 + ChronoDuration::seconds(5),
+        );
+
+        let now = t0 + ChronoDuration::seconds(6);
+        let peeked = queue.peek_next(now).map(|entry| entry.item.clone());
+        assert_eq!(peeked, Some(QueueControlItem::StopGraceful));
+        assert_eq!(queue.len(), 2);
+
+        let first = queue.dequeue_next(now).map(|entry| entry.item);
+        let second = queue.dequeue_next(now).map(|entry| entry.item);
+        assert_eq!(first, Some(QueueControlItem::StopGraceful));
+        assert_eq!(second, Some(QueueControlItem::MessageAppend));
+        assert!(queue.dequeue_next(now).is_none());
+    }
+
+    #[test]
+    fn equal_priority_preserves_arrival_order() {
+        let t0 = fixed_now();
+        let mut queue = InteractionQueue::new();
+        queue.enqueue(QueueControlItem::MessageAppend, QueuePriority::High, t0);
+        queue.enqueue(QueueControlItem::SteerMessage, QueuePriority::High, t0);
+        queue.enqueue(QueueControlItem::Pause, QueuePriority::High, t0);
+
+        let order: Vec<QueueControlItem> = std::iter::from_fn(|| queue.dequeue_next(t0))
+            .map(|entry| entry.item)
+            .collect();
+        assert_eq!(
+            order,
+            vec![
+                QueueControlItem::MessageAppend,
+                QueueControlItem::SteerMessage,
+                QueueControlItem::Pause,
+            ]
+        );
+    }
+
+    #[test]
+    fn aging_promotes_long_waiting_low_priority_item() {
+        let t0 = fixed_now();
+        let mut queue = InteractionQueue::with_aging(QueueAging {
+            interval: Duration::from_secs(60),
+            max_boost: 2,
+        });
+        queue.enqueue(QueueControlItem::MessageAppend, QueuePriority::Low, t0);
+        queue.enqueue(
+            QueueControlItem::SteerMessage,
+            QueuePriority::Normal,
+            t0 + ChronoDuration::seconds(150),
+        );
+
+        // Low waited 150s -> +2 ranks, beating the fresh Normal item.
+        let now = t0 + ChronoDuration::seconds(150);
+        let next = queue.peek_next(now).map(|entry| entry.item.clone());
+        assert_eq!(next, Some(QueueControlItem::MessageAppend));
+
+        // Without aging the Normal item would win.
+        let mut plain = InteractionQueue::new();
+        plain.enqueue(QueueControlItem::MessageAppend, QueuePriority::Low, t0);
+        plain.enqueue(QueueControlItem::SteerMessage, QueuePriority::Normal, now);
+        let next = plain.peek_next(now).map(|entry| entry.item.clone());
+        assert_eq!(next, Some(QueueControlItem::SteerMessage));
+    }
+
+    fn fixed_now() -> chrono::DateTime<Utc> {
+        match Utc.with_ymd_and_hms(2026, 2, 10, 12, 0, 0) {
+            chrono::LocalResult::Single(value) => value,
+            _ => panic!("invalid fixture timestamp"),
+        }
+    }
 }
@@ -7,6 +7,7 @@ pub enum LoopState {
     Running,
     Sleeping,
     Waiting,
+    Paused,
     Stopped,
     Error,
 }
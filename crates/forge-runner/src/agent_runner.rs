@@ -1,12 +1,14 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::Duration;
 
 use regex::Regex;
 
-use crate::config::load_config;
+use crate::config::{load_config, ReloadableConfig};
 use crate::runner::Runner;
-use crate::sink::{DatabaseEventSink, EventSink, SocketEventSink};
+use crate::sink::{BufferedSink, DatabaseEventSink, EventSink, SocketEventSink};
+
+const CONFIG_RELOAD_POLL_INTERVAL: Duration = Duration::from_secs(5);
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Args {
@@ -66,7 +68,7 @@ pub fn run_with_args(argv: &[String]) -> i32 {
         return 2;
     }
 
-    let (mut cfg, _used_path) = match load_config(if parsed.config_file.is_empty() {
+    let (mut cfg, used_path) = match load_config(if parsed.config_file.is_empty() {
         None
     } else {
         Some(parsed.config_file.as_str())
@@ -84,6 +86,10 @@ pub fn run_with_args(argv: &[String]) -> i32 {
     if !parsed.log_format.trim().is_empty() {
         cfg.logging.format = parsed.log_format.trim().to_string();
     }
+    if let Err(err) = cfg.validate() {
+        eprintln!("Error loading config: {err}");
+        return 1;
+    }
 
     if let Err(err) = cfg.ensure_directories() {
         eprintln!("Warning: failed to create directories: {err}");
@@ -135,17 +141,63 @@ pub fn run_with_args(argv: &[String]) -> i32 {
     runner.busy_regex = busy_re;
     runner.heartbeat_interval = parsed.heartbeat;
     runner.tail_lines = parsed.tail_lines;
-    runner.event_sink = sink;
+    let buffered = Arc::new(BufferedSink::new(
+        sink,
+        cfg.sink.buffer_capacity,
+        cfg.sink.backpressure,
+    ));
+    runner.event_sink = buffered.clone();
+    let live = ReloadableConfig::new(cfg);
+    if let Some(path) = used_path {
+        spawn_config_watcher(live.clone(), path, buffered.clone());
+    }
+    runner.live_config = Some(live);
     runner.control_reader = Some(Box::new(std::io::stdin()));
     runner.output_writer = Box::new(std::io::stdout());
 
-    if let Err(err) = runner.run() {
+    let result = runner.run();
+    let stats = buffered.stats();
+    if stats.engaged > 0 || stats.sink_errors > 0 {
+        eprintln!(
+            "event sink backpressure ({}): engaged={} dropped={} sink_errors={}",
+            buffered.policy().as_str(),
+            stats.engaged,
+            stats.dropped,
+            stats.sink_errors
+        );
+    }
+    if let Err(err) = result {
         eprintln!("{err}");
         return 1;
     }
     0
 }
 
+/// Poll the config file and hot-reload runtime limits when it changes.
+/// Invalid edits are reported and the previous config stays active.
+fn spawn_config_watcher(live: ReloadableConfig, path: PathBuf, buffered: Arc<BufferedSink>) {
+    let _ = reload_config(&live, &path, &buffered);
+    std::thread::spawn(move || loop {
+        std::thread::sleep(CONFIG_RELOAD_POLL_INTERVAL);
+        if let Err(err) = reload_config(&live, &path, &buffered) {
+            eprintln!("Warning: ignoring invalid config reload: {err}");
+        }
+    });
+}
+
+/// Reload `path` if it changed and apply its sink limits. The runner reads
+/// the output limits from `live` itself on every iteration.
+fn reload_config(
+    live: &ReloadableConfig,
+    path: &Path,
+    buffered: &BufferedSink,
+) -> Result<(), String> {
+    if let Some(cfg) = live.reload_if_changed(path)? {
+        buffered.set_limits(cfg.sink.buffer_capacity, cfg.sink.backpressure);
+    }
+    Ok(())
+}
+
 fn build_event_sink(
     cfg: &crate::config::Config,
     workspace_id: &str,
@@ -296,7 +348,56 @@ fn usage(message: Option<&str>) -> String {
 
 #[cfg(test)]
 mod tests {
-    use super::{parse_args, Args};
+    use std::sync::Arc;
+    use std::time::{Duration, SystemTime};
+
+    use super::{parse_args, reload_config, Args};
+    use crate::config::{BackpressurePolicy, Config, ReloadableConfig};
+    use crate::sink::{BufferedSink, NoopSink};
+
+    #[test]
+    fn config_reload_applies_sink_limits() {
+        let dir = match tempfile::tempdir() {
+            Ok(dir) => dir,
+            Err(err) => panic!("tempdir: {err}"),
+        };
+        let path = dir.path().join("config.yaml");
+        let live = ReloadableConfig::new(Config::default_from_env());
+        let buffered = BufferedSink::new(Arc::new(NoopSink), 2, BackpressurePolicy::Block);
+
+        write_config(
+            &path,
+            "sink:\n  buffer_capacity: 8\n  backpressure: drop_oldest\n",
+            SystemTime::UNIX_EPOCH + Duration::from_secs(1),
+        );
+        if let Err(err) = reload_config(&live, &path, &buffered) {
+            panic!("reload: {err}");
+        }
+        assert_eq!(buffered.capacity(), 8);
+        assert_eq!(buffered.policy(), BackpressurePolicy::DropOldest);
+
+        write_config(
+            &path,
+            "sink:\n  buffer_capacity: 4\n  backpressure: sideways\n",
+            SystemTime::UNIX_EPOCH + Duration::from_secs(2),
+        );
+        assert!(reload_config(&live, &path, &buffered).is_err());
+        assert_eq!(buffered.capacity(), 8);
+        assert_eq!(buffered.policy(), BackpressurePolicy::DropOldest);
+    }
+
+    fn write_config(path: &std::path::Path, body: &str, modified: SystemTime) {
+        if let Err(err) = std::fs::write(path, body) {
+            panic!("write config: {err}");
+        }
+        let file = match std::fs::File::options().write(true).open(path) {
+            Ok(file) => file,
+            Err(err) => panic!("open config: {err}"),
+        };
+        if let Err(err) = file.set_modified(modified) {
+            panic!("set mtime: {err}");
+        }
+    }
 
     #[test]
     fn parse_rejects_unknown_flag() {
@@ -1,4 +1,6 @@
 use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+use std::time::SystemTime;
 
 use serde::Deserialize;
 
@@ -7,6 +9,8 @@ pub struct Config {
     pub global: GlobalConfig,
     pub database: DatabaseConfig,
     pub logging: LoggingConfig,
+    pub limits: LimitsConfig,
+    pub sink: SinkConfig,
 }
 
 #[derive(Debug, Clone)]
@@ -28,6 +32,61 @@ pub struct LoggingConfig {
     pub format: String,
 }
 
+/// Runtime limits that a running runner picks up on reload.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LimitsConfig {
+    /// Bytes of trailing output kept for prompt/busy detection.
+    pub tail_bytes: usize,
+    /// Longest output line forwarded in an `output_line` event.
+    pub max_event_line_length: usize,
+}
+
+/// What the buffered event sink does when its queue is full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BackpressurePolicy {
+    /// Wait for the sink to catch up; nothing is lost.
+    #[default]
+    Block,
+    /// Evict the oldest queued event to make room.
+    DropOldest,
+    /// Discard the incoming event.
+    DropNewest,
+}
+
+impl BackpressurePolicy {
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value.trim().to_ascii_lowercase().replace('-', "_").as_str() {
+            "block" => Ok(Self::Block),
+            "drop_oldest" => Ok(Self::DropOldest),
+            "drop_newest" => Ok(Self::DropNewest),
+            other => Err(format!(
+                "invalid sink.backpressure {other:?} (expected block, drop_oldest, or drop_newest)"
+            )),
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Block => "block",
+            Self::DropOldest => "drop_oldest",
+            Self::DropNewest => "drop_newest",
+        }
+    }
+}
+
+/// Buffering between output production and the event sink. A running
+/// runner applies changes on reload.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SinkConfig {
+    pub buffer_capacity: usize,
+    pub backpressure: BackpressurePolicy,
+}
+
+pub const DEFAULT_SINK_BUFFER_CAPACITY: usize = 1024;
+
+/// Upper bound accepted for either limit; larger values are treated as typos.
+const MAX_LIMIT_BYTES: usize = 16 * 1024 * 1024;
+
 impl Config {
     pub fn default_from_env() -> Self {
         let home = std::env::var("HOME").unwrap_or_default();
@@ -58,9 +117,48 @@ impl Config {
                 level: "info".to_string(),
                 format: "console".to_string(),
             },
+            limits: LimitsConfig {
+                tail_bytes: crate::runner::DEFAULT_TAIL_BYTES,
+                max_event_line_length: crate::runner::MAX_EVENT_LINE_LENGTH,
+            },
+            sink: SinkConfig {
+                buffer_capacity: DEFAULT_SINK_BUFFER_CAPACITY,
+                backpressure: BackpressurePolicy::Block,
+            },
         }
     }
 
+    /// Reject values the runner cannot operate with.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.database.max_connections <= 0 {
+            return Err("database.max_connections must be positive".to_string());
+        }
+        match self.logging.level.as_str() {
+            "debug" | "info" | "warn" | "error" => {}
+            other => return Err(format!("invalid logging.level {other:?}")),
+        }
+        match self.logging.format.as_str() {
+            "console" | "json" => {}
+            other => return Err(format!("invalid logging.format {other:?}")),
+        }
+        if self.limits.tail_bytes == 0 || self.limits.tail_bytes > MAX_LIMIT_BYTES {
+            return Err(format!(
+                "limits.tail_bytes must be between 1 and {MAX_LIMIT_BYTES}"
+            ));
+        }
+        if self.limits.max_event_line_length == 0
+            || self.limits.max_event_line_length > MAX_LIMIT_BYTES
+        {
+            return Err(format!(
+                "limits.max_event_line_length must be between 1 and {MAX_LIMIT_BYTES}"
+            ));
+        }
+        if self.sink.buffer_capacity == 0 {
+            return Err("sink.buffer_capacity must be positive".to_string());
+        }
+        Ok(())
+    }
+
     pub fn database_path(&self) -> PathBuf {
         if let Some(path) = &self.database.path {
             return path.clone();
@@ -83,6 +181,10 @@ struct PartialConfig {
     database: PartialDatabaseConfig,
     #[serde(default)]
     logging: PartialLoggingConfig,
+    #[serde(default)]
+    limits: PartialLimitsConfig,
+    #[serde(default)]
+    sink: PartialSinkConfig,
 }
 
 #[derive(Debug, Default, Deserialize)]
@@ -111,6 +213,22 @@ struct PartialLoggingConfig {
     format: String,
 }
 
+#[derive(Debug, Default, Deserialize)]
+struct PartialLimitsConfig {
+    #[serde(default)]
+    tail_bytes: Option<i64>,
+    #[serde(default)]
+    max_event_line_length: Option<i64>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct PartialSinkConfig {
+    #[serde(default)]
+    buffer_capacity: Option<i64>,
+    #[serde(default)]
+    backpressure: String,
+}
+
 /// Load config with Go-like precedence:
 /// defaults < (optional) config file (explicit => hard error if unreadable).
 pub fn load_config(config_file: Option<&str>) -> Result<(Config, Option<PathBuf>), String> {
@@ -187,9 +305,106 @@ fn apply_partial(cfg: &mut Config, partial: PartialConfig) -> Result<(), String>
     if !partial.logging.format.trim().is_empty() {
         cfg.logging.format = partial.logging.format.trim().to_string();
     }
+    // Limits are validated rather than silently clamped so that a bad hot
+    // reload is rejected instead of half-applied.
+    if let Some(tail_bytes) = partial.limits.tail_bytes {
+        cfg.limits.tail_bytes = usize::try_from(tail_bytes).unwrap_or(0);
+    }
+    if let Some(max_len) = partial.limits.max_event_line_length {
+        cfg.limits.max_event_line_length = usize::try_from(max_len).unwrap_or(0);
+    }
+    if let Some(capacity) = partial.sink.buffer_capacity {
+        cfg.sink.buffer_capacity = usize::try_from(capacity).unwrap_or(0);
+    }
+    if !partial.sink.backpressure.trim().is_empty() {
+        cfg.sink.backpressure = BackpressurePolicy::parse(&partial.sink.backpressure)?;
+    }
     Ok(())
 }
 
+/// Shared, hot-reloadable runner config.
+///
+/// Readers take a cheap `Arc<Config>` snapshot per iteration; `reload_from`
+/// validates the new file before swapping it in, so a bad edit leaves the
+/// previous config active.
+#[derive(Debug, Clone)]
+pub struct ReloadableConfig {
+    inner: Arc<RwLock<ReloadState>>,
+}
+
+#[derive(Debug)]
+struct ReloadState {
+    config: Arc<Config>,
+    source_modified: Option<SystemTime>,
+}
+
+impl ReloadableConfig {
+    pub fn new(config: Config) -> Self {
+        Self {
+            inner: Arc::new(RwLock::new(ReloadState {
+                config: Arc::new(config),
+                source_modified: None,
+            })),
+        }
+    }
+
+    /// The currently active config.
+    pub fn current(&self) -> Arc<Config> {
+        match self.inner.read() {
+            Ok(state) => Arc::clone(&state.config),
+            Err(poisoned) => Arc::clone(&poisoned.into_inner().config),
+        }
+    }
+
+    /// Load and validate `path`, then make it the active config.
+    pub fn reload_from(&self, path: &Path) -> Result<Arc<Config>, String> {
+        let modified = file_modified(path);
+        let path_str = path.to_string_lossy();
+        let (candidate, _) = load_config(Some(path_str.as_ref()))?;
+        candidate.validate()?;
+
+        let candidate = Arc::new(candidate);
+        let mut state = match self.inner.write() {
+            Ok(state) => state,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        state.config = Arc::clone(&candidate);
+        state.source_modified = modified;
+        Ok(candidate)
+    }
+
+    /// Reload only when `path` changed since the last reload attempt.
+    /// Returns `Ok(None)` when nothing changed.
+    pub fn reload_if_changed(&self, path: &Path) -> Result<Option<Arc<Config>>, String> {
+        let modified = file_modified(path);
+        let last = match self.inner.read() {
+            Ok(state) => state.source_modified,
+            Err(poisoned) => poisoned.into_inner().source_modified,
+        };
+        if modified.is_none() || modified == last {
+            return Ok(None);
+        }
+        match self.reload_from(path) {
+            Ok(config) => Ok(Some(config)),
+            Err(err) => {
+                // Remember the rejected revision so it is reported once, not
+                // on every poll.
+                match self.inner.write() {
+                    Ok(mut state) => state.source_modified = modified,
+                    Err(poisoned) => poisoned.into_inner().source_modified = modified,
+                }
+                Err(err)
+            }
+        }
+    }
+}
+
+fn file_modified(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path)
+        .and_then(|meta| meta.modified())
+        .ok()
+}
+
 fn expand_tilde(input: &str) -> Result<PathBuf, String> {
     if input == "~" {
         let home = std::env::var("HOME").map_err(|_| "failed to resolve HOME".to_string())?;
@@ -204,7 +419,7 @@ fn expand_tilde(input: &str) -> Result<PathBuf, String> {
 
 #[cfg(test)]
 mod tests {
-    use super::{load_config, Config};
+    use super::{load_config, BackpressurePolicy, Config, ReloadableConfig};
 
     #[test]
     fn default_config_contains_expected_paths() {
@@ -223,4 +438,107 @@ mod tests {
         let _ = used;
         let _ = cfg.database_path();
     }
+
+    #[test]
+    fn backpressure_policy_parses_config_values() {
+        assert_eq!(
+            BackpressurePolicy::parse("drop-oldest"),
+            Ok(BackpressurePolicy::DropOldest)
+        );
+        assert_eq!(
+            BackpressurePolicy::parse(" DROP_NEWEST "),
+            Ok(BackpressurePolicy::DropNewest)
+        );
+        assert_eq!(
+            BackpressurePolicy::parse("block"),
+            Ok(BackpressurePolicy::Block)
+        );
+        assert!(BackpressurePolicy::parse("spill").is_err());
+        assert_eq!(
+            Config::default_from_env().sink.backpressure,
+            BackpressurePolicy::Block
+        );
+    }
+
+    #[test]
+    fn reload_applies_valid_config() {
+        let dir = match tempfile::tempdir() {
+            Ok(dir) => dir,
+            Err(err) => panic!("tempdir: {err}"),
+        };
+        let path = dir.path().join("config.yaml");
+        if let Err(err) = std::fs::write(&path, "limits:\n  tail_bytes: 8192\n") {
+            panic!("write config: {err}");
+        }
+
+        let live = ReloadableConfig::new(Config::default_from_env());
+        let reloaded = match live.reload_from(&path) {
+            Ok(cfg) => cfg,
+            Err(err) => panic!("reload: {err}"),
+        };
+        assert_eq!(reloaded.limits.tail_bytes, 8192);
+        assert_eq!(live.current().limits.tail_bytes, 8192);
+    }
+
+    #[test]
+    fn invalid_reload_keeps_prior_config() {
+        let dir = match tempfile::tempdir() {
+            Ok(dir) => dir,
+            Err(err) => panic!("tempdir: {err}"),
+        };
+        let path = dir.path().join("config.yaml");
+        if let Err(err) = std::fs::write(&path, "limits:\n  tail_bytes: 2048\n") {
+            panic!("write config: {err}");
+        }
+        let live = ReloadableConfig::new(Config::default_from_env());
+        if let Err(err) = live.reload_from(&path) {
+            panic!("initial reload: {err}");
+        }
+
+        if let Err(err) = std::fs::write(&path, "limits:\n  tail_bytes: 0\n") {
+            panic!("write config: {err}");
+        }
+        let err = match live.reload_from(&path) {
+            Ok(_) => panic!("expected zero tail_bytes to be rejected"),
+            Err(err) => err,
+        };
+        assert!(err.contains("limits.tail_bytes"), "unexpected error: {err}");
+        assert_eq!(live.current().limits.tail_bytes, 2048);
+
+        if let Err(err) = std::fs::write(&path, "logging:\n  level: loud\n") {
+            panic!("write config: {err}");
+        }
+        assert!(live.reload_from(&path).is_err());
+        assert_eq!(live.current().logging.level, "info");
+        assert_eq!(live.current().limits.tail_bytes, 2048);
+    }
+
+    #[test]
+    fn non_positive_buffer_capacity_is_rejected() {
+        let dir = match tempfile::tempdir() {
+            Ok(dir) => dir,
+            Err(err) => panic!("tempdir: {err}"),
+        };
+        let path = dir.path().join("config.yaml");
+        let path_str = path.to_string_lossy().to_string();
+        for capacity in ["0", "-4"] {
+            if let Err(err) =
+                std::fs::write(&path, format!("sink:\n  buffer_capacity: {capacity}\n"))
+            {
+                panic!("write config: {err}");
+            }
+            let (cfg, _) = match load_config(Some(&path_str)) {
+                Ok(value) => value,
+                Err(err) => panic!("load: {err}"),
+            };
+            let err = match cfg.validate() {
+                Ok(()) => panic!("expected buffer_capacity {capacity} to be rejected"),
+                Err(err) => err,
+            };
+            assert!(
+                err.contains("sink.buffer_capacity"),
+                "unexpected error: {err}"
+            );
+        }
+    }
 }
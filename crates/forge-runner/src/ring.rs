@@ -12,6 +12,7 @@ struct LineRingInner {
     lines: Vec<String>,
     next: usize,
     full: bool,
+    dropped: u64,
 }
 
 impl LineRing {
@@ -23,6 +24,7 @@ impl LineRing {
                 lines: vec![String::new(); size],
                 next: 0,
                 full: false,
+                dropped: 0,
             }),
         }
     }
@@ -35,6 +37,9 @@ impl LineRing {
             return;
         }
         let idx = inner.next;
+        if inner.full {
+            inner.dropped = inner.dropped.saturating_add(1);
+        }
         inner.lines[idx] = line.to_string();
         inner.next += 1;
         if inner.next >= self.size {
@@ -43,10 +48,32 @@ impl LineRing {
         }
     }
 
+    /// Current contents, oldest first.
     pub fn snapshot(&self) -> Vec<String> {
         let Ok(inner) = self.inner.lock() else {
             return Vec::new();
         };
+        self.ordered_lines(&inner)
+    }
+
+    /// Number of lines overwritten since the ring was created.
+    pub fn dropped_count(&self) -> u64 {
+        let Ok(inner) = self.inner.lock() else {
+            return 0;
+        };
+        inner.dropped
+    }
+
+    /// Contents and drop count taken under a single lock, so a concurrent
+    /// `add` cannot land between the two reads.
+    pub fn snapshot_with_dropped(&self) -> (Vec<String>, u64) {
+        let Ok(inner) = self.inner.lock() else {
+            return (Vec::new(), 0);
+        };
+        (self.ordered_lines(&inner), inner.dropped)
+    }
+
+    fn ordered_lines(&self, inner: &LineRingInner) -> Vec<String> {
         if !inner.full {
             return inner.lines[..inner.next].to_vec();
         }
@@ -80,4 +107,50 @@ mod tests {
             vec!["b".to_string(), "c".to_string(), "d".to_string()]
         );
     }
+
+    #[test]
+    fn overflow_increments_dropped_count() {
+        let ring = LineRing::new(2);
+        ring.add("a");
+        ring.add("b");
+        assert_eq!(ring.dropped_count(), 0);
+
+        ring.add("c");
+        ring.add("d");
+        ring.add("e");
+        assert_eq!(ring.dropped_count(), 3);
+
+        let (lines, dropped) = ring.snapshot_with_dropped();
+        assert_eq!(lines, vec!["d".to_string(), "e".to_string()]);
+        assert_eq!(dropped, 3);
+    }
+
+    #[test]
+    fn concurrent_adds_keep_snapshot_consistent() {
+        let ring = std::sync::Arc::new(LineRing::new(8));
+        let writers: Vec<_> = (0..4)
+            .map(|writer| {
+                let ring = std::sync::Arc::clone(&ring);
+                std::thread::spawn(move || {
+                    for i in 0..100 {
+                        ring.add(&format!("{writer}-{i}"));
+                    }
+                })
+            })
+            .collect();
+        for _ in 0..50 {
+            let (lines, dropped) = ring.snapshot_with_dropped();
+            let total = lines.len() as u64 + dropped;
+            assert!(lines.len() <= 8);
+            assert!(dropped == 0 || lines.len() == 8, "drops imply a full ring");
+            assert!(total <= 400);
+        }
+        for handle in writers {
+            if handle.join().is_err() {
+                panic!("writer thread panicked");
+            }
+        }
+        assert_eq!(ring.dropped_count(), 392);
+        assert_eq!(ring.snapshot().len(), 8);
+    }
 }
@@ -7,6 +7,7 @@ use std::time::Duration;
 use chrono::{DateTime, Utc};
 use regex::Regex;
 
+use crate::config::ReloadableConfig;
 use crate::ring::LineRing;
 use crate::sink::{EventSink, NoopSink};
 
@@ -44,6 +45,11 @@ pub struct Runner {
     pub heartbeat_interval: Duration,
     pub tail_lines: usize,
     pub tail_bytes: usize,
+    pub max_event_line_length: usize,
+
+    /// When set, limits are re-read from the live config on every output
+    /// iteration so operators can adjust them without a restart.
+    pub live_config: Option<ReloadableConfig>,
 
     pub event_sink: Arc<dyn EventSink>,
     pub control_reader: Option<Box<dyn Read + Send>>,
@@ -65,6 +71,8 @@ impl Runner {
             heartbeat_interval: DEFAULT_HEARTBEAT_INTERVAL,
             tail_lines: DEFAULT_TAIL_LINES,
             tail_bytes: DEFAULT_TAIL_BYTES,
+            max_event_line_length: MAX_EVENT_LINE_LENGTH,
+            live_config: None,
             event_sink: Arc::new(NoopSink),
             control_reader: None,
             output_writer: Box::new(std::io::sink()),
@@ -143,6 +151,7 @@ impl Runner {
 
         while eof_count < 2 {
             let chunk = rx.recv().map_err(|err| RunnerError::Io(err.to_string()))?;
+            self.apply_live_limits();
             if chunk.eof {
                 eof_count += 1;
                 continue;
@@ -221,6 +230,9 @@ impl Runner {
         if self.tail_bytes == 0 {
             self.tail_bytes = DEFAULT_TAIL_BYTES;
         }
+        if self.max_event_line_length == 0 {
+            self.max_event_line_length = MAX_EVENT_LINE_LENGTH;
+        }
         if self.prompt_regex.is_none() {
             self.prompt_regex = Regex::new(DEFAULT_PROMPT_REGEX).ok();
         }
@@ -230,6 +242,15 @@ impl Runner {
         self.output = Arc::new(LineRing::new(self.tail_lines));
     }
 
+    fn apply_live_limits(&mut self) {
+        let Some(live) = &self.live_config else {
+            return;
+        };
+        let limits = live.current().limits.clone();
+        self.tail_bytes = limits.tail_bytes;
+        self.max_event_line_length = limits.max_event_line_length;
+    }
+
     fn now_fn(&self) -> fn() -> DateTime<Utc> {
         self.now.unwrap_or(Utc::now)
     }
@@ -240,7 +261,7 @@ impl Runner {
 
     fn handle_line(&self, line: &str) {
         self.output.add(line);
-        let (preview, truncated) = truncate_text(line, MAX_EVENT_LINE_LENGTH);
+        let (preview, truncated) = truncate_text(line, self.max_event_line_length);
         self.emit(
             EVENT_TYPE_OUTPUT_LINE,
             serde_json::to_value(OutputLineData {
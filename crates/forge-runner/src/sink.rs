@@ -1,13 +1,16 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::io::Write;
 use std::os::unix::net::UnixStream;
 use std::path::Path;
-use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::thread::JoinHandle;
 
 use chrono::Utc;
 use forge_db::event_repository::{Event, EventRepository};
 use forge_db::{Config, Db};
 
+use crate::config::BackpressurePolicy;
 use crate::runner::RunnerEvent;
 
 const RUNNER_ENTITY_TYPE: &str = "agent";
@@ -30,6 +33,254 @@ impl EventSink for NoopSink {
     }
 }
 
+/// Fans each event out to several sinks.
+///
+/// Every sink receives every event in emit order. A failing sink does not stop
+/// delivery to the others; failures are joined into one error that names the
+/// index of each failing sink.
+#[derive(Default)]
+pub struct MultiSink {
+    sinks: Vec<Arc<dyn EventSink>>,
+}
+
+impl MultiSink {
+    pub fn new(sinks: Vec<Arc<dyn EventSink>>) -> Self {
+        Self { sinks }
+    }
+
+    pub fn push(&mut self, sink: Arc<dyn EventSink>) {
+        self.sinks.push(sink);
+    }
+
+    pub fn len(&self) -> usize {
+        self.sinks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sinks.is_empty()
+    }
+
+    fn for_each_sink(
+        &self,
+        op: impl Fn(&dyn EventSink) -> Result<(), String>,
+    ) -> Result<(), String> {
+        let failures: Vec<String> = self
+            .sinks
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, sink)| {
+                op(sink.as_ref())
+                    .err()
+                    .map(|err| format!("sink {idx}: {err}"))
+            })
+            .collect();
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(failures.join("; "))
+        }
+    }
+}
+
+impl EventSink for MultiSink {
+    fn emit(&self, event: &RunnerEvent) -> Result<(), String> {
+        self.for_each_sink(|sink| sink.emit(event))
+    }
+
+    fn close(&self) -> Result<(), String> {
+        self.for_each_sink(|sink| sink.close())
+    }
+}
+
+/// Counters describing how often a [`BufferedSink`] hit its capacity.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BackpressureStats {
+    /// Emits that found the queue full.
+    pub engaged: u64,
+    /// Events discarded by a drop policy.
+    pub dropped: u64,
+    /// Events the wrapped sink failed to accept.
+    pub sink_errors: u64,
+}
+
+/// Decouples event production from a slow sink with a bounded queue.
+///
+/// A worker thread drains the queue into the wrapped sink in order. When the
+/// queue is full the configured [`BackpressurePolicy`] decides whether the
+/// producer waits or an event is dropped. Both can be changed while running
+/// with [`BufferedSink::set_limits`]. `close` flushes what is queued before
+/// closing the wrapped sink and returns the wrapped sink's close error.
+pub struct BufferedSink {
+    shared: Arc<BufferShared>,
+    worker: Mutex<Option<JoinHandle<Result<(), String>>>>,
+}
+
+struct BufferShared {
+    queue: Mutex<BufferQueue>,
+    not_empty: Condvar,
+    not_full: Condvar,
+    engaged: AtomicU64,
+    dropped: AtomicU64,
+    sink_errors: AtomicU64,
+}
+
+struct BufferQueue {
+    events: VecDeque<RunnerEvent>,
+    capacity: usize,
+    policy: BackpressurePolicy,
+    closed: bool,
+}
+
+impl BufferedSink {
+    pub fn new(inner: Arc<dyn EventSink>, capacity: usize, policy: BackpressurePolicy) -> Self {
+        let shared = Arc::new(BufferShared {
+            queue: Mutex::new(BufferQueue {
+                events: VecDeque::with_capacity(capacity.max(1)),
+                capacity: capacity.max(1),
+                policy,
+                closed: false,
+            }),
+            not_empty: Condvar::new(),
+            not_full: Condvar::new(),
+            engaged: AtomicU64::new(0),
+            dropped: AtomicU64::new(0),
+            sink_errors: AtomicU64::new(0),
+        });
+        let worker_shared = Arc::clone(&shared);
+        let worker = std::thread::spawn(move || buffered_sink_worker(worker_shared, inner));
+        Self {
+            shared,
+            worker: Mutex::new(Some(worker)),
+        }
+    }
+
+    pub fn policy(&self) -> BackpressurePolicy {
+        lock_queue(&self.shared.queue).policy
+    }
+
+    pub fn capacity(&self) -> usize {
+        lock_queue(&self.shared.queue).capacity
+    }
+
+    /// Apply a new capacity and policy, taking effect on the next emit.
+    pub fn set_limits(&self, capacity: usize, policy: BackpressurePolicy) {
+        {
+            let mut queue = lock_queue(&self.shared.queue);
+            queue.capacity = capacity.max(1);
+            queue.policy = policy;
+        }
+        self.shared.not_full.notify_all();
+    }
+
+    pub fn stats(&self) -> BackpressureStats {
+        BackpressureStats {
+            engaged: self.shared.engaged.load(Ordering::Relaxed),
+            dropped: self.shared.dropped.load(Ordering::Relaxed),
+            sink_errors: self.shared.sink_errors.load(Ordering::Relaxed),
+        }
+    }
+}
+
+impl EventSink for BufferedSink {
+    fn emit(&self, event: &RunnerEvent) -> Result<(), String> {
+        let shared = &self.shared;
+        let mut queue = lock_queue(&shared.queue);
+        if queue.closed {
+            return Err("event sink closed".to_string());
+        }
+
+        if queue.events.len() >= queue.capacity {
+            shared.engaged.fetch_add(1, Ordering::Relaxed);
+            match queue.policy {
+                BackpressurePolicy::Block => {
+                    while queue.events.len() >= queue.capacity && !queue.closed {
+                        queue = match shared.not_full.wait(queue) {
+                            Ok(guard) => guard,
+                            Err(poisoned) => poisoned.into_inner(),
+                        };
+                    }
+                    if queue.closed {
+                        return Err("event sink closed".to_string());
+                    }
+                }
+                BackpressurePolicy::DropOldest => {
+                    while queue.events.len() >= queue.capacity {
+                        queue.events.pop_front();
+                        shared.dropped.fetch_add(1, Ordering::Relaxed);
+                    }
+                }
+                BackpressurePolicy::DropNewest => {
+                    shared.dropped.fetch_add(1, Ordering::Relaxed);
+                    return Ok(());
+                }
+            }
+        }
+
+        queue.events.push_back(event.clone());
+        shared.not_empty.notify_one();
+        Ok(())
+    }
+
+    fn close(&self) -> Result<(), String> {
+        {
+            let mut queue = lock_queue(&self.shared.queue);
+            queue.closed = true;
+        }
+        self.shared.not_empty.notify_all();
+        self.shared.not_full.notify_all();
+
+        let worker = match self.worker.lock() {
+            Ok(mut guard) => guard.take(),
+            Err(poisoned) => poisoned.into_inner().take(),
+        };
+        match worker {
+            Some(worker) => worker
+                .join()
+                .map_err(|_| "event sink worker panicked".to_string())?,
+            None => Ok(()),
+        }
+    }
+}
+
+impl Drop for BufferedSink {
+    fn drop(&mut self) {
+        let _ = self.close();
+    }
+}
+
+fn lock_queue(queue: &Mutex<BufferQueue>) -> MutexGuard<'_, BufferQueue> {
+    match queue.lock() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    }
+}
+
+fn buffered_sink_worker(
+    shared: Arc<BufferShared>,
+    inner: Arc<dyn EventSink>,
+) -> Result<(), String> {
+    loop {
+        let event = {
+            let mut queue = lock_queue(&shared.queue);
+            while queue.events.is_empty() && !queue.closed {
+                queue = match shared.not_empty.wait(queue) {
+                    Ok(guard) => guard,
+                    Err(poisoned) => poisoned.into_inner(),
+                };
+            }
+            match queue.events.pop_front() {
+                Some(event) => event,
+                None => break,
+            }
+        };
+        shared.not_full.notify_one();
+        if inner.emit(&event).is_err() {
+            shared.sink_errors.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+    inner.close()
+}
+
 #[derive(Debug)]
 pub struct SocketEventSink {
     inner: Mutex<SocketEventSinkInner>,
@@ -195,9 +446,56 @@ mod tests {
     use forge_db::{Config, Db};
     use tempfile::tempdir;
 
-    use super::{runner_event_type, DatabaseEventSink, EventSink, SocketEventSink};
+    use std::sync::{Arc, Mutex};
+
+    use super::{
+        runner_event_type, BufferedSink, DatabaseEventSink, EventSink, MultiSink, SocketEventSink,
+    };
+    use crate::config::BackpressurePolicy;
     use crate::runner::RunnerEvent;
 
+    #[derive(Default)]
+    struct RecordingSink {
+        events: Mutex<Vec<String>>,
+    }
+
+    impl EventSink for RecordingSink {
+        fn emit(&self, event: &RunnerEvent) -> Result<(), String> {
+            let mut events = self
+                .events
+                .lock()
+                .map_err(|_| "recording sink lock poisoned".to_string())?;
+            events.push(event.event_type.clone());
+            Ok(())
+        }
+
+        fn close(&self) -> Result<(), String> {
+            Ok(())
+        }
+    }
+
+    struct FailingSink;
+
+    impl EventSink for FailingSink {
+        fn emit(&self, _event: &RunnerEvent) -> Result<(), String> {
+            Err("disk full".to_string())
+        }
+
+        fn close(&self) -> Result<(), String> {
+            Err("already gone".to_string())
+        }
+    }
+
+    fn runner_event(event_type: &str) -> RunnerEvent {
+        RunnerEvent {
+            event_type: event_type.to_string(),
+            timestamp: "2026-01-01T00:00:00Z".to_string(),
+            workspace_id: String::new(),
+            agent_id: String::new(),
+            data: None,
+        }
+    }
+
     fn must<T, E: std::fmt::Display>(res: Result<T, E>) -> T {
         match res {
             Ok(value) => value,
@@ -213,6 +511,147 @@ mod tests {
         assert_eq!(runner_event_type(" runner.pause "), "runner.pause");
     }
 
+    #[test]
+    fn multi_sink_delivers_to_all_sinks_despite_failure() {
+        let first = Arc::new(RecordingSink::default());
+        let second = Arc::new(RecordingSink::default());
+        let sink = MultiSink::new(vec![first.clone(), Arc::new(FailingSink), second.clone()]);
+        assert_eq!(sink.len(), 3);
+
+        for event_type in ["start", "output_line", "stop"] {
+            let err = match sink.emit(&runner_event(event_type)) {
+                Ok(()) => panic!("expected aggregated error"),
+                Err(err) => err,
+            };
+            assert_eq!(err, "sink 1: disk full");
+        }
+
+        let want = vec![
+            "start".to_string(),
+            "output_line".to_string(),
+            "stop".to_string(),
+        ];
+        for recorded in [&first, &second] {
+            let events = match recorded.events.lock() {
+                Ok(guard) => guard.clone(),
+                Err(poisoned) => poisoned.into_inner().clone(),
+            };
+            assert_eq!(events, want);
+        }
+
+        let close_err = match sink.close() {
+            Ok(()) => panic!("expected close error"),
+            Err(err) => err,
+        };
+        assert_eq!(close_err, "sink 1: already gone");
+    }
+
+    #[test]
+    fn multi_sink_without_failures_is_ok() {
+        let mut sink = MultiSink::default();
+        assert!(sink.is_empty());
+        sink.push(Arc::new(RecordingSink::default()));
+        must(sink.emit(&runner_event("heartbeat")));
+        must(sink.close());
+    }
+
+    /// Blocks inside the first `emit` until released, so the producer can
+    /// overrun the buffer deterministically.
+    struct GatedSink {
+        recorded: RecordingSink,
+        entered: std::sync::mpsc::SyncSender<()>,
+        gate: Mutex<std::sync::mpsc::Receiver<()>>,
+    }
+
+    impl EventSink for GatedSink {
+        fn emit(&self, event: &RunnerEvent) -> Result<(), String> {
+            let _ = self.entered.try_send(());
+            if let Ok(gate) = self.gate.lock() {
+                let _ = gate.recv();
+            }
+            self.recorded.emit(event)
+        }
+
+        fn close(&self) -> Result<(), String> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn buffered_sink_drop_oldest_keeps_newest_events() {
+        let (entered_tx, entered_rx) = std::sync::mpsc::sync_channel(8);
+        let (gate_tx, gate_rx) = std::sync::mpsc::channel();
+        let slow = Arc::new(GatedSink {
+            recorded: RecordingSink::default(),
+            entered: entered_tx,
+            gate: Mutex::new(gate_rx),
+        });
+        let sink = BufferedSink::new(slow.clone(), 2, BackpressurePolicy::DropOldest);
+
+        must(sink.emit(&runner_event("e0")));
+        if entered_rx
+            .recv_timeout(std::time::Duration::from_secs(5))
+            .is_err()
+        {
+            panic!("worker never reached the slow sink");
+        }
+
+        for event_type in ["e1", "e2", "e3", "e4"] {
+            must(sink.emit(&runner_event(event_type)));
+        }
+        let stats = sink.stats();
+        assert_eq!(stats.engaged, 2);
+        assert_eq!(stats.dropped, 2);
+
+        for _ in 0..5 {
+            let _ = gate_tx.send(());
+        }
+        must(sink.close());
+
+        let events = match slow.recorded.events.lock() {
+            Ok(guard) => guard.clone(),
+            Err(poisoned) => poisoned.into_inner().clone(),
+        };
+        assert_eq!(
+            events,
+            vec!["e0".to_string(), "e3".to_string(), "e4".to_string()]
+        );
+    }
+
+    #[test]
+    fn buffered_sink_block_policy_delivers_everything() {
+        let recorded = Arc::new(RecordingSink::default());
+        let sink = BufferedSink::new(recorded.clone(), 1, BackpressurePolicy::Block);
+        for idx in 0..50 {
+            must(sink.emit(&runner_event(&format!("e{idx}"))));
+        }
+        must(sink.close());
+        assert_eq!(sink.stats().dropped, 0);
+
+        let events = match recorded.events.lock() {
+            Ok(guard) => guard.clone(),
+            Err(poisoned) => poisoned.into_inner().clone(),
+        };
+        let want: Vec<String> = (0..50).map(|idx| format!("e{idx}")).collect();
+        assert_eq!(events, want);
+        assert!(sink.emit(&runner_event("late")).is_err());
+    }
+
+    #[test]
+    fn buffered_sink_close_returns_wrapped_close_error() {
+        let sink = BufferedSink::new(Arc::new(FailingSink), 4, BackpressurePolicy::Block);
+        must(sink.emit(&runner_event("start")));
+
+        let err = match sink.close() {
+            Ok(()) => panic!("expected close error from the wrapped sink"),
+            Err(err) => err,
+        };
+        assert_eq!(err, "already gone");
+        assert_eq!(sink.stats().sink_errors, 1);
+        // The worker is gone after the first close; later closes are no-ops.
+        must(sink.close());
+    }
+
     #[test]
     fn socket_sink_requires_path() {
         let err = match SocketEventSink::connect("  ") {
@@ -2,6 +2,13 @@
 //!
 //! Focused parity slice for stop/kill/delete/resume flows from `internal/looptui/looptui.go`.
 
+use std::collections::VecDeque;
+
+use crate::log_anchors::LogAnchor;
+use crate::task_notes::LoopNote;
+
+pub const DEFAULT_UNDO_LIMIT: usize = 20;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum LoopState {
     Running,
@@ -340,14 +347,143 @@ pub fn action_status(kind: ActionKind) -> &'static str {
     }
 }
 
+/// Inverse of a reversible loop action; kill and delete cannot be undone.
+#[must_use]
+pub fn inverse_action(request: &ActionRequest) -> Option<ActionRequest> {
+    let kind = match request.kind {
+        ActionKind::Stop => ActionKind::Resume,
+        ActionKind::Resume => ActionKind::Stop,
+        ActionKind::Kill | ActionKind::Delete => return None,
+    };
+    Some(ActionRequest {
+        kind,
+        loop_id: request.loop_id.clone(),
+        force_delete: false,
+    })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UndoOperation {
+    /// Dispatch the inverse loop action.
+    Loop(ActionRequest),
+    RestoreNote(LoopNote),
+    RestoreBookmark(LogAnchor),
+}
+
+impl UndoOperation {
+    #[must_use]
+    pub fn loop_id(&self) -> &str {
+        match self {
+            Self::Loop(request) => &request.loop_id,
+            Self::RestoreNote(note) => &note.loop_id,
+            Self::RestoreBookmark(anchor) => &anchor.loop_id,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UndoEntry {
+    pub label: String,
+    pub operation: UndoOperation,
+}
+
+/// Bounded history of inverse operations, newest last.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UndoStack {
+    entries: VecDeque<UndoEntry>,
+    limit: usize,
+}
+
+impl Default for UndoStack {
+    fn default() -> Self {
+        Self::new(DEFAULT_UNDO_LIMIT)
+    }
+}
+
+impl UndoStack {
+    #[must_use]
+    pub fn new(limit: usize) -> Self {
+        Self {
+            entries: VecDeque::new(),
+            limit: limit.max(1),
+        }
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    #[must_use]
+    pub fn peek(&self) -> Option<&UndoEntry> {
+        self.entries.back()
+    }
+
+    pub fn push(&mut self, entry: UndoEntry) {
+        self.entries.push_back(entry);
+        while self.entries.len() > self.limit {
+            self.entries.pop_front();
+        }
+    }
+
+    pub fn pop(&mut self) -> Option<UndoEntry> {
+        self.entries.pop_back()
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Record an executed loop action. Reversible actions push their inverse;
+    /// irreversible ones drop pending undo entries for that loop, since those
+    /// no longer apply. Returns whether an entry was pushed.
+    pub fn record_action(&mut self, request: &ActionRequest) -> bool {
+        let Some(inverse) = inverse_action(request) else {
+            let loop_id = request.loop_id.as_str();
+            self.entries
+                .retain(|entry| entry.operation.loop_id() != loop_id);
+            return false;
+        };
+        let verb = match request.kind {
+            ActionKind::Stop => "stop",
+            _ => "resume",
+        };
+        self.push(UndoEntry {
+            label: format!("{verb} {}", loop_display_id(&request.loop_id, "")),
+            operation: UndoOperation::Loop(inverse),
+        });
+        true
+    }
+
+    pub fn record_note_delete(&mut self, note: LoopNote) {
+        self.push(UndoEntry {
+            label: format!("note delete {}", loop_display_id(&note.loop_id, "")),
+            operation: UndoOperation::RestoreNote(note),
+        });
+    }
+
+    pub fn record_bookmark_delete(&mut self, anchor: LogAnchor) {
+        self.push(UndoEntry {
+            label: format!("bookmark delete {}", anchor.marker),
+            operation: UndoOperation::RestoreBookmark(anchor),
+        });
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::expect_used)]
 
     use super::{
         action_status, confirm_to_request, enter_confirm, evaluate_action_guardrail,
-        handle_confirm_input, loop_display_id, ActionKind, ActionTarget, ConfirmInputResult,
-        GuardrailDecision, GuardrailPolicy, LoopState, PolicyOverride,
+        handle_confirm_input, loop_display_id, ActionKind, ActionRequest, ActionTarget,
+        ConfirmInputResult, GuardrailDecision, GuardrailPolicy, LoopState, PolicyOverride,
+        UndoOperation, UndoStack,
     };
 
     #[test]
@@ -681,4 +817,49 @@ mod tests {
         };
         assert!(reason.contains("override denied"));
     }
+
+    #[test]
+    fn undo_stack_inverts_stop_and_caps_history() {
+        let mut stack = UndoStack::new(3);
+        let stop = ActionRequest {
+            kind: ActionKind::Stop,
+            loop_id: "loop-a".to_owned(),
+            force_delete: false,
+        };
+        assert!(stack.record_action(&stop));
+        let entry = stack.pop().expect("undo entry");
+        assert_eq!(entry.label, "stop loop-a");
+        assert_eq!(
+            entry.operation,
+            UndoOperation::Loop(ActionRequest {
+                kind: ActionKind::Resume,
+                loop_id: "loop-a".to_owned(),
+                force_delete: false,
+            })
+        );
+
+        for index in 0..5 {
+            stack.record_action(&ActionRequest {
+                kind: ActionKind::Resume,
+                loop_id: format!("loop-{index}"),
+                force_delete: false,
+            });
+        }
+        assert_eq!(stack.len(), 3);
+        assert_eq!(
+            stack.peek().map(|entry| entry.label.as_str()),
+            Some("resume loop-4")
+        );
+
+        assert!(!stack.record_action(&ActionRequest {
+            kind: ActionKind::Kill,
+            loop_id: "loop-4".to_owned(),
+            force_delete: false,
+        }));
+        assert_eq!(stack.len(), 2);
+        assert_eq!(
+            stack.peek().map(|entry| entry.label.as_str()),
+            Some("resume loop-3")
+        );
+    }
 }
@@ -2,6 +2,8 @@
 
 use std::collections::BTreeSet;
 
+use forge_core::event::EventType;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum ActivityKind {
     Claim,
@@ -39,17 +41,133 @@ impl ActivityKind {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum ActivitySeverity {
+    #[default]
+    Info,
+    Warning,
+    Danger,
+}
+
+impl ActivitySeverity {
+    #[must_use]
+    pub fn slug(self) -> &'static str {
+        match self {
+            Self::Info => "info",
+            Self::Warning => "warning",
+            Self::Danger => "danger",
+        }
+    }
+
+    /// Default severity for a forge-core event type.
+    #[must_use]
+    pub fn for_event_type(event_type: EventType) -> Self {
+        match event_type {
+            EventType::Error => Self::Danger,
+            EventType::Warning
+            | EventType::RateLimitHit
+            | EventType::ApprovalExpired
+            | EventType::AccountCooldown => Self::Warning,
+            _ => Self::Info,
+        }
+    }
+
+    fn raised(self) -> Self {
+        match self {
+            Self::Info => Self::Warning,
+            Self::Warning | Self::Danger => Self::Danger,
+        }
+    }
+
+    fn lowered(self) -> Self {
+        match self {
+            Self::Danger => Self::Warning,
+            Self::Warning | Self::Info => Self::Info,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ActivityEvent {
     pub event_id: String,
     pub timestamp_epoch_s: i64,
     pub kind: ActivityKind,
+    pub event_type: Option<EventType>,
+    pub severity: ActivitySeverity,
     pub summary: String,
     pub agent_id: Option<String>,
     pub repo: Option<String>,
     pub task_id: Option<String>,
 }
 
+/// Stream-level visibility filter, applied as events arrive.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ActivityStreamFilter {
+    pub event_types: Vec<EventType>,
+    pub min_severity: ActivitySeverity,
+    pub errors_only: bool,
+}
+
+impl ActivityStreamFilter {
+    #[must_use]
+    pub fn effective_min_severity(&self) -> ActivitySeverity {
+        if self.errors_only {
+            ActivitySeverity::Danger
+        } else {
+            self.min_severity
+        }
+    }
+
+    #[must_use]
+    pub fn allows(&self, event: &ActivityEvent) -> bool {
+        if event.severity < self.effective_min_severity() {
+            return false;
+        }
+        self.event_types.is_empty()
+            || event
+                .event_type
+                .is_some_and(|event_type| self.event_types.contains(&event_type))
+    }
+
+    #[must_use]
+    pub fn is_active(&self) -> bool {
+        self.errors_only
+            || self.min_severity != ActivitySeverity::Info
+            || !self.event_types.is_empty()
+    }
+
+    #[must_use]
+    pub fn header(&self) -> String {
+        if !self.is_active() {
+            return "filters: none".to_owned();
+        }
+        let mut parts = Vec::new();
+        if self.errors_only {
+            parts.push("errors-only".to_owned());
+        } else if self.min_severity != ActivitySeverity::Info {
+            parts.push(format!("severity>={}", self.min_severity.slug()));
+        }
+        if !self.event_types.is_empty() {
+            let types = self
+                .event_types
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>();
+            parts.push(format!("types:{}", types.join(",")));
+        }
+        format!("filters: {}", parts.join(" "))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActivityFilterAction {
+    ToggleErrorsOnly,
+    ToggleEventType(EventType),
+    RaiseSeverityFloor,
+    LowerSeverityFloor,
+    ClearStreamFilters,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct ActivityFilter {
     pub agent_ids: Vec<String>,
@@ -89,6 +207,7 @@ pub struct ActivitySnapshot {
 pub struct ActivityStream {
     max_events: usize,
     events: Vec<ActivityEvent>,
+    stream_filter: ActivityStreamFilter,
 }
 
 impl ActivityStream {
@@ -97,9 +216,12 @@ impl ActivityStream {
         Self {
             max_events: max_events.max(1),
             events: Vec::new(),
+            stream_filter: ActivityStreamFilter::default(),
         }
     }
 
+    /// Buffer an event. Visible and filtered-out events are capped separately
+    /// so hidden noise never evicts events the operator can see.
     pub fn push(&mut self, event: ActivityEvent) -> Result<(), String> {
         let normalized = normalize_event(event)?;
         self.events.push(normalized);
@@ -108,7 +230,7 @@ impl ActivityStream {
                 .cmp(&a.timestamp_epoch_s)
                 .then(a.event_id.cmp(&b.event_id))
         });
-        self.events.truncate(self.max_events);
+        self.enforce_capacity();
         Ok(())
     }
 
@@ -117,9 +239,52 @@ impl ActivityStream {
         &self.events
     }
 
+    #[must_use]
+    pub fn visible_events(&self) -> Vec<ActivityEvent> {
+        self.events
+            .iter()
+            .filter(|event| self.stream_filter.allows(event))
+            .cloned()
+            .collect()
+    }
+
+    #[must_use]
+    pub fn stream_filter(&self) -> &ActivityStreamFilter {
+        &self.stream_filter
+    }
+
+    #[must_use]
+    pub fn filter_header(&self) -> String {
+        self.stream_filter.header()
+    }
+
+    pub fn apply(&mut self, action: ActivityFilterAction) {
+        let filter = &mut self.stream_filter;
+        match action {
+            ActivityFilterAction::ToggleErrorsOnly => filter.errors_only = !filter.errors_only,
+            ActivityFilterAction::ToggleEventType(event_type) => {
+                if let Some(index) = filter.event_types.iter().position(|t| *t == event_type) {
+                    filter.event_types.remove(index);
+                } else {
+                    filter.event_types.push(event_type);
+                }
+            }
+            ActivityFilterAction::RaiseSeverityFloor => {
+                filter.min_severity = filter.min_severity.raised();
+            }
+            ActivityFilterAction::LowerSeverityFloor => {
+                filter.errors_only = false;
+                filter.min_severity = filter.min_severity.lowered();
+            }
+            ActivityFilterAction::ClearStreamFilters => {
+                *filter = ActivityStreamFilter::default();
+            }
+        }
+    }
+
     #[must_use]
     pub fn snapshot(&self, filter: &ActivityFilter, limit: usize) -> ActivitySnapshot {
-        build_snapshot(&self.events, filter, limit)
+        build_snapshot(&self.visible_events(), filter, limit)
     }
 
     #[must_use]
@@ -130,13 +295,28 @@ impl ActivityStream {
         limit: usize,
     ) -> ActivitySnapshot {
         let filtered = self
-            .events
-            .iter()
+            .visible_events()
+            .into_iter()
             .filter(|event| event.timestamp_epoch_s > last_seen_epoch_s)
-            .cloned()
             .collect::<Vec<_>>();
         build_snapshot(&filtered, filter, limit)
     }
+
+    fn enforce_capacity(&mut self) {
+        let mut visible = 0usize;
+        let mut hidden = 0usize;
+        let max_events = self.max_events;
+        let stream_filter = &self.stream_filter;
+        self.events.retain(|event| {
+            let counter = if stream_filter.allows(event) {
+                &mut visible
+            } else {
+                &mut hidden
+            };
+            *counter += 1;
+            *counter <= max_events
+        });
+    }
 }
 
 fn build_snapshot(
@@ -352,7 +532,12 @@ fn normalize_set(values: &[String]) -> BTreeSet<String> {
 
 #[cfg(test)]
 mod tests {
-    use super::{ActivityEvent, ActivityFilter, ActivityKind, ActivityStream};
+    use forge_core::event::EventType;
+
+    use super::{
+        ActivityEvent, ActivityFilter, ActivityFilterAction, ActivityKind, ActivitySeverity,
+        ActivityStream,
+    };
 
     fn sample_event(
         event_id: &str,
@@ -367,6 +552,8 @@ mod tests {
             event_id: event_id.to_owned(),
             timestamp_epoch_s,
             kind,
+            event_type: None,
+            severity: ActivitySeverity::Info,
             summary: summary.to_owned(),
             agent_id: agent_id.map(str::to_owned),
             repo: repo.map(str::to_owned),
@@ -566,4 +753,107 @@ mod tests {
             .collect::<Vec<_>>();
         assert_eq!(ids, vec!["evt-a", "evt-b"]);
     }
+
+    fn typed_event(event_id: &str, timestamp_epoch_s: i64, event_type: EventType) -> ActivityEvent {
+        ActivityEvent {
+            event_type: Some(event_type),
+            severity: ActivitySeverity::for_event_type(event_type),
+            ..sample_event(
+                event_id,
+                timestamp_epoch_s,
+                ActivityKind::System,
+                None,
+                None,
+                None,
+                &format!("{event_type} event"),
+            )
+        }
+    }
+
+    #[test]
+    fn errors_only_hides_info_events_and_shows_danger_ones() {
+        let mut stream = ActivityStream::new(10);
+        for event in [
+            typed_event("e1", 10, EventType::AgentStarted),
+            typed_event("e2", 20, EventType::Error),
+            typed_event("e3", 30, EventType::LoopStateChanged),
+        ] {
+            if let Err(err) = stream.push(event) {
+                panic!("push failed: {err}");
+            }
+        }
+        assert_eq!(stream.filter_header(), "filters: none");
+
+        stream.apply(ActivityFilterAction::ToggleErrorsOnly);
+        let snapshot = stream.snapshot(&ActivityFilter::default(), 10);
+        let ids = snapshot
+            .rows
+            .iter()
+            .map(|row| row.event_id.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(ids, vec!["e2"]);
+        assert_eq!(stream.filter_header(), "filters: errors-only");
+
+        stream.apply(ActivityFilterAction::ToggleErrorsOnly);
+        assert_eq!(
+            stream.snapshot(&ActivityFilter::default(), 10).rows.len(),
+            3
+        );
+    }
+
+    #[test]
+    fn event_type_and_severity_floor_filters_combine_in_header() {
+        let mut stream = ActivityStream::new(10);
+        for event in [
+            typed_event("e1", 10, EventType::RateLimitHit),
+            typed_event("e2", 20, EventType::Warning),
+            typed_event("e3", 30, EventType::AgentStopped),
+        ] {
+            if let Err(err) = stream.push(event) {
+                panic!("push failed: {err}");
+            }
+        }
+
+        stream.apply(ActivityFilterAction::RaiseSeverityFloor);
+        stream.apply(ActivityFilterAction::ToggleEventType(
+            EventType::RateLimitHit,
+        ));
+        let snapshot = stream.snapshot(&ActivityFilter::default(), 10);
+        assert_eq!(snapshot.rows.len(), 1);
+        assert_eq!(snapshot.rows[0].event_id, "e1");
+        assert_eq!(
+            stream.filter_header(),
+            "filters: severity>=warning types:rate_limit.hit"
+        );
+
+        stream.apply(ActivityFilterAction::ClearStreamFilters);
+        assert_eq!(stream.filter_header(), "filters: none");
+    }
+
+    #[test]
+    fn filtered_out_events_do_not_evict_visible_events() {
+        let mut stream = ActivityStream::new(2);
+        stream.apply(ActivityFilterAction::ToggleErrorsOnly);
+        for (index, event) in [
+            typed_event("err-1", 1, EventType::Error),
+            typed_event("err-2", 2, EventType::Error),
+            typed_event("info-1", 3, EventType::AgentStarted),
+            typed_event("info-2", 4, EventType::AgentStarted),
+            typed_event("info-3", 5, EventType::AgentStarted),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            if let Err(err) = stream.push(event) {
+                panic!("push {index} failed: {err}");
+            }
+        }
+
+        let visible = stream.visible_events();
+        assert_eq!(visible.len(), 2);
+        assert!(visible
+            .iter()
+            .all(|event| event.severity == ActivitySeverity::Danger));
+        assert_eq!(stream.events().len(), 4);
+    }
 }
@@ -1,5 +1,7 @@
 //! Throughput, cycle-time, queue-aging, and completion-velocity dashboard model.
 
+use crate::analytics_fact_model::FactRow;
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ThroughputBucketSample {
     pub bucket_label: String,
@@ -241,6 +243,25 @@ pub fn build_analytics_dashboard(input: &DashboardInput) -> AnalyticsDashboardVi
     }
 }
 
+/// Chart rows returned by `UnifiedFactModel::query`.
+#[must_use]
+pub fn fact_query_chart(rows: &[FactRow], bar_width: usize) -> Vec<ChartPoint> {
+    let values: Vec<usize> = rows
+        .iter()
+        .map(|row| row.value.max(0.0).round() as usize)
+        .collect();
+    let peak = values.iter().copied().max().unwrap_or(0);
+    rows.iter()
+        .zip(values)
+        .map(|(row, value)| ChartPoint {
+            label: row.key.clone(),
+            value,
+            bar: ascii_bar(value, peak, bar_width),
+            detail: format!("value={:.1} samples={}", row.value, row.samples),
+        })
+        .collect()
+}
+
 fn completion_velocity_chart(
     tasks: &[TaskLifecycleSample],
     now_epoch_s: i64,
@@ -327,8 +348,10 @@ fn ascii_sparkline(values: &[usize]) -> String {
 #[cfg(test)]
 mod tests {
     use super::{
-        build_analytics_dashboard, DashboardInput, TaskLifecycleSample, ThroughputBucketSample,
+        build_analytics_dashboard, fact_query_chart, DashboardInput, TaskLifecycleSample,
+        ThroughputBucketSample,
     };
+    use crate::analytics_fact_model::FactRow;
 
     #[test]
     fn throughput_chart_and_summary_are_derived() {
@@ -472,4 +495,25 @@ mod tests {
         assert_eq!(view.summary.completion_velocity.peak_per_hour, 1);
         assert_eq!(view.summary.completion_velocity.sparkline.len(), 3);
     }
+
+    #[test]
+    fn fact_query_chart_scales_bars_to_peak() {
+        let rows = vec![
+            FactRow {
+                key: "done".to_owned(),
+                value: 4.0,
+                samples: 4,
+            },
+            FactRow {
+                key: "queued".to_owned(),
+                value: 2.0,
+                samples: 2,
+            },
+        ];
+        let chart = fact_query_chart(&rows, 4);
+        assert_eq!(chart[0].label, "done");
+        assert_eq!(chart[0].bar, "####");
+        assert_eq!(chart[1].bar, "##--");
+        assert_eq!(chart[1].detail, "value=2.0 samples=2");
+    }
 }
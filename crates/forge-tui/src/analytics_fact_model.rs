@@ -15,6 +15,9 @@ pub struct TaskSourceRecord {
     pub loop_id: String,
     pub status: String,
     pub assignee_agent_id: Option<String>,
+    pub topic: Option<String>,
+    pub updated_at_epoch_s: Option<i64>,
+    pub duration_secs: Option<u64>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -52,6 +55,9 @@ pub struct TaskFact {
     pub loop_id: String,
     pub status: String,
     pub assignee_agent_id: Option<String>,
+    pub topic: Option<String>,
+    pub updated_at_epoch_s: Option<i64>,
+    pub duration_secs: Option<u64>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -129,6 +135,9 @@ pub fn build_unified_fact_model(source: &SourceRepositories) -> UnifiedFactModel
             loop_id: row.loop_id.trim().to_owned(),
             status: normalize_status(&row.status),
             assignee_agent_id: normalize_optional(row.assignee_agent_id.as_deref()),
+            topic: normalize_optional(row.topic.as_deref()),
+            updated_at_epoch_s: row.updated_at_epoch_s,
+            duration_secs: row.duration_secs,
         })
         .collect();
     tasks.sort_by(|a, b| a.loop_id.cmp(&b.loop_id).then(a.task_id.cmp(&b.task_id)));
@@ -229,6 +238,156 @@ pub fn build_unified_fact_model(source: &SourceRepositories) -> UnifiedFactModel
     }
 }
 
+/// Dimension task facts can be grouped by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FactDimension {
+    Agent,
+    Topic,
+    Status,
+    Loop,
+}
+
+impl FactDimension {
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "agent" => Ok(Self::Agent),
+            "topic" => Ok(Self::Topic),
+            "status" => Ok(Self::Status),
+            "loop" => Ok(Self::Loop),
+            other => Err(format!(
+                "unknown dimension {other:?} (expected agent, topic, status, or loop)"
+            )),
+        }
+    }
+}
+
+/// Measure computed per group. `Sum` and `Avg` aggregate `duration_secs`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FactMeasure {
+    Count,
+    Sum,
+    Avg,
+}
+
+impl FactMeasure {
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "count" => Ok(Self::Count),
+            "sum" => Ok(Self::Sum),
+            "avg" => Ok(Self::Avg),
+            other => Err(format!(
+                "unknown measure {other:?} (expected count, sum, or avg)"
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct FactFilter {
+    pub loop_id: Option<String>,
+    pub status: Option<String>,
+    pub agent: Option<String>,
+    pub topic: Option<String>,
+}
+
+/// Half-open `[start, end)` window on `updated_at_epoch_s`; facts without a
+/// timestamp fall outside every window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FactWindow {
+    pub start_epoch_s: i64,
+    pub end_epoch_s: i64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FactRow {
+    pub key: String,
+    pub value: f64,
+    /// Facts in the group (for `Count`) or facts with a duration (`Sum`/`Avg`).
+    pub samples: usize,
+}
+
+const NO_VALUE_KEY: &str = "(none)";
+
+impl UnifiedFactModel {
+    /// Group task facts by `dimension` and compute `measure`, validating both
+    /// names. Rows are sorted by key.
+    pub fn query(
+        &self,
+        dimension: &str,
+        measure: &str,
+        filter: &FactFilter,
+        window: Option<FactWindow>,
+    ) -> Result<Vec<FactRow>, String> {
+        let dimension = FactDimension::parse(dimension)?;
+        let measure = FactMeasure::parse(measure)?;
+        Ok(self.query_typed(dimension, measure, filter, window))
+    }
+
+    #[must_use]
+    pub fn query_typed(
+        &self,
+        dimension: FactDimension,
+        measure: FactMeasure,
+        filter: &FactFilter,
+        window: Option<FactWindow>,
+    ) -> Vec<FactRow> {
+        let mut groups: BTreeMap<String, (usize, Vec<u64>)> = BTreeMap::new();
+        for task in self
+            .tasks
+            .iter()
+            .filter(|task| task_matches(task, filter, window))
+        {
+            let key = match dimension {
+                FactDimension::Agent => task.assignee_agent_id.clone(),
+                FactDimension::Topic => task.topic.clone(),
+                FactDimension::Status => Some(task.status.clone()),
+                FactDimension::Loop => Some(task.loop_id.clone()),
+            }
+            .unwrap_or_else(|| NO_VALUE_KEY.to_owned());
+            let group = groups.entry(key).or_default();
+            group.0 += 1;
+            group.1.extend(task.duration_secs);
+        }
+
+        groups
+            .into_iter()
+            .map(|(key, (count, durations))| {
+                let sum: u64 = durations.iter().sum();
+                let (value, samples) = match measure {
+                    FactMeasure::Count => (count as f64, count),
+                    FactMeasure::Sum => (sum as f64, durations.len()),
+                    FactMeasure::Avg if durations.is_empty() => (0.0, 0),
+                    FactMeasure::Avg => (sum as f64 / durations.len() as f64, durations.len()),
+                };
+                FactRow {
+                    key,
+                    value,
+                    samples,
+                }
+            })
+            .collect()
+    }
+}
+
+fn task_matches(task: &TaskFact, filter: &FactFilter, window: Option<FactWindow>) -> bool {
+    let field_matches = |expected: &Option<String>, actual: Option<&str>| {
+        expected.as_deref().map_or(true, |expected| {
+            actual.is_some_and(|actual| actual.eq_ignore_ascii_case(expected.trim()))
+        })
+    };
+    if !field_matches(&filter.loop_id, Some(&task.loop_id))
+        || !field_matches(&filter.status, Some(&task.status))
+        || !field_matches(&filter.agent, task.assignee_agent_id.as_deref())
+        || !field_matches(&filter.topic, task.topic.as_deref())
+    {
+        return false;
+    }
+    window.map_or(true, |window| {
+        task.updated_at_epoch_s
+            .is_some_and(|at| (window.start_epoch_s..window.end_epoch_s).contains(&at))
+    })
+}
+
 #[must_use]
 pub fn consistency_checks_against_sources(
     source: &SourceRepositories,
@@ -406,7 +565,8 @@ fn is_active_agent_state(state: &str) -> bool {
 mod tests {
     use super::{
         build_unified_fact_model, consistency_checks_against_sources, AgentSourceRecord,
-        QueueSourceRecord, RunSourceRecord, SourceRepositories, TaskSourceRecord,
+        FactFilter, FactRow, FactWindow, QueueSourceRecord, RunSourceRecord, SourceRepositories,
+        TaskSourceRecord,
     };
 
     fn aligned_sources() -> SourceRepositories {
@@ -422,12 +582,18 @@ mod tests {
                     loop_id: "loop-a".to_owned(),
                     status: "queued".to_owned(),
                     assignee_agent_id: Some("agent-a".to_owned()),
+                    topic: None,
+                    updated_at_epoch_s: None,
+                    duration_secs: None,
                 },
                 TaskSourceRecord {
                     task_id: "task-2".to_owned(),
                     loop_id: "loop-a".to_owned(),
                     status: "running".to_owned(),
                     assignee_agent_id: Some("agent-a".to_owned()),
+                    topic: None,
+                    updated_at_epoch_s: None,
+                    duration_secs: None,
                 },
             ],
             queues: vec![QueueSourceRecord {
@@ -483,6 +649,9 @@ mod tests {
                 loop_id: "loop-a".to_owned(),
                 status: "queued".to_owned(),
                 assignee_agent_id: Some("agent-missing".to_owned()),
+                topic: None,
+                updated_at_epoch_s: None,
+                duration_secs: None,
             }],
             queues: vec![QueueSourceRecord {
                 loop_id: "loop-a".to_owned(),
@@ -513,4 +682,104 @@ mod tests {
             .iter()
             .any(|issue| issue.detail.contains("unknown loop loop-orphan-agent")));
     }
+
+    fn timed_task(
+        task_id: &str,
+        status: &str,
+        agent: Option<&str>,
+        updated_at_epoch_s: i64,
+        duration_secs: Option<u64>,
+    ) -> TaskSourceRecord {
+        TaskSourceRecord {
+            task_id: task_id.to_owned(),
+            loop_id: "loop-a".to_owned(),
+            status: status.to_owned(),
+            assignee_agent_id: agent.map(str::to_owned),
+            topic: Some("release".to_owned()),
+            updated_at_epoch_s: Some(updated_at_epoch_s),
+            duration_secs,
+        }
+    }
+
+    fn timed_model() -> super::UnifiedFactModel {
+        build_unified_fact_model(&SourceRepositories {
+            tasks: vec![
+                timed_task("t-1", "done", Some("agent-a"), 100, Some(60)),
+                timed_task("t-2", "Done", Some("agent-b"), 200, Some(120)),
+                timed_task("t-3", "running", Some("agent-a"), 300, None),
+                timed_task("t-4", "queued", None, 400, None),
+                timed_task("t-5", "done", Some("agent-a"), 5_000, Some(30)),
+            ],
+            ..SourceRepositories::default()
+        })
+    }
+
+    #[test]
+    fn query_groups_by_status_with_count_measure() {
+        let model = timed_model();
+        let window = FactWindow {
+            start_epoch_s: 0,
+            end_epoch_s: 1_000,
+        };
+        let rows = model
+            .query("status", "count", &FactFilter::default(), Some(window))
+            .unwrap_or_else(|err| panic!("query: {err}"));
+        assert_eq!(
+            rows,
+            vec![
+                FactRow {
+                    key: "done".to_owned(),
+                    value: 2.0,
+                    samples: 2,
+                },
+                FactRow {
+                    key: "queued".to_owned(),
+                    value: 1.0,
+                    samples: 1,
+                },
+                FactRow {
+                    key: "running".to_owned(),
+                    value: 1.0,
+                    samples: 1,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn query_averages_durations_per_agent_with_filter() {
+        let model = timed_model();
+        let filter = FactFilter {
+            status: Some("done".to_owned()),
+            ..FactFilter::default()
+        };
+        let rows = model
+            .query("agent", "avg", &filter, None)
+            .unwrap_or_else(|err| panic!("query: {err}"));
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].key, "agent-a");
+        assert!((rows[0].value - 45.0).abs() < f64::EPSILON);
+        assert_eq!(rows[1].key, "agent-b");
+        assert!((rows[1].value - 120.0).abs() < f64::EPSILON);
+
+        let unassigned = model
+            .query("agent", "count", &FactFilter::default(), None)
+            .unwrap_or_else(|err| panic!("query: {err}"));
+        assert_eq!(unassigned[0].key, "(none)");
+    }
+
+    #[test]
+    fn query_rejects_unknown_dimension_and_measure() {
+        let model = timed_model();
+        let err = model
+            .query("color", "count", &FactFilter::default(), None)
+            .err()
+            .unwrap_or_default();
+        assert!(err.contains("unknown dimension \"color\""));
+        let err = model
+            .query("status", "median", &FactFilter::default(), None)
+            .err()
+            .unwrap_or_default();
+        assert!(err.contains("unknown measure \"median\""));
+    }
 }
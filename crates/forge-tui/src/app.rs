@@ -8,6 +8,7 @@ use std::collections::{HashMap, HashSet, VecDeque};
 use std::io::Write;
 
 use forge_cli::logs::{render_lines_for_layer, LogRenderLayer};
+use forge_core::event::EventType;
 use forge_ftui_adapter::input::{
     InputEvent, Key, KeyEvent, MouseButton, MouseEvent, MouseEventKind, MouseWheelDirection,
 };
@@ -15,18 +16,53 @@ use forge_ftui_adapter::render::{CellStyle, FrameSize, Rect, RenderFrame, Styled
 use forge_ftui_adapter::style::ThemeSpec;
 use forge_ftui_adapter::widgets::BorderStyle;
 
+use crate::actions::{
+    ActionKind as LoopActionKind, ActionRequest as LoopActionRequest, UndoEntry, UndoOperation,
+    UndoStack,
+};
 use crate::adaptive_hints::{AdaptiveHintRanker, HintSpec};
 use crate::command_palette::{
     CommandPalette, PaletteActionId, PaletteContext, DEFAULT_SEARCH_BUDGET,
 };
-use crate::keymap::{KeyChord, KeyCommand, KeyScope, Keymap, ModeScope};
+use crate::daily_summary::{
+    build_daily_summary_artifact, export_daily_summary_markdown, utc_date_from_epoch_s,
+    DailySummaryArtifact, DailySummaryEntry, DailySummaryInput, DailySummaryPolicy,
+    DailySummaryUsageRow,
+};
+use crate::help_overlay::keymap_help_lines;
+use crate::keymap::{
+    read_keymap_config, KeyChord, KeyCommand, KeyScope, Keymap, KeymapLoadReport, ModeScope,
+};
+use crate::lane_model::{classify_lines, LaneOrder, LogLane};
 use crate::layouts::{
     fit_pane_layout_for_breakpoint, layout_cell_size, layout_index_for, normalize_layout_index,
-    PaneLayout, PANE_LAYOUTS,
+    responsive_dashboard_layout, PaneLayout, ResponsiveLayout, ResponsiveThresholds, PANE_LAYOUTS,
 };
 use crate::link_registry::{LinkRegistry, LinkTarget};
+use crate::log_anchors::{
+    add_log_anchor, remove_log_anchor, restore_log_anchor, LogAnchorDraft, LogAnchorStore,
+};
+use crate::log_query::{
+    load_log_query_book_file, save_log_query_book_file, user_log_query_book_path, LogQueryEditor,
+    QueryError,
+};
 use crate::log_source_abstraction::{LogContentKind, LogSourceRoute, LogTransportKind};
+use crate::logs_tab::{LevelFold, LogLevel};
+use crate::overview_tab::{
+    overview_dashboard_panels, overview_event_is_relevant, overview_refresh_controller,
+    OVERVIEW_NEXT_ACTION_PANEL,
+};
+use crate::performance_gates::{
+    format_gate_result_lines, gate_elapsed_ms, gate_results_to_doctor_checks,
+    restore_runtime_budgets, user_runtime_budgets_path, GateResult, RuntimeBudgets, RuntimeGate,
+    RuntimeGateMonitor,
+};
+use crate::polling_pipeline::{PollingConfig, RefreshController, RefreshMode};
 use crate::search_overlay::SearchOverlay;
+use crate::split_view::{
+    default_split_partner, PaneScroll, SplitLayout, SplitOrientation, SplitPane,
+};
+use crate::task_notes::{LoopNote, LoopNotes};
 use crate::theme::{
     cycle_accessibility_preset, cycle_palette, resolve_palette_colors,
     resolve_palette_for_capability, Palette, ResolvedPalette, TerminalColorCapability,
@@ -49,6 +85,59 @@ const MAX_NAV_HISTORY: usize = 32;
 const DESTRUCTIVE_CONFIRM_REASON_MIN_CHARS: usize = 12;
 const MAX_DESTRUCTIVE_CONFIRM_REASON_CHARS: usize = 160;
 
+/// Help hints for keys handled outside the keymap; everything else in the
+/// help overlay is generated from the live bindings.
+const UNMAPPED_HELP_HINTS: &[(&str, &[&str])] = &[
+    (
+        "Navigation",
+        &[
+            "b         backtrack last deep-link jump",
+            "Ctrl+O    activate primary link (run/loop/url fallback)",
+            "tab/shift+tab focus next/prev pane (wrap)",
+            "left/right   directional pane focus traversal",
+        ],
+    ),
+    (
+        "Actions",
+        &[
+            "R         regex log search (logs/runs; j/k jump matches)",
+            "confirm rail: tab/left/right choose action, enter selects (safe default=cancel)",
+            "X         take over stale loop (claim and restart; confirm with reason)",
+            "high-risk confirm (kill/force-delete/takeover): type reason (12+ chars)",
+            "Ctrl+Y    copy context (run id/log line/thread content)",
+        ],
+    ),
+    (
+        "Multi Logs",
+        &[
+            "C         toggle side-by-side compare mode",
+            "ctrl+u/d  shared compare scroll (when compare enabled)",
+        ],
+    ),
+    (
+        "Inbox",
+        &[
+            "f         cycle inbox filter (all/unread/ack-required)",
+            "enter     mark selected thread read",
+            "a         ack latest pending message in thread",
+            "h         generate handoff snapshot package",
+            "r         quick reply shortcut (thread + reply-to id)",
+            "o         next claim conflict",
+            "O         show conflict resolution hint",
+        ],
+    ),
+    (
+        "Display",
+        &[
+            "A         cycle accessibility quick modes (contrast/typography/motion)",
+            "Z         deep focus mode (distraction-minimized)",
+            "M         cycle density (comfortable/compact)",
+            "i         dismiss first-run contextual hints for current tab",
+            "I         recall first-run contextual hints for current tab",
+        ],
+    ),
+];
+
 pub const FILTER_STATUS_OPTIONS: &[&str] =
     &["all", "running", "sleeping", "waiting", "stopped", "error"];
 
@@ -278,6 +367,7 @@ pub enum ActionType {
     Delete,
     Resume,
     Create,
+    Takeover,
 }
 
 // ---------------------------------------------------------------------------
@@ -359,6 +449,8 @@ pub struct LoopView {
     pub max_runtime_seconds: i64,
     pub max_iterations: i64,
     pub last_error: String,
+    /// Reason given to the latest `forge stop`, shown while the loop is stopped.
+    pub stop_cause: String,
     pub profile_name: String,
     pub profile_harness: String,
     pub profile_auth: String,
@@ -383,6 +475,10 @@ pub struct RunView {
     pub started_at: String,
     /// Parsed output tail lines (newest window), used by Runs sticky output pane.
     pub output_lines: Vec<String>,
+    /// Harness attempts made within the run, when reported.
+    pub attempts: Option<u32>,
+    /// Reported spend for the run in USD, when known.
+    pub cost_usd: Option<f64>,
 }
 
 /// Tail view of log content. Matches Go's `logTailView`.
@@ -611,11 +707,28 @@ impl Command {
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ActionKind {
-    Resume { loop_id: String },
-    Stop { loop_id: String },
-    Kill { loop_id: String },
-    Delete { loop_id: String, force: bool },
-    Create { wizard: Vec<(String, String)> },
+    Resume {
+        loop_id: String,
+    },
+    Stop {
+        loop_id: String,
+    },
+    Kill {
+        loop_id: String,
+    },
+    Delete {
+        loop_id: String,
+        force: bool,
+    },
+    Create {
+        wizard: Vec<(String, String)>,
+    },
+    /// Claim a stale loop with `stale_takeover::plan_loop_takeover` and
+    /// restart it through `stale_takeover::apply_loop_takeover`.
+    Takeover {
+        loop_id: String,
+        reason: String,
+    },
 }
 
 /// Result of an asynchronous action execution. Matches Go's `actionResultMsg`.
@@ -795,6 +908,10 @@ pub struct App {
     pub tab: MainTab,
     pub mode: UiMode,
     pub help_return: UiMode,
+    help_query: String,
+    runtime_gates: RuntimeGateMonitor,
+    refresh_requested_at: Option<std::time::Instant>,
+    refresh: RefreshController,
 
     // -- loop selection --
     loops: Vec<LoopView>,
@@ -806,10 +923,13 @@ pub struct App {
     // -- run selection --
     run_history: Vec<RunView>,
     selected_run: usize,
+    run_compare: crate::runs_tab::RunCompareSelection,
+    run_log_compare: bool,
 
     // -- log display --
     log_source: LogSource,
     log_layer: LogLayer,
+    log_level_fold: LevelFold,
     log_scroll: usize,
     pub log_lines: usize,
     follow_mode: bool,
@@ -817,6 +937,8 @@ pub struct App {
 
     // -- focus/layout --
     focus_right: bool,
+    split: Option<SplitLayout>,
+    dashboard_layout: ResponsiveLayout,
     density_mode: DensityMode,
     focus_mode: FocusMode,
     accessibility_quick_mode: AccessibilityQuickMode,
@@ -826,6 +948,14 @@ pub struct App {
     multi_compare_mode: bool,
     multi_logs: HashMap<String, LogTailView>,
     pinned: HashSet<String>,
+    loop_notes: LoopNotes,
+    log_anchors: LogAnchorStore,
+    lane_order: LaneOrder,
+    log_query: LogQueryEditor,
+    /// Where the query book is saved after each change; set by loading it.
+    log_query_book_path: Option<std::path::PathBuf>,
+    /// Where `W` writes the daily summary report.
+    daily_summary_dir: Option<std::path::PathBuf>,
     inbox_messages: Vec<InboxMessageView>,
     inbox_filter: InboxFilter,
     inbox_selected_thread: usize,
@@ -859,6 +989,8 @@ pub struct App {
     notification_queue: VecDeque<NotificationEvent>,
     notification_sequence: u64,
     action_busy: bool,
+    undo_stack: UndoStack,
+    undo_in_flight: Option<UndoEntry>,
 
     // -- display --
     width: usize,
@@ -898,10 +1030,14 @@ impl App {
             log_lines
         };
 
-        Self {
+        let mut app = Self {
             tab: MainTab::Overview,
             mode: UiMode::Main,
             help_return: UiMode::Main,
+            help_query: String::new(),
+            runtime_gates: RuntimeGateMonitor::default(),
+            refresh_requested_at: None,
+            refresh: overview_refresh_controller(RefreshMode::Interval, PollingConfig::default()),
 
             loops: Vec::new(),
             filtered: Vec::new(),
@@ -911,15 +1047,25 @@ impl App {
 
             run_history: Vec::new(),
             selected_run: 0,
+            run_compare: crate::runs_tab::RunCompareSelection::default(),
+            run_log_compare: false,
 
             log_source: LogSource::Live,
             log_layer: LogLayer::Raw,
+            log_level_fold: LevelFold::default(),
             log_scroll: 0,
             log_lines,
             follow_mode: true,
             prev_log_line_count: 0,
 
             focus_right: false,
+            split: None,
+            dashboard_layout: responsive_dashboard_layout(
+                &overview_dashboard_panels(),
+                120,
+                40,
+                ResponsiveThresholds::default(),
+            ),
             density_mode: DensityMode::Comfortable,
             focus_mode: FocusMode::Standard,
             accessibility_quick_mode: AccessibilityQuickMode::Contrast,
@@ -929,6 +1075,12 @@ impl App {
             multi_compare_mode: false,
             multi_logs: HashMap::new(),
             pinned: HashSet::new(),
+            loop_notes: LoopNotes::default(),
+            log_anchors: LogAnchorStore::default(),
+            lane_order: LaneOrder::default(),
+            log_query: LogQueryEditor::default(),
+            log_query_book_path: None,
+            daily_summary_dir: None,
             inbox_messages: Vec::new(),
             inbox_filter: InboxFilter::All,
             inbox_selected_thread: 0,
@@ -958,6 +1110,8 @@ impl App {
             notification_queue: VecDeque::new(),
             notification_sequence: 0,
             action_busy: false,
+            undo_stack: UndoStack::default(),
+            undo_in_flight: None,
 
             width: 120,
             height: 40,
@@ -972,7 +1126,9 @@ impl App {
             quitting: false,
 
             views: HashMap::new(),
-        }
+        };
+        app.load_user_runtime_budgets();
+        app
     }
 
     // -- view registration ---------------------------------------------------
@@ -1028,6 +1184,68 @@ impl App {
         &self.filtered
     }
 
+    /// Daily summary of the current loops: stopped loops count as completed,
+    /// errored loops as blockers, and loops with queued work as next actions.
+    #[must_use]
+    pub fn daily_summary_artifact(&self, date_utc: &str) -> DailySummaryArtifact {
+        let entry = |view: &LoopView, detail: Option<String>| DailySummaryEntry {
+            id: view.short_id.clone(),
+            title: view.name.clone(),
+            owner: (!view.profile_name.is_empty()).then(|| view.profile_name.clone()),
+            detail,
+        };
+        let mut input = DailySummaryInput {
+            date_utc: date_utc.to_owned(),
+            ..DailySummaryInput::default()
+        };
+        for view in &self.loops {
+            if view.state == "error" || !view.last_error.trim().is_empty() {
+                input
+                    .blockers
+                    .push(entry(view, Some(view.last_error.trim().to_owned())));
+            } else if view.state == "stopped" {
+                input
+                    .completed
+                    .push(entry(view, Some(format!("{} run(s)", view.runs))));
+            }
+            if view.queue_depth > 0 {
+                input
+                    .next_actions
+                    .push(entry(view, Some(format!("{} queued", view.queue_depth))));
+            }
+        }
+        input.usage = vec![
+            DailySummaryUsageRow {
+                metric: "loops".to_owned(),
+                value: self.loops.len().to_string(),
+            },
+            DailySummaryUsageRow {
+                metric: "loop runs".to_owned(),
+                value: self
+                    .loops
+                    .iter()
+                    .map(|view| view.runs)
+                    .sum::<usize>()
+                    .to_string(),
+            },
+        ];
+        build_daily_summary_artifact(&input, &DailySummaryPolicy::default())
+    }
+
+    pub fn set_daily_summary_dir(&mut self, dir: &std::path::Path) {
+        self.daily_summary_dir = Some(dir.to_path_buf());
+    }
+
+    /// Write the daily summary for `date_utc` as markdown into the configured
+    /// directory and return the report path.
+    pub fn export_daily_summary(&self, date_utc: &str) -> Result<std::path::PathBuf, String> {
+        let Some(dir) = self.daily_summary_dir.as_deref() else {
+            return Err("daily summary export directory not configured".to_owned());
+        };
+        let artifact = self.daily_summary_artifact(date_utc);
+        export_daily_summary_markdown(&artifact, dir, &format!("forge-daily-summary-{date_utc}"))
+    }
+
     #[must_use]
     pub fn run_history(&self) -> &[RunView] {
         &self.run_history
@@ -1109,6 +1327,16 @@ impl App {
         self.pinned.len()
     }
 
+    #[must_use]
+    pub fn loop_note(&self, loop_id: &str) -> Option<&LoopNote> {
+        self.loop_notes.note(loop_id)
+    }
+
+    #[must_use]
+    pub fn has_loop_note(&self, loop_id: &str) -> bool {
+        self.loop_notes.has_note(loop_id)
+    }
+
     #[must_use]
     pub fn filter_text(&self) -> &str {
         &self.filter_text
@@ -1286,6 +1514,29 @@ impl App {
             .resolve(&self.key_scope_chain(), KeyChord::from_event(key))
     }
 
+    /// Map `key` to the default key of the command the keymap binds it to,
+    /// so handlers matching default keys follow user rebinds. Keys the
+    /// keymap does not bind pass through; `None` means the key's default
+    /// command was rebound to another key.
+    fn canonical_key(&self, key: KeyEvent) -> Option<KeyEvent> {
+        let scopes = self.key_scope_chain();
+        let defaults = Keymap::defaults();
+        match self.resolve_key_command(key) {
+            Some(command) => Some(
+                defaults
+                    .chord_for(&scopes, command)
+                    .map_or(key, KeyChord::to_event),
+            ),
+            None if defaults
+                .resolve(&scopes, KeyChord::from_event(key))
+                .is_some() =>
+            {
+                None
+            }
+            None => Some(key),
+        }
+    }
+
     #[must_use]
     pub fn multi_logs(&self) -> &HashMap<String, LogTailView> {
         &self.multi_logs
@@ -1383,6 +1634,9 @@ impl App {
                 },
             ],
             pinned_loop_ids,
+            loop_notes: self.loop_notes.to_persisted(),
+            log_lane_order: self.lane_order.persisted_order(),
+            pinned_log_lanes: self.lane_order.persisted_pins(),
         }
     }
 
@@ -1474,6 +1728,17 @@ impl App {
             .map(|id| id.trim().to_ascii_lowercase())
             .filter(|id| !id.is_empty() && available_ids.contains(id))
             .collect();
+        self.lane_order =
+            LaneOrder::from_persisted(&context.log_lane_order, &context.pinned_log_lanes);
+        self.loop_notes = LoopNotes::from_persisted(&context.loop_notes);
+        if !self.loops.is_empty() {
+            let orphaned = self
+                .loop_notes
+                .retain_loops(available_ids.iter().map(String::as_str));
+            if orphaned > 0 {
+                notices.push(format!("dropped {orphaned} note(s) for removed loops"));
+            }
+        }
 
         self.log_scroll = context.log_scroll.min(MAX_LOG_BACKFILL);
         self.follow_mode = self.log_scroll == 0;
@@ -1877,9 +2142,53 @@ impl App {
         );
     }
 
+    // -- refresh scheduling --------------------------------------------------
+
+    #[must_use]
+    pub fn refresh_mode(&self) -> RefreshMode {
+        self.refresh.mode()
+    }
+
+    pub fn set_refresh_mode(&mut self, mode: RefreshMode) {
+        self.refresh.set_mode(mode);
+    }
+
+    /// Cursor for the next event page fetched for event-driven refresh.
+    #[must_use]
+    pub fn refresh_event_cursor(&self) -> &str {
+        self.refresh.event_cursor()
+    }
+
+    /// Feed a fetched event page; relevant events schedule an event-driven
+    /// refresh.
+    pub fn observe_refresh_events(&mut self, events: &[EventType], next_cursor: &str) -> bool {
+        self.refresh.observe_events(events, next_cursor, |event| {
+            overview_event_is_relevant(*event)
+        })
+    }
+
+    /// Called on every runtime tick (see [`InputEvent::Tick`]); returns
+    /// [`Command::Fetch`] when the refresh controller says the view is due
+    /// for fresh data.
+    pub fn poll_refresh(&mut self, now_ms: u64) -> Command {
+        match self.refresh.poll(now_ms, 0) {
+            Some(_) => {
+                self.refresh_requested_at = Some(std::time::Instant::now());
+                Command::Fetch
+            }
+            None => Command::None,
+        }
+    }
+
     // -- data setters (called from refresh/tick) -----------------------------
 
     pub fn set_loops(&mut self, loops: Vec<LoopView>) {
+        if let Some(requested_at) = self.refresh_requested_at.take() {
+            self.record_runtime_gate(
+                RuntimeGate::PollLatency,
+                gate_elapsed_ms(requested_at.elapsed()),
+            );
+        }
         let old_id = self.selected_id.clone();
         let old_idx = self.selected_idx;
         self.loops = loops;
@@ -1897,7 +2206,21 @@ impl App {
     }
 
     pub fn set_run_history(&mut self, runs: Vec<RunView>) {
+        // Marks are positional; carry them over to the same run ids.
+        let marked_ids = self
+            .run_compare
+            .marked()
+            .iter()
+            .filter_map(|index| self.run_history.get(*index))
+            .map(|run| run.id.clone())
+            .collect::<Vec<_>>();
         self.run_history = runs;
+        self.run_compare.clear();
+        for id in marked_ids {
+            if let Some(index) = self.run_history.iter().position(|run| run.id == id) {
+                self.run_compare.toggle(index);
+            }
+        }
         if self.run_history.is_empty() {
             self.selected_run = 0;
             self.log_source = LogSource::Live;
@@ -2083,6 +2406,9 @@ impl App {
             return;
         }
         self.tab = tab;
+        if let Some(split) = self.split.as_mut() {
+            split.set_focused_tab(tab);
+        }
         self.log_scroll = 0;
         self.follow_mode = true;
         if tab == MainTab::MultiLogs {
@@ -2097,6 +2423,102 @@ impl App {
         }
     }
 
+    // -- split panes ---------------------------------------------------------
+
+    /// Responsive dashboard arrangement for the current terminal size;
+    /// `status_label()` is what the status strip shows.
+    #[must_use]
+    pub fn dashboard_layout(&self) -> &ResponsiveLayout {
+        &self.dashboard_layout
+    }
+
+    #[must_use]
+    pub fn split_layout(&self) -> Option<&SplitLayout> {
+        self.split.as_ref()
+    }
+
+    /// Host the current tab and `secondary` side by side (or stacked); the
+    /// current tab keeps focus.
+    pub fn open_split(&mut self, orientation: SplitOrientation, secondary: MainTab) {
+        self.split = Some(SplitLayout::new(orientation, self.tab, secondary));
+        self.set_status(
+            StatusKind::Info,
+            &format!(
+                "Split {}: {} | {}",
+                orientation.label(),
+                self.tab.label(),
+                secondary.label()
+            ),
+        );
+    }
+
+    pub fn close_split(&mut self) {
+        if self.split.take().is_some() {
+            self.set_status(StatusKind::Info, "Split closed");
+        }
+    }
+
+    /// Cycle off -> horizontal -> vertical -> off.
+    pub fn cycle_split_layout(&mut self) {
+        match self.split.as_ref().map(SplitLayout::orientation) {
+            None => self.open_split(
+                SplitOrientation::Horizontal,
+                default_split_partner(self.tab),
+            ),
+            Some(SplitOrientation::Horizontal) => {
+                if let Some(split) = self.split.as_mut() {
+                    split.set_orientation(SplitOrientation::Vertical);
+                }
+                self.set_status(StatusKind::Info, "Split vertical");
+            }
+            Some(SplitOrientation::Vertical) => self.close_split(),
+        }
+    }
+
+    /// Move input focus to the other split pane; its tab becomes active.
+    pub fn switch_split_focus(&mut self) {
+        let current = self.live_pane_scroll();
+        let Some(split) = self.split.as_mut() else {
+            return;
+        };
+        let (tab, scroll) = split.toggle_focus(current);
+        let pane = split.focused();
+        if self.tab != tab {
+            self.tab = tab;
+            self.focus_right = tab == MainTab::MultiLogs;
+        }
+        self.log_scroll = scroll.log_scroll;
+        self.follow_mode = scroll.follow_mode;
+        let label = match pane {
+            SplitPane::Primary => "primary",
+            SplitPane::Secondary => "secondary",
+        };
+        self.set_status(
+            StatusKind::Info,
+            &format!("Split focus: {label} ({})", tab.label()),
+        );
+    }
+
+    fn live_pane_scroll(&self) -> PaneScroll {
+        PaneScroll {
+            log_scroll: self.log_scroll,
+            follow_mode: self.follow_mode,
+        }
+    }
+
+    /// Pane rects for the current content area, primary first.
+    #[must_use]
+    pub fn split_pane_rects(&self) -> Option<(Rect, Rect)> {
+        let split = self.split.as_ref()?;
+        let snapshot = self.layout_perf_hud_snapshot();
+        Some(split.pane_rects(Rect {
+            x: 0,
+            y: snapshot.content_start_row,
+            width: self.width.max(1),
+            height: snapshot.content_height,
+        }))
+    }
+
     pub fn cycle_tab(&mut self, delta: i32) {
         let order = &MainTab::ORDER;
         let mut idx = 0i32;
@@ -2306,6 +2728,295 @@ impl App {
         self.set_status(StatusKind::Info, "Cleared pinned loops");
     }
 
+    // -- loop notes ----------------------------------------------------------
+
+    /// Create or replace the operator note for a loop.
+    pub fn set_loop_note(&mut self, loop_id: &str, body: &str, updated_at_epoch_s: i64) {
+        match self.loop_notes.upsert(loop_id, body, updated_at_epoch_s) {
+            Ok(()) => self.set_status(StatusKind::Info, &format!("Saved note for {loop_id}")),
+            Err(err) => self.set_status(StatusKind::Err, &format!("Note not saved: {err}")),
+        }
+    }
+
+    pub fn delete_loop_note(&mut self, loop_id: &str) {
+        let note = self.loop_notes.note(loop_id).cloned();
+        if self.loop_notes.delete(loop_id) {
+            if let Some(note) = note {
+                self.undo_stack.record_note_delete(note);
+            }
+            self.set_status(StatusKind::Info, &format!("Deleted note for {loop_id}"));
+        }
+    }
+
+    // -- log bookmarks -------------------------------------------------------
+
+    #[must_use]
+    pub fn log_anchors(&self) -> &LogAnchorStore {
+        &self.log_anchors
+    }
+
+    /// Bookmark a log line; returns the new anchor id.
+    pub fn add_log_anchor(&mut self, draft: LogAnchorDraft) -> Option<String> {
+        match add_log_anchor(&mut self.log_anchors, draft) {
+            Ok(anchor_id) => {
+                self.set_status(StatusKind::Info, &format!("Bookmarked {anchor_id}"));
+                Some(anchor_id)
+            }
+            Err(err) => {
+                self.set_status(StatusKind::Err, &format!("Bookmark not saved: {err}"));
+                None
+            }
+        }
+    }
+
+    pub fn delete_log_anchor(&mut self, anchor_id: &str) {
+        let Some(anchor) = self.log_anchors.get(anchor_id).cloned() else {
+            self.set_status(StatusKind::Err, &format!("anchor '{anchor_id}' not found"));
+            return;
+        };
+        match remove_log_anchor(&mut self.log_anchors, &anchor.anchor_id) {
+            Ok(()) => {
+                self.set_status(
+                    StatusKind::Info,
+                    &format!("Deleted bookmark {}", anchor.marker),
+                );
+                self.undo_stack.record_bookmark_delete(anchor);
+            }
+            Err(err) => self.set_status(StatusKind::Err, &err),
+        }
+    }
+
+    // -- keymap --------------------------------------------------------------
+
+    /// Layer a user keymap config over the defaults; conflicts and parse
+    /// errors are returned and summarized in the status line.
+    pub fn load_keymap_config(&mut self, config: &str) -> KeymapLoadReport {
+        let report = self.keymap.load_user_config(config);
+        if report.conflicts.is_empty() && report.errors.is_empty() {
+            self.set_status(
+                StatusKind::Ok,
+                &format!("Keymap loaded ({} rebind(s))", report.applied.len()),
+            );
+        } else {
+            self.set_status(
+                StatusKind::Err,
+                &format!(
+                    "Keymap loaded with {} conflict(s), {} error(s)",
+                    report.conflicts.len(),
+                    report.errors.len()
+                ),
+            );
+        }
+        report
+    }
+
+    /// Load a keymap config file over the defaults. A missing file leaves
+    /// the defaults untouched; an unreadable one is reported in the status line.
+    pub fn load_keymap_file(&mut self, path: &std::path::Path) -> Option<KeymapLoadReport> {
+        match read_keymap_config(path) {
+            Ok(Some(config)) => Some(self.load_keymap_config(&config)),
+            Ok(None) => None,
+            Err(err) => {
+                self.set_status(StatusKind::Err, &err);
+                None
+            }
+        }
+    }
+
+    /// Builder form of [`Self::load_keymap_config`], used at startup to
+    /// apply the user keymap read by the binary.
+    #[must_use]
+    pub fn with_keymap(mut self, config: &str) -> Self {
+        self.load_keymap_config(config);
+        self
+    }
+
+    pub fn reset_keymap(&mut self) {
+        self.keymap.reset_to_defaults();
+        self.set_status(StatusKind::Info, "Keymap reset to defaults");
+    }
+
+    // -- runtime performance gates ------------------------------------------
+
+    pub fn set_runtime_budgets(&mut self, budgets: RuntimeBudgets) {
+        self.runtime_gates.set_budgets(budgets);
+    }
+
+    /// Apply budget overrides from a JSON file; a missing file keeps defaults.
+    pub fn load_runtime_budgets_file(&mut self, path: &std::path::Path) {
+        let raw = match std::fs::read_to_string(path) {
+            Ok(raw) => raw,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return,
+            Err(err) => {
+                self.set_status(
+                    StatusKind::Err,
+                    &format!("read runtime budgets {}: {err}", path.display()),
+                );
+                return;
+            }
+        };
+        let outcome = restore_runtime_budgets(&raw);
+        self.set_runtime_budgets(outcome.budgets);
+        if !outcome.warnings.is_empty() {
+            self.set_status(
+                StatusKind::Err,
+                &format!("runtime budgets: {}", outcome.warnings.join("; ")),
+            );
+        }
+    }
+
+    /// Load budgets from [`user_runtime_budgets_path`], if present.
+    pub fn load_user_runtime_budgets(&mut self) {
+        if let Some(path) = user_runtime_budgets_path() {
+            self.load_runtime_budgets_file(&path);
+        }
+    }
+
+    /// Render a frame and record how long building it took.
+    pub fn render_timed(&mut self) -> RenderFrame {
+        let started = std::time::Instant::now();
+        let frame = self.render();
+        self.record_runtime_gate(RuntimeGate::FrameBuild, gate_elapsed_ms(started.elapsed()));
+        frame
+    }
+
+    /// Write the current gate results as `forge doctor` checks.
+    pub fn write_runtime_gate_report(&self, path: &std::path::Path) -> Result<(), String> {
+        forge_cli::doctor::write_check_report(
+            path,
+            &gate_results_to_doctor_checks(&self.runtime_gate_results()),
+        )
+    }
+
+    /// Record a runtime measurement; a gate that starts breaching its budget
+    /// raises an error status instead of degrading silently.
+    pub fn record_runtime_gate(&mut self, gate: RuntimeGate, elapsed_ms: u64) {
+        let was_passing = self.runtime_gate_passing(gate);
+        self.runtime_gates.record(gate, elapsed_ms);
+        if !was_passing {
+            return;
+        }
+        let Some(result) = self
+            .runtime_gates
+            .evaluate()
+            .into_iter()
+            .find(|result| result.gate == gate && !result.passed)
+        else {
+            return;
+        };
+        self.set_status(
+            StatusKind::Err,
+            &format!(
+                "Perf gate breached: {} p95 {}ms > {}ms budget",
+                gate.label(),
+                result.measured_p95_ms.unwrap_or_default(),
+                result.threshold_ms
+            ),
+        );
+    }
+
+    #[must_use]
+    pub fn runtime_gate_results(&self) -> Vec<GateResult> {
+        self.runtime_gates.evaluate()
+    }
+
+    fn runtime_gate_passing(&self, gate: RuntimeGate) -> bool {
+        self.runtime_gates
+            .evaluate()
+            .iter()
+            .find(|result| result.gate == gate)
+            .map_or(true, |result| result.passed)
+    }
+
+    // -- log lane order -----------------------------------------------------
+
+    #[must_use]
+    pub fn lane_order(&self) -> &LaneOrder {
+        &self.lane_order
+    }
+
+    pub fn toggle_log_lane_pin(&mut self, lane: LogLane) {
+        if self.lane_order.unpin(lane) {
+            self.set_status(StatusKind::Info, &format!("Unpinned {} lane", lane.label()));
+        } else if self.lane_order.pin(lane) {
+            self.set_status(StatusKind::Info, &format!("Pinned {} lane", lane.label()));
+        }
+    }
+
+    pub fn move_log_lane_up(&mut self, lane: LogLane) -> bool {
+        self.lane_order.move_up(lane)
+    }
+
+    pub fn move_log_lane_down(&mut self, lane: LogLane) -> bool {
+        self.lane_order.move_down(lane)
+    }
+
+    // -- log queries ---------------------------------------------------------
+
+    #[must_use]
+    pub fn log_query_editor(&self) -> &LogQueryEditor {
+        &self.log_query
+    }
+
+    pub fn log_query_editor_mut(&mut self) -> &mut LogQueryEditor {
+        &mut self.log_query
+    }
+
+    /// Load query history and saved queries from `path`, which is then
+    /// rewritten whenever they change.
+    pub fn load_log_query_book_file(&mut self, path: &std::path::Path) {
+        self.log_query_book_path = Some(path.to_path_buf());
+        match load_log_query_book_file(path) {
+            Ok(outcome) => {
+                self.log_query = LogQueryEditor::new(outcome.book);
+                if !outcome.warnings.is_empty() {
+                    self.set_status(
+                        StatusKind::Err,
+                        &format!("log queries: {}", outcome.warnings.join("; ")),
+                    );
+                }
+            }
+            Err(err) => self.set_status(StatusKind::Err, &err),
+        }
+    }
+
+    /// Load the query book from [`user_log_query_book_path`], if set.
+    pub fn load_user_log_query_book(&mut self) {
+        if let Some(path) = user_log_query_book_path() {
+            self.load_log_query_book_file(&path);
+        }
+    }
+
+    /// Run the query box against the selected log and return the matching
+    /// lines. A successful run is recorded in the history and saved.
+    pub fn run_log_query(&mut self) -> Result<Vec<String>, QueryError> {
+        let model = classify_lines(&self.selected_log.lines);
+        let lines = self
+            .log_query
+            .run(&model)?
+            .into_iter()
+            .map(|line| line.text.clone())
+            .collect();
+        self.save_log_query_book();
+        Ok(lines)
+    }
+
+    /// Save the query box under `name` and persist the book.
+    pub fn save_log_query(&mut self, name: &str) -> Result<(), String> {
+        self.log_query.save_input(name)?;
+        self.save_log_query_book();
+        Ok(())
+    }
+
+    fn save_log_query_book(&mut self) {
+        let Some(path) = self.log_query_book_path.clone() else {
+            return;
+        };
+        if let Err(err) = save_log_query_book_file(&path, self.log_query.book()) {
+            self.set_status(StatusKind::Err, &format!("save log queries: {err}"));
+        }
+    }
+
     // -- filters -------------------------------------------------------------
 
     pub fn apply_filters(&mut self, previous_id: &str, previous_idx: usize) {
@@ -2421,6 +3132,37 @@ impl App {
         );
     }
 
+    /// Cycle the minimum log level, folding lower-level lines while keeping
+    /// the line at the bottom of the view in place.
+    pub fn cycle_log_min_level(&mut self) {
+        let lines = self.layer_log_lines();
+        let scroll = self
+            .log_level_fold
+            .cycle_min_level(&lines, self.log_scroll as i32);
+        self.log_scroll = scroll.max(0) as usize;
+        self.set_status(
+            StatusKind::Info,
+            &format!("Log level: {}", self.log_level_fold.min_level().label()),
+        );
+    }
+
+    /// Expand or collapse the nearest folded span at or above the bottom of
+    /// the logs view.
+    pub fn toggle_log_fold(&mut self) {
+        let lines = self.layer_log_lines();
+        match self
+            .log_level_fold
+            .toggle_fold_at(&lines, self.log_scroll as i32)
+        {
+            Some((expanded, scroll)) => {
+                self.log_scroll = scroll.max(0) as usize;
+                let verb = if expanded { "expanded" } else { "collapsed" };
+                self.set_status(StatusKind::Info, &format!("Fold {verb}"));
+            }
+            None => self.set_status(StatusKind::Info, "No folded lines in view"),
+        }
+    }
+
     // -- log scrolling -------------------------------------------------------
 
     pub fn scroll_logs(&mut self, delta: i32) {
@@ -2484,22 +3226,89 @@ impl App {
         self.log_scroll = 0;
     }
 
-    #[must_use]
-    pub fn selected_run_view(&self) -> Option<&RunView> {
+    /// Mark or unmark the selected run for side-by-side comparison.
+    pub fn toggle_run_compare(&mut self) {
         if self.run_history.is_empty() {
-            return None;
+            self.set_status(StatusKind::Info, "compare: no runs to compare");
+            return;
         }
-        let idx = self
+        let index = self
             .selected_run
             .min(self.run_history.len().saturating_sub(1));
-        Some(&self.run_history[idx])
-    }
-
-    fn stash_evidence_return_point(&mut self) {
-        if self.evidence_return.is_some() {
+        self.run_compare.toggle(index);
+        self.run_log_compare = false;
+        if self.run_compare.marked().is_empty() {
+            self.set_status(StatusKind::Info, "compare: cleared");
             return;
         }
-        self.evidence_return = Some(EvidenceReturnPoint {
+        match self.run_compare.compare(&self.run_entries()) {
+            Ok(comparison) => {
+                let changed = comparison.changed_fields().len();
+                self.set_status(
+                    StatusKind::Info,
+                    &format!(
+                        "compare: {} vs {} ({changed} field(s) differ)",
+                        crate::runs_tab::short_run_id(&comparison.left_id),
+                        crate::runs_tab::short_run_id(&comparison.right_id),
+                    ),
+                );
+            }
+            Err(prompt) => self.set_status(StatusKind::Info, &prompt),
+        }
+    }
+
+    /// Switch the compare view between field deltas and both runs' output.
+    pub fn toggle_run_log_compare(&mut self) {
+        if self.run_comparison().is_none() {
+            self.set_status(StatusKind::Info, "log compare: mark two runs with V first");
+            return;
+        }
+        self.run_log_compare = !self.run_log_compare;
+        let label = if self.run_log_compare { "on" } else { "off" };
+        self.set_status(StatusKind::Info, &format!("log compare: {label}"));
+    }
+
+    /// Comparison of the two runs marked for compare, if both are marked.
+    #[must_use]
+    pub fn run_comparison(&self) -> Option<crate::runs_tab::RunComparison> {
+        self.run_compare.compare(&self.run_entries()).ok()
+    }
+
+    fn run_entries(&self) -> Vec<crate::runs_tab::RunEntry> {
+        self.run_history
+            .iter()
+            .map(|rv| crate::runs_tab::RunEntry {
+                id: rv.id.clone(),
+                status: rv.status.clone(),
+                exit_code: rv.exit_code,
+                profile_name: rv.profile_name.clone(),
+                profile_id: rv.profile_id.clone(),
+                harness: rv.harness.clone(),
+                started_at: rv.started_at.clone(),
+                duration_display: rv.duration.clone(),
+                output_lines: rv.output_lines.clone(),
+                attempts: rv.attempts,
+                cost_usd: rv.cost_usd,
+            })
+            .collect()
+    }
+
+    #[must_use]
+    pub fn selected_run_view(&self) -> Option<&RunView> {
+        if self.run_history.is_empty() {
+            return None;
+        }
+        let idx = self
+            .selected_run
+            .min(self.run_history.len().saturating_sub(1));
+        Some(&self.run_history[idx])
+    }
+
+    fn stash_evidence_return_point(&mut self) {
+        if self.evidence_return.is_some() {
+            return;
+        }
+        self.evidence_return = Some(EvidenceReturnPoint {
             tab: self.tab,
             selected_id: self.selected_id.clone(),
             selected_idx: self.selected_idx,
@@ -2977,13 +3786,17 @@ impl App {
                     format!("Loop is still running. Force delete record {loop_id}? [y/N]")
                 }
             }
+            ActionType::Takeover => {
+                format!("Take over stale loop {loop_id} and restart it? [y/N]")
+            }
             _ => {
                 self.set_status(StatusKind::Err, "Unsupported destructive action");
                 return Command::None;
             }
         };
         let force_delete = action == ActionType::Delete && view.state != "stopped";
-        let reason_required = action == ActionType::Kill || force_delete;
+        let reason_required =
+            matches!(action, ActionType::Kill | ActionType::Takeover) || force_delete;
 
         self.confirm = Some(ConfirmState {
             action,
@@ -3002,9 +3815,19 @@ impl App {
 
     /// Process an input event. Returns a command for the host to execute.
     pub fn update(&mut self, event: InputEvent) -> Command {
+        if let InputEvent::Tick = event {
+            return self.poll_refresh(unix_epoch_ms());
+        }
+
         if let InputEvent::Resize(r) = event {
             self.width = r.width;
             self.height = r.height;
+            self.dashboard_layout = responsive_dashboard_layout(
+                &overview_dashboard_panels(),
+                r.width as i32,
+                r.height as i32,
+                ResponsiveThresholds::default(),
+            );
             if self.tab == MainTab::MultiLogs {
                 self.clamp_multi_page();
             }
@@ -3299,7 +4122,21 @@ impl App {
             self.mode = UiMode::Search;
             return Command::None;
         }
+        match self.resolve_key_command(key) {
+            Some(KeyCommand::CycleSplitLayout) => {
+                self.cycle_split_layout();
+                return Command::Fetch;
+            }
+            Some(KeyCommand::SwitchSplitFocus) => {
+                self.switch_split_focus();
+                return Command::Fetch;
+            }
+            _ => {}
+        }
 
+        let Some(key) = self.canonical_key(key) else {
+            return Command::None;
+        };
         match key.key {
             Key::Char('q') => {
                 self.quitting = true;
@@ -3411,6 +4248,23 @@ impl App {
                 }
             }
             Key::Char('E') => Command::ExportCurrentView,
+            Key::Char('W') => {
+                let date_utc = utc_date_from_epoch_s((unix_epoch_ms() / 1_000) as i64);
+                match self.export_daily_summary(&date_utc) {
+                    Ok(path) => self.set_status(
+                        StatusKind::Info,
+                        &format!("Daily summary written to {}", path.display()),
+                    ),
+                    Err(err) => self.set_status(StatusKind::Err, &err),
+                }
+                Command::None
+            }
+            Key::Char('V') => {
+                if self.tab == MainTab::Runs {
+                    self.toggle_run_compare();
+                }
+                Command::None
+            }
             Key::Char('j') | Key::Down => {
                 if self.tab == MainTab::Inbox {
                     self.move_inbox_selection(1);
@@ -3549,30 +4403,7 @@ impl App {
                     Command::None
                 }
             }
-            Key::Char('u') => {
-                if self.tab == MainTab::Logs
-                    || self.tab == MainTab::Runs
-                    || (self.tab == MainTab::MultiLogs && self.multi_compare_mode)
-                {
-                    let page = self.log_scroll_page_size() as i32;
-                    self.scroll_logs(page);
-                    Command::Fetch
-                } else {
-                    Command::None
-                }
-            }
-            Key::Char('d') => {
-                if self.tab == MainTab::Logs
-                    || self.tab == MainTab::Runs
-                    || (self.tab == MainTab::MultiLogs && self.multi_compare_mode)
-                {
-                    let page = self.log_scroll_page_size() as i32;
-                    self.scroll_logs(-page);
-                    Command::Fetch
-                } else {
-                    Command::None
-                }
-            }
+            Key::Char('u') => self.undo_last_action(),
             Key::Char(' ') => {
                 if let Some(view) = self.selected_view().cloned() {
                     self.toggle_pinned(&view.id);
@@ -3632,6 +4463,20 @@ impl App {
                 }
                 Command::None
             }
+            Key::Char('L') => {
+                if self.tab == MainTab::Logs {
+                    self.cycle_log_min_level();
+                } else if self.tab == MainTab::Runs {
+                    self.toggle_run_log_compare();
+                }
+                Command::None
+            }
+            Key::Char('H') => {
+                if self.tab == MainTab::Logs {
+                    self.toggle_log_fold();
+                }
+                Command::None
+            }
             Key::Char('F') => {
                 if self.tab == MainTab::Logs || self.tab == MainTab::Runs {
                     self.toggle_follow_mode();
@@ -3697,6 +4542,7 @@ impl App {
             Key::Char('S') => self.enter_confirm(ActionType::Stop),
             Key::Char('K') => self.enter_confirm(ActionType::Kill),
             Key::Char('D') => self.enter_confirm(ActionType::Delete),
+            Key::Char('X') => self.enter_confirm(ActionType::Takeover),
             _ => Command::None,
         }
     }
@@ -3884,14 +4730,21 @@ impl App {
         }
     }
 
-    fn rendered_log_lines(&self) -> Vec<String> {
+    /// Selected log lines rendered for the active layer. Raw lines are
+    /// arranged by lane order first, since rendering rewrites line prefixes
+    /// the lane classifier keys on.
+    fn layer_log_lines(&self) -> Vec<String> {
         render_lines_for_layer(
-            &self.selected_log.lines,
+            &self.lane_order.arrange(self.selected_log.lines.clone()),
             map_log_render_layer(self.log_layer),
             true,
         )
     }
 
+    fn rendered_log_lines(&self) -> Vec<String> {
+        self.log_level_fold.display_lines(&self.layer_log_lines())
+    }
+
     fn collect_regex_match_indices(&self, rendered_lines: &[String]) -> Vec<usize> {
         let Some(regex) = self.log_regex_compiled.as_ref() else {
             return Vec::new();
@@ -4147,6 +5000,10 @@ impl App {
                     loop_id: confirm.loop_id,
                     force: confirm.force_delete,
                 },
+                ActionType::Takeover => ActionKind::Takeover {
+                    loop_id: confirm.loop_id,
+                    reason: confirm.reason.trim().to_owned(),
+                },
                 _ => return Command::None,
             };
             Command::RunAction(action)
@@ -4347,7 +5204,11 @@ impl App {
 
     fn update_help_mode(&mut self, key: KeyEvent) -> Command {
         match key.key {
-            Key::Char('q') | Key::Escape | Key::Char('?') => {
+            Key::Escape if !self.help_query.is_empty() => {
+                self.help_query.clear();
+                Command::None
+            }
+            Key::Char('q') | Key::Escape | Key::Char('?') if self.help_query.is_empty() => {
                 if self.help_return == UiMode::Help {
                     self.mode = UiMode::Main;
                 } else {
@@ -4355,6 +5216,14 @@ impl App {
                 }
                 Command::None
             }
+            Key::Backspace => {
+                self.help_query.pop();
+                Command::None
+            }
+            Key::Char(ch) if !key.modifiers.ctrl && !key.modifiers.alt => {
+                self.help_query.push(ch);
+                Command::None
+            }
             _ => Command::None,
         }
     }
@@ -4569,9 +5438,13 @@ impl App {
     /// triggers a data refresh.
     pub fn handle_action_result(&mut self, result: ActionResult) -> Command {
         self.action_busy = false;
+        let from_undo = std::mem::take(&mut self.undo_in_flight);
 
         if let Some(ref err) = result.error {
             self.set_status(StatusKind::Err, err);
+            if let Some(entry) = from_undo {
+                self.undo_stack.push(entry);
+            }
             if result.kind == ActionType::Create {
                 self.mode = UiMode::Wizard;
                 self.wizard.error = err.clone();
@@ -4587,6 +5460,23 @@ impl App {
             }
         }
 
+        if from_undo.is_none() {
+            let kind = match result.kind {
+                ActionType::Resume => Some(LoopActionKind::Resume),
+                ActionType::Stop => Some(LoopActionKind::Stop),
+                ActionType::Kill => Some(LoopActionKind::Kill),
+                ActionType::Delete => Some(LoopActionKind::Delete),
+                ActionType::None | ActionType::Create | ActionType::Takeover => None,
+            };
+            if let Some(kind) = kind {
+                self.undo_stack.record_action(&LoopActionRequest {
+                    kind,
+                    loop_id: result.loop_id.clone(),
+                    force_delete: false,
+                });
+            }
+        }
+
         if !result.message.is_empty() {
             self.set_status(StatusKind::Ok, &result.message);
         }
@@ -4594,6 +5484,62 @@ impl App {
         Command::Fetch
     }
 
+    #[must_use]
+    pub fn undo_depth(&self) -> usize {
+        self.undo_stack.len()
+    }
+
+    /// Revert the most recent reversible action (stop/resume, note or
+    /// bookmark delete).
+    pub fn undo_last_action(&mut self) -> Command {
+        if self.action_busy {
+            self.set_status(StatusKind::Info, "Another action is still running");
+            return Command::None;
+        }
+        let Some(entry) = self.undo_stack.pop() else {
+            self.set_status(StatusKind::Info, "Nothing to undo");
+            return Command::None;
+        };
+        match &entry.operation {
+            UndoOperation::Loop(request) => {
+                let action = match request.kind {
+                    LoopActionKind::Resume => ActionType::Resume,
+                    LoopActionKind::Stop => ActionType::Stop,
+                    LoopActionKind::Kill | LoopActionKind::Delete => return Command::None,
+                };
+                let loop_id = request.loop_id.clone();
+                let status = format!("Undoing {}...", entry.label);
+                self.undo_in_flight = Some(entry);
+                let command = self.run_action(action, &loop_id);
+                self.set_status(StatusKind::Info, &status);
+                command
+            }
+            UndoOperation::RestoreNote(note) => {
+                match self
+                    .loop_notes
+                    .upsert(&note.loop_id, &note.body, note.updated_at_epoch_s)
+                {
+                    Ok(()) => self.set_status(StatusKind::Ok, &format!("Undid {}", entry.label)),
+                    Err(err) => {
+                        self.set_status(StatusKind::Err, &format!("Undo failed: {err}"));
+                        self.undo_stack.push(entry);
+                    }
+                }
+                Command::Fetch
+            }
+            UndoOperation::RestoreBookmark(anchor) => {
+                match restore_log_anchor(&mut self.log_anchors, anchor.clone()) {
+                    Ok(()) => self.set_status(StatusKind::Ok, &format!("Undid {}", entry.label)),
+                    Err(err) => {
+                        self.set_status(StatusKind::Err, &format!("Undo failed: {err}"));
+                        self.undo_stack.push(entry);
+                    }
+                }
+                Command::Fetch
+            }
+        }
+    }
+
     // -- render --------------------------------------------------------------
 
     /// Render the full TUI frame.
@@ -4826,162 +5772,41 @@ impl App {
                 }
             }
             _ => {
-                // Delegate to registered view if available.
-                if let Some(view) = self.views.get(&self.tab) {
-                    let view_frame = crate::panel_error_boundary::render_panel_with_boundary(
-                        self.tab.label(),
-                        FrameSize {
-                            width,
-                            height: content_height,
-                        },
-                        theme,
-                        &pal,
-                        || {
-                            view.view(
-                                FrameSize {
-                                    width,
-                                    height: content_height,
-                                },
-                                theme,
-                            )
-                        },
-                    );
-                    blit_frame(&mut frame, &view_frame, 0, content_start);
-                } else if self.mode == UiMode::Main && self.tab == MainTab::Overview {
-                    let overview_frame = crate::panel_error_boundary::render_panel_with_boundary(
-                        MainTab::Overview.label(),
-                        FrameSize {
-                            width,
-                            height: content_height,
-                        },
-                        theme,
-                        &pal,
-                        || {
-                            let mut pane_frame = RenderFrame::new(
-                                FrameSize {
-                                    width,
-                                    height: content_height,
-                                },
-                                theme,
-                            );
-                            crate::overview_tab::render_overview_paneled_with_options(
-                                &mut pane_frame,
-                                &self.loops,
-                                self.selected_view(),
-                                &self.run_history,
-                                self.selected_run,
-                                &pal,
-                                Rect {
-                                    x: 0,
-                                    y: 0,
-                                    width,
-                                    height: content_height,
-                                },
-                                self.focus_right,
-                                crate::overview_tab::OverviewPaneOptions {
-                                    reserve_next_action_slot: true,
-                                },
-                            );
-                            pane_frame
-                        },
-                    );
-                    blit_frame(&mut frame, &overview_frame, 0, content_start);
-                } else if self.mode == UiMode::Main && self.tab == MainTab::Logs {
-                    let logs_frame = crate::panel_error_boundary::render_panel_with_boundary(
-                        MainTab::Logs.label(),
-                        FrameSize {
-                            width,
-                            height: content_height,
-                        },
-                        theme,
-                        &pal,
-                        || self.render_logs_pane(width, content_height, &pal, self.focus_right),
-                    );
-                    blit_frame(&mut frame, &logs_frame, 0, content_start);
-                } else if self.mode == UiMode::Main && self.tab == MainTab::Runs {
-                    let runs_frame = crate::panel_error_boundary::render_panel_with_boundary(
-                        MainTab::Runs.label(),
-                        FrameSize {
-                            width,
-                            height: content_height,
-                        },
-                        theme,
-                        &pal,
-                        || {
-                            let runs_state = crate::runs_tab::RunsTabState {
-                                runs: self
-                                    .run_history
-                                    .iter()
-                                    .map(|rv| crate::runs_tab::RunEntry {
-                                        id: rv.id.clone(),
-                                        status: rv.status.clone(),
-                                        exit_code: rv.exit_code,
-                                        profile_name: rv.profile_name.clone(),
-                                        profile_id: rv.profile_id.clone(),
-                                        harness: rv.harness.clone(),
-                                        started_at: rv.started_at.clone(),
-                                        duration_display: rv.duration.clone(),
-                                        output_lines: rv.output_lines.clone(),
-                                    })
-                                    .collect(),
-                                selected_run: self.selected_run,
-                                layer_label: self.log_layer.label().to_owned(),
-                                loop_display_id: self
-                                    .selected_view()
-                                    .map(|lv| crate::filter::loop_display_id(&lv.id, &lv.short_id))
-                                    .unwrap_or_default(),
-                                log_scroll: self.log_scroll,
-                            };
-                            crate::runs_tab::render_runs_paneled(
-                                &runs_state,
-                                FrameSize {
-                                    width,
-                                    height: content_height,
-                                },
-                                theme,
-                                &pal,
-                                self.focus_right,
-                            )
-                        },
-                    );
-                    blit_frame(&mut frame, &runs_frame, 0, content_start);
-                } else if self.mode == UiMode::Main && self.tab == MainTab::MultiLogs {
-                    let multi_frame = crate::panel_error_boundary::render_panel_with_boundary(
-                        MainTab::MultiLogs.label(),
-                        FrameSize {
-                            width,
-                            height: content_height,
-                        },
-                        theme,
-                        &pal,
-                        || self.render_multi_logs_pane(width, content_height, &pal),
-                    );
-                    blit_frame(&mut frame, &multi_frame, 0, content_start);
-                } else if self.mode == UiMode::Main && self.tab == MainTab::Inbox {
-                    let inbox_frame = crate::panel_error_boundary::render_panel_with_boundary(
-                        MainTab::Inbox.label(),
-                        FrameSize {
-                            width,
-                            height: content_height,
-                        },
+                if let Some(split) = self.split.as_ref().filter(|_| self.mode == UiMode::Main) {
+                    let (first, second) = split.pane_rects(Rect {
+                        x: 0,
+                        y: content_start,
+                        width,
+                        height: content_height,
+                    });
+                    for (pane, rect) in
+                        [(SplitPane::Primary, first), (SplitPane::Secondary, second)]
+                    {
+                        let scroll = if pane == split.focused() {
+                            self.live_pane_scroll()
+                        } else {
+                            split.scroll(pane)
+                        };
+                        let pane_frame = self.render_tab_frame(
+                            split.tab(pane),
+                            rect.width,
+                            rect.height,
+                            scroll,
+                            theme,
+                            &pal,
+                        );
+                        blit_frame(&mut frame, &pane_frame, rect.x, rect.y);
+                    }
+                } else {
+                    let tab_frame = self.render_tab_frame(
+                        self.tab,
+                        width,
+                        content_height,
+                        self.live_pane_scroll(),
                         theme,
                         &pal,
-                        || self.render_inbox_pane(width, content_height, &pal, self.focus_right),
-                    );
-                    blit_frame(&mut frame, &inbox_frame, 0, content_start);
-                } else {
-                    // Placeholder: show tab label + selection info.
-                    let info = format!(
-                        "{} tab  |  {} loops  |  selected: {}",
-                        self.tab.label(),
-                        self.filtered.len(),
-                        if self.selected_id.is_empty() {
-                            "none"
-                        } else {
-                            &self.selected_id
-                        }
                     );
-                    frame.draw_text(0, content_start, &info, TextRole::Primary);
+                    blit_frame(&mut frame, &tab_frame, 0, content_start);
                 }
             }
         }
@@ -5026,6 +5851,185 @@ impl App {
         frame
     }
 
+    /// Render one main tab's content area; split layouts call this per pane.
+    fn render_tab_frame(
+        &self,
+        tab: MainTab,
+        width: usize,
+        content_height: usize,
+        scroll: PaneScroll,
+        theme: ThemeSpec,
+        pal: &ResolvedPalette,
+    ) -> RenderFrame {
+        let mut frame = RenderFrame::new(
+            FrameSize {
+                width,
+                height: content_height,
+            },
+            theme,
+        );
+        frame.fill_bg(
+            Rect {
+                x: 0,
+                y: 0,
+                width,
+                height: content_height,
+            },
+            pal.background,
+        );
+        // Delegate to registered view if available.
+        if let Some(view) = self.views.get(&tab) {
+            let view_frame = crate::panel_error_boundary::render_panel_with_boundary(
+                tab.label(),
+                FrameSize {
+                    width,
+                    height: content_height,
+                },
+                theme,
+                pal,
+                || {
+                    view.view(
+                        FrameSize {
+                            width,
+                            height: content_height,
+                        },
+                        theme,
+                    )
+                },
+            );
+            blit_frame(&mut frame, &view_frame, 0, 0);
+        } else if self.mode == UiMode::Main && tab == MainTab::Overview {
+            let overview_frame = crate::panel_error_boundary::render_panel_with_boundary(
+                MainTab::Overview.label(),
+                FrameSize {
+                    width,
+                    height: content_height,
+                },
+                theme,
+                pal,
+                || {
+                    let mut pane_frame = RenderFrame::new(
+                        FrameSize {
+                            width,
+                            height: content_height,
+                        },
+                        theme,
+                    );
+                    crate::overview_tab::render_overview_paneled_with_options(
+                        &mut pane_frame,
+                        &self.loops,
+                        self.selected_view(),
+                        &self.run_history,
+                        self.selected_run,
+                        pal,
+                        Rect {
+                            x: 0,
+                            y: 0,
+                            width,
+                            height: content_height,
+                        },
+                        self.focus_right,
+                        crate::overview_tab::OverviewPaneOptions {
+                            reserve_next_action_slot: !self
+                                .dashboard_layout
+                                .hidden_panels
+                                .iter()
+                                .any(|id| id == OVERVIEW_NEXT_ACTION_PANEL),
+                            columns: self.dashboard_layout.columns().max(1) as usize,
+                        },
+                    );
+                    pane_frame
+                },
+            );
+            blit_frame(&mut frame, &overview_frame, 0, 0);
+        } else if self.mode == UiMode::Main && tab == MainTab::Logs {
+            let logs_frame = crate::panel_error_boundary::render_panel_with_boundary(
+                MainTab::Logs.label(),
+                FrameSize {
+                    width,
+                    height: content_height,
+                },
+                theme,
+                pal,
+                || self.render_logs_pane(width, content_height, pal, self.focus_right, scroll),
+            );
+            blit_frame(&mut frame, &logs_frame, 0, 0);
+        } else if self.mode == UiMode::Main && tab == MainTab::Runs {
+            let runs_frame = crate::panel_error_boundary::render_panel_with_boundary(
+                MainTab::Runs.label(),
+                FrameSize {
+                    width,
+                    height: content_height,
+                },
+                theme,
+                pal,
+                || {
+                    let runs_state = crate::runs_tab::RunsTabState {
+                        runs: self.run_entries(),
+                        selected_run: self.selected_run,
+                        layer_label: self.log_layer.label().to_owned(),
+                        loop_display_id: self
+                            .selected_view()
+                            .map(|lv| crate::filter::loop_display_id(&lv.id, &lv.short_id))
+                            .unwrap_or_default(),
+                        log_scroll: scroll.log_scroll,
+                        comparison: self.run_comparison(),
+                        log_compare: self.run_log_compare,
+                    };
+                    crate::runs_tab::render_runs_paneled(
+                        &runs_state,
+                        FrameSize {
+                            width,
+                            height: content_height,
+                        },
+                        theme,
+                        pal,
+                        self.focus_right,
+                    )
+                },
+            );
+            blit_frame(&mut frame, &runs_frame, 0, 0);
+        } else if self.mode == UiMode::Main && tab == MainTab::MultiLogs {
+            let multi_frame = crate::panel_error_boundary::render_panel_with_boundary(
+                MainTab::MultiLogs.label(),
+                FrameSize {
+                    width,
+                    height: content_height,
+                },
+                theme,
+                pal,
+                || self.render_multi_logs_pane(width, content_height, pal),
+            );
+            blit_frame(&mut frame, &multi_frame, 0, 0);
+        } else if self.mode == UiMode::Main && tab == MainTab::Inbox {
+            let inbox_frame = crate::panel_error_boundary::render_panel_with_boundary(
+                MainTab::Inbox.label(),
+                FrameSize {
+                    width,
+                    height: content_height,
+                },
+                theme,
+                pal,
+                || self.render_inbox_pane(width, content_height, pal, self.focus_right),
+            );
+            blit_frame(&mut frame, &inbox_frame, 0, 0);
+        } else {
+            // Placeholder: show tab label + selection info.
+            let info = format!(
+                "{} tab  |  {} loops  |  selected: {}",
+                tab.label(),
+                self.filtered.len(),
+                if self.selected_id.is_empty() {
+                    "none"
+                } else {
+                    &self.selected_id
+                }
+            );
+            frame.draw_text(0, 0, &info, TextRole::Primary);
+        }
+        frame
+    }
+
     fn footer_hint_line(&self) -> String {
         let max_hints = if self.focus_mode == FocusMode::DeepDebug {
             6
@@ -5113,7 +6117,7 @@ impl App {
                     Some(KeyCommand::CycleLogLayer),
                 ));
                 hints.push(HintSpec::new(
-                    "u/d",
+                    "ctrl+u/d",
                     "scroll",
                     5,
                     Some(KeyCommand::ScrollLogsDown),
@@ -5134,7 +6138,7 @@ impl App {
                     Some(KeyCommand::RunSelectionNext),
                 ));
                 hints.push(HintSpec::new(
-                    "u/d",
+                    "ctrl+u/d",
                     "scroll",
                     5,
                     Some(KeyCommand::ScrollLogsDown),
@@ -5212,8 +6216,13 @@ impl App {
         } else {
             ""
         };
+        let layout_label = if self.tab == MainTab::Overview && self.mode == UiMode::Main {
+            format!("  layout:{}", self.dashboard_layout.status_label())
+        } else {
+            String::new()
+        };
         let header = format!(
-            " Forge Loops  [{tab}]  {count}  theme:{theme}  density:{density}  focus:{focus}{mode}{follow}",
+            " Forge Loops  [{tab}]  {count}{layout}  theme:{theme}  density:{density}  focus:{focus}{mode}{follow}",
             tab = self.tab.label(),
             count = count_label,
             theme = self.palette.name,
@@ -5221,6 +6230,7 @@ impl App {
             focus = self.focus_mode.label(),
             mode = mode_label,
             follow = follow_label,
+            layout = layout_label,
         );
         trim_to_width(&header, width)
     }
@@ -5457,6 +6467,7 @@ impl App {
         height: usize,
         pal: &ResolvedPalette,
         focus_right: bool,
+        scroll: PaneScroll,
     ) -> RenderFrame {
         let theme = crate::theme_for_capability(self.color_capability);
         let mut frame = RenderFrame::new(FrameSize { width, height }, theme);
@@ -5504,11 +6515,7 @@ impl App {
             return frame;
         }
 
-        let rendered_lines = render_lines_for_layer(
-            &self.selected_log.lines,
-            map_log_render_layer(self.log_layer),
-            true,
-        );
+        let rendered_lines = self.rendered_log_lines();
         let regex_matches = self.collect_regex_match_indices(&rendered_lines);
         let selected_regex_line = if regex_matches.is_empty() {
             None
@@ -5532,12 +6539,17 @@ impl App {
             )
         };
 
+        let level_suffix = match self.log_level_fold.min_level() {
+            LogLevel::Trace => String::new(),
+            level => format!("  level:{}", level.label()),
+        };
         let info_line = format!(
-            "source:{}  layer:{}  follow:{}  scroll:{}  lines:{}{}",
+            "source:{}  layer:{}{}  follow:{}  scroll:{}  lines:{}{}",
             self.log_source.label(),
             self.log_layer.label(),
-            if self.follow_mode { "on" } else { "off" },
-            self.log_scroll,
+            level_suffix,
+            if scroll.follow_mode { "on" } else { "off" },
+            scroll.log_scroll,
             rendered_lines.len(),
             regex_suffix
         );
@@ -5572,9 +6584,12 @@ impl App {
             return frame;
         }
 
-        let (start, end, _) =
-            crate::multi_logs::log_window_bounds(rendered_lines.len(), available, self.log_scroll);
-        for (offset, line) in rendered_lines[start..end].iter().enumerate() {
+        let (start, end, _) = crate::multi_logs::log_window_bounds(
+            rendered_lines.len(),
+            available,
+            scroll.log_scroll,
+        );
+        for (offset, line) in rendered_lines[start..end].iter().enumerate() {
             let line_index = start + offset;
             let is_regex_match = regex_matches.binary_search(&line_index).is_ok();
             let role = if Some(line_index) == selected_regex_line {
@@ -6174,82 +7189,44 @@ impl App {
         height: usize,
         y_offset: usize,
     ) {
+        let query = self.help_query.trim();
         let mut lines: Vec<String> = vec![
             "=== Forge Loop TUI Help ===".to_owned(),
             "".to_owned(),
-            "Navigation:".to_owned(),
-            "  1/2/3/4/5 switch tabs (Overview/Logs/Runs/MultiLogs/Inbox)".to_owned(),
-            "  ]/[       cycle tabs".to_owned(),
-            "  b         backtrack last deep-link jump".to_owned(),
-            "  Ctrl+O    activate primary link (run/loop/url fallback)".to_owned(),
-            "  j/k       move loop selection".to_owned(),
-            "  ,/.       move run selection / multi page".to_owned(),
-            "".to_owned(),
-            "Actions:".to_owned(),
-            "  S         stop selected loop".to_owned(),
-            "  K         kill selected loop".to_owned(),
-            "  D         delete selected loop".to_owned(),
-            "  r         resume selected loop".to_owned(),
-            "  n         new loop wizard".to_owned(),
-            "  R         regex log search (logs/runs; j/k jump matches)".to_owned(),
-            "  confirm rail: tab/left/right choose action, enter selects (safe default=cancel)"
-                .to_owned(),
-            "  high-risk confirm (kill/force-delete): type reason (12+ chars)".to_owned(),
-            "  E         export current view as text/html/svg".to_owned(),
-            "".to_owned(),
-            "Command Palette:".to_owned(),
-            "  Ctrl+P    open command palette".to_owned(),
-            "  type      fuzzy search action registry".to_owned(),
-            "  tab/j/k   move result selection".to_owned(),
-            "  enter     run selected action".to_owned(),
-            "".to_owned(),
-            "Logs:".to_owned(),
-            "  v         cycle log source".to_owned(),
-            "  x         cycle log layer".to_owned(),
-            "  u/d       scroll logs".to_owned(),
-            "  l         expand logs fullscreen".to_owned(),
-            "".to_owned(),
-            "Multi Logs:".to_owned(),
-            "  m         cycle layout".to_owned(),
-            "  C         toggle side-by-side compare mode".to_owned(),
-            "  u/d       shared compare scroll (when compare enabled)".to_owned(),
-            "  space     toggle pin".to_owned(),
-            "  c         clear pinned".to_owned(),
-            "  ,/.       page left/right".to_owned(),
-            "  g/G       first/last page".to_owned(),
-            "".to_owned(),
-            "Inbox:".to_owned(),
-            "  f         cycle inbox filter (all/unread/ack-required)".to_owned(),
-            "  enter     mark selected thread read".to_owned(),
-            "  a         ack latest pending message in thread".to_owned(),
-            "  h         generate handoff snapshot package".to_owned(),
-            "  r         quick reply shortcut (thread + reply-to id)".to_owned(),
-            "  o         next claim conflict".to_owned(),
-            "  O         show conflict resolution hint".to_owned(),
-            "".to_owned(),
-            "Global:".to_owned(),
-            "  ?         toggle help".to_owned(),
-            "  q         quit".to_owned(),
-            "  t         cycle all themes".to_owned(),
-            "  T         quick cycle accessibility presets".to_owned(),
-            "  tab/shift+tab focus next/prev pane (wrap)".to_owned(),
-            "  left/right   directional pane focus traversal".to_owned(),
-            "  A         cycle accessibility quick modes (contrast/typography/motion)".to_owned(),
-            "  z         zen mode (focus right pane)".to_owned(),
-            "  Z         deep focus mode (distraction-minimized)".to_owned(),
-            "  M         cycle density (comfortable/compact)".to_owned(),
-            "  i         dismiss first-run contextual hints for current tab".to_owned(),
-            "  I         recall first-run contextual hints for current tab".to_owned(),
-            "  /         filter mode".to_owned(),
-            "  Ctrl+E/W/A jump latest ERROR/WARN/ACK evidence".to_owned(),
-            "  Ctrl+B    return to sticky evidence source".to_owned(),
-            "  Ctrl+Y    copy context (run id/log line/thread content)".to_owned(),
+            if query.is_empty() {
+                "Search: type to filter bindings".to_owned()
+            } else {
+                format!("Search: {query}  (esc clears)")
+            },
             "".to_owned(),
         ];
-        lines.extend(
-            self.keymap
-                .conflict_diagnostics_lines(width, height.saturating_sub(lines.len())),
-        );
+        lines.extend(self.keymap.conflict_diagnostics_lines(width, 3));
+        let gate_results = self.runtime_gates.evaluate();
+        if gate_results.iter().any(|result| result.samples > 0) {
+            lines.push("".to_owned());
+            lines.extend(format_gate_result_lines(&gate_results));
+        }
+        lines.push("".to_owned());
+        lines.extend(keymap_help_lines(&self.keymap, query));
+
+        let query_lower = query.to_ascii_lowercase();
+        let mut other = Vec::new();
+        for (heading, hints) in UNMAPPED_HELP_HINTS {
+            let matching = hints
+                .iter()
+                .filter(|hint| hint.to_ascii_lowercase().contains(&query_lower))
+                .collect::<Vec<_>>();
+            if matching.is_empty() {
+                continue;
+            }
+            other.push(format!("{heading}:"));
+            other.extend(matching.into_iter().map(|hint| format!("  {hint}")));
+        }
+        if !other.is_empty() {
+            lines.push("".to_owned());
+            lines.push("Other keys:".to_owned());
+            lines.extend(other);
+        }
         for (i, line) in lines.iter().enumerate() {
             if i >= height {
                 break;
@@ -6325,15 +7302,15 @@ impl App {
                 "overview workflow: inspect state here, then pivot to runs/logs for root-cause",
             ),
             MainTab::Logs => (
-                "logs: v cycle source, x cycle layer, u/d scroll, l expand pane",
+                "logs: v cycle source, x cycle layer, ctrl+u/d scroll, l expand pane",
                 "logs workflow: pick run with ,/. then inspect raw/events/errors/tools/diff",
             ),
             MainTab::Runs => (
-                "runs: ,/. select run, x layer, u/d scroll output, l expand pane",
+                "runs: ,/. select run, x layer, ctrl+u/d scroll output, l expand pane",
                 "runs workflow: compare recent exits, then drill into run output window",
             ),
             MainTab::MultiLogs => (
-                "multi logs: m layout, C compare mode, u/d sync scroll, g/G first-last page",
+                "multi logs: m layout, C compare mode, ctrl+u/d sync scroll, g/G first-last page",
                 "multi workflow: pin loops with space, compare lanes side-by-side, clear with c",
             ),
             MainTab::Inbox => (
@@ -6363,6 +7340,12 @@ impl App {
 }
 
 /// Blit a source frame onto a destination frame at the given offset.
+fn unix_epoch_ms() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_millis() as u64)
+}
+
 fn blit_frame(dest: &mut RenderFrame, src: &RenderFrame, x_offset: usize, y_offset: usize) {
     let src_size = src.size();
     for sy in 0..src_size.height {
@@ -7076,6 +8059,7 @@ mod tests {
         app.focus_right = true;
         app.pinned.insert("loop-0".to_owned());
         app.pinned.insert("loop-3".to_owned());
+        app.set_loop_note("loop-1", "waiting on credentials", 1_700);
         app.log_scroll = 22;
         app.follow_mode = false;
 
@@ -7109,6 +8093,11 @@ mod tests {
         assert!(restored.focus_right());
         assert!(restored.pinned.contains("loop-0"));
         assert!(restored.pinned.contains("loop-3"));
+        assert!(restored.has_loop_note("loop-1"));
+        assert_eq!(
+            restored.loop_note("loop-1").map(|note| note.body.as_str()),
+            Some("waiting on credentials")
+        );
         assert_eq!(restored.log_scroll(), 22);
         assert!(!restored.follow_mode());
     }
@@ -7137,6 +8126,13 @@ mod tests {
                 focused: true,
             }],
             pinned_loop_ids: vec!["missing-loop".to_owned()],
+            loop_notes: vec![LoopNote {
+                loop_id: "missing-loop".to_owned(),
+                body: "stale".to_owned(),
+                updated_at_epoch_s: 1,
+            }],
+            log_lane_order: Vec::new(),
+            pinned_log_lanes: Vec::new(),
         };
 
         let notices = app.restore_from_session_context(&context);
@@ -7158,6 +8154,10 @@ mod tests {
         assert!(notices
             .iter()
             .any(|msg| msg.contains("stored pane focus unavailable")));
+        assert!(notices
+            .iter()
+            .any(|msg| msg.contains("dropped 1 note(s) for removed loops")));
+        assert!(!app.has_loop_note("missing-loop"));
         assert_eq!(app.tab(), MainTab::Overview);
         assert_eq!(app.active_layout(), original_layout);
         assert_eq!(app.selected_id(), "loop-0");
@@ -7788,6 +8788,103 @@ mod tests {
         assert_eq!(app.mode(), UiMode::Main);
     }
 
+    #[test]
+    fn keymap_file_is_layered_over_defaults() {
+        let dir = std::env::temp_dir().join(format!("forge-tui-keymap-{}", std::process::id()));
+        let _ = std::fs::create_dir_all(&dir);
+        let path = dir.join("tui-keymap.conf");
+        let _ = std::fs::write(&path, "main ctrl+o = open-filter\n");
+
+        let mut app = App::new("default", 12);
+        let report = app.load_keymap_file(&path);
+        assert_eq!(report.map(|report| report.applied.len()), Some(1));
+        assert_eq!(app.status_text(), "Keymap loaded (1 rebind(s))");
+        assert!(app.load_keymap_file(&dir.join("missing.conf")).is_none());
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn main_mode_keys_follow_user_rebinds() {
+        let mut app = App::new("default", 12).with_keymap("main ctrl+o = open-filter\n");
+
+        app.update(key(Key::Char('/')));
+        assert_eq!(app.mode(), UiMode::Main);
+
+        app.update(InputEvent::Key(KeyEvent {
+            key: Key::Char('o'),
+            modifiers: Modifiers {
+                shift: false,
+                ctrl: true,
+                alt: false,
+            },
+        }));
+        assert_eq!(app.mode(), UiMode::Filter);
+    }
+
+    #[test]
+    fn keymap_can_restore_u_log_scroll_and_move_undo() {
+        let mut app = App::new("default", 12)
+            .with_keymap(
+                "view:logs u = scroll-logs-up\nview:logs d = scroll-logs-down\nmain U = undo-last-action\n",
+            );
+        app.set_tab(MainTab::Logs);
+        app.handle_action_result(ActionResult {
+            kind: ActionType::Stop,
+            loop_id: "loop-a".to_owned(),
+            selected_loop_id: String::new(),
+            message: "Stopped".to_owned(),
+            error: None,
+        });
+
+        app.update(key(Key::Char('u')));
+        assert!(app.log_scroll() > 0);
+        assert_eq!(app.undo_depth(), 1);
+        app.update(key(Key::Char('d')));
+        assert_eq!(app.log_scroll(), 0);
+
+        match app.update(key(Key::Char('U'))) {
+            Command::RunAction(ActionKind::Resume { loop_id }) => assert_eq!(loop_id, "loop-a"),
+            other => panic!("expected resume, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn new_app_uses_default_keymap_until_one_is_passed_in() {
+        let app = App::new("default", 12);
+        assert_eq!(app.status_text(), "");
+
+        let app = App::new("default", 12).with_keymap("main ctrl+o = open-filter\n");
+        assert_eq!(app.status_text(), "Keymap loaded (1 rebind(s))");
+    }
+
+    #[test]
+    fn help_search_filters_generated_bindings() {
+        let mut app = App::new("default", 12);
+        app.height = 80;
+        let report = app.load_keymap_config("main ctrl+o = open-filter\n");
+        assert!(report.conflicts.is_empty());
+        app.update(key(Key::Char('?')));
+        for ch in "filter".chars() {
+            app.update(key(Key::Char(ch)));
+        }
+        assert_eq!(app.mode(), UiMode::Help);
+        let frame = app.render();
+        let all_text = (0..app.height())
+            .map(|row| frame.row_text(row))
+            .collect::<Vec<String>>()
+            .join("\n");
+        assert!(all_text.contains("Search: filter"));
+        assert!(all_text.contains("Ctrl+O     open filter"));
+        assert!(all_text.contains("f         cycle inbox filter"));
+        assert!(!all_text.contains("selection next"));
+
+        app.update(key(Key::Escape));
+        assert_eq!(app.mode(), UiMode::Help);
+        app.update(key(Key::Escape));
+        assert_eq!(app.mode(), UiMode::Main);
+    }
+
     #[test]
     fn help_returns_to_previous_mode() {
         let mut app = App::new("default", 12);
@@ -7823,8 +8920,10 @@ mod tests {
             .map(|row| frame.row_text(row))
             .collect::<Vec<String>>()
             .join("\n");
-        assert!(all_text.contains("Ctrl+E/W/A jump latest ERROR/WARN/ACK evidence"));
-        assert!(all_text.contains("Ctrl+B    return to sticky evidence source"));
+        assert!(all_text.contains("Ctrl+E     jump latest error evidence"));
+        assert!(all_text.contains("Ctrl+W     jump latest warning evidence"));
+        assert!(all_text.contains("Ctrl+A     jump latest ack evidence"));
+        assert!(all_text.contains("Ctrl+B     jump back from evidence"));
     }
 
     #[test]
@@ -7904,6 +9003,155 @@ mod tests {
         assert_eq!(cmd, Command::ExportCurrentView);
     }
 
+    #[test]
+    fn refresh_polls_through_controller_in_event_driven_mode() {
+        let mut app = App::new("default", 12);
+        app.set_refresh_mode(RefreshMode::EventDriven);
+        assert_eq!(app.poll_refresh(1_000), Command::Fetch);
+        assert_eq!(app.poll_refresh(60_000), Command::None);
+
+        assert!(!app.observe_refresh_events(&[EventType::NodeUpdated], "evt-1"));
+        assert_eq!(app.poll_refresh(61_000), Command::None);
+        assert!(app.observe_refresh_events(&[EventType::LoopStateChanged], "evt-2"));
+        assert_eq!(app.refresh_event_cursor(), "evt-2");
+        assert_eq!(app.poll_refresh(61_001), Command::Fetch);
+        assert_eq!(app.poll_refresh(61_002), Command::None);
+    }
+
+    #[test]
+    fn tick_events_drive_event_driven_refresh() {
+        let mut app = App::new("default", 12);
+        app.set_refresh_mode(RefreshMode::EventDriven);
+        assert_eq!(app.update(InputEvent::Tick), Command::Fetch);
+        assert_eq!(app.update(InputEvent::Tick), Command::None);
+        assert!(app.observe_refresh_events(&[EventType::LoopStateChanged], "evt-1"));
+        assert_eq!(app.update(InputEvent::Tick), Command::Fetch);
+    }
+
+    #[test]
+    fn compare_key_marks_runs_and_renders_comparison() {
+        let mut app = app_with_loops(1);
+        app.set_run_history(vec![
+            RunView {
+                id: "run-new".to_owned(),
+                status: "success".to_owned(),
+                duration: "2m0s".to_owned(),
+                attempts: Some(3),
+                cost_usd: Some(0.75),
+                ..RunView::default()
+            },
+            RunView {
+                id: "run-old".to_owned(),
+                status: "success".to_owned(),
+                duration: "1m0s".to_owned(),
+                attempts: Some(1),
+                cost_usd: Some(0.75),
+                ..RunView::default()
+            },
+        ]);
+        app.set_tab(MainTab::Runs);
+
+        app.update(key(Key::Char('V')));
+        assert_eq!(
+            app.status_text(),
+            "compare: run-new selected; pick a second run"
+        );
+        assert!(app.run_comparison().is_none());
+
+        app.update(key(Key::Char('.')));
+        app.update(key(Key::Char('V')));
+        assert_eq!(
+            app.status_text(),
+            "compare: run-new vs run-old (2 field(s) differ)"
+        );
+
+        // A refresh that prepends a run keeps the marks on the same runs.
+        let mut runs = vec![RunView {
+            id: "run-newest".to_owned(),
+            status: "running".to_owned(),
+            ..RunView::default()
+        }];
+        runs.extend(app.run_history().iter().cloned());
+        app.set_run_history(runs);
+        let Some(comparison) = app.run_comparison() else {
+            panic!("expected comparison after refresh");
+        };
+        assert_eq!(comparison.left_id, "run-new");
+        assert_eq!(comparison.right_id, "run-old");
+        assert_eq!(comparison.fields[1].delta.as_deref(), Some("-2"));
+
+        let snapshot = app.render().snapshot();
+        assert!(snapshot.contains("Compare run-new -> run-old"));
+    }
+
+    #[test]
+    fn log_compare_key_shows_both_runs_output() {
+        let mut app = app_with_loops(1);
+        app.set_run_history(vec![
+            RunView {
+                id: "run-new".to_owned(),
+                output_lines: vec!["build ok".to_owned(), "tests ok".to_owned()],
+                ..RunView::default()
+            },
+            RunView {
+                id: "run-old".to_owned(),
+                output_lines: vec!["build ok".to_owned(), "tests failed".to_owned()],
+                ..RunView::default()
+            },
+        ]);
+        app.set_tab(MainTab::Runs);
+
+        app.update(key(Key::Char('L')));
+        assert_eq!(app.status_text(), "log compare: mark two runs with V first");
+
+        app.update(key(Key::Char('V')));
+        app.update(key(Key::Char('.')));
+        app.update(key(Key::Char('V')));
+        app.update(key(Key::Char('L')));
+        assert_eq!(app.status_text(), "log compare: on");
+        let snapshot = app.render().snapshot();
+        assert!(snapshot.contains("Log compare run-new -> run-old"));
+        assert!(snapshot.contains("equal=1 differ=1"));
+
+        app.update(key(Key::Char('L')));
+        let snapshot = app.render().snapshot();
+        assert!(snapshot.contains("Compare run-new -> run-old"));
+        assert!(!snapshot.contains("Log compare"));
+    }
+
+    #[test]
+    fn daily_summary_key_exports_loop_summary() {
+        let mut app = App::new("default", 12);
+        let mut loops = sample_loops(3);
+        loops[1].short_id = "s1".to_owned();
+        loops[1].runs = 4;
+        loops[2].short_id = "s2".to_owned();
+        loops[2].last_error = "harness crashed".to_owned();
+        loops[2].queue_depth = 2;
+        app.set_loops(loops);
+
+        let dir = std::env::temp_dir().join(format!("forge-tui-daily-{}", std::process::id()));
+        app.set_daily_summary_dir(&dir);
+        assert_eq!(app.update(key(Key::Char('W'))), Command::None);
+        assert!(app.status_text().starts_with("Daily summary written to "));
+
+        let path = match app.export_daily_summary("2026-02-12") {
+            Ok(path) => path,
+            Err(err) => panic!("export daily summary: {err}"),
+        };
+        assert!(path.ends_with("forge-daily-summary-2026-02-12.md"));
+        let markdown = match std::fs::read_to_string(&path) {
+            Ok(markdown) => markdown,
+            Err(err) => panic!("read {}: {err}", path.display()),
+        };
+        let _ = std::fs::remove_dir_all(&dir);
+        assert!(markdown.starts_with("# Forge Daily Summary (2026-02-12)\n"));
+        assert!(markdown.contains("- s1: test-loop-1 | 4 run(s)"));
+        assert!(markdown.contains("## Blockers (1)\n\n- s2: test-loop-2 | harness crashed"));
+        assert!(markdown.contains("- s2: test-loop-2 | 2 queued"));
+        assert!(markdown.contains("| loop runs | 4 |"));
+    }
+
     // -- selection --
 
     #[test]
@@ -8250,12 +9498,49 @@ mod tests {
     // -- log scrolling --
 
     #[test]
-    fn u_d_scroll_in_logs_tab() {
+    fn ctrl_u_scrolls_up_in_logs_tab() {
         let mut app = App::new("default", 12);
         app.set_tab(MainTab::Logs);
         assert_eq!(app.log_scroll(), 0);
-        app.update(key(Key::Char('u')));
+        app.update(ctrl_key('u'));
         assert!(app.log_scroll() > 0);
+
+        let scrolled = app.log_scroll();
+        app.update(key(Key::Char('d')));
+        assert_eq!(app.log_scroll(), scrolled, "bare d no longer scrolls");
+        app.update(ctrl_key('d'));
+        assert!(app.log_scroll() < scrolled);
+    }
+
+    #[test]
+    fn pinned_log_lane_renders_first_in_logs_pane() {
+        let mut app = app_with_loops(2);
+        app.set_tab(MainTab::Logs);
+        app.set_selected_log(LogTailView {
+            lines: vec![
+                "plain output".to_owned(),
+                "error: failed to connect".to_owned(),
+            ],
+            message: String::new(),
+        });
+        app.update(InputEvent::Resize(ResizeEvent {
+            width: 120,
+            height: 30,
+        }));
+        app.update(key(Key::Char('i')));
+        let row_of = |snapshot: &str, needle: &str| {
+            snapshot
+                .lines()
+                .position(|line| line.contains(needle))
+                .unwrap_or_else(|| panic!("{needle:?} missing from snapshot"))
+        };
+
+        let snapshot = app.render().snapshot();
+        assert!(row_of(&snapshot, "plain output") < row_of(&snapshot, "error: failed"));
+
+        app.toggle_log_lane_pin(LogLane::Stderr);
+        let snapshot = app.render().snapshot();
+        assert!(row_of(&snapshot, "error: failed") < row_of(&snapshot, "plain output"));
     }
 
     #[test]
@@ -8456,7 +9741,7 @@ mod tests {
         let mut app = App::new("default", 12);
         app.set_tab(MainTab::Logs);
         assert!(app.follow_mode());
-        app.update(key(Key::Char('u')));
+        app.update(ctrl_key('u'));
         assert!(!app.follow_mode());
     }
 
@@ -8464,7 +9749,7 @@ mod tests {
     fn scroll_to_bottom_reengages_follow() {
         let mut app = App::new("default", 12);
         app.set_tab(MainTab::Logs);
-        app.update(key(Key::Char('u')));
+        app.update(ctrl_key('u'));
         assert!(!app.follow_mode());
         app.scroll_logs_to_bottom();
         assert!(app.follow_mode());
@@ -8498,7 +9783,7 @@ mod tests {
     fn follow_mode_resets_on_tab_change() {
         let mut app = App::new("default", 12);
         app.set_tab(MainTab::Logs);
-        app.update(key(Key::Char('u')));
+        app.update(ctrl_key('u'));
         assert!(!app.follow_mode());
         app.set_tab(MainTab::Runs);
         assert!(app.follow_mode());
@@ -8508,7 +9793,7 @@ mod tests {
     fn follow_mode_resets_on_source_change() {
         let mut app = App::new("default", 12);
         app.set_tab(MainTab::Logs);
-        app.update(key(Key::Char('u')));
+        app.update(ctrl_key('u'));
         assert!(!app.follow_mode());
         app.cycle_log_source(1);
         assert!(app.follow_mode());
@@ -8558,7 +9843,7 @@ mod tests {
             width: 160,
             height: 30,
         }));
-        app.update(key(Key::Char('u')));
+        app.update(ctrl_key('u'));
         let frame = app.render();
         let header = frame.row_text(0);
         assert!(
@@ -8627,6 +9912,8 @@ mod tests {
                 auth_kind: "ssh".into(),
                 started_at: "2026-02-13T12:00:00Z".into(),
                 output_lines: vec!["output-a-line-1".into(), "output-a-line-2".into()],
+                attempts: None,
+                cost_usd: None,
             },
             RunView {
                 id: "run-b".into(),
@@ -8639,6 +9926,8 @@ mod tests {
                 auth_kind: "ssh".into(),
                 started_at: "2026-02-13T12:01:00Z".into(),
                 output_lines: vec!["output-b-line-1".into(), "output-b-line-2".into()],
+                attempts: None,
+                cost_usd: None,
             },
         ]);
         app.update(InputEvent::Resize(ResizeEvent {
@@ -8673,6 +9962,8 @@ mod tests {
             output_lines: (0..220)
                 .map(|idx| format!("scroll-line-{idx:03}"))
                 .collect(),
+            attempts: None,
+            cost_usd: None,
         }]);
         app.update(InputEvent::Resize(ResizeEvent {
             width: 110,
@@ -8683,7 +9974,7 @@ mod tests {
         let before = app.render().snapshot();
         assert!(before.contains("scroll-line-219"), "snapshot:\n{before}");
 
-        app.update(key(Key::Char('u')));
+        app.update(ctrl_key('u'));
         let after = app.render().snapshot();
         assert!(after.contains("scroll-line-193"), "snapshot:\n{after}");
         assert!(!after.contains("scroll-line-219"), "snapshot:\n{after}");
@@ -9304,6 +10595,32 @@ mod tests {
         }
     }
 
+    #[test]
+    fn confirm_takeover_submits_with_reason() {
+        let mut app = app_with_loops(3);
+        app.update(key(Key::Char('X')));
+        let confirm = match app.confirm() {
+            Some(v) => v,
+            None => panic!("expected confirm state"),
+        };
+        assert_eq!(confirm.action, ActionType::Takeover);
+        assert!(confirm.reason_required);
+        assert!(confirm.prompt.contains("Take over stale loop"));
+
+        app.update(key(Key::Tab));
+        for ch in "runner host rebooted".chars() {
+            app.update(key(Key::Char(ch)));
+        }
+        let cmd = app.update(key(Key::Enter));
+        match cmd {
+            Command::RunAction(ActionKind::Takeover { loop_id, reason }) => {
+                assert_eq!(loop_id, "loop-0");
+                assert_eq!(reason, "runner host rebooted");
+            }
+            other => panic!("Expected RunAction(Takeover), got {other:?}"),
+        }
+    }
+
     // -- confirm no selection --
 
     #[test]
@@ -9481,6 +10798,295 @@ mod tests {
 
     // -- action busy --
 
+    #[test]
+    fn resize_recomputes_responsive_dashboard_layout() {
+        let mut app = App::new("default", 12);
+        assert_eq!(app.dashboard_layout().status_label(), "2col");
+        app.update(InputEvent::Resize(ResizeEvent {
+            width: 80,
+            height: 24,
+        }));
+        assert_eq!(app.dashboard_layout().columns(), 1);
+        assert_eq!(app.dashboard_layout().status_label(), "stacked (1 hidden)");
+        assert!(app
+            .render()
+            .snapshot()
+            .contains("layout:stacked (1 hidden)"));
+        app.update(InputEvent::Resize(ResizeEvent {
+            width: 200,
+            height: 50,
+        }));
+        assert_eq!(app.dashboard_layout().status_label(), "3col");
+    }
+
+    #[test]
+    fn split_routes_input_to_focused_pane_and_reflows_on_resize() {
+        let mut app = App::new("default", 12);
+        app.update(InputEvent::Resize(ResizeEvent {
+            width: 120,
+            height: 30,
+        }));
+        app.update(key(Key::Char('\\')));
+        let Some(split) = app.split_layout() else {
+            panic!("split should be open");
+        };
+        assert_eq!(split.tab(SplitPane::Primary), MainTab::Overview);
+        assert_eq!(split.tab(SplitPane::Secondary), MainTab::Logs);
+        assert_eq!(app.tab(), MainTab::Overview);
+
+        // 'L' only affects the logs pane, so it is ignored while overview has focus.
+        app.update(key(Key::Char('L')));
+        assert!(!app.status_text().starts_with("Log level"));
+
+        app.update(key(Key::Char('`')));
+        assert_eq!(app.tab(), MainTab::Logs);
+        app.update(key(Key::Char('L')));
+        assert!(app.status_text().starts_with("Log level"));
+
+        let Some((left, right)) = app.split_pane_rects() else {
+            panic!("split rects");
+        };
+        assert_eq!((left.width, right.x, right.width), (60, 60, 60));
+
+        app.update(InputEvent::Resize(ResizeEvent {
+            width: 80,
+            height: 30,
+        }));
+        let Some((left, right)) = app.split_pane_rects() else {
+            panic!("split rects");
+        };
+        assert_eq!((left.width, right.x, right.width), (40, 40, 40));
+        assert_eq!(left.height, right.height);
+    }
+
+    #[test]
+    fn split_panes_keep_independent_log_scroll() {
+        let mut app = app_with_loops(1);
+        app.set_tab(MainTab::Logs);
+        app.set_selected_log(LogTailView {
+            lines: (0..200).map(|idx| format!("line {idx}")).collect(),
+            message: String::new(),
+        });
+        app.update(key(Key::Char('\\')));
+        app.update(ctrl_key('u'));
+        let scrolled = app.log_scroll();
+        assert!(scrolled > 0);
+        assert!(!app.follow_mode());
+
+        app.update(key(Key::Char('`')));
+        assert_eq!(app.tab(), MainTab::Overview);
+        assert_eq!(app.log_scroll(), 0);
+        assert!(app.follow_mode());
+
+        app.update(key(Key::Char('`')));
+        assert_eq!(app.tab(), MainTab::Logs);
+        assert_eq!(app.log_scroll(), scrolled);
+        assert!(!app.follow_mode());
+    }
+
+    #[test]
+    fn fold_key_expands_folded_lines_in_logs_view() {
+        let mut app = app_with_loops(1);
+        app.set_tab(MainTab::Logs);
+        app.set_selected_log(LogTailView {
+            lines: vec![
+                "WARN retrying".to_owned(),
+                "DEBUG attempt 1".to_owned(),
+                "DEBUG attempt 2".to_owned(),
+                "ERROR gave up".to_owned(),
+            ],
+            message: String::new(),
+        });
+        app.update(key(Key::Char('L')));
+        app.update(key(Key::Char('L')));
+        app.update(key(Key::Char('L')));
+        assert_eq!(app.status_text(), "Log level: warn+");
+        assert!(app.rendered_log_lines().contains(&"… 2 lines …".to_owned()));
+
+        app.update(key(Key::Char('H')));
+        assert_eq!(app.status_text(), "Fold expanded");
+        assert!(app
+            .rendered_log_lines()
+            .contains(&"DEBUG attempt 2".to_owned()));
+
+        app.update(key(Key::Char('H')));
+        assert_eq!(app.status_text(), "Fold collapsed");
+    }
+
+    #[test]
+    fn undo_after_stop_dispatches_resume() {
+        let mut app = App::new("default", 12);
+        app.handle_action_result(ActionResult {
+            kind: ActionType::Stop,
+            loop_id: "loop-a".to_owned(),
+            selected_loop_id: String::new(),
+            message: "Stopped".to_owned(),
+            error: None,
+        });
+        assert_eq!(app.undo_depth(), 1);
+
+        match app.update(key(Key::Char('u'))) {
+            Command::RunAction(ActionKind::Resume { loop_id }) => assert_eq!(loop_id, "loop-a"),
+            other => panic!("expected resume, got {other:?}"),
+        }
+        assert_eq!(app.status_text(), "Undoing stop loop-a...");
+        app.handle_action_result(ActionResult {
+            kind: ActionType::Resume,
+            loop_id: "loop-a".to_owned(),
+            selected_loop_id: String::new(),
+            message: "Resumed".to_owned(),
+            error: None,
+        });
+        assert_eq!(app.undo_depth(), 0);
+    }
+
+    #[test]
+    fn undo_restores_deleted_bookmark() {
+        let mut app = App::new("default", 12);
+        let Some(anchor_id) = app.add_log_anchor(crate::log_anchors::LogAnchorDraft {
+            marker: "oom".to_owned(),
+            loop_id: "loop-a".to_owned(),
+            log_source: "live".to_owned(),
+            line_index: 42,
+            timestamp: "2026-02-12T11:00:00Z".to_owned(),
+            excerpt: "error: out of memory".to_owned(),
+            annotation: "check heap limits".to_owned(),
+            tags: vec!["memory".to_owned()],
+            created_by: "operator".to_owned(),
+            created_at: "2026-02-12T11:01:00Z".to_owned(),
+        }) else {
+            panic!("bookmark should be added: {}", app.status_text());
+        };
+        let before = app.log_anchors().clone();
+
+        app.delete_log_anchor(&anchor_id);
+        assert!(!app.log_anchors().contains(&anchor_id));
+        assert_eq!(app.undo_depth(), 1);
+
+        assert_eq!(app.update(key(Key::Char('u'))), Command::Fetch);
+        assert_eq!(app.log_anchors(), &before);
+        assert_eq!(app.undo_depth(), 0);
+    }
+
+    #[test]
+    fn failed_undo_keeps_entry_on_stack() {
+        let mut app = App::new("default", 12);
+        app.set_tab(MainTab::Logs);
+        app.handle_action_result(ActionResult {
+            kind: ActionType::Stop,
+            loop_id: "loop-a".to_owned(),
+            selected_loop_id: String::new(),
+            message: "Stopped".to_owned(),
+            error: None,
+        });
+
+        match app.update(key(Key::Char('u'))) {
+            Command::RunAction(ActionKind::Resume { loop_id }) => assert_eq!(loop_id, "loop-a"),
+            other => panic!("expected resume, got {other:?}"),
+        }
+        assert_eq!(app.undo_depth(), 0);
+        app.handle_action_result(ActionResult {
+            kind: ActionType::Resume,
+            loop_id: "loop-a".to_owned(),
+            selected_loop_id: String::new(),
+            message: String::new(),
+            error: Some("loop runner unavailable".to_owned()),
+        });
+        assert_eq!(app.undo_depth(), 1);
+        assert_eq!(app.status_kind(), StatusKind::Err);
+    }
+
+    #[test]
+    fn runtime_gate_breach_sets_error_status() {
+        let mut app = App::new("default", 12);
+        app.set_runtime_budgets(RuntimeBudgets {
+            render_ms: 20,
+            ..RuntimeBudgets::default()
+        });
+        app.record_runtime_gate(RuntimeGate::Render, 10);
+        assert_ne!(app.status_kind(), StatusKind::Err);
+
+        app.record_runtime_gate(RuntimeGate::Render, 40);
+        assert_eq!(app.status_kind(), StatusKind::Err);
+        assert!(app.status_text().contains("render p95 40ms > 20ms budget"));
+        assert!(app
+            .runtime_gate_results()
+            .iter()
+            .any(|result| result.gate == RuntimeGate::Render && !result.passed));
+    }
+
+    #[test]
+    fn log_queries_persist_across_app_instances() {
+        let dir =
+            std::env::temp_dir().join(format!("forge-tui-log-queries-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let path = dir.join("tui-log-queries.json");
+
+        let mut app = App::new("default", 12);
+        app.load_log_query_book_file(&path);
+        app.set_selected_log(LogTailView {
+            lines: vec!["error: boom".to_owned(), "plain output".to_owned()],
+            message: String::new(),
+        });
+        app.log_query_editor_mut().set_input("lane:stderr");
+        assert_eq!(app.run_log_query(), Ok(vec!["error: boom".to_owned()]));
+        app.log_query_editor_mut().set_input("boom OR plain");
+        assert_eq!(app.save_log_query("both"), Ok(()));
+
+        let mut restored = App::new("default", 12);
+        restored.load_log_query_book_file(&path);
+        assert_eq!(
+            restored.log_query_editor().book().history(),
+            vec!["lane:stderr"]
+        );
+        assert!(restored.log_query_editor_mut().recall_saved("both"));
+        assert_eq!(restored.log_query_editor().input(), "boom OR plain");
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn runtime_gates_record_timings_and_feed_doctor_report() {
+        let dir = std::env::temp_dir().join(format!("forge-tui-gates-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap_or_else(|err| panic!("create dir: {err}"));
+        let budgets = dir.join("tui-budgets.json");
+        std::fs::write(&budgets, r#"{"poll_latency_ms": 400}"#)
+            .unwrap_or_else(|err| panic!("write budgets: {err}"));
+
+        let mut app = App::new("default", 12);
+        app.load_runtime_budgets_file(&budgets);
+        let mut now_ms = 0;
+        while app.poll_refresh(now_ms) != Command::Fetch {
+            now_ms += 1_000;
+        }
+        app.set_loops(sample_loops(2));
+        let _ = app.render_timed();
+
+        let results = app.runtime_gate_results();
+        let samples = |gate: RuntimeGate| {
+            results
+                .iter()
+                .find(|result| result.gate == gate)
+                .map_or(0, |result| result.samples)
+        };
+        assert_eq!(samples(RuntimeGate::PollLatency), 1);
+        assert_eq!(samples(RuntimeGate::FrameBuild), 1);
+        assert_eq!(samples(RuntimeGate::Render), 0);
+        assert!(results
+            .iter()
+            .any(|result| result.gate == RuntimeGate::PollLatency && result.threshold_ms == 400));
+
+        let report = dir.join("tui-performance.json");
+        app.write_runtime_gate_report(&report)
+            .unwrap_or_else(|err| panic!("write report: {err}"));
+        let checks = forge_cli::doctor::read_check_report(&report)
+            .unwrap_or_else(|err| panic!("read report: {err}"));
+        assert!(checks
+            .iter()
+            .any(|check| check.category == "tui-performance" && check.name == "frame_build_ms"));
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
     #[test]
     fn set_status_enqueues_notification_event() {
         let mut app = App::new("default", 12);
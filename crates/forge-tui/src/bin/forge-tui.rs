@@ -1,8 +1,13 @@
 use std::collections::HashMap;
 use std::io::IsTerminal;
 use std::path::{Path, PathBuf};
+use std::time::Instant;
 
-use forge_tui::app::{App, LoopView};
+use forge_tui::app::{App, LoopView, RunView};
+use forge_tui::daily_summary::user_daily_summary_dir;
+use forge_tui::keymap::{read_keymap_config, user_keymap_config_path};
+use forge_tui::performance_gates::{doctor_report_path, gate_elapsed_ms, RuntimeGate};
+use forge_tui::polling_pipeline::RefreshMode;
 use forge_tui::theme::detect_terminal_color_capability;
 
 #[derive(Debug, Clone, Default)]
@@ -18,6 +23,8 @@ struct LiveLoopSnapshot {
     teams: Vec<TeamSummaryView>,
     team_tasks: Vec<TeamTaskInboxView>,
     log_paths: HashMap<String, String>,
+    /// Run history of the first loop, which the app selects on load.
+    run_history: Vec<RunView>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -95,13 +102,44 @@ fn render_snapshot_text() -> String {
     output
 }
 
+/// Read the user keymap config, if one exists; read errors are warned about
+/// and the defaults kept.
+fn load_user_keymap_config() -> Option<String> {
+    let path = user_keymap_config_path()?;
+    match read_keymap_config(&path) {
+        Ok(config) => config,
+        Err(err) => {
+            eprintln!("warning: {err}");
+            None
+        }
+    }
+}
+
+/// Read `tui.refresh_mode` from the layered forge config; load errors are
+/// warned about and interval refresh kept.
+fn load_refresh_mode() -> RefreshMode {
+    let loaded = forge_core::config::load_layered_config("", |key| std::env::var(key).ok());
+    match loaded {
+        Ok((resolved, _)) => {
+            RefreshMode::parse(&resolved.config.tui.refresh_mode).unwrap_or_default()
+        }
+        Err(err) => {
+            eprintln!("warning: {err}");
+            RefreshMode::default()
+        }
+    }
+}
+
 fn render_snapshot_lines() -> Vec<String> {
     let db_path = resolve_database_path();
-    render_snapshot_lines_for_path(&db_path)
+    render_snapshot_lines_for_path(&db_path, doctor_report_path().as_deref())
 }
 
-fn render_snapshot_lines_for_path(db_path: &Path) -> Vec<String> {
+/// Render the snapshot, timing load/build/render as runtime gate samples and
+/// leaving the results at `gate_report` for `forge doctor`.
+fn render_snapshot_lines_for_path(db_path: &Path, gate_report: Option<&Path>) -> Vec<String> {
     let mut lines = Vec::new();
+    let load_started = Instant::now();
     let snapshot = match load_live_loop_snapshot(db_path) {
         Ok(snapshot) => snapshot,
         Err(err) => {
@@ -112,8 +150,32 @@ fn render_snapshot_lines_for_path(db_path: &Path) -> Vec<String> {
 
     let capability = detect_terminal_color_capability();
     let mut app = App::new_with_capability("default", capability, 200);
+    if let Some(config) = load_user_keymap_config() {
+        app = app.with_keymap(&config);
+    }
+    app.set_refresh_mode(load_refresh_mode());
+    if let Some(dir) = user_daily_summary_dir() {
+        app.set_daily_summary_dir(&dir);
+    }
+    app.record_runtime_gate(
+        RuntimeGate::PollLatency,
+        gate_elapsed_ms(load_started.elapsed()),
+    );
     app.set_loops(snapshot.loops.clone());
-    lines.extend(app.render().snapshot().lines().map(str::to_owned));
+    app.set_run_history(snapshot.run_history.clone());
+    let frame = app.render_timed();
+    let render_started = Instant::now();
+    let rendered = frame.snapshot();
+    app.record_runtime_gate(
+        RuntimeGate::Render,
+        gate_elapsed_ms(render_started.elapsed()),
+    );
+    if let Some(path) = gate_report {
+        if let Err(err) = app.write_runtime_gate_report(path) {
+            eprintln!("warning: runtime gate report: {err}");
+        }
+    }
+    lines.extend(rendered.lines().map(str::to_owned));
     lines.push(String::new());
     lines.push("forge loop snapshot (rust)".to_string());
     lines.push(format!("db: {}", db_path.display()));
@@ -292,6 +354,14 @@ fn load_live_loop_snapshot(db_path: &Path) -> Result<LiveLoopSnapshot, String> {
                 .unwrap_or(loop_row.pool_id.clone())
         };
 
+        let stop_cause = if loop_row.state == forge_db::loop_repository::LoopState::Stopped {
+            forge_cli::explain::load_stop_cause(&db, &loop_row.id)?
+                .map(|cause| cause.reason)
+                .unwrap_or_default()
+        } else {
+            String::new()
+        };
+
         if !loop_row.log_path.is_empty() {
             snapshot
                 .log_paths
@@ -311,6 +381,7 @@ fn load_live_loop_snapshot(db_path: &Path) -> Result<LiveLoopSnapshot, String> {
             max_runtime_seconds: loop_row.max_runtime_seconds,
             max_iterations: loop_row.max_iterations,
             last_error: loop_row.last_error,
+            stop_cause,
             profile_name,
             profile_harness,
             profile_auth,
@@ -326,6 +397,19 @@ fn load_live_loop_snapshot(db_path: &Path) -> Result<LiveLoopSnapshot, String> {
             .cmp(&right.name.to_ascii_lowercase())
             .then_with(|| left.id.cmp(&right.id))
     });
+    if let Some(first) = snapshot.loops.first() {
+        snapshot.run_history = match run_repo.list_by_loop(&first.id) {
+            Ok(runs) => runs
+                .into_iter()
+                .map(|run| {
+                    let profile = profile_map.get(&run.profile_id);
+                    run_view_from_record(run, profile)
+                })
+                .collect(),
+            Err(err) if is_missing_table(&err, "loop_runs") => Vec::new(),
+            Err(err) => return Err(err.to_string()),
+        };
+    }
 
     let team_repo = forge_db::team_repository::TeamRepository::new(&db);
     let team_task_repo = forge_db::team_task_repository::TeamTaskRepository::new(&db);
@@ -398,6 +482,63 @@ fn load_live_loop_snapshot(db_path: &Path) -> Result<LiveLoopSnapshot, String> {
     Ok(snapshot)
 }
 
+fn run_view_from_record(
+    run: forge_db::loop_run_repository::LoopRun,
+    profile: Option<&(String, String, String)>,
+) -> RunView {
+    let (profile_name, harness, auth_kind) = profile.cloned().unwrap_or_default();
+    let duration = if run.finished_at.is_none() {
+        "running".to_owned()
+    } else {
+        "-".to_owned()
+    };
+    RunView {
+        attempts: run_attempts(&run),
+        cost_usd: run_cost_usd(&run),
+        id: run.id,
+        status: run.status.as_str().to_owned(),
+        exit_code: run.exit_code,
+        duration,
+        profile_name,
+        profile_id: run.profile_id,
+        harness,
+        auth_kind,
+        started_at: run.started_at,
+        output_lines: run.output_tail.lines().map(str::to_owned).collect(),
+    }
+}
+
+/// Attempts recorded in the run metadata.
+fn run_attempts(run: &forge_db::loop_run_repository::LoopRun) -> Option<u32> {
+    run.metadata
+        .as_ref()
+        .and_then(|meta| meta.get("attempts"))
+        .and_then(serde_json::Value::as_u64)
+        .and_then(|attempts| u32::try_from(attempts).ok())
+}
+
+/// Spend recorded in the run metadata, else the `total_cost_usd` the harness
+/// reported in its final result line.
+fn run_cost_usd(run: &forge_db::loop_run_repository::LoopRun) -> Option<f64> {
+    if let Some(cost) = run
+        .metadata
+        .as_ref()
+        .and_then(|meta| meta.get("cost_usd"))
+        .and_then(serde_json::Value::as_f64)
+    {
+        return Some(cost);
+    }
+    run.output_tail.lines().rev().find_map(|line| {
+        let value = serde_json::from_str::<serde_json::Value>(line.trim()).ok()?;
+        if value.get("type").and_then(serde_json::Value::as_str) != Some("result") {
+            return None;
+        }
+        value
+            .get("total_cost_usd")
+            .and_then(serde_json::Value::as_f64)
+    })
+}
+
 fn payload_title(payload_json: &str) -> String {
     serde_json::from_str::<serde_json::Value>(payload_json)
         .ok()
@@ -563,9 +704,16 @@ mod tests {
         let mut run = LoopRun {
             loop_id: loop_entry.id.clone(),
             profile_id: profile.id.clone(),
+            metadata: Some(std::collections::HashMap::from([(
+                "attempts".to_string(),
+                serde_json::json!(2),
+            )])),
             ..Default::default()
         };
         ok_or_panic(run_repo.create(&mut run), "create loop run");
+        run.output_tail =
+            "done\n{\"type\":\"result\",\"num_turns\":1,\"total_cost_usd\":0.25}".to_string();
+        ok_or_panic(run_repo.finish(&mut run), "finish loop run");
 
         let snapshot = ok_or_panic(load_live_loop_snapshot(&path), "load live snapshot");
         assert_eq!(snapshot.loops.len(), 1);
@@ -582,6 +730,14 @@ mod tests {
         assert_eq!(view.profile_auth, "oauth");
         assert_eq!(view.pool_name, "default");
 
+        assert_eq!(snapshot.run_history.len(), 1);
+        let run_view = &snapshot.run_history[0];
+        assert_eq!(run_view.id, run.id);
+        assert_eq!(run_view.profile_name, "dev");
+        assert_eq!(run_view.attempts, Some(2));
+        assert_eq!(run_view.cost_usd, Some(0.25));
+        assert_eq!(run_view.output_lines.len(), 2);
+
         cleanup_temp_dir(&path);
     }
 
@@ -628,7 +784,10 @@ mod tests {
         ok_or_panic(db.migrate_up(), "migrate db");
 
         let team_service = TeamService::new(&db);
-        let team = ok_or_panic(team_service.create_team("ops", "{}", "", 300), "create team");
+        let team = ok_or_panic(
+            team_service.create_team("ops", "{}", "", 300),
+            "create team",
+        );
         ok_or_panic(
             team_service.add_member(&team.id, "agent-lead", TeamRole::Leader),
             "add team leader",
@@ -636,7 +795,11 @@ mod tests {
 
         let task_service = TeamTaskService::new(&db);
         ok_or_panic(
-            task_service.submit(&team.id, r#"{"type":"incident","title":"database outage"}"#, 5),
+            task_service.submit(
+                &team.id,
+                r#"{"type":"incident","title":"database outage"}"#,
+                5,
+            ),
             "submit queued task",
         );
         let assigned = ok_or_panic(
@@ -655,7 +818,7 @@ mod tests {
         assert_eq!(snapshot.teams[0].open, 2);
         assert_eq!(snapshot.team_tasks.len(), 2);
 
-        let lines = render_snapshot_lines_for_path(&path);
+        let lines = render_snapshot_lines_for_path(&path, None);
         let rendered = lines.join("\n");
         assert!(rendered.contains("teams snapshot (read-only):"));
         assert!(rendered.contains("ops"));
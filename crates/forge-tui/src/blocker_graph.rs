@@ -15,6 +15,7 @@ pub struct TaskDependencySample {
 pub struct DependencyEdge {
     pub blocker_task_id: String,
     pub blocked_task_id: String,
+    pub on_critical_path: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -37,6 +38,7 @@ pub struct BlockerGraphNode {
     pub impact_score: usize,
     pub actionable: bool,
     pub drill_down_link: String,
+    pub critical_path_index: Option<usize>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -55,6 +57,24 @@ pub struct BlockerGraphView {
     pub bottlenecks: Vec<BottleneckView>,
 }
 
+impl BlockerGraphView {
+    /// Longest chain of unresolved blockers, from the root blocker to the
+    /// most deeply blocked task. Empty when nothing is blocked.
+    #[must_use]
+    pub fn critical_path(&self) -> Vec<String> {
+        let mut indexed = self
+            .nodes
+            .iter()
+            .filter_map(|node| {
+                node.critical_path_index
+                    .map(|index| (index, node.task_id.clone()))
+            })
+            .collect::<Vec<_>>();
+        indexed.sort();
+        indexed.into_iter().map(|(_, task_id)| task_id).collect()
+    }
+}
+
 #[must_use]
 pub fn build_blocker_graph_view(
     samples: &[TaskDependencySample],
@@ -84,6 +104,17 @@ pub fn build_blocker_graph_view(
     let outgoing = build_adjacency_map(&edges, true);
     let incoming = build_adjacency_map(&edges, false);
 
+    let critical_path = longest_blocking_chain(&edges, &tasks_by_id);
+    let critical_index = critical_path
+        .iter()
+        .enumerate()
+        .map(|(idx, task_id)| (task_id.clone(), idx))
+        .collect::<BTreeMap<_, _>>();
+    let critical_edges = critical_path
+        .windows(2)
+        .map(|pair| (pair[0].clone(), pair[1].clone()))
+        .collect::<BTreeSet<_>>();
+
     let mut transitive_by_id: BTreeMap<String, usize> = BTreeMap::new();
     let mut actionable_by_id: BTreeMap<String, bool> = BTreeMap::new();
     for task_id in tasks_by_id.keys() {
@@ -110,6 +141,7 @@ pub fn build_blocker_graph_view(
             impact_score,
             actionable: actionable_by_id.get(task_id).copied().unwrap_or(false),
             drill_down_link: task_drill_down_link(task_id),
+            critical_path_index: critical_index.get(task_id).copied(),
         });
     }
     nodes.sort_by(|a, b| {
@@ -123,6 +155,7 @@ pub fn build_blocker_graph_view(
         .map(|(blocker, blocked)| DependencyEdge {
             blocker_task_id: blocker.clone(),
             blocked_task_id: blocked.clone(),
+            on_critical_path: critical_edges.contains(&(blocker.clone(), blocked.clone())),
         })
         .collect::<Vec<_>>();
 
@@ -162,10 +195,116 @@ pub fn render_dependency_rows(view: &BlockerGraphView) -> Vec<String> {
     if view.edges.is_empty() {
         return vec!["(no dependencies)".to_owned()];
     }
-    view.edges
+    let mut rows = Vec::new();
+    let critical_path = view.critical_path();
+    if !critical_path.is_empty() {
+        rows.push(format!("critical: {}", critical_path.join(" -> ")));
+    }
+    rows.extend(view.edges.iter().map(|edge| {
+        if edge.on_critical_path {
+            format!(
+                "{} => {}  [critical]",
+                edge.blocker_task_id, edge.blocked_task_id
+            )
+        } else {
+            format!("{} -> {}", edge.blocker_task_id, edge.blocked_task_id)
+        }
+    }));
+    rows
+}
+
+/// Longest path over edges whose blocker is still unresolved. Cycles are broken
+/// by dropping back edges found by a DFS in task-id order, so the result is
+/// deterministic; ties prefer the lexicographically smaller chain.
+fn longest_blocking_chain(
+    edges: &BTreeSet<(String, String)>,
+    tasks_by_id: &BTreeMap<String, InternalTask>,
+) -> Vec<String> {
+    let active = edges
         .iter()
-        .map(|edge| format!("{} -> {}", edge.blocker_task_id, edge.blocked_task_id))
-        .collect()
+        .filter(|(blocker, _)| {
+            tasks_by_id
+                .get(blocker)
+                .is_some_and(|task| !is_terminal_status(&task.status))
+        })
+        .cloned()
+        .collect::<BTreeSet<_>>();
+    let dag = break_cycles(&build_adjacency_map(&active, true));
+
+    fn longest_from(
+        node: &str,
+        dag: &BTreeMap<String, BTreeSet<String>>,
+        memo: &mut BTreeMap<String, Vec<String>>,
+    ) -> Vec<String> {
+        if let Some(cached) = memo.get(node) {
+            return cached.clone();
+        }
+        let mut best = vec![node.to_owned()];
+        if let Some(children) = dag.get(node) {
+            for child in children {
+                let mut candidate = vec![node.to_owned()];
+                candidate.extend(longest_from(child, dag, memo));
+                if candidate.len() > best.len()
+                    || (candidate.len() == best.len() && candidate < best)
+                {
+                    best = candidate;
+                }
+            }
+        }
+        memo.insert(node.to_owned(), best.clone());
+        best
+    }
+
+    let mut memo = BTreeMap::new();
+    let mut best: Vec<String> = Vec::new();
+    for node in dag.keys() {
+        let candidate = longest_from(node, &dag, &mut memo);
+        if candidate.len() > best.len() || (candidate.len() == best.len() && candidate < best) {
+            best = candidate;
+        }
+    }
+    if best.len() < 2 {
+        return Vec::new();
+    }
+    best
+}
+
+fn break_cycles(
+    outgoing: &BTreeMap<String, BTreeSet<String>>,
+) -> BTreeMap<String, BTreeSet<String>> {
+    fn visit(
+        node: &str,
+        outgoing: &BTreeMap<String, BTreeSet<String>>,
+        on_stack: &mut BTreeSet<String>,
+        done: &mut BTreeSet<String>,
+        dag: &mut BTreeMap<String, BTreeSet<String>>,
+    ) {
+        if done.contains(node) {
+            return;
+        }
+        on_stack.insert(node.to_owned());
+        if let Some(children) = outgoing.get(node) {
+            for child in children {
+                if on_stack.contains(child) {
+                    continue;
+                }
+                dag.entry(node.to_owned())
+                    .or_default()
+                    .insert(child.clone());
+                visit(child, outgoing, on_stack, done, dag);
+            }
+        }
+        on_stack.remove(node);
+        done.insert(node.to_owned());
+    }
+
+    let mut dag = BTreeMap::new();
+    let mut on_stack = BTreeSet::new();
+    let mut done = BTreeSet::new();
+    for node in outgoing.keys() {
+        visit(node, outgoing, &mut on_stack, &mut done, &mut dag);
+    }
+    dag
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -1001,7 +1140,10 @@ mod tests {
             blocks: vec!["task-b".to_owned()],
         }];
         let view = build_blocker_graph_view(&samples, 1);
-        assert_eq!(render_dependency_rows(&view), vec!["task-a -> task-b"]);
+        assert_eq!(
+            render_dependency_rows(&view),
+            vec!["critical: task-a -> task-b", "task-a => task-b  [critical]"]
+        );
     }
 
     #[test]
@@ -1138,4 +1280,74 @@ mod tests {
             ]
         );
     }
+
+    fn dependency(task_id: &str, status: &str, blocks: &[&str]) -> TaskDependencySample {
+        TaskDependencySample {
+            task_id: task_id.to_owned(),
+            title: task_id.to_uppercase(),
+            status: status.to_owned(),
+            blocked_by: vec![],
+            blocks: blocks.iter().map(|id| (*id).to_owned()).collect(),
+        }
+    }
+
+    #[test]
+    fn critical_path_is_longest_blocking_chain() {
+        let samples = vec![
+            dependency("task-a", "open", &["task-b", "task-e"]),
+            dependency("task-b", "open", &["task-c"]),
+            dependency("task-c", "open", &["task-d"]),
+            dependency("task-d", "open", &[]),
+            dependency("task-e", "open", &[]),
+            dependency("task-x", "open", &["task-d"]),
+        ];
+        let view = build_blocker_graph_view(&samples, 3);
+
+        assert_eq!(
+            view.critical_path(),
+            vec!["task-a", "task-b", "task-c", "task-d"]
+        );
+        let critical_edges = view
+            .edges
+            .iter()
+            .filter(|edge| edge.on_critical_path)
+            .map(|edge| format!("{}>{}", edge.blocker_task_id, edge.blocked_task_id))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            critical_edges,
+            vec!["task-a>task-b", "task-b>task-c", "task-c>task-d"]
+        );
+        assert!(view
+            .nodes
+            .iter()
+            .filter(|node| node.task_id == "task-e" || node.task_id == "task-x")
+            .all(|node| node.critical_path_index.is_none()));
+    }
+
+    #[test]
+    fn critical_path_breaks_cycles_deterministically() {
+        let samples = vec![
+            dependency("task-p", "open", &["task-q"]),
+            dependency("task-q", "open", &["task-r"]),
+            dependency("task-r", "open", &["task-p", "task-s"]),
+        ];
+        let first = build_blocker_graph_view(&samples, 3).critical_path();
+        let mut reversed = samples.clone();
+        reversed.reverse();
+        let second = build_blocker_graph_view(&reversed, 3).critical_path();
+
+        assert_eq!(first, vec!["task-p", "task-q", "task-r", "task-s"]);
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn resolved_blockers_do_not_extend_critical_path() {
+        let samples = vec![
+            dependency("task-a", "done", &["task-b"]),
+            dependency("task-b", "open", &["task-c"]),
+            dependency("task-c", "open", &[]),
+        ];
+        let view = build_blocker_graph_view(&samples, 3);
+        assert_eq!(view.critical_path(), vec!["task-b", "task-c"]);
+    }
 }
@@ -797,7 +797,7 @@ fn normalize(value: &str) -> String {
     value.trim().to_ascii_lowercase()
 }
 
-fn shell_quote(value: &str) -> String {
+pub(crate) fn shell_quote(value: &str) -> String {
     let trimmed = value.trim();
     if trimmed.is_empty() {
         return "''".to_owned();
@@ -1,4 +1,13 @@
 //! Communication quality checks for unanswered asks, stale threads, and closure hygiene.
+//!
+//! Per-agent messaging health is scored from fmail data as a weighted sum of
+//! three sub-metrics, each normalized to 0..1 (1 is healthy):
+//!
+//! - latency (weight 0.4): `1 - median_reply_latency / latency_ceiling_secs`
+//! - reply rate (weight 0.4): replies sent per ask received
+//! - errors (weight 0.2): `1 - error_messages / messages_sent`
+//!
+//! A sub-metric with no data (no asks, no messages) counts as healthy.
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CommunicationThreadSample {
@@ -104,6 +113,119 @@ pub fn build_communication_quality_report(
     CommunicationQualityReport { alerts, summary }
 }
 
+const LATENCY_WEIGHT: f64 = 0.4;
+const REPLY_RATE_WEIGHT: f64 = 0.4;
+const ERROR_WEIGHT: f64 = 0.2;
+
+/// Messaging activity for one agent, aggregated from fmail.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AgentMessagingSample {
+    pub agent: String,
+    pub asks_received: usize,
+    pub replies_sent: usize,
+    pub reply_latencies_secs: Vec<u64>,
+    pub messages_sent: usize,
+    pub error_messages: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct QualityScore {
+    pub agent: String,
+    /// Weighted sum of the sub-metrics, 0..1.
+    pub score: f64,
+    pub latency: f64,
+    pub reply_rate: f64,
+    pub error_free: f64,
+    pub median_reply_latency_secs: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommunicationQualityScorer {
+    samples: Vec<AgentMessagingSample>,
+    latency_ceiling_secs: u64,
+}
+
+impl CommunicationQualityScorer {
+    #[must_use]
+    pub fn new(samples: Vec<AgentMessagingSample>, policy: &CommunicationQualityPolicy) -> Self {
+        Self {
+            samples,
+            latency_ceiling_secs: policy.unanswered_ask_after_secs.max(1),
+        }
+    }
+
+    /// Score for `agent`, merging every sample recorded under that name.
+    #[must_use]
+    pub fn quality(&self, agent: &str) -> Option<QualityScore> {
+        let agent = normalize_required(agent);
+        let mut matched = self
+            .samples
+            .iter()
+            .filter(|sample| normalize_required(&sample.agent) == agent)
+            .peekable();
+        matched.peek()?;
+
+        let mut asks_received = 0usize;
+        let mut replies_sent = 0usize;
+        let mut messages_sent = 0usize;
+        let mut error_messages = 0usize;
+        let mut latencies = Vec::new();
+        for sample in matched {
+            asks_received += sample.asks_received;
+            replies_sent += sample.replies_sent;
+            messages_sent += sample.messages_sent;
+            error_messages += sample.error_messages;
+            latencies.extend_from_slice(&sample.reply_latencies_secs);
+        }
+        latencies.sort_unstable();
+        let median_reply_latency_secs = latencies.get(latencies.len() / 2).copied();
+
+        let latency = median_reply_latency_secs.map_or(1.0, |median| {
+            1.0 - (median as f64 / self.latency_ceiling_secs as f64).min(1.0)
+        });
+        let reply_rate = if asks_received == 0 {
+            1.0
+        } else {
+            (replies_sent as f64 / asks_received as f64).min(1.0)
+        };
+        let error_free = if messages_sent == 0 {
+            1.0
+        } else {
+            1.0 - (error_messages as f64 / messages_sent as f64).min(1.0)
+        };
+
+        Some(QualityScore {
+            agent,
+            score: LATENCY_WEIGHT * latency
+                + REPLY_RATE_WEIGHT * reply_rate
+                + ERROR_WEIGHT * error_free,
+            latency,
+            reply_rate,
+            error_free,
+            median_reply_latency_secs,
+        })
+    }
+
+    /// Every agent's score, worst first, so silent agents surface at the top.
+    #[must_use]
+    pub fn ranked(&self) -> Vec<QualityScore> {
+        let mut agents: Vec<String> = self
+            .samples
+            .iter()
+            .map(|sample| normalize_required(&sample.agent))
+            .filter(|agent| !agent.is_empty())
+            .collect();
+        agents.sort();
+        agents.dedup();
+        let mut scores: Vec<QualityScore> = agents
+            .iter()
+            .filter_map(|agent| self.quality(agent))
+            .collect();
+        scores.sort_by(|a, b| a.score.total_cmp(&b.score).then(a.agent.cmp(&b.agent)));
+        scores
+    }
+}
+
 fn evaluate_thread(
     sample: &CommunicationThreadSample,
     now_epoch_s: i64,
@@ -251,8 +373,9 @@ fn normalize_optional(value: Option<&str>) -> Option<String> {
 #[cfg(test)]
 mod tests {
     use super::{
-        build_communication_quality_report, CommunicationAlertKind, CommunicationQualityPolicy,
-        CommunicationSeverity, CommunicationThreadSample,
+        build_communication_quality_report, AgentMessagingSample, CommunicationAlertKind,
+        CommunicationQualityPolicy, CommunicationQualityScorer, CommunicationSeverity,
+        CommunicationThreadSample,
     };
 
     fn sample_thread(
@@ -368,4 +491,61 @@ mod tests {
             CommunicationAlertKind::MissingClosureNote
         );
     }
+
+    fn messaging(
+        agent: &str,
+        asks_received: usize,
+        replies_sent: usize,
+        reply_latencies_secs: Vec<u64>,
+        error_messages: usize,
+    ) -> AgentMessagingSample {
+        AgentMessagingSample {
+            agent: agent.to_owned(),
+            asks_received,
+            replies_sent,
+            reply_latencies_secs,
+            messages_sent: 10,
+            error_messages,
+        }
+    }
+
+    #[test]
+    fn responsive_agent_outscores_slow_error_prone_agent() {
+        let scorer = CommunicationQualityScorer::new(
+            vec![
+                messaging("fast", 4, 4, vec![30, 60, 45, 20], 0),
+                messaging("slow", 4, 2, vec![900, 1_200], 5),
+            ],
+            &CommunicationQualityPolicy::default(),
+        );
+        let Some(fast) = scorer.quality("fast") else {
+            panic!("expected score for fast");
+        };
+        let Some(slow) = scorer.quality("slow") else {
+            panic!("expected score for slow");
+        };
+
+        assert!(fast.score > slow.score);
+        assert!((fast.reply_rate - 1.0).abs() < 1e-9);
+        assert!((fast.error_free - 1.0).abs() < 1e-9);
+        assert_eq!(fast.median_reply_latency_secs, Some(45));
+        assert!(slow.latency.abs() < 1e-9);
+        assert!((slow.reply_rate - 0.5).abs() < 1e-9);
+        assert!((slow.error_free - 0.5).abs() < 1e-9);
+        assert!((slow.score - 0.3).abs() < 1e-9);
+        for score in [&fast, &slow] {
+            for metric in [
+                score.score,
+                score.latency,
+                score.reply_rate,
+                score.error_free,
+            ] {
+                assert!((0.0..=1.0).contains(&metric));
+            }
+        }
+
+        let ranked = scorer.ranked();
+        assert_eq!(ranked[0].agent, "slow");
+        assert!(scorer.quality("missing").is_none());
+    }
 }
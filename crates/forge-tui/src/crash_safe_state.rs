@@ -12,6 +12,7 @@ use crate::session_restore::{
     snapshot_session_context, PaneSelection, PersistedSessionSnapshot, SessionContext,
     SessionRestorePolicy,
 };
+use crate::task_notes::LoopNote;
 
 pub const CRASH_SAFE_STATE_SCHEMA_VERSION: u32 = 1;
 
@@ -211,6 +212,9 @@ fn parse_snapshot_value(
 
     let panes = parse_panes(obj.get("panes"), warnings);
     let pinned_loop_ids = parse_id_list(obj.get("pinned_loop_ids"), warnings);
+    let loop_notes = parse_loop_notes(obj.get("loop_notes"), warnings);
+    let log_lane_order = parse_label_list(obj.get("log_lane_order"));
+    let pinned_log_lanes = parse_label_list(obj.get("pinned_log_lanes"));
 
     Ok(PersistedSessionSnapshot {
         schema_version,
@@ -227,6 +231,9 @@ fn parse_snapshot_value(
         ),
         panes,
         pinned_loop_ids,
+        loop_notes,
+        log_lane_order,
+        pinned_log_lanes,
     })
 }
 
@@ -296,6 +303,66 @@ fn parse_id_list(value: Option<&Value>, warnings: &mut Vec<String>) -> Vec<Strin
     normalized.into_iter().collect()
 }
 
+/// Order-preserving label list; lane labels are validated on restore.
+fn parse_label_list(value: Option<&Value>) -> Vec<String> {
+    value
+        .and_then(Value::as_array)
+        .map(|values| {
+            values
+                .iter()
+                .filter_map(Value::as_str)
+                .map(|label| label.trim().to_ascii_lowercase())
+                .filter(|label| !label.is_empty())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn parse_loop_notes(value: Option<&Value>, warnings: &mut Vec<String>) -> Vec<LoopNote> {
+    let Some(values) = value.and_then(Value::as_array) else {
+        return Vec::new();
+    };
+
+    let mut notes = Vec::new();
+    let mut seen = BTreeSet::new();
+    for (index, item) in values.iter().enumerate() {
+        let Some(obj) = item.as_object() else {
+            warnings.push(format!("loop_notes[{index}] ignored (not object)"));
+            continue;
+        };
+        let Some(loop_id) = normalize_optional(obj.get("loop_id").and_then(Value::as_str)) else {
+            warnings.push(format!("loop_notes[{index}] ignored (empty loop_id)"));
+            continue;
+        };
+        let body = obj
+            .get("body")
+            .and_then(Value::as_str)
+            .map(str::trim)
+            .unwrap_or_default();
+        if body.is_empty() {
+            warnings.push(format!("loop_notes[{index}] ignored (empty body)"));
+            continue;
+        }
+        if !seen.insert(loop_id.clone()) {
+            warnings.push(format!(
+                "loop_notes[{index}] ignored (duplicate loop_id={loop_id})"
+            ));
+            continue;
+        }
+        notes.push(LoopNote {
+            loop_id,
+            body: body.to_owned(),
+            updated_at_epoch_s: obj
+                .get("updated_at_epoch_s")
+                .and_then(Value::as_i64)
+                .unwrap_or(0)
+                .max(0),
+        });
+    }
+
+    notes
+}
+
 fn snapshot_to_value(snapshot: &PersistedSessionSnapshot) -> Value {
     let mut root = Map::new();
     root.insert(
@@ -363,6 +430,45 @@ fn snapshot_to_value(snapshot: &PersistedSessionSnapshot) -> Value {
                 .collect(),
         ),
     );
+    // Omitted when empty so digests of snapshots written before notes existed still match.
+    if !snapshot.loop_notes.is_empty() {
+        root.insert(
+            "loop_notes".to_owned(),
+            Value::Array(
+                snapshot
+                    .loop_notes
+                    .iter()
+                    .map(|note| {
+                        let mut item = Map::new();
+                        item.insert("loop_id".to_owned(), Value::from(note.loop_id.clone()));
+                        item.insert("body".to_owned(), Value::from(note.body.clone()));
+                        item.insert(
+                            "updated_at_epoch_s".to_owned(),
+                            Value::from(note.updated_at_epoch_s.max(0)),
+                        );
+                        Value::Object(item)
+                    })
+                    .collect(),
+            ),
+        );
+    }
+    // Lane order is also omitted when it is the default.
+    for (key, labels) in [
+        ("log_lane_order", &snapshot.log_lane_order),
+        ("pinned_log_lanes", &snapshot.pinned_log_lanes),
+    ] {
+        if !labels.is_empty() {
+            root.insert(
+                key.to_owned(),
+                Value::Array(
+                    labels
+                        .iter()
+                        .map(|label| Value::from(label.clone()))
+                        .collect(),
+                ),
+            );
+        }
+    }
     Value::Object(root)
 }
 
@@ -442,9 +548,12 @@ mod tests {
         persist_context_snapshot, persist_snapshot, recover_snapshot, CrashRecoveryOutcome,
         RecoverySource,
     };
+    use crate::lane_model::{LaneOrder, LogLane};
     use crate::session_restore::{
-        snapshot_session_context, PaneSelection, SessionContext, SessionRestorePolicy,
+        restore_session_context, snapshot_session_context, PaneSelection, RestoreUniverse,
+        SessionContext, SessionRestorePolicy,
     };
+    use crate::task_notes::LoopNotes;
     use serde_json::Value;
     use std::fs;
     use std::path::{Path, PathBuf};
@@ -592,6 +701,68 @@ mod tests {
         cleanup(&path);
     }
 
+    #[test]
+    fn loop_note_reloads_after_simulated_restart() {
+        let path = temp_path("loop-notes");
+        let mut notes = LoopNotes::default();
+        notes
+            .upsert("Loop-A", "Flaky harness; check auth before restart", 1_700)
+            .unwrap_or_else(|err| panic!("upsert note: {err}"));
+        let mut context = sample_context("loop-a");
+        context.loop_notes = notes.to_persisted();
+
+        persist_context_snapshot(&path, &context, &SessionRestorePolicy::default(), 1_800)
+            .unwrap_or_else(|err| panic!("persist context: {err}"));
+
+        let recovered = recover_snapshot(&path);
+        assert_eq!(recovered.source, RecoverySource::Primary);
+        let restored = restore_session_context(
+            recovered.snapshot.as_ref(),
+            &RestoreUniverse {
+                loop_ids: vec!["loop-a".to_owned()],
+                ..RestoreUniverse::default()
+            },
+            &SessionRestorePolicy::default(),
+        );
+        let reloaded = LoopNotes::from_persisted(&restored.context.loop_notes);
+        let Some(note) = reloaded.note("loop-a") else {
+            panic!("expected loop-a note after restart");
+        };
+        assert_eq!(note.body, "Flaky harness; check auth before restart");
+        assert_eq!(note.updated_at_epoch_s, 1_700);
+
+        cleanup(&path);
+    }
+
+    #[test]
+    fn log_lane_order_reloads_after_simulated_restart() {
+        let path = temp_path("lane-order");
+        let mut lane_order = LaneOrder::default();
+        lane_order.pin(LogLane::Stderr);
+        lane_order.move_up(LogLane::Event);
+        let mut context = sample_context("loop-a");
+        context.log_lane_order = lane_order.persisted_order();
+        context.pinned_log_lanes = lane_order.persisted_pins();
+
+        persist_context_snapshot(&path, &context, &SessionRestorePolicy::default(), 1_800)
+            .unwrap_or_else(|err| panic!("persist context: {err}"));
+
+        let recovered = recover_snapshot(&path);
+        let restored = restore_session_context(
+            recovered.snapshot.as_ref(),
+            &RestoreUniverse::default(),
+            &SessionRestorePolicy::default(),
+        );
+        let reloaded = LaneOrder::from_persisted(
+            &restored.context.log_lane_order,
+            &restored.context.pinned_log_lanes,
+        );
+        assert_eq!(reloaded, lane_order);
+        assert_eq!(reloaded.display_order()[0], LogLane::Stderr);
+
+        cleanup(&path);
+    }
+
     fn sample_snapshot(
         loop_id: &str,
         saved_at_epoch_s: i64,
@@ -621,6 +792,9 @@ mod tests {
                 },
             ],
             pinned_loop_ids: vec!["loop-a".to_owned(), "loop-b".to_owned()],
+            loop_notes: Vec::new(),
+            log_lane_order: Vec::new(),
+            pinned_log_lanes: Vec::new(),
         }
     }
 
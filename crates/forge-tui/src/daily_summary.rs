@@ -1,6 +1,8 @@
 //! Daily summary export artifact for operator handoff.
 
 use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct DailySummaryEntry {
@@ -26,6 +28,14 @@ pub struct DailySummaryInput {
     pub blockers: Vec<DailySummaryEntry>,
     pub incidents: Vec<IncidentSummaryEntry>,
     pub next_actions: Vec<DailySummaryEntry>,
+    pub usage: Vec<DailySummaryUsageRow>,
+}
+
+/// One metric/value row of the usage table (runs, tokens, spend, ...).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DailySummaryUsageRow {
+    pub metric: String,
+    pub value: String,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -59,6 +69,7 @@ pub struct DailySummarySection {
 pub struct DailySummaryArtifact {
     pub headline: String,
     pub sections: Vec<DailySummarySection>,
+    pub usage: Vec<DailySummaryUsageRow>,
     pub markdown: String,
     pub text: String,
 }
@@ -80,15 +91,108 @@ pub fn build_daily_summary_artifact(
     let sections = vec![completed, blockers, incidents, next_actions];
     let headline = format!("Forge Daily Summary ({date})");
 
-    let markdown = render_markdown(&headline, &sections);
-    let text = render_text(&headline, &sections);
+    let usage = normalize_usage(&input.usage);
+    let text = render_text(&headline, &sections, &usage);
 
-    DailySummaryArtifact {
+    let mut artifact = DailySummaryArtifact {
         headline,
         sections,
-        markdown,
+        usage,
+        markdown: String::new(),
         text,
+    };
+    artifact.markdown = to_markdown(&artifact);
+    artifact
+}
+
+/// Render the summary as a markdown report: a top-level heading, one `##`
+/// bulleted section per summary area, and a usage table when usage rows exist.
+#[must_use]
+pub fn to_markdown(artifact: &DailySummaryArtifact) -> String {
+    let mut out = Vec::new();
+    out.push(format!("# {}", artifact.headline));
+    out.push(String::new());
+
+    for section in &artifact.sections {
+        out.push(format!("## {} ({})", section.title, section.total_items));
+        out.push(String::new());
+        for line in &section.lines {
+            out.push(line.clone());
+        }
+        out.push(String::new());
+    }
+
+    if !artifact.usage.is_empty() {
+        out.push(format!("## Usage ({})", artifact.usage.len()));
+        out.push(String::new());
+        out.push("| Metric | Value |".to_owned());
+        out.push("| --- | --- |".to_owned());
+        for row in &artifact.usage {
+            out.push(format!(
+                "| {} | {} |",
+                escape_table_cell(&row.metric),
+                escape_table_cell(&row.value)
+            ));
+        }
+        out.push(String::new());
     }
+
+    let mut markdown = out.join("\n").trim_end().to_owned();
+    markdown.push('\n');
+    markdown
+}
+
+/// Write the markdown report to `<output_dir>/<basename>.md`.
+pub fn export_daily_summary_markdown(
+    artifact: &DailySummaryArtifact,
+    output_dir: &Path,
+    basename: &str,
+) -> Result<PathBuf, String> {
+    fs::create_dir_all(output_dir).map_err(|err| {
+        format!(
+            "create daily summary export directory {}: {err}",
+            output_dir.display()
+        )
+    })?;
+    let markdown_path = output_dir.join(format!("{basename}.md"));
+    fs::write(&markdown_path, to_markdown(artifact))
+        .map_err(|err| format!("write {}: {err}", markdown_path.display()))?;
+    Ok(markdown_path)
+}
+
+/// Default directory for exported daily summaries, under the forge data dir.
+#[must_use]
+pub fn user_daily_summary_dir() -> Option<PathBuf> {
+    std::env::var_os("HOME")
+        .filter(|home| !home.is_empty())
+        .map(|home| {
+            PathBuf::from(home)
+                .join(".local")
+                .join("share")
+                .join("forge")
+                .join("daily-summaries")
+        })
+}
+
+/// `YYYY-MM-DD` for a unix timestamp, in UTC.
+#[must_use]
+pub fn utc_date_from_epoch_s(epoch_s: i64) -> String {
+    // Civil-from-days over the proleptic Gregorian calendar (400-year eras).
+    let days = epoch_s.div_euclid(86_400) + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    format!("{year:04}-{month:02}-{day:02}")
 }
 
 fn summarize_entry_section(
@@ -172,23 +276,32 @@ fn summarize_lines(title: &str, mut lines: Vec<String>, max_items: usize) -> Dai
     }
 }
 
-fn render_markdown(headline: &str, sections: &[DailySummarySection]) -> String {
-    let mut out = Vec::new();
-    out.push(format!("# {headline}"));
-    out.push(String::new());
-
-    for section in sections {
-        out.push(format!("## {} ({})", section.title, section.total_items));
-        for line in &section.lines {
-            out.push(line.clone());
-        }
-        out.push(String::new());
-    }
+fn normalize_usage(rows: &[DailySummaryUsageRow]) -> Vec<DailySummaryUsageRow> {
+    let mut seen = BTreeSet::new();
+    rows.iter()
+        .filter_map(|row| {
+            let metric = row.metric.trim();
+            if metric.is_empty() || !seen.insert(metric.to_ascii_lowercase()) {
+                return None;
+            }
+            let value = row.value.trim();
+            Some(DailySummaryUsageRow {
+                metric: metric.to_owned(),
+                value: if value.is_empty() { "-" } else { value }.to_owned(),
+            })
+        })
+        .collect()
+}
 
-    out.join("\n").trim_end().to_owned()
+fn escape_table_cell(value: &str) -> String {
+    value.replace('|', "\\|").replace(['\r', '\n'], " ")
 }
 
-fn render_text(headline: &str, sections: &[DailySummarySection]) -> String {
+fn render_text(
+    headline: &str,
+    sections: &[DailySummarySection],
+    usage: &[DailySummaryUsageRow],
+) -> String {
     let mut out = Vec::new();
     out.push(headline.to_owned());
 
@@ -199,6 +312,13 @@ fn render_text(headline: &str, sections: &[DailySummarySection]) -> String {
         }
     }
 
+    if !usage.is_empty() {
+        out.push(format!("Usage [{}]", usage.len()));
+        for row in usage {
+            out.push(format!("- {}: {}", row.metric, row.value));
+        }
+    }
+
     out.join("\n")
 }
 
@@ -256,9 +376,16 @@ fn normalize_date(date_utc: &str) -> String {
 #[cfg(test)]
 #[allow(clippy::expect_used)]
 mod tests {
+    use std::env;
+    use std::fs;
+    use std::path::PathBuf;
+    use std::process;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
     use super::{
-        build_daily_summary_artifact, DailySummaryEntry, DailySummaryInput, DailySummaryPolicy,
-        IncidentSummaryEntry,
+        build_daily_summary_artifact, export_daily_summary_markdown, to_markdown,
+        utc_date_from_epoch_s, DailySummaryEntry, DailySummaryInput, DailySummaryPolicy,
+        DailySummaryUsageRow, IncidentSummaryEntry,
     };
 
     fn sample_input() -> DailySummaryInput {
@@ -306,9 +433,32 @@ mod tests {
                 owner: Some("agent-a".to_owned()),
                 detail: Some("claim after current close".to_owned()),
             }],
+            usage: vec![
+                DailySummaryUsageRow {
+                    metric: "loop runs".to_owned(),
+                    value: "42".to_owned(),
+                },
+                DailySummaryUsageRow {
+                    metric: "tokens in|out".to_owned(),
+                    value: "1.2M | 310k".to_owned(),
+                },
+            ],
         }
     }
 
+    fn temp_dir(label: &str) -> PathBuf {
+        let mut path = env::temp_dir();
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos())
+            .unwrap_or(0);
+        path.push(format!(
+            "forge-daily-summary-{label}-{}-{nanos}",
+            process::id()
+        ));
+        path
+    }
+
     #[test]
     fn builds_concise_daily_summary_with_required_sections() {
         let artifact =
@@ -387,4 +537,89 @@ mod tests {
             assert_eq!(section.lines, vec!["- none".to_owned()]);
         }
     }
+
+    #[test]
+    fn markdown_has_expected_headings_bullets_and_usage_table() {
+        let artifact =
+            build_daily_summary_artifact(&sample_input(), &DailySummaryPolicy::default());
+        let markdown = to_markdown(&artifact);
+        assert_eq!(markdown, artifact.markdown);
+
+        let headings: Vec<&str> = markdown
+            .lines()
+            .filter(|line| line.starts_with('#'))
+            .collect();
+        assert_eq!(
+            headings,
+            vec![
+                "# Forge Daily Summary (2026-02-12)",
+                "## Completed Work (2)",
+                "## Blockers (1)",
+                "## Incidents (2)",
+                "## Next Actions (1)",
+                "## Usage (2)",
+            ]
+        );
+        assert_eq!(
+            markdown
+                .lines()
+                .filter(|line| line.starts_with("- "))
+                .count(),
+            6
+        );
+
+        let table: Vec<&str> = markdown
+            .lines()
+            .filter(|line| line.starts_with('|'))
+            .collect();
+        assert_eq!(
+            table,
+            vec![
+                "| Metric | Value |",
+                "| --- | --- |",
+                "| loop runs | 42 |",
+                "| tokens in\\|out | 1.2M \\| 310k |",
+            ]
+        );
+        assert!(markdown.ends_with("| 1.2M \\| 310k |\n"));
+    }
+
+    #[test]
+    fn markdown_omits_usage_table_without_usage_rows() {
+        let mut input = sample_input();
+        input.usage.clear();
+        let artifact = build_daily_summary_artifact(&input, &DailySummaryPolicy::default());
+
+        assert!(!artifact.markdown.contains("## Usage"));
+        assert!(!artifact.markdown.contains("| Metric | Value |"));
+    }
+
+    #[test]
+    fn export_writes_markdown_report() {
+        let artifact =
+            build_daily_summary_artifact(&sample_input(), &DailySummaryPolicy::default());
+        let dir = temp_dir("export");
+        let path = match export_daily_summary_markdown(&artifact, &dir, "daily-2026-02-12") {
+            Ok(path) => path,
+            Err(err) => panic!("daily summary export should succeed: {err}"),
+        };
+        assert!(path.ends_with("daily-2026-02-12.md"));
+
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(err) => panic!("markdown file should be readable: {err}"),
+        };
+        assert_eq!(contents, artifact.markdown);
+
+        let _ = fs::remove_file(&path);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn utc_date_from_epoch_handles_leap_days_and_pre_epoch() {
+        assert_eq!(utc_date_from_epoch_s(0), "1970-01-01");
+        assert_eq!(utc_date_from_epoch_s(951_782_400), "2000-02-29");
+        assert_eq!(utc_date_from_epoch_s(1_770_940_799), "2026-02-12");
+        assert_eq!(utc_date_from_epoch_s(-1), "1969-12-31");
+    }
 }
@@ -1,11 +1,15 @@
 //! Stable extension API for custom Forge TUI panels.
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::sync::{Arc, Mutex};
 
 use forge_ftui_adapter::input::InputEvent;
 use forge_ftui_adapter::render::{FrameSize, RenderFrame, TextRole};
 use forge_ftui_adapter::style::ThemeSpec;
 
+use crate::extension_sandbox::{ExtensionCallError, ExtensionCallGuard};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PanelMode {
     ReadOnly,
@@ -77,9 +81,11 @@ pub enum PanelRegistryError {
 pub enum PanelSessionError {
     UnknownPanelId,
     InvalidLifecycle,
+    Faulted(ExtensionCallError),
 }
 
-pub trait ExtensionPanel {
+/// Panel callbacks run on guard worker threads, hence `Send`.
+pub trait ExtensionPanel: Send {
     fn descriptor(&self) -> &ExtensionPanelDescriptor;
 
     fn on_mount(&mut self, _context: &PanelMountContext) -> PanelUpdate {
@@ -95,9 +101,13 @@ pub trait ExtensionPanel {
     fn on_unmount(&mut self, _reason: PanelUnmountReason) {}
 }
 
+pub type PanelFactory = Box<dyn Fn() -> Box<dyn ExtensionPanel>>;
+
 struct RegisteredPanel {
+    /// Extension the panel's callbacks are budgeted and faulted under.
+    extension_id: String,
     descriptor: ExtensionPanelDescriptor,
-    factory: Box<dyn Fn() -> Box<dyn ExtensionPanel>>,
+    factory: PanelFactory,
 }
 
 #[derive(Default)]
@@ -114,7 +124,16 @@ impl PanelRegistry {
     pub fn register(
         &mut self,
         descriptor: ExtensionPanelDescriptor,
-        factory: Box<dyn Fn() -> Box<dyn ExtensionPanel>>,
+        factory: PanelFactory,
+    ) -> Result<(), PanelRegistryError> {
+        self.register_for(None, descriptor, factory)
+    }
+
+    fn register_for(
+        &mut self,
+        extension_id: Option<&str>,
+        descriptor: ExtensionPanelDescriptor,
+        factory: PanelFactory,
     ) -> Result<(), PanelRegistryError> {
         let id = normalize_id(&descriptor.id);
         if id.is_empty() {
@@ -135,8 +154,9 @@ impl PanelRegistry {
             normalized.description = "custom panel extension".to_owned();
         }
         self.panels.insert(
-            id,
+            id.clone(),
             RegisteredPanel {
+                extension_id: extension_id.map_or(id, str::to_owned),
                 descriptor: normalized,
                 factory,
             },
@@ -144,6 +164,47 @@ impl PanelRegistry {
         Ok(())
     }
 
+    /// Negotiate `extension` against `host`, then register its panels.
+    /// Nothing is registered unless negotiation succeeds and every panel id
+    /// is valid and free.
+    pub fn load_extension(
+        &mut self,
+        host: &HostApi,
+        extension: &dyn ExtensionApi,
+        state: BTreeMap<String, String>,
+    ) -> Result<ExtensionServices, ExtensionLoadError> {
+        let negotiated = host.negotiate(extension)?;
+        let panels = extension.panels();
+        let mut seen = BTreeSet::new();
+        for (descriptor, _) in &panels {
+            let id = normalize_id(&descriptor.id);
+            let error = if id.is_empty() {
+                Some(PanelRegistryError::InvalidId)
+            } else if self.panels.contains_key(&id) || !seen.insert(id) {
+                Some(PanelRegistryError::DuplicateId)
+            } else {
+                None
+            };
+            if let Some(error) = error {
+                return Err(ExtensionLoadError::PanelRejected {
+                    extension_id: negotiated.extension_id,
+                    panel_id: descriptor.id.clone(),
+                    error,
+                });
+            }
+        }
+        for (descriptor, factory) in panels {
+            let panel_id = descriptor.id.clone();
+            self.register_for(Some(&negotiated.extension_id), descriptor, factory)
+                .map_err(|error| ExtensionLoadError::PanelRejected {
+                    extension_id: negotiated.extension_id.clone(),
+                    panel_id,
+                    error,
+                })?;
+        }
+        Ok(ExtensionServices::new(negotiated, state))
+    }
+
     #[must_use]
     pub fn descriptors(&self) -> Vec<ExtensionPanelDescriptor> {
         self.panels
@@ -159,21 +220,28 @@ impl PanelRegistry {
         };
         let panel = (registered.factory)();
         Ok(PanelSession {
-            panel,
+            extension_id: registered.extension_id.clone(),
+            descriptor: panel.descriptor().clone(),
+            panel: Arc::new(Mutex::new(panel)),
             state: PanelSessionState::Created,
         })
     }
 }
 
+/// A mounted panel. Every callback runs through the host's
+/// `ExtensionCallGuard`, so a hung or panicking panel faults instead of
+/// freezing the UI thread.
 pub struct PanelSession {
-    panel: Box<dyn ExtensionPanel>,
+    extension_id: String,
+    descriptor: ExtensionPanelDescriptor,
+    panel: Arc<Mutex<Box<dyn ExtensionPanel>>>,
     state: PanelSessionState,
 }
 
 impl PanelSession {
     #[must_use]
     pub fn descriptor(&self) -> &ExtensionPanelDescriptor {
-        self.panel.descriptor()
+        &self.descriptor
     }
 
     #[must_use]
@@ -181,25 +249,30 @@ impl PanelSession {
         self.state
     }
 
-    pub fn mount(&mut self, context: &PanelMountContext) -> Result<PanelUpdate, PanelSessionError> {
+    pub fn mount(
+        &mut self,
+        guard: &mut ExtensionCallGuard,
+        context: &PanelMountContext,
+    ) -> Result<PanelUpdate, PanelSessionError> {
         if self.state != PanelSessionState::Created {
             return Err(PanelSessionError::InvalidLifecycle);
         }
+        let context = context.clone();
+        let update = self.call(guard, move |panel| panel.on_mount(&context))?;
         self.state = PanelSessionState::Mounted;
-        Ok(self.panel.on_mount(context))
+        Ok(update)
     }
 
     pub fn dispatch_event(
         &mut self,
+        guard: &mut ExtensionCallGuard,
         event: &PanelEvent,
         context: &PanelRuntimeContext,
     ) -> Result<PanelUpdate, PanelSessionError> {
         if self.state != PanelSessionState::Mounted {
             return Err(PanelSessionError::InvalidLifecycle);
         }
-        if self.panel.descriptor().mode == PanelMode::ReadOnly
-            && matches!(event, PanelEvent::Input(_))
-        {
+        if self.descriptor.mode == PanelMode::ReadOnly && matches!(event, PanelEvent::Input(_)) {
             return Ok(PanelUpdate {
                 effects: vec![PanelEffect::SetStatus(
                     "read-only panel ignored interactive input".to_owned(),
@@ -207,27 +280,317 @@ impl PanelSession {
                 close_requested: false,
             });
         }
-        Ok(self.panel.on_event(event, context))
+        let (event, context) = (event.clone(), context.clone());
+        self.call(guard, move |panel| panel.on_event(&event, &context))
     }
 
-    #[must_use]
-    pub fn render(&self, size: FrameSize, theme: ThemeSpec) -> RenderFrame {
+    /// Render the panel, or a fault notice when its render callback faults.
+    pub fn render(
+        &self,
+        guard: &mut ExtensionCallGuard,
+        size: FrameSize,
+        theme: ThemeSpec,
+    ) -> RenderFrame {
         if self.state == PanelSessionState::Closed {
             let mut frame = RenderFrame::new(size, theme);
             frame.draw_text(0, 0, "panel session closed", TextRole::Muted);
             return frame;
         }
-        self.panel.render(size, theme)
+        match self.call(guard, move |panel| panel.render(size, theme)) {
+            Ok(frame) => frame,
+            Err(_) => {
+                let mut frame = RenderFrame::new(size, theme);
+                frame.draw_text(0, 0, "panel unavailable", TextRole::Danger);
+                frame
+            }
+        }
     }
 
-    pub fn unmount(&mut self, reason: PanelUnmountReason) -> Result<(), PanelSessionError> {
+    /// Close the session. It is closed even when `on_unmount` faults.
+    pub fn unmount(
+        &mut self,
+        guard: &mut ExtensionCallGuard,
+        reason: PanelUnmountReason,
+    ) -> Result<(), PanelSessionError> {
         if self.state != PanelSessionState::Mounted {
             return Err(PanelSessionError::InvalidLifecycle);
         }
-        self.panel.on_unmount(reason);
         self.state = PanelSessionState::Closed;
+        self.call(guard, move |panel| panel.on_unmount(reason))
+    }
+
+    fn call<T, F>(
+        &self,
+        guard: &mut ExtensionCallGuard,
+        callback: F,
+    ) -> Result<T, PanelSessionError>
+    where
+        T: Send + 'static,
+        F: FnOnce(&mut dyn ExtensionPanel) -> T + Send + 'static,
+    {
+        let panel = Arc::clone(&self.panel);
+        guard
+            .call(&self.extension_id, move || {
+                let mut panel = match panel.lock() {
+                    Ok(guard) => guard,
+                    Err(poisoned) => poisoned.into_inner(),
+                };
+                callback(panel.as_mut())
+            })
+            .map_err(PanelSessionError::Faulted)
+    }
+}
+
+/// Extension API version implemented by this host. Bump on breaking changes.
+pub const EXTENSION_API_VERSION: u32 = 1;
+/// Oldest extension API version the host still loads.
+pub const MIN_EXTENSION_API_VERSION: u32 = 1;
+
+/// Host services an extension must declare before it may use them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum HostCapability {
+    ReadState,
+    EmitAction,
+    SubscribeEvents,
+}
+
+impl HostCapability {
+    #[must_use]
+    pub fn slug(self) -> &'static str {
+        match self {
+            Self::ReadState => "read-state",
+            Self::EmitAction => "emit-action",
+            Self::SubscribeEvents => "subscribe-events",
+        }
+    }
+}
+
+/// Versioned contract every extension implements; the host negotiates it at
+/// load time before creating any panels or services.
+pub trait ExtensionApi {
+    fn extension_id(&self) -> &str;
+
+    fn api_version(&self) -> u32;
+
+    /// Capabilities the extension cannot run without.
+    fn required_capabilities(&self) -> Vec<HostCapability>;
+
+    /// Capabilities used when available; missing ones are simply not granted.
+    fn optional_capabilities(&self) -> Vec<HostCapability> {
+        Vec::new()
+    }
+
+    /// Panels registered once negotiation succeeds.
+    fn panels(&self) -> Vec<(ExtensionPanelDescriptor, PanelFactory)> {
+        Vec::new()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExtensionLoadError {
+    UnsupportedApiVersion {
+        extension_id: String,
+        requested: u32,
+        min_supported: u32,
+        max_supported: u32,
+    },
+    MissingCapabilities {
+        extension_id: String,
+        missing: Vec<HostCapability>,
+    },
+    PanelRejected {
+        extension_id: String,
+        panel_id: String,
+        error: PanelRegistryError,
+    },
+}
+
+impl fmt::Display for ExtensionLoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedApiVersion {
+                extension_id,
+                requested,
+                min_supported,
+                max_supported,
+            } => write!(
+                f,
+                "extension {extension_id} targets api v{requested}; host supports v{min_supported}..=v{max_supported}"
+            ),
+            Self::MissingCapabilities {
+                extension_id,
+                missing,
+            } => write!(
+                f,
+                "extension {extension_id} requires unavailable capabilities: {}",
+                missing
+                    .iter()
+                    .map(|capability| capability.slug())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            Self::PanelRejected {
+                extension_id,
+                panel_id,
+                error,
+            } => {
+                let reason = match error {
+                    PanelRegistryError::InvalidId => "invalid panel id",
+                    PanelRegistryError::DuplicateId => "panel id already registered",
+                };
+                write!(f, "extension {extension_id} panel {panel_id:?}: {reason}")
+            }
+        }
+    }
+}
+
+/// Result of a successful negotiation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NegotiatedExtension {
+    pub extension_id: String,
+    pub api_version: u32,
+    pub granted: BTreeSet<HostCapability>,
+}
+
+impl NegotiatedExtension {
+    #[must_use]
+    pub fn allows(&self, capability: HostCapability) -> bool {
+        self.granted.contains(&capability)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HostApi {
+    pub min_version: u32,
+    pub max_version: u32,
+    pub capabilities: BTreeSet<HostCapability>,
+}
+
+impl Default for HostApi {
+    fn default() -> Self {
+        Self {
+            min_version: MIN_EXTENSION_API_VERSION,
+            max_version: EXTENSION_API_VERSION,
+            capabilities: BTreeSet::from([
+                HostCapability::ReadState,
+                HostCapability::EmitAction,
+                HostCapability::SubscribeEvents,
+            ]),
+        }
+    }
+}
+
+impl HostApi {
+    /// Check version compatibility and grant declared capabilities.
+    pub fn negotiate(
+        &self,
+        extension: &dyn ExtensionApi,
+    ) -> Result<NegotiatedExtension, ExtensionLoadError> {
+        let extension_id = normalize_id(extension.extension_id());
+        let requested = extension.api_version();
+        if !(self.min_version..=self.max_version).contains(&requested) {
+            return Err(ExtensionLoadError::UnsupportedApiVersion {
+                extension_id,
+                requested,
+                min_supported: self.min_version,
+                max_supported: self.max_version,
+            });
+        }
+
+        let required: BTreeSet<HostCapability> =
+            extension.required_capabilities().into_iter().collect();
+        let missing: Vec<HostCapability> = required
+            .iter()
+            .filter(|capability| !self.capabilities.contains(capability))
+            .copied()
+            .collect();
+        if !missing.is_empty() {
+            return Err(ExtensionLoadError::MissingCapabilities {
+                extension_id,
+                missing,
+            });
+        }
+
+        let mut granted = required;
+        granted.extend(
+            extension
+                .optional_capabilities()
+                .into_iter()
+                .filter(|capability| self.capabilities.contains(capability)),
+        );
+        Ok(NegotiatedExtension {
+            extension_id,
+            api_version: requested,
+            granted,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExtensionServiceError {
+    CapabilityNotGranted {
+        extension_id: String,
+        capability: HostCapability,
+    },
+}
+
+/// Host services handed to a negotiated extension; each call is checked
+/// against the capabilities it was granted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExtensionServices {
+    extension: NegotiatedExtension,
+    state: BTreeMap<String, String>,
+    emitted_actions: Vec<String>,
+    subscriptions: BTreeSet<String>,
+}
+
+impl ExtensionServices {
+    #[must_use]
+    pub fn new(extension: NegotiatedExtension, state: BTreeMap<String, String>) -> Self {
+        Self {
+            extension,
+            state,
+            emitted_actions: Vec::new(),
+            subscriptions: BTreeSet::new(),
+        }
+    }
+
+    pub fn read_state(&self, key: &str) -> Result<Option<&str>, ExtensionServiceError> {
+        self.require(HostCapability::ReadState)?;
+        Ok(self.state.get(key.trim()).map(String::as_str))
+    }
+
+    pub fn emit_action(&mut self, action: &str) -> Result<(), ExtensionServiceError> {
+        self.require(HostCapability::EmitAction)?;
+        self.emitted_actions.push(action.trim().to_owned());
         Ok(())
     }
+
+    pub fn subscribe(&mut self, topic: &str) -> Result<(), ExtensionServiceError> {
+        self.require(HostCapability::SubscribeEvents)?;
+        self.subscriptions.insert(topic.trim().to_owned());
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn subscriptions(&self) -> Vec<&str> {
+        self.subscriptions.iter().map(String::as_str).collect()
+    }
+
+    pub fn take_emitted_actions(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.emitted_actions)
+    }
+
+    fn require(&self, capability: HostCapability) -> Result<(), ExtensionServiceError> {
+        if self.extension.allows(capability) {
+            Ok(())
+        } else {
+            Err(ExtensionServiceError::CapabilityNotGranted {
+                extension_id: self.extension.extension_id.clone(),
+                capability,
+            })
+        }
+    }
 }
 
 fn normalize_id(value: &str) -> String {
@@ -244,22 +607,31 @@ fn normalize_id(value: &str) -> String {
 
 #[cfg(test)]
 mod tests {
-    use std::cell::RefCell;
-    use std::rc::Rc;
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+    use std::time::Duration;
 
     use forge_ftui_adapter::input::{InputEvent, Key, KeyEvent};
     use forge_ftui_adapter::render::FrameSize;
     use forge_ftui_adapter::style::ThemeSpec;
 
+    use std::collections::{BTreeMap, BTreeSet};
+
+    use crate::extension_sandbox::{
+        ExtensionCallError, ExtensionCallGuard, ExtensionCallLimits, ExtensionFaultReason,
+    };
+
     use super::{
-        ExtensionPanel, ExtensionPanelDescriptor, PanelEvent, PanelMode, PanelMountContext,
-        PanelRegistry, PanelRegistryError, PanelRuntimeContext, PanelSessionError,
-        PanelSessionState, PanelUnmountReason, PanelUpdate,
+        ExtensionApi, ExtensionLoadError, ExtensionPanel, ExtensionPanelDescriptor,
+        ExtensionServiceError, ExtensionServices, HostApi, HostCapability, PanelEvent,
+        PanelFactory, PanelMode, PanelMountContext, PanelRegistry, PanelRegistryError,
+        PanelRuntimeContext, PanelSessionError, PanelSessionState, PanelUnmountReason, PanelUpdate,
+        EXTENSION_API_VERSION,
     };
 
     struct FakePanel {
         descriptor: ExtensionPanelDescriptor,
-        calls: Rc<RefCell<Vec<String>>>,
+        calls: Arc<Mutex<Vec<String>>>,
     }
 
     impl ExtensionPanel for FakePanel {
@@ -268,9 +640,7 @@ mod tests {
         }
 
         fn on_mount(&mut self, context: &PanelMountContext) -> PanelUpdate {
-            self.calls
-                .borrow_mut()
-                .push(format!("mount:{}", context.workspace_id));
+            record(&self.calls, format!("mount:{}", context.workspace_id));
             PanelUpdate::default()
         }
 
@@ -280,7 +650,7 @@ mod tests {
                 PanelEvent::Tick => "event:tick",
                 PanelEvent::DataRefresh { .. } => "event:data",
             };
-            self.calls.borrow_mut().push(label.to_owned());
+            record(&self.calls, label.to_owned());
             PanelUpdate::default()
         }
 
@@ -300,7 +670,13 @@ mod tests {
         }
 
         fn on_unmount(&mut self, _reason: PanelUnmountReason) {
-            self.calls.borrow_mut().push("unmount".to_owned());
+            record(&self.calls, "unmount".to_owned());
+        }
+    }
+
+    fn record(calls: &Mutex<Vec<String>>, call: String) {
+        if let Ok(mut calls) = calls.lock() {
+            calls.push(call);
         }
     }
 
@@ -316,7 +692,7 @@ mod tests {
 
     #[test]
     fn register_and_list_descriptor() {
-        let calls = Rc::new(RefCell::new(Vec::<String>::new()));
+        let calls = Arc::new(Mutex::new(Vec::<String>::new()));
         let mut registry = PanelRegistry::new();
         let register_result = registry.register(
             descriptor(PanelMode::Interactive),
@@ -344,7 +720,7 @@ mod tests {
             Box::new(|| {
                 Box::new(FakePanel {
                     descriptor: descriptor(PanelMode::Interactive),
-                    calls: Rc::new(RefCell::new(Vec::new())),
+                    calls: Arc::new(Mutex::new(Vec::new())),
                 })
             }),
         );
@@ -354,7 +730,7 @@ mod tests {
             Box::new(|| {
                 Box::new(FakePanel {
                     descriptor: descriptor(PanelMode::Interactive),
-                    calls: Rc::new(RefCell::new(Vec::new())),
+                    calls: Arc::new(Mutex::new(Vec::new())),
                 })
             }),
         );
@@ -363,7 +739,7 @@ mod tests {
 
     #[test]
     fn lifecycle_mount_event_render_unmount() {
-        let calls = Rc::new(RefCell::new(Vec::<String>::new()));
+        let calls = Arc::new(Mutex::new(Vec::<String>::new()));
         let mut registry = PanelRegistry::new();
         let register = registry.register(
             descriptor(PanelMode::Interactive),
@@ -379,23 +755,31 @@ mod tests {
         );
         assert_eq!(register, Ok(()));
 
+        let mut guard = ExtensionCallGuard::default();
         let mut session = match registry.create_session("custom-panel") {
             Ok(session) => session,
             Err(err) => panic!("expected session, got {err:?}"),
         };
         assert_eq!(session.state(), PanelSessionState::Created);
-        let mount_result = session.mount(&PanelMountContext {
-            workspace_id: "forge".to_owned(),
-            now_epoch_s: 10,
-        });
+        let mount_result = session.mount(
+            &mut guard,
+            &PanelMountContext {
+                workspace_id: "forge".to_owned(),
+                now_epoch_s: 10,
+            },
+        );
         assert_eq!(mount_result, Ok(PanelUpdate::default()));
         assert_eq!(session.state(), PanelSessionState::Mounted);
 
-        let dispatch =
-            session.dispatch_event(&PanelEvent::Tick, &PanelRuntimeContext { now_epoch_s: 11 });
+        let dispatch = session.dispatch_event(
+            &mut guard,
+            &PanelEvent::Tick,
+            &PanelRuntimeContext { now_epoch_s: 11 },
+        );
         assert_eq!(dispatch, Ok(PanelUpdate::default()));
 
         let frame = session.render(
+            &mut guard,
             FrameSize {
                 width: 24,
                 height: 2,
@@ -404,11 +788,11 @@ mod tests {
         );
         assert!(frame.row_text(0).contains("Custom Panel"));
 
-        let unmount = session.unmount(PanelUnmountReason::HostShutdown);
+        let unmount = session.unmount(&mut guard, PanelUnmountReason::HostShutdown);
         assert_eq!(unmount, Ok(()));
         assert_eq!(session.state(), PanelSessionState::Closed);
 
-        let calls = calls.borrow().clone();
+        let calls = calls.lock().map(|calls| calls.clone()).unwrap_or_default();
         assert_eq!(
             calls,
             vec![
@@ -427,23 +811,28 @@ mod tests {
             Box::new(|| {
                 Box::new(FakePanel {
                     descriptor: descriptor(PanelMode::ReadOnly),
-                    calls: Rc::new(RefCell::new(Vec::new())),
+                    calls: Arc::new(Mutex::new(Vec::new())),
                 })
             }),
         );
         assert_eq!(register, Ok(()));
 
+        let mut guard = ExtensionCallGuard::default();
         let mut session = match registry.create_session("custom-panel") {
             Ok(session) => session,
             Err(err) => panic!("expected session, got {err:?}"),
         };
-        let mounted = session.mount(&PanelMountContext {
-            workspace_id: "forge".to_owned(),
-            now_epoch_s: 20,
-        });
+        let mounted = session.mount(
+            &mut guard,
+            &PanelMountContext {
+                workspace_id: "forge".to_owned(),
+                now_epoch_s: 20,
+            },
+        );
         assert_eq!(mounted, Ok(PanelUpdate::default()));
 
         let update = session.dispatch_event(
+            &mut guard,
             &PanelEvent::Input(InputEvent::Key(KeyEvent::plain(Key::Enter))),
             &PanelRuntimeContext { now_epoch_s: 21 },
         );
@@ -468,23 +857,279 @@ mod tests {
             Box::new(|| {
                 Box::new(FakePanel {
                     descriptor: descriptor(PanelMode::Interactive),
-                    calls: Rc::new(RefCell::new(Vec::new())),
+                    calls: Arc::new(Mutex::new(Vec::new())),
                 })
             }),
         );
         assert_eq!(registered, Ok(()));
+        let mut guard = ExtensionCallGuard::default();
         let mut session = match registry.create_session("custom-panel") {
             Ok(session) => session,
             Err(err) => panic!("expected session, got {err:?}"),
         };
 
-        let event_before_mount =
-            session.dispatch_event(&PanelEvent::Tick, &PanelRuntimeContext { now_epoch_s: 0 });
+        let event_before_mount = session.dispatch_event(
+            &mut guard,
+            &PanelEvent::Tick,
+            &PanelRuntimeContext { now_epoch_s: 0 },
+        );
         assert_eq!(event_before_mount, Err(PanelSessionError::InvalidLifecycle));
-        let unmount_before_mount = session.unmount(PanelUnmountReason::UserClosed);
+        let unmount_before_mount = session.unmount(&mut guard, PanelUnmountReason::UserClosed);
         assert_eq!(
             unmount_before_mount,
             Err(PanelSessionError::InvalidLifecycle)
         );
     }
+
+    struct FakeExtension {
+        api_version: u32,
+        required: Vec<HostCapability>,
+        optional: Vec<HostCapability>,
+        panels: Vec<ExtensionPanelDescriptor>,
+    }
+
+    impl ExtensionApi for FakeExtension {
+        fn extension_id(&self) -> &str {
+            "Fake Ext"
+        }
+
+        fn api_version(&self) -> u32 {
+            self.api_version
+        }
+
+        fn required_capabilities(&self) -> Vec<HostCapability> {
+            self.required.clone()
+        }
+
+        fn optional_capabilities(&self) -> Vec<HostCapability> {
+            self.optional.clone()
+        }
+
+        fn panels(&self) -> Vec<(ExtensionPanelDescriptor, PanelFactory)> {
+            self.panels
+                .iter()
+                .map(|descriptor| {
+                    let panel_descriptor = descriptor.clone();
+                    let factory: PanelFactory = Box::new(move || {
+                        Box::new(FakePanel {
+                            descriptor: panel_descriptor.clone(),
+                            calls: Arc::new(Mutex::new(Vec::new())),
+                        })
+                    });
+                    (descriptor.clone(), factory)
+                })
+                .collect()
+        }
+    }
+
+    #[test]
+    fn unsupported_api_version_is_rejected_at_load() {
+        let extension = FakeExtension {
+            api_version: EXTENSION_API_VERSION + 1,
+            required: vec![HostCapability::ReadState],
+            optional: Vec::new(),
+            panels: vec![descriptor(PanelMode::ReadOnly)],
+        };
+        let mut registry = PanelRegistry::new();
+        let err = match registry.load_extension(&HostApi::default(), &extension, BTreeMap::new()) {
+            Ok(services) => panic!("expected rejection, got {services:?}"),
+            Err(err) => err,
+        };
+        assert!(registry.descriptors().is_empty());
+        assert_eq!(
+            err,
+            ExtensionLoadError::UnsupportedApiVersion {
+                extension_id: "fake-ext".to_owned(),
+                requested: EXTENSION_API_VERSION + 1,
+                min_supported: 1,
+                max_supported: EXTENSION_API_VERSION,
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "extension fake-ext targets api v{}; host supports v1..=v{EXTENSION_API_VERSION}",
+                EXTENSION_API_VERSION + 1
+            )
+        );
+    }
+
+    #[test]
+    fn negotiation_requires_declared_capabilities_and_drops_missing_optional() {
+        let host = HostApi {
+            capabilities: BTreeSet::from([HostCapability::ReadState]),
+            ..HostApi::default()
+        };
+        let needs_actions = FakeExtension {
+            api_version: EXTENSION_API_VERSION,
+            required: vec![HostCapability::EmitAction, HostCapability::ReadState],
+            optional: Vec::new(),
+            panels: Vec::new(),
+        };
+        let err = host.negotiate(&needs_actions).err();
+        assert_eq!(
+            err.map(|err| err.to_string()).as_deref(),
+            Some("extension fake-ext requires unavailable capabilities: emit-action")
+        );
+
+        let reader = FakeExtension {
+            api_version: EXTENSION_API_VERSION,
+            required: vec![HostCapability::ReadState],
+            optional: vec![HostCapability::SubscribeEvents],
+            panels: Vec::new(),
+        };
+        let negotiated = host
+            .negotiate(&reader)
+            .unwrap_or_else(|err| panic!("negotiate: {err}"));
+        assert!(negotiated.allows(HostCapability::ReadState));
+        assert!(!negotiated.allows(HostCapability::SubscribeEvents));
+    }
+
+    #[test]
+    fn services_are_gated_by_granted_capabilities() {
+        let extension = FakeExtension {
+            api_version: EXTENSION_API_VERSION,
+            required: vec![HostCapability::ReadState, HostCapability::EmitAction],
+            optional: Vec::new(),
+            panels: Vec::new(),
+        };
+        let negotiated = HostApi::default()
+            .negotiate(&extension)
+            .unwrap_or_else(|err| panic!("negotiate: {err}"));
+        let mut services = ExtensionServices::new(
+            negotiated,
+            BTreeMap::from([("selected_loop".to_owned(), "loop-a".to_owned())]),
+        );
+
+        assert_eq!(services.read_state("selected_loop"), Ok(Some("loop-a")));
+        assert_eq!(services.emit_action("refresh"), Ok(()));
+        assert_eq!(services.take_emitted_actions(), vec!["refresh"]);
+        assert_eq!(
+            services.subscribe("loop.updated"),
+            Err(ExtensionServiceError::CapabilityNotGranted {
+                extension_id: "fake-ext".to_owned(),
+                capability: HostCapability::SubscribeEvents,
+            })
+        );
+        assert!(services.subscriptions().is_empty());
+    }
+
+    #[test]
+    fn load_extension_registers_panels_only_when_all_ids_are_free() {
+        let mut registry = PanelRegistry::new();
+        let extension = FakeExtension {
+            api_version: EXTENSION_API_VERSION,
+            required: vec![HostCapability::ReadState],
+            optional: Vec::new(),
+            panels: vec![descriptor(PanelMode::Interactive)],
+        };
+        let services = registry
+            .load_extension(&HostApi::default(), &extension, BTreeMap::new())
+            .unwrap_or_else(|err| panic!("load: {err}"));
+        assert_eq!(services.read_state("missing"), Ok(None));
+        assert_eq!(registry.descriptors().len(), 1);
+        assert!(registry.create_session("custom-panel").is_ok());
+
+        let mut second_panel = descriptor(PanelMode::ReadOnly);
+        second_panel.id = "second-panel".to_owned();
+        let clashing = FakeExtension {
+            api_version: EXTENSION_API_VERSION,
+            required: Vec::new(),
+            optional: Vec::new(),
+            panels: vec![second_panel, descriptor(PanelMode::ReadOnly)],
+        };
+        let err = registry
+            .load_extension(&HostApi::default(), &clashing, BTreeMap::new())
+            .err();
+        assert_eq!(
+            err,
+            Some(ExtensionLoadError::PanelRejected {
+                extension_id: "fake-ext".to_owned(),
+                panel_id: "custom-panel".to_owned(),
+                error: PanelRegistryError::DuplicateId,
+            })
+        );
+        assert_eq!(
+            err.map(|err| err.to_string()).as_deref(),
+            Some("extension fake-ext panel \"custom-panel\": panel id already registered")
+        );
+        assert_eq!(registry.descriptors().len(), 1);
+    }
+
+    struct SlowPanel {
+        descriptor: ExtensionPanelDescriptor,
+    }
+
+    impl ExtensionPanel for SlowPanel {
+        fn descriptor(&self) -> &ExtensionPanelDescriptor {
+            &self.descriptor
+        }
+
+        fn on_event(&mut self, _event: &PanelEvent, _context: &PanelRuntimeContext) -> PanelUpdate {
+            thread::sleep(Duration::from_millis(200));
+            PanelUpdate::default()
+        }
+
+        fn render(
+            &self,
+            size: FrameSize,
+            theme: ThemeSpec,
+        ) -> forge_ftui_adapter::render::RenderFrame {
+            forge_ftui_adapter::render::RenderFrame::new(size, theme)
+        }
+    }
+
+    #[test]
+    fn hung_panel_callback_faults_under_the_owning_extension() {
+        let mut registry = PanelRegistry::new();
+        let register = registry.register(
+            descriptor(PanelMode::Interactive),
+            Box::new(|| {
+                Box::new(SlowPanel {
+                    descriptor: descriptor(PanelMode::Interactive),
+                })
+            }),
+        );
+        assert_eq!(register, Ok(()));
+        let mut guard = ExtensionCallGuard::new(ExtensionCallLimits {
+            timeout: Duration::from_millis(20),
+            ..ExtensionCallLimits::default()
+        });
+        let mut session = match registry.create_session("custom-panel") {
+            Ok(session) => session,
+            Err(err) => panic!("expected session, got {err:?}"),
+        };
+        let mounted = session.mount(
+            &mut guard,
+            &PanelMountContext {
+                workspace_id: "forge".to_owned(),
+                now_epoch_s: 30,
+            },
+        );
+        assert_eq!(mounted, Ok(PanelUpdate::default()));
+
+        let dispatch = session.dispatch_event(
+            &mut guard,
+            &PanelEvent::Tick,
+            &PanelRuntimeContext { now_epoch_s: 31 },
+        );
+        let fault = match dispatch {
+            Err(PanelSessionError::Faulted(ExtensionCallError::Faulted(fault))) => fault,
+            other => panic!("expected fault, got {other:?}"),
+        };
+        assert_eq!(fault.extension_id, "custom-panel");
+        assert_eq!(fault.reason, ExtensionFaultReason::Timeout { limit_ms: 20 });
+        assert_eq!(guard.take_faults(), vec![fault]);
+
+        // The stalled worker still holds the panel, so rendering faults too.
+        let frame = session.render(
+            &mut guard,
+            FrameSize {
+                width: 24,
+                height: 1,
+            },
+            ThemeSpec::default(),
+        );
+        assert!(frame.row_text(0).contains("panel unavailable"));
+    }
 }
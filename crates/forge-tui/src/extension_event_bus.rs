@@ -1,6 +1,9 @@
 //! Versioned internal event bus for plugin/extension compatibility.
 
-use std::collections::{BTreeMap, BTreeSet};
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+/// Default per-subscriber queue bound; the oldest event is dropped past it.
+pub const DEFAULT_SUBSCRIBER_QUEUE_CAPACITY: usize = 256;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct SchemaVersion {
@@ -80,15 +83,63 @@ pub struct DispatchReport {
     pub kind: PluginEventKind,
     pub schema_version: SchemaVersion,
     pub delivered_plugin_ids: Vec<String>,
+    /// Subscribers whose full queue dropped its oldest event for this one.
+    pub overflowed_plugin_ids: Vec<String>,
     pub skipped: Vec<DispatchSkip>,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SubscriberStats {
+    /// Events handed to the subscriber by `drain_plugin_events`.
+    pub delivered: u64,
+    /// Events accepted into the queue, including ones later dropped on overflow.
+    pub enqueued: u64,
+    pub dropped: u64,
+    pub queued: usize,
+    pub capacity: usize,
+}
+
+#[derive(Debug, Clone)]
+struct SubscriberInbox {
+    events: VecDeque<PluginEventEnvelope>,
+    capacity: usize,
+    delivered: u64,
+    enqueued: u64,
+    dropped: u64,
+}
+
+impl SubscriberInbox {
+    fn new(capacity: usize) -> Self {
+        Self {
+            events: VecDeque::new(),
+            capacity: capacity.max(1),
+            delivered: 0,
+            enqueued: 0,
+            dropped: 0,
+        }
+    }
+
+    /// Enqueue without blocking; returns `true` when the oldest event was dropped.
+    fn push(&mut self, envelope: PluginEventEnvelope) -> bool {
+        let mut overflowed = false;
+        while self.events.len() >= self.capacity {
+            self.events.pop_front();
+            self.dropped = self.dropped.saturating_add(1);
+            overflowed = true;
+        }
+        self.events.push_back(envelope);
+        self.enqueued = self.enqueued.saturating_add(1);
+        overflowed
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ExtensionEventBus {
     next_event_seq: u64,
     schemas: BTreeMap<PluginEventKind, SchemaVersion>,
     subscribers: BTreeMap<String, PluginSubscriber>,
-    inboxes: BTreeMap<String, Vec<PluginEventEnvelope>>,
+    inboxes: BTreeMap<String, SubscriberInbox>,
+    default_queue_capacity: usize,
 }
 
 impl Default for ExtensionEventBus {
@@ -98,6 +149,7 @@ impl Default for ExtensionEventBus {
             schemas: default_schema_registry(),
             subscribers: BTreeMap::new(),
             inboxes: BTreeMap::new(),
+            default_queue_capacity: DEFAULT_SUBSCRIBER_QUEUE_CAPACITY,
         }
     }
 }
@@ -120,13 +172,55 @@ impl ExtensionEventBus {
             return Err(EventBusError::DuplicatePluginId);
         }
         subscriber.compatibility = normalized_compatibility(subscriber.compatibility);
-        self.inboxes
-            .insert(subscriber.plugin_id.clone(), Vec::new());
+        self.inboxes.insert(
+            subscriber.plugin_id.clone(),
+            SubscriberInbox::new(self.default_queue_capacity),
+        );
         self.subscribers
             .insert(subscriber.plugin_id.clone(), subscriber);
         Ok(())
     }
 
+    /// Bound a subscriber's queue; excess queued events are dropped oldest first.
+    pub fn set_queue_capacity(&mut self, plugin_id: &str, capacity: usize) -> bool {
+        let Some(inbox) = self.inboxes.get_mut(&normalize_id(plugin_id)) else {
+            return false;
+        };
+        inbox.capacity = capacity.max(1);
+        while inbox.events.len() > inbox.capacity {
+            inbox.events.pop_front();
+            inbox.dropped = inbox.dropped.saturating_add(1);
+        }
+        true
+    }
+
+    /// Add an event kind to a subscriber's filter.
+    pub fn subscribe(&mut self, plugin_id: &str, kind: PluginEventKind) -> bool {
+        self.subscribers
+            .get_mut(&normalize_id(plugin_id))
+            .is_some_and(|subscriber| subscriber.subscriptions.insert(kind))
+    }
+
+    /// Remove an event kind from a subscriber's filter.
+    pub fn unsubscribe(&mut self, plugin_id: &str, kind: PluginEventKind) -> bool {
+        self.subscribers
+            .get_mut(&normalize_id(plugin_id))
+            .is_some_and(|subscriber| subscriber.subscriptions.remove(&kind))
+    }
+
+    #[must_use]
+    pub fn subscriber_stats(&self, plugin_id: &str) -> Option<SubscriberStats> {
+        self.inboxes
+            .get(&normalize_id(plugin_id))
+            .map(|inbox| SubscriberStats {
+                delivered: inbox.delivered,
+                enqueued: inbox.enqueued,
+                dropped: inbox.dropped,
+                queued: inbox.events.len(),
+                capacity: inbox.capacity,
+            })
+    }
+
     pub fn set_schema_version(&mut self, kind: PluginEventKind, version: SchemaVersion) {
         self.schemas.insert(kind, version);
     }
@@ -159,6 +253,7 @@ impl ExtensionEventBus {
         };
 
         let mut delivered = Vec::new();
+        let mut overflowed = Vec::new();
         let mut skipped = Vec::new();
         for (plugin_id, subscriber) in &self.subscribers {
             if !subscriber.subscriptions.contains(&kind) {
@@ -189,7 +284,9 @@ impl ExtensionEventBus {
                 continue;
             }
             if let Some(inbox) = self.inboxes.get_mut(plugin_id) {
-                inbox.push(envelope.clone());
+                if inbox.push(envelope.clone()) {
+                    overflowed.push(plugin_id.clone());
+                }
                 delivered.push(plugin_id.clone());
             }
         }
@@ -199,6 +296,7 @@ impl ExtensionEventBus {
             kind,
             schema_version,
             delivered_plugin_ids: delivered,
+            overflowed_plugin_ids: overflowed,
             skipped,
         }
     }
@@ -209,7 +307,9 @@ impl ExtensionEventBus {
         let Some(inbox) = self.inboxes.get_mut(&plugin_id) else {
             return Vec::new();
         };
-        std::mem::take(inbox)
+        let events: Vec<PluginEventEnvelope> = inbox.events.drain(..).collect();
+        inbox.delivered = inbox.delivered.saturating_add(events.len() as u64);
+        events
     }
 }
 
@@ -259,8 +359,8 @@ mod tests {
     use std::collections::{BTreeMap, BTreeSet};
 
     use super::{
-        DispatchSkip, EventBusError, ExtensionEventBus, PluginEventKind, PluginSubscriber,
-        SchemaCompatibility, SchemaVersion,
+        DispatchSkip, EventBusError, ExtensionEventBus, PluginEventEnvelope, PluginEventKind,
+        PluginSubscriber, SchemaCompatibility, SchemaVersion, SubscriberStats,
     };
 
     fn subscriber(
@@ -412,4 +512,85 @@ mod tests {
         let report = bus.publish(PluginEventKind::PanelLifecycle, BTreeMap::new(), 0);
         assert_eq!(report.delivered_plugin_ids, vec!["plugin-a".to_owned()]);
     }
+
+    #[test]
+    fn full_queue_drops_oldest_for_slow_subscriber_only() {
+        let mut bus = ExtensionEventBus::new();
+        for plugin_id in ["slow", "fast"] {
+            let register = bus.register_subscriber(subscriber(
+                plugin_id,
+                PluginEventKind::TabChanged,
+                1,
+                0,
+                0,
+            ));
+            assert_eq!(register, Ok(()));
+        }
+        assert!(bus.set_queue_capacity("slow", 2));
+
+        let mut last_report = None;
+        for seq in 0..5 {
+            let mut payload = BTreeMap::new();
+            payload.insert("seq".to_owned(), seq.to_string());
+            last_report = Some(bus.publish(PluginEventKind::TabChanged, payload, seq));
+        }
+        let Some(report) = last_report else {
+            panic!("expected a dispatch report");
+        };
+        assert_eq!(report.delivered_plugin_ids, vec!["fast", "slow"]);
+        assert_eq!(report.overflowed_plugin_ids, vec!["slow"]);
+
+        let seqs = |events: Vec<PluginEventEnvelope>| {
+            events
+                .iter()
+                .map(|event| event.payload.get("seq").cloned().unwrap_or_default())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(seqs(bus.drain_plugin_events("slow")), vec!["3", "4"]);
+        assert_eq!(
+            seqs(bus.drain_plugin_events("fast")),
+            vec!["0", "1", "2", "3", "4"]
+        );
+        assert_eq!(
+            bus.subscriber_stats("slow"),
+            Some(SubscriberStats {
+                delivered: 2,
+                enqueued: 5,
+                dropped: 3,
+                queued: 0,
+                capacity: 2,
+            })
+        );
+        assert_eq!(
+            bus.subscriber_stats("fast")
+                .map(|stats| (stats.delivered, stats.dropped)),
+            Some((5, 0))
+        );
+    }
+
+    #[test]
+    fn typed_subscription_filters_event_kinds() {
+        let mut bus = ExtensionEventBus::new();
+        let mut plugin = subscriber("plugin-a", PluginEventKind::TabChanged, 1, 0, 0);
+        plugin.compatibility.insert(
+            PluginEventKind::PanelLifecycle,
+            SchemaCompatibility {
+                major: 1,
+                min_minor: 0,
+                max_minor: 0,
+            },
+        );
+        assert_eq!(bus.register_subscriber(plugin), Ok(()));
+
+        let _ = bus.publish(PluginEventKind::PanelLifecycle, BTreeMap::new(), 1);
+        assert!(bus.drain_plugin_events("plugin-a").is_empty());
+
+        assert!(bus.subscribe("plugin-a", PluginEventKind::PanelLifecycle));
+        assert!(bus.unsubscribe("plugin-a", PluginEventKind::TabChanged));
+        let _ = bus.publish(PluginEventKind::PanelLifecycle, BTreeMap::new(), 2);
+        let _ = bus.publish(PluginEventKind::TabChanged, BTreeMap::new(), 3);
+        let events = bus.drain_plugin_events("plugin-a");
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].kind, PluginEventKind::PanelLifecycle);
+    }
 }
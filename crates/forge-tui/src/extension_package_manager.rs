@@ -1,8 +1,10 @@
-//! Plugin packaging, discovery, signature verification, and lifecycle controls.
+//! Plugin packaging, discovery, signature and checksum verification, versioned
+//! updates with rollback, and lifecycle controls.
 
 use std::collections::{BTreeMap, BTreeSet};
 
 use serde_json::{Map, Value};
+use sha2::{Digest, Sha256};
 
 use crate::extension_actions::ExtensionPermission;
 use crate::extension_event_bus::SchemaVersion;
@@ -72,6 +74,8 @@ pub enum PluginLifecycleAction {
     Started,
     Stopped,
     Rejected,
+    Updated,
+    RolledBack,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -93,13 +97,27 @@ pub enum PluginManagerError {
     AlreadyExists,
     NotFound,
     InvalidStateTransition,
+    MissingArtifact,
+    ChecksumMismatch,
+    NoPreviousVersion,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstalledVersion {
+    pub version: String,
+    pub package: PluginPackage,
+    pub installed_at_epoch_s: i64,
+}
+
+/// Artifact contents keyed by artifact path, as fetched for install or update.
+pub type PluginArtifactContents = BTreeMap<String, Vec<u8>>;
+
 #[derive(Debug, Clone)]
 pub struct ExtensionPackageManager {
     host_api_version: SchemaVersion,
     trusted_signers: BTreeMap<String, String>,
     plugins: BTreeMap<String, ManagedPlugin>,
+    installed_versions: BTreeMap<String, Vec<InstalledVersion>>,
     lifecycle_events: Vec<PluginLifecycleEvent>,
 }
 
@@ -115,6 +133,7 @@ impl ExtensionPackageManager {
             host_api_version,
             trusted_signers,
             plugins: BTreeMap::new(),
+            installed_versions: BTreeMap::new(),
             lifecycle_events: Vec::new(),
         }
     }
@@ -157,7 +176,14 @@ impl ExtensionPackageManager {
         Ok(())
     }
 
-    pub fn install(&mut self, plugin_id: &str, now_epoch_s: i64) -> Result<(), PluginManagerError> {
+    /// Install a discovered plugin once `contents` match the checksums in its
+    /// manifest; on mismatch the plugin stays discovered with the error kept.
+    pub fn install(
+        &mut self,
+        plugin_id: &str,
+        contents: &PluginArtifactContents,
+        now_epoch_s: i64,
+    ) -> Result<(), PluginManagerError> {
         let plugin_id = normalize_id(plugin_id);
         let plugin = self
             .plugins
@@ -166,17 +192,173 @@ impl ExtensionPackageManager {
         if plugin.state != PluginLifecycleState::Discovered {
             return Err(PluginManagerError::InvalidStateTransition);
         }
+        if let Err(err) = verify_artifact_checksums(&plugin.package, contents) {
+            plugin.last_error = Some(format!("install rejected: {err:?}"));
+            self.record_event(
+                &plugin_id,
+                PluginLifecycleAction::Rejected,
+                now_epoch_s,
+                format!("install rejected: {err:?}"),
+            );
+            return Err(err);
+        }
+        plugin.last_error = None;
         plugin.state = PluginLifecycleState::Installed;
         plugin.installed_at_epoch_s = Some(now_epoch_s.max(0));
+        let package = plugin.package.clone();
+        self.push_installed_version(&plugin_id, package, now_epoch_s);
+        self.record_event(
+            &plugin_id,
+            PluginLifecycleAction::Installed,
+            now_epoch_s,
+            "plugin installed with verified checksums".to_owned(),
+        );
+        Ok(())
+    }
+
+    /// Verify signature, host range, and artifact checksums, then install.
+    /// Nothing is recorded as installed unless every check passes.
+    pub fn install_package(
+        &mut self,
+        package: PluginPackage,
+        contents: &PluginArtifactContents,
+        now_epoch_s: i64,
+    ) -> Result<(), PluginManagerError> {
+        let plugin_id = normalize_id(&package.manifest.plugin_id);
+        if let Err(err) = self.verify_for_install(&package, contents) {
+            self.record_event(
+                &plugin_id,
+                PluginLifecycleAction::Rejected,
+                now_epoch_s,
+                format!("install rejected: {err:?}"),
+            );
+            return Err(err);
+        }
+        if self.plugins.contains_key(&plugin_id) {
+            return Err(PluginManagerError::AlreadyExists);
+        }
+
+        let version = package.manifest.version.trim().to_owned();
+        self.plugins.insert(
+            plugin_id.clone(),
+            ManagedPlugin {
+                package: package.clone(),
+                state: PluginLifecycleState::Installed,
+                discovered_at_epoch_s: now_epoch_s.max(0),
+                installed_at_epoch_s: Some(now_epoch_s.max(0)),
+                enabled_at_epoch_s: None,
+                running_at_epoch_s: None,
+                last_error: None,
+            },
+        );
+        self.push_installed_version(&plugin_id, package, now_epoch_s);
         self.record_event(
             &plugin_id,
             PluginLifecycleAction::Installed,
             now_epoch_s,
-            "plugin installed".to_owned(),
+            format!("plugin {version} installed with verified checksums"),
+        );
+        Ok(())
+    }
+
+    /// Replace an installed plugin with a newer package. The new package is
+    /// fully verified before it is swapped in; on failure the current version
+    /// stays active and the error is kept on the plugin.
+    pub fn update(
+        &mut self,
+        package: PluginPackage,
+        contents: &PluginArtifactContents,
+        now_epoch_s: i64,
+    ) -> Result<(), PluginManagerError> {
+        let plugin_id = normalize_id(&package.manifest.plugin_id);
+        let current = self
+            .plugins
+            .get(&plugin_id)
+            .ok_or(PluginManagerError::NotFound)?;
+        if current.state == PluginLifecycleState::Discovered {
+            return Err(PluginManagerError::InvalidStateTransition);
+        }
+        let from_version = current.package.manifest.version.trim().to_owned();
+        let to_version = package.manifest.version.trim().to_owned();
+
+        if let Err(err) = self.verify_for_install(&package, contents) {
+            let detail = format!("update {from_version} -> {to_version} rolled back: {err:?}");
+            if let Some(plugin) = self.plugins.get_mut(&plugin_id) {
+                plugin.last_error = Some(detail.clone());
+            }
+            self.record_event(
+                &plugin_id,
+                PluginLifecycleAction::Rejected,
+                now_epoch_s,
+                detail,
+            );
+            return Err(err);
+        }
+
+        if let Some(plugin) = self.plugins.get_mut(&plugin_id) {
+            plugin.package = package.clone();
+            plugin.installed_at_epoch_s = Some(now_epoch_s.max(0));
+            plugin.last_error = None;
+        }
+        self.push_installed_version(&plugin_id, package, now_epoch_s);
+        self.record_event(
+            &plugin_id,
+            PluginLifecycleAction::Updated,
+            now_epoch_s,
+            format!("plugin updated {from_version} -> {to_version}"),
+        );
+        Ok(())
+    }
+
+    /// Restore the previously installed version of a plugin.
+    pub fn rollback(
+        &mut self,
+        plugin_id: &str,
+        now_epoch_s: i64,
+    ) -> Result<(), PluginManagerError> {
+        let plugin_id = normalize_id(plugin_id);
+        if !self.plugins.contains_key(&plugin_id) {
+            return Err(PluginManagerError::NotFound);
+        }
+        let history = self
+            .installed_versions
+            .get_mut(&plugin_id)
+            .ok_or(PluginManagerError::NoPreviousVersion)?;
+        if history.len() < 2 {
+            return Err(PluginManagerError::NoPreviousVersion);
+        }
+        let Some(abandoned) = history.pop() else {
+            return Err(PluginManagerError::NoPreviousVersion);
+        };
+        let Some(restored) = history.last().cloned() else {
+            return Err(PluginManagerError::NoPreviousVersion);
+        };
+        if let Some(plugin) = self.plugins.get_mut(&plugin_id) {
+            plugin.package = restored.package;
+            plugin.installed_at_epoch_s = Some(now_epoch_s.max(0));
+            plugin.last_error = None;
+        }
+        self.record_event(
+            &plugin_id,
+            PluginLifecycleAction::RolledBack,
+            now_epoch_s,
+            format!(
+                "plugin rolled back {} -> {}",
+                abandoned.version, restored.version
+            ),
         );
         Ok(())
     }
 
+    /// Installed versions of a plugin, oldest first; the last entry is active.
+    #[must_use]
+    pub fn installed_versions(&self, plugin_id: &str) -> &[InstalledVersion] {
+        self.installed_versions
+            .get(&normalize_id(plugin_id))
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
     pub fn set_enabled(
         &mut self,
         plugin_id: &str,
@@ -262,6 +444,7 @@ impl ExtensionPackageManager {
         if self.plugins.remove(&plugin_id).is_none() {
             return Err(PluginManagerError::NotFound);
         }
+        self.installed_versions.remove(&plugin_id);
         self.record_event(
             &plugin_id,
             PluginLifecycleAction::Uninstalled,
@@ -281,6 +464,32 @@ impl ExtensionPackageManager {
         &self.lifecycle_events
     }
 
+    fn verify_for_install(
+        &self,
+        package: &PluginPackage,
+        contents: &PluginArtifactContents,
+    ) -> Result<(), PluginManagerError> {
+        validate_package_shape(package)?;
+        verify_package_signature(package, &self.trusted_signers)?;
+        ensure_host_compatibility(
+            package.manifest.min_host_api,
+            package.manifest.max_host_api,
+            self.host_api_version,
+        )?;
+        verify_artifact_checksums(package, contents)
+    }
+
+    fn push_installed_version(&mut self, plugin_id: &str, package: PluginPackage, at_epoch_s: i64) {
+        self.installed_versions
+            .entry(plugin_id.to_owned())
+            .or_default()
+            .push(InstalledVersion {
+                version: package.manifest.version.trim().to_owned(),
+                package,
+                installed_at_epoch_s: at_epoch_s.max(0),
+            });
+    }
+
     fn record_event(
         &mut self,
         plugin_id: &str,
@@ -411,6 +620,33 @@ fn verify_package_signature(
     }
 }
 
+/// Digest recorded in a package manifest for an artifact's bytes.
+#[must_use]
+pub fn artifact_digest(bytes: &[u8]) -> String {
+    sha256_hex(bytes)
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    hex::encode(Sha256::digest(bytes))
+}
+
+fn verify_artifact_checksums(
+    package: &PluginPackage,
+    contents: &PluginArtifactContents,
+) -> Result<(), PluginManagerError> {
+    for artifact in &package.artifacts {
+        let Some(bytes) = contents.get(&artifact.path) else {
+            return Err(PluginManagerError::MissingArtifact);
+        };
+        if bytes.len() as u64 != artifact.size_bytes
+            || artifact_digest(bytes) != artifact.digest.trim().to_ascii_lowercase()
+        {
+            return Err(PluginManagerError::ChecksumMismatch);
+        }
+    }
+    Ok(())
+}
+
 fn ensure_host_compatibility(
     min_host: SchemaVersion,
     max_host: SchemaVersion,
@@ -434,12 +670,7 @@ pub fn sign_plugin_package(mut package: PluginPackage, signer_key: &str) -> Plug
 }
 
 fn compute_signature(package: &PluginPackage, signer_key: &str) -> String {
-    let mut hash = 1469598103934665603_u64;
-    for byte in canonical_signature_payload(package, signer_key).as_bytes() {
-        hash ^= u64::from(*byte);
-        hash = hash.wrapping_mul(1099511628211_u64);
-    }
-    format!("{hash:016x}")
+    sha256_hex(canonical_signature_payload(package, signer_key).as_bytes())
 }
 
 fn canonical_signature_payload(package: &PluginPackage, signer_key: &str) -> String {
@@ -669,9 +900,10 @@ mod tests {
     use std::collections::BTreeMap;
 
     use super::{
-        decode_plugin_package, encode_plugin_package, sign_plugin_package, ExtensionPackageManager,
-        PluginArtifact, PluginLifecycleAction, PluginLifecycleState, PluginManagerError,
-        PluginManifest, PluginPackage, PluginSignature, SchemaVersion,
+        artifact_digest, decode_plugin_package, encode_plugin_package, sign_plugin_package,
+        ExtensionPackageManager, PluginArtifact, PluginArtifactContents, PluginLifecycleAction,
+        PluginLifecycleState, PluginManagerError, PluginManifest, PluginPackage, PluginSignature,
+        SchemaVersion,
     };
     use crate::extension_actions::ExtensionPermission;
 
@@ -751,11 +983,11 @@ mod tests {
     #[test]
     fn lifecycle_install_enable_start_stop_uninstall() {
         let mut manager = manager();
-        let package = sign_plugin_package(sample_package(), "trusted-key");
+        let (package, contents) = release("1.0.0", b"wasm-v1");
         let discover = manager.discover_package(package, 10);
         assert_eq!(discover, Ok(()));
 
-        let install = manager.install("plugin-alpha", 11);
+        let install = manager.install("plugin-alpha", &contents, 11);
         assert_eq!(install, Ok(()));
         let plugin = manager.plugin("plugin-alpha");
         match plugin {
@@ -797,16 +1029,40 @@ mod tests {
     #[test]
     fn start_requires_enabled_state() {
         let mut manager = manager();
-        let package = sign_plugin_package(sample_package(), "trusted-key");
+        let (package, contents) = release("1.0.0", b"wasm-v1");
         let discover = manager.discover_package(package, 10);
         assert_eq!(discover, Ok(()));
-        let install = manager.install("plugin-alpha", 11);
+        let install = manager.install("plugin-alpha", &contents, 11);
         assert_eq!(install, Ok(()));
 
         let start = manager.set_running("plugin-alpha", true, 12);
         assert_eq!(start, Err(PluginManagerError::InvalidStateTransition));
     }
 
+    #[test]
+    fn install_of_discovered_plugin_verifies_checksums() {
+        let mut manager = manager();
+        let (package, mut contents) = release("1.0.0", b"wasm-v1");
+        assert_eq!(manager.discover_package(package, 10), Ok(()));
+        contents.insert("plugin.wasm".to_owned(), b"tampered".to_vec());
+
+        let install = manager.install("plugin-alpha", &contents, 11);
+        assert_eq!(install, Err(PluginManagerError::ChecksumMismatch));
+        let Some(plugin) = manager.plugin("plugin-alpha") else {
+            panic!("expected discovered plugin");
+        };
+        assert_eq!(plugin.state, PluginLifecycleState::Discovered);
+        assert!(plugin.last_error.is_some());
+        assert_eq!(
+            manager.lifecycle_events().last().map(|event| &event.action),
+            Some(&PluginLifecycleAction::Rejected)
+        );
+
+        let empty = PluginArtifactContents::new();
+        let install = manager.install("plugin-alpha", &empty, 12);
+        assert_eq!(install, Err(PluginManagerError::MissingArtifact));
+    }
+
     #[test]
     fn duplicate_discovery_rejected() {
         let mut manager = manager();
@@ -816,4 +1072,101 @@ mod tests {
         assert_eq!(first, Ok(()));
         assert_eq!(second, Err(PluginManagerError::AlreadyExists));
     }
+
+    fn release(version: &str, bytes: &[u8]) -> (PluginPackage, PluginArtifactContents) {
+        let mut package = sample_package();
+        package.manifest.version = version.to_owned();
+        package.artifacts[0].digest = artifact_digest(bytes);
+        package.artifacts[0].size_bytes = bytes.len() as u64;
+        let mut contents = PluginArtifactContents::new();
+        contents.insert("plugin.wasm".to_owned(), bytes.to_vec());
+        (sign_plugin_package(package, "trusted-key"), contents)
+    }
+
+    #[test]
+    fn artifact_digest_is_sha256_hex() {
+        assert_eq!(
+            artifact_digest(b"abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        let signed = sign_plugin_package(sample_package(), "trusted-key");
+        assert_eq!(signed.signature.value.len(), 64);
+    }
+
+    #[test]
+    fn install_with_mismatched_checksum_leaves_nothing_installed() {
+        let mut manager = manager();
+        let (package, mut contents) = release("1.0.0", b"wasm-v1");
+        contents.insert("plugin.wasm".to_owned(), b"wasm-vX".to_vec());
+
+        let result = manager.install_package(package, &contents, 10);
+        assert_eq!(result, Err(PluginManagerError::ChecksumMismatch));
+        assert_eq!(manager.plugin("plugin-alpha"), None);
+        assert!(manager.installed_versions("plugin-alpha").is_empty());
+        assert_eq!(
+            manager.lifecycle_events().last().map(|event| &event.action),
+            Some(&PluginLifecycleAction::Rejected)
+        );
+
+        let (package, contents) = release("1.0.0", b"wasm-v1");
+        assert_eq!(manager.install_package(package, &contents, 11), Ok(()));
+        assert_eq!(
+            manager.plugin("plugin-alpha").map(|plugin| plugin.state),
+            Some(PluginLifecycleState::Installed)
+        );
+    }
+
+    #[test]
+    fn failed_update_keeps_current_version_active() {
+        let mut manager = manager();
+        let (v1, v1_contents) = release("1.0.0", b"wasm-v1");
+        assert_eq!(manager.install_package(v1, &v1_contents, 10), Ok(()));
+        assert_eq!(manager.set_enabled("plugin-alpha", true, 11), Ok(()));
+        assert_eq!(manager.set_running("plugin-alpha", true, 12), Ok(()));
+
+        let (v2, mut v2_contents) = release("1.1.0", b"wasm-v2");
+        v2_contents.clear();
+        let result = manager.update(v2, &v2_contents, 13);
+        assert_eq!(result, Err(PluginManagerError::MissingArtifact));
+
+        let Some(plugin) = manager.plugin("plugin-alpha") else {
+            panic!("expected plugin to remain installed");
+        };
+        assert_eq!(plugin.package.manifest.version, "1.0.0");
+        assert_eq!(plugin.state, PluginLifecycleState::Running);
+        assert!(plugin
+            .last_error
+            .as_deref()
+            .is_some_and(|error| error.contains("rolled back")));
+        assert_eq!(manager.installed_versions("plugin-alpha").len(), 1);
+    }
+
+    #[test]
+    fn update_then_rollback_restores_previous_version() {
+        let mut manager = manager();
+        let (v1, v1_contents) = release("1.0.0", b"wasm-v1");
+        assert_eq!(manager.install_package(v1, &v1_contents, 10), Ok(()));
+        assert_eq!(
+            manager.rollback("plugin-alpha", 11),
+            Err(PluginManagerError::NoPreviousVersion)
+        );
+
+        let (v2, v2_contents) = release("1.1.0", b"wasm-v2");
+        assert_eq!(manager.update(v2, &v2_contents, 12), Ok(()));
+        let versions = manager
+            .installed_versions("plugin-alpha")
+            .iter()
+            .map(|entry| entry.version.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(versions, vec!["1.0.0", "1.1.0"]);
+
+        assert_eq!(manager.rollback("plugin-alpha", 13), Ok(()));
+        assert_eq!(
+            manager
+                .plugin("plugin-alpha")
+                .map(|plugin| plugin.package.manifest.version.as_str()),
+            Some("1.0.0")
+        );
+        assert_eq!(manager.installed_versions("plugin-alpha").len(), 1);
+    }
 }
@@ -1,5 +1,12 @@
 //! Sandbox and explicit grant enforcement for extension behaviors.
 
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
 use crate::extension_actions::ExtensionPermission;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -437,6 +444,249 @@ fn sandbox_decision(
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExtensionCallLimits {
+    /// Wall-clock limit for a single extension callback.
+    pub timeout: Duration,
+    /// Calls allowed per extension between `begin_tick` calls.
+    pub max_calls_per_tick: u32,
+    /// Faults after which the extension is disabled until re-enabled.
+    pub max_faults_before_disable: u32,
+    /// Timed-out callbacks of one extension that may still be running;
+    /// further calls are refused until one finishes.
+    pub max_stalled_workers: u32,
+}
+
+impl Default for ExtensionCallLimits {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_millis(250),
+            max_calls_per_tick: 64,
+            max_faults_before_disable: 3,
+            max_stalled_workers: 1,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExtensionFaultReason {
+    Timeout { limit_ms: u128 },
+    BudgetExhausted { max_calls_per_tick: u32 },
+    Stalled { max_stalled_workers: u32 },
+    Crashed,
+    SpawnFailed(String),
+}
+
+impl ExtensionFaultReason {
+    #[must_use]
+    pub fn describe(&self) -> String {
+        match self {
+            Self::Timeout { limit_ms } => format!("timed out after {limit_ms}ms"),
+            Self::BudgetExhausted { max_calls_per_tick } => {
+                format!("exceeded call budget of {max_calls_per_tick} per tick")
+            }
+            Self::Stalled {
+                max_stalled_workers,
+            } => format!("{max_stalled_workers} timed-out callback(s) still running"),
+            Self::Crashed => "callback panicked".to_owned(),
+            Self::SpawnFailed(err) => format!("could not start callback: {err}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExtensionFault {
+    pub extension_id: String,
+    pub reason: ExtensionFaultReason,
+    pub fault_count: u32,
+    pub disabled: bool,
+}
+
+impl ExtensionFault {
+    /// Operator-facing notice naming the extension and the reason.
+    #[must_use]
+    pub fn operator_message(&self) -> String {
+        let mut message = format!(
+            "extension {} faulted: {}",
+            self.extension_id,
+            self.reason.describe()
+        );
+        if self.disabled {
+            message.push_str(&format!("; disabled after {} faults", self.fault_count));
+        }
+        message
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExtensionCallError {
+    Disabled { extension_id: String },
+    Faulted(ExtensionFault),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExtensionHealth {
+    Healthy,
+    Faulted,
+    Disabled,
+}
+
+#[derive(Debug, Clone, Default)]
+struct ExtensionCallState {
+    calls_this_tick: u32,
+    faults: u32,
+    disabled: bool,
+    /// Workers still running, shared with the workers so each one can
+    /// count itself out when its callback returns.
+    running: Arc<AtomicU32>,
+}
+
+/// Runs extension callbacks off the UI thread under a deadline and call
+/// budget. A callback that misses its deadline is abandoned and counted as a
+/// fault; its worker thread cannot be killed, so calls are refused while
+/// `max_stalled_workers` of them are still running.
+#[derive(Debug, Clone, Default)]
+pub struct ExtensionCallGuard {
+    limits: ExtensionCallLimits,
+    states: BTreeMap<String, ExtensionCallState>,
+    faults: Vec<ExtensionFault>,
+}
+
+impl ExtensionCallGuard {
+    #[must_use]
+    pub fn new(limits: ExtensionCallLimits) -> Self {
+        Self {
+            limits,
+            states: BTreeMap::new(),
+            faults: Vec::new(),
+        }
+    }
+
+    /// Reset per-tick call budgets.
+    pub fn begin_tick(&mut self) {
+        for state in self.states.values_mut() {
+            state.calls_this_tick = 0;
+        }
+    }
+
+    pub fn call<T, F>(&mut self, extension_id: &str, callback: F) -> Result<T, ExtensionCallError>
+    where
+        T: Send + 'static,
+        F: FnOnce() -> T + Send + 'static,
+    {
+        let extension_id = normalize_id(extension_id);
+        let state = self.states.entry(extension_id.clone()).or_default();
+        if state.disabled {
+            return Err(ExtensionCallError::Disabled { extension_id });
+        }
+        if state.calls_this_tick >= self.limits.max_calls_per_tick {
+            let reason = ExtensionFaultReason::BudgetExhausted {
+                max_calls_per_tick: self.limits.max_calls_per_tick,
+            };
+            return Err(ExtensionCallError::Faulted(
+                self.record_fault(&extension_id, reason),
+            ));
+        }
+        // Calls block until they finish or time out, so every worker still
+        // running here was abandoned by an earlier timeout.
+        if state.running.load(Ordering::SeqCst) >= self.limits.max_stalled_workers.max(1) {
+            let reason = ExtensionFaultReason::Stalled {
+                max_stalled_workers: self.limits.max_stalled_workers.max(1),
+            };
+            return Err(ExtensionCallError::Faulted(
+                self.record_fault(&extension_id, reason),
+            ));
+        }
+        state.calls_this_tick += 1;
+
+        let (sender, receiver) = mpsc::channel();
+        let running = RunningWorker::start(&state.running);
+        let spawned = thread::Builder::new()
+            .name(format!("ext-{extension_id}"))
+            .spawn(move || {
+                let value = callback();
+                // Count out before replying so a caller that got its value
+                // never sees this worker as stalled.
+                drop(running);
+                let _ = sender.send(value);
+            });
+        if let Err(err) = spawned {
+            let reason = ExtensionFaultReason::SpawnFailed(err.to_string());
+            return Err(ExtensionCallError::Faulted(
+                self.record_fault(&extension_id, reason),
+            ));
+        }
+
+        let reason = match receiver.recv_timeout(self.limits.timeout) {
+            Ok(value) => return Ok(value),
+            Err(RecvTimeoutError::Timeout) => ExtensionFaultReason::Timeout {
+                limit_ms: self.limits.timeout.as_millis(),
+            },
+            Err(RecvTimeoutError::Disconnected) => ExtensionFaultReason::Crashed,
+        };
+        Err(ExtensionCallError::Faulted(
+            self.record_fault(&extension_id, reason),
+        ))
+    }
+
+    #[must_use]
+    pub fn health(&self, extension_id: &str) -> ExtensionHealth {
+        match self.states.get(&normalize_id(extension_id)) {
+            Some(state) if state.disabled => ExtensionHealth::Disabled,
+            Some(state) if state.faults > 0 => ExtensionHealth::Faulted,
+            _ => ExtensionHealth::Healthy,
+        }
+    }
+
+    /// Clear faults and re-enable a disabled extension. Workers it still
+    /// has running keep counting against `max_stalled_workers`.
+    pub fn enable(&mut self, extension_id: &str) {
+        if let Some(state) = self.states.get_mut(&normalize_id(extension_id)) {
+            *state = ExtensionCallState {
+                running: Arc::clone(&state.running),
+                ..ExtensionCallState::default()
+            };
+        }
+    }
+
+    /// Faults recorded since the last call, oldest first.
+    pub fn take_faults(&mut self) -> Vec<ExtensionFault> {
+        std::mem::take(&mut self.faults)
+    }
+
+    fn record_fault(&mut self, extension_id: &str, reason: ExtensionFaultReason) -> ExtensionFault {
+        let state = self.states.entry(extension_id.to_owned()).or_default();
+        state.faults += 1;
+        if state.faults >= self.limits.max_faults_before_disable.max(1) {
+            state.disabled = true;
+        }
+        let fault = ExtensionFault {
+            extension_id: extension_id.to_owned(),
+            reason,
+            fault_count: state.faults,
+            disabled: state.disabled,
+        };
+        self.faults.push(fault.clone());
+        fault
+    }
+}
+
+/// Counts one worker as running until dropped, including on panic.
+struct RunningWorker(Arc<AtomicU32>);
+
+impl RunningWorker {
+    fn start(running: &Arc<AtomicU32>) -> Self {
+        running.fetch_add(1, Ordering::SeqCst);
+        Self(Arc::clone(running))
+    }
+}
+
+impl Drop for RunningWorker {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
 fn render_intent(intent: &SandboxIntent) -> String {
     match intent {
         SandboxIntent::RunPaletteCommand { command } => format!("palette:{command}"),
@@ -503,9 +753,15 @@ fn normalize_scope(value: &str) -> String {
 
 #[cfg(test)]
 mod tests {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::{mpsc, Arc};
+    use std::thread;
+    use std::time::{Duration, Instant};
+
     use super::{
-        evaluate_sandbox_request, SandboxCapability, SandboxGrant, SandboxGrantRegistry,
-        SandboxIntent, SandboxPolicy, SandboxRequest,
+        evaluate_sandbox_request, ExtensionCallError, ExtensionCallGuard, ExtensionCallLimits,
+        ExtensionFaultReason, ExtensionHealth, SandboxCapability, SandboxGrant,
+        SandboxGrantRegistry, SandboxIntent, SandboxPolicy, SandboxRequest,
     };
     use crate::extension_actions::ExtensionPermission;
 
@@ -758,4 +1014,126 @@ mod tests {
         );
         assert!(decision.allowed);
     }
+
+    fn guard(max_calls_per_tick: u32) -> ExtensionCallGuard {
+        ExtensionCallGuard::new(ExtensionCallLimits {
+            timeout: Duration::from_millis(20),
+            max_calls_per_tick,
+            max_faults_before_disable: 2,
+            max_stalled_workers: 1,
+        })
+    }
+
+    #[test]
+    fn slow_extension_call_is_aborted_at_deadline_and_faulted() {
+        let mut guard = guard(8);
+        let started = Instant::now();
+        let result = guard.call("Slow Ext", || {
+            thread::sleep(Duration::from_millis(500));
+            7
+        });
+        assert!(started.elapsed() < Duration::from_millis(400));
+
+        let fault = match result {
+            Err(ExtensionCallError::Faulted(fault)) => fault,
+            other => panic!("expected timeout fault, got {other:?}"),
+        };
+        assert_eq!(fault.extension_id, "slow-ext");
+        assert_eq!(fault.reason, ExtensionFaultReason::Timeout { limit_ms: 20 });
+        assert_eq!(
+            fault.operator_message(),
+            "extension slow-ext faulted: timed out after 20ms"
+        );
+        assert_eq!(guard.health("slow-ext"), ExtensionHealth::Faulted);
+        assert_eq!(guard.take_faults().len(), 1);
+
+        assert_eq!(guard.call("fast-ext", || 7), Ok(7));
+        assert_eq!(guard.health("fast-ext"), ExtensionHealth::Healthy);
+    }
+
+    #[test]
+    fn repeated_faults_disable_extension_until_reenabled() {
+        // Printing a panic backtrace can outlast the usual 20ms deadline.
+        let mut guard = ExtensionCallGuard::new(ExtensionCallLimits {
+            timeout: Duration::from_secs(5),
+            ..guard(8).limits
+        });
+        for _ in 0..2 {
+            let _ = guard.call("crashy", || -> u8 { panic!("extension bug") });
+        }
+        let faults = guard.take_faults();
+        assert_eq!(faults[0].reason, ExtensionFaultReason::Crashed);
+        assert!(faults[1].disabled);
+        assert_eq!(
+            faults[1].operator_message(),
+            "extension crashy faulted: callback panicked; disabled after 2 faults"
+        );
+        assert_eq!(guard.health("crashy"), ExtensionHealth::Disabled);
+        assert_eq!(
+            guard.call("crashy", || 1),
+            Err(ExtensionCallError::Disabled {
+                extension_id: "crashy".to_owned()
+            })
+        );
+
+        guard.enable("crashy");
+        assert_eq!(guard.call("crashy", || 1), Ok(1));
+    }
+
+    #[test]
+    fn stalled_worker_blocks_new_calls_until_it_finishes() {
+        let mut guard = ExtensionCallGuard::new(ExtensionCallLimits {
+            timeout: Duration::from_millis(20),
+            max_calls_per_tick: 8,
+            max_faults_before_disable: 5,
+            max_stalled_workers: 1,
+        });
+        let (release_tx, release_rx) = mpsc::channel::<()>();
+        let result = guard.call("hung", move || {
+            let _ = release_rx.recv();
+            1
+        });
+        assert!(matches!(result, Err(ExtensionCallError::Faulted(_))));
+
+        let started = Arc::new(AtomicBool::new(false));
+        let flag = Arc::clone(&started);
+        match guard.call("hung", move || flag.store(true, Ordering::SeqCst)) {
+            Err(ExtensionCallError::Faulted(fault)) => assert_eq!(
+                fault.reason,
+                ExtensionFaultReason::Stalled {
+                    max_stalled_workers: 1
+                }
+            ),
+            other => panic!("expected stalled fault, got {other:?}"),
+        }
+        assert!(!started.load(Ordering::SeqCst));
+        assert_eq!(guard.call("other", || 2), Ok(2));
+
+        let _ = release_tx.send(());
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while guard.states["hung"].running.load(Ordering::SeqCst) > 0 {
+            assert!(Instant::now() < deadline, "worker never finished");
+            thread::sleep(Duration::from_millis(5));
+        }
+        assert_eq!(guard.call("hung", || 3), Ok(3));
+    }
+
+    #[test]
+    fn call_budget_resets_each_tick() {
+        let mut guard = guard(2);
+        assert_eq!(guard.call("chatty", || 1), Ok(1));
+        assert_eq!(guard.call("chatty", || 2), Ok(2));
+        match guard.call("chatty", || 3) {
+            Err(ExtensionCallError::Faulted(fault)) => assert_eq!(
+                fault.reason,
+                ExtensionFaultReason::BudgetExhausted {
+                    max_calls_per_tick: 2
+                }
+            ),
+            other => panic!("expected budget fault, got {other:?}"),
+        }
+
+        guard.begin_tick();
+        assert_eq!(guard.call("chatty", || 4), Ok(4));
+    }
 }
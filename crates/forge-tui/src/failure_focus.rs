@@ -1,5 +1,7 @@
 //! Failure jump and root-cause focus helpers for TUI logs.
 
+use std::collections::BTreeSet;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum HighlightRole {
     Failure,
@@ -411,12 +413,204 @@ fn is_application_frame_line(line: &str) -> bool {
     !library_markers.iter().any(|marker| lower.contains(marker))
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoopFailureSample {
+    pub loop_id: String,
+    /// Last error message or exit reason reported for the loop.
+    pub error: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FailureCluster {
+    /// Stable hash of `signature`; used as the cluster key.
+    pub fingerprint: String,
+    pub signature: String,
+    pub representative_loop_id: String,
+    pub representative_error: String,
+    pub member_loop_ids: Vec<String>,
+}
+
+impl FailureCluster {
+    #[must_use]
+    pub fn count(&self) -> usize {
+        self.member_loop_ids.len()
+    }
+}
+
+/// Group failing loops by normalized failure signature. Largest clusters first.
+#[must_use]
+pub fn cluster_failures(samples: &[LoopFailureSample]) -> Vec<FailureCluster> {
+    let mut clusters: Vec<FailureCluster> = Vec::new();
+    for sample in samples {
+        let loop_id = sample.loop_id.trim();
+        let error = sample.error.trim();
+        if loop_id.is_empty() || error.is_empty() {
+            continue;
+        }
+        let signature = normalize_failure_signature(error);
+        let fingerprint = signature_fingerprint(&signature);
+        if let Some(cluster) = clusters
+            .iter_mut()
+            .find(|cluster| cluster.fingerprint == fingerprint)
+        {
+            if !cluster.member_loop_ids.iter().any(|id| id == loop_id) {
+                cluster.member_loop_ids.push(loop_id.to_owned());
+            }
+            continue;
+        }
+        clusters.push(FailureCluster {
+            fingerprint,
+            signature,
+            representative_loop_id: loop_id.to_owned(),
+            representative_error: error.to_owned(),
+            member_loop_ids: vec![loop_id.to_owned()],
+        });
+    }
+
+    clusters.sort_by(|a, b| {
+        b.count()
+            .cmp(&a.count())
+            .then_with(|| a.signature.cmp(&b.signature))
+    });
+    clusters
+}
+
+/// Lowercase the message and replace volatile tokens (paths, timestamps,
+/// ids, numbers) with placeholders so related failures compare equal.
+#[must_use]
+pub fn normalize_failure_signature(message: &str) -> String {
+    message
+        .split_whitespace()
+        .map(normalize_signature_token)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn normalize_signature_token(token: &str) -> String {
+    let lower = token.to_ascii_lowercase();
+    let is_edge = |ch: char| matches!(ch, ',' | ';' | ':' | '(' | ')' | '[' | ']' | '"' | '\'');
+    let core = lower.trim_matches(is_edge);
+    if core.is_empty() {
+        return lower;
+    }
+    let start = lower.find(core).unwrap_or(0);
+    let prefix = &lower[..start];
+    let suffix = &lower[start + core.len()..];
+
+    let placeholder = if core.contains('/') || core.contains('\\') {
+        Some("<path>")
+    } else if is_timestamp_token(core) {
+        Some("<ts>")
+    } else if core.chars().all(|ch| ch.is_ascii_digit() || ch == '.') {
+        Some("<n>")
+    } else if core.chars().any(|ch| ch.is_ascii_digit()) {
+        Some("<id>")
+    } else {
+        None
+    };
+
+    match placeholder {
+        Some(placeholder) => format!("{prefix}{placeholder}{suffix}"),
+        None => lower,
+    }
+}
+
+fn is_timestamp_token(token: &str) -> bool {
+    let digits = token.chars().filter(char::is_ascii_digit).count();
+    digits >= 4
+        && (token.contains('-') || token.contains(':'))
+        && token
+            .chars()
+            .all(|ch| ch.is_ascii_digit() || matches!(ch, '-' | ':' | '.' | 't' | 'z' | '+'))
+}
+
+fn signature_fingerprint(signature: &str) -> String {
+    let mut hash = 1469598103934665603_u64;
+    for byte in signature.as_bytes() {
+        hash ^= u64::from(*byte);
+        hash = hash.wrapping_mul(1099511628211_u64);
+    }
+    format!("{hash:016x}")
+}
+
+/// Cluster list with per-cluster expand/collapse of member loops.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FailureClusterView {
+    clusters: Vec<FailureCluster>,
+    expanded: BTreeSet<String>,
+}
+
+impl FailureClusterView {
+    #[must_use]
+    pub fn new(samples: &[LoopFailureSample]) -> Self {
+        Self {
+            clusters: cluster_failures(samples),
+            expanded: BTreeSet::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn clusters(&self) -> &[FailureCluster] {
+        &self.clusters
+    }
+
+    #[must_use]
+    pub fn is_expanded(&self, fingerprint: &str) -> bool {
+        self.expanded.contains(fingerprint)
+    }
+
+    /// Expand or collapse the cluster members; returns the new expanded state.
+    pub fn toggle_expanded(&mut self, fingerprint: &str) -> bool {
+        if !self
+            .clusters
+            .iter()
+            .any(|cluster| cluster.fingerprint == fingerprint)
+        {
+            return false;
+        }
+        if self.expanded.remove(fingerprint) {
+            false
+        } else {
+            self.expanded.insert(fingerprint.to_owned());
+            true
+        }
+    }
+
+    #[must_use]
+    pub fn render_lines(&self) -> Vec<String> {
+        let mut lines = Vec::new();
+        for cluster in &self.clusters {
+            let expanded = self.is_expanded(&cluster.fingerprint);
+            let marker = if cluster.count() < 2 {
+                " "
+            } else if expanded {
+                "-"
+            } else {
+                "+"
+            };
+            lines.push(format!(
+                "{marker} x{} {}  {}",
+                cluster.count(),
+                cluster.representative_loop_id,
+                cluster.representative_error
+            ));
+            if expanded {
+                for member in &cluster.member_loop_ids {
+                    lines.push(format!("    {member}"));
+                }
+            }
+        }
+        lines
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::expect_used)]
 mod tests {
     use super::{
-        build_failure_focus, jump_to_first_failure, jump_to_probable_root_frame,
-        jump_to_root_cause, HighlightRole,
+        build_failure_focus, cluster_failures, jump_to_first_failure, jump_to_probable_root_frame,
+        jump_to_root_cause, normalize_failure_signature, FailureClusterView, HighlightRole,
+        LoopFailureSample,
     };
 
     fn sample_log() -> Vec<String> {
@@ -577,4 +771,83 @@ mod tests {
         assert!(build_failure_focus(&[], None).is_none());
         assert_eq!(jump_to_root_cause(&[], None), None);
     }
+
+    fn failure(loop_id: &str, error: &str) -> LoopFailureSample {
+        LoopFailureSample {
+            loop_id: loop_id.to_owned(),
+            error: error.to_owned(),
+        }
+    }
+
+    #[test]
+    fn signature_strips_ids_timestamps_and_paths() {
+        assert_eq!(
+            normalize_failure_signature(
+                "run-42 failed: open /tmp/forge/run-42/out.log: permission denied at 2026-02-12T10:00:00Z"
+            ),
+            "<id> failed: open <path>: permission denied at <ts>"
+        );
+    }
+
+    #[test]
+    fn same_normalized_error_forms_one_cluster_and_distinct_error_its_own() {
+        let clusters = cluster_failures(&[
+            failure(
+                "loop-a",
+                "harness exited 1: auth token expired (req 7f3a9c)",
+            ),
+            failure(
+                "loop-b",
+                "harness exited 1: auth token expired (req 11be02)",
+            ),
+            failure(
+                "loop-c",
+                "Harness exited 1: auth token expired (req 0042ff)",
+            ),
+            failure(
+                "loop-d",
+                "open /var/lib/forge/x.db: no such file or directory",
+            ),
+        ]);
+
+        assert_eq!(clusters.len(), 2);
+        assert_eq!(clusters[0].count(), 3);
+        assert_eq!(clusters[0].representative_loop_id, "loop-a");
+        assert_eq!(
+            clusters[0].member_loop_ids,
+            vec![
+                "loop-a".to_owned(),
+                "loop-b".to_owned(),
+                "loop-c".to_owned()
+            ]
+        );
+        assert_eq!(clusters[1].count(), 1);
+        assert_eq!(clusters[1].member_loop_ids, vec!["loop-d".to_owned()]);
+        assert_ne!(clusters[0].fingerprint, clusters[1].fingerprint);
+    }
+
+    #[test]
+    fn cluster_view_expands_to_show_members() {
+        let mut view = FailureClusterView::new(&[
+            failure("loop-a", "timed out after 30s"),
+            failure("loop-b", "timed out after 45s"),
+        ]);
+        let fingerprint = view.clusters()[0].fingerprint.clone();
+        assert_eq!(
+            view.render_lines(),
+            vec!["+ x2 loop-a  timed out after 30s"]
+        );
+
+        assert!(view.toggle_expanded(&fingerprint));
+        assert_eq!(
+            view.render_lines(),
+            vec![
+                "- x2 loop-a  timed out after 30s",
+                "    loop-a",
+                "    loop-b"
+            ]
+        );
+        assert!(!view.toggle_expanded(&fingerprint));
+        assert!(!view.toggle_expanded("unknown"));
+    }
 }
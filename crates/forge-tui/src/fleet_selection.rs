@@ -137,6 +137,121 @@ pub fn select_fleet(
         .collect()
 }
 
+/// Explicit multi-loop selection for bulk actions, keyed by loop id so it
+/// survives list reordering.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct FleetSelection {
+    selected: BTreeSet<String>,
+    anchor: Option<String>,
+}
+
+impl FleetSelection {
+    #[must_use]
+    pub fn is_selected(&self, id: &str) -> bool {
+        self.selected.contains(id.trim())
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.selected.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.selected.is_empty()
+    }
+
+    #[must_use]
+    pub fn anchor(&self) -> Option<&str> {
+        self.anchor.as_deref()
+    }
+
+    /// Toggle one loop and make it the anchor for the next range selection.
+    pub fn toggle(&mut self, id: &str) {
+        let id = id.trim();
+        if id.is_empty() {
+            return;
+        }
+        if !self.selected.remove(id) {
+            self.selected.insert(id.to_owned());
+        }
+        self.anchor = Some(id.to_owned());
+    }
+
+    /// Select every loop between the anchor and `cursor_id`, inclusive, in
+    /// list order. Without a usable anchor only the cursor loop is selected.
+    pub fn select_range(&mut self, loops: &[FleetLoopRecord], cursor_id: &str) {
+        let position = |id: &str| loops.iter().position(|entry| entry.id.trim() == id.trim());
+        let Some(cursor) = position(cursor_id) else {
+            return;
+        };
+        let anchor = self.anchor.as_deref().and_then(position).unwrap_or(cursor);
+        let (start, end) = (anchor.min(cursor), anchor.max(cursor));
+        self.selected.extend(
+            loops[start..=end]
+                .iter()
+                .map(|entry| entry.id.trim().to_owned()),
+        );
+        if self.anchor.is_none() {
+            self.anchor = Some(loops[cursor].id.trim().to_owned());
+        }
+    }
+
+    /// Add every loop matching the active filter.
+    pub fn select_all_matching(
+        &mut self,
+        loops: &[FleetLoopRecord],
+        filter: &FleetSelectionFilter,
+    ) {
+        self.selected.extend(
+            loops
+                .iter()
+                .filter(|entry| matches_filter(entry, filter))
+                .map(|entry| entry.id.trim().to_owned()),
+        );
+    }
+
+    /// Select exactly the loops in `loops` that are not selected now.
+    pub fn invert(&mut self, loops: &[FleetLoopRecord]) {
+        self.selected = loops
+            .iter()
+            .map(|entry| entry.id.trim().to_owned())
+            .filter(|id| !id.is_empty() && !self.selected.contains(id))
+            .collect();
+    }
+
+    pub fn clear(&mut self) {
+        self.selected.clear();
+        self.anchor = None;
+    }
+
+    /// Drop ids (and the anchor) that no longer exist in the refreshed list.
+    pub fn retain_existing(&mut self, loops: &[FleetLoopRecord]) {
+        let live = loops
+            .iter()
+            .map(|entry| entry.id.trim())
+            .collect::<BTreeSet<_>>();
+        self.selected.retain(|id| live.contains(id.as_str()));
+        if self
+            .anchor
+            .as_deref()
+            .is_some_and(|anchor| !live.contains(anchor))
+        {
+            self.anchor = None;
+        }
+    }
+
+    /// Selected loops in list order, ready for [`preview_fleet_action`].
+    #[must_use]
+    pub fn targets(&self, loops: &[FleetLoopRecord]) -> Vec<FleetLoopRecord> {
+        loops
+            .iter()
+            .filter(|entry| self.selected.contains(entry.id.trim()))
+            .cloned()
+            .collect()
+    }
+}
+
 #[must_use]
 pub fn preview_fleet_action(
     action: FleetAction,
@@ -233,7 +348,7 @@ fn normalize(value: &str) -> String {
 mod tests {
     use super::{
         matches_filter, preview_fleet_action, select_fleet, FleetAction, FleetLoopRecord,
-        FleetSelectionFilter,
+        FleetSelection, FleetSelectionFilter,
     };
 
     fn sample_loops() -> Vec<FleetLoopRecord> {
@@ -338,4 +453,55 @@ mod tests {
         assert_eq!(preview.summary, "no loops match current selection");
         assert_eq!(preview.command_preview, "forge msg --loop <id>");
     }
+
+    fn ids(records: &[FleetLoopRecord]) -> Vec<&str> {
+        records.iter().map(|entry| entry.id.as_str()).collect()
+    }
+
+    #[test]
+    fn range_selection_covers_inclusive_span_and_survives_refresh() {
+        let mut loops = sample_loops();
+        let mut selection = FleetSelection::default();
+        selection.toggle("loop-cc33");
+        selection.select_range(&loops, "loop-aa11");
+        assert_eq!(
+            ids(&selection.targets(&loops)),
+            vec!["loop-aa11", "loop-bb22", "loop-cc33"]
+        );
+
+        selection.invert(&loops);
+        assert!(selection.is_empty());
+        selection.toggle("loop-bb22");
+        selection.invert(&loops);
+        assert_eq!(
+            ids(&selection.targets(&loops)),
+            vec!["loop-aa11", "loop-cc33"]
+        );
+
+        loops.retain(|entry| entry.id != "loop-cc33");
+        selection.retain_existing(&loops);
+        assert_eq!(selection.len(), 1);
+        assert!(selection.is_selected("loop-aa11"));
+    }
+
+    #[test]
+    fn select_all_matching_respects_active_filter() {
+        let loops = sample_loops();
+        let filter = FleetSelectionFilter {
+            name_contains: "frontend".to_owned(),
+            ..FleetSelectionFilter::default()
+        };
+        let mut selection = FleetSelection::default();
+        selection.select_all_matching(&loops, &filter);
+        assert_eq!(
+            ids(&selection.targets(&loops)),
+            vec!["loop-aa11", "loop-cc33"]
+        );
+
+        let preview = preview_fleet_action(FleetAction::Stop, &selection.targets(&loops), 5);
+        assert_eq!(
+            preview.command_preview,
+            "forge stop --loop loop-aa11 --loop loop-cc33"
+        );
+    }
 }
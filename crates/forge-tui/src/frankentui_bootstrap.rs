@@ -1,5 +1,5 @@
 use std::path::{Path, PathBuf};
-use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 use forge_ftui_adapter::input::{
     translate_input, InputEvent, Key, KeyEvent, Modifiers, MouseButton, MouseEvent, MouseEventKind,
@@ -15,12 +15,20 @@ use ftui::render::drawing::Draw;
 use ftui::runtime::{Every, Subscription};
 use ftui::{App as FtuiApp, Cmd, Frame, Model, ScreenMode};
 
+use crate::performance_gates::{
+    doctor_report_path, gate_elapsed_ms, gate_results_to_doctor_checks, restore_runtime_budgets,
+    user_runtime_budgets_path, RuntimeBudgets, RuntimeGate, RuntimeGateMonitor,
+};
+
 const REFRESH_INTERVAL_MS: u64 = 900;
 const INLINE_UI_HEIGHT: u16 = 10;
 const INLINE_AUTO_MIN_HEIGHT: u16 = 6;
 const INLINE_AUTO_MAX_HEIGHT: u16 = 20;
+/// Rewrite the `forge doctor` gate report after this many refreshes (about a
+/// minute at the refresh interval), and once more on quit.
+const GATE_REPORT_EVERY_REFRESHES: usize = 60;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum RuntimeEvent {
     Input(InputEvent),
     Tick,
@@ -45,6 +53,8 @@ pub struct BootstrapSnapshot {
     pub loop_count: usize,
     pub refreshed_at_epoch_secs: u64,
     pub error: Option<String>,
+    /// How long loading the snapshot took; recorded as poll latency.
+    pub load_ms: u64,
 }
 
 impl BootstrapSnapshot {
@@ -53,6 +63,7 @@ impl BootstrapSnapshot {
             loop_count,
             refreshed_at_epoch_secs: unix_timestamp_secs(),
             error: None,
+            load_ms: 0,
         }
     }
 
@@ -61,6 +72,7 @@ impl BootstrapSnapshot {
             loop_count: 0,
             refreshed_at_epoch_secs: unix_timestamp_secs(),
             error: Some(message),
+            load_ms: 0,
         }
     }
 }
@@ -73,6 +85,8 @@ pub struct ForgeShell {
     last_event: RuntimeEvent,
     last_error: Option<String>,
     last_refreshed_at_epoch_secs: u64,
+    runtime_gates: RuntimeGateMonitor,
+    gate_report: Option<PathBuf>,
 }
 
 impl ForgeShell {
@@ -86,14 +100,39 @@ impl ForgeShell {
             last_event: RuntimeEvent::Ignore,
             last_error: None,
             last_refreshed_at_epoch_secs: 0,
+            runtime_gates: RuntimeGateMonitor::default(),
+            gate_report: None,
         }
     }
 
+    /// Leave runtime gate results at `path` for `forge doctor`.
+    #[must_use]
+    pub fn with_gate_report(mut self, path: Option<PathBuf>, budgets: RuntimeBudgets) -> Self {
+        self.gate_report = path;
+        self.runtime_gates.set_budgets(budgets);
+        self
+    }
+
     fn apply_snapshot(&mut self, snapshot: BootstrapSnapshot) {
         self.loop_count = snapshot.loop_count;
         self.last_refreshed_at_epoch_secs = snapshot.refreshed_at_epoch_secs;
         self.last_error = snapshot.error;
         self.refresh_count = self.refresh_count.saturating_add(1);
+        self.runtime_gates
+            .record(RuntimeGate::PollLatency, snapshot.load_ms);
+        if self.refresh_count % GATE_REPORT_EVERY_REFRESHES == 0 {
+            self.write_gate_report();
+        }
+    }
+
+    fn write_gate_report(&mut self) {
+        let Some(path) = self.gate_report.as_deref() else {
+            return;
+        };
+        let checks = gate_results_to_doctor_checks(&self.runtime_gates.evaluate());
+        if let Err(err) = forge_cli::doctor::write_check_report(path, &checks) {
+            self.last_error = Some(format!("runtime gate report: {err}"));
+        }
     }
 
     fn perform_refresh(&self, task_name: &'static str) -> Cmd<ForgeShellMsg> {
@@ -111,19 +150,23 @@ impl Model for ForgeShell {
     fn update(&mut self, msg: Self::Message) -> Cmd<Self::Message> {
         match msg {
             ForgeShellMsg::Runtime(runtime_event) => {
-                self.last_event = runtime_event;
+                self.last_event = runtime_event.clone();
                 match runtime_event {
                     RuntimeEvent::Input(input) => {
                         let action = translate_input(&input);
+                        let refresh = action == UiAction::Refresh;
                         self.last_action = action;
-                        if action == UiAction::Refresh {
+                        if refresh {
                             self.perform_refresh("forge-shell-input-refresh")
                         } else {
                             Cmd::none()
                         }
                     }
                     RuntimeEvent::Tick => self.perform_refresh("forge-shell-tick-refresh"),
-                    RuntimeEvent::Quit => Cmd::quit(),
+                    RuntimeEvent::Quit => {
+                        self.write_gate_report();
+                        Cmd::quit()
+                    }
                     RuntimeEvent::Ignore => Cmd::none(),
                 }
             }
@@ -166,7 +209,8 @@ impl Model for ForgeShell {
 }
 
 pub fn run(db_path: PathBuf) -> Result<(), String> {
-    FtuiApp::new(ForgeShell::new(db_path))
+    let shell = ForgeShell::new(db_path).with_gate_report(doctor_report_path(), load_budgets());
+    FtuiApp::new(shell)
         .screen_mode(resolve_screen_mode_from_env())
         .run()
         .map_err(|err| format!("run frankentui bootstrap runtime: {err}"))
@@ -309,10 +353,22 @@ fn perform_refresh(task_name: &'static str, db_path: PathBuf) -> Cmd<ForgeShellM
 }
 
 fn load_snapshot(db_path: &Path) -> BootstrapSnapshot {
-    match count_loops(db_path) {
+    let started = Instant::now();
+    let mut snapshot = match count_loops(db_path) {
         Ok(loop_count) => BootstrapSnapshot::ok(loop_count),
         Err(err) => BootstrapSnapshot::err(err),
-    }
+    };
+    snapshot.load_ms = gate_elapsed_ms(started.elapsed());
+    snapshot
+}
+
+/// Budgets from [`user_runtime_budgets_path`]; an unreadable file keeps the
+/// defaults, and invalid entries fall back per key.
+fn load_budgets() -> RuntimeBudgets {
+    user_runtime_budgets_path()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .map(|raw| restore_runtime_budgets(&raw).budgets)
+        .unwrap_or_default()
 }
 
 fn count_loops(db_path: &Path) -> Result<usize, String> {
@@ -355,6 +411,7 @@ mod tests {
         resolve_screen_mode_from_env, translate_runtime_event, ForgeShell, ForgeShellMsg,
         RuntimeEvent,
     };
+    use crate::performance_gates::{RuntimeBudgets, RuntimeGate};
     use forge_ftui_adapter::input::{
         InputEvent, Key, KeyEvent, Modifiers, MouseEvent, MouseEventKind, MouseWheelDirection,
         ResizeEvent,
@@ -425,6 +482,7 @@ mod tests {
             loop_count: 7,
             refreshed_at_epoch_secs: 123,
             error: Some("boom".to_owned()),
+            load_ms: 4,
         });
         let cmd = shell.update(completion);
 
@@ -435,6 +493,43 @@ mod tests {
         assert_eq!(shell.last_error.as_deref(), Some("boom"));
     }
 
+    #[test]
+    fn shell_quit_writes_gate_report_for_doctor() {
+        let report = std::env::temp_dir().join(format!(
+            "forge-shell-gate-report-{}.json",
+            std::process::id()
+        ));
+        let mut shell = ForgeShell::new(std::env::temp_dir().join("forge-shell-bootstrap.sqlite"))
+            .with_gate_report(Some(report.clone()), RuntimeBudgets::default());
+        shell.update(ForgeShellMsg::SnapshotLoaded(super::BootstrapSnapshot {
+            loop_count: 1,
+            refreshed_at_epoch_secs: 123,
+            error: None,
+            load_ms: 12,
+        }));
+        assert!(
+            !report.exists(),
+            "report waits for quit or the refresh cadence"
+        );
+
+        let _ = shell.update(ForgeShellMsg::Runtime(RuntimeEvent::Quit));
+        let checks = forge_cli::doctor::read_check_report(&report)
+            .unwrap_or_else(|err| panic!("read gate report: {err}"));
+        let _ = std::fs::remove_file(&report);
+
+        let Some(poll) = checks
+            .iter()
+            .find(|check| check.name == RuntimeGate::PollLatency.slug())
+        else {
+            panic!("poll latency check missing: {checks:?}");
+        };
+        assert_eq!(poll.category, "tui-performance");
+        assert_eq!(
+            poll.details.as_deref(),
+            Some("p95 12ms within 250ms budget")
+        );
+    }
+
     #[test]
     fn from_event_uses_translator() {
         let msg = ForgeShellMsg::from(Event::Resize {
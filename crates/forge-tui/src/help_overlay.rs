@@ -1,6 +1,9 @@
 use forge_ftui_adapter::render::{FrameSize, RenderFrame, TextRole};
 use forge_ftui_adapter::style::ThemeSpec;
 
+use crate::app::MainTab;
+use crate::keymap::{KeyChord, KeyCommand, KeyScope, KeyToken, Keymap, ModeScope};
+
 /// Render the loop TUI help overlay.
 ///
 /// Parity target: `model.renderHelpDialog` in `internal/looptui/looptui.go`.
@@ -34,7 +37,7 @@ pub fn help_lines() -> Vec<&'static str> {
         "Global:",
         "  q quit | ? toggle help | ]/[ tab cycle | 1..4 jump tabs | t/T themes | z zen",
         "  j/k or arrows move loop | / filter | l expanded logs | n new loop wizard",
-        "  S/K/D stop/kill/delete | r resume | space pin/unpin | c clear pins",
+        "  S/K/D stop/kill/delete | r resume | u undo | space pin/unpin | c clear pins",
         "  ctrl+f universal search | ctrl+p command palette",
         "",
         "Search (ctrl+f):",
@@ -45,7 +48,7 @@ pub fn help_lines() -> Vec<&'static str> {
         "  v source cycle (live/latest-run/selected-run)",
         "  x semantic layer cycle (raw/events/errors/tools/diff)",
         "  ,/. previous/next run",
-        "  pgup/pgdn/home/end/u/d scroll log output",
+        "  pgup/pgdn/home/end/ctrl+u/d scroll log output",
         "",
         "Multi Logs:",
         "  m cycle layouts (1x1 -> 4x4)",
@@ -55,6 +58,134 @@ pub fn help_lines() -> Vec<&'static str> {
     ]
 }
 
+/// One command's bindings within a help category.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HelpEntry {
+    pub category: String,
+    pub keys: String,
+    pub command: KeyCommand,
+    pub description: &'static str,
+}
+
+impl HelpEntry {
+    fn matches(&self, query: &str) -> bool {
+        let query = query.trim().to_ascii_lowercase();
+        query.is_empty()
+            || [
+                self.category.as_str(),
+                self.keys.as_str(),
+                self.description,
+                &self.command.slug(),
+            ]
+            .iter()
+            .any(|field| field.to_ascii_lowercase().contains(&query))
+    }
+}
+
+/// Help entries derived from the live keymap, so rebinds show up as soon as
+/// they are loaded. Grouped by scope: global, main, views, then other modes.
+#[must_use]
+pub fn keymap_help_entries(keymap: &Keymap) -> Vec<HelpEntry> {
+    let mut scopes = vec![KeyScope::Global, KeyScope::Mode(ModeScope::Main)];
+    scopes.extend(MainTab::ORDER.into_iter().map(KeyScope::View));
+    scopes.extend(
+        ModeScope::ALL
+            .into_iter()
+            .filter(|mode| *mode != ModeScope::Main)
+            .map(KeyScope::Mode),
+    );
+
+    let mut entries: Vec<HelpEntry> = Vec::new();
+    for scope in scopes {
+        let category = help_category(scope);
+        let start = entries.len();
+        for binding in keymap.bindings().iter().filter(|b| b.scope == scope) {
+            let key = help_key_label(binding.chord);
+            match entries[start..]
+                .iter_mut()
+                .find(|entry| entry.command == binding.command)
+            {
+                Some(entry) => {
+                    entry.keys.push('/');
+                    entry.keys.push_str(&key);
+                }
+                None => entries.push(HelpEntry {
+                    category: category.clone(),
+                    keys: key,
+                    command: binding.command,
+                    description: binding.description,
+                }),
+            }
+        }
+    }
+    entries
+}
+
+/// Render keymap help grouped by category, keeping only entries that match
+/// `query` (case-insensitive against keys, description, command, category).
+#[must_use]
+pub fn keymap_help_lines(keymap: &Keymap, query: &str) -> Vec<String> {
+    let entries = keymap_help_entries(keymap)
+        .into_iter()
+        .filter(|entry| entry.matches(query))
+        .collect::<Vec<_>>();
+    if entries.is_empty() {
+        return vec![format!("  no bindings match {:?}", query.trim())];
+    }
+
+    let mut lines = Vec::new();
+    let mut current: Option<&str> = None;
+    for entry in &entries {
+        if current != Some(entry.category.as_str()) {
+            if current.is_some() {
+                lines.push(String::new());
+            }
+            lines.push(format!("{}:", entry.category));
+            current = Some(entry.category.as_str());
+        }
+        lines.push(format!("  {:<10} {}", entry.keys, entry.description));
+    }
+    lines
+}
+
+fn help_category(scope: KeyScope) -> String {
+    match scope {
+        KeyScope::Global => "Global".to_owned(),
+        KeyScope::View(tab) => format!("{} view", tab.label()),
+        KeyScope::Mode(mode) => match mode {
+            ModeScope::Main => "Main",
+            ModeScope::Filter => "Filter",
+            ModeScope::ExpandedLogs => "Expanded Logs",
+            ModeScope::Confirm => "Confirm",
+            ModeScope::Wizard => "Wizard",
+            ModeScope::Help => "Help",
+            ModeScope::Palette => "Command Palette",
+            ModeScope::Search => "Search",
+        }
+        .to_owned(),
+    }
+}
+
+fn help_key_label(chord: KeyChord) -> String {
+    let mut label = String::new();
+    if chord.ctrl {
+        label.push_str("Ctrl+");
+    }
+    if chord.alt {
+        label.push_str("Alt+");
+    }
+    if chord.shift {
+        label.push_str("Shift+");
+    }
+    match chord.token {
+        KeyToken::Char(' ') => label.push_str("Space"),
+        KeyToken::Char(ch) if chord.ctrl => label.push(ch.to_ascii_uppercase()),
+        KeyToken::Char(ch) => label.push(ch),
+        _ => label.push_str(chord.display().rsplit('+').next().unwrap_or_default()),
+    }
+    label
+}
+
 fn truncate(input: &str, max_chars: usize) -> String {
     if max_chars == 0 {
         return String::new();
@@ -73,7 +204,8 @@ fn truncate(input: &str, max_chars: usize) -> String {
 
 #[cfg(test)]
 mod tests {
-    use super::{help_lines, render_help_overlay};
+    use super::{help_lines, keymap_help_entries, keymap_help_lines, render_help_overlay};
+    use crate::keymap::{KeyCommand, Keymap};
     use forge_ftui_adapter::snapshot::assert_render_frame_snapshot;
     use forge_ftui_adapter::style::ThemeSpec;
 
@@ -92,7 +224,33 @@ mod tests {
         assert_render_frame_snapshot(
             "forge_tui_help_overlay",
             &frame,
-            "Forge TUI Help                                                  \n                                                                \nGlobal:                                                         \n  q quit | ? toggle help | ]/[ tab cycle | 1..4 jump tabs | t/T…\n  j/k or arrows move loop | / filter | l expanded logs | n new …\n  S/K/D stop/kill/delete | r resume | space pin/unpin | c clear…\n  ctrl+f universal search | ctrl+p command palette              \n                                                                \nSearch (ctrl+f):                                                \n  type to search across loops, runs, logs | tab/arrows cycle re…",
+            "Forge TUI Help                                                  \n                                                                \nGlobal:                                                         \n  q quit | ? toggle help | ]/[ tab cycle | 1..4 jump tabs | t/T…\n  j/k or arrows move loop | / filter | l expanded logs | n new …\n  S/K/D stop/kill/delete | r resume | u undo | space pin/unpin …\n  ctrl+f universal search | ctrl+p command palette              \n                                                                \nSearch (ctrl+f):                                                \n  type to search across loops, runs, logs | tab/arrows cycle re…",
+        );
+    }
+
+    #[test]
+    fn generated_help_reflects_rebinds_and_filters() {
+        let mut keymap = Keymap::default_forge_tui();
+        let full = keymap_help_lines(&keymap, "");
+        assert!(full.contains(&"  /          open filter".to_owned()));
+        assert!(full.contains(&"  j/Down     selection next".to_owned()));
+
+        let report = keymap.load_user_config("main ctrl+o = open-filter\n");
+        assert_eq!(report.applied.len(), 1);
+        let Some(entry) = keymap_help_entries(&keymap)
+            .into_iter()
+            .find(|entry| entry.category == "Main" && entry.command == KeyCommand::OpenFilter)
+        else {
+            panic!("expected open-filter help entry");
+        };
+        assert_eq!(entry.keys, "Ctrl+O");
+
+        let filtered = keymap_help_lines(&keymap, "filter");
+        assert_eq!(filtered, vec!["Main:", "  Ctrl+O     open filter"]);
+        assert!(filtered.len() < full.len());
+        assert_eq!(
+            keymap_help_lines(&keymap, "zzz"),
+            vec!["  no bindings match \"zzz\""]
         );
     }
 }
@@ -4,10 +4,13 @@
 //! - scoped key resolution (mode + view + global)
 //! - collision detection
 //! - conflict diagnostics rendering
+//! - user rebinding from config, layered over the defaults
 
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
 
-use forge_ftui_adapter::input::{Key, KeyEvent};
+use forge_ftui_adapter::input::{Key, KeyEvent, Modifiers};
 
 use crate::app::MainTab;
 
@@ -23,6 +26,33 @@ pub enum ModeScope {
     Search,
 }
 
+impl ModeScope {
+    pub const ALL: [ModeScope; 8] = [
+        ModeScope::Main,
+        ModeScope::Filter,
+        ModeScope::ExpandedLogs,
+        ModeScope::Confirm,
+        ModeScope::Wizard,
+        ModeScope::Help,
+        ModeScope::Palette,
+        ModeScope::Search,
+    ];
+
+    #[must_use]
+    pub fn slug(self) -> &'static str {
+        match self {
+            Self::Main => "main",
+            Self::Filter => "filter",
+            Self::ExpandedLogs => "expanded-logs",
+            Self::Confirm => "confirm",
+            Self::Wizard => "wizard",
+            Self::Help => "help",
+            Self::Palette => "palette",
+            Self::Search => "search",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum KeyScope {
     Global,
@@ -30,6 +60,38 @@ pub enum KeyScope {
     View(MainTab),
 }
 
+impl KeyScope {
+    /// Parse a config scope: `global`, a mode slug such as `main`, or
+    /// `view:<tab>` such as `view:logs`.
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        let value = value.trim().to_ascii_lowercase();
+        if value == "global" {
+            return Some(Self::Global);
+        }
+        if let Some(view) = value.strip_prefix("view:") {
+            return MainTab::ORDER
+                .into_iter()
+                .find(|tab| view_slug(*tab) == view)
+                .map(Self::View);
+        }
+        ModeScope::ALL
+            .into_iter()
+            .find(|mode| mode.slug() == value)
+            .map(Self::Mode)
+    }
+}
+
+fn view_slug(tab: MainTab) -> &'static str {
+    match tab {
+        MainTab::Overview => "overview",
+        MainTab::Logs => "logs",
+        MainTab::Runs => "runs",
+        MainTab::MultiLogs => "multi-logs",
+        MainTab::Inbox => "inbox",
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum KeyToken {
     Char(char),
@@ -82,6 +144,54 @@ impl KeyChord {
         }
     }
 
+    /// Parse a config chord such as `q`, `E`, `ctrl+p`, `shift+tab`, or `space`.
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        let value = value.trim();
+        // The key is whatever follows the last `+` separator, so `+` and
+        // `ctrl++` name the plus key itself.
+        let (modifiers, key) = if value == "+" {
+            ("", "+")
+        } else if let Some(modifiers) = value.strip_suffix("++") {
+            (modifiers, "+")
+        } else {
+            value.rsplit_once('+').unwrap_or(("", value))
+        };
+        let key = key.trim();
+        let mut chord = Self::plain(KeyToken::Enter);
+        let modifiers = if modifiers.is_empty() {
+            Vec::new()
+        } else {
+            modifiers.split('+').collect()
+        };
+        for modifier in modifiers {
+            match modifier.trim().to_ascii_lowercase().as_str() {
+                "ctrl" => chord.ctrl = true,
+                "alt" => chord.alt = true,
+                "shift" => chord.shift = true,
+                _ => return None,
+            }
+        }
+        let mut chars = key.chars();
+        chord.token = match (chars.next(), chars.next()) {
+            (Some(ch), None) if chord.ctrl => KeyToken::Char(ch.to_ascii_lowercase()),
+            (Some(ch), None) => KeyToken::Char(ch),
+            _ => match key.to_ascii_lowercase().as_str() {
+                "enter" => KeyToken::Enter,
+                "esc" | "escape" => KeyToken::Escape,
+                "tab" => KeyToken::Tab,
+                "backspace" => KeyToken::Backspace,
+                "up" => KeyToken::Up,
+                "down" => KeyToken::Down,
+                "left" => KeyToken::Left,
+                "right" => KeyToken::Right,
+                "space" => KeyToken::Char(' '),
+                _ => return None,
+            },
+        };
+        Some(chord)
+    }
+
     #[must_use]
     pub fn from_event(event: KeyEvent) -> Self {
         Self {
@@ -102,6 +212,29 @@ impl KeyChord {
         }
     }
 
+    /// The key event this chord matches.
+    #[must_use]
+    pub fn to_event(self) -> KeyEvent {
+        KeyEvent {
+            key: match self.token {
+                KeyToken::Char(ch) => Key::Char(ch),
+                KeyToken::Enter => Key::Enter,
+                KeyToken::Escape => Key::Escape,
+                KeyToken::Tab => Key::Tab,
+                KeyToken::Backspace => Key::Backspace,
+                KeyToken::Up => Key::Up,
+                KeyToken::Down => Key::Down,
+                KeyToken::Left => Key::Left,
+                KeyToken::Right => Key::Right,
+            },
+            modifiers: Modifiers {
+                shift: self.shift,
+                ctrl: self.ctrl,
+                alt: self.alt,
+            },
+        }
+    }
+
     #[must_use]
     pub fn display(self) -> String {
         let mut parts = Vec::new();
@@ -146,6 +279,7 @@ pub enum KeyCommand {
     MoveSelectionPrev,
     OpenFilter,
     ExportCurrentView,
+    ExportDailySummary,
     CycleTheme,
     CycleAccessibilityTheme,
     ToggleZen,
@@ -154,13 +288,18 @@ pub enum KeyCommand {
     ConfirmStop,
     ConfirmKill,
     ConfirmDelete,
+    ConfirmTakeover,
     LogsCycleSource,
     CycleLogLayer,
+    CycleLogLevel,
+    ToggleLogFold,
     ScrollLogsUp,
     ScrollLogsDown,
     OpenExpandedLogs,
     RunSelectionPrev,
     RunSelectionNext,
+    ToggleRunCompare,
+    ToggleRunLogCompare,
     MultiCycleLayout,
     MultiPageStart,
     MultiPageEnd,
@@ -168,6 +307,9 @@ pub enum KeyCommand {
     MultiPageNext,
     TogglePin,
     ClearPinned,
+    UndoLastAction,
+    CycleSplitLayout,
+    SwitchSplitFocus,
     PaletteClose,
     PaletteMoveNext,
     PaletteMovePrev,
@@ -188,6 +330,95 @@ pub enum KeyCommand {
     JumpEvidenceBack,
 }
 
+impl KeyCommand {
+    pub const ALL: [KeyCommand; 63] = [
+        KeyCommand::Quit,
+        KeyCommand::ToggleHelp,
+        KeyCommand::OpenPalette,
+        KeyCommand::SwitchTabOverview,
+        KeyCommand::SwitchTabLogs,
+        KeyCommand::SwitchTabRuns,
+        KeyCommand::SwitchTabMultiLogs,
+        KeyCommand::SwitchTabInbox,
+        KeyCommand::CycleTabNext,
+        KeyCommand::CycleTabPrev,
+        KeyCommand::MoveSelectionNext,
+        KeyCommand::MoveSelectionPrev,
+        KeyCommand::OpenFilter,
+        KeyCommand::ExportCurrentView,
+        KeyCommand::ExportDailySummary,
+        KeyCommand::CycleTheme,
+        KeyCommand::CycleAccessibilityTheme,
+        KeyCommand::ToggleZen,
+        KeyCommand::OpenWizard,
+        KeyCommand::ResumeSelected,
+        KeyCommand::ConfirmStop,
+        KeyCommand::ConfirmKill,
+        KeyCommand::ConfirmDelete,
+        KeyCommand::ConfirmTakeover,
+        KeyCommand::LogsCycleSource,
+        KeyCommand::CycleLogLayer,
+        KeyCommand::CycleLogLevel,
+        KeyCommand::ToggleLogFold,
+        KeyCommand::ScrollLogsUp,
+        KeyCommand::ScrollLogsDown,
+        KeyCommand::OpenExpandedLogs,
+        KeyCommand::RunSelectionPrev,
+        KeyCommand::RunSelectionNext,
+        KeyCommand::ToggleRunCompare,
+        KeyCommand::ToggleRunLogCompare,
+        KeyCommand::MultiCycleLayout,
+        KeyCommand::MultiPageStart,
+        KeyCommand::MultiPageEnd,
+        KeyCommand::MultiPagePrev,
+        KeyCommand::MultiPageNext,
+        KeyCommand::TogglePin,
+        KeyCommand::ClearPinned,
+        KeyCommand::UndoLastAction,
+        KeyCommand::CycleSplitLayout,
+        KeyCommand::SwitchSplitFocus,
+        KeyCommand::PaletteClose,
+        KeyCommand::PaletteMoveNext,
+        KeyCommand::PaletteMovePrev,
+        KeyCommand::PaletteQueryBackspace,
+        KeyCommand::PaletteExecute,
+        KeyCommand::OpenSearch,
+        KeyCommand::SearchClose,
+        KeyCommand::SearchMoveNext,
+        KeyCommand::SearchMovePrev,
+        KeyCommand::SearchQueryBackspace,
+        KeyCommand::SearchExecute,
+        KeyCommand::SearchNextMatch,
+        KeyCommand::SearchPrevMatch,
+        KeyCommand::ToggleFollow,
+        KeyCommand::JumpEvidenceError,
+        KeyCommand::JumpEvidenceWarning,
+        KeyCommand::JumpEvidenceAck,
+        KeyCommand::JumpEvidenceBack,
+    ];
+
+    /// Config name, e.g. `cycle-log-layer` for `CycleLogLayer`.
+    #[must_use]
+    pub fn slug(self) -> String {
+        let mut slug = String::new();
+        for ch in format!("{self:?}").chars() {
+            if ch.is_ascii_uppercase() && !slug.is_empty() {
+                slug.push('-');
+            }
+            slug.push(ch.to_ascii_lowercase());
+        }
+        slug
+    }
+
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        let value = value.trim().to_ascii_lowercase();
+        Self::ALL
+            .into_iter()
+            .find(|command| command.slug() == value)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct KeyBinding {
     pub scope: KeyScope,
@@ -203,6 +434,99 @@ pub struct KeyConflict {
     pub commands: Vec<KeyCommand>,
 }
 
+/// One `<scope> <chord> = <command>` line from a user keymap config.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyRebind {
+    pub line: usize,
+    pub scope: KeyScope,
+    pub chord: KeyChord,
+    pub command: KeyCommand,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct KeymapLoadReport {
+    pub applied: Vec<KeyRebind>,
+    pub conflicts: Vec<KeyConflict>,
+    pub errors: Vec<String>,
+}
+
+/// Env var overriding the user keymap config path.
+pub const KEYMAP_CONFIG_ENV: &str = "FORGE_TUI_KEYMAP";
+
+/// User keymap config path: `$FORGE_TUI_KEYMAP`, else
+/// `~/.config/forge/tui-keymap.conf`.
+#[must_use]
+pub fn user_keymap_config_path() -> Option<PathBuf> {
+    if let Some(path) = std::env::var_os(KEYMAP_CONFIG_ENV).filter(|path| !path.is_empty()) {
+        return Some(PathBuf::from(path));
+    }
+    std::env::var_os("HOME")
+        .filter(|home| !home.is_empty())
+        .map(|home| {
+            PathBuf::from(home)
+                .join(".config")
+                .join("forge")
+                .join("tui-keymap.conf")
+        })
+}
+
+/// Read a keymap config file; `Ok(None)` when it does not exist.
+pub fn read_keymap_config(path: &Path) -> Result<Option<String>, String> {
+    match std::fs::read_to_string(path) {
+        Ok(config) => Ok(Some(config)),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(format!("read keymap {}: {err}", path.display())),
+    }
+}
+
+/// Parse a user keymap config. Blank lines and `#` comments are ignored.
+/// The command is taken after the last `=`, so `=` itself can be bound.
+///
+/// ```text
+/// main ctrl+q = quit
+/// view:logs f = toggle-follow
+/// main = = open-filter
+/// ```
+pub fn parse_keymap_config(config: &str) -> (Vec<KeyRebind>, Vec<String>) {
+    let mut rebinds = Vec::new();
+    let mut errors = Vec::new();
+    for (index, raw) in config.lines().enumerate() {
+        let line = index + 1;
+        let text = raw.trim();
+        if text.is_empty() || text.starts_with('#') {
+            continue;
+        }
+        let Some((lhs, rhs)) = text.rsplit_once('=') else {
+            errors.push(format!("line {line}: expected <scope> <key> = <command>"));
+            continue;
+        };
+        let lhs = lhs.split_whitespace().collect::<Vec<_>>();
+        let [scope, chord] = lhs.as_slice() else {
+            errors.push(format!("line {line}: expected <scope> <key> = <command>"));
+            continue;
+        };
+        let Some(scope) = KeyScope::parse(scope) else {
+            errors.push(format!("line {line}: unknown scope {scope:?}"));
+            continue;
+        };
+        let Some(chord) = KeyChord::parse(chord) else {
+            errors.push(format!("line {line}: unknown key {chord:?}"));
+            continue;
+        };
+        let Some(command) = KeyCommand::parse(rhs) else {
+            errors.push(format!("line {line}: unknown command {:?}", rhs.trim()));
+            continue;
+        };
+        rebinds.push(KeyRebind {
+            line,
+            scope,
+            chord,
+            command,
+        });
+    }
+    (rebinds, errors)
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct Keymap {
     bindings: Vec<KeyBinding>,
@@ -317,6 +641,12 @@ impl Keymap {
                 Cmd::ExportCurrentView,
                 "export current view",
             ),
+            bind(
+                Scope::Mode(ModeScope::Main),
+                KeyChord::plain(Tok::Char('W')),
+                Cmd::ExportDailySummary,
+                "write daily summary",
+            ),
             bind(
                 Scope::Mode(ModeScope::Main),
                 KeyChord::plain(Tok::Char('t')),
@@ -365,6 +695,12 @@ impl Keymap {
                 Cmd::ConfirmDelete,
                 "delete selected",
             ),
+            bind(
+                Scope::Mode(ModeScope::Main),
+                KeyChord::plain(Tok::Char('X')),
+                Cmd::ConfirmTakeover,
+                "take over stale loop",
+            ),
             bind(
                 Scope::Mode(ModeScope::Main),
                 KeyChord::plain(Tok::Char('x')),
@@ -389,6 +725,18 @@ impl Keymap {
                 Cmd::RunSelectionNext,
                 "run next",
             ),
+            bind(
+                Scope::View(MainTab::Runs),
+                KeyChord::plain(Tok::Char('V')),
+                Cmd::ToggleRunCompare,
+                "mark run for compare",
+            ),
+            bind(
+                Scope::View(MainTab::Runs),
+                KeyChord::plain(Tok::Char('L')),
+                Cmd::ToggleRunLogCompare,
+                "log compare marked runs",
+            ),
             bind(
                 Scope::Mode(ModeScope::Main),
                 KeyChord::plain(Tok::Char(' ')),
@@ -401,6 +749,24 @@ impl Keymap {
                 Cmd::ClearPinned,
                 "clear pins",
             ),
+            bind(
+                Scope::Mode(ModeScope::Main),
+                KeyChord::plain(Tok::Char('u')),
+                Cmd::UndoLastAction,
+                "undo last action",
+            ),
+            bind(
+                Scope::Mode(ModeScope::Main),
+                KeyChord::plain(Tok::Char('\\')),
+                Cmd::CycleSplitLayout,
+                "cycle split layout",
+            ),
+            bind(
+                Scope::Mode(ModeScope::Main),
+                KeyChord::plain(Tok::Char('`')),
+                Cmd::SwitchSplitFocus,
+                "switch split pane focus",
+            ),
             bind(
                 Scope::View(MainTab::Logs),
                 KeyChord::plain(Tok::Char('v')),
@@ -409,25 +775,37 @@ impl Keymap {
             ),
             bind(
                 Scope::View(MainTab::Logs),
-                KeyChord::plain(Tok::Char('u')),
+                KeyChord::plain(Tok::Char('L')),
+                Cmd::CycleLogLevel,
+                "cycle min log level",
+            ),
+            bind(
+                Scope::View(MainTab::Logs),
+                KeyChord::plain(Tok::Char('H')),
+                Cmd::ToggleLogFold,
+                "expand/collapse fold",
+            ),
+            bind(
+                Scope::View(MainTab::Logs),
+                KeyChord::ctrl_char('u'),
                 Cmd::ScrollLogsUp,
                 "scroll up",
             ),
             bind(
                 Scope::View(MainTab::Logs),
-                KeyChord::plain(Tok::Char('d')),
+                KeyChord::ctrl_char('d'),
                 Cmd::ScrollLogsDown,
                 "scroll down",
             ),
             bind(
                 Scope::View(MainTab::Runs),
-                KeyChord::plain(Tok::Char('u')),
+                KeyChord::ctrl_char('u'),
                 Cmd::ScrollLogsUp,
                 "scroll up",
             ),
             bind(
                 Scope::View(MainTab::Runs),
-                KeyChord::plain(Tok::Char('d')),
+                KeyChord::ctrl_char('d'),
                 Cmd::ScrollLogsDown,
                 "scroll down",
             ),
@@ -647,6 +1025,106 @@ impl Keymap {
         Self { bindings }
     }
 
+    /// Rebuild from the defaults and layer the user config on top.
+    ///
+    /// A rebind replaces the command's default keys in that scope. A rebind
+    /// whose key is already taken by another command in the same scope is
+    /// reported as a conflict and skipped, leaving that command's defaults.
+    pub fn load_user_config(&mut self, config: &str) -> KeymapLoadReport {
+        let defaults = Self::default_forge_tui().bindings;
+        let (rebinds, errors) = parse_keymap_config(config);
+        let mut report = KeymapLoadReport {
+            errors,
+            ..KeymapLoadReport::default()
+        };
+
+        let rebound = rebinds
+            .iter()
+            .map(|rebind| (rebind.scope, rebind.command))
+            .collect::<Vec<_>>();
+        let mut bindings = defaults
+            .iter()
+            .filter(|binding| !rebound.contains(&(binding.scope, binding.command)))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        for rebind in rebinds {
+            let existing = bindings
+                .iter()
+                .find(|binding| binding.scope == rebind.scope && binding.chord == rebind.chord)
+                .map(|binding| binding.command);
+            match existing {
+                Some(command) if command == rebind.command => {}
+                Some(command) => report.conflicts.push(KeyConflict {
+                    scope: rebind.scope,
+                    chord: rebind.chord,
+                    commands: vec![command, rebind.command],
+                }),
+                None => {
+                    let description = defaults
+                        .iter()
+                        .find(|binding| binding.command == rebind.command)
+                        .map_or("user binding", |binding| binding.description);
+                    bindings.push(bind(
+                        rebind.scope,
+                        rebind.chord,
+                        rebind.command,
+                        description,
+                    ));
+                    report.applied.push(rebind);
+                }
+            }
+        }
+
+        for default in &defaults {
+            let kept = report
+                .applied
+                .iter()
+                .any(|rebind| rebind.scope == default.scope && rebind.command == default.command);
+            let taken = bindings
+                .iter()
+                .any(|binding| binding.scope == default.scope && binding.chord == default.chord);
+            if !kept && !taken {
+                bindings.push(default.clone());
+            }
+        }
+
+        self.bindings = bindings;
+        report
+    }
+
+    #[must_use]
+    pub fn bindings(&self) -> &[KeyBinding] {
+        &self.bindings
+    }
+
+    pub fn reset_to_defaults(&mut self) {
+        *self = Self::default_forge_tui();
+    }
+
+    /// Shared copy of [`Keymap::default_forge_tui`].
+    #[must_use]
+    pub fn defaults() -> &'static Self {
+        static DEFAULTS: OnceLock<Keymap> = OnceLock::new();
+        DEFAULTS.get_or_init(Self::default_forge_tui)
+    }
+
+    /// First chord bound to `command`, preferring the earliest of `scopes`.
+    #[must_use]
+    pub fn chord_for(&self, scopes: &[KeyScope], command: KeyCommand) -> Option<KeyChord> {
+        let bound = |binding: &&KeyBinding| binding.command == command;
+        scopes
+            .iter()
+            .find_map(|scope| {
+                self.bindings
+                    .iter()
+                    .filter(bound)
+                    .find(|binding| binding.scope == *scope)
+            })
+            .or_else(|| self.bindings.iter().find(bound))
+            .map(|binding| binding.chord)
+    }
+
     #[must_use]
     pub fn resolve(&self, scopes: &[KeyScope], chord: KeyChord) -> Option<KeyCommand> {
         for scope in scopes {
@@ -755,7 +1233,10 @@ fn truncate(value: &str, width: usize) -> String {
 
 #[cfg(test)]
 mod tests {
-    use super::{KeyChord, KeyCommand, KeyScope, KeyToken, Keymap, ModeScope};
+    use super::{
+        parse_keymap_config, KeyChord, KeyCommand, KeyConflict, KeyScope, KeyToken, Keymap,
+        ModeScope,
+    };
     use crate::app::MainTab;
 
     #[test]
@@ -840,4 +1321,99 @@ mod tests {
             "Keymap diagnostics\n  no conflicts detected"
         );
     }
+
+    #[test]
+    fn user_rebind_conflict_is_reported_and_clean_rebind_applies() {
+        let mut map = Keymap::default_forge_tui();
+        let main = [KeyScope::Mode(ModeScope::Main), KeyScope::Global];
+        let report = map.load_user_config(
+            "# operator overrides\n\
+             main ctrl+o = open-filter\n\
+             main t = cycle-log-layer\n\
+             main y = no-such-command\n",
+        );
+
+        assert_eq!(report.applied.len(), 1);
+        assert_eq!(
+            report.conflicts,
+            vec![KeyConflict {
+                scope: KeyScope::Mode(ModeScope::Main),
+                chord: KeyChord::plain(KeyToken::Char('t')),
+                commands: vec![KeyCommand::CycleTheme, KeyCommand::CycleLogLayer],
+            }]
+        );
+        assert_eq!(
+            report.errors,
+            vec!["line 4: unknown command \"no-such-command\"".to_owned()]
+        );
+
+        assert_eq!(
+            map.resolve(&main, KeyChord::ctrl_char('o')),
+            Some(KeyCommand::OpenFilter)
+        );
+        assert_eq!(
+            map.resolve(&main, KeyChord::plain(KeyToken::Char('/'))),
+            None
+        );
+        assert_eq!(
+            map.resolve(&main, KeyChord::plain(KeyToken::Char('x'))),
+            Some(KeyCommand::CycleLogLayer)
+        );
+        assert!(map.conflicts().is_empty());
+
+        map.reset_to_defaults();
+        assert_eq!(
+            map.resolve(&main, KeyChord::plain(KeyToken::Char('/'))),
+            Some(KeyCommand::OpenFilter)
+        );
+    }
+
+    #[test]
+    fn config_can_bind_equals_and_plus_keys() {
+        let (rebinds, errors) = parse_keymap_config(
+            "main = = open-filter\nmain + = open-search\nmain ctrl++ = toggle-zen\n",
+        );
+        assert!(errors.is_empty(), "{errors:?}");
+        let chords = rebinds
+            .iter()
+            .map(|rebind| (rebind.chord, rebind.command))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            chords,
+            vec![
+                (KeyChord::plain(KeyToken::Char('=')), KeyCommand::OpenFilter),
+                (KeyChord::plain(KeyToken::Char('+')), KeyCommand::OpenSearch),
+                (KeyChord::ctrl_char('+'), KeyCommand::ToggleZen),
+            ]
+        );
+        assert_eq!(KeyChord::parse("ctrl+"), None);
+        assert_eq!(KeyChord::parse("meta+a"), None);
+    }
+
+    #[test]
+    fn view_scoped_rebind_layers_over_global() {
+        let mut map = Keymap::default_forge_tui();
+        let report = map.load_user_config("view:logs ctrl+c = toggle-follow\n");
+        assert_eq!(report.applied.len(), 1);
+        assert!(report.conflicts.is_empty());
+
+        let logs = [
+            KeyScope::View(MainTab::Logs),
+            KeyScope::Mode(ModeScope::Main),
+            KeyScope::Global,
+        ];
+        let overview = [
+            KeyScope::View(MainTab::Overview),
+            KeyScope::Mode(ModeScope::Main),
+            KeyScope::Global,
+        ];
+        assert_eq!(
+            map.resolve(&logs, KeyChord::ctrl_char('c')),
+            Some(KeyCommand::ToggleFollow)
+        );
+        assert_eq!(
+            map.resolve(&overview, KeyChord::ctrl_char('c')),
+            Some(KeyCommand::Quit)
+        );
+    }
 }
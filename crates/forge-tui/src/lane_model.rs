@@ -52,6 +52,15 @@ impl LogLane {
             Self::Unknown => "unknown",
         }
     }
+
+    /// Parse a lane from its label (case-insensitive).
+    #[must_use]
+    pub fn from_label(label: &str) -> Option<Self> {
+        let label = label.trim();
+        Self::ALL
+            .into_iter()
+            .find(|lane| lane.label().eq_ignore_ascii_case(label))
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -167,6 +176,174 @@ impl LanedLogModel {
     }
 }
 
+// ---------------------------------------------------------------------------
+// LaneOrder — operator lane ordering and pinning
+// ---------------------------------------------------------------------------
+
+/// Operator-controlled display order for lanes.
+///
+/// Pinned lanes always sort above unpinned ones; within each group lanes
+/// follow the operator order, which starts as [`LogLane::ALL`]. Reordering
+/// never changes which lines belong to a lane.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LaneOrder {
+    order: Vec<LogLane>,
+    pinned: Vec<LogLane>,
+}
+
+impl Default for LaneOrder {
+    fn default() -> Self {
+        Self {
+            order: LogLane::ALL.to_vec(),
+            pinned: Vec::new(),
+        }
+    }
+}
+
+impl LaneOrder {
+    /// Rebuild from persisted lane labels. Unknown labels are dropped and
+    /// lanes missing from `order` keep their natural position at the end.
+    #[must_use]
+    pub fn from_persisted(order: &[String], pinned: &[String]) -> Self {
+        let mut lanes = Vec::new();
+        for lane in order.iter().filter_map(|label| LogLane::from_label(label)) {
+            if !lanes.contains(&lane) {
+                lanes.push(lane);
+            }
+        }
+        for lane in LogLane::ALL {
+            if !lanes.contains(&lane) {
+                lanes.push(lane);
+            }
+        }
+        let mut lane_order = Self {
+            order: lanes,
+            pinned: Vec::new(),
+        };
+        for lane in pinned.iter().filter_map(|label| LogLane::from_label(label)) {
+            lane_order.pin(lane);
+        }
+        lane_order
+    }
+
+    /// Operator order as labels; empty while it matches the natural order.
+    #[must_use]
+    pub fn persisted_order(&self) -> Vec<String> {
+        if self.order == LogLane::ALL {
+            return Vec::new();
+        }
+        self.order
+            .iter()
+            .map(|lane| lane.label().to_owned())
+            .collect()
+    }
+
+    /// Pinned lanes as labels, in display order.
+    #[must_use]
+    pub fn persisted_pins(&self) -> Vec<String> {
+        self.display_order()
+            .into_iter()
+            .filter(|lane| self.is_pinned(*lane))
+            .map(|lane| lane.label().to_owned())
+            .collect()
+    }
+
+    /// Lanes in display order: pinned first, then unpinned.
+    #[must_use]
+    pub fn display_order(&self) -> Vec<LogLane> {
+        let pinned = self.order.iter().filter(|lane| self.is_pinned(**lane));
+        let unpinned = self.order.iter().filter(|lane| !self.is_pinned(**lane));
+        pinned.chain(unpinned).copied().collect()
+    }
+
+    #[must_use]
+    pub fn is_pinned(&self, lane: LogLane) -> bool {
+        self.pinned.contains(&lane)
+    }
+
+    /// Pin a lane above all unpinned lanes. Returns `false` if already pinned.
+    pub fn pin(&mut self, lane: LogLane) -> bool {
+        if self.is_pinned(lane) {
+            return false;
+        }
+        self.pinned.push(lane);
+        true
+    }
+
+    /// Unpin a lane, returning it to its operator-order position.
+    pub fn unpin(&mut self, lane: LogLane) -> bool {
+        let before = self.pinned.len();
+        self.pinned.retain(|pinned| *pinned != lane);
+        self.pinned.len() != before
+    }
+
+    /// Move a lane one step up within its group (pinned or unpinned).
+    pub fn move_up(&mut self, lane: LogLane) -> bool {
+        self.swap_with_neighbor(lane, false)
+    }
+
+    /// Move a lane one step down within its group (pinned or unpinned).
+    pub fn move_down(&mut self, lane: LogLane) -> bool {
+        self.swap_with_neighbor(lane, true)
+    }
+
+    /// Group a model's lines by lane in display order, skipping empty lanes.
+    #[must_use]
+    pub fn ordered_lines<'a>(
+        &self,
+        model: &'a LanedLogModel,
+    ) -> Vec<(LogLane, Vec<&'a LanedLogLine>)> {
+        self.display_order()
+            .into_iter()
+            .map(|lane| (lane, model.lines_for_lane(lane)))
+            .filter(|(_, lines)| !lines.is_empty())
+            .collect()
+    }
+
+    /// Arrange raw log lines for display. Lines stay chronological
+    /// until the operator pins or reorders a lane; after that they are
+    /// grouped by lane in display order, chronological within each lane.
+    #[must_use]
+    pub fn arrange(&self, lines: Vec<String>) -> Vec<String> {
+        if *self == Self::default() {
+            return lines;
+        }
+        let model = classify_lines(&lines);
+        self.ordered_lines(&model)
+            .into_iter()
+            .flat_map(|(_, lane_lines)| lane_lines.into_iter().map(|line| line.text.clone()))
+            .collect()
+    }
+
+    fn swap_with_neighbor(&mut self, lane: LogLane, down: bool) -> bool {
+        let display = self.display_order();
+        let Some(index) = display.iter().position(|candidate| *candidate == lane) else {
+            return false;
+        };
+        let neighbor_index = if down {
+            Some(index + 1)
+        } else {
+            index.checked_sub(1)
+        };
+        let Some(neighbor) = neighbor_index.and_then(|i| display.get(i)).copied() else {
+            return false;
+        };
+        if self.is_pinned(neighbor) != self.is_pinned(lane) {
+            return false;
+        }
+        let (Some(a), Some(b)) = (
+            self.order.iter().position(|candidate| *candidate == lane),
+            self.order
+                .iter()
+                .position(|candidate| *candidate == neighbor),
+        ) else {
+            return false;
+        };
+        self.order.swap(a, b);
+        true
+    }
+}
+
 // ---------------------------------------------------------------------------
 // classify_line — heuristic lane classifier
 // ---------------------------------------------------------------------------
@@ -620,4 +797,96 @@ mod tests {
         assert_eq!(model.all_lines()[0].text, "first");
         assert_eq!(model.all_lines()[1].text, "second");
     }
+
+    // -- LaneOrder --
+
+    #[test]
+    fn pinned_lane_sorts_above_unpinned_and_unpin_restores_natural_order() {
+        let mut order = LaneOrder::default();
+        assert_eq!(order.display_order(), LogLane::ALL.to_vec());
+
+        assert!(order.pin(LogLane::Event));
+        assert!(!order.pin(LogLane::Event));
+        assert_eq!(
+            order.display_order(),
+            vec![
+                LogLane::Event,
+                LogLane::Thinking,
+                LogLane::Tool,
+                LogLane::Stdout,
+                LogLane::Stderr,
+                LogLane::Unknown,
+            ]
+        );
+        // Unpinned lanes cannot be moved above a pinned one.
+        assert!(!order.move_up(LogLane::Thinking));
+        assert!(!order.move_up(LogLane::Event));
+
+        assert!(order.unpin(LogLane::Event));
+        assert_eq!(order.display_order(), LogLane::ALL.to_vec());
+        assert!(order.persisted_order().is_empty());
+    }
+
+    #[test]
+    fn lane_moves_reorder_within_group_and_round_trip() {
+        let mut order = LaneOrder::default();
+        assert!(order.move_down(LogLane::Thinking));
+        assert!(order.move_up(LogLane::Stderr));
+        assert!(order.pin(LogLane::Unknown));
+        assert!(!order.move_down(LogLane::Unknown));
+        assert_eq!(
+            order.display_order(),
+            vec![
+                LogLane::Unknown,
+                LogLane::Tool,
+                LogLane::Thinking,
+                LogLane::Stderr,
+                LogLane::Stdout,
+                LogLane::Event,
+            ]
+        );
+
+        let restored = LaneOrder::from_persisted(&order.persisted_order(), &order.persisted_pins());
+        assert_eq!(restored, order);
+    }
+
+    #[test]
+    fn lane_order_keeps_line_membership() {
+        let model = classify_lines(&[
+            "stderr: boom".to_owned(),
+            "plain output".to_owned(),
+            "error: again".to_owned(),
+        ]);
+        let mut order = LaneOrder::default();
+        order.pin(LogLane::Stderr);
+        let grouped = order.ordered_lines(&model);
+        assert_eq!(grouped[0].0, LogLane::Stderr);
+        let total: usize = grouped.iter().map(|(_, lines)| lines.len()).sum();
+        assert_eq!(total, model.len());
+        for (lane, lines) in grouped {
+            assert!(lines.iter().all(|line| line.lane == lane));
+            assert_eq!(lines.len(), model.lane_counts()[&lane]);
+        }
+    }
+
+    #[test]
+    fn arrange_is_chronological_until_lane_order_changes() {
+        let lines = vec![
+            "plain output".to_owned(),
+            "stderr: boom".to_owned(),
+            "tool: read".to_owned(),
+            "more output".to_owned(),
+        ];
+        let mut order = LaneOrder::default();
+        assert_eq!(order.arrange(lines.clone()), lines);
+
+        order.pin(LogLane::Stderr);
+        assert_eq!(
+            order.arrange(lines.clone()),
+            vec!["stderr: boom", "tool: read", "plain output", "more output"]
+        );
+
+        order.unpin(LogLane::Stderr);
+        assert_eq!(order.arrange(lines.clone()), lines);
+    }
 }
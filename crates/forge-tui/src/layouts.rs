@@ -255,11 +255,116 @@ pub fn fit_pane_layout_for_breakpoint(
     )
 }
 
+/// Width/height below which dashboards collapse to a single stacked column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResponsiveThresholds {
+    pub stack_below_width: i32,
+    pub stack_below_height: i32,
+    pub min_column_width: i32,
+}
+
+impl Default for ResponsiveThresholds {
+    fn default() -> Self {
+        Self {
+            stack_below_width: 100,
+            stack_below_height: 24,
+            // Wide enough for a run snapshot line untruncated; 120 columns
+            // gets two panels side by side, 180 and up gets three.
+            min_column_width: 60,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DashboardPanel {
+    pub id: String,
+    pub essential: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DashboardArrangement {
+    Columns(i32),
+    Stacked,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResponsiveLayout {
+    pub tier: BreakpointTier,
+    pub arrangement: DashboardArrangement,
+    pub visible_panels: Vec<String>,
+    pub hidden_panels: Vec<String>,
+}
+
+impl ResponsiveLayout {
+    #[must_use]
+    pub fn columns(&self) -> i32 {
+        match self.arrangement {
+            DashboardArrangement::Columns(columns) => columns,
+            DashboardArrangement::Stacked => 1,
+        }
+    }
+
+    /// Short label for the status strip, e.g. `3col` or `stacked (2 hidden)`.
+    #[must_use]
+    pub fn status_label(&self) -> String {
+        let base = match self.arrangement {
+            DashboardArrangement::Columns(columns) => format!("{columns}col"),
+            DashboardArrangement::Stacked => "stacked".to_owned(),
+        };
+        if self.hidden_panels.is_empty() {
+            base
+        } else {
+            format!("{base} ({} hidden)", self.hidden_panels.len())
+        }
+    }
+}
+
+/// Pick a dashboard arrangement for the viewport. Below either threshold the
+/// panels stack in one column and non-essential panels are hidden; otherwise
+/// as many columns as fit `min_column_width` (capped by the breakpoint tier
+/// and the panel count) are used.
+#[must_use]
+pub fn responsive_dashboard_layout(
+    panels: &[DashboardPanel],
+    width: i32,
+    height: i32,
+    thresholds: ResponsiveThresholds,
+) -> ResponsiveLayout {
+    let tier = classify_breakpoint(width, height);
+    if width < thresholds.stack_below_width || height < thresholds.stack_below_height {
+        let (visible, hidden): (Vec<_>, Vec<_>) = panels.iter().partition(|panel| panel.essential);
+        return ResponsiveLayout {
+            tier,
+            arrangement: DashboardArrangement::Stacked,
+            visible_panels: visible.into_iter().map(|panel| panel.id.clone()).collect(),
+            hidden_panels: hidden.into_iter().map(|panel| panel.id.clone()).collect(),
+        };
+    }
+
+    let contract = breakpoint_contract(width, height, thresholds.min_column_width, 1);
+    let fit = width / thresholds.min_column_width.max(1);
+    let columns = fit
+        .min(contract.max_cols)
+        .min(panels.len().max(1) as i32)
+        .max(1);
+    ResponsiveLayout {
+        tier,
+        arrangement: if columns > 1 {
+            DashboardArrangement::Columns(columns)
+        } else {
+            DashboardArrangement::Stacked
+        },
+        visible_panels: panels.iter().map(|panel| panel.id.clone()).collect(),
+        hidden_panels: Vec::new(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{
         classify_breakpoint, fit_pane_layout, fit_pane_layout_for_breakpoint, layout_cell_size,
-        BreakpointTier, PaneLayout,
+        responsive_dashboard_layout, BreakpointTier, DashboardArrangement, DashboardPanel,
+        PaneLayout, ResponsiveThresholds,
     };
 
     #[test]
@@ -321,4 +426,35 @@ mod tests {
             PaneLayout { rows: 4, cols: 4 }
         );
     }
+
+    #[test]
+    fn responsive_layout_stacks_below_width_threshold_and_expands_above() {
+        let panels = [
+            DashboardPanel {
+                id: "fleet".to_owned(),
+                essential: true,
+            },
+            DashboardPanel {
+                id: "queue".to_owned(),
+                essential: true,
+            },
+            DashboardPanel {
+                id: "sparklines".to_owned(),
+                essential: false,
+            },
+        ];
+        let thresholds = ResponsiveThresholds::default();
+
+        let narrow = responsive_dashboard_layout(&panels, 80, 40, thresholds);
+        assert_eq!(narrow.arrangement, DashboardArrangement::Stacked);
+        assert_eq!(narrow.columns(), 1);
+        assert_eq!(narrow.visible_panels, vec!["fleet", "queue"]);
+        assert_eq!(narrow.hidden_panels, vec!["sparklines"]);
+        assert_eq!(narrow.status_label(), "stacked (1 hidden)");
+
+        let wide = responsive_dashboard_layout(&panels, 180, 50, thresholds);
+        assert_eq!(wide.arrangement, DashboardArrangement::Columns(3));
+        assert!(wide.hidden_panels.is_empty());
+        assert_eq!(wide.status_label(), "3col");
+    }
 }
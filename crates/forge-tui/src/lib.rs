@@ -94,6 +94,7 @@ pub mod session_restore;
 pub mod shared_annotations;
 pub mod smart_context_panel;
 pub mod smart_loop_clustering;
+pub mod split_view;
 pub mod stale_takeover;
 pub mod status_strip;
 pub mod swarm_dogpile;
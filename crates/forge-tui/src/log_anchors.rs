@@ -5,6 +5,7 @@ use std::collections::BTreeSet;
 use serde_json::{Map, Value};
 
 pub const LOG_ANCHOR_SCHEMA_VERSION: u32 = 1;
+const ANCHOR_LINK_PREFIX: &str = "forge://log/";
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct LogAnchor {
@@ -49,6 +50,23 @@ pub struct LogAnchorStore {
     anchors: Vec<LogAnchor>,
 }
 
+/// Shareable anchor reference: loop, log source, line, and label.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnchorLink {
+    pub loop_id: String,
+    pub log_source: String,
+    pub line_index: usize,
+    pub label: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedAnchorLink {
+    pub link: AnchorLink,
+    /// Target line, clamped to the current log length.
+    pub line_index: usize,
+    pub warning: Option<String>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ImportAnchorsOutcome {
     pub imported: usize,
@@ -171,6 +189,20 @@ pub fn remove_log_anchor(store: &mut LogAnchorStore, anchor_id: &str) -> Result<
     Ok(())
 }
 
+/// Re-insert a previously removed anchor unchanged (used by undo).
+pub fn restore_log_anchor(store: &mut LogAnchorStore, anchor: LogAnchor) -> Result<(), String> {
+    if store
+        .anchors
+        .iter()
+        .any(|existing| existing.anchor_id == anchor.anchor_id || existing.marker == anchor.marker)
+    {
+        return Err(format!("anchor '{}' already exists", anchor.anchor_id));
+    }
+    store.anchors.push(anchor);
+    sort_anchors(&mut store.anchors);
+    Ok(())
+}
+
 #[must_use]
 pub fn list_log_anchors(store: &LogAnchorStore, filter: &LogAnchorFilter) -> Vec<LogAnchor> {
     let marker = normalize_marker(&filter.marker);
@@ -235,6 +267,68 @@ pub fn resolve_anchor_target(
     Some((anchor.log_source.clone(), anchor.line_index))
 }
 
+/// Shareable link for an anchor (by id or marker), e.g.
+/// `forge://log/loop-3/latest-run?line=17&label=hotspot`.
+#[must_use]
+pub fn export_anchor_link(store: &LogAnchorStore, marker_or_id: &str) -> Option<String> {
+    let query = normalize_required(marker_or_id);
+    let anchor = store.get(&query).or_else(|| store.get_by_marker(&query))?;
+    Some(format!(
+        "{ANCHOR_LINK_PREFIX}{}/{}?line={}&label={}",
+        encode_link_part(&anchor.loop_id),
+        encode_link_part(&anchor.log_source),
+        anchor.line_index,
+        encode_link_part(&anchor.marker)
+    ))
+}
+
+/// Parse a shared anchor link. A line beyond `current_line_count` is clamped
+/// to the last line and reported in `warning`.
+#[must_use]
+pub fn resolve_anchor_link(link: &str, current_line_count: usize) -> Option<ResolvedAnchorLink> {
+    let rest = link.trim().strip_prefix(ANCHOR_LINK_PREFIX)?;
+    let (path, query) = rest.split_once('?')?;
+    let (loop_id, log_source) = path.split_once('/')?;
+    let loop_id = decode_link_part(loop_id)?;
+    if loop_id.trim().is_empty() {
+        return None;
+    }
+
+    let mut line_index = None;
+    let mut label = String::new();
+    for pair in query.split('&') {
+        match pair.split_once('=') {
+            Some(("line", value)) => line_index = value.parse::<usize>().ok(),
+            Some(("label", value)) => label = decode_link_part(value)?,
+            _ => {}
+        }
+    }
+    let link = AnchorLink {
+        loop_id,
+        log_source: normalize_log_source(&decode_link_part(log_source)?),
+        line_index: line_index?,
+        label: normalize_marker(&label),
+    };
+
+    let (line_index, warning) = if link.line_index < current_line_count {
+        (link.line_index, None)
+    } else {
+        let clamped = current_line_count.saturating_sub(1);
+        (
+            clamped,
+            Some(format!(
+                "anchor line {} beyond log length {}; jumped to line {}",
+                link.line_index, current_line_count, clamped
+            )),
+        )
+    };
+    Some(ResolvedAnchorLink {
+        link,
+        line_index,
+        warning,
+    })
+}
+
 #[must_use]
 pub fn export_anchor_bundle_json(store: &LogAnchorStore, filter: &LogAnchorFilter) -> String {
     let mut anchors = list_log_anchors(store, filter);
@@ -616,6 +710,35 @@ fn next_marker(marker: &str, line_index: usize, anchors: &[LogAnchor]) -> String
     format!("{base}-overflow")
 }
 
+fn encode_link_part(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.' | b':') {
+            out.push(char::from(byte));
+        } else {
+            out.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    out
+}
+
+fn decode_link_part(value: &str) -> Option<String> {
+    let bytes = value.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        if bytes[index] == b'%' {
+            let hex = value.get(index + 1..index + 3)?;
+            out.push(u8::from_str_radix(hex, 16).ok()?);
+            index += 3;
+        } else {
+            out.push(bytes[index]);
+            index += 1;
+        }
+    }
+    String::from_utf8(out).ok()
+}
+
 fn normalize_required(value: &str) -> String {
     value.trim().to_owned()
 }
@@ -705,9 +828,9 @@ fn compact_text(primary: &str, fallback: &str) -> String {
 mod tests {
     use super::{
         add_log_anchor, annotate_log_anchor, export_anchor_bundle_json,
-        export_anchor_handoff_markdown, import_anchor_bundle_json, list_log_anchors,
-        remove_log_anchor, render_anchor_rows, resolve_anchor_target, LogAnchorDraft,
-        LogAnchorFilter, LogAnchorStore,
+        export_anchor_handoff_markdown, export_anchor_link, import_anchor_bundle_json,
+        list_log_anchors, remove_log_anchor, render_anchor_rows, resolve_anchor_link,
+        resolve_anchor_target, LogAnchorDraft, LogAnchorFilter, LogAnchorStore,
     };
 
     fn draft(loop_id: &str, source: &str, line: usize, excerpt: &str) -> LogAnchorDraft {
@@ -915,4 +1038,49 @@ mod tests {
         let by_id = resolve_anchor_target(&store, &anchor_id);
         assert_eq!(by_id, Some(("latest-run".to_owned(), 17)));
     }
+
+    #[test]
+    fn exported_link_resolves_to_same_position() {
+        let mut store = LogAnchorStore::default();
+        let mut item = draft("loop/3 a", "latest-run", 17, "build failed");
+        item.marker = "Hot Spot".to_owned();
+        if let Err(err) = add_log_anchor(&mut store, item) {
+            panic!("add anchor should succeed: {err}");
+        }
+
+        let Some(link) = export_anchor_link(&store, "hot-spot") else {
+            panic!("expected link for marker");
+        };
+        assert_eq!(
+            link,
+            "forge://log/loop%2F3%20a/latest-run?line=17&label=hot-spot"
+        );
+
+        let Some(resolved) = resolve_anchor_link(&link, 40) else {
+            panic!("expected link to resolve");
+        };
+        assert_eq!(resolved.link.loop_id, "loop/3 a");
+        assert_eq!(resolved.link.log_source, "latest-run");
+        assert_eq!(resolved.link.label, "hot-spot");
+        assert_eq!(resolved.line_index, 17);
+        assert_eq!(resolved.warning, None);
+        assert!(export_anchor_link(&store, "missing").is_none());
+    }
+
+    #[test]
+    fn stale_link_clamps_to_log_length_with_warning() {
+        let link = "forge://log/loop-3/live?line=120&label=tail";
+        let Some(resolved) = resolve_anchor_link(link, 50) else {
+            panic!("expected stale link to resolve");
+        };
+        assert_eq!(resolved.link.line_index, 120);
+        assert_eq!(resolved.line_index, 49);
+        assert_eq!(
+            resolved.warning.as_deref(),
+            Some("anchor line 120 beyond log length 50; jumped to line 49")
+        );
+
+        assert!(resolve_anchor_link("https://example.com/x", 50).is_none());
+        assert!(resolve_anchor_link("forge://log/loop-3/live?label=x", 50).is_none());
+    }
 }
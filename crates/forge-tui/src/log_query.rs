@@ -17,6 +17,11 @@
 //!
 //! Implicit `AND` when terms are juxtaposed without an operator.
 
+use std::collections::{BTreeMap, VecDeque};
+use std::path::{Path, PathBuf};
+
+use serde_json::{Map, Value};
+
 use crate::lane_model::{LanedLogLine, LanedLogModel, LogLane};
 
 // ---------------------------------------------------------------------------
@@ -613,6 +618,284 @@ pub fn query_model<'a>(
     Ok(filter_model(&query, model))
 }
 
+// ---------------------------------------------------------------------------
+// Query history + saved queries
+// ---------------------------------------------------------------------------
+
+pub const LOG_QUERY_BOOK_SCHEMA_VERSION: u32 = 1;
+pub const LOG_QUERY_HISTORY_CAPACITY: usize = 50;
+
+/// Recent queries (newest first, deduplicated) and named saved queries.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct LogQueryBook {
+    history: VecDeque<String>,
+    saved: BTreeMap<String, String>,
+}
+
+impl LogQueryBook {
+    /// Recent queries, newest first.
+    #[must_use]
+    pub fn history(&self) -> Vec<&str> {
+        self.history.iter().map(String::as_str).collect()
+    }
+
+    /// Push a query to the front of the history, dropping an older duplicate
+    /// and the oldest entry once the ring is full.
+    pub fn record(&mut self, query: &str) {
+        let query = query.trim();
+        if query.is_empty() {
+            return;
+        }
+        self.history.retain(|existing| existing != query);
+        self.history.push_front(query.to_owned());
+        self.history.truncate(LOG_QUERY_HISTORY_CAPACITY);
+    }
+
+    #[must_use]
+    pub fn saved(&self, name: &str) -> Option<&str> {
+        self.saved
+            .get(&normalize_query_name(name))
+            .map(String::as_str)
+    }
+
+    /// Saved queries as `(name, query)`, sorted by name.
+    #[must_use]
+    pub fn saved_queries(&self) -> Vec<(&str, &str)> {
+        self.saved
+            .iter()
+            .map(|(name, query)| (name.as_str(), query.as_str()))
+            .collect()
+    }
+
+    pub fn save(&mut self, name: &str, query: &str) -> Result<(), String> {
+        let name = normalize_query_name(name);
+        if name.is_empty() {
+            return Err("saved query name is required".to_owned());
+        }
+        let query = query.trim();
+        if let Err(err) = parse_query(query) {
+            return Err(format!("saved query {name} is invalid: {err}"));
+        }
+        self.saved.insert(name, query.to_owned());
+        Ok(())
+    }
+
+    pub fn remove_saved(&mut self, name: &str) -> bool {
+        self.saved.remove(&normalize_query_name(name)).is_some()
+    }
+}
+
+/// Editable query box backed by a [`LogQueryBook`].
+///
+/// Recalling history or a saved query only fills the input; nothing runs
+/// until [`LogQueryEditor::run`] confirms it.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct LogQueryEditor {
+    input: String,
+    history_cursor: Option<usize>,
+    book: LogQueryBook,
+}
+
+impl LogQueryEditor {
+    #[must_use]
+    pub fn new(book: LogQueryBook) -> Self {
+        Self {
+            input: String::new(),
+            history_cursor: None,
+            book,
+        }
+    }
+
+    #[must_use]
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    #[must_use]
+    pub fn book(&self) -> &LogQueryBook {
+        &self.book
+    }
+
+    pub fn book_mut(&mut self) -> &mut LogQueryBook {
+        &mut self.book
+    }
+
+    pub fn set_input(&mut self, input: &str) {
+        self.input = input.to_owned();
+        self.history_cursor = None;
+    }
+
+    /// Step to an older history entry (Up).
+    pub fn recall_older(&mut self) -> bool {
+        let next = self.history_cursor.map_or(0, |cursor| cursor + 1);
+        self.recall_history(next)
+    }
+
+    /// Step to a newer history entry (Down); past the newest clears the input.
+    pub fn recall_newer(&mut self) -> bool {
+        match self.history_cursor {
+            None => false,
+            Some(0) => {
+                self.set_input("");
+                true
+            }
+            Some(cursor) => self.recall_history(cursor - 1),
+        }
+    }
+
+    /// Load a saved query into the input box without running it.
+    pub fn recall_saved(&mut self, name: &str) -> bool {
+        let Some(query) = self.book.saved(name).map(str::to_owned) else {
+            return false;
+        };
+        self.set_input(&query);
+        true
+    }
+
+    pub fn save_input(&mut self, name: &str) -> Result<(), String> {
+        let input = self.input.clone();
+        self.book.save(name, &input)
+    }
+
+    /// Run the current input against `model`, recording it on success.
+    pub fn run<'a>(
+        &mut self,
+        model: &'a LanedLogModel,
+    ) -> Result<Vec<&'a LanedLogLine>, QueryError> {
+        let lines = query_model(&self.input, model)?;
+        self.book.record(&self.input);
+        self.history_cursor = None;
+        Ok(lines)
+    }
+
+    fn recall_history(&mut self, cursor: usize) -> bool {
+        let Some(query) = self.book.history.get(cursor).cloned() else {
+            return false;
+        };
+        self.input = query;
+        self.history_cursor = Some(cursor);
+        true
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogQueryBookLoadOutcome {
+    pub book: LogQueryBook,
+    pub warnings: Vec<String>,
+}
+
+#[must_use]
+pub fn persist_log_query_book(book: &LogQueryBook) -> String {
+    let mut saved = Map::new();
+    for (name, query) in &book.saved {
+        saved.insert(name.clone(), Value::from(query.clone()));
+    }
+    let mut root = Map::new();
+    root.insert(
+        "schema_version".to_owned(),
+        Value::from(LOG_QUERY_BOOK_SCHEMA_VERSION),
+    );
+    root.insert(
+        "history".to_owned(),
+        Value::Array(book.history.iter().cloned().map(Value::from).collect()),
+    );
+    root.insert("saved".to_owned(), Value::Object(saved));
+
+    match serde_json::to_string_pretty(&Value::Object(root)) {
+        Ok(raw) => raw,
+        Err(_) => "{}".to_owned(),
+    }
+}
+
+#[must_use]
+pub fn restore_log_query_book(raw: &str) -> LogQueryBookLoadOutcome {
+    let mut warnings = Vec::new();
+    let trimmed = raw.trim();
+    if trimmed.is_empty() {
+        return LogQueryBookLoadOutcome {
+            book: LogQueryBook::default(),
+            warnings,
+        };
+    }
+    let value = match serde_json::from_str::<Value>(trimmed) {
+        Ok(value) => value,
+        Err(err) => {
+            return LogQueryBookLoadOutcome {
+                book: LogQueryBook::default(),
+                warnings: vec![format!("invalid json; query history reset ({err})")],
+            };
+        }
+    };
+
+    let mut book = LogQueryBook::default();
+    if let Some(history) = value.get("history").and_then(Value::as_array) {
+        // Stored newest first; replay oldest first so `record` keeps the order.
+        for query in history.iter().rev().filter_map(Value::as_str) {
+            book.record(query);
+        }
+    }
+    if let Some(saved) = value.get("saved").and_then(Value::as_object) {
+        for (name, query) in saved {
+            let Some(query) = query.as_str() else {
+                warnings.push(format!("saved query {name} ignored (not a string)"));
+                continue;
+            };
+            if let Err(err) = book.save(name, query) {
+                warnings.push(format!("{err}; ignored"));
+            }
+        }
+    }
+
+    LogQueryBookLoadOutcome { book, warnings }
+}
+
+/// Default location of the persisted query book.
+#[must_use]
+pub fn user_log_query_book_path() -> Option<PathBuf> {
+    std::env::var_os("HOME")
+        .filter(|home| !home.is_empty())
+        .map(|home| {
+            PathBuf::from(home)
+                .join(".config")
+                .join("forge")
+                .join("tui-log-queries.json")
+        })
+}
+
+/// Read a query book from `path`; a missing file yields an empty book.
+pub fn load_log_query_book_file(path: &Path) -> Result<LogQueryBookLoadOutcome, String> {
+    match std::fs::read_to_string(path) {
+        Ok(raw) => Ok(restore_log_query_book(&raw)),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(LogQueryBookLoadOutcome {
+            book: LogQueryBook::default(),
+            warnings: Vec::new(),
+        }),
+        Err(err) => Err(format!("read log queries {}: {err}", path.display())),
+    }
+}
+
+/// Write `book` to `path` via a temp file and rename, so a crash never
+/// leaves a truncated book behind.
+pub fn save_log_query_book_file(path: &Path, book: &LogQueryBook) -> Result<(), String> {
+    if let Some(parent) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent)
+            .map_err(|err| format!("create {}: {err}", parent.display()))?;
+    }
+    let mut temp = path.as_os_str().to_os_string();
+    temp.push(format!(".tmp-{}", std::process::id()));
+    let temp = PathBuf::from(temp);
+    std::fs::write(&temp, persist_log_query_book(book))
+        .map_err(|err| format!("write {}: {err}", temp.display()))?;
+    std::fs::rename(&temp, path).map_err(|err| {
+        let _ = std::fs::remove_file(&temp);
+        format!("rename {} -> {}: {err}", temp.display(), path.display())
+    })
+}
+
+fn normalize_query_name(name: &str) -> String {
+    name.trim().to_ascii_lowercase()
+}
+
 // ---------------------------------------------------------------------------
 // Compiled regex cache for efficient filtering
 // ---------------------------------------------------------------------------
@@ -1298,4 +1581,71 @@ mod tests {
         let formatted = format!("{err}");
         assert_eq!(formatted, "test error");
     }
+
+    // -- history + saved queries --
+
+    #[test]
+    fn running_queries_fills_history_newest_first_without_duplicates() {
+        let model = sample_model();
+        let mut editor = LogQueryEditor::default();
+        for query in ["lane:stderr", "error", "lane:tool", "error"] {
+            editor.set_input(query);
+            if let Err(err) = editor.run(&model) {
+                panic!("query {query} should run: {err}");
+            }
+        }
+        editor.set_input("text:/(/");
+        assert!(editor.run(&model).is_err());
+
+        assert_eq!(
+            editor.book().history(),
+            vec!["error", "lane:tool", "lane:stderr"]
+        );
+
+        editor.set_input("");
+        assert!(editor.recall_older());
+        assert_eq!(editor.input(), "error");
+        assert!(editor.recall_older());
+        assert_eq!(editor.input(), "lane:tool");
+        assert!(editor.recall_newer());
+        assert_eq!(editor.input(), "error");
+        assert!(editor.recall_newer());
+        assert_eq!(editor.input(), "");
+    }
+
+    #[test]
+    fn saved_query_recalls_exact_text_without_running() {
+        let model = sample_model();
+        let mut editor = LogQueryEditor::default();
+        editor.set_input("lane:stderr AND NOT warning");
+        assert_eq!(editor.save_input("Stderr Only"), Ok(()));
+        assert!(editor.book_mut().save("broken", "(").is_err());
+
+        editor.set_input("something else");
+        assert!(editor.recall_saved("stderr only"));
+        assert_eq!(editor.input(), "lane:stderr AND NOT warning");
+        assert!(editor.book().history().is_empty());
+
+        let lines = editor
+            .run(&model)
+            .unwrap_or_else(|err| panic!("run: {err}"));
+        assert_eq!(lines.len(), 1);
+        assert_eq!(editor.book().history(), vec!["lane:stderr AND NOT warning"]);
+    }
+
+    #[test]
+    fn query_book_round_trips_through_persistence() {
+        let mut book = LogQueryBook::default();
+        book.record("lane:tool");
+        book.record("error");
+        assert_eq!(book.save("errors", "error OR warning"), Ok(()));
+
+        let restored = restore_log_query_book(&persist_log_query_book(&book));
+        assert!(restored.warnings.is_empty());
+        assert_eq!(restored.book, book);
+
+        let broken = restore_log_query_book("{\"saved\":{\"bad\":\"(\",\"n\":3}}");
+        assert!(broken.book.saved_queries().is_empty());
+        assert_eq!(broken.warnings.len(), 2);
+    }
 }
@@ -1,6 +1,16 @@
 //! Loop TUI logs-tab state/math helpers.
 //!
 //! Parity-oriented port of selected behavior from `internal/looptui/looptui.go`.
+//! Also hosts level-based folding, which collapses lines below a minimum
+//! level into expandable `… N lines …` markers.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::BTreeSet;
+use std::hash::{Hash, Hasher};
+
+use forge_ftui_adapter::render::{LogSpanSource, SpanSource, SpanStyle, TextRole};
+
+use crate::lane_model::{classify_line, LogLane};
 
 const DEFAULT_LOG_LINES: i32 = 12;
 const DEFAULT_LOG_BACKFILL: i32 = 1200;
@@ -177,6 +187,268 @@ impl LogsTabState {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum LogLevel {
+    #[default]
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl LogLevel {
+    pub const ORDER: [LogLevel; 5] = [
+        LogLevel::Trace,
+        LogLevel::Debug,
+        LogLevel::Info,
+        LogLevel::Warn,
+        LogLevel::Error,
+    ];
+
+    #[must_use]
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Trace => "all",
+            Self::Debug => "debug+",
+            Self::Info => "info+",
+            Self::Warn => "warn+",
+            Self::Error => "error",
+        }
+    }
+
+    /// Classify a log line with the `LogSpanSource` level role, then a
+    /// `level=error`-style token in the first three words, falling back to the
+    /// lane classifier so unlabelled stderr lines still count as errors.
+    #[must_use]
+    pub fn classify(line: &str) -> Self {
+        for span in LogSpanSource.style_line(line).spans {
+            match span.style {
+                SpanStyle::Role(TextRole::Danger) => return Self::Error,
+                SpanStyle::Role(TextRole::Warning) => return Self::Warn,
+                SpanStyle::Role(TextRole::Info) => return Self::Info,
+                SpanStyle::Role(TextRole::Muted) => {
+                    let bare = span
+                        .text
+                        .trim_matches(|ch: char| !ch.is_ascii_alphabetic())
+                        .to_ascii_uppercase();
+                    match bare.as_str() {
+                        "DEBUG" => return Self::Debug,
+                        "TRACE" => return Self::Trace,
+                        _ => {}
+                    }
+                }
+                _ => {}
+            }
+        }
+        for word in line.split_whitespace().take(3) {
+            let token = word
+                .trim_start_matches("level=")
+                .trim_matches(|ch: char| !ch.is_ascii_alphabetic())
+                .to_ascii_lowercase();
+            match token.as_str() {
+                "trace" => return Self::Trace,
+                "debug" | "dbg" => return Self::Debug,
+                "info" => return Self::Info,
+                "warn" | "warning" => return Self::Warn,
+                "error" | "err" | "fatal" | "panic" => return Self::Error,
+                _ => {}
+            }
+        }
+        match classify_line(line) {
+            LogLane::Stderr => Self::Error,
+            _ => Self::Info,
+        }
+    }
+}
+
+/// A row in the folded log view.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FoldedLogRow {
+    Line {
+        index: usize,
+        text: String,
+    },
+    /// Consecutive lines below the minimum level, starting at `start`.
+    Fold {
+        start: usize,
+        hidden: usize,
+        span_id: u64,
+    },
+}
+
+impl FoldedLogRow {
+    #[must_use]
+    pub fn text(&self) -> String {
+        match self {
+            Self::Line { text, .. } => text.clone(),
+            Self::Fold { hidden, .. } => {
+                format!("… {hidden} line{} …", if *hidden == 1 { "" } else { "s" })
+            }
+        }
+    }
+
+    fn covers(&self, index: usize) -> bool {
+        match self {
+            Self::Line { index: line, .. } => *line == index,
+            Self::Fold { start, hidden, .. } => (*start..start + hidden).contains(&index),
+        }
+    }
+}
+
+/// A run of lines below the minimum level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct FoldSpan {
+    start: usize,
+    end: usize,
+    id: u64,
+}
+
+/// Identify a fold by its first hidden line and the line above it, so an
+/// expanded fold stays expanded as the tail window shifts.
+fn fold_span_id(lines: &[String], start: usize) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    start
+        .checked_sub(1)
+        .and_then(|prev| lines.get(prev))
+        .hash(&mut hasher);
+    lines.get(start).hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Minimum-level filter plus the set of folds the operator has expanded.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LevelFold {
+    min_level: LogLevel,
+    expanded: BTreeSet<u64>,
+}
+
+impl LevelFold {
+    #[must_use]
+    pub fn min_level(&self) -> LogLevel {
+        self.min_level
+    }
+
+    fn spans(&self, lines: &[String]) -> Vec<FoldSpan> {
+        let mut spans = Vec::new();
+        let mut index = 0;
+        while index < lines.len() {
+            if LogLevel::classify(&lines[index]) >= self.min_level {
+                index += 1;
+                continue;
+            }
+            let start = index;
+            while index < lines.len() && LogLevel::classify(&lines[index]) < self.min_level {
+                index += 1;
+            }
+            spans.push(FoldSpan {
+                start,
+                end: index,
+                id: fold_span_id(lines, start),
+            });
+        }
+        spans
+    }
+
+    #[must_use]
+    pub fn rows(&self, lines: &[String]) -> Vec<FoldedLogRow> {
+        let mut rows = Vec::with_capacity(lines.len());
+        let mut index = 0;
+        for span in self.spans(lines) {
+            rows.extend((index..span.start).map(|line| FoldedLogRow::Line {
+                index: line,
+                text: lines[line].clone(),
+            }));
+            if self.expanded.contains(&span.id) {
+                rows.extend((span.start..span.end).map(|line| FoldedLogRow::Line {
+                    index: line,
+                    text: lines[line].clone(),
+                }));
+            } else {
+                rows.push(FoldedLogRow::Fold {
+                    start: span.start,
+                    hidden: span.end - span.start,
+                    span_id: span.id,
+                });
+            }
+            index = span.end;
+        }
+        rows.extend((index..lines.len()).map(|line| FoldedLogRow::Line {
+            index: line,
+            text: lines[line].clone(),
+        }));
+        rows
+    }
+
+    /// Rows rendered as display text, for panes that draw plain lines.
+    #[must_use]
+    pub fn display_lines(&self, lines: &[String]) -> Vec<String> {
+        if self.min_level == LogLevel::Trace {
+            return lines.to_vec();
+        }
+        self.rows(lines).iter().map(FoldedLogRow::text).collect()
+    }
+
+    /// Expand or collapse the fold with the given span id.
+    pub fn toggle_fold(&mut self, span_id: u64) -> bool {
+        if !self.expanded.remove(&span_id) {
+            self.expanded.insert(span_id);
+        }
+        self.expanded.contains(&span_id)
+    }
+
+    /// Toggle the nearest fold at or above the bottom of the current view.
+    /// Returns the expanded state and a scroll that keeps the bottom line in
+    /// place, or `None` when no fold is in reach.
+    pub fn toggle_fold_at(&mut self, lines: &[String], log_scroll: i32) -> Option<(bool, i32)> {
+        let anchor = self.anchor_line(lines, log_scroll)?;
+        let span = self
+            .spans(lines)
+            .into_iter()
+            .rev()
+            .find(|span| span.start <= anchor)?;
+        let expanded = self.toggle_fold(span.id);
+        Some((expanded, self.scroll_for_anchor(lines, anchor)))
+    }
+
+    /// Change the level and return a bottom-relative scroll that keeps the
+    /// line at the bottom of the current view (or the fold hiding it) in place.
+    pub fn set_min_level(&mut self, level: LogLevel, lines: &[String], log_scroll: i32) -> i32 {
+        let anchor = self.anchor_line(lines, log_scroll).unwrap_or(0);
+        self.min_level = level;
+        self.expanded.clear();
+        self.scroll_for_anchor(lines, anchor)
+    }
+
+    pub fn cycle_min_level(&mut self, lines: &[String], log_scroll: i32) -> i32 {
+        let idx = LogLevel::ORDER
+            .iter()
+            .position(|level| *level == self.min_level)
+            .unwrap_or(0);
+        let next = LogLevel::ORDER[(idx + 1) % LogLevel::ORDER.len()];
+        self.set_min_level(next, lines, log_scroll)
+    }
+
+    fn anchor_line(&self, lines: &[String], log_scroll: i32) -> Option<usize> {
+        let rows = self.rows(lines);
+        let anchor_row = rows
+            .len()
+            .saturating_sub(1)
+            .saturating_sub(log_scroll.max(0) as usize);
+        match rows.get(anchor_row)? {
+            FoldedLogRow::Line { index, .. } => Some(*index),
+            FoldedLogRow::Fold { start, .. } => Some(*start),
+        }
+    }
+
+    fn scroll_for_anchor(&self, lines: &[String], anchor: usize) -> i32 {
+        let rows = self.rows(lines);
+        rows.iter()
+            .position(|row| row.covers(anchor))
+            .map_or(0, |row| (rows.len() - 1 - row) as i32)
+    }
+}
+
 /// Compute start/end window from total lines, viewport height, and scroll offset.
 #[must_use]
 pub fn log_window_bounds(total_lines: i32, available: i32, mut scroll: i32) -> (i32, i32, i32) {
@@ -223,7 +495,10 @@ fn max_i32(a: i32, b: i32) -> i32 {
 
 #[cfg(test)]
 mod tests {
-    use super::{log_window_bounds, LogLayer, LogSource, LogsTabState, MainTab, UiMode};
+    use super::{
+        log_window_bounds, FoldedLogRow, LevelFold, LogLayer, LogLevel, LogSource, LogsTabState,
+        MainTab, UiMode,
+    };
 
     #[test]
     fn cycle_source_matches_go_order() {
@@ -317,4 +592,91 @@ mod tests {
         assert_eq!(state.source_label(), "latest-run");
         assert_eq!(state.layer_label(), "tools");
     }
+
+    #[test]
+    fn warn_level_folds_info_and_debug_lines() {
+        let lines = [
+            "INFO starting loop",
+            "DEBUG fetched 3 tasks",
+            "DEBUG claimed task-1",
+            "WARN retrying harness call",
+            "[info] harness ok",
+            "ERROR: task-1 failed",
+            "debug: cleanup",
+        ]
+        .iter()
+        .map(|line| (*line).to_owned())
+        .collect::<Vec<_>>();
+
+        let mut fold = LevelFold::default();
+        assert_eq!(fold.display_lines(&lines), lines);
+        let scroll = fold.set_min_level(LogLevel::Warn, &lines, 0);
+        assert_eq!(scroll, 0);
+        assert_eq!(
+            fold.display_lines(&lines),
+            vec![
+                "… 3 lines …",
+                "WARN retrying harness call",
+                "… 1 line …",
+                "ERROR: task-1 failed",
+                "… 1 line …",
+            ]
+        );
+
+        let Some(FoldedLogRow::Fold { span_id, .. }) = fold.rows(&lines).first().cloned() else {
+            panic!("expected leading fold");
+        };
+        assert!(fold.toggle_fold(span_id));
+        assert_eq!(
+            fold.rows(&lines)[1],
+            FoldedLogRow::Line {
+                index: 1,
+                text: "DEBUG fetched 3 tasks".to_owned(),
+            }
+        );
+    }
+
+    #[test]
+    fn expanded_fold_survives_tail_window_shift() {
+        let lines = ["INFO boot", "WARN a", "DEBUG b", "DEBUG c", "ERROR d"]
+            .iter()
+            .map(|line| (*line).to_owned())
+            .collect::<Vec<_>>();
+        let mut fold = LevelFold::default();
+        fold.set_min_level(LogLevel::Warn, &lines, 0);
+        // Bottom of the view is "ERROR d"; the nearest fold above is b..c.
+        assert_eq!(fold.toggle_fold_at(&lines, 0), Some((true, 0)));
+        assert_eq!(
+            fold.display_lines(&lines),
+            vec!["… 1 line …", "WARN a", "DEBUG b", "DEBUG c", "ERROR d"]
+        );
+
+        // The oldest line drops out of the tail; the b..c fold stays open.
+        let shifted = lines[1..].to_vec();
+        assert_eq!(
+            fold.display_lines(&shifted),
+            vec!["WARN a", "DEBUG b", "DEBUG c", "ERROR d"]
+        );
+
+        assert_eq!(fold.toggle_fold_at(&shifted, 0), Some((false, 0)));
+        assert_eq!(
+            fold.display_lines(&shifted),
+            vec!["WARN a", "… 2 lines …", "ERROR d"]
+        );
+    }
+
+    #[test]
+    fn changing_level_keeps_scroll_anchor_near_current_line() {
+        let lines = [
+            "WARN a", "DEBUG b", "DEBUG c", "ERROR d", "DEBUG e", "INFO f",
+        ]
+        .iter()
+        .map(|line| (*line).to_owned())
+        .collect::<Vec<_>>();
+        let mut fold = LevelFold::default();
+        // Bottom of the view sits on "ERROR d" (two lines up from the end).
+        let scroll = fold.set_min_level(LogLevel::Warn, &lines, 2);
+        let rows = fold.rows(&lines);
+        assert_eq!(rows[rows.len() - 1 - scroll as usize].text(), "ERROR d");
+    }
 }
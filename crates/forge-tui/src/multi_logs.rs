@@ -501,16 +501,17 @@ impl App {
             width,
         );
         draw_text_on_bg(&mut frame, 0, 0, &header, pal.accent, pal.background);
-        let subheader =
-            truncate(
-                &format!(
-                "layer:{}  toggle:C  sync:u/d ctrl+u/d  hints: same={} diff={} left={} right={}",
+        let subheader = truncate(
+            &format!(
+                "layer:{}  toggle:C  sync:ctrl+u/d  hints: same={} diff={} left={} right={}",
                 self.log_layer().label(),
-                diff_summary.equal, diff_summary.different,
-                diff_summary.left_only, diff_summary.right_only,
+                diff_summary.equal,
+                diff_summary.different,
+                diff_summary.left_only,
+                diff_summary.right_only,
             ),
-                width,
-            );
+            width,
+        );
         draw_text_on_bg(&mut frame, 0, 1, &subheader, pal.text_muted, pal.background);
         draw_text_on_bg(
             &mut frame,
@@ -587,6 +588,9 @@ impl App {
         if self.is_pinned(&view.id) {
             title.push_str(" [PIN]");
         }
+        if self.has_loop_note(&view.id) {
+            title.push_str(" [NOTE]");
+        }
 
         let inner = frame.draw_panel(
             Rect {
@@ -718,7 +722,7 @@ mod tests {
     use crate::layouts::layout_index_for;
     use crate::theme::{resolve_palette, resolve_palette_colors};
     use forge_cli::logs::{render_lines_for_layer, LogRenderLayer};
-    use forge_ftui_adapter::input::{InputEvent, Key, KeyEvent};
+    use forge_ftui_adapter::input::{InputEvent, Key, KeyEvent, Modifiers};
     use std::collections::HashMap;
 
     fn key(k: Key) -> InputEvent {
@@ -1033,7 +1037,7 @@ mod tests {
             "expected paired loops in header: {header}"
         );
         assert!(
-            subheader.contains("sync:u/d"),
+            subheader.contains("sync:ctrl+u/d"),
             "expected compare controls in subheader: {subheader}"
         );
     }
@@ -1063,10 +1067,24 @@ mod tests {
         app.set_multi_logs(logs);
         app.update(key(Key::Char('C')));
         assert_eq!(app.log_scroll(), 0);
-        app.update(key(Key::Char('u')));
+        app.update(InputEvent::Key(KeyEvent {
+            key: Key::Char('u'),
+            modifiers: Modifiers {
+                shift: false,
+                ctrl: true,
+                alt: false,
+            },
+        }));
         let after_up = app.log_scroll();
         assert!(after_up > 0, "expected compare scroll to move up");
-        app.update(key(Key::Char('d')));
+        app.update(InputEvent::Key(KeyEvent {
+            key: Key::Char('d'),
+            modifiers: Modifiers {
+                shift: false,
+                ctrl: true,
+                alt: false,
+            },
+        }));
         assert!(
             app.log_scroll() < after_up,
             "expected compare scroll to move back down"
@@ -1178,6 +1196,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn mini_pane_shows_note_marker() {
+        let mut app = multi_app(1);
+        app.set_loop_note("loop-0", "check auth", 100);
+        let view = &app.filtered()[0].clone();
+        let frame = app.render_mini_log_pane(view, 40, 10, &test_pal());
+        let snapshot = frame.snapshot();
+        assert!(
+            snapshot.contains("[NOTE]"),
+            "expected [NOTE] marker:\n{snapshot}"
+        );
+    }
+
     #[test]
     fn mini_pane_has_bordered_panel() {
         let app = multi_app(1);
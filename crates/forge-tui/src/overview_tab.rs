@@ -3,11 +3,14 @@
 //! Mirrors the Go `internal/looptui` overview pane content: loop metadata
 //! lines + a small run-status snapshot.
 
+use forge_core::event::EventType;
 use forge_ftui_adapter::render::{Rect, RenderFrame, TermColor, TextRole};
 use forge_ftui_adapter::widgets::BorderStyle;
 
 use crate::app::{LoopView, RunView};
 use crate::hero_widgets::{build_fleet_snapshot, FleetSnapshot};
+use crate::layouts::{DashboardPanel, ResponsiveThresholds};
+use crate::polling_pipeline::{PollingConfig, RefreshController, RefreshMode};
 use crate::smart_loop_clustering::{cluster_loops_by_domain, compact_domain_summary};
 use crate::theme::ResolvedPalette;
 
@@ -20,6 +23,53 @@ pub struct OverviewLine {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct OverviewPaneOptions {
     pub reserve_next_action_slot: bool,
+    /// Responsive column count; 0 or 1 stacks panels vertically.
+    pub columns: usize,
+}
+
+pub const OVERVIEW_NEXT_ACTION_PANEL: &str = "next-action";
+
+/// Overview panels for responsive layout; the next-action slot is optional
+/// and is dropped first on small terminals.
+#[must_use]
+pub fn overview_dashboard_panels() -> Vec<DashboardPanel> {
+    [
+        ("fleet", true),
+        ("loop-detail", true),
+        ("run-snapshot", true),
+        ("domains", true),
+        (OVERVIEW_NEXT_ACTION_PANEL, false),
+    ]
+    .into_iter()
+    .map(|(id, essential)| DashboardPanel {
+        id: id.to_owned(),
+        essential,
+    })
+    .collect()
+}
+
+/// Whether an event changes anything the overview shows (loop/agent state,
+/// queue depth, error/warning counts). Used by event-driven refresh.
+#[must_use]
+pub fn overview_event_is_relevant(event_type: EventType) -> bool {
+    matches!(
+        event_type,
+        EventType::LoopStateChanged
+            | EventType::LoopStopRequested
+            | EventType::AgentStarted
+            | EventType::AgentStopped
+            | EventType::AgentStateChanged
+            | EventType::MessageQueued
+            | EventType::MessageDispatched
+            | EventType::Error
+            | EventType::Warning
+    )
+}
+
+/// Refresh controller for the overview tab in the operator's chosen mode.
+#[must_use]
+pub fn overview_refresh_controller(mode: RefreshMode, config: PollingConfig) -> RefreshController {
+    RefreshController::new(mode, config, "overview")
 }
 
 fn push_unique_action(actions: &mut Vec<String>, text: &str) {
@@ -292,6 +342,12 @@ pub fn overview_pane_lines(
             format!("Last Error: {}", loop_view.last_error),
         );
     }
+    if !loop_view.stop_cause.trim().is_empty() {
+        push(
+            TextRole::Primary,
+            format!("Stop Cause: {}", loop_view.stop_cause),
+        );
+    }
 
     let counts = count_runs(run_history);
     push(TextRole::Primary, String::new());
@@ -445,16 +501,154 @@ pub fn render_overview_paneled_with_options(
         return;
     };
 
+    // -- Responsive columns: detail, run snapshot, domains and next action
+    // each go to the currently shortest column instead of stacking. --
+    let min_column_width = ResponsiveThresholds::default().min_column_width.max(1) as usize;
+    let columns = options.columns.min(rest.width / min_column_width).max(1);
+    if columns > 1 {
+        render_overview_columns(
+            frame,
+            rest,
+            columns,
+            loops,
+            loop_view,
+            run_history,
+            selected_run,
+            pal,
+            options,
+        );
+        return;
+    }
+
     // -- Loop detail panel --
-    let detail_fields = build_detail_fields(loop_view, pal);
-    let detail_h = (detail_fields.len() + 2).min(rest.height); // +2 for borders
+    let detail_h = (loop_view_detail_rows(loop_view, pal) + 2).min(rest.height); // +2 for borders
     let (detail_rect, rest2) = rest.split_vertical(detail_h);
+    draw_detail_panel(frame, detail_rect, loop_view, pal);
+
+    // -- Run snapshot panel --
+    if rest2.height >= 4 {
+        let has_domain_panel = rest2.height >= 9;
+        let has_next_action_slot = options.reserve_next_action_slot
+            && rest2.height >= if has_domain_panel { 13 } else { 9 };
+        let reserved_rows =
+            if has_domain_panel { 4 } else { 0 } + if has_next_action_slot { 4 } else { 0 };
+        let snap_h = 4usize.min(rest2.height.saturating_sub(reserved_rows));
+        let (snap_rect, rest3) = rest2.split_vertical(snap_h);
+        draw_run_snapshot_panel(frame, snap_rect, run_history, selected_run, pal);
+
+        let mut rest = rest3;
+
+        // -- Work domain panel (smart loop clustering) --
+        if has_domain_panel {
+            let domain_h = 4usize.min(rest.height);
+            let (domain_rect, rest_after_domain) = rest.split_vertical(domain_h);
+            rest = rest_after_domain;
+            draw_domain_panel(frame, domain_rect, loops, pal);
+        }
+
+        if has_next_action_slot && rest.height >= 4 {
+            let slot_h = 4usize.min(rest.height);
+            let (slot_rect, rest_after_slot) = rest.split_vertical(slot_h);
+            rest = rest_after_slot;
+            draw_next_action_panel(frame, slot_rect, loop_view, run_history, selected_run, pal);
+        }
+
+        draw_workflow_hint(frame, rest, pal);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OverviewPanel {
+    Detail,
+    RunSnapshot,
+    Domains,
+    NextAction,
+}
+
+#[allow(clippy::too_many_arguments)]
+fn render_overview_columns(
+    frame: &mut RenderFrame,
+    area: Rect,
+    columns: usize,
+    loops: &[LoopView],
+    loop_view: &LoopView,
+    run_history: &[RunView],
+    selected_run: usize,
+    pal: &ResolvedPalette,
+    options: OverviewPaneOptions,
+) {
+    let (body, hint_row) = area.split_vertical(area.height.saturating_sub(1));
+    let mut panels = vec![
+        OverviewPanel::Detail,
+        OverviewPanel::RunSnapshot,
+        OverviewPanel::Domains,
+    ];
+    if options.reserve_next_action_slot {
+        panels.push(OverviewPanel::NextAction);
+    }
+
+    let column_width = body.width / columns;
+    let mut column_rects = Vec::with_capacity(columns);
+    let mut remaining = body;
+    for column in 0..columns {
+        let width = if column + 1 == columns {
+            remaining.width
+        } else {
+            column_width
+        };
+        let (column_rect, rest) = remaining.split_horizontal(width);
+        remaining = rest;
+        column_rects.push(column_rect);
+    }
+
+    for panel in panels {
+        let height = match panel {
+            OverviewPanel::Detail => loop_view_detail_rows(loop_view, pal) + 2,
+            _ => 4,
+        };
+        // Shortest column = the one with the most room left; ties go left.
+        let Some(column_rect) = column_rects
+            .iter_mut()
+            .rev()
+            .max_by_key(|rect| rect.height)
+            .filter(|rect| rect.height >= 4)
+        else {
+            break;
+        };
+        let (rect, below) = column_rect.split_vertical(height.min(column_rect.height));
+        *column_rect = below;
+        match panel {
+            OverviewPanel::Detail => draw_detail_panel(frame, rect, loop_view, pal),
+            OverviewPanel::RunSnapshot => {
+                draw_run_snapshot_panel(frame, rect, run_history, selected_run, pal);
+            }
+            OverviewPanel::Domains => draw_domain_panel(frame, rect, loops, pal),
+            OverviewPanel::NextAction => {
+                draw_next_action_panel(frame, rect, loop_view, run_history, selected_run, pal);
+            }
+        }
+    }
+
+    draw_workflow_hint(frame, hint_row, pal);
+}
+
+fn loop_view_detail_rows(loop_view: &LoopView, pal: &ResolvedPalette) -> usize {
+    build_detail_fields(loop_view, pal).len()
+}
+
+fn draw_detail_panel(
+    frame: &mut RenderFrame,
+    rect: Rect,
+    loop_view: &LoopView,
+    pal: &ResolvedPalette,
+) {
+    let detail_fields = build_detail_fields(loop_view, pal);
     let detail_title = format!(
         "Loop: {}",
         display_name(&loop_view.name, &loop_display_id(loop_view))
     );
     let detail_inner = frame.draw_panel(
-        detail_rect,
+        rect,
         &detail_title,
         BorderStyle::Rounded,
         pal.accent,
@@ -474,156 +668,158 @@ pub fn render_overview_paneled_with_options(
             pal.panel,
         );
     }
+}
 
-    // -- Run snapshot panel --
-    if rest2.height >= 4 {
-        let counts = count_runs(run_history);
-        let has_domain_panel = rest2.height >= 9;
-        let has_next_action_slot = options.reserve_next_action_slot
-            && rest2.height >= if has_domain_panel { 13 } else { 9 };
-        let reserved_rows =
-            if has_domain_panel { 4 } else { 0 } + if has_next_action_slot { 4 } else { 0 };
-        let snap_h = 4usize.min(rest2.height.saturating_sub(reserved_rows));
-        let (snap_rect, rest3) = rest2.split_vertical(snap_h);
-        let snap_inner = frame.draw_panel(
-            snap_rect,
-            "Run Snapshot",
-            BorderStyle::Rounded,
-            pal.border,
-            pal.panel,
-        );
-        let summary = format!(
-            "total={}  success={}  error={}  killed={}  running={}",
-            run_history.len(),
-            counts.success,
-            counts.error,
-            counts.killed,
-            counts.running,
+fn draw_run_snapshot_panel(
+    frame: &mut RenderFrame,
+    rect: Rect,
+    run_history: &[RunView],
+    selected_run: usize,
+    pal: &ResolvedPalette,
+) {
+    let counts = count_runs(run_history);
+    let snap_inner = frame.draw_panel(
+        rect,
+        "Run Snapshot",
+        BorderStyle::Rounded,
+        pal.border,
+        pal.panel,
+    );
+    let summary = format!(
+        "total={}  success={}  error={}  killed={}  running={}",
+        run_history.len(),
+        counts.success,
+        counts.error,
+        counts.killed,
+        counts.running,
+    );
+    draw_text_on_bg(
+        frame,
+        snap_inner.x,
+        snap_inner.y,
+        &truncate_line(&summary, snap_inner.width),
+        pal.text,
+        pal.panel,
+    );
+    if !run_history.is_empty() && snap_inner.height > 1 {
+        let idx = selected_run.min(run_history.len().saturating_sub(1));
+        let run = &run_history[idx];
+        let latest = format!(
+            "latest={}  status={}  exit={}  duration={}",
+            short_run_id(&run.id),
+            run.status.trim().to_ascii_uppercase(),
+            run_exit_code(run),
+            format_run_duration(run),
         );
         draw_text_on_bg(
             frame,
             snap_inner.x,
-            snap_inner.y,
-            &truncate_line(&summary, snap_inner.width),
-            pal.text,
+            snap_inner.y + 1,
+            &truncate_line(&latest, snap_inner.width),
+            pal.text_muted,
             pal.panel,
         );
-        if !run_history.is_empty() && snap_inner.height > 1 {
-            let idx = selected_run.min(run_history.len().saturating_sub(1));
-            let run = &run_history[idx];
-            let latest = format!(
-                "latest={}  status={}  exit={}  duration={}",
-                short_run_id(&run.id),
-                run.status.trim().to_ascii_uppercase(),
-                run_exit_code(run),
-                format_run_duration(run),
-            );
-            draw_text_on_bg(
-                frame,
-                snap_inner.x,
-                snap_inner.y + 1,
-                &truncate_line(&latest, snap_inner.width),
-                pal.text_muted,
-                pal.panel,
-            );
-        }
-
-        let mut rest = rest3;
+    }
+}
 
-        // -- Work domain panel (smart loop clustering) --
-        if has_domain_panel {
-            let domain_h = 4usize.min(rest.height);
-            let (domain_rect, rest_after_domain) = rest.split_vertical(domain_h);
-            rest = rest_after_domain;
-            let domain_inner = frame.draw_panel(
-                domain_rect,
-                "Work Domains (Auto)",
-                BorderStyle::Rounded,
-                pal.border,
-                pal.panel,
-            );
+fn draw_domain_panel(
+    frame: &mut RenderFrame,
+    rect: Rect,
+    loops: &[LoopView],
+    pal: &ResolvedPalette,
+) {
+    let domain_inner = frame.draw_panel(
+        rect,
+        "Work Domains (Auto)",
+        BorderStyle::Rounded,
+        pal.border,
+        pal.panel,
+    );
 
-            let groups = cluster_loops_by_domain(loops);
-            let summary = compact_domain_summary(&groups, 2);
-            if domain_inner.height > 0 {
-                let line = format!("top: {summary}");
-                draw_text_on_bg(
-                    frame,
-                    domain_inner.x,
-                    domain_inner.y,
-                    &truncate_line(&line, domain_inner.width),
-                    pal.text,
-                    pal.panel,
-                );
-            }
-            if domain_inner.height > 1 {
-                let groups_line = format!("groups:{}  loops:{}", groups.len(), loops.len());
-                draw_text_on_bg(
-                    frame,
-                    domain_inner.x,
-                    domain_inner.y + 1,
-                    &truncate_line(&groups_line, domain_inner.width),
-                    pal.text_muted,
-                    pal.panel,
-                );
-            }
-        }
+    let groups = cluster_loops_by_domain(loops);
+    let summary = compact_domain_summary(&groups, 2);
+    if domain_inner.height > 0 {
+        let line = format!("top: {summary}");
+        draw_text_on_bg(
+            frame,
+            domain_inner.x,
+            domain_inner.y,
+            &truncate_line(&line, domain_inner.width),
+            pal.text,
+            pal.panel,
+        );
+    }
+    if domain_inner.height > 1 {
+        let groups_line = format!("groups:{}  loops:{}", groups.len(), loops.len());
+        draw_text_on_bg(
+            frame,
+            domain_inner.x,
+            domain_inner.y + 1,
+            &truncate_line(&groups_line, domain_inner.width),
+            pal.text_muted,
+            pal.panel,
+        );
+    }
+}
 
-        if has_next_action_slot && rest.height >= 4 {
-            let slot_h = 4usize.min(rest.height);
-            let (slot_rect, rest_after_slot) = rest.split_vertical(slot_h);
-            rest = rest_after_slot;
-            let actions = build_next_action_lines(loop_view, run_history, selected_run);
-            let slot_inner = frame.draw_panel(
-                slot_rect,
-                "Next Action",
-                BorderStyle::Rounded,
-                pal.border,
-                pal.panel,
-            );
-            if slot_inner.height > 0 {
-                draw_text_on_bg(
-                    frame,
-                    slot_inner.x,
-                    slot_inner.y,
-                    &truncate_line(
-                        actions
-                            .first()
-                            .map_or("[2] Logs: inspect selected loop", String::as_str),
-                        slot_inner.width,
-                    ),
-                    pal.accent,
-                    pal.panel,
-                );
-            }
-            if slot_inner.height > 1 {
-                draw_text_on_bg(
-                    frame,
-                    slot_inner.x,
-                    slot_inner.y + 1,
-                    &truncate_line(
-                        actions
-                            .get(1)
-                            .map_or("[3] Runs: inspect latest run context", String::as_str),
-                        slot_inner.width,
-                    ),
-                    pal.text_muted,
-                    pal.panel,
-                );
-            }
-        }
+fn draw_next_action_panel(
+    frame: &mut RenderFrame,
+    rect: Rect,
+    loop_view: &LoopView,
+    run_history: &[RunView],
+    selected_run: usize,
+    pal: &ResolvedPalette,
+) {
+    let actions = build_next_action_lines(loop_view, run_history, selected_run);
+    let slot_inner = frame.draw_panel(
+        rect,
+        "Next Action",
+        BorderStyle::Rounded,
+        pal.border,
+        pal.panel,
+    );
+    if slot_inner.height > 0 {
+        draw_text_on_bg(
+            frame,
+            slot_inner.x,
+            slot_inner.y,
+            &truncate_line(
+                actions
+                    .first()
+                    .map_or("[2] Logs: inspect selected loop", String::as_str),
+                slot_inner.width,
+            ),
+            pal.accent,
+            pal.panel,
+        );
+    }
+    if slot_inner.height > 1 {
+        draw_text_on_bg(
+            frame,
+            slot_inner.x,
+            slot_inner.y + 1,
+            &truncate_line(
+                actions
+                    .get(1)
+                    .map_or("[3] Runs: inspect latest run context", String::as_str),
+                slot_inner.width,
+            ),
+            pal.text_muted,
+            pal.panel,
+        );
+    }
+}
 
-        // -- Workflow hint --
-        if rest.height >= 1 {
-            draw_text_on_bg(
-                frame,
-                rest.x + 1,
-                rest.y,
-                "Workflow: 2=Logs (deep scroll) | 3=Runs | 4=Multi Logs",
-                pal.text_muted,
-                pal.background,
-            );
-        }
+fn draw_workflow_hint(frame: &mut RenderFrame, rect: Rect, pal: &ResolvedPalette) {
+    if rect.height >= 1 {
+        draw_text_on_bg(
+            frame,
+            rect.x + 1,
+            rect.y,
+            "Workflow: 2=Logs (deep scroll) | 3=Runs | 4=Multi Logs",
+            pal.text_muted,
+            pal.background,
+        );
     }
 }
 
@@ -768,6 +964,9 @@ fn build_detail_fields(lv: &LoopView, pal: &ResolvedPalette) -> Vec<(String, Ter
     if !lv.last_error.trim().is_empty() {
         fields.push((format!("Last Error: {}", lv.last_error), pal.error));
     }
+    if !lv.stop_cause.trim().is_empty() {
+        fields.push((format!("Stop Cause: {}", lv.stop_cause), pal.warning));
+    }
     fields
 }
 
@@ -825,6 +1024,7 @@ mod tests {
             max_runtime_seconds: 3600,
             max_iterations: 0,
             last_error: "boom".to_owned(),
+            stop_cause: String::new(),
             pool_name: "".to_owned(),
             pool_id: "pool-1".to_owned(),
             profile_name: "dev".to_owned(),
@@ -873,6 +1073,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn stopped_loop_shows_stop_cause() {
+        let view = LoopView {
+            id: "loop-1".to_owned(),
+            name: "demo-loop".to_owned(),
+            state: "stopped".to_owned(),
+            stop_cause: "deploy freeze".to_owned(),
+            ..LoopView::default()
+        };
+
+        let lines = overview_pane_lines(Some(&view), &[], 0, 60, 20);
+        assert!(lines
+            .iter()
+            .any(|line| line.text.trim() == "Stop Cause: deploy freeze"));
+
+        let pal = crate::theme::resolve_palette_colors(&crate::theme::DEFAULT_PALETTE);
+        let fields = build_detail_fields(&view, &pal);
+        assert!(fields
+            .iter()
+            .any(|(text, color)| text == "Stop Cause: deploy freeze" && *color == pal.warning));
+    }
+
     #[test]
     fn paneled_overview_shows_work_domains_when_space_allows() {
         let theme = crate::default_theme();
@@ -1031,6 +1253,7 @@ mod tests {
             false,
             OverviewPaneOptions {
                 reserve_next_action_slot: true,
+                ..OverviewPaneOptions::default()
             },
         );
 
@@ -1039,6 +1262,53 @@ mod tests {
         assert!(snapshot.contains("[2] Logs: verify healthy output stream"));
     }
 
+    #[test]
+    fn paneled_overview_places_panels_side_by_side_in_columns() {
+        let theme = crate::default_theme();
+        let mut frame = RenderFrame::new(
+            FrameSize {
+                width: 120,
+                height: 34,
+            },
+            theme,
+        );
+        let loops = vec![LoopView {
+            id: "loop-a".to_owned(),
+            short_id: "a".to_owned(),
+            name: "auth-worker-a".to_owned(),
+            ..LoopView::default()
+        }];
+        let pal = crate::theme::resolve_palette_colors(&crate::theme::DEFAULT_PALETTE);
+
+        render_overview_paneled_with_options(
+            &mut frame,
+            &loops,
+            Some(&loops[0]),
+            &[],
+            0,
+            &pal,
+            Rect {
+                x: 0,
+                y: 0,
+                width: 120,
+                height: 34,
+            },
+            false,
+            OverviewPaneOptions {
+                reserve_next_action_slot: false,
+                columns: 2,
+            },
+        );
+
+        let snapshot = frame.snapshot();
+        let row = snapshot
+            .lines()
+            .find(|line| line.contains("Loop: auth-worker-a"))
+            .unwrap_or_else(|| panic!("detail panel missing:\n{snapshot}"));
+        assert!(row.contains("Run Snapshot"), "row: {row}");
+        assert!(!row.contains("Work Domains"), "row: {row}");
+    }
+
     #[test]
     fn paneled_overview_next_action_slot_prioritizes_error_remediation() {
         let theme = crate::default_theme();
@@ -1084,6 +1354,7 @@ mod tests {
             false,
             OverviewPaneOptions {
                 reserve_next_action_slot: true,
+                ..OverviewPaneOptions::default()
             },
         );
 
@@ -1091,4 +1362,33 @@ mod tests {
         assert!(snapshot.contains("[2] Logs: inspect error lines and root cause"));
         assert!(snapshot.contains("[3] Runs: inspect latest run output"));
     }
+
+    #[test]
+    fn event_driven_refresh_recomputes_only_on_relevant_events() {
+        let mut refresh =
+            overview_refresh_controller(RefreshMode::EventDriven, PollingConfig::default());
+        assert_eq!(
+            refresh.poll(10_000, 0),
+            Some(crate::polling_pipeline::RefreshTrigger::Initial)
+        );
+        assert_eq!(refresh.poll(10_001, 0), None);
+
+        let irrelevant = [EventType::PortAllocated, EventType::NodeUpdated];
+        assert!(!refresh.observe_events(&irrelevant, "evt-2", |event| {
+            overview_event_is_relevant(*event)
+        }));
+        assert_eq!(refresh.poll(20_000, 0), None);
+        assert_eq!(refresh.event_cursor(), "evt-2");
+
+        let relevant = [EventType::AccountRotated, EventType::LoopStateChanged];
+        assert!(refresh.observe_events(&relevant, "evt-4", |event| {
+            overview_event_is_relevant(*event)
+        }));
+        assert_eq!(
+            refresh.poll(20_001, 0),
+            Some(crate::polling_pipeline::RefreshTrigger::Event)
+        );
+        assert_eq!(refresh.poll(20_002, 0), None);
+        assert_eq!(refresh.recompute_count(), 2);
+    }
 }
@@ -1,8 +1,10 @@
 //! Performance benchmark suite + SLO gate evaluation helpers for Forge TUI views.
 
-use std::collections::BTreeSet;
-use std::time::Instant;
+use std::collections::{BTreeSet, VecDeque};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
 
+use forge_cli::doctor::{CheckStatus, DoctorCheck, TUI_PERFORMANCE_REPORT_FILE};
 use serde_json::{Map, Value};
 
 pub const PERF_GATE_SCHEMA_VERSION: u32 = 1;
@@ -338,6 +340,311 @@ pub fn restore_benchmark_suite(raw: &str) -> BenchmarkSuiteLoadOutcome {
     }
 }
 
+pub const RUNTIME_GATE_WINDOW: usize = 120;
+pub const RUNTIME_BUDGETS_ENV: &str = "FORGE_TUI_RUNTIME_BUDGETS";
+
+/// Runtime budgets file: `$FORGE_TUI_RUNTIME_BUDGETS`, else
+/// `~/.config/forge/tui-budgets.json`.
+#[must_use]
+pub fn user_runtime_budgets_path() -> Option<PathBuf> {
+    if let Some(path) = std::env::var_os(RUNTIME_BUDGETS_ENV).filter(|path| !path.is_empty()) {
+        return Some(PathBuf::from(path));
+    }
+    std::env::var_os("HOME")
+        .filter(|home| !home.is_empty())
+        .map(|home| {
+            PathBuf::from(home)
+                .join(".config")
+                .join("forge")
+                .join("tui-budgets.json")
+        })
+}
+
+/// Where gate results are left for `forge doctor`, under the forge data dir.
+#[must_use]
+pub fn doctor_report_path() -> Option<PathBuf> {
+    std::env::var_os("HOME")
+        .filter(|home| !home.is_empty())
+        .map(|home| {
+            PathBuf::from(home)
+                .join(".local")
+                .join("share")
+                .join("forge")
+                .join(TUI_PERFORMANCE_REPORT_FILE)
+        })
+}
+
+/// Whole milliseconds for a gate sample, saturating on overflow.
+#[must_use]
+pub fn gate_elapsed_ms(elapsed: Duration) -> u64 {
+    u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RuntimeGate {
+    FrameBuild,
+    PollLatency,
+    Render,
+}
+
+impl RuntimeGate {
+    pub const ALL: [Self; 3] = [Self::FrameBuild, Self::PollLatency, Self::Render];
+
+    #[must_use]
+    pub fn slug(self) -> &'static str {
+        match self {
+            Self::FrameBuild => "frame_build_ms",
+            Self::PollLatency => "poll_latency_ms",
+            Self::Render => "render_ms",
+        }
+    }
+
+    #[must_use]
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::FrameBuild => "frame build",
+            Self::PollLatency => "poll latency",
+            Self::Render => "render",
+        }
+    }
+}
+
+/// Per-gate p95 budgets for the live runtime, in milliseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RuntimeBudgets {
+    pub frame_build_ms: u64,
+    pub poll_latency_ms: u64,
+    pub render_ms: u64,
+}
+
+impl Default for RuntimeBudgets {
+    fn default() -> Self {
+        Self {
+            frame_build_ms: 16,
+            poll_latency_ms: 250,
+            render_ms: 33,
+        }
+    }
+}
+
+impl RuntimeBudgets {
+    #[must_use]
+    pub fn threshold_ms(&self, gate: RuntimeGate) -> u64 {
+        match gate {
+            RuntimeGate::FrameBuild => self.frame_build_ms,
+            RuntimeGate::PollLatency => self.poll_latency_ms,
+            RuntimeGate::Render => self.render_ms,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuntimeBudgetsLoadOutcome {
+    pub budgets: RuntimeBudgets,
+    pub warnings: Vec<String>,
+}
+
+/// Parse budget overrides from JSON such as `{"render_ms": 40}`.
+///
+/// Missing keys keep their defaults; invalid values are reported and ignored.
+#[must_use]
+pub fn restore_runtime_budgets(raw: &str) -> RuntimeBudgetsLoadOutcome {
+    let mut budgets = RuntimeBudgets::default();
+    let mut warnings = Vec::new();
+    let trimmed = raw.trim();
+    if trimmed.is_empty() {
+        return RuntimeBudgetsLoadOutcome { budgets, warnings };
+    }
+
+    let value = match serde_json::from_str::<Value>(trimmed) {
+        Ok(value) => value,
+        Err(err) => {
+            return RuntimeBudgetsLoadOutcome {
+                budgets,
+                warnings: vec![format!("invalid runtime budgets json ({err})")],
+            };
+        }
+    };
+    let Some(obj) = value.as_object() else {
+        return RuntimeBudgetsLoadOutcome {
+            budgets,
+            warnings: vec!["runtime budgets must be an object".to_owned()],
+        };
+    };
+
+    for (key, value) in obj {
+        let Some(gate) = RuntimeGate::ALL.into_iter().find(|gate| gate.slug() == key) else {
+            warnings.push(format!("unknown runtime budget '{key}' ignored"));
+            continue;
+        };
+        let Some(threshold) = value.as_u64().filter(|threshold| *threshold > 0) else {
+            warnings.push(format!("runtime budget '{key}' must be a positive integer"));
+            continue;
+        };
+        match gate {
+            RuntimeGate::FrameBuild => budgets.frame_build_ms = threshold,
+            RuntimeGate::PollLatency => budgets.poll_latency_ms = threshold,
+            RuntimeGate::Render => budgets.render_ms = threshold,
+        }
+    }
+
+    RuntimeBudgetsLoadOutcome { budgets, warnings }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GateResult {
+    pub gate: RuntimeGate,
+    pub measured_p95_ms: Option<u64>,
+    pub threshold_ms: u64,
+    pub samples: usize,
+    pub passed: bool,
+}
+
+/// Rolling measurements for the live runtime gates.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuntimeGateMonitor {
+    budgets: RuntimeBudgets,
+    window: usize,
+    frame_build_ms: VecDeque<u64>,
+    poll_latency_ms: VecDeque<u64>,
+    render_ms: VecDeque<u64>,
+}
+
+impl Default for RuntimeGateMonitor {
+    fn default() -> Self {
+        Self::new(RuntimeBudgets::default())
+    }
+}
+
+impl RuntimeGateMonitor {
+    #[must_use]
+    pub fn new(budgets: RuntimeBudgets) -> Self {
+        Self {
+            budgets,
+            window: RUNTIME_GATE_WINDOW,
+            frame_build_ms: VecDeque::new(),
+            poll_latency_ms: VecDeque::new(),
+            render_ms: VecDeque::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn budgets(&self) -> RuntimeBudgets {
+        self.budgets
+    }
+
+    pub fn set_budgets(&mut self, budgets: RuntimeBudgets) {
+        self.budgets = budgets;
+    }
+
+    pub fn record(&mut self, gate: RuntimeGate, elapsed_ms: u64) {
+        let window = self.window.max(1);
+        let samples = self.samples_mut(gate);
+        samples.push_back(elapsed_ms);
+        while samples.len() > window {
+            samples.pop_front();
+        }
+    }
+
+    /// Evaluate every gate against its budget. Gates without samples pass
+    /// but report no measurement.
+    #[must_use]
+    pub fn evaluate(&self) -> Vec<GateResult> {
+        RuntimeGate::ALL
+            .into_iter()
+            .map(|gate| {
+                let samples = self.samples(gate).iter().copied().collect::<Vec<_>>();
+                let measured_p95_ms = percentile_ms(&samples, 95);
+                let threshold_ms = self.budgets.threshold_ms(gate);
+                GateResult {
+                    gate,
+                    measured_p95_ms,
+                    threshold_ms,
+                    samples: samples.len(),
+                    passed: measured_p95_ms.map_or(true, |measured| measured <= threshold_ms),
+                }
+            })
+            .collect()
+    }
+
+    fn samples(&self, gate: RuntimeGate) -> &VecDeque<u64> {
+        match gate {
+            RuntimeGate::FrameBuild => &self.frame_build_ms,
+            RuntimeGate::PollLatency => &self.poll_latency_ms,
+            RuntimeGate::Render => &self.render_ms,
+        }
+    }
+
+    fn samples_mut(&mut self, gate: RuntimeGate) -> &mut VecDeque<u64> {
+        match gate {
+            RuntimeGate::FrameBuild => &mut self.frame_build_ms,
+            RuntimeGate::PollLatency => &mut self.poll_latency_ms,
+            RuntimeGate::Render => &mut self.render_ms,
+        }
+    }
+}
+
+/// Diagnostics view rows; breaches are prefixed so they stand out.
+#[must_use]
+pub fn format_gate_result_lines(results: &[GateResult]) -> Vec<String> {
+    let failed = results.iter().filter(|result| !result.passed).count();
+    let mut lines = vec![if failed == 0 {
+        format!("Runtime gates: PASS ({})", results.len())
+    } else {
+        format!("Runtime gates: FAIL ({failed}/{} breached)", results.len())
+    }];
+    for result in results {
+        let marker = if result.passed { " " } else { "!" };
+        let measured = result
+            .measured_p95_ms
+            .map_or_else(|| "n/a".to_owned(), |measured| format!("{measured}ms"));
+        lines.push(format!(
+            "{marker} {:<13} p95={:<7} budget={}ms  n={}",
+            result.gate.label(),
+            measured,
+            result.threshold_ms,
+            result.samples
+        ));
+    }
+    lines
+}
+
+/// Map gate results onto `forge doctor` checks.
+#[must_use]
+pub fn gate_results_to_doctor_checks(results: &[GateResult]) -> Vec<DoctorCheck> {
+    results
+        .iter()
+        .map(|result| {
+            let (status, details, error) = match result.measured_p95_ms {
+                None => (CheckStatus::Skip, Some("no samples".to_owned()), None),
+                Some(measured) if result.passed => (
+                    CheckStatus::Pass,
+                    Some(format!(
+                        "p95 {measured}ms within {}ms budget",
+                        result.threshold_ms
+                    )),
+                    None,
+                ),
+                Some(measured) => (
+                    CheckStatus::Fail,
+                    None,
+                    Some(format!(
+                        "p95 {measured}ms exceeds {}ms budget",
+                        result.threshold_ms
+                    )),
+                ),
+            };
+            DoctorCheck {
+                category: "tui-performance".to_owned(),
+                name: result.gate.slug().to_owned(),
+                status,
+                details,
+                error,
+            }
+        })
+        .collect()
+}
+
 fn parse_cases(values: &[Value], warnings: &mut Vec<String>) -> Vec<BenchmarkCase> {
     let mut cases = Vec::new();
     for (index, value) in values.iter().enumerate() {
@@ -442,7 +749,12 @@ mod tests {
         persist_benchmark_suite, restore_benchmark_suite, run_benchmark_case,
         run_benchmark_case_with_work_units, BenchmarkCase, BenchmarkSample, ViewSlo,
     };
+    use super::{
+        format_gate_result_lines, gate_results_to_doctor_checks, restore_runtime_budgets,
+        RuntimeGate, RuntimeGateMonitor,
+    };
     use crate::app::{App, LogTailView, LoopView, MainTab, RunView};
+    use forge_cli::doctor::CheckStatus;
     use forge_cli::logs::{render_lines_for_layer, LogRenderLayer};
 
     #[test]
@@ -574,6 +886,44 @@ mod tests {
         assert!(summary.contains("missing sample"));
     }
 
+    #[test]
+    fn runtime_gate_fails_over_budget_and_passes_under() {
+        let outcome = restore_runtime_budgets(r#"{"render_ms": 20, "bogus_ms": 5}"#);
+        assert_eq!(outcome.budgets.render_ms, 20);
+        assert_eq!(
+            outcome.warnings,
+            vec!["unknown runtime budget 'bogus_ms' ignored"]
+        );
+
+        let mut monitor = RuntimeGateMonitor::new(outcome.budgets);
+        for _ in 0..10 {
+            monitor.record(RuntimeGate::Render, 35);
+            monitor.record(RuntimeGate::FrameBuild, 4);
+        }
+
+        let results = monitor.evaluate();
+        let render = results
+            .iter()
+            .find(|result| result.gate == RuntimeGate::Render)
+            .unwrap_or_else(|| panic!("render gate missing"));
+        assert!(!render.passed);
+        assert_eq!(render.measured_p95_ms, Some(35));
+        let frame = results
+            .iter()
+            .find(|result| result.gate == RuntimeGate::FrameBuild)
+            .unwrap_or_else(|| panic!("frame gate missing"));
+        assert!(frame.passed);
+
+        let lines = format_gate_result_lines(&results);
+        assert_eq!(lines[0], "Runtime gates: FAIL (1/3 breached)");
+        assert!(lines.iter().any(|line| line.starts_with("! render")));
+
+        let checks = gate_results_to_doctor_checks(&results);
+        assert_eq!(checks[0].status, CheckStatus::Pass);
+        assert_eq!(checks[1].status, CheckStatus::Skip);
+        assert_eq!(checks[2].status, CheckStatus::Fail);
+    }
+
     #[test]
     fn suite_persist_restore_round_trip() {
         let suite = default_benchmark_suite();
@@ -636,6 +986,7 @@ mod tests {
             } else {
                 String::new()
             },
+            stop_cause: String::new(),
             profile_name: "ops-prod".to_owned(),
             profile_harness: "codex".to_owned(),
             profile_auth: "sso".to_owned(),
@@ -670,6 +1021,8 @@ mod tests {
                 "run output line one".to_owned(),
                 "run output line two".to_owned(),
             ],
+            attempts: None,
+            cost_usd: None,
         }
     }
 
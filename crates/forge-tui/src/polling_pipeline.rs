@@ -151,6 +151,140 @@ impl PollScheduler {
     }
 }
 
+/// How a view decides when to recompute from fresh data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RefreshMode {
+    /// Recompute on every scheduler interval.
+    #[default]
+    Interval,
+    /// Recompute only when a relevant event arrives past the event cursor.
+    EventDriven,
+}
+
+impl RefreshMode {
+    #[must_use]
+    pub fn slug(self) -> &'static str {
+        match self {
+            Self::Interval => "interval",
+            Self::EventDriven => "event-driven",
+        }
+    }
+
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "interval" => Some(Self::Interval),
+            "event-driven" | "events" => Some(Self::EventDriven),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RefreshTrigger {
+    /// First poll after construction, so every mode starts with fresh data.
+    Initial,
+    Manual,
+    Interval,
+    Event,
+}
+
+/// Decides when a view recomputes, honoring its [`RefreshMode`]. The first
+/// poll always recomputes, and a manual refresh is available in any mode.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RefreshController {
+    mode: RefreshMode,
+    scheduler: PollScheduler,
+    next_interval_due_ms: u64,
+    event_cursor: String,
+    initial_pending: bool,
+    relevant_pending: bool,
+    manual_pending: bool,
+    recompute_count: u64,
+}
+
+impl RefreshController {
+    #[must_use]
+    pub fn new(mode: RefreshMode, config: PollingConfig, pipeline_key: &str) -> Self {
+        Self {
+            mode,
+            scheduler: PollScheduler::new(config, pipeline_key),
+            next_interval_due_ms: 0,
+            event_cursor: String::new(),
+            initial_pending: true,
+            relevant_pending: false,
+            manual_pending: false,
+            recompute_count: 0,
+        }
+    }
+
+    #[must_use]
+    pub fn mode(&self) -> RefreshMode {
+        self.mode
+    }
+
+    pub fn set_mode(&mut self, mode: RefreshMode) {
+        self.mode = mode;
+    }
+
+    /// Cursor to pass to the next event page query.
+    #[must_use]
+    pub fn event_cursor(&self) -> &str {
+        &self.event_cursor
+    }
+
+    #[must_use]
+    pub fn recompute_count(&self) -> u64 {
+        self.recompute_count
+    }
+
+    pub fn request_manual_refresh(&mut self) {
+        self.manual_pending = true;
+    }
+
+    /// Advance the event cursor past a fetched page, marking a recompute
+    /// pending when any event is relevant to the view. Returns whether one was.
+    pub fn observe_events<T>(
+        &mut self,
+        events: &[T],
+        next_cursor: &str,
+        is_relevant: impl Fn(&T) -> bool,
+    ) -> bool {
+        if !next_cursor.trim().is_empty() {
+            self.event_cursor = next_cursor.trim().to_owned();
+        }
+        let relevant = events.iter().any(is_relevant);
+        self.relevant_pending |= relevant;
+        relevant
+    }
+
+    /// Return the trigger for a recompute due at `now_ms`, if any, and mark
+    /// it consumed. Events seen in interval mode fold into the next interval.
+    pub fn poll(&mut self, now_ms: u64, backlog: usize) -> Option<RefreshTrigger> {
+        let trigger = if self.initial_pending {
+            RefreshTrigger::Initial
+        } else if self.manual_pending {
+            RefreshTrigger::Manual
+        } else {
+            match self.mode {
+                RefreshMode::EventDriven if self.relevant_pending => RefreshTrigger::Event,
+                RefreshMode::Interval if now_ms >= self.next_interval_due_ms => {
+                    RefreshTrigger::Interval
+                }
+                _ => return None,
+            }
+        };
+
+        self.initial_pending = false;
+        self.manual_pending = false;
+        self.relevant_pending = false;
+        self.recompute_count = self.recompute_count.saturating_add(1);
+        let interval_ms = self.scheduler.next_interval(backlog).as_millis() as u64;
+        self.next_interval_due_ms = now_ms.saturating_add(interval_ms);
+        Some(trigger)
+    }
+}
+
 #[must_use]
 pub fn deterministic_jitter_ms(seed: u64, tick: u64, max_jitter_ms: u64) -> u64 {
     if max_jitter_ms == 0 {
@@ -184,6 +318,7 @@ fn splitmix64(mut value: u64) -> u64 {
 mod tests {
     use super::{
         deterministic_jitter_ms, PollScheduler, PollingConfig, PollingQueue, QueuePressureEvent,
+        RefreshController, RefreshMode, RefreshTrigger,
     };
 
     #[test]
@@ -268,4 +403,24 @@ mod tests {
         assert_eq!(config.backpressure_step_ms, 400);
         assert_eq!(config.max_backpressure_ms, 400);
     }
+
+    #[test]
+    fn interval_mode_recomputes_on_schedule_and_manual_always_wins() {
+        let config = PollingConfig {
+            base_interval_ms: 1_000,
+            max_jitter_ms: 0,
+            ..PollingConfig::default()
+        };
+        let mut refresh = RefreshController::new(RefreshMode::Interval, config, "overview");
+
+        assert_eq!(refresh.poll(0, 0), Some(RefreshTrigger::Initial));
+        assert_eq!(refresh.poll(500, 0), None);
+        assert_eq!(refresh.poll(1_000, 0), Some(RefreshTrigger::Interval));
+
+        refresh.set_mode(RefreshMode::EventDriven);
+        assert_eq!(refresh.poll(5_000, 0), None);
+        refresh.request_manual_refresh();
+        assert_eq!(refresh.poll(5_001, 0), Some(RefreshTrigger::Manual));
+        assert_eq!(refresh.recompute_count(), 3);
+    }
 }
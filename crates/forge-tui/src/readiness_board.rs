@@ -2,6 +2,10 @@
 
 use std::collections::{BTreeMap, BTreeSet};
 
+use serde_json::{Map, Value};
+
+pub const DEFAULT_READY_MIN_SCORE: u8 = 90;
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ReadinessTaskSample {
     pub task_id: String,
@@ -13,6 +17,41 @@ pub struct ReadinessTaskSample {
     pub owner: Option<String>,
     pub updated_at_epoch_s: i64,
     pub blocked_by: Vec<String>,
+    pub open_alerts: usize,
+    pub pending_approvals: usize,
+}
+
+/// What "ready" means for this session: a minimum readiness score plus
+/// optional gates on open alerts and pending approvals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReadinessCriteria {
+    pub min_score: u8,
+    pub require_no_open_alerts: bool,
+    pub require_no_pending_approvals: bool,
+}
+
+impl Default for ReadinessCriteria {
+    fn default() -> Self {
+        Self {
+            min_score: DEFAULT_READY_MIN_SCORE,
+            require_no_open_alerts: false,
+            require_no_pending_approvals: false,
+        }
+    }
+}
+
+impl ReadinessCriteria {
+    #[must_use]
+    pub fn label(&self) -> String {
+        let mut parts = vec![format!("score>={}", self.min_score)];
+        if self.require_no_open_alerts {
+            parts.push("no-alerts".to_owned());
+        }
+        if self.require_no_pending_approvals {
+            parts.push("no-approvals".to_owned());
+        }
+        parts.join(" ")
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
@@ -32,7 +71,10 @@ pub struct ReadinessBoardRow {
     pub epic_id: Option<String>,
     pub owner: Option<String>,
     pub readiness_score: u8,
+    /// `ready` exactly when `ready` is set; otherwise the status class, with
+    /// `held` for ready-status tasks the criteria exclude.
     pub readiness_label: String,
+    pub ready: bool,
     pub stale_risk: bool,
     pub ownership_gap: bool,
     pub blocked: bool,
@@ -68,6 +110,23 @@ pub fn build_readiness_board_view(
     now_epoch_s: i64,
     stale_after_secs: u64,
     filter: &ReadinessBoardFilter,
+) -> ReadinessBoardView {
+    build_readiness_board_view_with_criteria(
+        samples,
+        now_epoch_s,
+        stale_after_secs,
+        filter,
+        &ReadinessCriteria::default(),
+    )
+}
+
+#[must_use]
+pub fn build_readiness_board_view_with_criteria(
+    samples: &[ReadinessTaskSample],
+    now_epoch_s: i64,
+    stale_after_secs: u64,
+    filter: &ReadinessBoardFilter,
+    criteria: &ReadinessCriteria,
 ) -> ReadinessBoardView {
     let now_epoch_s = now_epoch_s.max(0);
     let stale_after_secs = if stale_after_secs == 0 {
@@ -121,7 +180,7 @@ pub fn build_readiness_board_view(
             })
             .next()
             .is_some();
-        let (readiness_score, readiness_label, blocked) = classify_readiness(&status, has_blockers);
+        let (readiness_score, status_label, blocked) = classify_readiness(&status, has_blockers);
 
         let priority = normalize_priority(&sample.priority);
         let owner = normalize_optional(sample.owner.as_deref());
@@ -143,6 +202,19 @@ pub fn build_readiness_board_view(
         if ownership_gap {
             risk_overlays.push("risk:owner-gap".to_owned());
         }
+        if sample.open_alerts > 0 {
+            risk_overlays.push("risk:alerts".to_owned());
+        }
+        if sample.pending_approvals > 0 {
+            risk_overlays.push("risk:approvals".to_owned());
+        }
+
+        let ready = !blocked
+            && !is_terminal_status(&status)
+            && readiness_score >= criteria.min_score
+            && !(criteria.require_no_open_alerts && sample.open_alerts > 0)
+            && !(criteria.require_no_pending_approvals && sample.pending_approvals > 0);
+        let readiness_label = readiness_label(status_label, ready);
 
         let title = normalize_title(&sample.title, &task_id);
         rows.push(ReadinessBoardRow {
@@ -155,6 +227,7 @@ pub fn build_readiness_board_view(
             owner,
             readiness_score,
             readiness_label: readiness_label.to_owned(),
+            ready,
             stale_risk,
             ownership_gap,
             blocked,
@@ -190,10 +263,7 @@ pub fn build_readiness_board_view(
 
     let summary = ReadinessBoardSummary {
         total_rows: rows.len(),
-        ready_rows: rows
-            .iter()
-            .filter(|row| row.readiness_label.as_str() == "ready")
-            .count(),
+        ready_rows: rows.iter().filter(|row| row.ready).count(),
         stale_risk_rows: rows.iter().filter(|row| row.stale_risk).count(),
         ownership_gap_rows: rows.iter().filter(|row| row.ownership_gap).count(),
         blocked_rows: rows.iter().filter(|row| row.blocked).count(),
@@ -203,6 +273,164 @@ pub fn build_readiness_board_view(
     ReadinessBoardView { rows, summary }
 }
 
+/// Session-scoped board that recomputes whenever its inputs or criteria change.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ReadinessBoardSession {
+    samples: Vec<ReadinessTaskSample>,
+    now_epoch_s: i64,
+    stale_after_secs: u64,
+    filter: ReadinessBoardFilter,
+    criteria: ReadinessCriteria,
+    view: ReadinessBoardView,
+}
+
+impl ReadinessBoardSession {
+    #[must_use]
+    pub fn new(criteria: ReadinessCriteria) -> Self {
+        Self {
+            criteria,
+            ..Self::default()
+        }
+    }
+
+    #[must_use]
+    pub fn view(&self) -> &ReadinessBoardView {
+        &self.view
+    }
+
+    #[must_use]
+    pub fn criteria(&self) -> ReadinessCriteria {
+        self.criteria
+    }
+
+    pub fn set_samples(
+        &mut self,
+        samples: Vec<ReadinessTaskSample>,
+        now_epoch_s: i64,
+        stale_after_secs: u64,
+    ) {
+        self.samples = samples;
+        self.now_epoch_s = now_epoch_s;
+        self.stale_after_secs = stale_after_secs;
+        self.recompute();
+    }
+
+    pub fn set_filter(&mut self, filter: ReadinessBoardFilter) {
+        self.filter = filter;
+        self.recompute();
+    }
+
+    pub fn set_criteria(&mut self, criteria: ReadinessCriteria) {
+        self.criteria = criteria;
+        self.recompute();
+    }
+
+    pub fn set_min_score(&mut self, min_score: u8) {
+        self.criteria.min_score = min_score.min(100);
+        self.recompute();
+    }
+
+    pub fn adjust_min_score(&mut self, delta: i16) {
+        let next = (i16::from(self.criteria.min_score) + delta).clamp(0, 100);
+        self.set_min_score(next as u8);
+    }
+
+    pub fn toggle_require_no_open_alerts(&mut self) {
+        self.criteria.require_no_open_alerts = !self.criteria.require_no_open_alerts;
+        self.recompute();
+    }
+
+    pub fn toggle_require_no_pending_approvals(&mut self) {
+        self.criteria.require_no_pending_approvals = !self.criteria.require_no_pending_approvals;
+        self.recompute();
+    }
+
+    fn recompute(&mut self) {
+        self.view = build_readiness_board_view_with_criteria(
+            &self.samples,
+            self.now_epoch_s,
+            self.stale_after_secs,
+            &self.filter,
+            &self.criteria,
+        );
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ReadinessCriteriaLoadOutcome {
+    pub criteria: ReadinessCriteria,
+    pub warnings: Vec<String>,
+}
+
+#[must_use]
+pub fn persist_readiness_criteria(criteria: &ReadinessCriteria) -> String {
+    let mut root = Map::new();
+    root.insert("min_score".to_owned(), Value::from(criteria.min_score));
+    root.insert(
+        "require_no_open_alerts".to_owned(),
+        Value::from(criteria.require_no_open_alerts),
+    );
+    root.insert(
+        "require_no_pending_approvals".to_owned(),
+        Value::from(criteria.require_no_pending_approvals),
+    );
+
+    match serde_json::to_string_pretty(&Value::Object(root)) {
+        Ok(json) => json,
+        Err(_) => "{}".to_owned(),
+    }
+}
+
+#[must_use]
+pub fn restore_readiness_criteria(raw: &str) -> ReadinessCriteriaLoadOutcome {
+    let trimmed = raw.trim();
+    if trimmed.is_empty() {
+        return ReadinessCriteriaLoadOutcome::default();
+    }
+
+    let value = match serde_json::from_str::<Value>(trimmed) {
+        Ok(value) => value,
+        Err(err) => {
+            return ReadinessCriteriaLoadOutcome {
+                criteria: ReadinessCriteria::default(),
+                warnings: vec![format!("invalid json; defaults restored ({err})")],
+            };
+        }
+    };
+    let Some(obj) = value.as_object() else {
+        return ReadinessCriteriaLoadOutcome {
+            criteria: ReadinessCriteria::default(),
+            warnings: vec!["readiness criteria was not an object; defaults restored".to_owned()],
+        };
+    };
+
+    let mut warnings = Vec::new();
+    let defaults = ReadinessCriteria::default();
+    let min_score = match obj.get("min_score").and_then(Value::as_u64) {
+        Some(score) if score <= 100 => score as u8,
+        Some(score) => {
+            warnings.push(format!("min_score={score} out of range; clamped to 100"));
+            100
+        }
+        None => defaults.min_score,
+    };
+
+    ReadinessCriteriaLoadOutcome {
+        criteria: ReadinessCriteria {
+            min_score,
+            require_no_open_alerts: obj
+                .get("require_no_open_alerts")
+                .and_then(Value::as_bool)
+                .unwrap_or(defaults.require_no_open_alerts),
+            require_no_pending_approvals: obj
+                .get("require_no_pending_approvals")
+                .and_then(Value::as_bool)
+                .unwrap_or(defaults.require_no_pending_approvals),
+        },
+        warnings,
+    }
+}
+
 fn normalize_title(title: &str, task_id: &str) -> String {
     let normalized = normalize_required(title);
     if normalized.is_empty() {
@@ -278,6 +506,14 @@ fn classify_readiness(status: &str, has_blockers: bool) -> (u8, &'static str, bo
     (40, "unknown", false)
 }
 
+fn readiness_label(status_label: &'static str, ready: bool) -> &'static str {
+    match (ready, status_label) {
+        (true, _) => "ready",
+        (false, "ready") => "held",
+        (false, label) => label,
+    }
+}
+
 fn is_ready_status(status: &str) -> bool {
     matches!(status, "open" | "ready" | "queued" | "pending")
 }
@@ -300,8 +536,9 @@ fn is_terminal_status(status: &str) -> bool {
 #[cfg(test)]
 mod tests {
     use super::{
-        build_readiness_board_view, ReadinessBoardFilter, ReadinessBoardRow, ReadinessBoardView,
-        ReadinessTaskSample,
+        build_readiness_board_view, persist_readiness_criteria, restore_readiness_criteria,
+        ReadinessBoardFilter, ReadinessBoardRow, ReadinessBoardSession, ReadinessBoardView,
+        ReadinessCriteria, ReadinessTaskSample,
     };
 
     fn sample_tasks() -> Vec<ReadinessTaskSample> {
@@ -316,6 +553,8 @@ mod tests {
                 owner: Some("alice".to_owned()),
                 updated_at_epoch_s: 1_000,
                 blocked_by: Vec::new(),
+                open_alerts: 0,
+                pending_approvals: 0,
             },
             ReadinessTaskSample {
                 task_id: "forge-a2".to_owned(),
@@ -327,6 +566,8 @@ mod tests {
                 owner: None,
                 updated_at_epoch_s: 100,
                 blocked_by: vec!["forge-root".to_owned()],
+                open_alerts: 0,
+                pending_approvals: 0,
             },
             ReadinessTaskSample {
                 task_id: "forge-a3".to_owned(),
@@ -338,6 +579,8 @@ mod tests {
                 owner: Some("bob".to_owned()),
                 updated_at_epoch_s: 950,
                 blocked_by: Vec::new(),
+                open_alerts: 0,
+                pending_approvals: 0,
             },
             ReadinessTaskSample {
                 task_id: "forge-a4".to_owned(),
@@ -349,6 +592,8 @@ mod tests {
                 owner: None,
                 updated_at_epoch_s: 0,
                 blocked_by: Vec::new(),
+                open_alerts: 0,
+                pending_approvals: 0,
             },
         ]
     }
@@ -470,4 +715,91 @@ mod tests {
         let view = build_readiness_board_view(&sample_tasks(), 2_000, 300, &filter);
         assert!(view.rows.iter().any(|row| row.task_id == "forge-a4"));
     }
+
+    fn ready_ids(view: &ReadinessBoardView) -> Vec<&str> {
+        let mut ids = view
+            .rows
+            .iter()
+            .filter(|row| row.ready)
+            .map(|row| row.task_id.as_str())
+            .collect::<Vec<_>>();
+        ids.sort_unstable();
+        ids
+    }
+
+    #[test]
+    fn threshold_changes_move_borderline_rows_between_ready_and_not_ready() {
+        let mut session = ReadinessBoardSession::new(ReadinessCriteria {
+            min_score: 60,
+            ..ReadinessCriteria::default()
+        });
+        session.set_samples(sample_tasks(), 2_000, 300);
+        assert_eq!(ready_ids(session.view()), vec!["forge-a1", "forge-a3"]);
+        assert_eq!(session.view().summary.ready_rows, 2);
+        assert_eq!(
+            row_by_id(session.view(), "forge-a3").readiness_label,
+            "ready"
+        );
+
+        session.adjust_min_score(30);
+        assert_eq!(session.criteria().min_score, 90);
+        assert_eq!(ready_ids(session.view()), vec!["forge-a1"]);
+        assert!(!row_by_id(session.view(), "forge-a3").ready);
+        assert_eq!(
+            row_by_id(session.view(), "forge-a3").readiness_label,
+            "active"
+        );
+
+        session.set_min_score(60);
+        assert_eq!(ready_ids(session.view()), vec!["forge-a1", "forge-a3"]);
+    }
+
+    #[test]
+    fn alert_and_approval_gates_exclude_rows_when_enabled() {
+        let mut samples = sample_tasks();
+        samples[0].open_alerts = 2;
+        samples[2].pending_approvals = 1;
+        let mut session = ReadinessBoardSession::new(ReadinessCriteria {
+            min_score: 60,
+            ..ReadinessCriteria::default()
+        });
+        session.set_samples(samples, 2_000, 300);
+        assert_eq!(ready_ids(session.view()), vec!["forge-a1", "forge-a3"]);
+        assert!(row_by_id(session.view(), "forge-a1")
+            .risk_overlays
+            .contains(&"risk:alerts".to_owned()));
+
+        session.toggle_require_no_open_alerts();
+        assert_eq!(ready_ids(session.view()), vec!["forge-a3"]);
+        assert_eq!(
+            row_by_id(session.view(), "forge-a1").readiness_label,
+            "held"
+        );
+        session.toggle_require_no_pending_approvals();
+        assert!(ready_ids(session.view()).is_empty());
+        assert_eq!(
+            session.criteria().label(),
+            "score>=60 no-alerts no-approvals"
+        );
+    }
+
+    #[test]
+    fn readiness_criteria_round_trip_and_invalid_input_falls_back() {
+        let criteria = ReadinessCriteria {
+            min_score: 75,
+            require_no_open_alerts: true,
+            require_no_pending_approvals: false,
+        };
+        let restored = restore_readiness_criteria(&persist_readiness_criteria(&criteria));
+        assert_eq!(restored.criteria, criteria);
+        assert!(restored.warnings.is_empty());
+
+        let invalid = restore_readiness_criteria("{not json");
+        assert_eq!(invalid.criteria, ReadinessCriteria::default());
+        assert_eq!(invalid.warnings.len(), 1);
+
+        let clamped = restore_readiness_criteria(r#"{"min_score": 400}"#);
+        assert_eq!(clamped.criteria.min_score, 100);
+        assert_eq!(clamped.warnings.len(), 1);
+    }
 }
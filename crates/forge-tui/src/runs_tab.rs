@@ -9,6 +9,10 @@ use forge_ftui_adapter::style::ThemeSpec;
 use forge_ftui_adapter::widgets::BorderStyle;
 
 use crate::lane_model::classify_line;
+use crate::log_compare::{
+    diff_hint, summarize_diff_hints, synchronized_windows, DiffHint, DiffHintSummary,
+    SynchronizedCompareWindow,
+};
 use crate::log_pipeline::{highlight_spans, SpanKind};
 use crate::theme::ResolvedPalette;
 
@@ -70,6 +74,10 @@ pub struct RunEntry {
     pub started_at: String,
     pub duration_display: String,
     pub output_lines: Vec<String>,
+    /// Harness attempts made within the run, when known.
+    pub attempts: Option<u32>,
+    /// Reported spend for the run in USD, when known.
+    pub cost_usd: Option<f64>,
 }
 
 // ---------------------------------------------------------------------------
@@ -90,6 +98,10 @@ pub struct RunsTabState {
     pub loop_display_id: String,
     /// Current log scroll offset.
     pub log_scroll: usize,
+    /// Comparison of the two runs marked with `V`, shown in place of output.
+    pub comparison: Option<RunComparison>,
+    /// Show the compared runs' output side by side (`L`) instead of fields.
+    pub log_compare: bool,
 }
 
 // ---------------------------------------------------------------------------
@@ -158,6 +170,378 @@ pub fn run_output_lines(output: &str, max_lines: usize) -> Vec<String> {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Run compare – field-by-field delta between two selected runs
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RunCompareField {
+    Duration,
+    Attempts,
+    Cost,
+    Outcome,
+}
+
+impl RunCompareField {
+    pub const ALL: [RunCompareField; 4] = [
+        RunCompareField::Duration,
+        RunCompareField::Attempts,
+        RunCompareField::Cost,
+        RunCompareField::Outcome,
+    ];
+
+    #[must_use]
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Duration => "duration",
+            Self::Attempts => "attempts",
+            Self::Cost => "cost",
+            Self::Outcome => "outcome",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunFieldDelta {
+    pub field: RunCompareField,
+    pub left: String,
+    pub right: String,
+    /// Signed right-minus-left change for numeric fields, e.g. `+2` or `-30s`.
+    pub delta: Option<String>,
+    pub changed: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunComparison {
+    pub left_id: String,
+    pub right_id: String,
+    pub fields: Vec<RunFieldDelta>,
+}
+
+impl RunComparison {
+    #[must_use]
+    pub fn changed_fields(&self) -> Vec<RunCompareField> {
+        self.fields
+            .iter()
+            .filter(|delta| delta.changed)
+            .map(|delta| delta.field)
+            .collect()
+    }
+}
+
+/// Runs marked for comparison, in the order they were picked.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RunCompareSelection {
+    marked: Vec<usize>,
+}
+
+impl RunCompareSelection {
+    /// Mark or unmark a run index. Marking a third run replaces the oldest.
+    pub fn toggle(&mut self, index: usize) {
+        if let Some(pos) = self.marked.iter().position(|marked| *marked == index) {
+            self.marked.remove(pos);
+            return;
+        }
+        if self.marked.len() == 2 {
+            self.marked.remove(0);
+        }
+        self.marked.push(index);
+    }
+
+    pub fn clear(&mut self) {
+        self.marked.clear();
+    }
+
+    #[must_use]
+    pub fn marked(&self) -> &[usize] {
+        &self.marked
+    }
+
+    /// Compare the two marked runs, or return the prompt to show while the
+    /// selection is incomplete.
+    pub fn compare(&self, runs: &[RunEntry]) -> Result<RunComparison, String> {
+        let picked = self
+            .marked
+            .iter()
+            .filter_map(|index| runs.get(*index))
+            .collect::<Vec<_>>();
+        match picked.as_slice() {
+            [left, right] => Ok(compare_runs(left, right)),
+            [only] => Err(format!(
+                "compare: {} selected; pick a second run",
+                short_run_id(&only.id)
+            )),
+            _ => Err("compare: select two runs to compare".to_owned()),
+        }
+    }
+}
+
+#[must_use]
+pub fn compare_runs(left: &RunEntry, right: &RunEntry) -> RunComparison {
+    let fields = RunCompareField::ALL
+        .into_iter()
+        .map(|field| match field {
+            RunCompareField::Duration => {
+                let left_secs = parse_duration_secs(&left.duration_display);
+                let right_secs = parse_duration_secs(&right.duration_display);
+                let delta = match (left_secs, right_secs) {
+                    (Some(l), Some(r)) => Some(signed_duration(r - l)),
+                    _ => None,
+                };
+                field_delta(
+                    field,
+                    duration_chip(&left.duration_display),
+                    duration_chip(&right.duration_display),
+                    delta,
+                )
+            }
+            RunCompareField::Attempts => field_delta(
+                field,
+                optional_value(left.attempts),
+                optional_value(right.attempts),
+                match (left.attempts, right.attempts) {
+                    (Some(l), Some(r)) => Some(format!("{:+}", i64::from(r) - i64::from(l))),
+                    _ => None,
+                },
+            ),
+            RunCompareField::Cost => field_delta(
+                field,
+                left.cost_usd.map_or_else(|| "-".to_owned(), format_cost),
+                right.cost_usd.map_or_else(|| "-".to_owned(), format_cost),
+                match (left.cost_usd, right.cost_usd) {
+                    (Some(l), Some(r)) => Some(format!("{:+.2}", r - l)),
+                    _ => None,
+                },
+            ),
+            RunCompareField::Outcome => {
+                field_delta(field, run_outcome(left), run_outcome(right), None)
+            }
+        })
+        .collect();
+    RunComparison {
+        left_id: left.id.clone(),
+        right_id: right.id.clone(),
+        fields,
+    }
+}
+
+/// Synchronized `log_compare` windows plus diff hints for two runs' output.
+#[must_use]
+pub fn run_log_compare(
+    left: &RunEntry,
+    right: &RunEntry,
+    viewport_lines: usize,
+) -> (SynchronizedCompareWindow, DiffHintSummary) {
+    (
+        synchronized_windows(&left.output_lines, &right.output_lines, viewport_lines, 0),
+        summarize_diff_hints(&left.output_lines, &right.output_lines),
+    )
+}
+
+/// Compare rows; changed fields use the accent role and a `*` marker.
+#[must_use]
+pub fn run_compare_lines(comparison: &RunComparison, width: usize) -> Vec<(String, TextRole)> {
+    let mut lines = vec![
+        (
+            truncate_line(
+                &format!(
+                    "Compare {} -> {}",
+                    short_run_id(&comparison.left_id),
+                    short_run_id(&comparison.right_id)
+                ),
+                width,
+            ),
+            TextRole::Accent,
+        ),
+        (
+            truncate_line(
+                &format!("  {:<10} {:<16} {:<16} delta", "field", "left", "right"),
+                width,
+            ),
+            TextRole::Muted,
+        ),
+    ];
+    for delta in &comparison.fields {
+        let marker = if delta.changed { '*' } else { ' ' };
+        let row = format!(
+            "{marker} {:<10} {:<16} {:<16} {}",
+            delta.field.label(),
+            delta.left,
+            delta.right,
+            delta
+                .delta
+                .as_deref()
+                .unwrap_or(if delta.changed { "changed" } else { "=" })
+        );
+        let role = if delta.changed {
+            TextRole::Accent
+        } else {
+            TextRole::Primary
+        };
+        lines.push((truncate_line(&row, width), role));
+    }
+    lines.push((
+        truncate_line("  L: open log compare for both runs", width),
+        TextRole::Muted,
+    ));
+    lines.push((
+        truncate_line("  V: unmark a run to leave compare", width),
+        TextRole::Muted,
+    ));
+    lines
+}
+
+/// Side-by-side output rows for two compared runs: a diff summary header,
+/// then one `left | right` row per synchronized line prefixed by its hint.
+#[must_use]
+pub fn run_log_compare_lines(
+    left: &RunEntry,
+    right: &RunEntry,
+    width: usize,
+    viewport_lines: usize,
+) -> Vec<(String, TextRole)> {
+    let (window, summary) = run_log_compare(left, right, viewport_lines.saturating_sub(3));
+    let mut lines = vec![
+        (
+            truncate_line(
+                &format!(
+                    "Log compare {} -> {}",
+                    short_run_id(&left.id),
+                    short_run_id(&right.id)
+                ),
+                width,
+            ),
+            TextRole::Accent,
+        ),
+        (
+            truncate_line(
+                &format!(
+                    "  equal={} differ={} left-only={} right-only={}",
+                    summary.equal, summary.different, summary.left_only, summary.right_only
+                ),
+                width,
+            ),
+            TextRole::Muted,
+        ),
+    ];
+    let column = width.saturating_sub(5) / 2;
+    let left_rows = left
+        .output_lines
+        .get(window.left.start_line..window.left.end_line)
+        .unwrap_or_default();
+    let right_rows = right
+        .output_lines
+        .get(window.right.start_line..window.right.end_line)
+        .unwrap_or_default();
+    for index in 0..left_rows.len().max(right_rows.len()) {
+        let left_line = left_rows.get(index).map(String::as_str);
+        let right_line = right_rows.get(index).map(String::as_str);
+        let hint = diff_hint(left_line, right_line);
+        let row = format!(
+            "{} {:<column$} | {}",
+            hint.glyph(),
+            truncate_line(left_line.unwrap_or_default(), column),
+            truncate_line(right_line.unwrap_or_default(), column),
+        );
+        let role = match hint {
+            DiffHint::Equal | DiffHint::Empty => TextRole::Primary,
+            _ => TextRole::Accent,
+        };
+        lines.push((truncate_line(&row, width), role));
+    }
+    lines.push((
+        truncate_line("  L: back to field compare", width),
+        TextRole::Muted,
+    ));
+    lines
+}
+
+/// Rows for the compare view: field deltas, or both runs' output when
+/// `state.log_compare` is set and both runs are still listed.
+fn compare_view_lines(
+    state: &RunsTabState,
+    comparison: &RunComparison,
+    width: usize,
+    height: usize,
+) -> Vec<(String, TextRole)> {
+    if state.log_compare {
+        let left = state.runs.iter().find(|run| run.id == comparison.left_id);
+        let right = state.runs.iter().find(|run| run.id == comparison.right_id);
+        if let (Some(left), Some(right)) = (left, right) {
+            return run_log_compare_lines(left, right, width, height);
+        }
+    }
+    run_compare_lines(comparison, width)
+}
+
+fn field_delta(
+    field: RunCompareField,
+    left: String,
+    right: String,
+    delta: Option<String>,
+) -> RunFieldDelta {
+    let changed = left != right;
+    RunFieldDelta {
+        field,
+        left,
+        right,
+        delta: delta.filter(|_| changed),
+        changed,
+    }
+}
+
+fn optional_value(value: Option<u32>) -> String {
+    value.map_or_else(|| "-".to_owned(), |value| value.to_string())
+}
+
+fn format_cost(cost: f64) -> String {
+    format!("${cost:.2}")
+}
+
+fn run_outcome(run: &RunEntry) -> String {
+    let status = run.status.trim().to_ascii_lowercase();
+    match run.exit_code {
+        Some(code) => format!("{status} (exit {code})"),
+        None => status,
+    }
+}
+
+/// Parse preformatted durations such as `1h2m3s`, `5m30s`, or `12s`.
+fn parse_duration_secs(display: &str) -> Option<i64> {
+    let display = display.trim();
+    if display.is_empty() || !display.ends_with(['h', 'm', 's']) {
+        return None;
+    }
+    let mut total = 0_i64;
+    let mut digits = String::new();
+    for ch in display.chars() {
+        if ch.is_ascii_digit() {
+            digits.push(ch);
+            continue;
+        }
+        let value = digits.parse::<i64>().ok()?;
+        digits.clear();
+        total += match ch {
+            'h' => value * 3600,
+            'm' => value * 60,
+            's' => value,
+            _ => return None,
+        };
+    }
+    Some(total)
+}
+
+fn signed_duration(secs: i64) -> String {
+    let sign = if secs < 0 { '-' } else { '+' };
+    let secs = secs.unsigned_abs();
+    let (minutes, secs) = (secs / 60, secs % 60);
+    if minutes > 0 {
+        format!("{sign}{minutes}m{secs}s")
+    } else {
+        format!("{sign}{secs}s")
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Rendering – matches Go renderRunsPane
 // ---------------------------------------------------------------------------
@@ -187,7 +571,7 @@ pub fn render_runs_pane(state: &RunsTabState, size: FrameSize, theme: ThemeSpec)
     frame.draw_text(0, 0, &header, TextRole::Accent);
 
     let hints = truncate_line(
-        ",/. select run | enter jump logs | x layer | u/d scroll output | l expanded",
+        ",/. select run | V compare | L log compare | enter jump logs | x layer | ctrl+u/d scroll output | l expanded",
         width,
     );
     frame.draw_text(0, 1, &hints, TextRole::Muted);
@@ -252,6 +636,18 @@ pub fn render_runs_pane(state: &RunsTabState, size: FrameSize, theme: ThemeSpec)
     }
 
     // -- selected run output ------------------------------------------------
+    if let Some(comparison) = &state.comparison {
+        for (line, role) in
+            compare_view_lines(state, comparison, content_width, height.saturating_sub(y))
+        {
+            if y >= height {
+                break;
+            }
+            frame.draw_text(0, y, &line, role);
+            y += 1;
+        }
+        return frame;
+    }
     let (title, output_lines, empty_msg) = selected_run_display(state);
     if y < height {
         frame.draw_text(0, y, &truncate_line("---", content_width), TextRole::Muted);
@@ -538,6 +934,10 @@ pub fn render_runs_paneled(
 
     // -- Output panel ---------------------------------------------------------
     if rest.height >= 4 {
+        if let Some(comparison) = &state.comparison {
+            draw_compare_panel(&mut frame, rest, state, comparison, pal, focus_bottom);
+            return frame;
+        }
         let sel_run = &state.runs[selected_idx];
         let output_title = format!(
             "Output: {} {}",
@@ -609,6 +1009,44 @@ pub fn render_runs_paneled(
     frame
 }
 
+fn draw_compare_panel(
+    frame: &mut RenderFrame,
+    rect: Rect,
+    state: &RunsTabState,
+    comparison: &RunComparison,
+    pal: &ResolvedPalette,
+    focus: bool,
+) {
+    let border = if focus { pal.accent } else { pal.border };
+    let inner = frame.draw_panel(rect, "Compare", BorderStyle::Rounded, border, pal.panel);
+    for (row, (line, role)) in compare_view_lines(state, comparison, inner.width, inner.height)
+        .into_iter()
+        .enumerate()
+        .take(inner.height)
+    {
+        let fg = match role {
+            TextRole::Accent => pal.accent,
+            TextRole::Muted => pal.text_muted,
+            _ => pal.text,
+        };
+        frame.draw_spans_in_rect(
+            inner,
+            0,
+            row,
+            &[StyledSpan::cell(
+                &line,
+                CellStyle {
+                    fg,
+                    bg: pal.panel,
+                    bold: role == TextRole::Accent,
+                    dim: false,
+                    underline: false,
+                },
+            )],
+        );
+    }
+}
+
 /// Map run status to a palette color.
 fn status_color(status: &str, pal: &ResolvedPalette) -> TermColor {
     let normalized = status.trim().to_ascii_lowercase();
@@ -831,6 +1269,8 @@ mod tests {
                     format!("{i}m30s")
                 },
                 output_lines: vec![format!("line1 from run {i}"), format!("line2 from run {i}")],
+                attempts: None,
+                cost_usd: None,
             })
             .collect()
     }
@@ -921,6 +1361,8 @@ mod tests {
             layer_label: "raw".to_owned(),
             loop_display_id: "my-loop".to_owned(),
             log_scroll: 0,
+            comparison: None,
+            log_compare: false,
         };
         let frame = render_runs_pane(
             &state,
@@ -952,6 +1394,8 @@ mod tests {
             layer_label: "events".to_owned(),
             loop_display_id: "test-lp".to_owned(),
             log_scroll: 0,
+            comparison: None,
+            log_compare: false,
         };
         let frame = render_runs_pane(
             &state,
@@ -977,6 +1421,8 @@ mod tests {
             layer_label: "raw".to_owned(),
             loop_display_id: "lp".to_owned(),
             log_scroll: 0,
+            comparison: None,
+            log_compare: false,
         };
         let frame = render_runs_pane(
             &state,
@@ -999,6 +1445,8 @@ mod tests {
             layer_label: "raw".to_owned(),
             loop_display_id: "loop-id".to_owned(),
             log_scroll: 0,
+            comparison: None,
+            log_compare: false,
         };
         let frame = render_runs_pane(
             &state,
@@ -1023,6 +1471,8 @@ mod tests {
             layer_label: "raw".to_owned(),
             loop_display_id: "lp".to_owned(),
             log_scroll: 10,
+            comparison: None,
+            log_compare: false,
         };
         let frame = render_runs_pane(
             &state,
@@ -1071,11 +1521,15 @@ mod tests {
                 started_at: "2026-02-09T10:00:00Z".to_owned(),
                 duration_display: "5m30s".to_owned(),
                 output_lines: vec!["hello from run".to_owned()],
+                attempts: None,
+                cost_usd: None,
             }],
             selected_run: 0,
             layer_label: "raw".to_owned(),
             loop_display_id: "my-loop".to_owned(),
             log_scroll: 0,
+            comparison: None,
+            log_compare: false,
         };
         let frame = render_runs_pane(
             &state,
@@ -1140,6 +1594,8 @@ mod tests {
             layer_label: "raw".to_owned(),
             loop_display_id: "my-loop".to_owned(),
             log_scroll: 0,
+            comparison: None,
+            log_compare: false,
         };
         let pal = test_palette();
         let frame = render_runs_paneled(
@@ -1179,6 +1635,8 @@ mod tests {
             layer_label: "raw".to_owned(),
             loop_display_id: "lp".to_owned(),
             log_scroll: 0,
+            comparison: None,
+            log_compare: false,
         };
         let state2 = RunsTabState {
             selected_run: 2,
@@ -1220,6 +1678,8 @@ mod tests {
             layer_label: "raw".to_owned(),
             loop_display_id: "lp".to_owned(),
             log_scroll: 0,
+            comparison: None,
+            log_compare: false,
         };
         let pal = test_palette();
         let frame = render_runs_paneled(
@@ -1272,6 +1732,8 @@ mod tests {
             layer_label: "raw".to_owned(),
             loop_display_id: "lp".to_owned(),
             log_scroll: 10,
+            comparison: None,
+            log_compare: false,
         };
         let pal = test_palette();
         let frame = render_runs_paneled(
@@ -1299,6 +1761,8 @@ mod tests {
             layer_label: "raw".to_owned(),
             loop_display_id: "loop-narrow".to_owned(),
             log_scroll: 0,
+            comparison: None,
+            log_compare: false,
         };
         let pal = test_palette();
         // 30-wide render should not panic
@@ -1315,4 +1779,65 @@ mod tests {
         let snap = frame.snapshot();
         assert!(!snap.is_empty());
     }
+
+    #[test]
+    fn compare_highlights_differing_attempts() {
+        let mut runs = sample_runs(3);
+        runs[1].attempts = Some(1);
+        runs[2].attempts = Some(3);
+        runs[1].cost_usd = Some(0.5);
+        runs[2].cost_usd = Some(0.5);
+
+        let mut selection = RunCompareSelection::default();
+        selection.toggle(1);
+        assert_eq!(
+            selection.compare(&runs),
+            Err("compare: abcdefgh selected; pick a second run".to_owned())
+        );
+        selection.toggle(2);
+        let comparison = selection
+            .compare(&runs)
+            .unwrap_or_else(|err| panic!("expected comparison: {err}"));
+
+        assert_eq!(
+            comparison.changed_fields(),
+            vec![RunCompareField::Duration, RunCompareField::Attempts]
+        );
+        let attempts = &comparison.fields[1];
+        assert_eq!(attempts.delta.as_deref(), Some("+2"));
+        assert_eq!(comparison.fields[0].delta.as_deref(), Some("+1m0s"));
+
+        let lines = run_compare_lines(&comparison, 80);
+        let Some((row, role)) = lines.iter().find(|(row, _)| row.contains("attempts")) else {
+            panic!("expected attempts row");
+        };
+        assert!(row.starts_with('*'));
+        assert_eq!(*role, TextRole::Accent);
+        let Some((cost_row, cost_role)) = lines.iter().find(|(row, _)| row.contains("cost")) else {
+            panic!("expected cost row");
+        };
+        assert!(cost_row.starts_with(' '));
+        assert_eq!(*cost_role, TextRole::Primary);
+
+        let (_, summary) = run_log_compare(&runs[1], &runs[2], 10);
+        assert_eq!(summary.different, 2);
+    }
+
+    #[test]
+    fn log_compare_lines_mark_differing_rows() {
+        let mut runs = sample_runs(2);
+        runs[0].output_lines = vec!["same".to_owned(), "left".to_owned()];
+        runs[1].output_lines = vec!["same".to_owned(), "right".to_owned(), "extra".to_owned()];
+
+        let lines = run_log_compare_lines(&runs[0], &runs[1], 60, 10);
+        assert!(lines[1]
+            .0
+            .contains("equal=1 differ=1 left-only=0 right-only=1"));
+        assert!(lines[2].0.starts_with("= same"));
+        assert_eq!(lines[2].1, TextRole::Primary);
+        assert!(lines[3].0.starts_with("! left"));
+        assert!(lines[3].0.contains("| right"));
+        assert_eq!(lines[3].1, TextRole::Accent);
+        assert!(lines[4].0.starts_with("> "));
+    }
 }
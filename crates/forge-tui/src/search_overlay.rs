@@ -429,6 +429,8 @@ mod tests {
             auth_kind: "api-key".to_owned(),
             started_at: "2026-02-13T12:00:00Z".to_owned(),
             output_lines: vec!["ok".to_owned()],
+            attempts: None,
+            cost_usd: None,
         }];
         index_runs(overlay.index_mut(), &runs, "loop-1");
         overlay.push_char('s');
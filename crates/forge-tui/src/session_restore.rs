@@ -2,6 +2,9 @@
 
 use std::collections::BTreeSet;
 
+use crate::lane_model::LaneOrder;
+use crate::task_notes::{LoopNote, LoopNotes};
+
 const SNAPSHOT_SCHEMA_VERSION: u32 = 1;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -40,6 +43,10 @@ pub struct SessionContext {
     pub filter_query: Option<String>,
     pub panes: Vec<PaneSelection>,
     pub pinned_loop_ids: Vec<String>,
+    pub loop_notes: Vec<LoopNote>,
+    /// Log lane display order as lane labels; empty for the natural order.
+    pub log_lane_order: Vec<String>,
+    pub pinned_log_lanes: Vec<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -56,6 +63,10 @@ pub struct PersistedSessionSnapshot {
     pub filter_query_digest: Option<String>,
     pub panes: Vec<PaneSelection>,
     pub pinned_loop_ids: Vec<String>,
+    pub loop_notes: Vec<LoopNote>,
+    /// Log lane display order as lane labels; empty for the natural order.
+    pub log_lane_order: Vec<String>,
+    pub pinned_log_lanes: Vec<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
@@ -98,6 +109,8 @@ pub fn snapshot_session_context(
         None
     };
 
+    let lane_order = LaneOrder::from_persisted(&context.log_lane_order, &context.pinned_log_lanes);
+
     Some(PersistedSessionSnapshot {
         schema_version: SNAPSHOT_SCHEMA_VERSION,
         saved_at_epoch_s: saved_at_epoch_s.max(0),
@@ -115,6 +128,9 @@ pub fn snapshot_session_context(
         filter_query_digest: query_digest,
         panes: normalize_panes(&context.panes),
         pinned_loop_ids: normalize_id_list(&context.pinned_loop_ids),
+        loop_notes: LoopNotes::from_persisted(&context.loop_notes).to_persisted(),
+        log_lane_order: lane_order.persisted_order(),
+        pinned_log_lanes: lane_order.persisted_pins(),
     })
 }
 
@@ -214,6 +230,19 @@ pub fn restore_session_context(
         .filter(|id| !id.is_empty() && (loop_ids.is_empty() || loop_ids.contains(id)))
         .collect::<Vec<_>>();
 
+    let mut loop_notes = LoopNotes::from_persisted(&snapshot.loop_notes);
+    if !loop_ids.is_empty() {
+        let orphaned = loop_notes.retain_loops(loop_ids.iter().map(String::as_str));
+        if orphaned > 0 {
+            notices.push(format!(
+                "{orphaned} loop note(s) dropped for loops no longer available"
+            ));
+        }
+    }
+
+    let lane_order =
+        LaneOrder::from_persisted(&snapshot.log_lane_order, &snapshot.pinned_log_lanes);
+
     RestoredSession {
         context: SessionContext {
             selected_loop_id,
@@ -225,6 +254,9 @@ pub fn restore_session_context(
             filter_query,
             panes,
             pinned_loop_ids,
+            loop_notes: loop_notes.to_persisted(),
+            log_lane_order: lane_order.persisted_order(),
+            pinned_log_lanes: lane_order.persisted_pins(),
         },
         notices,
         from_snapshot: true,
@@ -312,6 +344,24 @@ pub fn build_delta_digest(
         lines.push(format!("pinned loops changed: +{added} -{removed}"));
     }
 
+    let previous_notes = LoopNotes::from_persisted(&previous.loop_notes).to_persisted();
+    let current_notes = LoopNotes::from_persisted(&current.loop_notes).to_persisted();
+    if previous_notes != current_notes {
+        lines.push(format!(
+            "loop notes changed: {} -> {}",
+            previous_notes.len(),
+            current_notes.len()
+        ));
+    }
+
+    let previous_lanes =
+        LaneOrder::from_persisted(&previous.log_lane_order, &previous.pinned_log_lanes);
+    let current_lanes =
+        LaneOrder::from_persisted(&current.log_lane_order, &current.pinned_log_lanes);
+    if previous_lanes != current_lanes {
+        lines.push("log lane order changed".to_owned());
+    }
+
     if lines.is_empty() {
         return SessionDeltaDigest {
             headline: "no context changes since last session".to_owned(),
@@ -450,6 +500,7 @@ mod tests {
         build_delta_digest, restore_session_context, snapshot_session_context, PaneSelection,
         PersistedSessionSnapshot, RestoreUniverse, SessionContext, SessionRestorePolicy,
     };
+    use crate::task_notes::LoopNote;
 
     fn sample_context() -> SessionContext {
         SessionContext {
@@ -471,6 +522,9 @@ mod tests {
                 },
             ],
             pinned_loop_ids: vec!["loop-a".to_owned(), "loop-b".to_owned()],
+            loop_notes: Vec::new(),
+            log_lane_order: Vec::new(),
+            pinned_log_lanes: Vec::new(),
         }
     }
 
@@ -539,6 +593,20 @@ mod tests {
                 },
             ],
             pinned_loop_ids: vec!["loop-z".to_owned(), "loop-a".to_owned()],
+            loop_notes: vec![
+                LoopNote {
+                    loop_id: "loop-z".to_owned(),
+                    body: "retired loop".to_owned(),
+                    updated_at_epoch_s: 5,
+                },
+                LoopNote {
+                    loop_id: "loop-a".to_owned(),
+                    body: "Watch the retry budget".to_owned(),
+                    updated_at_epoch_s: 9,
+                },
+            ],
+            log_lane_order: vec!["event".to_owned(), "bogus".to_owned()],
+            pinned_log_lanes: vec!["stderr".to_owned()],
         };
         let universe = RestoreUniverse {
             loop_ids: vec!["loop-a".to_owned(), "loop-b".to_owned()],
@@ -558,6 +626,20 @@ mod tests {
         assert_eq!(restored.context.panes[0].pane_id, "overview");
         assert!(restored.context.panes[0].focused);
         assert_eq!(restored.context.pinned_loop_ids, vec!["loop-a".to_owned()]);
+        assert_eq!(restored.context.loop_notes.len(), 1);
+        assert_eq!(
+            restored.context.log_lane_order,
+            vec!["event", "thinking", "tool", "stdout", "stderr", "unknown"]
+        );
+        assert_eq!(restored.context.pinned_log_lanes, vec!["stderr"]);
+        assert_eq!(
+            restored.context.loop_notes[0].body,
+            "Watch the retry budget"
+        );
+        assert!(restored
+            .notices
+            .iter()
+            .any(|msg| msg.contains("1 loop note(s) dropped")));
         assert!(restored
             .notices
             .iter()
@@ -594,6 +676,9 @@ mod tests {
                 focused: true,
             }],
             pinned_loop_ids: vec!["loop-a".to_owned()],
+            loop_notes: Vec::new(),
+            log_lane_order: Vec::new(),
+            pinned_log_lanes: Vec::new(),
         };
         let current = PersistedSessionSnapshot {
             schema_version: 1,
@@ -617,6 +702,9 @@ mod tests {
                 },
             ],
             pinned_loop_ids: vec!["loop-b".to_owned(), "loop-c".to_owned()],
+            loop_notes: Vec::new(),
+            log_lane_order: Vec::new(),
+            pinned_log_lanes: Vec::new(),
         };
 
         let digest = build_delta_digest(Some(&previous), &current);
@@ -645,6 +733,9 @@ mod tests {
                 focused: true,
             }],
             pinned_loop_ids: vec!["loop-a".to_owned()],
+            loop_notes: Vec::new(),
+            log_lane_order: Vec::new(),
+            pinned_log_lanes: Vec::new(),
         };
 
         let digest = build_delta_digest(Some(&snapshot), &snapshot);
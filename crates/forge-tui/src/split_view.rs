@@ -0,0 +1,228 @@
+//! Two-pane split layout hosting independent main tabs.
+//!
+//! Each pane owns a tab and its own log scroll/follow position; only the
+//! focused pane receives input, and pane rects are re-solved from the content
+//! area on every render so resizes reflow both.
+
+use forge_ftui_adapter::render::{Constraint, Layout, Rect};
+
+use crate::app::MainTab;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SplitOrientation {
+    /// Panes side by side.
+    Horizontal,
+    /// Panes stacked top and bottom.
+    Vertical,
+}
+
+impl SplitOrientation {
+    #[must_use]
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Horizontal => "horizontal",
+            Self::Vertical => "vertical",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SplitPane {
+    Primary,
+    Secondary,
+}
+
+impl SplitPane {
+    #[must_use]
+    pub fn other(self) -> Self {
+        match self {
+            Self::Primary => Self::Secondary,
+            Self::Secondary => Self::Primary,
+        }
+    }
+}
+
+/// Log scroll position a pane keeps while the other pane has focus.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PaneScroll {
+    pub log_scroll: usize,
+    pub follow_mode: bool,
+}
+
+impl Default for PaneScroll {
+    fn default() -> Self {
+        Self {
+            log_scroll: 0,
+            follow_mode: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SplitLayout {
+    orientation: SplitOrientation,
+    primary: MainTab,
+    secondary: MainTab,
+    primary_scroll: PaneScroll,
+    secondary_scroll: PaneScroll,
+    focused: SplitPane,
+}
+
+impl SplitLayout {
+    #[must_use]
+    pub fn new(orientation: SplitOrientation, primary: MainTab, secondary: MainTab) -> Self {
+        Self {
+            orientation,
+            primary,
+            secondary,
+            primary_scroll: PaneScroll::default(),
+            secondary_scroll: PaneScroll::default(),
+            focused: SplitPane::Primary,
+        }
+    }
+
+    #[must_use]
+    pub fn orientation(&self) -> SplitOrientation {
+        self.orientation
+    }
+
+    pub fn set_orientation(&mut self, orientation: SplitOrientation) {
+        self.orientation = orientation;
+    }
+
+    #[must_use]
+    pub fn tab(&self, pane: SplitPane) -> MainTab {
+        match pane {
+            SplitPane::Primary => self.primary,
+            SplitPane::Secondary => self.secondary,
+        }
+    }
+
+    #[must_use]
+    pub fn focused(&self) -> SplitPane {
+        self.focused
+    }
+
+    #[must_use]
+    pub fn focused_tab(&self) -> MainTab {
+        self.tab(self.focused)
+    }
+
+    #[must_use]
+    pub fn scroll(&self, pane: SplitPane) -> PaneScroll {
+        match pane {
+            SplitPane::Primary => self.primary_scroll,
+            SplitPane::Secondary => self.secondary_scroll,
+        }
+    }
+
+    pub fn set_scroll(&mut self, pane: SplitPane, scroll: PaneScroll) {
+        match pane {
+            SplitPane::Primary => self.primary_scroll = scroll,
+            SplitPane::Secondary => self.secondary_scroll = scroll,
+        }
+    }
+
+    /// Move focus to the other pane, parking `current` on the pane being
+    /// left, and return the tab and scroll position the new pane resumes.
+    pub fn toggle_focus(&mut self, current: PaneScroll) -> (MainTab, PaneScroll) {
+        self.set_scroll(self.focused, current);
+        self.focused = self.focused.other();
+        (self.focused_tab(), self.scroll(self.focused))
+    }
+
+    /// Swap the tab shown in the focused pane (tab switches stay in-pane).
+    pub fn set_focused_tab(&mut self, tab: MainTab) {
+        match self.focused {
+            SplitPane::Primary => self.primary = tab,
+            SplitPane::Secondary => self.secondary = tab,
+        }
+    }
+
+    /// Solve primary/secondary rects for `area`, primary first.
+    #[must_use]
+    pub fn pane_rects(&self, area: Rect) -> (Rect, Rect) {
+        let constraints = vec![Constraint::Ratio(1, 2), Constraint::Min(0)];
+        let layout = match self.orientation {
+            SplitOrientation::Horizontal => Layout::horizontal(constraints),
+            SplitOrientation::Vertical => Layout::vertical(constraints),
+        };
+        let rects = layout.split(area);
+        let empty = Rect {
+            x: area.x,
+            y: area.y,
+            width: 0,
+            height: 0,
+        };
+        (
+            rects.first().copied().unwrap_or(empty),
+            rects.get(1).copied().unwrap_or(empty),
+        )
+    }
+}
+
+/// Default companion tab when a split opens from `tab`: logs, or the
+/// overview when logs are already showing.
+#[must_use]
+pub fn default_split_partner(tab: MainTab) -> MainTab {
+    if tab == MainTab::Logs {
+        MainTab::Overview
+    } else {
+        MainTab::Logs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use forge_ftui_adapter::render::Rect;
+
+    use super::{PaneScroll, SplitLayout, SplitOrientation, SplitPane};
+    use crate::app::MainTab;
+
+    #[test]
+    fn pane_rects_follow_orientation_and_cover_area() {
+        let mut split =
+            SplitLayout::new(SplitOrientation::Horizontal, MainTab::Logs, MainTab::Inbox);
+        let area = Rect {
+            x: 0,
+            y: 2,
+            width: 81,
+            height: 20,
+        };
+        let (left, right) = split.pane_rects(area);
+        assert_eq!((left.x, left.width, left.height), (0, 40, 20));
+        assert_eq!((right.x, right.width), (40, 41));
+
+        split.set_orientation(SplitOrientation::Vertical);
+        let (top, bottom) = split.pane_rects(area);
+        assert_eq!((top.y, top.height, top.width), (2, 10, 81));
+        assert_eq!((bottom.y, bottom.height), (12, 10));
+
+        assert_eq!(
+            split.toggle_focus(PaneScroll::default()),
+            (MainTab::Inbox, PaneScroll::default())
+        );
+        assert_eq!(split.focused(), SplitPane::Secondary);
+        split.set_focused_tab(MainTab::Runs);
+        assert_eq!(split.tab(SplitPane::Secondary), MainTab::Runs);
+        assert_eq!(split.tab(SplitPane::Primary), MainTab::Logs);
+    }
+
+    #[test]
+    fn toggle_focus_parks_and_restores_pane_scroll() {
+        let mut split = SplitLayout::new(SplitOrientation::Vertical, MainTab::Logs, MainTab::Runs);
+        let scrolled = PaneScroll {
+            log_scroll: 40,
+            follow_mode: false,
+        };
+        let (_, restored) = split.toggle_focus(scrolled);
+        assert_eq!(restored, PaneScroll::default());
+
+        let (tab, restored) = split.toggle_focus(PaneScroll {
+            log_scroll: 3,
+            follow_mode: false,
+        });
+        assert_eq!((tab, restored), (MainTab::Logs, scrolled));
+        assert_eq!(split.scroll(SplitPane::Secondary).log_scroll, 3);
+    }
+}
@@ -3,6 +3,14 @@
 //! Models stale task/loop detection with explicit false-positive mitigation
 //! controls so TUI can surface safer recommendations.
 
+use forge_cli::resume::{ResumeBackend, ResumeResult, SpawnOptions};
+use forge_db::event_repository::{Event, EventRepository};
+use forge_db::loop_repository::{LoopRepository, LoopState};
+use forge_db::loop_run_repository::{LoopRunRepository, LoopRunStatus};
+use forge_db::Db;
+use forge_loop::stale_runner::{self, RunnerLiveness, LOOP_STALE_RUNNER_REASON};
+use serde_json::json;
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct StaleTaskSample {
     pub task_id: String,
@@ -258,6 +266,195 @@ fn evaluate_task(
     });
 }
 
+pub const LOOP_TAKEOVER_AUDIT_EVENT: &str = "loop.takeover";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoopTakeoverAudit {
+    pub event_type: String,
+    pub loop_id: String,
+    pub operator: String,
+    pub previous_owner: Option<String>,
+    pub stale_for_secs: u64,
+    pub reason: String,
+    pub at_epoch_s: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoopTakeover {
+    pub loop_id: String,
+    pub previous_owner: Option<String>,
+    pub new_owner: String,
+    pub reclaimed: StaleLoopSample,
+    pub audit: LoopTakeoverAudit,
+    pub command_hints: Vec<String>,
+}
+
+/// Claim a stale loop for `operator`.
+///
+/// The runner must be dead by forge-loop's `should_mark_loop_stale`, and the
+/// loop must also pass the stale report's rules, so a loop with a recent
+/// heartbeat or activity is refused rather than taken over. On success the
+/// plan carries the reclaimed sample, the audit event, and the command that
+/// restarts the loop on a fresh iteration; [`apply_loop_takeover`] persists it.
+pub fn plan_loop_takeover(
+    loop_entry: &StaleLoopSample,
+    runner: &RunnerLiveness,
+    daemon_reachable: bool,
+    operator: &str,
+    now_epoch_s: i64,
+    policy: &StaleDetectionPolicy,
+) -> Result<LoopTakeover, String> {
+    let operator = normalize_required(operator);
+    if operator.is_empty() {
+        return Err("takeover requires an operator".to_owned());
+    }
+    let loop_id = normalize_required(&loop_entry.loop_id);
+    if loop_id.is_empty() {
+        return Err("takeover requires a loop id".to_owned());
+    }
+
+    let Some(runner_state) = runner_loop_state(&loop_entry.state) else {
+        return Err(format!(
+            "takeover refused for {loop_id}: unknown state '{}'",
+            loop_entry.state.trim()
+        ));
+    };
+    if !stale_runner::should_mark_loop_stale(&runner_state, runner, daemon_reachable) {
+        return Err(format!(
+            "takeover refused for {loop_id}: runner is still alive or loop is not running"
+        ));
+    }
+
+    let now_epoch_s = now_epoch_s.max(0);
+    let mut alerts = Vec::new();
+    let mut suppressed = Vec::new();
+    evaluate_loop(
+        loop_entry,
+        now_epoch_s,
+        policy,
+        &mut alerts,
+        &mut suppressed,
+    );
+    let Some(alert) = alerts.pop() else {
+        let reason = suppressed
+            .pop()
+            .map(|candidate| candidate.reason)
+            .unwrap_or_else(|| "loop is not stale".to_owned());
+        return Err(format!("takeover refused for {loop_id}: {reason}"));
+    };
+
+    let previous_owner = alert.owner.clone();
+    if previous_owner.as_deref() == Some(operator.as_str()) {
+        return Err(format!(
+            "takeover refused for {loop_id}: already owned by {operator}"
+        ));
+    }
+
+    let mut reclaimed = loop_entry.clone();
+    reclaimed.owner = Some(operator.clone());
+    reclaimed.updated_at_epoch_s = now_epoch_s;
+    reclaimed.last_activity_epoch_s = Some(now_epoch_s);
+    reclaimed.stale_observation_count = 0;
+
+    let audit = LoopTakeoverAudit {
+        event_type: LOOP_TAKEOVER_AUDIT_EVENT.to_owned(),
+        loop_id: loop_id.clone(),
+        operator: operator.clone(),
+        previous_owner: previous_owner.clone(),
+        stale_for_secs: alert.stale_for_secs,
+        reason: alert.reasons.join("; "),
+        at_epoch_s: now_epoch_s,
+    };
+
+    Ok(LoopTakeover {
+        command_hints: vec![format!("forge resume {loop_id}")],
+        loop_id,
+        previous_owner,
+        new_owner: operator,
+        reclaimed,
+        audit,
+    })
+}
+
+/// Persist a planned takeover and restart the loop. In one transaction the
+/// dead runner's loop runs are closed as killed, the loop is stopped and
+/// handed to the operator, and the audit event is appended; the loop is then
+/// resumed through `resume` so it picks up a fresh iteration on a new runner.
+pub fn apply_loop_takeover(
+    db: &Db,
+    takeover: &LoopTakeover,
+    resume: &mut dyn ResumeBackend,
+) -> Result<ResumeResult, String> {
+    reclaim_loop(db, takeover)?;
+    resume
+        .resume_loop(
+            &takeover.loop_id,
+            "auto",
+            &SpawnOptions::default(),
+            &mut std::io::sink(),
+        )
+        .map_err(|err| format!("resume {} after takeover: {err}", takeover.loop_id))
+}
+
+fn reclaim_loop(db: &Db, takeover: &LoopTakeover) -> Result<(), String> {
+    db.immediate_transaction(|db| {
+        let run_repo = LoopRunRepository::new(db);
+        for mut run in run_repo.list_by_loop(&takeover.loop_id)? {
+            if run.status == LoopRunStatus::Running {
+                run.status = LoopRunStatus::Killed;
+                run_repo.finish(&mut run)?;
+            }
+        }
+
+        let loop_repo = LoopRepository::new(db);
+        let mut entry = loop_repo.get(&takeover.loop_id)?;
+        entry.state = LoopState::Stopped;
+        entry.last_error = LOOP_STALE_RUNNER_REASON.to_owned();
+        let mut metadata = entry.metadata.take().unwrap_or_default();
+        metadata.insert("owner".to_owned(), json!(takeover.new_owner));
+        metadata.insert(
+            "takeover".to_owned(),
+            json!({
+                "operator": takeover.audit.operator,
+                "previous_owner": takeover.audit.previous_owner,
+                "at_epoch_s": takeover.audit.at_epoch_s,
+            }),
+        );
+        entry.metadata = Some(metadata);
+        loop_repo.update(&mut entry)?;
+
+        let mut event = Event {
+            event_type: takeover.audit.event_type.clone(),
+            entity_type: "system".to_owned(),
+            entity_id: takeover.loop_id.clone(),
+            payload: json!({
+                "loop_id": takeover.audit.loop_id,
+                "operator": takeover.audit.operator,
+                "previous_owner": takeover.audit.previous_owner,
+                "stale_for_secs": takeover.audit.stale_for_secs,
+                "reason": takeover.audit.reason,
+            })
+            .to_string(),
+            ..Event::default()
+        };
+        EventRepository::new(db).create(&mut event)
+    })
+    .map_err(|err| format!("takeover {}: {err}", takeover.loop_id))
+}
+
+fn runner_loop_state(state: &str) -> Option<stale_runner::LoopState> {
+    match normalize_required(state).as_str() {
+        "pending" => Some(stale_runner::LoopState::Pending),
+        "running" => Some(stale_runner::LoopState::Running),
+        "sleeping" => Some(stale_runner::LoopState::Sleeping),
+        "waiting" => Some(stale_runner::LoopState::Waiting),
+        "paused" => Some(stale_runner::LoopState::Paused),
+        "stopped" => Some(stale_runner::LoopState::Stopped),
+        "error" => Some(stale_runner::LoopState::Error),
+        _ => None,
+    }
+}
+
 fn evaluate_loop(
     loop_entry: &StaleLoopSample,
     now_epoch_s: i64,
@@ -407,9 +604,24 @@ fn age_seconds(now_epoch_s: i64, then_epoch_s: i64) -> u64 {
 #[cfg(test)]
 mod tests {
     use super::{
-        build_stale_takeover_report, StaleDetectionPolicy, StaleEntityKind, StaleLoopSample,
-        StaleSeverity, StaleTaskSample,
+        apply_loop_takeover, build_stale_takeover_report, plan_loop_takeover, StaleDetectionPolicy,
+        StaleEntityKind, StaleLoopSample, StaleSeverity, StaleTaskSample,
+        LOOP_TAKEOVER_AUDIT_EVENT,
     };
+    use forge_cli::resume::{self, InMemoryResumeBackend, LoopRecord, ResumeBackend};
+    use forge_db::event_repository::EventRepository;
+    use forge_db::loop_repository::{Loop, LoopRepository, LoopState};
+    use forge_db::loop_run_repository::{LoopRun, LoopRunRepository, LoopRunStatus};
+    use forge_loop::stale_runner::RunnerLiveness;
+
+    fn dead_runner() -> RunnerLiveness {
+        RunnerLiveness {
+            owner: "local".to_owned(),
+            instance_id: String::new(),
+            pid_alive: Some(false),
+            daemon_alive: None,
+        }
+    }
 
     fn sample_policy() -> StaleDetectionPolicy {
         StaleDetectionPolicy {
@@ -572,4 +784,166 @@ mod tests {
             .reason
             .contains("queue depth 0 < minimum 1"));
     }
+
+    #[test]
+    fn takeover_claims_stale_loop_and_refuses_live_one() {
+        let stale = StaleLoopSample {
+            loop_id: "loop-a".to_owned(),
+            state: "running".to_owned(),
+            owner: Some("agent-a".to_owned()),
+            updated_at_epoch_s: 1_000,
+            stale_observation_count: 3,
+            last_activity_epoch_s: Some(100),
+            queue_depth: 3,
+            active_tasks: 1,
+        };
+        let takeover = plan_loop_takeover(
+            &stale,
+            &dead_runner(),
+            false,
+            "Operator-B",
+            4_000,
+            &sample_policy(),
+        )
+        .unwrap_or_else(|err| panic!("stale loop should be claimable: {err}"));
+        assert_eq!(takeover.previous_owner.as_deref(), Some("agent-a"));
+        assert_eq!(takeover.new_owner, "operator-b");
+        assert_eq!(takeover.reclaimed.owner.as_deref(), Some("operator-b"));
+        assert_eq!(takeover.reclaimed.stale_observation_count, 0);
+        assert_eq!(takeover.audit.event_type, LOOP_TAKEOVER_AUDIT_EVENT);
+        assert_eq!(takeover.audit.operator, "operator-b");
+        assert_eq!(takeover.audit.stale_for_secs, 3_000);
+        assert_eq!(
+            takeover.command_hints.last().map(String::as_str),
+            Some("forge resume loop-a")
+        );
+
+        let live_runner = RunnerLiveness {
+            pid_alive: Some(true),
+            ..dead_runner()
+        };
+        let err = match plan_loop_takeover(
+            &stale,
+            &live_runner,
+            false,
+            "operator-b",
+            4_000,
+            &sample_policy(),
+        ) {
+            Ok(_) => panic!("loop with a live runner must not be taken over"),
+            Err(err) => err,
+        };
+        assert!(err.contains("runner is still alive"), "{err}");
+
+        let recent = StaleLoopSample {
+            last_activity_epoch_s: Some(3_900),
+            ..stale
+        };
+        let err = match plan_loop_takeover(
+            &recent,
+            &dead_runner(),
+            false,
+            "operator-b",
+            4_000,
+            &sample_policy(),
+        ) {
+            Ok(_) => panic!("recently active loop must not be taken over"),
+            Err(err) => err,
+        };
+        assert!(err.contains("recent activity"), "{err}");
+    }
+
+    #[test]
+    fn applied_takeover_reclaims_run_and_writes_audit_event() {
+        let path = std::env::temp_dir().join(format!(
+            "forge-tui-takeover-{}-{}.sqlite",
+            std::process::id(),
+            unique_suffix()
+        ));
+        let mut db = forge_db::Db::open(forge_db::Config::new(&path))
+            .unwrap_or_else(|err| panic!("open db: {err}"));
+        db.migrate_up()
+            .unwrap_or_else(|err| panic!("migrate: {err}"));
+
+        let mut entry = Loop {
+            name: "takeover-loop".to_owned(),
+            repo_path: "/repo".to_owned(),
+            state: LoopState::Running,
+            ..Loop::default()
+        };
+        LoopRepository::new(&db)
+            .create(&mut entry)
+            .unwrap_or_else(|err| panic!("create loop: {err}"));
+        let mut run = LoopRun {
+            loop_id: entry.id.clone(),
+            status: LoopRunStatus::Running,
+            ..LoopRun::default()
+        };
+        LoopRunRepository::new(&db)
+            .create(&mut run)
+            .unwrap_or_else(|err| panic!("create run: {err}"));
+
+        let sample = StaleLoopSample {
+            loop_id: entry.id.clone(),
+            state: "running".to_owned(),
+            owner: Some("agent-a".to_owned()),
+            updated_at_epoch_s: 1_000,
+            stale_observation_count: 3,
+            last_activity_epoch_s: Some(100),
+            queue_depth: 1,
+            active_tasks: 0,
+        };
+        let takeover = plan_loop_takeover(
+            &sample,
+            &dead_runner(),
+            false,
+            "operator-b",
+            4_000,
+            &sample_policy(),
+        )
+        .unwrap_or_else(|err| panic!("plan takeover: {err}"));
+        let mut backend = InMemoryResumeBackend::with_loops(vec![LoopRecord {
+            id: entry.id.clone(),
+            short_id: entry.short_id.clone(),
+            name: entry.name.clone(),
+            state: resume::LoopState::Stopped,
+            runner_owner: String::new(),
+            runner_instance_id: String::new(),
+        }]);
+        let resumed = apply_loop_takeover(&db, &takeover, &mut backend)
+            .unwrap_or_else(|err| panic!("apply: {err}"));
+        assert_eq!(resumed.owner, "auto");
+        let loops = backend
+            .list_loops()
+            .unwrap_or_else(|err| panic!("list loops: {err}"));
+        assert_eq!(loops[0].state, resume::LoopState::Running);
+
+        let run = LoopRunRepository::new(&db)
+            .get(&run.id)
+            .unwrap_or_else(|err| panic!("get run: {err}"));
+        assert_eq!(run.status, LoopRunStatus::Killed);
+        let entry = LoopRepository::new(&db)
+            .get(&entry.id)
+            .unwrap_or_else(|err| panic!("get loop: {err}"));
+        assert_eq!(entry.state, LoopState::Stopped);
+        assert_eq!(
+            entry.metadata.as_ref().and_then(|meta| meta.get("owner")),
+            Some(&serde_json::json!("operator-b"))
+        );
+        let event = EventRepository::new(&db)
+            .latest_by_entity(LOOP_TAKEOVER_AUDIT_EVENT, "system", &entry.id)
+            .unwrap_or_else(|err| panic!("read audit: {err}"));
+        let Some(event) = event else {
+            panic!("expected takeover audit event");
+        };
+        assert!(event.payload.contains("\"operator\":\"operator-b\""));
+
+        let _ = std::fs::remove_file(path);
+    }
+
+    fn unique_suffix() -> u128 {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_nanos())
+    }
 }
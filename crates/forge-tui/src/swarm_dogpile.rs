@@ -153,6 +153,167 @@ pub fn detect_dogpile_report(
     DogpileReport { alerts, actions }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResourceTouchSample {
+    pub loop_id: String,
+    /// Contended key: a file path, task id, or other shared target.
+    pub resource: String,
+    pub touched_at_epoch_s: i64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DogpilePolicy {
+    pub now_epoch_s: i64,
+    pub window_s: i64,
+    pub min_score: f64,
+}
+
+impl Default for DogpilePolicy {
+    fn default() -> Self {
+        Self {
+            now_epoch_s: 0,
+            window_s: 300,
+            min_score: 3.0,
+        }
+    }
+}
+
+/// Weight of each touch beyond a loop's first on the same resource.
+const REPEAT_TOUCH_WEIGHT: f64 = 0.25;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Dogpile {
+    pub key: String,
+    /// Participants ordered by first touch; the first one is the keeper.
+    pub participant_loop_ids: Vec<String>,
+    pub touches: usize,
+    pub score: f64,
+}
+
+impl Dogpile {
+    #[must_use]
+    pub fn keeper_loop_id(&self) -> Option<&str> {
+        self.participant_loop_ids.first().map(String::as_str)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DogpileRemediation {
+    PauseAllButOne,
+    Stagger,
+}
+
+impl DogpileRemediation {
+    #[must_use]
+    pub fn from_key(key: char) -> Option<Self> {
+        match key {
+            'p' => Some(Self::PauseAllButOne),
+            's' => Some(Self::Stagger),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DogpileRemediationStep {
+    pub loop_id: String,
+    pub delay_s: u64,
+    pub command_hint: String,
+}
+
+/// Score each contended resource by its distinct loops in the window, plus a
+/// small weight for repeat touches; report those at or above `min_score`.
+#[must_use]
+pub fn detect(samples: &[ResourceTouchSample], policy: &DogpilePolicy) -> Vec<Dogpile> {
+    let window_start = policy.now_epoch_s.saturating_sub(policy.window_s.max(0));
+    let mut by_key: BTreeMap<String, Vec<&ResourceTouchSample>> = BTreeMap::new();
+    for sample in samples {
+        let key = sample.resource.trim();
+        if key.is_empty() || sample.loop_id.trim().is_empty() {
+            continue;
+        }
+        if policy.window_s > 0 && sample.touched_at_epoch_s < window_start {
+            continue;
+        }
+        by_key.entry(key.to_owned()).or_default().push(sample);
+    }
+
+    let mut dogpiles = Vec::new();
+    for (key, mut touches) in by_key {
+        touches.sort_by(|a, b| {
+            a.touched_at_epoch_s
+                .cmp(&b.touched_at_epoch_s)
+                .then(a.loop_id.trim().cmp(b.loop_id.trim()))
+        });
+        let mut participant_loop_ids = Vec::new();
+        let mut seen = BTreeSet::new();
+        for touch in &touches {
+            let loop_id = touch.loop_id.trim();
+            if seen.insert(loop_id.to_owned()) {
+                participant_loop_ids.push(loop_id.to_owned());
+            }
+        }
+        let repeats = touches.len() - participant_loop_ids.len();
+        let score = participant_loop_ids.len() as f64 + repeats as f64 * REPEAT_TOUCH_WEIGHT;
+        if participant_loop_ids.len() < 2 || score < policy.min_score {
+            continue;
+        }
+        dogpiles.push(Dogpile {
+            key,
+            participant_loop_ids,
+            touches: touches.len(),
+            score,
+        });
+    }
+
+    dogpiles.sort_by(|a, b| b.score.total_cmp(&a.score).then(a.key.cmp(&b.key)));
+    dogpiles
+}
+
+#[must_use]
+pub fn render_dogpile_alert(dogpile: &Dogpile) -> String {
+    format!(
+        "dogpile {}: {} loops (score {:.1})  p: pause all but {}  s: stagger",
+        dogpile.key,
+        dogpile.participant_loop_ids.len(),
+        dogpile.score,
+        dogpile.keeper_loop_id().unwrap_or("-")
+    )
+}
+
+/// Steps for the chosen remediation. The keeper keeps running; the others are
+/// paused, or paused and resumed one `stagger_s` apart.
+#[must_use]
+pub fn plan_dogpile_remediation(
+    dogpile: &Dogpile,
+    remediation: DogpileRemediation,
+    stagger_s: u64,
+) -> Vec<DogpileRemediationStep> {
+    dogpile
+        .participant_loop_ids
+        .iter()
+        .skip(1)
+        .enumerate()
+        .map(|(index, loop_id)| match remediation {
+            DogpileRemediation::PauseAllButOne => DogpileRemediationStep {
+                loop_id: loop_id.clone(),
+                delay_s: 0,
+                command_hint: format!("forge pause {loop_id}"),
+            },
+            DogpileRemediation::Stagger => {
+                let delay_s = stagger_s.max(1) * (index as u64 + 1);
+                DogpileRemediationStep {
+                    loop_id: loop_id.clone(),
+                    delay_s,
+                    command_hint: format!(
+                        "forge pause {loop_id}; sleep {delay_s}; forge resume {loop_id}"
+                    ),
+                }
+            }
+        })
+        .collect()
+}
+
 fn select_redistribution_target(
     loop_loads: &[LoopLoadSample],
     claimant_loops: &[String],
@@ -185,7 +346,10 @@ fn normalize_non_empty(value: &str, fallback: &str) -> String {
 
 #[cfg(test)]
 mod tests {
-    use super::{detect_dogpile_report, LoopLoadSample, TaskClaimSample};
+    use super::{
+        detect, detect_dogpile_report, plan_dogpile_remediation, render_dogpile_alert,
+        DogpilePolicy, DogpileRemediation, LoopLoadSample, ResourceTouchSample, TaskClaimSample,
+    };
 
     #[test]
     fn detects_dogpile_when_task_has_multiple_claimants() {
@@ -305,4 +469,93 @@ mod tests {
         let report = detect_dogpile_report(&claims, &[], 1);
         assert!(report.alerts.is_empty());
     }
+
+    fn touch(loop_id: &str, resource: &str, at: i64) -> ResourceTouchSample {
+        ResourceTouchSample {
+            loop_id: loop_id.to_owned(),
+            resource: resource.to_owned(),
+            touched_at_epoch_s: at,
+        }
+    }
+
+    fn policy() -> DogpilePolicy {
+        DogpilePolicy {
+            now_epoch_s: 1_000,
+            window_s: 300,
+            min_score: 3.0,
+        }
+    }
+
+    #[test]
+    fn loops_piling_on_one_resource_form_a_dogpile() {
+        let samples = vec![
+            touch("loop-c", "src/app.rs", 930),
+            touch("loop-a", "src/app.rs", 910),
+            touch("loop-b", "src/app.rs", 920),
+            touch("loop-d", "src/app.rs", 940),
+            touch("loop-a", "src/app.rs", 950),
+            touch("loop-b", "src/app.rs", 960),
+            touch("loop-a", "README.md", 950),
+        ];
+        let dogpiles = detect(&samples, &policy());
+
+        assert_eq!(dogpiles.len(), 1);
+        assert_eq!(dogpiles[0].key, "src/app.rs");
+        assert_eq!(
+            dogpiles[0].participant_loop_ids,
+            vec!["loop-a", "loop-b", "loop-c", "loop-d"]
+        );
+        assert_eq!(dogpiles[0].keeper_loop_id(), Some("loop-a"));
+        assert!((dogpiles[0].score - 4.5).abs() < 1e-9);
+        assert_eq!(
+            render_dogpile_alert(&dogpiles[0]),
+            "dogpile src/app.rs: 4 loops (score 4.5)  p: pause all but loop-a  s: stagger"
+        );
+    }
+
+    #[test]
+    fn fewer_loops_than_threshold_or_stale_touches_do_not_dogpile() {
+        let samples = vec![
+            touch("loop-a", "src/app.rs", 910),
+            touch("loop-b", "src/app.rs", 920),
+            touch("loop-c", "src/app.rs", 100),
+        ];
+        assert!(detect(&samples, &policy()).is_empty());
+    }
+
+    #[test]
+    fn remediation_keys_plan_pause_or_stagger_for_non_keepers() {
+        let samples = vec![
+            touch("loop-a", "task-9", 910),
+            touch("loop-b", "task-9", 920),
+            touch("loop-c", "task-9", 930),
+        ];
+        let dogpiles = detect(&samples, &policy());
+        let Some(dogpile) = dogpiles.first() else {
+            panic!("expected dogpile");
+        };
+
+        let Some(pause) = DogpileRemediation::from_key('p') else {
+            panic!("p should map to a remediation");
+        };
+        let steps = plan_dogpile_remediation(dogpile, pause, 30);
+        assert_eq!(
+            steps
+                .iter()
+                .map(|step| step.command_hint.as_str())
+                .collect::<Vec<_>>(),
+            vec!["forge pause loop-b", "forge pause loop-c"]
+        );
+
+        let steps = plan_dogpile_remediation(dogpile, DogpileRemediation::Stagger, 30);
+        assert_eq!(
+            steps.iter().map(|step| step.delay_s).collect::<Vec<_>>(),
+            vec![30, 60]
+        );
+        assert_eq!(
+            steps[0].command_hint,
+            "forge pause loop-b; sleep 30; forge resume loop-b"
+        );
+        assert_eq!(DogpileRemediation::from_key('x'), None);
+    }
 }
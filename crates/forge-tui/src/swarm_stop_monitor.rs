@@ -1,5 +1,7 @@
 //! Quant/qual stop-condition monitor for swarm orchestration.
 
+use std::collections::BTreeMap;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ThresholdDirection {
     AtMost,
@@ -174,6 +176,180 @@ pub fn evaluate_stop_signal_report(samples: &[LoopStopSignalSample]) -> StopSign
     }
 }
 
+pub const DEFAULT_STOP_ACK_TIMEOUT_SECS: u64 = 60;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LoopStopPhase {
+    Requested,
+    Acknowledged,
+    Stopped,
+}
+
+impl LoopStopPhase {
+    #[must_use]
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Requested => "requested",
+            Self::Acknowledged => "acknowledged",
+            Self::Stopped => "stopped",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoopStopTracking {
+    pub loop_id: String,
+    pub phase: LoopStopPhase,
+    pub requested_at_epoch_s: i64,
+    pub phase_changed_at_epoch_s: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StuckStopLoop {
+    pub loop_id: String,
+    pub phase: LoopStopPhase,
+    pub waiting_secs: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct StopProgress {
+    pub total: usize,
+    pub requested: usize,
+    pub acknowledged: usize,
+    pub stopped: usize,
+    pub stuck: Vec<StuckStopLoop>,
+}
+
+impl StopProgress {
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.total > 0 && self.stopped == self.total
+    }
+}
+
+/// Tracks stop propagation across the loops of one swarm stop request.
+///
+/// Phases only move forward; late or duplicate events for an earlier phase
+/// are ignored. Loops that have not reached `Stopped` within the timeout of
+/// their last transition are reported as stuck.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StopPropagationTracker {
+    swarm_id: String,
+    timeout_secs: u64,
+    loops: BTreeMap<String, LoopStopTracking>,
+}
+
+impl StopPropagationTracker {
+    #[must_use]
+    pub fn new(swarm_id: &str, timeout_secs: u64) -> Self {
+        Self {
+            swarm_id: normalize_or_fallback(swarm_id, "unknown-swarm"),
+            timeout_secs: timeout_secs.max(1),
+            loops: BTreeMap::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn swarm_id(&self) -> &str {
+        &self.swarm_id
+    }
+
+    pub fn request_stop(&mut self, loop_ids: &[String], now_epoch_s: i64) {
+        for loop_id in loop_ids {
+            let loop_id = loop_id.trim();
+            if loop_id.is_empty() || self.loops.contains_key(loop_id) {
+                continue;
+            }
+            self.loops.insert(
+                loop_id.to_owned(),
+                LoopStopTracking {
+                    loop_id: loop_id.to_owned(),
+                    phase: LoopStopPhase::Requested,
+                    requested_at_epoch_s: now_epoch_s,
+                    phase_changed_at_epoch_s: now_epoch_s,
+                },
+            );
+        }
+    }
+
+    /// Apply a stop event for one loop. Returns true when the phase advanced.
+    pub fn observe(&mut self, loop_id: &str, phase: LoopStopPhase, at_epoch_s: i64) -> bool {
+        let Some(entry) = self.loops.get_mut(loop_id.trim()) else {
+            return false;
+        };
+        if phase <= entry.phase {
+            return false;
+        }
+        entry.phase = phase;
+        entry.phase_changed_at_epoch_s = at_epoch_s;
+        true
+    }
+
+    #[must_use]
+    pub fn loop_phase(&self, loop_id: &str) -> Option<LoopStopPhase> {
+        self.loops.get(loop_id.trim()).map(|entry| entry.phase)
+    }
+
+    #[must_use]
+    pub fn progress(&self, now_epoch_s: i64) -> StopProgress {
+        let mut progress = StopProgress {
+            total: self.loops.len(),
+            ..StopProgress::default()
+        };
+        for entry in self.loops.values() {
+            match entry.phase {
+                LoopStopPhase::Requested => progress.requested += 1,
+                LoopStopPhase::Acknowledged => progress.acknowledged += 1,
+                LoopStopPhase::Stopped => {
+                    progress.stopped += 1;
+                    continue;
+                }
+            }
+            let waiting_secs = if now_epoch_s > entry.phase_changed_at_epoch_s {
+                (now_epoch_s - entry.phase_changed_at_epoch_s) as u64
+            } else {
+                0
+            };
+            if waiting_secs >= self.timeout_secs {
+                progress.stuck.push(StuckStopLoop {
+                    loop_id: entry.loop_id.clone(),
+                    phase: entry.phase,
+                    waiting_secs,
+                });
+            }
+        }
+        progress.stuck.sort_by(|a, b| {
+            b.waiting_secs
+                .cmp(&a.waiting_secs)
+                .then(a.loop_id.cmp(&b.loop_id))
+        });
+        progress
+    }
+
+    #[must_use]
+    pub fn summary_lines(&self, now_epoch_s: i64) -> Vec<String> {
+        let progress = self.progress(now_epoch_s);
+        let mut lines = vec![format!(
+            "stop {}: {}/{} stopped  ack:{}  pending:{}  stuck:{}",
+            self.swarm_id,
+            progress.stopped,
+            progress.total,
+            progress.acknowledged,
+            progress.requested,
+            progress.stuck.len()
+        )];
+        for stuck in &progress.stuck {
+            lines.push(format!(
+                "! {} stuck in {} for {}s",
+                stuck.loop_id,
+                stuck.phase.label(),
+                stuck.waiting_secs
+            ));
+        }
+        lines
+    }
+}
+
 fn is_breached(direction: ThresholdDirection, current: i64, threshold: i64) -> bool {
     match direction {
         ThresholdDirection::AtMost => current > threshold,
@@ -208,8 +384,8 @@ fn normalize_or_fallback(value: &str, fallback: &str) -> String {
 #[cfg(test)]
 mod tests {
     use super::{
-        evaluate_stop_signal_report, LoopStopSignalSample, QualSignalSample, QuantThresholdSample,
-        StopSignalState, ThresholdDirection,
+        evaluate_stop_signal_report, LoopStopPhase, LoopStopSignalSample, QualSignalSample,
+        QuantThresholdSample, StopPropagationTracker, StopSignalState, ThresholdDirection,
     };
 
     #[test]
@@ -300,4 +476,47 @@ mod tests {
         assert_eq!(report.rows[1].swarm_id, "swarm-b");
         assert_eq!(report.rows[1].loop_id, "loop-z");
     }
+
+    #[test]
+    fn stop_progress_tracks_phases_and_flags_stuck_loops() {
+        let mut tracker = StopPropagationTracker::new("swarm-1", 60);
+        tracker.request_stop(
+            &[
+                "loop-a".to_owned(),
+                "loop-b".to_owned(),
+                "loop-c".to_owned(),
+            ],
+            1_000,
+        );
+
+        let progress = tracker.progress(1_000);
+        assert_eq!(progress.total, 3);
+        assert_eq!(progress.requested, 3);
+        assert!(progress.stuck.is_empty());
+
+        assert!(tracker.observe("loop-a", LoopStopPhase::Acknowledged, 1_010));
+        assert!(tracker.observe("loop-a", LoopStopPhase::Stopped, 1_020));
+        assert!(tracker.observe("loop-b", LoopStopPhase::Acknowledged, 1_030));
+        assert!(!tracker.observe("loop-a", LoopStopPhase::Acknowledged, 1_040));
+
+        let progress = tracker.progress(1_050);
+        assert_eq!(progress.stopped, 1);
+        assert_eq!(progress.acknowledged, 1);
+        assert_eq!(progress.requested, 1);
+        assert!(progress.stuck.is_empty());
+        assert!(!progress.is_complete());
+
+        let progress = tracker.progress(1_070);
+        assert_eq!(progress.stuck.len(), 1);
+        assert_eq!(progress.stuck[0].loop_id, "loop-c");
+        assert_eq!(progress.stuck[0].phase, LoopStopPhase::Requested);
+        assert_eq!(progress.stuck[0].waiting_secs, 70);
+
+        let lines = tracker.summary_lines(1_070);
+        assert_eq!(
+            lines[0],
+            "stop swarm-1: 1/3 stopped  ack:1  pending:1  stuck:1"
+        );
+        assert_eq!(lines[1], "! loop-c stuck in requested for 70s");
+    }
 }
@@ -1,5 +1,9 @@
 //! Swarm template library and spawn presets for Forge TUI.
 
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::bulk_action_planner::shell_quote;
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SwarmTemplate {
     pub id: &'static str,
@@ -225,6 +229,181 @@ pub fn find_swarm_template(id_or_title: &str) -> Option<SwarmTemplate> {
     })
 }
 
+/// Parameter values keyed by name: `repo`, `count`, `profile`, lane-scoped
+/// `<lane>.count` / `<lane>.profile`, or any `{name}` placeholder used by the
+/// template's presets.
+pub type SwarmTemplateParams = BTreeMap<String, String>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SwarmSpecLane {
+    pub lane: String,
+    pub profile: String,
+    pub prompt: String,
+    pub count: usize,
+}
+
+/// Concrete swarm produced from a template; consumed by the up/scale path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SwarmSpec {
+    pub template_id: String,
+    pub repo: String,
+    pub max_concurrency: usize,
+    pub lanes: Vec<SwarmSpecLane>,
+    pub guardrails: SwarmGuardrails,
+}
+
+impl SwarmSpec {
+    #[must_use]
+    pub fn total_loops(&self) -> usize {
+        self.lanes.iter().map(|lane| lane.count).sum()
+    }
+
+    #[must_use]
+    pub fn up_commands(&self) -> Vec<String> {
+        self.lanes
+            .iter()
+            .map(|lane| {
+                format!(
+                    "forge -C {} up --profile {} --prompt {} --count {} --name-prefix {}",
+                    shell_quote(&self.repo),
+                    shell_quote(&lane.profile),
+                    shell_quote(&lane.prompt),
+                    lane.count,
+                    shell_quote(&format!("{}-{}", self.template_id, lane.lane))
+                )
+            })
+            .collect()
+    }
+}
+
+/// Instantiate `template` with `params`. `repo` and every `{name}` placeholder
+/// are required; `count` applies to the first (primary) lane and `profile` to
+/// all lanes unless a lane-scoped value overrides it. All problems are
+/// reported together.
+pub fn instantiate(
+    template: &SwarmTemplate,
+    params: &SwarmTemplateParams,
+) -> Result<SwarmSpec, String> {
+    let params: BTreeMap<&str, &str> = params
+        .iter()
+        .map(|(key, value)| (key.trim(), value.trim()))
+        .filter(|(key, value)| !key.is_empty() && !value.is_empty())
+        .collect();
+
+    let mut required = BTreeSet::from(["repo".to_owned()]);
+    for preset in &template.spawn_presets {
+        required.extend(template_placeholders(preset.profile));
+        required.extend(template_placeholders(preset.prompt));
+    }
+    let missing: Vec<&str> = required
+        .iter()
+        .map(String::as_str)
+        .filter(|name| !params.contains_key(name))
+        .collect();
+
+    let mut errors = Vec::new();
+    if !missing.is_empty() {
+        errors.push(format!("missing required params: {}", missing.join(", ")));
+    }
+
+    let lanes_known: BTreeSet<&str> = template
+        .spawn_presets
+        .iter()
+        .map(|preset| preset.lane)
+        .collect();
+    for key in params.keys() {
+        if let Some((lane, _)) = key.split_once('.') {
+            if !lanes_known.contains(lane) {
+                errors.push(format!("unknown lane in param {key}"));
+            }
+        }
+    }
+
+    let mut lanes = Vec::new();
+    for (index, preset) in template.spawn_presets.iter().enumerate() {
+        let lane_count_key = format!("{}.count", preset.lane);
+        let count_raw = params
+            .get(lane_count_key.as_str())
+            .copied()
+            .or(if index == 0 {
+                params.get("count").copied()
+            } else {
+                None
+            });
+        let count = match count_raw {
+            None => preset.count,
+            Some(raw) => match raw.parse::<usize>() {
+                Ok(count) if count > 0 => count,
+                _ => {
+                    errors.push(format!("invalid count for lane {}: {raw}", preset.lane));
+                    preset.count
+                }
+            },
+        };
+        let lane_profile_key = format!("{}.profile", preset.lane);
+        let profile = params
+            .get(lane_profile_key.as_str())
+            .or_else(|| params.get("profile"))
+            .map_or_else(
+                || substitute_placeholders(preset.profile, &params),
+                |value| (*value).to_owned(),
+            );
+        lanes.push(SwarmSpecLane {
+            lane: preset.lane.to_owned(),
+            profile,
+            prompt: substitute_placeholders(preset.prompt, &params),
+            count,
+        });
+    }
+
+    let spec = SwarmSpec {
+        template_id: template.id.to_owned(),
+        repo: params.get("repo").copied().unwrap_or_default().to_owned(),
+        max_concurrency: template.max_concurrency,
+        lanes,
+        guardrails: template.guardrails.clone(),
+    };
+    if errors.is_empty() && spec.total_loops() > spec.max_concurrency {
+        errors.push(format!(
+            "{} loops exceed template max concurrency {}",
+            spec.total_loops(),
+            spec.max_concurrency
+        ));
+    }
+    if errors.is_empty() {
+        Ok(spec)
+    } else {
+        Err(errors.join("; "))
+    }
+}
+
+fn template_placeholders(value: &str) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut rest = value;
+    while let Some(start) = rest.find('{') {
+        let after = &rest[start + 1..];
+        let Some(end) = after.find('}') else {
+            break;
+        };
+        let name = after[..end].trim();
+        if !name.is_empty() {
+            names.push(name.to_owned());
+        }
+        rest = &after[end + 1..];
+    }
+    names
+}
+
+fn substitute_placeholders(value: &str, params: &BTreeMap<&str, &str>) -> String {
+    let mut out = value.to_owned();
+    for name in template_placeholders(value) {
+        if let Some(replacement) = params.get(name.as_str()) {
+            out = out.replace(&format!("{{{name}}}"), replacement);
+        }
+    }
+    out
+}
+
 fn template_small() -> SwarmTemplate {
     SwarmTemplate {
         id: "small",
@@ -417,7 +596,8 @@ fn template_full() -> SwarmTemplate {
 mod tests {
     use super::{
         controlled_ramp_wizard, default_swarm_templates, evaluate_ramp_progression,
-        find_swarm_template, RampDecision, RampHealthSnapshot,
+        find_swarm_template, instantiate, RampDecision, RampHealthSnapshot, SwarmGuardrails,
+        SwarmSpawnPreset, SwarmTemplate, SwarmTemplateParams,
     };
 
     #[test]
@@ -609,4 +789,114 @@ mod tests {
         let decision = evaluate_ramp_progression(&wizard, wizard.stages.len() - 1, &snapshot);
         assert_eq!(decision, RampDecision::Complete);
     }
+
+    fn params(pairs: &[(&str, &str)]) -> SwarmTemplateParams {
+        pairs
+            .iter()
+            .map(|(key, value)| ((*key).to_owned(), (*value).to_owned()))
+            .collect()
+    }
+
+    #[test]
+    fn instantiate_applies_count_and_profile_params() {
+        let Some(template) = find_swarm_template("medium") else {
+            panic!("medium template should exist");
+        };
+        let spec = instantiate(
+            &template,
+            &params(&[("repo", "/src/forge"), ("count", "1"), ("profile", "cc3")]),
+        )
+        .unwrap_or_else(|err| panic!("instantiate: {err}"));
+
+        assert_eq!(spec.repo, "/src/forge");
+        assert_eq!(spec.lanes[0].lane, "dev-codex");
+        assert_eq!(spec.lanes[0].count, 1);
+        assert_eq!(spec.total_loops(), 5);
+        assert!(spec.lanes.iter().all(|lane| lane.profile == "cc3"));
+        assert_eq!(
+            spec.up_commands()[0],
+            "forge -C /src/forge up --profile cc3 --prompt swarm-tui-next-codex-continuous \
+             --count 1 --name-prefix medium-dev-codex"
+        );
+    }
+
+    #[test]
+    fn up_commands_shell_quote_params() {
+        let Some(template) = find_swarm_template("medium") else {
+            panic!("medium template should exist");
+        };
+        let spec = instantiate(
+            &template,
+            &params(&[
+                ("repo", "/src/my repo; rm -rf ~"),
+                ("count", "1"),
+                ("profile", "o'brien"),
+            ]),
+        )
+        .unwrap_or_else(|err| panic!("instantiate: {err}"));
+
+        assert_eq!(
+            spec.up_commands()[0],
+            "forge -C '/src/my repo; rm -rf ~' up --profile 'o'\"'\"'brien' \
+             --prompt swarm-tui-next-codex-continuous --count 1 --name-prefix medium-dev-codex"
+        );
+    }
+
+    #[test]
+    fn instantiate_reports_every_missing_param() {
+        let template = SwarmTemplate {
+            id: "custom",
+            title: "Custom",
+            description: "placeholder prompt",
+            max_concurrency: 4,
+            profile_map: Vec::new(),
+            spawn_presets: vec![SwarmSpawnPreset {
+                lane: "dev",
+                profile: "{dev_profile}",
+                prompt: "{prompt}",
+                count: 2,
+            }],
+            guardrails: SwarmGuardrails {
+                stale_takeover_minutes: 45,
+                require_claim_broadcast: true,
+                require_full_validation_before_close: true,
+                max_parallel_task_claims: 1,
+            },
+        };
+
+        let err = match instantiate(&template, &params(&[("prompt", "ship-it")])) {
+            Ok(spec) => panic!("expected missing params, got {spec:?}"),
+            Err(err) => err,
+        };
+        assert_eq!(err, "missing required params: dev_profile, repo");
+
+        let spec = instantiate(
+            &template,
+            &params(&[
+                ("repo", "/r"),
+                ("prompt", "ship-it"),
+                ("dev_profile", "codex3"),
+            ]),
+        )
+        .unwrap_or_else(|err| panic!("instantiate: {err}"));
+        assert_eq!(spec.lanes[0].profile, "codex3");
+        assert_eq!(spec.lanes[0].prompt, "ship-it");
+        assert_eq!(spec.total_loops(), 2);
+    }
+
+    #[test]
+    fn instantiate_rejects_bad_counts_and_overcapacity() {
+        let Some(template) = find_swarm_template("small") else {
+            panic!("small template should exist");
+        };
+        let err = instantiate(&template, &params(&[("repo", "/r"), ("count", "zero")]))
+            .err()
+            .unwrap_or_default();
+        assert_eq!(err, "invalid count for lane dev: zero");
+
+        let err = instantiate(&template, &params(&[("repo", "/r"), ("count", "4")]))
+            .err()
+            .unwrap_or_default();
+        assert_eq!(err, "6 loops exceed template max concurrency 3");
+    }
 }
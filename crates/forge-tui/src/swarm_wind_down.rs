@@ -1,5 +1,9 @@
 //! Wind-down workflow and final state reconciliation for swarm orchestration.
 
+use std::collections::BTreeMap;
+
+use crate::status_strip::{StatusWidgetDefinition, StripPosition};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum LoopRuntimeState {
     Running,
@@ -133,6 +137,244 @@ pub fn evaluate_wind_down_report(samples: &[WindDownLoopSample]) -> WindDownRepo
     }
 }
 
+/// Status strip widget id carrying staged wind-down progress.
+pub const WIND_DOWN_STATUS_WIDGET_ID: &str = "wind_down";
+
+#[must_use]
+pub fn wind_down_status_widget() -> StatusWidgetDefinition {
+    StatusWidgetDefinition::new(
+        WIND_DOWN_STATUS_WIDGET_ID,
+        "Wind-down",
+        StripPosition::Bottom,
+        35,
+    )
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WindDownPhase {
+    Idle,
+    AwaitingConfirmation,
+    Draining,
+    Stopping,
+    Stopped,
+}
+
+impl WindDownPhase {
+    #[must_use]
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Idle => "idle",
+            Self::AwaitingConfirmation => "awaiting-confirmation",
+            Self::Draining => "draining",
+            Self::Stopping => "stopping",
+            Self::Stopped => "stopped",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StagedLoopState {
+    /// Accepting no new work; the in-flight iteration is still running.
+    Draining,
+    /// Iteration finished; stop has been issued.
+    Stopping,
+    Stopped,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct WindDownProgress {
+    pub draining: usize,
+    pub stopping: usize,
+    pub stopped: usize,
+    pub total: usize,
+}
+
+/// Staged wind-down: confirm, drain in-flight iterations, then stop. `force`
+/// aborts the drain and marks every loop stopped, handing back the kill
+/// commands for loops that had not stopped yet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StagedWindDown {
+    phase: WindDownPhase,
+    loops: BTreeMap<String, StagedLoopState>,
+    forced: bool,
+}
+
+impl StagedWindDown {
+    #[must_use]
+    pub fn new<I, S>(loop_ids: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let loops = loop_ids
+            .into_iter()
+            .map(|loop_id| loop_id.as_ref().trim().to_owned())
+            .filter(|loop_id| !loop_id.is_empty())
+            .map(|loop_id| (loop_id, StagedLoopState::Draining))
+            .collect();
+        Self {
+            phase: WindDownPhase::Idle,
+            loops,
+            forced: false,
+        }
+    }
+
+    #[must_use]
+    pub fn phase(&self) -> WindDownPhase {
+        self.phase
+    }
+
+    #[must_use]
+    pub fn forced(&self) -> bool {
+        self.forced
+    }
+
+    #[must_use]
+    pub fn loop_state(&self, loop_id: &str) -> Option<StagedLoopState> {
+        self.loops.get(loop_id.trim()).copied()
+    }
+
+    /// New work is only dispatched before the wind-down is confirmed.
+    #[must_use]
+    pub fn accepts_new_work(&self) -> bool {
+        matches!(
+            self.phase,
+            WindDownPhase::Idle | WindDownPhase::AwaitingConfirmation
+        )
+    }
+
+    pub fn request(&mut self) -> bool {
+        if self.phase != WindDownPhase::Idle {
+            return false;
+        }
+        self.phase = WindDownPhase::AwaitingConfirmation;
+        true
+    }
+
+    pub fn cancel(&mut self) -> bool {
+        if self.phase != WindDownPhase::AwaitingConfirmation {
+            return false;
+        }
+        self.phase = WindDownPhase::Idle;
+        true
+    }
+
+    pub fn confirm(&mut self) -> bool {
+        if self.phase != WindDownPhase::AwaitingConfirmation {
+            return false;
+        }
+        self.phase = WindDownPhase::Draining;
+        self.recompute_phase();
+        true
+    }
+
+    /// Feed the latest runtime state for a loop. A loop that is no longer
+    /// running has finished its iteration and moves on to stopping.
+    pub fn observe(&mut self, loop_id: &str, runtime_state: LoopRuntimeState) -> bool {
+        if matches!(
+            self.phase,
+            WindDownPhase::Idle | WindDownPhase::AwaitingConfirmation | WindDownPhase::Stopped
+        ) {
+            return false;
+        }
+        let Some(state) = self.loops.get_mut(loop_id.trim()) else {
+            return false;
+        };
+        let next = match (*state, runtime_state) {
+            (_, LoopRuntimeState::Stopped | LoopRuntimeState::Error) => StagedLoopState::Stopped,
+            (StagedLoopState::Draining, LoopRuntimeState::Sleeping | LoopRuntimeState::Waiting) => {
+                StagedLoopState::Stopping
+            }
+            (current, _) => current,
+        };
+        if next == *state {
+            return false;
+        }
+        *state = next;
+        self.recompute_phase();
+        true
+    }
+
+    /// Abort the staged drain and stop everything immediately. Returns the
+    /// kill commands for loops that had not stopped yet; empty when there is
+    /// nothing to force.
+    pub fn force(&mut self) -> Vec<String> {
+        if matches!(self.phase, WindDownPhase::Idle | WindDownPhase::Stopped) {
+            return Vec::new();
+        }
+        let mut commands = Vec::new();
+        for (loop_id, state) in &mut self.loops {
+            if *state != StagedLoopState::Stopped {
+                commands.push(format!("forge kill {loop_id}"));
+            }
+            *state = StagedLoopState::Stopped;
+        }
+        self.forced = true;
+        self.phase = WindDownPhase::Stopped;
+        commands
+    }
+
+    /// Loops whose iteration finished and still need a stop issued.
+    #[must_use]
+    pub fn pending_stop_commands(&self) -> Vec<String> {
+        self.loops
+            .iter()
+            .filter(|(_, state)| **state == StagedLoopState::Stopping)
+            .map(|(loop_id, _)| format!("forge stop {loop_id}"))
+            .collect()
+    }
+
+    #[must_use]
+    pub fn progress(&self) -> WindDownProgress {
+        let mut progress = WindDownProgress {
+            total: self.loops.len(),
+            ..WindDownProgress::default()
+        };
+        for state in self.loops.values() {
+            match state {
+                StagedLoopState::Draining => progress.draining += 1,
+                StagedLoopState::Stopping => progress.stopping += 1,
+                StagedLoopState::Stopped => progress.stopped += 1,
+            }
+        }
+        progress
+    }
+
+    /// Value for the wind-down status strip widget; `None` while idle.
+    #[must_use]
+    pub fn status_strip_value(&self) -> Option<String> {
+        let progress = self.progress();
+        match self.phase {
+            WindDownPhase::Idle => None,
+            WindDownPhase::AwaitingConfirmation => Some(format!(
+                "wind down {} loop(s)? y:confirm n:cancel",
+                progress.total
+            )),
+            WindDownPhase::Draining | WindDownPhase::Stopping => Some(format!(
+                "wind-down: {} draining, {} stopping, {} stopped  F:force",
+                progress.draining, progress.stopping, progress.stopped
+            )),
+            WindDownPhase::Stopped => Some(format!(
+                "wind-down: {}/{} stopped{}",
+                progress.stopped,
+                progress.total,
+                if self.forced { " (forced)" } else { "" }
+            )),
+        }
+    }
+
+    fn recompute_phase(&mut self) {
+        let progress = self.progress();
+        self.phase = if progress.draining > 0 {
+            WindDownPhase::Draining
+        } else if progress.stopping > 0 {
+            WindDownPhase::Stopping
+        } else {
+            WindDownPhase::Stopped
+        };
+    }
+}
+
 fn graceful_stop_stage(sample: &WindDownLoopSample) -> (WindDownStepStatus, String) {
     match sample.runtime_state {
         LoopRuntimeState::Stopped => (
@@ -231,7 +473,9 @@ fn normalize_or_fallback(value: &str, fallback: &str) -> String {
 #[cfg(test)]
 mod tests {
     use super::{
-        evaluate_wind_down_report, LoopRuntimeState, WindDownLoopSample, WindDownStepStatus,
+        evaluate_wind_down_report, wind_down_status_widget, LoopRuntimeState, StagedLoopState,
+        StagedWindDown, WindDownLoopSample, WindDownPhase, WindDownStepStatus,
+        WIND_DOWN_STATUS_WIDGET_ID,
     };
 
     #[test]
@@ -338,4 +582,83 @@ mod tests {
         assert_eq!(report.loops[1].swarm_id, "swarm-b");
         assert_eq!(report.loops[1].loop_id, "loop-z");
     }
+
+    #[test]
+    fn staged_wind_down_drains_then_stops() {
+        let mut wind_down = StagedWindDown::new(["loop-a", "loop-b"]);
+        assert!(wind_down.accepts_new_work());
+        assert!(!wind_down.confirm());
+
+        assert!(wind_down.request());
+        assert_eq!(wind_down.phase(), WindDownPhase::AwaitingConfirmation);
+        assert!(wind_down.confirm());
+        assert_eq!(wind_down.phase(), WindDownPhase::Draining);
+        assert!(!wind_down.accepts_new_work());
+        assert_eq!(
+            wind_down.status_strip_value().as_deref(),
+            Some("wind-down: 2 draining, 0 stopping, 0 stopped  F:force")
+        );
+
+        assert!(wind_down.observe("loop-a", LoopRuntimeState::Sleeping));
+        assert_eq!(wind_down.phase(), WindDownPhase::Draining);
+        assert!(!wind_down.observe("loop-b", LoopRuntimeState::Running));
+        assert!(wind_down.observe("loop-b", LoopRuntimeState::Waiting));
+        assert_eq!(wind_down.phase(), WindDownPhase::Stopping);
+        assert_eq!(
+            wind_down.pending_stop_commands(),
+            vec!["forge stop loop-a", "forge stop loop-b"]
+        );
+
+        assert!(wind_down.observe("loop-a", LoopRuntimeState::Stopped));
+        assert!(wind_down.observe("loop-b", LoopRuntimeState::Stopped));
+        assert_eq!(wind_down.phase(), WindDownPhase::Stopped);
+        assert!(!wind_down.forced());
+        assert_eq!(
+            wind_down.status_strip_value().as_deref(),
+            Some("wind-down: 2/2 stopped")
+        );
+    }
+
+    #[test]
+    fn force_jumps_straight_to_stopped() {
+        let mut wind_down = StagedWindDown::new(["loop-a", "loop-b", "loop-c"]);
+        assert!(wind_down.force().is_empty());
+        assert!(wind_down.request());
+        assert!(wind_down.confirm());
+        assert!(wind_down.observe("loop-a", LoopRuntimeState::Sleeping));
+
+        assert_eq!(
+            wind_down.force(),
+            vec![
+                "forge kill loop-a",
+                "forge kill loop-b",
+                "forge kill loop-c"
+            ]
+        );
+        assert_eq!(wind_down.phase(), WindDownPhase::Stopped);
+        assert_eq!(
+            wind_down.loop_state("loop-b"),
+            Some(StagedLoopState::Stopped)
+        );
+        assert_eq!(wind_down.progress().stopped, 3);
+        assert_eq!(
+            wind_down.status_strip_value().as_deref(),
+            Some("wind-down: 3/3 stopped (forced)")
+        );
+    }
+
+    #[test]
+    fn cancel_before_confirmation_keeps_accepting_work() {
+        let mut wind_down = StagedWindDown::new(["loop-a"]);
+        assert!(wind_down.request());
+        assert_eq!(
+            wind_down.status_strip_value().as_deref(),
+            Some("wind down 1 loop(s)? y:confirm n:cancel")
+        );
+        assert!(wind_down.cancel());
+        assert_eq!(wind_down.phase(), WindDownPhase::Idle);
+        assert!(wind_down.accepts_new_work());
+        assert_eq!(wind_down.status_strip_value(), None);
+        assert_eq!(wind_down_status_widget().id, WIND_DOWN_STATUS_WIDGET_ID);
+    }
 }
@@ -1,6 +1,6 @@
 //! Shared task notes and breadcrumb timeline model for collaboration panes.
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum BreadcrumbKind {
@@ -171,6 +171,92 @@ impl TaskNotesBoard {
     }
 }
 
+/// Operator annotation attached to a loop; persisted with the session snapshot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoopNote {
+    pub loop_id: String,
+    pub body: String,
+    pub updated_at_epoch_s: i64,
+}
+
+/// Per-loop notes keyed by normalized loop id (one note per loop).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LoopNotes {
+    notes: BTreeMap<String, LoopNote>,
+}
+
+impl LoopNotes {
+    #[must_use]
+    pub fn from_persisted(notes: &[LoopNote]) -> Self {
+        let mut out = Self::default();
+        for note in notes {
+            let _ = out.upsert(&note.loop_id, &note.body, note.updated_at_epoch_s);
+        }
+        out
+    }
+
+    /// Notes ordered by loop id, ready for the session snapshot.
+    #[must_use]
+    pub fn to_persisted(&self) -> Vec<LoopNote> {
+        self.notes.values().cloned().collect()
+    }
+
+    #[must_use]
+    pub fn note(&self, loop_id: &str) -> Option<&LoopNote> {
+        self.notes.get(&normalize_loop_id(loop_id))
+    }
+
+    #[must_use]
+    pub fn has_note(&self, loop_id: &str) -> bool {
+        self.note(loop_id).is_some()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.notes.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.notes.is_empty()
+    }
+
+    /// Create or replace the note for `loop_id`.
+    pub fn upsert(
+        &mut self,
+        loop_id: &str,
+        body: &str,
+        updated_at_epoch_s: i64,
+    ) -> Result<(), String> {
+        let loop_id = normalize_required(loop_id, "loop_id")?.to_ascii_lowercase();
+        let body = normalize_required(body, "body")?;
+        self.notes.insert(
+            loop_id.clone(),
+            LoopNote {
+                loop_id,
+                body,
+                updated_at_epoch_s: updated_at_epoch_s.max(0),
+            },
+        );
+        Ok(())
+    }
+
+    pub fn delete(&mut self, loop_id: &str) -> bool {
+        self.notes.remove(&normalize_loop_id(loop_id)).is_some()
+    }
+
+    /// Drop notes whose loop no longer exists; returns how many were removed.
+    pub fn retain_loops<'a>(&mut self, loop_ids: impl IntoIterator<Item = &'a str>) -> usize {
+        let live = loop_ids
+            .into_iter()
+            .map(normalize_loop_id)
+            .collect::<BTreeSet<_>>();
+        let before = self.notes.len();
+        self.notes.retain(|loop_id, _| live.contains(loop_id));
+        before - self.notes.len()
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum OperatorActionKind {
     Pause,
@@ -453,6 +539,10 @@ fn normalize_required(value: &str, field: &str) -> Result<String, String> {
     }
 }
 
+fn normalize_loop_id(loop_id: &str) -> String {
+    loop_id.trim().to_ascii_lowercase()
+}
+
 fn render_alerts(alerts: &[String]) -> String {
     if alerts.is_empty() {
         "none".to_owned()
@@ -465,9 +555,51 @@ fn render_alerts(alerts: &[String]) -> String {
 mod tests {
     use super::{
         render_operator_decision_journal_pane, render_task_notes_pane, BreadcrumbKind,
-        DecisionFleetState, OperatorActionKind, OperatorDecisionJournal, TaskNotesBoard,
+        DecisionFleetState, LoopNote, LoopNotes, OperatorActionKind, OperatorDecisionJournal,
+        TaskNotesBoard,
     };
 
+    #[test]
+    fn loop_notes_support_create_edit_delete() {
+        let mut notes = LoopNotes::default();
+        assert!(notes.upsert(" Loop-1 ", "watch retries", 100).is_ok());
+        assert!(notes.has_note("loop-1"));
+        assert!(notes.upsert("loop-1", "retries fixed", 200).is_ok());
+        assert_eq!(
+            notes.note("LOOP-1"),
+            Some(&LoopNote {
+                loop_id: "loop-1".to_owned(),
+                body: "retries fixed".to_owned(),
+                updated_at_epoch_s: 200,
+            })
+        );
+        assert!(notes.upsert("loop-2", "  ", 300).is_err());
+        assert!(notes.delete("loop-1"));
+        assert!(!notes.delete("loop-1"));
+        assert!(notes.is_empty());
+    }
+
+    #[test]
+    fn loop_notes_retain_loops_drops_orphans() {
+        let mut notes = LoopNotes::from_persisted(&[
+            LoopNote {
+                loop_id: "loop-a".to_owned(),
+                body: "keep".to_owned(),
+                updated_at_epoch_s: 1,
+            },
+            LoopNote {
+                loop_id: "loop-gone".to_owned(),
+                body: "orphan".to_owned(),
+                updated_at_epoch_s: 2,
+            },
+        ]);
+
+        assert_eq!(notes.retain_loops(["loop-a", "loop-b"]), 1);
+        assert!(notes.has_note("loop-a"));
+        assert!(!notes.has_note("loop-gone"));
+        assert_eq!(notes.len(), 1);
+    }
+
     #[test]
     fn add_note_requires_non_empty_fields() {
         let mut board = TaskNotesBoard::default();
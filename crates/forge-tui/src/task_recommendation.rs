@@ -50,6 +50,131 @@ pub struct RecommendationReport {
     pub excluded_invalid: usize,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct LoopRecommendationSample {
+    pub loop_id: String,
+    pub name: String,
+    pub state: String,
+    pub last_error: Option<String>,
+    pub consecutive_failures: u32,
+    pub queue_depth: usize,
+    pub last_activity_epoch_s: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoopRecommendationPolicy {
+    pub now_epoch_s: i64,
+    pub stale_after_s: i64,
+    pub limit: usize,
+}
+
+impl Default for LoopRecommendationPolicy {
+    fn default() -> Self {
+        Self {
+            now_epoch_s: 0,
+            stale_after_s: 15 * 60,
+            limit: 5,
+        }
+    }
+}
+
+/// A loop worth looking at next, with the weighted factors that put it there.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Recommendation {
+    pub loop_id: String,
+    pub name: String,
+    pub score: f64,
+    pub reasons: Vec<(String, f64)>,
+}
+
+impl Recommendation {
+    /// "why recommended" line, e.g. `failing +0.40, stale +0.30`.
+    #[must_use]
+    pub fn why(&self) -> String {
+        self.reasons
+            .iter()
+            .map(|(factor, weight)| format!("{factor} {weight:+.2}"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+const WEIGHT_FAILING: f64 = 0.4;
+const WEIGHT_STALE: f64 = 0.3;
+const WEIGHT_REPEAT_FAILURE: f64 = 0.05;
+const MAX_REPEAT_FAILURE_WEIGHT: f64 = 0.2;
+const WEIGHT_QUEUED: f64 = 0.1;
+
+/// Rank loops by attention need. Loops with no contributing factor are omitted.
+#[must_use]
+pub fn recommendations(
+    samples: &[LoopRecommendationSample],
+    policy: &LoopRecommendationPolicy,
+) -> Vec<Recommendation> {
+    let limit = if policy.limit == 0 { 5 } else { policy.limit };
+    let mut out = Vec::new();
+
+    for sample in samples {
+        let loop_id = normalize_required(&sample.loop_id);
+        if loop_id.is_empty() {
+            continue;
+        }
+        let state = normalize_required(&sample.state);
+        if matches!(state.as_str(), "stopped" | "done" | "canceled") {
+            continue;
+        }
+
+        let mut reasons = Vec::new();
+        let has_error = sample
+            .last_error
+            .as_deref()
+            .is_some_and(|err| !err.trim().is_empty());
+        let failing = has_error || matches!(state.as_str(), "error" | "failed");
+        if failing {
+            reasons.push(("failing".to_owned(), WEIGHT_FAILING));
+            let repeats = sample.consecutive_failures.saturating_sub(1);
+            if repeats > 0 {
+                let weight =
+                    (f64::from(repeats) * WEIGHT_REPEAT_FAILURE).min(MAX_REPEAT_FAILURE_WEIGHT);
+                reasons.push(("repeat-failures".to_owned(), weight));
+            }
+        }
+
+        let idle_s = policy.now_epoch_s - sample.last_activity_epoch_s.max(0);
+        if sample.last_activity_epoch_s > 0 && idle_s >= policy.stale_after_s.max(1) {
+            reasons.push(("stale".to_owned(), WEIGHT_STALE));
+        }
+
+        if sample.queue_depth > 0 {
+            reasons.push(("queued".to_owned(), WEIGHT_QUEUED));
+        }
+
+        if reasons.is_empty() {
+            continue;
+        }
+        let score = reasons.iter().map(|(_, weight)| weight).sum::<f64>();
+        let name = sample.name.trim();
+        out.push(Recommendation {
+            name: if name.is_empty() {
+                loop_id.clone()
+            } else {
+                name.to_owned()
+            },
+            loop_id,
+            score,
+            reasons,
+        });
+    }
+
+    out.sort_by(|a, b| {
+        b.score
+            .total_cmp(&a.score)
+            .then_with(|| a.loop_id.cmp(&b.loop_id))
+    });
+    out.truncate(limit);
+    out
+}
+
 #[must_use]
 pub fn recommend_next_best_tasks(
     samples: &[TaskRecommendationSample],
@@ -266,7 +391,10 @@ fn priority_rank(priority: &str) -> usize {
 #[cfg(test)]
 #[allow(clippy::expect_used)]
 mod tests {
-    use super::{recommend_next_best_tasks, RecommendationContext, TaskRecommendationSample};
+    use super::{
+        recommend_next_best_tasks, recommendations, LoopRecommendationPolicy,
+        LoopRecommendationSample, RecommendationContext, TaskRecommendationSample,
+    };
 
     fn sample_tasks() -> Vec<TaskRecommendationSample> {
         vec![
@@ -407,4 +535,59 @@ mod tests {
         );
         assert_eq!(explicitly_limited.recommendations.len(), 2);
     }
+
+    fn loop_sample(loop_id: &str, last_activity_epoch_s: i64) -> LoopRecommendationSample {
+        LoopRecommendationSample {
+            loop_id: loop_id.to_owned(),
+            name: format!("{loop_id} name"),
+            state: "running".to_owned(),
+            last_activity_epoch_s,
+            ..LoopRecommendationSample::default()
+        }
+    }
+
+    #[test]
+    fn failing_and_stale_loop_outranks_stale_only_loop_with_reasons() {
+        let policy = LoopRecommendationPolicy {
+            now_epoch_s: 10_000,
+            stale_after_s: 600,
+            limit: 5,
+        };
+        let mut failing_stale = loop_sample("loop-b", 1_000);
+        failing_stale.state = "error".to_owned();
+        failing_stale.last_error = Some("harness exited 1".to_owned());
+        let stale_only = loop_sample("loop-a", 2_000);
+        let healthy = loop_sample("loop-c", 9_900);
+
+        let ranked = recommendations(&[stale_only, healthy, failing_stale], &policy);
+
+        assert_eq!(ranked.len(), 2);
+        assert_eq!(ranked[0].loop_id, "loop-b");
+        assert_eq!(ranked[1].loop_id, "loop-a");
+        assert!(ranked[0].score > ranked[1].score);
+
+        let factors = ranked[0]
+            .reasons
+            .iter()
+            .map(|(factor, _)| factor.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(factors, vec!["failing", "stale"]);
+        assert!((ranked[0].score - 0.7).abs() < 1e-9);
+        assert_eq!(ranked[0].why(), "failing +0.40, stale +0.30");
+        assert_eq!(ranked[1].reasons, vec![("stale".to_owned(), 0.3)]);
+    }
+
+    #[test]
+    fn repeat_failures_add_capped_weight() {
+        let mut sample = loop_sample("loop-a", 0);
+        sample.last_error = Some("timeout".to_owned());
+        sample.consecutive_failures = 20;
+
+        let ranked = recommendations(&[sample], &LoopRecommendationPolicy::default());
+        assert_eq!(ranked.len(), 1);
+        assert!(ranked[0]
+            .reasons
+            .iter()
+            .any(|(factor, weight)| factor == "repeat-failures" && (*weight - 0.2).abs() < 1e-9));
+    }
 }
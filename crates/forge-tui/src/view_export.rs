@@ -6,6 +6,7 @@ use std::path::{Path, PathBuf};
 use std::time::{SystemTime, UNIX_EPOCH};
 
 use forge_ftui_adapter::render::{CellStyle, RenderFrame, TermColor};
+use forge_ftui_adapter::snapshot::to_svg;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ViewExportMeta {
@@ -187,77 +188,19 @@ fn render_html(frame: &RenderFrame, meta: &ViewExportMeta) -> String {
     out
 }
 
+/// Adapter SVG (see [`to_svg`]) with the export metadata as its `<desc>`.
 fn render_svg(frame: &RenderFrame, meta: &ViewExportMeta) -> String {
-    const CELL_WIDTH: usize = 9;
-    const CELL_HEIGHT: usize = 16;
-    const FONT_SIZE: usize = 14;
-    const BASELINE_OFFSET: usize = 12;
-
-    let size = frame.size();
-    let canvas_width = size.width.saturating_mul(CELL_WIDTH);
-    let canvas_height = size.height.saturating_mul(CELL_HEIGHT);
-
-    let mut out = String::new();
-    let _ = writeln!(
-        &mut out,
-        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{canvas_width}\" height=\"{canvas_height}\" viewBox=\"0 0 {canvas_width} {canvas_height}\">"
-    );
-    let _ = writeln!(
-        &mut out,
-        "  <desc>forge-tui export view={} mode={} generated-epoch-ms={}</desc>",
+    let svg = to_svg(frame);
+    let desc = format!(
+        "<desc>forge-tui export view={} mode={} generated-epoch-ms={}</desc>\n",
         escape_xml(&meta.view_label),
         escape_xml(&meta.mode_label),
         meta.generated_epoch_ms
     );
-
-    let background = frame
-        .cell(0, 0)
-        .map(|cell| cell.style.bg)
-        .unwrap_or(TermColor::Ansi256(0));
-    let _ = writeln!(
-        &mut out,
-        "  <rect x=\"0\" y=\"0\" width=\"{canvas_width}\" height=\"{canvas_height}\" fill=\"{}\"/>",
-        color_hex(background)
-    );
-
-    for y in 0..size.height {
-        let runs = collect_row_runs(frame, y);
-        for run in runs {
-            let x = run.start_col.saturating_mul(CELL_WIDTH);
-            let row_y = y.saturating_mul(CELL_HEIGHT);
-            let width = run.char_count.saturating_mul(CELL_WIDTH);
-            let _ = writeln!(
-                &mut out,
-                "  <rect x=\"{x}\" y=\"{row_y}\" width=\"{width}\" height=\"{CELL_HEIGHT}\" fill=\"{}\"/>",
-                color_hex(run.style.bg)
-            );
-
-            if run.text.chars().all(|ch| ch == ' ') {
-                continue;
-            }
-            let text_y = row_y.saturating_add(BASELINE_OFFSET);
-            let weight = if run.style.bold { "bold" } else { "normal" };
-            let decoration = if run.style.underline {
-                " text-decoration=\"underline\""
-            } else {
-                ""
-            };
-            let opacity = if run.style.dim {
-                " opacity=\"0.7\""
-            } else {
-                ""
-            };
-            let _ = writeln!(
-                &mut out,
-                "  <text x=\"{x}\" y=\"{text_y}\" fill=\"{}\" font-family=\"Menlo, Consolas, Monaco, monospace\" font-size=\"{FONT_SIZE}\" font-weight=\"{weight}\" xml:space=\"preserve\"{decoration}{opacity}>{}</text>",
-                color_hex(run.style.fg),
-                escape_xml(&run.text)
-            );
-        }
+    match svg.split_once('\n') {
+        Some((open, body)) => format!("{open}\n{desc}{body}"),
+        None => svg,
     }
-
-    let _ = writeln!(&mut out, "</svg>");
-    out
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -340,54 +283,10 @@ fn css_style(style: CellStyle) -> String {
 }
 
 fn color_hex(color: TermColor) -> String {
-    let (r, g, b) = term_color_rgb(color);
+    let (r, g, b) = color.to_rgb();
     format!("#{r:02x}{g:02x}{b:02x}")
 }
 
-fn term_color_rgb(color: TermColor) -> (u8, u8, u8) {
-    match color {
-        TermColor::Rgb(r, g, b) => (r, g, b),
-        TermColor::Ansi256(idx) => ansi256_to_rgb(idx),
-    }
-}
-
-fn ansi256_to_rgb(idx: u8) -> (u8, u8, u8) {
-    match idx {
-        0 => (0x00, 0x00, 0x00),
-        1 => (0x80, 0x00, 0x00),
-        2 => (0x00, 0x80, 0x00),
-        3 => (0x80, 0x80, 0x00),
-        4 => (0x00, 0x00, 0x80),
-        5 => (0x80, 0x00, 0x80),
-        6 => (0x00, 0x80, 0x80),
-        7 => (0xc0, 0xc0, 0xc0),
-        8 => (0x80, 0x80, 0x80),
-        9 => (0xff, 0x00, 0x00),
-        10 => (0x00, 0xff, 0x00),
-        11 => (0xff, 0xff, 0x00),
-        12 => (0x00, 0x00, 0xff),
-        13 => (0xff, 0x00, 0xff),
-        14 => (0x00, 0xff, 0xff),
-        15 => (0xff, 0xff, 0xff),
-        16..=231 => {
-            let cube = idx - 16;
-            let r = cube / 36;
-            let g = (cube % 36) / 6;
-            let b = cube % 6;
-            let levels = [0, 95, 135, 175, 215, 255];
-            (
-                levels[usize::from(r)],
-                levels[usize::from(g)],
-                levels[usize::from(b)],
-            )
-        }
-        232..=255 => {
-            let level = 8 + (idx - 232) * 10;
-            (level, level, level)
-        }
-    }
-}
-
 fn escape_html(value: &str) -> String {
     let mut out = String::new();
     for ch in value.chars() {
@@ -486,9 +385,15 @@ mod tests {
         assert!(payload.html.contains("&lt;A&amp;B&gt;"));
         assert!(payload.html.contains("<pre>"));
 
-        assert!(payload.svg.contains("<svg"));
-        assert!(payload.svg.contains("&lt;A&amp;B&gt;"));
-        assert!(payload.svg.contains("generated-epoch-ms=1700000000123"));
+        assert!(payload.svg.starts_with("<svg"));
+        assert!(payload.svg.contains(">&lt;</text>"));
+        assert!(payload.svg.contains(">&amp;</text>"));
+        assert!(payload
+            .svg
+            .contains("fill=\"#010203\" font-weight=\"bold\">o</text>"));
+        assert!(payload.svg.contains(
+            "<desc>forge-tui export view=Logs mode=Main generated-epoch-ms=1700000000123</desc>"
+        ));
     }
 
     #[test]
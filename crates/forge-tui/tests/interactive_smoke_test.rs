@@ -99,6 +99,7 @@ fn sample_loops() -> Vec<LoopView> {
             } else {
                 String::new()
             },
+            stop_cause: String::new(),
             profile_name: "prod-sre".to_owned(),
             profile_harness: "codex".to_owned(),
             profile_auth: "ssh".to_owned(),
@@ -122,6 +123,8 @@ fn sample_runs() -> Vec<RunView> {
             auth_kind: "ssh".to_owned(),
             started_at: String::new(),
             output_lines: Vec::new(),
+            attempts: None,
+            cost_usd: None,
         },
         RunView {
             id: "run-0171".to_owned(),
@@ -134,6 +137,8 @@ fn sample_runs() -> Vec<RunView> {
             auth_kind: "ssh".to_owned(),
             started_at: String::new(),
             output_lines: Vec::new(),
+            attempts: None,
+            cost_usd: None,
         },
         RunView {
             id: "run-0170".to_owned(),
@@ -146,6 +151,8 @@ fn sample_runs() -> Vec<RunView> {
             auth_kind: "ssh".to_owned(),
             started_at: String::new(),
             output_lines: Vec::new(),
+            attempts: None,
+            cost_usd: None,
         },
     ]
 }
@@ -83,6 +83,7 @@ fn sample_loops() -> Vec<LoopView> {
             } else {
                 String::new()
             },
+            stop_cause: String::new(),
             profile_name: "prod-sre".to_owned(),
             profile_harness: "codex".to_owned(),
             profile_auth: "ssh".to_owned(),
@@ -106,6 +107,8 @@ fn sample_runs() -> Vec<RunView> {
             auth_kind: "ssh".to_owned(),
             started_at: String::new(),
             output_lines: Vec::new(),
+            attempts: None,
+            cost_usd: None,
         },
         RunView {
             id: "run-0171".to_owned(),
@@ -118,6 +121,8 @@ fn sample_runs() -> Vec<RunView> {
             auth_kind: "ssh".to_owned(),
             started_at: String::new(),
             output_lines: Vec::new(),
+            attempts: None,
+            cost_usd: None,
         },
         RunView {
             id: "run-0170".to_owned(),
@@ -130,6 +135,8 @@ fn sample_runs() -> Vec<RunView> {
             auth_kind: "ssh".to_owned(),
             started_at: String::new(),
             output_lines: Vec::new(),
+            attempts: None,
+            cost_usd: None,
         },
     ]
 }
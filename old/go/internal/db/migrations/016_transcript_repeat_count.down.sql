@@ -0,0 +1,27 @@
+-- Migration: 016_transcript_repeat_count (DOWN)
+-- Description: Remove transcript repeat counter
+-- Created: 2026-10-16
+
+DROP INDEX IF EXISTS idx_transcripts_agent_id;
+DROP INDEX IF EXISTS idx_transcripts_captured_at;
+DROP INDEX IF EXISTS idx_transcripts_hash;
+
+-- SQLite does not support DROP COLUMN; rebuild the table without repeat_count.
+CREATE TABLE transcripts_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    agent_id TEXT NOT NULL REFERENCES agents(id) ON DELETE CASCADE,
+    content TEXT NOT NULL,
+    content_hash TEXT NOT NULL,
+    captured_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+INSERT INTO transcripts_new (id, agent_id, content, content_hash, captured_at)
+SELECT id, agent_id, content, content_hash, captured_at
+FROM transcripts;
+
+DROP TABLE transcripts;
+ALTER TABLE transcripts_new RENAME TO transcripts;
+
+CREATE INDEX IF NOT EXISTS idx_transcripts_agent_id ON transcripts(agent_id);
+CREATE INDEX IF NOT EXISTS idx_transcripts_captured_at ON transcripts(agent_id, captured_at);
+CREATE INDEX IF NOT EXISTS idx_transcripts_hash ON transcripts(agent_id, content_hash);
@@ -0,0 +1,5 @@
+-- Migration: 016_transcript_repeat_count
+-- Description: Track repeated identical transcript captures instead of storing duplicates
+-- Created: 2026-10-16
+
+ALTER TABLE transcripts ADD COLUMN repeat_count INTEGER NOT NULL DEFAULT 1;
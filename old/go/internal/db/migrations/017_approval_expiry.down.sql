@@ -0,0 +1,34 @@
+-- Migration: 017_approval_expiry (DOWN)
+-- Description: Remove approval deadlines
+-- Created: 2026-10-16
+
+DROP INDEX IF EXISTS idx_approvals_expires_at;
+DROP INDEX IF EXISTS idx_approvals_agent_id;
+DROP INDEX IF EXISTS idx_approvals_status;
+
+-- SQLite does not support DROP COLUMN; rebuild the table without expires_at.
+CREATE TABLE approvals_new (
+    id TEXT PRIMARY KEY,
+    agent_id TEXT NOT NULL REFERENCES agents(id) ON DELETE CASCADE,
+    request_type TEXT NOT NULL,
+    request_details_json TEXT NOT NULL,
+    status TEXT NOT NULL DEFAULT 'pending' CHECK (status IN ('pending', 'approved', 'denied', 'expired')),
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    resolved_at TEXT,
+    resolved_by TEXT
+);
+
+INSERT INTO approvals_new (
+    id, agent_id, request_type, request_details_json,
+    status, created_at, resolved_at, resolved_by
+)
+SELECT
+    id, agent_id, request_type, request_details_json,
+    status, created_at, resolved_at, resolved_by
+FROM approvals;
+
+DROP TABLE approvals;
+ALTER TABLE approvals_new RENAME TO approvals;
+
+CREATE INDEX IF NOT EXISTS idx_approvals_agent_id ON approvals(agent_id);
+CREATE INDEX IF NOT EXISTS idx_approvals_status ON approvals(status);
@@ -0,0 +1,7 @@
+-- Migration: 017_approval_expiry
+-- Description: Add approval deadlines so stale pending approvals can be auto-expired
+-- Created: 2026-10-16
+
+ALTER TABLE approvals ADD COLUMN expires_at TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_approvals_expires_at ON approvals(status, expires_at);
@@ -0,0 +1,6 @@
+-- Migration: 018_event_hash_chain
+-- Description: Chain event rows by hash so tampering with the audit log is detectable
+-- Created: 2026-10-16
+
+ALTER TABLE events ADD COLUMN prev_hash TEXT;
+ALTER TABLE events ADD COLUMN chain_hash TEXT;
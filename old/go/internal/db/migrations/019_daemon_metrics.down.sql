@@ -0,0 +1,10 @@
+-- Migration: 019_daemon_metrics (rollback)
+-- Description: remove daemon metrics time-series table
+
+DROP TABLE IF EXISTS metric_series;
+
+DROP INDEX IF EXISTS idx_metrics_series_timestamp;
+DROP INDEX IF EXISTS idx_metrics_timestamp;
+DROP INDEX IF EXISTS idx_metrics_name_timestamp;
+
+DROP TABLE IF EXISTS metrics;
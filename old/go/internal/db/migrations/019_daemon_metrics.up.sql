@@ -0,0 +1,24 @@
+-- Migration: 019_daemon_metrics
+-- Description: Time-series table for daemon-internal gauges (thread count, queue latency)
+-- Created: 2026-10-16
+
+CREATE TABLE IF NOT EXISTS metrics (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    name TEXT NOT NULL,
+    value REAL NOT NULL,
+    tags_json TEXT NOT NULL DEFAULT '{}',
+    timestamp TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_metrics_name_timestamp ON metrics(name, timestamp);
+CREATE INDEX IF NOT EXISTS idx_metrics_timestamp ON metrics(timestamp);
+CREATE INDEX IF NOT EXISTS idx_metrics_series_timestamp ON metrics(name, tags_json, timestamp);
+
+-- One row per (name, tags) series so the cardinality cap is a key lookup
+-- and a count over at most the cap, not a scan of every sample.
+CREATE TABLE IF NOT EXISTS metric_series (
+    name TEXT NOT NULL,
+    tags_json TEXT NOT NULL DEFAULT '{}',
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+    PRIMARY KEY (name, tags_json)
+);
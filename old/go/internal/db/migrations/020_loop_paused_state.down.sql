@@ -0,0 +1,75 @@
+-- Migration: 020_loop_paused_state (DOWN)
+-- Description: Drop the paused loop state; paused loops become stopped
+-- Created: 2026-10-16
+
+-- SQLite cannot alter a CHECK constraint; rebuild the table.
+CREATE TABLE loops_new (
+    id TEXT PRIMARY KEY,
+    short_id TEXT,
+    name TEXT NOT NULL UNIQUE,
+    repo_path TEXT NOT NULL,
+    base_prompt_path TEXT,
+    base_prompt_msg TEXT,
+    interval_seconds INTEGER NOT NULL DEFAULT 30,
+    max_iterations INTEGER NOT NULL DEFAULT 0,
+    max_runtime_seconds INTEGER NOT NULL DEFAULT 0,
+    pool_id TEXT REFERENCES pools(id) ON DELETE SET NULL,
+    profile_id TEXT REFERENCES profiles(id) ON DELETE SET NULL,
+    state TEXT NOT NULL DEFAULT 'stopped' CHECK (state IN ('running', 'sleeping', 'waiting', 'stopped', 'error')),
+    last_run_at TEXT,
+    last_exit_code INTEGER,
+    last_error TEXT,
+    log_path TEXT,
+    ledger_path TEXT,
+    tags_json TEXT,
+    metadata_json TEXT,
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+INSERT INTO loops_new (
+    id, short_id, name, repo_path, base_prompt_path, base_prompt_msg,
+    interval_seconds, max_iterations, max_runtime_seconds, pool_id, profile_id, state,
+    last_run_at, last_exit_code, last_error,
+    log_path, ledger_path, tags_json, metadata_json,
+    created_at, updated_at
+)
+SELECT
+    id, short_id, name, repo_path, base_prompt_path, base_prompt_msg,
+    interval_seconds, max_iterations, max_runtime_seconds, pool_id, profile_id,
+    CASE state WHEN 'paused' THEN 'stopped' ELSE state END,
+    last_run_at, last_exit_code, last_error,
+    log_path, ledger_path, tags_json, metadata_json,
+    created_at, updated_at
+FROM loops;
+
+-- DROP TABLE cascades into the child tables while foreign keys are on, so
+-- keep their rows aside and restore them once the new table is in place.
+CREATE TEMP TABLE loop_queue_items_stash AS SELECT * FROM loop_queue_items;
+CREATE TEMP TABLE loop_runs_stash AS SELECT * FROM loop_runs;
+CREATE TEMP TABLE loop_kv_stash AS SELECT * FROM loop_kv;
+CREATE TEMP TABLE loop_work_state_stash AS SELECT * FROM loop_work_state;
+
+DROP TABLE loops;
+ALTER TABLE loops_new RENAME TO loops;
+
+INSERT INTO loop_queue_items SELECT * FROM temp.loop_queue_items_stash;
+INSERT INTO loop_runs SELECT * FROM temp.loop_runs_stash;
+INSERT INTO loop_kv SELECT * FROM temp.loop_kv_stash;
+INSERT INTO loop_work_state SELECT * FROM temp.loop_work_state_stash;
+DROP TABLE temp.loop_queue_items_stash;
+DROP TABLE temp.loop_runs_stash;
+DROP TABLE temp.loop_kv_stash;
+DROP TABLE temp.loop_work_state_stash;
+
+CREATE INDEX IF NOT EXISTS idx_loops_repo_path ON loops(repo_path);
+CREATE INDEX IF NOT EXISTS idx_loops_state ON loops(state);
+CREATE INDEX IF NOT EXISTS idx_loops_pool_id ON loops(pool_id);
+CREATE INDEX IF NOT EXISTS idx_loops_profile_id ON loops(profile_id);
+CREATE UNIQUE INDEX IF NOT EXISTS idx_loops_short_id ON loops(short_id);
+
+CREATE TRIGGER IF NOT EXISTS update_loops_timestamp
+AFTER UPDATE ON loops
+BEGIN
+    UPDATE loops SET updated_at = datetime('now') WHERE id = NEW.id;
+END;
@@ -0,0 +1,9 @@
+-- Migration: 021_event_loop_entity (DOWN)
+-- Description: Keep the events table as-is on rollback
+-- Created: 2026-10-16
+
+-- The up migration only widens the entity_type CHECK. Narrowing it again
+-- would mean deleting loop events, and every event after the first deleted
+-- one would then fail hash-chain verification. Older code never writes loop
+-- events, so the wider constraint is harmless to leave in place.
+SELECT 1;
@@ -0,0 +1,39 @@
+-- Migration: 021_event_loop_entity
+-- Description: Allow events to be recorded against loops
+-- Created: 2026-10-16
+
+DROP INDEX IF EXISTS idx_events_timestamp;
+DROP INDEX IF EXISTS idx_events_type;
+DROP INDEX IF EXISTS idx_events_entity;
+DROP INDEX IF EXISTS idx_events_entity_timestamp;
+
+-- SQLite cannot alter a CHECK constraint; rebuild the table.
+CREATE TABLE events_new (
+    id TEXT PRIMARY KEY,
+    timestamp TEXT NOT NULL DEFAULT (datetime('now')),
+    type TEXT NOT NULL,
+    entity_type TEXT NOT NULL CHECK (entity_type IN ('node', 'workspace', 'agent', 'queue', 'account', 'system', 'loop')),
+    entity_id TEXT NOT NULL,
+    payload_json TEXT,
+    metadata_json TEXT,
+    prev_hash TEXT,
+    chain_hash TEXT
+);
+
+INSERT INTO events_new (
+    id, timestamp, type, entity_type, entity_id, payload_json, metadata_json,
+    prev_hash, chain_hash
+)
+SELECT
+    id, timestamp, type, entity_type, entity_id, payload_json, metadata_json,
+    prev_hash, chain_hash
+FROM events
+ORDER BY rowid;
+
+DROP TABLE events;
+ALTER TABLE events_new RENAME TO events;
+
+CREATE INDEX IF NOT EXISTS idx_events_timestamp ON events(timestamp);
+CREATE INDEX IF NOT EXISTS idx_events_type ON events(type);
+CREATE INDEX IF NOT EXISTS idx_events_entity ON events(entity_type, entity_id);
+CREATE INDEX IF NOT EXISTS idx_events_entity_timestamp ON events(entity_type, entity_id, timestamp);
@@ -0,0 +1,5 @@
+-- Migration: 022_event_chain_anchor (DOWN)
+-- Description: Remove the event chain anchor
+-- Created: 2026-10-16
+
+DROP TABLE IF EXISTS event_chain_anchor;
@@ -0,0 +1,18 @@
+-- Migration: 022_event_chain_anchor
+-- Description: Record the event chain's genesis and tail hashes so removing the first or last events is detectable
+-- Created: 2026-10-16
+
+-- genesis_hash is the prev_hash the oldest remaining event must carry; it
+-- only moves when retention prunes the head of the log. tail_hash is the
+-- chain_hash of the newest event ever written.
+CREATE TABLE IF NOT EXISTS event_chain_anchor (
+    id INTEGER PRIMARY KEY CHECK (id = 1),
+    genesis_hash TEXT NOT NULL DEFAULT '',
+    tail_hash TEXT NOT NULL DEFAULT ''
+);
+
+INSERT OR IGNORE INTO event_chain_anchor (id, genesis_hash, tail_hash)
+SELECT
+    1,
+    COALESCE((SELECT prev_hash FROM events WHERE chain_hash IS NOT NULL ORDER BY rowid LIMIT 1), ''),
+    COALESCE((SELECT chain_hash FROM events WHERE chain_hash IS NOT NULL ORDER BY rowid DESC LIMIT 1), '');
@@ -0,0 +1,5 @@
+-- Migration: 023_alert_list_order (DOWN)
+-- Description: Drop the alert list ordering index
+-- Created: 2026-10-16
+
+DROP INDEX IF EXISTS idx_alerts_list_order;
@@ -0,0 +1,17 @@
+-- Migration: 023_alert_list_order
+-- Description: Index alerts in the order AlertRepository::list returns them
+-- Created: 2026-10-16
+
+-- The expression must match the ORDER BY in AlertRepository::list exactly
+-- for SQLite to walk the index instead of sorting.
+CREATE INDEX IF NOT EXISTS idx_alerts_list_order ON alerts(
+    is_resolved,
+    (CASE severity
+        WHEN 'critical' THEN 0
+        WHEN 'error' THEN 1
+        WHEN 'warning' THEN 2
+        ELSE 3
+    END),
+    created_at DESC,
+    id
+);
@@ -0,0 +1,37 @@
+-- Migration: 024_queue_dead_letter (DOWN)
+-- Description: Drop the dead_letter queue status; dead-lettered items become failed
+-- Created: 2026-10-16
+
+-- SQLite cannot alter a CHECK constraint; rebuild the table. No table
+-- references queue_items, so nothing has to be stashed across the drop.
+CREATE TABLE queue_items_new (
+    id TEXT PRIMARY KEY,
+    agent_id TEXT NOT NULL REFERENCES agents(id) ON DELETE CASCADE,
+    type TEXT NOT NULL CHECK (type IN ('message', 'pause', 'conditional')),
+    position INTEGER NOT NULL,
+    status TEXT NOT NULL DEFAULT 'pending' CHECK (status IN ('pending', 'dispatched', 'completed', 'failed', 'skipped')),
+    payload_json TEXT NOT NULL,
+    error_message TEXT,
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    dispatched_at TEXT,
+    completed_at TEXT,
+    attempts INTEGER NOT NULL DEFAULT 0
+);
+
+INSERT INTO queue_items_new (
+    id, agent_id, type, position, status, payload_json, error_message,
+    created_at, dispatched_at, completed_at, attempts
+)
+SELECT
+    id, agent_id, type, position,
+    CASE status WHEN 'dead_letter' THEN 'failed' ELSE status END,
+    payload_json, error_message,
+    created_at, dispatched_at, completed_at, attempts
+FROM queue_items;
+
+DROP TABLE queue_items;
+ALTER TABLE queue_items_new RENAME TO queue_items;
+
+CREATE INDEX IF NOT EXISTS idx_queue_items_agent_id ON queue_items(agent_id);
+CREATE INDEX IF NOT EXISTS idx_queue_items_status ON queue_items(status);
+CREATE INDEX IF NOT EXISTS idx_queue_items_position ON queue_items(agent_id, position);
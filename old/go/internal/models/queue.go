@@ -25,6 +25,7 @@ const (
 	QueueItemStatusCompleted  QueueItemStatus = "completed"
 	QueueItemStatusFailed     QueueItemStatus = "failed"
 	QueueItemStatusSkipped    QueueItemStatus = "skipped"
+	QueueItemStatusDeadLetter QueueItemStatus = "dead_letter"
 )
 
 // QueueItem represents an item in an agent's message queue.